@@ -0,0 +1,86 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:kivixa/src/rust/frb_generated.dart';
+import 'package:kivixa/src/rust/mcp.dart';
+
+/// Full trace of an agent run
+class AgentRun {
+  /// The final answer (empty when the step limit was hit)
+  final String answer;
+
+  /// Why the run ended
+  final AgentStopReason stopReason;
+
+  /// Every reply with its tool calls and results
+  final List<AgentStep> steps;
+
+  /// The conversation including the tool prompt, replies and tool results
+  final List<(String, String)> messages;
+
+  const AgentRun({
+    required this.answer,
+    required this.stopReason,
+    required this.steps,
+    required this.messages,
+  });
+
+  @override
+  int get hashCode =>
+      answer.hashCode ^
+      stopReason.hashCode ^
+      steps.hashCode ^
+      messages.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AgentRun &&
+          runtimeType == other.runtimeType &&
+          answer == other.answer &&
+          stopReason == other.stopReason &&
+          steps == other.steps &&
+          messages == other.messages;
+}
+
+/// One model reply and the tool calls it made
+class AgentStep {
+  /// The raw reply
+  final String reply;
+
+  /// Tool calls parsed from the reply, in order
+  final List<MCPToolCall> toolCalls;
+
+  /// Result of each tool call
+  final List<MCPToolResult> results;
+
+  const AgentStep({
+    required this.reply,
+    required this.toolCalls,
+    required this.results,
+  });
+
+  @override
+  int get hashCode => reply.hashCode ^ toolCalls.hashCode ^ results.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AgentStep &&
+          runtimeType == other.runtimeType &&
+          reply == other.reply &&
+          toolCalls == other.toolCalls &&
+          results == other.results;
+}
+
+/// Why an agent run ended
+enum AgentStopReason {
+  /// The model replied without calling a tool
+  finalAnswer,
+
+  /// `max_steps` replies were used up while the model kept calling tools
+  stepLimit,
+}
@@ -4,13 +4,32 @@
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:kivixa/src/rust/agent.dart';
+import 'package:kivixa/src/rust/chunker.dart';
 import 'package:kivixa/src/rust/clustering.dart';
+import 'package:kivixa/src/rust/context_window.dart';
+import 'package:kivixa/src/rust/dedup.dart';
+import 'package:kivixa/src/rust/embedder.dart';
+import 'package:kivixa/src/rust/embedding_cache.dart';
 import 'package:kivixa/src/rust/embeddings.dart';
+import 'package:kivixa/src/rust/filtered_search.dart';
 import 'package:kivixa/src/rust/frb_generated.dart';
+import 'package:kivixa/src/rust/gguf.dart';
 import 'package:kivixa/src/rust/graph.dart';
+import 'package:kivixa/src/rust/hnsw.dart';
+import 'package:kivixa/src/rust/hybrid_search.dart';
+import 'package:kivixa/src/rust/inference.dart';
 import 'package:kivixa/src/rust/mcp.dart';
+import 'package:kivixa/src/rust/quantization.dart';
+import 'package:kivixa/src/rust/rag.dart';
+import 'package:kivixa/src/rust/reasoning.dart';
 import 'package:kivixa/src/rust/streaming.dart';
+import 'package:kivixa/src/rust/summarize.dart';
+import 'package:kivixa/src/rust/tokenizer.dart';
+import 'package:kivixa/src/rust/tool_parser.dart';
+import 'package:kivixa/src/rust/vector_store.dart';
 
+// These functions are ignored because they are not marked as `pub`: `agent_config`, `chunker_options`, `stop_options`
 // These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`
 
 /// Initialize an AI model from the given path (auto-detects model type)
@@ -39,19 +58,64 @@ Future<void> initModelWithConfig({
   modelType: modelType,
 );
 
+/// Read a GGUF file's metadata without loading the model
+///
+/// Useful for showing model details (architecture, size, quantization,
+/// context length) before the user picks a model.
+Future<GgufMetadata> inspectModel({required String modelPath}) =>
+    RustLib.instance.api.crateApiInspectModel(modelPath: modelPath);
+
+/// Get the GGUF metadata of a loaded model (None = the default model)
+GgufMetadata getModelMetadata({String? modelId}) =>
+    RustLib.instance.api.crateApiGetModelMetadata(modelId: modelId);
+
 /// Get the loaded model type (0 = Phi4, 1 = Qwen, 2 = Functionary, -1 = not loaded)
 int getModelType() => RustLib.instance.api.crateApiGetModelType();
 
 /// Check if the model is loaded
 bool isModelLoaded() => RustLib.instance.api.crateApiIsModelLoaded();
 
-/// Unload the model and free resources
+/// Unload the model loaded by `init_model` and free resources
+///
+/// Models loaded under other ids stay loaded.
 void unloadModel() => RustLib.instance.api.crateApiUnloadModel();
 
 /// Get the embedding dimension of the loaded model
 BigInt getEmbeddingDimension() =>
     RustLib.instance.api.crateApiGetEmbeddingDimension();
 
+/// Load an additional model under an id, keeping other models loaded
+///
+/// The model type is auto-detected from the path. If the memory budget
+/// would be exceeded, least recently used models are unloaded first.
+Future<void> loadModel({
+  required String modelId,
+  required String modelPath,
+  int? nCtx,
+  int? nGpuLayers,
+}) => RustLib.instance.api.crateApiLoadModel(
+  modelId: modelId,
+  modelPath: modelPath,
+  nCtx: nCtx,
+  nGpuLayers: nGpuLayers,
+);
+
+/// Unload a single model, returning false if it was not loaded
+bool unloadModelById({required String modelId}) =>
+    RustLib.instance.api.crateApiUnloadModelById(modelId: modelId);
+
+/// Make a loaded model the default for calls that do not name a model
+void setDefaultModel({required String modelId}) =>
+    RustLib.instance.api.crateApiSetDefaultModel(modelId: modelId);
+
+/// List loaded models, most recently used first
+List<LoadedModelInfo> listLoadedModels() =>
+    RustLib.instance.api.crateApiListLoadedModels();
+
+/// Limit the combined size of loaded models in bytes (None = unlimited)
+void setModelMemoryBudget({BigInt? budgetBytes}) =>
+    RustLib.instance.api.crateApiSetModelMemoryBudget(budgetBytes: budgetBytes);
+
 /// Generate text completion from a prompt
 Future<String> generateText({required String prompt, int? maxTokens}) => RustLib
     .instance
@@ -70,6 +134,203 @@ Future<String> chatCompletion({
   maxTokens: maxTokens,
 );
 
+/// Generate text with a specific loaded model
+Future<String> generateTextWithModel({
+  required String prompt,
+  int? maxTokens,
+  String? modelId,
+}) => RustLib.instance.api.crateApiGenerateTextWithModel(
+  prompt: prompt,
+  maxTokens: maxTokens,
+  modelId: modelId,
+);
+
+/// Chat completion with a specific loaded model
+///
+/// Messages should be a list of (role, content) tuples where role is
+/// "system", "user", or "assistant"
+Future<String> chatCompletionWithModel({
+  required List<(String, String)> messages,
+  int? maxTokens,
+  String? modelId,
+}) => RustLib.instance.api.crateApiChatCompletionWithModel(
+  messages: messages,
+  maxTokens: maxTokens,
+  modelId: modelId,
+);
+
+/// Generate text with per-request sampling, token limit and stop sequences
+Future<GenerationResult> generateTextWithOptions({
+  required String prompt,
+  required GenerationOptions options,
+}) => RustLib.instance.api.crateApiGenerateTextWithOptions(
+  prompt: prompt,
+  options: options,
+);
+
+/// Chat completion with per-request sampling, token limit and stop sequences
+Future<GenerationResult> chatCompletionWithOptions({
+  required List<(String, String)> messages,
+  required GenerationOptions options,
+}) => RustLib.instance.api.crateApiChatCompletionWithOptions(
+  messages: messages,
+  options: options,
+);
+
+/// Default context policy, the same as passing no policy: fail with the
+/// token counts when the window is full
+ContextPolicy defaultContextPolicy() =>
+    RustLib.instance.api.crateApiDefaultContextPolicy();
+
+/// Count the tokens in each message, e.g. to show how full the context is
+Future<Uint32List> countChatTokens({
+  required List<(String, String)> messages,
+  String? modelId,
+}) => RustLib.instance.api.crateApiCountChatTokens(
+  messages: messages,
+  modelId: modelId,
+);
+
+/// Tokenize text into token ids (None model = the default model)
+///
+/// With `parse_special`, control tokens written in the text (e.g.
+/// `<|im_start|>`) become single tokens; otherwise they are plain text.
+Future<Int32List> tokenizeText({
+  required String text,
+  required bool addBos,
+  required bool parseSpecial,
+  String? modelId,
+}) => RustLib.instance.api.crateApiTokenizeText(
+  text: text,
+  addBos: addBos,
+  parseSpecial: parseSpecial,
+  modelId: modelId,
+);
+
+/// Turn token ids back into text, optionally rendering control tokens
+Future<String> detokenizeTokens({
+  required List<int> tokens,
+  required bool renderSpecial,
+  String? modelId,
+}) => RustLib.instance.api.crateApiDetokenizeTokens(
+  tokens: tokens,
+  renderSpecial: renderSpecial,
+  modelId: modelId,
+);
+
+/// Count the tokens in a text
+Future<int> countTokens({required String text, String? modelId}) =>
+    RustLib.instance.api.crateApiCountTokens(text: text, modelId: modelId);
+
+/// Count the tokens of a conversation after the chat template is applied
+Future<int> countChatPromptTokens({
+  required List<(String, String)> messages,
+  String? modelId,
+}) => RustLib.instance.api.crateApiCountChatPromptTokens(
+  messages: messages,
+  modelId: modelId,
+);
+
+/// Get vocabulary size, training context and special tokens of a loaded model
+TokenizerInfo getTokenizerInfo({String? modelId}) =>
+    RustLib.instance.api.crateApiGetTokenizerInfo(modelId: modelId);
+
+/// Default sampler settings (temperature 0.7, top_k 40, top_p 0.9, min_p 0.05)
+SamplerConfig defaultSamplerConfig() =>
+    RustLib.instance.api.crateApiDefaultSamplerConfig();
+
+/// Greedy sampler settings for deterministic output
+SamplerConfig greedySamplerConfig() =>
+    RustLib.instance.api.crateApiGreedySamplerConfig();
+
+/// Default reasoning separation (`<think>` tags, no thinking budget)
+///
+/// Set `GenerationOptions::reasoning` to change the tags, cap the thinking
+/// budget or return the raw output of a reasoning model.
+ReasoningOptions defaultReasoningOptions() =>
+    RustLib.instance.api.crateApiDefaultReasoningOptions();
+
+/// Convert a JSON Schema into a GBNF grammar for `GenerationOptions::grammar`
+String jsonSchemaToGrammar({required String schemaJson}) =>
+    RustLib.instance.api.crateApiJsonSchemaToGrammar(schemaJson: schemaJson);
+
+/// Stream a text completion token by token
+///
+/// Each detokenized piece is pushed to the sink as soon as it is sampled.
+/// The final chunk has empty text and reports why generation stopped.
+Stream<GenerationChunk> generateTextStream({
+  required String prompt,
+  int? maxTokens,
+  List<String>? stopSequences,
+  String? modelId,
+}) => RustLib.instance.api.crateApiGenerateTextStream(
+  prompt: prompt,
+  maxTokens: maxTokens,
+  stopSequences: stopSequences,
+  modelId: modelId,
+);
+
+/// Stream a chat completion token by token
+///
+/// Messages should be a list of (role, content) tuples where role is
+/// "system", "user", or "assistant". The final chunk has empty text and
+/// reports why generation stopped.
+Stream<GenerationChunk> chatCompletionStream({
+  required List<(String, String)> messages,
+  int? maxTokens,
+  List<String>? stopSequences,
+  String? modelId,
+}) => RustLib.instance.api.crateApiChatCompletionStream(
+  messages: messages,
+  maxTokens: maxTokens,
+  stopSequences: stopSequences,
+  modelId: modelId,
+);
+
+/// Start a text completion in the background and return its request id
+///
+/// Unlike `generate_text`, the request can be stopped with `cancel_generation`.
+Future<BigInt> startGeneration({
+  required String prompt,
+  int? maxTokens,
+  List<String>? stopSequences,
+  String? modelId,
+}) => RustLib.instance.api.crateApiStartGeneration(
+  prompt: prompt,
+  maxTokens: maxTokens,
+  stopSequences: stopSequences,
+  modelId: modelId,
+);
+
+/// Start a chat completion in the background and return its request id
+Future<BigInt> startChatCompletion({
+  required List<(String, String)> messages,
+  int? maxTokens,
+  List<String>? stopSequences,
+  String? modelId,
+}) => RustLib.instance.api.crateApiStartChatCompletion(
+  messages: messages,
+  maxTokens: maxTokens,
+  stopSequences: stopSequences,
+  modelId: modelId,
+);
+
+/// Cancel a running request at the next token boundary
+///
+/// Returns false if the request is unknown or has already finished.
+bool cancelGeneration({required BigInt requestId}) =>
+    RustLib.instance.api.crateApiCancelGeneration(requestId: requestId);
+
+/// Get the text generated since the last poll, plus the result once finished
+GenerationPoll pollGeneration({required BigInt requestId}) =>
+    RustLib.instance.api.crateApiPollGeneration(requestId: requestId);
+
+/// Wait for a request to finish
+///
+/// Cancelled requests return their partial output.
+Future<GenerationResult> waitForGeneration({required BigInt requestId}) =>
+    RustLib.instance.api.crateApiWaitForGeneration(requestId: requestId);
+
 /// Extract topics from note content
 Future<List<String>> extractTopics({required String text, int? numTopics}) =>
     RustLib.instance.api.crateApiExtractTopics(
@@ -77,14 +338,167 @@ Future<List<String>> extractTopics({required String text, int? numTopics}) =>
       numTopics: numTopics,
     );
 
+/// Extract topics from note content with a specific loaded model
+///
+/// With `min_confidence` set (0.0 to 1.0), low-confidence answers are
+/// rejected with an error instead of returned.
+Future<List<String>> extractTopicsWithModel({
+  required String text,
+  int? numTopics,
+  String? modelId,
+  double? minConfidence,
+}) => RustLib.instance.api.crateApiExtractTopicsWithModel(
+  text: text,
+  numTopics: numTopics,
+  modelId: modelId,
+  minConfidence: minConfidence,
+);
+
+/// Create a chat session that keeps its KV cache between turns
+///
+/// Returns the session id used by the other session functions. The session
+/// stays on the model it was created with.
+Future<BigInt> createChatSession({int? nCtx, String? modelId}) => RustLib
+    .instance
+    .api
+    .crateApiCreateChatSession(nCtx: nCtx, modelId: modelId);
+
+/// Run a chat turn in a session
+///
+/// Pass the full conversation each turn. Only messages that are new or
+/// edited since the previous turn are evaluated.
+Future<GenerationResult> chatSessionCompletion({
+  required BigInt sessionId,
+  required List<(String, String)> messages,
+  required GenerationOptions options,
+}) => RustLib.instance.api.crateApiChatSessionCompletion(
+  sessionId: sessionId,
+  messages: messages,
+  options: options,
+);
+
+/// Stream a chat turn in a session token by token
+Stream<GenerationChunk> chatSessionStream({
+  required BigInt sessionId,
+  required List<(String, String)> messages,
+  int? maxTokens,
+  List<String>? stopSequences,
+}) => RustLib.instance.api.crateApiChatSessionStream(
+  sessionId: sessionId,
+  messages: messages,
+  maxTokens: maxTokens,
+  stopSequences: stopSequences,
+);
+
+/// Number of tokens currently cached by a session
+BigInt chatSessionTokenCount({required BigInt sessionId}) =>
+    RustLib.instance.api.crateApiChatSessionTokenCount(sessionId: sessionId);
+
+/// Save a session to disk so it can be restored after a restart
+///
+/// Also writes `<path>.meta`, which records the model and context size.
+Future<void> saveChatSession({
+  required BigInt sessionId,
+  required String path,
+}) => RustLib.instance.api.crateApiSaveChatSession(
+  sessionId: sessionId,
+  path: path,
+);
+
+/// Restore a session saved with `save_chat_session`, returning its new id
+///
+/// Fails if the loaded model is not the one the session was saved from.
+/// `n_ctx` defaults to the saved context size.
+Future<BigInt> loadChatSession({
+  required String path,
+  int? nCtx,
+  String? modelId,
+}) => RustLib.instance.api.crateApiLoadChatSession(
+  path: path,
+  nCtx: nCtx,
+  modelId: modelId,
+);
+
+/// Close a session and free its context
+bool closeChatSession({required BigInt sessionId}) =>
+    RustLib.instance.api.crateApiCloseChatSession(sessionId: sessionId);
+
+/// Load a dedicated embedding model (e.g. nomic-embed or bge GGUF)
+///
+/// Once loaded, all embedding functions use it instead of the chat model.
+Future<void> loadEmbeddingModel({
+  required String modelPath,
+  EmbeddingConfig? config,
+}) => RustLib.instance.api.crateApiLoadEmbeddingModel(
+  modelPath: modelPath,
+  config: config,
+);
+
+/// Unload the embedding model; embeddings fall back to the chat model
+void unloadEmbeddingModel() =>
+    RustLib.instance.api.crateApiUnloadEmbeddingModel();
+
+/// Check if a dedicated embedding model is loaded
+bool isEmbeddingModelLoaded() =>
+    RustLib.instance.api.crateApiIsEmbeddingModelLoaded();
+
+/// Default embedding model settings (native pooling, 512-token chunks)
+EmbeddingConfig defaultEmbeddingConfig() =>
+    RustLib.instance.api.crateApiDefaultEmbeddingConfig();
+
+/// Open an on-disk embedding cache (e.g. in the app support directory)
+///
+/// While it is open, `batch_embed` and `embed_markdown_note` only embed
+/// texts that are not cached for the current embedding model.
+Future<void> openEmbeddingCache({required String path}) =>
+    RustLib.instance.api.crateApiOpenEmbeddingCache(path: path);
+
+/// Close the embedding cache; embeddings are computed every time again
+void closeEmbeddingCache() =>
+    RustLib.instance.api.crateApiCloseEmbeddingCache();
+
+/// Rewrite the cache file without entries of previous models
+Future<EmbeddingCacheStats> compactEmbeddingCache() =>
+    RustLib.instance.api.crateApiCompactEmbeddingCache();
+
+/// Entry count and file size of the embedding cache (None if none is open)
+Future<EmbeddingCacheStats?> getEmbeddingCacheStats() =>
+    RustLib.instance.api.crateApiGetEmbeddingCacheStats();
+
+/// Embed many texts in shared batches
+///
+/// Queries and documents get their configured instruction prefixes.
+Future<List<Float32List>> embedTexts({
+  required List<String> texts,
+  required EmbeddingKind kind,
+}) => RustLib.instance.api.crateApiEmbedTexts(texts: texts, kind: kind);
+
 /// Get embedding for text
 Future<Float32List> getEmbedding({required String text}) =>
     RustLib.instance.api.crateApiGetEmbedding(text: text);
 
+/// Get embedding for text with a specific loaded model
+Future<Float32List> getEmbeddingWithModel({
+  required String text,
+  String? modelId,
+}) => RustLib.instance.api.crateApiGetEmbeddingWithModel(
+  text: text,
+  modelId: modelId,
+);
+
 /// Compute embeddings for multiple texts
 Future<List<EmbeddingEntry>> batchEmbed({required List<String> texts}) =>
     RustLib.instance.api.crateApiBatchEmbed(texts: texts);
 
+/// Compute embeddings for multiple texts with a specific loaded model
+Future<List<EmbeddingEntry>> batchEmbedWithModel({
+  required List<String> texts,
+  String? modelId,
+}) => RustLib.instance.api.crateApiBatchEmbedWithModel(
+  texts: texts,
+  modelId: modelId,
+);
+
 /// Find similar entries to a query embedding
 Future<List<SimilarityResult>> findSimilar({
   required List<double> query,
@@ -109,6 +523,494 @@ Future<List<SimilarityResult>> semanticSearch({
   topK: topK,
 );
 
+/// Split a markdown note into token-limited chunks
+///
+/// # Arguments
+/// * `text` - The note's markdown
+/// * `max_tokens` - Maximum tokens per chunk (default: 512)
+/// * `overlap_tokens` - Tokens repeated between chunks (default: 64)
+/// * `model_id` - Model whose tokenizer counts tokens
+///
+/// # Returns
+/// * Chunks with their heading paths and byte ranges
+Future<List<MarkdownChunk>> chunkMarkdown({
+  required String text,
+  int? maxTokens,
+  int? overlapTokens,
+  String? modelId,
+}) => RustLib.instance.api.crateApiChunkMarkdown(
+  text: text,
+  maxTokens: maxTokens,
+  overlapTokens: overlapTokens,
+  modelId: modelId,
+);
+
+/// Chunk a markdown note and embed each chunk
+///
+/// # Arguments
+/// * `note_id` - Id of the note; entries get ids like `note_id#chunk3`
+/// * `text` - The note's markdown
+/// * `max_tokens` - Maximum tokens per chunk (default: 512)
+/// * `overlap_tokens` - Tokens repeated between chunks (default: 64)
+/// * `metadata` - Note metadata copied to every chunk, for filtered search
+///
+/// # Returns
+/// * One entry per chunk, with the chunk text as `text_preview` and its
+///   byte range as `metadata.chunk_start`/`chunk_end`
+Future<List<EmbeddingEntry>> embedMarkdownNote({
+  required String noteId,
+  required String text,
+  int? maxTokens,
+  int? overlapTokens,
+  NoteMetadata? metadata,
+}) => RustLib.instance.api.crateApiEmbedMarkdownNote(
+  noteId: noteId,
+  text: text,
+  maxTokens: maxTokens,
+  overlapTokens: overlapTokens,
+  metadata: metadata,
+);
+
+/// Default summary options: bullets of at most 200 words
+SummaryOptions defaultSummaryOptions() =>
+    RustLib.instance.api.crateApiDefaultSummaryOptions();
+
+/// Summarize a long note by summarizing its chunks and merging the results
+Future<Summary> summarizeText({
+  required String text,
+  SummaryOptions? options,
+}) => RustLib.instance.api.crateApiSummarizeText(text: text, options: options);
+
+/// Summarize a transcript (e.g. a recording's transcription segments),
+/// keeping timestamps for the key points
+Future<Summary> summarizeTranscript({
+  required List<TranscriptSegment> segments,
+  SummaryOptions? options,
+}) => RustLib.instance.api.crateApiSummarizeTranscript(
+  segments: segments,
+  options: options,
+);
+
+/// Answer a question from note chunks, citing the chunks used
+///
+/// # Arguments
+/// * `question` - The user's question
+/// * `chunks` - Embedded chunks with ids `note_id#offset` or `note_id#chunkN`
+///   and the chunk text as `text_preview`
+/// * `top_k` - Number of chunks to retrieve (default: 5)
+/// * `context_tokens` - Token budget for the retrieved chunks (default: 1024)
+/// * `max_tokens` - Maximum tokens for the answer
+/// * `model_id` - Model to answer with (`None` = the default model)
+///
+/// # Returns
+/// * The answer with its sources, their scores and which were cited
+Future<RagAnswer> ragAnswer({
+  required String question,
+  required List<EmbeddingEntry> chunks,
+  int? topK,
+  int? contextTokens,
+  int? maxTokens,
+  String? modelId,
+}) => RustLib.instance.api.crateApiRagAnswer(
+  question: question,
+  chunks: chunks,
+  topK: topK,
+  contextTokens: contextTokens,
+  maxTokens: maxTokens,
+  modelId: modelId,
+);
+
+/// Default HNSW parameters (m = 16, ef_construction = 200, ef_search = 64)
+HnswConfig defaultHnswConfig() =>
+    RustLib.instance.api.crateApiDefaultHnswConfig();
+
+/// Create an empty HNSW index for fast similarity search
+///
+/// Returns the index id used by the other index functions.
+BigInt createHnswIndex({HnswConfig? config}) =>
+    RustLib.instance.api.crateApiCreateHnswIndex(config: config);
+
+/// Load an HNSW index saved with `save_hnsw_index`
+Future<BigInt> loadHnswIndex({required String path}) =>
+    RustLib.instance.api.crateApiLoadHnswIndex(path: path);
+
+/// Save an HNSW index to disk
+Future<void> saveHnswIndex({required BigInt indexId, required String path}) =>
+    RustLib.instance.api.crateApiSaveHnswIndex(indexId: indexId, path: path);
+
+/// Close an HNSW index and free its memory
+bool closeHnswIndex({required BigInt indexId}) =>
+    RustLib.instance.api.crateApiCloseHnswIndex(indexId: indexId);
+
+/// Insert entries into an index, replacing entries with the same id
+Future<void> hnswIndexInsert({
+  required BigInt indexId,
+  required List<EmbeddingEntry> entries,
+}) => RustLib.instance.api.crateApiHnswIndexInsert(
+  indexId: indexId,
+  entries: entries,
+);
+
+/// Insert entries into an index with the full text of each, so keyword
+/// search in `hybrid_search_in_index` sees more than `text_preview`
+Future<void> hnswIndexInsertWithTexts({
+  required BigInt indexId,
+  required List<EmbeddingEntry> entries,
+  required List<String> texts,
+}) => RustLib.instance.api.crateApiHnswIndexInsertWithTexts(
+  indexId: indexId,
+  entries: entries,
+  texts: texts,
+);
+
+/// Remove entries from an index; returns how many existed
+Future<BigInt> hnswIndexRemove({
+  required BigInt indexId,
+  required List<String> ids,
+}) => RustLib.instance.api.crateApiHnswIndexRemove(indexId: indexId, ids: ids);
+
+/// Number of entries in an index
+BigInt hnswIndexLen({required BigInt indexId}) =>
+    RustLib.instance.api.crateApiHnswIndexLen(indexId: indexId);
+
+/// Find similar entries to a query embedding using an index
+Future<List<SimilarityResult>> findSimilarInIndex({
+  required BigInt indexId,
+  required List<double> query,
+  required BigInt topK,
+  required double threshold,
+}) => RustLib.instance.api.crateApiFindSimilarInIndex(
+  indexId: indexId,
+  query: query,
+  topK: topK,
+  threshold: threshold,
+);
+
+/// Semantic search: embed query and find similar entries using an index
+Future<List<SimilarityResult>> semanticSearchInIndex({
+  required BigInt indexId,
+  required String queryText,
+  required BigInt topK,
+}) => RustLib.instance.api.crateApiSemanticSearchInIndex(
+  indexId: indexId,
+  queryText: queryText,
+  topK: topK,
+);
+
+/// Default filtered search settings (top 10, threshold 0.5, no filter, no MMR)
+SearchOptions defaultSearchOptions() =>
+    RustLib.instance.api.crateApiDefaultSearchOptions();
+
+/// Check a metadata filter expression, e.g.
+/// `tag in [exam, lecture] and folder under courses and modified >= 2024-09-01`
+///
+/// Returns an error describing the first problem.
+void validateSearchFilter({required String filter}) =>
+    RustLib.instance.api.crateApiValidateSearchFilter(filter: filter);
+
+/// Find similar entries that pass a metadata filter, optionally re-ranked
+/// with maximal marginal relevance for more varied results
+Future<List<SimilarityResult>> findSimilarFiltered({
+  required List<double> query,
+  required List<EmbeddingEntry> entries,
+  required SearchOptions options,
+}) => RustLib.instance.api.crateApiFindSimilarFiltered(
+  query: query,
+  entries: entries,
+  options: options,
+);
+
+/// Semantic search over entries that pass a metadata filter
+Future<List<SimilarityResult>> semanticSearchFiltered({
+  required String queryText,
+  required List<EmbeddingEntry> entries,
+  required SearchOptions options,
+}) => RustLib.instance.api.crateApiSemanticSearchFiltered(
+  queryText: queryText,
+  entries: entries,
+  options: options,
+);
+
+/// Semantic search with a metadata filter using an HNSW index
+Future<List<SimilarityResult>> semanticSearchInIndexFiltered({
+  required BigInt indexId,
+  required String queryText,
+  required SearchOptions options,
+}) => RustLib.instance.api.crateApiSemanticSearchInIndexFiltered(
+  indexId: indexId,
+  queryText: queryText,
+  options: options,
+);
+
+/// Default hybrid search settings (reciprocal rank fusion, equal weights)
+HybridOptions defaultHybridOptions() =>
+    RustLib.instance.api.crateApiDefaultHybridOptions();
+
+/// Hybrid search: rank entries by keywords (BM25 over `text_preview`) and
+/// by embedding similarity, then fuse the rankings
+///
+/// Each hit reports both scores and the matched query terms. The keyword
+/// index is rebuilt on every call; `hybrid_search_in_index` keeps one.
+Future<List<HybridHit>> hybridSearch({
+  required String queryText,
+  required List<EmbeddingEntry> entries,
+  required BigInt topK,
+  HybridOptions? options,
+}) => RustLib.instance.api.crateApiHybridSearch(
+  queryText: queryText,
+  entries: entries,
+  topK: topK,
+  options: options,
+);
+
+/// Hybrid search using an HNSW index and its keyword index
+Future<List<HybridHit>> hybridSearchInIndex({
+  required BigInt indexId,
+  required String queryText,
+  required BigInt topK,
+  HybridOptions? options,
+}) => RustLib.instance.api.crateApiHybridSearchInIndex(
+  indexId: indexId,
+  queryText: queryText,
+  topK: topK,
+  options: options,
+);
+
+/// Open (or create) a persistent vector store directory
+///
+/// Entries stored here stay in Rust, so searches and clustering take the
+/// store id instead of the full entry list. Returns the store id.
+Future<BigInt> openVectorStore({required String path}) =>
+    RustLib.instance.api.crateApiOpenVectorStore(path: path);
+
+/// Close a vector store
+bool closeVectorStore({required BigInt storeId}) =>
+    RustLib.instance.api.crateApiCloseVectorStore(storeId: storeId);
+
+/// Insert entries into a store, replacing entries with the same id
+///
+/// The entries are on disk when this returns.
+Future<void> vectorStoreUpsert({
+  required BigInt storeId,
+  required List<EmbeddingEntry> entries,
+}) => RustLib.instance.api.crateApiVectorStoreUpsert(
+  storeId: storeId,
+  entries: entries,
+);
+
+/// Delete entries from a store; returns how many existed
+Future<BigInt> vectorStoreDelete({
+  required BigInt storeId,
+  required List<String> ids,
+}) =>
+    RustLib.instance.api.crateApiVectorStoreDelete(storeId: storeId, ids: ids);
+
+/// Stored entries by id; unknown ids are skipped
+Future<List<EmbeddingEntry>> vectorStoreGet({
+  required BigInt storeId,
+  required List<String> ids,
+}) => RustLib.instance.api.crateApiVectorStoreGet(storeId: storeId, ids: ids);
+
+/// Number of entries in a store
+BigInt vectorStoreLen({required BigInt storeId}) =>
+    RustLib.instance.api.crateApiVectorStoreLen(storeId: storeId);
+
+/// Entry count, garbage and file size of a store
+Future<VectorStoreStats> getVectorStoreStats({required BigInt storeId}) =>
+    RustLib.instance.api.crateApiGetVectorStoreStats(storeId: storeId);
+
+/// Rewrite a store without replaced and deleted entries
+Future<VectorStoreStats> compactVectorStore({required BigInt storeId}) =>
+    RustLib.instance.api.crateApiCompactVectorStore(storeId: storeId);
+
+/// Find similar entries to a query embedding in a store
+Future<List<SimilarityResult>> findSimilarInStore({
+  required BigInt storeId,
+  required List<double> query,
+  required BigInt topK,
+  required double threshold,
+}) => RustLib.instance.api.crateApiFindSimilarInStore(
+  storeId: storeId,
+  query: query,
+  topK: topK,
+  threshold: threshold,
+);
+
+/// Semantic search: embed query and find similar entries in a store
+Future<List<SimilarityResult>> semanticSearchInStore({
+  required BigInt storeId,
+  required String queryText,
+  required BigInt topK,
+}) => RustLib.instance.api.crateApiSemanticSearchInStore(
+  storeId: storeId,
+  queryText: queryText,
+  topK: topK,
+);
+
+/// Semantic search with a metadata filter over a store
+Future<List<SimilarityResult>> semanticSearchInStoreFiltered({
+  required BigInt storeId,
+  required String queryText,
+  required SearchOptions options,
+}) => RustLib.instance.api.crateApiSemanticSearchInStoreFiltered(
+  storeId: storeId,
+  queryText: queryText,
+  options: options,
+);
+
+/// Build an HNSW index over the entries of a store
+Future<BigInt> createHnswIndexFromStore({
+  required BigInt storeId,
+  HnswConfig? config,
+}) => RustLib.instance.api.crateApiCreateHnswIndexFromStore(
+  storeId: storeId,
+  config: config,
+);
+
+/// Quantize entries into a compact in-memory index (int8 or binary)
+///
+/// Returns the index id. Such an index has no full-precision vectors, so
+/// it cannot rescore; see `create_quantized_index_from_store`.
+Future<BigInt> createQuantizedIndex({
+  required List<EmbeddingEntry> entries,
+  required QuantizationKind kind,
+}) => RustLib.instance.api.crateApiCreateQuantizedIndex(
+  entries: entries,
+  kind: kind,
+);
+
+/// Quantize the entries of a vector store into a compact index that
+/// rescores with the store's full-precision vectors
+///
+/// The index follows later `vector_store_upsert` and `vector_store_delete`
+/// calls on the store.
+Future<BigInt> createQuantizedIndexFromStore({
+  required BigInt storeId,
+  required QuantizationKind kind,
+}) => RustLib.instance.api.crateApiCreateQuantizedIndexFromStore(
+  storeId: storeId,
+  kind: kind,
+);
+
+/// Save a quantized index's codes to disk
+Future<void> saveQuantizedIndex({
+  required BigInt indexId,
+  required String path,
+}) => RustLib.instance.api.crateApiSaveQuantizedIndex(
+  indexId: indexId,
+  path: path,
+);
+
+/// Load a quantized index saved with `save_quantized_index`
+///
+/// With `store_id`, the index rescores with and follows that open store.
+Future<BigInt> loadQuantizedIndex({required String path, BigInt? storeId}) =>
+    RustLib.instance.api.crateApiLoadQuantizedIndex(
+      path: path,
+      storeId: storeId,
+    );
+
+/// Close a quantized index and free its memory
+bool closeQuantizedIndex({required BigInt indexId}) =>
+    RustLib.instance.api.crateApiCloseQuantizedIndex(indexId: indexId);
+
+/// Insert entries into a quantized index, replacing entries with the same id
+///
+/// Only needed for entries that are not written through a vector store the
+/// index was built from.
+Future<void> quantizedIndexInsert({
+  required BigInt indexId,
+  required List<EmbeddingEntry> entries,
+}) => RustLib.instance.api.crateApiQuantizedIndexInsert(
+  indexId: indexId,
+  entries: entries,
+);
+
+/// Remove entries from a quantized index; returns how many existed
+Future<BigInt> quantizedIndexRemove({
+  required BigInt indexId,
+  required List<String> ids,
+}) => RustLib.instance.api.crateApiQuantizedIndexRemove(
+  indexId: indexId,
+  ids: ids,
+);
+
+/// Find similar entries using a quantized index
+///
+/// With `rescore_candidates`, that many quantized results are rescored with
+/// full-precision vectors before the top `top_k` are returned.
+Future<List<SimilarityResult>> findSimilarQuantized({
+  required BigInt indexId,
+  required List<double> query,
+  required BigInt topK,
+  required double threshold,
+  BigInt? rescoreCandidates,
+}) => RustLib.instance.api.crateApiFindSimilarQuantized(
+  indexId: indexId,
+  query: query,
+  topK: topK,
+  threshold: threshold,
+  rescoreCandidates: rescoreCandidates,
+);
+
+/// Measure the recall of quantized search against exact cosine search
+///
+/// Without `queries`, up to 100 of the entries' own vectors are used.
+Future<RecallReport> measureQuantizationRecall({
+  required List<EmbeddingEntry> entries,
+  List<Float32List>? queries,
+  required QuantizationKind kind,
+  required BigInt topK,
+  required BigInt rescoreCandidates,
+}) => RustLib.instance.api.crateApiMeasureQuantizationRecall(
+  entries: entries,
+  queries: queries,
+  kind: kind,
+  topK: topK,
+  rescoreCandidates: rescoreCandidates,
+);
+
+/// Default near-duplicate detection thresholds
+DedupOptions defaultDedupOptions() =>
+    RustLib.instance.api.crateApiDefaultDedupOptions();
+
+/// Group near-duplicate notes or chunks
+///
+/// Pairs are found by MinHash over word shingles and by embedding
+/// similarity, then confirmed by text overlap. Each group lists the shared
+/// passages and a suggested canonical note.
+Future<List<DuplicateGroup>> findDuplicates({
+  required List<DedupDocument> documents,
+  DedupOptions? options,
+}) => RustLib.instance.api.crateApiFindDuplicates(
+  documents: documents,
+  options: options,
+);
+
+/// Group near-duplicate entries, using `text_preview` as the text
+///
+/// Suited to chunk entries, whose preview holds the full chunk text. Fails
+/// if an entry has no preview; previews cut to 100 bytes by `embed_texts`
+/// only match within those bytes.
+Future<List<DuplicateGroup>> findDuplicateEntries({
+  required List<EmbeddingEntry> entries,
+  DedupOptions? options,
+}) => RustLib.instance.api.crateApiFindDuplicateEntries(
+  entries: entries,
+  options: options,
+);
+
+/// Group near-duplicate entries of a vector store
+///
+/// Entries need their text as `text_preview`, as for `find_duplicate_entries`.
+Future<List<DuplicateGroup>> findDuplicatesInStore({
+  required BigInt storeId,
+  DedupOptions? options,
+}) => RustLib.instance.api.crateApiFindDuplicatesInStore(
+  storeId: storeId,
+  options: options,
+);
+
 /// Cluster embeddings by similarity
 Future<List<EmbeddingCluster>> clusterEmbeddings({
   required List<EmbeddingEntry> entries,
@@ -199,6 +1101,25 @@ String healthCheck() => RustLib.instance.api.crateApiHealthCheck();
 /// Get version info
 String getVersion() => RustLib.instance.api.crateApiGetVersion();
 
+/// Serve generation, chat, embeddings and tokenization from a scripted mock
+///
+/// For integration tests without a model file: chat and generation return
+/// `completions` in order (then `fallback`), embeddings are word hashes of
+/// length `embedding_dimension`.
+void useMockInferenceBackend({
+  required List<String> completions,
+  String? fallback,
+  required int embeddingDimension,
+}) => RustLib.instance.api.crateApiUseMockInferenceBackend(
+  completions: completions,
+  fallback: fallback,
+  embeddingDimension: embeddingDimension,
+);
+
+/// Go back to serving requests from the loaded llama.cpp models
+void useLlamaInferenceBackend() =>
+    RustLib.instance.api.crateApiUseLlamaInferenceBackend();
+
 /// Start the graph streaming simulation
 /// This runs physics simulation at 60fps and streams visible nodes
 Future<void> startGraphStream() =>
@@ -322,6 +1243,44 @@ Future<SemanticEdgeResult> discoverSemanticEdges({
   existingLinks: existingLinks,
 );
 
+/// Cluster the notes of a vector store using K-Means
+Future<ClusteringResult> clusterNotesInStore({
+  required BigInt storeId,
+  BigInt? k,
+  BigInt? maxIterations,
+}) => RustLib.instance.api.crateApiClusterNotesInStore(
+  storeId: storeId,
+  k: k,
+  maxIterations: maxIterations,
+);
+
+/// Discover semantic edges between the notes of a vector store
+Future<SemanticEdgeResult> discoverSemanticEdgesInStore({
+  required BigInt storeId,
+  double? threshold,
+  List<(String, String)>? existingLinks,
+}) => RustLib.instance.api.crateApiDiscoverSemanticEdgesInStore(
+  storeId: storeId,
+  threshold: threshold,
+  existingLinks: existingLinks,
+);
+
+/// Discover semantic edges using an HNSW index over the note embeddings
+///
+/// Scales to large collections; each note gets at most `max_neighbors`
+/// edges (default: 10).
+Future<SemanticEdgeResult> discoverSemanticEdgesInIndex({
+  required BigInt indexId,
+  double? threshold,
+  List<(String, String)>? existingLinks,
+  BigInt? maxNeighbors,
+}) => RustLib.instance.api.crateApiDiscoverSemanticEdgesInIndex(
+  indexId: indexId,
+  threshold: threshold,
+  existingLinks: existingLinks,
+  maxNeighbors: maxNeighbors,
+);
+
 /// Analyze knowledge graph: cluster and find semantic edges in one pass
 ///
 /// More efficient than calling both functions separately
@@ -337,6 +1296,19 @@ Future<KnowledgeGraphAnalysis> analyzeKnowledgeGraph({
   existingLinks: existingLinks,
 );
 
+/// Analyze the notes of a vector store: cluster and find semantic edges
+Future<KnowledgeGraphAnalysis> analyzeKnowledgeGraphInStore({
+  required BigInt storeId,
+  BigInt? k,
+  double? similarityThreshold,
+  List<(String, String)>? existingLinks,
+}) => RustLib.instance.api.crateApiAnalyzeKnowledgeGraphInStore(
+  storeId: storeId,
+  k: k,
+  similarityThreshold: similarityThreshold,
+  existingLinks: existingLinks,
+);
+
 /// Initialize the MCP system with configuration
 ///
 /// # Arguments
@@ -424,6 +1396,82 @@ String mcpGetToolSchemas() => RustLib.instance.api.crateApiMcpGetToolSchemas();
 Future<MCPToolCall> mcpParseToolCall({required String json}) =>
     RustLib.instance.api.crateApiMcpParseToolCall(json: json);
 
+/// Split a model reply into tool calls and plain text
+///
+/// # Arguments
+/// * `reply` - The raw model output
+/// * `model_type` - Family whose call format to expect
+///   (0 = Phi4, 1 = Qwen, 2 = Functionary, anything else = generic)
+///
+/// # Returns
+/// * The calls, the text, and which byte spans were treated as each
+ParsedReply mcpParseToolCalls({required String reply, int? modelType}) =>
+    RustLib.instance.api.crateApiMcpParseToolCalls(
+      reply: reply,
+      modelType: modelType,
+    );
+
+/// Ask the loaded model for a tool call
+///
+/// Decoding is constrained to the tool schemas, so the result always parses.
+///
+/// # Arguments
+/// * `messages` - Conversation as (role, content) tuples
+/// * `max_tokens` - Optional override for max tokens to generate
+/// * `model_id` - Model to run on (None = the default model)
+///
+/// # Returns
+/// * The generated tool call, ready for `mcp_execute_tool_call`
+Future<MCPToolCall> mcpGenerateToolCall({
+  required List<(String, String)> messages,
+  int? maxTokens,
+  String? modelId,
+}) => RustLib.instance.api.crateApiMcpGenerateToolCall(
+  messages: messages,
+  maxTokens: maxTokens,
+  modelId: modelId,
+);
+
+/// Run a conversation in which the model may call tools
+///
+/// Tool calls in each reply are executed and their results fed back until
+/// the model answers without calling a tool or `max_steps` replies are used.
+///
+/// # Arguments
+/// * `messages` - Conversation as (role, content) tuples
+/// * `max_steps` - Maximum model replies (default: 5)
+/// * `max_tokens` - Optional override for max tokens per reply
+/// * `model_id` - Model to run on (None = the default model)
+///
+/// # Returns
+/// * The final answer with a trace of every step
+Future<AgentRun> mcpRunAgent({
+  required List<(String, String)> messages,
+  int? maxSteps,
+  int? maxTokens,
+  String? modelId,
+}) => RustLib.instance.api.crateApiMcpRunAgent(
+  messages: messages,
+  maxSteps: maxSteps,
+  maxTokens: maxTokens,
+  modelId: modelId,
+);
+
+/// Run the agent loop, pushing each step to the sink as it completes
+///
+/// The last step without tool calls holds the final answer.
+Stream<AgentStep> mcpRunAgentStream({
+  required List<(String, String)> messages,
+  int? maxSteps,
+  int? maxTokens,
+  String? modelId,
+}) => RustLib.instance.api.crateApiMcpRunAgentStream(
+  messages: messages,
+  maxSteps: maxSteps,
+  maxTokens: maxTokens,
+  modelId: modelId,
+);
+
 /// Execute a tool call
 ///
 /// # Arguments
@@ -444,6 +1492,26 @@ Future<MCPToolResult> mcpExecuteToolCall({required MCPToolCall toolCall}) =>
 TaskCategory mcpClassifyTask({required String message}) =>
     RustLib.instance.api.crateApiMcpClassifyTask(message: message);
 
+/// Classify a task with a loaded model, falling back to keywords
+///
+/// # Arguments
+/// * `message` - User message to classify
+/// * `model_id` - Model to ask (None = the default model)
+/// * `min_confidence` - Average token probability the model's answer needs
+///   (default 0.5); less confident answers use the keyword classifier
+///
+/// # Returns
+/// * Task category (Conversation, ToolUse, CodeGeneration)
+Future<TaskCategory> mcpClassifyTaskWithModel({
+  required String message,
+  String? modelId,
+  double? minConfidence,
+}) => RustLib.instance.api.crateApiMcpClassifyTaskWithModel(
+  message: message,
+  modelId: modelId,
+  minConfidence: minConfidence,
+);
+
 /// Get the recommended model for a task category
 ///
 /// # Arguments
@@ -454,6 +1522,13 @@ TaskCategory mcpClassifyTask({required String message}) =>
 String mcpGetModelForTask({required TaskCategory category}) =>
     RustLib.instance.api.crateApiMcpGetModelForTask(category: category);
 
+/// Get the id of a loaded model suited to a task category
+///
+/// Pass the id to any inference call to route the task to that model.
+/// Returns None if no suitable model is loaded.
+String? mcpGetModelIdForTask({required TaskCategory category}) =>
+    RustLib.instance.api.crateApiMcpGetModelIdForTask(category: category);
+
 /// Get all available MCP tools
 List<MCPTool> mcpGetAllTools() => RustLib.instance.api.crateApiMcpGetAllTools();
 
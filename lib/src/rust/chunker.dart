@@ -0,0 +1,58 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:kivixa/src/rust/frb_generated.dart';
+
+/// A chunk of a markdown note
+class MarkdownChunk {
+  /// Position of the chunk within the note
+  final BigInt index;
+
+  /// Titles of the enclosing headings, outermost first
+  final List<String> headingPath;
+
+  /// Start byte offset in the note
+  final BigInt start;
+
+  /// End byte offset in the note (exclusive)
+  final BigInt end;
+
+  /// The chunk text (`note[start..end]`)
+  final String text;
+
+  /// Number of tokens in the embedded input: the heading path and `text`
+  final int tokenCount;
+
+  const MarkdownChunk({
+    required this.index,
+    required this.headingPath,
+    required this.start,
+    required this.end,
+    required this.text,
+    required this.tokenCount,
+  });
+
+  @override
+  int get hashCode =>
+      index.hashCode ^
+      headingPath.hashCode ^
+      start.hashCode ^
+      end.hashCode ^
+      text.hashCode ^
+      tokenCount.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is MarkdownChunk &&
+          runtimeType == other.runtimeType &&
+          index == other.index &&
+          headingPath == other.headingPath &&
+          start == other.start &&
+          end == other.end &&
+          text == other.text &&
+          tokenCount == other.tokenCount;
+}
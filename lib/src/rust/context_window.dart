@@ -0,0 +1,104 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:kivixa/src/rust/frb_generated.dart';
+
+/// How conversations that exceed the context window are handled
+class ContextPolicy {
+  /// Strategy applied when the window is full
+  final ContextStrategy strategy;
+
+  /// Maximum length of a summary of evicted turns
+  final int summaryMaxTokens;
+
+  const ContextPolicy({required this.strategy, required this.summaryMaxTokens});
+
+  @override
+  int get hashCode => strategy.hashCode ^ summaryMaxTokens.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ContextPolicy &&
+          runtimeType == other.runtimeType &&
+          strategy == other.strategy &&
+          summaryMaxTokens == other.summaryMaxTokens;
+}
+
+/// What the context manager did to a conversation
+///
+/// Message indices refer to the conversation as it was passed in.
+class ContextReport {
+  /// Size of the context window in tokens
+  final int contextSize;
+
+  /// Tokens used by the prompt that was actually sent
+  final int promptTokens;
+
+  /// Tokens in the content of each original message
+  final Uint32List messageTokens;
+
+  /// Messages left out entirely
+  final Uint32List dropped;
+
+  /// Messages whose beginning was cut off
+  final Uint32List truncated;
+
+  /// Messages replaced by the summary
+  final Uint32List summarized;
+
+  /// Summary inserted in place of the summarized messages
+  final String? summary;
+
+  const ContextReport({
+    required this.contextSize,
+    required this.promptTokens,
+    required this.messageTokens,
+    required this.dropped,
+    required this.truncated,
+    required this.summarized,
+    this.summary,
+  });
+
+  @override
+  int get hashCode =>
+      contextSize.hashCode ^
+      promptTokens.hashCode ^
+      messageTokens.hashCode ^
+      dropped.hashCode ^
+      truncated.hashCode ^
+      summarized.hashCode ^
+      summary.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ContextReport &&
+          runtimeType == other.runtimeType &&
+          contextSize == other.contextSize &&
+          promptTokens == other.promptTokens &&
+          messageTokens == other.messageTokens &&
+          dropped == other.dropped &&
+          truncated == other.truncated &&
+          summarized == other.summarized &&
+          summary == other.summary;
+}
+
+/// What to do when a conversation does not fit the context window
+enum ContextStrategy {
+  /// Fail with an error that reports the token counts
+  error,
+
+  /// Drop the oldest turns, keeping system messages and the latest message
+  dropOldest,
+
+  /// Drop the oldest messages one by one and cut the oldest kept message
+  /// so the window is filled as far as possible
+  slidingWindow,
+
+  /// Replace the oldest turns with a summary written by the model
+  summarize,
+}
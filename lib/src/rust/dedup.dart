@@ -0,0 +1,224 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:kivixa/src/rust/frb_generated.dart';
+
+/// A note or chunk to check for duplicates
+class DedupDocument {
+  /// Note id, or chunk id such as `note#chunk3`
+  final String id;
+
+  /// Full text
+  final String text;
+
+  /// Embedding of the text, if available
+  final Float32List? vector;
+
+  /// Creation time in milliseconds since the Unix epoch; older documents
+  /// are preferred as the canonical copy
+  final PlatformInt64? createdAt;
+
+  const DedupDocument({
+    required this.id,
+    required this.text,
+    this.vector,
+    this.createdAt,
+  });
+
+  @override
+  int get hashCode =>
+      id.hashCode ^ text.hashCode ^ vector.hashCode ^ createdAt.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is DedupDocument &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          text == other.text &&
+          vector == other.vector &&
+          createdAt == other.createdAt;
+}
+
+/// Thresholds for duplicate detection
+class DedupOptions {
+  /// Words per shingle (default: 5)
+  final BigInt shingleWords;
+
+  /// MinHash signature length; a multiple of `bands` (default: 128)
+  final BigInt numHashes;
+
+  /// LSH bands; more bands find pairs with lower similarity (default: 32)
+  final BigInt bands;
+
+  /// Shingle Jaccard similarity that makes a pair near-duplicates (default: 0.5)
+  final double minJaccard;
+
+  /// Cosine similarity that makes a pair worth checking for shared passages (default: 0.9)
+  final double minCosine;
+
+  /// Words in the shortest shared passage reported (default: 12)
+  final BigInt minSpanWords;
+
+  const DedupOptions({
+    required this.shingleWords,
+    required this.numHashes,
+    required this.bands,
+    required this.minJaccard,
+    required this.minCosine,
+    required this.minSpanWords,
+  });
+
+  @override
+  int get hashCode =>
+      shingleWords.hashCode ^
+      numHashes.hashCode ^
+      bands.hashCode ^
+      minJaccard.hashCode ^
+      minCosine.hashCode ^
+      minSpanWords.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is DedupOptions &&
+          runtimeType == other.runtimeType &&
+          shingleWords == other.shingleWords &&
+          numHashes == other.numHashes &&
+          bands == other.bands &&
+          minJaccard == other.minJaccard &&
+          minCosine == other.minCosine &&
+          minSpanWords == other.minSpanWords;
+}
+
+/// Documents connected by duplicate pairs
+class DuplicateGroup {
+  /// Document ids in the group
+  final List<String> ids;
+
+  /// Suggested document to keep: the oldest, then the longest
+  final String canonical;
+
+  /// Note of the suggested document (differs from `canonical` for chunks)
+  final String canonicalNote;
+
+  /// The pairs that formed the group
+  final List<DuplicatePair> pairs;
+
+  const DuplicateGroup({
+    required this.ids,
+    required this.canonical,
+    required this.canonicalNote,
+    required this.pairs,
+  });
+
+  @override
+  int get hashCode =>
+      ids.hashCode ^
+      canonical.hashCode ^
+      canonicalNote.hashCode ^
+      pairs.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is DuplicateGroup &&
+          runtimeType == other.runtimeType &&
+          ids == other.ids &&
+          canonical == other.canonical &&
+          canonicalNote == other.canonicalNote &&
+          pairs == other.pairs;
+}
+
+/// Two documents that duplicate each other
+class DuplicatePair {
+  /// First document id
+  final String a;
+
+  /// Second document id
+  final String b;
+
+  /// Jaccard similarity of the shingle sets
+  final double jaccard;
+
+  /// Cosine similarity of the embeddings, if both have one
+  final double? cosine;
+
+  /// Shared passages, longest first
+  final List<DuplicateSpan> spans;
+
+  const DuplicatePair({
+    required this.a,
+    required this.b,
+    required this.jaccard,
+    this.cosine,
+    required this.spans,
+  });
+
+  @override
+  int get hashCode =>
+      a.hashCode ^
+      b.hashCode ^
+      jaccard.hashCode ^
+      cosine.hashCode ^
+      spans.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is DuplicatePair &&
+          runtimeType == other.runtimeType &&
+          a == other.a &&
+          b == other.b &&
+          jaccard == other.jaccard &&
+          cosine == other.cosine &&
+          spans == other.spans;
+}
+
+/// A passage found in both documents of a pair, as byte ranges
+class DuplicateSpan {
+  /// Start of the passage in the first document
+  final BigInt aStart;
+
+  /// End of the passage in the first document
+  final BigInt aEnd;
+
+  /// Start of the passage in the second document
+  final BigInt bStart;
+
+  /// End of the passage in the second document
+  final BigInt bEnd;
+
+  /// Length of the passage in words
+  final BigInt words;
+
+  const DuplicateSpan({
+    required this.aStart,
+    required this.aEnd,
+    required this.bStart,
+    required this.bEnd,
+    required this.words,
+  });
+
+  @override
+  int get hashCode =>
+      aStart.hashCode ^
+      aEnd.hashCode ^
+      bStart.hashCode ^
+      bEnd.hashCode ^
+      words.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is DuplicateSpan &&
+          runtimeType == other.runtimeType &&
+          aStart == other.aStart &&
+          aEnd == other.aEnd &&
+          bStart == other.bStart &&
+          bEnd == other.bEnd &&
+          words == other.words;
+}
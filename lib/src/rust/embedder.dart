@@ -0,0 +1,94 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:kivixa/src/rust/frb_generated.dart';
+
+/// Configuration for the embedding model
+class EmbeddingConfig {
+  /// Number of GPU layers to offload (99 = all possible)
+  final int nGpuLayers;
+
+  /// Number of CPU threads for processing
+  final int nThreads;
+
+  /// How token states are pooled
+  final PoolingMode pooling;
+
+  /// Maximum tokens per chunk (capped at the model's training context)
+  final int chunkTokens;
+
+  /// Tokens shared by consecutive chunks of a long text
+  final int chunkOverlap;
+
+  /// Chunks decoded together in one batch
+  final int maxSequences;
+
+  /// Prepended to queries (e.g. "search_query: " for nomic-embed)
+  final String queryPrefix;
+
+  /// Prepended to documents (e.g. "search_document: " for nomic-embed)
+  final String documentPrefix;
+
+  const EmbeddingConfig({
+    required this.nGpuLayers,
+    required this.nThreads,
+    required this.pooling,
+    required this.chunkTokens,
+    required this.chunkOverlap,
+    required this.maxSequences,
+    required this.queryPrefix,
+    required this.documentPrefix,
+  });
+
+  @override
+  int get hashCode =>
+      nGpuLayers.hashCode ^
+      nThreads.hashCode ^
+      pooling.hashCode ^
+      chunkTokens.hashCode ^
+      chunkOverlap.hashCode ^
+      maxSequences.hashCode ^
+      queryPrefix.hashCode ^
+      documentPrefix.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is EmbeddingConfig &&
+          runtimeType == other.runtimeType &&
+          nGpuLayers == other.nGpuLayers &&
+          nThreads == other.nThreads &&
+          pooling == other.pooling &&
+          chunkTokens == other.chunkTokens &&
+          chunkOverlap == other.chunkOverlap &&
+          maxSequences == other.maxSequences &&
+          queryPrefix == other.queryPrefix &&
+          documentPrefix == other.documentPrefix;
+}
+
+/// What a text is embedded as, which selects the instruction prefix
+enum EmbeddingKind {
+  /// A search query
+  query,
+
+  /// A document to be searched (notes, chunks)
+  document,
+}
+
+/// How token states are combined into one vector per sequence
+enum PoolingMode {
+  /// Use the pooling the model was trained with (mean if it declares none)
+  native,
+
+  /// Average of all token states
+  mean,
+
+  /// State of the first (CLS) token
+  cls,
+
+  /// State of the last token
+  last,
+}
@@ -0,0 +1,38 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:kivixa/src/rust/frb_generated.dart';
+
+/// Size and health of a cache
+class EmbeddingCacheStats {
+  /// Embeddings served for the current model
+  final BigInt entries;
+
+  /// Records in the file that compaction would remove
+  final BigInt staleRecords;
+
+  /// Size of the cache file in bytes
+  final BigInt fileBytes;
+
+  const EmbeddingCacheStats({
+    required this.entries,
+    required this.staleRecords,
+    required this.fileBytes,
+  });
+
+  @override
+  int get hashCode =>
+      entries.hashCode ^ staleRecords.hashCode ^ fileBytes.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is EmbeddingCacheStats &&
+          runtimeType == other.runtimeType &&
+          entries == other.entries &&
+          staleRecords == other.staleRecords &&
+          fileBytes == other.fileBytes;
+}
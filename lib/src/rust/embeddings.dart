@@ -17,14 +17,19 @@ class EmbeddingEntry {
   /// Optional text preview
   final String? textPreview;
 
+  /// Folder, tags, timestamps and type of the source note
+  final NoteMetadata metadata;
+
   const EmbeddingEntry({
     required this.id,
     required this.vector,
     this.textPreview,
+    required this.metadata,
   });
 
   @override
-  int get hashCode => id.hashCode ^ vector.hashCode ^ textPreview.hashCode;
+  int get hashCode =>
+      id.hashCode ^ vector.hashCode ^ textPreview.hashCode ^ metadata.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -33,7 +38,65 @@ class EmbeddingEntry {
           runtimeType == other.runtimeType &&
           id == other.id &&
           vector == other.vector &&
-          textPreview == other.textPreview;
+          textPreview == other.textPreview &&
+          metadata == other.metadata;
+}
+
+/// Typed metadata of the note an embedding came from
+class NoteMetadata {
+  /// Folder path relative to the notes root (e.g., "courses/math")
+  final String? folder;
+
+  /// Tags without the leading `#`
+  final List<String> tags;
+
+  /// Creation time in milliseconds since the Unix epoch
+  final PlatformInt64? createdAt;
+
+  /// Last modification time in milliseconds since the Unix epoch
+  final PlatformInt64? modifiedAt;
+
+  /// Kind of note (e.g., "markdown", "canvas", "transcript")
+  final String? noteType;
+
+  /// Start byte offset of a chunk entry within its note
+  final BigInt? chunkStart;
+
+  /// End byte offset (exclusive) of a chunk entry within its note
+  final BigInt? chunkEnd;
+
+  const NoteMetadata({
+    this.folder,
+    required this.tags,
+    this.createdAt,
+    this.modifiedAt,
+    this.noteType,
+    this.chunkStart,
+    this.chunkEnd,
+  });
+
+  @override
+  int get hashCode =>
+      folder.hashCode ^
+      tags.hashCode ^
+      createdAt.hashCode ^
+      modifiedAt.hashCode ^
+      noteType.hashCode ^
+      chunkStart.hashCode ^
+      chunkEnd.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is NoteMetadata &&
+          runtimeType == other.runtimeType &&
+          folder == other.folder &&
+          tags == other.tags &&
+          createdAt == other.createdAt &&
+          modifiedAt == other.modifiedAt &&
+          noteType == other.noteType &&
+          chunkStart == other.chunkStart &&
+          chunkEnd == other.chunkEnd;
 }
 
 /// Result of a similarity search
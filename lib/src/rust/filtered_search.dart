@@ -0,0 +1,53 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:kivixa/src/rust/frb_generated.dart';
+
+/// Settings for a filtered similarity search
+class SearchOptions {
+  /// Number of results to return (default: 10)
+  final BigInt topK;
+
+  /// Minimum cosine similarity (default: 0.5)
+  final double threshold;
+
+  /// Filter expression over note metadata (`None` = every entry)
+  final String? filter;
+
+  /// MMR trade-off: 1.0 ranks by relevance only, 0.0 by novelty only
+  /// (`None` = no re-ranking)
+  final double? mmrLambda;
+
+  /// Most relevant results considered by MMR (default: 50)
+  final BigInt mmrCandidates;
+
+  const SearchOptions({
+    required this.topK,
+    required this.threshold,
+    this.filter,
+    this.mmrLambda,
+    required this.mmrCandidates,
+  });
+
+  @override
+  int get hashCode =>
+      topK.hashCode ^
+      threshold.hashCode ^
+      filter.hashCode ^
+      mmrLambda.hashCode ^
+      mmrCandidates.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SearchOptions &&
+          runtimeType == other.runtimeType &&
+          topK == other.topK &&
+          threshold == other.threshold &&
+          filter == other.filter &&
+          mmrLambda == other.mmrLambda &&
+          mmrCandidates == other.mmrCandidates;
+}
@@ -7,15 +7,33 @@ import 'dart:async';
 import 'dart:convert';
 
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:kivixa/src/rust/agent.dart';
 import 'package:kivixa/src/rust/api.dart';
+import 'package:kivixa/src/rust/chunker.dart';
 import 'package:kivixa/src/rust/clustering.dart';
+import 'package:kivixa/src/rust/context_window.dart';
+import 'package:kivixa/src/rust/dedup.dart';
+import 'package:kivixa/src/rust/embedder.dart';
+import 'package:kivixa/src/rust/embedding_cache.dart';
 import 'package:kivixa/src/rust/embeddings.dart';
+import 'package:kivixa/src/rust/filtered_search.dart';
 import 'package:kivixa/src/rust/frb_generated.dart';
 import 'package:kivixa/src/rust/frb_generated.io.dart'
     if (dart.library.js_interop) 'frb_generated.web.dart';
+import 'package:kivixa/src/rust/gguf.dart';
 import 'package:kivixa/src/rust/graph.dart';
+import 'package:kivixa/src/rust/hnsw.dart';
+import 'package:kivixa/src/rust/hybrid_search.dart';
+import 'package:kivixa/src/rust/inference.dart';
 import 'package:kivixa/src/rust/mcp.dart';
+import 'package:kivixa/src/rust/quantization.dart';
+import 'package:kivixa/src/rust/rag.dart';
+import 'package:kivixa/src/rust/reasoning.dart';
 import 'package:kivixa/src/rust/streaming.dart';
+import 'package:kivixa/src/rust/summarize.dart';
+import 'package:kivixa/src/rust/tokenizer.dart';
+import 'package:kivixa/src/rust/tool_parser.dart';
+import 'package:kivixa/src/rust/vector_store.dart';
 
 /// Main entrypoint of the Rust API
 class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
@@ -70,7 +88,7 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
   String get codegenVersion => '2.12.0';
 
   @override
-  int get rustContentHash => 1959240715;
+  int get rustContentHash => -1814554323;
 
   static const kDefaultExternalLibraryLoaderConfig =
       ExternalLibraryLoaderConfig(
@@ -124,19 +142,83 @@ abstract class RustLibApi extends BaseApi {
     List<(String, String)>? existingLinks,
   });
 
+  Future<KnowledgeGraphAnalysis> crateApiAnalyzeKnowledgeGraphInStore({
+    required BigInt storeId,
+    BigInt? k,
+    double? similarityThreshold,
+    List<(String, String)>? existingLinks,
+  });
+
   Future<List<EmbeddingEntry>> crateApiBatchEmbed({
     required List<String> texts,
   });
 
+  Future<List<EmbeddingEntry>> crateApiBatchEmbedWithModel({
+    required List<String> texts,
+    String? modelId,
+  });
+
+  bool crateApiCancelGeneration({required BigInt requestId});
+
   Future<String> crateApiChatCompletion({
     required List<(String, String)> messages,
     int? maxTokens,
   });
 
+  Stream<GenerationChunk> crateApiChatCompletionStream({
+    required List<(String, String)> messages,
+    int? maxTokens,
+    List<String>? stopSequences,
+    String? modelId,
+  });
+
+  Future<String> crateApiChatCompletionWithModel({
+    required List<(String, String)> messages,
+    int? maxTokens,
+    String? modelId,
+  });
+
+  Future<GenerationResult> crateApiChatCompletionWithOptions({
+    required List<(String, String)> messages,
+    required GenerationOptions options,
+  });
+
+  Future<GenerationResult> crateApiChatSessionCompletion({
+    required BigInt sessionId,
+    required List<(String, String)> messages,
+    required GenerationOptions options,
+  });
+
+  Stream<GenerationChunk> crateApiChatSessionStream({
+    required BigInt sessionId,
+    required List<(String, String)> messages,
+    int? maxTokens,
+    List<String>? stopSequences,
+  });
+
+  BigInt crateApiChatSessionTokenCount({required BigInt sessionId});
+
+  Future<List<MarkdownChunk>> crateApiChunkMarkdown({
+    required String text,
+    int? maxTokens,
+    int? overlapTokens,
+    String? modelId,
+  });
+
   void crateApiClearGraph();
 
   void crateApiClearStreamGraph();
 
+  bool crateApiCloseChatSession({required BigInt sessionId});
+
+  void crateApiCloseEmbeddingCache();
+
+  bool crateApiCloseHnswIndex({required BigInt indexId});
+
+  bool crateApiCloseQuantizedIndex({required BigInt indexId});
+
+  bool crateApiCloseVectorStore({required BigInt storeId});
+
   Future<List<EmbeddingCluster>> crateApiClusterEmbeddings({
     required List<EmbeddingEntry> entries,
     required double threshold,
@@ -148,6 +230,18 @@ abstract class RustLibApi extends BaseApi {
     BigInt? maxIterations,
   });
 
+  Future<ClusteringResult> crateApiClusterNotesInStore({
+    required BigInt storeId,
+    BigInt? k,
+    BigInt? maxIterations,
+  });
+
+  Future<EmbeddingCacheStats> crateApiCompactEmbeddingCache();
+
+  Future<VectorStoreStats> crateApiCompactVectorStore({
+    required BigInt storeId,
+  });
+
   Future<GraphState> crateApiComputeGraphLayout({int? iterations});
 
   Future<void> crateApiConnectNoteToTopics({
@@ -160,17 +254,120 @@ abstract class RustLibApi extends BaseApi {
     required List<double> b,
   });
 
+  Future<int> crateApiCountChatPromptTokens({
+    required List<(String, String)> messages,
+    String? modelId,
+  });
+
+  Future<Uint32List> crateApiCountChatTokens({
+    required List<(String, String)> messages,
+    String? modelId,
+  });
+
+  Future<int> crateApiCountTokens({required String text, String? modelId});
+
+  Future<BigInt> crateApiCreateChatSession({int? nCtx, String? modelId});
+
+  BigInt crateApiCreateHnswIndex({HnswConfig? config});
+
+  Future<BigInt> crateApiCreateHnswIndexFromStore({
+    required BigInt storeId,
+    HnswConfig? config,
+  });
+
+  Future<BigInt> crateApiCreateQuantizedIndex({
+    required List<EmbeddingEntry> entries,
+    required QuantizationKind kind,
+  });
+
+  Future<BigInt> crateApiCreateQuantizedIndexFromStore({
+    required BigInt storeId,
+    required QuantizationKind kind,
+  });
+
+  ContextPolicy crateApiDefaultContextPolicy();
+
+  DedupOptions crateApiDefaultDedupOptions();
+
+  EmbeddingConfig crateApiDefaultEmbeddingConfig();
+
+  HnswConfig crateApiDefaultHnswConfig();
+
+  HybridOptions crateApiDefaultHybridOptions();
+
+  ReasoningOptions crateApiDefaultReasoningOptions();
+
+  SamplerConfig crateApiDefaultSamplerConfig();
+
+  SearchOptions crateApiDefaultSearchOptions();
+
+  SummaryOptions crateApiDefaultSummaryOptions();
+
+  Future<String> crateApiDetokenizeTokens({
+    required List<int> tokens,
+    required bool renderSpecial,
+    String? modelId,
+  });
+
   Future<SemanticEdgeResult> crateApiDiscoverSemanticEdges({
     required List<EmbeddingEntry> entries,
     double? threshold,
     List<(String, String)>? existingLinks,
   });
 
+  Future<SemanticEdgeResult> crateApiDiscoverSemanticEdgesInIndex({
+    required BigInt indexId,
+    double? threshold,
+    List<(String, String)>? existingLinks,
+    BigInt? maxNeighbors,
+  });
+
+  Future<SemanticEdgeResult> crateApiDiscoverSemanticEdgesInStore({
+    required BigInt storeId,
+    double? threshold,
+    List<(String, String)>? existingLinks,
+  });
+
+  Future<List<EmbeddingEntry>> crateApiEmbedMarkdownNote({
+    required String noteId,
+    required String text,
+    int? maxTokens,
+    int? overlapTokens,
+    NoteMetadata? metadata,
+  });
+
+  Future<List<Float32List>> crateApiEmbedTexts({
+    required List<String> texts,
+    required EmbeddingKind kind,
+  });
+
   Future<List<String>> crateApiExtractTopics({
     required String text,
     int? numTopics,
   });
 
+  Future<List<String>> crateApiExtractTopicsWithModel({
+    required String text,
+    int? numTopics,
+    String? modelId,
+    double? minConfidence,
+  });
+
+  Future<List<DuplicateGroup>> crateApiFindDuplicateEntries({
+    required List<EmbeddingEntry> entries,
+    DedupOptions? options,
+  });
+
+  Future<List<DuplicateGroup>> crateApiFindDuplicates({
+    required List<DedupDocument> documents,
+    DedupOptions? options,
+  });
+
+  Future<List<DuplicateGroup>> crateApiFindDuplicatesInStore({
+    required BigInt storeId,
+    DedupOptions? options,
+  });
+
   Future<List<SimilarityResult>> crateApiFindSimilar({
     required List<double> query,
     required List<EmbeddingEntry> entries,
@@ -178,26 +375,121 @@ abstract class RustLibApi extends BaseApi {
     required double threshold,
   });
 
+  Future<List<SimilarityResult>> crateApiFindSimilarFiltered({
+    required List<double> query,
+    required List<EmbeddingEntry> entries,
+    required SearchOptions options,
+  });
+
+  Future<List<SimilarityResult>> crateApiFindSimilarInIndex({
+    required BigInt indexId,
+    required List<double> query,
+    required BigInt topK,
+    required double threshold,
+  });
+
+  Future<List<SimilarityResult>> crateApiFindSimilarInStore({
+    required BigInt storeId,
+    required List<double> query,
+    required BigInt topK,
+    required double threshold,
+  });
+
+  Future<List<SimilarityResult>> crateApiFindSimilarQuantized({
+    required BigInt indexId,
+    required List<double> query,
+    required BigInt topK,
+    required double threshold,
+    BigInt? rescoreCandidates,
+  });
+
   Future<String> crateApiGenerateText({required String prompt, int? maxTokens});
 
+  Stream<GenerationChunk> crateApiGenerateTextStream({
+    required String prompt,
+    int? maxTokens,
+    List<String>? stopSequences,
+    String? modelId,
+  });
+
+  Future<String> crateApiGenerateTextWithModel({
+    required String prompt,
+    int? maxTokens,
+    String? modelId,
+  });
+
+  Future<GenerationResult> crateApiGenerateTextWithOptions({
+    required String prompt,
+    required GenerationOptions options,
+  });
+
   Future<Float32List> crateApiGetEmbedding({required String text});
 
+  Future<EmbeddingCacheStats?> crateApiGetEmbeddingCacheStats();
+
   BigInt crateApiGetEmbeddingDimension();
 
+  Future<Float32List> crateApiGetEmbeddingWithModel({
+    required String text,
+    String? modelId,
+  });
+
   Future<GraphState> crateApiGetGraphState();
 
+  GgufMetadata crateApiGetModelMetadata({String? modelId});
+
   int crateApiGetModelType();
 
   Future<String> crateApiGetOrCreateTopicHub({required String topic});
 
   StreamGraphStats crateApiGetStreamGraphStats();
 
+  TokenizerInfo crateApiGetTokenizerInfo({String? modelId});
+
+  Future<VectorStoreStats> crateApiGetVectorStoreStats({
+    required BigInt storeId,
+  });
+
   String crateApiGetVersion();
 
   Future<List<NodePosition>> crateApiGetVisibleGraphNodes();
 
+  SamplerConfig crateApiGreedySamplerConfig();
+
   String crateApiHealthCheck();
 
+  Future<void> crateApiHnswIndexInsert({
+    required BigInt indexId,
+    required List<EmbeddingEntry> entries,
+  });
+
+  Future<void> crateApiHnswIndexInsertWithTexts({
+    required BigInt indexId,
+    required List<EmbeddingEntry> entries,
+    required List<String> texts,
+  });
+
+  BigInt crateApiHnswIndexLen({required BigInt indexId});
+
+  Future<BigInt> crateApiHnswIndexRemove({
+    required BigInt indexId,
+    required List<String> ids,
+  });
+
+  Future<List<HybridHit>> crateApiHybridSearch({
+    required String queryText,
+    required List<EmbeddingEntry> entries,
+    required BigInt topK,
+    HybridOptions? options,
+  });
+
+  Future<List<HybridHit>> crateApiHybridSearchInIndex({
+    required BigInt indexId,
+    required String queryText,
+    required BigInt topK,
+    HybridOptions? options,
+  });
+
   void crateApiInitGraph();
 
   Future<void> crateApiInitMcp({
@@ -219,14 +511,53 @@ abstract class RustLibApi extends BaseApi {
     int? modelType,
   });
 
+  Future<GgufMetadata> crateApiInspectModel({required String modelPath});
+
+  bool crateApiIsEmbeddingModelLoaded();
+
   bool crateApiIsGraphStreamRunning();
 
   bool crateApiIsMcpInitialized();
 
   bool crateApiIsModelLoaded();
 
+  String crateApiJsonSchemaToGrammar({required String schemaJson});
+
+  List<LoadedModelInfo> crateApiListLoadedModels();
+
+  Future<BigInt> crateApiLoadChatSession({
+    required String path,
+    int? nCtx,
+    String? modelId,
+  });
+
+  Future<void> crateApiLoadEmbeddingModel({
+    required String modelPath,
+    EmbeddingConfig? config,
+  });
+
+  Future<BigInt> crateApiLoadHnswIndex({required String path});
+
+  Future<void> crateApiLoadModel({
+    required String modelId,
+    required String modelPath,
+    int? nCtx,
+    int? nGpuLayers,
+  });
+
+  Future<BigInt> crateApiLoadQuantizedIndex({
+    required String path,
+    BigInt? storeId,
+  });
+
   TaskCategory crateApiMcpClassifyTask({required String message});
 
+  Future<TaskCategory> crateApiMcpClassifyTaskWithModel({
+    required String message,
+    String? modelId,
+    double? minConfidence,
+  });
+
   Future<void> crateApiMcpCreateFolder({required String path});
 
   Future<void> crateApiMcpDeleteFile({required String path});
@@ -235,10 +566,18 @@ abstract class RustLibApi extends BaseApi {
     required MCPToolCall toolCall,
   });
 
+  Future<MCPToolCall> crateApiMcpGenerateToolCall({
+    required List<(String, String)> messages,
+    int? maxTokens,
+    String? modelId,
+  });
+
   List<MCPTool> crateApiMcpGetAllTools();
 
   String crateApiMcpGetModelForTask({required TaskCategory category});
 
+  String? crateApiMcpGetModelIdForTask({required TaskCategory category});
+
   String crateApiMcpGetToolDescription({required MCPTool tool});
 
   String crateApiMcpGetToolName({required MCPTool tool});
@@ -251,8 +590,27 @@ abstract class RustLibApi extends BaseApi {
 
   Future<MCPToolCall> crateApiMcpParseToolCall({required String json});
 
+  ParsedReply crateApiMcpParseToolCalls({
+    required String reply,
+    int? modelType,
+  });
+
   Future<String> crateApiMcpReadFile({required String path});
 
+  Future<AgentRun> crateApiMcpRunAgent({
+    required List<(String, String)> messages,
+    int? maxSteps,
+    int? maxTokens,
+    String? modelId,
+  });
+
+  Stream<AgentStep> crateApiMcpRunAgentStream({
+    required List<(String, String)> messages,
+    int? maxSteps,
+    int? maxTokens,
+    String? modelId,
+  });
+
   bool crateApiMcpValidatePath({required String path});
 
   Future<void> crateApiMcpWriteFile({
@@ -260,11 +618,44 @@ abstract class RustLibApi extends BaseApi {
     required String content,
   });
 
+  Future<RecallReport> crateApiMeasureQuantizationRecall({
+    required List<EmbeddingEntry> entries,
+    List<Float32List>? queries,
+    required QuantizationKind kind,
+    required BigInt topK,
+    required BigInt rescoreCandidates,
+  });
+
+  Future<void> crateApiOpenEmbeddingCache({required String path});
+
+  Future<BigInt> crateApiOpenVectorStore({required String path});
+
   Future<void> crateApiPinStreamNode({
     required String id,
     required bool pinned,
   });
 
+  GenerationPoll crateApiPollGeneration({required BigInt requestId});
+
+  Future<void> crateApiQuantizedIndexInsert({
+    required BigInt indexId,
+    required List<EmbeddingEntry> entries,
+  });
+
+  Future<BigInt> crateApiQuantizedIndexRemove({
+    required BigInt indexId,
+    required List<String> ids,
+  });
+
+  Future<RagAnswer> crateApiRagAnswer({
+    required String question,
+    required List<EmbeddingEntry> chunks,
+    int? topK,
+    int? contextTokens,
+    int? maxTokens,
+    String? modelId,
+  });
+
   Future<void> crateApiRemoveGraphNode({required String nodeId});
 
   Future<void> crateApiRemoveStreamEdge({
@@ -274,24 +665,108 @@ abstract class RustLibApi extends BaseApi {
 
   Future<void> crateApiRemoveStreamNode({required String id});
 
+  Future<void> crateApiSaveChatSession({
+    required BigInt sessionId,
+    required String path,
+  });
+
+  Future<void> crateApiSaveHnswIndex({
+    required BigInt indexId,
+    required String path,
+  });
+
+  Future<void> crateApiSaveQuantizedIndex({
+    required BigInt indexId,
+    required String path,
+  });
+
   Future<List<SimilarityResult>> crateApiSemanticSearch({
     required String queryText,
     required List<EmbeddingEntry> entries,
     required BigInt topK,
   });
 
+  Future<List<SimilarityResult>> crateApiSemanticSearchFiltered({
+    required String queryText,
+    required List<EmbeddingEntry> entries,
+    required SearchOptions options,
+  });
+
+  Future<List<SimilarityResult>> crateApiSemanticSearchInIndex({
+    required BigInt indexId,
+    required String queryText,
+    required BigInt topK,
+  });
+
+  Future<List<SimilarityResult>> crateApiSemanticSearchInIndexFiltered({
+    required BigInt indexId,
+    required String queryText,
+    required SearchOptions options,
+  });
+
+  Future<List<SimilarityResult>> crateApiSemanticSearchInStore({
+    required BigInt storeId,
+    required String queryText,
+    required BigInt topK,
+  });
+
+  Future<List<SimilarityResult>> crateApiSemanticSearchInStoreFiltered({
+    required BigInt storeId,
+    required String queryText,
+    required SearchOptions options,
+  });
+
+  void crateApiSetDefaultModel({required String modelId});
+
+  void crateApiSetModelMemoryBudget({BigInt? budgetBytes});
+
   Future<void> crateApiSetStreamNodePosition({
     required String id,
     required double x,
     required double y,
   });
 
+  Future<BigInt> crateApiStartChatCompletion({
+    required List<(String, String)> messages,
+    int? maxTokens,
+    List<String>? stopSequences,
+    String? modelId,
+  });
+
+  Future<BigInt> crateApiStartGeneration({
+    required String prompt,
+    int? maxTokens,
+    List<String>? stopSequences,
+    String? modelId,
+  });
+
   Future<void> crateApiStartGraphStream();
 
   void crateApiStopGraphStream();
 
+  Future<Summary> crateApiSummarizeText({
+    required String text,
+    SummaryOptions? options,
+  });
+
+  Future<Summary> crateApiSummarizeTranscript({
+    required List<TranscriptSegment> segments,
+    SummaryOptions? options,
+  });
+
+  Future<Int32List> crateApiTokenizeText({
+    required String text,
+    required bool addBos,
+    required bool parseSpecial,
+    String? modelId,
+  });
+
+  void crateApiUnloadEmbeddingModel();
+
   void crateApiUnloadModel();
 
+  bool crateApiUnloadModelById({required String modelId});
+
   Future<void> crateApiUpdateGraphViewport({
     required double x,
     required double y,
@@ -299,10 +774,41 @@ abstract class RustLibApi extends BaseApi {
     required double height,
     required double scale,
   });
-}
 
-class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
-  RustLibApiImpl({
+  void crateApiUseLlamaInferenceBackend();
+
+  void crateApiUseMockInferenceBackend({
+    required List<String> completions,
+    String? fallback,
+    required int embeddingDimension,
+  });
+
+  void crateApiValidateSearchFilter({required String filter});
+
+  Future<BigInt> crateApiVectorStoreDelete({
+    required BigInt storeId,
+    required List<String> ids,
+  });
+
+  Future<List<EmbeddingEntry>> crateApiVectorStoreGet({
+    required BigInt storeId,
+    required List<String> ids,
+  });
+
+  BigInt crateApiVectorStoreLen({required BigInt storeId});
+
+  Future<void> crateApiVectorStoreUpsert({
+    required BigInt storeId,
+    required List<EmbeddingEntry> entries,
+  });
+
+  Future<GenerationResult> crateApiWaitForGeneration({
+    required BigInt requestId,
+  });
+}
+
+class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
+  RustLibApiImpl({
     required super.handler,
     required super.wire,
     required super.generalizedFrbRustBinding,
@@ -553,6 +1059,45 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ['entries', 'k', 'similarityThreshold', 'existingLinks'],
       );
 
+  @override
+  Future<KnowledgeGraphAnalysis> crateApiAnalyzeKnowledgeGraphInStore({
+    required BigInt storeId,
+    BigInt? k,
+    double? similarityThreshold,
+    List<(String, String)>? existingLinks,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final arg0 = cst_encode_u_64(storeId);
+          final arg1 = cst_encode_opt_box_autoadd_usize(k);
+          final arg2 = cst_encode_opt_box_autoadd_f_32(similarityThreshold);
+          final arg3 = cst_encode_opt_list_record_string_string(existingLinks);
+          return wire.wire__crate__api__analyze_knowledge_graph_in_store(
+            port_,
+            arg0,
+            arg1,
+            arg2,
+            arg3,
+          );
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_knowledge_graph_analysis,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiAnalyzeKnowledgeGraphInStoreConstMeta,
+        argValues: [storeId, k, similarityThreshold, existingLinks],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiAnalyzeKnowledgeGraphInStoreConstMeta =>
+      const TaskConstMeta(
+        debugName: 'analyze_knowledge_graph_in_store',
+        argNames: ['storeId', 'k', 'similarityThreshold', 'existingLinks'],
+      );
+
   @override
   Future<List<EmbeddingEntry>> crateApiBatchEmbed({
     required List<String> texts,
@@ -578,2965 +1123,9486 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       const TaskConstMeta(debugName: 'batch_embed', argNames: ['texts']);
 
   @override
-  Future<String> crateApiChatCompletion({
-    required List<(String, String)> messages,
-    int? maxTokens,
+  Future<List<EmbeddingEntry>> crateApiBatchEmbedWithModel({
+    required List<String> texts,
+    String? modelId,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
-          final arg0 = cst_encode_list_record_string_string(messages);
-          final arg1 = cst_encode_opt_box_autoadd_u_32(maxTokens);
-          return wire.wire__crate__api__chat_completion(port_, arg0, arg1);
+          final arg0 = cst_encode_list_String(texts);
+          final arg1 = cst_encode_opt_String(modelId);
+          return wire.wire__crate__api__batch_embed_with_model(
+            port_,
+            arg0,
+            arg1,
+          );
         },
         codec: DcoCodec(
-          decodeSuccessData: dco_decode_String,
+          decodeSuccessData: dco_decode_list_embedding_entry,
           decodeErrorData: dco_decode_AnyhowException,
         ),
-        constMeta: kCrateApiChatCompletionConstMeta,
-        argValues: [messages, maxTokens],
+        constMeta: kCrateApiBatchEmbedWithModelConstMeta,
+        argValues: [texts, modelId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiChatCompletionConstMeta => const TaskConstMeta(
-    debugName: 'chat_completion',
-    argNames: ['messages', 'maxTokens'],
-  );
+  TaskConstMeta get kCrateApiBatchEmbedWithModelConstMeta =>
+      const TaskConstMeta(
+        debugName: 'batch_embed_with_model',
+        argNames: ['texts', 'modelId'],
+      );
 
   @override
-  void crateApiClearGraph() {
+  bool crateApiCancelGeneration({required BigInt requestId}) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
-          return wire.wire__crate__api__clear_graph();
+          final arg0 = cst_encode_u_64(requestId);
+          return wire.wire__crate__api__cancel_generation(arg0);
         },
         codec: DcoCodec(
-          decodeSuccessData: dco_decode_unit,
+          decodeSuccessData: dco_decode_bool,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiClearGraphConstMeta,
-        argValues: [],
+        constMeta: kCrateApiCancelGenerationConstMeta,
+        argValues: [requestId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiClearGraphConstMeta =>
-      const TaskConstMeta(debugName: 'clear_graph', argNames: []);
+  TaskConstMeta get kCrateApiCancelGenerationConstMeta => const TaskConstMeta(
+    debugName: 'cancel_generation',
+    argNames: ['requestId'],
+  );
 
   @override
-  void crateApiClearStreamGraph() {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          return wire.wire__crate__api__clear_stream_graph();
+  Future<String> crateApiChatCompletion({
+    required List<(String, String)> messages,
+    int? maxTokens,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final arg0 = cst_encode_list_record_string_string(messages);
+          final arg1 = cst_encode_opt_box_autoadd_u_32(maxTokens);
+          return wire.wire__crate__api__chat_completion(port_, arg0, arg1);
         },
         codec: DcoCodec(
-          decodeSuccessData: dco_decode_unit,
-          decodeErrorData: null,
+          decodeSuccessData: dco_decode_String,
+          decodeErrorData: dco_decode_AnyhowException,
         ),
-        constMeta: kCrateApiClearStreamGraphConstMeta,
-        argValues: [],
+        constMeta: kCrateApiChatCompletionConstMeta,
+        argValues: [messages, maxTokens],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiClearStreamGraphConstMeta =>
-      const TaskConstMeta(debugName: 'clear_stream_graph', argNames: []);
+  TaskConstMeta get kCrateApiChatCompletionConstMeta => const TaskConstMeta(
+    debugName: 'chat_completion',
+    argNames: ['messages', 'maxTokens'],
+  );
 
   @override
-  Future<List<EmbeddingCluster>> crateApiClusterEmbeddings({
-    required List<EmbeddingEntry> entries,
-    required double threshold,
+  Stream<GenerationChunk> crateApiChatCompletionStream({
+    required List<(String, String)> messages,
+    int? maxTokens,
+    List<String>? stopSequences,
+    String? modelId,
   }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final arg0 = cst_encode_list_embedding_entry(entries);
-          final arg1 = cst_encode_f_32(threshold);
-          return wire.wire__crate__api__cluster_embeddings(port_, arg0, arg1);
-        },
-        codec: DcoCodec(
-          decodeSuccessData: dco_decode_list_embedding_cluster,
-          decodeErrorData: null,
+    final sink = RustStreamSink<GenerationChunk>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final arg0 = cst_encode_list_record_string_string(messages);
+            final arg1 = cst_encode_opt_box_autoadd_u_32(maxTokens);
+            final arg2 = cst_encode_opt_list_String(stopSequences);
+            final arg3 = cst_encode_opt_String(modelId);
+            final arg4 = cst_encode_StreamSink_generation_chunk_Dco(sink);
+            return wire.wire__crate__api__chat_completion_stream(
+              port_,
+              arg0,
+              arg1,
+              arg2,
+              arg3,
+              arg4,
+            );
+          },
+          codec: DcoCodec(
+            decodeSuccessData: dco_decode_unit,
+            decodeErrorData: dco_decode_AnyhowException,
+          ),
+          constMeta: kCrateApiChatCompletionStreamConstMeta,
+          argValues: [messages, maxTokens, stopSequences, modelId, sink],
+          apiImpl: this,
         ),
-        constMeta: kCrateApiClusterEmbeddingsConstMeta,
-        argValues: [entries, threshold],
-        apiImpl: this,
       ),
     );
+    return sink.stream;
   }
 
-  TaskConstMeta get kCrateApiClusterEmbeddingsConstMeta => const TaskConstMeta(
-    debugName: 'cluster_embeddings',
-    argNames: ['entries', 'threshold'],
-  );
+  TaskConstMeta get kCrateApiChatCompletionStreamConstMeta =>
+      const TaskConstMeta(
+        debugName: 'chat_completion_stream',
+        argNames: ['messages', 'maxTokens', 'stopSequences', 'modelId', 'sink'],
+      );
 
   @override
-  Future<ClusteringResult> crateApiClusterNotes({
-    required List<EmbeddingEntry> entries,
-    BigInt? k,
-    BigInt? maxIterations,
+  Future<String> crateApiChatCompletionWithModel({
+    required List<(String, String)> messages,
+    int? maxTokens,
+    String? modelId,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
-          final arg0 = cst_encode_list_embedding_entry(entries);
-          final arg1 = cst_encode_opt_box_autoadd_usize(k);
-          final arg2 = cst_encode_opt_box_autoadd_usize(maxIterations);
-          return wire.wire__crate__api__cluster_notes(port_, arg0, arg1, arg2);
+          final arg0 = cst_encode_list_record_string_string(messages);
+          final arg1 = cst_encode_opt_box_autoadd_u_32(maxTokens);
+          final arg2 = cst_encode_opt_String(modelId);
+          return wire.wire__crate__api__chat_completion_with_model(
+            port_,
+            arg0,
+            arg1,
+            arg2,
+          );
         },
         codec: DcoCodec(
-          decodeSuccessData: dco_decode_clustering_result,
+          decodeSuccessData: dco_decode_String,
           decodeErrorData: dco_decode_AnyhowException,
         ),
-        constMeta: kCrateApiClusterNotesConstMeta,
-        argValues: [entries, k, maxIterations],
+        constMeta: kCrateApiChatCompletionWithModelConstMeta,
+        argValues: [messages, maxTokens, modelId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiClusterNotesConstMeta => const TaskConstMeta(
-    debugName: 'cluster_notes',
-    argNames: ['entries', 'k', 'maxIterations'],
-  );
+  TaskConstMeta get kCrateApiChatCompletionWithModelConstMeta =>
+      const TaskConstMeta(
+        debugName: 'chat_completion_with_model',
+        argNames: ['messages', 'maxTokens', 'modelId'],
+      );
 
   @override
-  Future<GraphState> crateApiComputeGraphLayout({int? iterations}) {
+  Future<GenerationResult> crateApiChatCompletionWithOptions({
+    required List<(String, String)> messages,
+    required GenerationOptions options,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
-          final arg0 = cst_encode_opt_box_autoadd_u_32(iterations);
-          return wire.wire__crate__api__compute_graph_layout(port_, arg0);
+          final arg0 = cst_encode_list_record_string_string(messages);
+          final arg1 = cst_encode_box_autoadd_generation_options(options);
+          return wire.wire__crate__api__chat_completion_with_options(
+            port_,
+            arg0,
+            arg1,
+          );
         },
         codec: DcoCodec(
-          decodeSuccessData: dco_decode_graph_state,
+          decodeSuccessData: dco_decode_generation_result,
           decodeErrorData: dco_decode_AnyhowException,
         ),
-        constMeta: kCrateApiComputeGraphLayoutConstMeta,
-        argValues: [iterations],
+        constMeta: kCrateApiChatCompletionWithOptionsConstMeta,
+        argValues: [messages, options],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiComputeGraphLayoutConstMeta => const TaskConstMeta(
-    debugName: 'compute_graph_layout',
-    argNames: ['iterations'],
-  );
+  TaskConstMeta get kCrateApiChatCompletionWithOptionsConstMeta =>
+      const TaskConstMeta(
+        debugName: 'chat_completion_with_options',
+        argNames: ['messages', 'options'],
+      );
 
   @override
-  Future<void> crateApiConnectNoteToTopics({
-    required String noteId,
-    required List<String> topicIds,
+  Future<GenerationResult> crateApiChatSessionCompletion({
+    required BigInt sessionId,
+    required List<(String, String)> messages,
+    required GenerationOptions options,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
-          final arg0 = cst_encode_String(noteId);
-          final arg1 = cst_encode_list_String(topicIds);
-          return wire.wire__crate__api__connect_note_to_topics(
+          final arg0 = cst_encode_u_64(sessionId);
+          final arg1 = cst_encode_list_record_string_string(messages);
+          final arg2 = cst_encode_box_autoadd_generation_options(options);
+          return wire.wire__crate__api__chat_session_completion(
             port_,
             arg0,
             arg1,
+            arg2,
           );
         },
         codec: DcoCodec(
-          decodeSuccessData: dco_decode_unit,
+          decodeSuccessData: dco_decode_generation_result,
           decodeErrorData: dco_decode_AnyhowException,
         ),
-        constMeta: kCrateApiConnectNoteToTopicsConstMeta,
-        argValues: [noteId, topicIds],
+        constMeta: kCrateApiChatSessionCompletionConstMeta,
+        argValues: [sessionId, messages, options],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiConnectNoteToTopicsConstMeta =>
+  TaskConstMeta get kCrateApiChatSessionCompletionConstMeta =>
       const TaskConstMeta(
-        debugName: 'connect_note_to_topics',
-        argNames: ['noteId', 'topicIds'],
+        debugName: 'chat_session_completion',
+        argNames: ['sessionId', 'messages', 'options'],
       );
 
   @override
-  double crateApiCosineSimilarity({
-    required List<double> a,
-    required List<double> b,
+  Stream<GenerationChunk> crateApiChatSessionStream({
+    required BigInt sessionId,
+    required List<(String, String)> messages,
+    int? maxTokens,
+    List<String>? stopSequences,
   }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final arg0 = cst_encode_list_prim_f_32_loose(a);
-          final arg1 = cst_encode_list_prim_f_32_loose(b);
-          return wire.wire__crate__api__cosine_similarity(arg0, arg1);
-        },
-        codec: DcoCodec(
-          decodeSuccessData: dco_decode_f_32,
-          decodeErrorData: null,
+    final sink = RustStreamSink<GenerationChunk>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final arg0 = cst_encode_u_64(sessionId);
+            final arg1 = cst_encode_list_record_string_string(messages);
+            final arg2 = cst_encode_opt_box_autoadd_u_32(maxTokens);
+            final arg3 = cst_encode_opt_list_String(stopSequences);
+            final arg4 = cst_encode_StreamSink_generation_chunk_Dco(sink);
+            return wire.wire__crate__api__chat_session_stream(
+              port_,
+              arg0,
+              arg1,
+              arg2,
+              arg3,
+              arg4,
+            );
+          },
+          codec: DcoCodec(
+            decodeSuccessData: dco_decode_unit,
+            decodeErrorData: dco_decode_AnyhowException,
+          ),
+          constMeta: kCrateApiChatSessionStreamConstMeta,
+          argValues: [sessionId, messages, maxTokens, stopSequences, sink],
+          apiImpl: this,
         ),
-        constMeta: kCrateApiCosineSimilarityConstMeta,
-        argValues: [a, b],
-        apiImpl: this,
       ),
     );
+    return sink.stream;
   }
 
-  TaskConstMeta get kCrateApiCosineSimilarityConstMeta =>
-      const TaskConstMeta(debugName: 'cosine_similarity', argNames: ['a', 'b']);
+  TaskConstMeta get kCrateApiChatSessionStreamConstMeta => const TaskConstMeta(
+    debugName: 'chat_session_stream',
+    argNames: ['sessionId', 'messages', 'maxTokens', 'stopSequences', 'sink'],
+  );
 
   @override
-  Future<SemanticEdgeResult> crateApiDiscoverSemanticEdges({
-    required List<EmbeddingEntry> entries,
-    double? threshold,
-    List<(String, String)>? existingLinks,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final arg0 = cst_encode_list_embedding_entry(entries);
-          final arg1 = cst_encode_opt_box_autoadd_f_32(threshold);
-          final arg2 = cst_encode_opt_list_record_string_string(existingLinks);
-          return wire.wire__crate__api__discover_semantic_edges(
-            port_,
-            arg0,
-            arg1,
-            arg2,
-          );
+  BigInt crateApiChatSessionTokenCount({required BigInt sessionId}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final arg0 = cst_encode_u_64(sessionId);
+          return wire.wire__crate__api__chat_session_token_count(arg0);
         },
         codec: DcoCodec(
-          decodeSuccessData: dco_decode_semantic_edge_result,
-          decodeErrorData: null,
+          decodeSuccessData: dco_decode_usize,
+          decodeErrorData: dco_decode_AnyhowException,
         ),
-        constMeta: kCrateApiDiscoverSemanticEdgesConstMeta,
-        argValues: [entries, threshold, existingLinks],
+        constMeta: kCrateApiChatSessionTokenCountConstMeta,
+        argValues: [sessionId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiDiscoverSemanticEdgesConstMeta =>
+  TaskConstMeta get kCrateApiChatSessionTokenCountConstMeta =>
       const TaskConstMeta(
-        debugName: 'discover_semantic_edges',
-        argNames: ['entries', 'threshold', 'existingLinks'],
+        debugName: 'chat_session_token_count',
+        argNames: ['sessionId'],
       );
 
   @override
-  Future<List<String>> crateApiExtractTopics({
+  Future<List<MarkdownChunk>> crateApiChunkMarkdown({
     required String text,
-    int? numTopics,
+    int? maxTokens,
+    int? overlapTokens,
+    String? modelId,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final arg0 = cst_encode_String(text);
-          final arg1 = cst_encode_opt_box_autoadd_u_32(numTopics);
-          return wire.wire__crate__api__extract_topics(port_, arg0, arg1);
+          final arg1 = cst_encode_opt_box_autoadd_u_32(maxTokens);
+          final arg2 = cst_encode_opt_box_autoadd_u_32(overlapTokens);
+          final arg3 = cst_encode_opt_String(modelId);
+          return wire.wire__crate__api__chunk_markdown(
+            port_,
+            arg0,
+            arg1,
+            arg2,
+            arg3,
+          );
         },
         codec: DcoCodec(
-          decodeSuccessData: dco_decode_list_String,
+          decodeSuccessData: dco_decode_list_markdown_chunk,
           decodeErrorData: dco_decode_AnyhowException,
         ),
-        constMeta: kCrateApiExtractTopicsConstMeta,
-        argValues: [text, numTopics],
+        constMeta: kCrateApiChunkMarkdownConstMeta,
+        argValues: [text, maxTokens, overlapTokens, modelId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiExtractTopicsConstMeta => const TaskConstMeta(
-    debugName: 'extract_topics',
-    argNames: ['text', 'numTopics'],
+  TaskConstMeta get kCrateApiChunkMarkdownConstMeta => const TaskConstMeta(
+    debugName: 'chunk_markdown',
+    argNames: ['text', 'maxTokens', 'overlapTokens', 'modelId'],
   );
 
   @override
-  Future<List<SimilarityResult>> crateApiFindSimilar({
-    required List<double> query,
-    required List<EmbeddingEntry> entries,
-    required BigInt topK,
-    required double threshold,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final arg0 = cst_encode_list_prim_f_32_loose(query);
-          final arg1 = cst_encode_list_embedding_entry(entries);
-          final arg2 = cst_encode_usize(topK);
-          final arg3 = cst_encode_f_32(threshold);
-          return wire.wire__crate__api__find_similar(
-            port_,
-            arg0,
-            arg1,
-            arg2,
-            arg3,
-          );
+  void crateApiClearGraph() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          return wire.wire__crate__api__clear_graph();
         },
         codec: DcoCodec(
-          decodeSuccessData: dco_decode_list_similarity_result,
+          decodeSuccessData: dco_decode_unit,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiFindSimilarConstMeta,
-        argValues: [query, entries, topK, threshold],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiFindSimilarConstMeta => const TaskConstMeta(
-    debugName: 'find_similar',
-    argNames: ['query', 'entries', 'topK', 'threshold'],
-  );
-
-  @override
-  Future<String> crateApiGenerateText({
-    required String prompt,
-    int? maxTokens,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final arg0 = cst_encode_String(prompt);
-          final arg1 = cst_encode_opt_box_autoadd_u_32(maxTokens);
-          return wire.wire__crate__api__generate_text(port_, arg0, arg1);
-        },
-        codec: DcoCodec(
-          decodeSuccessData: dco_decode_String,
-          decodeErrorData: dco_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiGenerateTextConstMeta,
-        argValues: [prompt, maxTokens],
+        constMeta: kCrateApiClearGraphConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiGenerateTextConstMeta => const TaskConstMeta(
-    debugName: 'generate_text',
-    argNames: ['prompt', 'maxTokens'],
-  );
+  TaskConstMeta get kCrateApiClearGraphConstMeta =>
+      const TaskConstMeta(debugName: 'clear_graph', argNames: []);
 
   @override
-  Future<Float32List> crateApiGetEmbedding({required String text}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final arg0 = cst_encode_String(text);
-          return wire.wire__crate__api__get_embedding(port_, arg0);
+  void crateApiClearStreamGraph() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          return wire.wire__crate__api__clear_stream_graph();
         },
         codec: DcoCodec(
-          decodeSuccessData: dco_decode_list_prim_f_32_strict,
-          decodeErrorData: dco_decode_AnyhowException,
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiGetEmbeddingConstMeta,
-        argValues: [text],
+        constMeta: kCrateApiClearStreamGraphConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiGetEmbeddingConstMeta =>
-      const TaskConstMeta(debugName: 'get_embedding', argNames: ['text']);
+  TaskConstMeta get kCrateApiClearStreamGraphConstMeta =>
+      const TaskConstMeta(debugName: 'clear_stream_graph', argNames: []);
 
   @override
-  BigInt crateApiGetEmbeddingDimension() {
+  bool crateApiCloseChatSession({required BigInt sessionId}) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
-          return wire.wire__crate__api__get_embedding_dimension();
+          final arg0 = cst_encode_u_64(sessionId);
+          return wire.wire__crate__api__close_chat_session(arg0);
         },
         codec: DcoCodec(
-          decodeSuccessData: dco_decode_usize,
-          decodeErrorData: dco_decode_AnyhowException,
+          decodeSuccessData: dco_decode_bool,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiGetEmbeddingDimensionConstMeta,
-        argValues: [],
+        constMeta: kCrateApiCloseChatSessionConstMeta,
+        argValues: [sessionId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiGetEmbeddingDimensionConstMeta =>
-      const TaskConstMeta(debugName: 'get_embedding_dimension', argNames: []);
+  TaskConstMeta get kCrateApiCloseChatSessionConstMeta => const TaskConstMeta(
+    debugName: 'close_chat_session',
+    argNames: ['sessionId'],
+  );
 
   @override
-  Future<GraphState> crateApiGetGraphState() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          return wire.wire__crate__api__get_graph_state(port_);
+  void crateApiCloseEmbeddingCache() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          return wire.wire__crate__api__close_embedding_cache();
         },
         codec: DcoCodec(
-          decodeSuccessData: dco_decode_graph_state,
-          decodeErrorData: dco_decode_AnyhowException,
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiGetGraphStateConstMeta,
+        constMeta: kCrateApiCloseEmbeddingCacheConstMeta,
         argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiGetGraphStateConstMeta =>
-      const TaskConstMeta(debugName: 'get_graph_state', argNames: []);
+  TaskConstMeta get kCrateApiCloseEmbeddingCacheConstMeta =>
+      const TaskConstMeta(debugName: 'close_embedding_cache', argNames: []);
 
   @override
-  int crateApiGetModelType() {
+  bool crateApiCloseHnswIndex({required BigInt indexId}) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
-          return wire.wire__crate__api__get_model_type();
+          final arg0 = cst_encode_u_64(indexId);
+          return wire.wire__crate__api__close_hnsw_index(arg0);
         },
         codec: DcoCodec(
-          decodeSuccessData: dco_decode_i_32,
+          decodeSuccessData: dco_decode_bool,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiGetModelTypeConstMeta,
-        argValues: [],
+        constMeta: kCrateApiCloseHnswIndexConstMeta,
+        argValues: [indexId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiGetModelTypeConstMeta =>
-      const TaskConstMeta(debugName: 'get_model_type', argNames: []);
+  TaskConstMeta get kCrateApiCloseHnswIndexConstMeta =>
+      const TaskConstMeta(debugName: 'close_hnsw_index', argNames: ['indexId']);
 
   @override
-  Future<String> crateApiGetOrCreateTopicHub({required String topic}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final arg0 = cst_encode_String(topic);
-          return wire.wire__crate__api__get_or_create_topic_hub(port_, arg0);
+  bool crateApiCloseQuantizedIndex({required BigInt indexId}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final arg0 = cst_encode_u_64(indexId);
+          return wire.wire__crate__api__close_quantized_index(arg0);
         },
         codec: DcoCodec(
-          decodeSuccessData: dco_decode_String,
-          decodeErrorData: dco_decode_AnyhowException,
+          decodeSuccessData: dco_decode_bool,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiGetOrCreateTopicHubConstMeta,
-        argValues: [topic],
+        constMeta: kCrateApiCloseQuantizedIndexConstMeta,
+        argValues: [indexId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiGetOrCreateTopicHubConstMeta =>
+  TaskConstMeta get kCrateApiCloseQuantizedIndexConstMeta =>
       const TaskConstMeta(
-        debugName: 'get_or_create_topic_hub',
-        argNames: ['topic'],
+        debugName: 'close_quantized_index',
+        argNames: ['indexId'],
       );
 
   @override
-  StreamGraphStats crateApiGetStreamGraphStats() {
+  bool crateApiCloseVectorStore({required BigInt storeId}) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
-          return wire.wire__crate__api__get_stream_graph_stats();
+          final arg0 = cst_encode_u_64(storeId);
+          return wire.wire__crate__api__close_vector_store(arg0);
         },
         codec: DcoCodec(
-          decodeSuccessData: dco_decode_stream_graph_stats,
+          decodeSuccessData: dco_decode_bool,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiGetStreamGraphStatsConstMeta,
-        argValues: [],
+        constMeta: kCrateApiCloseVectorStoreConstMeta,
+        argValues: [storeId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiGetStreamGraphStatsConstMeta =>
-      const TaskConstMeta(debugName: 'get_stream_graph_stats', argNames: []);
+  TaskConstMeta get kCrateApiCloseVectorStoreConstMeta => const TaskConstMeta(
+    debugName: 'close_vector_store',
+    argNames: ['storeId'],
+  );
 
   @override
-  String crateApiGetVersion() {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          return wire.wire__crate__api__get_version();
+  Future<List<EmbeddingCluster>> crateApiClusterEmbeddings({
+    required List<EmbeddingEntry> entries,
+    required double threshold,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final arg0 = cst_encode_list_embedding_entry(entries);
+          final arg1 = cst_encode_f_32(threshold);
+          return wire.wire__crate__api__cluster_embeddings(port_, arg0, arg1);
         },
         codec: DcoCodec(
-          decodeSuccessData: dco_decode_String,
+          decodeSuccessData: dco_decode_list_embedding_cluster,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiGetVersionConstMeta,
-        argValues: [],
+        constMeta: kCrateApiClusterEmbeddingsConstMeta,
+        argValues: [entries, threshold],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiGetVersionConstMeta =>
-      const TaskConstMeta(debugName: 'get_version', argNames: []);
+  TaskConstMeta get kCrateApiClusterEmbeddingsConstMeta => const TaskConstMeta(
+    debugName: 'cluster_embeddings',
+    argNames: ['entries', 'threshold'],
+  );
 
   @override
-  Future<List<NodePosition>> crateApiGetVisibleGraphNodes() {
+  Future<ClusteringResult> crateApiClusterNotes({
+    required List<EmbeddingEntry> entries,
+    BigInt? k,
+    BigInt? maxIterations,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
-          return wire.wire__crate__api__get_visible_graph_nodes(port_);
+          final arg0 = cst_encode_list_embedding_entry(entries);
+          final arg1 = cst_encode_opt_box_autoadd_usize(k);
+          final arg2 = cst_encode_opt_box_autoadd_usize(maxIterations);
+          return wire.wire__crate__api__cluster_notes(port_, arg0, arg1, arg2);
         },
         codec: DcoCodec(
-          decodeSuccessData: dco_decode_list_node_position,
-          decodeErrorData: null,
+          decodeSuccessData: dco_decode_clustering_result,
+          decodeErrorData: dco_decode_AnyhowException,
         ),
-        constMeta: kCrateApiGetVisibleGraphNodesConstMeta,
-        argValues: [],
+        constMeta: kCrateApiClusterNotesConstMeta,
+        argValues: [entries, k, maxIterations],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiGetVisibleGraphNodesConstMeta =>
-      const TaskConstMeta(debugName: 'get_visible_graph_nodes', argNames: []);
+  TaskConstMeta get kCrateApiClusterNotesConstMeta => const TaskConstMeta(
+    debugName: 'cluster_notes',
+    argNames: ['entries', 'k', 'maxIterations'],
+  );
 
   @override
-  String crateApiHealthCheck() {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          return wire.wire__crate__api__health_check();
+  Future<ClusteringResult> crateApiClusterNotesInStore({
+    required BigInt storeId,
+    BigInt? k,
+    BigInt? maxIterations,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final arg0 = cst_encode_u_64(storeId);
+          final arg1 = cst_encode_opt_box_autoadd_usize(k);
+          final arg2 = cst_encode_opt_box_autoadd_usize(maxIterations);
+          return wire.wire__crate__api__cluster_notes_in_store(
+            port_,
+            arg0,
+            arg1,
+            arg2,
+          );
         },
         codec: DcoCodec(
-          decodeSuccessData: dco_decode_String,
-          decodeErrorData: null,
+          decodeSuccessData: dco_decode_clustering_result,
+          decodeErrorData: dco_decode_AnyhowException,
         ),
-        constMeta: kCrateApiHealthCheckConstMeta,
-        argValues: [],
+        constMeta: kCrateApiClusterNotesInStoreConstMeta,
+        argValues: [storeId, k, maxIterations],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiHealthCheckConstMeta =>
-      const TaskConstMeta(debugName: 'health_check', argNames: []);
+  TaskConstMeta get kCrateApiClusterNotesInStoreConstMeta =>
+      const TaskConstMeta(
+        debugName: 'cluster_notes_in_store',
+        argNames: ['storeId', 'k', 'maxIterations'],
+      );
 
   @override
-  void crateApiInitGraph() {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          return wire.wire__crate__api__init_graph();
+  Future<EmbeddingCacheStats> crateApiCompactEmbeddingCache() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          return wire.wire__crate__api__compact_embedding_cache(port_);
         },
         codec: DcoCodec(
-          decodeSuccessData: dco_decode_unit,
-          decodeErrorData: null,
+          decodeSuccessData: dco_decode_embedding_cache_stats,
+          decodeErrorData: dco_decode_AnyhowException,
         ),
-        constMeta: kCrateApiInitGraphConstMeta,
+        constMeta: kCrateApiCompactEmbeddingCacheConstMeta,
         argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiInitGraphConstMeta =>
-      const TaskConstMeta(debugName: 'init_graph', argNames: []);
+  TaskConstMeta get kCrateApiCompactEmbeddingCacheConstMeta =>
+      const TaskConstMeta(debugName: 'compact_embedding_cache', argNames: []);
 
   @override
-  Future<void> crateApiInitMcp({
-    required String basePath,
-    BigInt? maxFileSize,
-    List<String>? allowedExtensions,
+  Future<VectorStoreStats> crateApiCompactVectorStore({
+    required BigInt storeId,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
-          final arg0 = cst_encode_String(basePath);
-          final arg1 = cst_encode_opt_box_autoadd_usize(maxFileSize);
-          final arg2 = cst_encode_opt_list_String(allowedExtensions);
-          return wire.wire__crate__api__init_mcp(port_, arg0, arg1, arg2);
+          final arg0 = cst_encode_u_64(storeId);
+          return wire.wire__crate__api__compact_vector_store(port_, arg0);
         },
         codec: DcoCodec(
-          decodeSuccessData: dco_decode_unit,
+          decodeSuccessData: dco_decode_vector_store_stats,
           decodeErrorData: dco_decode_AnyhowException,
         ),
-        constMeta: kCrateApiInitMcpConstMeta,
-        argValues: [basePath, maxFileSize, allowedExtensions],
+        constMeta: kCrateApiCompactVectorStoreConstMeta,
+        argValues: [storeId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiInitMcpConstMeta => const TaskConstMeta(
-    debugName: 'init_mcp',
-    argNames: ['basePath', 'maxFileSize', 'allowedExtensions'],
+  TaskConstMeta get kCrateApiCompactVectorStoreConstMeta => const TaskConstMeta(
+    debugName: 'compact_vector_store',
+    argNames: ['storeId'],
   );
 
   @override
-  void crateApiInitModel({required String modelPath}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final arg0 = cst_encode_String(modelPath);
-          return wire.wire__crate__api__init_model(arg0);
+  Future<GraphState> crateApiComputeGraphLayout({int? iterations}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final arg0 = cst_encode_opt_box_autoadd_u_32(iterations);
+          return wire.wire__crate__api__compute_graph_layout(port_, arg0);
         },
         codec: DcoCodec(
-          decodeSuccessData: dco_decode_unit,
+          decodeSuccessData: dco_decode_graph_state,
           decodeErrorData: dco_decode_AnyhowException,
         ),
-        constMeta: kCrateApiInitModelConstMeta,
-        argValues: [modelPath],
+        constMeta: kCrateApiComputeGraphLayoutConstMeta,
+        argValues: [iterations],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiInitModelConstMeta =>
-      const TaskConstMeta(debugName: 'init_model', argNames: ['modelPath']);
+  TaskConstMeta get kCrateApiComputeGraphLayoutConstMeta => const TaskConstMeta(
+    debugName: 'compute_graph_layout',
+    argNames: ['iterations'],
+  );
 
   @override
-  Future<void> crateApiInitModelWithConfig({
-    required String modelPath,
-    required int nGpuLayers,
-    required int nCtx,
-    required int nThreads,
-    required double temperature,
-    required double topP,
-    required int maxTokens,
-    int? modelType,
+  Future<void> crateApiConnectNoteToTopics({
+    required String noteId,
+    required List<String> topicIds,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
-          final arg0 = cst_encode_String(modelPath);
-          final arg1 = cst_encode_u_32(nGpuLayers);
-          final arg2 = cst_encode_u_32(nCtx);
-          final arg3 = cst_encode_i_32(nThreads);
-          final arg4 = cst_encode_f_32(temperature);
-          final arg5 = cst_encode_f_32(topP);
-          final arg6 = cst_encode_u_32(maxTokens);
-          final arg7 = cst_encode_opt_box_autoadd_i_32(modelType);
-          return wire.wire__crate__api__init_model_with_config(
+          final arg0 = cst_encode_String(noteId);
+          final arg1 = cst_encode_list_String(topicIds);
+          return wire.wire__crate__api__connect_note_to_topics(
             port_,
             arg0,
             arg1,
-            arg2,
-            arg3,
-            arg4,
-            arg5,
-            arg6,
-            arg7,
           );
         },
         codec: DcoCodec(
           decodeSuccessData: dco_decode_unit,
           decodeErrorData: dco_decode_AnyhowException,
         ),
-        constMeta: kCrateApiInitModelWithConfigConstMeta,
-        argValues: [
-          modelPath,
-          nGpuLayers,
-          nCtx,
-          nThreads,
-          temperature,
-          topP,
-          maxTokens,
-          modelType,
-        ],
+        constMeta: kCrateApiConnectNoteToTopicsConstMeta,
+        argValues: [noteId, topicIds],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiInitModelWithConfigConstMeta =>
+  TaskConstMeta get kCrateApiConnectNoteToTopicsConstMeta =>
       const TaskConstMeta(
-        debugName: 'init_model_with_config',
-        argNames: [
-          'modelPath',
-          'nGpuLayers',
-          'nCtx',
-          'nThreads',
-          'temperature',
-          'topP',
-          'maxTokens',
-          'modelType',
-        ],
+        debugName: 'connect_note_to_topics',
+        argNames: ['noteId', 'topicIds'],
       );
 
   @override
-  bool crateApiIsGraphStreamRunning() {
+  double crateApiCosineSimilarity({
+    required List<double> a,
+    required List<double> b,
+  }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
-          return wire.wire__crate__api__is_graph_stream_running();
+          final arg0 = cst_encode_list_prim_f_32_loose(a);
+          final arg1 = cst_encode_list_prim_f_32_loose(b);
+          return wire.wire__crate__api__cosine_similarity(arg0, arg1);
         },
         codec: DcoCodec(
-          decodeSuccessData: dco_decode_bool,
+          decodeSuccessData: dco_decode_f_32,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiIsGraphStreamRunningConstMeta,
-        argValues: [],
+        constMeta: kCrateApiCosineSimilarityConstMeta,
+        argValues: [a, b],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiIsGraphStreamRunningConstMeta =>
-      const TaskConstMeta(debugName: 'is_graph_stream_running', argNames: []);
+  TaskConstMeta get kCrateApiCosineSimilarityConstMeta =>
+      const TaskConstMeta(debugName: 'cosine_similarity', argNames: ['a', 'b']);
 
   @override
-  bool crateApiIsMcpInitialized() {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          return wire.wire__crate__api__is_mcp_initialized();
+  Future<int> crateApiCountChatPromptTokens({
+    required List<(String, String)> messages,
+    String? modelId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final arg0 = cst_encode_list_record_string_string(messages);
+          final arg1 = cst_encode_opt_String(modelId);
+          return wire.wire__crate__api__count_chat_prompt_tokens(
+            port_,
+            arg0,
+            arg1,
+          );
         },
         codec: DcoCodec(
-          decodeSuccessData: dco_decode_bool,
-          decodeErrorData: null,
+          decodeSuccessData: dco_decode_u_32,
+          decodeErrorData: dco_decode_AnyhowException,
         ),
-        constMeta: kCrateApiIsMcpInitializedConstMeta,
-        argValues: [],
+        constMeta: kCrateApiCountChatPromptTokensConstMeta,
+        argValues: [messages, modelId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiIsMcpInitializedConstMeta =>
-      const TaskConstMeta(debugName: 'is_mcp_initialized', argNames: []);
+  TaskConstMeta get kCrateApiCountChatPromptTokensConstMeta =>
+      const TaskConstMeta(
+        debugName: 'count_chat_prompt_tokens',
+        argNames: ['messages', 'modelId'],
+      );
 
   @override
-  bool crateApiIsModelLoaded() {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          return wire.wire__crate__api__is_model_loaded();
+  Future<Uint32List> crateApiCountChatTokens({
+    required List<(String, String)> messages,
+    String? modelId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final arg0 = cst_encode_list_record_string_string(messages);
+          final arg1 = cst_encode_opt_String(modelId);
+          return wire.wire__crate__api__count_chat_tokens(port_, arg0, arg1);
         },
         codec: DcoCodec(
-          decodeSuccessData: dco_decode_bool,
-          decodeErrorData: null,
+          decodeSuccessData: dco_decode_list_prim_u_32_strict,
+          decodeErrorData: dco_decode_AnyhowException,
         ),
-        constMeta: kCrateApiIsModelLoadedConstMeta,
-        argValues: [],
+        constMeta: kCrateApiCountChatTokensConstMeta,
+        argValues: [messages, modelId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiIsModelLoadedConstMeta =>
-      const TaskConstMeta(debugName: 'is_model_loaded', argNames: []);
+  TaskConstMeta get kCrateApiCountChatTokensConstMeta => const TaskConstMeta(
+    debugName: 'count_chat_tokens',
+    argNames: ['messages', 'modelId'],
+  );
 
   @override
-  TaskCategory crateApiMcpClassifyTask({required String message}) {
+  Future<int> crateApiCountTokens({required String text, String? modelId}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final arg0 = cst_encode_String(text);
+          final arg1 = cst_encode_opt_String(modelId);
+          return wire.wire__crate__api__count_tokens(port_, arg0, arg1);
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_u_32,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiCountTokensConstMeta,
+        argValues: [text, modelId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCountTokensConstMeta => const TaskConstMeta(
+    debugName: 'count_tokens',
+    argNames: ['text', 'modelId'],
+  );
+
+  @override
+  Future<BigInt> crateApiCreateChatSession({int? nCtx, String? modelId}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final arg0 = cst_encode_opt_box_autoadd_u_32(nCtx);
+          final arg1 = cst_encode_opt_String(modelId);
+          return wire.wire__crate__api__create_chat_session(port_, arg0, arg1);
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_u_64,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiCreateChatSessionConstMeta,
+        argValues: [nCtx, modelId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCreateChatSessionConstMeta => const TaskConstMeta(
+    debugName: 'create_chat_session',
+    argNames: ['nCtx', 'modelId'],
+  );
+
+  @override
+  BigInt crateApiCreateHnswIndex({HnswConfig? config}) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
-          final arg0 = cst_encode_String(message);
-          return wire.wire__crate__api__mcp_classify_task(arg0);
+          final arg0 = cst_encode_opt_box_autoadd_hnsw_config(config);
+          return wire.wire__crate__api__create_hnsw_index(arg0);
         },
         codec: DcoCodec(
-          decodeSuccessData: dco_decode_task_category,
+          decodeSuccessData: dco_decode_u_64,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiMcpClassifyTaskConstMeta,
-        argValues: [message],
+        constMeta: kCrateApiCreateHnswIndexConstMeta,
+        argValues: [config],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiMcpClassifyTaskConstMeta => const TaskConstMeta(
-    debugName: 'mcp_classify_task',
-    argNames: ['message'],
-  );
+  TaskConstMeta get kCrateApiCreateHnswIndexConstMeta =>
+      const TaskConstMeta(debugName: 'create_hnsw_index', argNames: ['config']);
 
   @override
-  Future<void> crateApiMcpCreateFolder({required String path}) {
+  Future<BigInt> crateApiCreateHnswIndexFromStore({
+    required BigInt storeId,
+    HnswConfig? config,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
-          final arg0 = cst_encode_String(path);
-          return wire.wire__crate__api__mcp_create_folder(port_, arg0);
+          final arg0 = cst_encode_u_64(storeId);
+          final arg1 = cst_encode_opt_box_autoadd_hnsw_config(config);
+          return wire.wire__crate__api__create_hnsw_index_from_store(
+            port_,
+            arg0,
+            arg1,
+          );
         },
         codec: DcoCodec(
-          decodeSuccessData: dco_decode_unit,
+          decodeSuccessData: dco_decode_u_64,
           decodeErrorData: dco_decode_AnyhowException,
         ),
-        constMeta: kCrateApiMcpCreateFolderConstMeta,
-        argValues: [path],
+        constMeta: kCrateApiCreateHnswIndexFromStoreConstMeta,
+        argValues: [storeId, config],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiMcpCreateFolderConstMeta =>
-      const TaskConstMeta(debugName: 'mcp_create_folder', argNames: ['path']);
+  TaskConstMeta get kCrateApiCreateHnswIndexFromStoreConstMeta =>
+      const TaskConstMeta(
+        debugName: 'create_hnsw_index_from_store',
+        argNames: ['storeId', 'config'],
+      );
 
   @override
-  Future<void> crateApiMcpDeleteFile({required String path}) {
+  Future<BigInt> crateApiCreateQuantizedIndex({
+    required List<EmbeddingEntry> entries,
+    required QuantizationKind kind,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
-          final arg0 = cst_encode_String(path);
-          return wire.wire__crate__api__mcp_delete_file(port_, arg0);
+          final arg0 = cst_encode_list_embedding_entry(entries);
+          final arg1 = cst_encode_quantization_kind(kind);
+          return wire.wire__crate__api__create_quantized_index(
+            port_,
+            arg0,
+            arg1,
+          );
         },
         codec: DcoCodec(
-          decodeSuccessData: dco_decode_unit,
+          decodeSuccessData: dco_decode_u_64,
           decodeErrorData: dco_decode_AnyhowException,
         ),
-        constMeta: kCrateApiMcpDeleteFileConstMeta,
-        argValues: [path],
+        constMeta: kCrateApiCreateQuantizedIndexConstMeta,
+        argValues: [entries, kind],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiMcpDeleteFileConstMeta =>
-      const TaskConstMeta(debugName: 'mcp_delete_file', argNames: ['path']);
+  TaskConstMeta get kCrateApiCreateQuantizedIndexConstMeta =>
+      const TaskConstMeta(
+        debugName: 'create_quantized_index',
+        argNames: ['entries', 'kind'],
+      );
 
   @override
-  Future<MCPToolResult> crateApiMcpExecuteToolCall({
-    required MCPToolCall toolCall,
+  Future<BigInt> crateApiCreateQuantizedIndexFromStore({
+    required BigInt storeId,
+    required QuantizationKind kind,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
-          final arg0 = cst_encode_box_autoadd_mcp_tool_call(toolCall);
-          return wire.wire__crate__api__mcp_execute_tool_call(port_, arg0);
+          final arg0 = cst_encode_u_64(storeId);
+          final arg1 = cst_encode_quantization_kind(kind);
+          return wire.wire__crate__api__create_quantized_index_from_store(
+            port_,
+            arg0,
+            arg1,
+          );
         },
         codec: DcoCodec(
-          decodeSuccessData: dco_decode_mcp_tool_result,
-          decodeErrorData: null,
+          decodeSuccessData: dco_decode_u_64,
+          decodeErrorData: dco_decode_AnyhowException,
         ),
-        constMeta: kCrateApiMcpExecuteToolCallConstMeta,
-        argValues: [toolCall],
+        constMeta: kCrateApiCreateQuantizedIndexFromStoreConstMeta,
+        argValues: [storeId, kind],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiMcpExecuteToolCallConstMeta => const TaskConstMeta(
-    debugName: 'mcp_execute_tool_call',
-    argNames: ['toolCall'],
-  );
+  TaskConstMeta get kCrateApiCreateQuantizedIndexFromStoreConstMeta =>
+      const TaskConstMeta(
+        debugName: 'create_quantized_index_from_store',
+        argNames: ['storeId', 'kind'],
+      );
 
   @override
-  List<MCPTool> crateApiMcpGetAllTools() {
+  ContextPolicy crateApiDefaultContextPolicy() {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
-          return wire.wire__crate__api__mcp_get_all_tools();
+          return wire.wire__crate__api__default_context_policy();
         },
         codec: DcoCodec(
-          decodeSuccessData: dco_decode_list_mcp_tool,
+          decodeSuccessData: dco_decode_context_policy,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiMcpGetAllToolsConstMeta,
+        constMeta: kCrateApiDefaultContextPolicyConstMeta,
         argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiMcpGetAllToolsConstMeta =>
-      const TaskConstMeta(debugName: 'mcp_get_all_tools', argNames: []);
+  TaskConstMeta get kCrateApiDefaultContextPolicyConstMeta =>
+      const TaskConstMeta(debugName: 'default_context_policy', argNames: []);
 
   @override
-  String crateApiMcpGetModelForTask({required TaskCategory category}) {
+  DedupOptions crateApiDefaultDedupOptions() {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
-          final arg0 = cst_encode_task_category(category);
-          return wire.wire__crate__api__mcp_get_model_for_task(arg0);
+          return wire.wire__crate__api__default_dedup_options();
         },
         codec: DcoCodec(
-          decodeSuccessData: dco_decode_String,
+          decodeSuccessData: dco_decode_dedup_options,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiMcpGetModelForTaskConstMeta,
-        argValues: [category],
+        constMeta: kCrateApiDefaultDedupOptionsConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiMcpGetModelForTaskConstMeta => const TaskConstMeta(
-    debugName: 'mcp_get_model_for_task',
-    argNames: ['category'],
-  );
+  TaskConstMeta get kCrateApiDefaultDedupOptionsConstMeta =>
+      const TaskConstMeta(debugName: 'default_dedup_options', argNames: []);
 
   @override
-  String crateApiMcpGetToolDescription({required MCPTool tool}) {
+  EmbeddingConfig crateApiDefaultEmbeddingConfig() {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
-          final arg0 = cst_encode_mcp_tool(tool);
-          return wire.wire__crate__api__mcp_get_tool_description(arg0);
+          return wire.wire__crate__api__default_embedding_config();
         },
         codec: DcoCodec(
-          decodeSuccessData: dco_decode_String,
+          decodeSuccessData: dco_decode_embedding_config,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiMcpGetToolDescriptionConstMeta,
-        argValues: [tool],
+        constMeta: kCrateApiDefaultEmbeddingConfigConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiMcpGetToolDescriptionConstMeta =>
-      const TaskConstMeta(
-        debugName: 'mcp_get_tool_description',
-        argNames: ['tool'],
-      );
+  TaskConstMeta get kCrateApiDefaultEmbeddingConfigConstMeta =>
+      const TaskConstMeta(debugName: 'default_embedding_config', argNames: []);
 
   @override
-  String crateApiMcpGetToolName({required MCPTool tool}) {
+  HnswConfig crateApiDefaultHnswConfig() {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
-          final arg0 = cst_encode_mcp_tool(tool);
-          return wire.wire__crate__api__mcp_get_tool_name(arg0);
+          return wire.wire__crate__api__default_hnsw_config();
         },
         codec: DcoCodec(
-          decodeSuccessData: dco_decode_String,
+          decodeSuccessData: dco_decode_hnsw_config,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiMcpGetToolNameConstMeta,
-        argValues: [tool],
+        constMeta: kCrateApiDefaultHnswConfigConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiMcpGetToolNameConstMeta =>
-      const TaskConstMeta(debugName: 'mcp_get_tool_name', argNames: ['tool']);
+  TaskConstMeta get kCrateApiDefaultHnswConfigConstMeta =>
+      const TaskConstMeta(debugName: 'default_hnsw_config', argNames: []);
 
   @override
-  List<MCPParameter> crateApiMcpGetToolParameters({required MCPTool tool}) {
+  HybridOptions crateApiDefaultHybridOptions() {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
-          final arg0 = cst_encode_mcp_tool(tool);
-          return wire.wire__crate__api__mcp_get_tool_parameters(arg0);
+          return wire.wire__crate__api__default_hybrid_options();
         },
         codec: DcoCodec(
-          decodeSuccessData: dco_decode_list_mcp_parameter,
+          decodeSuccessData: dco_decode_hybrid_options,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiMcpGetToolParametersConstMeta,
-        argValues: [tool],
+        constMeta: kCrateApiDefaultHybridOptionsConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiMcpGetToolParametersConstMeta =>
-      const TaskConstMeta(
-        debugName: 'mcp_get_tool_parameters',
-        argNames: ['tool'],
-      );
+  TaskConstMeta get kCrateApiDefaultHybridOptionsConstMeta =>
+      const TaskConstMeta(debugName: 'default_hybrid_options', argNames: []);
 
   @override
-  String crateApiMcpGetToolSchemas() {
+  ReasoningOptions crateApiDefaultReasoningOptions() {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
-          return wire.wire__crate__api__mcp_get_tool_schemas();
+          return wire.wire__crate__api__default_reasoning_options();
         },
         codec: DcoCodec(
-          decodeSuccessData: dco_decode_String,
+          decodeSuccessData: dco_decode_reasoning_options,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiMcpGetToolSchemasConstMeta,
+        constMeta: kCrateApiDefaultReasoningOptionsConstMeta,
         argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiMcpGetToolSchemasConstMeta =>
-      const TaskConstMeta(debugName: 'mcp_get_tool_schemas', argNames: []);
-
-  @override
-  Future<List<String>> crateApiMcpListFiles({required String path}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final arg0 = cst_encode_String(path);
-          return wire.wire__crate__api__mcp_list_files(port_, arg0);
-        },
-        codec: DcoCodec(
-          decodeSuccessData: dco_decode_list_String,
-          decodeErrorData: dco_decode_AnyhowException,
-        ),
-        constMeta: kCrateApiMcpListFilesConstMeta,
-        argValues: [path],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiMcpListFilesConstMeta =>
-      const TaskConstMeta(debugName: 'mcp_list_files', argNames: ['path']);
+  TaskConstMeta get kCrateApiDefaultReasoningOptionsConstMeta =>
+      const TaskConstMeta(debugName: 'default_reasoning_options', argNames: []);
 
   @override
-  Future<MCPToolCall> crateApiMcpParseToolCall({required String json}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final arg0 = cst_encode_String(json);
-          return wire.wire__crate__api__mcp_parse_tool_call(port_, arg0);
+  SamplerConfig crateApiDefaultSamplerConfig() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          return wire.wire__crate__api__default_sampler_config();
         },
         codec: DcoCodec(
-          decodeSuccessData: dco_decode_mcp_tool_call,
-          decodeErrorData: dco_decode_AnyhowException,
+          decodeSuccessData: dco_decode_sampler_config,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiMcpParseToolCallConstMeta,
-        argValues: [json],
+        constMeta: kCrateApiDefaultSamplerConfigConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiMcpParseToolCallConstMeta =>
-      const TaskConstMeta(debugName: 'mcp_parse_tool_call', argNames: ['json']);
+  TaskConstMeta get kCrateApiDefaultSamplerConfigConstMeta =>
+      const TaskConstMeta(debugName: 'default_sampler_config', argNames: []);
 
   @override
-  Future<String> crateApiMcpReadFile({required String path}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final arg0 = cst_encode_String(path);
-          return wire.wire__crate__api__mcp_read_file(port_, arg0);
+  SearchOptions crateApiDefaultSearchOptions() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          return wire.wire__crate__api__default_search_options();
         },
         codec: DcoCodec(
-          decodeSuccessData: dco_decode_String,
-          decodeErrorData: dco_decode_AnyhowException,
+          decodeSuccessData: dco_decode_search_options,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiMcpReadFileConstMeta,
-        argValues: [path],
+        constMeta: kCrateApiDefaultSearchOptionsConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiMcpReadFileConstMeta =>
-      const TaskConstMeta(debugName: 'mcp_read_file', argNames: ['path']);
+  TaskConstMeta get kCrateApiDefaultSearchOptionsConstMeta =>
+      const TaskConstMeta(debugName: 'default_search_options', argNames: []);
 
   @override
-  bool crateApiMcpValidatePath({required String path}) {
+  SummaryOptions crateApiDefaultSummaryOptions() {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
-          final arg0 = cst_encode_String(path);
-          return wire.wire__crate__api__mcp_validate_path(arg0);
+          return wire.wire__crate__api__default_summary_options();
         },
         codec: DcoCodec(
-          decodeSuccessData: dco_decode_bool,
+          decodeSuccessData: dco_decode_summary_options,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiMcpValidatePathConstMeta,
-        argValues: [path],
+        constMeta: kCrateApiDefaultSummaryOptionsConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiMcpValidatePathConstMeta =>
-      const TaskConstMeta(debugName: 'mcp_validate_path', argNames: ['path']);
+  TaskConstMeta get kCrateApiDefaultSummaryOptionsConstMeta =>
+      const TaskConstMeta(debugName: 'default_summary_options', argNames: []);
 
   @override
-  Future<void> crateApiMcpWriteFile({
-    required String path,
-    required String content,
+  Future<String> crateApiDetokenizeTokens({
+    required List<int> tokens,
+    required bool renderSpecial,
+    String? modelId,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
-          final arg0 = cst_encode_String(path);
-          final arg1 = cst_encode_String(content);
-          return wire.wire__crate__api__mcp_write_file(port_, arg0, arg1);
+          final arg0 = cst_encode_list_prim_i_32_loose(tokens);
+          final arg1 = cst_encode_bool(renderSpecial);
+          final arg2 = cst_encode_opt_String(modelId);
+          return wire.wire__crate__api__detokenize_tokens(
+            port_,
+            arg0,
+            arg1,
+            arg2,
+          );
         },
         codec: DcoCodec(
-          decodeSuccessData: dco_decode_unit,
+          decodeSuccessData: dco_decode_String,
           decodeErrorData: dco_decode_AnyhowException,
         ),
-        constMeta: kCrateApiMcpWriteFileConstMeta,
-        argValues: [path, content],
+        constMeta: kCrateApiDetokenizeTokensConstMeta,
+        argValues: [tokens, renderSpecial, modelId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiMcpWriteFileConstMeta => const TaskConstMeta(
-    debugName: 'mcp_write_file',
-    argNames: ['path', 'content'],
+  TaskConstMeta get kCrateApiDetokenizeTokensConstMeta => const TaskConstMeta(
+    debugName: 'detokenize_tokens',
+    argNames: ['tokens', 'renderSpecial', 'modelId'],
   );
 
   @override
-  Future<void> crateApiPinStreamNode({
-    required String id,
-    required bool pinned,
+  Future<SemanticEdgeResult> crateApiDiscoverSemanticEdges({
+    required List<EmbeddingEntry> entries,
+    double? threshold,
+    List<(String, String)>? existingLinks,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
-          final arg0 = cst_encode_String(id);
-          final arg1 = cst_encode_bool(pinned);
-          return wire.wire__crate__api__pin_stream_node(port_, arg0, arg1);
+          final arg0 = cst_encode_list_embedding_entry(entries);
+          final arg1 = cst_encode_opt_box_autoadd_f_32(threshold);
+          final arg2 = cst_encode_opt_list_record_string_string(existingLinks);
+          return wire.wire__crate__api__discover_semantic_edges(
+            port_,
+            arg0,
+            arg1,
+            arg2,
+          );
         },
         codec: DcoCodec(
-          decodeSuccessData: dco_decode_unit,
-          decodeErrorData: dco_decode_AnyhowException,
+          decodeSuccessData: dco_decode_semantic_edge_result,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiPinStreamNodeConstMeta,
-        argValues: [id, pinned],
+        constMeta: kCrateApiDiscoverSemanticEdgesConstMeta,
+        argValues: [entries, threshold, existingLinks],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiPinStreamNodeConstMeta => const TaskConstMeta(
-    debugName: 'pin_stream_node',
-    argNames: ['id', 'pinned'],
-  );
+  TaskConstMeta get kCrateApiDiscoverSemanticEdgesConstMeta =>
+      const TaskConstMeta(
+        debugName: 'discover_semantic_edges',
+        argNames: ['entries', 'threshold', 'existingLinks'],
+      );
 
   @override
-  Future<void> crateApiRemoveGraphNode({required String nodeId}) {
+  Future<SemanticEdgeResult> crateApiDiscoverSemanticEdgesInIndex({
+    required BigInt indexId,
+    double? threshold,
+    List<(String, String)>? existingLinks,
+    BigInt? maxNeighbors,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
-          final arg0 = cst_encode_String(nodeId);
-          return wire.wire__crate__api__remove_graph_node(port_, arg0);
+          final arg0 = cst_encode_u_64(indexId);
+          final arg1 = cst_encode_opt_box_autoadd_f_32(threshold);
+          final arg2 = cst_encode_opt_list_record_string_string(existingLinks);
+          final arg3 = cst_encode_opt_box_autoadd_usize(maxNeighbors);
+          return wire.wire__crate__api__discover_semantic_edges_in_index(
+            port_,
+            arg0,
+            arg1,
+            arg2,
+            arg3,
+          );
         },
         codec: DcoCodec(
-          decodeSuccessData: dco_decode_unit,
+          decodeSuccessData: dco_decode_semantic_edge_result,
           decodeErrorData: dco_decode_AnyhowException,
         ),
-        constMeta: kCrateApiRemoveGraphNodeConstMeta,
-        argValues: [nodeId],
+        constMeta: kCrateApiDiscoverSemanticEdgesInIndexConstMeta,
+        argValues: [indexId, threshold, existingLinks, maxNeighbors],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiRemoveGraphNodeConstMeta =>
-      const TaskConstMeta(debugName: 'remove_graph_node', argNames: ['nodeId']);
+  TaskConstMeta get kCrateApiDiscoverSemanticEdgesInIndexConstMeta =>
+      const TaskConstMeta(
+        debugName: 'discover_semantic_edges_in_index',
+        argNames: ['indexId', 'threshold', 'existingLinks', 'maxNeighbors'],
+      );
 
   @override
-  Future<void> crateApiRemoveStreamEdge({
-    required String fromId,
-    required String toId,
+  Future<SemanticEdgeResult> crateApiDiscoverSemanticEdgesInStore({
+    required BigInt storeId,
+    double? threshold,
+    List<(String, String)>? existingLinks,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
-          final arg0 = cst_encode_String(fromId);
-          final arg1 = cst_encode_String(toId);
-          return wire.wire__crate__api__remove_stream_edge(port_, arg0, arg1);
+          final arg0 = cst_encode_u_64(storeId);
+          final arg1 = cst_encode_opt_box_autoadd_f_32(threshold);
+          final arg2 = cst_encode_opt_list_record_string_string(existingLinks);
+          return wire.wire__crate__api__discover_semantic_edges_in_store(
+            port_,
+            arg0,
+            arg1,
+            arg2,
+          );
         },
         codec: DcoCodec(
-          decodeSuccessData: dco_decode_unit,
+          decodeSuccessData: dco_decode_semantic_edge_result,
           decodeErrorData: dco_decode_AnyhowException,
         ),
-        constMeta: kCrateApiRemoveStreamEdgeConstMeta,
-        argValues: [fromId, toId],
+        constMeta: kCrateApiDiscoverSemanticEdgesInStoreConstMeta,
+        argValues: [storeId, threshold, existingLinks],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiRemoveStreamEdgeConstMeta => const TaskConstMeta(
-    debugName: 'remove_stream_edge',
-    argNames: ['fromId', 'toId'],
-  );
+  TaskConstMeta get kCrateApiDiscoverSemanticEdgesInStoreConstMeta =>
+      const TaskConstMeta(
+        debugName: 'discover_semantic_edges_in_store',
+        argNames: ['storeId', 'threshold', 'existingLinks'],
+      );
 
   @override
-  Future<void> crateApiRemoveStreamNode({required String id}) {
+  Future<List<EmbeddingEntry>> crateApiEmbedMarkdownNote({
+    required String noteId,
+    required String text,
+    int? maxTokens,
+    int? overlapTokens,
+    NoteMetadata? metadata,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
-          final arg0 = cst_encode_String(id);
-          return wire.wire__crate__api__remove_stream_node(port_, arg0);
+          final arg0 = cst_encode_String(noteId);
+          final arg1 = cst_encode_String(text);
+          final arg2 = cst_encode_opt_box_autoadd_u_32(maxTokens);
+          final arg3 = cst_encode_opt_box_autoadd_u_32(overlapTokens);
+          final arg4 = cst_encode_opt_box_autoadd_note_metadata(metadata);
+          return wire.wire__crate__api__embed_markdown_note(
+            port_,
+            arg0,
+            arg1,
+            arg2,
+            arg3,
+            arg4,
+          );
         },
         codec: DcoCodec(
-          decodeSuccessData: dco_decode_unit,
+          decodeSuccessData: dco_decode_list_embedding_entry,
           decodeErrorData: dco_decode_AnyhowException,
         ),
-        constMeta: kCrateApiRemoveStreamNodeConstMeta,
-        argValues: [id],
+        constMeta: kCrateApiEmbedMarkdownNoteConstMeta,
+        argValues: [noteId, text, maxTokens, overlapTokens, metadata],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiRemoveStreamNodeConstMeta =>
-      const TaskConstMeta(debugName: 'remove_stream_node', argNames: ['id']);
+  TaskConstMeta get kCrateApiEmbedMarkdownNoteConstMeta => const TaskConstMeta(
+    debugName: 'embed_markdown_note',
+    argNames: ['noteId', 'text', 'maxTokens', 'overlapTokens', 'metadata'],
+  );
 
   @override
-  Future<List<SimilarityResult>> crateApiSemanticSearch({
-    required String queryText,
-    required List<EmbeddingEntry> entries,
-    required BigInt topK,
+  Future<List<Float32List>> crateApiEmbedTexts({
+    required List<String> texts,
+    required EmbeddingKind kind,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
-          final arg0 = cst_encode_String(queryText);
-          final arg1 = cst_encode_list_embedding_entry(entries);
-          final arg2 = cst_encode_usize(topK);
-          return wire.wire__crate__api__semantic_search(
-            port_,
-            arg0,
-            arg1,
-            arg2,
-          );
+          final arg0 = cst_encode_list_String(texts);
+          final arg1 = cst_encode_embedding_kind(kind);
+          return wire.wire__crate__api__embed_texts(port_, arg0, arg1);
         },
         codec: DcoCodec(
-          decodeSuccessData: dco_decode_list_similarity_result,
+          decodeSuccessData: dco_decode_list_list_prim_f_32_strict,
           decodeErrorData: dco_decode_AnyhowException,
         ),
-        constMeta: kCrateApiSemanticSearchConstMeta,
-        argValues: [queryText, entries, topK],
+        constMeta: kCrateApiEmbedTextsConstMeta,
+        argValues: [texts, kind],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSemanticSearchConstMeta => const TaskConstMeta(
-    debugName: 'semantic_search',
-    argNames: ['queryText', 'entries', 'topK'],
+  TaskConstMeta get kCrateApiEmbedTextsConstMeta => const TaskConstMeta(
+    debugName: 'embed_texts',
+    argNames: ['texts', 'kind'],
   );
 
   @override
-  Future<void> crateApiSetStreamNodePosition({
-    required String id,
-    required double x,
-    required double y,
+  Future<List<String>> crateApiExtractTopics({
+    required String text,
+    int? numTopics,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
-          final arg0 = cst_encode_String(id);
-          final arg1 = cst_encode_f_32(x);
-          final arg2 = cst_encode_f_32(y);
-          return wire.wire__crate__api__set_stream_node_position(
+          final arg0 = cst_encode_String(text);
+          final arg1 = cst_encode_opt_box_autoadd_u_32(numTopics);
+          return wire.wire__crate__api__extract_topics(port_, arg0, arg1);
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_list_String,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiExtractTopicsConstMeta,
+        argValues: [text, numTopics],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiExtractTopicsConstMeta => const TaskConstMeta(
+    debugName: 'extract_topics',
+    argNames: ['text', 'numTopics'],
+  );
+
+  @override
+  Future<List<String>> crateApiExtractTopicsWithModel({
+    required String text,
+    int? numTopics,
+    String? modelId,
+    double? minConfidence,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final arg0 = cst_encode_String(text);
+          final arg1 = cst_encode_opt_box_autoadd_u_32(numTopics);
+          final arg2 = cst_encode_opt_String(modelId);
+          final arg3 = cst_encode_opt_box_autoadd_f_32(minConfidence);
+          return wire.wire__crate__api__extract_topics_with_model(
             port_,
             arg0,
             arg1,
             arg2,
+            arg3,
           );
         },
         codec: DcoCodec(
-          decodeSuccessData: dco_decode_unit,
+          decodeSuccessData: dco_decode_list_String,
           decodeErrorData: dco_decode_AnyhowException,
         ),
-        constMeta: kCrateApiSetStreamNodePositionConstMeta,
-        argValues: [id, x, y],
+        constMeta: kCrateApiExtractTopicsWithModelConstMeta,
+        argValues: [text, numTopics, modelId, minConfidence],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSetStreamNodePositionConstMeta =>
+  TaskConstMeta get kCrateApiExtractTopicsWithModelConstMeta =>
       const TaskConstMeta(
-        debugName: 'set_stream_node_position',
-        argNames: ['id', 'x', 'y'],
+        debugName: 'extract_topics_with_model',
+        argNames: ['text', 'numTopics', 'modelId', 'minConfidence'],
       );
 
   @override
-  Future<void> crateApiStartGraphStream() {
+  Future<List<DuplicateGroup>> crateApiFindDuplicateEntries({
+    required List<EmbeddingEntry> entries,
+    DedupOptions? options,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
-          return wire.wire__crate__api__start_graph_stream(port_);
+          final arg0 = cst_encode_list_embedding_entry(entries);
+          final arg1 = cst_encode_opt_box_autoadd_dedup_options(options);
+          return wire.wire__crate__api__find_duplicate_entries(
+            port_,
+            arg0,
+            arg1,
+          );
         },
         codec: DcoCodec(
-          decodeSuccessData: dco_decode_unit,
+          decodeSuccessData: dco_decode_list_duplicate_group,
           decodeErrorData: dco_decode_AnyhowException,
         ),
-        constMeta: kCrateApiStartGraphStreamConstMeta,
-        argValues: [],
+        constMeta: kCrateApiFindDuplicateEntriesConstMeta,
+        argValues: [entries, options],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiStartGraphStreamConstMeta =>
-      const TaskConstMeta(debugName: 'start_graph_stream', argNames: []);
+  TaskConstMeta get kCrateApiFindDuplicateEntriesConstMeta =>
+      const TaskConstMeta(
+        debugName: 'find_duplicate_entries',
+        argNames: ['entries', 'options'],
+      );
 
   @override
-  void crateApiStopGraphStream() {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          return wire.wire__crate__api__stop_graph_stream();
+  Future<List<DuplicateGroup>> crateApiFindDuplicates({
+    required List<DedupDocument> documents,
+    DedupOptions? options,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final arg0 = cst_encode_list_dedup_document(documents);
+          final arg1 = cst_encode_opt_box_autoadd_dedup_options(options);
+          return wire.wire__crate__api__find_duplicates(port_, arg0, arg1);
         },
         codec: DcoCodec(
-          decodeSuccessData: dco_decode_unit,
+          decodeSuccessData: dco_decode_list_duplicate_group,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiStopGraphStreamConstMeta,
-        argValues: [],
+        constMeta: kCrateApiFindDuplicatesConstMeta,
+        argValues: [documents, options],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiStopGraphStreamConstMeta =>
-      const TaskConstMeta(debugName: 'stop_graph_stream', argNames: []);
+  TaskConstMeta get kCrateApiFindDuplicatesConstMeta => const TaskConstMeta(
+    debugName: 'find_duplicates',
+    argNames: ['documents', 'options'],
+  );
 
   @override
-  void crateApiUnloadModel() {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          return wire.wire__crate__api__unload_model();
+  Future<List<DuplicateGroup>> crateApiFindDuplicatesInStore({
+    required BigInt storeId,
+    DedupOptions? options,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final arg0 = cst_encode_u_64(storeId);
+          final arg1 = cst_encode_opt_box_autoadd_dedup_options(options);
+          return wire.wire__crate__api__find_duplicates_in_store(
+            port_,
+            arg0,
+            arg1,
+          );
         },
         codec: DcoCodec(
-          decodeSuccessData: dco_decode_unit,
-          decodeErrorData: null,
+          decodeSuccessData: dco_decode_list_duplicate_group,
+          decodeErrorData: dco_decode_AnyhowException,
         ),
-        constMeta: kCrateApiUnloadModelConstMeta,
-        argValues: [],
+        constMeta: kCrateApiFindDuplicatesInStoreConstMeta,
+        argValues: [storeId, options],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiUnloadModelConstMeta =>
-      const TaskConstMeta(debugName: 'unload_model', argNames: []);
+  TaskConstMeta get kCrateApiFindDuplicatesInStoreConstMeta =>
+      const TaskConstMeta(
+        debugName: 'find_duplicates_in_store',
+        argNames: ['storeId', 'options'],
+      );
 
   @override
-  Future<void> crateApiUpdateGraphViewport({
-    required double x,
-    required double y,
-    required double width,
-    required double height,
-    required double scale,
+  Future<List<SimilarityResult>> crateApiFindSimilar({
+    required List<double> query,
+    required List<EmbeddingEntry> entries,
+    required BigInt topK,
+    required double threshold,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
-          final arg0 = cst_encode_f_32(x);
-          final arg1 = cst_encode_f_32(y);
-          final arg2 = cst_encode_f_32(width);
-          final arg3 = cst_encode_f_32(height);
-          final arg4 = cst_encode_f_32(scale);
-          return wire.wire__crate__api__update_graph_viewport(
+          final arg0 = cst_encode_list_prim_f_32_loose(query);
+          final arg1 = cst_encode_list_embedding_entry(entries);
+          final arg2 = cst_encode_usize(topK);
+          final arg3 = cst_encode_f_32(threshold);
+          return wire.wire__crate__api__find_similar(
             port_,
             arg0,
             arg1,
             arg2,
             arg3,
-            arg4,
           );
         },
         codec: DcoCodec(
-          decodeSuccessData: dco_decode_unit,
+          decodeSuccessData: dco_decode_list_similarity_result,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiFindSimilarConstMeta,
+        argValues: [query, entries, topK, threshold],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiFindSimilarConstMeta => const TaskConstMeta(
+    debugName: 'find_similar',
+    argNames: ['query', 'entries', 'topK', 'threshold'],
+  );
+
+  @override
+  Future<List<SimilarityResult>> crateApiFindSimilarFiltered({
+    required List<double> query,
+    required List<EmbeddingEntry> entries,
+    required SearchOptions options,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final arg0 = cst_encode_list_prim_f_32_loose(query);
+          final arg1 = cst_encode_list_embedding_entry(entries);
+          final arg2 = cst_encode_box_autoadd_search_options(options);
+          return wire.wire__crate__api__find_similar_filtered(
+            port_,
+            arg0,
+            arg1,
+            arg2,
+          );
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_list_similarity_result,
           decodeErrorData: dco_decode_AnyhowException,
         ),
-        constMeta: kCrateApiUpdateGraphViewportConstMeta,
-        argValues: [x, y, width, height, scale],
+        constMeta: kCrateApiFindSimilarFilteredConstMeta,
+        argValues: [query, entries, options],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiUpdateGraphViewportConstMeta =>
+  TaskConstMeta get kCrateApiFindSimilarFilteredConstMeta =>
       const TaskConstMeta(
-        debugName: 'update_graph_viewport',
-        argNames: ['x', 'y', 'width', 'height', 'scale'],
+        debugName: 'find_similar_filtered',
+        argNames: ['query', 'entries', 'options'],
       );
 
-  @protected
-  AnyhowException dco_decode_AnyhowException(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return AnyhowException(raw as String);
+  @override
+  Future<List<SimilarityResult>> crateApiFindSimilarInIndex({
+    required BigInt indexId,
+    required List<double> query,
+    required BigInt topK,
+    required double threshold,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final arg0 = cst_encode_u_64(indexId);
+          final arg1 = cst_encode_list_prim_f_32_loose(query);
+          final arg2 = cst_encode_usize(topK);
+          final arg3 = cst_encode_f_32(threshold);
+          return wire.wire__crate__api__find_similar_in_index(
+            port_,
+            arg0,
+            arg1,
+            arg2,
+            arg3,
+          );
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_list_similarity_result,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiFindSimilarInIndexConstMeta,
+        argValues: [indexId, query, topK, threshold],
+        apiImpl: this,
+      ),
+    );
   }
 
-  @protected
-  String dco_decode_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as String;
-  }
+  TaskConstMeta get kCrateApiFindSimilarInIndexConstMeta => const TaskConstMeta(
+    debugName: 'find_similar_in_index',
+    argNames: ['indexId', 'query', 'topK', 'threshold'],
+  );
 
-  @protected
-  bool dco_decode_bool(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as bool;
+  @override
+  Future<List<SimilarityResult>> crateApiFindSimilarInStore({
+    required BigInt storeId,
+    required List<double> query,
+    required BigInt topK,
+    required double threshold,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final arg0 = cst_encode_u_64(storeId);
+          final arg1 = cst_encode_list_prim_f_32_loose(query);
+          final arg2 = cst_encode_usize(topK);
+          final arg3 = cst_encode_f_32(threshold);
+          return wire.wire__crate__api__find_similar_in_store(
+            port_,
+            arg0,
+            arg1,
+            arg2,
+            arg3,
+          );
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_list_similarity_result,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiFindSimilarInStoreConstMeta,
+        argValues: [storeId, query, topK, threshold],
+        apiImpl: this,
+      ),
+    );
   }
 
-  @protected
-  double dco_decode_box_autoadd_f_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as double;
-  }
+  TaskConstMeta get kCrateApiFindSimilarInStoreConstMeta => const TaskConstMeta(
+    debugName: 'find_similar_in_store',
+    argNames: ['storeId', 'query', 'topK', 'threshold'],
+  );
 
-  @protected
-  int dco_decode_box_autoadd_i_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  @override
+  Future<List<SimilarityResult>> crateApiFindSimilarQuantized({
+    required BigInt indexId,
+    required List<double> query,
+    required BigInt topK,
+    required double threshold,
+    BigInt? rescoreCandidates,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final arg0 = cst_encode_u_64(indexId);
+          final arg1 = cst_encode_list_prim_f_32_loose(query);
+          final arg2 = cst_encode_usize(topK);
+          final arg3 = cst_encode_f_32(threshold);
+          final arg4 = cst_encode_opt_box_autoadd_usize(rescoreCandidates);
+          return wire.wire__crate__api__find_similar_quantized(
+            port_,
+            arg0,
+            arg1,
+            arg2,
+            arg3,
+            arg4,
+          );
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_list_similarity_result,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiFindSimilarQuantizedConstMeta,
+        argValues: [indexId, query, topK, threshold, rescoreCandidates],
+        apiImpl: this,
+      ),
+    );
   }
 
-  @protected
-  MCPToolCall dco_decode_box_autoadd_mcp_tool_call(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_mcp_tool_call(raw);
+  TaskConstMeta get kCrateApiFindSimilarQuantizedConstMeta =>
+      const TaskConstMeta(
+        debugName: 'find_similar_quantized',
+        argNames: [
+          'indexId',
+          'query',
+          'topK',
+          'threshold',
+          'rescoreCandidates',
+        ],
+      );
+
+  @override
+  Future<String> crateApiGenerateText({
+    required String prompt,
+    int? maxTokens,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final arg0 = cst_encode_String(prompt);
+          final arg1 = cst_encode_opt_box_autoadd_u_32(maxTokens);
+          return wire.wire__crate__api__generate_text(port_, arg0, arg1);
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_String,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiGenerateTextConstMeta,
+        argValues: [prompt, maxTokens],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGenerateTextConstMeta => const TaskConstMeta(
+    debugName: 'generate_text',
+    argNames: ['prompt', 'maxTokens'],
+  );
+
+  @override
+  Stream<GenerationChunk> crateApiGenerateTextStream({
+    required String prompt,
+    int? maxTokens,
+    List<String>? stopSequences,
+    String? modelId,
+  }) {
+    final sink = RustStreamSink<GenerationChunk>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final arg0 = cst_encode_String(prompt);
+            final arg1 = cst_encode_opt_box_autoadd_u_32(maxTokens);
+            final arg2 = cst_encode_opt_list_String(stopSequences);
+            final arg3 = cst_encode_opt_String(modelId);
+            final arg4 = cst_encode_StreamSink_generation_chunk_Dco(sink);
+            return wire.wire__crate__api__generate_text_stream(
+              port_,
+              arg0,
+              arg1,
+              arg2,
+              arg3,
+              arg4,
+            );
+          },
+          codec: DcoCodec(
+            decodeSuccessData: dco_decode_unit,
+            decodeErrorData: dco_decode_AnyhowException,
+          ),
+          constMeta: kCrateApiGenerateTextStreamConstMeta,
+          argValues: [prompt, maxTokens, stopSequences, modelId, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiGenerateTextStreamConstMeta => const TaskConstMeta(
+    debugName: 'generate_text_stream',
+    argNames: ['prompt', 'maxTokens', 'stopSequences', 'modelId', 'sink'],
+  );
+
+  @override
+  Future<String> crateApiGenerateTextWithModel({
+    required String prompt,
+    int? maxTokens,
+    String? modelId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final arg0 = cst_encode_String(prompt);
+          final arg1 = cst_encode_opt_box_autoadd_u_32(maxTokens);
+          final arg2 = cst_encode_opt_String(modelId);
+          return wire.wire__crate__api__generate_text_with_model(
+            port_,
+            arg0,
+            arg1,
+            arg2,
+          );
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_String,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiGenerateTextWithModelConstMeta,
+        argValues: [prompt, maxTokens, modelId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGenerateTextWithModelConstMeta =>
+      const TaskConstMeta(
+        debugName: 'generate_text_with_model',
+        argNames: ['prompt', 'maxTokens', 'modelId'],
+      );
+
+  @override
+  Future<GenerationResult> crateApiGenerateTextWithOptions({
+    required String prompt,
+    required GenerationOptions options,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final arg0 = cst_encode_String(prompt);
+          final arg1 = cst_encode_box_autoadd_generation_options(options);
+          return wire.wire__crate__api__generate_text_with_options(
+            port_,
+            arg0,
+            arg1,
+          );
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_generation_result,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiGenerateTextWithOptionsConstMeta,
+        argValues: [prompt, options],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGenerateTextWithOptionsConstMeta =>
+      const TaskConstMeta(
+        debugName: 'generate_text_with_options',
+        argNames: ['prompt', 'options'],
+      );
+
+  @override
+  Future<Float32List> crateApiGetEmbedding({required String text}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final arg0 = cst_encode_String(text);
+          return wire.wire__crate__api__get_embedding(port_, arg0);
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_list_prim_f_32_strict,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiGetEmbeddingConstMeta,
+        argValues: [text],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGetEmbeddingConstMeta =>
+      const TaskConstMeta(debugName: 'get_embedding', argNames: ['text']);
+
+  @override
+  Future<EmbeddingCacheStats?> crateApiGetEmbeddingCacheStats() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          return wire.wire__crate__api__get_embedding_cache_stats(port_);
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_opt_box_autoadd_embedding_cache_stats,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiGetEmbeddingCacheStatsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGetEmbeddingCacheStatsConstMeta =>
+      const TaskConstMeta(debugName: 'get_embedding_cache_stats', argNames: []);
+
+  @override
+  BigInt crateApiGetEmbeddingDimension() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          return wire.wire__crate__api__get_embedding_dimension();
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_usize,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiGetEmbeddingDimensionConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGetEmbeddingDimensionConstMeta =>
+      const TaskConstMeta(debugName: 'get_embedding_dimension', argNames: []);
+
+  @override
+  Future<Float32List> crateApiGetEmbeddingWithModel({
+    required String text,
+    String? modelId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final arg0 = cst_encode_String(text);
+          final arg1 = cst_encode_opt_String(modelId);
+          return wire.wire__crate__api__get_embedding_with_model(
+            port_,
+            arg0,
+            arg1,
+          );
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_list_prim_f_32_strict,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiGetEmbeddingWithModelConstMeta,
+        argValues: [text, modelId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGetEmbeddingWithModelConstMeta =>
+      const TaskConstMeta(
+        debugName: 'get_embedding_with_model',
+        argNames: ['text', 'modelId'],
+      );
+
+  @override
+  Future<GraphState> crateApiGetGraphState() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          return wire.wire__crate__api__get_graph_state(port_);
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_graph_state,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiGetGraphStateConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGetGraphStateConstMeta =>
+      const TaskConstMeta(debugName: 'get_graph_state', argNames: []);
+
+  @override
+  GgufMetadata crateApiGetModelMetadata({String? modelId}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final arg0 = cst_encode_opt_String(modelId);
+          return wire.wire__crate__api__get_model_metadata(arg0);
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_gguf_metadata,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiGetModelMetadataConstMeta,
+        argValues: [modelId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGetModelMetadataConstMeta => const TaskConstMeta(
+    debugName: 'get_model_metadata',
+    argNames: ['modelId'],
+  );
+
+  @override
+  int crateApiGetModelType() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          return wire.wire__crate__api__get_model_type();
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_i_32,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiGetModelTypeConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGetModelTypeConstMeta =>
+      const TaskConstMeta(debugName: 'get_model_type', argNames: []);
+
+  @override
+  Future<String> crateApiGetOrCreateTopicHub({required String topic}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final arg0 = cst_encode_String(topic);
+          return wire.wire__crate__api__get_or_create_topic_hub(port_, arg0);
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_String,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiGetOrCreateTopicHubConstMeta,
+        argValues: [topic],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGetOrCreateTopicHubConstMeta =>
+      const TaskConstMeta(
+        debugName: 'get_or_create_topic_hub',
+        argNames: ['topic'],
+      );
+
+  @override
+  StreamGraphStats crateApiGetStreamGraphStats() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          return wire.wire__crate__api__get_stream_graph_stats();
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_stream_graph_stats,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiGetStreamGraphStatsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGetStreamGraphStatsConstMeta =>
+      const TaskConstMeta(debugName: 'get_stream_graph_stats', argNames: []);
+
+  @override
+  TokenizerInfo crateApiGetTokenizerInfo({String? modelId}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final arg0 = cst_encode_opt_String(modelId);
+          return wire.wire__crate__api__get_tokenizer_info(arg0);
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_tokenizer_info,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiGetTokenizerInfoConstMeta,
+        argValues: [modelId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGetTokenizerInfoConstMeta => const TaskConstMeta(
+    debugName: 'get_tokenizer_info',
+    argNames: ['modelId'],
+  );
+
+  @override
+  Future<VectorStoreStats> crateApiGetVectorStoreStats({
+    required BigInt storeId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final arg0 = cst_encode_u_64(storeId);
+          return wire.wire__crate__api__get_vector_store_stats(port_, arg0);
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_vector_store_stats,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiGetVectorStoreStatsConstMeta,
+        argValues: [storeId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGetVectorStoreStatsConstMeta =>
+      const TaskConstMeta(
+        debugName: 'get_vector_store_stats',
+        argNames: ['storeId'],
+      );
+
+  @override
+  String crateApiGetVersion() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          return wire.wire__crate__api__get_version();
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiGetVersionConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGetVersionConstMeta =>
+      const TaskConstMeta(debugName: 'get_version', argNames: []);
+
+  @override
+  Future<List<NodePosition>> crateApiGetVisibleGraphNodes() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          return wire.wire__crate__api__get_visible_graph_nodes(port_);
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_list_node_position,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiGetVisibleGraphNodesConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGetVisibleGraphNodesConstMeta =>
+      const TaskConstMeta(debugName: 'get_visible_graph_nodes', argNames: []);
+
+  @override
+  SamplerConfig crateApiGreedySamplerConfig() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          return wire.wire__crate__api__greedy_sampler_config();
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_sampler_config,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiGreedySamplerConfigConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGreedySamplerConfigConstMeta =>
+      const TaskConstMeta(debugName: 'greedy_sampler_config', argNames: []);
+
+  @override
+  String crateApiHealthCheck() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          return wire.wire__crate__api__health_check();
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiHealthCheckConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiHealthCheckConstMeta =>
+      const TaskConstMeta(debugName: 'health_check', argNames: []);
+
+  @override
+  Future<void> crateApiHnswIndexInsert({
+    required BigInt indexId,
+    required List<EmbeddingEntry> entries,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final arg0 = cst_encode_u_64(indexId);
+          final arg1 = cst_encode_list_embedding_entry(entries);
+          return wire.wire__crate__api__hnsw_index_insert(port_, arg0, arg1);
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiHnswIndexInsertConstMeta,
+        argValues: [indexId, entries],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiHnswIndexInsertConstMeta => const TaskConstMeta(
+    debugName: 'hnsw_index_insert',
+    argNames: ['indexId', 'entries'],
+  );
+
+  @override
+  Future<void> crateApiHnswIndexInsertWithTexts({
+    required BigInt indexId,
+    required List<EmbeddingEntry> entries,
+    required List<String> texts,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final arg0 = cst_encode_u_64(indexId);
+          final arg1 = cst_encode_list_embedding_entry(entries);
+          final arg2 = cst_encode_list_String(texts);
+          return wire.wire__crate__api__hnsw_index_insert_with_texts(
+            port_,
+            arg0,
+            arg1,
+            arg2,
+          );
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiHnswIndexInsertWithTextsConstMeta,
+        argValues: [indexId, entries, texts],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiHnswIndexInsertWithTextsConstMeta =>
+      const TaskConstMeta(
+        debugName: 'hnsw_index_insert_with_texts',
+        argNames: ['indexId', 'entries', 'texts'],
+      );
+
+  @override
+  BigInt crateApiHnswIndexLen({required BigInt indexId}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final arg0 = cst_encode_u_64(indexId);
+          return wire.wire__crate__api__hnsw_index_len(arg0);
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_usize,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiHnswIndexLenConstMeta,
+        argValues: [indexId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiHnswIndexLenConstMeta =>
+      const TaskConstMeta(debugName: 'hnsw_index_len', argNames: ['indexId']);
+
+  @override
+  Future<BigInt> crateApiHnswIndexRemove({
+    required BigInt indexId,
+    required List<String> ids,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final arg0 = cst_encode_u_64(indexId);
+          final arg1 = cst_encode_list_String(ids);
+          return wire.wire__crate__api__hnsw_index_remove(port_, arg0, arg1);
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_usize,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiHnswIndexRemoveConstMeta,
+        argValues: [indexId, ids],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiHnswIndexRemoveConstMeta => const TaskConstMeta(
+    debugName: 'hnsw_index_remove',
+    argNames: ['indexId', 'ids'],
+  );
+
+  @override
+  Future<List<HybridHit>> crateApiHybridSearch({
+    required String queryText,
+    required List<EmbeddingEntry> entries,
+    required BigInt topK,
+    HybridOptions? options,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final arg0 = cst_encode_String(queryText);
+          final arg1 = cst_encode_list_embedding_entry(entries);
+          final arg2 = cst_encode_usize(topK);
+          final arg3 = cst_encode_opt_box_autoadd_hybrid_options(options);
+          return wire.wire__crate__api__hybrid_search(
+            port_,
+            arg0,
+            arg1,
+            arg2,
+            arg3,
+          );
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_list_hybrid_hit,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiHybridSearchConstMeta,
+        argValues: [queryText, entries, topK, options],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiHybridSearchConstMeta => const TaskConstMeta(
+    debugName: 'hybrid_search',
+    argNames: ['queryText', 'entries', 'topK', 'options'],
+  );
+
+  @override
+  Future<List<HybridHit>> crateApiHybridSearchInIndex({
+    required BigInt indexId,
+    required String queryText,
+    required BigInt topK,
+    HybridOptions? options,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final arg0 = cst_encode_u_64(indexId);
+          final arg1 = cst_encode_String(queryText);
+          final arg2 = cst_encode_usize(topK);
+          final arg3 = cst_encode_opt_box_autoadd_hybrid_options(options);
+          return wire.wire__crate__api__hybrid_search_in_index(
+            port_,
+            arg0,
+            arg1,
+            arg2,
+            arg3,
+          );
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_list_hybrid_hit,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiHybridSearchInIndexConstMeta,
+        argValues: [indexId, queryText, topK, options],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiHybridSearchInIndexConstMeta =>
+      const TaskConstMeta(
+        debugName: 'hybrid_search_in_index',
+        argNames: ['indexId', 'queryText', 'topK', 'options'],
+      );
+
+  @override
+  void crateApiInitGraph() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          return wire.wire__crate__api__init_graph();
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiInitGraphConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiInitGraphConstMeta =>
+      const TaskConstMeta(debugName: 'init_graph', argNames: []);
+
+  @override
+  Future<void> crateApiInitMcp({
+    required String basePath,
+    BigInt? maxFileSize,
+    List<String>? allowedExtensions,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final arg0 = cst_encode_String(basePath);
+          final arg1 = cst_encode_opt_box_autoadd_usize(maxFileSize);
+          final arg2 = cst_encode_opt_list_String(allowedExtensions);
+          return wire.wire__crate__api__init_mcp(port_, arg0, arg1, arg2);
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiInitMcpConstMeta,
+        argValues: [basePath, maxFileSize, allowedExtensions],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiInitMcpConstMeta => const TaskConstMeta(
+    debugName: 'init_mcp',
+    argNames: ['basePath', 'maxFileSize', 'allowedExtensions'],
+  );
+
+  @override
+  void crateApiInitModel({required String modelPath}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final arg0 = cst_encode_String(modelPath);
+          return wire.wire__crate__api__init_model(arg0);
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiInitModelConstMeta,
+        argValues: [modelPath],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiInitModelConstMeta =>
+      const TaskConstMeta(debugName: 'init_model', argNames: ['modelPath']);
+
+  @override
+  Future<void> crateApiInitModelWithConfig({
+    required String modelPath,
+    required int nGpuLayers,
+    required int nCtx,
+    required int nThreads,
+    required double temperature,
+    required double topP,
+    required int maxTokens,
+    int? modelType,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final arg0 = cst_encode_String(modelPath);
+          final arg1 = cst_encode_u_32(nGpuLayers);
+          final arg2 = cst_encode_u_32(nCtx);
+          final arg3 = cst_encode_i_32(nThreads);
+          final arg4 = cst_encode_f_32(temperature);
+          final arg5 = cst_encode_f_32(topP);
+          final arg6 = cst_encode_u_32(maxTokens);
+          final arg7 = cst_encode_opt_box_autoadd_i_32(modelType);
+          return wire.wire__crate__api__init_model_with_config(
+            port_,
+            arg0,
+            arg1,
+            arg2,
+            arg3,
+            arg4,
+            arg5,
+            arg6,
+            arg7,
+          );
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiInitModelWithConfigConstMeta,
+        argValues: [
+          modelPath,
+          nGpuLayers,
+          nCtx,
+          nThreads,
+          temperature,
+          topP,
+          maxTokens,
+          modelType,
+        ],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiInitModelWithConfigConstMeta =>
+      const TaskConstMeta(
+        debugName: 'init_model_with_config',
+        argNames: [
+          'modelPath',
+          'nGpuLayers',
+          'nCtx',
+          'nThreads',
+          'temperature',
+          'topP',
+          'maxTokens',
+          'modelType',
+        ],
+      );
+
+  @override
+  Future<GgufMetadata> crateApiInspectModel({required String modelPath}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final arg0 = cst_encode_String(modelPath);
+          return wire.wire__crate__api__inspect_model(port_, arg0);
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_gguf_metadata,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiInspectModelConstMeta,
+        argValues: [modelPath],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiInspectModelConstMeta =>
+      const TaskConstMeta(debugName: 'inspect_model', argNames: ['modelPath']);
+
+  @override
+  bool crateApiIsEmbeddingModelLoaded() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          return wire.wire__crate__api__is_embedding_model_loaded();
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiIsEmbeddingModelLoadedConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiIsEmbeddingModelLoadedConstMeta =>
+      const TaskConstMeta(debugName: 'is_embedding_model_loaded', argNames: []);
+
+  @override
+  bool crateApiIsGraphStreamRunning() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          return wire.wire__crate__api__is_graph_stream_running();
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiIsGraphStreamRunningConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiIsGraphStreamRunningConstMeta =>
+      const TaskConstMeta(debugName: 'is_graph_stream_running', argNames: []);
+
+  @override
+  bool crateApiIsMcpInitialized() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          return wire.wire__crate__api__is_mcp_initialized();
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiIsMcpInitializedConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiIsMcpInitializedConstMeta =>
+      const TaskConstMeta(debugName: 'is_mcp_initialized', argNames: []);
+
+  @override
+  bool crateApiIsModelLoaded() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          return wire.wire__crate__api__is_model_loaded();
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiIsModelLoadedConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiIsModelLoadedConstMeta =>
+      const TaskConstMeta(debugName: 'is_model_loaded', argNames: []);
+
+  @override
+  String crateApiJsonSchemaToGrammar({required String schemaJson}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final arg0 = cst_encode_String(schemaJson);
+          return wire.wire__crate__api__json_schema_to_grammar(arg0);
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_String,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiJsonSchemaToGrammarConstMeta,
+        argValues: [schemaJson],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiJsonSchemaToGrammarConstMeta =>
+      const TaskConstMeta(
+        debugName: 'json_schema_to_grammar',
+        argNames: ['schemaJson'],
+      );
+
+  @override
+  List<LoadedModelInfo> crateApiListLoadedModels() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          return wire.wire__crate__api__list_loaded_models();
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_list_loaded_model_info,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiListLoadedModelsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiListLoadedModelsConstMeta =>
+      const TaskConstMeta(debugName: 'list_loaded_models', argNames: []);
+
+  @override
+  Future<BigInt> crateApiLoadChatSession({
+    required String path,
+    int? nCtx,
+    String? modelId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final arg0 = cst_encode_String(path);
+          final arg1 = cst_encode_opt_box_autoadd_u_32(nCtx);
+          final arg2 = cst_encode_opt_String(modelId);
+          return wire.wire__crate__api__load_chat_session(
+            port_,
+            arg0,
+            arg1,
+            arg2,
+          );
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_u_64,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiLoadChatSessionConstMeta,
+        argValues: [path, nCtx, modelId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiLoadChatSessionConstMeta => const TaskConstMeta(
+    debugName: 'load_chat_session',
+    argNames: ['path', 'nCtx', 'modelId'],
+  );
+
+  @override
+  Future<void> crateApiLoadEmbeddingModel({
+    required String modelPath,
+    EmbeddingConfig? config,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final arg0 = cst_encode_String(modelPath);
+          final arg1 = cst_encode_opt_box_autoadd_embedding_config(config);
+          return wire.wire__crate__api__load_embedding_model(port_, arg0, arg1);
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiLoadEmbeddingModelConstMeta,
+        argValues: [modelPath, config],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiLoadEmbeddingModelConstMeta => const TaskConstMeta(
+    debugName: 'load_embedding_model',
+    argNames: ['modelPath', 'config'],
+  );
+
+  @override
+  Future<BigInt> crateApiLoadHnswIndex({required String path}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final arg0 = cst_encode_String(path);
+          return wire.wire__crate__api__load_hnsw_index(port_, arg0);
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_u_64,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiLoadHnswIndexConstMeta,
+        argValues: [path],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiLoadHnswIndexConstMeta =>
+      const TaskConstMeta(debugName: 'load_hnsw_index', argNames: ['path']);
+
+  @override
+  Future<void> crateApiLoadModel({
+    required String modelId,
+    required String modelPath,
+    int? nCtx,
+    int? nGpuLayers,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final arg0 = cst_encode_String(modelId);
+          final arg1 = cst_encode_String(modelPath);
+          final arg2 = cst_encode_opt_box_autoadd_u_32(nCtx);
+          final arg3 = cst_encode_opt_box_autoadd_u_32(nGpuLayers);
+          return wire.wire__crate__api__load_model(
+            port_,
+            arg0,
+            arg1,
+            arg2,
+            arg3,
+          );
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiLoadModelConstMeta,
+        argValues: [modelId, modelPath, nCtx, nGpuLayers],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiLoadModelConstMeta => const TaskConstMeta(
+    debugName: 'load_model',
+    argNames: ['modelId', 'modelPath', 'nCtx', 'nGpuLayers'],
+  );
+
+  @override
+  Future<BigInt> crateApiLoadQuantizedIndex({
+    required String path,
+    BigInt? storeId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final arg0 = cst_encode_String(path);
+          final arg1 = cst_encode_opt_box_autoadd_u_64(storeId);
+          return wire.wire__crate__api__load_quantized_index(port_, arg0, arg1);
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_u_64,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiLoadQuantizedIndexConstMeta,
+        argValues: [path, storeId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiLoadQuantizedIndexConstMeta => const TaskConstMeta(
+    debugName: 'load_quantized_index',
+    argNames: ['path', 'storeId'],
+  );
+
+  @override
+  TaskCategory crateApiMcpClassifyTask({required String message}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final arg0 = cst_encode_String(message);
+          return wire.wire__crate__api__mcp_classify_task(arg0);
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_task_category,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiMcpClassifyTaskConstMeta,
+        argValues: [message],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMcpClassifyTaskConstMeta => const TaskConstMeta(
+    debugName: 'mcp_classify_task',
+    argNames: ['message'],
+  );
+
+  @override
+  Future<TaskCategory> crateApiMcpClassifyTaskWithModel({
+    required String message,
+    String? modelId,
+    double? minConfidence,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final arg0 = cst_encode_String(message);
+          final arg1 = cst_encode_opt_String(modelId);
+          final arg2 = cst_encode_opt_box_autoadd_f_32(minConfidence);
+          return wire.wire__crate__api__mcp_classify_task_with_model(
+            port_,
+            arg0,
+            arg1,
+            arg2,
+          );
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_task_category,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiMcpClassifyTaskWithModelConstMeta,
+        argValues: [message, modelId, minConfidence],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMcpClassifyTaskWithModelConstMeta =>
+      const TaskConstMeta(
+        debugName: 'mcp_classify_task_with_model',
+        argNames: ['message', 'modelId', 'minConfidence'],
+      );
+
+  @override
+  Future<void> crateApiMcpCreateFolder({required String path}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final arg0 = cst_encode_String(path);
+          return wire.wire__crate__api__mcp_create_folder(port_, arg0);
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiMcpCreateFolderConstMeta,
+        argValues: [path],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMcpCreateFolderConstMeta =>
+      const TaskConstMeta(debugName: 'mcp_create_folder', argNames: ['path']);
+
+  @override
+  Future<void> crateApiMcpDeleteFile({required String path}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final arg0 = cst_encode_String(path);
+          return wire.wire__crate__api__mcp_delete_file(port_, arg0);
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiMcpDeleteFileConstMeta,
+        argValues: [path],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMcpDeleteFileConstMeta =>
+      const TaskConstMeta(debugName: 'mcp_delete_file', argNames: ['path']);
+
+  @override
+  Future<MCPToolResult> crateApiMcpExecuteToolCall({
+    required MCPToolCall toolCall,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final arg0 = cst_encode_box_autoadd_mcp_tool_call(toolCall);
+          return wire.wire__crate__api__mcp_execute_tool_call(port_, arg0);
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_mcp_tool_result,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiMcpExecuteToolCallConstMeta,
+        argValues: [toolCall],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMcpExecuteToolCallConstMeta => const TaskConstMeta(
+    debugName: 'mcp_execute_tool_call',
+    argNames: ['toolCall'],
+  );
+
+  @override
+  Future<MCPToolCall> crateApiMcpGenerateToolCall({
+    required List<(String, String)> messages,
+    int? maxTokens,
+    String? modelId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final arg0 = cst_encode_list_record_string_string(messages);
+          final arg1 = cst_encode_opt_box_autoadd_u_32(maxTokens);
+          final arg2 = cst_encode_opt_String(modelId);
+          return wire.wire__crate__api__mcp_generate_tool_call(
+            port_,
+            arg0,
+            arg1,
+            arg2,
+          );
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_mcp_tool_call,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiMcpGenerateToolCallConstMeta,
+        argValues: [messages, maxTokens, modelId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMcpGenerateToolCallConstMeta =>
+      const TaskConstMeta(
+        debugName: 'mcp_generate_tool_call',
+        argNames: ['messages', 'maxTokens', 'modelId'],
+      );
+
+  @override
+  List<MCPTool> crateApiMcpGetAllTools() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          return wire.wire__crate__api__mcp_get_all_tools();
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_list_mcp_tool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiMcpGetAllToolsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMcpGetAllToolsConstMeta =>
+      const TaskConstMeta(debugName: 'mcp_get_all_tools', argNames: []);
+
+  @override
+  String crateApiMcpGetModelForTask({required TaskCategory category}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final arg0 = cst_encode_task_category(category);
+          return wire.wire__crate__api__mcp_get_model_for_task(arg0);
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiMcpGetModelForTaskConstMeta,
+        argValues: [category],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMcpGetModelForTaskConstMeta => const TaskConstMeta(
+    debugName: 'mcp_get_model_for_task',
+    argNames: ['category'],
+  );
+
+  @override
+  String? crateApiMcpGetModelIdForTask({required TaskCategory category}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final arg0 = cst_encode_task_category(category);
+          return wire.wire__crate__api__mcp_get_model_id_for_task(arg0);
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_opt_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiMcpGetModelIdForTaskConstMeta,
+        argValues: [category],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMcpGetModelIdForTaskConstMeta =>
+      const TaskConstMeta(
+        debugName: 'mcp_get_model_id_for_task',
+        argNames: ['category'],
+      );
+
+  @override
+  String crateApiMcpGetToolDescription({required MCPTool tool}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final arg0 = cst_encode_mcp_tool(tool);
+          return wire.wire__crate__api__mcp_get_tool_description(arg0);
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiMcpGetToolDescriptionConstMeta,
+        argValues: [tool],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMcpGetToolDescriptionConstMeta =>
+      const TaskConstMeta(
+        debugName: 'mcp_get_tool_description',
+        argNames: ['tool'],
+      );
+
+  @override
+  String crateApiMcpGetToolName({required MCPTool tool}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final arg0 = cst_encode_mcp_tool(tool);
+          return wire.wire__crate__api__mcp_get_tool_name(arg0);
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiMcpGetToolNameConstMeta,
+        argValues: [tool],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMcpGetToolNameConstMeta =>
+      const TaskConstMeta(debugName: 'mcp_get_tool_name', argNames: ['tool']);
+
+  @override
+  List<MCPParameter> crateApiMcpGetToolParameters({required MCPTool tool}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final arg0 = cst_encode_mcp_tool(tool);
+          return wire.wire__crate__api__mcp_get_tool_parameters(arg0);
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_list_mcp_parameter,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiMcpGetToolParametersConstMeta,
+        argValues: [tool],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMcpGetToolParametersConstMeta =>
+      const TaskConstMeta(
+        debugName: 'mcp_get_tool_parameters',
+        argNames: ['tool'],
+      );
+
+  @override
+  String crateApiMcpGetToolSchemas() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          return wire.wire__crate__api__mcp_get_tool_schemas();
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiMcpGetToolSchemasConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMcpGetToolSchemasConstMeta =>
+      const TaskConstMeta(debugName: 'mcp_get_tool_schemas', argNames: []);
+
+  @override
+  Future<List<String>> crateApiMcpListFiles({required String path}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final arg0 = cst_encode_String(path);
+          return wire.wire__crate__api__mcp_list_files(port_, arg0);
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_list_String,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiMcpListFilesConstMeta,
+        argValues: [path],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMcpListFilesConstMeta =>
+      const TaskConstMeta(debugName: 'mcp_list_files', argNames: ['path']);
+
+  @override
+  Future<MCPToolCall> crateApiMcpParseToolCall({required String json}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final arg0 = cst_encode_String(json);
+          return wire.wire__crate__api__mcp_parse_tool_call(port_, arg0);
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_mcp_tool_call,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiMcpParseToolCallConstMeta,
+        argValues: [json],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMcpParseToolCallConstMeta =>
+      const TaskConstMeta(debugName: 'mcp_parse_tool_call', argNames: ['json']);
+
+  @override
+  ParsedReply crateApiMcpParseToolCalls({
+    required String reply,
+    int? modelType,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final arg0 = cst_encode_String(reply);
+          final arg1 = cst_encode_opt_box_autoadd_i_32(modelType);
+          return wire.wire__crate__api__mcp_parse_tool_calls(arg0, arg1);
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_parsed_reply,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiMcpParseToolCallsConstMeta,
+        argValues: [reply, modelType],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMcpParseToolCallsConstMeta => const TaskConstMeta(
+    debugName: 'mcp_parse_tool_calls',
+    argNames: ['reply', 'modelType'],
+  );
+
+  @override
+  Future<String> crateApiMcpReadFile({required String path}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final arg0 = cst_encode_String(path);
+          return wire.wire__crate__api__mcp_read_file(port_, arg0);
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_String,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiMcpReadFileConstMeta,
+        argValues: [path],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMcpReadFileConstMeta =>
+      const TaskConstMeta(debugName: 'mcp_read_file', argNames: ['path']);
+
+  @override
+  Future<AgentRun> crateApiMcpRunAgent({
+    required List<(String, String)> messages,
+    int? maxSteps,
+    int? maxTokens,
+    String? modelId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final arg0 = cst_encode_list_record_string_string(messages);
+          final arg1 = cst_encode_opt_box_autoadd_u_32(maxSteps);
+          final arg2 = cst_encode_opt_box_autoadd_u_32(maxTokens);
+          final arg3 = cst_encode_opt_String(modelId);
+          return wire.wire__crate__api__mcp_run_agent(
+            port_,
+            arg0,
+            arg1,
+            arg2,
+            arg3,
+          );
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_agent_run,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiMcpRunAgentConstMeta,
+        argValues: [messages, maxSteps, maxTokens, modelId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMcpRunAgentConstMeta => const TaskConstMeta(
+    debugName: 'mcp_run_agent',
+    argNames: ['messages', 'maxSteps', 'maxTokens', 'modelId'],
+  );
+
+  @override
+  Stream<AgentStep> crateApiMcpRunAgentStream({
+    required List<(String, String)> messages,
+    int? maxSteps,
+    int? maxTokens,
+    String? modelId,
+  }) {
+    final sink = RustStreamSink<AgentStep>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final arg0 = cst_encode_list_record_string_string(messages);
+            final arg1 = cst_encode_opt_box_autoadd_u_32(maxSteps);
+            final arg2 = cst_encode_opt_box_autoadd_u_32(maxTokens);
+            final arg3 = cst_encode_opt_String(modelId);
+            final arg4 = cst_encode_StreamSink_agent_step_Dco(sink);
+            return wire.wire__crate__api__mcp_run_agent_stream(
+              port_,
+              arg0,
+              arg1,
+              arg2,
+              arg3,
+              arg4,
+            );
+          },
+          codec: DcoCodec(
+            decodeSuccessData: dco_decode_unit,
+            decodeErrorData: dco_decode_AnyhowException,
+          ),
+          constMeta: kCrateApiMcpRunAgentStreamConstMeta,
+          argValues: [messages, maxSteps, maxTokens, modelId, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiMcpRunAgentStreamConstMeta => const TaskConstMeta(
+    debugName: 'mcp_run_agent_stream',
+    argNames: ['messages', 'maxSteps', 'maxTokens', 'modelId', 'sink'],
+  );
+
+  @override
+  bool crateApiMcpValidatePath({required String path}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final arg0 = cst_encode_String(path);
+          return wire.wire__crate__api__mcp_validate_path(arg0);
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiMcpValidatePathConstMeta,
+        argValues: [path],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMcpValidatePathConstMeta =>
+      const TaskConstMeta(debugName: 'mcp_validate_path', argNames: ['path']);
+
+  @override
+  Future<void> crateApiMcpWriteFile({
+    required String path,
+    required String content,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final arg0 = cst_encode_String(path);
+          final arg1 = cst_encode_String(content);
+          return wire.wire__crate__api__mcp_write_file(port_, arg0, arg1);
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiMcpWriteFileConstMeta,
+        argValues: [path, content],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMcpWriteFileConstMeta => const TaskConstMeta(
+    debugName: 'mcp_write_file',
+    argNames: ['path', 'content'],
+  );
+
+  @override
+  Future<RecallReport> crateApiMeasureQuantizationRecall({
+    required List<EmbeddingEntry> entries,
+    List<Float32List>? queries,
+    required QuantizationKind kind,
+    required BigInt topK,
+    required BigInt rescoreCandidates,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final arg0 = cst_encode_list_embedding_entry(entries);
+          final arg1 = cst_encode_opt_list_list_prim_f_32_strict(queries);
+          final arg2 = cst_encode_quantization_kind(kind);
+          final arg3 = cst_encode_usize(topK);
+          final arg4 = cst_encode_usize(rescoreCandidates);
+          return wire.wire__crate__api__measure_quantization_recall(
+            port_,
+            arg0,
+            arg1,
+            arg2,
+            arg3,
+            arg4,
+          );
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_recall_report,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiMeasureQuantizationRecallConstMeta,
+        argValues: [entries, queries, kind, topK, rescoreCandidates],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMeasureQuantizationRecallConstMeta =>
+      const TaskConstMeta(
+        debugName: 'measure_quantization_recall',
+        argNames: ['entries', 'queries', 'kind', 'topK', 'rescoreCandidates'],
+      );
+
+  @override
+  Future<void> crateApiOpenEmbeddingCache({required String path}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final arg0 = cst_encode_String(path);
+          return wire.wire__crate__api__open_embedding_cache(port_, arg0);
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiOpenEmbeddingCacheConstMeta,
+        argValues: [path],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiOpenEmbeddingCacheConstMeta => const TaskConstMeta(
+    debugName: 'open_embedding_cache',
+    argNames: ['path'],
+  );
+
+  @override
+  Future<BigInt> crateApiOpenVectorStore({required String path}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final arg0 = cst_encode_String(path);
+          return wire.wire__crate__api__open_vector_store(port_, arg0);
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_u_64,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiOpenVectorStoreConstMeta,
+        argValues: [path],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiOpenVectorStoreConstMeta =>
+      const TaskConstMeta(debugName: 'open_vector_store', argNames: ['path']);
+
+  @override
+  Future<void> crateApiPinStreamNode({
+    required String id,
+    required bool pinned,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final arg0 = cst_encode_String(id);
+          final arg1 = cst_encode_bool(pinned);
+          return wire.wire__crate__api__pin_stream_node(port_, arg0, arg1);
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiPinStreamNodeConstMeta,
+        argValues: [id, pinned],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiPinStreamNodeConstMeta => const TaskConstMeta(
+    debugName: 'pin_stream_node',
+    argNames: ['id', 'pinned'],
+  );
+
+  @override
+  GenerationPoll crateApiPollGeneration({required BigInt requestId}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final arg0 = cst_encode_u_64(requestId);
+          return wire.wire__crate__api__poll_generation(arg0);
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_generation_poll,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiPollGenerationConstMeta,
+        argValues: [requestId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiPollGenerationConstMeta => const TaskConstMeta(
+    debugName: 'poll_generation',
+    argNames: ['requestId'],
+  );
+
+  @override
+  Future<void> crateApiQuantizedIndexInsert({
+    required BigInt indexId,
+    required List<EmbeddingEntry> entries,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final arg0 = cst_encode_u_64(indexId);
+          final arg1 = cst_encode_list_embedding_entry(entries);
+          return wire.wire__crate__api__quantized_index_insert(
+            port_,
+            arg0,
+            arg1,
+          );
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiQuantizedIndexInsertConstMeta,
+        argValues: [indexId, entries],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiQuantizedIndexInsertConstMeta =>
+      const TaskConstMeta(
+        debugName: 'quantized_index_insert',
+        argNames: ['indexId', 'entries'],
+      );
+
+  @override
+  Future<BigInt> crateApiQuantizedIndexRemove({
+    required BigInt indexId,
+    required List<String> ids,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final arg0 = cst_encode_u_64(indexId);
+          final arg1 = cst_encode_list_String(ids);
+          return wire.wire__crate__api__quantized_index_remove(
+            port_,
+            arg0,
+            arg1,
+          );
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_usize,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiQuantizedIndexRemoveConstMeta,
+        argValues: [indexId, ids],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiQuantizedIndexRemoveConstMeta =>
+      const TaskConstMeta(
+        debugName: 'quantized_index_remove',
+        argNames: ['indexId', 'ids'],
+      );
+
+  @override
+  Future<RagAnswer> crateApiRagAnswer({
+    required String question,
+    required List<EmbeddingEntry> chunks,
+    int? topK,
+    int? contextTokens,
+    int? maxTokens,
+    String? modelId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final arg0 = cst_encode_String(question);
+          final arg1 = cst_encode_list_embedding_entry(chunks);
+          final arg2 = cst_encode_opt_box_autoadd_u_32(topK);
+          final arg3 = cst_encode_opt_box_autoadd_u_32(contextTokens);
+          final arg4 = cst_encode_opt_box_autoadd_u_32(maxTokens);
+          final arg5 = cst_encode_opt_String(modelId);
+          return wire.wire__crate__api__rag_answer(
+            port_,
+            arg0,
+            arg1,
+            arg2,
+            arg3,
+            arg4,
+            arg5,
+          );
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_rag_answer,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiRagAnswerConstMeta,
+        argValues: [question, chunks, topK, contextTokens, maxTokens, modelId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiRagAnswerConstMeta => const TaskConstMeta(
+    debugName: 'rag_answer',
+    argNames: [
+      'question',
+      'chunks',
+      'topK',
+      'contextTokens',
+      'maxTokens',
+      'modelId',
+    ],
+  );
+
+  @override
+  Future<void> crateApiRemoveGraphNode({required String nodeId}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final arg0 = cst_encode_String(nodeId);
+          return wire.wire__crate__api__remove_graph_node(port_, arg0);
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiRemoveGraphNodeConstMeta,
+        argValues: [nodeId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiRemoveGraphNodeConstMeta =>
+      const TaskConstMeta(debugName: 'remove_graph_node', argNames: ['nodeId']);
+
+  @override
+  Future<void> crateApiRemoveStreamEdge({
+    required String fromId,
+    required String toId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final arg0 = cst_encode_String(fromId);
+          final arg1 = cst_encode_String(toId);
+          return wire.wire__crate__api__remove_stream_edge(port_, arg0, arg1);
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiRemoveStreamEdgeConstMeta,
+        argValues: [fromId, toId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiRemoveStreamEdgeConstMeta => const TaskConstMeta(
+    debugName: 'remove_stream_edge',
+    argNames: ['fromId', 'toId'],
+  );
+
+  @override
+  Future<void> crateApiRemoveStreamNode({required String id}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final arg0 = cst_encode_String(id);
+          return wire.wire__crate__api__remove_stream_node(port_, arg0);
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiRemoveStreamNodeConstMeta,
+        argValues: [id],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiRemoveStreamNodeConstMeta =>
+      const TaskConstMeta(debugName: 'remove_stream_node', argNames: ['id']);
+
+  @override
+  Future<void> crateApiSaveChatSession({
+    required BigInt sessionId,
+    required String path,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final arg0 = cst_encode_u_64(sessionId);
+          final arg1 = cst_encode_String(path);
+          return wire.wire__crate__api__save_chat_session(port_, arg0, arg1);
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSaveChatSessionConstMeta,
+        argValues: [sessionId, path],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSaveChatSessionConstMeta => const TaskConstMeta(
+    debugName: 'save_chat_session',
+    argNames: ['sessionId', 'path'],
+  );
+
+  @override
+  Future<void> crateApiSaveHnswIndex({
+    required BigInt indexId,
+    required String path,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final arg0 = cst_encode_u_64(indexId);
+          final arg1 = cst_encode_String(path);
+          return wire.wire__crate__api__save_hnsw_index(port_, arg0, arg1);
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSaveHnswIndexConstMeta,
+        argValues: [indexId, path],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSaveHnswIndexConstMeta => const TaskConstMeta(
+    debugName: 'save_hnsw_index',
+    argNames: ['indexId', 'path'],
+  );
+
+  @override
+  Future<void> crateApiSaveQuantizedIndex({
+    required BigInt indexId,
+    required String path,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final arg0 = cst_encode_u_64(indexId);
+          final arg1 = cst_encode_String(path);
+          return wire.wire__crate__api__save_quantized_index(port_, arg0, arg1);
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSaveQuantizedIndexConstMeta,
+        argValues: [indexId, path],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSaveQuantizedIndexConstMeta => const TaskConstMeta(
+    debugName: 'save_quantized_index',
+    argNames: ['indexId', 'path'],
+  );
+
+  @override
+  Future<List<SimilarityResult>> crateApiSemanticSearch({
+    required String queryText,
+    required List<EmbeddingEntry> entries,
+    required BigInt topK,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final arg0 = cst_encode_String(queryText);
+          final arg1 = cst_encode_list_embedding_entry(entries);
+          final arg2 = cst_encode_usize(topK);
+          return wire.wire__crate__api__semantic_search(
+            port_,
+            arg0,
+            arg1,
+            arg2,
+          );
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_list_similarity_result,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSemanticSearchConstMeta,
+        argValues: [queryText, entries, topK],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSemanticSearchConstMeta => const TaskConstMeta(
+    debugName: 'semantic_search',
+    argNames: ['queryText', 'entries', 'topK'],
+  );
+
+  @override
+  Future<List<SimilarityResult>> crateApiSemanticSearchFiltered({
+    required String queryText,
+    required List<EmbeddingEntry> entries,
+    required SearchOptions options,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final arg0 = cst_encode_String(queryText);
+          final arg1 = cst_encode_list_embedding_entry(entries);
+          final arg2 = cst_encode_box_autoadd_search_options(options);
+          return wire.wire__crate__api__semantic_search_filtered(
+            port_,
+            arg0,
+            arg1,
+            arg2,
+          );
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_list_similarity_result,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSemanticSearchFilteredConstMeta,
+        argValues: [queryText, entries, options],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSemanticSearchFilteredConstMeta =>
+      const TaskConstMeta(
+        debugName: 'semantic_search_filtered',
+        argNames: ['queryText', 'entries', 'options'],
+      );
+
+  @override
+  Future<List<SimilarityResult>> crateApiSemanticSearchInIndex({
+    required BigInt indexId,
+    required String queryText,
+    required BigInt topK,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final arg0 = cst_encode_u_64(indexId);
+          final arg1 = cst_encode_String(queryText);
+          final arg2 = cst_encode_usize(topK);
+          return wire.wire__crate__api__semantic_search_in_index(
+            port_,
+            arg0,
+            arg1,
+            arg2,
+          );
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_list_similarity_result,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSemanticSearchInIndexConstMeta,
+        argValues: [indexId, queryText, topK],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSemanticSearchInIndexConstMeta =>
+      const TaskConstMeta(
+        debugName: 'semantic_search_in_index',
+        argNames: ['indexId', 'queryText', 'topK'],
+      );
+
+  @override
+  Future<List<SimilarityResult>> crateApiSemanticSearchInIndexFiltered({
+    required BigInt indexId,
+    required String queryText,
+    required SearchOptions options,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final arg0 = cst_encode_u_64(indexId);
+          final arg1 = cst_encode_String(queryText);
+          final arg2 = cst_encode_box_autoadd_search_options(options);
+          return wire.wire__crate__api__semantic_search_in_index_filtered(
+            port_,
+            arg0,
+            arg1,
+            arg2,
+          );
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_list_similarity_result,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSemanticSearchInIndexFilteredConstMeta,
+        argValues: [indexId, queryText, options],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSemanticSearchInIndexFilteredConstMeta =>
+      const TaskConstMeta(
+        debugName: 'semantic_search_in_index_filtered',
+        argNames: ['indexId', 'queryText', 'options'],
+      );
+
+  @override
+  Future<List<SimilarityResult>> crateApiSemanticSearchInStore({
+    required BigInt storeId,
+    required String queryText,
+    required BigInt topK,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final arg0 = cst_encode_u_64(storeId);
+          final arg1 = cst_encode_String(queryText);
+          final arg2 = cst_encode_usize(topK);
+          return wire.wire__crate__api__semantic_search_in_store(
+            port_,
+            arg0,
+            arg1,
+            arg2,
+          );
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_list_similarity_result,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSemanticSearchInStoreConstMeta,
+        argValues: [storeId, queryText, topK],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSemanticSearchInStoreConstMeta =>
+      const TaskConstMeta(
+        debugName: 'semantic_search_in_store',
+        argNames: ['storeId', 'queryText', 'topK'],
+      );
+
+  @override
+  Future<List<SimilarityResult>> crateApiSemanticSearchInStoreFiltered({
+    required BigInt storeId,
+    required String queryText,
+    required SearchOptions options,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final arg0 = cst_encode_u_64(storeId);
+          final arg1 = cst_encode_String(queryText);
+          final arg2 = cst_encode_box_autoadd_search_options(options);
+          return wire.wire__crate__api__semantic_search_in_store_filtered(
+            port_,
+            arg0,
+            arg1,
+            arg2,
+          );
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_list_similarity_result,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSemanticSearchInStoreFilteredConstMeta,
+        argValues: [storeId, queryText, options],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSemanticSearchInStoreFilteredConstMeta =>
+      const TaskConstMeta(
+        debugName: 'semantic_search_in_store_filtered',
+        argNames: ['storeId', 'queryText', 'options'],
+      );
+
+  @override
+  void crateApiSetDefaultModel({required String modelId}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final arg0 = cst_encode_String(modelId);
+          return wire.wire__crate__api__set_default_model(arg0);
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSetDefaultModelConstMeta,
+        argValues: [modelId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSetDefaultModelConstMeta => const TaskConstMeta(
+    debugName: 'set_default_model',
+    argNames: ['modelId'],
+  );
+
+  @override
+  void crateApiSetModelMemoryBudget({BigInt? budgetBytes}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final arg0 = cst_encode_opt_box_autoadd_u_64(budgetBytes);
+          return wire.wire__crate__api__set_model_memory_budget(arg0);
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSetModelMemoryBudgetConstMeta,
+        argValues: [budgetBytes],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSetModelMemoryBudgetConstMeta =>
+      const TaskConstMeta(
+        debugName: 'set_model_memory_budget',
+        argNames: ['budgetBytes'],
+      );
+
+  @override
+  Future<void> crateApiSetStreamNodePosition({
+    required String id,
+    required double x,
+    required double y,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final arg0 = cst_encode_String(id);
+          final arg1 = cst_encode_f_32(x);
+          final arg2 = cst_encode_f_32(y);
+          return wire.wire__crate__api__set_stream_node_position(
+            port_,
+            arg0,
+            arg1,
+            arg2,
+          );
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSetStreamNodePositionConstMeta,
+        argValues: [id, x, y],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSetStreamNodePositionConstMeta =>
+      const TaskConstMeta(
+        debugName: 'set_stream_node_position',
+        argNames: ['id', 'x', 'y'],
+      );
+
+  @override
+  Future<BigInt> crateApiStartChatCompletion({
+    required List<(String, String)> messages,
+    int? maxTokens,
+    List<String>? stopSequences,
+    String? modelId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final arg0 = cst_encode_list_record_string_string(messages);
+          final arg1 = cst_encode_opt_box_autoadd_u_32(maxTokens);
+          final arg2 = cst_encode_opt_list_String(stopSequences);
+          final arg3 = cst_encode_opt_String(modelId);
+          return wire.wire__crate__api__start_chat_completion(
+            port_,
+            arg0,
+            arg1,
+            arg2,
+            arg3,
+          );
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_u_64,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiStartChatCompletionConstMeta,
+        argValues: [messages, maxTokens, stopSequences, modelId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiStartChatCompletionConstMeta =>
+      const TaskConstMeta(
+        debugName: 'start_chat_completion',
+        argNames: ['messages', 'maxTokens', 'stopSequences', 'modelId'],
+      );
+
+  @override
+  Future<BigInt> crateApiStartGeneration({
+    required String prompt,
+    int? maxTokens,
+    List<String>? stopSequences,
+    String? modelId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final arg0 = cst_encode_String(prompt);
+          final arg1 = cst_encode_opt_box_autoadd_u_32(maxTokens);
+          final arg2 = cst_encode_opt_list_String(stopSequences);
+          final arg3 = cst_encode_opt_String(modelId);
+          return wire.wire__crate__api__start_generation(
+            port_,
+            arg0,
+            arg1,
+            arg2,
+            arg3,
+          );
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_u_64,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiStartGenerationConstMeta,
+        argValues: [prompt, maxTokens, stopSequences, modelId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiStartGenerationConstMeta => const TaskConstMeta(
+    debugName: 'start_generation',
+    argNames: ['prompt', 'maxTokens', 'stopSequences', 'modelId'],
+  );
+
+  @override
+  Future<void> crateApiStartGraphStream() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          return wire.wire__crate__api__start_graph_stream(port_);
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiStartGraphStreamConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiStartGraphStreamConstMeta =>
+      const TaskConstMeta(debugName: 'start_graph_stream', argNames: []);
+
+  @override
+  void crateApiStopGraphStream() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          return wire.wire__crate__api__stop_graph_stream();
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiStopGraphStreamConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiStopGraphStreamConstMeta =>
+      const TaskConstMeta(debugName: 'stop_graph_stream', argNames: []);
+
+  @override
+  Future<Summary> crateApiSummarizeText({
+    required String text,
+    SummaryOptions? options,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final arg0 = cst_encode_String(text);
+          final arg1 = cst_encode_opt_box_autoadd_summary_options(options);
+          return wire.wire__crate__api__summarize_text(port_, arg0, arg1);
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_summary,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSummarizeTextConstMeta,
+        argValues: [text, options],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSummarizeTextConstMeta => const TaskConstMeta(
+    debugName: 'summarize_text',
+    argNames: ['text', 'options'],
+  );
+
+  @override
+  Future<Summary> crateApiSummarizeTranscript({
+    required List<TranscriptSegment> segments,
+    SummaryOptions? options,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final arg0 = cst_encode_list_transcript_segment(segments);
+          final arg1 = cst_encode_opt_box_autoadd_summary_options(options);
+          return wire.wire__crate__api__summarize_transcript(port_, arg0, arg1);
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_summary,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSummarizeTranscriptConstMeta,
+        argValues: [segments, options],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSummarizeTranscriptConstMeta =>
+      const TaskConstMeta(
+        debugName: 'summarize_transcript',
+        argNames: ['segments', 'options'],
+      );
+
+  @override
+  Future<Int32List> crateApiTokenizeText({
+    required String text,
+    required bool addBos,
+    required bool parseSpecial,
+    String? modelId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final arg0 = cst_encode_String(text);
+          final arg1 = cst_encode_bool(addBos);
+          final arg2 = cst_encode_bool(parseSpecial);
+          final arg3 = cst_encode_opt_String(modelId);
+          return wire.wire__crate__api__tokenize_text(
+            port_,
+            arg0,
+            arg1,
+            arg2,
+            arg3,
+          );
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_list_prim_i_32_strict,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiTokenizeTextConstMeta,
+        argValues: [text, addBos, parseSpecial, modelId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTokenizeTextConstMeta => const TaskConstMeta(
+    debugName: 'tokenize_text',
+    argNames: ['text', 'addBos', 'parseSpecial', 'modelId'],
+  );
+
+  @override
+  void crateApiUnloadEmbeddingModel() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          return wire.wire__crate__api__unload_embedding_model();
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiUnloadEmbeddingModelConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiUnloadEmbeddingModelConstMeta =>
+      const TaskConstMeta(debugName: 'unload_embedding_model', argNames: []);
+
+  @override
+  void crateApiUnloadModel() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          return wire.wire__crate__api__unload_model();
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiUnloadModelConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiUnloadModelConstMeta =>
+      const TaskConstMeta(debugName: 'unload_model', argNames: []);
+
+  @override
+  bool crateApiUnloadModelById({required String modelId}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final arg0 = cst_encode_String(modelId);
+          return wire.wire__crate__api__unload_model_by_id(arg0);
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiUnloadModelByIdConstMeta,
+        argValues: [modelId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiUnloadModelByIdConstMeta => const TaskConstMeta(
+    debugName: 'unload_model_by_id',
+    argNames: ['modelId'],
+  );
+
+  @override
+  Future<void> crateApiUpdateGraphViewport({
+    required double x,
+    required double y,
+    required double width,
+    required double height,
+    required double scale,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final arg0 = cst_encode_f_32(x);
+          final arg1 = cst_encode_f_32(y);
+          final arg2 = cst_encode_f_32(width);
+          final arg3 = cst_encode_f_32(height);
+          final arg4 = cst_encode_f_32(scale);
+          return wire.wire__crate__api__update_graph_viewport(
+            port_,
+            arg0,
+            arg1,
+            arg2,
+            arg3,
+            arg4,
+          );
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiUpdateGraphViewportConstMeta,
+        argValues: [x, y, width, height, scale],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiUpdateGraphViewportConstMeta =>
+      const TaskConstMeta(
+        debugName: 'update_graph_viewport',
+        argNames: ['x', 'y', 'width', 'height', 'scale'],
+      );
+
+  @override
+  void crateApiUseLlamaInferenceBackend() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          return wire.wire__crate__api__use_llama_inference_backend();
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiUseLlamaInferenceBackendConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiUseLlamaInferenceBackendConstMeta =>
+      const TaskConstMeta(
+        debugName: 'use_llama_inference_backend',
+        argNames: [],
+      );
+
+  @override
+  void crateApiUseMockInferenceBackend({
+    required List<String> completions,
+    String? fallback,
+    required int embeddingDimension,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final arg0 = cst_encode_list_String(completions);
+          final arg1 = cst_encode_opt_String(fallback);
+          final arg2 = cst_encode_u_32(embeddingDimension);
+          return wire.wire__crate__api__use_mock_inference_backend(
+            arg0,
+            arg1,
+            arg2,
+          );
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiUseMockInferenceBackendConstMeta,
+        argValues: [completions, fallback, embeddingDimension],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiUseMockInferenceBackendConstMeta =>
+      const TaskConstMeta(
+        debugName: 'use_mock_inference_backend',
+        argNames: ['completions', 'fallback', 'embeddingDimension'],
+      );
+
+  @override
+  void crateApiValidateSearchFilter({required String filter}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final arg0 = cst_encode_String(filter);
+          return wire.wire__crate__api__validate_search_filter(arg0);
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiValidateSearchFilterConstMeta,
+        argValues: [filter],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiValidateSearchFilterConstMeta =>
+      const TaskConstMeta(
+        debugName: 'validate_search_filter',
+        argNames: ['filter'],
+      );
+
+  @override
+  Future<BigInt> crateApiVectorStoreDelete({
+    required BigInt storeId,
+    required List<String> ids,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final arg0 = cst_encode_u_64(storeId);
+          final arg1 = cst_encode_list_String(ids);
+          return wire.wire__crate__api__vector_store_delete(port_, arg0, arg1);
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_usize,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiVectorStoreDeleteConstMeta,
+        argValues: [storeId, ids],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiVectorStoreDeleteConstMeta => const TaskConstMeta(
+    debugName: 'vector_store_delete',
+    argNames: ['storeId', 'ids'],
+  );
+
+  @override
+  Future<List<EmbeddingEntry>> crateApiVectorStoreGet({
+    required BigInt storeId,
+    required List<String> ids,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final arg0 = cst_encode_u_64(storeId);
+          final arg1 = cst_encode_list_String(ids);
+          return wire.wire__crate__api__vector_store_get(port_, arg0, arg1);
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_list_embedding_entry,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiVectorStoreGetConstMeta,
+        argValues: [storeId, ids],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiVectorStoreGetConstMeta => const TaskConstMeta(
+    debugName: 'vector_store_get',
+    argNames: ['storeId', 'ids'],
+  );
+
+  @override
+  BigInt crateApiVectorStoreLen({required BigInt storeId}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final arg0 = cst_encode_u_64(storeId);
+          return wire.wire__crate__api__vector_store_len(arg0);
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_usize,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiVectorStoreLenConstMeta,
+        argValues: [storeId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiVectorStoreLenConstMeta =>
+      const TaskConstMeta(debugName: 'vector_store_len', argNames: ['storeId']);
+
+  @override
+  Future<void> crateApiVectorStoreUpsert({
+    required BigInt storeId,
+    required List<EmbeddingEntry> entries,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final arg0 = cst_encode_u_64(storeId);
+          final arg1 = cst_encode_list_embedding_entry(entries);
+          return wire.wire__crate__api__vector_store_upsert(port_, arg0, arg1);
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_unit,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiVectorStoreUpsertConstMeta,
+        argValues: [storeId, entries],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiVectorStoreUpsertConstMeta => const TaskConstMeta(
+    debugName: 'vector_store_upsert',
+    argNames: ['storeId', 'entries'],
+  );
+
+  @override
+  Future<GenerationResult> crateApiWaitForGeneration({
+    required BigInt requestId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final arg0 = cst_encode_u_64(requestId);
+          return wire.wire__crate__api__wait_for_generation(port_, arg0);
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_generation_result,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiWaitForGenerationConstMeta,
+        argValues: [requestId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWaitForGenerationConstMeta => const TaskConstMeta(
+    debugName: 'wait_for_generation',
+    argNames: ['requestId'],
+  );
+
+  @protected
+  AnyhowException dco_decode_AnyhowException(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return AnyhowException(raw as String);
+  }
+
+  @protected
+  RustStreamSink<AgentStep> dco_decode_StreamSink_agent_step_Dco(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<GenerationChunk> dco_decode_StreamSink_generation_chunk_Dco(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  String dco_decode_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as String;
+  }
+
+  @protected
+  AgentRun dco_decode_agent_run(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return AgentRun(
+      answer: dco_decode_String(arr[0]),
+      stopReason: dco_decode_agent_stop_reason(arr[1]),
+      steps: dco_decode_list_agent_step(arr[2]),
+      messages: dco_decode_list_record_string_string(arr[3]),
+    );
+  }
+
+  @protected
+  AgentStep dco_decode_agent_step(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return AgentStep(
+      reply: dco_decode_String(arr[0]),
+      toolCalls: dco_decode_list_mcp_tool_call(arr[1]),
+      results: dco_decode_list_mcp_tool_result(arr[2]),
+    );
+  }
+
+  @protected
+  AgentStopReason dco_decode_agent_stop_reason(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return AgentStopReason.values[raw as int];
+  }
+
+  @protected
+  bool dco_decode_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as bool;
+  }
+
+  @protected
+  ContextPolicy dco_decode_box_autoadd_context_policy(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_context_policy(raw);
+  }
+
+  @protected
+  ContextReport dco_decode_box_autoadd_context_report(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_context_report(raw);
+  }
+
+  @protected
+  DedupOptions dco_decode_box_autoadd_dedup_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_dedup_options(raw);
+  }
+
+  @protected
+  EmbeddingCacheStats dco_decode_box_autoadd_embedding_cache_stats(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_embedding_cache_stats(raw);
+  }
+
+  @protected
+  EmbeddingConfig dco_decode_box_autoadd_embedding_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_embedding_config(raw);
+  }
+
+  @protected
+  double dco_decode_box_autoadd_f_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as double;
+  }
+
+  @protected
+  GenerationOptions dco_decode_box_autoadd_generation_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_generation_options(raw);
+  }
+
+  @protected
+  GenerationResult dco_decode_box_autoadd_generation_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_generation_result(raw);
+  }
+
+  @protected
+  HnswConfig dco_decode_box_autoadd_hnsw_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_hnsw_config(raw);
+  }
+
+  @protected
+  HybridOptions dco_decode_box_autoadd_hybrid_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_hybrid_options(raw);
+  }
+
+  @protected
+  int dco_decode_box_autoadd_i_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_i_64(raw);
+  }
+
+  @protected
+  MCPToolCall dco_decode_box_autoadd_mcp_tool_call(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_mcp_tool_call(raw);
+  }
+
+  @protected
+  NoteMetadata dco_decode_box_autoadd_note_metadata(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_note_metadata(raw);
+  }
+
+  @protected
+  ReasoningOptions dco_decode_box_autoadd_reasoning_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_reasoning_options(raw);
+  }
+
+  @protected
+  SamplerConfig dco_decode_box_autoadd_sampler_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_sampler_config(raw);
+  }
+
+  @protected
+  SearchOptions dco_decode_box_autoadd_search_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_search_options(raw);
+  }
+
+  @protected
+  StopReason dco_decode_box_autoadd_stop_reason(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_stop_reason(raw);
+  }
+
+  @protected
+  SummaryOptions dco_decode_box_autoadd_summary_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_summary_options(raw);
+  }
+
+  @protected
+  int dco_decode_box_autoadd_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  BigInt dco_decode_box_autoadd_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_u_64(raw);
+  }
+
+  @protected
+  BigInt dco_decode_box_autoadd_usize(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_usize(raw);
+  }
+
+  @protected
+  ClusterAssignment dco_decode_cluster_assignment(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return ClusterAssignment(
+      id: dco_decode_String(arr[0]),
+      clusterId: dco_decode_usize(arr[1]),
+      color: dco_decode_String(arr[2]),
+    );
+  }
+
+  @protected
+  ClusterInfo dco_decode_cluster_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return ClusterInfo(
+      id: dco_decode_usize(arr[0]),
+      size: dco_decode_usize(arr[1]),
+      color: dco_decode_String(arr[2]),
+      centroid: dco_decode_opt_list_prim_f_32_strict(arr[3]),
+    );
+  }
+
+  @protected
+  ClusteringResult dco_decode_clustering_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return ClusteringResult(
+      assignments: dco_decode_list_cluster_assignment(arr[0]),
+      clusters: dco_decode_list_cluster_info(arr[1]),
+      k: dco_decode_usize(arr[2]),
+    );
+  }
+
+  @protected
+  ContextPolicy dco_decode_context_policy(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return ContextPolicy(
+      strategy: dco_decode_context_strategy(arr[0]),
+      summaryMaxTokens: dco_decode_u_32(arr[1]),
+    );
+  }
+
+  @protected
+  ContextReport dco_decode_context_report(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return ContextReport(
+      contextSize: dco_decode_u_32(arr[0]),
+      promptTokens: dco_decode_u_32(arr[1]),
+      messageTokens: dco_decode_list_prim_u_32_strict(arr[2]),
+      dropped: dco_decode_list_prim_u_32_strict(arr[3]),
+      truncated: dco_decode_list_prim_u_32_strict(arr[4]),
+      summarized: dco_decode_list_prim_u_32_strict(arr[5]),
+      summary: dco_decode_opt_String(arr[6]),
+    );
+  }
+
+  @protected
+  ContextStrategy dco_decode_context_strategy(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ContextStrategy.values[raw as int];
+  }
+
+  @protected
+  DedupDocument dco_decode_dedup_document(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return DedupDocument(
+      id: dco_decode_String(arr[0]),
+      text: dco_decode_String(arr[1]),
+      vector: dco_decode_opt_list_prim_f_32_strict(arr[2]),
+      createdAt: dco_decode_opt_box_autoadd_i_64(arr[3]),
+    );
+  }
+
+  @protected
+  DedupOptions dco_decode_dedup_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return DedupOptions(
+      shingleWords: dco_decode_usize(arr[0]),
+      numHashes: dco_decode_usize(arr[1]),
+      bands: dco_decode_usize(arr[2]),
+      minJaccard: dco_decode_f_32(arr[3]),
+      minCosine: dco_decode_f_32(arr[4]),
+      minSpanWords: dco_decode_usize(arr[5]),
+    );
+  }
+
+  @protected
+  DuplicateGroup dco_decode_duplicate_group(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return DuplicateGroup(
+      ids: dco_decode_list_String(arr[0]),
+      canonical: dco_decode_String(arr[1]),
+      canonicalNote: dco_decode_String(arr[2]),
+      pairs: dco_decode_list_duplicate_pair(arr[3]),
+    );
+  }
+
+  @protected
+  DuplicatePair dco_decode_duplicate_pair(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return DuplicatePair(
+      a: dco_decode_String(arr[0]),
+      b: dco_decode_String(arr[1]),
+      jaccard: dco_decode_f_32(arr[2]),
+      cosine: dco_decode_opt_box_autoadd_f_32(arr[3]),
+      spans: dco_decode_list_duplicate_span(arr[4]),
+    );
+  }
+
+  @protected
+  DuplicateSpan dco_decode_duplicate_span(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return DuplicateSpan(
+      aStart: dco_decode_usize(arr[0]),
+      aEnd: dco_decode_usize(arr[1]),
+      bStart: dco_decode_usize(arr[2]),
+      bEnd: dco_decode_usize(arr[3]),
+      words: dco_decode_usize(arr[4]),
+    );
+  }
+
+  @protected
+  EmbeddingCacheStats dco_decode_embedding_cache_stats(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return EmbeddingCacheStats(
+      entries: dco_decode_usize(arr[0]),
+      staleRecords: dco_decode_usize(arr[1]),
+      fileBytes: dco_decode_u_64(arr[2]),
+    );
+  }
+
+  @protected
+  EmbeddingCluster dco_decode_embedding_cluster(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 1)
+      throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+    return EmbeddingCluster(ids: dco_decode_list_String(arr[0]));
+  }
+
+  @protected
+  EmbeddingConfig dco_decode_embedding_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 8)
+      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    return EmbeddingConfig(
+      nGpuLayers: dco_decode_u_32(arr[0]),
+      nThreads: dco_decode_i_32(arr[1]),
+      pooling: dco_decode_pooling_mode(arr[2]),
+      chunkTokens: dco_decode_u_32(arr[3]),
+      chunkOverlap: dco_decode_u_32(arr[4]),
+      maxSequences: dco_decode_u_32(arr[5]),
+      queryPrefix: dco_decode_String(arr[6]),
+      documentPrefix: dco_decode_String(arr[7]),
+    );
+  }
+
+  @protected
+  EmbeddingEntry dco_decode_embedding_entry(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return EmbeddingEntry(
+      id: dco_decode_String(arr[0]),
+      vector: dco_decode_list_prim_f_32_strict(arr[1]),
+      textPreview: dco_decode_opt_String(arr[2]),
+      metadata: dco_decode_note_metadata(arr[3]),
+    );
+  }
+
+  @protected
+  EmbeddingKind dco_decode_embedding_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return EmbeddingKind.values[raw as int];
+  }
+
+  @protected
+  double dco_decode_f_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as double;
+  }
+
+  @protected
+  FusionMode dco_decode_fusion_mode(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return FusionMode.values[raw as int];
+  }
+
+  @protected
+  GenerationChunk dco_decode_generation_chunk(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return GenerationChunk(
+      text: dco_decode_String(arr[0]),
+      reasoning: dco_decode_String(arr[1]),
+      stopReason: dco_decode_opt_box_autoadd_stop_reason(arr[2]),
+    );
+  }
+
+  @protected
+  GenerationOptions dco_decode_generation_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 9)
+      throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+    return GenerationOptions(
+      maxTokens: dco_decode_opt_box_autoadd_u_32(arr[0]),
+      stopSequences: dco_decode_list_String(arr[1]),
+      sampler: dco_decode_opt_box_autoadd_sampler_config(arr[2]),
+      grammar: dco_decode_opt_String(arr[3]),
+      jsonSchema: dco_decode_opt_String(arr[4]),
+      modelId: dco_decode_opt_String(arr[5]),
+      context: dco_decode_opt_box_autoadd_context_policy(arr[6]),
+      reasoning: dco_decode_opt_box_autoadd_reasoning_options(arr[7]),
+      logprobs: dco_decode_opt_box_autoadd_u_32(arr[8]),
+    );
+  }
+
+  @protected
+  GenerationPoll dco_decode_generation_poll(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return GenerationPoll(
+      text: dco_decode_String(arr[0]),
+      reasoning: dco_decode_String(arr[1]),
+      result: dco_decode_opt_box_autoadd_generation_result(arr[2]),
+    );
+  }
+
+  @protected
+  GenerationResult dco_decode_generation_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return GenerationResult(
+      text: dco_decode_String(arr[0]),
+      reasoning: dco_decode_opt_String(arr[1]),
+      reasoningTokens: dco_decode_u_32(arr[2]),
+      stopReason: dco_decode_stop_reason(arr[3]),
+      tokensGenerated: dco_decode_u_32(arr[4]),
+      context: dco_decode_opt_box_autoadd_context_report(arr[5]),
+      logprobs: dco_decode_opt_list_token_logprob(arr[6]),
+    );
+  }
+
+  @protected
+  GgufMetadata dco_decode_gguf_metadata(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 16)
+      throw Exception('unexpected arr length: expect 16 but see ${arr.length}');
+    return GgufMetadata(
+      version: dco_decode_u_32(arr[0]),
+      architecture: dco_decode_opt_String(arr[1]),
+      name: dco_decode_opt_String(arr[2]),
+      chatTemplate: dco_decode_opt_String(arr[3]),
+      contextLength: dco_decode_opt_box_autoadd_u_64(arr[4]),
+      embeddingLength: dco_decode_opt_box_autoadd_u_64(arr[5]),
+      blockCount: dco_decode_opt_box_autoadd_u_64(arr[6]),
+      poolingType: dco_decode_opt_box_autoadd_u_32(arr[7]),
+      quantization: dco_decode_opt_String(arr[8]),
+      parameterCount: dco_decode_u_64(arr[9]),
+      tensorCount: dco_decode_u_64(arr[10]),
+      tokenizerModel: dco_decode_opt_String(arr[11]),
+      vocabSize: dco_decode_opt_box_autoadd_u_64(arr[12]),
+      bosTokenId: dco_decode_opt_box_autoadd_u_32(arr[13]),
+      eosTokenId: dco_decode_opt_box_autoadd_u_32(arr[14]),
+      fileSize: dco_decode_u_64(arr[15]),
+    );
+  }
+
+  @protected
+  GraphEdge dco_decode_graph_edge(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return GraphEdge(
+      source: dco_decode_String(arr[0]),
+      target: dco_decode_String(arr[1]),
+      weight: dco_decode_f_32(arr[2]),
+      edgeType: dco_decode_String(arr[3]),
+    );
+  }
+
+  @protected
+  GraphNode dco_decode_graph_node(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return GraphNode(
+      id: dco_decode_String(arr[0]),
+      label: dco_decode_String(arr[1]),
+      nodeType: dco_decode_String(arr[2]),
+      x: dco_decode_f_32(arr[3]),
+      y: dco_decode_f_32(arr[4]),
+      color: dco_decode_opt_String(arr[5]),
+      metadata: dco_decode_opt_String(arr[6]),
+    );
+  }
+
+  @protected
+  GraphState dco_decode_graph_state(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return GraphState(
+      nodes: dco_decode_list_graph_node(arr[0]),
+      edges: dco_decode_list_graph_edge(arr[1]),
+    );
+  }
+
+  @protected
+  HnswConfig dco_decode_hnsw_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return HnswConfig(
+      m: dco_decode_u_32(arr[0]),
+      efConstruction: dco_decode_u_32(arr[1]),
+      efSearch: dco_decode_u_32(arr[2]),
+    );
+  }
+
+  @protected
+  HybridHit dco_decode_hybrid_hit(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 8)
+      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    return HybridHit(
+      id: dco_decode_String(arr[0]),
+      score: dco_decode_f_32(arr[1]),
+      semanticScore: dco_decode_opt_box_autoadd_f_32(arr[2]),
+      semanticRank: dco_decode_opt_box_autoadd_u_32(arr[3]),
+      lexicalScore: dco_decode_opt_box_autoadd_f_32(arr[4]),
+      lexicalRank: dco_decode_opt_box_autoadd_u_32(arr[5]),
+      matchedTerms: dco_decode_list_String(arr[6]),
+      textPreview: dco_decode_opt_String(arr[7]),
+    );
+  }
+
+  @protected
+  HybridOptions dco_decode_hybrid_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return HybridOptions(
+      fusion: dco_decode_fusion_mode(arr[0]),
+      semanticWeight: dco_decode_f_32(arr[1]),
+      rrfK: dco_decode_f_32(arr[2]),
+      candidates: dco_decode_usize(arr[3]),
+      minSemanticScore: dco_decode_f_32(arr[4]),
+    );
+  }
+
+  @protected
+  int dco_decode_i_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  PlatformInt64 dco_decode_i_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeI64(raw);
+  }
+
+  @protected
+  KnowledgeGraphAnalysis dco_decode_knowledge_graph_analysis(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return KnowledgeGraphAnalysis(
+      clustering: dco_decode_clustering_result(arr[0]),
+      semanticEdges: dco_decode_semantic_edge_result(arr[1]),
+    );
+  }
+
+  @protected
+  List<String> dco_decode_list_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_String).toList();
+  }
+
+  @protected
+  List<AgentStep> dco_decode_list_agent_step(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_agent_step).toList();
+  }
+
+  @protected
+  List<ClusterAssignment> dco_decode_list_cluster_assignment(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_cluster_assignment).toList();
+  }
+
+  @protected
+  List<ClusterInfo> dco_decode_list_cluster_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_cluster_info).toList();
+  }
+
+  @protected
+  List<DedupDocument> dco_decode_list_dedup_document(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_dedup_document).toList();
+  }
+
+  @protected
+  List<DuplicateGroup> dco_decode_list_duplicate_group(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_duplicate_group).toList();
+  }
+
+  @protected
+  List<DuplicatePair> dco_decode_list_duplicate_pair(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_duplicate_pair).toList();
+  }
+
+  @protected
+  List<DuplicateSpan> dco_decode_list_duplicate_span(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_duplicate_span).toList();
+  }
+
+  @protected
+  List<EmbeddingCluster> dco_decode_list_embedding_cluster(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_embedding_cluster).toList();
+  }
+
+  @protected
+  List<EmbeddingEntry> dco_decode_list_embedding_entry(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_embedding_entry).toList();
+  }
+
+  @protected
+  List<GraphEdge> dco_decode_list_graph_edge(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_graph_edge).toList();
+  }
+
+  @protected
+  List<GraphNode> dco_decode_list_graph_node(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_graph_node).toList();
+  }
+
+  @protected
+  List<HybridHit> dco_decode_list_hybrid_hit(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_hybrid_hit).toList();
+  }
+
+  @protected
+  List<Float32List> dco_decode_list_list_prim_f_32_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>)
+        .map(dco_decode_list_prim_f_32_strict)
+        .toList();
+  }
+
+  @protected
+  List<LoadedModelInfo> dco_decode_list_loaded_model_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_loaded_model_info).toList();
+  }
+
+  @protected
+  List<MarkdownChunk> dco_decode_list_markdown_chunk(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_markdown_chunk).toList();
+  }
+
+  @protected
+  List<MCPParameter> dco_decode_list_mcp_parameter(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_mcp_parameter).toList();
+  }
+
+  @protected
+  List<MCPTool> dco_decode_list_mcp_tool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_mcp_tool).toList();
+  }
+
+  @protected
+  List<MCPToolCall> dco_decode_list_mcp_tool_call(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_mcp_tool_call).toList();
+  }
+
+  @protected
+  List<MCPToolResult> dco_decode_list_mcp_tool_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_mcp_tool_result).toList();
+  }
+
+  @protected
+  List<NodePosition> dco_decode_list_node_position(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_node_position).toList();
+  }
+
+  @protected
+  List<double> dco_decode_list_prim_f_32_loose(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as List<double>;
+  }
+
+  @protected
+  Float32List dco_decode_list_prim_f_32_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as Float32List;
+  }
+
+  @protected
+  List<int> dco_decode_list_prim_i_32_loose(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as List<int>;
+  }
+
+  @protected
+  Int32List dco_decode_list_prim_i_32_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as Int32List;
+  }
+
+  @protected
+  Uint32List dco_decode_list_prim_u_32_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as Uint32List;
+  }
+
+  @protected
+  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as Uint8List;
+  }
+
+  @protected
+  List<RagSource> dco_decode_list_rag_source(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_rag_source).toList();
+  }
+
+  @protected
+  List<(String, String)> dco_decode_list_record_string_string(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_record_string_string).toList();
+  }
+
+  @protected
+  List<ReplySpan> dco_decode_list_reply_span(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_reply_span).toList();
+  }
+
+  @protected
+  List<SemanticEdge> dco_decode_list_semantic_edge(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_semantic_edge).toList();
+  }
+
+  @protected
+  List<SimilarityResult> dco_decode_list_similarity_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_similarity_result).toList();
+  }
+
+  @protected
+  List<TokenCandidate> dco_decode_list_token_candidate(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_token_candidate).toList();
+  }
+
+  @protected
+  List<TokenLogprob> dco_decode_list_token_logprob(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_token_logprob).toList();
+  }
+
+  @protected
+  List<TranscriptSegment> dco_decode_list_transcript_segment(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_transcript_segment).toList();
+  }
+
+  @protected
+  LoadedModelInfo dco_decode_loaded_model_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return LoadedModelInfo(
+      modelId: dco_decode_String(arr[0]),
+      modelPath: dco_decode_String(arr[1]),
+      sizeBytes: dco_decode_u_64(arr[2]),
+      isDefault: dco_decode_bool(arr[3]),
+    );
+  }
+
+  @protected
+  MarkdownChunk dco_decode_markdown_chunk(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return MarkdownChunk(
+      index: dco_decode_usize(arr[0]),
+      headingPath: dco_decode_list_String(arr[1]),
+      start: dco_decode_usize(arr[2]),
+      end: dco_decode_usize(arr[3]),
+      text: dco_decode_String(arr[4]),
+      tokenCount: dco_decode_u_32(arr[5]),
+    );
+  }
+
+  @protected
+  MCPParamType dco_decode_mcp_param_type(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return MCPParamType.values[raw as int];
+  }
+
+  @protected
+  MCPParameter dco_decode_mcp_parameter(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return MCPParameter(
+      name: dco_decode_String(arr[0]),
+      description: dco_decode_String(arr[1]),
+      paramType: dco_decode_mcp_param_type(arr[2]),
+      required_: dco_decode_bool(arr[3]),
+    );
+  }
+
+  @protected
+  MCPTool dco_decode_mcp_tool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return MCPTool.values[raw as int];
+  }
+
+  @protected
+  MCPToolCall dco_decode_mcp_tool_call(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return MCPToolCall(
+      tool: dco_decode_String(arr[0]),
+      parametersJson: dco_decode_String(arr[1]),
+      description: dco_decode_String(arr[2]),
+    );
+  }
+
+  @protected
+  MCPToolResult dco_decode_mcp_tool_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return MCPToolResult(
+      success: dco_decode_bool(arr[0]),
+      result: dco_decode_String(arr[1]),
+      tool: dco_decode_String(arr[2]),
+    );
+  }
+
+  @protected
+  NodePosition dco_decode_node_position(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return NodePosition(
+      id: dco_decode_String(arr[0]),
+      x: dco_decode_f_32(arr[1]),
+      y: dco_decode_f_32(arr[2]),
+      radius: dco_decode_f_32(arr[3]),
+      color: dco_decode_u_32(arr[4]),
+      nodeType: dco_decode_String(arr[5]),
+    );
+  }
+
+  @protected
+  NoteMetadata dco_decode_note_metadata(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return NoteMetadata(
+      folder: dco_decode_opt_String(arr[0]),
+      tags: dco_decode_list_String(arr[1]),
+      createdAt: dco_decode_opt_box_autoadd_i_64(arr[2]),
+      modifiedAt: dco_decode_opt_box_autoadd_i_64(arr[3]),
+      noteType: dco_decode_opt_String(arr[4]),
+      chunkStart: dco_decode_opt_box_autoadd_usize(arr[5]),
+      chunkEnd: dco_decode_opt_box_autoadd_usize(arr[6]),
+    );
+  }
+
+  @protected
+  String? dco_decode_opt_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_String(raw);
+  }
+
+  @protected
+  ContextPolicy? dco_decode_opt_box_autoadd_context_policy(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_context_policy(raw);
+  }
+
+  @protected
+  ContextReport? dco_decode_opt_box_autoadd_context_report(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_context_report(raw);
+  }
+
+  @protected
+  DedupOptions? dco_decode_opt_box_autoadd_dedup_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_dedup_options(raw);
+  }
+
+  @protected
+  EmbeddingCacheStats? dco_decode_opt_box_autoadd_embedding_cache_stats(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null
+        ? null
+        : dco_decode_box_autoadd_embedding_cache_stats(raw);
+  }
+
+  @protected
+  EmbeddingConfig? dco_decode_opt_box_autoadd_embedding_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_embedding_config(raw);
+  }
+
+  @protected
+  double? dco_decode_opt_box_autoadd_f_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_f_32(raw);
+  }
+
+  @protected
+  GenerationResult? dco_decode_opt_box_autoadd_generation_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_generation_result(raw);
+  }
+
+  @protected
+  HnswConfig? dco_decode_opt_box_autoadd_hnsw_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_hnsw_config(raw);
+  }
+
+  @protected
+  HybridOptions? dco_decode_opt_box_autoadd_hybrid_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_hybrid_options(raw);
+  }
+
+  @protected
+  int? dco_decode_opt_box_autoadd_i_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_i_32(raw);
+  }
+
+  @protected
+  PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_i_64(raw);
+  }
+
+  @protected
+  NoteMetadata? dco_decode_opt_box_autoadd_note_metadata(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_note_metadata(raw);
+  }
+
+  @protected
+  ReasoningOptions? dco_decode_opt_box_autoadd_reasoning_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_reasoning_options(raw);
+  }
+
+  @protected
+  SamplerConfig? dco_decode_opt_box_autoadd_sampler_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_sampler_config(raw);
+  }
+
+  @protected
+  StopReason? dco_decode_opt_box_autoadd_stop_reason(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_stop_reason(raw);
+  }
+
+  @protected
+  SummaryOptions? dco_decode_opt_box_autoadd_summary_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_summary_options(raw);
+  }
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_32(raw);
+  }
+
+  @protected
+  BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_64(raw);
+  }
+
+  @protected
+  BigInt? dco_decode_opt_box_autoadd_usize(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_usize(raw);
+  }
+
+  @protected
+  List<String>? dco_decode_opt_list_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_list_String(raw);
+  }
+
+  @protected
+  List<Float32List>? dco_decode_opt_list_list_prim_f_32_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_list_list_prim_f_32_strict(raw);
+  }
+
+  @protected
+  Float32List? dco_decode_opt_list_prim_f_32_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_list_prim_f_32_strict(raw);
+  }
+
+  @protected
+  List<(String, String)>? dco_decode_opt_list_record_string_string(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_list_record_string_string(raw);
+  }
+
+  @protected
+  List<TokenLogprob>? dco_decode_opt_list_token_logprob(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_list_token_logprob(raw);
+  }
+
+  @protected
+  ParsedReply dco_decode_parsed_reply(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return ParsedReply(
+      toolCalls: dco_decode_list_mcp_tool_call(arr[0]),
+      spans: dco_decode_list_reply_span(arr[1]),
+      text: dco_decode_String(arr[2]),
+    );
+  }
+
+  @protected
+  PoolingMode dco_decode_pooling_mode(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return PoolingMode.values[raw as int];
+  }
+
+  @protected
+  QuantizationKind dco_decode_quantization_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return QuantizationKind.values[raw as int];
+  }
+
+  @protected
+  RagAnswer dco_decode_rag_answer(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return RagAnswer(
+      answer: dco_decode_String(arr[0]),
+      sources: dco_decode_list_rag_source(arr[1]),
+      skipped: dco_decode_usize(arr[2]),
+    );
+  }
+
+  @protected
+  RagSource dco_decode_rag_source(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return RagSource(
+      citation: dco_decode_u_32(arr[0]),
+      noteId: dco_decode_String(arr[1]),
+      chunkOffset: dco_decode_opt_box_autoadd_usize(arr[2]),
+      chunkIndex: dco_decode_opt_box_autoadd_usize(arr[3]),
+      score: dco_decode_f_32(arr[4]),
+      text: dco_decode_String(arr[5]),
+      cited: dco_decode_bool(arr[6]),
+    );
+  }
+
+  @protected
+  ReasoningOptions dco_decode_reasoning_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return ReasoningOptions(
+      enabled: dco_decode_bool(arr[0]),
+      openTag: dco_decode_String(arr[1]),
+      closeTag: dco_decode_String(arr[2]),
+      thinkingBudget: dco_decode_opt_box_autoadd_u_32(arr[3]),
+    );
+  }
+
+  @protected
+  RecallReport dco_decode_recall_report(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return RecallReport(
+      queries: dco_decode_usize(arr[0]),
+      topK: dco_decode_usize(arr[1]),
+      recall: dco_decode_f_32(arr[2]),
+      rescoredRecall: dco_decode_f_32(arr[3]),
+      f32Bytes: dco_decode_usize(arr[4]),
+      quantizedBytes: dco_decode_usize(arr[5]),
+    );
+  }
+
+  @protected
+  (String, String) dco_decode_record_string_string(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) {
+      throw Exception('Expected 2 elements, got ${arr.length}');
+    }
+    return (dco_decode_String(arr[0]), dco_decode_String(arr[1]));
+  }
+
+  @protected
+  ReplySpan dco_decode_reply_span(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return ReplySpan(
+      start: dco_decode_usize(arr[0]),
+      end: dco_decode_usize(arr[1]),
+      kind: dco_decode_reply_span_kind(arr[2]),
+      callCount: dco_decode_usize(arr[3]),
+    );
+  }
+
+  @protected
+  ReplySpanKind dco_decode_reply_span_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ReplySpanKind.values[raw as int];
+  }
+
+  @protected
+  SamplerConfig dco_decode_sampler_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 10)
+      throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
+    return SamplerConfig(
+      temperature: dco_decode_f_32(arr[0]),
+      topK: dco_decode_i_32(arr[1]),
+      topP: dco_decode_f_32(arr[2]),
+      minP: dco_decode_f_32(arr[3]),
+      repeatPenalty: dco_decode_f_32(arr[4]),
+      frequencyPenalty: dco_decode_f_32(arr[5]),
+      presencePenalty: dco_decode_f_32(arr[6]),
+      penaltyLastN: dco_decode_i_32(arr[7]),
+      seed: dco_decode_opt_box_autoadd_u_32(arr[8]),
+      greedy: dco_decode_bool(arr[9]),
+    );
+  }
+
+  @protected
+  SearchOptions dco_decode_search_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return SearchOptions(
+      topK: dco_decode_usize(arr[0]),
+      threshold: dco_decode_f_32(arr[1]),
+      filter: dco_decode_opt_String(arr[2]),
+      mmrLambda: dco_decode_opt_box_autoadd_f_32(arr[3]),
+      mmrCandidates: dco_decode_usize(arr[4]),
+    );
+  }
+
+  @protected
+  SemanticEdge dco_decode_semantic_edge(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return SemanticEdge(
+      source: dco_decode_String(arr[0]),
+      target: dco_decode_String(arr[1]),
+      similarity: dco_decode_f_32(arr[2]),
+      isGhost: dco_decode_bool(arr[3]),
+    );
+  }
+
+  @protected
+  SemanticEdgeResult dco_decode_semantic_edge_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return SemanticEdgeResult(
+      edges: dco_decode_list_semantic_edge(arr[0]),
+      count: dco_decode_usize(arr[1]),
+    );
+  }
+
+  @protected
+  SimilarityResult dco_decode_similarity_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return SimilarityResult(
+      id: dco_decode_String(arr[0]),
+      score: dco_decode_f_32(arr[1]),
+      textPreview: dco_decode_opt_String(arr[2]),
+    );
+  }
+
+  @protected
+  StopReason dco_decode_stop_reason(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return StopReason.values[raw as int];
+  }
+
+  @protected
+  StreamGraphStats dco_decode_stream_graph_stats(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return StreamGraphStats(
+      nodeCount: dco_decode_usize(arr[0]),
+      edgeCount: dco_decode_usize(arr[1]),
+      visibleCount: dco_decode_usize(arr[2]),
+    );
+  }
+
+  @protected
+  Summary dco_decode_summary(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return Summary(
+      summary: dco_decode_String(arr[0]),
+      chunkSummaries: dco_decode_list_String(arr[1]),
+      mergeLevels: dco_decode_u_32(arr[2]),
+    );
+  }
+
+  @protected
+  SummaryOptions dco_decode_summary_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return SummaryOptions(
+      style: dco_decode_summary_style(arr[0]),
+      maxWords: dco_decode_u_32(arr[1]),
+      chunkTokens: dco_decode_u_32(arr[2]),
+      modelId: dco_decode_opt_String(arr[3]),
+    );
+  }
+
+  @protected
+  SummaryStyle dco_decode_summary_style(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return SummaryStyle.values[raw as int];
+  }
+
+  @protected
+  TaskCategory dco_decode_task_category(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return TaskCategory.values[raw as int];
+  }
+
+  @protected
+  TokenCandidate dco_decode_token_candidate(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return TokenCandidate(
+      token: dco_decode_i_32(arr[0]),
+      text: dco_decode_String(arr[1]),
+      logprob: dco_decode_f_32(arr[2]),
+    );
+  }
+
+  @protected
+  TokenLogprob dco_decode_token_logprob(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return TokenLogprob(
+      token: dco_decode_i_32(arr[0]),
+      text: dco_decode_String(arr[1]),
+      logprob: dco_decode_f_32(arr[2]),
+      topAlternatives: dco_decode_list_token_candidate(arr[3]),
+    );
+  }
+
+  @protected
+  TokenizerInfo dco_decode_tokenizer_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return TokenizerInfo(
+      vocabSize: dco_decode_u_32(arr[0]),
+      nCtxTrain: dco_decode_u_32(arr[1]),
+      nCtx: dco_decode_u_32(arr[2]),
+      bosToken: dco_decode_i_32(arr[3]),
+      eosToken: dco_decode_i_32(arr[4]),
+      tokenizerModel: dco_decode_opt_String(arr[5]),
+    );
+  }
+
+  @protected
+  TranscriptSegment dco_decode_transcript_segment(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return TranscriptSegment(
+      text: dco_decode_String(arr[0]),
+      startTime: dco_decode_f_32(arr[1]),
+      endTime: dco_decode_f_32(arr[2]),
+    );
+  }
+
+  @protected
+  int dco_decode_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  BigInt dco_decode_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeU64(raw);
+  }
+
+  @protected
+  int dco_decode_u_8(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  void dco_decode_unit(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return;
+  }
+
+  @protected
+  BigInt dco_decode_usize(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeU64(raw);
+  }
+
+  @protected
+  VectorStoreStats dco_decode_vector_store_stats(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return VectorStoreStats(
+      entries: dco_decode_usize(arr[0]),
+      deadRows: dco_decode_usize(arr[1]),
+      dimension: dco_decode_usize(arr[2]),
+      fileBytes: dco_decode_u_64(arr[3]),
+    );
+  }
+
+  @protected
+  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final inner = sse_decode_String(deserializer);
+    return AnyhowException(inner);
+  }
+
+  @protected
+  RustStreamSink<AgentStep> sse_decode_StreamSink_agent_step_Dco(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<GenerationChunk> sse_decode_StreamSink_generation_chunk_Dco(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  String sse_decode_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final inner = sse_decode_list_prim_u_8_strict(deserializer);
+    return utf8.decoder.convert(inner);
+  }
+
+  @protected
+  AgentRun sse_decode_agent_run(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_answer = sse_decode_String(deserializer);
+    final var_stopReason = sse_decode_agent_stop_reason(deserializer);
+    final var_steps = sse_decode_list_agent_step(deserializer);
+    final var_messages = sse_decode_list_record_string_string(deserializer);
+    return AgentRun(
+      answer: var_answer,
+      stopReason: var_stopReason,
+      steps: var_steps,
+      messages: var_messages,
+    );
+  }
+
+  @protected
+  AgentStep sse_decode_agent_step(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_reply = sse_decode_String(deserializer);
+    final var_toolCalls = sse_decode_list_mcp_tool_call(deserializer);
+    final var_results = sse_decode_list_mcp_tool_result(deserializer);
+    return AgentStep(
+      reply: var_reply,
+      toolCalls: var_toolCalls,
+      results: var_results,
+    );
+  }
+
+  @protected
+  AgentStopReason sse_decode_agent_stop_reason(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final inner = sse_decode_i_32(deserializer);
+    return AgentStopReason.values[inner];
+  }
+
+  @protected
+  bool sse_decode_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint8() != 0;
+  }
+
+  @protected
+  ContextPolicy sse_decode_box_autoadd_context_policy(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_context_policy(deserializer));
+  }
+
+  @protected
+  ContextReport sse_decode_box_autoadd_context_report(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_context_report(deserializer));
+  }
+
+  @protected
+  DedupOptions sse_decode_box_autoadd_dedup_options(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_dedup_options(deserializer));
+  }
+
+  @protected
+  EmbeddingCacheStats sse_decode_box_autoadd_embedding_cache_stats(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_embedding_cache_stats(deserializer));
+  }
+
+  @protected
+  EmbeddingConfig sse_decode_box_autoadd_embedding_config(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_embedding_config(deserializer));
+  }
+
+  @protected
+  double sse_decode_box_autoadd_f_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_f_32(deserializer));
+  }
+
+  @protected
+  GenerationOptions sse_decode_box_autoadd_generation_options(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_generation_options(deserializer));
+  }
+
+  @protected
+  GenerationResult sse_decode_box_autoadd_generation_result(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_generation_result(deserializer));
+  }
+
+  @protected
+  HnswConfig sse_decode_box_autoadd_hnsw_config(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_hnsw_config(deserializer));
+  }
+
+  @protected
+  HybridOptions sse_decode_box_autoadd_hybrid_options(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_hybrid_options(deserializer));
+  }
+
+  @protected
+  int sse_decode_box_autoadd_i_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_i_32(deserializer));
+  }
+
+  @protected
+  PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_i_64(deserializer));
+  }
+
+  @protected
+  MCPToolCall sse_decode_box_autoadd_mcp_tool_call(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_mcp_tool_call(deserializer));
+  }
+
+  @protected
+  NoteMetadata sse_decode_box_autoadd_note_metadata(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_note_metadata(deserializer));
+  }
+
+  @protected
+  ReasoningOptions sse_decode_box_autoadd_reasoning_options(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_reasoning_options(deserializer));
+  }
+
+  @protected
+  SamplerConfig sse_decode_box_autoadd_sampler_config(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_sampler_config(deserializer));
+  }
+
+  @protected
+  SearchOptions sse_decode_box_autoadd_search_options(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_search_options(deserializer));
+  }
+
+  @protected
+  StopReason sse_decode_box_autoadd_stop_reason(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_stop_reason(deserializer));
+  }
+
+  @protected
+  SummaryOptions sse_decode_box_autoadd_summary_options(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_summary_options(deserializer));
+  }
+
+  @protected
+  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_32(deserializer));
+  }
+
+  @protected
+  BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_64(deserializer));
+  }
+
+  @protected
+  BigInt sse_decode_box_autoadd_usize(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_usize(deserializer));
+  }
+
+  @protected
+  ClusterAssignment sse_decode_cluster_assignment(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_id = sse_decode_String(deserializer);
+    final var_clusterId = sse_decode_usize(deserializer);
+    final var_color = sse_decode_String(deserializer);
+    return ClusterAssignment(
+      id: var_id,
+      clusterId: var_clusterId,
+      color: var_color,
+    );
+  }
+
+  @protected
+  ClusterInfo sse_decode_cluster_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_id = sse_decode_usize(deserializer);
+    final var_size = sse_decode_usize(deserializer);
+    final var_color = sse_decode_String(deserializer);
+    final var_centroid = sse_decode_opt_list_prim_f_32_strict(deserializer);
+    return ClusterInfo(
+      id: var_id,
+      size: var_size,
+      color: var_color,
+      centroid: var_centroid,
+    );
+  }
+
+  @protected
+  ClusteringResult sse_decode_clustering_result(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_assignments = sse_decode_list_cluster_assignment(deserializer);
+    final var_clusters = sse_decode_list_cluster_info(deserializer);
+    final var_k = sse_decode_usize(deserializer);
+    return ClusteringResult(
+      assignments: var_assignments,
+      clusters: var_clusters,
+      k: var_k,
+    );
+  }
+
+  @protected
+  ContextPolicy sse_decode_context_policy(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_strategy = sse_decode_context_strategy(deserializer);
+    final var_summaryMaxTokens = sse_decode_u_32(deserializer);
+    return ContextPolicy(
+      strategy: var_strategy,
+      summaryMaxTokens: var_summaryMaxTokens,
+    );
+  }
+
+  @protected
+  ContextReport sse_decode_context_report(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_contextSize = sse_decode_u_32(deserializer);
+    final var_promptTokens = sse_decode_u_32(deserializer);
+    final var_messageTokens = sse_decode_list_prim_u_32_strict(deserializer);
+    final var_dropped = sse_decode_list_prim_u_32_strict(deserializer);
+    final var_truncated = sse_decode_list_prim_u_32_strict(deserializer);
+    final var_summarized = sse_decode_list_prim_u_32_strict(deserializer);
+    final var_summary = sse_decode_opt_String(deserializer);
+    return ContextReport(
+      contextSize: var_contextSize,
+      promptTokens: var_promptTokens,
+      messageTokens: var_messageTokens,
+      dropped: var_dropped,
+      truncated: var_truncated,
+      summarized: var_summarized,
+      summary: var_summary,
+    );
+  }
+
+  @protected
+  ContextStrategy sse_decode_context_strategy(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final inner = sse_decode_i_32(deserializer);
+    return ContextStrategy.values[inner];
+  }
+
+  @protected
+  DedupDocument sse_decode_dedup_document(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_id = sse_decode_String(deserializer);
+    final var_text = sse_decode_String(deserializer);
+    final var_vector = sse_decode_opt_list_prim_f_32_strict(deserializer);
+    final var_createdAt = sse_decode_opt_box_autoadd_i_64(deserializer);
+    return DedupDocument(
+      id: var_id,
+      text: var_text,
+      vector: var_vector,
+      createdAt: var_createdAt,
+    );
+  }
+
+  @protected
+  DedupOptions sse_decode_dedup_options(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_shingleWords = sse_decode_usize(deserializer);
+    final var_numHashes = sse_decode_usize(deserializer);
+    final var_bands = sse_decode_usize(deserializer);
+    final var_minJaccard = sse_decode_f_32(deserializer);
+    final var_minCosine = sse_decode_f_32(deserializer);
+    final var_minSpanWords = sse_decode_usize(deserializer);
+    return DedupOptions(
+      shingleWords: var_shingleWords,
+      numHashes: var_numHashes,
+      bands: var_bands,
+      minJaccard: var_minJaccard,
+      minCosine: var_minCosine,
+      minSpanWords: var_minSpanWords,
+    );
+  }
+
+  @protected
+  DuplicateGroup sse_decode_duplicate_group(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_ids = sse_decode_list_String(deserializer);
+    final var_canonical = sse_decode_String(deserializer);
+    final var_canonicalNote = sse_decode_String(deserializer);
+    final var_pairs = sse_decode_list_duplicate_pair(deserializer);
+    return DuplicateGroup(
+      ids: var_ids,
+      canonical: var_canonical,
+      canonicalNote: var_canonicalNote,
+      pairs: var_pairs,
+    );
+  }
+
+  @protected
+  DuplicatePair sse_decode_duplicate_pair(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_a = sse_decode_String(deserializer);
+    final var_b = sse_decode_String(deserializer);
+    final var_jaccard = sse_decode_f_32(deserializer);
+    final var_cosine = sse_decode_opt_box_autoadd_f_32(deserializer);
+    final var_spans = sse_decode_list_duplicate_span(deserializer);
+    return DuplicatePair(
+      a: var_a,
+      b: var_b,
+      jaccard: var_jaccard,
+      cosine: var_cosine,
+      spans: var_spans,
+    );
+  }
+
+  @protected
+  DuplicateSpan sse_decode_duplicate_span(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_aStart = sse_decode_usize(deserializer);
+    final var_aEnd = sse_decode_usize(deserializer);
+    final var_bStart = sse_decode_usize(deserializer);
+    final var_bEnd = sse_decode_usize(deserializer);
+    final var_words = sse_decode_usize(deserializer);
+    return DuplicateSpan(
+      aStart: var_aStart,
+      aEnd: var_aEnd,
+      bStart: var_bStart,
+      bEnd: var_bEnd,
+      words: var_words,
+    );
+  }
+
+  @protected
+  EmbeddingCacheStats sse_decode_embedding_cache_stats(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_entries = sse_decode_usize(deserializer);
+    final var_staleRecords = sse_decode_usize(deserializer);
+    final var_fileBytes = sse_decode_u_64(deserializer);
+    return EmbeddingCacheStats(
+      entries: var_entries,
+      staleRecords: var_staleRecords,
+      fileBytes: var_fileBytes,
+    );
+  }
+
+  @protected
+  EmbeddingCluster sse_decode_embedding_cluster(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_ids = sse_decode_list_String(deserializer);
+    return EmbeddingCluster(ids: var_ids);
+  }
+
+  @protected
+  EmbeddingConfig sse_decode_embedding_config(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_nGpuLayers = sse_decode_u_32(deserializer);
+    final var_nThreads = sse_decode_i_32(deserializer);
+    final var_pooling = sse_decode_pooling_mode(deserializer);
+    final var_chunkTokens = sse_decode_u_32(deserializer);
+    final var_chunkOverlap = sse_decode_u_32(deserializer);
+    final var_maxSequences = sse_decode_u_32(deserializer);
+    final var_queryPrefix = sse_decode_String(deserializer);
+    final var_documentPrefix = sse_decode_String(deserializer);
+    return EmbeddingConfig(
+      nGpuLayers: var_nGpuLayers,
+      nThreads: var_nThreads,
+      pooling: var_pooling,
+      chunkTokens: var_chunkTokens,
+      chunkOverlap: var_chunkOverlap,
+      maxSequences: var_maxSequences,
+      queryPrefix: var_queryPrefix,
+      documentPrefix: var_documentPrefix,
+    );
+  }
+
+  @protected
+  EmbeddingEntry sse_decode_embedding_entry(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_id = sse_decode_String(deserializer);
+    final var_vector = sse_decode_list_prim_f_32_strict(deserializer);
+    final var_textPreview = sse_decode_opt_String(deserializer);
+    final var_metadata = sse_decode_note_metadata(deserializer);
+    return EmbeddingEntry(
+      id: var_id,
+      vector: var_vector,
+      textPreview: var_textPreview,
+      metadata: var_metadata,
+    );
+  }
+
+  @protected
+  EmbeddingKind sse_decode_embedding_kind(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final inner = sse_decode_i_32(deserializer);
+    return EmbeddingKind.values[inner];
+  }
+
+  @protected
+  double sse_decode_f_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getFloat32();
+  }
+
+  @protected
+  FusionMode sse_decode_fusion_mode(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final inner = sse_decode_i_32(deserializer);
+    return FusionMode.values[inner];
+  }
+
+  @protected
+  GenerationChunk sse_decode_generation_chunk(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_text = sse_decode_String(deserializer);
+    final var_reasoning = sse_decode_String(deserializer);
+    final var_stopReason = sse_decode_opt_box_autoadd_stop_reason(deserializer);
+    return GenerationChunk(
+      text: var_text,
+      reasoning: var_reasoning,
+      stopReason: var_stopReason,
+    );
+  }
+
+  @protected
+  GenerationOptions sse_decode_generation_options(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_maxTokens = sse_decode_opt_box_autoadd_u_32(deserializer);
+    final var_stopSequences = sse_decode_list_String(deserializer);
+    final var_sampler = sse_decode_opt_box_autoadd_sampler_config(deserializer);
+    final var_grammar = sse_decode_opt_String(deserializer);
+    final var_jsonSchema = sse_decode_opt_String(deserializer);
+    final var_modelId = sse_decode_opt_String(deserializer);
+    final var_context = sse_decode_opt_box_autoadd_context_policy(deserializer);
+    final var_reasoning =
+        sse_decode_opt_box_autoadd_reasoning_options(deserializer);
+    final var_logprobs = sse_decode_opt_box_autoadd_u_32(deserializer);
+    return GenerationOptions(
+      maxTokens: var_maxTokens,
+      stopSequences: var_stopSequences,
+      sampler: var_sampler,
+      grammar: var_grammar,
+      jsonSchema: var_jsonSchema,
+      modelId: var_modelId,
+      context: var_context,
+      reasoning: var_reasoning,
+      logprobs: var_logprobs,
+    );
+  }
+
+  @protected
+  GenerationPoll sse_decode_generation_poll(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_text = sse_decode_String(deserializer);
+    final var_reasoning = sse_decode_String(deserializer);
+    final var_result =
+        sse_decode_opt_box_autoadd_generation_result(deserializer);
+    return GenerationPoll(
+      text: var_text,
+      reasoning: var_reasoning,
+      result: var_result,
+    );
+  }
+
+  @protected
+  GenerationResult sse_decode_generation_result(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_text = sse_decode_String(deserializer);
+    final var_reasoning = sse_decode_opt_String(deserializer);
+    final var_reasoningTokens = sse_decode_u_32(deserializer);
+    final var_stopReason = sse_decode_stop_reason(deserializer);
+    final var_tokensGenerated = sse_decode_u_32(deserializer);
+    final var_context = sse_decode_opt_box_autoadd_context_report(deserializer);
+    final var_logprobs = sse_decode_opt_list_token_logprob(deserializer);
+    return GenerationResult(
+      text: var_text,
+      reasoning: var_reasoning,
+      reasoningTokens: var_reasoningTokens,
+      stopReason: var_stopReason,
+      tokensGenerated: var_tokensGenerated,
+      context: var_context,
+      logprobs: var_logprobs,
+    );
+  }
+
+  @protected
+  GgufMetadata sse_decode_gguf_metadata(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_version = sse_decode_u_32(deserializer);
+    final var_architecture = sse_decode_opt_String(deserializer);
+    final var_name = sse_decode_opt_String(deserializer);
+    final var_chatTemplate = sse_decode_opt_String(deserializer);
+    final var_contextLength = sse_decode_opt_box_autoadd_u_64(deserializer);
+    final var_embeddingLength = sse_decode_opt_box_autoadd_u_64(deserializer);
+    final var_blockCount = sse_decode_opt_box_autoadd_u_64(deserializer);
+    final var_poolingType = sse_decode_opt_box_autoadd_u_32(deserializer);
+    final var_quantization = sse_decode_opt_String(deserializer);
+    final var_parameterCount = sse_decode_u_64(deserializer);
+    final var_tensorCount = sse_decode_u_64(deserializer);
+    final var_tokenizerModel = sse_decode_opt_String(deserializer);
+    final var_vocabSize = sse_decode_opt_box_autoadd_u_64(deserializer);
+    final var_bosTokenId = sse_decode_opt_box_autoadd_u_32(deserializer);
+    final var_eosTokenId = sse_decode_opt_box_autoadd_u_32(deserializer);
+    final var_fileSize = sse_decode_u_64(deserializer);
+    return GgufMetadata(
+      version: var_version,
+      architecture: var_architecture,
+      name: var_name,
+      chatTemplate: var_chatTemplate,
+      contextLength: var_contextLength,
+      embeddingLength: var_embeddingLength,
+      blockCount: var_blockCount,
+      poolingType: var_poolingType,
+      quantization: var_quantization,
+      parameterCount: var_parameterCount,
+      tensorCount: var_tensorCount,
+      tokenizerModel: var_tokenizerModel,
+      vocabSize: var_vocabSize,
+      bosTokenId: var_bosTokenId,
+      eosTokenId: var_eosTokenId,
+      fileSize: var_fileSize,
+    );
+  }
+
+  @protected
+  GraphEdge sse_decode_graph_edge(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_source = sse_decode_String(deserializer);
+    final var_target = sse_decode_String(deserializer);
+    final var_weight = sse_decode_f_32(deserializer);
+    final var_edgeType = sse_decode_String(deserializer);
+    return GraphEdge(
+      source: var_source,
+      target: var_target,
+      weight: var_weight,
+      edgeType: var_edgeType,
+    );
+  }
+
+  @protected
+  GraphNode sse_decode_graph_node(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_id = sse_decode_String(deserializer);
+    final var_label = sse_decode_String(deserializer);
+    final var_nodeType = sse_decode_String(deserializer);
+    final var_x = sse_decode_f_32(deserializer);
+    final var_y = sse_decode_f_32(deserializer);
+    final var_color = sse_decode_opt_String(deserializer);
+    final var_metadata = sse_decode_opt_String(deserializer);
+    return GraphNode(
+      id: var_id,
+      label: var_label,
+      nodeType: var_nodeType,
+      x: var_x,
+      y: var_y,
+      color: var_color,
+      metadata: var_metadata,
+    );
+  }
+
+  @protected
+  GraphState sse_decode_graph_state(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_nodes = sse_decode_list_graph_node(deserializer);
+    final var_edges = sse_decode_list_graph_edge(deserializer);
+    return GraphState(nodes: var_nodes, edges: var_edges);
+  }
+
+  @protected
+  HnswConfig sse_decode_hnsw_config(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_m = sse_decode_u_32(deserializer);
+    final var_efConstruction = sse_decode_u_32(deserializer);
+    final var_efSearch = sse_decode_u_32(deserializer);
+    return HnswConfig(
+      m: var_m,
+      efConstruction: var_efConstruction,
+      efSearch: var_efSearch,
+    );
+  }
+
+  @protected
+  HybridHit sse_decode_hybrid_hit(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_id = sse_decode_String(deserializer);
+    final var_score = sse_decode_f_32(deserializer);
+    final var_semanticScore = sse_decode_opt_box_autoadd_f_32(deserializer);
+    final var_semanticRank = sse_decode_opt_box_autoadd_u_32(deserializer);
+    final var_lexicalScore = sse_decode_opt_box_autoadd_f_32(deserializer);
+    final var_lexicalRank = sse_decode_opt_box_autoadd_u_32(deserializer);
+    final var_matchedTerms = sse_decode_list_String(deserializer);
+    final var_textPreview = sse_decode_opt_String(deserializer);
+    return HybridHit(
+      id: var_id,
+      score: var_score,
+      semanticScore: var_semanticScore,
+      semanticRank: var_semanticRank,
+      lexicalScore: var_lexicalScore,
+      lexicalRank: var_lexicalRank,
+      matchedTerms: var_matchedTerms,
+      textPreview: var_textPreview,
+    );
+  }
+
+  @protected
+  HybridOptions sse_decode_hybrid_options(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_fusion = sse_decode_fusion_mode(deserializer);
+    final var_semanticWeight = sse_decode_f_32(deserializer);
+    final var_rrfK = sse_decode_f_32(deserializer);
+    final var_candidates = sse_decode_usize(deserializer);
+    final var_minSemanticScore = sse_decode_f_32(deserializer);
+    return HybridOptions(
+      fusion: var_fusion,
+      semanticWeight: var_semanticWeight,
+      rrfK: var_rrfK,
+      candidates: var_candidates,
+      minSemanticScore: var_minSemanticScore,
+    );
+  }
+
+  @protected
+  int sse_decode_i_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getInt32();
+  }
+
+  @protected
+  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getPlatformInt64();
+  }
+
+  @protected
+  KnowledgeGraphAnalysis sse_decode_knowledge_graph_analysis(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_clustering = sse_decode_clustering_result(deserializer);
+    final var_semanticEdges = sse_decode_semantic_edge_result(deserializer);
+    return KnowledgeGraphAnalysis(
+      clustering: var_clustering,
+      semanticEdges: var_semanticEdges,
+    );
+  }
+
+  @protected
+  List<String> sse_decode_list_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    final len_ = sse_decode_i_32(deserializer);
+    final ans_ = <String>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_String(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<AgentStep> sse_decode_list_agent_step(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    final len_ = sse_decode_i_32(deserializer);
+    final ans_ = <AgentStep>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_agent_step(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ClusterAssignment> sse_decode_list_cluster_assignment(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    final len_ = sse_decode_i_32(deserializer);
+    final ans_ = <ClusterAssignment>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_cluster_assignment(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ClusterInfo> sse_decode_list_cluster_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    final len_ = sse_decode_i_32(deserializer);
+    final ans_ = <ClusterInfo>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_cluster_info(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<DedupDocument> sse_decode_list_dedup_document(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    final len_ = sse_decode_i_32(deserializer);
+    final ans_ = <DedupDocument>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_dedup_document(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<DuplicateGroup> sse_decode_list_duplicate_group(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    final len_ = sse_decode_i_32(deserializer);
+    final ans_ = <DuplicateGroup>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_duplicate_group(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<DuplicatePair> sse_decode_list_duplicate_pair(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    final len_ = sse_decode_i_32(deserializer);
+    final ans_ = <DuplicatePair>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_duplicate_pair(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<DuplicateSpan> sse_decode_list_duplicate_span(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    final len_ = sse_decode_i_32(deserializer);
+    final ans_ = <DuplicateSpan>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_duplicate_span(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<EmbeddingCluster> sse_decode_list_embedding_cluster(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    final len_ = sse_decode_i_32(deserializer);
+    final ans_ = <EmbeddingCluster>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_embedding_cluster(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<EmbeddingEntry> sse_decode_list_embedding_entry(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    final len_ = sse_decode_i_32(deserializer);
+    final ans_ = <EmbeddingEntry>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_embedding_entry(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<GraphEdge> sse_decode_list_graph_edge(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    final len_ = sse_decode_i_32(deserializer);
+    final ans_ = <GraphEdge>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_graph_edge(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<GraphNode> sse_decode_list_graph_node(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    final len_ = sse_decode_i_32(deserializer);
+    final ans_ = <GraphNode>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_graph_node(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<HybridHit> sse_decode_list_hybrid_hit(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    final len_ = sse_decode_i_32(deserializer);
+    final ans_ = <HybridHit>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_hybrid_hit(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<Float32List> sse_decode_list_list_prim_f_32_strict(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    final len_ = sse_decode_i_32(deserializer);
+    final ans_ = <Float32List>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_list_prim_f_32_strict(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<LoadedModelInfo> sse_decode_list_loaded_model_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    final len_ = sse_decode_i_32(deserializer);
+    final ans_ = <LoadedModelInfo>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_loaded_model_info(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<MarkdownChunk> sse_decode_list_markdown_chunk(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    final len_ = sse_decode_i_32(deserializer);
+    final ans_ = <MarkdownChunk>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_markdown_chunk(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<MCPParameter> sse_decode_list_mcp_parameter(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    final len_ = sse_decode_i_32(deserializer);
+    final ans_ = <MCPParameter>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_mcp_parameter(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<MCPTool> sse_decode_list_mcp_tool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    final len_ = sse_decode_i_32(deserializer);
+    final ans_ = <MCPTool>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_mcp_tool(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<MCPToolCall> sse_decode_list_mcp_tool_call(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    final len_ = sse_decode_i_32(deserializer);
+    final ans_ = <MCPToolCall>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_mcp_tool_call(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<MCPToolResult> sse_decode_list_mcp_tool_result(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    final len_ = sse_decode_i_32(deserializer);
+    final ans_ = <MCPToolResult>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_mcp_tool_result(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<NodePosition> sse_decode_list_node_position(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    final len_ = sse_decode_i_32(deserializer);
+    final ans_ = <NodePosition>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_node_position(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<double> sse_decode_list_prim_f_32_loose(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getFloat32List(len_);
+  }
+
+  @protected
+  Float32List sse_decode_list_prim_f_32_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getFloat32List(len_);
+  }
+
+  @protected
+  List<int> sse_decode_list_prim_i_32_loose(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getInt32List(len_);
+  }
+
+  @protected
+  Int32List sse_decode_list_prim_i_32_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getInt32List(len_);
+  }
+
+  @protected
+  Uint32List sse_decode_list_prim_u_32_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint32List(len_);
+  }
+
+  @protected
+  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint8List(len_);
+  }
+
+  @protected
+  List<RagSource> sse_decode_list_rag_source(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    final len_ = sse_decode_i_32(deserializer);
+    final ans_ = <RagSource>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_rag_source(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<(String, String)> sse_decode_list_record_string_string(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    final len_ = sse_decode_i_32(deserializer);
+    final ans_ = <(String, String)>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_record_string_string(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ReplySpan> sse_decode_list_reply_span(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    final len_ = sse_decode_i_32(deserializer);
+    final ans_ = <ReplySpan>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_reply_span(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<SemanticEdge> sse_decode_list_semantic_edge(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    final len_ = sse_decode_i_32(deserializer);
+    final ans_ = <SemanticEdge>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_semantic_edge(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<SimilarityResult> sse_decode_list_similarity_result(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    final len_ = sse_decode_i_32(deserializer);
+    final ans_ = <SimilarityResult>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_similarity_result(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<TokenCandidate> sse_decode_list_token_candidate(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    final len_ = sse_decode_i_32(deserializer);
+    final ans_ = <TokenCandidate>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_token_candidate(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<TokenLogprob> sse_decode_list_token_logprob(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    final len_ = sse_decode_i_32(deserializer);
+    final ans_ = <TokenLogprob>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_token_logprob(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<TranscriptSegment> sse_decode_list_transcript_segment(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    final len_ = sse_decode_i_32(deserializer);
+    final ans_ = <TranscriptSegment>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_transcript_segment(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  LoadedModelInfo sse_decode_loaded_model_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_modelId = sse_decode_String(deserializer);
+    final var_modelPath = sse_decode_String(deserializer);
+    final var_sizeBytes = sse_decode_u_64(deserializer);
+    final var_isDefault = sse_decode_bool(deserializer);
+    return LoadedModelInfo(
+      modelId: var_modelId,
+      modelPath: var_modelPath,
+      sizeBytes: var_sizeBytes,
+      isDefault: var_isDefault,
+    );
+  }
+
+  @protected
+  MarkdownChunk sse_decode_markdown_chunk(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_index = sse_decode_usize(deserializer);
+    final var_headingPath = sse_decode_list_String(deserializer);
+    final var_start = sse_decode_usize(deserializer);
+    final var_end = sse_decode_usize(deserializer);
+    final var_text = sse_decode_String(deserializer);
+    final var_tokenCount = sse_decode_u_32(deserializer);
+    return MarkdownChunk(
+      index: var_index,
+      headingPath: var_headingPath,
+      start: var_start,
+      end: var_end,
+      text: var_text,
+      tokenCount: var_tokenCount,
+    );
+  }
+
+  @protected
+  MCPParamType sse_decode_mcp_param_type(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final inner = sse_decode_i_32(deserializer);
+    return MCPParamType.values[inner];
+  }
+
+  @protected
+  MCPParameter sse_decode_mcp_parameter(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_name = sse_decode_String(deserializer);
+    final var_description = sse_decode_String(deserializer);
+    final var_paramType = sse_decode_mcp_param_type(deserializer);
+    final var_required_ = sse_decode_bool(deserializer);
+    return MCPParameter(
+      name: var_name,
+      description: var_description,
+      paramType: var_paramType,
+      required_: var_required_,
+    );
+  }
+
+  @protected
+  MCPTool sse_decode_mcp_tool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final inner = sse_decode_i_32(deserializer);
+    return MCPTool.values[inner];
+  }
+
+  @protected
+  MCPToolCall sse_decode_mcp_tool_call(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_tool = sse_decode_String(deserializer);
+    final var_parametersJson = sse_decode_String(deserializer);
+    final var_description = sse_decode_String(deserializer);
+    return MCPToolCall(
+      tool: var_tool,
+      parametersJson: var_parametersJson,
+      description: var_description,
+    );
+  }
+
+  @protected
+  MCPToolResult sse_decode_mcp_tool_result(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_success = sse_decode_bool(deserializer);
+    final var_result = sse_decode_String(deserializer);
+    final var_tool = sse_decode_String(deserializer);
+    return MCPToolResult(
+      success: var_success,
+      result: var_result,
+      tool: var_tool,
+    );
+  }
+
+  @protected
+  NodePosition sse_decode_node_position(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_id = sse_decode_String(deserializer);
+    final var_x = sse_decode_f_32(deserializer);
+    final var_y = sse_decode_f_32(deserializer);
+    final var_radius = sse_decode_f_32(deserializer);
+    final var_color = sse_decode_u_32(deserializer);
+    final var_nodeType = sse_decode_String(deserializer);
+    return NodePosition(
+      id: var_id,
+      x: var_x,
+      y: var_y,
+      radius: var_radius,
+      color: var_color,
+      nodeType: var_nodeType,
+    );
+  }
+
+  @protected
+  NoteMetadata sse_decode_note_metadata(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_folder = sse_decode_opt_String(deserializer);
+    final var_tags = sse_decode_list_String(deserializer);
+    final var_createdAt = sse_decode_opt_box_autoadd_i_64(deserializer);
+    final var_modifiedAt = sse_decode_opt_box_autoadd_i_64(deserializer);
+    final var_noteType = sse_decode_opt_String(deserializer);
+    final var_chunkStart = sse_decode_opt_box_autoadd_usize(deserializer);
+    final var_chunkEnd = sse_decode_opt_box_autoadd_usize(deserializer);
+    return NoteMetadata(
+      folder: var_folder,
+      tags: var_tags,
+      createdAt: var_createdAt,
+      modifiedAt: var_modifiedAt,
+      noteType: var_noteType,
+      chunkStart: var_chunkStart,
+      chunkEnd: var_chunkEnd,
+    );
+  }
+
+  @protected
+  String? sse_decode_opt_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_String(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  ContextPolicy? sse_decode_opt_box_autoadd_context_policy(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_context_policy(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  ContextReport? sse_decode_opt_box_autoadd_context_report(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_context_report(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  DedupOptions? sse_decode_opt_box_autoadd_dedup_options(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_dedup_options(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  EmbeddingCacheStats? sse_decode_opt_box_autoadd_embedding_cache_stats(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_embedding_cache_stats(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  EmbeddingConfig? sse_decode_opt_box_autoadd_embedding_config(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_embedding_config(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  double? sse_decode_opt_box_autoadd_f_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_f_32(deserializer));
+    } else {
+      return null;
+    }
   }
 
   @protected
-  int dco_decode_box_autoadd_u_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  GenerationResult? sse_decode_opt_box_autoadd_generation_result(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_generation_result(deserializer));
+    } else {
+      return null;
+    }
   }
 
   @protected
-  BigInt dco_decode_box_autoadd_usize(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_usize(raw);
+  HnswConfig? sse_decode_opt_box_autoadd_hnsw_config(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_hnsw_config(deserializer));
+    } else {
+      return null;
+    }
   }
 
   @protected
-  ClusterAssignment dco_decode_cluster_assignment(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 3)
-      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
-    return ClusterAssignment(
-      id: dco_decode_String(arr[0]),
-      clusterId: dco_decode_usize(arr[1]),
-      color: dco_decode_String(arr[2]),
-    );
+  HybridOptions? sse_decode_opt_box_autoadd_hybrid_options(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_hybrid_options(deserializer));
+    } else {
+      return null;
+    }
   }
 
   @protected
-  ClusterInfo dco_decode_cluster_info(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 4)
-      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
-    return ClusterInfo(
-      id: dco_decode_usize(arr[0]),
-      size: dco_decode_usize(arr[1]),
-      color: dco_decode_String(arr[2]),
-      centroid: dco_decode_opt_list_prim_f_32_strict(arr[3]),
-    );
+  int? sse_decode_opt_box_autoadd_i_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_i_32(deserializer));
+    } else {
+      return null;
+    }
   }
 
   @protected
-  ClusteringResult dco_decode_clustering_result(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 3)
-      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
-    return ClusteringResult(
-      assignments: dco_decode_list_cluster_assignment(arr[0]),
-      clusters: dco_decode_list_cluster_info(arr[1]),
-      k: dco_decode_usize(arr[2]),
+  PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_i_64(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  NoteMetadata? sse_decode_opt_box_autoadd_note_metadata(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_note_metadata(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  ReasoningOptions? sse_decode_opt_box_autoadd_reasoning_options(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_reasoning_options(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  SamplerConfig? sse_decode_opt_box_autoadd_sampler_config(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_sampler_config(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  StopReason? sse_decode_opt_box_autoadd_stop_reason(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_stop_reason(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  SummaryOptions? sse_decode_opt_box_autoadd_summary_options(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_summary_options(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_32(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_64(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  BigInt? sse_decode_opt_box_autoadd_usize(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_usize(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  List<String>? sse_decode_opt_list_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_list_String(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  List<Float32List>? sse_decode_opt_list_list_prim_f_32_strict(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_list_list_prim_f_32_strict(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  Float32List? sse_decode_opt_list_prim_f_32_strict(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_list_prim_f_32_strict(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  List<(String, String)>? sse_decode_opt_list_record_string_string(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_list_record_string_string(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  List<TokenLogprob>? sse_decode_opt_list_token_logprob(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_list_token_logprob(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  ParsedReply sse_decode_parsed_reply(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_toolCalls = sse_decode_list_mcp_tool_call(deserializer);
+    final var_spans = sse_decode_list_reply_span(deserializer);
+    final var_text = sse_decode_String(deserializer);
+    return ParsedReply(
+      toolCalls: var_toolCalls,
+      spans: var_spans,
+      text: var_text,
     );
   }
 
   @protected
-  EmbeddingCluster dco_decode_embedding_cluster(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 1)
-      throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
-    return EmbeddingCluster(ids: dco_decode_list_String(arr[0]));
+  PoolingMode sse_decode_pooling_mode(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final inner = sse_decode_i_32(deserializer);
+    return PoolingMode.values[inner];
   }
 
   @protected
-  EmbeddingEntry dco_decode_embedding_entry(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 3)
-      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
-    return EmbeddingEntry(
-      id: dco_decode_String(arr[0]),
-      vector: dco_decode_list_prim_f_32_strict(arr[1]),
-      textPreview: dco_decode_opt_String(arr[2]),
+  QuantizationKind sse_decode_quantization_kind(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final inner = sse_decode_i_32(deserializer);
+    return QuantizationKind.values[inner];
+  }
+
+  @protected
+  RagAnswer sse_decode_rag_answer(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_answer = sse_decode_String(deserializer);
+    final var_sources = sse_decode_list_rag_source(deserializer);
+    final var_skipped = sse_decode_usize(deserializer);
+    return RagAnswer(
+      answer: var_answer,
+      sources: var_sources,
+      skipped: var_skipped,
+    );
+  }
+
+  @protected
+  RagSource sse_decode_rag_source(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_citation = sse_decode_u_32(deserializer);
+    final var_noteId = sse_decode_String(deserializer);
+    final var_chunkOffset = sse_decode_opt_box_autoadd_usize(deserializer);
+    final var_chunkIndex = sse_decode_opt_box_autoadd_usize(deserializer);
+    final var_score = sse_decode_f_32(deserializer);
+    final var_text = sse_decode_String(deserializer);
+    final var_cited = sse_decode_bool(deserializer);
+    return RagSource(
+      citation: var_citation,
+      noteId: var_noteId,
+      chunkOffset: var_chunkOffset,
+      chunkIndex: var_chunkIndex,
+      score: var_score,
+      text: var_text,
+      cited: var_cited,
     );
   }
 
   @protected
-  double dco_decode_f_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as double;
+  ReasoningOptions sse_decode_reasoning_options(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_enabled = sse_decode_bool(deserializer);
+    final var_openTag = sse_decode_String(deserializer);
+    final var_closeTag = sse_decode_String(deserializer);
+    final var_thinkingBudget = sse_decode_opt_box_autoadd_u_32(deserializer);
+    return ReasoningOptions(
+      enabled: var_enabled,
+      openTag: var_openTag,
+      closeTag: var_closeTag,
+      thinkingBudget: var_thinkingBudget,
+    );
   }
 
   @protected
-  GraphEdge dco_decode_graph_edge(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 4)
-      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
-    return GraphEdge(
-      source: dco_decode_String(arr[0]),
-      target: dco_decode_String(arr[1]),
-      weight: dco_decode_f_32(arr[2]),
-      edgeType: dco_decode_String(arr[3]),
+  RecallReport sse_decode_recall_report(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_queries = sse_decode_usize(deserializer);
+    final var_topK = sse_decode_usize(deserializer);
+    final var_recall = sse_decode_f_32(deserializer);
+    final var_rescoredRecall = sse_decode_f_32(deserializer);
+    final var_f32Bytes = sse_decode_usize(deserializer);
+    final var_quantizedBytes = sse_decode_usize(deserializer);
+    return RecallReport(
+      queries: var_queries,
+      topK: var_topK,
+      recall: var_recall,
+      rescoredRecall: var_rescoredRecall,
+      f32Bytes: var_f32Bytes,
+      quantizedBytes: var_quantizedBytes,
     );
   }
 
   @protected
-  GraphNode dco_decode_graph_node(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 7)
-      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
-    return GraphNode(
-      id: dco_decode_String(arr[0]),
-      label: dco_decode_String(arr[1]),
-      nodeType: dco_decode_String(arr[2]),
-      x: dco_decode_f_32(arr[3]),
-      y: dco_decode_f_32(arr[4]),
-      color: dco_decode_opt_String(arr[5]),
-      metadata: dco_decode_opt_String(arr[6]),
-    );
+  (String, String) sse_decode_record_string_string(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_field0 = sse_decode_String(deserializer);
+    final var_field1 = sse_decode_String(deserializer);
+    return (var_field0, var_field1);
   }
 
   @protected
-  GraphState dco_decode_graph_state(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2)
-      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return GraphState(
-      nodes: dco_decode_list_graph_node(arr[0]),
-      edges: dco_decode_list_graph_edge(arr[1]),
+  ReplySpan sse_decode_reply_span(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_start = sse_decode_usize(deserializer);
+    final var_end = sse_decode_usize(deserializer);
+    final var_kind = sse_decode_reply_span_kind(deserializer);
+    final var_callCount = sse_decode_usize(deserializer);
+    return ReplySpan(
+      start: var_start,
+      end: var_end,
+      kind: var_kind,
+      callCount: var_callCount,
     );
   }
 
   @protected
-  int dco_decode_i_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  ReplySpanKind sse_decode_reply_span_kind(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final inner = sse_decode_i_32(deserializer);
+    return ReplySpanKind.values[inner];
   }
 
   @protected
-  KnowledgeGraphAnalysis dco_decode_knowledge_graph_analysis(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2)
-      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return KnowledgeGraphAnalysis(
-      clustering: dco_decode_clustering_result(arr[0]),
-      semanticEdges: dco_decode_semantic_edge_result(arr[1]),
+  SamplerConfig sse_decode_sampler_config(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_temperature = sse_decode_f_32(deserializer);
+    final var_topK = sse_decode_i_32(deserializer);
+    final var_topP = sse_decode_f_32(deserializer);
+    final var_minP = sse_decode_f_32(deserializer);
+    final var_repeatPenalty = sse_decode_f_32(deserializer);
+    final var_frequencyPenalty = sse_decode_f_32(deserializer);
+    final var_presencePenalty = sse_decode_f_32(deserializer);
+    final var_penaltyLastN = sse_decode_i_32(deserializer);
+    final var_seed = sse_decode_opt_box_autoadd_u_32(deserializer);
+    final var_greedy = sse_decode_bool(deserializer);
+    return SamplerConfig(
+      temperature: var_temperature,
+      topK: var_topK,
+      topP: var_topP,
+      minP: var_minP,
+      repeatPenalty: var_repeatPenalty,
+      frequencyPenalty: var_frequencyPenalty,
+      presencePenalty: var_presencePenalty,
+      penaltyLastN: var_penaltyLastN,
+      seed: var_seed,
+      greedy: var_greedy,
     );
   }
 
   @protected
-  List<String> dco_decode_list_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_String).toList();
+  SearchOptions sse_decode_search_options(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_topK = sse_decode_usize(deserializer);
+    final var_threshold = sse_decode_f_32(deserializer);
+    final var_filter = sse_decode_opt_String(deserializer);
+    final var_mmrLambda = sse_decode_opt_box_autoadd_f_32(deserializer);
+    final var_mmrCandidates = sse_decode_usize(deserializer);
+    return SearchOptions(
+      topK: var_topK,
+      threshold: var_threshold,
+      filter: var_filter,
+      mmrLambda: var_mmrLambda,
+      mmrCandidates: var_mmrCandidates,
+    );
   }
 
   @protected
-  List<ClusterAssignment> dco_decode_list_cluster_assignment(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_cluster_assignment).toList();
+  SemanticEdge sse_decode_semantic_edge(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_source = sse_decode_String(deserializer);
+    final var_target = sse_decode_String(deserializer);
+    final var_similarity = sse_decode_f_32(deserializer);
+    final var_isGhost = sse_decode_bool(deserializer);
+    return SemanticEdge(
+      source: var_source,
+      target: var_target,
+      similarity: var_similarity,
+      isGhost: var_isGhost,
+    );
   }
 
   @protected
-  List<ClusterInfo> dco_decode_list_cluster_info(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_cluster_info).toList();
+  SemanticEdgeResult sse_decode_semantic_edge_result(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_edges = sse_decode_list_semantic_edge(deserializer);
+    final var_count = sse_decode_usize(deserializer);
+    return SemanticEdgeResult(edges: var_edges, count: var_count);
   }
 
   @protected
-  List<EmbeddingCluster> dco_decode_list_embedding_cluster(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_embedding_cluster).toList();
+  SimilarityResult sse_decode_similarity_result(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_id = sse_decode_String(deserializer);
+    final var_score = sse_decode_f_32(deserializer);
+    final var_textPreview = sse_decode_opt_String(deserializer);
+    return SimilarityResult(
+      id: var_id,
+      score: var_score,
+      textPreview: var_textPreview,
+    );
   }
 
   @protected
-  List<EmbeddingEntry> dco_decode_list_embedding_entry(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_embedding_entry).toList();
+  StopReason sse_decode_stop_reason(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final inner = sse_decode_i_32(deserializer);
+    return StopReason.values[inner];
   }
 
   @protected
-  List<GraphEdge> dco_decode_list_graph_edge(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_graph_edge).toList();
+  StreamGraphStats sse_decode_stream_graph_stats(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_nodeCount = sse_decode_usize(deserializer);
+    final var_edgeCount = sse_decode_usize(deserializer);
+    final var_visibleCount = sse_decode_usize(deserializer);
+    return StreamGraphStats(
+      nodeCount: var_nodeCount,
+      edgeCount: var_edgeCount,
+      visibleCount: var_visibleCount,
+    );
   }
 
   @protected
-  List<GraphNode> dco_decode_list_graph_node(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_graph_node).toList();
+  Summary sse_decode_summary(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_summary = sse_decode_String(deserializer);
+    final var_chunkSummaries = sse_decode_list_String(deserializer);
+    final var_mergeLevels = sse_decode_u_32(deserializer);
+    return Summary(
+      summary: var_summary,
+      chunkSummaries: var_chunkSummaries,
+      mergeLevels: var_mergeLevels,
+    );
   }
 
   @protected
-  List<MCPParameter> dco_decode_list_mcp_parameter(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_mcp_parameter).toList();
+  SummaryOptions sse_decode_summary_options(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_style = sse_decode_summary_style(deserializer);
+    final var_maxWords = sse_decode_u_32(deserializer);
+    final var_chunkTokens = sse_decode_u_32(deserializer);
+    final var_modelId = sse_decode_opt_String(deserializer);
+    return SummaryOptions(
+      style: var_style,
+      maxWords: var_maxWords,
+      chunkTokens: var_chunkTokens,
+      modelId: var_modelId,
+    );
   }
 
   @protected
-  List<MCPTool> dco_decode_list_mcp_tool(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_mcp_tool).toList();
+  SummaryStyle sse_decode_summary_style(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final inner = sse_decode_i_32(deserializer);
+    return SummaryStyle.values[inner];
   }
 
   @protected
-  List<NodePosition> dco_decode_list_node_position(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_node_position).toList();
+  TaskCategory sse_decode_task_category(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final inner = sse_decode_i_32(deserializer);
+    return TaskCategory.values[inner];
   }
 
   @protected
-  List<double> dco_decode_list_prim_f_32_loose(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as List<double>;
+  TokenCandidate sse_decode_token_candidate(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_token = sse_decode_i_32(deserializer);
+    final var_text = sse_decode_String(deserializer);
+    final var_logprob = sse_decode_f_32(deserializer);
+    return TokenCandidate(
+      token: var_token,
+      text: var_text,
+      logprob: var_logprob,
+    );
   }
 
   @protected
-  Float32List dco_decode_list_prim_f_32_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Float32List;
+  TokenLogprob sse_decode_token_logprob(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_token = sse_decode_i_32(deserializer);
+    final var_text = sse_decode_String(deserializer);
+    final var_logprob = sse_decode_f_32(deserializer);
+    final var_topAlternatives = sse_decode_list_token_candidate(deserializer);
+    return TokenLogprob(
+      token: var_token,
+      text: var_text,
+      logprob: var_logprob,
+      topAlternatives: var_topAlternatives,
+    );
   }
 
   @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Uint8List;
+  TokenizerInfo sse_decode_tokenizer_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_vocabSize = sse_decode_u_32(deserializer);
+    final var_nCtxTrain = sse_decode_u_32(deserializer);
+    final var_nCtx = sse_decode_u_32(deserializer);
+    final var_bosToken = sse_decode_i_32(deserializer);
+    final var_eosToken = sse_decode_i_32(deserializer);
+    final var_tokenizerModel = sse_decode_opt_String(deserializer);
+    return TokenizerInfo(
+      vocabSize: var_vocabSize,
+      nCtxTrain: var_nCtxTrain,
+      nCtx: var_nCtx,
+      bosToken: var_bosToken,
+      eosToken: var_eosToken,
+      tokenizerModel: var_tokenizerModel,
+    );
   }
 
   @protected
-  List<(String, String)> dco_decode_list_record_string_string(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_record_string_string).toList();
+  TranscriptSegment sse_decode_transcript_segment(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_text = sse_decode_String(deserializer);
+    final var_startTime = sse_decode_f_32(deserializer);
+    final var_endTime = sse_decode_f_32(deserializer);
+    return TranscriptSegment(
+      text: var_text,
+      startTime: var_startTime,
+      endTime: var_endTime,
+    );
   }
 
   @protected
-  List<SemanticEdge> dco_decode_list_semantic_edge(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_semantic_edge).toList();
+  int sse_decode_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint32();
   }
 
   @protected
-  List<SimilarityResult> dco_decode_list_similarity_result(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_similarity_result).toList();
+  BigInt sse_decode_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getBigUint64();
   }
 
   @protected
-  MCPParamType dco_decode_mcp_param_type(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return MCPParamType.values[raw as int];
+  int sse_decode_u_8(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint8();
   }
 
   @protected
-  MCPParameter dco_decode_mcp_parameter(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 4)
-      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
-    return MCPParameter(
-      name: dco_decode_String(arr[0]),
-      description: dco_decode_String(arr[1]),
-      paramType: dco_decode_mcp_param_type(arr[2]),
-      required_: dco_decode_bool(arr[3]),
-    );
+  void sse_decode_unit(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
   }
 
   @protected
-  MCPTool dco_decode_mcp_tool(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return MCPTool.values[raw as int];
+  BigInt sse_decode_usize(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getBigUint64();
   }
 
   @protected
-  MCPToolCall dco_decode_mcp_tool_call(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 3)
-      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
-    return MCPToolCall(
-      tool: dco_decode_String(arr[0]),
-      parametersJson: dco_decode_String(arr[1]),
-      description: dco_decode_String(arr[2]),
+  VectorStoreStats sse_decode_vector_store_stats(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    final var_entries = sse_decode_usize(deserializer);
+    final var_deadRows = sse_decode_usize(deserializer);
+    final var_dimension = sse_decode_usize(deserializer);
+    final var_fileBytes = sse_decode_u_64(deserializer);
+    return VectorStoreStats(
+      entries: var_entries,
+      deadRows: var_deadRows,
+      dimension: var_dimension,
+      fileBytes: var_fileBytes,
     );
   }
 
   @protected
-  MCPToolResult dco_decode_mcp_tool_result(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 3)
-      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
-    return MCPToolResult(
-      success: dco_decode_bool(arr[0]),
-      result: dco_decode_String(arr[1]),
-      tool: dco_decode_String(arr[2]),
-    );
+  int cst_encode_agent_stop_reason(AgentStopReason raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_i_32(raw.index);
   }
 
   @protected
-  NodePosition dco_decode_node_position(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 6)
-      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
-    return NodePosition(
-      id: dco_decode_String(arr[0]),
-      x: dco_decode_f_32(arr[1]),
-      y: dco_decode_f_32(arr[2]),
-      radius: dco_decode_f_32(arr[3]),
-      color: dco_decode_u_32(arr[4]),
-      nodeType: dco_decode_String(arr[5]),
-    );
+  bool cst_encode_bool(bool raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw;
   }
 
   @protected
-  String? dco_decode_opt_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_String(raw);
+  int cst_encode_context_strategy(ContextStrategy raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_i_32(raw.index);
   }
 
   @protected
-  double? dco_decode_opt_box_autoadd_f_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_f_32(raw);
+  int cst_encode_embedding_kind(EmbeddingKind raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_i_32(raw.index);
   }
 
   @protected
-  int? dco_decode_opt_box_autoadd_i_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_i_32(raw);
+  double cst_encode_f_32(double raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw;
   }
 
   @protected
-  int? dco_decode_opt_box_autoadd_u_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_u_32(raw);
+  int cst_encode_fusion_mode(FusionMode raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_i_32(raw.index);
   }
 
   @protected
-  BigInt? dco_decode_opt_box_autoadd_usize(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_usize(raw);
+  int cst_encode_i_32(int raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw;
   }
 
   @protected
-  List<String>? dco_decode_opt_list_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_list_String(raw);
+  int cst_encode_mcp_param_type(MCPParamType raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_i_32(raw.index);
   }
 
   @protected
-  Float32List? dco_decode_opt_list_prim_f_32_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_list_prim_f_32_strict(raw);
+  int cst_encode_mcp_tool(MCPTool raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_i_32(raw.index);
   }
 
   @protected
-  List<(String, String)>? dco_decode_opt_list_record_string_string(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_list_record_string_string(raw);
+  int cst_encode_pooling_mode(PoolingMode raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_i_32(raw.index);
   }
 
   @protected
-  (String, String) dco_decode_record_string_string(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2) {
-      throw Exception('Expected 2 elements, got ${arr.length}');
-    }
-    return (dco_decode_String(arr[0]), dco_decode_String(arr[1]));
+  int cst_encode_quantization_kind(QuantizationKind raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_i_32(raw.index);
   }
 
   @protected
-  SemanticEdge dco_decode_semantic_edge(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 4)
-      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
-    return SemanticEdge(
-      source: dco_decode_String(arr[0]),
-      target: dco_decode_String(arr[1]),
-      similarity: dco_decode_f_32(arr[2]),
-      isGhost: dco_decode_bool(arr[3]),
-    );
+  int cst_encode_reply_span_kind(ReplySpanKind raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_i_32(raw.index);
   }
 
   @protected
-  SemanticEdgeResult dco_decode_semantic_edge_result(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2)
-      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return SemanticEdgeResult(
-      edges: dco_decode_list_semantic_edge(arr[0]),
-      count: dco_decode_usize(arr[1]),
-    );
+  int cst_encode_stop_reason(StopReason raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_i_32(raw.index);
   }
 
   @protected
-  SimilarityResult dco_decode_similarity_result(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 3)
-      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
-    return SimilarityResult(
-      id: dco_decode_String(arr[0]),
-      score: dco_decode_f_32(arr[1]),
-      textPreview: dco_decode_opt_String(arr[2]),
-    );
+  int cst_encode_summary_style(SummaryStyle raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_i_32(raw.index);
   }
 
   @protected
-  StreamGraphStats dco_decode_stream_graph_stats(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 3)
-      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
-    return StreamGraphStats(
-      nodeCount: dco_decode_usize(arr[0]),
-      edgeCount: dco_decode_usize(arr[1]),
-      visibleCount: dco_decode_usize(arr[2]),
-    );
+  int cst_encode_task_category(TaskCategory raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_i_32(raw.index);
   }
 
   @protected
-  TaskCategory dco_decode_task_category(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return TaskCategory.values[raw as int];
+  int cst_encode_u_32(int raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw;
   }
 
   @protected
-  int dco_decode_u_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  int cst_encode_u_8(int raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw;
   }
 
   @protected
-  int dco_decode_u_8(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  void cst_encode_unit(void raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw;
   }
 
   @protected
-  void dco_decode_unit(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return;
+  void sse_encode_AnyhowException(
+    AnyhowException self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.message, serializer);
   }
 
   @protected
-  BigInt dco_decode_usize(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeU64(raw);
+  void sse_encode_StreamSink_agent_step_Dco(
+    RustStreamSink<AgentStep> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_agent_step,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
   }
 
   @protected
-  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
+  void sse_encode_StreamSink_generation_chunk_Dco(
+    RustStreamSink<GenerationChunk> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    final inner = sse_decode_String(deserializer);
-    return AnyhowException(inner);
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_generation_chunk,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
   }
 
   @protected
-  String sse_decode_String(SseDeserializer deserializer) {
+  void sse_encode_String(String self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    final inner = sse_decode_list_prim_u_8_strict(deserializer);
-    return utf8.decoder.convert(inner);
+    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
   }
 
   @protected
-  bool sse_decode_bool(SseDeserializer deserializer) {
+  void sse_encode_agent_run(AgentRun self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8() != 0;
+    sse_encode_String(self.answer, serializer);
+    sse_encode_agent_stop_reason(self.stopReason, serializer);
+    sse_encode_list_agent_step(self.steps, serializer);
+    sse_encode_list_record_string_string(self.messages, serializer);
   }
 
   @protected
-  double sse_decode_box_autoadd_f_32(SseDeserializer deserializer) {
+  void sse_encode_agent_step(AgentStep self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_f_32(deserializer));
+    sse_encode_String(self.reply, serializer);
+    sse_encode_list_mcp_tool_call(self.toolCalls, serializer);
+    sse_encode_list_mcp_tool_result(self.results, serializer);
   }
 
   @protected
-  int sse_decode_box_autoadd_i_32(SseDeserializer deserializer) {
+  void sse_encode_agent_stop_reason(
+    AgentStopReason self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_i_32(deserializer));
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  MCPToolCall sse_decode_box_autoadd_mcp_tool_call(
-    SseDeserializer deserializer,
-  ) {
+  void sse_encode_bool(bool self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_mcp_tool_call(deserializer));
+    serializer.buffer.putUint8(self ? 1 : 0);
   }
 
   @protected
-  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_context_policy(
+    ContextPolicy self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_u_32(deserializer));
+    sse_encode_context_policy(self, serializer);
   }
 
   @protected
-  BigInt sse_decode_box_autoadd_usize(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_context_report(
+    ContextReport self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_usize(deserializer));
+    sse_encode_context_report(self, serializer);
   }
 
   @protected
-  ClusterAssignment sse_decode_cluster_assignment(
-    SseDeserializer deserializer,
+  void sse_encode_box_autoadd_dedup_options(
+    DedupOptions self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    final var_id = sse_decode_String(deserializer);
-    final var_clusterId = sse_decode_usize(deserializer);
-    final var_color = sse_decode_String(deserializer);
-    return ClusterAssignment(
-      id: var_id,
-      clusterId: var_clusterId,
-      color: var_color,
-    );
+    sse_encode_dedup_options(self, serializer);
   }
 
   @protected
-  ClusterInfo sse_decode_cluster_info(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_embedding_cache_stats(
+    EmbeddingCacheStats self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    final var_id = sse_decode_usize(deserializer);
-    final var_size = sse_decode_usize(deserializer);
-    final var_color = sse_decode_String(deserializer);
-    final var_centroid = sse_decode_opt_list_prim_f_32_strict(deserializer);
-    return ClusterInfo(
-      id: var_id,
-      size: var_size,
-      color: var_color,
-      centroid: var_centroid,
-    );
+    sse_encode_embedding_cache_stats(self, serializer);
   }
 
   @protected
-  ClusteringResult sse_decode_clustering_result(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_embedding_config(
+    EmbeddingConfig self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    final var_assignments = sse_decode_list_cluster_assignment(deserializer);
-    final var_clusters = sse_decode_list_cluster_info(deserializer);
-    final var_k = sse_decode_usize(deserializer);
-    return ClusteringResult(
-      assignments: var_assignments,
-      clusters: var_clusters,
-      k: var_k,
-    );
+    sse_encode_embedding_config(self, serializer);
   }
 
   @protected
-  EmbeddingCluster sse_decode_embedding_cluster(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_f_32(double self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    final var_ids = sse_decode_list_String(deserializer);
-    return EmbeddingCluster(ids: var_ids);
+    sse_encode_f_32(self, serializer);
   }
 
   @protected
-  EmbeddingEntry sse_decode_embedding_entry(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_generation_options(
+    GenerationOptions self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    final var_id = sse_decode_String(deserializer);
-    final var_vector = sse_decode_list_prim_f_32_strict(deserializer);
-    final var_textPreview = sse_decode_opt_String(deserializer);
-    return EmbeddingEntry(
-      id: var_id,
-      vector: var_vector,
-      textPreview: var_textPreview,
-    );
+    sse_encode_generation_options(self, serializer);
   }
 
   @protected
-  double sse_decode_f_32(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_generation_result(
+    GenerationResult self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getFloat32();
+    sse_encode_generation_result(self, serializer);
   }
 
   @protected
-  GraphEdge sse_decode_graph_edge(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_hnsw_config(
+    HnswConfig self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    final var_source = sse_decode_String(deserializer);
-    final var_target = sse_decode_String(deserializer);
-    final var_weight = sse_decode_f_32(deserializer);
-    final var_edgeType = sse_decode_String(deserializer);
-    return GraphEdge(
-      source: var_source,
-      target: var_target,
-      weight: var_weight,
-      edgeType: var_edgeType,
-    );
+    sse_encode_hnsw_config(self, serializer);
   }
 
   @protected
-  GraphNode sse_decode_graph_node(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_hybrid_options(
+    HybridOptions self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    final var_id = sse_decode_String(deserializer);
-    final var_label = sse_decode_String(deserializer);
-    final var_nodeType = sse_decode_String(deserializer);
-    final var_x = sse_decode_f_32(deserializer);
-    final var_y = sse_decode_f_32(deserializer);
-    final var_color = sse_decode_opt_String(deserializer);
-    final var_metadata = sse_decode_opt_String(deserializer);
-    return GraphNode(
-      id: var_id,
-      label: var_label,
-      nodeType: var_nodeType,
-      x: var_x,
-      y: var_y,
-      color: var_color,
-      metadata: var_metadata,
-    );
+    sse_encode_hybrid_options(self, serializer);
   }
 
   @protected
-  GraphState sse_decode_graph_state(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_i_32(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    final var_nodes = sse_decode_list_graph_node(deserializer);
-    final var_edges = sse_decode_list_graph_edge(deserializer);
-    return GraphState(nodes: var_nodes, edges: var_edges);
+    sse_encode_i_32(self, serializer);
   }
 
   @protected
-  int sse_decode_i_32(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_i_64(
+    PlatformInt64 self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getInt32();
+    sse_encode_i_64(self, serializer);
   }
 
   @protected
-  KnowledgeGraphAnalysis sse_decode_knowledge_graph_analysis(
-    SseDeserializer deserializer,
+  void sse_encode_box_autoadd_mcp_tool_call(
+    MCPToolCall self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    final var_clustering = sse_decode_clustering_result(deserializer);
-    final var_semanticEdges = sse_decode_semantic_edge_result(deserializer);
-    return KnowledgeGraphAnalysis(
-      clustering: var_clustering,
-      semanticEdges: var_semanticEdges,
-    );
+    sse_encode_mcp_tool_call(self, serializer);
   }
 
   @protected
-  List<String> sse_decode_list_String(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_note_metadata(
+    NoteMetadata self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    final len_ = sse_decode_i_32(deserializer);
-    final ans_ = <String>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_String(deserializer));
-    }
-    return ans_;
+    sse_encode_note_metadata(self, serializer);
   }
 
   @protected
-  List<ClusterAssignment> sse_decode_list_cluster_assignment(
-    SseDeserializer deserializer,
+  void sse_encode_box_autoadd_reasoning_options(
+    ReasoningOptions self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    final len_ = sse_decode_i_32(deserializer);
-    final ans_ = <ClusterAssignment>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_cluster_assignment(deserializer));
-    }
-    return ans_;
+    sse_encode_reasoning_options(self, serializer);
   }
 
   @protected
-  List<ClusterInfo> sse_decode_list_cluster_info(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_sampler_config(
+    SamplerConfig self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    final len_ = sse_decode_i_32(deserializer);
-    final ans_ = <ClusterInfo>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_cluster_info(deserializer));
-    }
-    return ans_;
+    sse_encode_sampler_config(self, serializer);
   }
 
   @protected
-  List<EmbeddingCluster> sse_decode_list_embedding_cluster(
-    SseDeserializer deserializer,
+  void sse_encode_box_autoadd_search_options(
+    SearchOptions self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    final len_ = sse_decode_i_32(deserializer);
-    final ans_ = <EmbeddingCluster>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_embedding_cluster(deserializer));
-    }
-    return ans_;
+    sse_encode_search_options(self, serializer);
   }
 
   @protected
-  List<EmbeddingEntry> sse_decode_list_embedding_entry(
-    SseDeserializer deserializer,
+  void sse_encode_box_autoadd_stop_reason(
+    StopReason self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    final len_ = sse_decode_i_32(deserializer);
-    final ans_ = <EmbeddingEntry>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_embedding_entry(deserializer));
-    }
-    return ans_;
+    sse_encode_stop_reason(self, serializer);
   }
 
   @protected
-  List<GraphEdge> sse_decode_list_graph_edge(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_summary_options(
+    SummaryOptions self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    final len_ = sse_decode_i_32(deserializer);
-    final ans_ = <GraphEdge>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_graph_edge(deserializer));
-    }
-    return ans_;
+    sse_encode_summary_options(self, serializer);
   }
 
   @protected
-  List<GraphNode> sse_decode_list_graph_node(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    final len_ = sse_decode_i_32(deserializer);
-    final ans_ = <GraphNode>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_graph_node(deserializer));
-    }
-    return ans_;
+    sse_encode_u_32(self, serializer);
   }
 
   @protected
-  List<MCPParameter> sse_decode_list_mcp_parameter(
-    SseDeserializer deserializer,
-  ) {
+  void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    final len_ = sse_decode_i_32(deserializer);
-    final ans_ = <MCPParameter>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_mcp_parameter(deserializer));
-    }
-    return ans_;
+    sse_encode_u_64(self, serializer);
   }
 
   @protected
-  List<MCPTool> sse_decode_list_mcp_tool(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_usize(BigInt self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    final len_ = sse_decode_i_32(deserializer);
-    final ans_ = <MCPTool>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_mcp_tool(deserializer));
-    }
-    return ans_;
+    sse_encode_usize(self, serializer);
   }
 
   @protected
-  List<NodePosition> sse_decode_list_node_position(
-    SseDeserializer deserializer,
+  void sse_encode_cluster_assignment(
+    ClusterAssignment self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    final len_ = sse_decode_i_32(deserializer);
-    final ans_ = <NodePosition>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_node_position(deserializer));
-    }
-    return ans_;
+    sse_encode_String(self.id, serializer);
+    sse_encode_usize(self.clusterId, serializer);
+    sse_encode_String(self.color, serializer);
   }
 
   @protected
-  List<double> sse_decode_list_prim_f_32_loose(SseDeserializer deserializer) {
+  void sse_encode_cluster_info(ClusterInfo self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    final len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getFloat32List(len_);
+    sse_encode_usize(self.id, serializer);
+    sse_encode_usize(self.size, serializer);
+    sse_encode_String(self.color, serializer);
+    sse_encode_opt_list_prim_f_32_strict(self.centroid, serializer);
   }
 
   @protected
-  Float32List sse_decode_list_prim_f_32_strict(SseDeserializer deserializer) {
+  void sse_encode_clustering_result(
+    ClusteringResult self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    final len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getFloat32List(len_);
+    sse_encode_list_cluster_assignment(self.assignments, serializer);
+    sse_encode_list_cluster_info(self.clusters, serializer);
+    sse_encode_usize(self.k, serializer);
   }
 
   @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+  void sse_encode_context_policy(ContextPolicy self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    final len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
+    sse_encode_context_strategy(self.strategy, serializer);
+    sse_encode_u_32(self.summaryMaxTokens, serializer);
   }
 
   @protected
-  List<(String, String)> sse_decode_list_record_string_string(
-    SseDeserializer deserializer,
-  ) {
+  void sse_encode_context_report(ContextReport self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    final len_ = sse_decode_i_32(deserializer);
-    final ans_ = <(String, String)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_string_string(deserializer));
-    }
-    return ans_;
+    sse_encode_u_32(self.contextSize, serializer);
+    sse_encode_u_32(self.promptTokens, serializer);
+    sse_encode_list_prim_u_32_strict(self.messageTokens, serializer);
+    sse_encode_list_prim_u_32_strict(self.dropped, serializer);
+    sse_encode_list_prim_u_32_strict(self.truncated, serializer);
+    sse_encode_list_prim_u_32_strict(self.summarized, serializer);
+    sse_encode_opt_String(self.summary, serializer);
   }
 
   @protected
-  List<SemanticEdge> sse_decode_list_semantic_edge(
-    SseDeserializer deserializer,
+  void sse_encode_context_strategy(
+    ContextStrategy self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    final len_ = sse_decode_i_32(deserializer);
-    final ans_ = <SemanticEdge>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_semantic_edge(deserializer));
-    }
-    return ans_;
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  List<SimilarityResult> sse_decode_list_similarity_result(
-    SseDeserializer deserializer,
-  ) {
+  void sse_encode_dedup_document(DedupDocument self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    final len_ = sse_decode_i_32(deserializer);
-    final ans_ = <SimilarityResult>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_similarity_result(deserializer));
-    }
-    return ans_;
+    sse_encode_String(self.id, serializer);
+    sse_encode_String(self.text, serializer);
+    sse_encode_opt_list_prim_f_32_strict(self.vector, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.createdAt, serializer);
   }
 
   @protected
-  MCPParamType sse_decode_mcp_param_type(SseDeserializer deserializer) {
+  void sse_encode_dedup_options(DedupOptions self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    final inner = sse_decode_i_32(deserializer);
-    return MCPParamType.values[inner];
+    sse_encode_usize(self.shingleWords, serializer);
+    sse_encode_usize(self.numHashes, serializer);
+    sse_encode_usize(self.bands, serializer);
+    sse_encode_f_32(self.minJaccard, serializer);
+    sse_encode_f_32(self.minCosine, serializer);
+    sse_encode_usize(self.minSpanWords, serializer);
   }
 
   @protected
-  MCPParameter sse_decode_mcp_parameter(SseDeserializer deserializer) {
+  void sse_encode_duplicate_group(
+    DuplicateGroup self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    final var_name = sse_decode_String(deserializer);
-    final var_description = sse_decode_String(deserializer);
-    final var_paramType = sse_decode_mcp_param_type(deserializer);
-    final var_required_ = sse_decode_bool(deserializer);
-    return MCPParameter(
-      name: var_name,
-      description: var_description,
-      paramType: var_paramType,
-      required_: var_required_,
-    );
+    sse_encode_list_String(self.ids, serializer);
+    sse_encode_String(self.canonical, serializer);
+    sse_encode_String(self.canonicalNote, serializer);
+    sse_encode_list_duplicate_pair(self.pairs, serializer);
   }
 
   @protected
-  MCPTool sse_decode_mcp_tool(SseDeserializer deserializer) {
+  void sse_encode_duplicate_pair(DuplicatePair self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    final inner = sse_decode_i_32(deserializer);
-    return MCPTool.values[inner];
+    sse_encode_String(self.a, serializer);
+    sse_encode_String(self.b, serializer);
+    sse_encode_f_32(self.jaccard, serializer);
+    sse_encode_opt_box_autoadd_f_32(self.cosine, serializer);
+    sse_encode_list_duplicate_span(self.spans, serializer);
   }
 
   @protected
-  MCPToolCall sse_decode_mcp_tool_call(SseDeserializer deserializer) {
+  void sse_encode_duplicate_span(DuplicateSpan self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    final var_tool = sse_decode_String(deserializer);
-    final var_parametersJson = sse_decode_String(deserializer);
-    final var_description = sse_decode_String(deserializer);
-    return MCPToolCall(
-      tool: var_tool,
-      parametersJson: var_parametersJson,
-      description: var_description,
-    );
+    sse_encode_usize(self.aStart, serializer);
+    sse_encode_usize(self.aEnd, serializer);
+    sse_encode_usize(self.bStart, serializer);
+    sse_encode_usize(self.bEnd, serializer);
+    sse_encode_usize(self.words, serializer);
   }
 
   @protected
-  MCPToolResult sse_decode_mcp_tool_result(SseDeserializer deserializer) {
+  void sse_encode_embedding_cache_stats(
+    EmbeddingCacheStats self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    final var_success = sse_decode_bool(deserializer);
-    final var_result = sse_decode_String(deserializer);
-    final var_tool = sse_decode_String(deserializer);
-    return MCPToolResult(
-      success: var_success,
-      result: var_result,
-      tool: var_tool,
-    );
+    sse_encode_usize(self.entries, serializer);
+    sse_encode_usize(self.staleRecords, serializer);
+    sse_encode_u_64(self.fileBytes, serializer);
   }
 
   @protected
-  NodePosition sse_decode_node_position(SseDeserializer deserializer) {
+  void sse_encode_embedding_cluster(
+    EmbeddingCluster self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    final var_id = sse_decode_String(deserializer);
-    final var_x = sse_decode_f_32(deserializer);
-    final var_y = sse_decode_f_32(deserializer);
-    final var_radius = sse_decode_f_32(deserializer);
-    final var_color = sse_decode_u_32(deserializer);
-    final var_nodeType = sse_decode_String(deserializer);
-    return NodePosition(
-      id: var_id,
-      x: var_x,
-      y: var_y,
-      radius: var_radius,
-      color: var_color,
-      nodeType: var_nodeType,
-    );
+    sse_encode_list_String(self.ids, serializer);
   }
 
   @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer) {
+  void sse_encode_embedding_config(
+    EmbeddingConfig self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_String(deserializer));
-    } else {
-      return null;
-    }
+    sse_encode_u_32(self.nGpuLayers, serializer);
+    sse_encode_i_32(self.nThreads, serializer);
+    sse_encode_pooling_mode(self.pooling, serializer);
+    sse_encode_u_32(self.chunkTokens, serializer);
+    sse_encode_u_32(self.chunkOverlap, serializer);
+    sse_encode_u_32(self.maxSequences, serializer);
+    sse_encode_String(self.queryPrefix, serializer);
+    sse_encode_String(self.documentPrefix, serializer);
   }
 
   @protected
-  double? sse_decode_opt_box_autoadd_f_32(SseDeserializer deserializer) {
+  void sse_encode_embedding_entry(
+    EmbeddingEntry self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_f_32(deserializer));
-    } else {
-      return null;
-    }
+    sse_encode_String(self.id, serializer);
+    sse_encode_list_prim_f_32_strict(self.vector, serializer);
+    sse_encode_opt_String(self.textPreview, serializer);
+    sse_encode_note_metadata(self.metadata, serializer);
   }
 
   @protected
-  int? sse_decode_opt_box_autoadd_i_32(SseDeserializer deserializer) {
+  void sse_encode_embedding_kind(EmbeddingKind self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_i_32(deserializer));
-    } else {
-      return null;
-    }
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer) {
+  void sse_encode_f_32(double self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_u_32(deserializer));
-    } else {
-      return null;
-    }
+    serializer.buffer.putFloat32(self);
   }
 
   @protected
-  BigInt? sse_decode_opt_box_autoadd_usize(SseDeserializer deserializer) {
+  void sse_encode_fusion_mode(FusionMode self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_usize(deserializer));
-    } else {
-      return null;
-    }
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  List<String>? sse_decode_opt_list_String(SseDeserializer deserializer) {
+  void sse_encode_generation_chunk(
+    GenerationChunk self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_list_String(deserializer));
-    } else {
-      return null;
-    }
+    sse_encode_String(self.text, serializer);
+    sse_encode_String(self.reasoning, serializer);
+    sse_encode_opt_box_autoadd_stop_reason(self.stopReason, serializer);
   }
 
   @protected
-  Float32List? sse_decode_opt_list_prim_f_32_strict(
-    SseDeserializer deserializer,
+  void sse_encode_generation_options(
+    GenerationOptions self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_box_autoadd_u_32(self.maxTokens, serializer);
+    sse_encode_list_String(self.stopSequences, serializer);
+    sse_encode_opt_box_autoadd_sampler_config(self.sampler, serializer);
+    sse_encode_opt_String(self.grammar, serializer);
+    sse_encode_opt_String(self.jsonSchema, serializer);
+    sse_encode_opt_String(self.modelId, serializer);
+    sse_encode_opt_box_autoadd_context_policy(self.context, serializer);
+    sse_encode_opt_box_autoadd_reasoning_options(self.reasoning, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.logprobs, serializer);
+  }
 
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_list_prim_f_32_strict(deserializer));
-    } else {
-      return null;
-    }
+  @protected
+  void sse_encode_generation_poll(
+    GenerationPoll self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.text, serializer);
+    sse_encode_String(self.reasoning, serializer);
+    sse_encode_opt_box_autoadd_generation_result(self.result, serializer);
   }
 
   @protected
-  List<(String, String)>? sse_decode_opt_list_record_string_string(
-    SseDeserializer deserializer,
+  void sse_encode_generation_result(
+    GenerationResult self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.text, serializer);
+    sse_encode_opt_String(self.reasoning, serializer);
+    sse_encode_u_32(self.reasoningTokens, serializer);
+    sse_encode_stop_reason(self.stopReason, serializer);
+    sse_encode_u_32(self.tokensGenerated, serializer);
+    sse_encode_opt_box_autoadd_context_report(self.context, serializer);
+    sse_encode_opt_list_token_logprob(self.logprobs, serializer);
+  }
 
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_list_record_string_string(deserializer));
-    } else {
-      return null;
-    }
+  @protected
+  void sse_encode_gguf_metadata(GgufMetadata self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.version, serializer);
+    sse_encode_opt_String(self.architecture, serializer);
+    sse_encode_opt_String(self.name, serializer);
+    sse_encode_opt_String(self.chatTemplate, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.contextLength, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.embeddingLength, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.blockCount, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.poolingType, serializer);
+    sse_encode_opt_String(self.quantization, serializer);
+    sse_encode_u_64(self.parameterCount, serializer);
+    sse_encode_u_64(self.tensorCount, serializer);
+    sse_encode_opt_String(self.tokenizerModel, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.vocabSize, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.bosTokenId, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.eosTokenId, serializer);
+    sse_encode_u_64(self.fileSize, serializer);
   }
 
   @protected
-  (String, String) sse_decode_record_string_string(
-    SseDeserializer deserializer,
-  ) {
+  void sse_encode_graph_edge(GraphEdge self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    final var_field0 = sse_decode_String(deserializer);
-    final var_field1 = sse_decode_String(deserializer);
-    return (var_field0, var_field1);
+    sse_encode_String(self.source, serializer);
+    sse_encode_String(self.target, serializer);
+    sse_encode_f_32(self.weight, serializer);
+    sse_encode_String(self.edgeType, serializer);
   }
 
   @protected
-  SemanticEdge sse_decode_semantic_edge(SseDeserializer deserializer) {
+  void sse_encode_graph_node(GraphNode self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    final var_source = sse_decode_String(deserializer);
-    final var_target = sse_decode_String(deserializer);
-    final var_similarity = sse_decode_f_32(deserializer);
-    final var_isGhost = sse_decode_bool(deserializer);
-    return SemanticEdge(
-      source: var_source,
-      target: var_target,
-      similarity: var_similarity,
-      isGhost: var_isGhost,
-    );
+    sse_encode_String(self.id, serializer);
+    sse_encode_String(self.label, serializer);
+    sse_encode_String(self.nodeType, serializer);
+    sse_encode_f_32(self.x, serializer);
+    sse_encode_f_32(self.y, serializer);
+    sse_encode_opt_String(self.color, serializer);
+    sse_encode_opt_String(self.metadata, serializer);
   }
 
   @protected
-  SemanticEdgeResult sse_decode_semantic_edge_result(
-    SseDeserializer deserializer,
-  ) {
+  void sse_encode_graph_state(GraphState self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    final var_edges = sse_decode_list_semantic_edge(deserializer);
-    final var_count = sse_decode_usize(deserializer);
-    return SemanticEdgeResult(edges: var_edges, count: var_count);
+    sse_encode_list_graph_node(self.nodes, serializer);
+    sse_encode_list_graph_edge(self.edges, serializer);
   }
 
   @protected
-  SimilarityResult sse_decode_similarity_result(SseDeserializer deserializer) {
+  void sse_encode_hnsw_config(HnswConfig self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    final var_id = sse_decode_String(deserializer);
-    final var_score = sse_decode_f_32(deserializer);
-    final var_textPreview = sse_decode_opt_String(deserializer);
-    return SimilarityResult(
-      id: var_id,
-      score: var_score,
-      textPreview: var_textPreview,
-    );
+    sse_encode_u_32(self.m, serializer);
+    sse_encode_u_32(self.efConstruction, serializer);
+    sse_encode_u_32(self.efSearch, serializer);
   }
 
   @protected
-  StreamGraphStats sse_decode_stream_graph_stats(SseDeserializer deserializer) {
+  void sse_encode_hybrid_hit(HybridHit self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    final var_nodeCount = sse_decode_usize(deserializer);
-    final var_edgeCount = sse_decode_usize(deserializer);
-    final var_visibleCount = sse_decode_usize(deserializer);
-    return StreamGraphStats(
-      nodeCount: var_nodeCount,
-      edgeCount: var_edgeCount,
-      visibleCount: var_visibleCount,
-    );
+    sse_encode_String(self.id, serializer);
+    sse_encode_f_32(self.score, serializer);
+    sse_encode_opt_box_autoadd_f_32(self.semanticScore, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.semanticRank, serializer);
+    sse_encode_opt_box_autoadd_f_32(self.lexicalScore, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.lexicalRank, serializer);
+    sse_encode_list_String(self.matchedTerms, serializer);
+    sse_encode_opt_String(self.textPreview, serializer);
   }
 
   @protected
-  TaskCategory sse_decode_task_category(SseDeserializer deserializer) {
+  void sse_encode_hybrid_options(HybridOptions self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    final inner = sse_decode_i_32(deserializer);
-    return TaskCategory.values[inner];
+    sse_encode_fusion_mode(self.fusion, serializer);
+    sse_encode_f_32(self.semanticWeight, serializer);
+    sse_encode_f_32(self.rrfK, serializer);
+    sse_encode_usize(self.candidates, serializer);
+    sse_encode_f_32(self.minSemanticScore, serializer);
   }
 
   @protected
-  int sse_decode_u_32(SseDeserializer deserializer) {
+  void sse_encode_i_32(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint32();
+    serializer.buffer.putInt32(self);
   }
 
   @protected
-  int sse_decode_u_8(SseDeserializer deserializer) {
+  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8();
+    serializer.buffer.putPlatformInt64(self);
   }
 
   @protected
-  void sse_decode_unit(SseDeserializer deserializer) {
+  void sse_encode_knowledge_graph_analysis(
+    KnowledgeGraphAnalysis self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_clustering_result(self.clustering, serializer);
+    sse_encode_semantic_edge_result(self.semanticEdges, serializer);
   }
 
   @protected
-  BigInt sse_decode_usize(SseDeserializer deserializer) {
+  void sse_encode_list_String(List<String> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getBigUint64();
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_String(item, serializer);
+    }
   }
 
   @protected
-  bool cst_encode_bool(bool raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw;
+  void sse_encode_list_agent_step(
+    List<AgentStep> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_agent_step(item, serializer);
+    }
   }
 
   @protected
-  double cst_encode_f_32(double raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw;
+  void sse_encode_list_cluster_assignment(
+    List<ClusterAssignment> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_cluster_assignment(item, serializer);
+    }
   }
 
   @protected
-  int cst_encode_i_32(int raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw;
+  void sse_encode_list_cluster_info(
+    List<ClusterInfo> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_cluster_info(item, serializer);
+    }
   }
 
   @protected
-  int cst_encode_mcp_param_type(MCPParamType raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return cst_encode_i_32(raw.index);
+  void sse_encode_list_dedup_document(
+    List<DedupDocument> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_dedup_document(item, serializer);
+    }
   }
 
   @protected
-  int cst_encode_mcp_tool(MCPTool raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return cst_encode_i_32(raw.index);
+  void sse_encode_list_duplicate_group(
+    List<DuplicateGroup> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_duplicate_group(item, serializer);
+    }
   }
 
   @protected
-  int cst_encode_task_category(TaskCategory raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return cst_encode_i_32(raw.index);
+  void sse_encode_list_duplicate_pair(
+    List<DuplicatePair> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_duplicate_pair(item, serializer);
+    }
   }
 
   @protected
-  int cst_encode_u_32(int raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw;
+  void sse_encode_list_duplicate_span(
+    List<DuplicateSpan> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_duplicate_span(item, serializer);
+    }
   }
 
   @protected
-  int cst_encode_u_8(int raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw;
+  void sse_encode_list_embedding_cluster(
+    List<EmbeddingCluster> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_embedding_cluster(item, serializer);
+    }
   }
 
   @protected
-  void cst_encode_unit(void raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw;
+  void sse_encode_list_embedding_entry(
+    List<EmbeddingEntry> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_embedding_entry(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_AnyhowException(
-    AnyhowException self,
+  void sse_encode_list_graph_edge(
+    List<GraphEdge> self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.message, serializer);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_graph_edge(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_String(String self, SseSerializer serializer) {
+  void sse_encode_list_graph_node(
+    List<GraphNode> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_graph_node(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_bool(bool self, SseSerializer serializer) {
+  void sse_encode_list_hybrid_hit(
+    List<HybridHit> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self ? 1 : 0);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_hybrid_hit(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_box_autoadd_f_32(double self, SseSerializer serializer) {
+  void sse_encode_list_list_prim_f_32_strict(
+    List<Float32List> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_f_32(self, serializer);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_list_prim_f_32_strict(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_box_autoadd_i_32(int self, SseSerializer serializer) {
+  void sse_encode_list_loaded_model_info(
+    List<LoadedModelInfo> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self, serializer);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_loaded_model_info(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_box_autoadd_mcp_tool_call(
-    MCPToolCall self,
+  void sse_encode_list_markdown_chunk(
+    List<MarkdownChunk> self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_mcp_tool_call(self, serializer);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_markdown_chunk(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer) {
+  void sse_encode_list_mcp_parameter(
+    List<MCPParameter> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_32(self, serializer);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_mcp_parameter(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_box_autoadd_usize(BigInt self, SseSerializer serializer) {
+  void sse_encode_list_mcp_tool(List<MCPTool> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(self, serializer);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_mcp_tool(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_cluster_assignment(
-    ClusterAssignment self,
+  void sse_encode_list_mcp_tool_call(
+    List<MCPToolCall> self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.id, serializer);
-    sse_encode_usize(self.clusterId, serializer);
-    sse_encode_String(self.color, serializer);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_mcp_tool_call(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_cluster_info(ClusterInfo self, SseSerializer serializer) {
+  void sse_encode_list_mcp_tool_result(
+    List<MCPToolResult> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(self.id, serializer);
-    sse_encode_usize(self.size, serializer);
-    sse_encode_String(self.color, serializer);
-    sse_encode_opt_list_prim_f_32_strict(self.centroid, serializer);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_mcp_tool_result(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_clustering_result(
-    ClusteringResult self,
+  void sse_encode_list_node_position(
+    List<NodePosition> self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_cluster_assignment(self.assignments, serializer);
-    sse_encode_list_cluster_info(self.clusters, serializer);
-    sse_encode_usize(self.k, serializer);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_node_position(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_embedding_cluster(
-    EmbeddingCluster self,
+  void sse_encode_list_prim_f_32_loose(
+    List<double> self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_String(self.ids, serializer);
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putFloat32List(
+      self is Float32List ? self : Float32List.fromList(self),
+    );
   }
 
   @protected
-  void sse_encode_embedding_entry(
-    EmbeddingEntry self,
+  void sse_encode_list_prim_f_32_strict(
+    Float32List self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.id, serializer);
-    sse_encode_list_prim_f_32_strict(self.vector, serializer);
-    sse_encode_opt_String(self.textPreview, serializer);
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putFloat32List(self);
   }
 
   @protected
-  void sse_encode_f_32(double self, SseSerializer serializer) {
+  void sse_encode_list_prim_i_32_loose(
+    List<int> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putFloat32(self);
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putInt32List(
+      self is Int32List ? self : Int32List.fromList(self),
+    );
   }
 
   @protected
-  void sse_encode_graph_edge(GraphEdge self, SseSerializer serializer) {
+  void sse_encode_list_prim_i_32_strict(
+    Int32List self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.source, serializer);
-    sse_encode_String(self.target, serializer);
-    sse_encode_f_32(self.weight, serializer);
-    sse_encode_String(self.edgeType, serializer);
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putInt32List(self);
   }
 
   @protected
-  void sse_encode_graph_node(GraphNode self, SseSerializer serializer) {
+  void sse_encode_list_prim_u_32_strict(
+    Uint32List self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.id, serializer);
-    sse_encode_String(self.label, serializer);
-    sse_encode_String(self.nodeType, serializer);
-    sse_encode_f_32(self.x, serializer);
-    sse_encode_f_32(self.y, serializer);
-    sse_encode_opt_String(self.color, serializer);
-    sse_encode_opt_String(self.metadata, serializer);
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putUint32List(self);
   }
 
   @protected
-  void sse_encode_graph_state(GraphState self, SseSerializer serializer) {
+  void sse_encode_list_prim_u_8_strict(
+    Uint8List self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_graph_node(self.nodes, serializer);
-    sse_encode_list_graph_edge(self.edges, serializer);
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putUint8List(self);
   }
 
   @protected
-  void sse_encode_i_32(int self, SseSerializer serializer) {
+  void sse_encode_list_rag_source(
+    List<RagSource> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putInt32(self);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_rag_source(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_knowledge_graph_analysis(
-    KnowledgeGraphAnalysis self,
+  void sse_encode_list_record_string_string(
+    List<(String, String)> self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_clustering_result(self.clustering, serializer);
-    sse_encode_semantic_edge_result(self.semanticEdges, serializer);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_record_string_string(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_list_String(List<String> self, SseSerializer serializer) {
+  void sse_encode_list_reply_span(
+    List<ReplySpan> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_i_32(self.length, serializer);
     for (final item in self) {
-      sse_encode_String(item, serializer);
+      sse_encode_reply_span(item, serializer);
     }
   }
 
   @protected
-  void sse_encode_list_cluster_assignment(
-    List<ClusterAssignment> self,
+  void sse_encode_list_semantic_edge(
+    List<SemanticEdge> self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_i_32(self.length, serializer);
     for (final item in self) {
-      sse_encode_cluster_assignment(item, serializer);
+      sse_encode_semantic_edge(item, serializer);
     }
   }
 
   @protected
-  void sse_encode_list_cluster_info(
-    List<ClusterInfo> self,
+  void sse_encode_list_similarity_result(
+    List<SimilarityResult> self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_i_32(self.length, serializer);
     for (final item in self) {
-      sse_encode_cluster_info(item, serializer);
+      sse_encode_similarity_result(item, serializer);
     }
   }
 
   @protected
-  void sse_encode_list_embedding_cluster(
-    List<EmbeddingCluster> self,
+  void sse_encode_list_token_candidate(
+    List<TokenCandidate> self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_i_32(self.length, serializer);
     for (final item in self) {
-      sse_encode_embedding_cluster(item, serializer);
+      sse_encode_token_candidate(item, serializer);
     }
   }
 
   @protected
-  void sse_encode_list_embedding_entry(
-    List<EmbeddingEntry> self,
+  void sse_encode_list_token_logprob(
+    List<TokenLogprob> self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_i_32(self.length, serializer);
     for (final item in self) {
-      sse_encode_embedding_entry(item, serializer);
+      sse_encode_token_logprob(item, serializer);
     }
   }
 
   @protected
-  void sse_encode_list_graph_edge(
-    List<GraphEdge> self,
+  void sse_encode_list_transcript_segment(
+    List<TranscriptSegment> self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_i_32(self.length, serializer);
     for (final item in self) {
-      sse_encode_graph_edge(item, serializer);
+      sse_encode_transcript_segment(item, serializer);
     }
   }
 
   @protected
-  void sse_encode_list_graph_node(
-    List<GraphNode> self,
+  void sse_encode_loaded_model_info(
+    LoadedModelInfo self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_graph_node(item, serializer);
+    sse_encode_String(self.modelId, serializer);
+    sse_encode_String(self.modelPath, serializer);
+    sse_encode_u_64(self.sizeBytes, serializer);
+    sse_encode_bool(self.isDefault, serializer);
+  }
+
+  @protected
+  void sse_encode_markdown_chunk(MarkdownChunk self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(self.index, serializer);
+    sse_encode_list_String(self.headingPath, serializer);
+    sse_encode_usize(self.start, serializer);
+    sse_encode_usize(self.end, serializer);
+    sse_encode_String(self.text, serializer);
+    sse_encode_u_32(self.tokenCount, serializer);
+  }
+
+  @protected
+  void sse_encode_mcp_param_type(MCPParamType self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_mcp_parameter(MCPParameter self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.name, serializer);
+    sse_encode_String(self.description, serializer);
+    sse_encode_mcp_param_type(self.paramType, serializer);
+    sse_encode_bool(self.required_, serializer);
+  }
+
+  @protected
+  void sse_encode_mcp_tool(MCPTool self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_mcp_tool_call(MCPToolCall self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.tool, serializer);
+    sse_encode_String(self.parametersJson, serializer);
+    sse_encode_String(self.description, serializer);
+  }
+
+  @protected
+  void sse_encode_mcp_tool_result(
+    MCPToolResult self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self.success, serializer);
+    sse_encode_String(self.result, serializer);
+    sse_encode_String(self.tool, serializer);
+  }
+
+  @protected
+  void sse_encode_node_position(NodePosition self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.id, serializer);
+    sse_encode_f_32(self.x, serializer);
+    sse_encode_f_32(self.y, serializer);
+    sse_encode_f_32(self.radius, serializer);
+    sse_encode_u_32(self.color, serializer);
+    sse_encode_String(self.nodeType, serializer);
+  }
+
+  @protected
+  void sse_encode_note_metadata(NoteMetadata self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_String(self.folder, serializer);
+    sse_encode_list_String(self.tags, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.createdAt, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.modifiedAt, serializer);
+    sse_encode_opt_String(self.noteType, serializer);
+    sse_encode_opt_box_autoadd_usize(self.chunkStart, serializer);
+    sse_encode_opt_box_autoadd_usize(self.chunkEnd, serializer);
+  }
+
+  @protected
+  void sse_encode_opt_String(String? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_String(self, serializer);
     }
   }
 
   @protected
-  void sse_encode_list_mcp_parameter(
-    List<MCPParameter> self,
+  void sse_encode_opt_box_autoadd_context_policy(
+    ContextPolicy? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_mcp_parameter(item, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_context_policy(self, serializer);
     }
   }
 
   @protected
-  void sse_encode_list_mcp_tool(List<MCPTool> self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_context_report(
+    ContextReport? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_mcp_tool(item, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_context_report(self, serializer);
     }
   }
 
   @protected
-  void sse_encode_list_node_position(
-    List<NodePosition> self,
+  void sse_encode_opt_box_autoadd_dedup_options(
+    DedupOptions? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_node_position(item, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_dedup_options(self, serializer);
     }
   }
 
   @protected
-  void sse_encode_list_prim_f_32_loose(
-    List<double> self,
+  void sse_encode_opt_box_autoadd_embedding_cache_stats(
+    EmbeddingCacheStats? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putFloat32List(
-      self is Float32List ? self : Float32List.fromList(self),
-    );
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_embedding_cache_stats(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_list_prim_f_32_strict(
-    Float32List self,
+  void sse_encode_opt_box_autoadd_embedding_config(
+    EmbeddingConfig? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putFloat32List(self);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_embedding_config(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_list_prim_u_8_strict(
-    Uint8List self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_opt_box_autoadd_f_32(double? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(self);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_f_32(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_list_record_string_string(
-    List<(String, String)> self,
+  void sse_encode_opt_box_autoadd_generation_result(
+    GenerationResult? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_string_string(item, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_generation_result(self, serializer);
     }
   }
 
   @protected
-  void sse_encode_list_semantic_edge(
-    List<SemanticEdge> self,
+  void sse_encode_opt_box_autoadd_hnsw_config(
+    HnswConfig? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_semantic_edge(item, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_hnsw_config(self, serializer);
     }
   }
 
   @protected
-  void sse_encode_list_similarity_result(
-    List<SimilarityResult> self,
+  void sse_encode_opt_box_autoadd_hybrid_options(
+    HybridOptions? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_similarity_result(item, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_hybrid_options(self, serializer);
     }
   }
 
   @protected
-  void sse_encode_mcp_param_type(MCPParamType self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_i_32(int? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.index, serializer);
-  }
 
-  @protected
-  void sse_encode_mcp_parameter(MCPParameter self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.name, serializer);
-    sse_encode_String(self.description, serializer);
-    sse_encode_mcp_param_type(self.paramType, serializer);
-    sse_encode_bool(self.required_, serializer);
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_i_32(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_mcp_tool(MCPTool self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_i_64(
+    PlatformInt64? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.index, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_i_64(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_mcp_tool_call(MCPToolCall self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_note_metadata(
+    NoteMetadata? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.tool, serializer);
-    sse_encode_String(self.parametersJson, serializer);
-    sse_encode_String(self.description, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_note_metadata(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_mcp_tool_result(
-    MCPToolResult self,
+  void sse_encode_opt_box_autoadd_reasoning_options(
+    ReasoningOptions? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_bool(self.success, serializer);
-    sse_encode_String(self.result, serializer);
-    sse_encode_String(self.tool, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_reasoning_options(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_node_position(NodePosition self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_sampler_config(
+    SamplerConfig? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.id, serializer);
-    sse_encode_f_32(self.x, serializer);
-    sse_encode_f_32(self.y, serializer);
-    sse_encode_f_32(self.radius, serializer);
-    sse_encode_u_32(self.color, serializer);
-    sse_encode_String(self.nodeType, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_sampler_config(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_opt_String(String? self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_stop_reason(
+    StopReason? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
     sse_encode_bool(self != null, serializer);
     if (self != null) {
-      sse_encode_String(self, serializer);
+      sse_encode_box_autoadd_stop_reason(self, serializer);
     }
   }
 
   @protected
-  void sse_encode_opt_box_autoadd_f_32(double? self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_summary_options(
+    SummaryOptions? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
     sse_encode_bool(self != null, serializer);
     if (self != null) {
-      sse_encode_box_autoadd_f_32(self, serializer);
+      sse_encode_box_autoadd_summary_options(self, serializer);
     }
   }
 
   @protected
-  void sse_encode_opt_box_autoadd_i_32(int? self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
     sse_encode_bool(self != null, serializer);
     if (self != null) {
-      sse_encode_box_autoadd_i_32(self, serializer);
+      sse_encode_box_autoadd_u_32(self, serializer);
     }
   }
 
   @protected
-  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
     sse_encode_bool(self != null, serializer);
     if (self != null) {
-      sse_encode_box_autoadd_u_32(self, serializer);
+      sse_encode_box_autoadd_u_64(self, serializer);
     }
   }
 
@@ -3566,6 +10632,19 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_opt_list_list_prim_f_32_strict(
+    List<Float32List>? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_list_list_prim_f_32_strict(self, serializer);
+    }
+  }
+
   @protected
   void sse_encode_opt_list_prim_f_32_strict(
     Float32List? self,
@@ -3592,6 +10671,85 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_opt_list_token_logprob(
+    List<TokenLogprob>? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_list_token_logprob(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_parsed_reply(ParsedReply self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_mcp_tool_call(self.toolCalls, serializer);
+    sse_encode_list_reply_span(self.spans, serializer);
+    sse_encode_String(self.text, serializer);
+  }
+
+  @protected
+  void sse_encode_pooling_mode(PoolingMode self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_quantization_kind(
+    QuantizationKind self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_rag_answer(RagAnswer self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.answer, serializer);
+    sse_encode_list_rag_source(self.sources, serializer);
+    sse_encode_usize(self.skipped, serializer);
+  }
+
+  @protected
+  void sse_encode_rag_source(RagSource self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.citation, serializer);
+    sse_encode_String(self.noteId, serializer);
+    sse_encode_opt_box_autoadd_usize(self.chunkOffset, serializer);
+    sse_encode_opt_box_autoadd_usize(self.chunkIndex, serializer);
+    sse_encode_f_32(self.score, serializer);
+    sse_encode_String(self.text, serializer);
+    sse_encode_bool(self.cited, serializer);
+  }
+
+  @protected
+  void sse_encode_reasoning_options(
+    ReasoningOptions self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self.enabled, serializer);
+    sse_encode_String(self.openTag, serializer);
+    sse_encode_String(self.closeTag, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.thinkingBudget, serializer);
+  }
+
+  @protected
+  void sse_encode_recall_report(RecallReport self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(self.queries, serializer);
+    sse_encode_usize(self.topK, serializer);
+    sse_encode_f_32(self.recall, serializer);
+    sse_encode_f_32(self.rescoredRecall, serializer);
+    sse_encode_usize(self.f32Bytes, serializer);
+    sse_encode_usize(self.quantizedBytes, serializer);
+  }
+
   @protected
   void sse_encode_record_string_string(
     (String, String) self,
@@ -3602,6 +10760,49 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_String(self.$2, serializer);
   }
 
+  @protected
+  void sse_encode_reply_span(ReplySpan self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(self.start, serializer);
+    sse_encode_usize(self.end, serializer);
+    sse_encode_reply_span_kind(self.kind, serializer);
+    sse_encode_usize(self.callCount, serializer);
+  }
+
+  @protected
+  void sse_encode_reply_span_kind(
+    ReplySpanKind self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_sampler_config(SamplerConfig self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_f_32(self.temperature, serializer);
+    sse_encode_i_32(self.topK, serializer);
+    sse_encode_f_32(self.topP, serializer);
+    sse_encode_f_32(self.minP, serializer);
+    sse_encode_f_32(self.repeatPenalty, serializer);
+    sse_encode_f_32(self.frequencyPenalty, serializer);
+    sse_encode_f_32(self.presencePenalty, serializer);
+    sse_encode_i_32(self.penaltyLastN, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.seed, serializer);
+    sse_encode_bool(self.greedy, serializer);
+  }
+
+  @protected
+  void sse_encode_search_options(SearchOptions self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(self.topK, serializer);
+    sse_encode_f_32(self.threshold, serializer);
+    sse_encode_opt_String(self.filter, serializer);
+    sse_encode_opt_box_autoadd_f_32(self.mmrLambda, serializer);
+    sse_encode_usize(self.mmrCandidates, serializer);
+  }
+
   @protected
   void sse_encode_semantic_edge(SemanticEdge self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3632,6 +10833,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_opt_String(self.textPreview, serializer);
   }
 
+  @protected
+  void sse_encode_stop_reason(StopReason self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
   @protected
   void sse_encode_stream_graph_stats(
     StreamGraphStats self,
@@ -3643,18 +10850,92 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_usize(self.visibleCount, serializer);
   }
 
+  @protected
+  void sse_encode_summary(Summary self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.summary, serializer);
+    sse_encode_list_String(self.chunkSummaries, serializer);
+    sse_encode_u_32(self.mergeLevels, serializer);
+  }
+
+  @protected
+  void sse_encode_summary_options(
+    SummaryOptions self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_summary_style(self.style, serializer);
+    sse_encode_u_32(self.maxWords, serializer);
+    sse_encode_u_32(self.chunkTokens, serializer);
+    sse_encode_opt_String(self.modelId, serializer);
+  }
+
+  @protected
+  void sse_encode_summary_style(SummaryStyle self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
   @protected
   void sse_encode_task_category(TaskCategory self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_i_32(self.index, serializer);
   }
 
+  @protected
+  void sse_encode_token_candidate(
+    TokenCandidate self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.token, serializer);
+    sse_encode_String(self.text, serializer);
+    sse_encode_f_32(self.logprob, serializer);
+  }
+
+  @protected
+  void sse_encode_token_logprob(TokenLogprob self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.token, serializer);
+    sse_encode_String(self.text, serializer);
+    sse_encode_f_32(self.logprob, serializer);
+    sse_encode_list_token_candidate(self.topAlternatives, serializer);
+  }
+
+  @protected
+  void sse_encode_tokenizer_info(TokenizerInfo self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.vocabSize, serializer);
+    sse_encode_u_32(self.nCtxTrain, serializer);
+    sse_encode_u_32(self.nCtx, serializer);
+    sse_encode_i_32(self.bosToken, serializer);
+    sse_encode_i_32(self.eosToken, serializer);
+    sse_encode_opt_String(self.tokenizerModel, serializer);
+  }
+
+  @protected
+  void sse_encode_transcript_segment(
+    TranscriptSegment self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.text, serializer);
+    sse_encode_f_32(self.startTime, serializer);
+    sse_encode_f_32(self.endTime, serializer);
+  }
+
   @protected
   void sse_encode_u_32(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     serializer.buffer.putUint32(self);
   }
 
+  @protected
+  void sse_encode_u_64(BigInt self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putBigUint64(self);
+  }
+
   @protected
   void sse_encode_u_8(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3671,4 +10952,16 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     // Codec=Sse (Serialization based), see doc to use other codecs
     serializer.buffer.putBigUint64(self);
   }
+
+  @protected
+  void sse_encode_vector_store_stats(
+    VectorStoreStats self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(self.entries, serializer);
+    sse_encode_usize(self.deadRows, serializer);
+    sse_encode_usize(self.dimension, serializer);
+    sse_encode_u_64(self.fileBytes, serializer);
+  }
 }
@@ -3,17 +3,35 @@
 
 // ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
 
+import 'agent.dart';
 import 'api.dart';
+import 'chunker.dart';
 import 'clustering.dart';
+import 'context_window.dart';
 import 'dart:async';
 import 'dart:convert';
 import 'dart:ffi' as ffi;
+import 'dedup.dart';
+import 'embedder.dart';
+import 'embedding_cache.dart';
 import 'embeddings.dart';
+import 'filtered_search.dart';
 import 'frb_generated.dart';
+import 'gguf.dart';
 import 'graph.dart';
+import 'hnsw.dart';
+import 'hybrid_search.dart';
+import 'inference.dart';
 import 'mcp.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated_io.dart';
+import 'quantization.dart';
+import 'rag.dart';
+import 'reasoning.dart';
 import 'streaming.dart';
+import 'summarize.dart';
+import 'tokenizer.dart';
+import 'tool_parser.dart';
+import 'vector_store.dart';
 
 abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   RustLibApiImplPlatform({
@@ -26,24 +44,92 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   AnyhowException dco_decode_AnyhowException(dynamic raw);
 
+  @protected
+  RustStreamSink<AgentStep> dco_decode_StreamSink_agent_step_Dco(dynamic raw);
+
+  @protected
+  RustStreamSink<GenerationChunk> dco_decode_StreamSink_generation_chunk_Dco(
+    dynamic raw,
+  );
+
   @protected
   String dco_decode_String(dynamic raw);
 
+  @protected
+  AgentRun dco_decode_agent_run(dynamic raw);
+
+  @protected
+  AgentStep dco_decode_agent_step(dynamic raw);
+
+  @protected
+  AgentStopReason dco_decode_agent_stop_reason(dynamic raw);
+
   @protected
   bool dco_decode_bool(dynamic raw);
 
+  @protected
+  ContextPolicy dco_decode_box_autoadd_context_policy(dynamic raw);
+
+  @protected
+  ContextReport dco_decode_box_autoadd_context_report(dynamic raw);
+
+  @protected
+  DedupOptions dco_decode_box_autoadd_dedup_options(dynamic raw);
+
+  @protected
+  EmbeddingCacheStats dco_decode_box_autoadd_embedding_cache_stats(dynamic raw);
+
+  @protected
+  EmbeddingConfig dco_decode_box_autoadd_embedding_config(dynamic raw);
+
   @protected
   double dco_decode_box_autoadd_f_32(dynamic raw);
 
+  @protected
+  GenerationOptions dco_decode_box_autoadd_generation_options(dynamic raw);
+
+  @protected
+  GenerationResult dco_decode_box_autoadd_generation_result(dynamic raw);
+
+  @protected
+  HnswConfig dco_decode_box_autoadd_hnsw_config(dynamic raw);
+
+  @protected
+  HybridOptions dco_decode_box_autoadd_hybrid_options(dynamic raw);
+
   @protected
   int dco_decode_box_autoadd_i_32(dynamic raw);
 
+  @protected
+  PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw);
+
   @protected
   MCPToolCall dco_decode_box_autoadd_mcp_tool_call(dynamic raw);
 
+  @protected
+  NoteMetadata dco_decode_box_autoadd_note_metadata(dynamic raw);
+
+  @protected
+  ReasoningOptions dco_decode_box_autoadd_reasoning_options(dynamic raw);
+
+  @protected
+  SamplerConfig dco_decode_box_autoadd_sampler_config(dynamic raw);
+
+  @protected
+  SearchOptions dco_decode_box_autoadd_search_options(dynamic raw);
+
+  @protected
+  StopReason dco_decode_box_autoadd_stop_reason(dynamic raw);
+
+  @protected
+  SummaryOptions dco_decode_box_autoadd_summary_options(dynamic raw);
+
   @protected
   int dco_decode_box_autoadd_u_32(dynamic raw);
 
+  @protected
+  BigInt dco_decode_box_autoadd_u_64(dynamic raw);
+
   @protected
   BigInt dco_decode_box_autoadd_usize(dynamic raw);
 
@@ -56,15 +142,66 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   ClusteringResult dco_decode_clustering_result(dynamic raw);
 
+  @protected
+  ContextPolicy dco_decode_context_policy(dynamic raw);
+
+  @protected
+  ContextReport dco_decode_context_report(dynamic raw);
+
+  @protected
+  ContextStrategy dco_decode_context_strategy(dynamic raw);
+
+  @protected
+  DedupDocument dco_decode_dedup_document(dynamic raw);
+
+  @protected
+  DedupOptions dco_decode_dedup_options(dynamic raw);
+
+  @protected
+  DuplicateGroup dco_decode_duplicate_group(dynamic raw);
+
+  @protected
+  DuplicatePair dco_decode_duplicate_pair(dynamic raw);
+
+  @protected
+  DuplicateSpan dco_decode_duplicate_span(dynamic raw);
+
+  @protected
+  EmbeddingCacheStats dco_decode_embedding_cache_stats(dynamic raw);
+
   @protected
   EmbeddingCluster dco_decode_embedding_cluster(dynamic raw);
 
+  @protected
+  EmbeddingConfig dco_decode_embedding_config(dynamic raw);
+
   @protected
   EmbeddingEntry dco_decode_embedding_entry(dynamic raw);
 
+  @protected
+  EmbeddingKind dco_decode_embedding_kind(dynamic raw);
+
   @protected
   double dco_decode_f_32(dynamic raw);
 
+  @protected
+  FusionMode dco_decode_fusion_mode(dynamic raw);
+
+  @protected
+  GenerationChunk dco_decode_generation_chunk(dynamic raw);
+
+  @protected
+  GenerationOptions dco_decode_generation_options(dynamic raw);
+
+  @protected
+  GenerationPoll dco_decode_generation_poll(dynamic raw);
+
+  @protected
+  GenerationResult dco_decode_generation_result(dynamic raw);
+
+  @protected
+  GgufMetadata dco_decode_gguf_metadata(dynamic raw);
+
   @protected
   GraphEdge dco_decode_graph_edge(dynamic raw);
 
@@ -74,21 +211,48 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   GraphState dco_decode_graph_state(dynamic raw);
 
+  @protected
+  HnswConfig dco_decode_hnsw_config(dynamic raw);
+
+  @protected
+  HybridHit dco_decode_hybrid_hit(dynamic raw);
+
+  @protected
+  HybridOptions dco_decode_hybrid_options(dynamic raw);
+
   @protected
   int dco_decode_i_32(dynamic raw);
 
+  @protected
+  PlatformInt64 dco_decode_i_64(dynamic raw);
+
   @protected
   KnowledgeGraphAnalysis dco_decode_knowledge_graph_analysis(dynamic raw);
 
   @protected
   List<String> dco_decode_list_String(dynamic raw);
 
+  @protected
+  List<AgentStep> dco_decode_list_agent_step(dynamic raw);
+
   @protected
   List<ClusterAssignment> dco_decode_list_cluster_assignment(dynamic raw);
 
   @protected
   List<ClusterInfo> dco_decode_list_cluster_info(dynamic raw);
 
+  @protected
+  List<DedupDocument> dco_decode_list_dedup_document(dynamic raw);
+
+  @protected
+  List<DuplicateGroup> dco_decode_list_duplicate_group(dynamic raw);
+
+  @protected
+  List<DuplicatePair> dco_decode_list_duplicate_pair(dynamic raw);
+
+  @protected
+  List<DuplicateSpan> dco_decode_list_duplicate_span(dynamic raw);
+
   @protected
   List<EmbeddingCluster> dco_decode_list_embedding_cluster(dynamic raw);
 
@@ -101,12 +265,30 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   List<GraphNode> dco_decode_list_graph_node(dynamic raw);
 
+  @protected
+  List<HybridHit> dco_decode_list_hybrid_hit(dynamic raw);
+
+  @protected
+  List<Float32List> dco_decode_list_list_prim_f_32_strict(dynamic raw);
+
+  @protected
+  List<LoadedModelInfo> dco_decode_list_loaded_model_info(dynamic raw);
+
+  @protected
+  List<MarkdownChunk> dco_decode_list_markdown_chunk(dynamic raw);
+
   @protected
   List<MCPParameter> dco_decode_list_mcp_parameter(dynamic raw);
 
   @protected
   List<MCPTool> dco_decode_list_mcp_tool(dynamic raw);
 
+  @protected
+  List<MCPToolCall> dco_decode_list_mcp_tool_call(dynamic raw);
+
+  @protected
+  List<MCPToolResult> dco_decode_list_mcp_tool_result(dynamic raw);
+
   @protected
   List<NodePosition> dco_decode_list_node_position(dynamic raw);
 
@@ -116,18 +298,48 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   Float32List dco_decode_list_prim_f_32_strict(dynamic raw);
 
+  @protected
+  List<int> dco_decode_list_prim_i_32_loose(dynamic raw);
+
+  @protected
+  Int32List dco_decode_list_prim_i_32_strict(dynamic raw);
+
+  @protected
+  Uint32List dco_decode_list_prim_u_32_strict(dynamic raw);
+
   @protected
   Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
 
+  @protected
+  List<RagSource> dco_decode_list_rag_source(dynamic raw);
+
   @protected
   List<(String, String)> dco_decode_list_record_string_string(dynamic raw);
 
+  @protected
+  List<ReplySpan> dco_decode_list_reply_span(dynamic raw);
+
   @protected
   List<SemanticEdge> dco_decode_list_semantic_edge(dynamic raw);
 
   @protected
   List<SimilarityResult> dco_decode_list_similarity_result(dynamic raw);
 
+  @protected
+  List<TokenCandidate> dco_decode_list_token_candidate(dynamic raw);
+
+  @protected
+  List<TokenLogprob> dco_decode_list_token_logprob(dynamic raw);
+
+  @protected
+  List<TranscriptSegment> dco_decode_list_transcript_segment(dynamic raw);
+
+  @protected
+  LoadedModelInfo dco_decode_loaded_model_info(dynamic raw);
+
+  @protected
+  MarkdownChunk dco_decode_markdown_chunk(dynamic raw);
+
   @protected
   MCPParamType dco_decode_mcp_param_type(dynamic raw);
 
@@ -147,2897 +359,8965 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   NodePosition dco_decode_node_position(dynamic raw);
 
   @protected
-  String? dco_decode_opt_String(dynamic raw);
+  NoteMetadata dco_decode_note_metadata(dynamic raw);
 
   @protected
-  double? dco_decode_opt_box_autoadd_f_32(dynamic raw);
+  String? dco_decode_opt_String(dynamic raw);
 
   @protected
-  int? dco_decode_opt_box_autoadd_i_32(dynamic raw);
+  ContextPolicy? dco_decode_opt_box_autoadd_context_policy(dynamic raw);
 
   @protected
-  int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
+  ContextReport? dco_decode_opt_box_autoadd_context_report(dynamic raw);
 
   @protected
-  BigInt? dco_decode_opt_box_autoadd_usize(dynamic raw);
+  DedupOptions? dco_decode_opt_box_autoadd_dedup_options(dynamic raw);
 
   @protected
-  List<String>? dco_decode_opt_list_String(dynamic raw);
+  EmbeddingCacheStats? dco_decode_opt_box_autoadd_embedding_cache_stats(
+    dynamic raw,
+  );
 
   @protected
-  Float32List? dco_decode_opt_list_prim_f_32_strict(dynamic raw);
+  EmbeddingConfig? dco_decode_opt_box_autoadd_embedding_config(dynamic raw);
 
   @protected
-  List<(String, String)>? dco_decode_opt_list_record_string_string(dynamic raw);
+  double? dco_decode_opt_box_autoadd_f_32(dynamic raw);
 
   @protected
-  (String, String) dco_decode_record_string_string(dynamic raw);
+  GenerationResult? dco_decode_opt_box_autoadd_generation_result(dynamic raw);
 
   @protected
-  SemanticEdge dco_decode_semantic_edge(dynamic raw);
+  HnswConfig? dco_decode_opt_box_autoadd_hnsw_config(dynamic raw);
 
   @protected
-  SemanticEdgeResult dco_decode_semantic_edge_result(dynamic raw);
+  HybridOptions? dco_decode_opt_box_autoadd_hybrid_options(dynamic raw);
 
   @protected
-  SimilarityResult dco_decode_similarity_result(dynamic raw);
+  int? dco_decode_opt_box_autoadd_i_32(dynamic raw);
 
   @protected
-  StreamGraphStats dco_decode_stream_graph_stats(dynamic raw);
+  PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw);
 
   @protected
-  TaskCategory dco_decode_task_category(dynamic raw);
+  NoteMetadata? dco_decode_opt_box_autoadd_note_metadata(dynamic raw);
 
   @protected
-  int dco_decode_u_32(dynamic raw);
+  ReasoningOptions? dco_decode_opt_box_autoadd_reasoning_options(dynamic raw);
 
   @protected
-  int dco_decode_u_8(dynamic raw);
+  SamplerConfig? dco_decode_opt_box_autoadd_sampler_config(dynamic raw);
 
   @protected
-  void dco_decode_unit(dynamic raw);
+  StopReason? dco_decode_opt_box_autoadd_stop_reason(dynamic raw);
 
   @protected
-  BigInt dco_decode_usize(dynamic raw);
+  SummaryOptions? dco_decode_opt_box_autoadd_summary_options(dynamic raw);
 
   @protected
-  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
+  int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
 
   @protected
-  String sse_decode_String(SseDeserializer deserializer);
+  BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw);
 
   @protected
-  bool sse_decode_bool(SseDeserializer deserializer);
+  BigInt? dco_decode_opt_box_autoadd_usize(dynamic raw);
 
   @protected
-  double sse_decode_box_autoadd_f_32(SseDeserializer deserializer);
+  List<String>? dco_decode_opt_list_String(dynamic raw);
 
   @protected
-  int sse_decode_box_autoadd_i_32(SseDeserializer deserializer);
+  List<Float32List>? dco_decode_opt_list_list_prim_f_32_strict(dynamic raw);
 
   @protected
-  MCPToolCall sse_decode_box_autoadd_mcp_tool_call(
-    SseDeserializer deserializer,
-  );
+  Float32List? dco_decode_opt_list_prim_f_32_strict(dynamic raw);
 
   @protected
-  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
+  List<(String, String)>? dco_decode_opt_list_record_string_string(dynamic raw);
 
   @protected
-  BigInt sse_decode_box_autoadd_usize(SseDeserializer deserializer);
+  List<TokenLogprob>? dco_decode_opt_list_token_logprob(dynamic raw);
 
   @protected
-  ClusterAssignment sse_decode_cluster_assignment(SseDeserializer deserializer);
+  ParsedReply dco_decode_parsed_reply(dynamic raw);
 
   @protected
-  ClusterInfo sse_decode_cluster_info(SseDeserializer deserializer);
+  PoolingMode dco_decode_pooling_mode(dynamic raw);
 
   @protected
-  ClusteringResult sse_decode_clustering_result(SseDeserializer deserializer);
+  QuantizationKind dco_decode_quantization_kind(dynamic raw);
 
   @protected
-  EmbeddingCluster sse_decode_embedding_cluster(SseDeserializer deserializer);
+  RagAnswer dco_decode_rag_answer(dynamic raw);
 
   @protected
-  EmbeddingEntry sse_decode_embedding_entry(SseDeserializer deserializer);
+  RagSource dco_decode_rag_source(dynamic raw);
 
   @protected
-  double sse_decode_f_32(SseDeserializer deserializer);
+  ReasoningOptions dco_decode_reasoning_options(dynamic raw);
 
   @protected
-  GraphEdge sse_decode_graph_edge(SseDeserializer deserializer);
+  RecallReport dco_decode_recall_report(dynamic raw);
 
   @protected
-  GraphNode sse_decode_graph_node(SseDeserializer deserializer);
+  (String, String) dco_decode_record_string_string(dynamic raw);
 
   @protected
-  GraphState sse_decode_graph_state(SseDeserializer deserializer);
+  ReplySpan dco_decode_reply_span(dynamic raw);
 
   @protected
-  int sse_decode_i_32(SseDeserializer deserializer);
+  ReplySpanKind dco_decode_reply_span_kind(dynamic raw);
 
   @protected
-  KnowledgeGraphAnalysis sse_decode_knowledge_graph_analysis(
-    SseDeserializer deserializer,
-  );
+  SamplerConfig dco_decode_sampler_config(dynamic raw);
 
   @protected
-  List<String> sse_decode_list_String(SseDeserializer deserializer);
+  SearchOptions dco_decode_search_options(dynamic raw);
 
   @protected
-  List<ClusterAssignment> sse_decode_list_cluster_assignment(
-    SseDeserializer deserializer,
-  );
+  SemanticEdge dco_decode_semantic_edge(dynamic raw);
 
   @protected
-  List<ClusterInfo> sse_decode_list_cluster_info(SseDeserializer deserializer);
+  SemanticEdgeResult dco_decode_semantic_edge_result(dynamic raw);
 
   @protected
-  List<EmbeddingCluster> sse_decode_list_embedding_cluster(
-    SseDeserializer deserializer,
-  );
+  SimilarityResult dco_decode_similarity_result(dynamic raw);
 
   @protected
-  List<EmbeddingEntry> sse_decode_list_embedding_entry(
-    SseDeserializer deserializer,
-  );
+  StopReason dco_decode_stop_reason(dynamic raw);
 
   @protected
-  List<GraphEdge> sse_decode_list_graph_edge(SseDeserializer deserializer);
+  StreamGraphStats dco_decode_stream_graph_stats(dynamic raw);
 
   @protected
-  List<GraphNode> sse_decode_list_graph_node(SseDeserializer deserializer);
+  Summary dco_decode_summary(dynamic raw);
 
   @protected
-  List<MCPParameter> sse_decode_list_mcp_parameter(
-    SseDeserializer deserializer,
-  );
+  SummaryOptions dco_decode_summary_options(dynamic raw);
 
   @protected
-  List<MCPTool> sse_decode_list_mcp_tool(SseDeserializer deserializer);
+  SummaryStyle dco_decode_summary_style(dynamic raw);
 
   @protected
-  List<NodePosition> sse_decode_list_node_position(
-    SseDeserializer deserializer,
-  );
+  TaskCategory dco_decode_task_category(dynamic raw);
 
   @protected
-  List<double> sse_decode_list_prim_f_32_loose(SseDeserializer deserializer);
+  TokenCandidate dco_decode_token_candidate(dynamic raw);
 
   @protected
-  Float32List sse_decode_list_prim_f_32_strict(SseDeserializer deserializer);
+  TokenLogprob dco_decode_token_logprob(dynamic raw);
 
   @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+  TokenizerInfo dco_decode_tokenizer_info(dynamic raw);
 
   @protected
-  List<(String, String)> sse_decode_list_record_string_string(
-    SseDeserializer deserializer,
-  );
+  TranscriptSegment dco_decode_transcript_segment(dynamic raw);
 
   @protected
-  List<SemanticEdge> sse_decode_list_semantic_edge(
-    SseDeserializer deserializer,
-  );
+  int dco_decode_u_32(dynamic raw);
 
   @protected
-  List<SimilarityResult> sse_decode_list_similarity_result(
-    SseDeserializer deserializer,
-  );
+  BigInt dco_decode_u_64(dynamic raw);
 
   @protected
-  MCPParamType sse_decode_mcp_param_type(SseDeserializer deserializer);
+  int dco_decode_u_8(dynamic raw);
 
   @protected
-  MCPParameter sse_decode_mcp_parameter(SseDeserializer deserializer);
+  void dco_decode_unit(dynamic raw);
 
   @protected
-  MCPTool sse_decode_mcp_tool(SseDeserializer deserializer);
+  BigInt dco_decode_usize(dynamic raw);
 
   @protected
-  MCPToolCall sse_decode_mcp_tool_call(SseDeserializer deserializer);
+  VectorStoreStats dco_decode_vector_store_stats(dynamic raw);
 
   @protected
-  MCPToolResult sse_decode_mcp_tool_result(SseDeserializer deserializer);
+  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
 
   @protected
-  NodePosition sse_decode_node_position(SseDeserializer deserializer);
+  RustStreamSink<AgentStep> sse_decode_StreamSink_agent_step_Dco(
+    SseDeserializer deserializer,
+  );
 
   @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer);
+  RustStreamSink<GenerationChunk> sse_decode_StreamSink_generation_chunk_Dco(
+    SseDeserializer deserializer,
+  );
 
   @protected
-  double? sse_decode_opt_box_autoadd_f_32(SseDeserializer deserializer);
+  String sse_decode_String(SseDeserializer deserializer);
 
   @protected
-  int? sse_decode_opt_box_autoadd_i_32(SseDeserializer deserializer);
+  AgentRun sse_decode_agent_run(SseDeserializer deserializer);
 
   @protected
-  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
+  AgentStep sse_decode_agent_step(SseDeserializer deserializer);
 
   @protected
-  BigInt? sse_decode_opt_box_autoadd_usize(SseDeserializer deserializer);
+  AgentStopReason sse_decode_agent_stop_reason(SseDeserializer deserializer);
 
   @protected
-  List<String>? sse_decode_opt_list_String(SseDeserializer deserializer);
+  bool sse_decode_bool(SseDeserializer deserializer);
 
   @protected
-  Float32List? sse_decode_opt_list_prim_f_32_strict(
+  ContextPolicy sse_decode_box_autoadd_context_policy(
     SseDeserializer deserializer,
   );
 
   @protected
-  List<(String, String)>? sse_decode_opt_list_record_string_string(
+  ContextReport sse_decode_box_autoadd_context_report(
     SseDeserializer deserializer,
   );
 
   @protected
-  (String, String) sse_decode_record_string_string(
+  DedupOptions sse_decode_box_autoadd_dedup_options(
     SseDeserializer deserializer,
   );
 
   @protected
-  SemanticEdge sse_decode_semantic_edge(SseDeserializer deserializer);
+  EmbeddingCacheStats sse_decode_box_autoadd_embedding_cache_stats(
+    SseDeserializer deserializer,
+  );
 
   @protected
-  SemanticEdgeResult sse_decode_semantic_edge_result(
+  EmbeddingConfig sse_decode_box_autoadd_embedding_config(
     SseDeserializer deserializer,
   );
 
   @protected
-  SimilarityResult sse_decode_similarity_result(SseDeserializer deserializer);
+  double sse_decode_box_autoadd_f_32(SseDeserializer deserializer);
 
   @protected
-  StreamGraphStats sse_decode_stream_graph_stats(SseDeserializer deserializer);
+  GenerationOptions sse_decode_box_autoadd_generation_options(
+    SseDeserializer deserializer,
+  );
 
   @protected
-  TaskCategory sse_decode_task_category(SseDeserializer deserializer);
+  GenerationResult sse_decode_box_autoadd_generation_result(
+    SseDeserializer deserializer,
+  );
 
   @protected
-  int sse_decode_u_32(SseDeserializer deserializer);
+  HnswConfig sse_decode_box_autoadd_hnsw_config(SseDeserializer deserializer);
 
   @protected
-  int sse_decode_u_8(SseDeserializer deserializer);
+  HybridOptions sse_decode_box_autoadd_hybrid_options(
+    SseDeserializer deserializer,
+  );
 
   @protected
-  void sse_decode_unit(SseDeserializer deserializer);
+  int sse_decode_box_autoadd_i_32(SseDeserializer deserializer);
 
   @protected
-  BigInt sse_decode_usize(SseDeserializer deserializer);
+  PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer);
 
   @protected
-  ffi.Pointer<wire_cst_list_prim_u_8_strict> cst_encode_AnyhowException(
-    AnyhowException raw,
-  ) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    throw UnimplementedError();
-  }
+  MCPToolCall sse_decode_box_autoadd_mcp_tool_call(
+    SseDeserializer deserializer,
+  );
 
   @protected
-  ffi.Pointer<wire_cst_list_prim_u_8_strict> cst_encode_String(String raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return cst_encode_list_prim_u_8_strict(utf8.encoder.convert(raw));
-  }
+  NoteMetadata sse_decode_box_autoadd_note_metadata(
+    SseDeserializer deserializer,
+  );
 
   @protected
-  ffi.Pointer<ffi.Float> cst_encode_box_autoadd_f_32(double raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return wire.cst_new_box_autoadd_f_32(cst_encode_f_32(raw));
-  }
+  ReasoningOptions sse_decode_box_autoadd_reasoning_options(
+    SseDeserializer deserializer,
+  );
 
   @protected
-  ffi.Pointer<ffi.Int32> cst_encode_box_autoadd_i_32(int raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return wire.cst_new_box_autoadd_i_32(cst_encode_i_32(raw));
-  }
+  SamplerConfig sse_decode_box_autoadd_sampler_config(
+    SseDeserializer deserializer,
+  );
 
   @protected
-  ffi.Pointer<wire_cst_mcp_tool_call> cst_encode_box_autoadd_mcp_tool_call(
-    MCPToolCall raw,
-  ) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    final ptr = wire.cst_new_box_autoadd_mcp_tool_call();
-    cst_api_fill_to_wire_mcp_tool_call(raw, ptr.ref);
-    return ptr;
-  }
+  SearchOptions sse_decode_box_autoadd_search_options(
+    SseDeserializer deserializer,
+  );
 
   @protected
-  ffi.Pointer<ffi.Uint32> cst_encode_box_autoadd_u_32(int raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return wire.cst_new_box_autoadd_u_32(cst_encode_u_32(raw));
-  }
+  StopReason sse_decode_box_autoadd_stop_reason(SseDeserializer deserializer);
 
   @protected
-  ffi.Pointer<ffi.UintPtr> cst_encode_box_autoadd_usize(BigInt raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return wire.cst_new_box_autoadd_usize(cst_encode_usize(raw));
-  }
+  SummaryOptions sse_decode_box_autoadd_summary_options(
+    SseDeserializer deserializer,
+  );
 
   @protected
-  ffi.Pointer<wire_cst_list_String> cst_encode_list_String(List<String> raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    final ans = wire.cst_new_list_String(raw.length);
-    for (var i = 0; i < raw.length; ++i) {
-      ans.ref.ptr[i] = cst_encode_String(raw[i]);
-    }
-    return ans;
-  }
+  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
 
   @protected
-  ffi.Pointer<wire_cst_list_cluster_assignment>
-  cst_encode_list_cluster_assignment(List<ClusterAssignment> raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    final ans = wire.cst_new_list_cluster_assignment(raw.length);
-    for (var i = 0; i < raw.length; ++i) {
-      cst_api_fill_to_wire_cluster_assignment(raw[i], ans.ref.ptr[i]);
-    }
-    return ans;
-  }
+  BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer);
 
   @protected
-  ffi.Pointer<wire_cst_list_cluster_info> cst_encode_list_cluster_info(
-    List<ClusterInfo> raw,
-  ) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    final ans = wire.cst_new_list_cluster_info(raw.length);
-    for (var i = 0; i < raw.length; ++i) {
-      cst_api_fill_to_wire_cluster_info(raw[i], ans.ref.ptr[i]);
-    }
-    return ans;
-  }
+  BigInt sse_decode_box_autoadd_usize(SseDeserializer deserializer);
 
   @protected
-  ffi.Pointer<wire_cst_list_embedding_cluster>
-  cst_encode_list_embedding_cluster(List<EmbeddingCluster> raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    final ans = wire.cst_new_list_embedding_cluster(raw.length);
-    for (var i = 0; i < raw.length; ++i) {
-      cst_api_fill_to_wire_embedding_cluster(raw[i], ans.ref.ptr[i]);
-    }
-    return ans;
-  }
+  ClusterAssignment sse_decode_cluster_assignment(SseDeserializer deserializer);
 
   @protected
-  ffi.Pointer<wire_cst_list_embedding_entry> cst_encode_list_embedding_entry(
-    List<EmbeddingEntry> raw,
-  ) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    final ans = wire.cst_new_list_embedding_entry(raw.length);
-    for (var i = 0; i < raw.length; ++i) {
-      cst_api_fill_to_wire_embedding_entry(raw[i], ans.ref.ptr[i]);
-    }
-    return ans;
-  }
+  ClusterInfo sse_decode_cluster_info(SseDeserializer deserializer);
 
   @protected
-  ffi.Pointer<wire_cst_list_graph_edge> cst_encode_list_graph_edge(
-    List<GraphEdge> raw,
-  ) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    final ans = wire.cst_new_list_graph_edge(raw.length);
-    for (var i = 0; i < raw.length; ++i) {
-      cst_api_fill_to_wire_graph_edge(raw[i], ans.ref.ptr[i]);
-    }
-    return ans;
-  }
+  ClusteringResult sse_decode_clustering_result(SseDeserializer deserializer);
 
   @protected
-  ffi.Pointer<wire_cst_list_graph_node> cst_encode_list_graph_node(
-    List<GraphNode> raw,
-  ) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    final ans = wire.cst_new_list_graph_node(raw.length);
-    for (var i = 0; i < raw.length; ++i) {
-      cst_api_fill_to_wire_graph_node(raw[i], ans.ref.ptr[i]);
-    }
-    return ans;
-  }
+  ContextPolicy sse_decode_context_policy(SseDeserializer deserializer);
 
   @protected
-  ffi.Pointer<wire_cst_list_mcp_parameter> cst_encode_list_mcp_parameter(
-    List<MCPParameter> raw,
-  ) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    final ans = wire.cst_new_list_mcp_parameter(raw.length);
-    for (var i = 0; i < raw.length; ++i) {
-      cst_api_fill_to_wire_mcp_parameter(raw[i], ans.ref.ptr[i]);
-    }
-    return ans;
-  }
+  ContextReport sse_decode_context_report(SseDeserializer deserializer);
 
   @protected
-  ffi.Pointer<wire_cst_list_mcp_tool> cst_encode_list_mcp_tool(
-    List<MCPTool> raw,
-  ) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    final ans = wire.cst_new_list_mcp_tool(raw.length);
-    for (var i = 0; i < raw.length; ++i) {
-      ans.ref.ptr[i] = cst_encode_mcp_tool(raw[i]);
-    }
-    return ans;
-  }
+  ContextStrategy sse_decode_context_strategy(SseDeserializer deserializer);
 
   @protected
-  ffi.Pointer<wire_cst_list_node_position> cst_encode_list_node_position(
-    List<NodePosition> raw,
-  ) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    final ans = wire.cst_new_list_node_position(raw.length);
-    for (var i = 0; i < raw.length; ++i) {
-      cst_api_fill_to_wire_node_position(raw[i], ans.ref.ptr[i]);
-    }
-    return ans;
-  }
+  DedupDocument sse_decode_dedup_document(SseDeserializer deserializer);
 
   @protected
-  ffi.Pointer<wire_cst_list_prim_f_32_loose> cst_encode_list_prim_f_32_loose(
-    List<double> raw,
-  ) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    final ans = wire.cst_new_list_prim_f_32_loose(raw.length);
-    ans.ref.ptr.asTypedList(raw.length).setAll(0, raw);
-    return ans;
-  }
+  DedupOptions sse_decode_dedup_options(SseDeserializer deserializer);
 
   @protected
-  ffi.Pointer<wire_cst_list_prim_f_32_strict> cst_encode_list_prim_f_32_strict(
-    Float32List raw,
-  ) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    final ans = wire.cst_new_list_prim_f_32_strict(raw.length);
-    ans.ref.ptr.asTypedList(raw.length).setAll(0, raw);
-    return ans;
-  }
+  DuplicateGroup sse_decode_duplicate_group(SseDeserializer deserializer);
 
   @protected
-  ffi.Pointer<wire_cst_list_prim_u_8_strict> cst_encode_list_prim_u_8_strict(
-    Uint8List raw,
-  ) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    final ans = wire.cst_new_list_prim_u_8_strict(raw.length);
-    ans.ref.ptr.asTypedList(raw.length).setAll(0, raw);
-    return ans;
-  }
+  DuplicatePair sse_decode_duplicate_pair(SseDeserializer deserializer);
 
   @protected
-  ffi.Pointer<wire_cst_list_record_string_string>
-  cst_encode_list_record_string_string(List<(String, String)> raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    final ans = wire.cst_new_list_record_string_string(raw.length);
-    for (var i = 0; i < raw.length; ++i) {
-      cst_api_fill_to_wire_record_string_string(raw[i], ans.ref.ptr[i]);
-    }
-    return ans;
-  }
+  DuplicateSpan sse_decode_duplicate_span(SseDeserializer deserializer);
 
   @protected
-  ffi.Pointer<wire_cst_list_semantic_edge> cst_encode_list_semantic_edge(
-    List<SemanticEdge> raw,
-  ) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    final ans = wire.cst_new_list_semantic_edge(raw.length);
-    for (var i = 0; i < raw.length; ++i) {
-      cst_api_fill_to_wire_semantic_edge(raw[i], ans.ref.ptr[i]);
-    }
-    return ans;
-  }
+  EmbeddingCacheStats sse_decode_embedding_cache_stats(
+    SseDeserializer deserializer,
+  );
 
   @protected
-  ffi.Pointer<wire_cst_list_similarity_result>
-  cst_encode_list_similarity_result(List<SimilarityResult> raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    final ans = wire.cst_new_list_similarity_result(raw.length);
-    for (var i = 0; i < raw.length; ++i) {
-      cst_api_fill_to_wire_similarity_result(raw[i], ans.ref.ptr[i]);
-    }
-    return ans;
-  }
+  EmbeddingCluster sse_decode_embedding_cluster(SseDeserializer deserializer);
 
   @protected
-  ffi.Pointer<wire_cst_list_prim_u_8_strict> cst_encode_opt_String(
-    String? raw,
-  ) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw == null ? ffi.nullptr : cst_encode_String(raw);
-  }
+  EmbeddingConfig sse_decode_embedding_config(SseDeserializer deserializer);
 
   @protected
-  ffi.Pointer<ffi.Float> cst_encode_opt_box_autoadd_f_32(double? raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw == null ? ffi.nullptr : cst_encode_box_autoadd_f_32(raw);
-  }
+  EmbeddingEntry sse_decode_embedding_entry(SseDeserializer deserializer);
 
   @protected
-  ffi.Pointer<ffi.Int32> cst_encode_opt_box_autoadd_i_32(int? raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw == null ? ffi.nullptr : cst_encode_box_autoadd_i_32(raw);
-  }
+  EmbeddingKind sse_decode_embedding_kind(SseDeserializer deserializer);
 
   @protected
-  ffi.Pointer<ffi.Uint32> cst_encode_opt_box_autoadd_u_32(int? raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw == null ? ffi.nullptr : cst_encode_box_autoadd_u_32(raw);
-  }
+  double sse_decode_f_32(SseDeserializer deserializer);
 
   @protected
-  ffi.Pointer<ffi.UintPtr> cst_encode_opt_box_autoadd_usize(BigInt? raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw == null ? ffi.nullptr : cst_encode_box_autoadd_usize(raw);
-  }
+  FusionMode sse_decode_fusion_mode(SseDeserializer deserializer);
 
   @protected
-  ffi.Pointer<wire_cst_list_String> cst_encode_opt_list_String(
-    List<String>? raw,
-  ) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw == null ? ffi.nullptr : cst_encode_list_String(raw);
-  }
+  GenerationChunk sse_decode_generation_chunk(SseDeserializer deserializer);
 
   @protected
-  ffi.Pointer<wire_cst_list_prim_f_32_strict>
-  cst_encode_opt_list_prim_f_32_strict(Float32List? raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw == null ? ffi.nullptr : cst_encode_list_prim_f_32_strict(raw);
-  }
+  GenerationOptions sse_decode_generation_options(SseDeserializer deserializer);
 
   @protected
-  ffi.Pointer<wire_cst_list_record_string_string>
-  cst_encode_opt_list_record_string_string(List<(String, String)>? raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw == null
-        ? ffi.nullptr
-        : cst_encode_list_record_string_string(raw);
-  }
+  GenerationPoll sse_decode_generation_poll(SseDeserializer deserializer);
 
   @protected
-  int cst_encode_usize(BigInt raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw.toSigned(64).toInt();
-  }
+  GenerationResult sse_decode_generation_result(SseDeserializer deserializer);
 
   @protected
-  void cst_api_fill_to_wire_box_autoadd_mcp_tool_call(
-    MCPToolCall apiObj,
-    ffi.Pointer<wire_cst_mcp_tool_call> wireObj,
-  ) {
-    cst_api_fill_to_wire_mcp_tool_call(apiObj, wireObj.ref);
-  }
+  GgufMetadata sse_decode_gguf_metadata(SseDeserializer deserializer);
 
   @protected
-  void cst_api_fill_to_wire_cluster_assignment(
-    ClusterAssignment apiObj,
-    wire_cst_cluster_assignment wireObj,
-  ) {
-    wireObj.id = cst_encode_String(apiObj.id);
-    wireObj.cluster_id = cst_encode_usize(apiObj.clusterId);
-    wireObj.color = cst_encode_String(apiObj.color);
-  }
+  GraphEdge sse_decode_graph_edge(SseDeserializer deserializer);
 
   @protected
-  void cst_api_fill_to_wire_cluster_info(
-    ClusterInfo apiObj,
-    wire_cst_cluster_info wireObj,
-  ) {
-    wireObj.id = cst_encode_usize(apiObj.id);
-    wireObj.size = cst_encode_usize(apiObj.size);
-    wireObj.color = cst_encode_String(apiObj.color);
-    wireObj.centroid = cst_encode_opt_list_prim_f_32_strict(apiObj.centroid);
-  }
+  GraphNode sse_decode_graph_node(SseDeserializer deserializer);
 
   @protected
-  void cst_api_fill_to_wire_clustering_result(
-    ClusteringResult apiObj,
-    wire_cst_clustering_result wireObj,
-  ) {
-    wireObj.assignments = cst_encode_list_cluster_assignment(
-      apiObj.assignments,
-    );
-    wireObj.clusters = cst_encode_list_cluster_info(apiObj.clusters);
-    wireObj.k = cst_encode_usize(apiObj.k);
-  }
+  GraphState sse_decode_graph_state(SseDeserializer deserializer);
 
   @protected
-  void cst_api_fill_to_wire_embedding_cluster(
-    EmbeddingCluster apiObj,
-    wire_cst_embedding_cluster wireObj,
-  ) {
-    wireObj.ids = cst_encode_list_String(apiObj.ids);
-  }
+  HnswConfig sse_decode_hnsw_config(SseDeserializer deserializer);
 
   @protected
-  void cst_api_fill_to_wire_embedding_entry(
-    EmbeddingEntry apiObj,
-    wire_cst_embedding_entry wireObj,
-  ) {
-    wireObj.id = cst_encode_String(apiObj.id);
-    wireObj.vector = cst_encode_list_prim_f_32_strict(apiObj.vector);
-    wireObj.text_preview = cst_encode_opt_String(apiObj.textPreview);
-  }
+  HybridHit sse_decode_hybrid_hit(SseDeserializer deserializer);
 
   @protected
-  void cst_api_fill_to_wire_graph_edge(
-    GraphEdge apiObj,
-    wire_cst_graph_edge wireObj,
-  ) {
-    wireObj.source = cst_encode_String(apiObj.source);
-    wireObj.target = cst_encode_String(apiObj.target);
-    wireObj.weight = cst_encode_f_32(apiObj.weight);
-    wireObj.edge_type = cst_encode_String(apiObj.edgeType);
-  }
+  HybridOptions sse_decode_hybrid_options(SseDeserializer deserializer);
 
   @protected
-  void cst_api_fill_to_wire_graph_node(
-    GraphNode apiObj,
-    wire_cst_graph_node wireObj,
-  ) {
-    wireObj.id = cst_encode_String(apiObj.id);
-    wireObj.label = cst_encode_String(apiObj.label);
-    wireObj.node_type = cst_encode_String(apiObj.nodeType);
-    wireObj.x = cst_encode_f_32(apiObj.x);
-    wireObj.y = cst_encode_f_32(apiObj.y);
-    wireObj.color = cst_encode_opt_String(apiObj.color);
-    wireObj.metadata = cst_encode_opt_String(apiObj.metadata);
-  }
+  int sse_decode_i_32(SseDeserializer deserializer);
 
   @protected
-  void cst_api_fill_to_wire_graph_state(
-    GraphState apiObj,
-    wire_cst_graph_state wireObj,
-  ) {
-    wireObj.nodes = cst_encode_list_graph_node(apiObj.nodes);
-    wireObj.edges = cst_encode_list_graph_edge(apiObj.edges);
-  }
+  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer);
 
   @protected
-  void cst_api_fill_to_wire_knowledge_graph_analysis(
-    KnowledgeGraphAnalysis apiObj,
-    wire_cst_knowledge_graph_analysis wireObj,
-  ) {
-    cst_api_fill_to_wire_clustering_result(
-      apiObj.clustering,
-      wireObj.clustering,
-    );
-    cst_api_fill_to_wire_semantic_edge_result(
-      apiObj.semanticEdges,
-      wireObj.semantic_edges,
-    );
-  }
+  KnowledgeGraphAnalysis sse_decode_knowledge_graph_analysis(
+    SseDeserializer deserializer,
+  );
 
   @protected
-  void cst_api_fill_to_wire_mcp_parameter(
-    MCPParameter apiObj,
-    wire_cst_mcp_parameter wireObj,
-  ) {
-    wireObj.name = cst_encode_String(apiObj.name);
-    wireObj.description = cst_encode_String(apiObj.description);
-    wireObj.param_type = cst_encode_mcp_param_type(apiObj.paramType);
-    wireObj.required = cst_encode_bool(apiObj.required_);
-  }
+  List<String> sse_decode_list_String(SseDeserializer deserializer);
 
   @protected
-  void cst_api_fill_to_wire_mcp_tool_call(
-    MCPToolCall apiObj,
-    wire_cst_mcp_tool_call wireObj,
-  ) {
-    wireObj.tool = cst_encode_String(apiObj.tool);
-    wireObj.parameters_json = cst_encode_String(apiObj.parametersJson);
-    wireObj.description = cst_encode_String(apiObj.description);
-  }
+  List<AgentStep> sse_decode_list_agent_step(SseDeserializer deserializer);
 
   @protected
-  void cst_api_fill_to_wire_mcp_tool_result(
-    MCPToolResult apiObj,
-    wire_cst_mcp_tool_result wireObj,
-  ) {
-    wireObj.success = cst_encode_bool(apiObj.success);
-    wireObj.result = cst_encode_String(apiObj.result);
-    wireObj.tool = cst_encode_String(apiObj.tool);
-  }
+  List<ClusterAssignment> sse_decode_list_cluster_assignment(
+    SseDeserializer deserializer,
+  );
 
   @protected
-  void cst_api_fill_to_wire_node_position(
-    NodePosition apiObj,
-    wire_cst_node_position wireObj,
-  ) {
-    wireObj.id = cst_encode_String(apiObj.id);
-    wireObj.x = cst_encode_f_32(apiObj.x);
-    wireObj.y = cst_encode_f_32(apiObj.y);
-    wireObj.radius = cst_encode_f_32(apiObj.radius);
-    wireObj.color = cst_encode_u_32(apiObj.color);
-    wireObj.node_type = cst_encode_String(apiObj.nodeType);
-  }
+  List<ClusterInfo> sse_decode_list_cluster_info(SseDeserializer deserializer);
 
   @protected
-  void cst_api_fill_to_wire_record_string_string(
-    (String, String) apiObj,
-    wire_cst_record_string_string wireObj,
-  ) {
-    wireObj.field0 = cst_encode_String(apiObj.$1);
-    wireObj.field1 = cst_encode_String(apiObj.$2);
-  }
+  List<DedupDocument> sse_decode_list_dedup_document(
+    SseDeserializer deserializer,
+  );
 
   @protected
-  void cst_api_fill_to_wire_semantic_edge(
-    SemanticEdge apiObj,
-    wire_cst_semantic_edge wireObj,
-  ) {
-    wireObj.source = cst_encode_String(apiObj.source);
-    wireObj.target = cst_encode_String(apiObj.target);
-    wireObj.similarity = cst_encode_f_32(apiObj.similarity);
-    wireObj.is_ghost = cst_encode_bool(apiObj.isGhost);
-  }
+  List<DuplicateGroup> sse_decode_list_duplicate_group(
+    SseDeserializer deserializer,
+  );
 
   @protected
-  void cst_api_fill_to_wire_semantic_edge_result(
-    SemanticEdgeResult apiObj,
-    wire_cst_semantic_edge_result wireObj,
-  ) {
-    wireObj.edges = cst_encode_list_semantic_edge(apiObj.edges);
-    wireObj.count = cst_encode_usize(apiObj.count);
-  }
+  List<DuplicatePair> sse_decode_list_duplicate_pair(
+    SseDeserializer deserializer,
+  );
 
   @protected
-  void cst_api_fill_to_wire_similarity_result(
-    SimilarityResult apiObj,
-    wire_cst_similarity_result wireObj,
-  ) {
-    wireObj.id = cst_encode_String(apiObj.id);
-    wireObj.score = cst_encode_f_32(apiObj.score);
-    wireObj.text_preview = cst_encode_opt_String(apiObj.textPreview);
-  }
+  List<DuplicateSpan> sse_decode_list_duplicate_span(
+    SseDeserializer deserializer,
+  );
 
   @protected
-  void cst_api_fill_to_wire_stream_graph_stats(
-    StreamGraphStats apiObj,
-    wire_cst_stream_graph_stats wireObj,
-  ) {
-    wireObj.node_count = cst_encode_usize(apiObj.nodeCount);
-    wireObj.edge_count = cst_encode_usize(apiObj.edgeCount);
-    wireObj.visible_count = cst_encode_usize(apiObj.visibleCount);
-  }
+  List<EmbeddingCluster> sse_decode_list_embedding_cluster(
+    SseDeserializer deserializer,
+  );
 
   @protected
-  bool cst_encode_bool(bool raw);
+  List<EmbeddingEntry> sse_decode_list_embedding_entry(
+    SseDeserializer deserializer,
+  );
 
   @protected
-  double cst_encode_f_32(double raw);
+  List<GraphEdge> sse_decode_list_graph_edge(SseDeserializer deserializer);
 
   @protected
-  int cst_encode_i_32(int raw);
+  List<GraphNode> sse_decode_list_graph_node(SseDeserializer deserializer);
 
   @protected
-  int cst_encode_mcp_param_type(MCPParamType raw);
+  List<HybridHit> sse_decode_list_hybrid_hit(SseDeserializer deserializer);
 
   @protected
-  int cst_encode_mcp_tool(MCPTool raw);
+  List<Float32List> sse_decode_list_list_prim_f_32_strict(
+    SseDeserializer deserializer,
+  );
 
   @protected
-  int cst_encode_task_category(TaskCategory raw);
+  List<LoadedModelInfo> sse_decode_list_loaded_model_info(
+    SseDeserializer deserializer,
+  );
 
   @protected
-  int cst_encode_u_32(int raw);
+  List<MarkdownChunk> sse_decode_list_markdown_chunk(
+    SseDeserializer deserializer,
+  );
 
   @protected
-  int cst_encode_u_8(int raw);
+  List<MCPParameter> sse_decode_list_mcp_parameter(
+    SseDeserializer deserializer,
+  );
 
   @protected
-  void cst_encode_unit(void raw);
+  List<MCPTool> sse_decode_list_mcp_tool(SseDeserializer deserializer);
 
   @protected
-  void sse_encode_AnyhowException(
-    AnyhowException self,
-    SseSerializer serializer,
+  List<MCPToolCall> sse_decode_list_mcp_tool_call(SseDeserializer deserializer);
+
+  @protected
+  List<MCPToolResult> sse_decode_list_mcp_tool_result(
+    SseDeserializer deserializer,
   );
 
   @protected
-  void sse_encode_String(String self, SseSerializer serializer);
+  List<NodePosition> sse_decode_list_node_position(
+    SseDeserializer deserializer,
+  );
 
   @protected
-  void sse_encode_bool(bool self, SseSerializer serializer);
+  List<double> sse_decode_list_prim_f_32_loose(SseDeserializer deserializer);
 
   @protected
-  void sse_encode_box_autoadd_f_32(double self, SseSerializer serializer);
+  Float32List sse_decode_list_prim_f_32_strict(SseDeserializer deserializer);
 
   @protected
-  void sse_encode_box_autoadd_i_32(int self, SseSerializer serializer);
+  List<int> sse_decode_list_prim_i_32_loose(SseDeserializer deserializer);
 
   @protected
-  void sse_encode_box_autoadd_mcp_tool_call(
-    MCPToolCall self,
-    SseSerializer serializer,
-  );
+  Int32List sse_decode_list_prim_i_32_strict(SseDeserializer deserializer);
 
   @protected
-  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
+  Uint32List sse_decode_list_prim_u_32_strict(SseDeserializer deserializer);
 
   @protected
-  void sse_encode_box_autoadd_usize(BigInt self, SseSerializer serializer);
+  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
 
   @protected
-  void sse_encode_cluster_assignment(
-    ClusterAssignment self,
-    SseSerializer serializer,
+  List<RagSource> sse_decode_list_rag_source(SseDeserializer deserializer);
+
+  @protected
+  List<(String, String)> sse_decode_list_record_string_string(
+    SseDeserializer deserializer,
   );
 
   @protected
-  void sse_encode_cluster_info(ClusterInfo self, SseSerializer serializer);
+  List<ReplySpan> sse_decode_list_reply_span(SseDeserializer deserializer);
 
   @protected
-  void sse_encode_clustering_result(
-    ClusteringResult self,
-    SseSerializer serializer,
+  List<SemanticEdge> sse_decode_list_semantic_edge(
+    SseDeserializer deserializer,
   );
 
   @protected
-  void sse_encode_embedding_cluster(
-    EmbeddingCluster self,
-    SseSerializer serializer,
+  List<SimilarityResult> sse_decode_list_similarity_result(
+    SseDeserializer deserializer,
   );
 
   @protected
-  void sse_encode_embedding_entry(
-    EmbeddingEntry self,
-    SseSerializer serializer,
+  List<TokenCandidate> sse_decode_list_token_candidate(
+    SseDeserializer deserializer,
   );
 
   @protected
-  void sse_encode_f_32(double self, SseSerializer serializer);
+  List<TokenLogprob> sse_decode_list_token_logprob(
+    SseDeserializer deserializer,
+  );
 
   @protected
-  void sse_encode_graph_edge(GraphEdge self, SseSerializer serializer);
+  List<TranscriptSegment> sse_decode_list_transcript_segment(
+    SseDeserializer deserializer,
+  );
 
   @protected
-  void sse_encode_graph_node(GraphNode self, SseSerializer serializer);
+  LoadedModelInfo sse_decode_loaded_model_info(SseDeserializer deserializer);
 
   @protected
-  void sse_encode_graph_state(GraphState self, SseSerializer serializer);
+  MarkdownChunk sse_decode_markdown_chunk(SseDeserializer deserializer);
 
   @protected
-  void sse_encode_i_32(int self, SseSerializer serializer);
+  MCPParamType sse_decode_mcp_param_type(SseDeserializer deserializer);
 
   @protected
-  void sse_encode_knowledge_graph_analysis(
-    KnowledgeGraphAnalysis self,
-    SseSerializer serializer,
+  MCPParameter sse_decode_mcp_parameter(SseDeserializer deserializer);
+
+  @protected
+  MCPTool sse_decode_mcp_tool(SseDeserializer deserializer);
+
+  @protected
+  MCPToolCall sse_decode_mcp_tool_call(SseDeserializer deserializer);
+
+  @protected
+  MCPToolResult sse_decode_mcp_tool_result(SseDeserializer deserializer);
+
+  @protected
+  NodePosition sse_decode_node_position(SseDeserializer deserializer);
+
+  @protected
+  NoteMetadata sse_decode_note_metadata(SseDeserializer deserializer);
+
+  @protected
+  String? sse_decode_opt_String(SseDeserializer deserializer);
+
+  @protected
+  ContextPolicy? sse_decode_opt_box_autoadd_context_policy(
+    SseDeserializer deserializer,
   );
 
   @protected
-  void sse_encode_list_String(List<String> self, SseSerializer serializer);
+  ContextReport? sse_decode_opt_box_autoadd_context_report(
+    SseDeserializer deserializer,
+  );
 
   @protected
-  void sse_encode_list_cluster_assignment(
-    List<ClusterAssignment> self,
-    SseSerializer serializer,
+  DedupOptions? sse_decode_opt_box_autoadd_dedup_options(
+    SseDeserializer deserializer,
   );
 
   @protected
-  void sse_encode_list_cluster_info(
-    List<ClusterInfo> self,
-    SseSerializer serializer,
+  EmbeddingCacheStats? sse_decode_opt_box_autoadd_embedding_cache_stats(
+    SseDeserializer deserializer,
   );
 
   @protected
-  void sse_encode_list_embedding_cluster(
-    List<EmbeddingCluster> self,
-    SseSerializer serializer,
+  EmbeddingConfig? sse_decode_opt_box_autoadd_embedding_config(
+    SseDeserializer deserializer,
   );
 
   @protected
-  void sse_encode_list_embedding_entry(
-    List<EmbeddingEntry> self,
-    SseSerializer serializer,
+  double? sse_decode_opt_box_autoadd_f_32(SseDeserializer deserializer);
+
+  @protected
+  GenerationResult? sse_decode_opt_box_autoadd_generation_result(
+    SseDeserializer deserializer,
   );
 
   @protected
-  void sse_encode_list_graph_edge(
-    List<GraphEdge> self,
-    SseSerializer serializer,
+  HnswConfig? sse_decode_opt_box_autoadd_hnsw_config(
+    SseDeserializer deserializer,
   );
 
   @protected
-  void sse_encode_list_graph_node(
-    List<GraphNode> self,
-    SseSerializer serializer,
+  HybridOptions? sse_decode_opt_box_autoadd_hybrid_options(
+    SseDeserializer deserializer,
   );
 
   @protected
-  void sse_encode_list_mcp_parameter(
-    List<MCPParameter> self,
-    SseSerializer serializer,
+  int? sse_decode_opt_box_autoadd_i_32(SseDeserializer deserializer);
+
+  @protected
+  PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer);
+
+  @protected
+  NoteMetadata? sse_decode_opt_box_autoadd_note_metadata(
+    SseDeserializer deserializer,
   );
 
   @protected
-  void sse_encode_list_mcp_tool(List<MCPTool> self, SseSerializer serializer);
+  ReasoningOptions? sse_decode_opt_box_autoadd_reasoning_options(
+    SseDeserializer deserializer,
+  );
 
   @protected
-  void sse_encode_list_node_position(
-    List<NodePosition> self,
-    SseSerializer serializer,
+  SamplerConfig? sse_decode_opt_box_autoadd_sampler_config(
+    SseDeserializer deserializer,
   );
 
   @protected
-  void sse_encode_list_prim_f_32_loose(
-    List<double> self,
-    SseSerializer serializer,
+  StopReason? sse_decode_opt_box_autoadd_stop_reason(
+    SseDeserializer deserializer,
   );
 
   @protected
-  void sse_encode_list_prim_f_32_strict(
-    Float32List self,
-    SseSerializer serializer,
+  SummaryOptions? sse_decode_opt_box_autoadd_summary_options(
+    SseDeserializer deserializer,
   );
 
   @protected
-  void sse_encode_list_prim_u_8_strict(
-    Uint8List self,
-    SseSerializer serializer,
+  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
+
+  @protected
+  BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer);
+
+  @protected
+  BigInt? sse_decode_opt_box_autoadd_usize(SseDeserializer deserializer);
+
+  @protected
+  List<String>? sse_decode_opt_list_String(SseDeserializer deserializer);
+
+  @protected
+  List<Float32List>? sse_decode_opt_list_list_prim_f_32_strict(
+    SseDeserializer deserializer,
   );
 
   @protected
-  void sse_encode_list_record_string_string(
-    List<(String, String)> self,
-    SseSerializer serializer,
+  Float32List? sse_decode_opt_list_prim_f_32_strict(
+    SseDeserializer deserializer,
   );
 
   @protected
-  void sse_encode_list_semantic_edge(
-    List<SemanticEdge> self,
-    SseSerializer serializer,
+  List<(String, String)>? sse_decode_opt_list_record_string_string(
+    SseDeserializer deserializer,
   );
 
   @protected
-  void sse_encode_list_similarity_result(
-    List<SimilarityResult> self,
-    SseSerializer serializer,
+  List<TokenLogprob>? sse_decode_opt_list_token_logprob(
+    SseDeserializer deserializer,
   );
 
   @protected
-  void sse_encode_mcp_param_type(MCPParamType self, SseSerializer serializer);
+  ParsedReply sse_decode_parsed_reply(SseDeserializer deserializer);
 
   @protected
-  void sse_encode_mcp_parameter(MCPParameter self, SseSerializer serializer);
+  PoolingMode sse_decode_pooling_mode(SseDeserializer deserializer);
 
   @protected
-  void sse_encode_mcp_tool(MCPTool self, SseSerializer serializer);
+  QuantizationKind sse_decode_quantization_kind(SseDeserializer deserializer);
 
   @protected
-  void sse_encode_mcp_tool_call(MCPToolCall self, SseSerializer serializer);
+  RagAnswer sse_decode_rag_answer(SseDeserializer deserializer);
 
   @protected
-  void sse_encode_mcp_tool_result(MCPToolResult self, SseSerializer serializer);
+  RagSource sse_decode_rag_source(SseDeserializer deserializer);
 
   @protected
-  void sse_encode_node_position(NodePosition self, SseSerializer serializer);
+  ReasoningOptions sse_decode_reasoning_options(SseDeserializer deserializer);
 
   @protected
-  void sse_encode_opt_String(String? self, SseSerializer serializer);
+  RecallReport sse_decode_recall_report(SseDeserializer deserializer);
 
   @protected
-  void sse_encode_opt_box_autoadd_f_32(double? self, SseSerializer serializer);
+  (String, String) sse_decode_record_string_string(
+    SseDeserializer deserializer,
+  );
 
   @protected
-  void sse_encode_opt_box_autoadd_i_32(int? self, SseSerializer serializer);
+  ReplySpan sse_decode_reply_span(SseDeserializer deserializer);
 
   @protected
-  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer);
+  ReplySpanKind sse_decode_reply_span_kind(SseDeserializer deserializer);
 
   @protected
-  void sse_encode_opt_box_autoadd_usize(BigInt? self, SseSerializer serializer);
+  SamplerConfig sse_decode_sampler_config(SseDeserializer deserializer);
 
   @protected
-  void sse_encode_opt_list_String(List<String>? self, SseSerializer serializer);
+  SearchOptions sse_decode_search_options(SseDeserializer deserializer);
 
   @protected
-  void sse_encode_opt_list_prim_f_32_strict(
-    Float32List? self,
-    SseSerializer serializer,
-  );
+  SemanticEdge sse_decode_semantic_edge(SseDeserializer deserializer);
 
   @protected
-  void sse_encode_opt_list_record_string_string(
-    List<(String, String)>? self,
-    SseSerializer serializer,
+  SemanticEdgeResult sse_decode_semantic_edge_result(
+    SseDeserializer deserializer,
   );
 
   @protected
-  void sse_encode_record_string_string(
-    (String, String) self,
-    SseSerializer serializer,
-  );
+  SimilarityResult sse_decode_similarity_result(SseDeserializer deserializer);
 
   @protected
-  void sse_encode_semantic_edge(SemanticEdge self, SseSerializer serializer);
+  StopReason sse_decode_stop_reason(SseDeserializer deserializer);
 
   @protected
-  void sse_encode_semantic_edge_result(
-    SemanticEdgeResult self,
-    SseSerializer serializer,
-  );
+  StreamGraphStats sse_decode_stream_graph_stats(SseDeserializer deserializer);
 
   @protected
-  void sse_encode_similarity_result(
-    SimilarityResult self,
-    SseSerializer serializer,
-  );
+  Summary sse_decode_summary(SseDeserializer deserializer);
 
   @protected
-  void sse_encode_stream_graph_stats(
-    StreamGraphStats self,
-    SseSerializer serializer,
-  );
+  SummaryOptions sse_decode_summary_options(SseDeserializer deserializer);
 
   @protected
-  void sse_encode_task_category(TaskCategory self, SseSerializer serializer);
+  SummaryStyle sse_decode_summary_style(SseDeserializer deserializer);
 
   @protected
-  void sse_encode_u_32(int self, SseSerializer serializer);
+  TaskCategory sse_decode_task_category(SseDeserializer deserializer);
 
   @protected
-  void sse_encode_u_8(int self, SseSerializer serializer);
+  TokenCandidate sse_decode_token_candidate(SseDeserializer deserializer);
 
   @protected
-  void sse_encode_unit(void self, SseSerializer serializer);
+  TokenLogprob sse_decode_token_logprob(SseDeserializer deserializer);
 
   @protected
-  void sse_encode_usize(BigInt self, SseSerializer serializer);
-}
+  TokenizerInfo sse_decode_tokenizer_info(SseDeserializer deserializer);
 
-// Section: wire_class
+  @protected
+  TranscriptSegment sse_decode_transcript_segment(SseDeserializer deserializer);
 
-// ignore_for_file: camel_case_types, non_constant_identifier_names, avoid_positional_boolean_parameters, annotate_overrides, constant_identifier_names
-// AUTO GENERATED FILE, DO NOT EDIT.
-//
-// Generated by `package:ffigen`.
-// ignore_for_file: type=lint, unused_import
+  @protected
+  int sse_decode_u_32(SseDeserializer deserializer);
 
-/// generated by flutter_rust_bridge
-class RustLibWire implements BaseWire {
-  factory RustLibWire.fromExternalLibrary(ExternalLibrary lib) =>
-      RustLibWire(lib.ffiDynamicLibrary);
+  @protected
+  BigInt sse_decode_u_64(SseDeserializer deserializer);
 
-  /// Holds the symbol lookup function.
-  final ffi.Pointer<T> Function<T extends ffi.NativeType>(String symbolName)
-  _lookup;
+  @protected
+  int sse_decode_u_8(SseDeserializer deserializer);
 
-  /// The symbols are looked up in [dynamicLibrary].
-  RustLibWire(ffi.DynamicLibrary dynamicLibrary)
-    : _lookup = dynamicLibrary.lookup;
+  @protected
+  void sse_decode_unit(SseDeserializer deserializer);
 
-  /// The symbols are looked up with [lookup].
-  RustLibWire.fromLookup(
-    ffi.Pointer<T> Function<T extends ffi.NativeType>(String symbolName) lookup,
-  ) : _lookup = lookup;
+  @protected
+  BigInt sse_decode_usize(SseDeserializer deserializer);
 
-  void store_dart_post_cobject(DartPostCObjectFnType ptr) {
-    return _store_dart_post_cobject(ptr);
+  @protected
+  VectorStoreStats sse_decode_vector_store_stats(SseDeserializer deserializer);
+
+  @protected
+  ffi.Pointer<wire_cst_list_prim_u_8_strict> cst_encode_AnyhowException(
+    AnyhowException raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    throw UnimplementedError();
   }
 
-  late final _store_dart_post_cobjectPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(DartPostCObjectFnType)>>(
-        'store_dart_post_cobject',
-      );
-  late final _store_dart_post_cobject = _store_dart_post_cobjectPtr
-      .asFunction<void Function(DartPostCObjectFnType)>();
+  @protected
+  ffi.Pointer<wire_cst_list_prim_u_8_strict>
+  cst_encode_StreamSink_agent_step_Dco(RustStreamSink<AgentStep> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_String(
+      raw.setupAndSerialize(
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_agent_step,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+      ),
+    );
+  }
 
-  void wire__crate__api__add_graph_edge(
-    int port_,
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> source,
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> target,
-    double weight,
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> edge_type,
+  @protected
+  ffi.Pointer<wire_cst_list_prim_u_8_strict>
+  cst_encode_StreamSink_generation_chunk_Dco(
+    RustStreamSink<GenerationChunk> raw,
   ) {
-    return _wire__crate__api__add_graph_edge(
-      port_,
-      source,
-      target,
-      weight,
-      edge_type,
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_String(
+      raw.setupAndSerialize(
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_generation_chunk,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+      ),
     );
   }
 
-  late final _wire__crate__api__add_graph_edgePtr =
-      _lookup<
-        ffi.NativeFunction<
-          ffi.Void Function(
-            ffi.Int64,
-            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-            ffi.Float,
-            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-          )
-        >
-      >('frbgen_kivixa_wire__crate__api__add_graph_edge');
-  late final _wire__crate__api__add_graph_edge =
-      _wire__crate__api__add_graph_edgePtr
-          .asFunction<
-            void Function(
-              int,
-              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-              double,
-              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-            )
-          >();
-
-  void wire__crate__api__add_graph_edges(
-    int port_,
-    ffi.Pointer<wire_cst_list_graph_edge> edges,
+  @protected
+  ffi.Pointer<wire_cst_list_prim_u_8_strict> cst_encode_String(String raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_list_prim_u_8_strict(utf8.encoder.convert(raw));
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_context_policy> cst_encode_box_autoadd_context_policy(
+    ContextPolicy raw,
   ) {
-    return _wire__crate__api__add_graph_edges(port_, edges);
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_context_policy();
+    cst_api_fill_to_wire_context_policy(raw, ptr.ref);
+    return ptr;
   }
 
-  late final _wire__crate__api__add_graph_edgesPtr =
-      _lookup<
-        ffi.NativeFunction<
-          ffi.Void Function(ffi.Int64, ffi.Pointer<wire_cst_list_graph_edge>)
-        >
-      >('frbgen_kivixa_wire__crate__api__add_graph_edges');
-  late final _wire__crate__api__add_graph_edges =
-      _wire__crate__api__add_graph_edgesPtr
-          .asFunction<
-            void Function(int, ffi.Pointer<wire_cst_list_graph_edge>)
-          >();
+  @protected
+  ffi.Pointer<wire_cst_context_report> cst_encode_box_autoadd_context_report(
+    ContextReport raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_context_report();
+    cst_api_fill_to_wire_context_report(raw, ptr.ref);
+    return ptr;
+  }
 
-  void wire__crate__api__add_graph_node(
-    int port_,
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> id,
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> label,
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> node_type,
-    double x,
-    double y,
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> color,
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> metadata,
+  @protected
+  ffi.Pointer<wire_cst_dedup_options> cst_encode_box_autoadd_dedup_options(
+    DedupOptions raw,
   ) {
-    return _wire__crate__api__add_graph_node(
-      port_,
-      id,
-      label,
-      node_type,
-      x,
-      y,
-      color,
-      metadata,
-    );
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_dedup_options();
+    cst_api_fill_to_wire_dedup_options(raw, ptr.ref);
+    return ptr;
   }
 
-  late final _wire__crate__api__add_graph_nodePtr =
-      _lookup<
-        ffi.NativeFunction<
-          ffi.Void Function(
-            ffi.Int64,
-            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-            ffi.Float,
-            ffi.Float,
-            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-          )
-        >
-      >('frbgen_kivixa_wire__crate__api__add_graph_node');
-  late final _wire__crate__api__add_graph_node =
-      _wire__crate__api__add_graph_nodePtr
-          .asFunction<
-            void Function(
-              int,
-              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-              double,
-              double,
-              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-            )
-          >();
+  @protected
+  ffi.Pointer<wire_cst_embedding_cache_stats>
+  cst_encode_box_autoadd_embedding_cache_stats(EmbeddingCacheStats raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_embedding_cache_stats();
+    cst_api_fill_to_wire_embedding_cache_stats(raw, ptr.ref);
+    return ptr;
+  }
 
-  void wire__crate__api__add_graph_nodes(
-    int port_,
-    ffi.Pointer<wire_cst_list_graph_node> nodes,
-  ) {
-    return _wire__crate__api__add_graph_nodes(port_, nodes);
+  @protected
+  ffi.Pointer<wire_cst_embedding_config>
+  cst_encode_box_autoadd_embedding_config(EmbeddingConfig raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_embedding_config();
+    cst_api_fill_to_wire_embedding_config(raw, ptr.ref);
+    return ptr;
   }
 
-  late final _wire__crate__api__add_graph_nodesPtr =
-      _lookup<
-        ffi.NativeFunction<
-          ffi.Void Function(ffi.Int64, ffi.Pointer<wire_cst_list_graph_node>)
-        >
-      >('frbgen_kivixa_wire__crate__api__add_graph_nodes');
-  late final _wire__crate__api__add_graph_nodes =
-      _wire__crate__api__add_graph_nodesPtr
-          .asFunction<
-            void Function(int, ffi.Pointer<wire_cst_list_graph_node>)
-          >();
+  @protected
+  ffi.Pointer<ffi.Float> cst_encode_box_autoadd_f_32(double raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return wire.cst_new_box_autoadd_f_32(cst_encode_f_32(raw));
+  }
 
-  void wire__crate__api__add_stream_edge(
-    int port_,
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> from_id,
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> to_id,
-    double strength,
-  ) {
-    return _wire__crate__api__add_stream_edge(port_, from_id, to_id, strength);
+  @protected
+  ffi.Pointer<wire_cst_generation_options>
+  cst_encode_box_autoadd_generation_options(GenerationOptions raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_generation_options();
+    cst_api_fill_to_wire_generation_options(raw, ptr.ref);
+    return ptr;
   }
 
-  late final _wire__crate__api__add_stream_edgePtr =
-      _lookup<
-        ffi.NativeFunction<
-          ffi.Void Function(
-            ffi.Int64,
-            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-            ffi.Float,
-          )
-        >
-      >('frbgen_kivixa_wire__crate__api__add_stream_edge');
-  late final _wire__crate__api__add_stream_edge =
-      _wire__crate__api__add_stream_edgePtr
-          .asFunction<
-            void Function(
-              int,
-              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-              double,
-            )
-          >();
+  @protected
+  ffi.Pointer<wire_cst_generation_result>
+  cst_encode_box_autoadd_generation_result(GenerationResult raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_generation_result();
+    cst_api_fill_to_wire_generation_result(raw, ptr.ref);
+    return ptr;
+  }
 
-  void wire__crate__api__add_stream_node(
-    int port_,
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> id,
-    double x,
-    double y,
-    double radius,
-    int color,
+  @protected
+  ffi.Pointer<wire_cst_hnsw_config> cst_encode_box_autoadd_hnsw_config(
+    HnswConfig raw,
   ) {
-    return _wire__crate__api__add_stream_node(port_, id, x, y, radius, color);
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_hnsw_config();
+    cst_api_fill_to_wire_hnsw_config(raw, ptr.ref);
+    return ptr;
   }
 
-  late final _wire__crate__api__add_stream_nodePtr =
-      _lookup<
-        ffi.NativeFunction<
-          ffi.Void Function(
-            ffi.Int64,
-            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-            ffi.Float,
-            ffi.Float,
-            ffi.Float,
-            ffi.Uint32,
-          )
-        >
-      >('frbgen_kivixa_wire__crate__api__add_stream_node');
-  late final _wire__crate__api__add_stream_node =
-      _wire__crate__api__add_stream_nodePtr
-          .asFunction<
-            void Function(
-              int,
-              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-              double,
-              double,
-              double,
-              int,
-            )
-          >();
-
-  void wire__crate__api__analyze_knowledge_graph(
-    int port_,
-    ffi.Pointer<wire_cst_list_embedding_entry> entries,
-    ffi.Pointer<ffi.UintPtr> k,
-    ffi.Pointer<ffi.Float> similarity_threshold,
-    ffi.Pointer<wire_cst_list_record_string_string> existing_links,
+  @protected
+  ffi.Pointer<wire_cst_hybrid_options> cst_encode_box_autoadd_hybrid_options(
+    HybridOptions raw,
   ) {
-    return _wire__crate__api__analyze_knowledge_graph(
-      port_,
-      entries,
-      k,
-      similarity_threshold,
-      existing_links,
-    );
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_hybrid_options();
+    cst_api_fill_to_wire_hybrid_options(raw, ptr.ref);
+    return ptr;
   }
 
-  late final _wire__crate__api__analyze_knowledge_graphPtr =
-      _lookup<
-        ffi.NativeFunction<
-          ffi.Void Function(
-            ffi.Int64,
-            ffi.Pointer<wire_cst_list_embedding_entry>,
-            ffi.Pointer<ffi.UintPtr>,
-            ffi.Pointer<ffi.Float>,
-            ffi.Pointer<wire_cst_list_record_string_string>,
-          )
-        >
-      >('frbgen_kivixa_wire__crate__api__analyze_knowledge_graph');
-  late final _wire__crate__api__analyze_knowledge_graph =
-      _wire__crate__api__analyze_knowledge_graphPtr
-          .asFunction<
-            void Function(
-              int,
-              ffi.Pointer<wire_cst_list_embedding_entry>,
-              ffi.Pointer<ffi.UintPtr>,
-              ffi.Pointer<ffi.Float>,
-              ffi.Pointer<wire_cst_list_record_string_string>,
-            )
-          >();
+  @protected
+  ffi.Pointer<ffi.Int32> cst_encode_box_autoadd_i_32(int raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return wire.cst_new_box_autoadd_i_32(cst_encode_i_32(raw));
+  }
 
-  void wire__crate__api__batch_embed(
-    int port_,
-    ffi.Pointer<wire_cst_list_String> texts,
-  ) {
-    return _wire__crate__api__batch_embed(port_, texts);
+  @protected
+  ffi.Pointer<ffi.Int64> cst_encode_box_autoadd_i_64(PlatformInt64 raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return wire.cst_new_box_autoadd_i_64(cst_encode_i_64(raw));
   }
 
-  late final _wire__crate__api__batch_embedPtr =
-      _lookup<
-        ffi.NativeFunction<
-          ffi.Void Function(ffi.Int64, ffi.Pointer<wire_cst_list_String>)
-        >
-      >('frbgen_kivixa_wire__crate__api__batch_embed');
-  late final _wire__crate__api__batch_embed = _wire__crate__api__batch_embedPtr
-      .asFunction<void Function(int, ffi.Pointer<wire_cst_list_String>)>();
+  @protected
+  ffi.Pointer<wire_cst_mcp_tool_call> cst_encode_box_autoadd_mcp_tool_call(
+    MCPToolCall raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_mcp_tool_call();
+    cst_api_fill_to_wire_mcp_tool_call(raw, ptr.ref);
+    return ptr;
+  }
 
-  void wire__crate__api__chat_completion(
-    int port_,
-    ffi.Pointer<wire_cst_list_record_string_string> messages,
-    ffi.Pointer<ffi.Uint32> max_tokens,
+  @protected
+  ffi.Pointer<wire_cst_note_metadata> cst_encode_box_autoadd_note_metadata(
+    NoteMetadata raw,
   ) {
-    return _wire__crate__api__chat_completion(port_, messages, max_tokens);
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_note_metadata();
+    cst_api_fill_to_wire_note_metadata(raw, ptr.ref);
+    return ptr;
   }
 
-  late final _wire__crate__api__chat_completionPtr =
-      _lookup<
-        ffi.NativeFunction<
-          ffi.Void Function(
-            ffi.Int64,
-            ffi.Pointer<wire_cst_list_record_string_string>,
-            ffi.Pointer<ffi.Uint32>,
-          )
-        >
-      >('frbgen_kivixa_wire__crate__api__chat_completion');
-  late final _wire__crate__api__chat_completion =
-      _wire__crate__api__chat_completionPtr
-          .asFunction<
-            void Function(
-              int,
-              ffi.Pointer<wire_cst_list_record_string_string>,
-              ffi.Pointer<ffi.Uint32>,
-            )
-          >();
-
-  WireSyncRust2DartDco wire__crate__api__clear_graph() {
-    return _wire__crate__api__clear_graph();
+  @protected
+  ffi.Pointer<wire_cst_reasoning_options>
+  cst_encode_box_autoadd_reasoning_options(ReasoningOptions raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_reasoning_options();
+    cst_api_fill_to_wire_reasoning_options(raw, ptr.ref);
+    return ptr;
   }
 
-  late final _wire__crate__api__clear_graphPtr =
-      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>(
-        'frbgen_kivixa_wire__crate__api__clear_graph',
-      );
-  late final _wire__crate__api__clear_graph = _wire__crate__api__clear_graphPtr
-      .asFunction<WireSyncRust2DartDco Function()>();
-
-  WireSyncRust2DartDco wire__crate__api__clear_stream_graph() {
-    return _wire__crate__api__clear_stream_graph();
+  @protected
+  ffi.Pointer<wire_cst_sampler_config> cst_encode_box_autoadd_sampler_config(
+    SamplerConfig raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_sampler_config();
+    cst_api_fill_to_wire_sampler_config(raw, ptr.ref);
+    return ptr;
   }
 
-  late final _wire__crate__api__clear_stream_graphPtr =
-      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>(
-        'frbgen_kivixa_wire__crate__api__clear_stream_graph',
-      );
-  late final _wire__crate__api__clear_stream_graph =
-      _wire__crate__api__clear_stream_graphPtr
-          .asFunction<WireSyncRust2DartDco Function()>();
-
-  void wire__crate__api__cluster_embeddings(
-    int port_,
-    ffi.Pointer<wire_cst_list_embedding_entry> entries,
-    double threshold,
+  @protected
+  ffi.Pointer<wire_cst_search_options> cst_encode_box_autoadd_search_options(
+    SearchOptions raw,
   ) {
-    return _wire__crate__api__cluster_embeddings(port_, entries, threshold);
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_search_options();
+    cst_api_fill_to_wire_search_options(raw, ptr.ref);
+    return ptr;
   }
 
-  late final _wire__crate__api__cluster_embeddingsPtr =
-      _lookup<
-        ffi.NativeFunction<
-          ffi.Void Function(
-            ffi.Int64,
-            ffi.Pointer<wire_cst_list_embedding_entry>,
-            ffi.Float,
-          )
-        >
-      >('frbgen_kivixa_wire__crate__api__cluster_embeddings');
-  late final _wire__crate__api__cluster_embeddings =
-      _wire__crate__api__cluster_embeddingsPtr
-          .asFunction<
-            void Function(
-              int,
-              ffi.Pointer<wire_cst_list_embedding_entry>,
-              double,
-            )
-          >();
+  @protected
+  ffi.Pointer<ffi.Int32> cst_encode_box_autoadd_stop_reason(StopReason raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return wire.cst_new_box_autoadd_stop_reason(cst_encode_stop_reason(raw));
+  }
 
-  void wire__crate__api__cluster_notes(
-    int port_,
-    ffi.Pointer<wire_cst_list_embedding_entry> entries,
-    ffi.Pointer<ffi.UintPtr> k,
-    ffi.Pointer<ffi.UintPtr> max_iterations,
+  @protected
+  ffi.Pointer<wire_cst_summary_options> cst_encode_box_autoadd_summary_options(
+    SummaryOptions raw,
   ) {
-    return _wire__crate__api__cluster_notes(port_, entries, k, max_iterations);
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_summary_options();
+    cst_api_fill_to_wire_summary_options(raw, ptr.ref);
+    return ptr;
   }
 
-  late final _wire__crate__api__cluster_notesPtr =
-      _lookup<
-        ffi.NativeFunction<
-          ffi.Void Function(
-            ffi.Int64,
-            ffi.Pointer<wire_cst_list_embedding_entry>,
-            ffi.Pointer<ffi.UintPtr>,
-            ffi.Pointer<ffi.UintPtr>,
-          )
-        >
-      >('frbgen_kivixa_wire__crate__api__cluster_notes');
-  late final _wire__crate__api__cluster_notes =
-      _wire__crate__api__cluster_notesPtr
-          .asFunction<
-            void Function(
-              int,
-              ffi.Pointer<wire_cst_list_embedding_entry>,
-              ffi.Pointer<ffi.UintPtr>,
-              ffi.Pointer<ffi.UintPtr>,
-            )
-          >();
+  @protected
+  ffi.Pointer<ffi.Uint32> cst_encode_box_autoadd_u_32(int raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return wire.cst_new_box_autoadd_u_32(cst_encode_u_32(raw));
+  }
 
-  void wire__crate__api__compute_graph_layout(
-    int port_,
-    ffi.Pointer<ffi.Uint32> iterations,
-  ) {
-    return _wire__crate__api__compute_graph_layout(port_, iterations);
+  @protected
+  ffi.Pointer<ffi.Uint64> cst_encode_box_autoadd_u_64(BigInt raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return wire.cst_new_box_autoadd_u_64(cst_encode_u_64(raw));
   }
 
-  late final _wire__crate__api__compute_graph_layoutPtr =
-      _lookup<
-        ffi.NativeFunction<
-          ffi.Void Function(ffi.Int64, ffi.Pointer<ffi.Uint32>)
-        >
-      >('frbgen_kivixa_wire__crate__api__compute_graph_layout');
-  late final _wire__crate__api__compute_graph_layout =
-      _wire__crate__api__compute_graph_layoutPtr
-          .asFunction<void Function(int, ffi.Pointer<ffi.Uint32>)>();
+  @protected
+  ffi.Pointer<ffi.UintPtr> cst_encode_box_autoadd_usize(BigInt raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return wire.cst_new_box_autoadd_usize(cst_encode_usize(raw));
+  }
 
-  void wire__crate__api__connect_note_to_topics(
-    int port_,
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> note_id,
-    ffi.Pointer<wire_cst_list_String> topic_ids,
-  ) {
-    return _wire__crate__api__connect_note_to_topics(port_, note_id, topic_ids);
+  @protected
+  int cst_encode_i_64(PlatformInt64 raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw.toInt();
   }
 
-  late final _wire__crate__api__connect_note_to_topicsPtr =
-      _lookup<
-        ffi.NativeFunction<
-          ffi.Void Function(
-            ffi.Int64,
-            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-            ffi.Pointer<wire_cst_list_String>,
-          )
-        >
-      >('frbgen_kivixa_wire__crate__api__connect_note_to_topics');
-  late final _wire__crate__api__connect_note_to_topics =
-      _wire__crate__api__connect_note_to_topicsPtr
-          .asFunction<
-            void Function(
-              int,
-              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-              ffi.Pointer<wire_cst_list_String>,
-            )
-          >();
+  @protected
+  ffi.Pointer<wire_cst_list_String> cst_encode_list_String(List<String> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ans = wire.cst_new_list_String(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      ans.ref.ptr[i] = cst_encode_String(raw[i]);
+    }
+    return ans;
+  }
 
-  WireSyncRust2DartDco wire__crate__api__cosine_similarity(
-    ffi.Pointer<wire_cst_list_prim_f_32_loose> a,
-    ffi.Pointer<wire_cst_list_prim_f_32_loose> b,
+  @protected
+  ffi.Pointer<wire_cst_list_agent_step> cst_encode_list_agent_step(
+    List<AgentStep> raw,
   ) {
-    return _wire__crate__api__cosine_similarity(a, b);
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ans = wire.cst_new_list_agent_step(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      cst_api_fill_to_wire_agent_step(raw[i], ans.ref.ptr[i]);
+    }
+    return ans;
   }
 
-  late final _wire__crate__api__cosine_similarityPtr =
-      _lookup<
-        ffi.NativeFunction<
-          WireSyncRust2DartDco Function(
-            ffi.Pointer<wire_cst_list_prim_f_32_loose>,
-            ffi.Pointer<wire_cst_list_prim_f_32_loose>,
-          )
-        >
-      >('frbgen_kivixa_wire__crate__api__cosine_similarity');
-  late final _wire__crate__api__cosine_similarity =
-      _wire__crate__api__cosine_similarityPtr
-          .asFunction<
-            WireSyncRust2DartDco Function(
-              ffi.Pointer<wire_cst_list_prim_f_32_loose>,
-              ffi.Pointer<wire_cst_list_prim_f_32_loose>,
-            )
-          >();
+  @protected
+  ffi.Pointer<wire_cst_list_cluster_assignment>
+  cst_encode_list_cluster_assignment(List<ClusterAssignment> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ans = wire.cst_new_list_cluster_assignment(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      cst_api_fill_to_wire_cluster_assignment(raw[i], ans.ref.ptr[i]);
+    }
+    return ans;
+  }
 
-  void wire__crate__api__discover_semantic_edges(
-    int port_,
-    ffi.Pointer<wire_cst_list_embedding_entry> entries,
-    ffi.Pointer<ffi.Float> threshold,
-    ffi.Pointer<wire_cst_list_record_string_string> existing_links,
+  @protected
+  ffi.Pointer<wire_cst_list_cluster_info> cst_encode_list_cluster_info(
+    List<ClusterInfo> raw,
   ) {
-    return _wire__crate__api__discover_semantic_edges(
-      port_,
-      entries,
-      threshold,
-      existing_links,
-    );
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ans = wire.cst_new_list_cluster_info(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      cst_api_fill_to_wire_cluster_info(raw[i], ans.ref.ptr[i]);
+    }
+    return ans;
   }
 
-  late final _wire__crate__api__discover_semantic_edgesPtr =
-      _lookup<
-        ffi.NativeFunction<
-          ffi.Void Function(
-            ffi.Int64,
-            ffi.Pointer<wire_cst_list_embedding_entry>,
-            ffi.Pointer<ffi.Float>,
-            ffi.Pointer<wire_cst_list_record_string_string>,
-          )
-        >
-      >('frbgen_kivixa_wire__crate__api__discover_semantic_edges');
-  late final _wire__crate__api__discover_semantic_edges =
-      _wire__crate__api__discover_semantic_edgesPtr
-          .asFunction<
-            void Function(
-              int,
-              ffi.Pointer<wire_cst_list_embedding_entry>,
-              ffi.Pointer<ffi.Float>,
-              ffi.Pointer<wire_cst_list_record_string_string>,
-            )
-          >();
-
-  void wire__crate__api__extract_topics(
-    int port_,
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> text,
-    ffi.Pointer<ffi.Uint32> num_topics,
+  @protected
+  ffi.Pointer<wire_cst_list_dedup_document> cst_encode_list_dedup_document(
+    List<DedupDocument> raw,
   ) {
-    return _wire__crate__api__extract_topics(port_, text, num_topics);
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ans = wire.cst_new_list_dedup_document(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      cst_api_fill_to_wire_dedup_document(raw[i], ans.ref.ptr[i]);
+    }
+    return ans;
   }
 
-  late final _wire__crate__api__extract_topicsPtr =
-      _lookup<
-        ffi.NativeFunction<
-          ffi.Void Function(
-            ffi.Int64,
-            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-            ffi.Pointer<ffi.Uint32>,
-          )
-        >
-      >('frbgen_kivixa_wire__crate__api__extract_topics');
-  late final _wire__crate__api__extract_topics =
-      _wire__crate__api__extract_topicsPtr
-          .asFunction<
-            void Function(
-              int,
-              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-              ffi.Pointer<ffi.Uint32>,
-            )
-          >();
-
-  void wire__crate__api__find_similar(
-    int port_,
-    ffi.Pointer<wire_cst_list_prim_f_32_loose> query,
-    ffi.Pointer<wire_cst_list_embedding_entry> entries,
-    int top_k,
-    double threshold,
+  @protected
+  ffi.Pointer<wire_cst_list_duplicate_group> cst_encode_list_duplicate_group(
+    List<DuplicateGroup> raw,
   ) {
-    return _wire__crate__api__find_similar(
-      port_,
-      query,
-      entries,
-      top_k,
-      threshold,
-    );
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ans = wire.cst_new_list_duplicate_group(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      cst_api_fill_to_wire_duplicate_group(raw[i], ans.ref.ptr[i]);
+    }
+    return ans;
   }
 
-  late final _wire__crate__api__find_similarPtr =
-      _lookup<
-        ffi.NativeFunction<
-          ffi.Void Function(
-            ffi.Int64,
-            ffi.Pointer<wire_cst_list_prim_f_32_loose>,
-            ffi.Pointer<wire_cst_list_embedding_entry>,
-            ffi.UintPtr,
-            ffi.Float,
-          )
-        >
-      >('frbgen_kivixa_wire__crate__api__find_similar');
-  late final _wire__crate__api__find_similar =
-      _wire__crate__api__find_similarPtr
-          .asFunction<
-            void Function(
-              int,
-              ffi.Pointer<wire_cst_list_prim_f_32_loose>,
-              ffi.Pointer<wire_cst_list_embedding_entry>,
-              int,
-              double,
-            )
-          >();
+  @protected
+  ffi.Pointer<wire_cst_list_duplicate_pair> cst_encode_list_duplicate_pair(
+    List<DuplicatePair> raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ans = wire.cst_new_list_duplicate_pair(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      cst_api_fill_to_wire_duplicate_pair(raw[i], ans.ref.ptr[i]);
+    }
+    return ans;
+  }
 
-  void wire__crate__api__generate_text(
-    int port_,
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> prompt,
-    ffi.Pointer<ffi.Uint32> max_tokens,
+  @protected
+  ffi.Pointer<wire_cst_list_duplicate_span> cst_encode_list_duplicate_span(
+    List<DuplicateSpan> raw,
   ) {
-    return _wire__crate__api__generate_text(port_, prompt, max_tokens);
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ans = wire.cst_new_list_duplicate_span(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      cst_api_fill_to_wire_duplicate_span(raw[i], ans.ref.ptr[i]);
+    }
+    return ans;
   }
 
-  late final _wire__crate__api__generate_textPtr =
-      _lookup<
-        ffi.NativeFunction<
-          ffi.Void Function(
-            ffi.Int64,
-            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-            ffi.Pointer<ffi.Uint32>,
-          )
-        >
-      >('frbgen_kivixa_wire__crate__api__generate_text');
-  late final _wire__crate__api__generate_text =
-      _wire__crate__api__generate_textPtr
-          .asFunction<
-            void Function(
-              int,
-              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-              ffi.Pointer<ffi.Uint32>,
-            )
-          >();
+  @protected
+  ffi.Pointer<wire_cst_list_embedding_cluster>
+  cst_encode_list_embedding_cluster(List<EmbeddingCluster> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ans = wire.cst_new_list_embedding_cluster(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      cst_api_fill_to_wire_embedding_cluster(raw[i], ans.ref.ptr[i]);
+    }
+    return ans;
+  }
 
-  void wire__crate__api__get_embedding(
-    int port_,
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> text,
+  @protected
+  ffi.Pointer<wire_cst_list_embedding_entry> cst_encode_list_embedding_entry(
+    List<EmbeddingEntry> raw,
   ) {
-    return _wire__crate__api__get_embedding(port_, text);
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ans = wire.cst_new_list_embedding_entry(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      cst_api_fill_to_wire_embedding_entry(raw[i], ans.ref.ptr[i]);
+    }
+    return ans;
   }
 
-  late final _wire__crate__api__get_embeddingPtr =
-      _lookup<
-        ffi.NativeFunction<
-          ffi.Void Function(
-            ffi.Int64,
-            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-          )
-        >
-      >('frbgen_kivixa_wire__crate__api__get_embedding');
-  late final _wire__crate__api__get_embedding =
-      _wire__crate__api__get_embeddingPtr
-          .asFunction<
-            void Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)
-          >();
+  @protected
+  ffi.Pointer<wire_cst_list_graph_edge> cst_encode_list_graph_edge(
+    List<GraphEdge> raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ans = wire.cst_new_list_graph_edge(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      cst_api_fill_to_wire_graph_edge(raw[i], ans.ref.ptr[i]);
+    }
+    return ans;
+  }
 
-  WireSyncRust2DartDco wire__crate__api__get_embedding_dimension() {
-    return _wire__crate__api__get_embedding_dimension();
+  @protected
+  ffi.Pointer<wire_cst_list_graph_node> cst_encode_list_graph_node(
+    List<GraphNode> raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ans = wire.cst_new_list_graph_node(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      cst_api_fill_to_wire_graph_node(raw[i], ans.ref.ptr[i]);
+    }
+    return ans;
   }
 
-  late final _wire__crate__api__get_embedding_dimensionPtr =
-      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>(
-        'frbgen_kivixa_wire__crate__api__get_embedding_dimension',
-      );
-  late final _wire__crate__api__get_embedding_dimension =
-      _wire__crate__api__get_embedding_dimensionPtr
-          .asFunction<WireSyncRust2DartDco Function()>();
+  @protected
+  ffi.Pointer<wire_cst_list_hybrid_hit> cst_encode_list_hybrid_hit(
+    List<HybridHit> raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ans = wire.cst_new_list_hybrid_hit(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      cst_api_fill_to_wire_hybrid_hit(raw[i], ans.ref.ptr[i]);
+    }
+    return ans;
+  }
 
-  void wire__crate__api__get_graph_state(int port_) {
-    return _wire__crate__api__get_graph_state(port_);
+  @protected
+  ffi.Pointer<wire_cst_list_list_prim_f_32_strict>
+  cst_encode_list_list_prim_f_32_strict(List<Float32List> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ans = wire.cst_new_list_list_prim_f_32_strict(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      ans.ref.ptr[i] = cst_encode_list_prim_f_32_strict(raw[i]);
+    }
+    return ans;
   }
 
-  late final _wire__crate__api__get_graph_statePtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>(
-        'frbgen_kivixa_wire__crate__api__get_graph_state',
-      );
-  late final _wire__crate__api__get_graph_state =
-      _wire__crate__api__get_graph_statePtr.asFunction<void Function(int)>();
+  @protected
+  ffi.Pointer<wire_cst_list_loaded_model_info>
+  cst_encode_list_loaded_model_info(List<LoadedModelInfo> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ans = wire.cst_new_list_loaded_model_info(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      cst_api_fill_to_wire_loaded_model_info(raw[i], ans.ref.ptr[i]);
+    }
+    return ans;
+  }
 
-  WireSyncRust2DartDco wire__crate__api__get_model_type() {
-    return _wire__crate__api__get_model_type();
+  @protected
+  ffi.Pointer<wire_cst_list_markdown_chunk> cst_encode_list_markdown_chunk(
+    List<MarkdownChunk> raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ans = wire.cst_new_list_markdown_chunk(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      cst_api_fill_to_wire_markdown_chunk(raw[i], ans.ref.ptr[i]);
+    }
+    return ans;
   }
 
-  late final _wire__crate__api__get_model_typePtr =
-      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>(
-        'frbgen_kivixa_wire__crate__api__get_model_type',
-      );
-  late final _wire__crate__api__get_model_type =
-      _wire__crate__api__get_model_typePtr
-          .asFunction<WireSyncRust2DartDco Function()>();
+  @protected
+  ffi.Pointer<wire_cst_list_mcp_parameter> cst_encode_list_mcp_parameter(
+    List<MCPParameter> raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ans = wire.cst_new_list_mcp_parameter(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      cst_api_fill_to_wire_mcp_parameter(raw[i], ans.ref.ptr[i]);
+    }
+    return ans;
+  }
 
-  void wire__crate__api__get_or_create_topic_hub(
-    int port_,
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> topic,
+  @protected
+  ffi.Pointer<wire_cst_list_mcp_tool> cst_encode_list_mcp_tool(
+    List<MCPTool> raw,
   ) {
-    return _wire__crate__api__get_or_create_topic_hub(port_, topic);
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ans = wire.cst_new_list_mcp_tool(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      ans.ref.ptr[i] = cst_encode_mcp_tool(raw[i]);
+    }
+    return ans;
   }
 
-  late final _wire__crate__api__get_or_create_topic_hubPtr =
-      _lookup<
-        ffi.NativeFunction<
-          ffi.Void Function(
-            ffi.Int64,
-            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-          )
-        >
-      >('frbgen_kivixa_wire__crate__api__get_or_create_topic_hub');
-  late final _wire__crate__api__get_or_create_topic_hub =
-      _wire__crate__api__get_or_create_topic_hubPtr
-          .asFunction<
-            void Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)
-          >();
+  @protected
+  ffi.Pointer<wire_cst_list_mcp_tool_call> cst_encode_list_mcp_tool_call(
+    List<MCPToolCall> raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ans = wire.cst_new_list_mcp_tool_call(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      cst_api_fill_to_wire_mcp_tool_call(raw[i], ans.ref.ptr[i]);
+    }
+    return ans;
+  }
 
-  WireSyncRust2DartDco wire__crate__api__get_stream_graph_stats() {
-    return _wire__crate__api__get_stream_graph_stats();
+  @protected
+  ffi.Pointer<wire_cst_list_mcp_tool_result> cst_encode_list_mcp_tool_result(
+    List<MCPToolResult> raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ans = wire.cst_new_list_mcp_tool_result(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      cst_api_fill_to_wire_mcp_tool_result(raw[i], ans.ref.ptr[i]);
+    }
+    return ans;
   }
 
-  late final _wire__crate__api__get_stream_graph_statsPtr =
+  @protected
+  ffi.Pointer<wire_cst_list_node_position> cst_encode_list_node_position(
+    List<NodePosition> raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ans = wire.cst_new_list_node_position(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      cst_api_fill_to_wire_node_position(raw[i], ans.ref.ptr[i]);
+    }
+    return ans;
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_list_prim_f_32_loose> cst_encode_list_prim_f_32_loose(
+    List<double> raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ans = wire.cst_new_list_prim_f_32_loose(raw.length);
+    ans.ref.ptr.asTypedList(raw.length).setAll(0, raw);
+    return ans;
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_list_prim_f_32_strict> cst_encode_list_prim_f_32_strict(
+    Float32List raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ans = wire.cst_new_list_prim_f_32_strict(raw.length);
+    ans.ref.ptr.asTypedList(raw.length).setAll(0, raw);
+    return ans;
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_list_prim_i_32_loose> cst_encode_list_prim_i_32_loose(
+    List<int> raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ans = wire.cst_new_list_prim_i_32_loose(raw.length);
+    ans.ref.ptr.asTypedList(raw.length).setAll(0, raw);
+    return ans;
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_list_prim_i_32_strict> cst_encode_list_prim_i_32_strict(
+    Int32List raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ans = wire.cst_new_list_prim_i_32_strict(raw.length);
+    ans.ref.ptr.asTypedList(raw.length).setAll(0, raw);
+    return ans;
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_list_prim_u_32_strict> cst_encode_list_prim_u_32_strict(
+    Uint32List raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ans = wire.cst_new_list_prim_u_32_strict(raw.length);
+    ans.ref.ptr.asTypedList(raw.length).setAll(0, raw);
+    return ans;
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_list_prim_u_8_strict> cst_encode_list_prim_u_8_strict(
+    Uint8List raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ans = wire.cst_new_list_prim_u_8_strict(raw.length);
+    ans.ref.ptr.asTypedList(raw.length).setAll(0, raw);
+    return ans;
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_list_rag_source> cst_encode_list_rag_source(
+    List<RagSource> raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ans = wire.cst_new_list_rag_source(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      cst_api_fill_to_wire_rag_source(raw[i], ans.ref.ptr[i]);
+    }
+    return ans;
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_list_record_string_string>
+  cst_encode_list_record_string_string(List<(String, String)> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ans = wire.cst_new_list_record_string_string(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      cst_api_fill_to_wire_record_string_string(raw[i], ans.ref.ptr[i]);
+    }
+    return ans;
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_list_reply_span> cst_encode_list_reply_span(
+    List<ReplySpan> raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ans = wire.cst_new_list_reply_span(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      cst_api_fill_to_wire_reply_span(raw[i], ans.ref.ptr[i]);
+    }
+    return ans;
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_list_semantic_edge> cst_encode_list_semantic_edge(
+    List<SemanticEdge> raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ans = wire.cst_new_list_semantic_edge(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      cst_api_fill_to_wire_semantic_edge(raw[i], ans.ref.ptr[i]);
+    }
+    return ans;
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_list_similarity_result>
+  cst_encode_list_similarity_result(List<SimilarityResult> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ans = wire.cst_new_list_similarity_result(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      cst_api_fill_to_wire_similarity_result(raw[i], ans.ref.ptr[i]);
+    }
+    return ans;
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_list_token_candidate> cst_encode_list_token_candidate(
+    List<TokenCandidate> raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ans = wire.cst_new_list_token_candidate(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      cst_api_fill_to_wire_token_candidate(raw[i], ans.ref.ptr[i]);
+    }
+    return ans;
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_list_token_logprob> cst_encode_list_token_logprob(
+    List<TokenLogprob> raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ans = wire.cst_new_list_token_logprob(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      cst_api_fill_to_wire_token_logprob(raw[i], ans.ref.ptr[i]);
+    }
+    return ans;
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_list_transcript_segment>
+  cst_encode_list_transcript_segment(List<TranscriptSegment> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ans = wire.cst_new_list_transcript_segment(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      cst_api_fill_to_wire_transcript_segment(raw[i], ans.ref.ptr[i]);
+    }
+    return ans;
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_list_prim_u_8_strict> cst_encode_opt_String(
+    String? raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? ffi.nullptr : cst_encode_String(raw);
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_context_policy>
+  cst_encode_opt_box_autoadd_context_policy(ContextPolicy? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null
+        ? ffi.nullptr
+        : cst_encode_box_autoadd_context_policy(raw);
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_context_report>
+  cst_encode_opt_box_autoadd_context_report(ContextReport? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null
+        ? ffi.nullptr
+        : cst_encode_box_autoadd_context_report(raw);
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_dedup_options> cst_encode_opt_box_autoadd_dedup_options(
+    DedupOptions? raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null
+        ? ffi.nullptr
+        : cst_encode_box_autoadd_dedup_options(raw);
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_embedding_cache_stats>
+  cst_encode_opt_box_autoadd_embedding_cache_stats(EmbeddingCacheStats? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null
+        ? ffi.nullptr
+        : cst_encode_box_autoadd_embedding_cache_stats(raw);
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_embedding_config>
+  cst_encode_opt_box_autoadd_embedding_config(EmbeddingConfig? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null
+        ? ffi.nullptr
+        : cst_encode_box_autoadd_embedding_config(raw);
+  }
+
+  @protected
+  ffi.Pointer<ffi.Float> cst_encode_opt_box_autoadd_f_32(double? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? ffi.nullptr : cst_encode_box_autoadd_f_32(raw);
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_generation_result>
+  cst_encode_opt_box_autoadd_generation_result(GenerationResult? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null
+        ? ffi.nullptr
+        : cst_encode_box_autoadd_generation_result(raw);
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_hnsw_config> cst_encode_opt_box_autoadd_hnsw_config(
+    HnswConfig? raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? ffi.nullptr : cst_encode_box_autoadd_hnsw_config(raw);
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_hybrid_options>
+  cst_encode_opt_box_autoadd_hybrid_options(HybridOptions? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null
+        ? ffi.nullptr
+        : cst_encode_box_autoadd_hybrid_options(raw);
+  }
+
+  @protected
+  ffi.Pointer<ffi.Int32> cst_encode_opt_box_autoadd_i_32(int? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? ffi.nullptr : cst_encode_box_autoadd_i_32(raw);
+  }
+
+  @protected
+  ffi.Pointer<ffi.Int64> cst_encode_opt_box_autoadd_i_64(PlatformInt64? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? ffi.nullptr : cst_encode_box_autoadd_i_64(raw);
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_note_metadata> cst_encode_opt_box_autoadd_note_metadata(
+    NoteMetadata? raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null
+        ? ffi.nullptr
+        : cst_encode_box_autoadd_note_metadata(raw);
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_reasoning_options>
+  cst_encode_opt_box_autoadd_reasoning_options(ReasoningOptions? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null
+        ? ffi.nullptr
+        : cst_encode_box_autoadd_reasoning_options(raw);
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_sampler_config>
+  cst_encode_opt_box_autoadd_sampler_config(SamplerConfig? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null
+        ? ffi.nullptr
+        : cst_encode_box_autoadd_sampler_config(raw);
+  }
+
+  @protected
+  ffi.Pointer<ffi.Int32> cst_encode_opt_box_autoadd_stop_reason(
+    StopReason? raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? ffi.nullptr : cst_encode_box_autoadd_stop_reason(raw);
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_summary_options>
+  cst_encode_opt_box_autoadd_summary_options(SummaryOptions? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null
+        ? ffi.nullptr
+        : cst_encode_box_autoadd_summary_options(raw);
+  }
+
+  @protected
+  ffi.Pointer<ffi.Uint32> cst_encode_opt_box_autoadd_u_32(int? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? ffi.nullptr : cst_encode_box_autoadd_u_32(raw);
+  }
+
+  @protected
+  ffi.Pointer<ffi.Uint64> cst_encode_opt_box_autoadd_u_64(BigInt? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? ffi.nullptr : cst_encode_box_autoadd_u_64(raw);
+  }
+
+  @protected
+  ffi.Pointer<ffi.UintPtr> cst_encode_opt_box_autoadd_usize(BigInt? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? ffi.nullptr : cst_encode_box_autoadd_usize(raw);
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_list_String> cst_encode_opt_list_String(
+    List<String>? raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? ffi.nullptr : cst_encode_list_String(raw);
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_list_list_prim_f_32_strict>
+  cst_encode_opt_list_list_prim_f_32_strict(List<Float32List>? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null
+        ? ffi.nullptr
+        : cst_encode_list_list_prim_f_32_strict(raw);
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_list_prim_f_32_strict>
+  cst_encode_opt_list_prim_f_32_strict(Float32List? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? ffi.nullptr : cst_encode_list_prim_f_32_strict(raw);
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_list_record_string_string>
+  cst_encode_opt_list_record_string_string(List<(String, String)>? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null
+        ? ffi.nullptr
+        : cst_encode_list_record_string_string(raw);
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_list_token_logprob> cst_encode_opt_list_token_logprob(
+    List<TokenLogprob>? raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? ffi.nullptr : cst_encode_list_token_logprob(raw);
+  }
+
+  @protected
+  int cst_encode_u_64(BigInt raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw.toSigned(64).toInt();
+  }
+
+  @protected
+  int cst_encode_usize(BigInt raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw.toSigned(64).toInt();
+  }
+
+  @protected
+  void cst_api_fill_to_wire_agent_run(
+    AgentRun apiObj,
+    wire_cst_agent_run wireObj,
+  ) {
+    wireObj.answer = cst_encode_String(apiObj.answer);
+    wireObj.stop_reason = cst_encode_agent_stop_reason(apiObj.stopReason);
+    wireObj.steps = cst_encode_list_agent_step(apiObj.steps);
+    wireObj.messages = cst_encode_list_record_string_string(apiObj.messages);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_agent_step(
+    AgentStep apiObj,
+    wire_cst_agent_step wireObj,
+  ) {
+    wireObj.reply = cst_encode_String(apiObj.reply);
+    wireObj.tool_calls = cst_encode_list_mcp_tool_call(apiObj.toolCalls);
+    wireObj.results = cst_encode_list_mcp_tool_result(apiObj.results);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_context_policy(
+    ContextPolicy apiObj,
+    ffi.Pointer<wire_cst_context_policy> wireObj,
+  ) {
+    cst_api_fill_to_wire_context_policy(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_context_report(
+    ContextReport apiObj,
+    ffi.Pointer<wire_cst_context_report> wireObj,
+  ) {
+    cst_api_fill_to_wire_context_report(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_dedup_options(
+    DedupOptions apiObj,
+    ffi.Pointer<wire_cst_dedup_options> wireObj,
+  ) {
+    cst_api_fill_to_wire_dedup_options(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_embedding_cache_stats(
+    EmbeddingCacheStats apiObj,
+    ffi.Pointer<wire_cst_embedding_cache_stats> wireObj,
+  ) {
+    cst_api_fill_to_wire_embedding_cache_stats(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_embedding_config(
+    EmbeddingConfig apiObj,
+    ffi.Pointer<wire_cst_embedding_config> wireObj,
+  ) {
+    cst_api_fill_to_wire_embedding_config(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_generation_options(
+    GenerationOptions apiObj,
+    ffi.Pointer<wire_cst_generation_options> wireObj,
+  ) {
+    cst_api_fill_to_wire_generation_options(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_generation_result(
+    GenerationResult apiObj,
+    ffi.Pointer<wire_cst_generation_result> wireObj,
+  ) {
+    cst_api_fill_to_wire_generation_result(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_hnsw_config(
+    HnswConfig apiObj,
+    ffi.Pointer<wire_cst_hnsw_config> wireObj,
+  ) {
+    cst_api_fill_to_wire_hnsw_config(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_hybrid_options(
+    HybridOptions apiObj,
+    ffi.Pointer<wire_cst_hybrid_options> wireObj,
+  ) {
+    cst_api_fill_to_wire_hybrid_options(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_mcp_tool_call(
+    MCPToolCall apiObj,
+    ffi.Pointer<wire_cst_mcp_tool_call> wireObj,
+  ) {
+    cst_api_fill_to_wire_mcp_tool_call(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_note_metadata(
+    NoteMetadata apiObj,
+    ffi.Pointer<wire_cst_note_metadata> wireObj,
+  ) {
+    cst_api_fill_to_wire_note_metadata(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_reasoning_options(
+    ReasoningOptions apiObj,
+    ffi.Pointer<wire_cst_reasoning_options> wireObj,
+  ) {
+    cst_api_fill_to_wire_reasoning_options(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_sampler_config(
+    SamplerConfig apiObj,
+    ffi.Pointer<wire_cst_sampler_config> wireObj,
+  ) {
+    cst_api_fill_to_wire_sampler_config(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_search_options(
+    SearchOptions apiObj,
+    ffi.Pointer<wire_cst_search_options> wireObj,
+  ) {
+    cst_api_fill_to_wire_search_options(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_summary_options(
+    SummaryOptions apiObj,
+    ffi.Pointer<wire_cst_summary_options> wireObj,
+  ) {
+    cst_api_fill_to_wire_summary_options(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_cluster_assignment(
+    ClusterAssignment apiObj,
+    wire_cst_cluster_assignment wireObj,
+  ) {
+    wireObj.id = cst_encode_String(apiObj.id);
+    wireObj.cluster_id = cst_encode_usize(apiObj.clusterId);
+    wireObj.color = cst_encode_String(apiObj.color);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_cluster_info(
+    ClusterInfo apiObj,
+    wire_cst_cluster_info wireObj,
+  ) {
+    wireObj.id = cst_encode_usize(apiObj.id);
+    wireObj.size = cst_encode_usize(apiObj.size);
+    wireObj.color = cst_encode_String(apiObj.color);
+    wireObj.centroid = cst_encode_opt_list_prim_f_32_strict(apiObj.centroid);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_clustering_result(
+    ClusteringResult apiObj,
+    wire_cst_clustering_result wireObj,
+  ) {
+    wireObj.assignments = cst_encode_list_cluster_assignment(
+      apiObj.assignments,
+    );
+    wireObj.clusters = cst_encode_list_cluster_info(apiObj.clusters);
+    wireObj.k = cst_encode_usize(apiObj.k);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_context_policy(
+    ContextPolicy apiObj,
+    wire_cst_context_policy wireObj,
+  ) {
+    wireObj.strategy = cst_encode_context_strategy(apiObj.strategy);
+    wireObj.summary_max_tokens = cst_encode_u_32(apiObj.summaryMaxTokens);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_context_report(
+    ContextReport apiObj,
+    wire_cst_context_report wireObj,
+  ) {
+    wireObj.context_size = cst_encode_u_32(apiObj.contextSize);
+    wireObj.prompt_tokens = cst_encode_u_32(apiObj.promptTokens);
+    wireObj.message_tokens = cst_encode_list_prim_u_32_strict(
+      apiObj.messageTokens,
+    );
+    wireObj.dropped = cst_encode_list_prim_u_32_strict(apiObj.dropped);
+    wireObj.truncated = cst_encode_list_prim_u_32_strict(apiObj.truncated);
+    wireObj.summarized = cst_encode_list_prim_u_32_strict(apiObj.summarized);
+    wireObj.summary = cst_encode_opt_String(apiObj.summary);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_dedup_document(
+    DedupDocument apiObj,
+    wire_cst_dedup_document wireObj,
+  ) {
+    wireObj.id = cst_encode_String(apiObj.id);
+    wireObj.text = cst_encode_String(apiObj.text);
+    wireObj.vector = cst_encode_opt_list_prim_f_32_strict(apiObj.vector);
+    wireObj.created_at = cst_encode_opt_box_autoadd_i_64(apiObj.createdAt);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_dedup_options(
+    DedupOptions apiObj,
+    wire_cst_dedup_options wireObj,
+  ) {
+    wireObj.shingle_words = cst_encode_usize(apiObj.shingleWords);
+    wireObj.num_hashes = cst_encode_usize(apiObj.numHashes);
+    wireObj.bands = cst_encode_usize(apiObj.bands);
+    wireObj.min_jaccard = cst_encode_f_32(apiObj.minJaccard);
+    wireObj.min_cosine = cst_encode_f_32(apiObj.minCosine);
+    wireObj.min_span_words = cst_encode_usize(apiObj.minSpanWords);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_duplicate_group(
+    DuplicateGroup apiObj,
+    wire_cst_duplicate_group wireObj,
+  ) {
+    wireObj.ids = cst_encode_list_String(apiObj.ids);
+    wireObj.canonical = cst_encode_String(apiObj.canonical);
+    wireObj.canonical_note = cst_encode_String(apiObj.canonicalNote);
+    wireObj.pairs = cst_encode_list_duplicate_pair(apiObj.pairs);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_duplicate_pair(
+    DuplicatePair apiObj,
+    wire_cst_duplicate_pair wireObj,
+  ) {
+    wireObj.a = cst_encode_String(apiObj.a);
+    wireObj.b = cst_encode_String(apiObj.b);
+    wireObj.jaccard = cst_encode_f_32(apiObj.jaccard);
+    wireObj.cosine = cst_encode_opt_box_autoadd_f_32(apiObj.cosine);
+    wireObj.spans = cst_encode_list_duplicate_span(apiObj.spans);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_duplicate_span(
+    DuplicateSpan apiObj,
+    wire_cst_duplicate_span wireObj,
+  ) {
+    wireObj.a_start = cst_encode_usize(apiObj.aStart);
+    wireObj.a_end = cst_encode_usize(apiObj.aEnd);
+    wireObj.b_start = cst_encode_usize(apiObj.bStart);
+    wireObj.b_end = cst_encode_usize(apiObj.bEnd);
+    wireObj.words = cst_encode_usize(apiObj.words);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_embedding_cache_stats(
+    EmbeddingCacheStats apiObj,
+    wire_cst_embedding_cache_stats wireObj,
+  ) {
+    wireObj.entries = cst_encode_usize(apiObj.entries);
+    wireObj.stale_records = cst_encode_usize(apiObj.staleRecords);
+    wireObj.file_bytes = cst_encode_u_64(apiObj.fileBytes);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_embedding_cluster(
+    EmbeddingCluster apiObj,
+    wire_cst_embedding_cluster wireObj,
+  ) {
+    wireObj.ids = cst_encode_list_String(apiObj.ids);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_embedding_config(
+    EmbeddingConfig apiObj,
+    wire_cst_embedding_config wireObj,
+  ) {
+    wireObj.n_gpu_layers = cst_encode_u_32(apiObj.nGpuLayers);
+    wireObj.n_threads = cst_encode_i_32(apiObj.nThreads);
+    wireObj.pooling = cst_encode_pooling_mode(apiObj.pooling);
+    wireObj.chunk_tokens = cst_encode_u_32(apiObj.chunkTokens);
+    wireObj.chunk_overlap = cst_encode_u_32(apiObj.chunkOverlap);
+    wireObj.max_sequences = cst_encode_u_32(apiObj.maxSequences);
+    wireObj.query_prefix = cst_encode_String(apiObj.queryPrefix);
+    wireObj.document_prefix = cst_encode_String(apiObj.documentPrefix);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_embedding_entry(
+    EmbeddingEntry apiObj,
+    wire_cst_embedding_entry wireObj,
+  ) {
+    wireObj.id = cst_encode_String(apiObj.id);
+    wireObj.vector = cst_encode_list_prim_f_32_strict(apiObj.vector);
+    wireObj.text_preview = cst_encode_opt_String(apiObj.textPreview);
+    cst_api_fill_to_wire_note_metadata(apiObj.metadata, wireObj.metadata);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_generation_chunk(
+    GenerationChunk apiObj,
+    wire_cst_generation_chunk wireObj,
+  ) {
+    wireObj.text = cst_encode_String(apiObj.text);
+    wireObj.reasoning = cst_encode_String(apiObj.reasoning);
+    wireObj.stop_reason = cst_encode_opt_box_autoadd_stop_reason(
+      apiObj.stopReason,
+    );
+  }
+
+  @protected
+  void cst_api_fill_to_wire_generation_options(
+    GenerationOptions apiObj,
+    wire_cst_generation_options wireObj,
+  ) {
+    wireObj.max_tokens = cst_encode_opt_box_autoadd_u_32(apiObj.maxTokens);
+    wireObj.stop_sequences = cst_encode_list_String(apiObj.stopSequences);
+    wireObj.sampler = cst_encode_opt_box_autoadd_sampler_config(apiObj.sampler);
+    wireObj.grammar = cst_encode_opt_String(apiObj.grammar);
+    wireObj.json_schema = cst_encode_opt_String(apiObj.jsonSchema);
+    wireObj.model_id = cst_encode_opt_String(apiObj.modelId);
+    wireObj.context = cst_encode_opt_box_autoadd_context_policy(apiObj.context);
+    wireObj.reasoning = cst_encode_opt_box_autoadd_reasoning_options(
+      apiObj.reasoning,
+    );
+    wireObj.logprobs = cst_encode_opt_box_autoadd_u_32(apiObj.logprobs);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_generation_poll(
+    GenerationPoll apiObj,
+    wire_cst_generation_poll wireObj,
+  ) {
+    wireObj.text = cst_encode_String(apiObj.text);
+    wireObj.reasoning = cst_encode_String(apiObj.reasoning);
+    wireObj.result = cst_encode_opt_box_autoadd_generation_result(
+      apiObj.result,
+    );
+  }
+
+  @protected
+  void cst_api_fill_to_wire_generation_result(
+    GenerationResult apiObj,
+    wire_cst_generation_result wireObj,
+  ) {
+    wireObj.text = cst_encode_String(apiObj.text);
+    wireObj.reasoning = cst_encode_opt_String(apiObj.reasoning);
+    wireObj.reasoning_tokens = cst_encode_u_32(apiObj.reasoningTokens);
+    wireObj.stop_reason = cst_encode_stop_reason(apiObj.stopReason);
+    wireObj.tokens_generated = cst_encode_u_32(apiObj.tokensGenerated);
+    wireObj.context = cst_encode_opt_box_autoadd_context_report(apiObj.context);
+    wireObj.logprobs = cst_encode_opt_list_token_logprob(apiObj.logprobs);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_gguf_metadata(
+    GgufMetadata apiObj,
+    wire_cst_gguf_metadata wireObj,
+  ) {
+    wireObj.version = cst_encode_u_32(apiObj.version);
+    wireObj.architecture = cst_encode_opt_String(apiObj.architecture);
+    wireObj.name = cst_encode_opt_String(apiObj.name);
+    wireObj.chat_template = cst_encode_opt_String(apiObj.chatTemplate);
+    wireObj.context_length = cst_encode_opt_box_autoadd_u_64(
+      apiObj.contextLength,
+    );
+    wireObj.embedding_length = cst_encode_opt_box_autoadd_u_64(
+      apiObj.embeddingLength,
+    );
+    wireObj.block_count = cst_encode_opt_box_autoadd_u_64(apiObj.blockCount);
+    wireObj.pooling_type = cst_encode_opt_box_autoadd_u_32(apiObj.poolingType);
+    wireObj.quantization = cst_encode_opt_String(apiObj.quantization);
+    wireObj.parameter_count = cst_encode_u_64(apiObj.parameterCount);
+    wireObj.tensor_count = cst_encode_u_64(apiObj.tensorCount);
+    wireObj.tokenizer_model = cst_encode_opt_String(apiObj.tokenizerModel);
+    wireObj.vocab_size = cst_encode_opt_box_autoadd_u_64(apiObj.vocabSize);
+    wireObj.bos_token_id = cst_encode_opt_box_autoadd_u_32(apiObj.bosTokenId);
+    wireObj.eos_token_id = cst_encode_opt_box_autoadd_u_32(apiObj.eosTokenId);
+    wireObj.file_size = cst_encode_u_64(apiObj.fileSize);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_graph_edge(
+    GraphEdge apiObj,
+    wire_cst_graph_edge wireObj,
+  ) {
+    wireObj.source = cst_encode_String(apiObj.source);
+    wireObj.target = cst_encode_String(apiObj.target);
+    wireObj.weight = cst_encode_f_32(apiObj.weight);
+    wireObj.edge_type = cst_encode_String(apiObj.edgeType);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_graph_node(
+    GraphNode apiObj,
+    wire_cst_graph_node wireObj,
+  ) {
+    wireObj.id = cst_encode_String(apiObj.id);
+    wireObj.label = cst_encode_String(apiObj.label);
+    wireObj.node_type = cst_encode_String(apiObj.nodeType);
+    wireObj.x = cst_encode_f_32(apiObj.x);
+    wireObj.y = cst_encode_f_32(apiObj.y);
+    wireObj.color = cst_encode_opt_String(apiObj.color);
+    wireObj.metadata = cst_encode_opt_String(apiObj.metadata);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_graph_state(
+    GraphState apiObj,
+    wire_cst_graph_state wireObj,
+  ) {
+    wireObj.nodes = cst_encode_list_graph_node(apiObj.nodes);
+    wireObj.edges = cst_encode_list_graph_edge(apiObj.edges);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_hnsw_config(
+    HnswConfig apiObj,
+    wire_cst_hnsw_config wireObj,
+  ) {
+    wireObj.m = cst_encode_u_32(apiObj.m);
+    wireObj.ef_construction = cst_encode_u_32(apiObj.efConstruction);
+    wireObj.ef_search = cst_encode_u_32(apiObj.efSearch);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_hybrid_hit(
+    HybridHit apiObj,
+    wire_cst_hybrid_hit wireObj,
+  ) {
+    wireObj.id = cst_encode_String(apiObj.id);
+    wireObj.score = cst_encode_f_32(apiObj.score);
+    wireObj.semantic_score = cst_encode_opt_box_autoadd_f_32(
+      apiObj.semanticScore,
+    );
+    wireObj.semantic_rank = cst_encode_opt_box_autoadd_u_32(
+      apiObj.semanticRank,
+    );
+    wireObj.lexical_score = cst_encode_opt_box_autoadd_f_32(
+      apiObj.lexicalScore,
+    );
+    wireObj.lexical_rank = cst_encode_opt_box_autoadd_u_32(apiObj.lexicalRank);
+    wireObj.matched_terms = cst_encode_list_String(apiObj.matchedTerms);
+    wireObj.text_preview = cst_encode_opt_String(apiObj.textPreview);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_hybrid_options(
+    HybridOptions apiObj,
+    wire_cst_hybrid_options wireObj,
+  ) {
+    wireObj.fusion = cst_encode_fusion_mode(apiObj.fusion);
+    wireObj.semantic_weight = cst_encode_f_32(apiObj.semanticWeight);
+    wireObj.rrf_k = cst_encode_f_32(apiObj.rrfK);
+    wireObj.candidates = cst_encode_usize(apiObj.candidates);
+    wireObj.min_semantic_score = cst_encode_f_32(apiObj.minSemanticScore);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_knowledge_graph_analysis(
+    KnowledgeGraphAnalysis apiObj,
+    wire_cst_knowledge_graph_analysis wireObj,
+  ) {
+    cst_api_fill_to_wire_clustering_result(
+      apiObj.clustering,
+      wireObj.clustering,
+    );
+    cst_api_fill_to_wire_semantic_edge_result(
+      apiObj.semanticEdges,
+      wireObj.semantic_edges,
+    );
+  }
+
+  @protected
+  void cst_api_fill_to_wire_loaded_model_info(
+    LoadedModelInfo apiObj,
+    wire_cst_loaded_model_info wireObj,
+  ) {
+    wireObj.model_id = cst_encode_String(apiObj.modelId);
+    wireObj.model_path = cst_encode_String(apiObj.modelPath);
+    wireObj.size_bytes = cst_encode_u_64(apiObj.sizeBytes);
+    wireObj.is_default = cst_encode_bool(apiObj.isDefault);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_markdown_chunk(
+    MarkdownChunk apiObj,
+    wire_cst_markdown_chunk wireObj,
+  ) {
+    wireObj.index = cst_encode_usize(apiObj.index);
+    wireObj.heading_path = cst_encode_list_String(apiObj.headingPath);
+    wireObj.start = cst_encode_usize(apiObj.start);
+    wireObj.end = cst_encode_usize(apiObj.end);
+    wireObj.text = cst_encode_String(apiObj.text);
+    wireObj.token_count = cst_encode_u_32(apiObj.tokenCount);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_mcp_parameter(
+    MCPParameter apiObj,
+    wire_cst_mcp_parameter wireObj,
+  ) {
+    wireObj.name = cst_encode_String(apiObj.name);
+    wireObj.description = cst_encode_String(apiObj.description);
+    wireObj.param_type = cst_encode_mcp_param_type(apiObj.paramType);
+    wireObj.required = cst_encode_bool(apiObj.required_);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_mcp_tool_call(
+    MCPToolCall apiObj,
+    wire_cst_mcp_tool_call wireObj,
+  ) {
+    wireObj.tool = cst_encode_String(apiObj.tool);
+    wireObj.parameters_json = cst_encode_String(apiObj.parametersJson);
+    wireObj.description = cst_encode_String(apiObj.description);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_mcp_tool_result(
+    MCPToolResult apiObj,
+    wire_cst_mcp_tool_result wireObj,
+  ) {
+    wireObj.success = cst_encode_bool(apiObj.success);
+    wireObj.result = cst_encode_String(apiObj.result);
+    wireObj.tool = cst_encode_String(apiObj.tool);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_node_position(
+    NodePosition apiObj,
+    wire_cst_node_position wireObj,
+  ) {
+    wireObj.id = cst_encode_String(apiObj.id);
+    wireObj.x = cst_encode_f_32(apiObj.x);
+    wireObj.y = cst_encode_f_32(apiObj.y);
+    wireObj.radius = cst_encode_f_32(apiObj.radius);
+    wireObj.color = cst_encode_u_32(apiObj.color);
+    wireObj.node_type = cst_encode_String(apiObj.nodeType);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_note_metadata(
+    NoteMetadata apiObj,
+    wire_cst_note_metadata wireObj,
+  ) {
+    wireObj.folder = cst_encode_opt_String(apiObj.folder);
+    wireObj.tags = cst_encode_list_String(apiObj.tags);
+    wireObj.created_at = cst_encode_opt_box_autoadd_i_64(apiObj.createdAt);
+    wireObj.modified_at = cst_encode_opt_box_autoadd_i_64(apiObj.modifiedAt);
+    wireObj.note_type = cst_encode_opt_String(apiObj.noteType);
+    wireObj.chunk_start = cst_encode_opt_box_autoadd_usize(apiObj.chunkStart);
+    wireObj.chunk_end = cst_encode_opt_box_autoadd_usize(apiObj.chunkEnd);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_parsed_reply(
+    ParsedReply apiObj,
+    wire_cst_parsed_reply wireObj,
+  ) {
+    wireObj.tool_calls = cst_encode_list_mcp_tool_call(apiObj.toolCalls);
+    wireObj.spans = cst_encode_list_reply_span(apiObj.spans);
+    wireObj.text = cst_encode_String(apiObj.text);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_rag_answer(
+    RagAnswer apiObj,
+    wire_cst_rag_answer wireObj,
+  ) {
+    wireObj.answer = cst_encode_String(apiObj.answer);
+    wireObj.sources = cst_encode_list_rag_source(apiObj.sources);
+    wireObj.skipped = cst_encode_usize(apiObj.skipped);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_rag_source(
+    RagSource apiObj,
+    wire_cst_rag_source wireObj,
+  ) {
+    wireObj.citation = cst_encode_u_32(apiObj.citation);
+    wireObj.note_id = cst_encode_String(apiObj.noteId);
+    wireObj.chunk_offset = cst_encode_opt_box_autoadd_usize(apiObj.chunkOffset);
+    wireObj.chunk_index = cst_encode_opt_box_autoadd_usize(apiObj.chunkIndex);
+    wireObj.score = cst_encode_f_32(apiObj.score);
+    wireObj.text = cst_encode_String(apiObj.text);
+    wireObj.cited = cst_encode_bool(apiObj.cited);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_reasoning_options(
+    ReasoningOptions apiObj,
+    wire_cst_reasoning_options wireObj,
+  ) {
+    wireObj.enabled = cst_encode_bool(apiObj.enabled);
+    wireObj.open_tag = cst_encode_String(apiObj.openTag);
+    wireObj.close_tag = cst_encode_String(apiObj.closeTag);
+    wireObj.thinking_budget = cst_encode_opt_box_autoadd_u_32(
+      apiObj.thinkingBudget,
+    );
+  }
+
+  @protected
+  void cst_api_fill_to_wire_recall_report(
+    RecallReport apiObj,
+    wire_cst_recall_report wireObj,
+  ) {
+    wireObj.queries = cst_encode_usize(apiObj.queries);
+    wireObj.top_k = cst_encode_usize(apiObj.topK);
+    wireObj.recall = cst_encode_f_32(apiObj.recall);
+    wireObj.rescored_recall = cst_encode_f_32(apiObj.rescoredRecall);
+    wireObj.f32_bytes = cst_encode_usize(apiObj.f32Bytes);
+    wireObj.quantized_bytes = cst_encode_usize(apiObj.quantizedBytes);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_record_string_string(
+    (String, String) apiObj,
+    wire_cst_record_string_string wireObj,
+  ) {
+    wireObj.field0 = cst_encode_String(apiObj.$1);
+    wireObj.field1 = cst_encode_String(apiObj.$2);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_reply_span(
+    ReplySpan apiObj,
+    wire_cst_reply_span wireObj,
+  ) {
+    wireObj.start = cst_encode_usize(apiObj.start);
+    wireObj.end = cst_encode_usize(apiObj.end);
+    wireObj.kind = cst_encode_reply_span_kind(apiObj.kind);
+    wireObj.call_count = cst_encode_usize(apiObj.callCount);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_sampler_config(
+    SamplerConfig apiObj,
+    wire_cst_sampler_config wireObj,
+  ) {
+    wireObj.temperature = cst_encode_f_32(apiObj.temperature);
+    wireObj.top_k = cst_encode_i_32(apiObj.topK);
+    wireObj.top_p = cst_encode_f_32(apiObj.topP);
+    wireObj.min_p = cst_encode_f_32(apiObj.minP);
+    wireObj.repeat_penalty = cst_encode_f_32(apiObj.repeatPenalty);
+    wireObj.frequency_penalty = cst_encode_f_32(apiObj.frequencyPenalty);
+    wireObj.presence_penalty = cst_encode_f_32(apiObj.presencePenalty);
+    wireObj.penalty_last_n = cst_encode_i_32(apiObj.penaltyLastN);
+    wireObj.seed = cst_encode_opt_box_autoadd_u_32(apiObj.seed);
+    wireObj.greedy = cst_encode_bool(apiObj.greedy);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_search_options(
+    SearchOptions apiObj,
+    wire_cst_search_options wireObj,
+  ) {
+    wireObj.top_k = cst_encode_usize(apiObj.topK);
+    wireObj.threshold = cst_encode_f_32(apiObj.threshold);
+    wireObj.filter = cst_encode_opt_String(apiObj.filter);
+    wireObj.mmr_lambda = cst_encode_opt_box_autoadd_f_32(apiObj.mmrLambda);
+    wireObj.mmr_candidates = cst_encode_usize(apiObj.mmrCandidates);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_semantic_edge(
+    SemanticEdge apiObj,
+    wire_cst_semantic_edge wireObj,
+  ) {
+    wireObj.source = cst_encode_String(apiObj.source);
+    wireObj.target = cst_encode_String(apiObj.target);
+    wireObj.similarity = cst_encode_f_32(apiObj.similarity);
+    wireObj.is_ghost = cst_encode_bool(apiObj.isGhost);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_semantic_edge_result(
+    SemanticEdgeResult apiObj,
+    wire_cst_semantic_edge_result wireObj,
+  ) {
+    wireObj.edges = cst_encode_list_semantic_edge(apiObj.edges);
+    wireObj.count = cst_encode_usize(apiObj.count);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_similarity_result(
+    SimilarityResult apiObj,
+    wire_cst_similarity_result wireObj,
+  ) {
+    wireObj.id = cst_encode_String(apiObj.id);
+    wireObj.score = cst_encode_f_32(apiObj.score);
+    wireObj.text_preview = cst_encode_opt_String(apiObj.textPreview);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_stream_graph_stats(
+    StreamGraphStats apiObj,
+    wire_cst_stream_graph_stats wireObj,
+  ) {
+    wireObj.node_count = cst_encode_usize(apiObj.nodeCount);
+    wireObj.edge_count = cst_encode_usize(apiObj.edgeCount);
+    wireObj.visible_count = cst_encode_usize(apiObj.visibleCount);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_summary(Summary apiObj, wire_cst_summary wireObj) {
+    wireObj.summary = cst_encode_String(apiObj.summary);
+    wireObj.chunk_summaries = cst_encode_list_String(apiObj.chunkSummaries);
+    wireObj.merge_levels = cst_encode_u_32(apiObj.mergeLevels);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_summary_options(
+    SummaryOptions apiObj,
+    wire_cst_summary_options wireObj,
+  ) {
+    wireObj.style = cst_encode_summary_style(apiObj.style);
+    wireObj.max_words = cst_encode_u_32(apiObj.maxWords);
+    wireObj.chunk_tokens = cst_encode_u_32(apiObj.chunkTokens);
+    wireObj.model_id = cst_encode_opt_String(apiObj.modelId);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_token_candidate(
+    TokenCandidate apiObj,
+    wire_cst_token_candidate wireObj,
+  ) {
+    wireObj.token = cst_encode_i_32(apiObj.token);
+    wireObj.text = cst_encode_String(apiObj.text);
+    wireObj.logprob = cst_encode_f_32(apiObj.logprob);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_token_logprob(
+    TokenLogprob apiObj,
+    wire_cst_token_logprob wireObj,
+  ) {
+    wireObj.token = cst_encode_i_32(apiObj.token);
+    wireObj.text = cst_encode_String(apiObj.text);
+    wireObj.logprob = cst_encode_f_32(apiObj.logprob);
+    wireObj.top_alternatives = cst_encode_list_token_candidate(
+      apiObj.topAlternatives,
+    );
+  }
+
+  @protected
+  void cst_api_fill_to_wire_tokenizer_info(
+    TokenizerInfo apiObj,
+    wire_cst_tokenizer_info wireObj,
+  ) {
+    wireObj.vocab_size = cst_encode_u_32(apiObj.vocabSize);
+    wireObj.n_ctx_train = cst_encode_u_32(apiObj.nCtxTrain);
+    wireObj.n_ctx = cst_encode_u_32(apiObj.nCtx);
+    wireObj.bos_token = cst_encode_i_32(apiObj.bosToken);
+    wireObj.eos_token = cst_encode_i_32(apiObj.eosToken);
+    wireObj.tokenizer_model = cst_encode_opt_String(apiObj.tokenizerModel);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_transcript_segment(
+    TranscriptSegment apiObj,
+    wire_cst_transcript_segment wireObj,
+  ) {
+    wireObj.text = cst_encode_String(apiObj.text);
+    wireObj.start_time = cst_encode_f_32(apiObj.startTime);
+    wireObj.end_time = cst_encode_f_32(apiObj.endTime);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_vector_store_stats(
+    VectorStoreStats apiObj,
+    wire_cst_vector_store_stats wireObj,
+  ) {
+    wireObj.entries = cst_encode_usize(apiObj.entries);
+    wireObj.dead_rows = cst_encode_usize(apiObj.deadRows);
+    wireObj.dimension = cst_encode_usize(apiObj.dimension);
+    wireObj.file_bytes = cst_encode_u_64(apiObj.fileBytes);
+  }
+
+  @protected
+  int cst_encode_agent_stop_reason(AgentStopReason raw);
+
+  @protected
+  bool cst_encode_bool(bool raw);
+
+  @protected
+  int cst_encode_context_strategy(ContextStrategy raw);
+
+  @protected
+  int cst_encode_embedding_kind(EmbeddingKind raw);
+
+  @protected
+  double cst_encode_f_32(double raw);
+
+  @protected
+  int cst_encode_fusion_mode(FusionMode raw);
+
+  @protected
+  int cst_encode_i_32(int raw);
+
+  @protected
+  int cst_encode_mcp_param_type(MCPParamType raw);
+
+  @protected
+  int cst_encode_mcp_tool(MCPTool raw);
+
+  @protected
+  int cst_encode_pooling_mode(PoolingMode raw);
+
+  @protected
+  int cst_encode_quantization_kind(QuantizationKind raw);
+
+  @protected
+  int cst_encode_reply_span_kind(ReplySpanKind raw);
+
+  @protected
+  int cst_encode_stop_reason(StopReason raw);
+
+  @protected
+  int cst_encode_summary_style(SummaryStyle raw);
+
+  @protected
+  int cst_encode_task_category(TaskCategory raw);
+
+  @protected
+  int cst_encode_u_32(int raw);
+
+  @protected
+  int cst_encode_u_8(int raw);
+
+  @protected
+  void cst_encode_unit(void raw);
+
+  @protected
+  void sse_encode_AnyhowException(
+    AnyhowException self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_agent_step_Dco(
+    RustStreamSink<AgentStep> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_generation_chunk_Dco(
+    RustStreamSink<GenerationChunk> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_String(String self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_agent_run(AgentRun self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_agent_step(AgentStep self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_agent_stop_reason(
+    AgentStopReason self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_bool(bool self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_context_policy(
+    ContextPolicy self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_context_report(
+    ContextReport self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_dedup_options(
+    DedupOptions self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_embedding_cache_stats(
+    EmbeddingCacheStats self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_embedding_config(
+    EmbeddingConfig self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_f_32(double self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_generation_options(
+    GenerationOptions self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_generation_result(
+    GenerationResult self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_hnsw_config(
+    HnswConfig self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_hybrid_options(
+    HybridOptions self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_i_32(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_i_64(
+    PlatformInt64 self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_mcp_tool_call(
+    MCPToolCall self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_note_metadata(
+    NoteMetadata self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_reasoning_options(
+    ReasoningOptions self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_sampler_config(
+    SamplerConfig self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_search_options(
+    SearchOptions self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_stop_reason(
+    StopReason self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_summary_options(
+    SummaryOptions self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_usize(BigInt self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_cluster_assignment(
+    ClusterAssignment self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_cluster_info(ClusterInfo self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_clustering_result(
+    ClusteringResult self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_context_policy(ContextPolicy self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_context_report(ContextReport self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_context_strategy(
+    ContextStrategy self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_dedup_document(DedupDocument self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_dedup_options(DedupOptions self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_duplicate_group(
+    DuplicateGroup self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_duplicate_pair(DuplicatePair self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_duplicate_span(DuplicateSpan self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_embedding_cache_stats(
+    EmbeddingCacheStats self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_embedding_cluster(
+    EmbeddingCluster self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_embedding_config(
+    EmbeddingConfig self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_embedding_entry(
+    EmbeddingEntry self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_embedding_kind(EmbeddingKind self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_f_32(double self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_fusion_mode(FusionMode self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_generation_chunk(
+    GenerationChunk self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_generation_options(
+    GenerationOptions self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_generation_poll(
+    GenerationPoll self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_generation_result(
+    GenerationResult self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_gguf_metadata(GgufMetadata self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_graph_edge(GraphEdge self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_graph_node(GraphNode self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_graph_state(GraphState self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_hnsw_config(HnswConfig self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_hybrid_hit(HybridHit self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_hybrid_options(HybridOptions self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_i_32(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_knowledge_graph_analysis(
+    KnowledgeGraphAnalysis self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_String(List<String> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_agent_step(
+    List<AgentStep> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_cluster_assignment(
+    List<ClusterAssignment> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_cluster_info(
+    List<ClusterInfo> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_dedup_document(
+    List<DedupDocument> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_duplicate_group(
+    List<DuplicateGroup> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_duplicate_pair(
+    List<DuplicatePair> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_duplicate_span(
+    List<DuplicateSpan> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_embedding_cluster(
+    List<EmbeddingCluster> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_embedding_entry(
+    List<EmbeddingEntry> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_graph_edge(
+    List<GraphEdge> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_graph_node(
+    List<GraphNode> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_hybrid_hit(
+    List<HybridHit> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_list_prim_f_32_strict(
+    List<Float32List> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_loaded_model_info(
+    List<LoadedModelInfo> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_markdown_chunk(
+    List<MarkdownChunk> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_mcp_parameter(
+    List<MCPParameter> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_mcp_tool(List<MCPTool> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_mcp_tool_call(
+    List<MCPToolCall> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_mcp_tool_result(
+    List<MCPToolResult> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_node_position(
+    List<NodePosition> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_prim_f_32_loose(
+    List<double> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_prim_f_32_strict(
+    Float32List self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_prim_i_32_loose(
+    List<int> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_prim_i_32_strict(
+    Int32List self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_prim_u_32_strict(
+    Uint32List self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_prim_u_8_strict(
+    Uint8List self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_rag_source(
+    List<RagSource> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_record_string_string(
+    List<(String, String)> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_reply_span(
+    List<ReplySpan> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_semantic_edge(
+    List<SemanticEdge> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_similarity_result(
+    List<SimilarityResult> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_token_candidate(
+    List<TokenCandidate> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_token_logprob(
+    List<TokenLogprob> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_transcript_segment(
+    List<TranscriptSegment> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_loaded_model_info(
+    LoadedModelInfo self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_markdown_chunk(MarkdownChunk self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_mcp_param_type(MCPParamType self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_mcp_parameter(MCPParameter self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_mcp_tool(MCPTool self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_mcp_tool_call(MCPToolCall self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_mcp_tool_result(MCPToolResult self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_node_position(NodePosition self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_note_metadata(NoteMetadata self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_String(String? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_context_policy(
+    ContextPolicy? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_context_report(
+    ContextReport? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_dedup_options(
+    DedupOptions? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_embedding_cache_stats(
+    EmbeddingCacheStats? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_embedding_config(
+    EmbeddingConfig? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_f_32(double? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_generation_result(
+    GenerationResult? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_hnsw_config(
+    HnswConfig? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_hybrid_options(
+    HybridOptions? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_i_32(int? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_i_64(
+    PlatformInt64? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_note_metadata(
+    NoteMetadata? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_reasoning_options(
+    ReasoningOptions? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_sampler_config(
+    SamplerConfig? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_stop_reason(
+    StopReason? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_summary_options(
+    SummaryOptions? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_usize(BigInt? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_list_String(List<String>? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_list_list_prim_f_32_strict(
+    List<Float32List>? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_list_prim_f_32_strict(
+    Float32List? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_list_record_string_string(
+    List<(String, String)>? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_list_token_logprob(
+    List<TokenLogprob>? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_parsed_reply(ParsedReply self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_pooling_mode(PoolingMode self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_quantization_kind(
+    QuantizationKind self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_rag_answer(RagAnswer self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_rag_source(RagSource self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_reasoning_options(
+    ReasoningOptions self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_recall_report(RecallReport self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_record_string_string(
+    (String, String) self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_reply_span(ReplySpan self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_reply_span_kind(ReplySpanKind self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_sampler_config(SamplerConfig self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_search_options(SearchOptions self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_semantic_edge(SemanticEdge self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_semantic_edge_result(
+    SemanticEdgeResult self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_similarity_result(
+    SimilarityResult self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_stop_reason(StopReason self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_stream_graph_stats(
+    StreamGraphStats self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_summary(Summary self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_summary_options(
+    SummaryOptions self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_summary_style(SummaryStyle self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_task_category(TaskCategory self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_token_candidate(
+    TokenCandidate self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_token_logprob(TokenLogprob self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_tokenizer_info(TokenizerInfo self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_transcript_segment(
+    TranscriptSegment self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_u_32(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_u_64(BigInt self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_u_8(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_unit(void self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_usize(BigInt self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_vector_store_stats(
+    VectorStoreStats self,
+    SseSerializer serializer,
+  );
+}
+
+// Section: wire_class
+
+// ignore_for_file: camel_case_types, non_constant_identifier_names, avoid_positional_boolean_parameters, annotate_overrides, constant_identifier_names
+// AUTO GENERATED FILE, DO NOT EDIT.
+//
+// Generated by `package:ffigen`.
+// ignore_for_file: type=lint, unused_import
+
+/// generated by flutter_rust_bridge
+class RustLibWire implements BaseWire {
+  factory RustLibWire.fromExternalLibrary(ExternalLibrary lib) =>
+      RustLibWire(lib.ffiDynamicLibrary);
+
+  /// Holds the symbol lookup function.
+  final ffi.Pointer<T> Function<T extends ffi.NativeType>(String symbolName)
+  _lookup;
+
+  /// The symbols are looked up in [dynamicLibrary].
+  RustLibWire(ffi.DynamicLibrary dynamicLibrary)
+    : _lookup = dynamicLibrary.lookup;
+
+  /// The symbols are looked up with [lookup].
+  RustLibWire.fromLookup(
+    ffi.Pointer<T> Function<T extends ffi.NativeType>(String symbolName) lookup,
+  ) : _lookup = lookup;
+
+  void store_dart_post_cobject(DartPostCObjectFnType ptr) {
+    return _store_dart_post_cobject(ptr);
+  }
+
+  late final _store_dart_post_cobjectPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(DartPostCObjectFnType)>>(
+        'store_dart_post_cobject',
+      );
+  late final _store_dart_post_cobject = _store_dart_post_cobjectPtr
+      .asFunction<void Function(DartPostCObjectFnType)>();
+
+  void wire__crate__api__add_graph_edge(
+    int port_,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> source,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> target,
+    double weight,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> edge_type,
+  ) {
+    return _wire__crate__api__add_graph_edge(
+      port_,
+      source,
+      target,
+      weight,
+      edge_type,
+    );
+  }
+
+  late final _wire__crate__api__add_graph_edgePtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Float,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__add_graph_edge');
+  late final _wire__crate__api__add_graph_edge =
+      _wire__crate__api__add_graph_edgePtr
+          .asFunction<
+            void Function(
+              int,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              double,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            )
+          >();
+
+  void wire__crate__api__add_graph_edges(
+    int port_,
+    ffi.Pointer<wire_cst_list_graph_edge> edges,
+  ) {
+    return _wire__crate__api__add_graph_edges(port_, edges);
+  }
+
+  late final _wire__crate__api__add_graph_edgesPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(ffi.Int64, ffi.Pointer<wire_cst_list_graph_edge>)
+        >
+      >('frbgen_kivixa_wire__crate__api__add_graph_edges');
+  late final _wire__crate__api__add_graph_edges =
+      _wire__crate__api__add_graph_edgesPtr
+          .asFunction<
+            void Function(int, ffi.Pointer<wire_cst_list_graph_edge>)
+          >();
+
+  void wire__crate__api__add_graph_node(
+    int port_,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> id,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> label,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> node_type,
+    double x,
+    double y,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> color,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> metadata,
+  ) {
+    return _wire__crate__api__add_graph_node(
+      port_,
+      id,
+      label,
+      node_type,
+      x,
+      y,
+      color,
+      metadata,
+    );
+  }
+
+  late final _wire__crate__api__add_graph_nodePtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Float,
+            ffi.Float,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__add_graph_node');
+  late final _wire__crate__api__add_graph_node =
+      _wire__crate__api__add_graph_nodePtr
+          .asFunction<
+            void Function(
+              int,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              double,
+              double,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            )
+          >();
+
+  void wire__crate__api__add_graph_nodes(
+    int port_,
+    ffi.Pointer<wire_cst_list_graph_node> nodes,
+  ) {
+    return _wire__crate__api__add_graph_nodes(port_, nodes);
+  }
+
+  late final _wire__crate__api__add_graph_nodesPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(ffi.Int64, ffi.Pointer<wire_cst_list_graph_node>)
+        >
+      >('frbgen_kivixa_wire__crate__api__add_graph_nodes');
+  late final _wire__crate__api__add_graph_nodes =
+      _wire__crate__api__add_graph_nodesPtr
+          .asFunction<
+            void Function(int, ffi.Pointer<wire_cst_list_graph_node>)
+          >();
+
+  void wire__crate__api__add_stream_edge(
+    int port_,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> from_id,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> to_id,
+    double strength,
+  ) {
+    return _wire__crate__api__add_stream_edge(port_, from_id, to_id, strength);
+  }
+
+  late final _wire__crate__api__add_stream_edgePtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Float,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__add_stream_edge');
+  late final _wire__crate__api__add_stream_edge =
+      _wire__crate__api__add_stream_edgePtr
+          .asFunction<
+            void Function(
+              int,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              double,
+            )
+          >();
+
+  void wire__crate__api__add_stream_node(
+    int port_,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> id,
+    double x,
+    double y,
+    double radius,
+    int color,
+  ) {
+    return _wire__crate__api__add_stream_node(port_, id, x, y, radius, color);
+  }
+
+  late final _wire__crate__api__add_stream_nodePtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Float,
+            ffi.Float,
+            ffi.Float,
+            ffi.Uint32,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__add_stream_node');
+  late final _wire__crate__api__add_stream_node =
+      _wire__crate__api__add_stream_nodePtr
+          .asFunction<
+            void Function(
+              int,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              double,
+              double,
+              double,
+              int,
+            )
+          >();
+
+  void wire__crate__api__analyze_knowledge_graph(
+    int port_,
+    ffi.Pointer<wire_cst_list_embedding_entry> entries,
+    ffi.Pointer<ffi.UintPtr> k,
+    ffi.Pointer<ffi.Float> similarity_threshold,
+    ffi.Pointer<wire_cst_list_record_string_string> existing_links,
+  ) {
+    return _wire__crate__api__analyze_knowledge_graph(
+      port_,
+      entries,
+      k,
+      similarity_threshold,
+      existing_links,
+    );
+  }
+
+  late final _wire__crate__api__analyze_knowledge_graphPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_embedding_entry>,
+            ffi.Pointer<ffi.UintPtr>,
+            ffi.Pointer<ffi.Float>,
+            ffi.Pointer<wire_cst_list_record_string_string>,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__analyze_knowledge_graph');
+  late final _wire__crate__api__analyze_knowledge_graph =
+      _wire__crate__api__analyze_knowledge_graphPtr
+          .asFunction<
+            void Function(
+              int,
+              ffi.Pointer<wire_cst_list_embedding_entry>,
+              ffi.Pointer<ffi.UintPtr>,
+              ffi.Pointer<ffi.Float>,
+              ffi.Pointer<wire_cst_list_record_string_string>,
+            )
+          >();
+
+  void wire__crate__api__analyze_knowledge_graph_in_store(
+    int port_,
+    int store_id,
+    ffi.Pointer<ffi.UintPtr> k,
+    ffi.Pointer<ffi.Float> similarity_threshold,
+    ffi.Pointer<wire_cst_list_record_string_string> existing_links,
+  ) {
+    return _wire__crate__api__analyze_knowledge_graph_in_store(
+      port_,
+      store_id,
+      k,
+      similarity_threshold,
+      existing_links,
+    );
+  }
+
+  late final _wire__crate__api__analyze_knowledge_graph_in_storePtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Uint64,
+            ffi.Pointer<ffi.UintPtr>,
+            ffi.Pointer<ffi.Float>,
+            ffi.Pointer<wire_cst_list_record_string_string>,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__analyze_knowledge_graph_in_store');
+  late final _wire__crate__api__analyze_knowledge_graph_in_store =
+      _wire__crate__api__analyze_knowledge_graph_in_storePtr
+          .asFunction<
+            void Function(
+              int,
+              int,
+              ffi.Pointer<ffi.UintPtr>,
+              ffi.Pointer<ffi.Float>,
+              ffi.Pointer<wire_cst_list_record_string_string>,
+            )
+          >();
+
+  void wire__crate__api__batch_embed(
+    int port_,
+    ffi.Pointer<wire_cst_list_String> texts,
+  ) {
+    return _wire__crate__api__batch_embed(port_, texts);
+  }
+
+  late final _wire__crate__api__batch_embedPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(ffi.Int64, ffi.Pointer<wire_cst_list_String>)
+        >
+      >('frbgen_kivixa_wire__crate__api__batch_embed');
+  late final _wire__crate__api__batch_embed = _wire__crate__api__batch_embedPtr
+      .asFunction<void Function(int, ffi.Pointer<wire_cst_list_String>)>();
+
+  void wire__crate__api__batch_embed_with_model(
+    int port_,
+    ffi.Pointer<wire_cst_list_String> texts,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> model_id,
+  ) {
+    return _wire__crate__api__batch_embed_with_model(port_, texts, model_id);
+  }
+
+  late final _wire__crate__api__batch_embed_with_modelPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_String>,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__batch_embed_with_model');
+  late final _wire__crate__api__batch_embed_with_model =
+      _wire__crate__api__batch_embed_with_modelPtr
+          .asFunction<
+            void Function(
+              int,
+              ffi.Pointer<wire_cst_list_String>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            )
+          >();
+
+  WireSyncRust2DartDco wire__crate__api__cancel_generation(int request_id) {
+    return _wire__crate__api__cancel_generation(request_id);
+  }
+
+  late final _wire__crate__api__cancel_generationPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Uint64)>>(
+        'frbgen_kivixa_wire__crate__api__cancel_generation',
+      );
+  late final _wire__crate__api__cancel_generation =
+      _wire__crate__api__cancel_generationPtr
+          .asFunction<WireSyncRust2DartDco Function(int)>();
+
+  void wire__crate__api__chat_completion(
+    int port_,
+    ffi.Pointer<wire_cst_list_record_string_string> messages,
+    ffi.Pointer<ffi.Uint32> max_tokens,
+  ) {
+    return _wire__crate__api__chat_completion(port_, messages, max_tokens);
+  }
+
+  late final _wire__crate__api__chat_completionPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_record_string_string>,
+            ffi.Pointer<ffi.Uint32>,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__chat_completion');
+  late final _wire__crate__api__chat_completion =
+      _wire__crate__api__chat_completionPtr
+          .asFunction<
+            void Function(
+              int,
+              ffi.Pointer<wire_cst_list_record_string_string>,
+              ffi.Pointer<ffi.Uint32>,
+            )
+          >();
+
+  void wire__crate__api__chat_completion_stream(
+    int port_,
+    ffi.Pointer<wire_cst_list_record_string_string> messages,
+    ffi.Pointer<ffi.Uint32> max_tokens,
+    ffi.Pointer<wire_cst_list_String> stop_sequences,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> model_id,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> sink,
+  ) {
+    return _wire__crate__api__chat_completion_stream(
+      port_,
+      messages,
+      max_tokens,
+      stop_sequences,
+      model_id,
+      sink,
+    );
+  }
+
+  late final _wire__crate__api__chat_completion_streamPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_record_string_string>,
+            ffi.Pointer<ffi.Uint32>,
+            ffi.Pointer<wire_cst_list_String>,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__chat_completion_stream');
+  late final _wire__crate__api__chat_completion_stream =
+      _wire__crate__api__chat_completion_streamPtr
+          .asFunction<
+            void Function(
+              int,
+              ffi.Pointer<wire_cst_list_record_string_string>,
+              ffi.Pointer<ffi.Uint32>,
+              ffi.Pointer<wire_cst_list_String>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            )
+          >();
+
+  void wire__crate__api__chat_completion_with_model(
+    int port_,
+    ffi.Pointer<wire_cst_list_record_string_string> messages,
+    ffi.Pointer<ffi.Uint32> max_tokens,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> model_id,
+  ) {
+    return _wire__crate__api__chat_completion_with_model(
+      port_,
+      messages,
+      max_tokens,
+      model_id,
+    );
+  }
+
+  late final _wire__crate__api__chat_completion_with_modelPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_record_string_string>,
+            ffi.Pointer<ffi.Uint32>,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__chat_completion_with_model');
+  late final _wire__crate__api__chat_completion_with_model =
+      _wire__crate__api__chat_completion_with_modelPtr
+          .asFunction<
+            void Function(
+              int,
+              ffi.Pointer<wire_cst_list_record_string_string>,
+              ffi.Pointer<ffi.Uint32>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            )
+          >();
+
+  void wire__crate__api__chat_completion_with_options(
+    int port_,
+    ffi.Pointer<wire_cst_list_record_string_string> messages,
+    ffi.Pointer<wire_cst_generation_options> options,
+  ) {
+    return _wire__crate__api__chat_completion_with_options(
+      port_,
+      messages,
+      options,
+    );
+  }
+
+  late final _wire__crate__api__chat_completion_with_optionsPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_record_string_string>,
+            ffi.Pointer<wire_cst_generation_options>,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__chat_completion_with_options');
+  late final _wire__crate__api__chat_completion_with_options =
+      _wire__crate__api__chat_completion_with_optionsPtr
+          .asFunction<
+            void Function(
+              int,
+              ffi.Pointer<wire_cst_list_record_string_string>,
+              ffi.Pointer<wire_cst_generation_options>,
+            )
+          >();
+
+  void wire__crate__api__chat_session_completion(
+    int port_,
+    int session_id,
+    ffi.Pointer<wire_cst_list_record_string_string> messages,
+    ffi.Pointer<wire_cst_generation_options> options,
+  ) {
+    return _wire__crate__api__chat_session_completion(
+      port_,
+      session_id,
+      messages,
+      options,
+    );
+  }
+
+  late final _wire__crate__api__chat_session_completionPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Uint64,
+            ffi.Pointer<wire_cst_list_record_string_string>,
+            ffi.Pointer<wire_cst_generation_options>,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__chat_session_completion');
+  late final _wire__crate__api__chat_session_completion =
+      _wire__crate__api__chat_session_completionPtr
+          .asFunction<
+            void Function(
+              int,
+              int,
+              ffi.Pointer<wire_cst_list_record_string_string>,
+              ffi.Pointer<wire_cst_generation_options>,
+            )
+          >();
+
+  void wire__crate__api__chat_session_stream(
+    int port_,
+    int session_id,
+    ffi.Pointer<wire_cst_list_record_string_string> messages,
+    ffi.Pointer<ffi.Uint32> max_tokens,
+    ffi.Pointer<wire_cst_list_String> stop_sequences,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> sink,
+  ) {
+    return _wire__crate__api__chat_session_stream(
+      port_,
+      session_id,
+      messages,
+      max_tokens,
+      stop_sequences,
+      sink,
+    );
+  }
+
+  late final _wire__crate__api__chat_session_streamPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Uint64,
+            ffi.Pointer<wire_cst_list_record_string_string>,
+            ffi.Pointer<ffi.Uint32>,
+            ffi.Pointer<wire_cst_list_String>,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__chat_session_stream');
+  late final _wire__crate__api__chat_session_stream =
+      _wire__crate__api__chat_session_streamPtr
+          .asFunction<
+            void Function(
+              int,
+              int,
+              ffi.Pointer<wire_cst_list_record_string_string>,
+              ffi.Pointer<ffi.Uint32>,
+              ffi.Pointer<wire_cst_list_String>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            )
+          >();
+
+  WireSyncRust2DartDco wire__crate__api__chat_session_token_count(
+    int session_id,
+  ) {
+    return _wire__crate__api__chat_session_token_count(session_id);
+  }
+
+  late final _wire__crate__api__chat_session_token_countPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Uint64)>>(
+        'frbgen_kivixa_wire__crate__api__chat_session_token_count',
+      );
+  late final _wire__crate__api__chat_session_token_count =
+      _wire__crate__api__chat_session_token_countPtr
+          .asFunction<WireSyncRust2DartDco Function(int)>();
+
+  void wire__crate__api__chunk_markdown(
+    int port_,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> text,
+    ffi.Pointer<ffi.Uint32> max_tokens,
+    ffi.Pointer<ffi.Uint32> overlap_tokens,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> model_id,
+  ) {
+    return _wire__crate__api__chunk_markdown(
+      port_,
+      text,
+      max_tokens,
+      overlap_tokens,
+      model_id,
+    );
+  }
+
+  late final _wire__crate__api__chunk_markdownPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Pointer<ffi.Uint32>,
+            ffi.Pointer<ffi.Uint32>,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__chunk_markdown');
+  late final _wire__crate__api__chunk_markdown =
+      _wire__crate__api__chunk_markdownPtr
+          .asFunction<
+            void Function(
+              int,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              ffi.Pointer<ffi.Uint32>,
+              ffi.Pointer<ffi.Uint32>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            )
+          >();
+
+  WireSyncRust2DartDco wire__crate__api__clear_graph() {
+    return _wire__crate__api__clear_graph();
+  }
+
+  late final _wire__crate__api__clear_graphPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>(
+        'frbgen_kivixa_wire__crate__api__clear_graph',
+      );
+  late final _wire__crate__api__clear_graph = _wire__crate__api__clear_graphPtr
+      .asFunction<WireSyncRust2DartDco Function()>();
+
+  WireSyncRust2DartDco wire__crate__api__clear_stream_graph() {
+    return _wire__crate__api__clear_stream_graph();
+  }
+
+  late final _wire__crate__api__clear_stream_graphPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>(
+        'frbgen_kivixa_wire__crate__api__clear_stream_graph',
+      );
+  late final _wire__crate__api__clear_stream_graph =
+      _wire__crate__api__clear_stream_graphPtr
+          .asFunction<WireSyncRust2DartDco Function()>();
+
+  WireSyncRust2DartDco wire__crate__api__close_chat_session(int session_id) {
+    return _wire__crate__api__close_chat_session(session_id);
+  }
+
+  late final _wire__crate__api__close_chat_sessionPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Uint64)>>(
+        'frbgen_kivixa_wire__crate__api__close_chat_session',
+      );
+  late final _wire__crate__api__close_chat_session =
+      _wire__crate__api__close_chat_sessionPtr
+          .asFunction<WireSyncRust2DartDco Function(int)>();
+
+  WireSyncRust2DartDco wire__crate__api__close_embedding_cache() {
+    return _wire__crate__api__close_embedding_cache();
+  }
+
+  late final _wire__crate__api__close_embedding_cachePtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>(
+        'frbgen_kivixa_wire__crate__api__close_embedding_cache',
+      );
+  late final _wire__crate__api__close_embedding_cache =
+      _wire__crate__api__close_embedding_cachePtr
+          .asFunction<WireSyncRust2DartDco Function()>();
+
+  WireSyncRust2DartDco wire__crate__api__close_hnsw_index(int index_id) {
+    return _wire__crate__api__close_hnsw_index(index_id);
+  }
+
+  late final _wire__crate__api__close_hnsw_indexPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Uint64)>>(
+        'frbgen_kivixa_wire__crate__api__close_hnsw_index',
+      );
+  late final _wire__crate__api__close_hnsw_index =
+      _wire__crate__api__close_hnsw_indexPtr
+          .asFunction<WireSyncRust2DartDco Function(int)>();
+
+  WireSyncRust2DartDco wire__crate__api__close_quantized_index(int index_id) {
+    return _wire__crate__api__close_quantized_index(index_id);
+  }
+
+  late final _wire__crate__api__close_quantized_indexPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Uint64)>>(
+        'frbgen_kivixa_wire__crate__api__close_quantized_index',
+      );
+  late final _wire__crate__api__close_quantized_index =
+      _wire__crate__api__close_quantized_indexPtr
+          .asFunction<WireSyncRust2DartDco Function(int)>();
+
+  WireSyncRust2DartDco wire__crate__api__close_vector_store(int store_id) {
+    return _wire__crate__api__close_vector_store(store_id);
+  }
+
+  late final _wire__crate__api__close_vector_storePtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Uint64)>>(
+        'frbgen_kivixa_wire__crate__api__close_vector_store',
+      );
+  late final _wire__crate__api__close_vector_store =
+      _wire__crate__api__close_vector_storePtr
+          .asFunction<WireSyncRust2DartDco Function(int)>();
+
+  void wire__crate__api__cluster_embeddings(
+    int port_,
+    ffi.Pointer<wire_cst_list_embedding_entry> entries,
+    double threshold,
+  ) {
+    return _wire__crate__api__cluster_embeddings(port_, entries, threshold);
+  }
+
+  late final _wire__crate__api__cluster_embeddingsPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_embedding_entry>,
+            ffi.Float,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__cluster_embeddings');
+  late final _wire__crate__api__cluster_embeddings =
+      _wire__crate__api__cluster_embeddingsPtr
+          .asFunction<
+            void Function(
+              int,
+              ffi.Pointer<wire_cst_list_embedding_entry>,
+              double,
+            )
+          >();
+
+  void wire__crate__api__cluster_notes(
+    int port_,
+    ffi.Pointer<wire_cst_list_embedding_entry> entries,
+    ffi.Pointer<ffi.UintPtr> k,
+    ffi.Pointer<ffi.UintPtr> max_iterations,
+  ) {
+    return _wire__crate__api__cluster_notes(port_, entries, k, max_iterations);
+  }
+
+  late final _wire__crate__api__cluster_notesPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_embedding_entry>,
+            ffi.Pointer<ffi.UintPtr>,
+            ffi.Pointer<ffi.UintPtr>,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__cluster_notes');
+  late final _wire__crate__api__cluster_notes =
+      _wire__crate__api__cluster_notesPtr
+          .asFunction<
+            void Function(
+              int,
+              ffi.Pointer<wire_cst_list_embedding_entry>,
+              ffi.Pointer<ffi.UintPtr>,
+              ffi.Pointer<ffi.UintPtr>,
+            )
+          >();
+
+  void wire__crate__api__cluster_notes_in_store(
+    int port_,
+    int store_id,
+    ffi.Pointer<ffi.UintPtr> k,
+    ffi.Pointer<ffi.UintPtr> max_iterations,
+  ) {
+    return _wire__crate__api__cluster_notes_in_store(
+      port_,
+      store_id,
+      k,
+      max_iterations,
+    );
+  }
+
+  late final _wire__crate__api__cluster_notes_in_storePtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Uint64,
+            ffi.Pointer<ffi.UintPtr>,
+            ffi.Pointer<ffi.UintPtr>,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__cluster_notes_in_store');
+  late final _wire__crate__api__cluster_notes_in_store =
+      _wire__crate__api__cluster_notes_in_storePtr
+          .asFunction<
+            void Function(
+              int,
+              int,
+              ffi.Pointer<ffi.UintPtr>,
+              ffi.Pointer<ffi.UintPtr>,
+            )
+          >();
+
+  void wire__crate__api__compact_embedding_cache(int port_) {
+    return _wire__crate__api__compact_embedding_cache(port_);
+  }
+
+  late final _wire__crate__api__compact_embedding_cachePtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>(
+        'frbgen_kivixa_wire__crate__api__compact_embedding_cache',
+      );
+  late final _wire__crate__api__compact_embedding_cache =
+      _wire__crate__api__compact_embedding_cachePtr
+          .asFunction<void Function(int)>();
+
+  void wire__crate__api__compact_vector_store(int port_, int store_id) {
+    return _wire__crate__api__compact_vector_store(port_, store_id);
+  }
+
+  late final _wire__crate__api__compact_vector_storePtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint64)>>(
+        'frbgen_kivixa_wire__crate__api__compact_vector_store',
+      );
+  late final _wire__crate__api__compact_vector_store =
+      _wire__crate__api__compact_vector_storePtr
+          .asFunction<void Function(int, int)>();
+
+  void wire__crate__api__compute_graph_layout(
+    int port_,
+    ffi.Pointer<ffi.Uint32> iterations,
+  ) {
+    return _wire__crate__api__compute_graph_layout(port_, iterations);
+  }
+
+  late final _wire__crate__api__compute_graph_layoutPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(ffi.Int64, ffi.Pointer<ffi.Uint32>)
+        >
+      >('frbgen_kivixa_wire__crate__api__compute_graph_layout');
+  late final _wire__crate__api__compute_graph_layout =
+      _wire__crate__api__compute_graph_layoutPtr
+          .asFunction<void Function(int, ffi.Pointer<ffi.Uint32>)>();
+
+  void wire__crate__api__connect_note_to_topics(
+    int port_,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> note_id,
+    ffi.Pointer<wire_cst_list_String> topic_ids,
+  ) {
+    return _wire__crate__api__connect_note_to_topics(port_, note_id, topic_ids);
+  }
+
+  late final _wire__crate__api__connect_note_to_topicsPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Pointer<wire_cst_list_String>,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__connect_note_to_topics');
+  late final _wire__crate__api__connect_note_to_topics =
+      _wire__crate__api__connect_note_to_topicsPtr
+          .asFunction<
+            void Function(
+              int,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              ffi.Pointer<wire_cst_list_String>,
+            )
+          >();
+
+  WireSyncRust2DartDco wire__crate__api__cosine_similarity(
+    ffi.Pointer<wire_cst_list_prim_f_32_loose> a,
+    ffi.Pointer<wire_cst_list_prim_f_32_loose> b,
+  ) {
+    return _wire__crate__api__cosine_similarity(a, b);
+  }
+
+  late final _wire__crate__api__cosine_similarityPtr =
+      _lookup<
+        ffi.NativeFunction<
+          WireSyncRust2DartDco Function(
+            ffi.Pointer<wire_cst_list_prim_f_32_loose>,
+            ffi.Pointer<wire_cst_list_prim_f_32_loose>,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__cosine_similarity');
+  late final _wire__crate__api__cosine_similarity =
+      _wire__crate__api__cosine_similarityPtr
+          .asFunction<
+            WireSyncRust2DartDco Function(
+              ffi.Pointer<wire_cst_list_prim_f_32_loose>,
+              ffi.Pointer<wire_cst_list_prim_f_32_loose>,
+            )
+          >();
+
+  void wire__crate__api__count_chat_prompt_tokens(
+    int port_,
+    ffi.Pointer<wire_cst_list_record_string_string> messages,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> model_id,
+  ) {
+    return _wire__crate__api__count_chat_prompt_tokens(
+      port_,
+      messages,
+      model_id,
+    );
+  }
+
+  late final _wire__crate__api__count_chat_prompt_tokensPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_record_string_string>,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__count_chat_prompt_tokens');
+  late final _wire__crate__api__count_chat_prompt_tokens =
+      _wire__crate__api__count_chat_prompt_tokensPtr
+          .asFunction<
+            void Function(
+              int,
+              ffi.Pointer<wire_cst_list_record_string_string>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            )
+          >();
+
+  void wire__crate__api__count_chat_tokens(
+    int port_,
+    ffi.Pointer<wire_cst_list_record_string_string> messages,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> model_id,
+  ) {
+    return _wire__crate__api__count_chat_tokens(port_, messages, model_id);
+  }
+
+  late final _wire__crate__api__count_chat_tokensPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_record_string_string>,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__count_chat_tokens');
+  late final _wire__crate__api__count_chat_tokens =
+      _wire__crate__api__count_chat_tokensPtr
+          .asFunction<
+            void Function(
+              int,
+              ffi.Pointer<wire_cst_list_record_string_string>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            )
+          >();
+
+  void wire__crate__api__count_tokens(
+    int port_,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> text,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> model_id,
+  ) {
+    return _wire__crate__api__count_tokens(port_, text, model_id);
+  }
+
+  late final _wire__crate__api__count_tokensPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__count_tokens');
+  late final _wire__crate__api__count_tokens =
+      _wire__crate__api__count_tokensPtr
+          .asFunction<
+            void Function(
+              int,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            )
+          >();
+
+  void wire__crate__api__create_chat_session(
+    int port_,
+    ffi.Pointer<ffi.Uint32> n_ctx,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> model_id,
+  ) {
+    return _wire__crate__api__create_chat_session(port_, n_ctx, model_id);
+  }
+
+  late final _wire__crate__api__create_chat_sessionPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<ffi.Uint32>,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__create_chat_session');
+  late final _wire__crate__api__create_chat_session =
+      _wire__crate__api__create_chat_sessionPtr
+          .asFunction<
+            void Function(
+              int,
+              ffi.Pointer<ffi.Uint32>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            )
+          >();
+
+  WireSyncRust2DartDco wire__crate__api__create_hnsw_index(
+    ffi.Pointer<wire_cst_hnsw_config> config,
+  ) {
+    return _wire__crate__api__create_hnsw_index(config);
+  }
+
+  late final _wire__crate__api__create_hnsw_indexPtr =
+      _lookup<
+        ffi.NativeFunction<
+          WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_hnsw_config>)
+        >
+      >('frbgen_kivixa_wire__crate__api__create_hnsw_index');
+  late final _wire__crate__api__create_hnsw_index =
+      _wire__crate__api__create_hnsw_indexPtr
+          .asFunction<
+            WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_hnsw_config>)
+          >();
+
+  void wire__crate__api__create_hnsw_index_from_store(
+    int port_,
+    int store_id,
+    ffi.Pointer<wire_cst_hnsw_config> config,
+  ) {
+    return _wire__crate__api__create_hnsw_index_from_store(
+      port_,
+      store_id,
+      config,
+    );
+  }
+
+  late final _wire__crate__api__create_hnsw_index_from_storePtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Uint64,
+            ffi.Pointer<wire_cst_hnsw_config>,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__create_hnsw_index_from_store');
+  late final _wire__crate__api__create_hnsw_index_from_store =
+      _wire__crate__api__create_hnsw_index_from_storePtr
+          .asFunction<
+            void Function(int, int, ffi.Pointer<wire_cst_hnsw_config>)
+          >();
+
+  void wire__crate__api__create_quantized_index(
+    int port_,
+    ffi.Pointer<wire_cst_list_embedding_entry> entries,
+    int kind,
+  ) {
+    return _wire__crate__api__create_quantized_index(port_, entries, kind);
+  }
+
+  late final _wire__crate__api__create_quantized_indexPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_embedding_entry>,
+            ffi.Int32,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__create_quantized_index');
+  late final _wire__crate__api__create_quantized_index =
+      _wire__crate__api__create_quantized_indexPtr
+          .asFunction<
+            void Function(int, ffi.Pointer<wire_cst_list_embedding_entry>, int)
+          >();
+
+  void wire__crate__api__create_quantized_index_from_store(
+    int port_,
+    int store_id,
+    int kind,
+  ) {
+    return _wire__crate__api__create_quantized_index_from_store(
+      port_,
+      store_id,
+      kind,
+    );
+  }
+
+  late final _wire__crate__api__create_quantized_index_from_storePtr =
+      _lookup<
+        ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint64, ffi.Int32)>
+      >('frbgen_kivixa_wire__crate__api__create_quantized_index_from_store');
+  late final _wire__crate__api__create_quantized_index_from_store =
+      _wire__crate__api__create_quantized_index_from_storePtr
+          .asFunction<void Function(int, int, int)>();
+
+  WireSyncRust2DartDco wire__crate__api__default_context_policy() {
+    return _wire__crate__api__default_context_policy();
+  }
+
+  late final _wire__crate__api__default_context_policyPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>(
+        'frbgen_kivixa_wire__crate__api__default_context_policy',
+      );
+  late final _wire__crate__api__default_context_policy =
+      _wire__crate__api__default_context_policyPtr
+          .asFunction<WireSyncRust2DartDco Function()>();
+
+  WireSyncRust2DartDco wire__crate__api__default_dedup_options() {
+    return _wire__crate__api__default_dedup_options();
+  }
+
+  late final _wire__crate__api__default_dedup_optionsPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>(
+        'frbgen_kivixa_wire__crate__api__default_dedup_options',
+      );
+  late final _wire__crate__api__default_dedup_options =
+      _wire__crate__api__default_dedup_optionsPtr
+          .asFunction<WireSyncRust2DartDco Function()>();
+
+  WireSyncRust2DartDco wire__crate__api__default_embedding_config() {
+    return _wire__crate__api__default_embedding_config();
+  }
+
+  late final _wire__crate__api__default_embedding_configPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>(
+        'frbgen_kivixa_wire__crate__api__default_embedding_config',
+      );
+  late final _wire__crate__api__default_embedding_config =
+      _wire__crate__api__default_embedding_configPtr
+          .asFunction<WireSyncRust2DartDco Function()>();
+
+  WireSyncRust2DartDco wire__crate__api__default_hnsw_config() {
+    return _wire__crate__api__default_hnsw_config();
+  }
+
+  late final _wire__crate__api__default_hnsw_configPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>(
+        'frbgen_kivixa_wire__crate__api__default_hnsw_config',
+      );
+  late final _wire__crate__api__default_hnsw_config =
+      _wire__crate__api__default_hnsw_configPtr
+          .asFunction<WireSyncRust2DartDco Function()>();
+
+  WireSyncRust2DartDco wire__crate__api__default_hybrid_options() {
+    return _wire__crate__api__default_hybrid_options();
+  }
+
+  late final _wire__crate__api__default_hybrid_optionsPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>(
+        'frbgen_kivixa_wire__crate__api__default_hybrid_options',
+      );
+  late final _wire__crate__api__default_hybrid_options =
+      _wire__crate__api__default_hybrid_optionsPtr
+          .asFunction<WireSyncRust2DartDco Function()>();
+
+  WireSyncRust2DartDco wire__crate__api__default_reasoning_options() {
+    return _wire__crate__api__default_reasoning_options();
+  }
+
+  late final _wire__crate__api__default_reasoning_optionsPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>(
+        'frbgen_kivixa_wire__crate__api__default_reasoning_options',
+      );
+  late final _wire__crate__api__default_reasoning_options =
+      _wire__crate__api__default_reasoning_optionsPtr
+          .asFunction<WireSyncRust2DartDco Function()>();
+
+  WireSyncRust2DartDco wire__crate__api__default_sampler_config() {
+    return _wire__crate__api__default_sampler_config();
+  }
+
+  late final _wire__crate__api__default_sampler_configPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>(
+        'frbgen_kivixa_wire__crate__api__default_sampler_config',
+      );
+  late final _wire__crate__api__default_sampler_config =
+      _wire__crate__api__default_sampler_configPtr
+          .asFunction<WireSyncRust2DartDco Function()>();
+
+  WireSyncRust2DartDco wire__crate__api__default_search_options() {
+    return _wire__crate__api__default_search_options();
+  }
+
+  late final _wire__crate__api__default_search_optionsPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>(
+        'frbgen_kivixa_wire__crate__api__default_search_options',
+      );
+  late final _wire__crate__api__default_search_options =
+      _wire__crate__api__default_search_optionsPtr
+          .asFunction<WireSyncRust2DartDco Function()>();
+
+  WireSyncRust2DartDco wire__crate__api__default_summary_options() {
+    return _wire__crate__api__default_summary_options();
+  }
+
+  late final _wire__crate__api__default_summary_optionsPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>(
+        'frbgen_kivixa_wire__crate__api__default_summary_options',
+      );
+  late final _wire__crate__api__default_summary_options =
+      _wire__crate__api__default_summary_optionsPtr
+          .asFunction<WireSyncRust2DartDco Function()>();
+
+  void wire__crate__api__detokenize_tokens(
+    int port_,
+    ffi.Pointer<wire_cst_list_prim_i_32_loose> tokens,
+    bool render_special,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> model_id,
+  ) {
+    return _wire__crate__api__detokenize_tokens(
+      port_,
+      tokens,
+      render_special,
+      model_id,
+    );
+  }
+
+  late final _wire__crate__api__detokenize_tokensPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_prim_i_32_loose>,
+            ffi.Bool,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__detokenize_tokens');
+  late final _wire__crate__api__detokenize_tokens =
+      _wire__crate__api__detokenize_tokensPtr
+          .asFunction<
+            void Function(
+              int,
+              ffi.Pointer<wire_cst_list_prim_i_32_loose>,
+              bool,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            )
+          >();
+
+  void wire__crate__api__discover_semantic_edges(
+    int port_,
+    ffi.Pointer<wire_cst_list_embedding_entry> entries,
+    ffi.Pointer<ffi.Float> threshold,
+    ffi.Pointer<wire_cst_list_record_string_string> existing_links,
+  ) {
+    return _wire__crate__api__discover_semantic_edges(
+      port_,
+      entries,
+      threshold,
+      existing_links,
+    );
+  }
+
+  late final _wire__crate__api__discover_semantic_edgesPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_embedding_entry>,
+            ffi.Pointer<ffi.Float>,
+            ffi.Pointer<wire_cst_list_record_string_string>,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__discover_semantic_edges');
+  late final _wire__crate__api__discover_semantic_edges =
+      _wire__crate__api__discover_semantic_edgesPtr
+          .asFunction<
+            void Function(
+              int,
+              ffi.Pointer<wire_cst_list_embedding_entry>,
+              ffi.Pointer<ffi.Float>,
+              ffi.Pointer<wire_cst_list_record_string_string>,
+            )
+          >();
+
+  void wire__crate__api__discover_semantic_edges_in_index(
+    int port_,
+    int index_id,
+    ffi.Pointer<ffi.Float> threshold,
+    ffi.Pointer<wire_cst_list_record_string_string> existing_links,
+    ffi.Pointer<ffi.UintPtr> max_neighbors,
+  ) {
+    return _wire__crate__api__discover_semantic_edges_in_index(
+      port_,
+      index_id,
+      threshold,
+      existing_links,
+      max_neighbors,
+    );
+  }
+
+  late final _wire__crate__api__discover_semantic_edges_in_indexPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Uint64,
+            ffi.Pointer<ffi.Float>,
+            ffi.Pointer<wire_cst_list_record_string_string>,
+            ffi.Pointer<ffi.UintPtr>,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__discover_semantic_edges_in_index');
+  late final _wire__crate__api__discover_semantic_edges_in_index =
+      _wire__crate__api__discover_semantic_edges_in_indexPtr
+          .asFunction<
+            void Function(
+              int,
+              int,
+              ffi.Pointer<ffi.Float>,
+              ffi.Pointer<wire_cst_list_record_string_string>,
+              ffi.Pointer<ffi.UintPtr>,
+            )
+          >();
+
+  void wire__crate__api__discover_semantic_edges_in_store(
+    int port_,
+    int store_id,
+    ffi.Pointer<ffi.Float> threshold,
+    ffi.Pointer<wire_cst_list_record_string_string> existing_links,
+  ) {
+    return _wire__crate__api__discover_semantic_edges_in_store(
+      port_,
+      store_id,
+      threshold,
+      existing_links,
+    );
+  }
+
+  late final _wire__crate__api__discover_semantic_edges_in_storePtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Uint64,
+            ffi.Pointer<ffi.Float>,
+            ffi.Pointer<wire_cst_list_record_string_string>,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__discover_semantic_edges_in_store');
+  late final _wire__crate__api__discover_semantic_edges_in_store =
+      _wire__crate__api__discover_semantic_edges_in_storePtr
+          .asFunction<
+            void Function(
+              int,
+              int,
+              ffi.Pointer<ffi.Float>,
+              ffi.Pointer<wire_cst_list_record_string_string>,
+            )
+          >();
+
+  void wire__crate__api__embed_markdown_note(
+    int port_,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> note_id,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> text,
+    ffi.Pointer<ffi.Uint32> max_tokens,
+    ffi.Pointer<ffi.Uint32> overlap_tokens,
+    ffi.Pointer<wire_cst_note_metadata> metadata,
+  ) {
+    return _wire__crate__api__embed_markdown_note(
+      port_,
+      note_id,
+      text,
+      max_tokens,
+      overlap_tokens,
+      metadata,
+    );
+  }
+
+  late final _wire__crate__api__embed_markdown_notePtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Pointer<ffi.Uint32>,
+            ffi.Pointer<ffi.Uint32>,
+            ffi.Pointer<wire_cst_note_metadata>,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__embed_markdown_note');
+  late final _wire__crate__api__embed_markdown_note =
+      _wire__crate__api__embed_markdown_notePtr
+          .asFunction<
+            void Function(
+              int,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              ffi.Pointer<ffi.Uint32>,
+              ffi.Pointer<ffi.Uint32>,
+              ffi.Pointer<wire_cst_note_metadata>,
+            )
+          >();
+
+  void wire__crate__api__embed_texts(
+    int port_,
+    ffi.Pointer<wire_cst_list_String> texts,
+    int kind,
+  ) {
+    return _wire__crate__api__embed_texts(port_, texts, kind);
+  }
+
+  late final _wire__crate__api__embed_textsPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_String>,
+            ffi.Int32,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__embed_texts');
+  late final _wire__crate__api__embed_texts = _wire__crate__api__embed_textsPtr
+      .asFunction<void Function(int, ffi.Pointer<wire_cst_list_String>, int)>();
+
+  void wire__crate__api__extract_topics(
+    int port_,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> text,
+    ffi.Pointer<ffi.Uint32> num_topics,
+  ) {
+    return _wire__crate__api__extract_topics(port_, text, num_topics);
+  }
+
+  late final _wire__crate__api__extract_topicsPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Pointer<ffi.Uint32>,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__extract_topics');
+  late final _wire__crate__api__extract_topics =
+      _wire__crate__api__extract_topicsPtr
+          .asFunction<
+            void Function(
+              int,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              ffi.Pointer<ffi.Uint32>,
+            )
+          >();
+
+  void wire__crate__api__extract_topics_with_model(
+    int port_,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> text,
+    ffi.Pointer<ffi.Uint32> num_topics,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> model_id,
+    ffi.Pointer<ffi.Float> min_confidence,
+  ) {
+    return _wire__crate__api__extract_topics_with_model(
+      port_,
+      text,
+      num_topics,
+      model_id,
+      min_confidence,
+    );
+  }
+
+  late final _wire__crate__api__extract_topics_with_modelPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Pointer<ffi.Uint32>,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Pointer<ffi.Float>,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__extract_topics_with_model');
+  late final _wire__crate__api__extract_topics_with_model =
+      _wire__crate__api__extract_topics_with_modelPtr
+          .asFunction<
+            void Function(
+              int,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              ffi.Pointer<ffi.Uint32>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              ffi.Pointer<ffi.Float>,
+            )
+          >();
+
+  void wire__crate__api__find_duplicate_entries(
+    int port_,
+    ffi.Pointer<wire_cst_list_embedding_entry> entries,
+    ffi.Pointer<wire_cst_dedup_options> options,
+  ) {
+    return _wire__crate__api__find_duplicate_entries(port_, entries, options);
+  }
+
+  late final _wire__crate__api__find_duplicate_entriesPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_embedding_entry>,
+            ffi.Pointer<wire_cst_dedup_options>,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__find_duplicate_entries');
+  late final _wire__crate__api__find_duplicate_entries =
+      _wire__crate__api__find_duplicate_entriesPtr
+          .asFunction<
+            void Function(
+              int,
+              ffi.Pointer<wire_cst_list_embedding_entry>,
+              ffi.Pointer<wire_cst_dedup_options>,
+            )
+          >();
+
+  void wire__crate__api__find_duplicates(
+    int port_,
+    ffi.Pointer<wire_cst_list_dedup_document> documents,
+    ffi.Pointer<wire_cst_dedup_options> options,
+  ) {
+    return _wire__crate__api__find_duplicates(port_, documents, options);
+  }
+
+  late final _wire__crate__api__find_duplicatesPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_dedup_document>,
+            ffi.Pointer<wire_cst_dedup_options>,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__find_duplicates');
+  late final _wire__crate__api__find_duplicates =
+      _wire__crate__api__find_duplicatesPtr
+          .asFunction<
+            void Function(
+              int,
+              ffi.Pointer<wire_cst_list_dedup_document>,
+              ffi.Pointer<wire_cst_dedup_options>,
+            )
+          >();
+
+  void wire__crate__api__find_duplicates_in_store(
+    int port_,
+    int store_id,
+    ffi.Pointer<wire_cst_dedup_options> options,
+  ) {
+    return _wire__crate__api__find_duplicates_in_store(
+      port_,
+      store_id,
+      options,
+    );
+  }
+
+  late final _wire__crate__api__find_duplicates_in_storePtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Uint64,
+            ffi.Pointer<wire_cst_dedup_options>,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__find_duplicates_in_store');
+  late final _wire__crate__api__find_duplicates_in_store =
+      _wire__crate__api__find_duplicates_in_storePtr
+          .asFunction<
+            void Function(int, int, ffi.Pointer<wire_cst_dedup_options>)
+          >();
+
+  void wire__crate__api__find_similar(
+    int port_,
+    ffi.Pointer<wire_cst_list_prim_f_32_loose> query,
+    ffi.Pointer<wire_cst_list_embedding_entry> entries,
+    int top_k,
+    double threshold,
+  ) {
+    return _wire__crate__api__find_similar(
+      port_,
+      query,
+      entries,
+      top_k,
+      threshold,
+    );
+  }
+
+  late final _wire__crate__api__find_similarPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_prim_f_32_loose>,
+            ffi.Pointer<wire_cst_list_embedding_entry>,
+            ffi.UintPtr,
+            ffi.Float,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__find_similar');
+  late final _wire__crate__api__find_similar =
+      _wire__crate__api__find_similarPtr
+          .asFunction<
+            void Function(
+              int,
+              ffi.Pointer<wire_cst_list_prim_f_32_loose>,
+              ffi.Pointer<wire_cst_list_embedding_entry>,
+              int,
+              double,
+            )
+          >();
+
+  void wire__crate__api__find_similar_filtered(
+    int port_,
+    ffi.Pointer<wire_cst_list_prim_f_32_loose> query,
+    ffi.Pointer<wire_cst_list_embedding_entry> entries,
+    ffi.Pointer<wire_cst_search_options> options,
+  ) {
+    return _wire__crate__api__find_similar_filtered(
+      port_,
+      query,
+      entries,
+      options,
+    );
+  }
+
+  late final _wire__crate__api__find_similar_filteredPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_prim_f_32_loose>,
+            ffi.Pointer<wire_cst_list_embedding_entry>,
+            ffi.Pointer<wire_cst_search_options>,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__find_similar_filtered');
+  late final _wire__crate__api__find_similar_filtered =
+      _wire__crate__api__find_similar_filteredPtr
+          .asFunction<
+            void Function(
+              int,
+              ffi.Pointer<wire_cst_list_prim_f_32_loose>,
+              ffi.Pointer<wire_cst_list_embedding_entry>,
+              ffi.Pointer<wire_cst_search_options>,
+            )
+          >();
+
+  void wire__crate__api__find_similar_in_index(
+    int port_,
+    int index_id,
+    ffi.Pointer<wire_cst_list_prim_f_32_loose> query,
+    int top_k,
+    double threshold,
+  ) {
+    return _wire__crate__api__find_similar_in_index(
+      port_,
+      index_id,
+      query,
+      top_k,
+      threshold,
+    );
+  }
+
+  late final _wire__crate__api__find_similar_in_indexPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Uint64,
+            ffi.Pointer<wire_cst_list_prim_f_32_loose>,
+            ffi.UintPtr,
+            ffi.Float,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__find_similar_in_index');
+  late final _wire__crate__api__find_similar_in_index =
+      _wire__crate__api__find_similar_in_indexPtr
+          .asFunction<
+            void Function(
+              int,
+              int,
+              ffi.Pointer<wire_cst_list_prim_f_32_loose>,
+              int,
+              double,
+            )
+          >();
+
+  void wire__crate__api__find_similar_in_store(
+    int port_,
+    int store_id,
+    ffi.Pointer<wire_cst_list_prim_f_32_loose> query,
+    int top_k,
+    double threshold,
+  ) {
+    return _wire__crate__api__find_similar_in_store(
+      port_,
+      store_id,
+      query,
+      top_k,
+      threshold,
+    );
+  }
+
+  late final _wire__crate__api__find_similar_in_storePtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Uint64,
+            ffi.Pointer<wire_cst_list_prim_f_32_loose>,
+            ffi.UintPtr,
+            ffi.Float,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__find_similar_in_store');
+  late final _wire__crate__api__find_similar_in_store =
+      _wire__crate__api__find_similar_in_storePtr
+          .asFunction<
+            void Function(
+              int,
+              int,
+              ffi.Pointer<wire_cst_list_prim_f_32_loose>,
+              int,
+              double,
+            )
+          >();
+
+  void wire__crate__api__find_similar_quantized(
+    int port_,
+    int index_id,
+    ffi.Pointer<wire_cst_list_prim_f_32_loose> query,
+    int top_k,
+    double threshold,
+    ffi.Pointer<ffi.UintPtr> rescore_candidates,
+  ) {
+    return _wire__crate__api__find_similar_quantized(
+      port_,
+      index_id,
+      query,
+      top_k,
+      threshold,
+      rescore_candidates,
+    );
+  }
+
+  late final _wire__crate__api__find_similar_quantizedPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Uint64,
+            ffi.Pointer<wire_cst_list_prim_f_32_loose>,
+            ffi.UintPtr,
+            ffi.Float,
+            ffi.Pointer<ffi.UintPtr>,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__find_similar_quantized');
+  late final _wire__crate__api__find_similar_quantized =
+      _wire__crate__api__find_similar_quantizedPtr
+          .asFunction<
+            void Function(
+              int,
+              int,
+              ffi.Pointer<wire_cst_list_prim_f_32_loose>,
+              int,
+              double,
+              ffi.Pointer<ffi.UintPtr>,
+            )
+          >();
+
+  void wire__crate__api__generate_text(
+    int port_,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> prompt,
+    ffi.Pointer<ffi.Uint32> max_tokens,
+  ) {
+    return _wire__crate__api__generate_text(port_, prompt, max_tokens);
+  }
+
+  late final _wire__crate__api__generate_textPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Pointer<ffi.Uint32>,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__generate_text');
+  late final _wire__crate__api__generate_text =
+      _wire__crate__api__generate_textPtr
+          .asFunction<
+            void Function(
+              int,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              ffi.Pointer<ffi.Uint32>,
+            )
+          >();
+
+  void wire__crate__api__generate_text_stream(
+    int port_,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> prompt,
+    ffi.Pointer<ffi.Uint32> max_tokens,
+    ffi.Pointer<wire_cst_list_String> stop_sequences,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> model_id,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> sink,
+  ) {
+    return _wire__crate__api__generate_text_stream(
+      port_,
+      prompt,
+      max_tokens,
+      stop_sequences,
+      model_id,
+      sink,
+    );
+  }
+
+  late final _wire__crate__api__generate_text_streamPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Pointer<ffi.Uint32>,
+            ffi.Pointer<wire_cst_list_String>,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__generate_text_stream');
+  late final _wire__crate__api__generate_text_stream =
+      _wire__crate__api__generate_text_streamPtr
+          .asFunction<
+            void Function(
+              int,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              ffi.Pointer<ffi.Uint32>,
+              ffi.Pointer<wire_cst_list_String>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            )
+          >();
+
+  void wire__crate__api__generate_text_with_model(
+    int port_,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> prompt,
+    ffi.Pointer<ffi.Uint32> max_tokens,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> model_id,
+  ) {
+    return _wire__crate__api__generate_text_with_model(
+      port_,
+      prompt,
+      max_tokens,
+      model_id,
+    );
+  }
+
+  late final _wire__crate__api__generate_text_with_modelPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Pointer<ffi.Uint32>,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__generate_text_with_model');
+  late final _wire__crate__api__generate_text_with_model =
+      _wire__crate__api__generate_text_with_modelPtr
+          .asFunction<
+            void Function(
+              int,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              ffi.Pointer<ffi.Uint32>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            )
+          >();
+
+  void wire__crate__api__generate_text_with_options(
+    int port_,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> prompt,
+    ffi.Pointer<wire_cst_generation_options> options,
+  ) {
+    return _wire__crate__api__generate_text_with_options(
+      port_,
+      prompt,
+      options,
+    );
+  }
+
+  late final _wire__crate__api__generate_text_with_optionsPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Pointer<wire_cst_generation_options>,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__generate_text_with_options');
+  late final _wire__crate__api__generate_text_with_options =
+      _wire__crate__api__generate_text_with_optionsPtr
+          .asFunction<
+            void Function(
+              int,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              ffi.Pointer<wire_cst_generation_options>,
+            )
+          >();
+
+  void wire__crate__api__get_embedding(
+    int port_,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> text,
+  ) {
+    return _wire__crate__api__get_embedding(port_, text);
+  }
+
+  late final _wire__crate__api__get_embeddingPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__get_embedding');
+  late final _wire__crate__api__get_embedding =
+      _wire__crate__api__get_embeddingPtr
+          .asFunction<
+            void Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)
+          >();
+
+  void wire__crate__api__get_embedding_cache_stats(int port_) {
+    return _wire__crate__api__get_embedding_cache_stats(port_);
+  }
+
+  late final _wire__crate__api__get_embedding_cache_statsPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>(
+        'frbgen_kivixa_wire__crate__api__get_embedding_cache_stats',
+      );
+  late final _wire__crate__api__get_embedding_cache_stats =
+      _wire__crate__api__get_embedding_cache_statsPtr
+          .asFunction<void Function(int)>();
+
+  WireSyncRust2DartDco wire__crate__api__get_embedding_dimension() {
+    return _wire__crate__api__get_embedding_dimension();
+  }
+
+  late final _wire__crate__api__get_embedding_dimensionPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>(
+        'frbgen_kivixa_wire__crate__api__get_embedding_dimension',
+      );
+  late final _wire__crate__api__get_embedding_dimension =
+      _wire__crate__api__get_embedding_dimensionPtr
+          .asFunction<WireSyncRust2DartDco Function()>();
+
+  void wire__crate__api__get_embedding_with_model(
+    int port_,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> text,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> model_id,
+  ) {
+    return _wire__crate__api__get_embedding_with_model(port_, text, model_id);
+  }
+
+  late final _wire__crate__api__get_embedding_with_modelPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__get_embedding_with_model');
+  late final _wire__crate__api__get_embedding_with_model =
+      _wire__crate__api__get_embedding_with_modelPtr
+          .asFunction<
+            void Function(
+              int,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            )
+          >();
+
+  void wire__crate__api__get_graph_state(int port_) {
+    return _wire__crate__api__get_graph_state(port_);
+  }
+
+  late final _wire__crate__api__get_graph_statePtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>(
+        'frbgen_kivixa_wire__crate__api__get_graph_state',
+      );
+  late final _wire__crate__api__get_graph_state =
+      _wire__crate__api__get_graph_statePtr.asFunction<void Function(int)>();
+
+  WireSyncRust2DartDco wire__crate__api__get_model_metadata(
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> model_id,
+  ) {
+    return _wire__crate__api__get_model_metadata(model_id);
+  }
+
+  late final _wire__crate__api__get_model_metadataPtr =
+      _lookup<
+        ffi.NativeFunction<
+          WireSyncRust2DartDco Function(
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__get_model_metadata');
+  late final _wire__crate__api__get_model_metadata =
+      _wire__crate__api__get_model_metadataPtr
+          .asFunction<
+            WireSyncRust2DartDco Function(
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            )
+          >();
+
+  WireSyncRust2DartDco wire__crate__api__get_model_type() {
+    return _wire__crate__api__get_model_type();
+  }
+
+  late final _wire__crate__api__get_model_typePtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>(
+        'frbgen_kivixa_wire__crate__api__get_model_type',
+      );
+  late final _wire__crate__api__get_model_type =
+      _wire__crate__api__get_model_typePtr
+          .asFunction<WireSyncRust2DartDco Function()>();
+
+  void wire__crate__api__get_or_create_topic_hub(
+    int port_,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> topic,
+  ) {
+    return _wire__crate__api__get_or_create_topic_hub(port_, topic);
+  }
+
+  late final _wire__crate__api__get_or_create_topic_hubPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__get_or_create_topic_hub');
+  late final _wire__crate__api__get_or_create_topic_hub =
+      _wire__crate__api__get_or_create_topic_hubPtr
+          .asFunction<
+            void Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)
+          >();
+
+  WireSyncRust2DartDco wire__crate__api__get_stream_graph_stats() {
+    return _wire__crate__api__get_stream_graph_stats();
+  }
+
+  late final _wire__crate__api__get_stream_graph_statsPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>(
+        'frbgen_kivixa_wire__crate__api__get_stream_graph_stats',
+      );
+  late final _wire__crate__api__get_stream_graph_stats =
+      _wire__crate__api__get_stream_graph_statsPtr
+          .asFunction<WireSyncRust2DartDco Function()>();
+
+  WireSyncRust2DartDco wire__crate__api__get_tokenizer_info(
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> model_id,
+  ) {
+    return _wire__crate__api__get_tokenizer_info(model_id);
+  }
+
+  late final _wire__crate__api__get_tokenizer_infoPtr =
+      _lookup<
+        ffi.NativeFunction<
+          WireSyncRust2DartDco Function(
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__get_tokenizer_info');
+  late final _wire__crate__api__get_tokenizer_info =
+      _wire__crate__api__get_tokenizer_infoPtr
+          .asFunction<
+            WireSyncRust2DartDco Function(
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            )
+          >();
+
+  void wire__crate__api__get_vector_store_stats(int port_, int store_id) {
+    return _wire__crate__api__get_vector_store_stats(port_, store_id);
+  }
+
+  late final _wire__crate__api__get_vector_store_statsPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint64)>>(
+        'frbgen_kivixa_wire__crate__api__get_vector_store_stats',
+      );
+  late final _wire__crate__api__get_vector_store_stats =
+      _wire__crate__api__get_vector_store_statsPtr
+          .asFunction<void Function(int, int)>();
+
+  WireSyncRust2DartDco wire__crate__api__get_version() {
+    return _wire__crate__api__get_version();
+  }
+
+  late final _wire__crate__api__get_versionPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>(
+        'frbgen_kivixa_wire__crate__api__get_version',
+      );
+  late final _wire__crate__api__get_version = _wire__crate__api__get_versionPtr
+      .asFunction<WireSyncRust2DartDco Function()>();
+
+  void wire__crate__api__get_visible_graph_nodes(int port_) {
+    return _wire__crate__api__get_visible_graph_nodes(port_);
+  }
+
+  late final _wire__crate__api__get_visible_graph_nodesPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>(
+        'frbgen_kivixa_wire__crate__api__get_visible_graph_nodes',
+      );
+  late final _wire__crate__api__get_visible_graph_nodes =
+      _wire__crate__api__get_visible_graph_nodesPtr
+          .asFunction<void Function(int)>();
+
+  WireSyncRust2DartDco wire__crate__api__greedy_sampler_config() {
+    return _wire__crate__api__greedy_sampler_config();
+  }
+
+  late final _wire__crate__api__greedy_sampler_configPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>(
+        'frbgen_kivixa_wire__crate__api__greedy_sampler_config',
+      );
+  late final _wire__crate__api__greedy_sampler_config =
+      _wire__crate__api__greedy_sampler_configPtr
+          .asFunction<WireSyncRust2DartDco Function()>();
+
+  WireSyncRust2DartDco wire__crate__api__health_check() {
+    return _wire__crate__api__health_check();
+  }
+
+  late final _wire__crate__api__health_checkPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>(
+        'frbgen_kivixa_wire__crate__api__health_check',
+      );
+  late final _wire__crate__api__health_check =
+      _wire__crate__api__health_checkPtr
+          .asFunction<WireSyncRust2DartDco Function()>();
+
+  void wire__crate__api__hnsw_index_insert(
+    int port_,
+    int index_id,
+    ffi.Pointer<wire_cst_list_embedding_entry> entries,
+  ) {
+    return _wire__crate__api__hnsw_index_insert(port_, index_id, entries);
+  }
+
+  late final _wire__crate__api__hnsw_index_insertPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Uint64,
+            ffi.Pointer<wire_cst_list_embedding_entry>,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__hnsw_index_insert');
+  late final _wire__crate__api__hnsw_index_insert =
+      _wire__crate__api__hnsw_index_insertPtr
+          .asFunction<
+            void Function(int, int, ffi.Pointer<wire_cst_list_embedding_entry>)
+          >();
+
+  void wire__crate__api__hnsw_index_insert_with_texts(
+    int port_,
+    int index_id,
+    ffi.Pointer<wire_cst_list_embedding_entry> entries,
+    ffi.Pointer<wire_cst_list_String> texts,
+  ) {
+    return _wire__crate__api__hnsw_index_insert_with_texts(
+      port_,
+      index_id,
+      entries,
+      texts,
+    );
+  }
+
+  late final _wire__crate__api__hnsw_index_insert_with_textsPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Uint64,
+            ffi.Pointer<wire_cst_list_embedding_entry>,
+            ffi.Pointer<wire_cst_list_String>,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__hnsw_index_insert_with_texts');
+  late final _wire__crate__api__hnsw_index_insert_with_texts =
+      _wire__crate__api__hnsw_index_insert_with_textsPtr
+          .asFunction<
+            void Function(
+              int,
+              int,
+              ffi.Pointer<wire_cst_list_embedding_entry>,
+              ffi.Pointer<wire_cst_list_String>,
+            )
+          >();
+
+  WireSyncRust2DartDco wire__crate__api__hnsw_index_len(int index_id) {
+    return _wire__crate__api__hnsw_index_len(index_id);
+  }
+
+  late final _wire__crate__api__hnsw_index_lenPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Uint64)>>(
+        'frbgen_kivixa_wire__crate__api__hnsw_index_len',
+      );
+  late final _wire__crate__api__hnsw_index_len =
+      _wire__crate__api__hnsw_index_lenPtr
+          .asFunction<WireSyncRust2DartDco Function(int)>();
+
+  void wire__crate__api__hnsw_index_remove(
+    int port_,
+    int index_id,
+    ffi.Pointer<wire_cst_list_String> ids,
+  ) {
+    return _wire__crate__api__hnsw_index_remove(port_, index_id, ids);
+  }
+
+  late final _wire__crate__api__hnsw_index_removePtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Uint64,
+            ffi.Pointer<wire_cst_list_String>,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__hnsw_index_remove');
+  late final _wire__crate__api__hnsw_index_remove =
+      _wire__crate__api__hnsw_index_removePtr
+          .asFunction<
+            void Function(int, int, ffi.Pointer<wire_cst_list_String>)
+          >();
+
+  void wire__crate__api__hybrid_search(
+    int port_,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> query_text,
+    ffi.Pointer<wire_cst_list_embedding_entry> entries,
+    int top_k,
+    ffi.Pointer<wire_cst_hybrid_options> options,
+  ) {
+    return _wire__crate__api__hybrid_search(
+      port_,
+      query_text,
+      entries,
+      top_k,
+      options,
+    );
+  }
+
+  late final _wire__crate__api__hybrid_searchPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Pointer<wire_cst_list_embedding_entry>,
+            ffi.UintPtr,
+            ffi.Pointer<wire_cst_hybrid_options>,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__hybrid_search');
+  late final _wire__crate__api__hybrid_search =
+      _wire__crate__api__hybrid_searchPtr
+          .asFunction<
+            void Function(
+              int,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              ffi.Pointer<wire_cst_list_embedding_entry>,
+              int,
+              ffi.Pointer<wire_cst_hybrid_options>,
+            )
+          >();
+
+  void wire__crate__api__hybrid_search_in_index(
+    int port_,
+    int index_id,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> query_text,
+    int top_k,
+    ffi.Pointer<wire_cst_hybrid_options> options,
+  ) {
+    return _wire__crate__api__hybrid_search_in_index(
+      port_,
+      index_id,
+      query_text,
+      top_k,
+      options,
+    );
+  }
+
+  late final _wire__crate__api__hybrid_search_in_indexPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Uint64,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.UintPtr,
+            ffi.Pointer<wire_cst_hybrid_options>,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__hybrid_search_in_index');
+  late final _wire__crate__api__hybrid_search_in_index =
+      _wire__crate__api__hybrid_search_in_indexPtr
+          .asFunction<
+            void Function(
+              int,
+              int,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              int,
+              ffi.Pointer<wire_cst_hybrid_options>,
+            )
+          >();
+
+  WireSyncRust2DartDco wire__crate__api__init_graph() {
+    return _wire__crate__api__init_graph();
+  }
+
+  late final _wire__crate__api__init_graphPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>(
+        'frbgen_kivixa_wire__crate__api__init_graph',
+      );
+  late final _wire__crate__api__init_graph = _wire__crate__api__init_graphPtr
+      .asFunction<WireSyncRust2DartDco Function()>();
+
+  void wire__crate__api__init_mcp(
+    int port_,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> base_path,
+    ffi.Pointer<ffi.UintPtr> max_file_size,
+    ffi.Pointer<wire_cst_list_String> allowed_extensions,
+  ) {
+    return _wire__crate__api__init_mcp(
+      port_,
+      base_path,
+      max_file_size,
+      allowed_extensions,
+    );
+  }
+
+  late final _wire__crate__api__init_mcpPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Pointer<ffi.UintPtr>,
+            ffi.Pointer<wire_cst_list_String>,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__init_mcp');
+  late final _wire__crate__api__init_mcp = _wire__crate__api__init_mcpPtr
+      .asFunction<
+        void Function(
+          int,
+          ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+          ffi.Pointer<ffi.UintPtr>,
+          ffi.Pointer<wire_cst_list_String>,
+        )
+      >();
+
+  WireSyncRust2DartDco wire__crate__api__init_model(
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> model_path,
+  ) {
+    return _wire__crate__api__init_model(model_path);
+  }
+
+  late final _wire__crate__api__init_modelPtr =
+      _lookup<
+        ffi.NativeFunction<
+          WireSyncRust2DartDco Function(
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__init_model');
+  late final _wire__crate__api__init_model = _wire__crate__api__init_modelPtr
+      .asFunction<
+        WireSyncRust2DartDco Function(
+          ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+        )
+      >();
+
+  void wire__crate__api__init_model_with_config(
+    int port_,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> model_path,
+    int n_gpu_layers,
+    int n_ctx,
+    int n_threads,
+    double temperature,
+    double top_p,
+    int max_tokens,
+    ffi.Pointer<ffi.Int32> model_type,
+  ) {
+    return _wire__crate__api__init_model_with_config(
+      port_,
+      model_path,
+      n_gpu_layers,
+      n_ctx,
+      n_threads,
+      temperature,
+      top_p,
+      max_tokens,
+      model_type,
+    );
+  }
+
+  late final _wire__crate__api__init_model_with_configPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Uint32,
+            ffi.Uint32,
+            ffi.Int32,
+            ffi.Float,
+            ffi.Float,
+            ffi.Uint32,
+            ffi.Pointer<ffi.Int32>,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__init_model_with_config');
+  late final _wire__crate__api__init_model_with_config =
+      _wire__crate__api__init_model_with_configPtr
+          .asFunction<
+            void Function(
+              int,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              int,
+              int,
+              int,
+              double,
+              double,
+              int,
+              ffi.Pointer<ffi.Int32>,
+            )
+          >();
+
+  void wire__crate__api__inspect_model(
+    int port_,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> model_path,
+  ) {
+    return _wire__crate__api__inspect_model(port_, model_path);
+  }
+
+  late final _wire__crate__api__inspect_modelPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__inspect_model');
+  late final _wire__crate__api__inspect_model =
+      _wire__crate__api__inspect_modelPtr
+          .asFunction<
+            void Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)
+          >();
+
+  WireSyncRust2DartDco wire__crate__api__is_embedding_model_loaded() {
+    return _wire__crate__api__is_embedding_model_loaded();
+  }
+
+  late final _wire__crate__api__is_embedding_model_loadedPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>(
+        'frbgen_kivixa_wire__crate__api__is_embedding_model_loaded',
+      );
+  late final _wire__crate__api__is_embedding_model_loaded =
+      _wire__crate__api__is_embedding_model_loadedPtr
+          .asFunction<WireSyncRust2DartDco Function()>();
+
+  WireSyncRust2DartDco wire__crate__api__is_graph_stream_running() {
+    return _wire__crate__api__is_graph_stream_running();
+  }
+
+  late final _wire__crate__api__is_graph_stream_runningPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>(
+        'frbgen_kivixa_wire__crate__api__is_graph_stream_running',
+      );
+  late final _wire__crate__api__is_graph_stream_running =
+      _wire__crate__api__is_graph_stream_runningPtr
+          .asFunction<WireSyncRust2DartDco Function()>();
+
+  WireSyncRust2DartDco wire__crate__api__is_mcp_initialized() {
+    return _wire__crate__api__is_mcp_initialized();
+  }
+
+  late final _wire__crate__api__is_mcp_initializedPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>(
+        'frbgen_kivixa_wire__crate__api__is_mcp_initialized',
+      );
+  late final _wire__crate__api__is_mcp_initialized =
+      _wire__crate__api__is_mcp_initializedPtr
+          .asFunction<WireSyncRust2DartDco Function()>();
+
+  WireSyncRust2DartDco wire__crate__api__is_model_loaded() {
+    return _wire__crate__api__is_model_loaded();
+  }
+
+  late final _wire__crate__api__is_model_loadedPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>(
+        'frbgen_kivixa_wire__crate__api__is_model_loaded',
+      );
+  late final _wire__crate__api__is_model_loaded =
+      _wire__crate__api__is_model_loadedPtr
+          .asFunction<WireSyncRust2DartDco Function()>();
+
+  WireSyncRust2DartDco wire__crate__api__json_schema_to_grammar(
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> schema_json,
+  ) {
+    return _wire__crate__api__json_schema_to_grammar(schema_json);
+  }
+
+  late final _wire__crate__api__json_schema_to_grammarPtr =
+      _lookup<
+        ffi.NativeFunction<
+          WireSyncRust2DartDco Function(
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__json_schema_to_grammar');
+  late final _wire__crate__api__json_schema_to_grammar =
+      _wire__crate__api__json_schema_to_grammarPtr
+          .asFunction<
+            WireSyncRust2DartDco Function(
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            )
+          >();
+
+  WireSyncRust2DartDco wire__crate__api__list_loaded_models() {
+    return _wire__crate__api__list_loaded_models();
+  }
+
+  late final _wire__crate__api__list_loaded_modelsPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>(
+        'frbgen_kivixa_wire__crate__api__list_loaded_models',
+      );
+  late final _wire__crate__api__list_loaded_models =
+      _wire__crate__api__list_loaded_modelsPtr
+          .asFunction<WireSyncRust2DartDco Function()>();
+
+  void wire__crate__api__load_chat_session(
+    int port_,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> path,
+    ffi.Pointer<ffi.Uint32> n_ctx,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> model_id,
+  ) {
+    return _wire__crate__api__load_chat_session(port_, path, n_ctx, model_id);
+  }
+
+  late final _wire__crate__api__load_chat_sessionPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Pointer<ffi.Uint32>,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__load_chat_session');
+  late final _wire__crate__api__load_chat_session =
+      _wire__crate__api__load_chat_sessionPtr
+          .asFunction<
+            void Function(
+              int,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              ffi.Pointer<ffi.Uint32>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            )
+          >();
+
+  void wire__crate__api__load_embedding_model(
+    int port_,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> model_path,
+    ffi.Pointer<wire_cst_embedding_config> config,
+  ) {
+    return _wire__crate__api__load_embedding_model(port_, model_path, config);
+  }
+
+  late final _wire__crate__api__load_embedding_modelPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Pointer<wire_cst_embedding_config>,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__load_embedding_model');
+  late final _wire__crate__api__load_embedding_model =
+      _wire__crate__api__load_embedding_modelPtr
+          .asFunction<
+            void Function(
+              int,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              ffi.Pointer<wire_cst_embedding_config>,
+            )
+          >();
+
+  void wire__crate__api__load_hnsw_index(
+    int port_,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> path,
+  ) {
+    return _wire__crate__api__load_hnsw_index(port_, path);
+  }
+
+  late final _wire__crate__api__load_hnsw_indexPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__load_hnsw_index');
+  late final _wire__crate__api__load_hnsw_index =
+      _wire__crate__api__load_hnsw_indexPtr
+          .asFunction<
+            void Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)
+          >();
+
+  void wire__crate__api__load_model(
+    int port_,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> model_id,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> model_path,
+    ffi.Pointer<ffi.Uint32> n_ctx,
+    ffi.Pointer<ffi.Uint32> n_gpu_layers,
+  ) {
+    return _wire__crate__api__load_model(
+      port_,
+      model_id,
+      model_path,
+      n_ctx,
+      n_gpu_layers,
+    );
+  }
+
+  late final _wire__crate__api__load_modelPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Pointer<ffi.Uint32>,
+            ffi.Pointer<ffi.Uint32>,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__load_model');
+  late final _wire__crate__api__load_model = _wire__crate__api__load_modelPtr
+      .asFunction<
+        void Function(
+          int,
+          ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+          ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+          ffi.Pointer<ffi.Uint32>,
+          ffi.Pointer<ffi.Uint32>,
+        )
+      >();
+
+  void wire__crate__api__load_quantized_index(
+    int port_,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> path,
+    ffi.Pointer<ffi.Uint64> store_id,
+  ) {
+    return _wire__crate__api__load_quantized_index(port_, path, store_id);
+  }
+
+  late final _wire__crate__api__load_quantized_indexPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Pointer<ffi.Uint64>,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__load_quantized_index');
+  late final _wire__crate__api__load_quantized_index =
+      _wire__crate__api__load_quantized_indexPtr
+          .asFunction<
+            void Function(
+              int,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              ffi.Pointer<ffi.Uint64>,
+            )
+          >();
+
+  WireSyncRust2DartDco wire__crate__api__mcp_classify_task(
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> message,
+  ) {
+    return _wire__crate__api__mcp_classify_task(message);
+  }
+
+  late final _wire__crate__api__mcp_classify_taskPtr =
+      _lookup<
+        ffi.NativeFunction<
+          WireSyncRust2DartDco Function(
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__mcp_classify_task');
+  late final _wire__crate__api__mcp_classify_task =
+      _wire__crate__api__mcp_classify_taskPtr
+          .asFunction<
+            WireSyncRust2DartDco Function(
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            )
+          >();
+
+  void wire__crate__api__mcp_classify_task_with_model(
+    int port_,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> message,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> model_id,
+    ffi.Pointer<ffi.Float> min_confidence,
+  ) {
+    return _wire__crate__api__mcp_classify_task_with_model(
+      port_,
+      message,
+      model_id,
+      min_confidence,
+    );
+  }
+
+  late final _wire__crate__api__mcp_classify_task_with_modelPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Pointer<ffi.Float>,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__mcp_classify_task_with_model');
+  late final _wire__crate__api__mcp_classify_task_with_model =
+      _wire__crate__api__mcp_classify_task_with_modelPtr
+          .asFunction<
+            void Function(
+              int,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              ffi.Pointer<ffi.Float>,
+            )
+          >();
+
+  void wire__crate__api__mcp_create_folder(
+    int port_,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> path,
+  ) {
+    return _wire__crate__api__mcp_create_folder(port_, path);
+  }
+
+  late final _wire__crate__api__mcp_create_folderPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__mcp_create_folder');
+  late final _wire__crate__api__mcp_create_folder =
+      _wire__crate__api__mcp_create_folderPtr
+          .asFunction<
+            void Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)
+          >();
+
+  void wire__crate__api__mcp_delete_file(
+    int port_,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> path,
+  ) {
+    return _wire__crate__api__mcp_delete_file(port_, path);
+  }
+
+  late final _wire__crate__api__mcp_delete_filePtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__mcp_delete_file');
+  late final _wire__crate__api__mcp_delete_file =
+      _wire__crate__api__mcp_delete_filePtr
+          .asFunction<
+            void Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)
+          >();
+
+  void wire__crate__api__mcp_execute_tool_call(
+    int port_,
+    ffi.Pointer<wire_cst_mcp_tool_call> tool_call,
+  ) {
+    return _wire__crate__api__mcp_execute_tool_call(port_, tool_call);
+  }
+
+  late final _wire__crate__api__mcp_execute_tool_callPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(ffi.Int64, ffi.Pointer<wire_cst_mcp_tool_call>)
+        >
+      >('frbgen_kivixa_wire__crate__api__mcp_execute_tool_call');
+  late final _wire__crate__api__mcp_execute_tool_call =
+      _wire__crate__api__mcp_execute_tool_callPtr
+          .asFunction<
+            void Function(int, ffi.Pointer<wire_cst_mcp_tool_call>)
+          >();
+
+  void wire__crate__api__mcp_generate_tool_call(
+    int port_,
+    ffi.Pointer<wire_cst_list_record_string_string> messages,
+    ffi.Pointer<ffi.Uint32> max_tokens,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> model_id,
+  ) {
+    return _wire__crate__api__mcp_generate_tool_call(
+      port_,
+      messages,
+      max_tokens,
+      model_id,
+    );
+  }
+
+  late final _wire__crate__api__mcp_generate_tool_callPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_record_string_string>,
+            ffi.Pointer<ffi.Uint32>,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__mcp_generate_tool_call');
+  late final _wire__crate__api__mcp_generate_tool_call =
+      _wire__crate__api__mcp_generate_tool_callPtr
+          .asFunction<
+            void Function(
+              int,
+              ffi.Pointer<wire_cst_list_record_string_string>,
+              ffi.Pointer<ffi.Uint32>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            )
+          >();
+
+  WireSyncRust2DartDco wire__crate__api__mcp_get_all_tools() {
+    return _wire__crate__api__mcp_get_all_tools();
+  }
+
+  late final _wire__crate__api__mcp_get_all_toolsPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>(
+        'frbgen_kivixa_wire__crate__api__mcp_get_all_tools',
+      );
+  late final _wire__crate__api__mcp_get_all_tools =
+      _wire__crate__api__mcp_get_all_toolsPtr
+          .asFunction<WireSyncRust2DartDco Function()>();
+
+  WireSyncRust2DartDco wire__crate__api__mcp_get_model_for_task(int category) {
+    return _wire__crate__api__mcp_get_model_for_task(category);
+  }
+
+  late final _wire__crate__api__mcp_get_model_for_taskPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Int32)>>(
+        'frbgen_kivixa_wire__crate__api__mcp_get_model_for_task',
+      );
+  late final _wire__crate__api__mcp_get_model_for_task =
+      _wire__crate__api__mcp_get_model_for_taskPtr
+          .asFunction<WireSyncRust2DartDco Function(int)>();
+
+  WireSyncRust2DartDco wire__crate__api__mcp_get_model_id_for_task(
+    int category,
+  ) {
+    return _wire__crate__api__mcp_get_model_id_for_task(category);
+  }
+
+  late final _wire__crate__api__mcp_get_model_id_for_taskPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Int32)>>(
+        'frbgen_kivixa_wire__crate__api__mcp_get_model_id_for_task',
+      );
+  late final _wire__crate__api__mcp_get_model_id_for_task =
+      _wire__crate__api__mcp_get_model_id_for_taskPtr
+          .asFunction<WireSyncRust2DartDco Function(int)>();
+
+  WireSyncRust2DartDco wire__crate__api__mcp_get_tool_description(int tool) {
+    return _wire__crate__api__mcp_get_tool_description(tool);
+  }
+
+  late final _wire__crate__api__mcp_get_tool_descriptionPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Int32)>>(
+        'frbgen_kivixa_wire__crate__api__mcp_get_tool_description',
+      );
+  late final _wire__crate__api__mcp_get_tool_description =
+      _wire__crate__api__mcp_get_tool_descriptionPtr
+          .asFunction<WireSyncRust2DartDco Function(int)>();
+
+  WireSyncRust2DartDco wire__crate__api__mcp_get_tool_name(int tool) {
+    return _wire__crate__api__mcp_get_tool_name(tool);
+  }
+
+  late final _wire__crate__api__mcp_get_tool_namePtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Int32)>>(
+        'frbgen_kivixa_wire__crate__api__mcp_get_tool_name',
+      );
+  late final _wire__crate__api__mcp_get_tool_name =
+      _wire__crate__api__mcp_get_tool_namePtr
+          .asFunction<WireSyncRust2DartDco Function(int)>();
+
+  WireSyncRust2DartDco wire__crate__api__mcp_get_tool_parameters(int tool) {
+    return _wire__crate__api__mcp_get_tool_parameters(tool);
+  }
+
+  late final _wire__crate__api__mcp_get_tool_parametersPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Int32)>>(
+        'frbgen_kivixa_wire__crate__api__mcp_get_tool_parameters',
+      );
+  late final _wire__crate__api__mcp_get_tool_parameters =
+      _wire__crate__api__mcp_get_tool_parametersPtr
+          .asFunction<WireSyncRust2DartDco Function(int)>();
+
+  WireSyncRust2DartDco wire__crate__api__mcp_get_tool_schemas() {
+    return _wire__crate__api__mcp_get_tool_schemas();
+  }
+
+  late final _wire__crate__api__mcp_get_tool_schemasPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>(
+        'frbgen_kivixa_wire__crate__api__mcp_get_tool_schemas',
+      );
+  late final _wire__crate__api__mcp_get_tool_schemas =
+      _wire__crate__api__mcp_get_tool_schemasPtr
+          .asFunction<WireSyncRust2DartDco Function()>();
+
+  void wire__crate__api__mcp_list_files(
+    int port_,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> path,
+  ) {
+    return _wire__crate__api__mcp_list_files(port_, path);
+  }
+
+  late final _wire__crate__api__mcp_list_filesPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__mcp_list_files');
+  late final _wire__crate__api__mcp_list_files =
+      _wire__crate__api__mcp_list_filesPtr
+          .asFunction<
+            void Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)
+          >();
+
+  void wire__crate__api__mcp_parse_tool_call(
+    int port_,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> json,
+  ) {
+    return _wire__crate__api__mcp_parse_tool_call(port_, json);
+  }
+
+  late final _wire__crate__api__mcp_parse_tool_callPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__mcp_parse_tool_call');
+  late final _wire__crate__api__mcp_parse_tool_call =
+      _wire__crate__api__mcp_parse_tool_callPtr
+          .asFunction<
+            void Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)
+          >();
+
+  WireSyncRust2DartDco wire__crate__api__mcp_parse_tool_calls(
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> reply,
+    ffi.Pointer<ffi.Int32> model_type,
+  ) {
+    return _wire__crate__api__mcp_parse_tool_calls(reply, model_type);
+  }
+
+  late final _wire__crate__api__mcp_parse_tool_callsPtr =
+      _lookup<
+        ffi.NativeFunction<
+          WireSyncRust2DartDco Function(
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Pointer<ffi.Int32>,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__mcp_parse_tool_calls');
+  late final _wire__crate__api__mcp_parse_tool_calls =
+      _wire__crate__api__mcp_parse_tool_callsPtr
+          .asFunction<
+            WireSyncRust2DartDco Function(
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              ffi.Pointer<ffi.Int32>,
+            )
+          >();
+
+  void wire__crate__api__mcp_read_file(
+    int port_,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> path,
+  ) {
+    return _wire__crate__api__mcp_read_file(port_, path);
+  }
+
+  late final _wire__crate__api__mcp_read_filePtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__mcp_read_file');
+  late final _wire__crate__api__mcp_read_file =
+      _wire__crate__api__mcp_read_filePtr
+          .asFunction<
+            void Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)
+          >();
+
+  void wire__crate__api__mcp_run_agent(
+    int port_,
+    ffi.Pointer<wire_cst_list_record_string_string> messages,
+    ffi.Pointer<ffi.Uint32> max_steps,
+    ffi.Pointer<ffi.Uint32> max_tokens,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> model_id,
+  ) {
+    return _wire__crate__api__mcp_run_agent(
+      port_,
+      messages,
+      max_steps,
+      max_tokens,
+      model_id,
+    );
+  }
+
+  late final _wire__crate__api__mcp_run_agentPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_record_string_string>,
+            ffi.Pointer<ffi.Uint32>,
+            ffi.Pointer<ffi.Uint32>,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__mcp_run_agent');
+  late final _wire__crate__api__mcp_run_agent =
+      _wire__crate__api__mcp_run_agentPtr
+          .asFunction<
+            void Function(
+              int,
+              ffi.Pointer<wire_cst_list_record_string_string>,
+              ffi.Pointer<ffi.Uint32>,
+              ffi.Pointer<ffi.Uint32>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            )
+          >();
+
+  void wire__crate__api__mcp_run_agent_stream(
+    int port_,
+    ffi.Pointer<wire_cst_list_record_string_string> messages,
+    ffi.Pointer<ffi.Uint32> max_steps,
+    ffi.Pointer<ffi.Uint32> max_tokens,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> model_id,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> sink,
+  ) {
+    return _wire__crate__api__mcp_run_agent_stream(
+      port_,
+      messages,
+      max_steps,
+      max_tokens,
+      model_id,
+      sink,
+    );
+  }
+
+  late final _wire__crate__api__mcp_run_agent_streamPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_record_string_string>,
+            ffi.Pointer<ffi.Uint32>,
+            ffi.Pointer<ffi.Uint32>,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__mcp_run_agent_stream');
+  late final _wire__crate__api__mcp_run_agent_stream =
+      _wire__crate__api__mcp_run_agent_streamPtr
+          .asFunction<
+            void Function(
+              int,
+              ffi.Pointer<wire_cst_list_record_string_string>,
+              ffi.Pointer<ffi.Uint32>,
+              ffi.Pointer<ffi.Uint32>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            )
+          >();
+
+  WireSyncRust2DartDco wire__crate__api__mcp_validate_path(
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> path,
+  ) {
+    return _wire__crate__api__mcp_validate_path(path);
+  }
+
+  late final _wire__crate__api__mcp_validate_pathPtr =
+      _lookup<
+        ffi.NativeFunction<
+          WireSyncRust2DartDco Function(
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__mcp_validate_path');
+  late final _wire__crate__api__mcp_validate_path =
+      _wire__crate__api__mcp_validate_pathPtr
+          .asFunction<
+            WireSyncRust2DartDco Function(
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            )
+          >();
+
+  void wire__crate__api__mcp_write_file(
+    int port_,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> path,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> content,
+  ) {
+    return _wire__crate__api__mcp_write_file(port_, path, content);
+  }
+
+  late final _wire__crate__api__mcp_write_filePtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__mcp_write_file');
+  late final _wire__crate__api__mcp_write_file =
+      _wire__crate__api__mcp_write_filePtr
+          .asFunction<
+            void Function(
+              int,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            )
+          >();
+
+  void wire__crate__api__measure_quantization_recall(
+    int port_,
+    ffi.Pointer<wire_cst_list_embedding_entry> entries,
+    ffi.Pointer<wire_cst_list_list_prim_f_32_strict> queries,
+    int kind,
+    int top_k,
+    int rescore_candidates,
+  ) {
+    return _wire__crate__api__measure_quantization_recall(
+      port_,
+      entries,
+      queries,
+      kind,
+      top_k,
+      rescore_candidates,
+    );
+  }
+
+  late final _wire__crate__api__measure_quantization_recallPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_embedding_entry>,
+            ffi.Pointer<wire_cst_list_list_prim_f_32_strict>,
+            ffi.Int32,
+            ffi.UintPtr,
+            ffi.UintPtr,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__measure_quantization_recall');
+  late final _wire__crate__api__measure_quantization_recall =
+      _wire__crate__api__measure_quantization_recallPtr
+          .asFunction<
+            void Function(
+              int,
+              ffi.Pointer<wire_cst_list_embedding_entry>,
+              ffi.Pointer<wire_cst_list_list_prim_f_32_strict>,
+              int,
+              int,
+              int,
+            )
+          >();
+
+  void wire__crate__api__open_embedding_cache(
+    int port_,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> path,
+  ) {
+    return _wire__crate__api__open_embedding_cache(port_, path);
+  }
+
+  late final _wire__crate__api__open_embedding_cachePtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__open_embedding_cache');
+  late final _wire__crate__api__open_embedding_cache =
+      _wire__crate__api__open_embedding_cachePtr
+          .asFunction<
+            void Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)
+          >();
+
+  void wire__crate__api__open_vector_store(
+    int port_,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> path,
+  ) {
+    return _wire__crate__api__open_vector_store(port_, path);
+  }
+
+  late final _wire__crate__api__open_vector_storePtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__open_vector_store');
+  late final _wire__crate__api__open_vector_store =
+      _wire__crate__api__open_vector_storePtr
+          .asFunction<
+            void Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)
+          >();
+
+  void wire__crate__api__pin_stream_node(
+    int port_,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> id,
+    bool pinned,
+  ) {
+    return _wire__crate__api__pin_stream_node(port_, id, pinned);
+  }
+
+  late final _wire__crate__api__pin_stream_nodePtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Bool,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__pin_stream_node');
+  late final _wire__crate__api__pin_stream_node =
+      _wire__crate__api__pin_stream_nodePtr
+          .asFunction<
+            void Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, bool)
+          >();
+
+  WireSyncRust2DartDco wire__crate__api__poll_generation(int request_id) {
+    return _wire__crate__api__poll_generation(request_id);
+  }
+
+  late final _wire__crate__api__poll_generationPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Uint64)>>(
+        'frbgen_kivixa_wire__crate__api__poll_generation',
+      );
+  late final _wire__crate__api__poll_generation =
+      _wire__crate__api__poll_generationPtr
+          .asFunction<WireSyncRust2DartDco Function(int)>();
+
+  void wire__crate__api__quantized_index_insert(
+    int port_,
+    int index_id,
+    ffi.Pointer<wire_cst_list_embedding_entry> entries,
+  ) {
+    return _wire__crate__api__quantized_index_insert(port_, index_id, entries);
+  }
+
+  late final _wire__crate__api__quantized_index_insertPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Uint64,
+            ffi.Pointer<wire_cst_list_embedding_entry>,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__quantized_index_insert');
+  late final _wire__crate__api__quantized_index_insert =
+      _wire__crate__api__quantized_index_insertPtr
+          .asFunction<
+            void Function(int, int, ffi.Pointer<wire_cst_list_embedding_entry>)
+          >();
+
+  void wire__crate__api__quantized_index_remove(
+    int port_,
+    int index_id,
+    ffi.Pointer<wire_cst_list_String> ids,
+  ) {
+    return _wire__crate__api__quantized_index_remove(port_, index_id, ids);
+  }
+
+  late final _wire__crate__api__quantized_index_removePtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Uint64,
+            ffi.Pointer<wire_cst_list_String>,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__quantized_index_remove');
+  late final _wire__crate__api__quantized_index_remove =
+      _wire__crate__api__quantized_index_removePtr
+          .asFunction<
+            void Function(int, int, ffi.Pointer<wire_cst_list_String>)
+          >();
+
+  void wire__crate__api__rag_answer(
+    int port_,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> question,
+    ffi.Pointer<wire_cst_list_embedding_entry> chunks,
+    ffi.Pointer<ffi.Uint32> top_k,
+    ffi.Pointer<ffi.Uint32> context_tokens,
+    ffi.Pointer<ffi.Uint32> max_tokens,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> model_id,
+  ) {
+    return _wire__crate__api__rag_answer(
+      port_,
+      question,
+      chunks,
+      top_k,
+      context_tokens,
+      max_tokens,
+      model_id,
+    );
+  }
+
+  late final _wire__crate__api__rag_answerPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Pointer<wire_cst_list_embedding_entry>,
+            ffi.Pointer<ffi.Uint32>,
+            ffi.Pointer<ffi.Uint32>,
+            ffi.Pointer<ffi.Uint32>,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__rag_answer');
+  late final _wire__crate__api__rag_answer = _wire__crate__api__rag_answerPtr
+      .asFunction<
+        void Function(
+          int,
+          ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+          ffi.Pointer<wire_cst_list_embedding_entry>,
+          ffi.Pointer<ffi.Uint32>,
+          ffi.Pointer<ffi.Uint32>,
+          ffi.Pointer<ffi.Uint32>,
+          ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+        )
+      >();
+
+  void wire__crate__api__remove_graph_node(
+    int port_,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> node_id,
+  ) {
+    return _wire__crate__api__remove_graph_node(port_, node_id);
+  }
+
+  late final _wire__crate__api__remove_graph_nodePtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__remove_graph_node');
+  late final _wire__crate__api__remove_graph_node =
+      _wire__crate__api__remove_graph_nodePtr
+          .asFunction<
+            void Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)
+          >();
+
+  void wire__crate__api__remove_stream_edge(
+    int port_,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> from_id,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> to_id,
+  ) {
+    return _wire__crate__api__remove_stream_edge(port_, from_id, to_id);
+  }
+
+  late final _wire__crate__api__remove_stream_edgePtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__remove_stream_edge');
+  late final _wire__crate__api__remove_stream_edge =
+      _wire__crate__api__remove_stream_edgePtr
+          .asFunction<
+            void Function(
+              int,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            )
+          >();
+
+  void wire__crate__api__remove_stream_node(
+    int port_,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> id,
+  ) {
+    return _wire__crate__api__remove_stream_node(port_, id);
+  }
+
+  late final _wire__crate__api__remove_stream_nodePtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__remove_stream_node');
+  late final _wire__crate__api__remove_stream_node =
+      _wire__crate__api__remove_stream_nodePtr
+          .asFunction<
+            void Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)
+          >();
+
+  void wire__crate__api__save_chat_session(
+    int port_,
+    int session_id,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> path,
+  ) {
+    return _wire__crate__api__save_chat_session(port_, session_id, path);
+  }
+
+  late final _wire__crate__api__save_chat_sessionPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Uint64,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__save_chat_session');
+  late final _wire__crate__api__save_chat_session =
+      _wire__crate__api__save_chat_sessionPtr
+          .asFunction<
+            void Function(int, int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)
+          >();
+
+  void wire__crate__api__save_hnsw_index(
+    int port_,
+    int index_id,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> path,
+  ) {
+    return _wire__crate__api__save_hnsw_index(port_, index_id, path);
+  }
+
+  late final _wire__crate__api__save_hnsw_indexPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Uint64,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__save_hnsw_index');
+  late final _wire__crate__api__save_hnsw_index =
+      _wire__crate__api__save_hnsw_indexPtr
+          .asFunction<
+            void Function(int, int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)
+          >();
+
+  void wire__crate__api__save_quantized_index(
+    int port_,
+    int index_id,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> path,
+  ) {
+    return _wire__crate__api__save_quantized_index(port_, index_id, path);
+  }
+
+  late final _wire__crate__api__save_quantized_indexPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Uint64,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__save_quantized_index');
+  late final _wire__crate__api__save_quantized_index =
+      _wire__crate__api__save_quantized_indexPtr
+          .asFunction<
+            void Function(int, int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)
+          >();
+
+  void wire__crate__api__semantic_search(
+    int port_,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> query_text,
+    ffi.Pointer<wire_cst_list_embedding_entry> entries,
+    int top_k,
+  ) {
+    return _wire__crate__api__semantic_search(
+      port_,
+      query_text,
+      entries,
+      top_k,
+    );
+  }
+
+  late final _wire__crate__api__semantic_searchPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Pointer<wire_cst_list_embedding_entry>,
+            ffi.UintPtr,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__semantic_search');
+  late final _wire__crate__api__semantic_search =
+      _wire__crate__api__semantic_searchPtr
+          .asFunction<
+            void Function(
+              int,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              ffi.Pointer<wire_cst_list_embedding_entry>,
+              int,
+            )
+          >();
+
+  void wire__crate__api__semantic_search_filtered(
+    int port_,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> query_text,
+    ffi.Pointer<wire_cst_list_embedding_entry> entries,
+    ffi.Pointer<wire_cst_search_options> options,
+  ) {
+    return _wire__crate__api__semantic_search_filtered(
+      port_,
+      query_text,
+      entries,
+      options,
+    );
+  }
+
+  late final _wire__crate__api__semantic_search_filteredPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Pointer<wire_cst_list_embedding_entry>,
+            ffi.Pointer<wire_cst_search_options>,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__semantic_search_filtered');
+  late final _wire__crate__api__semantic_search_filtered =
+      _wire__crate__api__semantic_search_filteredPtr
+          .asFunction<
+            void Function(
+              int,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              ffi.Pointer<wire_cst_list_embedding_entry>,
+              ffi.Pointer<wire_cst_search_options>,
+            )
+          >();
+
+  void wire__crate__api__semantic_search_in_index(
+    int port_,
+    int index_id,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> query_text,
+    int top_k,
+  ) {
+    return _wire__crate__api__semantic_search_in_index(
+      port_,
+      index_id,
+      query_text,
+      top_k,
+    );
+  }
+
+  late final _wire__crate__api__semantic_search_in_indexPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Uint64,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.UintPtr,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__semantic_search_in_index');
+  late final _wire__crate__api__semantic_search_in_index =
+      _wire__crate__api__semantic_search_in_indexPtr
+          .asFunction<
+            void Function(
+              int,
+              int,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              int,
+            )
+          >();
+
+  void wire__crate__api__semantic_search_in_index_filtered(
+    int port_,
+    int index_id,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> query_text,
+    ffi.Pointer<wire_cst_search_options> options,
+  ) {
+    return _wire__crate__api__semantic_search_in_index_filtered(
+      port_,
+      index_id,
+      query_text,
+      options,
+    );
+  }
+
+  late final _wire__crate__api__semantic_search_in_index_filteredPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Uint64,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Pointer<wire_cst_search_options>,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__semantic_search_in_index_filtered');
+  late final _wire__crate__api__semantic_search_in_index_filtered =
+      _wire__crate__api__semantic_search_in_index_filteredPtr
+          .asFunction<
+            void Function(
+              int,
+              int,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              ffi.Pointer<wire_cst_search_options>,
+            )
+          >();
+
+  void wire__crate__api__semantic_search_in_store(
+    int port_,
+    int store_id,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> query_text,
+    int top_k,
+  ) {
+    return _wire__crate__api__semantic_search_in_store(
+      port_,
+      store_id,
+      query_text,
+      top_k,
+    );
+  }
+
+  late final _wire__crate__api__semantic_search_in_storePtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Uint64,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.UintPtr,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__semantic_search_in_store');
+  late final _wire__crate__api__semantic_search_in_store =
+      _wire__crate__api__semantic_search_in_storePtr
+          .asFunction<
+            void Function(
+              int,
+              int,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              int,
+            )
+          >();
+
+  void wire__crate__api__semantic_search_in_store_filtered(
+    int port_,
+    int store_id,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> query_text,
+    ffi.Pointer<wire_cst_search_options> options,
+  ) {
+    return _wire__crate__api__semantic_search_in_store_filtered(
+      port_,
+      store_id,
+      query_text,
+      options,
+    );
+  }
+
+  late final _wire__crate__api__semantic_search_in_store_filteredPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Uint64,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Pointer<wire_cst_search_options>,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__semantic_search_in_store_filtered');
+  late final _wire__crate__api__semantic_search_in_store_filtered =
+      _wire__crate__api__semantic_search_in_store_filteredPtr
+          .asFunction<
+            void Function(
+              int,
+              int,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              ffi.Pointer<wire_cst_search_options>,
+            )
+          >();
+
+  WireSyncRust2DartDco wire__crate__api__set_default_model(
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> model_id,
+  ) {
+    return _wire__crate__api__set_default_model(model_id);
+  }
+
+  late final _wire__crate__api__set_default_modelPtr =
+      _lookup<
+        ffi.NativeFunction<
+          WireSyncRust2DartDco Function(
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__set_default_model');
+  late final _wire__crate__api__set_default_model =
+      _wire__crate__api__set_default_modelPtr
+          .asFunction<
+            WireSyncRust2DartDco Function(
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            )
+          >();
+
+  WireSyncRust2DartDco wire__crate__api__set_model_memory_budget(
+    ffi.Pointer<ffi.Uint64> budget_bytes,
+  ) {
+    return _wire__crate__api__set_model_memory_budget(budget_bytes);
+  }
+
+  late final _wire__crate__api__set_model_memory_budgetPtr =
+      _lookup<
+        ffi.NativeFunction<
+          WireSyncRust2DartDco Function(ffi.Pointer<ffi.Uint64>)
+        >
+      >('frbgen_kivixa_wire__crate__api__set_model_memory_budget');
+  late final _wire__crate__api__set_model_memory_budget =
+      _wire__crate__api__set_model_memory_budgetPtr
+          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<ffi.Uint64>)>();
+
+  void wire__crate__api__set_stream_node_position(
+    int port_,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> id,
+    double x,
+    double y,
+  ) {
+    return _wire__crate__api__set_stream_node_position(port_, id, x, y);
+  }
+
+  late final _wire__crate__api__set_stream_node_positionPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Float,
+            ffi.Float,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__set_stream_node_position');
+  late final _wire__crate__api__set_stream_node_position =
+      _wire__crate__api__set_stream_node_positionPtr
+          .asFunction<
+            void Function(
+              int,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              double,
+              double,
+            )
+          >();
+
+  void wire__crate__api__start_chat_completion(
+    int port_,
+    ffi.Pointer<wire_cst_list_record_string_string> messages,
+    ffi.Pointer<ffi.Uint32> max_tokens,
+    ffi.Pointer<wire_cst_list_String> stop_sequences,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> model_id,
+  ) {
+    return _wire__crate__api__start_chat_completion(
+      port_,
+      messages,
+      max_tokens,
+      stop_sequences,
+      model_id,
+    );
+  }
+
+  late final _wire__crate__api__start_chat_completionPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_record_string_string>,
+            ffi.Pointer<ffi.Uint32>,
+            ffi.Pointer<wire_cst_list_String>,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__start_chat_completion');
+  late final _wire__crate__api__start_chat_completion =
+      _wire__crate__api__start_chat_completionPtr
+          .asFunction<
+            void Function(
+              int,
+              ffi.Pointer<wire_cst_list_record_string_string>,
+              ffi.Pointer<ffi.Uint32>,
+              ffi.Pointer<wire_cst_list_String>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            )
+          >();
+
+  void wire__crate__api__start_generation(
+    int port_,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> prompt,
+    ffi.Pointer<ffi.Uint32> max_tokens,
+    ffi.Pointer<wire_cst_list_String> stop_sequences,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> model_id,
+  ) {
+    return _wire__crate__api__start_generation(
+      port_,
+      prompt,
+      max_tokens,
+      stop_sequences,
+      model_id,
+    );
+  }
+
+  late final _wire__crate__api__start_generationPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Pointer<ffi.Uint32>,
+            ffi.Pointer<wire_cst_list_String>,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__start_generation');
+  late final _wire__crate__api__start_generation =
+      _wire__crate__api__start_generationPtr
+          .asFunction<
+            void Function(
+              int,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              ffi.Pointer<ffi.Uint32>,
+              ffi.Pointer<wire_cst_list_String>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            )
+          >();
+
+  void wire__crate__api__start_graph_stream(int port_) {
+    return _wire__crate__api__start_graph_stream(port_);
+  }
+
+  late final _wire__crate__api__start_graph_streamPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>(
+        'frbgen_kivixa_wire__crate__api__start_graph_stream',
+      );
+  late final _wire__crate__api__start_graph_stream =
+      _wire__crate__api__start_graph_streamPtr.asFunction<void Function(int)>();
+
+  WireSyncRust2DartDco wire__crate__api__stop_graph_stream() {
+    return _wire__crate__api__stop_graph_stream();
+  }
+
+  late final _wire__crate__api__stop_graph_streamPtr =
       _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>(
-        'frbgen_kivixa_wire__crate__api__get_stream_graph_stats',
+        'frbgen_kivixa_wire__crate__api__stop_graph_stream',
+      );
+  late final _wire__crate__api__stop_graph_stream =
+      _wire__crate__api__stop_graph_streamPtr
+          .asFunction<WireSyncRust2DartDco Function()>();
+
+  void wire__crate__api__summarize_text(
+    int port_,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> text,
+    ffi.Pointer<wire_cst_summary_options> options,
+  ) {
+    return _wire__crate__api__summarize_text(port_, text, options);
+  }
+
+  late final _wire__crate__api__summarize_textPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Pointer<wire_cst_summary_options>,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__summarize_text');
+  late final _wire__crate__api__summarize_text =
+      _wire__crate__api__summarize_textPtr
+          .asFunction<
+            void Function(
+              int,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              ffi.Pointer<wire_cst_summary_options>,
+            )
+          >();
+
+  void wire__crate__api__summarize_transcript(
+    int port_,
+    ffi.Pointer<wire_cst_list_transcript_segment> segments,
+    ffi.Pointer<wire_cst_summary_options> options,
+  ) {
+    return _wire__crate__api__summarize_transcript(port_, segments, options);
+  }
+
+  late final _wire__crate__api__summarize_transcriptPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_transcript_segment>,
+            ffi.Pointer<wire_cst_summary_options>,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__summarize_transcript');
+  late final _wire__crate__api__summarize_transcript =
+      _wire__crate__api__summarize_transcriptPtr
+          .asFunction<
+            void Function(
+              int,
+              ffi.Pointer<wire_cst_list_transcript_segment>,
+              ffi.Pointer<wire_cst_summary_options>,
+            )
+          >();
+
+  void wire__crate__api__tokenize_text(
+    int port_,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> text,
+    bool add_bos,
+    bool parse_special,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> model_id,
+  ) {
+    return _wire__crate__api__tokenize_text(
+      port_,
+      text,
+      add_bos,
+      parse_special,
+      model_id,
+    );
+  }
+
+  late final _wire__crate__api__tokenize_textPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Bool,
+            ffi.Bool,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__tokenize_text');
+  late final _wire__crate__api__tokenize_text =
+      _wire__crate__api__tokenize_textPtr
+          .asFunction<
+            void Function(
+              int,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              bool,
+              bool,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            )
+          >();
+
+  WireSyncRust2DartDco wire__crate__api__unload_embedding_model() {
+    return _wire__crate__api__unload_embedding_model();
+  }
+
+  late final _wire__crate__api__unload_embedding_modelPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>(
+        'frbgen_kivixa_wire__crate__api__unload_embedding_model',
+      );
+  late final _wire__crate__api__unload_embedding_model =
+      _wire__crate__api__unload_embedding_modelPtr
+          .asFunction<WireSyncRust2DartDco Function()>();
+
+  WireSyncRust2DartDco wire__crate__api__unload_model() {
+    return _wire__crate__api__unload_model();
+  }
+
+  late final _wire__crate__api__unload_modelPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>(
+        'frbgen_kivixa_wire__crate__api__unload_model',
+      );
+  late final _wire__crate__api__unload_model =
+      _wire__crate__api__unload_modelPtr
+          .asFunction<WireSyncRust2DartDco Function()>();
+
+  WireSyncRust2DartDco wire__crate__api__unload_model_by_id(
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> model_id,
+  ) {
+    return _wire__crate__api__unload_model_by_id(model_id);
+  }
+
+  late final _wire__crate__api__unload_model_by_idPtr =
+      _lookup<
+        ffi.NativeFunction<
+          WireSyncRust2DartDco Function(
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__unload_model_by_id');
+  late final _wire__crate__api__unload_model_by_id =
+      _wire__crate__api__unload_model_by_idPtr
+          .asFunction<
+            WireSyncRust2DartDco Function(
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            )
+          >();
+
+  void wire__crate__api__update_graph_viewport(
+    int port_,
+    double x,
+    double y,
+    double width,
+    double height,
+    double scale,
+  ) {
+    return _wire__crate__api__update_graph_viewport(
+      port_,
+      x,
+      y,
+      width,
+      height,
+      scale,
+    );
+  }
+
+  late final _wire__crate__api__update_graph_viewportPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Float,
+            ffi.Float,
+            ffi.Float,
+            ffi.Float,
+            ffi.Float,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__update_graph_viewport');
+  late final _wire__crate__api__update_graph_viewport =
+      _wire__crate__api__update_graph_viewportPtr
+          .asFunction<
+            void Function(int, double, double, double, double, double)
+          >();
+
+  WireSyncRust2DartDco wire__crate__api__use_llama_inference_backend() {
+    return _wire__crate__api__use_llama_inference_backend();
+  }
+
+  late final _wire__crate__api__use_llama_inference_backendPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>(
+        'frbgen_kivixa_wire__crate__api__use_llama_inference_backend',
+      );
+  late final _wire__crate__api__use_llama_inference_backend =
+      _wire__crate__api__use_llama_inference_backendPtr
+          .asFunction<WireSyncRust2DartDco Function()>();
+
+  WireSyncRust2DartDco wire__crate__api__use_mock_inference_backend(
+    ffi.Pointer<wire_cst_list_String> completions,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> fallback,
+    int embedding_dimension,
+  ) {
+    return _wire__crate__api__use_mock_inference_backend(
+      completions,
+      fallback,
+      embedding_dimension,
+    );
+  }
+
+  late final _wire__crate__api__use_mock_inference_backendPtr =
+      _lookup<
+        ffi.NativeFunction<
+          WireSyncRust2DartDco Function(
+            ffi.Pointer<wire_cst_list_String>,
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            ffi.Uint32,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__use_mock_inference_backend');
+  late final _wire__crate__api__use_mock_inference_backend =
+      _wire__crate__api__use_mock_inference_backendPtr
+          .asFunction<
+            WireSyncRust2DartDco Function(
+              ffi.Pointer<wire_cst_list_String>,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              int,
+            )
+          >();
+
+  WireSyncRust2DartDco wire__crate__api__validate_search_filter(
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> filter,
+  ) {
+    return _wire__crate__api__validate_search_filter(filter);
+  }
+
+  late final _wire__crate__api__validate_search_filterPtr =
+      _lookup<
+        ffi.NativeFunction<
+          WireSyncRust2DartDco Function(
+            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__validate_search_filter');
+  late final _wire__crate__api__validate_search_filter =
+      _wire__crate__api__validate_search_filterPtr
+          .asFunction<
+            WireSyncRust2DartDco Function(
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+            )
+          >();
+
+  void wire__crate__api__vector_store_delete(
+    int port_,
+    int store_id,
+    ffi.Pointer<wire_cst_list_String> ids,
+  ) {
+    return _wire__crate__api__vector_store_delete(port_, store_id, ids);
+  }
+
+  late final _wire__crate__api__vector_store_deletePtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Uint64,
+            ffi.Pointer<wire_cst_list_String>,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__vector_store_delete');
+  late final _wire__crate__api__vector_store_delete =
+      _wire__crate__api__vector_store_deletePtr
+          .asFunction<
+            void Function(int, int, ffi.Pointer<wire_cst_list_String>)
+          >();
+
+  void wire__crate__api__vector_store_get(
+    int port_,
+    int store_id,
+    ffi.Pointer<wire_cst_list_String> ids,
+  ) {
+    return _wire__crate__api__vector_store_get(port_, store_id, ids);
+  }
+
+  late final _wire__crate__api__vector_store_getPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Uint64,
+            ffi.Pointer<wire_cst_list_String>,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__vector_store_get');
+  late final _wire__crate__api__vector_store_get =
+      _wire__crate__api__vector_store_getPtr
+          .asFunction<
+            void Function(int, int, ffi.Pointer<wire_cst_list_String>)
+          >();
+
+  WireSyncRust2DartDco wire__crate__api__vector_store_len(int store_id) {
+    return _wire__crate__api__vector_store_len(store_id);
+  }
+
+  late final _wire__crate__api__vector_store_lenPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Uint64)>>(
+        'frbgen_kivixa_wire__crate__api__vector_store_len',
+      );
+  late final _wire__crate__api__vector_store_len =
+      _wire__crate__api__vector_store_lenPtr
+          .asFunction<WireSyncRust2DartDco Function(int)>();
+
+  void wire__crate__api__vector_store_upsert(
+    int port_,
+    int store_id,
+    ffi.Pointer<wire_cst_list_embedding_entry> entries,
+  ) {
+    return _wire__crate__api__vector_store_upsert(port_, store_id, entries);
+  }
+
+  late final _wire__crate__api__vector_store_upsertPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Int64,
+            ffi.Uint64,
+            ffi.Pointer<wire_cst_list_embedding_entry>,
+          )
+        >
+      >('frbgen_kivixa_wire__crate__api__vector_store_upsert');
+  late final _wire__crate__api__vector_store_upsert =
+      _wire__crate__api__vector_store_upsertPtr
+          .asFunction<
+            void Function(int, int, ffi.Pointer<wire_cst_list_embedding_entry>)
+          >();
+
+  void wire__crate__api__wait_for_generation(int port_, int request_id) {
+    return _wire__crate__api__wait_for_generation(port_, request_id);
+  }
+
+  late final _wire__crate__api__wait_for_generationPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Uint64)>>(
+        'frbgen_kivixa_wire__crate__api__wait_for_generation',
+      );
+  late final _wire__crate__api__wait_for_generation =
+      _wire__crate__api__wait_for_generationPtr
+          .asFunction<void Function(int, int)>();
+
+  ffi.Pointer<wire_cst_context_policy> cst_new_box_autoadd_context_policy() {
+    return _cst_new_box_autoadd_context_policy();
+  }
+
+  late final _cst_new_box_autoadd_context_policyPtr =
+      _lookup<
+        ffi.NativeFunction<ffi.Pointer<wire_cst_context_policy> Function()>
+      >('frbgen_kivixa_cst_new_box_autoadd_context_policy');
+  late final _cst_new_box_autoadd_context_policy =
+      _cst_new_box_autoadd_context_policyPtr
+          .asFunction<ffi.Pointer<wire_cst_context_policy> Function()>();
+
+  ffi.Pointer<wire_cst_context_report> cst_new_box_autoadd_context_report() {
+    return _cst_new_box_autoadd_context_report();
+  }
+
+  late final _cst_new_box_autoadd_context_reportPtr =
+      _lookup<
+        ffi.NativeFunction<ffi.Pointer<wire_cst_context_report> Function()>
+      >('frbgen_kivixa_cst_new_box_autoadd_context_report');
+  late final _cst_new_box_autoadd_context_report =
+      _cst_new_box_autoadd_context_reportPtr
+          .asFunction<ffi.Pointer<wire_cst_context_report> Function()>();
+
+  ffi.Pointer<wire_cst_dedup_options> cst_new_box_autoadd_dedup_options() {
+    return _cst_new_box_autoadd_dedup_options();
+  }
+
+  late final _cst_new_box_autoadd_dedup_optionsPtr =
+      _lookup<
+        ffi.NativeFunction<ffi.Pointer<wire_cst_dedup_options> Function()>
+      >('frbgen_kivixa_cst_new_box_autoadd_dedup_options');
+  late final _cst_new_box_autoadd_dedup_options =
+      _cst_new_box_autoadd_dedup_optionsPtr
+          .asFunction<ffi.Pointer<wire_cst_dedup_options> Function()>();
+
+  ffi.Pointer<wire_cst_embedding_cache_stats>
+  cst_new_box_autoadd_embedding_cache_stats() {
+    return _cst_new_box_autoadd_embedding_cache_stats();
+  }
+
+  late final _cst_new_box_autoadd_embedding_cache_statsPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Pointer<wire_cst_embedding_cache_stats> Function()
+        >
+      >('frbgen_kivixa_cst_new_box_autoadd_embedding_cache_stats');
+  late final _cst_new_box_autoadd_embedding_cache_stats =
+      _cst_new_box_autoadd_embedding_cache_statsPtr
+          .asFunction<ffi.Pointer<wire_cst_embedding_cache_stats> Function()>();
+
+  ffi.Pointer<wire_cst_embedding_config>
+  cst_new_box_autoadd_embedding_config() {
+    return _cst_new_box_autoadd_embedding_config();
+  }
+
+  late final _cst_new_box_autoadd_embedding_configPtr =
+      _lookup<
+        ffi.NativeFunction<ffi.Pointer<wire_cst_embedding_config> Function()>
+      >('frbgen_kivixa_cst_new_box_autoadd_embedding_config');
+  late final _cst_new_box_autoadd_embedding_config =
+      _cst_new_box_autoadd_embedding_configPtr
+          .asFunction<ffi.Pointer<wire_cst_embedding_config> Function()>();
+
+  ffi.Pointer<ffi.Float> cst_new_box_autoadd_f_32(double value) {
+    return _cst_new_box_autoadd_f_32(value);
+  }
+
+  late final _cst_new_box_autoadd_f_32Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Float> Function(ffi.Float)>>(
+        'frbgen_kivixa_cst_new_box_autoadd_f_32',
       );
-  late final _wire__crate__api__get_stream_graph_stats =
-      _wire__crate__api__get_stream_graph_statsPtr
-          .asFunction<WireSyncRust2DartDco Function()>();
+  late final _cst_new_box_autoadd_f_32 = _cst_new_box_autoadd_f_32Ptr
+      .asFunction<ffi.Pointer<ffi.Float> Function(double)>();
+
+  ffi.Pointer<wire_cst_generation_options>
+  cst_new_box_autoadd_generation_options() {
+    return _cst_new_box_autoadd_generation_options();
+  }
+
+  late final _cst_new_box_autoadd_generation_optionsPtr =
+      _lookup<
+        ffi.NativeFunction<ffi.Pointer<wire_cst_generation_options> Function()>
+      >('frbgen_kivixa_cst_new_box_autoadd_generation_options');
+  late final _cst_new_box_autoadd_generation_options =
+      _cst_new_box_autoadd_generation_optionsPtr
+          .asFunction<ffi.Pointer<wire_cst_generation_options> Function()>();
+
+  ffi.Pointer<wire_cst_generation_result>
+  cst_new_box_autoadd_generation_result() {
+    return _cst_new_box_autoadd_generation_result();
+  }
+
+  late final _cst_new_box_autoadd_generation_resultPtr =
+      _lookup<
+        ffi.NativeFunction<ffi.Pointer<wire_cst_generation_result> Function()>
+      >('frbgen_kivixa_cst_new_box_autoadd_generation_result');
+  late final _cst_new_box_autoadd_generation_result =
+      _cst_new_box_autoadd_generation_resultPtr
+          .asFunction<ffi.Pointer<wire_cst_generation_result> Function()>();
+
+  ffi.Pointer<wire_cst_hnsw_config> cst_new_box_autoadd_hnsw_config() {
+    return _cst_new_box_autoadd_hnsw_config();
+  }
+
+  late final _cst_new_box_autoadd_hnsw_configPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_hnsw_config> Function()>>(
+        'frbgen_kivixa_cst_new_box_autoadd_hnsw_config',
+      );
+  late final _cst_new_box_autoadd_hnsw_config =
+      _cst_new_box_autoadd_hnsw_configPtr
+          .asFunction<ffi.Pointer<wire_cst_hnsw_config> Function()>();
+
+  ffi.Pointer<wire_cst_hybrid_options> cst_new_box_autoadd_hybrid_options() {
+    return _cst_new_box_autoadd_hybrid_options();
+  }
+
+  late final _cst_new_box_autoadd_hybrid_optionsPtr =
+      _lookup<
+        ffi.NativeFunction<ffi.Pointer<wire_cst_hybrid_options> Function()>
+      >('frbgen_kivixa_cst_new_box_autoadd_hybrid_options');
+  late final _cst_new_box_autoadd_hybrid_options =
+      _cst_new_box_autoadd_hybrid_optionsPtr
+          .asFunction<ffi.Pointer<wire_cst_hybrid_options> Function()>();
+
+  ffi.Pointer<ffi.Int32> cst_new_box_autoadd_i_32(int value) {
+    return _cst_new_box_autoadd_i_32(value);
+  }
+
+  late final _cst_new_box_autoadd_i_32Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Int32> Function(ffi.Int32)>>(
+        'frbgen_kivixa_cst_new_box_autoadd_i_32',
+      );
+  late final _cst_new_box_autoadd_i_32 = _cst_new_box_autoadd_i_32Ptr
+      .asFunction<ffi.Pointer<ffi.Int32> Function(int)>();
+
+  ffi.Pointer<ffi.Int64> cst_new_box_autoadd_i_64(int value) {
+    return _cst_new_box_autoadd_i_64(value);
+  }
+
+  late final _cst_new_box_autoadd_i_64Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Int64> Function(ffi.Int64)>>(
+        'frbgen_kivixa_cst_new_box_autoadd_i_64',
+      );
+  late final _cst_new_box_autoadd_i_64 = _cst_new_box_autoadd_i_64Ptr
+      .asFunction<ffi.Pointer<ffi.Int64> Function(int)>();
+
+  ffi.Pointer<wire_cst_mcp_tool_call> cst_new_box_autoadd_mcp_tool_call() {
+    return _cst_new_box_autoadd_mcp_tool_call();
+  }
+
+  late final _cst_new_box_autoadd_mcp_tool_callPtr =
+      _lookup<
+        ffi.NativeFunction<ffi.Pointer<wire_cst_mcp_tool_call> Function()>
+      >('frbgen_kivixa_cst_new_box_autoadd_mcp_tool_call');
+  late final _cst_new_box_autoadd_mcp_tool_call =
+      _cst_new_box_autoadd_mcp_tool_callPtr
+          .asFunction<ffi.Pointer<wire_cst_mcp_tool_call> Function()>();
+
+  ffi.Pointer<wire_cst_note_metadata> cst_new_box_autoadd_note_metadata() {
+    return _cst_new_box_autoadd_note_metadata();
+  }
+
+  late final _cst_new_box_autoadd_note_metadataPtr =
+      _lookup<
+        ffi.NativeFunction<ffi.Pointer<wire_cst_note_metadata> Function()>
+      >('frbgen_kivixa_cst_new_box_autoadd_note_metadata');
+  late final _cst_new_box_autoadd_note_metadata =
+      _cst_new_box_autoadd_note_metadataPtr
+          .asFunction<ffi.Pointer<wire_cst_note_metadata> Function()>();
+
+  ffi.Pointer<wire_cst_reasoning_options>
+  cst_new_box_autoadd_reasoning_options() {
+    return _cst_new_box_autoadd_reasoning_options();
+  }
+
+  late final _cst_new_box_autoadd_reasoning_optionsPtr =
+      _lookup<
+        ffi.NativeFunction<ffi.Pointer<wire_cst_reasoning_options> Function()>
+      >('frbgen_kivixa_cst_new_box_autoadd_reasoning_options');
+  late final _cst_new_box_autoadd_reasoning_options =
+      _cst_new_box_autoadd_reasoning_optionsPtr
+          .asFunction<ffi.Pointer<wire_cst_reasoning_options> Function()>();
+
+  ffi.Pointer<wire_cst_sampler_config> cst_new_box_autoadd_sampler_config() {
+    return _cst_new_box_autoadd_sampler_config();
+  }
+
+  late final _cst_new_box_autoadd_sampler_configPtr =
+      _lookup<
+        ffi.NativeFunction<ffi.Pointer<wire_cst_sampler_config> Function()>
+      >('frbgen_kivixa_cst_new_box_autoadd_sampler_config');
+  late final _cst_new_box_autoadd_sampler_config =
+      _cst_new_box_autoadd_sampler_configPtr
+          .asFunction<ffi.Pointer<wire_cst_sampler_config> Function()>();
+
+  ffi.Pointer<wire_cst_search_options> cst_new_box_autoadd_search_options() {
+    return _cst_new_box_autoadd_search_options();
+  }
+
+  late final _cst_new_box_autoadd_search_optionsPtr =
+      _lookup<
+        ffi.NativeFunction<ffi.Pointer<wire_cst_search_options> Function()>
+      >('frbgen_kivixa_cst_new_box_autoadd_search_options');
+  late final _cst_new_box_autoadd_search_options =
+      _cst_new_box_autoadd_search_optionsPtr
+          .asFunction<ffi.Pointer<wire_cst_search_options> Function()>();
+
+  ffi.Pointer<ffi.Int32> cst_new_box_autoadd_stop_reason(int value) {
+    return _cst_new_box_autoadd_stop_reason(value);
+  }
+
+  late final _cst_new_box_autoadd_stop_reasonPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Int32> Function(ffi.Int32)>>(
+        'frbgen_kivixa_cst_new_box_autoadd_stop_reason',
+      );
+  late final _cst_new_box_autoadd_stop_reason =
+      _cst_new_box_autoadd_stop_reasonPtr
+          .asFunction<ffi.Pointer<ffi.Int32> Function(int)>();
+
+  ffi.Pointer<wire_cst_summary_options> cst_new_box_autoadd_summary_options() {
+    return _cst_new_box_autoadd_summary_options();
+  }
+
+  late final _cst_new_box_autoadd_summary_optionsPtr =
+      _lookup<
+        ffi.NativeFunction<ffi.Pointer<wire_cst_summary_options> Function()>
+      >('frbgen_kivixa_cst_new_box_autoadd_summary_options');
+  late final _cst_new_box_autoadd_summary_options =
+      _cst_new_box_autoadd_summary_optionsPtr
+          .asFunction<ffi.Pointer<wire_cst_summary_options> Function()>();
+
+  ffi.Pointer<ffi.Uint32> cst_new_box_autoadd_u_32(int value) {
+    return _cst_new_box_autoadd_u_32(value);
+  }
+
+  late final _cst_new_box_autoadd_u_32Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Uint32> Function(ffi.Uint32)>>(
+        'frbgen_kivixa_cst_new_box_autoadd_u_32',
+      );
+  late final _cst_new_box_autoadd_u_32 = _cst_new_box_autoadd_u_32Ptr
+      .asFunction<ffi.Pointer<ffi.Uint32> Function(int)>();
+
+  ffi.Pointer<ffi.Uint64> cst_new_box_autoadd_u_64(int value) {
+    return _cst_new_box_autoadd_u_64(value);
+  }
+
+  late final _cst_new_box_autoadd_u_64Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Uint64> Function(ffi.Uint64)>>(
+        'frbgen_kivixa_cst_new_box_autoadd_u_64',
+      );
+  late final _cst_new_box_autoadd_u_64 = _cst_new_box_autoadd_u_64Ptr
+      .asFunction<ffi.Pointer<ffi.Uint64> Function(int)>();
+
+  ffi.Pointer<ffi.UintPtr> cst_new_box_autoadd_usize(int value) {
+    return _cst_new_box_autoadd_usize(value);
+  }
+
+  late final _cst_new_box_autoadd_usizePtr =
+      _lookup<
+        ffi.NativeFunction<ffi.Pointer<ffi.UintPtr> Function(ffi.UintPtr)>
+      >('frbgen_kivixa_cst_new_box_autoadd_usize');
+  late final _cst_new_box_autoadd_usize = _cst_new_box_autoadd_usizePtr
+      .asFunction<ffi.Pointer<ffi.UintPtr> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_String> cst_new_list_String(int len) {
+    return _cst_new_list_String(len);
+  }
+
+  late final _cst_new_list_StringPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Pointer<wire_cst_list_String> Function(ffi.Int32)
+        >
+      >('frbgen_kivixa_cst_new_list_String');
+  late final _cst_new_list_String = _cst_new_list_StringPtr
+      .asFunction<ffi.Pointer<wire_cst_list_String> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_agent_step> cst_new_list_agent_step(int len) {
+    return _cst_new_list_agent_step(len);
+  }
+
+  late final _cst_new_list_agent_stepPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Pointer<wire_cst_list_agent_step> Function(ffi.Int32)
+        >
+      >('frbgen_kivixa_cst_new_list_agent_step');
+  late final _cst_new_list_agent_step = _cst_new_list_agent_stepPtr
+      .asFunction<ffi.Pointer<wire_cst_list_agent_step> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_cluster_assignment> cst_new_list_cluster_assignment(
+    int len,
+  ) {
+    return _cst_new_list_cluster_assignment(len);
+  }
+
+  late final _cst_new_list_cluster_assignmentPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Pointer<wire_cst_list_cluster_assignment> Function(ffi.Int32)
+        >
+      >('frbgen_kivixa_cst_new_list_cluster_assignment');
+  late final _cst_new_list_cluster_assignment =
+      _cst_new_list_cluster_assignmentPtr
+          .asFunction<
+            ffi.Pointer<wire_cst_list_cluster_assignment> Function(int)
+          >();
+
+  ffi.Pointer<wire_cst_list_cluster_info> cst_new_list_cluster_info(int len) {
+    return _cst_new_list_cluster_info(len);
+  }
+
+  late final _cst_new_list_cluster_infoPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Pointer<wire_cst_list_cluster_info> Function(ffi.Int32)
+        >
+      >('frbgen_kivixa_cst_new_list_cluster_info');
+  late final _cst_new_list_cluster_info = _cst_new_list_cluster_infoPtr
+      .asFunction<ffi.Pointer<wire_cst_list_cluster_info> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_dedup_document> cst_new_list_dedup_document(
+    int len,
+  ) {
+    return _cst_new_list_dedup_document(len);
+  }
+
+  late final _cst_new_list_dedup_documentPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Pointer<wire_cst_list_dedup_document> Function(ffi.Int32)
+        >
+      >('frbgen_kivixa_cst_new_list_dedup_document');
+  late final _cst_new_list_dedup_document = _cst_new_list_dedup_documentPtr
+      .asFunction<ffi.Pointer<wire_cst_list_dedup_document> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_duplicate_group> cst_new_list_duplicate_group(
+    int len,
+  ) {
+    return _cst_new_list_duplicate_group(len);
+  }
+
+  late final _cst_new_list_duplicate_groupPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Pointer<wire_cst_list_duplicate_group> Function(ffi.Int32)
+        >
+      >('frbgen_kivixa_cst_new_list_duplicate_group');
+  late final _cst_new_list_duplicate_group = _cst_new_list_duplicate_groupPtr
+      .asFunction<ffi.Pointer<wire_cst_list_duplicate_group> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_duplicate_pair> cst_new_list_duplicate_pair(
+    int len,
+  ) {
+    return _cst_new_list_duplicate_pair(len);
+  }
+
+  late final _cst_new_list_duplicate_pairPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Pointer<wire_cst_list_duplicate_pair> Function(ffi.Int32)
+        >
+      >('frbgen_kivixa_cst_new_list_duplicate_pair');
+  late final _cst_new_list_duplicate_pair = _cst_new_list_duplicate_pairPtr
+      .asFunction<ffi.Pointer<wire_cst_list_duplicate_pair> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_duplicate_span> cst_new_list_duplicate_span(
+    int len,
+  ) {
+    return _cst_new_list_duplicate_span(len);
+  }
+
+  late final _cst_new_list_duplicate_spanPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Pointer<wire_cst_list_duplicate_span> Function(ffi.Int32)
+        >
+      >('frbgen_kivixa_cst_new_list_duplicate_span');
+  late final _cst_new_list_duplicate_span = _cst_new_list_duplicate_spanPtr
+      .asFunction<ffi.Pointer<wire_cst_list_duplicate_span> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_embedding_cluster> cst_new_list_embedding_cluster(
+    int len,
+  ) {
+    return _cst_new_list_embedding_cluster(len);
+  }
+
+  late final _cst_new_list_embedding_clusterPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Pointer<wire_cst_list_embedding_cluster> Function(ffi.Int32)
+        >
+      >('frbgen_kivixa_cst_new_list_embedding_cluster');
+  late final _cst_new_list_embedding_cluster =
+      _cst_new_list_embedding_clusterPtr
+          .asFunction<
+            ffi.Pointer<wire_cst_list_embedding_cluster> Function(int)
+          >();
+
+  ffi.Pointer<wire_cst_list_embedding_entry> cst_new_list_embedding_entry(
+    int len,
+  ) {
+    return _cst_new_list_embedding_entry(len);
+  }
+
+  late final _cst_new_list_embedding_entryPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Pointer<wire_cst_list_embedding_entry> Function(ffi.Int32)
+        >
+      >('frbgen_kivixa_cst_new_list_embedding_entry');
+  late final _cst_new_list_embedding_entry = _cst_new_list_embedding_entryPtr
+      .asFunction<ffi.Pointer<wire_cst_list_embedding_entry> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_graph_edge> cst_new_list_graph_edge(int len) {
+    return _cst_new_list_graph_edge(len);
+  }
+
+  late final _cst_new_list_graph_edgePtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Pointer<wire_cst_list_graph_edge> Function(ffi.Int32)
+        >
+      >('frbgen_kivixa_cst_new_list_graph_edge');
+  late final _cst_new_list_graph_edge = _cst_new_list_graph_edgePtr
+      .asFunction<ffi.Pointer<wire_cst_list_graph_edge> Function(int)>();
 
-  WireSyncRust2DartDco wire__crate__api__get_version() {
-    return _wire__crate__api__get_version();
+  ffi.Pointer<wire_cst_list_graph_node> cst_new_list_graph_node(int len) {
+    return _cst_new_list_graph_node(len);
   }
 
-  late final _wire__crate__api__get_versionPtr =
-      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>(
-        'frbgen_kivixa_wire__crate__api__get_version',
-      );
-  late final _wire__crate__api__get_version = _wire__crate__api__get_versionPtr
-      .asFunction<WireSyncRust2DartDco Function()>();
+  late final _cst_new_list_graph_nodePtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Pointer<wire_cst_list_graph_node> Function(ffi.Int32)
+        >
+      >('frbgen_kivixa_cst_new_list_graph_node');
+  late final _cst_new_list_graph_node = _cst_new_list_graph_nodePtr
+      .asFunction<ffi.Pointer<wire_cst_list_graph_node> Function(int)>();
 
-  void wire__crate__api__get_visible_graph_nodes(int port_) {
-    return _wire__crate__api__get_visible_graph_nodes(port_);
+  ffi.Pointer<wire_cst_list_hybrid_hit> cst_new_list_hybrid_hit(int len) {
+    return _cst_new_list_hybrid_hit(len);
   }
 
-  late final _wire__crate__api__get_visible_graph_nodesPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>(
-        'frbgen_kivixa_wire__crate__api__get_visible_graph_nodes',
-      );
-  late final _wire__crate__api__get_visible_graph_nodes =
-      _wire__crate__api__get_visible_graph_nodesPtr
-          .asFunction<void Function(int)>();
+  late final _cst_new_list_hybrid_hitPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Pointer<wire_cst_list_hybrid_hit> Function(ffi.Int32)
+        >
+      >('frbgen_kivixa_cst_new_list_hybrid_hit');
+  late final _cst_new_list_hybrid_hit = _cst_new_list_hybrid_hitPtr
+      .asFunction<ffi.Pointer<wire_cst_list_hybrid_hit> Function(int)>();
 
-  WireSyncRust2DartDco wire__crate__api__health_check() {
-    return _wire__crate__api__health_check();
+  ffi.Pointer<wire_cst_list_list_prim_f_32_strict>
+  cst_new_list_list_prim_f_32_strict(int len) {
+    return _cst_new_list_list_prim_f_32_strict(len);
   }
 
-  late final _wire__crate__api__health_checkPtr =
-      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>(
-        'frbgen_kivixa_wire__crate__api__health_check',
-      );
-  late final _wire__crate__api__health_check =
-      _wire__crate__api__health_checkPtr
-          .asFunction<WireSyncRust2DartDco Function()>();
+  late final _cst_new_list_list_prim_f_32_strictPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Pointer<wire_cst_list_list_prim_f_32_strict> Function(ffi.Int32)
+        >
+      >('frbgen_kivixa_cst_new_list_list_prim_f_32_strict');
+  late final _cst_new_list_list_prim_f_32_strict =
+      _cst_new_list_list_prim_f_32_strictPtr
+          .asFunction<
+            ffi.Pointer<wire_cst_list_list_prim_f_32_strict> Function(int)
+          >();
 
-  WireSyncRust2DartDco wire__crate__api__init_graph() {
-    return _wire__crate__api__init_graph();
+  ffi.Pointer<wire_cst_list_loaded_model_info> cst_new_list_loaded_model_info(
+    int len,
+  ) {
+    return _cst_new_list_loaded_model_info(len);
   }
 
-  late final _wire__crate__api__init_graphPtr =
-      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>(
-        'frbgen_kivixa_wire__crate__api__init_graph',
-      );
-  late final _wire__crate__api__init_graph = _wire__crate__api__init_graphPtr
-      .asFunction<WireSyncRust2DartDco Function()>();
+  late final _cst_new_list_loaded_model_infoPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Pointer<wire_cst_list_loaded_model_info> Function(ffi.Int32)
+        >
+      >('frbgen_kivixa_cst_new_list_loaded_model_info');
+  late final _cst_new_list_loaded_model_info =
+      _cst_new_list_loaded_model_infoPtr
+          .asFunction<
+            ffi.Pointer<wire_cst_list_loaded_model_info> Function(int)
+          >();
 
-  void wire__crate__api__init_mcp(
-    int port_,
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> base_path,
-    ffi.Pointer<ffi.UintPtr> max_file_size,
-    ffi.Pointer<wire_cst_list_String> allowed_extensions,
+  ffi.Pointer<wire_cst_list_markdown_chunk> cst_new_list_markdown_chunk(
+    int len,
   ) {
-    return _wire__crate__api__init_mcp(
-      port_,
-      base_path,
-      max_file_size,
-      allowed_extensions,
-    );
+    return _cst_new_list_markdown_chunk(len);
   }
 
-  late final _wire__crate__api__init_mcpPtr =
+  late final _cst_new_list_markdown_chunkPtr =
       _lookup<
         ffi.NativeFunction<
-          ffi.Void Function(
-            ffi.Int64,
-            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-            ffi.Pointer<ffi.UintPtr>,
-            ffi.Pointer<wire_cst_list_String>,
-          )
+          ffi.Pointer<wire_cst_list_markdown_chunk> Function(ffi.Int32)
         >
-      >('frbgen_kivixa_wire__crate__api__init_mcp');
-  late final _wire__crate__api__init_mcp = _wire__crate__api__init_mcpPtr
-      .asFunction<
-        void Function(
-          int,
-          ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-          ffi.Pointer<ffi.UintPtr>,
-          ffi.Pointer<wire_cst_list_String>,
-        )
-      >();
+      >('frbgen_kivixa_cst_new_list_markdown_chunk');
+  late final _cst_new_list_markdown_chunk = _cst_new_list_markdown_chunkPtr
+      .asFunction<ffi.Pointer<wire_cst_list_markdown_chunk> Function(int)>();
 
-  WireSyncRust2DartDco wire__crate__api__init_model(
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> model_path,
+  ffi.Pointer<wire_cst_list_mcp_parameter> cst_new_list_mcp_parameter(int len) {
+    return _cst_new_list_mcp_parameter(len);
+  }
+
+  late final _cst_new_list_mcp_parameterPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Pointer<wire_cst_list_mcp_parameter> Function(ffi.Int32)
+        >
+      >('frbgen_kivixa_cst_new_list_mcp_parameter');
+  late final _cst_new_list_mcp_parameter = _cst_new_list_mcp_parameterPtr
+      .asFunction<ffi.Pointer<wire_cst_list_mcp_parameter> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_mcp_tool> cst_new_list_mcp_tool(int len) {
+    return _cst_new_list_mcp_tool(len);
+  }
+
+  late final _cst_new_list_mcp_toolPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Pointer<wire_cst_list_mcp_tool> Function(ffi.Int32)
+        >
+      >('frbgen_kivixa_cst_new_list_mcp_tool');
+  late final _cst_new_list_mcp_tool = _cst_new_list_mcp_toolPtr
+      .asFunction<ffi.Pointer<wire_cst_list_mcp_tool> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_mcp_tool_call> cst_new_list_mcp_tool_call(int len) {
+    return _cst_new_list_mcp_tool_call(len);
+  }
+
+  late final _cst_new_list_mcp_tool_callPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Pointer<wire_cst_list_mcp_tool_call> Function(ffi.Int32)
+        >
+      >('frbgen_kivixa_cst_new_list_mcp_tool_call');
+  late final _cst_new_list_mcp_tool_call = _cst_new_list_mcp_tool_callPtr
+      .asFunction<ffi.Pointer<wire_cst_list_mcp_tool_call> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_mcp_tool_result> cst_new_list_mcp_tool_result(
+    int len,
   ) {
-    return _wire__crate__api__init_model(model_path);
+    return _cst_new_list_mcp_tool_result(len);
   }
 
-  late final _wire__crate__api__init_modelPtr =
+  late final _cst_new_list_mcp_tool_resultPtr =
       _lookup<
         ffi.NativeFunction<
-          WireSyncRust2DartDco Function(
-            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-          )
+          ffi.Pointer<wire_cst_list_mcp_tool_result> Function(ffi.Int32)
         >
-      >('frbgen_kivixa_wire__crate__api__init_model');
-  late final _wire__crate__api__init_model = _wire__crate__api__init_modelPtr
-      .asFunction<
-        WireSyncRust2DartDco Function(
-          ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-        )
-      >();
+      >('frbgen_kivixa_cst_new_list_mcp_tool_result');
+  late final _cst_new_list_mcp_tool_result = _cst_new_list_mcp_tool_resultPtr
+      .asFunction<ffi.Pointer<wire_cst_list_mcp_tool_result> Function(int)>();
 
-  void wire__crate__api__init_model_with_config(
-    int port_,
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> model_path,
-    int n_gpu_layers,
-    int n_ctx,
-    int n_threads,
-    double temperature,
-    double top_p,
-    int max_tokens,
-    ffi.Pointer<ffi.Int32> model_type,
+  ffi.Pointer<wire_cst_list_node_position> cst_new_list_node_position(int len) {
+    return _cst_new_list_node_position(len);
+  }
+
+  late final _cst_new_list_node_positionPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Pointer<wire_cst_list_node_position> Function(ffi.Int32)
+        >
+      >('frbgen_kivixa_cst_new_list_node_position');
+  late final _cst_new_list_node_position = _cst_new_list_node_positionPtr
+      .asFunction<ffi.Pointer<wire_cst_list_node_position> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_prim_f_32_loose> cst_new_list_prim_f_32_loose(
+    int len,
   ) {
-    return _wire__crate__api__init_model_with_config(
-      port_,
-      model_path,
-      n_gpu_layers,
-      n_ctx,
-      n_threads,
-      temperature,
-      top_p,
-      max_tokens,
-      model_type,
-    );
+    return _cst_new_list_prim_f_32_loose(len);
   }
 
-  late final _wire__crate__api__init_model_with_configPtr =
+  late final _cst_new_list_prim_f_32_loosePtr =
       _lookup<
         ffi.NativeFunction<
-          ffi.Void Function(
-            ffi.Int64,
-            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-            ffi.Uint32,
-            ffi.Uint32,
-            ffi.Int32,
-            ffi.Float,
-            ffi.Float,
-            ffi.Uint32,
-            ffi.Pointer<ffi.Int32>,
-          )
+          ffi.Pointer<wire_cst_list_prim_f_32_loose> Function(ffi.Int32)
         >
-      >('frbgen_kivixa_wire__crate__api__init_model_with_config');
-  late final _wire__crate__api__init_model_with_config =
-      _wire__crate__api__init_model_with_configPtr
-          .asFunction<
-            void Function(
-              int,
-              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-              int,
-              int,
-              int,
-              double,
-              double,
-              int,
-              ffi.Pointer<ffi.Int32>,
-            )
-          >();
+      >('frbgen_kivixa_cst_new_list_prim_f_32_loose');
+  late final _cst_new_list_prim_f_32_loose = _cst_new_list_prim_f_32_loosePtr
+      .asFunction<ffi.Pointer<wire_cst_list_prim_f_32_loose> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_prim_f_32_strict> cst_new_list_prim_f_32_strict(
+    int len,
+  ) {
+    return _cst_new_list_prim_f_32_strict(len);
+  }
+
+  late final _cst_new_list_prim_f_32_strictPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Pointer<wire_cst_list_prim_f_32_strict> Function(ffi.Int32)
+        >
+      >('frbgen_kivixa_cst_new_list_prim_f_32_strict');
+  late final _cst_new_list_prim_f_32_strict = _cst_new_list_prim_f_32_strictPtr
+      .asFunction<ffi.Pointer<wire_cst_list_prim_f_32_strict> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_prim_i_32_loose> cst_new_list_prim_i_32_loose(
+    int len,
+  ) {
+    return _cst_new_list_prim_i_32_loose(len);
+  }
+
+  late final _cst_new_list_prim_i_32_loosePtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Pointer<wire_cst_list_prim_i_32_loose> Function(ffi.Int32)
+        >
+      >('frbgen_kivixa_cst_new_list_prim_i_32_loose');
+  late final _cst_new_list_prim_i_32_loose = _cst_new_list_prim_i_32_loosePtr
+      .asFunction<ffi.Pointer<wire_cst_list_prim_i_32_loose> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_prim_i_32_strict> cst_new_list_prim_i_32_strict(
+    int len,
+  ) {
+    return _cst_new_list_prim_i_32_strict(len);
+  }
+
+  late final _cst_new_list_prim_i_32_strictPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Pointer<wire_cst_list_prim_i_32_strict> Function(ffi.Int32)
+        >
+      >('frbgen_kivixa_cst_new_list_prim_i_32_strict');
+  late final _cst_new_list_prim_i_32_strict = _cst_new_list_prim_i_32_strictPtr
+      .asFunction<ffi.Pointer<wire_cst_list_prim_i_32_strict> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_prim_u_32_strict> cst_new_list_prim_u_32_strict(
+    int len,
+  ) {
+    return _cst_new_list_prim_u_32_strict(len);
+  }
+
+  late final _cst_new_list_prim_u_32_strictPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Pointer<wire_cst_list_prim_u_32_strict> Function(ffi.Int32)
+        >
+      >('frbgen_kivixa_cst_new_list_prim_u_32_strict');
+  late final _cst_new_list_prim_u_32_strict = _cst_new_list_prim_u_32_strictPtr
+      .asFunction<ffi.Pointer<wire_cst_list_prim_u_32_strict> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_prim_u_8_strict> cst_new_list_prim_u_8_strict(
+    int len,
+  ) {
+    return _cst_new_list_prim_u_8_strict(len);
+  }
+
+  late final _cst_new_list_prim_u_8_strictPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Pointer<wire_cst_list_prim_u_8_strict> Function(ffi.Int32)
+        >
+      >('frbgen_kivixa_cst_new_list_prim_u_8_strict');
+  late final _cst_new_list_prim_u_8_strict = _cst_new_list_prim_u_8_strictPtr
+      .asFunction<ffi.Pointer<wire_cst_list_prim_u_8_strict> Function(int)>();
 
-  WireSyncRust2DartDco wire__crate__api__is_graph_stream_running() {
-    return _wire__crate__api__is_graph_stream_running();
+  ffi.Pointer<wire_cst_list_rag_source> cst_new_list_rag_source(int len) {
+    return _cst_new_list_rag_source(len);
   }
 
-  late final _wire__crate__api__is_graph_stream_runningPtr =
-      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>(
-        'frbgen_kivixa_wire__crate__api__is_graph_stream_running',
-      );
-  late final _wire__crate__api__is_graph_stream_running =
-      _wire__crate__api__is_graph_stream_runningPtr
-          .asFunction<WireSyncRust2DartDco Function()>();
+  late final _cst_new_list_rag_sourcePtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Pointer<wire_cst_list_rag_source> Function(ffi.Int32)
+        >
+      >('frbgen_kivixa_cst_new_list_rag_source');
+  late final _cst_new_list_rag_source = _cst_new_list_rag_sourcePtr
+      .asFunction<ffi.Pointer<wire_cst_list_rag_source> Function(int)>();
 
-  WireSyncRust2DartDco wire__crate__api__is_mcp_initialized() {
-    return _wire__crate__api__is_mcp_initialized();
+  ffi.Pointer<wire_cst_list_record_string_string>
+  cst_new_list_record_string_string(int len) {
+    return _cst_new_list_record_string_string(len);
   }
 
-  late final _wire__crate__api__is_mcp_initializedPtr =
-      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>(
-        'frbgen_kivixa_wire__crate__api__is_mcp_initialized',
-      );
-  late final _wire__crate__api__is_mcp_initialized =
-      _wire__crate__api__is_mcp_initializedPtr
-          .asFunction<WireSyncRust2DartDco Function()>();
+  late final _cst_new_list_record_string_stringPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Pointer<wire_cst_list_record_string_string> Function(ffi.Int32)
+        >
+      >('frbgen_kivixa_cst_new_list_record_string_string');
+  late final _cst_new_list_record_string_string =
+      _cst_new_list_record_string_stringPtr
+          .asFunction<
+            ffi.Pointer<wire_cst_list_record_string_string> Function(int)
+          >();
 
-  WireSyncRust2DartDco wire__crate__api__is_model_loaded() {
-    return _wire__crate__api__is_model_loaded();
+  ffi.Pointer<wire_cst_list_reply_span> cst_new_list_reply_span(int len) {
+    return _cst_new_list_reply_span(len);
   }
 
-  late final _wire__crate__api__is_model_loadedPtr =
-      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>(
-        'frbgen_kivixa_wire__crate__api__is_model_loaded',
-      );
-  late final _wire__crate__api__is_model_loaded =
-      _wire__crate__api__is_model_loadedPtr
-          .asFunction<WireSyncRust2DartDco Function()>();
+  late final _cst_new_list_reply_spanPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Pointer<wire_cst_list_reply_span> Function(ffi.Int32)
+        >
+      >('frbgen_kivixa_cst_new_list_reply_span');
+  late final _cst_new_list_reply_span = _cst_new_list_reply_spanPtr
+      .asFunction<ffi.Pointer<wire_cst_list_reply_span> Function(int)>();
 
-  WireSyncRust2DartDco wire__crate__api__mcp_classify_task(
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> message,
+  ffi.Pointer<wire_cst_list_semantic_edge> cst_new_list_semantic_edge(int len) {
+    return _cst_new_list_semantic_edge(len);
+  }
+
+  late final _cst_new_list_semantic_edgePtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Pointer<wire_cst_list_semantic_edge> Function(ffi.Int32)
+        >
+      >('frbgen_kivixa_cst_new_list_semantic_edge');
+  late final _cst_new_list_semantic_edge = _cst_new_list_semantic_edgePtr
+      .asFunction<ffi.Pointer<wire_cst_list_semantic_edge> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_similarity_result> cst_new_list_similarity_result(
+    int len,
   ) {
-    return _wire__crate__api__mcp_classify_task(message);
+    return _cst_new_list_similarity_result(len);
   }
 
-  late final _wire__crate__api__mcp_classify_taskPtr =
+  late final _cst_new_list_similarity_resultPtr =
       _lookup<
         ffi.NativeFunction<
-          WireSyncRust2DartDco Function(
-            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-          )
+          ffi.Pointer<wire_cst_list_similarity_result> Function(ffi.Int32)
         >
-      >('frbgen_kivixa_wire__crate__api__mcp_classify_task');
-  late final _wire__crate__api__mcp_classify_task =
-      _wire__crate__api__mcp_classify_taskPtr
+      >('frbgen_kivixa_cst_new_list_similarity_result');
+  late final _cst_new_list_similarity_result =
+      _cst_new_list_similarity_resultPtr
           .asFunction<
-            WireSyncRust2DartDco Function(
-              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-            )
+            ffi.Pointer<wire_cst_list_similarity_result> Function(int)
           >();
 
-  void wire__crate__api__mcp_create_folder(
-    int port_,
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> path,
+  ffi.Pointer<wire_cst_list_token_candidate> cst_new_list_token_candidate(
+    int len,
   ) {
-    return _wire__crate__api__mcp_create_folder(port_, path);
+    return _cst_new_list_token_candidate(len);
   }
 
-  late final _wire__crate__api__mcp_create_folderPtr =
+  late final _cst_new_list_token_candidatePtr =
       _lookup<
         ffi.NativeFunction<
-          ffi.Void Function(
-            ffi.Int64,
-            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-          )
+          ffi.Pointer<wire_cst_list_token_candidate> Function(ffi.Int32)
         >
-      >('frbgen_kivixa_wire__crate__api__mcp_create_folder');
-  late final _wire__crate__api__mcp_create_folder =
-      _wire__crate__api__mcp_create_folderPtr
-          .asFunction<
-            void Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)
-          >();
+      >('frbgen_kivixa_cst_new_list_token_candidate');
+  late final _cst_new_list_token_candidate = _cst_new_list_token_candidatePtr
+      .asFunction<ffi.Pointer<wire_cst_list_token_candidate> Function(int)>();
 
-  void wire__crate__api__mcp_delete_file(
-    int port_,
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> path,
+  ffi.Pointer<wire_cst_list_token_logprob> cst_new_list_token_logprob(int len) {
+    return _cst_new_list_token_logprob(len);
+  }
+
+  late final _cst_new_list_token_logprobPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Pointer<wire_cst_list_token_logprob> Function(ffi.Int32)
+        >
+      >('frbgen_kivixa_cst_new_list_token_logprob');
+  late final _cst_new_list_token_logprob = _cst_new_list_token_logprobPtr
+      .asFunction<ffi.Pointer<wire_cst_list_token_logprob> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_transcript_segment> cst_new_list_transcript_segment(
+    int len,
   ) {
-    return _wire__crate__api__mcp_delete_file(port_, path);
+    return _cst_new_list_transcript_segment(len);
   }
 
-  late final _wire__crate__api__mcp_delete_filePtr =
+  late final _cst_new_list_transcript_segmentPtr =
       _lookup<
         ffi.NativeFunction<
-          ffi.Void Function(
-            ffi.Int64,
-            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-          )
+          ffi.Pointer<wire_cst_list_transcript_segment> Function(ffi.Int32)
         >
-      >('frbgen_kivixa_wire__crate__api__mcp_delete_file');
-  late final _wire__crate__api__mcp_delete_file =
-      _wire__crate__api__mcp_delete_filePtr
+      >('frbgen_kivixa_cst_new_list_transcript_segment');
+  late final _cst_new_list_transcript_segment =
+      _cst_new_list_transcript_segmentPtr
           .asFunction<
-            void Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)
+            ffi.Pointer<wire_cst_list_transcript_segment> Function(int)
           >();
 
-  void wire__crate__api__mcp_execute_tool_call(
-    int port_,
-    ffi.Pointer<wire_cst_mcp_tool_call> tool_call,
-  ) {
-    return _wire__crate__api__mcp_execute_tool_call(port_, tool_call);
-  }
+  int dummy_method_to_enforce_bundling() {
+    return _dummy_method_to_enforce_bundling();
+  }
+
+  late final _dummy_method_to_enforce_bundlingPtr =
+      _lookup<ffi.NativeFunction<ffi.Int64 Function()>>(
+        'dummy_method_to_enforce_bundling',
+      );
+  late final _dummy_method_to_enforce_bundling =
+      _dummy_method_to_enforce_bundlingPtr.asFunction<int Function()>();
+}
+
+typedef DartPort = ffi.Int64;
+typedef DartDartPort = int;
+typedef DartPostCObjectFnTypeFunction =
+    ffi.Bool Function(DartPort port_id, ffi.Pointer<ffi.Void> message);
+typedef DartDartPostCObjectFnTypeFunction =
+    bool Function(DartDartPort port_id, ffi.Pointer<ffi.Void> message);
+typedef DartPostCObjectFnType =
+    ffi.Pointer<ffi.NativeFunction<DartPostCObjectFnTypeFunction>>;
+
+final class wire_cst_list_prim_u_8_strict extends ffi.Struct {
+  external ffi.Pointer<ffi.Uint8> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_graph_edge extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> source;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> target;
+
+  @ffi.Float()
+  external double weight;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> edge_type;
+}
+
+final class wire_cst_list_graph_edge extends ffi.Struct {
+  external ffi.Pointer<wire_cst_graph_edge> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_graph_node extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> id;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> label;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> node_type;
+
+  @ffi.Float()
+  external double x;
+
+  @ffi.Float()
+  external double y;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> color;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> metadata;
+}
+
+final class wire_cst_list_graph_node extends ffi.Struct {
+  external ffi.Pointer<wire_cst_graph_node> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_list_prim_f_32_strict extends ffi.Struct {
+  external ffi.Pointer<ffi.Float> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_list_String extends ffi.Struct {
+  external ffi.Pointer<ffi.Pointer<wire_cst_list_prim_u_8_strict>> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_note_metadata extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> folder;
+
+  external ffi.Pointer<wire_cst_list_String> tags;
+
+  external ffi.Pointer<ffi.Int64> created_at;
+
+  external ffi.Pointer<ffi.Int64> modified_at;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> note_type;
+
+  external ffi.Pointer<ffi.UintPtr> chunk_start;
+
+  external ffi.Pointer<ffi.UintPtr> chunk_end;
+}
+
+final class wire_cst_embedding_entry extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> id;
+
+  external ffi.Pointer<wire_cst_list_prim_f_32_strict> vector;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> text_preview;
+
+  external wire_cst_note_metadata metadata;
+}
+
+final class wire_cst_list_embedding_entry extends ffi.Struct {
+  external ffi.Pointer<wire_cst_embedding_entry> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_record_string_string extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> field0;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> field1;
+}
+
+final class wire_cst_list_record_string_string extends ffi.Struct {
+  external ffi.Pointer<wire_cst_record_string_string> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_sampler_config extends ffi.Struct {
+  @ffi.Float()
+  external double temperature;
+
+  @ffi.Int32()
+  external int top_k;
+
+  @ffi.Float()
+  external double top_p;
+
+  @ffi.Float()
+  external double min_p;
+
+  @ffi.Float()
+  external double repeat_penalty;
+
+  @ffi.Float()
+  external double frequency_penalty;
+
+  @ffi.Float()
+  external double presence_penalty;
+
+  @ffi.Int32()
+  external int penalty_last_n;
 
-  late final _wire__crate__api__mcp_execute_tool_callPtr =
-      _lookup<
-        ffi.NativeFunction<
-          ffi.Void Function(ffi.Int64, ffi.Pointer<wire_cst_mcp_tool_call>)
-        >
-      >('frbgen_kivixa_wire__crate__api__mcp_execute_tool_call');
-  late final _wire__crate__api__mcp_execute_tool_call =
-      _wire__crate__api__mcp_execute_tool_callPtr
-          .asFunction<
-            void Function(int, ffi.Pointer<wire_cst_mcp_tool_call>)
-          >();
+  external ffi.Pointer<ffi.Uint32> seed;
 
-  WireSyncRust2DartDco wire__crate__api__mcp_get_all_tools() {
-    return _wire__crate__api__mcp_get_all_tools();
-  }
+  @ffi.Bool()
+  external bool greedy;
+}
 
-  late final _wire__crate__api__mcp_get_all_toolsPtr =
-      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>(
-        'frbgen_kivixa_wire__crate__api__mcp_get_all_tools',
-      );
-  late final _wire__crate__api__mcp_get_all_tools =
-      _wire__crate__api__mcp_get_all_toolsPtr
-          .asFunction<WireSyncRust2DartDco Function()>();
+final class wire_cst_context_policy extends ffi.Struct {
+  @ffi.Int32()
+  external int strategy;
 
-  WireSyncRust2DartDco wire__crate__api__mcp_get_model_for_task(int category) {
-    return _wire__crate__api__mcp_get_model_for_task(category);
-  }
+  @ffi.Uint32()
+  external int summary_max_tokens;
+}
 
-  late final _wire__crate__api__mcp_get_model_for_taskPtr =
-      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Int32)>>(
-        'frbgen_kivixa_wire__crate__api__mcp_get_model_for_task',
-      );
-  late final _wire__crate__api__mcp_get_model_for_task =
-      _wire__crate__api__mcp_get_model_for_taskPtr
-          .asFunction<WireSyncRust2DartDco Function(int)>();
+final class wire_cst_reasoning_options extends ffi.Struct {
+  @ffi.Bool()
+  external bool enabled;
 
-  WireSyncRust2DartDco wire__crate__api__mcp_get_tool_description(int tool) {
-    return _wire__crate__api__mcp_get_tool_description(tool);
-  }
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> open_tag;
 
-  late final _wire__crate__api__mcp_get_tool_descriptionPtr =
-      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Int32)>>(
-        'frbgen_kivixa_wire__crate__api__mcp_get_tool_description',
-      );
-  late final _wire__crate__api__mcp_get_tool_description =
-      _wire__crate__api__mcp_get_tool_descriptionPtr
-          .asFunction<WireSyncRust2DartDco Function(int)>();
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> close_tag;
 
-  WireSyncRust2DartDco wire__crate__api__mcp_get_tool_name(int tool) {
-    return _wire__crate__api__mcp_get_tool_name(tool);
-  }
+  external ffi.Pointer<ffi.Uint32> thinking_budget;
+}
 
-  late final _wire__crate__api__mcp_get_tool_namePtr =
-      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Int32)>>(
-        'frbgen_kivixa_wire__crate__api__mcp_get_tool_name',
-      );
-  late final _wire__crate__api__mcp_get_tool_name =
-      _wire__crate__api__mcp_get_tool_namePtr
-          .asFunction<WireSyncRust2DartDco Function(int)>();
+final class wire_cst_generation_options extends ffi.Struct {
+  external ffi.Pointer<ffi.Uint32> max_tokens;
 
-  WireSyncRust2DartDco wire__crate__api__mcp_get_tool_parameters(int tool) {
-    return _wire__crate__api__mcp_get_tool_parameters(tool);
-  }
+  external ffi.Pointer<wire_cst_list_String> stop_sequences;
 
-  late final _wire__crate__api__mcp_get_tool_parametersPtr =
-      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Int32)>>(
-        'frbgen_kivixa_wire__crate__api__mcp_get_tool_parameters',
-      );
-  late final _wire__crate__api__mcp_get_tool_parameters =
-      _wire__crate__api__mcp_get_tool_parametersPtr
-          .asFunction<WireSyncRust2DartDco Function(int)>();
+  external ffi.Pointer<wire_cst_sampler_config> sampler;
 
-  WireSyncRust2DartDco wire__crate__api__mcp_get_tool_schemas() {
-    return _wire__crate__api__mcp_get_tool_schemas();
-  }
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> grammar;
 
-  late final _wire__crate__api__mcp_get_tool_schemasPtr =
-      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>(
-        'frbgen_kivixa_wire__crate__api__mcp_get_tool_schemas',
-      );
-  late final _wire__crate__api__mcp_get_tool_schemas =
-      _wire__crate__api__mcp_get_tool_schemasPtr
-          .asFunction<WireSyncRust2DartDco Function()>();
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> json_schema;
 
-  void wire__crate__api__mcp_list_files(
-    int port_,
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> path,
-  ) {
-    return _wire__crate__api__mcp_list_files(port_, path);
-  }
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> model_id;
 
-  late final _wire__crate__api__mcp_list_filesPtr =
-      _lookup<
-        ffi.NativeFunction<
-          ffi.Void Function(
-            ffi.Int64,
-            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-          )
-        >
-      >('frbgen_kivixa_wire__crate__api__mcp_list_files');
-  late final _wire__crate__api__mcp_list_files =
-      _wire__crate__api__mcp_list_filesPtr
-          .asFunction<
-            void Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)
-          >();
+  external ffi.Pointer<wire_cst_context_policy> context;
 
-  void wire__crate__api__mcp_parse_tool_call(
-    int port_,
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> json,
-  ) {
-    return _wire__crate__api__mcp_parse_tool_call(port_, json);
-  }
+  external ffi.Pointer<wire_cst_reasoning_options> reasoning;
 
-  late final _wire__crate__api__mcp_parse_tool_callPtr =
-      _lookup<
-        ffi.NativeFunction<
-          ffi.Void Function(
-            ffi.Int64,
-            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-          )
-        >
-      >('frbgen_kivixa_wire__crate__api__mcp_parse_tool_call');
-  late final _wire__crate__api__mcp_parse_tool_call =
-      _wire__crate__api__mcp_parse_tool_callPtr
-          .asFunction<
-            void Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)
-          >();
+  external ffi.Pointer<ffi.Uint32> logprobs;
+}
 
-  void wire__crate__api__mcp_read_file(
-    int port_,
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> path,
-  ) {
-    return _wire__crate__api__mcp_read_file(port_, path);
-  }
+final class wire_cst_list_prim_f_32_loose extends ffi.Struct {
+  external ffi.Pointer<ffi.Float> ptr;
 
-  late final _wire__crate__api__mcp_read_filePtr =
-      _lookup<
-        ffi.NativeFunction<
-          ffi.Void Function(
-            ffi.Int64,
-            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-          )
-        >
-      >('frbgen_kivixa_wire__crate__api__mcp_read_file');
-  late final _wire__crate__api__mcp_read_file =
-      _wire__crate__api__mcp_read_filePtr
-          .asFunction<
-            void Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)
-          >();
+  @ffi.Int32()
+  external int len;
+}
 
-  WireSyncRust2DartDco wire__crate__api__mcp_validate_path(
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> path,
-  ) {
-    return _wire__crate__api__mcp_validate_path(path);
-  }
+final class wire_cst_hnsw_config extends ffi.Struct {
+  @ffi.Uint32()
+  external int m;
 
-  late final _wire__crate__api__mcp_validate_pathPtr =
-      _lookup<
-        ffi.NativeFunction<
-          WireSyncRust2DartDco Function(
-            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-          )
-        >
-      >('frbgen_kivixa_wire__crate__api__mcp_validate_path');
-  late final _wire__crate__api__mcp_validate_path =
-      _wire__crate__api__mcp_validate_pathPtr
-          .asFunction<
-            WireSyncRust2DartDco Function(
-              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-            )
-          >();
+  @ffi.Uint32()
+  external int ef_construction;
 
-  void wire__crate__api__mcp_write_file(
-    int port_,
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> path,
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> content,
-  ) {
-    return _wire__crate__api__mcp_write_file(port_, path, content);
-  }
+  @ffi.Uint32()
+  external int ef_search;
+}
 
-  late final _wire__crate__api__mcp_write_filePtr =
-      _lookup<
-        ffi.NativeFunction<
-          ffi.Void Function(
-            ffi.Int64,
-            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-          )
-        >
-      >('frbgen_kivixa_wire__crate__api__mcp_write_file');
-  late final _wire__crate__api__mcp_write_file =
-      _wire__crate__api__mcp_write_filePtr
-          .asFunction<
-            void Function(
-              int,
-              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-            )
-          >();
+final class wire_cst_list_prim_i_32_loose extends ffi.Struct {
+  external ffi.Pointer<ffi.Int32> ptr;
 
-  void wire__crate__api__pin_stream_node(
-    int port_,
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> id,
-    bool pinned,
-  ) {
-    return _wire__crate__api__pin_stream_node(port_, id, pinned);
-  }
+  @ffi.Int32()
+  external int len;
+}
 
-  late final _wire__crate__api__pin_stream_nodePtr =
-      _lookup<
-        ffi.NativeFunction<
-          ffi.Void Function(
-            ffi.Int64,
-            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-            ffi.Bool,
-          )
-        >
-      >('frbgen_kivixa_wire__crate__api__pin_stream_node');
-  late final _wire__crate__api__pin_stream_node =
-      _wire__crate__api__pin_stream_nodePtr
-          .asFunction<
-            void Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>, bool)
-          >();
+final class wire_cst_dedup_options extends ffi.Struct {
+  @ffi.UintPtr()
+  external int shingle_words;
 
-  void wire__crate__api__remove_graph_node(
-    int port_,
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> node_id,
-  ) {
-    return _wire__crate__api__remove_graph_node(port_, node_id);
-  }
+  @ffi.UintPtr()
+  external int num_hashes;
 
-  late final _wire__crate__api__remove_graph_nodePtr =
-      _lookup<
-        ffi.NativeFunction<
-          ffi.Void Function(
-            ffi.Int64,
-            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-          )
-        >
-      >('frbgen_kivixa_wire__crate__api__remove_graph_node');
-  late final _wire__crate__api__remove_graph_node =
-      _wire__crate__api__remove_graph_nodePtr
-          .asFunction<
-            void Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)
-          >();
+  @ffi.UintPtr()
+  external int bands;
+
+  @ffi.Float()
+  external double min_jaccard;
+
+  @ffi.Float()
+  external double min_cosine;
+
+  @ffi.UintPtr()
+  external int min_span_words;
+}
 
-  void wire__crate__api__remove_stream_edge(
-    int port_,
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> from_id,
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> to_id,
-  ) {
-    return _wire__crate__api__remove_stream_edge(port_, from_id, to_id);
-  }
+final class wire_cst_dedup_document extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> id;
 
-  late final _wire__crate__api__remove_stream_edgePtr =
-      _lookup<
-        ffi.NativeFunction<
-          ffi.Void Function(
-            ffi.Int64,
-            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-          )
-        >
-      >('frbgen_kivixa_wire__crate__api__remove_stream_edge');
-  late final _wire__crate__api__remove_stream_edge =
-      _wire__crate__api__remove_stream_edgePtr
-          .asFunction<
-            void Function(
-              int,
-              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-            )
-          >();
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> text;
 
-  void wire__crate__api__remove_stream_node(
-    int port_,
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> id,
-  ) {
-    return _wire__crate__api__remove_stream_node(port_, id);
-  }
+  external ffi.Pointer<wire_cst_list_prim_f_32_strict> vector;
 
-  late final _wire__crate__api__remove_stream_nodePtr =
-      _lookup<
-        ffi.NativeFunction<
-          ffi.Void Function(
-            ffi.Int64,
-            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-          )
-        >
-      >('frbgen_kivixa_wire__crate__api__remove_stream_node');
-  late final _wire__crate__api__remove_stream_node =
-      _wire__crate__api__remove_stream_nodePtr
-          .asFunction<
-            void Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)
-          >();
+  external ffi.Pointer<ffi.Int64> created_at;
+}
 
-  void wire__crate__api__semantic_search(
-    int port_,
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> query_text,
-    ffi.Pointer<wire_cst_list_embedding_entry> entries,
-    int top_k,
-  ) {
-    return _wire__crate__api__semantic_search(
-      port_,
-      query_text,
-      entries,
-      top_k,
-    );
-  }
+final class wire_cst_list_dedup_document extends ffi.Struct {
+  external ffi.Pointer<wire_cst_dedup_document> ptr;
 
-  late final _wire__crate__api__semantic_searchPtr =
-      _lookup<
-        ffi.NativeFunction<
-          ffi.Void Function(
-            ffi.Int64,
-            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-            ffi.Pointer<wire_cst_list_embedding_entry>,
-            ffi.UintPtr,
-          )
-        >
-      >('frbgen_kivixa_wire__crate__api__semantic_search');
-  late final _wire__crate__api__semantic_search =
-      _wire__crate__api__semantic_searchPtr
-          .asFunction<
-            void Function(
-              int,
-              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-              ffi.Pointer<wire_cst_list_embedding_entry>,
-              int,
-            )
-          >();
+  @ffi.Int32()
+  external int len;
+}
 
-  void wire__crate__api__set_stream_node_position(
-    int port_,
-    ffi.Pointer<wire_cst_list_prim_u_8_strict> id,
-    double x,
-    double y,
-  ) {
-    return _wire__crate__api__set_stream_node_position(port_, id, x, y);
-  }
+final class wire_cst_search_options extends ffi.Struct {
+  @ffi.UintPtr()
+  external int top_k;
 
-  late final _wire__crate__api__set_stream_node_positionPtr =
-      _lookup<
-        ffi.NativeFunction<
-          ffi.Void Function(
-            ffi.Int64,
-            ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-            ffi.Float,
-            ffi.Float,
-          )
-        >
-      >('frbgen_kivixa_wire__crate__api__set_stream_node_position');
-  late final _wire__crate__api__set_stream_node_position =
-      _wire__crate__api__set_stream_node_positionPtr
-          .asFunction<
-            void Function(
-              int,
-              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
-              double,
-              double,
-            )
-          >();
+  @ffi.Float()
+  external double threshold;
 
-  void wire__crate__api__start_graph_stream(int port_) {
-    return _wire__crate__api__start_graph_stream(port_);
-  }
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> filter;
 
-  late final _wire__crate__api__start_graph_streamPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64)>>(
-        'frbgen_kivixa_wire__crate__api__start_graph_stream',
-      );
-  late final _wire__crate__api__start_graph_stream =
-      _wire__crate__api__start_graph_streamPtr.asFunction<void Function(int)>();
+  external ffi.Pointer<ffi.Float> mmr_lambda;
 
-  WireSyncRust2DartDco wire__crate__api__stop_graph_stream() {
-    return _wire__crate__api__stop_graph_stream();
-  }
+  @ffi.UintPtr()
+  external int mmr_candidates;
+}
 
-  late final _wire__crate__api__stop_graph_streamPtr =
-      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>(
-        'frbgen_kivixa_wire__crate__api__stop_graph_stream',
-      );
-  late final _wire__crate__api__stop_graph_stream =
-      _wire__crate__api__stop_graph_streamPtr
-          .asFunction<WireSyncRust2DartDco Function()>();
+final class wire_cst_hybrid_options extends ffi.Struct {
+  @ffi.Int32()
+  external int fusion;
 
-  WireSyncRust2DartDco wire__crate__api__unload_model() {
-    return _wire__crate__api__unload_model();
-  }
+  @ffi.Float()
+  external double semantic_weight;
 
-  late final _wire__crate__api__unload_modelPtr =
-      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function()>>(
-        'frbgen_kivixa_wire__crate__api__unload_model',
-      );
-  late final _wire__crate__api__unload_model =
-      _wire__crate__api__unload_modelPtr
-          .asFunction<WireSyncRust2DartDco Function()>();
+  @ffi.Float()
+  external double rrf_k;
 
-  void wire__crate__api__update_graph_viewport(
-    int port_,
-    double x,
-    double y,
-    double width,
-    double height,
-    double scale,
-  ) {
-    return _wire__crate__api__update_graph_viewport(
-      port_,
-      x,
-      y,
-      width,
-      height,
-      scale,
-    );
-  }
+  @ffi.UintPtr()
+  external int candidates;
 
-  late final _wire__crate__api__update_graph_viewportPtr =
-      _lookup<
-        ffi.NativeFunction<
-          ffi.Void Function(
-            ffi.Int64,
-            ffi.Float,
-            ffi.Float,
-            ffi.Float,
-            ffi.Float,
-            ffi.Float,
-          )
-        >
-      >('frbgen_kivixa_wire__crate__api__update_graph_viewport');
-  late final _wire__crate__api__update_graph_viewport =
-      _wire__crate__api__update_graph_viewportPtr
-          .asFunction<
-            void Function(int, double, double, double, double, double)
-          >();
+  @ffi.Float()
+  external double min_semantic_score;
+}
 
-  ffi.Pointer<ffi.Float> cst_new_box_autoadd_f_32(double value) {
-    return _cst_new_box_autoadd_f_32(value);
-  }
+final class wire_cst_embedding_config extends ffi.Struct {
+  @ffi.Uint32()
+  external int n_gpu_layers;
 
-  late final _cst_new_box_autoadd_f_32Ptr =
-      _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Float> Function(ffi.Float)>>(
-        'frbgen_kivixa_cst_new_box_autoadd_f_32',
-      );
-  late final _cst_new_box_autoadd_f_32 = _cst_new_box_autoadd_f_32Ptr
-      .asFunction<ffi.Pointer<ffi.Float> Function(double)>();
+  @ffi.Int32()
+  external int n_threads;
 
-  ffi.Pointer<ffi.Int32> cst_new_box_autoadd_i_32(int value) {
-    return _cst_new_box_autoadd_i_32(value);
-  }
+  @ffi.Int32()
+  external int pooling;
 
-  late final _cst_new_box_autoadd_i_32Ptr =
-      _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Int32> Function(ffi.Int32)>>(
-        'frbgen_kivixa_cst_new_box_autoadd_i_32',
-      );
-  late final _cst_new_box_autoadd_i_32 = _cst_new_box_autoadd_i_32Ptr
-      .asFunction<ffi.Pointer<ffi.Int32> Function(int)>();
+  @ffi.Uint32()
+  external int chunk_tokens;
 
-  ffi.Pointer<wire_cst_mcp_tool_call> cst_new_box_autoadd_mcp_tool_call() {
-    return _cst_new_box_autoadd_mcp_tool_call();
-  }
+  @ffi.Uint32()
+  external int chunk_overlap;
 
-  late final _cst_new_box_autoadd_mcp_tool_callPtr =
-      _lookup<
-        ffi.NativeFunction<ffi.Pointer<wire_cst_mcp_tool_call> Function()>
-      >('frbgen_kivixa_cst_new_box_autoadd_mcp_tool_call');
-  late final _cst_new_box_autoadd_mcp_tool_call =
-      _cst_new_box_autoadd_mcp_tool_callPtr
-          .asFunction<ffi.Pointer<wire_cst_mcp_tool_call> Function()>();
+  @ffi.Uint32()
+  external int max_sequences;
 
-  ffi.Pointer<ffi.Uint32> cst_new_box_autoadd_u_32(int value) {
-    return _cst_new_box_autoadd_u_32(value);
-  }
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> query_prefix;
 
-  late final _cst_new_box_autoadd_u_32Ptr =
-      _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Uint32> Function(ffi.Uint32)>>(
-        'frbgen_kivixa_cst_new_box_autoadd_u_32',
-      );
-  late final _cst_new_box_autoadd_u_32 = _cst_new_box_autoadd_u_32Ptr
-      .asFunction<ffi.Pointer<ffi.Uint32> Function(int)>();
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> document_prefix;
+}
 
-  ffi.Pointer<ffi.UintPtr> cst_new_box_autoadd_usize(int value) {
-    return _cst_new_box_autoadd_usize(value);
-  }
+final class wire_cst_mcp_tool_call extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> tool;
 
-  late final _cst_new_box_autoadd_usizePtr =
-      _lookup<
-        ffi.NativeFunction<ffi.Pointer<ffi.UintPtr> Function(ffi.UintPtr)>
-      >('frbgen_kivixa_cst_new_box_autoadd_usize');
-  late final _cst_new_box_autoadd_usize = _cst_new_box_autoadd_usizePtr
-      .asFunction<ffi.Pointer<ffi.UintPtr> Function(int)>();
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> parameters_json;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> description;
+}
+
+final class wire_cst_list_list_prim_f_32_strict extends ffi.Struct {
+  external ffi.Pointer<ffi.Pointer<wire_cst_list_prim_f_32_strict>> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_summary_options extends ffi.Struct {
+  @ffi.Int32()
+  external int style;
 
-  ffi.Pointer<wire_cst_list_String> cst_new_list_String(int len) {
-    return _cst_new_list_String(len);
-  }
+  @ffi.Uint32()
+  external int max_words;
 
-  late final _cst_new_list_StringPtr =
-      _lookup<
-        ffi.NativeFunction<
-          ffi.Pointer<wire_cst_list_String> Function(ffi.Int32)
-        >
-      >('frbgen_kivixa_cst_new_list_String');
-  late final _cst_new_list_String = _cst_new_list_StringPtr
-      .asFunction<ffi.Pointer<wire_cst_list_String> Function(int)>();
+  @ffi.Uint32()
+  external int chunk_tokens;
 
-  ffi.Pointer<wire_cst_list_cluster_assignment> cst_new_list_cluster_assignment(
-    int len,
-  ) {
-    return _cst_new_list_cluster_assignment(len);
-  }
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> model_id;
+}
 
-  late final _cst_new_list_cluster_assignmentPtr =
-      _lookup<
-        ffi.NativeFunction<
-          ffi.Pointer<wire_cst_list_cluster_assignment> Function(ffi.Int32)
-        >
-      >('frbgen_kivixa_cst_new_list_cluster_assignment');
-  late final _cst_new_list_cluster_assignment =
-      _cst_new_list_cluster_assignmentPtr
-          .asFunction<
-            ffi.Pointer<wire_cst_list_cluster_assignment> Function(int)
-          >();
+final class wire_cst_transcript_segment extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> text;
 
-  ffi.Pointer<wire_cst_list_cluster_info> cst_new_list_cluster_info(int len) {
-    return _cst_new_list_cluster_info(len);
-  }
+  @ffi.Float()
+  external double start_time;
 
-  late final _cst_new_list_cluster_infoPtr =
-      _lookup<
-        ffi.NativeFunction<
-          ffi.Pointer<wire_cst_list_cluster_info> Function(ffi.Int32)
-        >
-      >('frbgen_kivixa_cst_new_list_cluster_info');
-  late final _cst_new_list_cluster_info = _cst_new_list_cluster_infoPtr
-      .asFunction<ffi.Pointer<wire_cst_list_cluster_info> Function(int)>();
+  @ffi.Float()
+  external double end_time;
+}
 
-  ffi.Pointer<wire_cst_list_embedding_cluster> cst_new_list_embedding_cluster(
-    int len,
-  ) {
-    return _cst_new_list_embedding_cluster(len);
-  }
+final class wire_cst_list_transcript_segment extends ffi.Struct {
+  external ffi.Pointer<wire_cst_transcript_segment> ptr;
 
-  late final _cst_new_list_embedding_clusterPtr =
-      _lookup<
-        ffi.NativeFunction<
-          ffi.Pointer<wire_cst_list_embedding_cluster> Function(ffi.Int32)
-        >
-      >('frbgen_kivixa_cst_new_list_embedding_cluster');
-  late final _cst_new_list_embedding_cluster =
-      _cst_new_list_embedding_clusterPtr
-          .asFunction<
-            ffi.Pointer<wire_cst_list_embedding_cluster> Function(int)
-          >();
+  @ffi.Int32()
+  external int len;
+}
 
-  ffi.Pointer<wire_cst_list_embedding_entry> cst_new_list_embedding_entry(
-    int len,
-  ) {
-    return _cst_new_list_embedding_entry(len);
-  }
+final class wire_cst_list_prim_u_32_strict extends ffi.Struct {
+  external ffi.Pointer<ffi.Uint32> ptr;
 
-  late final _cst_new_list_embedding_entryPtr =
-      _lookup<
-        ffi.NativeFunction<
-          ffi.Pointer<wire_cst_list_embedding_entry> Function(ffi.Int32)
-        >
-      >('frbgen_kivixa_cst_new_list_embedding_entry');
-  late final _cst_new_list_embedding_entry = _cst_new_list_embedding_entryPtr
-      .asFunction<ffi.Pointer<wire_cst_list_embedding_entry> Function(int)>();
+  @ffi.Int32()
+  external int len;
+}
 
-  ffi.Pointer<wire_cst_list_graph_edge> cst_new_list_graph_edge(int len) {
-    return _cst_new_list_graph_edge(len);
-  }
+final class wire_cst_context_report extends ffi.Struct {
+  @ffi.Uint32()
+  external int context_size;
 
-  late final _cst_new_list_graph_edgePtr =
-      _lookup<
-        ffi.NativeFunction<
-          ffi.Pointer<wire_cst_list_graph_edge> Function(ffi.Int32)
-        >
-      >('frbgen_kivixa_cst_new_list_graph_edge');
-  late final _cst_new_list_graph_edge = _cst_new_list_graph_edgePtr
-      .asFunction<ffi.Pointer<wire_cst_list_graph_edge> Function(int)>();
+  @ffi.Uint32()
+  external int prompt_tokens;
 
-  ffi.Pointer<wire_cst_list_graph_node> cst_new_list_graph_node(int len) {
-    return _cst_new_list_graph_node(len);
-  }
+  external ffi.Pointer<wire_cst_list_prim_u_32_strict> message_tokens;
 
-  late final _cst_new_list_graph_nodePtr =
-      _lookup<
-        ffi.NativeFunction<
-          ffi.Pointer<wire_cst_list_graph_node> Function(ffi.Int32)
-        >
-      >('frbgen_kivixa_cst_new_list_graph_node');
-  late final _cst_new_list_graph_node = _cst_new_list_graph_nodePtr
-      .asFunction<ffi.Pointer<wire_cst_list_graph_node> Function(int)>();
+  external ffi.Pointer<wire_cst_list_prim_u_32_strict> dropped;
 
-  ffi.Pointer<wire_cst_list_mcp_parameter> cst_new_list_mcp_parameter(int len) {
-    return _cst_new_list_mcp_parameter(len);
-  }
+  external ffi.Pointer<wire_cst_list_prim_u_32_strict> truncated;
 
-  late final _cst_new_list_mcp_parameterPtr =
-      _lookup<
-        ffi.NativeFunction<
-          ffi.Pointer<wire_cst_list_mcp_parameter> Function(ffi.Int32)
-        >
-      >('frbgen_kivixa_cst_new_list_mcp_parameter');
-  late final _cst_new_list_mcp_parameter = _cst_new_list_mcp_parameterPtr
-      .asFunction<ffi.Pointer<wire_cst_list_mcp_parameter> Function(int)>();
+  external ffi.Pointer<wire_cst_list_prim_u_32_strict> summarized;
 
-  ffi.Pointer<wire_cst_list_mcp_tool> cst_new_list_mcp_tool(int len) {
-    return _cst_new_list_mcp_tool(len);
-  }
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> summary;
+}
 
-  late final _cst_new_list_mcp_toolPtr =
-      _lookup<
-        ffi.NativeFunction<
-          ffi.Pointer<wire_cst_list_mcp_tool> Function(ffi.Int32)
-        >
-      >('frbgen_kivixa_cst_new_list_mcp_tool');
-  late final _cst_new_list_mcp_tool = _cst_new_list_mcp_toolPtr
-      .asFunction<ffi.Pointer<wire_cst_list_mcp_tool> Function(int)>();
+final class wire_cst_embedding_cache_stats extends ffi.Struct {
+  @ffi.UintPtr()
+  external int entries;
 
-  ffi.Pointer<wire_cst_list_node_position> cst_new_list_node_position(int len) {
-    return _cst_new_list_node_position(len);
-  }
+  @ffi.UintPtr()
+  external int stale_records;
 
-  late final _cst_new_list_node_positionPtr =
-      _lookup<
-        ffi.NativeFunction<
-          ffi.Pointer<wire_cst_list_node_position> Function(ffi.Int32)
-        >
-      >('frbgen_kivixa_cst_new_list_node_position');
-  late final _cst_new_list_node_position = _cst_new_list_node_positionPtr
-      .asFunction<ffi.Pointer<wire_cst_list_node_position> Function(int)>();
+  @ffi.Uint64()
+  external int file_bytes;
+}
 
-  ffi.Pointer<wire_cst_list_prim_f_32_loose> cst_new_list_prim_f_32_loose(
-    int len,
-  ) {
-    return _cst_new_list_prim_f_32_loose(len);
-  }
+final class wire_cst_token_candidate extends ffi.Struct {
+  @ffi.Int32()
+  external int token;
 
-  late final _cst_new_list_prim_f_32_loosePtr =
-      _lookup<
-        ffi.NativeFunction<
-          ffi.Pointer<wire_cst_list_prim_f_32_loose> Function(ffi.Int32)
-        >
-      >('frbgen_kivixa_cst_new_list_prim_f_32_loose');
-  late final _cst_new_list_prim_f_32_loose = _cst_new_list_prim_f_32_loosePtr
-      .asFunction<ffi.Pointer<wire_cst_list_prim_f_32_loose> Function(int)>();
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> text;
 
-  ffi.Pointer<wire_cst_list_prim_f_32_strict> cst_new_list_prim_f_32_strict(
-    int len,
-  ) {
-    return _cst_new_list_prim_f_32_strict(len);
-  }
+  @ffi.Float()
+  external double logprob;
+}
 
-  late final _cst_new_list_prim_f_32_strictPtr =
-      _lookup<
-        ffi.NativeFunction<
-          ffi.Pointer<wire_cst_list_prim_f_32_strict> Function(ffi.Int32)
-        >
-      >('frbgen_kivixa_cst_new_list_prim_f_32_strict');
-  late final _cst_new_list_prim_f_32_strict = _cst_new_list_prim_f_32_strictPtr
-      .asFunction<ffi.Pointer<wire_cst_list_prim_f_32_strict> Function(int)>();
+final class wire_cst_list_token_candidate extends ffi.Struct {
+  external ffi.Pointer<wire_cst_token_candidate> ptr;
 
-  ffi.Pointer<wire_cst_list_prim_u_8_strict> cst_new_list_prim_u_8_strict(
-    int len,
-  ) {
-    return _cst_new_list_prim_u_8_strict(len);
-  }
+  @ffi.Int32()
+  external int len;
+}
 
-  late final _cst_new_list_prim_u_8_strictPtr =
-      _lookup<
-        ffi.NativeFunction<
-          ffi.Pointer<wire_cst_list_prim_u_8_strict> Function(ffi.Int32)
-        >
-      >('frbgen_kivixa_cst_new_list_prim_u_8_strict');
-  late final _cst_new_list_prim_u_8_strict = _cst_new_list_prim_u_8_strictPtr
-      .asFunction<ffi.Pointer<wire_cst_list_prim_u_8_strict> Function(int)>();
+final class wire_cst_token_logprob extends ffi.Struct {
+  @ffi.Int32()
+  external int token;
 
-  ffi.Pointer<wire_cst_list_record_string_string>
-  cst_new_list_record_string_string(int len) {
-    return _cst_new_list_record_string_string(len);
-  }
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> text;
 
-  late final _cst_new_list_record_string_stringPtr =
-      _lookup<
-        ffi.NativeFunction<
-          ffi.Pointer<wire_cst_list_record_string_string> Function(ffi.Int32)
-        >
-      >('frbgen_kivixa_cst_new_list_record_string_string');
-  late final _cst_new_list_record_string_string =
-      _cst_new_list_record_string_stringPtr
-          .asFunction<
-            ffi.Pointer<wire_cst_list_record_string_string> Function(int)
-          >();
+  @ffi.Float()
+  external double logprob;
 
-  ffi.Pointer<wire_cst_list_semantic_edge> cst_new_list_semantic_edge(int len) {
-    return _cst_new_list_semantic_edge(len);
-  }
+  external ffi.Pointer<wire_cst_list_token_candidate> top_alternatives;
+}
 
-  late final _cst_new_list_semantic_edgePtr =
-      _lookup<
-        ffi.NativeFunction<
-          ffi.Pointer<wire_cst_list_semantic_edge> Function(ffi.Int32)
-        >
-      >('frbgen_kivixa_cst_new_list_semantic_edge');
-  late final _cst_new_list_semantic_edge = _cst_new_list_semantic_edgePtr
-      .asFunction<ffi.Pointer<wire_cst_list_semantic_edge> Function(int)>();
+final class wire_cst_list_token_logprob extends ffi.Struct {
+  external ffi.Pointer<wire_cst_token_logprob> ptr;
 
-  ffi.Pointer<wire_cst_list_similarity_result> cst_new_list_similarity_result(
-    int len,
-  ) {
-    return _cst_new_list_similarity_result(len);
-  }
+  @ffi.Int32()
+  external int len;
+}
 
-  late final _cst_new_list_similarity_resultPtr =
-      _lookup<
-        ffi.NativeFunction<
-          ffi.Pointer<wire_cst_list_similarity_result> Function(ffi.Int32)
-        >
-      >('frbgen_kivixa_cst_new_list_similarity_result');
-  late final _cst_new_list_similarity_result =
-      _cst_new_list_similarity_resultPtr
-          .asFunction<
-            ffi.Pointer<wire_cst_list_similarity_result> Function(int)
-          >();
+final class wire_cst_generation_result extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> text;
 
-  int dummy_method_to_enforce_bundling() {
-    return _dummy_method_to_enforce_bundling();
-  }
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> reasoning;
 
-  late final _dummy_method_to_enforce_bundlingPtr =
-      _lookup<ffi.NativeFunction<ffi.Int64 Function()>>(
-        'dummy_method_to_enforce_bundling',
-      );
-  late final _dummy_method_to_enforce_bundling =
-      _dummy_method_to_enforce_bundlingPtr.asFunction<int Function()>();
-}
+  @ffi.Uint32()
+  external int reasoning_tokens;
 
-typedef DartPort = ffi.Int64;
-typedef DartDartPort = int;
-typedef DartPostCObjectFnTypeFunction =
-    ffi.Bool Function(DartPort port_id, ffi.Pointer<ffi.Void> message);
-typedef DartDartPostCObjectFnTypeFunction =
-    bool Function(DartDartPort port_id, ffi.Pointer<ffi.Void> message);
-typedef DartPostCObjectFnType =
-    ffi.Pointer<ffi.NativeFunction<DartPostCObjectFnTypeFunction>>;
+  @ffi.Int32()
+  external int stop_reason;
+
+  @ffi.Uint32()
+  external int tokens_generated;
 
-final class wire_cst_list_prim_u_8_strict extends ffi.Struct {
-  external ffi.Pointer<ffi.Uint8> ptr;
+  external ffi.Pointer<wire_cst_context_report> context;
+
+  external ffi.Pointer<wire_cst_list_token_logprob> logprobs;
+}
+
+final class wire_cst_list_mcp_tool_call extends ffi.Struct {
+  external ffi.Pointer<wire_cst_mcp_tool_call> ptr;
 
   @ffi.Int32()
   external int len;
 }
 
-final class wire_cst_graph_edge extends ffi.Struct {
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> source;
-
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> target;
+final class wire_cst_mcp_tool_result extends ffi.Struct {
+  @ffi.Bool()
+  external bool success;
 
-  @ffi.Float()
-  external double weight;
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> result;
 
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> edge_type;
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> tool;
 }
 
-final class wire_cst_list_graph_edge extends ffi.Struct {
-  external ffi.Pointer<wire_cst_graph_edge> ptr;
+final class wire_cst_list_mcp_tool_result extends ffi.Struct {
+  external ffi.Pointer<wire_cst_mcp_tool_result> ptr;
 
   @ffi.Int32()
   external int len;
 }
 
-final class wire_cst_graph_node extends ffi.Struct {
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> id;
+final class wire_cst_agent_step extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> reply;
 
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> label;
+  external ffi.Pointer<wire_cst_list_mcp_tool_call> tool_calls;
 
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> node_type;
+  external ffi.Pointer<wire_cst_list_mcp_tool_result> results;
+}
 
-  @ffi.Float()
-  external double x;
+final class wire_cst_list_agent_step extends ffi.Struct {
+  external ffi.Pointer<wire_cst_agent_step> ptr;
 
-  @ffi.Float()
-  external double y;
+  @ffi.Int32()
+  external int len;
+}
 
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> color;
+final class wire_cst_cluster_assignment extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> id;
 
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> metadata;
+  @ffi.UintPtr()
+  external int cluster_id;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> color;
 }
 
-final class wire_cst_list_graph_node extends ffi.Struct {
-  external ffi.Pointer<wire_cst_graph_node> ptr;
+final class wire_cst_list_cluster_assignment extends ffi.Struct {
+  external ffi.Pointer<wire_cst_cluster_assignment> ptr;
 
   @ffi.Int32()
   external int len;
 }
 
-final class wire_cst_list_prim_f_32_strict extends ffi.Struct {
-  external ffi.Pointer<ffi.Float> ptr;
+final class wire_cst_cluster_info extends ffi.Struct {
+  @ffi.UintPtr()
+  external int id;
+
+  @ffi.UintPtr()
+  external int size;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> color;
+
+  external ffi.Pointer<wire_cst_list_prim_f_32_strict> centroid;
+}
+
+final class wire_cst_list_cluster_info extends ffi.Struct {
+  external ffi.Pointer<wire_cst_cluster_info> ptr;
 
   @ffi.Int32()
   external int len;
 }
 
-final class wire_cst_embedding_entry extends ffi.Struct {
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> id;
+final class wire_cst_duplicate_span extends ffi.Struct {
+  @ffi.UintPtr()
+  external int a_start;
 
-  external ffi.Pointer<wire_cst_list_prim_f_32_strict> vector;
+  @ffi.UintPtr()
+  external int a_end;
 
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> text_preview;
+  @ffi.UintPtr()
+  external int b_start;
+
+  @ffi.UintPtr()
+  external int b_end;
+
+  @ffi.UintPtr()
+  external int words;
 }
 
-final class wire_cst_list_embedding_entry extends ffi.Struct {
-  external ffi.Pointer<wire_cst_embedding_entry> ptr;
+final class wire_cst_list_duplicate_span extends ffi.Struct {
+  external ffi.Pointer<wire_cst_duplicate_span> ptr;
 
   @ffi.Int32()
   external int len;
 }
 
-final class wire_cst_record_string_string extends ffi.Struct {
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> field0;
+final class wire_cst_duplicate_pair extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> a;
 
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> field1;
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> b;
+
+  @ffi.Float()
+  external double jaccard;
+
+  external ffi.Pointer<ffi.Float> cosine;
+
+  external ffi.Pointer<wire_cst_list_duplicate_span> spans;
 }
 
-final class wire_cst_list_record_string_string extends ffi.Struct {
-  external ffi.Pointer<wire_cst_record_string_string> ptr;
+final class wire_cst_list_duplicate_pair extends ffi.Struct {
+  external ffi.Pointer<wire_cst_duplicate_pair> ptr;
 
   @ffi.Int32()
   external int len;
 }
 
-final class wire_cst_list_String extends ffi.Struct {
-  external ffi.Pointer<ffi.Pointer<wire_cst_list_prim_u_8_strict>> ptr;
+final class wire_cst_duplicate_group extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_String> ids;
 
-  @ffi.Int32()
-  external int len;
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> canonical;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> canonical_note;
+
+  external ffi.Pointer<wire_cst_list_duplicate_pair> pairs;
 }
 
-final class wire_cst_list_prim_f_32_loose extends ffi.Struct {
-  external ffi.Pointer<ffi.Float> ptr;
+final class wire_cst_list_duplicate_group extends ffi.Struct {
+  external ffi.Pointer<wire_cst_duplicate_group> ptr;
 
   @ffi.Int32()
   external int len;
 }
 
-final class wire_cst_mcp_tool_call extends ffi.Struct {
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> tool;
+final class wire_cst_embedding_cluster extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_String> ids;
+}
 
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> parameters_json;
+final class wire_cst_list_embedding_cluster extends ffi.Struct {
+  external ffi.Pointer<wire_cst_embedding_cluster> ptr;
 
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> description;
+  @ffi.Int32()
+  external int len;
 }
 
-final class wire_cst_cluster_assignment extends ffi.Struct {
+final class wire_cst_hybrid_hit extends ffi.Struct {
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> id;
 
-  @ffi.UintPtr()
-  external int cluster_id;
+  @ffi.Float()
+  external double score;
 
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> color;
+  external ffi.Pointer<ffi.Float> semantic_score;
+
+  external ffi.Pointer<ffi.Uint32> semantic_rank;
+
+  external ffi.Pointer<ffi.Float> lexical_score;
+
+  external ffi.Pointer<ffi.Uint32> lexical_rank;
+
+  external ffi.Pointer<wire_cst_list_String> matched_terms;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> text_preview;
 }
 
-final class wire_cst_list_cluster_assignment extends ffi.Struct {
-  external ffi.Pointer<wire_cst_cluster_assignment> ptr;
+final class wire_cst_list_hybrid_hit extends ffi.Struct {
+  external ffi.Pointer<wire_cst_hybrid_hit> ptr;
 
   @ffi.Int32()
   external int len;
 }
 
-final class wire_cst_cluster_info extends ffi.Struct {
-  @ffi.UintPtr()
-  external int id;
+final class wire_cst_loaded_model_info extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> model_id;
 
-  @ffi.UintPtr()
-  external int size;
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> model_path;
 
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> color;
+  @ffi.Uint64()
+  external int size_bytes;
 
-  external ffi.Pointer<wire_cst_list_prim_f_32_strict> centroid;
+  @ffi.Bool()
+  external bool is_default;
 }
 
-final class wire_cst_list_cluster_info extends ffi.Struct {
-  external ffi.Pointer<wire_cst_cluster_info> ptr;
+final class wire_cst_list_loaded_model_info extends ffi.Struct {
+  external ffi.Pointer<wire_cst_loaded_model_info> ptr;
 
   @ffi.Int32()
   external int len;
 }
 
-final class wire_cst_embedding_cluster extends ffi.Struct {
-  external ffi.Pointer<wire_cst_list_String> ids;
+final class wire_cst_markdown_chunk extends ffi.Struct {
+  @ffi.UintPtr()
+  external int index;
+
+  external ffi.Pointer<wire_cst_list_String> heading_path;
+
+  @ffi.UintPtr()
+  external int start;
+
+  @ffi.UintPtr()
+  external int end;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> text;
+
+  @ffi.Uint32()
+  external int token_count;
 }
 
-final class wire_cst_list_embedding_cluster extends ffi.Struct {
-  external ffi.Pointer<wire_cst_embedding_cluster> ptr;
+final class wire_cst_list_markdown_chunk extends ffi.Struct {
+  external ffi.Pointer<wire_cst_markdown_chunk> ptr;
 
   @ffi.Int32()
   external int len;
@@ -3094,6 +9374,60 @@ final class wire_cst_list_node_position extends ffi.Struct {
   external int len;
 }
 
+final class wire_cst_list_prim_i_32_strict extends ffi.Struct {
+  external ffi.Pointer<ffi.Int32> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_rag_source extends ffi.Struct {
+  @ffi.Uint32()
+  external int citation;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> note_id;
+
+  external ffi.Pointer<ffi.UintPtr> chunk_offset;
+
+  external ffi.Pointer<ffi.UintPtr> chunk_index;
+
+  @ffi.Float()
+  external double score;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> text;
+
+  @ffi.Bool()
+  external bool cited;
+}
+
+final class wire_cst_list_rag_source extends ffi.Struct {
+  external ffi.Pointer<wire_cst_rag_source> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_reply_span extends ffi.Struct {
+  @ffi.UintPtr()
+  external int start;
+
+  @ffi.UintPtr()
+  external int end;
+
+  @ffi.Int32()
+  external int kind;
+
+  @ffi.UintPtr()
+  external int call_count;
+}
+
+final class wire_cst_list_reply_span extends ffi.Struct {
+  external ffi.Pointer<wire_cst_reply_span> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
 final class wire_cst_semantic_edge extends ffi.Struct {
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> source;
 
@@ -3129,6 +9463,17 @@ final class wire_cst_list_similarity_result extends ffi.Struct {
   external int len;
 }
 
+final class wire_cst_agent_run extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> answer;
+
+  @ffi.Int32()
+  external int stop_reason;
+
+  external ffi.Pointer<wire_cst_list_agent_step> steps;
+
+  external ffi.Pointer<wire_cst_list_record_string_string> messages;
+}
+
 final class wire_cst_clustering_result extends ffi.Struct {
   external ffi.Pointer<wire_cst_list_cluster_assignment> assignments;
 
@@ -3138,6 +9483,60 @@ final class wire_cst_clustering_result extends ffi.Struct {
   external int k;
 }
 
+final class wire_cst_generation_chunk extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> text;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> reasoning;
+
+  external ffi.Pointer<ffi.Int32> stop_reason;
+}
+
+final class wire_cst_generation_poll extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> text;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> reasoning;
+
+  external ffi.Pointer<wire_cst_generation_result> result;
+}
+
+final class wire_cst_gguf_metadata extends ffi.Struct {
+  @ffi.Uint32()
+  external int version;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> architecture;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> name;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> chat_template;
+
+  external ffi.Pointer<ffi.Uint64> context_length;
+
+  external ffi.Pointer<ffi.Uint64> embedding_length;
+
+  external ffi.Pointer<ffi.Uint64> block_count;
+
+  external ffi.Pointer<ffi.Uint32> pooling_type;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> quantization;
+
+  @ffi.Uint64()
+  external int parameter_count;
+
+  @ffi.Uint64()
+  external int tensor_count;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> tokenizer_model;
+
+  external ffi.Pointer<ffi.Uint64> vocab_size;
+
+  external ffi.Pointer<ffi.Uint32> bos_token_id;
+
+  external ffi.Pointer<ffi.Uint32> eos_token_id;
+
+  @ffi.Uint64()
+  external int file_size;
+}
+
 final class wire_cst_graph_state extends ffi.Struct {
   external ffi.Pointer<wire_cst_list_graph_node> nodes;
 
@@ -3157,13 +9556,41 @@ final class wire_cst_knowledge_graph_analysis extends ffi.Struct {
   external wire_cst_semantic_edge_result semantic_edges;
 }
 
-final class wire_cst_mcp_tool_result extends ffi.Struct {
-  @ffi.Bool()
-  external bool success;
+final class wire_cst_parsed_reply extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_mcp_tool_call> tool_calls;
 
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> result;
+  external ffi.Pointer<wire_cst_list_reply_span> spans;
 
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> tool;
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> text;
+}
+
+final class wire_cst_rag_answer extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> answer;
+
+  external ffi.Pointer<wire_cst_list_rag_source> sources;
+
+  @ffi.UintPtr()
+  external int skipped;
+}
+
+final class wire_cst_recall_report extends ffi.Struct {
+  @ffi.UintPtr()
+  external int queries;
+
+  @ffi.UintPtr()
+  external int top_k;
+
+  @ffi.Float()
+  external double recall;
+
+  @ffi.Float()
+  external double rescored_recall;
+
+  @ffi.UintPtr()
+  external int f32_bytes;
+
+  @ffi.UintPtr()
+  external int quantized_bytes;
 }
 
 final class wire_cst_stream_graph_stats extends ffi.Struct {
@@ -3176,3 +9603,45 @@ final class wire_cst_stream_graph_stats extends ffi.Struct {
   @ffi.UintPtr()
   external int visible_count;
 }
+
+final class wire_cst_summary extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> summary;
+
+  external ffi.Pointer<wire_cst_list_String> chunk_summaries;
+
+  @ffi.Uint32()
+  external int merge_levels;
+}
+
+final class wire_cst_tokenizer_info extends ffi.Struct {
+  @ffi.Uint32()
+  external int vocab_size;
+
+  @ffi.Uint32()
+  external int n_ctx_train;
+
+  @ffi.Uint32()
+  external int n_ctx;
+
+  @ffi.Int32()
+  external int bos_token;
+
+  @ffi.Int32()
+  external int eos_token;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> tokenizer_model;
+}
+
+final class wire_cst_vector_store_stats extends ffi.Struct {
+  @ffi.UintPtr()
+  external int entries;
+
+  @ffi.UintPtr()
+  external int dead_rows;
+
+  @ffi.UintPtr()
+  external int dimension;
+
+  @ffi.Uint64()
+  external int file_bytes;
+}
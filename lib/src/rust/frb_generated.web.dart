@@ -10,13 +10,31 @@ import 'dart:async';
 import 'dart:convert';
 
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated_web.dart';
+import 'package:kivixa/src/rust/agent.dart';
 import 'package:kivixa/src/rust/api.dart';
+import 'package:kivixa/src/rust/chunker.dart';
 import 'package:kivixa/src/rust/clustering.dart';
+import 'package:kivixa/src/rust/context_window.dart';
+import 'package:kivixa/src/rust/dedup.dart';
+import 'package:kivixa/src/rust/embedder.dart';
+import 'package:kivixa/src/rust/embedding_cache.dart';
 import 'package:kivixa/src/rust/embeddings.dart';
+import 'package:kivixa/src/rust/filtered_search.dart';
 import 'package:kivixa/src/rust/frb_generated.dart';
+import 'package:kivixa/src/rust/gguf.dart';
 import 'package:kivixa/src/rust/graph.dart';
+import 'package:kivixa/src/rust/hnsw.dart';
+import 'package:kivixa/src/rust/hybrid_search.dart';
+import 'package:kivixa/src/rust/inference.dart';
 import 'package:kivixa/src/rust/mcp.dart';
+import 'package:kivixa/src/rust/quantization.dart';
+import 'package:kivixa/src/rust/rag.dart';
+import 'package:kivixa/src/rust/reasoning.dart';
 import 'package:kivixa/src/rust/streaming.dart';
+import 'package:kivixa/src/rust/summarize.dart';
+import 'package:kivixa/src/rust/tokenizer.dart';
+import 'package:kivixa/src/rust/tool_parser.dart';
+import 'package:kivixa/src/rust/vector_store.dart';
 
 abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   RustLibApiImplPlatform({
@@ -29,24 +47,92 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   AnyhowException dco_decode_AnyhowException(dynamic raw);
 
+  @protected
+  RustStreamSink<AgentStep> dco_decode_StreamSink_agent_step_Dco(dynamic raw);
+
+  @protected
+  RustStreamSink<GenerationChunk> dco_decode_StreamSink_generation_chunk_Dco(
+    dynamic raw,
+  );
+
   @protected
   String dco_decode_String(dynamic raw);
 
+  @protected
+  AgentRun dco_decode_agent_run(dynamic raw);
+
+  @protected
+  AgentStep dco_decode_agent_step(dynamic raw);
+
+  @protected
+  AgentStopReason dco_decode_agent_stop_reason(dynamic raw);
+
   @protected
   bool dco_decode_bool(dynamic raw);
 
+  @protected
+  ContextPolicy dco_decode_box_autoadd_context_policy(dynamic raw);
+
+  @protected
+  ContextReport dco_decode_box_autoadd_context_report(dynamic raw);
+
+  @protected
+  DedupOptions dco_decode_box_autoadd_dedup_options(dynamic raw);
+
+  @protected
+  EmbeddingCacheStats dco_decode_box_autoadd_embedding_cache_stats(dynamic raw);
+
+  @protected
+  EmbeddingConfig dco_decode_box_autoadd_embedding_config(dynamic raw);
+
   @protected
   double dco_decode_box_autoadd_f_32(dynamic raw);
 
+  @protected
+  GenerationOptions dco_decode_box_autoadd_generation_options(dynamic raw);
+
+  @protected
+  GenerationResult dco_decode_box_autoadd_generation_result(dynamic raw);
+
+  @protected
+  HnswConfig dco_decode_box_autoadd_hnsw_config(dynamic raw);
+
+  @protected
+  HybridOptions dco_decode_box_autoadd_hybrid_options(dynamic raw);
+
   @protected
   int dco_decode_box_autoadd_i_32(dynamic raw);
 
+  @protected
+  PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw);
+
   @protected
   MCPToolCall dco_decode_box_autoadd_mcp_tool_call(dynamic raw);
 
+  @protected
+  NoteMetadata dco_decode_box_autoadd_note_metadata(dynamic raw);
+
+  @protected
+  ReasoningOptions dco_decode_box_autoadd_reasoning_options(dynamic raw);
+
+  @protected
+  SamplerConfig dco_decode_box_autoadd_sampler_config(dynamic raw);
+
+  @protected
+  SearchOptions dco_decode_box_autoadd_search_options(dynamic raw);
+
+  @protected
+  StopReason dco_decode_box_autoadd_stop_reason(dynamic raw);
+
+  @protected
+  SummaryOptions dco_decode_box_autoadd_summary_options(dynamic raw);
+
   @protected
   int dco_decode_box_autoadd_u_32(dynamic raw);
 
+  @protected
+  BigInt dco_decode_box_autoadd_u_64(dynamic raw);
+
   @protected
   BigInt dco_decode_box_autoadd_usize(dynamic raw);
 
@@ -59,15 +145,66 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   ClusteringResult dco_decode_clustering_result(dynamic raw);
 
+  @protected
+  ContextPolicy dco_decode_context_policy(dynamic raw);
+
+  @protected
+  ContextReport dco_decode_context_report(dynamic raw);
+
+  @protected
+  ContextStrategy dco_decode_context_strategy(dynamic raw);
+
+  @protected
+  DedupDocument dco_decode_dedup_document(dynamic raw);
+
+  @protected
+  DedupOptions dco_decode_dedup_options(dynamic raw);
+
+  @protected
+  DuplicateGroup dco_decode_duplicate_group(dynamic raw);
+
+  @protected
+  DuplicatePair dco_decode_duplicate_pair(dynamic raw);
+
+  @protected
+  DuplicateSpan dco_decode_duplicate_span(dynamic raw);
+
+  @protected
+  EmbeddingCacheStats dco_decode_embedding_cache_stats(dynamic raw);
+
   @protected
   EmbeddingCluster dco_decode_embedding_cluster(dynamic raw);
 
+  @protected
+  EmbeddingConfig dco_decode_embedding_config(dynamic raw);
+
   @protected
   EmbeddingEntry dco_decode_embedding_entry(dynamic raw);
 
+  @protected
+  EmbeddingKind dco_decode_embedding_kind(dynamic raw);
+
   @protected
   double dco_decode_f_32(dynamic raw);
 
+  @protected
+  FusionMode dco_decode_fusion_mode(dynamic raw);
+
+  @protected
+  GenerationChunk dco_decode_generation_chunk(dynamic raw);
+
+  @protected
+  GenerationOptions dco_decode_generation_options(dynamic raw);
+
+  @protected
+  GenerationPoll dco_decode_generation_poll(dynamic raw);
+
+  @protected
+  GenerationResult dco_decode_generation_result(dynamic raw);
+
+  @protected
+  GgufMetadata dco_decode_gguf_metadata(dynamic raw);
+
   @protected
   GraphEdge dco_decode_graph_edge(dynamic raw);
 
@@ -77,21 +214,48 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   GraphState dco_decode_graph_state(dynamic raw);
 
+  @protected
+  HnswConfig dco_decode_hnsw_config(dynamic raw);
+
+  @protected
+  HybridHit dco_decode_hybrid_hit(dynamic raw);
+
+  @protected
+  HybridOptions dco_decode_hybrid_options(dynamic raw);
+
   @protected
   int dco_decode_i_32(dynamic raw);
 
+  @protected
+  PlatformInt64 dco_decode_i_64(dynamic raw);
+
   @protected
   KnowledgeGraphAnalysis dco_decode_knowledge_graph_analysis(dynamic raw);
 
   @protected
   List<String> dco_decode_list_String(dynamic raw);
 
+  @protected
+  List<AgentStep> dco_decode_list_agent_step(dynamic raw);
+
   @protected
   List<ClusterAssignment> dco_decode_list_cluster_assignment(dynamic raw);
 
   @protected
   List<ClusterInfo> dco_decode_list_cluster_info(dynamic raw);
 
+  @protected
+  List<DedupDocument> dco_decode_list_dedup_document(dynamic raw);
+
+  @protected
+  List<DuplicateGroup> dco_decode_list_duplicate_group(dynamic raw);
+
+  @protected
+  List<DuplicatePair> dco_decode_list_duplicate_pair(dynamic raw);
+
+  @protected
+  List<DuplicateSpan> dco_decode_list_duplicate_span(dynamic raw);
+
   @protected
   List<EmbeddingCluster> dco_decode_list_embedding_cluster(dynamic raw);
 
@@ -104,12 +268,30 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   List<GraphNode> dco_decode_list_graph_node(dynamic raw);
 
+  @protected
+  List<HybridHit> dco_decode_list_hybrid_hit(dynamic raw);
+
+  @protected
+  List<Float32List> dco_decode_list_list_prim_f_32_strict(dynamic raw);
+
+  @protected
+  List<LoadedModelInfo> dco_decode_list_loaded_model_info(dynamic raw);
+
+  @protected
+  List<MarkdownChunk> dco_decode_list_markdown_chunk(dynamic raw);
+
   @protected
   List<MCPParameter> dco_decode_list_mcp_parameter(dynamic raw);
 
   @protected
   List<MCPTool> dco_decode_list_mcp_tool(dynamic raw);
 
+  @protected
+  List<MCPToolCall> dco_decode_list_mcp_tool_call(dynamic raw);
+
+  @protected
+  List<MCPToolResult> dco_decode_list_mcp_tool_result(dynamic raw);
+
   @protected
   List<NodePosition> dco_decode_list_node_position(dynamic raw);
 
@@ -119,18 +301,48 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   Float32List dco_decode_list_prim_f_32_strict(dynamic raw);
 
+  @protected
+  List<int> dco_decode_list_prim_i_32_loose(dynamic raw);
+
+  @protected
+  Int32List dco_decode_list_prim_i_32_strict(dynamic raw);
+
+  @protected
+  Uint32List dco_decode_list_prim_u_32_strict(dynamic raw);
+
   @protected
   Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
 
+  @protected
+  List<RagSource> dco_decode_list_rag_source(dynamic raw);
+
   @protected
   List<(String, String)> dco_decode_list_record_string_string(dynamic raw);
 
+  @protected
+  List<ReplySpan> dco_decode_list_reply_span(dynamic raw);
+
   @protected
   List<SemanticEdge> dco_decode_list_semantic_edge(dynamic raw);
 
   @protected
   List<SimilarityResult> dco_decode_list_similarity_result(dynamic raw);
 
+  @protected
+  List<TokenCandidate> dco_decode_list_token_candidate(dynamic raw);
+
+  @protected
+  List<TokenLogprob> dco_decode_list_token_logprob(dynamic raw);
+
+  @protected
+  List<TranscriptSegment> dco_decode_list_transcript_segment(dynamic raw);
+
+  @protected
+  LoadedModelInfo dco_decode_loaded_model_info(dynamic raw);
+
+  @protected
+  MarkdownChunk dco_decode_markdown_chunk(dynamic raw);
+
   @protected
   MCPParamType dco_decode_mcp_param_type(dynamic raw);
 
@@ -150,588 +362,1831 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   NodePosition dco_decode_node_position(dynamic raw);
 
   @protected
-  String? dco_decode_opt_String(dynamic raw);
+  NoteMetadata dco_decode_note_metadata(dynamic raw);
 
   @protected
-  double? dco_decode_opt_box_autoadd_f_32(dynamic raw);
+  String? dco_decode_opt_String(dynamic raw);
 
   @protected
-  int? dco_decode_opt_box_autoadd_i_32(dynamic raw);
+  ContextPolicy? dco_decode_opt_box_autoadd_context_policy(dynamic raw);
 
   @protected
-  int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
+  ContextReport? dco_decode_opt_box_autoadd_context_report(dynamic raw);
 
   @protected
-  BigInt? dco_decode_opt_box_autoadd_usize(dynamic raw);
+  DedupOptions? dco_decode_opt_box_autoadd_dedup_options(dynamic raw);
 
   @protected
-  List<String>? dco_decode_opt_list_String(dynamic raw);
+  EmbeddingCacheStats? dco_decode_opt_box_autoadd_embedding_cache_stats(
+    dynamic raw,
+  );
 
   @protected
-  Float32List? dco_decode_opt_list_prim_f_32_strict(dynamic raw);
+  EmbeddingConfig? dco_decode_opt_box_autoadd_embedding_config(dynamic raw);
 
   @protected
-  List<(String, String)>? dco_decode_opt_list_record_string_string(dynamic raw);
+  double? dco_decode_opt_box_autoadd_f_32(dynamic raw);
 
   @protected
-  (String, String) dco_decode_record_string_string(dynamic raw);
+  GenerationResult? dco_decode_opt_box_autoadd_generation_result(dynamic raw);
 
   @protected
-  SemanticEdge dco_decode_semantic_edge(dynamic raw);
+  HnswConfig? dco_decode_opt_box_autoadd_hnsw_config(dynamic raw);
 
   @protected
-  SemanticEdgeResult dco_decode_semantic_edge_result(dynamic raw);
+  HybridOptions? dco_decode_opt_box_autoadd_hybrid_options(dynamic raw);
 
   @protected
-  SimilarityResult dco_decode_similarity_result(dynamic raw);
+  int? dco_decode_opt_box_autoadd_i_32(dynamic raw);
 
   @protected
-  StreamGraphStats dco_decode_stream_graph_stats(dynamic raw);
+  PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw);
 
   @protected
-  TaskCategory dco_decode_task_category(dynamic raw);
+  NoteMetadata? dco_decode_opt_box_autoadd_note_metadata(dynamic raw);
 
   @protected
-  int dco_decode_u_32(dynamic raw);
+  ReasoningOptions? dco_decode_opt_box_autoadd_reasoning_options(dynamic raw);
 
   @protected
-  int dco_decode_u_8(dynamic raw);
+  SamplerConfig? dco_decode_opt_box_autoadd_sampler_config(dynamic raw);
 
   @protected
-  void dco_decode_unit(dynamic raw);
+  StopReason? dco_decode_opt_box_autoadd_stop_reason(dynamic raw);
 
   @protected
-  BigInt dco_decode_usize(dynamic raw);
+  SummaryOptions? dco_decode_opt_box_autoadd_summary_options(dynamic raw);
 
   @protected
-  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
+  int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
 
   @protected
-  String sse_decode_String(SseDeserializer deserializer);
+  BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw);
 
   @protected
-  bool sse_decode_bool(SseDeserializer deserializer);
+  BigInt? dco_decode_opt_box_autoadd_usize(dynamic raw);
 
   @protected
-  double sse_decode_box_autoadd_f_32(SseDeserializer deserializer);
+  List<String>? dco_decode_opt_list_String(dynamic raw);
 
   @protected
-  int sse_decode_box_autoadd_i_32(SseDeserializer deserializer);
+  List<Float32List>? dco_decode_opt_list_list_prim_f_32_strict(dynamic raw);
 
   @protected
-  MCPToolCall sse_decode_box_autoadd_mcp_tool_call(
-    SseDeserializer deserializer,
-  );
+  Float32List? dco_decode_opt_list_prim_f_32_strict(dynamic raw);
 
   @protected
-  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
+  List<(String, String)>? dco_decode_opt_list_record_string_string(dynamic raw);
 
   @protected
-  BigInt sse_decode_box_autoadd_usize(SseDeserializer deserializer);
+  List<TokenLogprob>? dco_decode_opt_list_token_logprob(dynamic raw);
 
   @protected
-  ClusterAssignment sse_decode_cluster_assignment(SseDeserializer deserializer);
+  ParsedReply dco_decode_parsed_reply(dynamic raw);
 
   @protected
-  ClusterInfo sse_decode_cluster_info(SseDeserializer deserializer);
+  PoolingMode dco_decode_pooling_mode(dynamic raw);
 
   @protected
-  ClusteringResult sse_decode_clustering_result(SseDeserializer deserializer);
+  QuantizationKind dco_decode_quantization_kind(dynamic raw);
 
   @protected
-  EmbeddingCluster sse_decode_embedding_cluster(SseDeserializer deserializer);
+  RagAnswer dco_decode_rag_answer(dynamic raw);
 
   @protected
-  EmbeddingEntry sse_decode_embedding_entry(SseDeserializer deserializer);
+  RagSource dco_decode_rag_source(dynamic raw);
 
   @protected
-  double sse_decode_f_32(SseDeserializer deserializer);
+  ReasoningOptions dco_decode_reasoning_options(dynamic raw);
 
   @protected
-  GraphEdge sse_decode_graph_edge(SseDeserializer deserializer);
+  RecallReport dco_decode_recall_report(dynamic raw);
 
   @protected
-  GraphNode sse_decode_graph_node(SseDeserializer deserializer);
+  (String, String) dco_decode_record_string_string(dynamic raw);
 
   @protected
-  GraphState sse_decode_graph_state(SseDeserializer deserializer);
+  ReplySpan dco_decode_reply_span(dynamic raw);
 
   @protected
-  int sse_decode_i_32(SseDeserializer deserializer);
+  ReplySpanKind dco_decode_reply_span_kind(dynamic raw);
 
   @protected
-  KnowledgeGraphAnalysis sse_decode_knowledge_graph_analysis(
-    SseDeserializer deserializer,
-  );
+  SamplerConfig dco_decode_sampler_config(dynamic raw);
 
   @protected
-  List<String> sse_decode_list_String(SseDeserializer deserializer);
+  SearchOptions dco_decode_search_options(dynamic raw);
 
   @protected
-  List<ClusterAssignment> sse_decode_list_cluster_assignment(
-    SseDeserializer deserializer,
-  );
+  SemanticEdge dco_decode_semantic_edge(dynamic raw);
 
   @protected
-  List<ClusterInfo> sse_decode_list_cluster_info(SseDeserializer deserializer);
+  SemanticEdgeResult dco_decode_semantic_edge_result(dynamic raw);
 
   @protected
-  List<EmbeddingCluster> sse_decode_list_embedding_cluster(
-    SseDeserializer deserializer,
-  );
+  SimilarityResult dco_decode_similarity_result(dynamic raw);
 
   @protected
-  List<EmbeddingEntry> sse_decode_list_embedding_entry(
-    SseDeserializer deserializer,
-  );
+  StopReason dco_decode_stop_reason(dynamic raw);
 
   @protected
-  List<GraphEdge> sse_decode_list_graph_edge(SseDeserializer deserializer);
+  StreamGraphStats dco_decode_stream_graph_stats(dynamic raw);
 
   @protected
-  List<GraphNode> sse_decode_list_graph_node(SseDeserializer deserializer);
+  Summary dco_decode_summary(dynamic raw);
 
   @protected
-  List<MCPParameter> sse_decode_list_mcp_parameter(
-    SseDeserializer deserializer,
-  );
+  SummaryOptions dco_decode_summary_options(dynamic raw);
 
   @protected
-  List<MCPTool> sse_decode_list_mcp_tool(SseDeserializer deserializer);
+  SummaryStyle dco_decode_summary_style(dynamic raw);
 
   @protected
-  List<NodePosition> sse_decode_list_node_position(
-    SseDeserializer deserializer,
-  );
+  TaskCategory dco_decode_task_category(dynamic raw);
 
   @protected
-  List<double> sse_decode_list_prim_f_32_loose(SseDeserializer deserializer);
+  TokenCandidate dco_decode_token_candidate(dynamic raw);
 
   @protected
-  Float32List sse_decode_list_prim_f_32_strict(SseDeserializer deserializer);
+  TokenLogprob dco_decode_token_logprob(dynamic raw);
 
   @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+  TokenizerInfo dco_decode_tokenizer_info(dynamic raw);
 
   @protected
-  List<(String, String)> sse_decode_list_record_string_string(
-    SseDeserializer deserializer,
-  );
+  TranscriptSegment dco_decode_transcript_segment(dynamic raw);
 
   @protected
-  List<SemanticEdge> sse_decode_list_semantic_edge(
-    SseDeserializer deserializer,
-  );
+  int dco_decode_u_32(dynamic raw);
 
   @protected
-  List<SimilarityResult> sse_decode_list_similarity_result(
-    SseDeserializer deserializer,
-  );
+  BigInt dco_decode_u_64(dynamic raw);
 
   @protected
-  MCPParamType sse_decode_mcp_param_type(SseDeserializer deserializer);
+  int dco_decode_u_8(dynamic raw);
 
   @protected
-  MCPParameter sse_decode_mcp_parameter(SseDeserializer deserializer);
+  void dco_decode_unit(dynamic raw);
 
   @protected
-  MCPTool sse_decode_mcp_tool(SseDeserializer deserializer);
+  BigInt dco_decode_usize(dynamic raw);
 
   @protected
-  MCPToolCall sse_decode_mcp_tool_call(SseDeserializer deserializer);
+  VectorStoreStats dco_decode_vector_store_stats(dynamic raw);
 
   @protected
-  MCPToolResult sse_decode_mcp_tool_result(SseDeserializer deserializer);
+  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
 
   @protected
-  NodePosition sse_decode_node_position(SseDeserializer deserializer);
+  RustStreamSink<AgentStep> sse_decode_StreamSink_agent_step_Dco(
+    SseDeserializer deserializer,
+  );
 
   @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer);
+  RustStreamSink<GenerationChunk> sse_decode_StreamSink_generation_chunk_Dco(
+    SseDeserializer deserializer,
+  );
 
   @protected
-  double? sse_decode_opt_box_autoadd_f_32(SseDeserializer deserializer);
+  String sse_decode_String(SseDeserializer deserializer);
 
   @protected
-  int? sse_decode_opt_box_autoadd_i_32(SseDeserializer deserializer);
+  AgentRun sse_decode_agent_run(SseDeserializer deserializer);
 
   @protected
-  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
+  AgentStep sse_decode_agent_step(SseDeserializer deserializer);
 
   @protected
-  BigInt? sse_decode_opt_box_autoadd_usize(SseDeserializer deserializer);
+  AgentStopReason sse_decode_agent_stop_reason(SseDeserializer deserializer);
 
   @protected
-  List<String>? sse_decode_opt_list_String(SseDeserializer deserializer);
+  bool sse_decode_bool(SseDeserializer deserializer);
 
   @protected
-  Float32List? sse_decode_opt_list_prim_f_32_strict(
+  ContextPolicy sse_decode_box_autoadd_context_policy(
     SseDeserializer deserializer,
   );
 
   @protected
-  List<(String, String)>? sse_decode_opt_list_record_string_string(
+  ContextReport sse_decode_box_autoadd_context_report(
     SseDeserializer deserializer,
   );
 
   @protected
-  (String, String) sse_decode_record_string_string(
+  DedupOptions sse_decode_box_autoadd_dedup_options(
     SseDeserializer deserializer,
   );
 
   @protected
-  SemanticEdge sse_decode_semantic_edge(SseDeserializer deserializer);
+  EmbeddingCacheStats sse_decode_box_autoadd_embedding_cache_stats(
+    SseDeserializer deserializer,
+  );
 
   @protected
-  SemanticEdgeResult sse_decode_semantic_edge_result(
+  EmbeddingConfig sse_decode_box_autoadd_embedding_config(
     SseDeserializer deserializer,
   );
 
   @protected
-  SimilarityResult sse_decode_similarity_result(SseDeserializer deserializer);
+  double sse_decode_box_autoadd_f_32(SseDeserializer deserializer);
 
   @protected
-  StreamGraphStats sse_decode_stream_graph_stats(SseDeserializer deserializer);
+  GenerationOptions sse_decode_box_autoadd_generation_options(
+    SseDeserializer deserializer,
+  );
 
   @protected
-  TaskCategory sse_decode_task_category(SseDeserializer deserializer);
+  GenerationResult sse_decode_box_autoadd_generation_result(
+    SseDeserializer deserializer,
+  );
 
   @protected
-  int sse_decode_u_32(SseDeserializer deserializer);
+  HnswConfig sse_decode_box_autoadd_hnsw_config(SseDeserializer deserializer);
+
+  @protected
+  HybridOptions sse_decode_box_autoadd_hybrid_options(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  int sse_decode_box_autoadd_i_32(SseDeserializer deserializer);
+
+  @protected
+  PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer);
+
+  @protected
+  MCPToolCall sse_decode_box_autoadd_mcp_tool_call(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  NoteMetadata sse_decode_box_autoadd_note_metadata(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ReasoningOptions sse_decode_box_autoadd_reasoning_options(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  SamplerConfig sse_decode_box_autoadd_sampler_config(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  SearchOptions sse_decode_box_autoadd_search_options(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  StopReason sse_decode_box_autoadd_stop_reason(SseDeserializer deserializer);
+
+  @protected
+  SummaryOptions sse_decode_box_autoadd_summary_options(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
+
+  @protected
+  BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer);
+
+  @protected
+  BigInt sse_decode_box_autoadd_usize(SseDeserializer deserializer);
+
+  @protected
+  ClusterAssignment sse_decode_cluster_assignment(SseDeserializer deserializer);
+
+  @protected
+  ClusterInfo sse_decode_cluster_info(SseDeserializer deserializer);
+
+  @protected
+  ClusteringResult sse_decode_clustering_result(SseDeserializer deserializer);
+
+  @protected
+  ContextPolicy sse_decode_context_policy(SseDeserializer deserializer);
+
+  @protected
+  ContextReport sse_decode_context_report(SseDeserializer deserializer);
+
+  @protected
+  ContextStrategy sse_decode_context_strategy(SseDeserializer deserializer);
+
+  @protected
+  DedupDocument sse_decode_dedup_document(SseDeserializer deserializer);
+
+  @protected
+  DedupOptions sse_decode_dedup_options(SseDeserializer deserializer);
+
+  @protected
+  DuplicateGroup sse_decode_duplicate_group(SseDeserializer deserializer);
+
+  @protected
+  DuplicatePair sse_decode_duplicate_pair(SseDeserializer deserializer);
+
+  @protected
+  DuplicateSpan sse_decode_duplicate_span(SseDeserializer deserializer);
+
+  @protected
+  EmbeddingCacheStats sse_decode_embedding_cache_stats(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  EmbeddingCluster sse_decode_embedding_cluster(SseDeserializer deserializer);
+
+  @protected
+  EmbeddingConfig sse_decode_embedding_config(SseDeserializer deserializer);
+
+  @protected
+  EmbeddingEntry sse_decode_embedding_entry(SseDeserializer deserializer);
+
+  @protected
+  EmbeddingKind sse_decode_embedding_kind(SseDeserializer deserializer);
+
+  @protected
+  double sse_decode_f_32(SseDeserializer deserializer);
+
+  @protected
+  FusionMode sse_decode_fusion_mode(SseDeserializer deserializer);
+
+  @protected
+  GenerationChunk sse_decode_generation_chunk(SseDeserializer deserializer);
+
+  @protected
+  GenerationOptions sse_decode_generation_options(SseDeserializer deserializer);
+
+  @protected
+  GenerationPoll sse_decode_generation_poll(SseDeserializer deserializer);
+
+  @protected
+  GenerationResult sse_decode_generation_result(SseDeserializer deserializer);
+
+  @protected
+  GgufMetadata sse_decode_gguf_metadata(SseDeserializer deserializer);
+
+  @protected
+  GraphEdge sse_decode_graph_edge(SseDeserializer deserializer);
+
+  @protected
+  GraphNode sse_decode_graph_node(SseDeserializer deserializer);
+
+  @protected
+  GraphState sse_decode_graph_state(SseDeserializer deserializer);
+
+  @protected
+  HnswConfig sse_decode_hnsw_config(SseDeserializer deserializer);
+
+  @protected
+  HybridHit sse_decode_hybrid_hit(SseDeserializer deserializer);
+
+  @protected
+  HybridOptions sse_decode_hybrid_options(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_i_32(SseDeserializer deserializer);
+
+  @protected
+  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer);
+
+  @protected
+  KnowledgeGraphAnalysis sse_decode_knowledge_graph_analysis(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<String> sse_decode_list_String(SseDeserializer deserializer);
+
+  @protected
+  List<AgentStep> sse_decode_list_agent_step(SseDeserializer deserializer);
+
+  @protected
+  List<ClusterAssignment> sse_decode_list_cluster_assignment(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<ClusterInfo> sse_decode_list_cluster_info(SseDeserializer deserializer);
+
+  @protected
+  List<DedupDocument> sse_decode_list_dedup_document(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<DuplicateGroup> sse_decode_list_duplicate_group(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<DuplicatePair> sse_decode_list_duplicate_pair(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<DuplicateSpan> sse_decode_list_duplicate_span(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<EmbeddingCluster> sse_decode_list_embedding_cluster(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<EmbeddingEntry> sse_decode_list_embedding_entry(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<GraphEdge> sse_decode_list_graph_edge(SseDeserializer deserializer);
+
+  @protected
+  List<GraphNode> sse_decode_list_graph_node(SseDeserializer deserializer);
+
+  @protected
+  List<HybridHit> sse_decode_list_hybrid_hit(SseDeserializer deserializer);
+
+  @protected
+  List<Float32List> sse_decode_list_list_prim_f_32_strict(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<LoadedModelInfo> sse_decode_list_loaded_model_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<MarkdownChunk> sse_decode_list_markdown_chunk(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<MCPParameter> sse_decode_list_mcp_parameter(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<MCPTool> sse_decode_list_mcp_tool(SseDeserializer deserializer);
+
+  @protected
+  List<MCPToolCall> sse_decode_list_mcp_tool_call(SseDeserializer deserializer);
+
+  @protected
+  List<MCPToolResult> sse_decode_list_mcp_tool_result(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<NodePosition> sse_decode_list_node_position(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<double> sse_decode_list_prim_f_32_loose(SseDeserializer deserializer);
+
+  @protected
+  Float32List sse_decode_list_prim_f_32_strict(SseDeserializer deserializer);
+
+  @protected
+  List<int> sse_decode_list_prim_i_32_loose(SseDeserializer deserializer);
+
+  @protected
+  Int32List sse_decode_list_prim_i_32_strict(SseDeserializer deserializer);
+
+  @protected
+  Uint32List sse_decode_list_prim_u_32_strict(SseDeserializer deserializer);
+
+  @protected
+  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+
+  @protected
+  List<RagSource> sse_decode_list_rag_source(SseDeserializer deserializer);
+
+  @protected
+  List<(String, String)> sse_decode_list_record_string_string(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<ReplySpan> sse_decode_list_reply_span(SseDeserializer deserializer);
+
+  @protected
+  List<SemanticEdge> sse_decode_list_semantic_edge(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<SimilarityResult> sse_decode_list_similarity_result(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<TokenCandidate> sse_decode_list_token_candidate(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<TokenLogprob> sse_decode_list_token_logprob(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<TranscriptSegment> sse_decode_list_transcript_segment(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  LoadedModelInfo sse_decode_loaded_model_info(SseDeserializer deserializer);
+
+  @protected
+  MarkdownChunk sse_decode_markdown_chunk(SseDeserializer deserializer);
+
+  @protected
+  MCPParamType sse_decode_mcp_param_type(SseDeserializer deserializer);
+
+  @protected
+  MCPParameter sse_decode_mcp_parameter(SseDeserializer deserializer);
+
+  @protected
+  MCPTool sse_decode_mcp_tool(SseDeserializer deserializer);
+
+  @protected
+  MCPToolCall sse_decode_mcp_tool_call(SseDeserializer deserializer);
+
+  @protected
+  MCPToolResult sse_decode_mcp_tool_result(SseDeserializer deserializer);
+
+  @protected
+  NodePosition sse_decode_node_position(SseDeserializer deserializer);
+
+  @protected
+  NoteMetadata sse_decode_note_metadata(SseDeserializer deserializer);
+
+  @protected
+  String? sse_decode_opt_String(SseDeserializer deserializer);
+
+  @protected
+  ContextPolicy? sse_decode_opt_box_autoadd_context_policy(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ContextReport? sse_decode_opt_box_autoadd_context_report(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  DedupOptions? sse_decode_opt_box_autoadd_dedup_options(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  EmbeddingCacheStats? sse_decode_opt_box_autoadd_embedding_cache_stats(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  EmbeddingConfig? sse_decode_opt_box_autoadd_embedding_config(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  double? sse_decode_opt_box_autoadd_f_32(SseDeserializer deserializer);
+
+  @protected
+  GenerationResult? sse_decode_opt_box_autoadd_generation_result(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  HnswConfig? sse_decode_opt_box_autoadd_hnsw_config(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  HybridOptions? sse_decode_opt_box_autoadd_hybrid_options(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  int? sse_decode_opt_box_autoadd_i_32(SseDeserializer deserializer);
+
+  @protected
+  PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer);
+
+  @protected
+  NoteMetadata? sse_decode_opt_box_autoadd_note_metadata(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ReasoningOptions? sse_decode_opt_box_autoadd_reasoning_options(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  SamplerConfig? sse_decode_opt_box_autoadd_sampler_config(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  StopReason? sse_decode_opt_box_autoadd_stop_reason(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  SummaryOptions? sse_decode_opt_box_autoadd_summary_options(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
+
+  @protected
+  BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer);
+
+  @protected
+  BigInt? sse_decode_opt_box_autoadd_usize(SseDeserializer deserializer);
+
+  @protected
+  List<String>? sse_decode_opt_list_String(SseDeserializer deserializer);
+
+  @protected
+  List<Float32List>? sse_decode_opt_list_list_prim_f_32_strict(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  Float32List? sse_decode_opt_list_prim_f_32_strict(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<(String, String)>? sse_decode_opt_list_record_string_string(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<TokenLogprob>? sse_decode_opt_list_token_logprob(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ParsedReply sse_decode_parsed_reply(SseDeserializer deserializer);
+
+  @protected
+  PoolingMode sse_decode_pooling_mode(SseDeserializer deserializer);
+
+  @protected
+  QuantizationKind sse_decode_quantization_kind(SseDeserializer deserializer);
+
+  @protected
+  RagAnswer sse_decode_rag_answer(SseDeserializer deserializer);
+
+  @protected
+  RagSource sse_decode_rag_source(SseDeserializer deserializer);
+
+  @protected
+  ReasoningOptions sse_decode_reasoning_options(SseDeserializer deserializer);
+
+  @protected
+  RecallReport sse_decode_recall_report(SseDeserializer deserializer);
+
+  @protected
+  (String, String) sse_decode_record_string_string(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ReplySpan sse_decode_reply_span(SseDeserializer deserializer);
+
+  @protected
+  ReplySpanKind sse_decode_reply_span_kind(SseDeserializer deserializer);
+
+  @protected
+  SamplerConfig sse_decode_sampler_config(SseDeserializer deserializer);
+
+  @protected
+  SearchOptions sse_decode_search_options(SseDeserializer deserializer);
+
+  @protected
+  SemanticEdge sse_decode_semantic_edge(SseDeserializer deserializer);
+
+  @protected
+  SemanticEdgeResult sse_decode_semantic_edge_result(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  SimilarityResult sse_decode_similarity_result(SseDeserializer deserializer);
+
+  @protected
+  StopReason sse_decode_stop_reason(SseDeserializer deserializer);
+
+  @protected
+  StreamGraphStats sse_decode_stream_graph_stats(SseDeserializer deserializer);
+
+  @protected
+  Summary sse_decode_summary(SseDeserializer deserializer);
+
+  @protected
+  SummaryOptions sse_decode_summary_options(SseDeserializer deserializer);
+
+  @protected
+  SummaryStyle sse_decode_summary_style(SseDeserializer deserializer);
+
+  @protected
+  TaskCategory sse_decode_task_category(SseDeserializer deserializer);
+
+  @protected
+  TokenCandidate sse_decode_token_candidate(SseDeserializer deserializer);
+
+  @protected
+  TokenLogprob sse_decode_token_logprob(SseDeserializer deserializer);
+
+  @protected
+  TokenizerInfo sse_decode_tokenizer_info(SseDeserializer deserializer);
+
+  @protected
+  TranscriptSegment sse_decode_transcript_segment(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_u_32(SseDeserializer deserializer);
+
+  @protected
+  BigInt sse_decode_u_64(SseDeserializer deserializer);
 
   @protected
   int sse_decode_u_8(SseDeserializer deserializer);
 
   @protected
-  void sse_decode_unit(SseDeserializer deserializer);
+  void sse_decode_unit(SseDeserializer deserializer);
+
+  @protected
+  BigInt sse_decode_usize(SseDeserializer deserializer);
+
+  @protected
+  VectorStoreStats sse_decode_vector_store_stats(SseDeserializer deserializer);
+
+  @protected
+  String cst_encode_AnyhowException(AnyhowException raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  String cst_encode_StreamSink_agent_step_Dco(RustStreamSink<AgentStep> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_String(
+      raw.setupAndSerialize(
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_agent_step,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+      ),
+    );
+  }
+
+  @protected
+  String cst_encode_StreamSink_generation_chunk_Dco(
+    RustStreamSink<GenerationChunk> raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_String(
+      raw.setupAndSerialize(
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_generation_chunk,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+      ),
+    );
+  }
+
+  @protected
+  String cst_encode_String(String raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw;
+  }
+
+  @protected
+  JSAny cst_encode_agent_run(AgentRun raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return [
+      cst_encode_String(raw.answer),
+      cst_encode_agent_stop_reason(raw.stopReason),
+      cst_encode_list_agent_step(raw.steps),
+      cst_encode_list_record_string_string(raw.messages),
+    ].jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_agent_step(AgentStep raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return [
+      cst_encode_String(raw.reply),
+      cst_encode_list_mcp_tool_call(raw.toolCalls),
+      cst_encode_list_mcp_tool_result(raw.results),
+    ].jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_box_autoadd_context_policy(ContextPolicy raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_context_policy(raw);
+  }
+
+  @protected
+  JSAny cst_encode_box_autoadd_context_report(ContextReport raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_context_report(raw);
+  }
+
+  @protected
+  JSAny cst_encode_box_autoadd_dedup_options(DedupOptions raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_dedup_options(raw);
+  }
+
+  @protected
+  JSAny cst_encode_box_autoadd_embedding_cache_stats(EmbeddingCacheStats raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_embedding_cache_stats(raw);
+  }
+
+  @protected
+  JSAny cst_encode_box_autoadd_embedding_config(EmbeddingConfig raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_embedding_config(raw);
+  }
+
+  @protected
+  double cst_encode_box_autoadd_f_32(double raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_f_32(raw);
+  }
+
+  @protected
+  JSAny cst_encode_box_autoadd_generation_options(GenerationOptions raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_generation_options(raw);
+  }
+
+  @protected
+  JSAny cst_encode_box_autoadd_generation_result(GenerationResult raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_generation_result(raw);
+  }
+
+  @protected
+  JSAny cst_encode_box_autoadd_hnsw_config(HnswConfig raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_hnsw_config(raw);
+  }
+
+  @protected
+  JSAny cst_encode_box_autoadd_hybrid_options(HybridOptions raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_hybrid_options(raw);
+  }
+
+  @protected
+  int cst_encode_box_autoadd_i_32(int raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_i_32(raw);
+  }
+
+  @protected
+  JSAny cst_encode_box_autoadd_i_64(PlatformInt64 raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_i_64(raw);
+  }
+
+  @protected
+  JSAny cst_encode_box_autoadd_mcp_tool_call(MCPToolCall raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_mcp_tool_call(raw);
+  }
+
+  @protected
+  JSAny cst_encode_box_autoadd_note_metadata(NoteMetadata raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_note_metadata(raw);
+  }
+
+  @protected
+  JSAny cst_encode_box_autoadd_reasoning_options(ReasoningOptions raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_reasoning_options(raw);
+  }
+
+  @protected
+  JSAny cst_encode_box_autoadd_sampler_config(SamplerConfig raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_sampler_config(raw);
+  }
+
+  @protected
+  JSAny cst_encode_box_autoadd_search_options(SearchOptions raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_search_options(raw);
+  }
+
+  @protected
+  int cst_encode_box_autoadd_stop_reason(StopReason raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_stop_reason(raw);
+  }
+
+  @protected
+  JSAny cst_encode_box_autoadd_summary_options(SummaryOptions raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_summary_options(raw);
+  }
+
+  @protected
+  int cst_encode_box_autoadd_u_32(int raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_u_32(raw);
+  }
+
+  @protected
+  JSAny cst_encode_box_autoadd_u_64(BigInt raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_u_64(raw);
+  }
+
+  @protected
+  JSAny cst_encode_box_autoadd_usize(BigInt raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_usize(raw);
+  }
+
+  @protected
+  JSAny cst_encode_cluster_assignment(ClusterAssignment raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return [
+      cst_encode_String(raw.id),
+      cst_encode_usize(raw.clusterId),
+      cst_encode_String(raw.color),
+    ].jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_cluster_info(ClusterInfo raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return [
+      cst_encode_usize(raw.id),
+      cst_encode_usize(raw.size),
+      cst_encode_String(raw.color),
+      cst_encode_opt_list_prim_f_32_strict(raw.centroid),
+    ].jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_clustering_result(ClusteringResult raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return [
+      cst_encode_list_cluster_assignment(raw.assignments),
+      cst_encode_list_cluster_info(raw.clusters),
+      cst_encode_usize(raw.k),
+    ].jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_context_policy(ContextPolicy raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return [
+      cst_encode_context_strategy(raw.strategy),
+      cst_encode_u_32(raw.summaryMaxTokens),
+    ].jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_context_report(ContextReport raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return [
+      cst_encode_u_32(raw.contextSize),
+      cst_encode_u_32(raw.promptTokens),
+      cst_encode_list_prim_u_32_strict(raw.messageTokens),
+      cst_encode_list_prim_u_32_strict(raw.dropped),
+      cst_encode_list_prim_u_32_strict(raw.truncated),
+      cst_encode_list_prim_u_32_strict(raw.summarized),
+      cst_encode_opt_String(raw.summary),
+    ].jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_dedup_document(DedupDocument raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return [
+      cst_encode_String(raw.id),
+      cst_encode_String(raw.text),
+      cst_encode_opt_list_prim_f_32_strict(raw.vector),
+      cst_encode_opt_box_autoadd_i_64(raw.createdAt),
+    ].jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_dedup_options(DedupOptions raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return [
+      cst_encode_usize(raw.shingleWords),
+      cst_encode_usize(raw.numHashes),
+      cst_encode_usize(raw.bands),
+      cst_encode_f_32(raw.minJaccard),
+      cst_encode_f_32(raw.minCosine),
+      cst_encode_usize(raw.minSpanWords),
+    ].jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_duplicate_group(DuplicateGroup raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return [
+      cst_encode_list_String(raw.ids),
+      cst_encode_String(raw.canonical),
+      cst_encode_String(raw.canonicalNote),
+      cst_encode_list_duplicate_pair(raw.pairs),
+    ].jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_duplicate_pair(DuplicatePair raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return [
+      cst_encode_String(raw.a),
+      cst_encode_String(raw.b),
+      cst_encode_f_32(raw.jaccard),
+      cst_encode_opt_box_autoadd_f_32(raw.cosine),
+      cst_encode_list_duplicate_span(raw.spans),
+    ].jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_duplicate_span(DuplicateSpan raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return [
+      cst_encode_usize(raw.aStart),
+      cst_encode_usize(raw.aEnd),
+      cst_encode_usize(raw.bStart),
+      cst_encode_usize(raw.bEnd),
+      cst_encode_usize(raw.words),
+    ].jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_embedding_cache_stats(EmbeddingCacheStats raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return [
+      cst_encode_usize(raw.entries),
+      cst_encode_usize(raw.staleRecords),
+      cst_encode_u_64(raw.fileBytes),
+    ].jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_embedding_cluster(EmbeddingCluster raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return [cst_encode_list_String(raw.ids)].jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_embedding_config(EmbeddingConfig raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return [
+      cst_encode_u_32(raw.nGpuLayers),
+      cst_encode_i_32(raw.nThreads),
+      cst_encode_pooling_mode(raw.pooling),
+      cst_encode_u_32(raw.chunkTokens),
+      cst_encode_u_32(raw.chunkOverlap),
+      cst_encode_u_32(raw.maxSequences),
+      cst_encode_String(raw.queryPrefix),
+      cst_encode_String(raw.documentPrefix),
+    ].jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_embedding_entry(EmbeddingEntry raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return [
+      cst_encode_String(raw.id),
+      cst_encode_list_prim_f_32_strict(raw.vector),
+      cst_encode_opt_String(raw.textPreview),
+      cst_encode_note_metadata(raw.metadata),
+    ].jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_generation_chunk(GenerationChunk raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return [
+      cst_encode_String(raw.text),
+      cst_encode_String(raw.reasoning),
+      cst_encode_opt_box_autoadd_stop_reason(raw.stopReason),
+    ].jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_generation_options(GenerationOptions raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return [
+      cst_encode_opt_box_autoadd_u_32(raw.maxTokens),
+      cst_encode_list_String(raw.stopSequences),
+      cst_encode_opt_box_autoadd_sampler_config(raw.sampler),
+      cst_encode_opt_String(raw.grammar),
+      cst_encode_opt_String(raw.jsonSchema),
+      cst_encode_opt_String(raw.modelId),
+      cst_encode_opt_box_autoadd_context_policy(raw.context),
+      cst_encode_opt_box_autoadd_reasoning_options(raw.reasoning),
+      cst_encode_opt_box_autoadd_u_32(raw.logprobs),
+    ].jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_generation_poll(GenerationPoll raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return [
+      cst_encode_String(raw.text),
+      cst_encode_String(raw.reasoning),
+      cst_encode_opt_box_autoadd_generation_result(raw.result),
+    ].jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_generation_result(GenerationResult raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return [
+      cst_encode_String(raw.text),
+      cst_encode_opt_String(raw.reasoning),
+      cst_encode_u_32(raw.reasoningTokens),
+      cst_encode_stop_reason(raw.stopReason),
+      cst_encode_u_32(raw.tokensGenerated),
+      cst_encode_opt_box_autoadd_context_report(raw.context),
+      cst_encode_opt_list_token_logprob(raw.logprobs),
+    ].jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_gguf_metadata(GgufMetadata raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return [
+      cst_encode_u_32(raw.version),
+      cst_encode_opt_String(raw.architecture),
+      cst_encode_opt_String(raw.name),
+      cst_encode_opt_String(raw.chatTemplate),
+      cst_encode_opt_box_autoadd_u_64(raw.contextLength),
+      cst_encode_opt_box_autoadd_u_64(raw.embeddingLength),
+      cst_encode_opt_box_autoadd_u_64(raw.blockCount),
+      cst_encode_opt_box_autoadd_u_32(raw.poolingType),
+      cst_encode_opt_String(raw.quantization),
+      cst_encode_u_64(raw.parameterCount),
+      cst_encode_u_64(raw.tensorCount),
+      cst_encode_opt_String(raw.tokenizerModel),
+      cst_encode_opt_box_autoadd_u_64(raw.vocabSize),
+      cst_encode_opt_box_autoadd_u_32(raw.bosTokenId),
+      cst_encode_opt_box_autoadd_u_32(raw.eosTokenId),
+      cst_encode_u_64(raw.fileSize),
+    ].jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_graph_edge(GraphEdge raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return [
+      cst_encode_String(raw.source),
+      cst_encode_String(raw.target),
+      cst_encode_f_32(raw.weight),
+      cst_encode_String(raw.edgeType),
+    ].jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_graph_node(GraphNode raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return [
+      cst_encode_String(raw.id),
+      cst_encode_String(raw.label),
+      cst_encode_String(raw.nodeType),
+      cst_encode_f_32(raw.x),
+      cst_encode_f_32(raw.y),
+      cst_encode_opt_String(raw.color),
+      cst_encode_opt_String(raw.metadata),
+    ].jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_graph_state(GraphState raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return [
+      cst_encode_list_graph_node(raw.nodes),
+      cst_encode_list_graph_edge(raw.edges),
+    ].jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_hnsw_config(HnswConfig raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return [
+      cst_encode_u_32(raw.m),
+      cst_encode_u_32(raw.efConstruction),
+      cst_encode_u_32(raw.efSearch),
+    ].jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_hybrid_hit(HybridHit raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return [
+      cst_encode_String(raw.id),
+      cst_encode_f_32(raw.score),
+      cst_encode_opt_box_autoadd_f_32(raw.semanticScore),
+      cst_encode_opt_box_autoadd_u_32(raw.semanticRank),
+      cst_encode_opt_box_autoadd_f_32(raw.lexicalScore),
+      cst_encode_opt_box_autoadd_u_32(raw.lexicalRank),
+      cst_encode_list_String(raw.matchedTerms),
+      cst_encode_opt_String(raw.textPreview),
+    ].jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_hybrid_options(HybridOptions raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return [
+      cst_encode_fusion_mode(raw.fusion),
+      cst_encode_f_32(raw.semanticWeight),
+      cst_encode_f_32(raw.rrfK),
+      cst_encode_usize(raw.candidates),
+      cst_encode_f_32(raw.minSemanticScore),
+    ].jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_i_64(PlatformInt64 raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return castNativeBigInt(raw);
+  }
+
+  @protected
+  JSAny cst_encode_knowledge_graph_analysis(KnowledgeGraphAnalysis raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return [
+      cst_encode_clustering_result(raw.clustering),
+      cst_encode_semantic_edge_result(raw.semanticEdges),
+    ].jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_list_String(List<String> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw.map(cst_encode_String).toList().jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_list_agent_step(List<AgentStep> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw.map(cst_encode_agent_step).toList().jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_list_cluster_assignment(List<ClusterAssignment> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw.map(cst_encode_cluster_assignment).toList().jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_list_cluster_info(List<ClusterInfo> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw.map(cst_encode_cluster_info).toList().jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_list_dedup_document(List<DedupDocument> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw.map(cst_encode_dedup_document).toList().jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_list_duplicate_group(List<DuplicateGroup> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw.map(cst_encode_duplicate_group).toList().jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_list_duplicate_pair(List<DuplicatePair> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw.map(cst_encode_duplicate_pair).toList().jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_list_duplicate_span(List<DuplicateSpan> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw.map(cst_encode_duplicate_span).toList().jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_list_embedding_cluster(List<EmbeddingCluster> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw.map(cst_encode_embedding_cluster).toList().jsify()!;
+  }
 
   @protected
-  BigInt sse_decode_usize(SseDeserializer deserializer);
+  JSAny cst_encode_list_embedding_entry(List<EmbeddingEntry> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw.map(cst_encode_embedding_entry).toList().jsify()!;
+  }
 
   @protected
-  String cst_encode_AnyhowException(AnyhowException raw) {
+  JSAny cst_encode_list_graph_edge(List<GraphEdge> raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-    throw UnimplementedError();
+    return raw.map(cst_encode_graph_edge).toList().jsify()!;
   }
 
   @protected
-  String cst_encode_String(String raw) {
+  JSAny cst_encode_list_graph_node(List<GraphNode> raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw;
+    return raw.map(cst_encode_graph_node).toList().jsify()!;
   }
 
   @protected
-  double cst_encode_box_autoadd_f_32(double raw) {
+  JSAny cst_encode_list_hybrid_hit(List<HybridHit> raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-    return cst_encode_f_32(raw);
+    return raw.map(cst_encode_hybrid_hit).toList().jsify()!;
   }
 
   @protected
-  int cst_encode_box_autoadd_i_32(int raw) {
+  JSAny cst_encode_list_list_prim_f_32_strict(List<Float32List> raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-    return cst_encode_i_32(raw);
+    return raw.map(cst_encode_list_prim_f_32_strict).toList().jsify()!;
   }
 
   @protected
-  JSAny cst_encode_box_autoadd_mcp_tool_call(MCPToolCall raw) {
+  JSAny cst_encode_list_loaded_model_info(List<LoadedModelInfo> raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-    return cst_encode_mcp_tool_call(raw);
+    return raw.map(cst_encode_loaded_model_info).toList().jsify()!;
   }
 
   @protected
-  int cst_encode_box_autoadd_u_32(int raw) {
+  JSAny cst_encode_list_markdown_chunk(List<MarkdownChunk> raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-    return cst_encode_u_32(raw);
+    return raw.map(cst_encode_markdown_chunk).toList().jsify()!;
   }
 
   @protected
-  JSAny cst_encode_box_autoadd_usize(BigInt raw) {
+  JSAny cst_encode_list_mcp_parameter(List<MCPParameter> raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-    return cst_encode_usize(raw);
+    return raw.map(cst_encode_mcp_parameter).toList().jsify()!;
   }
 
   @protected
-  JSAny cst_encode_cluster_assignment(ClusterAssignment raw) {
+  JSAny cst_encode_list_mcp_tool(List<MCPTool> raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-    return [
-      cst_encode_String(raw.id),
-      cst_encode_usize(raw.clusterId),
-      cst_encode_String(raw.color),
-    ].jsify()!;
+    return raw.map(cst_encode_mcp_tool).toList().jsify()!;
   }
 
   @protected
-  JSAny cst_encode_cluster_info(ClusterInfo raw) {
+  JSAny cst_encode_list_mcp_tool_call(List<MCPToolCall> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw.map(cst_encode_mcp_tool_call).toList().jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_list_mcp_tool_result(List<MCPToolResult> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw.map(cst_encode_mcp_tool_result).toList().jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_list_node_position(List<NodePosition> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw.map(cst_encode_node_position).toList().jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_list_prim_f_32_loose(List<double> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw.jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_list_prim_f_32_strict(Float32List raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw.jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_list_prim_i_32_loose(List<int> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw.jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_list_prim_i_32_strict(Int32List raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw.jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_list_prim_u_32_strict(Uint32List raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw.jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_list_prim_u_8_strict(Uint8List raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw.jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_list_rag_source(List<RagSource> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw.map(cst_encode_rag_source).toList().jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_list_record_string_string(List<(String, String)> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw.map(cst_encode_record_string_string).toList().jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_list_reply_span(List<ReplySpan> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw.map(cst_encode_reply_span).toList().jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_list_semantic_edge(List<SemanticEdge> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw.map(cst_encode_semantic_edge).toList().jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_list_similarity_result(List<SimilarityResult> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw.map(cst_encode_similarity_result).toList().jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_list_token_candidate(List<TokenCandidate> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw.map(cst_encode_token_candidate).toList().jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_list_token_logprob(List<TokenLogprob> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw.map(cst_encode_token_logprob).toList().jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_list_transcript_segment(List<TranscriptSegment> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw.map(cst_encode_transcript_segment).toList().jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_loaded_model_info(LoadedModelInfo raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
     return [
-      cst_encode_usize(raw.id),
-      cst_encode_usize(raw.size),
-      cst_encode_String(raw.color),
-      cst_encode_opt_list_prim_f_32_strict(raw.centroid),
+      cst_encode_String(raw.modelId),
+      cst_encode_String(raw.modelPath),
+      cst_encode_u_64(raw.sizeBytes),
+      cst_encode_bool(raw.isDefault),
     ].jsify()!;
   }
 
   @protected
-  JSAny cst_encode_clustering_result(ClusteringResult raw) {
+  JSAny cst_encode_markdown_chunk(MarkdownChunk raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
     return [
-      cst_encode_list_cluster_assignment(raw.assignments),
-      cst_encode_list_cluster_info(raw.clusters),
-      cst_encode_usize(raw.k),
+      cst_encode_usize(raw.index),
+      cst_encode_list_String(raw.headingPath),
+      cst_encode_usize(raw.start),
+      cst_encode_usize(raw.end),
+      cst_encode_String(raw.text),
+      cst_encode_u_32(raw.tokenCount),
     ].jsify()!;
   }
 
   @protected
-  JSAny cst_encode_embedding_cluster(EmbeddingCluster raw) {
+  JSAny cst_encode_mcp_parameter(MCPParameter raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-    return [cst_encode_list_String(raw.ids)].jsify()!;
+    return [
+      cst_encode_String(raw.name),
+      cst_encode_String(raw.description),
+      cst_encode_mcp_param_type(raw.paramType),
+      cst_encode_bool(raw.required_),
+    ].jsify()!;
   }
 
   @protected
-  JSAny cst_encode_embedding_entry(EmbeddingEntry raw) {
+  JSAny cst_encode_mcp_tool_call(MCPToolCall raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
     return [
-      cst_encode_String(raw.id),
-      cst_encode_list_prim_f_32_strict(raw.vector),
-      cst_encode_opt_String(raw.textPreview),
+      cst_encode_String(raw.tool),
+      cst_encode_String(raw.parametersJson),
+      cst_encode_String(raw.description),
     ].jsify()!;
   }
 
   @protected
-  JSAny cst_encode_graph_edge(GraphEdge raw) {
+  JSAny cst_encode_mcp_tool_result(MCPToolResult raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
     return [
-      cst_encode_String(raw.source),
-      cst_encode_String(raw.target),
-      cst_encode_f_32(raw.weight),
-      cst_encode_String(raw.edgeType),
+      cst_encode_bool(raw.success),
+      cst_encode_String(raw.result),
+      cst_encode_String(raw.tool),
     ].jsify()!;
   }
 
   @protected
-  JSAny cst_encode_graph_node(GraphNode raw) {
+  JSAny cst_encode_node_position(NodePosition raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
     return [
       cst_encode_String(raw.id),
-      cst_encode_String(raw.label),
-      cst_encode_String(raw.nodeType),
       cst_encode_f_32(raw.x),
       cst_encode_f_32(raw.y),
-      cst_encode_opt_String(raw.color),
-      cst_encode_opt_String(raw.metadata),
+      cst_encode_f_32(raw.radius),
+      cst_encode_u_32(raw.color),
+      cst_encode_String(raw.nodeType),
     ].jsify()!;
   }
 
   @protected
-  JSAny cst_encode_graph_state(GraphState raw) {
+  JSAny cst_encode_note_metadata(NoteMetadata raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
     return [
-      cst_encode_list_graph_node(raw.nodes),
-      cst_encode_list_graph_edge(raw.edges),
+      cst_encode_opt_String(raw.folder),
+      cst_encode_list_String(raw.tags),
+      cst_encode_opt_box_autoadd_i_64(raw.createdAt),
+      cst_encode_opt_box_autoadd_i_64(raw.modifiedAt),
+      cst_encode_opt_String(raw.noteType),
+      cst_encode_opt_box_autoadd_usize(raw.chunkStart),
+      cst_encode_opt_box_autoadd_usize(raw.chunkEnd),
     ].jsify()!;
   }
 
   @protected
-  JSAny cst_encode_knowledge_graph_analysis(KnowledgeGraphAnalysis raw) {
+  String? cst_encode_opt_String(String? raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-    return [
-      cst_encode_clustering_result(raw.clustering),
-      cst_encode_semantic_edge_result(raw.semanticEdges),
-    ].jsify()!;
+    return raw == null ? null : cst_encode_String(raw);
   }
 
   @protected
-  JSAny cst_encode_list_String(List<String> raw) {
+  JSAny? cst_encode_opt_box_autoadd_context_policy(ContextPolicy? raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw.map(cst_encode_String).toList().jsify()!;
+    return raw == null ? null : cst_encode_box_autoadd_context_policy(raw);
   }
 
   @protected
-  JSAny cst_encode_list_cluster_assignment(List<ClusterAssignment> raw) {
+  JSAny? cst_encode_opt_box_autoadd_context_report(ContextReport? raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw.map(cst_encode_cluster_assignment).toList().jsify()!;
+    return raw == null ? null : cst_encode_box_autoadd_context_report(raw);
   }
 
   @protected
-  JSAny cst_encode_list_cluster_info(List<ClusterInfo> raw) {
+  JSAny? cst_encode_opt_box_autoadd_dedup_options(DedupOptions? raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw.map(cst_encode_cluster_info).toList().jsify()!;
+    return raw == null ? null : cst_encode_box_autoadd_dedup_options(raw);
   }
 
   @protected
-  JSAny cst_encode_list_embedding_cluster(List<EmbeddingCluster> raw) {
+  JSAny? cst_encode_opt_box_autoadd_embedding_cache_stats(
+    EmbeddingCacheStats? raw,
+  ) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw.map(cst_encode_embedding_cluster).toList().jsify()!;
+    return raw == null
+        ? null
+        : cst_encode_box_autoadd_embedding_cache_stats(raw);
   }
 
   @protected
-  JSAny cst_encode_list_embedding_entry(List<EmbeddingEntry> raw) {
+  JSAny? cst_encode_opt_box_autoadd_embedding_config(EmbeddingConfig? raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw.map(cst_encode_embedding_entry).toList().jsify()!;
+    return raw == null ? null : cst_encode_box_autoadd_embedding_config(raw);
   }
 
   @protected
-  JSAny cst_encode_list_graph_edge(List<GraphEdge> raw) {
+  double? cst_encode_opt_box_autoadd_f_32(double? raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw.map(cst_encode_graph_edge).toList().jsify()!;
+    return raw == null ? null : cst_encode_box_autoadd_f_32(raw);
   }
 
   @protected
-  JSAny cst_encode_list_graph_node(List<GraphNode> raw) {
+  JSAny? cst_encode_opt_box_autoadd_generation_result(GenerationResult? raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw.map(cst_encode_graph_node).toList().jsify()!;
+    return raw == null ? null : cst_encode_box_autoadd_generation_result(raw);
   }
 
   @protected
-  JSAny cst_encode_list_mcp_parameter(List<MCPParameter> raw) {
+  JSAny? cst_encode_opt_box_autoadd_hnsw_config(HnswConfig? raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw.map(cst_encode_mcp_parameter).toList().jsify()!;
+    return raw == null ? null : cst_encode_box_autoadd_hnsw_config(raw);
   }
 
   @protected
-  JSAny cst_encode_list_mcp_tool(List<MCPTool> raw) {
+  JSAny? cst_encode_opt_box_autoadd_hybrid_options(HybridOptions? raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw.map(cst_encode_mcp_tool).toList().jsify()!;
+    return raw == null ? null : cst_encode_box_autoadd_hybrid_options(raw);
   }
 
   @protected
-  JSAny cst_encode_list_node_position(List<NodePosition> raw) {
+  int? cst_encode_opt_box_autoadd_i_32(int? raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw.map(cst_encode_node_position).toList().jsify()!;
+    return raw == null ? null : cst_encode_box_autoadd_i_32(raw);
   }
 
   @protected
-  JSAny cst_encode_list_prim_f_32_loose(List<double> raw) {
+  JSAny? cst_encode_opt_box_autoadd_i_64(PlatformInt64? raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw.jsify()!;
+    return raw == null ? null : cst_encode_box_autoadd_i_64(raw);
   }
 
   @protected
-  JSAny cst_encode_list_prim_f_32_strict(Float32List raw) {
+  JSAny? cst_encode_opt_box_autoadd_note_metadata(NoteMetadata? raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw.jsify()!;
+    return raw == null ? null : cst_encode_box_autoadd_note_metadata(raw);
   }
 
   @protected
-  JSAny cst_encode_list_prim_u_8_strict(Uint8List raw) {
+  JSAny? cst_encode_opt_box_autoadd_reasoning_options(ReasoningOptions? raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw.jsify()!;
+    return raw == null ? null : cst_encode_box_autoadd_reasoning_options(raw);
   }
 
   @protected
-  JSAny cst_encode_list_record_string_string(List<(String, String)> raw) {
+  JSAny? cst_encode_opt_box_autoadd_sampler_config(SamplerConfig? raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw.map(cst_encode_record_string_string).toList().jsify()!;
+    return raw == null ? null : cst_encode_box_autoadd_sampler_config(raw);
   }
 
   @protected
-  JSAny cst_encode_list_semantic_edge(List<SemanticEdge> raw) {
+  int? cst_encode_opt_box_autoadd_stop_reason(StopReason? raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw.map(cst_encode_semantic_edge).toList().jsify()!;
+    return raw == null ? null : cst_encode_box_autoadd_stop_reason(raw);
   }
 
   @protected
-  JSAny cst_encode_list_similarity_result(List<SimilarityResult> raw) {
+  JSAny? cst_encode_opt_box_autoadd_summary_options(SummaryOptions? raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw.map(cst_encode_similarity_result).toList().jsify()!;
+    return raw == null ? null : cst_encode_box_autoadd_summary_options(raw);
   }
 
   @protected
-  JSAny cst_encode_mcp_parameter(MCPParameter raw) {
+  int? cst_encode_opt_box_autoadd_u_32(int? raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-    return [
-      cst_encode_String(raw.name),
-      cst_encode_String(raw.description),
-      cst_encode_mcp_param_type(raw.paramType),
-      cst_encode_bool(raw.required_),
-    ].jsify()!;
+    return raw == null ? null : cst_encode_box_autoadd_u_32(raw);
   }
 
   @protected
-  JSAny cst_encode_mcp_tool_call(MCPToolCall raw) {
+  JSAny? cst_encode_opt_box_autoadd_u_64(BigInt? raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-    return [
-      cst_encode_String(raw.tool),
-      cst_encode_String(raw.parametersJson),
-      cst_encode_String(raw.description),
-    ].jsify()!;
+    return raw == null ? null : cst_encode_box_autoadd_u_64(raw);
+  }
+
+  @protected
+  JSAny? cst_encode_opt_box_autoadd_usize(BigInt? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? null : cst_encode_box_autoadd_usize(raw);
+  }
+
+  @protected
+  JSAny? cst_encode_opt_list_String(List<String>? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? null : cst_encode_list_String(raw);
+  }
+
+  @protected
+  JSAny? cst_encode_opt_list_list_prim_f_32_strict(List<Float32List>? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? null : cst_encode_list_list_prim_f_32_strict(raw);
+  }
+
+  @protected
+  JSAny? cst_encode_opt_list_prim_f_32_strict(Float32List? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? null : cst_encode_list_prim_f_32_strict(raw);
+  }
+
+  @protected
+  JSAny? cst_encode_opt_list_record_string_string(List<(String, String)>? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? null : cst_encode_list_record_string_string(raw);
   }
 
   @protected
-  JSAny cst_encode_mcp_tool_result(MCPToolResult raw) {
+  JSAny? cst_encode_opt_list_token_logprob(List<TokenLogprob>? raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-    return [
-      cst_encode_bool(raw.success),
-      cst_encode_String(raw.result),
-      cst_encode_String(raw.tool),
-    ].jsify()!;
+    return raw == null ? null : cst_encode_list_token_logprob(raw);
   }
 
   @protected
-  JSAny cst_encode_node_position(NodePosition raw) {
+  JSAny cst_encode_parsed_reply(ParsedReply raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
     return [
-      cst_encode_String(raw.id),
-      cst_encode_f_32(raw.x),
-      cst_encode_f_32(raw.y),
-      cst_encode_f_32(raw.radius),
-      cst_encode_u_32(raw.color),
-      cst_encode_String(raw.nodeType),
+      cst_encode_list_mcp_tool_call(raw.toolCalls),
+      cst_encode_list_reply_span(raw.spans),
+      cst_encode_String(raw.text),
     ].jsify()!;
   }
 
   @protected
-  String? cst_encode_opt_String(String? raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw == null ? null : cst_encode_String(raw);
-  }
-
-  @protected
-  double? cst_encode_opt_box_autoadd_f_32(double? raw) {
+  JSAny cst_encode_rag_answer(RagAnswer raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw == null ? null : cst_encode_box_autoadd_f_32(raw);
+    return [
+      cst_encode_String(raw.answer),
+      cst_encode_list_rag_source(raw.sources),
+      cst_encode_usize(raw.skipped),
+    ].jsify()!;
   }
 
   @protected
-  int? cst_encode_opt_box_autoadd_i_32(int? raw) {
+  JSAny cst_encode_rag_source(RagSource raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw == null ? null : cst_encode_box_autoadd_i_32(raw);
+    return [
+      cst_encode_u_32(raw.citation),
+      cst_encode_String(raw.noteId),
+      cst_encode_opt_box_autoadd_usize(raw.chunkOffset),
+      cst_encode_opt_box_autoadd_usize(raw.chunkIndex),
+      cst_encode_f_32(raw.score),
+      cst_encode_String(raw.text),
+      cst_encode_bool(raw.cited),
+    ].jsify()!;
   }
 
   @protected
-  int? cst_encode_opt_box_autoadd_u_32(int? raw) {
+  JSAny cst_encode_reasoning_options(ReasoningOptions raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw == null ? null : cst_encode_box_autoadd_u_32(raw);
+    return [
+      cst_encode_bool(raw.enabled),
+      cst_encode_String(raw.openTag),
+      cst_encode_String(raw.closeTag),
+      cst_encode_opt_box_autoadd_u_32(raw.thinkingBudget),
+    ].jsify()!;
   }
 
   @protected
-  JSAny? cst_encode_opt_box_autoadd_usize(BigInt? raw) {
+  JSAny cst_encode_recall_report(RecallReport raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw == null ? null : cst_encode_box_autoadd_usize(raw);
+    return [
+      cst_encode_usize(raw.queries),
+      cst_encode_usize(raw.topK),
+      cst_encode_f_32(raw.recall),
+      cst_encode_f_32(raw.rescoredRecall),
+      cst_encode_usize(raw.f32Bytes),
+      cst_encode_usize(raw.quantizedBytes),
+    ].jsify()!;
   }
 
   @protected
-  JSAny? cst_encode_opt_list_String(List<String>? raw) {
+  JSAny cst_encode_record_string_string((String, String) raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw == null ? null : cst_encode_list_String(raw);
+    return [cst_encode_String(raw.$1), cst_encode_String(raw.$2)].jsify()!;
   }
 
   @protected
-  JSAny? cst_encode_opt_list_prim_f_32_strict(Float32List? raw) {
+  JSAny cst_encode_reply_span(ReplySpan raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw == null ? null : cst_encode_list_prim_f_32_strict(raw);
+    return [
+      cst_encode_usize(raw.start),
+      cst_encode_usize(raw.end),
+      cst_encode_reply_span_kind(raw.kind),
+      cst_encode_usize(raw.callCount),
+    ].jsify()!;
   }
 
   @protected
-  JSAny? cst_encode_opt_list_record_string_string(List<(String, String)>? raw) {
+  JSAny cst_encode_sampler_config(SamplerConfig raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw == null ? null : cst_encode_list_record_string_string(raw);
+    return [
+      cst_encode_f_32(raw.temperature),
+      cst_encode_i_32(raw.topK),
+      cst_encode_f_32(raw.topP),
+      cst_encode_f_32(raw.minP),
+      cst_encode_f_32(raw.repeatPenalty),
+      cst_encode_f_32(raw.frequencyPenalty),
+      cst_encode_f_32(raw.presencePenalty),
+      cst_encode_i_32(raw.penaltyLastN),
+      cst_encode_opt_box_autoadd_u_32(raw.seed),
+      cst_encode_bool(raw.greedy),
+    ].jsify()!;
   }
 
   @protected
-  JSAny cst_encode_record_string_string((String, String) raw) {
+  JSAny cst_encode_search_options(SearchOptions raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-    return [cst_encode_String(raw.$1), cst_encode_String(raw.$2)].jsify()!;
+    return [
+      cst_encode_usize(raw.topK),
+      cst_encode_f_32(raw.threshold),
+      cst_encode_opt_String(raw.filter),
+      cst_encode_opt_box_autoadd_f_32(raw.mmrLambda),
+      cst_encode_usize(raw.mmrCandidates),
+    ].jsify()!;
   }
 
   @protected
@@ -774,98 +2229,405 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     ].jsify()!;
   }
 
+  @protected
+  JSAny cst_encode_summary(Summary raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return [
+      cst_encode_String(raw.summary),
+      cst_encode_list_String(raw.chunkSummaries),
+      cst_encode_u_32(raw.mergeLevels),
+    ].jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_summary_options(SummaryOptions raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return [
+      cst_encode_summary_style(raw.style),
+      cst_encode_u_32(raw.maxWords),
+      cst_encode_u_32(raw.chunkTokens),
+      cst_encode_opt_String(raw.modelId),
+    ].jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_token_candidate(TokenCandidate raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return [
+      cst_encode_i_32(raw.token),
+      cst_encode_String(raw.text),
+      cst_encode_f_32(raw.logprob),
+    ].jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_token_logprob(TokenLogprob raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return [
+      cst_encode_i_32(raw.token),
+      cst_encode_String(raw.text),
+      cst_encode_f_32(raw.logprob),
+      cst_encode_list_token_candidate(raw.topAlternatives),
+    ].jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_tokenizer_info(TokenizerInfo raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return [
+      cst_encode_u_32(raw.vocabSize),
+      cst_encode_u_32(raw.nCtxTrain),
+      cst_encode_u_32(raw.nCtx),
+      cst_encode_i_32(raw.bosToken),
+      cst_encode_i_32(raw.eosToken),
+      cst_encode_opt_String(raw.tokenizerModel),
+    ].jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_transcript_segment(TranscriptSegment raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return [
+      cst_encode_String(raw.text),
+      cst_encode_f_32(raw.startTime),
+      cst_encode_f_32(raw.endTime),
+    ].jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_u_64(BigInt raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return castNativeBigInt(raw);
+  }
+
   @protected
   JSAny cst_encode_usize(BigInt raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
     return castNativeBigInt(raw);
   }
 
+  @protected
+  JSAny cst_encode_vector_store_stats(VectorStoreStats raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return [
+      cst_encode_usize(raw.entries),
+      cst_encode_usize(raw.deadRows),
+      cst_encode_usize(raw.dimension),
+      cst_encode_u_64(raw.fileBytes),
+    ].jsify()!;
+  }
+
+  @protected
+  int cst_encode_agent_stop_reason(AgentStopReason raw);
+
   @protected
   bool cst_encode_bool(bool raw);
 
+  @protected
+  int cst_encode_context_strategy(ContextStrategy raw);
+
+  @protected
+  int cst_encode_embedding_kind(EmbeddingKind raw);
+
   @protected
   double cst_encode_f_32(double raw);
 
+  @protected
+  int cst_encode_fusion_mode(FusionMode raw);
+
   @protected
   int cst_encode_i_32(int raw);
 
   @protected
-  int cst_encode_mcp_param_type(MCPParamType raw);
+  int cst_encode_mcp_param_type(MCPParamType raw);
+
+  @protected
+  int cst_encode_mcp_tool(MCPTool raw);
+
+  @protected
+  int cst_encode_pooling_mode(PoolingMode raw);
+
+  @protected
+  int cst_encode_quantization_kind(QuantizationKind raw);
+
+  @protected
+  int cst_encode_reply_span_kind(ReplySpanKind raw);
+
+  @protected
+  int cst_encode_stop_reason(StopReason raw);
+
+  @protected
+  int cst_encode_summary_style(SummaryStyle raw);
+
+  @protected
+  int cst_encode_task_category(TaskCategory raw);
+
+  @protected
+  int cst_encode_u_32(int raw);
+
+  @protected
+  int cst_encode_u_8(int raw);
+
+  @protected
+  void cst_encode_unit(void raw);
+
+  @protected
+  void sse_encode_AnyhowException(
+    AnyhowException self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_agent_step_Dco(
+    RustStreamSink<AgentStep> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_generation_chunk_Dco(
+    RustStreamSink<GenerationChunk> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_String(String self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_agent_run(AgentRun self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_agent_step(AgentStep self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_agent_stop_reason(
+    AgentStopReason self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_bool(bool self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_context_policy(
+    ContextPolicy self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_context_report(
+    ContextReport self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_dedup_options(
+    DedupOptions self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_embedding_cache_stats(
+    EmbeddingCacheStats self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_embedding_config(
+    EmbeddingConfig self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_f_32(double self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_generation_options(
+    GenerationOptions self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_generation_result(
+    GenerationResult self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_hnsw_config(
+    HnswConfig self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_hybrid_options(
+    HybridOptions self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_i_32(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_i_64(
+    PlatformInt64 self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_mcp_tool_call(
+    MCPToolCall self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_note_metadata(
+    NoteMetadata self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_reasoning_options(
+    ReasoningOptions self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_sampler_config(
+    SamplerConfig self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_search_options(
+    SearchOptions self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_stop_reason(
+    StopReason self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_summary_options(
+    SummaryOptions self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_usize(BigInt self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_cluster_assignment(
+    ClusterAssignment self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_cluster_info(ClusterInfo self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_clustering_result(
+    ClusteringResult self,
+    SseSerializer serializer,
+  );
 
   @protected
-  int cst_encode_mcp_tool(MCPTool raw);
+  void sse_encode_context_policy(ContextPolicy self, SseSerializer serializer);
 
   @protected
-  int cst_encode_task_category(TaskCategory raw);
+  void sse_encode_context_report(ContextReport self, SseSerializer serializer);
 
   @protected
-  int cst_encode_u_32(int raw);
+  void sse_encode_context_strategy(
+    ContextStrategy self,
+    SseSerializer serializer,
+  );
 
   @protected
-  int cst_encode_u_8(int raw);
+  void sse_encode_dedup_document(DedupDocument self, SseSerializer serializer);
 
   @protected
-  void cst_encode_unit(void raw);
+  void sse_encode_dedup_options(DedupOptions self, SseSerializer serializer);
 
   @protected
-  void sse_encode_AnyhowException(
-    AnyhowException self,
+  void sse_encode_duplicate_group(
+    DuplicateGroup self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_String(String self, SseSerializer serializer);
+  void sse_encode_duplicate_pair(DuplicatePair self, SseSerializer serializer);
 
   @protected
-  void sse_encode_bool(bool self, SseSerializer serializer);
+  void sse_encode_duplicate_span(DuplicateSpan self, SseSerializer serializer);
 
   @protected
-  void sse_encode_box_autoadd_f_32(double self, SseSerializer serializer);
+  void sse_encode_embedding_cache_stats(
+    EmbeddingCacheStats self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_box_autoadd_i_32(int self, SseSerializer serializer);
+  void sse_encode_embedding_cluster(
+    EmbeddingCluster self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_box_autoadd_mcp_tool_call(
-    MCPToolCall self,
+  void sse_encode_embedding_config(
+    EmbeddingConfig self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
+  void sse_encode_embedding_entry(
+    EmbeddingEntry self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_box_autoadd_usize(BigInt self, SseSerializer serializer);
+  void sse_encode_embedding_kind(EmbeddingKind self, SseSerializer serializer);
 
   @protected
-  void sse_encode_cluster_assignment(
-    ClusterAssignment self,
-    SseSerializer serializer,
-  );
+  void sse_encode_f_32(double self, SseSerializer serializer);
 
   @protected
-  void sse_encode_cluster_info(ClusterInfo self, SseSerializer serializer);
+  void sse_encode_fusion_mode(FusionMode self, SseSerializer serializer);
 
   @protected
-  void sse_encode_clustering_result(
-    ClusteringResult self,
+  void sse_encode_generation_chunk(
+    GenerationChunk self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_embedding_cluster(
-    EmbeddingCluster self,
+  void sse_encode_generation_options(
+    GenerationOptions self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_embedding_entry(
-    EmbeddingEntry self,
+  void sse_encode_generation_poll(
+    GenerationPoll self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_f_32(double self, SseSerializer serializer);
+  void sse_encode_generation_result(
+    GenerationResult self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_gguf_metadata(GgufMetadata self, SseSerializer serializer);
 
   @protected
   void sse_encode_graph_edge(GraphEdge self, SseSerializer serializer);
@@ -876,9 +2638,21 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_graph_state(GraphState self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_hnsw_config(HnswConfig self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_hybrid_hit(HybridHit self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_hybrid_options(HybridOptions self, SseSerializer serializer);
+
   @protected
   void sse_encode_i_32(int self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer);
+
   @protected
   void sse_encode_knowledge_graph_analysis(
     KnowledgeGraphAnalysis self,
@@ -888,6 +2662,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_list_String(List<String> self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_agent_step(
+    List<AgentStep> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_cluster_assignment(
     List<ClusterAssignment> self,
@@ -900,6 +2680,30 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_list_dedup_document(
+    List<DedupDocument> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_duplicate_group(
+    List<DuplicateGroup> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_duplicate_pair(
+    List<DuplicatePair> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_duplicate_span(
+    List<DuplicateSpan> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_embedding_cluster(
     List<EmbeddingCluster> self,
@@ -924,6 +2728,30 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_list_hybrid_hit(
+    List<HybridHit> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_list_prim_f_32_strict(
+    List<Float32List> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_loaded_model_info(
+    List<LoadedModelInfo> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_markdown_chunk(
+    List<MarkdownChunk> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_mcp_parameter(
     List<MCPParameter> self,
@@ -933,6 +2761,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_list_mcp_tool(List<MCPTool> self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_mcp_tool_call(
+    List<MCPToolCall> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_mcp_tool_result(
+    List<MCPToolResult> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_node_position(
     List<NodePosition> self,
@@ -951,18 +2791,48 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_list_prim_i_32_loose(
+    List<int> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_prim_i_32_strict(
+    Int32List self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_prim_u_32_strict(
+    Uint32List self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_prim_u_8_strict(
     Uint8List self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_list_rag_source(
+    List<RagSource> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_record_string_string(
     List<(String, String)> self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_list_reply_span(
+    List<ReplySpan> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_semantic_edge(
     List<SemanticEdge> self,
@@ -975,6 +2845,33 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_list_token_candidate(
+    List<TokenCandidate> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_token_logprob(
+    List<TokenLogprob> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_transcript_segment(
+    List<TranscriptSegment> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_loaded_model_info(
+    LoadedModelInfo self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_markdown_chunk(MarkdownChunk self, SseSerializer serializer);
+
   @protected
   void sse_encode_mcp_param_type(MCPParamType self, SseSerializer serializer);
 
@@ -993,24 +2890,120 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_node_position(NodePosition self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_note_metadata(NoteMetadata self, SseSerializer serializer);
+
   @protected
   void sse_encode_opt_String(String? self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_opt_box_autoadd_context_policy(
+    ContextPolicy? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_context_report(
+    ContextReport? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_dedup_options(
+    DedupOptions? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_embedding_cache_stats(
+    EmbeddingCacheStats? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_embedding_config(
+    EmbeddingConfig? self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_opt_box_autoadd_f_32(double? self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_opt_box_autoadd_generation_result(
+    GenerationResult? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_hnsw_config(
+    HnswConfig? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_hybrid_options(
+    HybridOptions? self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_opt_box_autoadd_i_32(int? self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_opt_box_autoadd_i_64(
+    PlatformInt64? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_note_metadata(
+    NoteMetadata? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_reasoning_options(
+    ReasoningOptions? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_sampler_config(
+    SamplerConfig? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_stop_reason(
+    StopReason? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_summary_options(
+    SummaryOptions? self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer);
+
   @protected
   void sse_encode_opt_box_autoadd_usize(BigInt? self, SseSerializer serializer);
 
   @protected
   void sse_encode_opt_list_String(List<String>? self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_opt_list_list_prim_f_32_strict(
+    List<Float32List>? self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_opt_list_prim_f_32_strict(
     Float32List? self,
@@ -1023,6 +3016,39 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_opt_list_token_logprob(
+    List<TokenLogprob>? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_parsed_reply(ParsedReply self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_pooling_mode(PoolingMode self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_quantization_kind(
+    QuantizationKind self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_rag_answer(RagAnswer self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_rag_source(RagSource self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_reasoning_options(
+    ReasoningOptions self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_recall_report(RecallReport self, SseSerializer serializer);
+
   @protected
   void sse_encode_record_string_string(
     (String, String) self,
@@ -1030,31 +3056,79 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   );
 
   @protected
-  void sse_encode_semantic_edge(SemanticEdge self, SseSerializer serializer);
+  void sse_encode_reply_span(ReplySpan self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_reply_span_kind(ReplySpanKind self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_sampler_config(SamplerConfig self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_search_options(SearchOptions self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_semantic_edge(SemanticEdge self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_semantic_edge_result(
+    SemanticEdgeResult self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_similarity_result(
+    SimilarityResult self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_stop_reason(StopReason self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_stream_graph_stats(
+    StreamGraphStats self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_summary(Summary self, SseSerializer serializer);
 
   @protected
-  void sse_encode_semantic_edge_result(
-    SemanticEdgeResult self,
+  void sse_encode_summary_options(
+    SummaryOptions self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_similarity_result(
-    SimilarityResult self,
+  void sse_encode_summary_style(SummaryStyle self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_task_category(TaskCategory self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_token_candidate(
+    TokenCandidate self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_stream_graph_stats(
-    StreamGraphStats self,
+  void sse_encode_token_logprob(TokenLogprob self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_tokenizer_info(TokenizerInfo self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_transcript_segment(
+    TranscriptSegment self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_task_category(TaskCategory self, SseSerializer serializer);
+  void sse_encode_u_32(int self, SseSerializer serializer);
 
   @protected
-  void sse_encode_u_32(int self, SseSerializer serializer);
+  void sse_encode_u_64(BigInt self, SseSerializer serializer);
 
   @protected
   void sse_encode_u_8(int self, SseSerializer serializer);
@@ -1064,6 +3138,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
 
   @protected
   void sse_encode_usize(BigInt self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_vector_store_stats(
+    VectorStoreStats self,
+    SseSerializer serializer,
+  );
 }
 
 // Section: wire_class
@@ -1153,9 +3233,37 @@ class RustLibWire implements BaseWire {
     existing_links,
   );
 
+  void wire__crate__api__analyze_knowledge_graph_in_store(
+    NativePortType port_,
+    JSAny store_id,
+    JSAny? k,
+    double? similarity_threshold,
+    JSAny? existing_links,
+  ) => wasmModule.wire__crate__api__analyze_knowledge_graph_in_store(
+    port_,
+    store_id,
+    k,
+    similarity_threshold,
+    existing_links,
+  );
+
   void wire__crate__api__batch_embed(NativePortType port_, JSAny texts) =>
       wasmModule.wire__crate__api__batch_embed(port_, texts);
 
+  void wire__crate__api__batch_embed_with_model(
+    NativePortType port_,
+    JSAny texts,
+    String? model_id,
+  ) => wasmModule.wire__crate__api__batch_embed_with_model(
+    port_,
+    texts,
+    model_id,
+  );
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+  wire__crate__api__cancel_generation(JSAny request_id) =>
+      wasmModule.wire__crate__api__cancel_generation(request_id);
+
   void wire__crate__api__chat_completion(
     NativePortType port_,
     JSAny messages,
@@ -1163,6 +3271,90 @@ class RustLibWire implements BaseWire {
   ) =>
       wasmModule.wire__crate__api__chat_completion(port_, messages, max_tokens);
 
+  void wire__crate__api__chat_completion_stream(
+    NativePortType port_,
+    JSAny messages,
+    int? max_tokens,
+    JSAny? stop_sequences,
+    String? model_id,
+    String sink,
+  ) => wasmModule.wire__crate__api__chat_completion_stream(
+    port_,
+    messages,
+    max_tokens,
+    stop_sequences,
+    model_id,
+    sink,
+  );
+
+  void wire__crate__api__chat_completion_with_model(
+    NativePortType port_,
+    JSAny messages,
+    int? max_tokens,
+    String? model_id,
+  ) => wasmModule.wire__crate__api__chat_completion_with_model(
+    port_,
+    messages,
+    max_tokens,
+    model_id,
+  );
+
+  void wire__crate__api__chat_completion_with_options(
+    NativePortType port_,
+    JSAny messages,
+    JSAny options,
+  ) => wasmModule.wire__crate__api__chat_completion_with_options(
+    port_,
+    messages,
+    options,
+  );
+
+  void wire__crate__api__chat_session_completion(
+    NativePortType port_,
+    JSAny session_id,
+    JSAny messages,
+    JSAny options,
+  ) => wasmModule.wire__crate__api__chat_session_completion(
+    port_,
+    session_id,
+    messages,
+    options,
+  );
+
+  void wire__crate__api__chat_session_stream(
+    NativePortType port_,
+    JSAny session_id,
+    JSAny messages,
+    int? max_tokens,
+    JSAny? stop_sequences,
+    String sink,
+  ) => wasmModule.wire__crate__api__chat_session_stream(
+    port_,
+    session_id,
+    messages,
+    max_tokens,
+    stop_sequences,
+    sink,
+  );
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+  wire__crate__api__chat_session_token_count(JSAny session_id) =>
+      wasmModule.wire__crate__api__chat_session_token_count(session_id);
+
+  void wire__crate__api__chunk_markdown(
+    NativePortType port_,
+    String text,
+    int? max_tokens,
+    int? overlap_tokens,
+    String? model_id,
+  ) => wasmModule.wire__crate__api__chunk_markdown(
+    port_,
+    text,
+    max_tokens,
+    overlap_tokens,
+    model_id,
+  );
+
   JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
   wire__crate__api__clear_graph() => wasmModule.wire__crate__api__clear_graph();
 
@@ -1170,77 +3362,369 @@ class RustLibWire implements BaseWire {
   wire__crate__api__clear_stream_graph() =>
       wasmModule.wire__crate__api__clear_stream_graph();
 
-  void wire__crate__api__cluster_embeddings(
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+  wire__crate__api__close_chat_session(JSAny session_id) =>
+      wasmModule.wire__crate__api__close_chat_session(session_id);
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+  wire__crate__api__close_embedding_cache() =>
+      wasmModule.wire__crate__api__close_embedding_cache();
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+  wire__crate__api__close_hnsw_index(JSAny index_id) =>
+      wasmModule.wire__crate__api__close_hnsw_index(index_id);
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+  wire__crate__api__close_quantized_index(JSAny index_id) =>
+      wasmModule.wire__crate__api__close_quantized_index(index_id);
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+  wire__crate__api__close_vector_store(JSAny store_id) =>
+      wasmModule.wire__crate__api__close_vector_store(store_id);
+
+  void wire__crate__api__cluster_embeddings(
+    NativePortType port_,
+    JSAny entries,
+    double threshold,
+  ) => wasmModule.wire__crate__api__cluster_embeddings(
+    port_,
+    entries,
+    threshold,
+  );
+
+  void wire__crate__api__cluster_notes(
+    NativePortType port_,
+    JSAny entries,
+    JSAny? k,
+    JSAny? max_iterations,
+  ) => wasmModule.wire__crate__api__cluster_notes(
+    port_,
+    entries,
+    k,
+    max_iterations,
+  );
+
+  void wire__crate__api__cluster_notes_in_store(
+    NativePortType port_,
+    JSAny store_id,
+    JSAny? k,
+    JSAny? max_iterations,
+  ) => wasmModule.wire__crate__api__cluster_notes_in_store(
+    port_,
+    store_id,
+    k,
+    max_iterations,
+  );
+
+  void wire__crate__api__compact_embedding_cache(NativePortType port_) =>
+      wasmModule.wire__crate__api__compact_embedding_cache(port_);
+
+  void wire__crate__api__compact_vector_store(
+    NativePortType port_,
+    JSAny store_id,
+  ) => wasmModule.wire__crate__api__compact_vector_store(port_, store_id);
+
+  void wire__crate__api__compute_graph_layout(
+    NativePortType port_,
+    int? iterations,
+  ) => wasmModule.wire__crate__api__compute_graph_layout(port_, iterations);
+
+  void wire__crate__api__connect_note_to_topics(
+    NativePortType port_,
+    String note_id,
+    JSAny topic_ids,
+  ) => wasmModule.wire__crate__api__connect_note_to_topics(
+    port_,
+    note_id,
+    topic_ids,
+  );
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+  wire__crate__api__cosine_similarity(JSAny a, JSAny b) =>
+      wasmModule.wire__crate__api__cosine_similarity(a, b);
+
+  void wire__crate__api__count_chat_prompt_tokens(
+    NativePortType port_,
+    JSAny messages,
+    String? model_id,
+  ) => wasmModule.wire__crate__api__count_chat_prompt_tokens(
+    port_,
+    messages,
+    model_id,
+  );
+
+  void wire__crate__api__count_chat_tokens(
+    NativePortType port_,
+    JSAny messages,
+    String? model_id,
+  ) =>
+      wasmModule.wire__crate__api__count_chat_tokens(port_, messages, model_id);
+
+  void wire__crate__api__count_tokens(
+    NativePortType port_,
+    String text,
+    String? model_id,
+  ) => wasmModule.wire__crate__api__count_tokens(port_, text, model_id);
+
+  void wire__crate__api__create_chat_session(
+    NativePortType port_,
+    int? n_ctx,
+    String? model_id,
+  ) => wasmModule.wire__crate__api__create_chat_session(port_, n_ctx, model_id);
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+  wire__crate__api__create_hnsw_index(JSAny? config) =>
+      wasmModule.wire__crate__api__create_hnsw_index(config);
+
+  void wire__crate__api__create_hnsw_index_from_store(
+    NativePortType port_,
+    JSAny store_id,
+    JSAny? config,
+  ) => wasmModule.wire__crate__api__create_hnsw_index_from_store(
+    port_,
+    store_id,
+    config,
+  );
+
+  void wire__crate__api__create_quantized_index(
+    NativePortType port_,
+    JSAny entries,
+    int kind,
+  ) =>
+      wasmModule.wire__crate__api__create_quantized_index(port_, entries, kind);
+
+  void wire__crate__api__create_quantized_index_from_store(
+    NativePortType port_,
+    JSAny store_id,
+    int kind,
+  ) => wasmModule.wire__crate__api__create_quantized_index_from_store(
+    port_,
+    store_id,
+    kind,
+  );
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+  wire__crate__api__default_context_policy() =>
+      wasmModule.wire__crate__api__default_context_policy();
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+  wire__crate__api__default_dedup_options() =>
+      wasmModule.wire__crate__api__default_dedup_options();
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+  wire__crate__api__default_embedding_config() =>
+      wasmModule.wire__crate__api__default_embedding_config();
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+  wire__crate__api__default_hnsw_config() =>
+      wasmModule.wire__crate__api__default_hnsw_config();
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+  wire__crate__api__default_hybrid_options() =>
+      wasmModule.wire__crate__api__default_hybrid_options();
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+  wire__crate__api__default_reasoning_options() =>
+      wasmModule.wire__crate__api__default_reasoning_options();
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+  wire__crate__api__default_sampler_config() =>
+      wasmModule.wire__crate__api__default_sampler_config();
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+  wire__crate__api__default_search_options() =>
+      wasmModule.wire__crate__api__default_search_options();
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+  wire__crate__api__default_summary_options() =>
+      wasmModule.wire__crate__api__default_summary_options();
+
+  void wire__crate__api__detokenize_tokens(
+    NativePortType port_,
+    JSAny tokens,
+    bool render_special,
+    String? model_id,
+  ) => wasmModule.wire__crate__api__detokenize_tokens(
+    port_,
+    tokens,
+    render_special,
+    model_id,
+  );
+
+  void wire__crate__api__discover_semantic_edges(
+    NativePortType port_,
+    JSAny entries,
+    double? threshold,
+    JSAny? existing_links,
+  ) => wasmModule.wire__crate__api__discover_semantic_edges(
+    port_,
+    entries,
+    threshold,
+    existing_links,
+  );
+
+  void wire__crate__api__discover_semantic_edges_in_index(
+    NativePortType port_,
+    JSAny index_id,
+    double? threshold,
+    JSAny? existing_links,
+    JSAny? max_neighbors,
+  ) => wasmModule.wire__crate__api__discover_semantic_edges_in_index(
+    port_,
+    index_id,
+    threshold,
+    existing_links,
+    max_neighbors,
+  );
+
+  void wire__crate__api__discover_semantic_edges_in_store(
+    NativePortType port_,
+    JSAny store_id,
+    double? threshold,
+    JSAny? existing_links,
+  ) => wasmModule.wire__crate__api__discover_semantic_edges_in_store(
+    port_,
+    store_id,
+    threshold,
+    existing_links,
+  );
+
+  void wire__crate__api__embed_markdown_note(
+    NativePortType port_,
+    String note_id,
+    String text,
+    int? max_tokens,
+    int? overlap_tokens,
+    JSAny? metadata,
+  ) => wasmModule.wire__crate__api__embed_markdown_note(
+    port_,
+    note_id,
+    text,
+    max_tokens,
+    overlap_tokens,
+    metadata,
+  );
+
+  void wire__crate__api__embed_texts(
+    NativePortType port_,
+    JSAny texts,
+    int kind,
+  ) => wasmModule.wire__crate__api__embed_texts(port_, texts, kind);
+
+  void wire__crate__api__extract_topics(
+    NativePortType port_,
+    String text,
+    int? num_topics,
+  ) => wasmModule.wire__crate__api__extract_topics(port_, text, num_topics);
+
+  void wire__crate__api__extract_topics_with_model(
+    NativePortType port_,
+    String text,
+    int? num_topics,
+    String? model_id,
+    double? min_confidence,
+  ) => wasmModule.wire__crate__api__extract_topics_with_model(
+    port_,
+    text,
+    num_topics,
+    model_id,
+    min_confidence,
+  );
+
+  void wire__crate__api__find_duplicate_entries(
+    NativePortType port_,
+    JSAny entries,
+    JSAny? options,
+  ) => wasmModule.wire__crate__api__find_duplicate_entries(
+    port_,
+    entries,
+    options,
+  );
+
+  void wire__crate__api__find_duplicates(
+    NativePortType port_,
+    JSAny documents,
+    JSAny? options,
+  ) => wasmModule.wire__crate__api__find_duplicates(port_, documents, options);
+
+  void wire__crate__api__find_duplicates_in_store(
+    NativePortType port_,
+    JSAny store_id,
+    JSAny? options,
+  ) => wasmModule.wire__crate__api__find_duplicates_in_store(
+    port_,
+    store_id,
+    options,
+  );
+
+  void wire__crate__api__find_similar(
     NativePortType port_,
+    JSAny query,
     JSAny entries,
+    JSAny top_k,
     double threshold,
-  ) => wasmModule.wire__crate__api__cluster_embeddings(
+  ) => wasmModule.wire__crate__api__find_similar(
     port_,
+    query,
     entries,
+    top_k,
     threshold,
   );
 
-  void wire__crate__api__cluster_notes(
+  void wire__crate__api__find_similar_filtered(
     NativePortType port_,
+    JSAny query,
     JSAny entries,
-    JSAny? k,
-    JSAny? max_iterations,
-  ) => wasmModule.wire__crate__api__cluster_notes(
+    JSAny options,
+  ) => wasmModule.wire__crate__api__find_similar_filtered(
     port_,
+    query,
     entries,
-    k,
-    max_iterations,
+    options,
   );
 
-  void wire__crate__api__compute_graph_layout(
-    NativePortType port_,
-    int? iterations,
-  ) => wasmModule.wire__crate__api__compute_graph_layout(port_, iterations);
-
-  void wire__crate__api__connect_note_to_topics(
+  void wire__crate__api__find_similar_in_index(
     NativePortType port_,
-    String note_id,
-    JSAny topic_ids,
-  ) => wasmModule.wire__crate__api__connect_note_to_topics(
+    JSAny index_id,
+    JSAny query,
+    JSAny top_k,
+    double threshold,
+  ) => wasmModule.wire__crate__api__find_similar_in_index(
     port_,
-    note_id,
-    topic_ids,
+    index_id,
+    query,
+    top_k,
+    threshold,
   );
 
-  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-  wire__crate__api__cosine_similarity(JSAny a, JSAny b) =>
-      wasmModule.wire__crate__api__cosine_similarity(a, b);
-
-  void wire__crate__api__discover_semantic_edges(
+  void wire__crate__api__find_similar_in_store(
     NativePortType port_,
-    JSAny entries,
-    double? threshold,
-    JSAny? existing_links,
-  ) => wasmModule.wire__crate__api__discover_semantic_edges(
+    JSAny store_id,
+    JSAny query,
+    JSAny top_k,
+    double threshold,
+  ) => wasmModule.wire__crate__api__find_similar_in_store(
     port_,
-    entries,
+    store_id,
+    query,
+    top_k,
     threshold,
-    existing_links,
   );
 
-  void wire__crate__api__extract_topics(
-    NativePortType port_,
-    String text,
-    int? num_topics,
-  ) => wasmModule.wire__crate__api__extract_topics(port_, text, num_topics);
-
-  void wire__crate__api__find_similar(
+  void wire__crate__api__find_similar_quantized(
     NativePortType port_,
+    JSAny index_id,
     JSAny query,
-    JSAny entries,
     JSAny top_k,
     double threshold,
-  ) => wasmModule.wire__crate__api__find_similar(
+    JSAny? rescore_candidates,
+  ) => wasmModule.wire__crate__api__find_similar_quantized(
     port_,
+    index_id,
     query,
-    entries,
     top_k,
     threshold,
+    rescore_candidates,
   );
 
   void wire__crate__api__generate_text(
@@ -1249,16 +3733,71 @@ class RustLibWire implements BaseWire {
     int? max_tokens,
   ) => wasmModule.wire__crate__api__generate_text(port_, prompt, max_tokens);
 
+  void wire__crate__api__generate_text_stream(
+    NativePortType port_,
+    String prompt,
+    int? max_tokens,
+    JSAny? stop_sequences,
+    String? model_id,
+    String sink,
+  ) => wasmModule.wire__crate__api__generate_text_stream(
+    port_,
+    prompt,
+    max_tokens,
+    stop_sequences,
+    model_id,
+    sink,
+  );
+
+  void wire__crate__api__generate_text_with_model(
+    NativePortType port_,
+    String prompt,
+    int? max_tokens,
+    String? model_id,
+  ) => wasmModule.wire__crate__api__generate_text_with_model(
+    port_,
+    prompt,
+    max_tokens,
+    model_id,
+  );
+
+  void wire__crate__api__generate_text_with_options(
+    NativePortType port_,
+    String prompt,
+    JSAny options,
+  ) => wasmModule.wire__crate__api__generate_text_with_options(
+    port_,
+    prompt,
+    options,
+  );
+
   void wire__crate__api__get_embedding(NativePortType port_, String text) =>
       wasmModule.wire__crate__api__get_embedding(port_, text);
 
+  void wire__crate__api__get_embedding_cache_stats(NativePortType port_) =>
+      wasmModule.wire__crate__api__get_embedding_cache_stats(port_);
+
   JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
   wire__crate__api__get_embedding_dimension() =>
       wasmModule.wire__crate__api__get_embedding_dimension();
 
+  void wire__crate__api__get_embedding_with_model(
+    NativePortType port_,
+    String text,
+    String? model_id,
+  ) => wasmModule.wire__crate__api__get_embedding_with_model(
+    port_,
+    text,
+    model_id,
+  );
+
   void wire__crate__api__get_graph_state(NativePortType port_) =>
       wasmModule.wire__crate__api__get_graph_state(port_);
 
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+  wire__crate__api__get_model_metadata(String? model_id) =>
+      wasmModule.wire__crate__api__get_model_metadata(model_id);
+
   JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
   wire__crate__api__get_model_type() =>
       wasmModule.wire__crate__api__get_model_type();
@@ -1272,16 +3811,85 @@ class RustLibWire implements BaseWire {
   wire__crate__api__get_stream_graph_stats() =>
       wasmModule.wire__crate__api__get_stream_graph_stats();
 
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+  wire__crate__api__get_tokenizer_info(String? model_id) =>
+      wasmModule.wire__crate__api__get_tokenizer_info(model_id);
+
+  void wire__crate__api__get_vector_store_stats(
+    NativePortType port_,
+    JSAny store_id,
+  ) => wasmModule.wire__crate__api__get_vector_store_stats(port_, store_id);
+
   JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
   wire__crate__api__get_version() => wasmModule.wire__crate__api__get_version();
 
   void wire__crate__api__get_visible_graph_nodes(NativePortType port_) =>
       wasmModule.wire__crate__api__get_visible_graph_nodes(port_);
 
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+  wire__crate__api__greedy_sampler_config() =>
+      wasmModule.wire__crate__api__greedy_sampler_config();
+
   JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
   wire__crate__api__health_check() =>
       wasmModule.wire__crate__api__health_check();
 
+  void wire__crate__api__hnsw_index_insert(
+    NativePortType port_,
+    JSAny index_id,
+    JSAny entries,
+  ) => wasmModule.wire__crate__api__hnsw_index_insert(port_, index_id, entries);
+
+  void wire__crate__api__hnsw_index_insert_with_texts(
+    NativePortType port_,
+    JSAny index_id,
+    JSAny entries,
+    JSAny texts,
+  ) => wasmModule.wire__crate__api__hnsw_index_insert_with_texts(
+    port_,
+    index_id,
+    entries,
+    texts,
+  );
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+  wire__crate__api__hnsw_index_len(JSAny index_id) =>
+      wasmModule.wire__crate__api__hnsw_index_len(index_id);
+
+  void wire__crate__api__hnsw_index_remove(
+    NativePortType port_,
+    JSAny index_id,
+    JSAny ids,
+  ) => wasmModule.wire__crate__api__hnsw_index_remove(port_, index_id, ids);
+
+  void wire__crate__api__hybrid_search(
+    NativePortType port_,
+    String query_text,
+    JSAny entries,
+    JSAny top_k,
+    JSAny? options,
+  ) => wasmModule.wire__crate__api__hybrid_search(
+    port_,
+    query_text,
+    entries,
+    top_k,
+    options,
+  );
+
+  void wire__crate__api__hybrid_search_in_index(
+    NativePortType port_,
+    JSAny index_id,
+    String query_text,
+    JSAny top_k,
+    JSAny? options,
+  ) => wasmModule.wire__crate__api__hybrid_search_in_index(
+    port_,
+    index_id,
+    query_text,
+    top_k,
+    options,
+  );
+
   JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
   wire__crate__api__init_graph() => wasmModule.wire__crate__api__init_graph();
 
@@ -1323,6 +3931,15 @@ class RustLibWire implements BaseWire {
     model_type,
   );
 
+  void wire__crate__api__inspect_model(
+    NativePortType port_,
+    String model_path,
+  ) => wasmModule.wire__crate__api__inspect_model(port_, model_path);
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+  wire__crate__api__is_embedding_model_loaded() =>
+      wasmModule.wire__crate__api__is_embedding_model_loaded();
+
   JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
   wire__crate__api__is_graph_stream_running() =>
       wasmModule.wire__crate__api__is_graph_stream_running();
@@ -1335,10 +3952,75 @@ class RustLibWire implements BaseWire {
   wire__crate__api__is_model_loaded() =>
       wasmModule.wire__crate__api__is_model_loaded();
 
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+  wire__crate__api__json_schema_to_grammar(String schema_json) =>
+      wasmModule.wire__crate__api__json_schema_to_grammar(schema_json);
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+  wire__crate__api__list_loaded_models() =>
+      wasmModule.wire__crate__api__list_loaded_models();
+
+  void wire__crate__api__load_chat_session(
+    NativePortType port_,
+    String path,
+    int? n_ctx,
+    String? model_id,
+  ) => wasmModule.wire__crate__api__load_chat_session(
+    port_,
+    path,
+    n_ctx,
+    model_id,
+  );
+
+  void wire__crate__api__load_embedding_model(
+    NativePortType port_,
+    String model_path,
+    JSAny? config,
+  ) => wasmModule.wire__crate__api__load_embedding_model(
+    port_,
+    model_path,
+    config,
+  );
+
+  void wire__crate__api__load_hnsw_index(NativePortType port_, String path) =>
+      wasmModule.wire__crate__api__load_hnsw_index(port_, path);
+
+  void wire__crate__api__load_model(
+    NativePortType port_,
+    String model_id,
+    String model_path,
+    int? n_ctx,
+    int? n_gpu_layers,
+  ) => wasmModule.wire__crate__api__load_model(
+    port_,
+    model_id,
+    model_path,
+    n_ctx,
+    n_gpu_layers,
+  );
+
+  void wire__crate__api__load_quantized_index(
+    NativePortType port_,
+    String path,
+    JSAny? store_id,
+  ) => wasmModule.wire__crate__api__load_quantized_index(port_, path, store_id);
+
   JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
   wire__crate__api__mcp_classify_task(String message) =>
       wasmModule.wire__crate__api__mcp_classify_task(message);
 
+  void wire__crate__api__mcp_classify_task_with_model(
+    NativePortType port_,
+    String message,
+    String? model_id,
+    double? min_confidence,
+  ) => wasmModule.wire__crate__api__mcp_classify_task_with_model(
+    port_,
+    message,
+    model_id,
+    min_confidence,
+  );
+
   void wire__crate__api__mcp_create_folder(NativePortType port_, String path) =>
       wasmModule.wire__crate__api__mcp_create_folder(port_, path);
 
@@ -1350,6 +4032,18 @@ class RustLibWire implements BaseWire {
     JSAny tool_call,
   ) => wasmModule.wire__crate__api__mcp_execute_tool_call(port_, tool_call);
 
+  void wire__crate__api__mcp_generate_tool_call(
+    NativePortType port_,
+    JSAny messages,
+    int? max_tokens,
+    String? model_id,
+  ) => wasmModule.wire__crate__api__mcp_generate_tool_call(
+    port_,
+    messages,
+    max_tokens,
+    model_id,
+  );
+
   JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
   wire__crate__api__mcp_get_all_tools() =>
       wasmModule.wire__crate__api__mcp_get_all_tools();
@@ -1358,6 +4052,10 @@ class RustLibWire implements BaseWire {
   wire__crate__api__mcp_get_model_for_task(int category) =>
       wasmModule.wire__crate__api__mcp_get_model_for_task(category);
 
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+  wire__crate__api__mcp_get_model_id_for_task(int category) =>
+      wasmModule.wire__crate__api__mcp_get_model_id_for_task(category);
+
   JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
   wire__crate__api__mcp_get_tool_description(int tool) =>
       wasmModule.wire__crate__api__mcp_get_tool_description(tool);
@@ -1382,9 +4080,43 @@ class RustLibWire implements BaseWire {
     String json,
   ) => wasmModule.wire__crate__api__mcp_parse_tool_call(port_, json);
 
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+  wire__crate__api__mcp_parse_tool_calls(String reply, int? model_type) =>
+      wasmModule.wire__crate__api__mcp_parse_tool_calls(reply, model_type);
+
   void wire__crate__api__mcp_read_file(NativePortType port_, String path) =>
       wasmModule.wire__crate__api__mcp_read_file(port_, path);
 
+  void wire__crate__api__mcp_run_agent(
+    NativePortType port_,
+    JSAny messages,
+    int? max_steps,
+    int? max_tokens,
+    String? model_id,
+  ) => wasmModule.wire__crate__api__mcp_run_agent(
+    port_,
+    messages,
+    max_steps,
+    max_tokens,
+    model_id,
+  );
+
+  void wire__crate__api__mcp_run_agent_stream(
+    NativePortType port_,
+    JSAny messages,
+    int? max_steps,
+    int? max_tokens,
+    String? model_id,
+    String sink,
+  ) => wasmModule.wire__crate__api__mcp_run_agent_stream(
+    port_,
+    messages,
+    max_steps,
+    max_tokens,
+    model_id,
+    sink,
+  );
+
   JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
   wire__crate__api__mcp_validate_path(String path) =>
       wasmModule.wire__crate__api__mcp_validate_path(path);
@@ -1395,38 +4127,187 @@ class RustLibWire implements BaseWire {
     String content,
   ) => wasmModule.wire__crate__api__mcp_write_file(port_, path, content);
 
+  void wire__crate__api__measure_quantization_recall(
+    NativePortType port_,
+    JSAny entries,
+    JSAny? queries,
+    int kind,
+    JSAny top_k,
+    JSAny rescore_candidates,
+  ) => wasmModule.wire__crate__api__measure_quantization_recall(
+    port_,
+    entries,
+    queries,
+    kind,
+    top_k,
+    rescore_candidates,
+  );
+
+  void wire__crate__api__open_embedding_cache(
+    NativePortType port_,
+    String path,
+  ) => wasmModule.wire__crate__api__open_embedding_cache(port_, path);
+
+  void wire__crate__api__open_vector_store(NativePortType port_, String path) =>
+      wasmModule.wire__crate__api__open_vector_store(port_, path);
+
   void wire__crate__api__pin_stream_node(
     NativePortType port_,
     String id,
     bool pinned,
   ) => wasmModule.wire__crate__api__pin_stream_node(port_, id, pinned);
 
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+  wire__crate__api__poll_generation(JSAny request_id) =>
+      wasmModule.wire__crate__api__poll_generation(request_id);
+
+  void wire__crate__api__quantized_index_insert(
+    NativePortType port_,
+    JSAny index_id,
+    JSAny entries,
+  ) => wasmModule.wire__crate__api__quantized_index_insert(
+    port_,
+    index_id,
+    entries,
+  );
+
+  void wire__crate__api__quantized_index_remove(
+    NativePortType port_,
+    JSAny index_id,
+    JSAny ids,
+  ) =>
+      wasmModule.wire__crate__api__quantized_index_remove(port_, index_id, ids);
+
+  void wire__crate__api__rag_answer(
+    NativePortType port_,
+    String question,
+    JSAny chunks,
+    int? top_k,
+    int? context_tokens,
+    int? max_tokens,
+    String? model_id,
+  ) => wasmModule.wire__crate__api__rag_answer(
+    port_,
+    question,
+    chunks,
+    top_k,
+    context_tokens,
+    max_tokens,
+    model_id,
+  );
+
   void wire__crate__api__remove_graph_node(
     NativePortType port_,
     String node_id,
   ) => wasmModule.wire__crate__api__remove_graph_node(port_, node_id);
 
-  void wire__crate__api__remove_stream_edge(
+  void wire__crate__api__remove_stream_edge(
+    NativePortType port_,
+    String from_id,
+    String to_id,
+  ) => wasmModule.wire__crate__api__remove_stream_edge(port_, from_id, to_id);
+
+  void wire__crate__api__remove_stream_node(NativePortType port_, String id) =>
+      wasmModule.wire__crate__api__remove_stream_node(port_, id);
+
+  void wire__crate__api__save_chat_session(
+    NativePortType port_,
+    JSAny session_id,
+    String path,
+  ) => wasmModule.wire__crate__api__save_chat_session(port_, session_id, path);
+
+  void wire__crate__api__save_hnsw_index(
+    NativePortType port_,
+    JSAny index_id,
+    String path,
+  ) => wasmModule.wire__crate__api__save_hnsw_index(port_, index_id, path);
+
+  void wire__crate__api__save_quantized_index(
+    NativePortType port_,
+    JSAny index_id,
+    String path,
+  ) => wasmModule.wire__crate__api__save_quantized_index(port_, index_id, path);
+
+  void wire__crate__api__semantic_search(
+    NativePortType port_,
+    String query_text,
+    JSAny entries,
+    JSAny top_k,
+  ) => wasmModule.wire__crate__api__semantic_search(
+    port_,
+    query_text,
+    entries,
+    top_k,
+  );
+
+  void wire__crate__api__semantic_search_filtered(
+    NativePortType port_,
+    String query_text,
+    JSAny entries,
+    JSAny options,
+  ) => wasmModule.wire__crate__api__semantic_search_filtered(
+    port_,
+    query_text,
+    entries,
+    options,
+  );
+
+  void wire__crate__api__semantic_search_in_index(
     NativePortType port_,
-    String from_id,
-    String to_id,
-  ) => wasmModule.wire__crate__api__remove_stream_edge(port_, from_id, to_id);
+    JSAny index_id,
+    String query_text,
+    JSAny top_k,
+  ) => wasmModule.wire__crate__api__semantic_search_in_index(
+    port_,
+    index_id,
+    query_text,
+    top_k,
+  );
 
-  void wire__crate__api__remove_stream_node(NativePortType port_, String id) =>
-      wasmModule.wire__crate__api__remove_stream_node(port_, id);
+  void wire__crate__api__semantic_search_in_index_filtered(
+    NativePortType port_,
+    JSAny index_id,
+    String query_text,
+    JSAny options,
+  ) => wasmModule.wire__crate__api__semantic_search_in_index_filtered(
+    port_,
+    index_id,
+    query_text,
+    options,
+  );
 
-  void wire__crate__api__semantic_search(
+  void wire__crate__api__semantic_search_in_store(
     NativePortType port_,
+    JSAny store_id,
     String query_text,
-    JSAny entries,
     JSAny top_k,
-  ) => wasmModule.wire__crate__api__semantic_search(
+  ) => wasmModule.wire__crate__api__semantic_search_in_store(
     port_,
+    store_id,
     query_text,
-    entries,
     top_k,
   );
 
+  void wire__crate__api__semantic_search_in_store_filtered(
+    NativePortType port_,
+    JSAny store_id,
+    String query_text,
+    JSAny options,
+  ) => wasmModule.wire__crate__api__semantic_search_in_store_filtered(
+    port_,
+    store_id,
+    query_text,
+    options,
+  );
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+  wire__crate__api__set_default_model(String model_id) =>
+      wasmModule.wire__crate__api__set_default_model(model_id);
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+  wire__crate__api__set_model_memory_budget(JSAny? budget_bytes) =>
+      wasmModule.wire__crate__api__set_model_memory_budget(budget_bytes);
+
   void wire__crate__api__set_stream_node_position(
     NativePortType port_,
     String id,
@@ -1434,6 +4315,34 @@ class RustLibWire implements BaseWire {
     double y,
   ) => wasmModule.wire__crate__api__set_stream_node_position(port_, id, x, y);
 
+  void wire__crate__api__start_chat_completion(
+    NativePortType port_,
+    JSAny messages,
+    int? max_tokens,
+    JSAny? stop_sequences,
+    String? model_id,
+  ) => wasmModule.wire__crate__api__start_chat_completion(
+    port_,
+    messages,
+    max_tokens,
+    stop_sequences,
+    model_id,
+  );
+
+  void wire__crate__api__start_generation(
+    NativePortType port_,
+    String prompt,
+    int? max_tokens,
+    JSAny? stop_sequences,
+    String? model_id,
+  ) => wasmModule.wire__crate__api__start_generation(
+    port_,
+    prompt,
+    max_tokens,
+    stop_sequences,
+    model_id,
+  );
+
   void wire__crate__api__start_graph_stream(NativePortType port_) =>
       wasmModule.wire__crate__api__start_graph_stream(port_);
 
@@ -1441,10 +4350,48 @@ class RustLibWire implements BaseWire {
   wire__crate__api__stop_graph_stream() =>
       wasmModule.wire__crate__api__stop_graph_stream();
 
+  void wire__crate__api__summarize_text(
+    NativePortType port_,
+    String text,
+    JSAny? options,
+  ) => wasmModule.wire__crate__api__summarize_text(port_, text, options);
+
+  void wire__crate__api__summarize_transcript(
+    NativePortType port_,
+    JSAny segments,
+    JSAny? options,
+  ) => wasmModule.wire__crate__api__summarize_transcript(
+    port_,
+    segments,
+    options,
+  );
+
+  void wire__crate__api__tokenize_text(
+    NativePortType port_,
+    String text,
+    bool add_bos,
+    bool parse_special,
+    String? model_id,
+  ) => wasmModule.wire__crate__api__tokenize_text(
+    port_,
+    text,
+    add_bos,
+    parse_special,
+    model_id,
+  );
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+  wire__crate__api__unload_embedding_model() =>
+      wasmModule.wire__crate__api__unload_embedding_model();
+
   JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
   wire__crate__api__unload_model() =>
       wasmModule.wire__crate__api__unload_model();
 
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+  wire__crate__api__unload_model_by_id(String model_id) =>
+      wasmModule.wire__crate__api__unload_model_by_id(model_id);
+
   void wire__crate__api__update_graph_viewport(
     NativePortType port_,
     double x,
@@ -1460,6 +4407,56 @@ class RustLibWire implements BaseWire {
     height,
     scale,
   );
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+  wire__crate__api__use_llama_inference_backend() =>
+      wasmModule.wire__crate__api__use_llama_inference_backend();
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+  wire__crate__api__use_mock_inference_backend(
+    JSAny completions,
+    String? fallback,
+    int embedding_dimension,
+  ) => wasmModule.wire__crate__api__use_mock_inference_backend(
+    completions,
+    fallback,
+    embedding_dimension,
+  );
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+  wire__crate__api__validate_search_filter(String filter) =>
+      wasmModule.wire__crate__api__validate_search_filter(filter);
+
+  void wire__crate__api__vector_store_delete(
+    NativePortType port_,
+    JSAny store_id,
+    JSAny ids,
+  ) => wasmModule.wire__crate__api__vector_store_delete(port_, store_id, ids);
+
+  void wire__crate__api__vector_store_get(
+    NativePortType port_,
+    JSAny store_id,
+    JSAny ids,
+  ) => wasmModule.wire__crate__api__vector_store_get(port_, store_id, ids);
+
+  JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+  wire__crate__api__vector_store_len(JSAny store_id) =>
+      wasmModule.wire__crate__api__vector_store_len(store_id);
+
+  void wire__crate__api__vector_store_upsert(
+    NativePortType port_,
+    JSAny store_id,
+    JSAny entries,
+  ) => wasmModule.wire__crate__api__vector_store_upsert(
+    port_,
+    store_id,
+    entries,
+  );
+
+  void wire__crate__api__wait_for_generation(
+    NativePortType port_,
+    JSAny request_id,
+  ) => wasmModule.wire__crate__api__wait_for_generation(port_, request_id);
 }
 
 @JS('wasm_bindgen')
@@ -1521,69 +4518,331 @@ extension type RustLibWasmModule._(JSObject _) implements JSObject {
     JSAny? existing_links,
   );
 
+  external void wire__crate__api__analyze_knowledge_graph_in_store(
+    NativePortType port_,
+    JSAny store_id,
+    JSAny? k,
+    double? similarity_threshold,
+    JSAny? existing_links,
+  );
+
   external void wire__crate__api__batch_embed(
     NativePortType port_,
     JSAny texts,
   );
 
+  external void wire__crate__api__batch_embed_with_model(
+    NativePortType port_,
+    JSAny texts,
+    String? model_id,
+  );
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+  wire__crate__api__cancel_generation(JSAny request_id);
+
   external void wire__crate__api__chat_completion(
     NativePortType port_,
     JSAny messages,
     int? max_tokens,
   );
 
+  external void wire__crate__api__chat_completion_stream(
+    NativePortType port_,
+    JSAny messages,
+    int? max_tokens,
+    JSAny? stop_sequences,
+    String? model_id,
+    String sink,
+  );
+
+  external void wire__crate__api__chat_completion_with_model(
+    NativePortType port_,
+    JSAny messages,
+    int? max_tokens,
+    String? model_id,
+  );
+
+  external void wire__crate__api__chat_completion_with_options(
+    NativePortType port_,
+    JSAny messages,
+    JSAny options,
+  );
+
+  external void wire__crate__api__chat_session_completion(
+    NativePortType port_,
+    JSAny session_id,
+    JSAny messages,
+    JSAny options,
+  );
+
+  external void wire__crate__api__chat_session_stream(
+    NativePortType port_,
+    JSAny session_id,
+    JSAny messages,
+    int? max_tokens,
+    JSAny? stop_sequences,
+    String sink,
+  );
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+  wire__crate__api__chat_session_token_count(JSAny session_id);
+
+  external void wire__crate__api__chunk_markdown(
+    NativePortType port_,
+    String text,
+    int? max_tokens,
+    int? overlap_tokens,
+    String? model_id,
+  );
+
   external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
   wire__crate__api__clear_graph();
 
   external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
   wire__crate__api__clear_stream_graph();
 
-  external void wire__crate__api__cluster_embeddings(
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+  wire__crate__api__close_chat_session(JSAny session_id);
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+  wire__crate__api__close_embedding_cache();
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+  wire__crate__api__close_hnsw_index(JSAny index_id);
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+  wire__crate__api__close_quantized_index(JSAny index_id);
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+  wire__crate__api__close_vector_store(JSAny store_id);
+
+  external void wire__crate__api__cluster_embeddings(
+    NativePortType port_,
+    JSAny entries,
+    double threshold,
+  );
+
+  external void wire__crate__api__cluster_notes(
+    NativePortType port_,
+    JSAny entries,
+    JSAny? k,
+    JSAny? max_iterations,
+  );
+
+  external void wire__crate__api__cluster_notes_in_store(
+    NativePortType port_,
+    JSAny store_id,
+    JSAny? k,
+    JSAny? max_iterations,
+  );
+
+  external void wire__crate__api__compact_embedding_cache(NativePortType port_);
+
+  external void wire__crate__api__compact_vector_store(
+    NativePortType port_,
+    JSAny store_id,
+  );
+
+  external void wire__crate__api__compute_graph_layout(
+    NativePortType port_,
+    int? iterations,
+  );
+
+  external void wire__crate__api__connect_note_to_topics(
+    NativePortType port_,
+    String note_id,
+    JSAny topic_ids,
+  );
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+  wire__crate__api__cosine_similarity(JSAny a, JSAny b);
+
+  external void wire__crate__api__count_chat_prompt_tokens(
+    NativePortType port_,
+    JSAny messages,
+    String? model_id,
+  );
+
+  external void wire__crate__api__count_chat_tokens(
+    NativePortType port_,
+    JSAny messages,
+    String? model_id,
+  );
+
+  external void wire__crate__api__count_tokens(
+    NativePortType port_,
+    String text,
+    String? model_id,
+  );
+
+  external void wire__crate__api__create_chat_session(
+    NativePortType port_,
+    int? n_ctx,
+    String? model_id,
+  );
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+  wire__crate__api__create_hnsw_index(JSAny? config);
+
+  external void wire__crate__api__create_hnsw_index_from_store(
+    NativePortType port_,
+    JSAny store_id,
+    JSAny? config,
+  );
+
+  external void wire__crate__api__create_quantized_index(
+    NativePortType port_,
+    JSAny entries,
+    int kind,
+  );
+
+  external void wire__crate__api__create_quantized_index_from_store(
+    NativePortType port_,
+    JSAny store_id,
+    int kind,
+  );
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+  wire__crate__api__default_context_policy();
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+  wire__crate__api__default_dedup_options();
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+  wire__crate__api__default_embedding_config();
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+  wire__crate__api__default_hnsw_config();
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+  wire__crate__api__default_hybrid_options();
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+  wire__crate__api__default_reasoning_options();
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+  wire__crate__api__default_sampler_config();
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+  wire__crate__api__default_search_options();
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+  wire__crate__api__default_summary_options();
+
+  external void wire__crate__api__detokenize_tokens(
+    NativePortType port_,
+    JSAny tokens,
+    bool render_special,
+    String? model_id,
+  );
+
+  external void wire__crate__api__discover_semantic_edges(
+    NativePortType port_,
+    JSAny entries,
+    double? threshold,
+    JSAny? existing_links,
+  );
+
+  external void wire__crate__api__discover_semantic_edges_in_index(
+    NativePortType port_,
+    JSAny index_id,
+    double? threshold,
+    JSAny? existing_links,
+    JSAny? max_neighbors,
+  );
+
+  external void wire__crate__api__discover_semantic_edges_in_store(
+    NativePortType port_,
+    JSAny store_id,
+    double? threshold,
+    JSAny? existing_links,
+  );
+
+  external void wire__crate__api__embed_markdown_note(
+    NativePortType port_,
+    String note_id,
+    String text,
+    int? max_tokens,
+    int? overlap_tokens,
+    JSAny? metadata,
+  );
+
+  external void wire__crate__api__embed_texts(
+    NativePortType port_,
+    JSAny texts,
+    int kind,
+  );
+
+  external void wire__crate__api__extract_topics(
+    NativePortType port_,
+    String text,
+    int? num_topics,
+  );
+
+  external void wire__crate__api__extract_topics_with_model(
+    NativePortType port_,
+    String text,
+    int? num_topics,
+    String? model_id,
+    double? min_confidence,
+  );
+
+  external void wire__crate__api__find_duplicate_entries(
     NativePortType port_,
     JSAny entries,
-    double threshold,
+    JSAny? options,
   );
 
-  external void wire__crate__api__cluster_notes(
+  external void wire__crate__api__find_duplicates(
     NativePortType port_,
-    JSAny entries,
-    JSAny? k,
-    JSAny? max_iterations,
+    JSAny documents,
+    JSAny? options,
   );
 
-  external void wire__crate__api__compute_graph_layout(
+  external void wire__crate__api__find_duplicates_in_store(
     NativePortType port_,
-    int? iterations,
+    JSAny store_id,
+    JSAny? options,
   );
 
-  external void wire__crate__api__connect_note_to_topics(
+  external void wire__crate__api__find_similar(
     NativePortType port_,
-    String note_id,
-    JSAny topic_ids,
+    JSAny query,
+    JSAny entries,
+    JSAny top_k,
+    double threshold,
   );
 
-  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
-  wire__crate__api__cosine_similarity(JSAny a, JSAny b);
-
-  external void wire__crate__api__discover_semantic_edges(
+  external void wire__crate__api__find_similar_filtered(
     NativePortType port_,
+    JSAny query,
     JSAny entries,
-    double? threshold,
-    JSAny? existing_links,
+    JSAny options,
   );
 
-  external void wire__crate__api__extract_topics(
+  external void wire__crate__api__find_similar_in_index(
     NativePortType port_,
-    String text,
-    int? num_topics,
+    JSAny index_id,
+    JSAny query,
+    JSAny top_k,
+    double threshold,
   );
 
-  external void wire__crate__api__find_similar(
+  external void wire__crate__api__find_similar_in_store(
     NativePortType port_,
+    JSAny store_id,
+    JSAny query,
+    JSAny top_k,
+    double threshold,
+  );
+
+  external void wire__crate__api__find_similar_quantized(
+    NativePortType port_,
+    JSAny index_id,
     JSAny query,
-    JSAny entries,
     JSAny top_k,
     double threshold,
+    JSAny? rescore_candidates,
   );
 
   external void wire__crate__api__generate_text(
@@ -1592,16 +4851,51 @@ extension type RustLibWasmModule._(JSObject _) implements JSObject {
     int? max_tokens,
   );
 
+  external void wire__crate__api__generate_text_stream(
+    NativePortType port_,
+    String prompt,
+    int? max_tokens,
+    JSAny? stop_sequences,
+    String? model_id,
+    String sink,
+  );
+
+  external void wire__crate__api__generate_text_with_model(
+    NativePortType port_,
+    String prompt,
+    int? max_tokens,
+    String? model_id,
+  );
+
+  external void wire__crate__api__generate_text_with_options(
+    NativePortType port_,
+    String prompt,
+    JSAny options,
+  );
+
   external void wire__crate__api__get_embedding(
     NativePortType port_,
     String text,
   );
 
+  external void wire__crate__api__get_embedding_cache_stats(
+    NativePortType port_,
+  );
+
   external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
   wire__crate__api__get_embedding_dimension();
 
+  external void wire__crate__api__get_embedding_with_model(
+    NativePortType port_,
+    String text,
+    String? model_id,
+  );
+
   external void wire__crate__api__get_graph_state(NativePortType port_);
 
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+  wire__crate__api__get_model_metadata(String? model_id);
+
   external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
   wire__crate__api__get_model_type();
 
@@ -1613,14 +4907,63 @@ extension type RustLibWasmModule._(JSObject _) implements JSObject {
   external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
   wire__crate__api__get_stream_graph_stats();
 
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+  wire__crate__api__get_tokenizer_info(String? model_id);
+
+  external void wire__crate__api__get_vector_store_stats(
+    NativePortType port_,
+    JSAny store_id,
+  );
+
   external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
   wire__crate__api__get_version();
 
   external void wire__crate__api__get_visible_graph_nodes(NativePortType port_);
 
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+  wire__crate__api__greedy_sampler_config();
+
   external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
   wire__crate__api__health_check();
 
+  external void wire__crate__api__hnsw_index_insert(
+    NativePortType port_,
+    JSAny index_id,
+    JSAny entries,
+  );
+
+  external void wire__crate__api__hnsw_index_insert_with_texts(
+    NativePortType port_,
+    JSAny index_id,
+    JSAny entries,
+    JSAny texts,
+  );
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+  wire__crate__api__hnsw_index_len(JSAny index_id);
+
+  external void wire__crate__api__hnsw_index_remove(
+    NativePortType port_,
+    JSAny index_id,
+    JSAny ids,
+  );
+
+  external void wire__crate__api__hybrid_search(
+    NativePortType port_,
+    String query_text,
+    JSAny entries,
+    JSAny top_k,
+    JSAny? options,
+  );
+
+  external void wire__crate__api__hybrid_search_in_index(
+    NativePortType port_,
+    JSAny index_id,
+    String query_text,
+    JSAny top_k,
+    JSAny? options,
+  );
+
   external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
   wire__crate__api__init_graph();
 
@@ -1646,6 +4989,14 @@ extension type RustLibWasmModule._(JSObject _) implements JSObject {
     int? model_type,
   );
 
+  external void wire__crate__api__inspect_model(
+    NativePortType port_,
+    String model_path,
+  );
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+  wire__crate__api__is_embedding_model_loaded();
+
   external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
   wire__crate__api__is_graph_stream_running();
 
@@ -1655,9 +5006,54 @@ extension type RustLibWasmModule._(JSObject _) implements JSObject {
   external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
   wire__crate__api__is_model_loaded();
 
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+  wire__crate__api__json_schema_to_grammar(String schema_json);
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+  wire__crate__api__list_loaded_models();
+
+  external void wire__crate__api__load_chat_session(
+    NativePortType port_,
+    String path,
+    int? n_ctx,
+    String? model_id,
+  );
+
+  external void wire__crate__api__load_embedding_model(
+    NativePortType port_,
+    String model_path,
+    JSAny? config,
+  );
+
+  external void wire__crate__api__load_hnsw_index(
+    NativePortType port_,
+    String path,
+  );
+
+  external void wire__crate__api__load_model(
+    NativePortType port_,
+    String model_id,
+    String model_path,
+    int? n_ctx,
+    int? n_gpu_layers,
+  );
+
+  external void wire__crate__api__load_quantized_index(
+    NativePortType port_,
+    String path,
+    JSAny? store_id,
+  );
+
   external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
   wire__crate__api__mcp_classify_task(String message);
 
+  external void wire__crate__api__mcp_classify_task_with_model(
+    NativePortType port_,
+    String message,
+    String? model_id,
+    double? min_confidence,
+  );
+
   external void wire__crate__api__mcp_create_folder(
     NativePortType port_,
     String path,
@@ -1673,12 +5069,22 @@ extension type RustLibWasmModule._(JSObject _) implements JSObject {
     JSAny tool_call,
   );
 
+  external void wire__crate__api__mcp_generate_tool_call(
+    NativePortType port_,
+    JSAny messages,
+    int? max_tokens,
+    String? model_id,
+  );
+
   external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
   wire__crate__api__mcp_get_all_tools();
 
   external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
   wire__crate__api__mcp_get_model_for_task(int category);
 
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+  wire__crate__api__mcp_get_model_id_for_task(int category);
+
   external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
   wire__crate__api__mcp_get_tool_description(int tool);
 
@@ -1701,11 +5107,31 @@ extension type RustLibWasmModule._(JSObject _) implements JSObject {
     String json,
   );
 
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+  wire__crate__api__mcp_parse_tool_calls(String reply, int? model_type);
+
   external void wire__crate__api__mcp_read_file(
     NativePortType port_,
     String path,
   );
 
+  external void wire__crate__api__mcp_run_agent(
+    NativePortType port_,
+    JSAny messages,
+    int? max_steps,
+    int? max_tokens,
+    String? model_id,
+  );
+
+  external void wire__crate__api__mcp_run_agent_stream(
+    NativePortType port_,
+    JSAny messages,
+    int? max_steps,
+    int? max_tokens,
+    String? model_id,
+    String sink,
+  );
+
   external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
   wire__crate__api__mcp_validate_path(String path);
 
@@ -1715,12 +5141,56 @@ extension type RustLibWasmModule._(JSObject _) implements JSObject {
     String content,
   );
 
+  external void wire__crate__api__measure_quantization_recall(
+    NativePortType port_,
+    JSAny entries,
+    JSAny? queries,
+    int kind,
+    JSAny top_k,
+    JSAny rescore_candidates,
+  );
+
+  external void wire__crate__api__open_embedding_cache(
+    NativePortType port_,
+    String path,
+  );
+
+  external void wire__crate__api__open_vector_store(
+    NativePortType port_,
+    String path,
+  );
+
   external void wire__crate__api__pin_stream_node(
     NativePortType port_,
     String id,
     bool pinned,
   );
 
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+  wire__crate__api__poll_generation(JSAny request_id);
+
+  external void wire__crate__api__quantized_index_insert(
+    NativePortType port_,
+    JSAny index_id,
+    JSAny entries,
+  );
+
+  external void wire__crate__api__quantized_index_remove(
+    NativePortType port_,
+    JSAny index_id,
+    JSAny ids,
+  );
+
+  external void wire__crate__api__rag_answer(
+    NativePortType port_,
+    String question,
+    JSAny chunks,
+    int? top_k,
+    int? context_tokens,
+    int? max_tokens,
+    String? model_id,
+  );
+
   external void wire__crate__api__remove_graph_node(
     NativePortType port_,
     String node_id,
@@ -1737,6 +5207,24 @@ extension type RustLibWasmModule._(JSObject _) implements JSObject {
     String id,
   );
 
+  external void wire__crate__api__save_chat_session(
+    NativePortType port_,
+    JSAny session_id,
+    String path,
+  );
+
+  external void wire__crate__api__save_hnsw_index(
+    NativePortType port_,
+    JSAny index_id,
+    String path,
+  );
+
+  external void wire__crate__api__save_quantized_index(
+    NativePortType port_,
+    JSAny index_id,
+    String path,
+  );
+
   external void wire__crate__api__semantic_search(
     NativePortType port_,
     String query_text,
@@ -1744,6 +5232,47 @@ extension type RustLibWasmModule._(JSObject _) implements JSObject {
     JSAny top_k,
   );
 
+  external void wire__crate__api__semantic_search_filtered(
+    NativePortType port_,
+    String query_text,
+    JSAny entries,
+    JSAny options,
+  );
+
+  external void wire__crate__api__semantic_search_in_index(
+    NativePortType port_,
+    JSAny index_id,
+    String query_text,
+    JSAny top_k,
+  );
+
+  external void wire__crate__api__semantic_search_in_index_filtered(
+    NativePortType port_,
+    JSAny index_id,
+    String query_text,
+    JSAny options,
+  );
+
+  external void wire__crate__api__semantic_search_in_store(
+    NativePortType port_,
+    JSAny store_id,
+    String query_text,
+    JSAny top_k,
+  );
+
+  external void wire__crate__api__semantic_search_in_store_filtered(
+    NativePortType port_,
+    JSAny store_id,
+    String query_text,
+    JSAny options,
+  );
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+  wire__crate__api__set_default_model(String model_id);
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+  wire__crate__api__set_model_memory_budget(JSAny? budget_bytes);
+
   external void wire__crate__api__set_stream_node_position(
     NativePortType port_,
     String id,
@@ -1751,14 +5280,56 @@ extension type RustLibWasmModule._(JSObject _) implements JSObject {
     double y,
   );
 
+  external void wire__crate__api__start_chat_completion(
+    NativePortType port_,
+    JSAny messages,
+    int? max_tokens,
+    JSAny? stop_sequences,
+    String? model_id,
+  );
+
+  external void wire__crate__api__start_generation(
+    NativePortType port_,
+    String prompt,
+    int? max_tokens,
+    JSAny? stop_sequences,
+    String? model_id,
+  );
+
   external void wire__crate__api__start_graph_stream(NativePortType port_);
 
   external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
   wire__crate__api__stop_graph_stream();
 
+  external void wire__crate__api__summarize_text(
+    NativePortType port_,
+    String text,
+    JSAny? options,
+  );
+
+  external void wire__crate__api__summarize_transcript(
+    NativePortType port_,
+    JSAny segments,
+    JSAny? options,
+  );
+
+  external void wire__crate__api__tokenize_text(
+    NativePortType port_,
+    String text,
+    bool add_bos,
+    bool parse_special,
+    String? model_id,
+  );
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+  wire__crate__api__unload_embedding_model();
+
   external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
   wire__crate__api__unload_model();
 
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+  wire__crate__api__unload_model_by_id(String model_id);
+
   external void wire__crate__api__update_graph_viewport(
     NativePortType port_,
     double x,
@@ -1767,4 +5338,43 @@ extension type RustLibWasmModule._(JSObject _) implements JSObject {
     double height,
     double scale,
   );
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+  wire__crate__api__use_llama_inference_backend();
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+  wire__crate__api__use_mock_inference_backend(
+    JSAny completions,
+    String? fallback,
+    int embedding_dimension,
+  );
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+  wire__crate__api__validate_search_filter(String filter);
+
+  external void wire__crate__api__vector_store_delete(
+    NativePortType port_,
+    JSAny store_id,
+    JSAny ids,
+  );
+
+  external void wire__crate__api__vector_store_get(
+    NativePortType port_,
+    JSAny store_id,
+    JSAny ids,
+  );
+
+  external JSAny? /* flutter_rust_bridge::for_generated::WireSyncRust2DartDco */
+  wire__crate__api__vector_store_len(JSAny store_id);
+
+  external void wire__crate__api__vector_store_upsert(
+    NativePortType port_,
+    JSAny store_id,
+    JSAny entries,
+  );
+
+  external void wire__crate__api__wait_for_generation(
+    NativePortType port_,
+    JSAny request_id,
+  );
 }
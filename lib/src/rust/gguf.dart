@@ -0,0 +1,118 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:kivixa/src/rust/frb_generated.dart';
+
+/// Model details read from a GGUF header
+class GgufMetadata {
+  /// GGUF format version
+  final int version;
+
+  /// Model architecture (e.g. "llama", "qwen2", "phi3", "gemma3")
+  final String? architecture;
+
+  /// Model name set by the converter
+  final String? name;
+
+  /// Jinja chat template embedded in the file
+  final String? chatTemplate;
+
+  /// Context length the model was trained with
+  final BigInt? contextLength;
+
+  /// Size of the hidden state (embedding dimension)
+  final BigInt? embeddingLength;
+
+  /// Number of transformer layers
+  final BigInt? blockCount;
+
+  /// Pooling declared by embedding models (llama.cpp `LLAMA_POOLING_TYPE_*`)
+  final int? poolingType;
+
+  /// Quantization type of the weights (e.g. "Q4_K_M")
+  final String? quantization;
+
+  /// Total number of weights across all tensors
+  final BigInt parameterCount;
+
+  /// Number of tensors in the file
+  final BigInt tensorCount;
+
+  /// Tokenizer family (e.g. "gpt2", "llama")
+  final String? tokenizerModel;
+
+  /// Number of tokens in the vocabulary
+  final BigInt? vocabSize;
+
+  /// Beginning-of-sequence token id
+  final int? bosTokenId;
+
+  /// End-of-sequence token id
+  final int? eosTokenId;
+
+  /// Size of the model file in bytes
+  final BigInt fileSize;
+
+  const GgufMetadata({
+    required this.version,
+    this.architecture,
+    this.name,
+    this.chatTemplate,
+    this.contextLength,
+    this.embeddingLength,
+    this.blockCount,
+    this.poolingType,
+    this.quantization,
+    required this.parameterCount,
+    required this.tensorCount,
+    this.tokenizerModel,
+    this.vocabSize,
+    this.bosTokenId,
+    this.eosTokenId,
+    required this.fileSize,
+  });
+
+  @override
+  int get hashCode =>
+      version.hashCode ^
+      architecture.hashCode ^
+      name.hashCode ^
+      chatTemplate.hashCode ^
+      contextLength.hashCode ^
+      embeddingLength.hashCode ^
+      blockCount.hashCode ^
+      poolingType.hashCode ^
+      quantization.hashCode ^
+      parameterCount.hashCode ^
+      tensorCount.hashCode ^
+      tokenizerModel.hashCode ^
+      vocabSize.hashCode ^
+      bosTokenId.hashCode ^
+      eosTokenId.hashCode ^
+      fileSize.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is GgufMetadata &&
+          runtimeType == other.runtimeType &&
+          version == other.version &&
+          architecture == other.architecture &&
+          name == other.name &&
+          chatTemplate == other.chatTemplate &&
+          contextLength == other.contextLength &&
+          embeddingLength == other.embeddingLength &&
+          blockCount == other.blockCount &&
+          poolingType == other.poolingType &&
+          quantization == other.quantization &&
+          parameterCount == other.parameterCount &&
+          tensorCount == other.tensorCount &&
+          tokenizerModel == other.tokenizerModel &&
+          vocabSize == other.vocabSize &&
+          bosTokenId == other.bosTokenId &&
+          eosTokenId == other.eosTokenId &&
+          fileSize == other.fileSize;
+}
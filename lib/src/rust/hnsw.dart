@@ -0,0 +1,37 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:kivixa/src/rust/frb_generated.dart';
+
+/// Graph parameters
+class HnswConfig {
+  /// Links per node on the upper layers; twice as many on layer 0 (default: 16)
+  final int m;
+
+  /// Candidates considered while inserting (default: 200)
+  final int efConstruction;
+
+  /// Candidates considered while searching (default: 64)
+  final int efSearch;
+
+  const HnswConfig({
+    required this.m,
+    required this.efConstruction,
+    required this.efSearch,
+  });
+
+  @override
+  int get hashCode => m.hashCode ^ efConstruction.hashCode ^ efSearch.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is HnswConfig &&
+          runtimeType == other.runtimeType &&
+          m == other.m &&
+          efConstruction == other.efConstruction &&
+          efSearch == other.efSearch;
+}
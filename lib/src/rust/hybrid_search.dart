@@ -0,0 +1,124 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:kivixa/src/rust/frb_generated.dart';
+
+/// How the two rankings are combined
+enum FusionMode {
+  /// Sum of `weight / (rrf_k + rank)` over both rankings
+  reciprocalRank,
+
+  /// Weighted sum of the cosine score and the BM25 score scaled to 0..1
+  weighted,
+}
+
+/// A hybrid search result
+class HybridHit {
+  /// ID of the matched entry
+  final String id;
+
+  /// Fused score used for ranking
+  final double score;
+
+  /// Cosine similarity, if the entry was a semantic candidate
+  final double? semanticScore;
+
+  /// 1-based rank in the semantic ranking
+  final int? semanticRank;
+
+  /// BM25 score, if the entry matched a query term
+  final double? lexicalScore;
+
+  /// 1-based rank in the keyword ranking
+  final int? lexicalRank;
+
+  /// Query terms found in the entry (lowercase)
+  final List<String> matchedTerms;
+
+  /// Optional text preview
+  final String? textPreview;
+
+  const HybridHit({
+    required this.id,
+    required this.score,
+    this.semanticScore,
+    this.semanticRank,
+    this.lexicalScore,
+    this.lexicalRank,
+    required this.matchedTerms,
+    this.textPreview,
+  });
+
+  @override
+  int get hashCode =>
+      id.hashCode ^
+      score.hashCode ^
+      semanticScore.hashCode ^
+      semanticRank.hashCode ^
+      lexicalScore.hashCode ^
+      lexicalRank.hashCode ^
+      matchedTerms.hashCode ^
+      textPreview.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is HybridHit &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          score == other.score &&
+          semanticScore == other.semanticScore &&
+          semanticRank == other.semanticRank &&
+          lexicalScore == other.lexicalScore &&
+          lexicalRank == other.lexicalRank &&
+          matchedTerms == other.matchedTerms &&
+          textPreview == other.textPreview;
+}
+
+/// Settings for a hybrid search
+class HybridOptions {
+  /// How the rankings are combined
+  final FusionMode fusion;
+
+  /// Weight of the semantic signal; the keyword signal gets the rest (default: 0.5)
+  final double semanticWeight;
+
+  /// Rank offset for reciprocal rank fusion (default: 60)
+  final double rrfK;
+
+  /// Candidates taken from each ranking before fusion (default: 50)
+  final BigInt candidates;
+
+  /// Minimum cosine similarity for a semantic candidate (default: 0.0)
+  final double minSemanticScore;
+
+  const HybridOptions({
+    required this.fusion,
+    required this.semanticWeight,
+    required this.rrfK,
+    required this.candidates,
+    required this.minSemanticScore,
+  });
+
+  @override
+  int get hashCode =>
+      fusion.hashCode ^
+      semanticWeight.hashCode ^
+      rrfK.hashCode ^
+      candidates.hashCode ^
+      minSemanticScore.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is HybridOptions &&
+          runtimeType == other.runtimeType &&
+          fusion == other.fusion &&
+          semanticWeight == other.semanticWeight &&
+          rrfK == other.rrfK &&
+          candidates == other.candidates &&
+          minSemanticScore == other.minSemanticScore;
+}
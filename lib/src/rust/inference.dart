@@ -0,0 +1,402 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:kivixa/src/rust/context_window.dart';
+import 'package:kivixa/src/rust/frb_generated.dart';
+import 'package:kivixa/src/rust/reasoning.dart';
+
+/// A piece of streamed generation output
+class GenerationChunk {
+  /// Newly detokenized answer text (empty on the final chunk)
+  final String text;
+
+  /// Newly detokenized reasoning text (empty unless reasoning is separated)
+  final String reasoning;
+
+  /// Set only on the final chunk, reporting why generation stopped
+  final StopReason? stopReason;
+
+  const GenerationChunk({
+    required this.text,
+    required this.reasoning,
+    this.stopReason,
+  });
+
+  @override
+  int get hashCode => text.hashCode ^ reasoning.hashCode ^ stopReason.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is GenerationChunk &&
+          runtimeType == other.runtimeType &&
+          text == other.text &&
+          reasoning == other.reasoning &&
+          stopReason == other.stopReason;
+}
+
+/// Per-request generation settings
+class GenerationOptions {
+  /// Maximum tokens to generate (defaults to the model config)
+  final int? maxTokens;
+
+  /// Strings that end generation when they appear in the output
+  final List<String> stopSequences;
+
+  /// Sampling parameters (defaults to the model config)
+  final SamplerConfig? sampler;
+
+  /// GBNF grammar the output must match
+  final String? grammar;
+
+  /// JSON Schema the output must match (converted to a grammar)
+  final String? jsonSchema;
+
+  /// Model to run on (defaults to the default model)
+  final String? modelId;
+
+  /// How chat histories that exceed the context window are fitted
+  /// (`None` = fail with an error)
+  final ContextPolicy? context;
+
+  /// How a reasoning trace is separated from the answer
+  /// (`None` = automatic for reasoning models such as DeepSeek-R1; always
+  /// off when a grammar or JSON Schema is set)
+  final ReasoningOptions? reasoning;
+
+  /// Return per-token log-probabilities with this many alternatives each
+  /// (`None` = off, capped at `MAX_TOP_LOGPROBS`)
+  final int? logprobs;
+
+  const GenerationOptions({
+    this.maxTokens,
+    required this.stopSequences,
+    this.sampler,
+    this.grammar,
+    this.jsonSchema,
+    this.modelId,
+    this.context,
+    this.reasoning,
+    this.logprobs,
+  });
+
+  @override
+  int get hashCode =>
+      maxTokens.hashCode ^
+      stopSequences.hashCode ^
+      sampler.hashCode ^
+      grammar.hashCode ^
+      jsonSchema.hashCode ^
+      modelId.hashCode ^
+      context.hashCode ^
+      reasoning.hashCode ^
+      logprobs.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is GenerationOptions &&
+          runtimeType == other.runtimeType &&
+          maxTokens == other.maxTokens &&
+          stopSequences == other.stopSequences &&
+          sampler == other.sampler &&
+          grammar == other.grammar &&
+          jsonSchema == other.jsonSchema &&
+          modelId == other.modelId &&
+          context == other.context &&
+          reasoning == other.reasoning &&
+          logprobs == other.logprobs;
+}
+
+/// Progress of a generation started through a request handle
+class GenerationPoll {
+  /// Text generated since the previous poll
+  final String text;
+
+  /// Reasoning text generated since the previous poll
+  final String reasoning;
+
+  /// Final result, present once the request has finished
+  final GenerationResult? result;
+
+  const GenerationPoll({
+    required this.text,
+    required this.reasoning,
+    this.result,
+  });
+
+  @override
+  int get hashCode => text.hashCode ^ reasoning.hashCode ^ result.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is GenerationPoll &&
+          runtimeType == other.runtimeType &&
+          text == other.text &&
+          reasoning == other.reasoning &&
+          result == other.result;
+}
+
+/// Complete result of a generation loop
+class GenerationResult {
+  /// The generated text (without any matched stop sequence or reasoning trace)
+  final String text;
+
+  /// The reasoning trace, when it was separated from the answer
+  final String? reasoning;
+
+  /// Number of sampled tokens that belonged to the reasoning trace
+  final int reasoningTokens;
+
+  /// Why generation stopped
+  final StopReason stopReason;
+
+  /// Number of tokens sampled from the model
+  final int tokensGenerated;
+
+  /// How a chat history was fitted into the context window (chat requests only)
+  final ContextReport? context;
+
+  /// Every sampled token with its log-probability (when requested)
+  final List<TokenLogprob>? logprobs;
+
+  const GenerationResult({
+    required this.text,
+    this.reasoning,
+    required this.reasoningTokens,
+    required this.stopReason,
+    required this.tokensGenerated,
+    this.context,
+    this.logprobs,
+  });
+
+  @override
+  int get hashCode =>
+      text.hashCode ^
+      reasoning.hashCode ^
+      reasoningTokens.hashCode ^
+      stopReason.hashCode ^
+      tokensGenerated.hashCode ^
+      context.hashCode ^
+      logprobs.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is GenerationResult &&
+          runtimeType == other.runtimeType &&
+          text == other.text &&
+          reasoning == other.reasoning &&
+          reasoningTokens == other.reasoningTokens &&
+          stopReason == other.stopReason &&
+          tokensGenerated == other.tokensGenerated &&
+          context == other.context &&
+          logprobs == other.logprobs;
+}
+
+/// Summary of a loaded model
+class LoadedModelInfo {
+  /// Id the model was loaded under
+  final String modelId;
+
+  /// Path of the GGUF file
+  final String modelPath;
+
+  /// Size of the model file, counted against the memory budget
+  final BigInt sizeBytes;
+
+  /// Whether calls without a model id use this model
+  final bool isDefault;
+
+  const LoadedModelInfo({
+    required this.modelId,
+    required this.modelPath,
+    required this.sizeBytes,
+    required this.isDefault,
+  });
+
+  @override
+  int get hashCode =>
+      modelId.hashCode ^
+      modelPath.hashCode ^
+      sizeBytes.hashCode ^
+      isDefault.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is LoadedModelInfo &&
+          runtimeType == other.runtimeType &&
+          modelId == other.modelId &&
+          modelPath == other.modelPath &&
+          sizeBytes == other.sizeBytes &&
+          isDefault == other.isDefault;
+}
+
+/// Sampling parameters applied at every decode step
+class SamplerConfig {
+  /// Temperature for sampling (0.0 = deterministic, 1.0 = creative)
+  final double temperature;
+
+  /// Keep only the k most likely tokens (0 = disabled)
+  final int topK;
+
+  /// Top-p sampling (nucleus sampling, 1.0 = disabled)
+  final double topP;
+
+  /// Drop tokens less likely than `min_p` times the most likely one (0.0 = disabled)
+  final double minP;
+
+  /// Penalty for repeating recent tokens (1.0 = disabled)
+  final double repeatPenalty;
+
+  /// Penalty scaled by how often a token already appeared (0.0 = disabled)
+  final double frequencyPenalty;
+
+  /// Flat penalty for any token that already appeared (0.0 = disabled)
+  final double presencePenalty;
+
+  /// Number of recent tokens the penalties look at (-1 = whole context)
+  final int penaltyLastN;
+
+  /// Seed for reproducible output (None = random)
+  final int? seed;
+
+  /// Always pick the most likely token, ignoring temperature and truncation
+  final bool greedy;
+
+  const SamplerConfig({
+    required this.temperature,
+    required this.topK,
+    required this.topP,
+    required this.minP,
+    required this.repeatPenalty,
+    required this.frequencyPenalty,
+    required this.presencePenalty,
+    required this.penaltyLastN,
+    this.seed,
+    required this.greedy,
+  });
+
+  @override
+  int get hashCode =>
+      temperature.hashCode ^
+      topK.hashCode ^
+      topP.hashCode ^
+      minP.hashCode ^
+      repeatPenalty.hashCode ^
+      frequencyPenalty.hashCode ^
+      presencePenalty.hashCode ^
+      penaltyLastN.hashCode ^
+      seed.hashCode ^
+      greedy.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SamplerConfig &&
+          runtimeType == other.runtimeType &&
+          temperature == other.temperature &&
+          topK == other.topK &&
+          topP == other.topP &&
+          minP == other.minP &&
+          repeatPenalty == other.repeatPenalty &&
+          frequencyPenalty == other.frequencyPenalty &&
+          presencePenalty == other.presencePenalty &&
+          penaltyLastN == other.penaltyLastN &&
+          seed == other.seed &&
+          greedy == other.greedy;
+}
+
+/// Why a generation loop stopped
+enum StopReason {
+  /// The model emitted an end-of-generation token
+  endOfGeneration,
+
+  /// The `max_tokens` limit was reached
+  maxTokens,
+
+  /// One of the requested stop sequences appeared in the output
+  stopSequence,
+
+  /// The request was cancelled through its handle
+  cancelled,
+}
+
+/// A candidate token and its log-probability
+class TokenCandidate {
+  /// Token id
+  final int token;
+
+  /// Token text (partial characters are replaced)
+  final String text;
+
+  /// Natural-log probability under the model
+  final double logprob;
+
+  const TokenCandidate({
+    required this.token,
+    required this.text,
+    required this.logprob,
+  });
+
+  @override
+  int get hashCode => token.hashCode ^ text.hashCode ^ logprob.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is TokenCandidate &&
+          runtimeType == other.runtimeType &&
+          token == other.token &&
+          text == other.text &&
+          logprob == other.logprob;
+}
+
+/// A sampled token with its log-probability and the likeliest alternatives
+///
+/// Probabilities come from the model's distribution before sampling
+/// (temperature, top-k), so they measure the model's own confidence. A grammar
+/// is applied first: tokens it rejects get no probability and the rest are
+/// renormalized.
+class TokenLogprob {
+  /// Token id
+  final int token;
+
+  /// Token text (partial characters are replaced)
+  final String text;
+
+  /// Natural-log probability under the model
+  final double logprob;
+
+  /// Most likely tokens at this position, best first
+  final List<TokenCandidate> topAlternatives;
+
+  const TokenLogprob({
+    required this.token,
+    required this.text,
+    required this.logprob,
+    required this.topAlternatives,
+  });
+
+  @override
+  int get hashCode =>
+      token.hashCode ^
+      text.hashCode ^
+      logprob.hashCode ^
+      topAlternatives.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is TokenLogprob &&
+          runtimeType == other.runtimeType &&
+          token == other.token &&
+          text == other.text &&
+          logprob == other.logprob &&
+          topAlternatives == other.topAlternatives;
+}
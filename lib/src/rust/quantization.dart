@@ -0,0 +1,67 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:kivixa/src/rust/frb_generated.dart';
+
+/// How vectors are compressed
+enum QuantizationKind {
+  /// Scalar quantization to signed bytes
+  int8,
+
+  /// One sign bit per dimension
+  binary,
+}
+
+/// Recall of quantized search against exact search
+class RecallReport {
+  /// Queries evaluated
+  final BigInt queries;
+
+  /// Results compared per query
+  final BigInt topK;
+
+  /// Fraction of the exact top-k found by the quantized scan alone
+  final double recall;
+
+  /// Fraction of the exact top-k found after rescoring
+  final double rescoredRecall;
+
+  /// Bytes of the f32 vectors
+  final BigInt f32Bytes;
+
+  /// Bytes of the quantized vectors
+  final BigInt quantizedBytes;
+
+  const RecallReport({
+    required this.queries,
+    required this.topK,
+    required this.recall,
+    required this.rescoredRecall,
+    required this.f32Bytes,
+    required this.quantizedBytes,
+  });
+
+  @override
+  int get hashCode =>
+      queries.hashCode ^
+      topK.hashCode ^
+      recall.hashCode ^
+      rescoredRecall.hashCode ^
+      f32Bytes.hashCode ^
+      quantizedBytes.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RecallReport &&
+          runtimeType == other.runtimeType &&
+          queries == other.queries &&
+          topK == other.topK &&
+          recall == other.recall &&
+          rescoredRecall == other.rescoredRecall &&
+          f32Bytes == other.f32Bytes &&
+          quantizedBytes == other.quantizedBytes;
+}
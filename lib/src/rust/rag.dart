@@ -0,0 +1,95 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:kivixa/src/rust/frb_generated.dart';
+
+/// An answer grounded in retrieved notes
+class RagAnswer {
+  /// The model's answer, with `[n]` citations
+  final String answer;
+
+  /// Chunks placed in the prompt, best first
+  final List<RagSource> sources;
+
+  /// Retrieved chunks left out because the token budget was used up
+  final BigInt skipped;
+
+  const RagAnswer({
+    required this.answer,
+    required this.sources,
+    required this.skipped,
+  });
+
+  @override
+  int get hashCode => answer.hashCode ^ sources.hashCode ^ skipped.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RagAnswer &&
+          runtimeType == other.runtimeType &&
+          answer == other.answer &&
+          sources == other.sources &&
+          skipped == other.skipped;
+}
+
+/// A retrieved chunk given to the model
+class RagSource {
+  /// Citation number used in the answer (`[1]`, `[2]`, ...)
+  final int citation;
+
+  /// Note the chunk belongs to
+  final String noteId;
+
+  /// Byte offset of the chunk within the note (from `note_id#offset` ids or
+  /// the entry's `chunk_start`)
+  final BigInt? chunkOffset;
+
+  /// Position of the chunk within the note (for `note_id#chunkN` ids)
+  final BigInt? chunkIndex;
+
+  /// Similarity between the question and the chunk
+  final double score;
+
+  /// The chunk text
+  final String text;
+
+  /// Whether the answer cites this chunk
+  final bool cited;
+
+  const RagSource({
+    required this.citation,
+    required this.noteId,
+    this.chunkOffset,
+    this.chunkIndex,
+    required this.score,
+    required this.text,
+    required this.cited,
+  });
+
+  @override
+  int get hashCode =>
+      citation.hashCode ^
+      noteId.hashCode ^
+      chunkOffset.hashCode ^
+      chunkIndex.hashCode ^
+      score.hashCode ^
+      text.hashCode ^
+      cited.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RagSource &&
+          runtimeType == other.runtimeType &&
+          citation == other.citation &&
+          noteId == other.noteId &&
+          chunkOffset == other.chunkOffset &&
+          chunkIndex == other.chunkIndex &&
+          score == other.score &&
+          text == other.text &&
+          cited == other.cited;
+}
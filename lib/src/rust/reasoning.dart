@@ -0,0 +1,47 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:kivixa/src/rust/frb_generated.dart';
+
+/// How reasoning output is separated from the answer
+class ReasoningOptions {
+  /// Separate the reasoning trace (false = return the raw output verbatim)
+  final bool enabled;
+
+  /// Tag that opens the reasoning block
+  final String openTag;
+
+  /// Tag that closes the reasoning block
+  final String closeTag;
+
+  /// Maximum tokens spent thinking before the block is closed and the
+  /// model has to answer (`None` = unlimited)
+  final int? thinkingBudget;
+
+  const ReasoningOptions({
+    required this.enabled,
+    required this.openTag,
+    required this.closeTag,
+    this.thinkingBudget,
+  });
+
+  @override
+  int get hashCode =>
+      enabled.hashCode ^
+      openTag.hashCode ^
+      closeTag.hashCode ^
+      thinkingBudget.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ReasoningOptions &&
+          runtimeType == other.runtimeType &&
+          enabled == other.enabled &&
+          openTag == other.openTag &&
+          closeTag == other.closeTag &&
+          thinkingBudget == other.thinkingBudget;
+}
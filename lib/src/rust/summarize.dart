@@ -0,0 +1,122 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:kivixa/src/rust/frb_generated.dart';
+
+/// A finished summary with its intermediate steps
+class Summary {
+  /// The final summary
+  final String summary;
+
+  /// Summary of each input chunk, in order
+  final List<String> chunkSummaries;
+
+  /// Merge rounds, including the final one (0 = the input fit in one chunk)
+  final int mergeLevels;
+
+  const Summary({
+    required this.summary,
+    required this.chunkSummaries,
+    required this.mergeLevels,
+  });
+
+  @override
+  int get hashCode =>
+      summary.hashCode ^ chunkSummaries.hashCode ^ mergeLevels.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is Summary &&
+          runtimeType == other.runtimeType &&
+          summary == other.summary &&
+          chunkSummaries == other.chunkSummaries &&
+          mergeLevels == other.mergeLevels;
+}
+
+/// Length, style and chunking settings for a summary
+class SummaryOptions {
+  /// Shape of the final summary
+  final SummaryStyle style;
+
+  /// Target length of the final summary in words
+  final int maxWords;
+
+  /// Tokens of input per request (chunks and merge groups)
+  final int chunkTokens;
+
+  /// Model to summarize with (`None` = the default model)
+  final String? modelId;
+
+  const SummaryOptions({
+    required this.style,
+    required this.maxWords,
+    required this.chunkTokens,
+    this.modelId,
+  });
+
+  @override
+  int get hashCode =>
+      style.hashCode ^
+      maxWords.hashCode ^
+      chunkTokens.hashCode ^
+      modelId.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SummaryOptions &&
+          runtimeType == other.runtimeType &&
+          style == other.style &&
+          maxWords == other.maxWords &&
+          chunkTokens == other.chunkTokens &&
+          modelId == other.modelId;
+}
+
+/// Shape of the final summary
+enum SummaryStyle {
+  /// A bulleted list of the main points
+  bullets,
+
+  /// One or two paragraphs of prose
+  abstract_,
+
+  /// A numbered list of the most important conclusions
+  keyTakeaways,
+}
+
+/// A timed piece of a transcript
+///
+/// Mirrors `TranscriptionSegment` from the audio crate, so its serialized
+/// segments can be deserialized directly.
+class TranscriptSegment {
+  /// The transcribed text
+  final String text;
+
+  /// Start time in seconds
+  final double startTime;
+
+  /// End time in seconds
+  final double endTime;
+
+  const TranscriptSegment({
+    required this.text,
+    required this.startTime,
+    required this.endTime,
+  });
+
+  @override
+  int get hashCode => text.hashCode ^ startTime.hashCode ^ endTime.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is TranscriptSegment &&
+          runtimeType == other.runtimeType &&
+          text == other.text &&
+          startTime == other.startTime &&
+          endTime == other.endTime;
+}
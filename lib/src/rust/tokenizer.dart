@@ -0,0 +1,58 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:kivixa/src/rust/frb_generated.dart';
+
+/// Tokenizer details and limits of a loaded model
+class TokenizerInfo {
+  /// Number of tokens in the vocabulary
+  final int vocabSize;
+
+  /// Context length the model was trained with
+  final int nCtxTrain;
+
+  /// Context length configured for generation
+  final int nCtx;
+
+  /// Beginning-of-sequence token id
+  final int bosToken;
+
+  /// End-of-sequence token id
+  final int eosToken;
+
+  /// Tokenizer family from the GGUF metadata (e.g. "gpt2", "llama")
+  final String? tokenizerModel;
+
+  const TokenizerInfo({
+    required this.vocabSize,
+    required this.nCtxTrain,
+    required this.nCtx,
+    required this.bosToken,
+    required this.eosToken,
+    this.tokenizerModel,
+  });
+
+  @override
+  int get hashCode =>
+      vocabSize.hashCode ^
+      nCtxTrain.hashCode ^
+      nCtx.hashCode ^
+      bosToken.hashCode ^
+      eosToken.hashCode ^
+      tokenizerModel.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is TokenizerInfo &&
+          runtimeType == other.runtimeType &&
+          vocabSize == other.vocabSize &&
+          nCtxTrain == other.nCtxTrain &&
+          nCtx == other.nCtx &&
+          bosToken == other.bosToken &&
+          eosToken == other.eosToken &&
+          tokenizerModel == other.tokenizerModel;
+}
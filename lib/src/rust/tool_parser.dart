@@ -0,0 +1,84 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:kivixa/src/rust/frb_generated.dart';
+import 'package:kivixa/src/rust/mcp.dart';
+
+/// A model reply split into tool calls and text
+class ParsedReply {
+  /// Calls in the order they appear
+  final List<MCPToolCall> toolCalls;
+
+  /// Text and call spans covering the reply in order (whitespace-only
+  /// text between calls is left out)
+  final List<ReplySpan> spans;
+
+  /// The text spans joined, without control markers
+  final String text;
+
+  const ParsedReply({
+    required this.toolCalls,
+    required this.spans,
+    required this.text,
+  });
+
+  @override
+  int get hashCode => toolCalls.hashCode ^ spans.hashCode ^ text.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ParsedReply &&
+          runtimeType == other.runtimeType &&
+          toolCalls == other.toolCalls &&
+          spans == other.spans &&
+          text == other.text;
+}
+
+/// A byte range of a reply
+class ReplySpan {
+  /// Start byte offset
+  final BigInt start;
+
+  /// End byte offset (exclusive)
+  final BigInt end;
+
+  /// Whether the range is text or tool calls
+  final ReplySpanKind kind;
+
+  /// Number of calls parsed from the range (0 for text)
+  final BigInt callCount;
+
+  const ReplySpan({
+    required this.start,
+    required this.end,
+    required this.kind,
+    required this.callCount,
+  });
+
+  @override
+  int get hashCode =>
+      start.hashCode ^ end.hashCode ^ kind.hashCode ^ callCount.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ReplySpan &&
+          runtimeType == other.runtimeType &&
+          start == other.start &&
+          end == other.end &&
+          kind == other.kind &&
+          callCount == other.callCount;
+}
+
+/// What a span of a reply holds
+enum ReplySpanKind {
+  /// Prose meant for the user
+  text,
+
+  /// One or more tool calls, including their wrapping tags
+  toolCall,
+}
@@ -0,0 +1,46 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:kivixa/src/rust/frb_generated.dart';
+
+/// Size and garbage of a vector store
+class VectorStoreStats {
+  /// Live entries
+  final BigInt entries;
+
+  /// Vector rows of replaced or deleted entries, dropped by compaction
+  final BigInt deadRows;
+
+  /// Embedding dimension (0 until the first upsert)
+  final BigInt dimension;
+
+  /// Size of the vector and log files in bytes
+  final BigInt fileBytes;
+
+  const VectorStoreStats({
+    required this.entries,
+    required this.deadRows,
+    required this.dimension,
+    required this.fileBytes,
+  });
+
+  @override
+  int get hashCode =>
+      entries.hashCode ^
+      deadRows.hashCode ^
+      dimension.hashCode ^
+      fileBytes.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is VectorStoreStats &&
+          runtimeType == other.runtimeType &&
+          entries == other.entries &&
+          deadRows == other.deadRows &&
+          dimension == other.dimension &&
+          fileBytes == other.fileBytes;
+}
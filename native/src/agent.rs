@@ -0,0 +1,374 @@
+//! Tool-Using Agent Loop
+//!
+//! Runs a conversation in which the model may call MCP tools:
+//! - Tool schemas are injected into the system prompt in each model family's format
+//! - Every reply is parsed for tool calls in the family's format, which are
+//!   executed in the sandbox
+//! - Results are appended as messages and the model is asked again
+//! - The loop ends on a reply without tool calls or at the step limit
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::inference::{self, GenerationOptions, ModelType};
+use crate::mcp::{self, MCPParamType, MCPTool, MCPToolCall, MCPToolResult};
+use crate::tool_parser;
+
+/// Settings for an agent run
+#[derive(Debug, Clone)]
+pub struct AgentConfig {
+    /// Maximum number of model replies (default: 5)
+    pub max_steps: u32,
+    /// Maximum tokens per reply (defaults to the model config)
+    pub max_tokens: Option<u32>,
+    /// Model to run on (`None` = the default model)
+    pub model_id: Option<String>,
+    /// Family whose tool-prompt format to use (`None` = that of the model)
+    pub model_type: Option<ModelType>,
+}
+
+impl Default for AgentConfig {
+    fn default() -> Self {
+        Self {
+            max_steps: 5,
+            max_tokens: None,
+            model_id: None,
+            model_type: None,
+        }
+    }
+}
+
+/// One model reply and the tool calls it made
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AgentStep {
+    /// The raw reply
+    pub reply: String,
+    /// Tool calls parsed from the reply, in order
+    pub tool_calls: Vec<MCPToolCall>,
+    /// Result of each tool call
+    pub results: Vec<MCPToolResult>,
+}
+
+/// Why an agent run ended
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AgentStopReason {
+    /// The model replied without calling a tool
+    FinalAnswer,
+    /// `max_steps` replies were used up while the model kept calling tools
+    StepLimit,
+}
+
+/// Full trace of an agent run
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AgentRun {
+    /// The final answer (empty when the step limit was hit)
+    pub answer: String,
+    /// Why the run ended
+    pub stop_reason: AgentStopReason,
+    /// Every reply with its tool calls and results
+    pub steps: Vec<AgentStep>,
+    /// The conversation including the tool prompt, replies and tool results
+    pub messages: Vec<(String, String)>,
+}
+
+/// Run the agent loop on a conversation
+///
+/// # Arguments
+/// * `messages` - Conversation as (role, content) pairs
+/// * `config` - Step limit, token limit and model selection
+/// * `on_step` - Called after each step, once its tool calls have run
+///
+/// # Returns
+/// * The final answer with the trace of every step
+pub fn run_agent(
+    mut messages: Vec<(String, String)>,
+    config: &AgentConfig,
+    on_step: &mut dyn FnMut(&AgentStep),
+) -> Result<AgentRun> {
+    let model_type = config
+        .model_type
+        .or_else(|| inference::get_model_type_by_id(config.model_id.as_deref()));
+    inject_tool_prompt(&mut messages, model_type);
+
+    let options = GenerationOptions {
+        max_tokens: config.max_tokens,
+        model_id: config.model_id.clone(),
+        ..GenerationOptions::default()
+    };
+    let mut steps = Vec::new();
+
+    for _ in 0..config.max_steps.max(1) {
+        let reply = inference::chat_completion_with_options(messages.clone(), options.clone())?
+            .text
+            .trim()
+            .to_string();
+        let parsed = tool_parser::parse_reply(&reply, model_type);
+        let tool_calls = parsed.tool_calls;
+        messages.push(("assistant".to_string(), reply.clone()));
+
+        if tool_calls.is_empty() {
+            let step = AgentStep {
+                reply,
+                tool_calls,
+                results: Vec::new(),
+            };
+            on_step(&step);
+            steps.push(step);
+            return Ok(AgentRun {
+                answer: parsed.text,
+                stop_reason: AgentStopReason::FinalAnswer,
+                steps,
+                messages,
+            });
+        }
+
+        let results: Vec<MCPToolResult> = tool_calls.iter().map(mcp::execute_tool_call).collect();
+        messages.extend(result_messages(&results, model_type));
+
+        let step = AgentStep {
+            reply,
+            tool_calls,
+            results,
+        };
+        on_step(&step);
+        steps.push(step);
+    }
+
+    Ok(AgentRun {
+        answer: String::new(),
+        stop_reason: AgentStopReason::StepLimit,
+        steps,
+        messages,
+    })
+}
+
+/// Add the tool prompt to the system message (or insert one)
+fn inject_tool_prompt(messages: &mut Vec<(String, String)>, model_type: Option<ModelType>) {
+    let prompt = tool_prompt(model_type);
+    match messages.first_mut() {
+        Some((role, content)) if role == "system" => {
+            content.push_str("\n\n");
+            content.push_str(&prompt);
+        }
+        _ => messages.insert(0, ("system".to_string(), prompt)),
+    }
+}
+
+/// Tool definitions and calling instructions in a model family's format
+fn tool_prompt(model_type: Option<ModelType>) -> String {
+    let tools = MCPTool::all();
+    match model_type {
+        // Hermes-style <tools> block, as in Qwen's own template
+        Some(ModelType::Qwen) => {
+            let definitions: Vec<String> = tools
+                .iter()
+                .map(|tool| {
+                    serde_json::json!({
+                        "type": "function",
+                        "function": {
+                            "name": tool.name(),
+                            "description": tool.description(),
+                            "parameters": tool.parameters_schema(),
+                        },
+                    })
+                    .to_string()
+                })
+                .collect();
+            format!(
+                "# Tools\n\n\
+                You may call one or more functions to assist with the user query.\n\n\
+                You are provided with function signatures within <tools></tools> XML tags:\n\
+                <tools>\n{}\n</tools>\n\n\
+                For each function call, return a json object with function name and arguments \
+                within <tool_call></tool_call> XML tags:\n\
+                <tool_call>\n{{\"name\": <function-name>, \"arguments\": <args-json-object>}}\n</tool_call>\n\n\
+                When no function is needed, answer the user directly.",
+                definitions.join("\n")
+            )
+        }
+        // TypeScript namespace, as Functionary was trained on
+        Some(ModelType::Functionary) => {
+            let definitions: Vec<String> = tools.iter().map(typescript_definition).collect();
+            format!(
+                "// Supported function definitions that should be called when necessary.\n\
+                namespace functions {{\n\n{}\n\n}} // namespace functions\n\n\
+                To call a function, reply with {{\"name\": \"function_name\", \"arguments\": {{...}}}}. \
+                When no function is needed, answer the user directly.",
+                definitions.join("\n\n")
+            )
+        }
+        // Phi-4's <|tool|> block with a JSON list of functions
+        Some(ModelType::Phi4) => {
+            let definitions: Vec<serde_json::Value> = tools
+                .iter()
+                .map(|tool| {
+                    serde_json::json!({
+                        "name": tool.name(),
+                        "description": tool.description(),
+                        "parameters": tool.parameters_schema(),
+                    })
+                })
+                .collect();
+            format!(
+                "You can call tools. <|tool|>{}<|/tool|>\n\n\
+                To call tools, reply with one JSON object per call: \
+                {{\"name\": \"tool_name\", \"arguments\": {{...}}}}. \
+                When no tool is needed, answer the user directly.",
+                serde_json::Value::Array(definitions)
+            )
+        }
+        None => format!(
+            "You can call tools. To call one, reply with a JSON object per call: \
+            {{\"tool\": \"tool_name\", \"parameters\": {{...}}, \"description\": \"what this action does\"}}. \
+            When no tool is needed, answer the user directly.\n\n\
+            Available tools:\n{}",
+            mcp::get_tool_schemas()
+        ),
+    }
+}
+
+/// One tool as a Functionary TypeScript type
+fn typescript_definition(tool: &MCPTool) -> String {
+    let fields: Vec<String> = tool
+        .parameters()
+        .iter()
+        .map(|p| {
+            let ts_type = match p.param_type {
+                MCPParamType::String => "string",
+                MCPParamType::Boolean => "boolean",
+                MCPParamType::Integer => "number",
+                MCPParamType::Array => "any[]",
+            };
+            let optional = if p.required { "" } else { "?" };
+            format!("// {}\n{}{}: {},", p.description, p.name, optional, ts_type)
+        })
+        .collect();
+    format!(
+        "// {}\ntype {} = (_: {{\n{}\n}}) => any;",
+        tool.description(),
+        tool.name(),
+        fields.join("\n")
+    )
+}
+
+/// Messages reporting tool results back to the model
+fn result_messages(
+    results: &[MCPToolResult],
+    model_type: Option<ModelType>,
+) -> Vec<(String, String)> {
+    let as_json = |result: &MCPToolResult| serde_json::to_string(result).unwrap_or_default();
+    match model_type {
+        // Functionary reads each result from a `function` turn
+        Some(ModelType::Functionary) => results
+            .iter()
+            .map(|result| ("function".to_string(), as_json(result)))
+            .collect(),
+        Some(ModelType::Qwen) => {
+            let responses: Vec<String> = results
+                .iter()
+                .map(|result| format!("<tool_response>\n{}\n</tool_response>", as_json(result)))
+                .collect();
+            vec![("user".to_string(), responses.join("\n"))]
+        }
+        Some(ModelType::Phi4) | None => {
+            let responses: Vec<String> = results.iter().map(as_json).collect();
+            vec![(
+                "user".to_string(),
+                format!("Tool results:\n{}", responses.join("\n")),
+            )]
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::{with_inference_backend, MockBackend};
+    use std::sync::Arc;
+
+    #[test]
+    fn test_tool_prompt_formats() {
+        let qwen = tool_prompt(Some(ModelType::Qwen));
+        assert!(qwen.contains("<tools>") && qwen.contains("\"name\":\"read_file\""));
+
+        let functionary = tool_prompt(Some(ModelType::Functionary));
+        assert!(functionary.contains("namespace functions {"));
+        assert!(functionary.contains("type read_file = (_: {"));
+        assert!(functionary.contains("recursive?: boolean,"));
+
+        let phi4 = tool_prompt(Some(ModelType::Phi4));
+        assert!(phi4.contains("<|tool|>[") && phi4.contains("<|/tool|>"));
+
+        assert!(tool_prompt(None).contains("Available tools:"));
+    }
+
+    #[test]
+    fn test_agent_runs_tools_until_answer() {
+        let backend = Arc::new(MockBackend::with_script(
+            16,
+            [
+                r#"<tool_call>
+{"name": "read_file", "arguments": {"path": "missing.md"}}
+</tool_call>"#,
+                "That note does not exist.",
+            ],
+        ));
+        let config = AgentConfig {
+            model_type: Some(ModelType::Qwen),
+            ..AgentConfig::default()
+        };
+
+        let mut seen = 0;
+        let run = with_inference_backend(backend.clone(), || {
+            run_agent(
+                vec![("user".to_string(), "Open missing.md".to_string())],
+                &config,
+                &mut |_| seen += 1,
+            )
+        })
+        .unwrap();
+
+        assert_eq!(run.stop_reason, AgentStopReason::FinalAnswer);
+        assert_eq!(run.answer, "That note does not exist.");
+        assert_eq!(seen, 2);
+        assert_eq!(run.steps[0].tool_calls[0].tool, "read_file");
+        assert_eq!(run.steps[0].results.len(), 1);
+
+        // The second request saw the tool result
+        let prompts = backend.prompts();
+        assert!(prompts[0].starts_with("system: # Tools"));
+        assert!(prompts[1].contains("<tool_response>"));
+        assert_eq!(run.messages.last().unwrap().0, "assistant");
+    }
+
+    #[test]
+    fn test_agent_stops_at_step_limit() {
+        let call = r#"{"tool": "list_files", "parameters": {}}"#;
+        let backend = Arc::new(MockBackend::new(16).with_fallback(call));
+        let config = AgentConfig {
+            max_steps: 3,
+            model_type: Some(ModelType::Functionary),
+            ..AgentConfig::default()
+        };
+
+        let run = with_inference_backend(backend, || {
+            run_agent(
+                vec![("user".to_string(), "List my files".to_string())],
+                &config,
+                &mut |_| {},
+            )
+        })
+        .unwrap();
+
+        assert_eq!(run.stop_reason, AgentStopReason::StepLimit);
+        assert_eq!(run.steps.len(), 3);
+        assert!(run.answer.is_empty());
+        assert_eq!(
+            run.messages
+                .iter()
+                .filter(|(role, _)| role == "function")
+                .count(),
+            3
+        );
+    }
+}
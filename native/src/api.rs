@@ -6,13 +6,34 @@
 use anyhow::Result;
 use flutter_rust_bridge::frb;
 use std::path::PathBuf;
+use std::sync::Arc;
 
+use crate::agent::{self, AgentConfig};
+use crate::backend::{self, MockBackend};
+use crate::chunker;
 use crate::clustering;
-use crate::embeddings::{self, EmbeddingEntry, SimilarityResult};
+use crate::context_window;
+use crate::dedup::{self, DedupDocument, DedupOptions, DuplicateGroup};
+use crate::embedder;
+use crate::embedding_cache;
+use crate::embeddings::{self, EmbeddingEntry, NoteMetadata, SimilarityResult};
+use crate::filtered_search::{self, SearchOptions};
+use crate::frb_generated::StreamSink;
+use crate::gguf::{self, GgufMetadata};
+use crate::grammar;
 use crate::graph::{self, GraphEdge, GraphNode, GraphState};
-use crate::inference::{self, InferenceConfig, ModelType};
+use crate::hnsw;
+use crate::hybrid_search::{self, HybridOptions};
+use crate::inference::{self, GenerationOptions, InferenceConfig, ModelType, SamplerConfig};
 use crate::mcp;
+use crate::quantization;
+use crate::rag;
+use crate::session;
 use crate::streaming::{self, NodePosition, ViewportUpdate};
+use crate::summarize;
+use crate::tokenizer;
+use crate::tool_parser;
+use crate::vector_store;
 
 
 // Initialization
@@ -27,6 +48,7 @@ pub fn init_model(model_path: String) -> Result<()> {
 /// Initialize the model with custom configuration
 /// model_type: 0 = Phi4, 1 = Qwen, 2 = Functionary (auto-detected if not in range)
 #[frb]
+#[allow(clippy::too_many_arguments)]
 pub fn init_model_with_config(
     model_path: String,
     n_gpu_layers: u32,
@@ -37,47 +59,43 @@ pub fn init_model_with_config(
     max_tokens: u32,
     model_type: Option<i32>,
 ) -> Result<()> {
-    let detected_type = match model_type {
-        Some(0) => ModelType::Phi4,
-        Some(1) => ModelType::Qwen,
-        Some(2) => ModelType::Functionary,
-        _ => {
-            // Auto-detect from path
-            let lower = model_path.to_lowercase();
-            if lower.contains("qwen")
-                || lower.contains("deepseek-r1-distill-qwen")
-                || lower.contains("smollm2")
-            {
-                ModelType::Qwen
-            } else if lower.contains("functionary") || lower.contains("function-gemma") {
-                ModelType::Functionary
-            } else {
-                ModelType::Phi4
-            }
-        }
-    };
+    // Anything else is detected from the GGUF metadata when the model loads
+    let model_type = ModelType::from_code(model_type);
 
     let config = InferenceConfig {
         n_gpu_layers,
         n_ctx,
         n_threads,
-        temperature,
-        top_p,
+        sampler: SamplerConfig {
+            temperature,
+            top_p,
+            ..SamplerConfig::default()
+        },
         max_tokens,
-        model_type: detected_type,
+        model_type,
     };
     inference::init_model(model_path, Some(config))
 }
 
+/// Read a GGUF file's metadata without loading the model
+///
+/// Useful for showing model details (architecture, size, quantization,
+/// context length) before the user picks a model.
+#[frb]
+pub fn inspect_model(model_path: String) -> Result<GgufMetadata> {
+    gguf::inspect_gguf(&PathBuf::from(model_path))
+}
+
+/// Get the GGUF metadata of a loaded model (None = the default model)
+#[frb(sync)]
+pub fn get_model_metadata(model_id: Option<String>) -> Result<GgufMetadata> {
+    inference::get_model_metadata(model_id.as_deref())
+}
+
 /// Get the loaded model type (0 = Phi4, 1 = Qwen, 2 = Functionary, -1 = not loaded)
 #[frb(sync)]
 pub fn get_model_type() -> i32 {
-    match inference::get_model_type() {
-        Some(ModelType::Phi4) => 0,
-        Some(ModelType::Qwen) => 1,
-        Some(ModelType::Functionary) => 2,
-        None => -1,
-    }
+    inference::get_model_type().map_or(-1, ModelType::code)
 }
 
 /// Check if the model is loaded
@@ -86,7 +104,9 @@ pub fn is_model_loaded() -> bool {
     inference::is_model_loaded()
 }
 
-/// Unload the model and free resources
+/// Unload the model loaded by `init_model` and free resources
+///
+/// Models loaded under other ids stay loaded.
 #[frb(sync)]
 pub fn unload_model() {
     inference::unload_model()
@@ -95,7 +115,57 @@ pub fn unload_model() {
 /// Get the embedding dimension of the loaded model
 #[frb(sync)]
 pub fn get_embedding_dimension() -> Result<usize> {
-    inference::get_embedding_dimension()
+    embedder::embedding_dimension()
+}
+
+
+// Model Registry
+
+
+pub use crate::inference::LoadedModelInfo;
+
+/// Load an additional model under an id, keeping other models loaded
+///
+/// The model type is auto-detected from the path. If the memory budget
+/// would be exceeded, least recently used models are unloaded first.
+#[frb]
+pub fn load_model(
+    model_id: String,
+    model_path: String,
+    n_ctx: Option<u32>,
+    n_gpu_layers: Option<u32>,
+) -> Result<()> {
+    let defaults = InferenceConfig::default();
+    let config = InferenceConfig {
+        n_ctx: n_ctx.unwrap_or(defaults.n_ctx),
+        n_gpu_layers: n_gpu_layers.unwrap_or(defaults.n_gpu_layers),
+        ..defaults
+    };
+    inference::load_model(model_id, model_path, Some(config))
+}
+
+/// Unload a single model, returning false if it was not loaded
+#[frb(sync)]
+pub fn unload_model_by_id(model_id: String) -> bool {
+    inference::unload_model_by_id(&model_id)
+}
+
+/// Make a loaded model the default for calls that do not name a model
+#[frb(sync)]
+pub fn set_default_model(model_id: String) -> Result<()> {
+    inference::set_default_model(&model_id)
+}
+
+/// List loaded models, most recently used first
+#[frb(sync)]
+pub fn list_loaded_models() -> Vec<LoadedModelInfo> {
+    inference::list_loaded_models()
+}
+
+/// Limit the combined size of loaded models in bytes (None = unlimited)
+#[frb(sync)]
+pub fn set_model_memory_budget(budget_bytes: Option<u64>) {
+    inference::set_model_memory_budget(budget_bytes)
 }
 
 
@@ -117,28 +187,456 @@ pub fn chat_completion(messages: Vec<(String, String)>, max_tokens: Option<u32>)
     inference::chat_completion(messages, max_tokens)
 }
 
+/// Generate text with a specific loaded model
+#[frb]
+pub fn generate_text_with_model(
+    prompt: String,
+    max_tokens: Option<u32>,
+    model_id: Option<String>,
+) -> Result<String> {
+    let options = GenerationOptions {
+        max_tokens,
+        model_id,
+        ..Default::default()
+    };
+    inference::generate_text_with_options(prompt, options).map(|result| result.text)
+}
+
+/// Chat completion with a specific loaded model
+///
+/// Messages should be a list of (role, content) tuples where role is
+/// "system", "user", or "assistant"
+#[frb]
+pub fn chat_completion_with_model(
+    messages: Vec<(String, String)>,
+    max_tokens: Option<u32>,
+    model_id: Option<String>,
+) -> Result<String> {
+    let options = GenerationOptions {
+        max_tokens,
+        model_id,
+        ..Default::default()
+    };
+    inference::chat_completion_with_options(messages, options).map(|result| result.text)
+}
+
+/// Generate text with per-request sampling, token limit and stop sequences
+#[frb]
+pub fn generate_text_with_options(
+    prompt: String,
+    options: GenerationOptions,
+) -> Result<GenerationResult> {
+    inference::generate_text_with_options(prompt, options)
+}
+
+/// Chat completion with per-request sampling, token limit and stop sequences
+#[frb]
+pub fn chat_completion_with_options(
+    messages: Vec<(String, String)>,
+    options: GenerationOptions,
+) -> Result<GenerationResult> {
+    inference::chat_completion_with_options(messages, options)
+}
+
+pub use crate::context_window::{ContextPolicy, ContextReport, ContextStrategy};
+
+/// Default context policy, the same as passing no policy: fail with the
+/// token counts when the window is full
+#[frb(sync)]
+pub fn default_context_policy() -> ContextPolicy {
+    ContextPolicy::default()
+}
+
+/// Count the tokens in each message, e.g. to show how full the context is
+#[frb]
+pub fn count_chat_tokens(
+    messages: Vec<(String, String)>,
+    model_id: Option<String>,
+) -> Result<Vec<u32>> {
+    context_window::count_chat_tokens(&messages, model_id.as_deref())
+}
+
+pub use crate::tokenizer::TokenizerInfo;
+
+/// Tokenize text into token ids (None model = the default model)
+///
+/// With `parse_special`, control tokens written in the text (e.g.
+/// `<|im_start|>`) become single tokens; otherwise they are plain text.
+#[frb]
+pub fn tokenize_text(
+    text: String,
+    add_bos: bool,
+    parse_special: bool,
+    model_id: Option<String>,
+) -> Result<Vec<i32>> {
+    tokenizer::tokenize(&text, add_bos, parse_special, model_id.as_deref())
+}
+
+/// Turn token ids back into text, optionally rendering control tokens
+#[frb]
+pub fn detokenize_tokens(
+    tokens: Vec<i32>,
+    render_special: bool,
+    model_id: Option<String>,
+) -> Result<String> {
+    tokenizer::detokenize(&tokens, render_special, model_id.as_deref())
+}
+
+/// Count the tokens in a text
+#[frb]
+pub fn count_tokens(text: String, model_id: Option<String>) -> Result<u32> {
+    tokenizer::count_tokens(&text, model_id.as_deref())
+}
+
+/// Count the tokens of a conversation after the chat template is applied
+#[frb]
+pub fn count_chat_prompt_tokens(
+    messages: Vec<(String, String)>,
+    model_id: Option<String>,
+) -> Result<u32> {
+    tokenizer::count_chat_prompt_tokens(&messages, model_id.as_deref())
+}
+
+/// Get vocabulary size, training context and special tokens of a loaded model
+#[frb(sync)]
+pub fn get_tokenizer_info(model_id: Option<String>) -> Result<TokenizerInfo> {
+    tokenizer::get_tokenizer_info(model_id.as_deref())
+}
+
+/// Default sampler settings (temperature 0.7, top_k 40, top_p 0.9, min_p 0.05)
+#[frb(sync)]
+pub fn default_sampler_config() -> SamplerConfig {
+    SamplerConfig::default()
+}
+
+/// Greedy sampler settings for deterministic output
+#[frb(sync)]
+pub fn greedy_sampler_config() -> SamplerConfig {
+    SamplerConfig::greedy()
+}
+
+pub use crate::reasoning::ReasoningOptions;
+
+/// Default reasoning separation (`<think>` tags, no thinking budget)
+///
+/// Set `GenerationOptions::reasoning` to change the tags, cap the thinking
+/// budget or return the raw output of a reasoning model.
+#[frb(sync)]
+pub fn default_reasoning_options() -> ReasoningOptions {
+    ReasoningOptions::default()
+}
+
+/// Convert a JSON Schema into a GBNF grammar for `GenerationOptions::grammar`
+#[frb(sync)]
+pub fn json_schema_to_grammar(schema_json: String) -> Result<String> {
+    grammar::json_schema_str_to_gbnf(&schema_json)
+}
+
+fn stop_options(
+    max_tokens: Option<u32>,
+    stop_sequences: Option<Vec<String>>,
+    model_id: Option<String>,
+) -> GenerationOptions {
+    GenerationOptions {
+        max_tokens,
+        stop_sequences: stop_sequences.unwrap_or_default(),
+        model_id,
+        ..GenerationOptions::default()
+    }
+}
+
+pub use crate::inference::{
+    GenerationChunk, GenerationResult, StopReason, TokenCandidate, TokenLogprob,
+};
+
+/// Stream a text completion token by token
+///
+/// Each detokenized piece is pushed to the sink as soon as it is sampled.
+/// The final chunk has empty text and reports why generation stopped.
+#[frb]
+pub fn generate_text_stream(
+    prompt: String,
+    max_tokens: Option<u32>,
+    stop_sequences: Option<Vec<String>>,
+    model_id: Option<String>,
+    sink: StreamSink<GenerationChunk>,
+) -> Result<()> {
+    inference::generate_text_streaming(
+        prompt,
+        stop_options(max_tokens, stop_sequences, model_id),
+        |chunk| {
+            let _ = sink.add(chunk);
+        },
+    )?;
+    Ok(())
+}
+
+/// Stream a chat completion token by token
+///
+/// Messages should be a list of (role, content) tuples where role is
+/// "system", "user", or "assistant". The final chunk has empty text and
+/// reports why generation stopped.
+#[frb]
+pub fn chat_completion_stream(
+    messages: Vec<(String, String)>,
+    max_tokens: Option<u32>,
+    stop_sequences: Option<Vec<String>>,
+    model_id: Option<String>,
+    sink: StreamSink<GenerationChunk>,
+) -> Result<()> {
+    inference::chat_completion_streaming(
+        messages,
+        stop_options(max_tokens, stop_sequences, model_id),
+        |chunk| {
+            let _ = sink.add(chunk);
+        },
+    )?;
+    Ok(())
+}
+
+pub use crate::inference::GenerationPoll;
+
+/// Start a text completion in the background and return its request id
+///
+/// Unlike `generate_text`, the request can be stopped with `cancel_generation`.
+#[frb]
+pub fn start_generation(
+    prompt: String,
+    max_tokens: Option<u32>,
+    stop_sequences: Option<Vec<String>>,
+    model_id: Option<String>,
+) -> Result<u64> {
+    inference::start_generation(prompt, stop_options(max_tokens, stop_sequences, model_id))
+}
+
+/// Start a chat completion in the background and return its request id
+#[frb]
+pub fn start_chat_completion(
+    messages: Vec<(String, String)>,
+    max_tokens: Option<u32>,
+    stop_sequences: Option<Vec<String>>,
+    model_id: Option<String>,
+) -> Result<u64> {
+    inference::start_chat_completion(messages, stop_options(max_tokens, stop_sequences, model_id))
+}
+
+/// Cancel a running request at the next token boundary
+///
+/// Returns false if the request is unknown or has already finished.
+#[frb(sync)]
+pub fn cancel_generation(request_id: u64) -> bool {
+    inference::cancel_generation(request_id)
+}
+
+/// Get the text generated since the last poll, plus the result once finished
+#[frb(sync)]
+pub fn poll_generation(request_id: u64) -> Result<GenerationPoll> {
+    inference::poll_generation(request_id)
+}
+
+/// Wait for a request to finish
+///
+/// Cancelled requests return their partial output.
+#[frb]
+pub fn wait_for_generation(request_id: u64) -> Result<GenerationResult> {
+    inference::wait_for_generation(request_id)
+}
+
 /// Extract topics from note content
 #[frb]
 pub fn extract_topics(text: String, num_topics: Option<u32>) -> Result<Vec<String>> {
     inference::extract_topics(text, num_topics)
 }
 
+/// Extract topics from note content with a specific loaded model
+///
+/// With `min_confidence` set (0.0 to 1.0), low-confidence answers are
+/// rejected with an error instead of returned.
+#[frb]
+pub fn extract_topics_with_model(
+    text: String,
+    num_topics: Option<u32>,
+    model_id: Option<String>,
+    min_confidence: Option<f32>,
+) -> Result<Vec<String>> {
+    inference::extract_topics_with_model(text, num_topics, model_id, min_confidence)
+}
+
+
+// Chat Sessions
+
+
+/// Create a chat session that keeps its KV cache between turns
+///
+/// Returns the session id used by the other session functions. The session
+/// stays on the model it was created with.
+#[frb]
+pub fn create_chat_session(n_ctx: Option<u32>, model_id: Option<String>) -> Result<u64> {
+    session::create_chat_session(n_ctx, model_id.as_deref())
+}
+
+/// Run a chat turn in a session
+///
+/// Pass the full conversation each turn. Only messages that are new or
+/// edited since the previous turn are evaluated.
+#[frb]
+pub fn chat_session_completion(
+    session_id: u64,
+    messages: Vec<(String, String)>,
+    options: GenerationOptions,
+) -> Result<GenerationResult> {
+    session::chat_session_completion(session_id, messages, options)
+}
+
+/// Stream a chat turn in a session token by token
+#[frb]
+pub fn chat_session_stream(
+    session_id: u64,
+    messages: Vec<(String, String)>,
+    max_tokens: Option<u32>,
+    stop_sequences: Option<Vec<String>>,
+    sink: StreamSink<GenerationChunk>,
+) -> Result<()> {
+    session::chat_session_streaming(
+        session_id,
+        messages,
+        stop_options(max_tokens, stop_sequences, None),
+        |chunk| {
+            let _ = sink.add(chunk);
+        },
+    )?;
+    Ok(())
+}
+
+/// Number of tokens currently cached by a session
+#[frb(sync)]
+pub fn chat_session_token_count(session_id: u64) -> Result<usize> {
+    session::chat_session_token_count(session_id)
+}
+
+/// Save a session to disk so it can be restored after a restart
+///
+/// Also writes `<path>.meta`, which records the model and context size.
+#[frb]
+pub fn save_chat_session(session_id: u64, path: String) -> Result<()> {
+    session::save_chat_session(session_id, &PathBuf::from(path))
+}
+
+/// Restore a session saved with `save_chat_session`, returning its new id
+///
+/// Fails if the loaded model is not the one the session was saved from.
+/// `n_ctx` defaults to the saved context size.
+#[frb]
+pub fn load_chat_session(
+    path: String,
+    n_ctx: Option<u32>,
+    model_id: Option<String>,
+) -> Result<u64> {
+    session::load_chat_session(&PathBuf::from(path), n_ctx, model_id.as_deref())
+}
+
+/// Close a session and free its context
+#[frb(sync)]
+pub fn close_chat_session(session_id: u64) -> bool {
+    session::close_chat_session(session_id)
+}
+
 
 // Embeddings
 
 
+pub use crate::embedder::{EmbeddingConfig, EmbeddingKind, PoolingMode};
+
+/// Load a dedicated embedding model (e.g. nomic-embed or bge GGUF)
+///
+/// Once loaded, all embedding functions use it instead of the chat model.
+#[frb]
+pub fn load_embedding_model(model_path: String, config: Option<EmbeddingConfig>) -> Result<()> {
+    embedder::load_embedding_model(model_path, config)
+}
+
+/// Unload the embedding model; embeddings fall back to the chat model
+#[frb(sync)]
+pub fn unload_embedding_model() {
+    embedder::unload_embedding_model()
+}
+
+/// Check if a dedicated embedding model is loaded
+#[frb(sync)]
+pub fn is_embedding_model_loaded() -> bool {
+    embedder::is_embedding_model_loaded()
+}
+
+/// Default embedding model settings (native pooling, 512-token chunks)
+#[frb(sync)]
+pub fn default_embedding_config() -> EmbeddingConfig {
+    EmbeddingConfig::default()
+}
+
+pub use crate::embedding_cache::EmbeddingCacheStats;
+
+/// Open an on-disk embedding cache (e.g. in the app support directory)
+///
+/// While it is open, `batch_embed` and `embed_markdown_note` only embed
+/// texts that are not cached for the current embedding model.
+#[frb]
+pub fn open_embedding_cache(path: String) -> Result<()> {
+    embedding_cache::open_embedding_cache(&path)
+}
+
+/// Close the embedding cache; embeddings are computed every time again
+#[frb(sync)]
+pub fn close_embedding_cache() {
+    embedding_cache::close_embedding_cache()
+}
+
+/// Rewrite the cache file without entries of previous models
+#[frb]
+pub fn compact_embedding_cache() -> Result<EmbeddingCacheStats> {
+    embedding_cache::compact_embedding_cache()
+}
+
+/// Entry count and file size of the embedding cache (None if none is open)
+#[frb]
+pub fn get_embedding_cache_stats() -> Result<Option<EmbeddingCacheStats>> {
+    embedding_cache::embedding_cache_stats()
+}
+
+/// Embed many texts in shared batches
+///
+/// Queries and documents get their configured instruction prefixes.
+#[frb]
+pub fn embed_texts(texts: Vec<String>, kind: EmbeddingKind) -> Result<Vec<Vec<f32>>> {
+    embedder::embed_texts(&texts, kind)
+}
+
 /// Get embedding for text
 #[frb]
 pub fn get_embedding(text: String) -> Result<Vec<f32>> {
     inference::get_embedding(text)
 }
 
+/// Get embedding for text with a specific loaded model
+#[frb]
+pub fn get_embedding_with_model(text: String, model_id: Option<String>) -> Result<Vec<f32>> {
+    inference::get_embedding_with_model(text, model_id.as_deref())
+}
+
 /// Compute embeddings for multiple texts
 #[frb]
 pub fn batch_embed(texts: Vec<String>) -> Result<Vec<EmbeddingEntry>> {
     embeddings::batch_embed(texts)
 }
 
+/// Compute embeddings for multiple texts with a specific loaded model
+#[frb]
+pub fn batch_embed_with_model(
+    texts: Vec<String>,
+    model_id: Option<String>,
+) -> Result<Vec<EmbeddingEntry>> {
+    embeddings::batch_embed_with_model(texts, model_id.as_deref())
+}
+
 /// Find similar entries to a query embedding
 #[frb]
 pub fn find_similar(
@@ -160,6 +658,548 @@ pub fn semantic_search(
     embeddings::semantic_search(query_text, &entries, top_k)
 }
 
+pub use crate::chunker::MarkdownChunk;
+
+/// Split a markdown note into token-limited chunks
+///
+/// # Arguments
+/// * `text` - The note's markdown
+/// * `max_tokens` - Maximum tokens per chunk (default: 512)
+/// * `overlap_tokens` - Tokens repeated between chunks (default: 64)
+/// * `model_id` - Model whose tokenizer counts tokens
+///
+/// # Returns
+/// * Chunks with their heading paths and byte ranges
+#[frb]
+pub fn chunk_markdown(
+    text: String,
+    max_tokens: Option<u32>,
+    overlap_tokens: Option<u32>,
+    model_id: Option<String>,
+) -> Result<Vec<MarkdownChunk>> {
+    let options = chunker_options(max_tokens, overlap_tokens, model_id);
+    chunker::chunk_markdown(&text, &options)
+}
+
+/// Chunk a markdown note and embed each chunk
+///
+/// # Arguments
+/// * `note_id` - Id of the note; entries get ids like `note_id#chunk3`
+/// * `text` - The note's markdown
+/// * `max_tokens` - Maximum tokens per chunk (default: 512)
+/// * `overlap_tokens` - Tokens repeated between chunks (default: 64)
+/// * `metadata` - Note metadata copied to every chunk, for filtered search
+///
+/// # Returns
+/// * One entry per chunk, with the chunk text as `text_preview` and its
+///   byte range as `metadata.chunk_start`/`chunk_end`
+#[frb]
+pub fn embed_markdown_note(
+    note_id: String,
+    text: String,
+    max_tokens: Option<u32>,
+    overlap_tokens: Option<u32>,
+    metadata: Option<NoteMetadata>,
+) -> Result<Vec<EmbeddingEntry>> {
+    let options = chunker_options(max_tokens, overlap_tokens, None);
+    let mut entries = chunker::embed_markdown(&note_id, &text, &options)?;
+    if let Some(metadata) = metadata {
+        for entry in &mut entries {
+            entry.metadata = NoteMetadata {
+                chunk_start: entry.metadata.chunk_start,
+                chunk_end: entry.metadata.chunk_end,
+                ..metadata.clone()
+            };
+        }
+    }
+    Ok(entries)
+}
+
+fn chunker_options(
+    max_tokens: Option<u32>,
+    overlap_tokens: Option<u32>,
+    model_id: Option<String>,
+) -> chunker::ChunkerOptions {
+    let defaults = chunker::ChunkerOptions::default();
+    chunker::ChunkerOptions {
+        max_tokens: max_tokens.unwrap_or(defaults.max_tokens),
+        overlap_tokens: overlap_tokens.unwrap_or(defaults.overlap_tokens),
+        model_id,
+    }
+}
+
+pub use crate::summarize::{Summary, SummaryOptions, SummaryStyle, TranscriptSegment};
+
+/// Default summary options: bullets of at most 200 words
+#[frb(sync)]
+pub fn default_summary_options() -> SummaryOptions {
+    SummaryOptions::default()
+}
+
+/// Summarize a long note by summarizing its chunks and merging the results
+#[frb]
+pub fn summarize_text(text: String, options: Option<SummaryOptions>) -> Result<Summary> {
+    summarize::summarize_text(&text, &options.unwrap_or_default())
+}
+
+/// Summarize a transcript (e.g. a recording's transcription segments),
+/// keeping timestamps for the key points
+#[frb]
+pub fn summarize_transcript(
+    segments: Vec<TranscriptSegment>,
+    options: Option<SummaryOptions>,
+) -> Result<Summary> {
+    summarize::summarize_transcript(&segments, &options.unwrap_or_default())
+}
+
+pub use crate::rag::{RagAnswer, RagSource};
+
+/// Answer a question from note chunks, citing the chunks used
+///
+/// # Arguments
+/// * `question` - The user's question
+/// * `chunks` - Embedded chunks with ids `note_id#offset` or `note_id#chunkN`
+///   and the chunk text as `text_preview`
+/// * `top_k` - Number of chunks to retrieve (default: 5)
+/// * `context_tokens` - Token budget for the retrieved chunks (default: 1024)
+/// * `max_tokens` - Maximum tokens for the answer
+/// * `model_id` - Model to answer with (`None` = the default model)
+///
+/// # Returns
+/// * The answer with its sources, their scores and which were cited
+#[frb]
+pub fn rag_answer(
+    question: String,
+    chunks: Vec<EmbeddingEntry>,
+    top_k: Option<u32>,
+    context_tokens: Option<u32>,
+    max_tokens: Option<u32>,
+    model_id: Option<String>,
+) -> Result<RagAnswer> {
+    let defaults = rag::RagOptions::default();
+    let options = rag::RagOptions {
+        top_k: top_k.map_or(defaults.top_k, |k| k as usize),
+        context_tokens: context_tokens.unwrap_or(defaults.context_tokens),
+        max_tokens,
+        model_id,
+        ..defaults
+    };
+    rag::answer_with_notes(&question, &chunks, &options)
+}
+
+pub use crate::hnsw::HnswConfig;
+
+/// Default HNSW parameters (m = 16, ef_construction = 200, ef_search = 64)
+#[frb(sync)]
+pub fn default_hnsw_config() -> HnswConfig {
+    HnswConfig::default()
+}
+
+/// Create an empty HNSW index for fast similarity search
+///
+/// Returns the index id used by the other index functions.
+#[frb(sync)]
+pub fn create_hnsw_index(config: Option<HnswConfig>) -> u64 {
+    hnsw::create_index(config)
+}
+
+/// Load an HNSW index saved with `save_hnsw_index`
+#[frb]
+pub fn load_hnsw_index(path: String) -> Result<u64> {
+    hnsw::load_index(&PathBuf::from(path))
+}
+
+/// Save an HNSW index to disk
+#[frb]
+pub fn save_hnsw_index(index_id: u64, path: String) -> Result<()> {
+    hnsw::save_index(index_id, &PathBuf::from(path))
+}
+
+/// Close an HNSW index and free its memory
+#[frb(sync)]
+pub fn close_hnsw_index(index_id: u64) -> bool {
+    hnsw::close_index(index_id)
+}
+
+/// Insert entries into an index, replacing entries with the same id
+#[frb]
+pub fn hnsw_index_insert(index_id: u64, entries: Vec<EmbeddingEntry>) -> Result<()> {
+    hnsw::index_insert(index_id, entries)
+}
+
+/// Insert entries into an index with the full text of each, so keyword
+/// search in `hybrid_search_in_index` sees more than `text_preview`
+#[frb]
+pub fn hnsw_index_insert_with_texts(
+    index_id: u64,
+    entries: Vec<EmbeddingEntry>,
+    texts: Vec<String>,
+) -> Result<()> {
+    hnsw::index_insert_with_texts(index_id, entries, texts)
+}
+
+/// Remove entries from an index; returns how many existed
+#[frb]
+pub fn hnsw_index_remove(index_id: u64, ids: Vec<String>) -> Result<usize> {
+    hnsw::index_remove(index_id, &ids)
+}
+
+/// Number of entries in an index
+#[frb(sync)]
+pub fn hnsw_index_len(index_id: u64) -> Result<usize> {
+    hnsw::index_len(index_id)
+}
+
+/// Find similar entries to a query embedding using an index
+#[frb]
+pub fn find_similar_in_index(
+    index_id: u64,
+    query: Vec<f32>,
+    top_k: usize,
+    threshold: f32,
+) -> Result<Vec<SimilarityResult>> {
+    Ok(hnsw::find_index(index_id)?
+        .lock()
+        .search(&query, top_k, threshold))
+}
+
+/// Semantic search: embed query and find similar entries using an index
+#[frb]
+pub fn semantic_search_in_index(
+    index_id: u64,
+    query_text: String,
+    top_k: usize,
+) -> Result<Vec<SimilarityResult>> {
+    let index = hnsw::find_index(index_id)?;
+    embeddings::semantic_search_indexed(query_text, &index, top_k)
+}
+
+/// Default filtered search settings (top 10, threshold 0.5, no filter, no MMR)
+#[frb(sync)]
+pub fn default_search_options() -> SearchOptions {
+    SearchOptions::default()
+}
+
+/// Check a metadata filter expression, e.g.
+/// `tag in [exam, lecture] and folder under courses and modified >= 2024-09-01`
+///
+/// Returns an error describing the first problem.
+#[frb(sync)]
+pub fn validate_search_filter(filter: String) -> Result<()> {
+    filtered_search::MetadataFilter::parse(&filter).map(|_| ())
+}
+
+/// Find similar entries that pass a metadata filter, optionally re-ranked
+/// with maximal marginal relevance for more varied results
+#[frb]
+pub fn find_similar_filtered(
+    query: Vec<f32>,
+    entries: Vec<EmbeddingEntry>,
+    options: SearchOptions,
+) -> Result<Vec<SimilarityResult>> {
+    filtered_search::find_similar_filtered(&query, &entries, &options)
+}
+
+/// Semantic search over entries that pass a metadata filter
+#[frb]
+pub fn semantic_search_filtered(
+    query_text: String,
+    entries: Vec<EmbeddingEntry>,
+    options: SearchOptions,
+) -> Result<Vec<SimilarityResult>> {
+    filtered_search::semantic_search_filtered(query_text, &entries, &options)
+}
+
+/// Semantic search with a metadata filter using an HNSW index
+#[frb]
+pub fn semantic_search_in_index_filtered(
+    index_id: u64,
+    query_text: String,
+    options: SearchOptions,
+) -> Result<Vec<SimilarityResult>> {
+    let index = hnsw::find_index(index_id)?;
+    filtered_search::semantic_search_filtered_indexed(query_text, &index, &options)
+}
+
+pub use crate::hybrid_search::{FusionMode, HybridHit};
+
+/// Default hybrid search settings (reciprocal rank fusion, equal weights)
+#[frb(sync)]
+pub fn default_hybrid_options() -> HybridOptions {
+    HybridOptions::default()
+}
+
+/// Hybrid search: rank entries by keywords (BM25 over `text_preview`) and
+/// by embedding similarity, then fuse the rankings
+///
+/// Each hit reports both scores and the matched query terms. The keyword
+/// index is rebuilt on every call; `hybrid_search_in_index` keeps one.
+#[frb]
+pub fn hybrid_search(
+    query_text: String,
+    entries: Vec<EmbeddingEntry>,
+    top_k: usize,
+    options: Option<HybridOptions>,
+) -> Result<Vec<HybridHit>> {
+    hybrid_search::hybrid_search(&query_text, &entries, top_k, &options.unwrap_or_default())
+}
+
+/// Hybrid search using an HNSW index and its keyword index
+#[frb]
+pub fn hybrid_search_in_index(
+    index_id: u64,
+    query_text: String,
+    top_k: usize,
+    options: Option<HybridOptions>,
+) -> Result<Vec<HybridHit>> {
+    let index = hnsw::find_index(index_id)?;
+    hybrid_search::hybrid_search_indexed(&query_text, &index, top_k, &options.unwrap_or_default())
+}
+
+pub use crate::vector_store::VectorStoreStats;
+
+/// Open (or create) a persistent vector store directory
+///
+/// Entries stored here stay in Rust, so searches and clustering take the
+/// store id instead of the full entry list. Returns the store id.
+#[frb]
+pub fn open_vector_store(path: String) -> Result<u64> {
+    vector_store::open_store(&PathBuf::from(path))
+}
+
+/// Close a vector store
+#[frb(sync)]
+pub fn close_vector_store(store_id: u64) -> bool {
+    vector_store::close_store(store_id)
+}
+
+/// Insert entries into a store, replacing entries with the same id
+///
+/// The entries are on disk when this returns.
+#[frb]
+pub fn vector_store_upsert(store_id: u64, entries: Vec<EmbeddingEntry>) -> Result<()> {
+    vector_store::store_upsert(store_id, entries)
+}
+
+/// Delete entries from a store; returns how many existed
+#[frb]
+pub fn vector_store_delete(store_id: u64, ids: Vec<String>) -> Result<usize> {
+    vector_store::store_delete(store_id, &ids)
+}
+
+/// Stored entries by id; unknown ids are skipped
+#[frb]
+pub fn vector_store_get(store_id: u64, ids: Vec<String>) -> Result<Vec<EmbeddingEntry>> {
+    vector_store::store_get(store_id, &ids)
+}
+
+/// Number of entries in a store
+#[frb(sync)]
+pub fn vector_store_len(store_id: u64) -> Result<usize> {
+    vector_store::store_len(store_id)
+}
+
+/// Entry count, garbage and file size of a store
+#[frb]
+pub fn get_vector_store_stats(store_id: u64) -> Result<VectorStoreStats> {
+    vector_store::store_stats(store_id)
+}
+
+/// Rewrite a store without replaced and deleted entries
+#[frb]
+pub fn compact_vector_store(store_id: u64) -> Result<VectorStoreStats> {
+    vector_store::compact_store(store_id)
+}
+
+/// Find similar entries to a query embedding in a store
+#[frb]
+pub fn find_similar_in_store(
+    store_id: u64,
+    query: Vec<f32>,
+    top_k: usize,
+    threshold: f32,
+) -> Result<Vec<SimilarityResult>> {
+    Ok(vector_store::find_store(store_id)?
+        .lock()
+        .search(&query, top_k, threshold))
+}
+
+/// Semantic search: embed query and find similar entries in a store
+#[frb]
+pub fn semantic_search_in_store(
+    store_id: u64,
+    query_text: String,
+    top_k: usize,
+) -> Result<Vec<SimilarityResult>> {
+    let store = vector_store::find_store(store_id)?;
+    embeddings::semantic_search_stored(query_text, &store, top_k)
+}
+
+/// Semantic search with a metadata filter over a store
+#[frb]
+pub fn semantic_search_in_store_filtered(
+    store_id: u64,
+    query_text: String,
+    options: SearchOptions,
+) -> Result<Vec<SimilarityResult>> {
+    let store = vector_store::find_store(store_id)?;
+    filtered_search::semantic_search_filtered_stored(query_text, &store, &options)
+}
+
+/// Build an HNSW index over the entries of a store
+#[frb]
+pub fn create_hnsw_index_from_store(store_id: u64, config: Option<HnswConfig>) -> Result<u64> {
+    let store = vector_store::find_store(store_id)?;
+    let index_id = hnsw::create_index(config);
+    hnsw::index_insert_stored(index_id, &store.lock())?;
+    Ok(index_id)
+}
+
+pub use crate::quantization::{QuantizationKind, RecallReport};
+
+/// Quantize entries into a compact in-memory index (int8 or binary)
+///
+/// Returns the index id. Such an index has no full-precision vectors, so
+/// it cannot rescore; see `create_quantized_index_from_store`.
+#[frb]
+pub fn create_quantized_index(entries: Vec<EmbeddingEntry>, kind: QuantizationKind) -> Result<u64> {
+    quantization::create_index(&entries, kind)
+}
+
+/// Quantize the entries of a vector store into a compact index that
+/// rescores with the store's full-precision vectors
+///
+/// The index follows later `vector_store_upsert` and `vector_store_delete`
+/// calls on the store.
+#[frb]
+pub fn create_quantized_index_from_store(store_id: u64, kind: QuantizationKind) -> Result<u64> {
+    quantization::create_index_from_store(store_id, kind)
+}
+
+/// Save a quantized index's codes to disk
+#[frb]
+pub fn save_quantized_index(index_id: u64, path: String) -> Result<()> {
+    quantization::save_index(index_id, &PathBuf::from(path))
+}
+
+/// Load a quantized index saved with `save_quantized_index`
+///
+/// With `store_id`, the index rescores with and follows that open store.
+#[frb]
+pub fn load_quantized_index(path: String, store_id: Option<u64>) -> Result<u64> {
+    quantization::load_index(&PathBuf::from(path), store_id)
+}
+
+/// Close a quantized index and free its memory
+#[frb(sync)]
+pub fn close_quantized_index(index_id: u64) -> bool {
+    quantization::close_index(index_id)
+}
+
+/// Insert entries into a quantized index, replacing entries with the same id
+///
+/// Only needed for entries that are not written through a vector store the
+/// index was built from.
+#[frb]
+pub fn quantized_index_insert(index_id: u64, entries: Vec<EmbeddingEntry>) -> Result<()> {
+    quantization::index_insert(index_id, &entries)
+}
+
+/// Remove entries from a quantized index; returns how many existed
+#[frb]
+pub fn quantized_index_remove(index_id: u64, ids: Vec<String>) -> Result<usize> {
+    quantization::index_remove(index_id, &ids)
+}
+
+/// Find similar entries using a quantized index
+///
+/// With `rescore_candidates`, that many quantized results are rescored with
+/// full-precision vectors before the top `top_k` are returned.
+#[frb]
+pub fn find_similar_quantized(
+    index_id: u64,
+    query: Vec<f32>,
+    top_k: usize,
+    threshold: f32,
+    rescore_candidates: Option<usize>,
+) -> Result<Vec<SimilarityResult>> {
+    quantization::index_search(index_id, &query, top_k, threshold, rescore_candidates)
+}
+
+/// Measure the recall of quantized search against exact cosine search
+///
+/// Without `queries`, up to 100 of the entries' own vectors are used.
+#[frb]
+pub fn measure_quantization_recall(
+    entries: Vec<EmbeddingEntry>,
+    queries: Option<Vec<Vec<f32>>>,
+    kind: QuantizationKind,
+    top_k: usize,
+    rescore_candidates: usize,
+) -> Result<RecallReport> {
+    quantization::measure_recall(
+        &entries,
+        &queries.unwrap_or_default(),
+        kind,
+        top_k,
+        rescore_candidates,
+    )
+}
+
+pub use crate::dedup::{DuplicatePair, DuplicateSpan};
+
+/// Default near-duplicate detection thresholds
+#[frb(sync)]
+pub fn default_dedup_options() -> DedupOptions {
+    DedupOptions::default()
+}
+
+/// Group near-duplicate notes or chunks
+///
+/// Pairs are found by MinHash over word shingles and by embedding
+/// similarity, then confirmed by text overlap. Each group lists the shared
+/// passages and a suggested canonical note.
+#[frb]
+pub fn find_duplicates(
+    documents: Vec<DedupDocument>,
+    options: Option<DedupOptions>,
+) -> Vec<DuplicateGroup> {
+    dedup::find_duplicates(&documents, &options.unwrap_or_default())
+}
+
+/// Group near-duplicate entries, using `text_preview` as the text
+///
+/// Suited to chunk entries, whose preview holds the full chunk text. Fails
+/// if an entry has no preview; previews cut to 100 bytes by `embed_texts`
+/// only match within those bytes.
+#[frb]
+pub fn find_duplicate_entries(
+    entries: Vec<EmbeddingEntry>,
+    options: Option<DedupOptions>,
+) -> Result<Vec<DuplicateGroup>> {
+    let documents = entries
+        .iter()
+        .map(DedupDocument::try_from)
+        .collect::<Result<Vec<DedupDocument>>>()?;
+    Ok(dedup::find_duplicates(
+        &documents,
+        &options.unwrap_or_default(),
+    ))
+}
+
+/// Group near-duplicate entries of a vector store
+///
+/// Entries need their text as `text_preview`, as for `find_duplicate_entries`.
+#[frb]
+pub fn find_duplicates_in_store(
+    store_id: u64,
+    options: Option<DedupOptions>,
+) -> Result<Vec<DuplicateGroup>> {
+    let store = vector_store::find_store(store_id)?;
+    let store = store.lock();
+    dedup::find_duplicates_in_store(&store, &options.unwrap_or_default())
+}
+
 /// A cluster of embedding IDs
 #[derive(Debug, Clone)]
 #[frb]
@@ -295,6 +1335,28 @@ pub fn get_version() -> String {
     env!("CARGO_PKG_VERSION").to_string()
 }
 
+/// Serve generation, chat, embeddings and tokenization from a scripted mock
+///
+/// For integration tests without a model file: chat and generation return
+/// `completions` in order (then `fallback`), embeddings are word hashes of
+/// length `embedding_dimension`.
+#[frb(sync)]
+pub fn use_mock_inference_backend(
+    completions: Vec<String>,
+    fallback: Option<String>,
+    embedding_dimension: u32,
+) {
+    let mock = MockBackend::with_script(embedding_dimension as usize, completions)
+        .with_fallback(fallback.unwrap_or_default());
+    backend::set_inference_backend(Some(Arc::new(mock)));
+}
+
+/// Go back to serving requests from the loaded llama.cpp models
+#[frb(sync)]
+pub fn use_llama_inference_backend() {
+    backend::set_inference_backend(None);
+}
+
 
 // Streaming Graph (60fps viewport-culled simulation)
 
@@ -445,6 +1507,55 @@ pub fn discover_semantic_edges(
     clustering::discover_semantic_edges(&entries, threshold, links_ref)
 }
 
+/// Cluster the notes of a vector store using K-Means
+#[frb]
+pub fn cluster_notes_in_store(
+    store_id: u64,
+    k: Option<usize>,
+    max_iterations: Option<usize>,
+) -> Result<ClusteringResult> {
+    let store = vector_store::find_store(store_id)?;
+    let store = store.lock();
+    clustering::cluster_store_kmeans(&store, k, max_iterations)
+}
+
+/// Discover semantic edges between the notes of a vector store
+#[frb]
+pub fn discover_semantic_edges_in_store(
+    store_id: u64,
+    threshold: Option<f32>,
+    existing_links: Option<Vec<(String, String)>>,
+) -> Result<SemanticEdgeResult> {
+    let store = vector_store::find_store(store_id)?;
+    let store = store.lock();
+    let links_ref = existing_links.as_deref();
+    Ok(clustering::discover_semantic_edges_stored(
+        &store, threshold, links_ref,
+    ))
+}
+
+/// Discover semantic edges using an HNSW index over the note embeddings
+///
+/// Scales to large collections; each note gets at most `max_neighbors`
+/// edges (default: 10).
+#[frb]
+pub fn discover_semantic_edges_in_index(
+    index_id: u64,
+    threshold: Option<f32>,
+    existing_links: Option<Vec<(String, String)>>,
+    max_neighbors: Option<usize>,
+) -> Result<SemanticEdgeResult> {
+    let index = hnsw::find_index(index_id)?;
+    let index = index.lock();
+    let links_ref = existing_links.as_deref();
+    Ok(clustering::discover_semantic_edges_indexed(
+        &index,
+        threshold,
+        links_ref,
+        max_neighbors,
+    ))
+}
+
 /// Analyze knowledge graph: cluster and find semantic edges in one pass
 ///
 /// More efficient than calling both functions separately
@@ -465,6 +1576,26 @@ pub fn analyze_knowledge_graph(
     })
 }
 
+/// Analyze the notes of a vector store: cluster and find semantic edges
+#[frb]
+pub fn analyze_knowledge_graph_in_store(
+    store_id: u64,
+    k: Option<usize>,
+    similarity_threshold: Option<f32>,
+    existing_links: Option<Vec<(String, String)>>,
+) -> Result<KnowledgeGraphAnalysis> {
+    let store = vector_store::find_store(store_id)?;
+    let store = store.lock();
+    let links_ref = existing_links.as_deref();
+    let (clustering, edges) =
+        clustering::analyze_knowledge_graph_stored(&store, k, similarity_threshold, links_ref)?;
+
+    Ok(KnowledgeGraphAnalysis {
+        clustering,
+        semantic_edges: edges,
+    })
+}
+
 /// Combined result of knowledge graph analysis
 #[derive(Debug, Clone)]
 #[frb]
@@ -589,6 +1720,106 @@ pub fn mcp_parse_tool_call(json: String) -> Result<MCPToolCall> {
     mcp::parse_tool_call(&json)
 }
 
+pub use crate::tool_parser::{ParsedReply, ReplySpan, ReplySpanKind};
+
+/// Split a model reply into tool calls and plain text
+///
+/// # Arguments
+/// * `reply` - The raw model output
+/// * `model_type` - Family whose call format to expect
+///   (0 = Phi4, 1 = Qwen, 2 = Functionary, anything else = generic)
+///
+/// # Returns
+/// * The calls, the text, and which byte spans were treated as each
+#[frb(sync)]
+pub fn mcp_parse_tool_calls(reply: String, model_type: Option<i32>) -> ParsedReply {
+    tool_parser::parse_reply(&reply, ModelType::from_code(model_type))
+}
+
+/// Ask the loaded model for a tool call
+///
+/// Decoding is constrained to the tool schemas, so the result always parses.
+///
+/// # Arguments
+/// * `messages` - Conversation as (role, content) tuples
+/// * `max_tokens` - Optional override for max tokens to generate
+/// * `model_id` - Model to run on (None = the default model)
+///
+/// # Returns
+/// * The generated tool call, ready for `mcp_execute_tool_call`
+#[frb]
+pub fn mcp_generate_tool_call(
+    messages: Vec<(String, String)>,
+    max_tokens: Option<u32>,
+    model_id: Option<String>,
+) -> Result<MCPToolCall> {
+    mcp::generate_tool_call(messages, max_tokens, model_id)
+}
+
+pub use crate::agent::{AgentRun, AgentStep, AgentStopReason};
+
+fn agent_config(
+    max_steps: Option<u32>,
+    max_tokens: Option<u32>,
+    model_id: Option<String>,
+) -> AgentConfig {
+    let defaults = AgentConfig::default();
+    AgentConfig {
+        max_steps: max_steps.unwrap_or(defaults.max_steps),
+        max_tokens,
+        model_id,
+        ..defaults
+    }
+}
+
+/// Run a conversation in which the model may call tools
+///
+/// Tool calls in each reply are executed and their results fed back until
+/// the model answers without calling a tool or `max_steps` replies are used.
+///
+/// # Arguments
+/// * `messages` - Conversation as (role, content) tuples
+/// * `max_steps` - Maximum model replies (default: 5)
+/// * `max_tokens` - Optional override for max tokens per reply
+/// * `model_id` - Model to run on (None = the default model)
+///
+/// # Returns
+/// * The final answer with a trace of every step
+#[frb]
+pub fn mcp_run_agent(
+    messages: Vec<(String, String)>,
+    max_steps: Option<u32>,
+    max_tokens: Option<u32>,
+    model_id: Option<String>,
+) -> Result<AgentRun> {
+    agent::run_agent(
+        messages,
+        &agent_config(max_steps, max_tokens, model_id),
+        &mut |_| {},
+    )
+}
+
+/// Run the agent loop, pushing each step to the sink as it completes
+///
+/// The last step without tool calls holds the final answer.
+#[frb]
+pub fn mcp_run_agent_stream(
+    messages: Vec<(String, String)>,
+    max_steps: Option<u32>,
+    max_tokens: Option<u32>,
+    model_id: Option<String>,
+    sink: StreamSink<AgentStep>,
+) -> Result<()> {
+    agent::run_agent(
+        messages,
+        &agent_config(max_steps, max_tokens, model_id),
+        &mut |step| {
+            let _ = sink.add(step.clone());
+        },
+    )?;
+    Ok(())
+}
+
 /// Execute a tool call
 ///
 /// # Arguments
@@ -613,6 +1844,29 @@ pub fn mcp_classify_task(message: String) -> TaskCategory {
     mcp::classify_task(&message)
 }
 
+/// Classify a task with a loaded model, falling back to keywords
+///
+/// # Arguments
+/// * `message` - User message to classify
+/// * `model_id` - Model to ask (None = the default model)
+/// * `min_confidence` - Average token probability the model's answer needs
+///   (default 0.5); less confident answers use the keyword classifier
+///
+/// # Returns
+/// * Task category (Conversation, ToolUse, CodeGeneration)
+#[frb]
+pub fn mcp_classify_task_with_model(
+    message: String,
+    model_id: Option<String>,
+    min_confidence: Option<f32>,
+) -> Result<TaskCategory> {
+    mcp::classify_task_with_model(
+        &message,
+        model_id,
+        min_confidence.unwrap_or(mcp::DEFAULT_CLASSIFIER_CONFIDENCE),
+    )
+}
+
 /// Get the recommended model for a task category
 ///
 /// # Arguments
@@ -625,6 +1879,15 @@ pub fn mcp_get_model_for_task(category: TaskCategory) -> String {
     mcp::get_model_name_for_task(category).to_string()
 }
 
+/// Get the id of a loaded model suited to a task category
+///
+/// Pass the id to any inference call to route the task to that model.
+/// Returns None if no suitable model is loaded.
+#[frb(sync)]
+pub fn mcp_get_model_id_for_task(category: TaskCategory) -> Option<String> {
+    mcp::get_model_id_for_task(category)
+}
+
 /// Get all available MCP tools
 #[frb(sync)]
 pub fn mcp_get_all_tools() -> Vec<MCPTool> {
@@ -0,0 +1,468 @@
+//! Pluggable Inference Backends
+//!
+//! Generation, chat, embedding and tokenization go through the active
+//! `InferenceBackend`:
+//! - `LlamaCppBackend` runs the loaded GGUF models (the default)
+//! - `MockBackend` replays scripted completions and hashes words into
+//!   embeddings, so model-dependent features can be tested without a model file
+//!
+//! The backend can be replaced process-wide or for the current thread only.
+
+use anyhow::Result;
+use flutter_rust_bridge::frb;
+use once_cell::sync::Lazy;
+use parking_lot::{Mutex, RwLock};
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use crate::embedder::{self, EmbeddingKind};
+use crate::inference::{self, GenerationChunk, GenerationOptions, GenerationResult};
+use crate::tokenizer;
+
+/// Source of completions, embeddings and tokens
+pub trait InferenceBackend: Send + Sync {
+    /// Complete a raw prompt, calling `on_chunk` with each piece and then
+    /// once more with the stop reason
+    fn generate(
+        &self,
+        prompt: &str,
+        options: &GenerationOptions,
+        on_chunk: &mut dyn FnMut(GenerationChunk),
+    ) -> Result<GenerationResult>;
+
+    /// Reply to a conversation of (role, content) pairs
+    fn chat(
+        &self,
+        messages: &[(String, String)],
+        options: &GenerationOptions,
+        on_chunk: &mut dyn FnMut(GenerationChunk),
+    ) -> Result<GenerationResult>;
+
+    /// Embed texts into L2-normalized vectors
+    fn embed(&self, texts: &[String], kind: EmbeddingKind) -> Result<Vec<Vec<f32>>>;
+
+    /// Identity of the embedding model, which changes whenever `embed`
+    /// would return different vectors for the same text
+    fn embedding_model_id(&self, kind: EmbeddingKind) -> Result<String>;
+
+    /// Tokenize text into token ids
+    fn tokenize(
+        &self,
+        text: &str,
+        add_bos: bool,
+        parse_special: bool,
+        model_id: Option<&str>,
+    ) -> Result<Vec<i32>>;
+
+    /// Byte offsets in `text` where each token starts
+    ///
+    /// `model_id` selects a chat model's tokenizer; `None` uses the tokenizer
+    /// of the model that embeds documents.
+    fn token_offsets(&self, text: &str, model_id: Option<&str>) -> Result<Vec<usize>>;
+}
+
+/// Backend running the models loaded through `inference::load_model`
+#[frb(opaque)]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct LlamaCppBackend;
+
+impl InferenceBackend for LlamaCppBackend {
+    fn generate(
+        &self,
+        prompt: &str,
+        options: &GenerationOptions,
+        on_chunk: &mut dyn FnMut(GenerationChunk),
+    ) -> Result<GenerationResult> {
+        inference::llama_generate(prompt, options, on_chunk)
+    }
+
+    fn chat(
+        &self,
+        messages: &[(String, String)],
+        options: &GenerationOptions,
+        on_chunk: &mut dyn FnMut(GenerationChunk),
+    ) -> Result<GenerationResult> {
+        inference::llama_chat(messages, options, on_chunk)
+    }
+
+    fn embed(&self, texts: &[String], kind: EmbeddingKind) -> Result<Vec<Vec<f32>>> {
+        embedder::embed_texts(texts, kind)
+    }
+
+    fn embedding_model_id(&self, kind: EmbeddingKind) -> Result<String> {
+        embedder::embedding_model_identity(kind)
+    }
+
+    fn tokenize(
+        &self,
+        text: &str,
+        add_bos: bool,
+        parse_special: bool,
+        model_id: Option<&str>,
+    ) -> Result<Vec<i32>> {
+        tokenizer::llama_tokenize(text, add_bos, parse_special, model_id)
+    }
+
+    fn token_offsets(&self, text: &str, model_id: Option<&str>) -> Result<Vec<usize>> {
+        embedder::token_offsets(text, model_id)
+    }
+}
+
+/// Process-wide replacement for the llama.cpp backend
+static GLOBAL_BACKEND: Lazy<RwLock<Option<Arc<dyn InferenceBackend>>>> =
+    Lazy::new(|| RwLock::new(None));
+
+thread_local! {
+    /// Replacement for the current thread, taking precedence over the global one
+    static SCOPED_BACKEND: RefCell<Option<Arc<dyn InferenceBackend>>> = const { RefCell::new(None) };
+}
+
+/// The backend that serves requests on this thread
+pub fn active_backend() -> Arc<dyn InferenceBackend> {
+    if let Some(backend) = SCOPED_BACKEND.with(|scoped| scoped.borrow().clone()) {
+        return backend;
+    }
+    match GLOBAL_BACKEND.read().as_ref() {
+        Some(backend) => backend.clone(),
+        None => Arc::new(LlamaCppBackend),
+    }
+}
+
+/// Replace the backend for the whole process (`None` = back to llama.cpp)
+pub fn set_inference_backend(backend: Option<Arc<dyn InferenceBackend>>) {
+    *GLOBAL_BACKEND.write() = backend;
+}
+
+/// Run `f` with `backend` active on the current thread only
+///
+/// Work that `f` hands to other threads (e.g. `start_generation`) still uses
+/// the process-wide backend.
+pub fn with_inference_backend<R>(backend: Arc<dyn InferenceBackend>, f: impl FnOnce() -> R) -> R {
+    struct Restore(Option<Arc<dyn InferenceBackend>>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            let previous = self.0.take();
+            SCOPED_BACKEND.with(|scoped| *scoped.borrow_mut() = previous);
+        }
+    }
+
+    let previous = SCOPED_BACKEND.with(|scoped| scoped.borrow_mut().replace(backend));
+    let _restore = Restore(previous);
+    f()
+}
+
+/// Vocabulary size of the mock tokenizer
+const MOCK_VOCAB_SIZE: u64 = 32_000;
+
+/// Deterministic backend for tests
+///
+/// Completions are taken from a script in order; once it runs out, the
+/// fallback completion is returned. Output is streamed one word at a time
+/// and honours `max_tokens`, stop sequences and reasoning separation.
+/// Embeddings hash each lowercase word into a signed bucket, so texts that
+/// share words are similar. Every prompt is recorded for assertions.
+pub struct MockBackend {
+    script: Mutex<VecDeque<String>>,
+    fallback: String,
+    dimension: usize,
+    prompts: Mutex<Vec<String>>,
+    embedded: Mutex<Vec<String>>,
+}
+
+impl MockBackend {
+    /// Create a mock with an empty script
+    ///
+    /// # Arguments
+    /// * `dimension` - Length of the embedding vectors
+    pub fn new(dimension: usize) -> Self {
+        Self {
+            script: Mutex::new(VecDeque::new()),
+            fallback: String::new(),
+            dimension: dimension.max(1),
+            prompts: Mutex::new(Vec::new()),
+            embedded: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Create a mock that returns `completions` in order
+    pub fn with_script<I, S>(dimension: usize, completions: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let mock = Self::new(dimension);
+        mock.script
+            .lock()
+            .extend(completions.into_iter().map(Into::into));
+        mock
+    }
+
+    /// Set the completion returned once the script is used up
+    pub fn with_fallback(mut self, completion: impl Into<String>) -> Self {
+        self.fallback = completion.into();
+        self
+    }
+
+    /// Append a completion to the script
+    pub fn push_completion(&self, completion: impl Into<String>) {
+        self.script.lock().push_back(completion.into());
+    }
+
+    /// Prompts received so far (chat requests are rendered as `role: content` lines)
+    pub fn prompts(&self) -> Vec<String> {
+        self.prompts.lock().clone()
+    }
+
+    /// Texts embedded so far, in order
+    pub fn embedded_texts(&self) -> Vec<String> {
+        self.embedded.lock().clone()
+    }
+
+    fn next_completion(&self, prompt: String) -> String {
+        self.prompts.lock().push(prompt);
+        self.script
+            .lock()
+            .pop_front()
+            .unwrap_or_else(|| self.fallback.clone())
+    }
+}
+
+impl InferenceBackend for MockBackend {
+    fn generate(
+        &self,
+        prompt: &str,
+        options: &GenerationOptions,
+        on_chunk: &mut dyn FnMut(GenerationChunk),
+    ) -> Result<GenerationResult> {
+        let completion = self.next_completion(prompt.to_string());
+        Ok(inference::replay_tokens(
+            &mock_tokens(&completion),
+            options,
+            on_chunk,
+        ))
+    }
+
+    fn chat(
+        &self,
+        messages: &[(String, String)],
+        options: &GenerationOptions,
+        on_chunk: &mut dyn FnMut(GenerationChunk),
+    ) -> Result<GenerationResult> {
+        let prompt = messages
+            .iter()
+            .map(|(role, content)| format!("{}: {}\n", role, content))
+            .collect();
+        let completion = self.next_completion(prompt);
+        Ok(inference::replay_tokens(
+            &mock_tokens(&completion),
+            options,
+            on_chunk,
+        ))
+    }
+
+    fn embed(&self, texts: &[String], _kind: EmbeddingKind) -> Result<Vec<Vec<f32>>> {
+        self.embedded.lock().extend_from_slice(texts);
+        Ok(texts
+            .iter()
+            .map(|text| hash_embedding(text, self.dimension))
+            .collect())
+    }
+
+    fn embedding_model_id(&self, _kind: EmbeddingKind) -> Result<String> {
+        Ok(format!("mock:{}", self.dimension))
+    }
+
+    fn tokenize(
+        &self,
+        text: &str,
+        add_bos: bool,
+        _parse_special: bool,
+        _model_id: Option<&str>,
+    ) -> Result<Vec<i32>> {
+        let bos = add_bos.then_some(1);
+        Ok(bos
+            .into_iter()
+            .chain(mock_tokens(text).into_iter().map(|(id, _)| id))
+            .collect())
+    }
+
+    fn token_offsets(&self, text: &str, _model_id: Option<&str>) -> Result<Vec<usize>> {
+        let mut pos = 0;
+        Ok(mock_tokens(text)
+            .into_iter()
+            .map(|(_, word)| {
+                let start = pos;
+                pos += word.len();
+                start
+            })
+            .collect())
+    }
+}
+
+/// Split text into word tokens (each keeps its trailing whitespace)
+fn mock_tokens(text: &str) -> Vec<(i32, String)> {
+    text.split_inclusive(char::is_whitespace)
+        .map(|word| {
+            // Ids 0-2 are left for control tokens
+            let id = 3 + fnv1a(word.trim().as_bytes()) % (MOCK_VOCAB_SIZE - 3);
+            (id as i32, word.to_string())
+        })
+        .collect()
+}
+
+/// Feature-hash the lowercase words of `text` into a unit vector
+fn hash_embedding(text: &str, dimension: usize) -> Vec<f32> {
+    let mut vector = vec![0.0f32; dimension];
+    for word in text.split(|c: char| !c.is_alphanumeric()) {
+        if word.is_empty() {
+            continue;
+        }
+        let hash = fnv1a(word.to_lowercase().as_bytes());
+        let sign = if hash >> 63 == 0 { 1.0 } else { -1.0 };
+        vector[(hash % dimension as u64) as usize] += sign;
+    }
+
+    let norm = vector.iter().map(|x| x * x).sum::<f32>().sqrt();
+    if norm > 0.0 {
+        vector.iter_mut().for_each(|x| *x /= norm);
+    }
+    vector
+}
+
+/// 64-bit FNV-1a, stable across platforms and Rust versions
+pub(crate) fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, &byte| {
+        (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::embeddings;
+    use crate::inference::StopReason;
+    use crate::mcp;
+    use crate::reasoning::ReasoningOptions;
+
+    fn mock(completions: &[&str]) -> Arc<MockBackend> {
+        Arc::new(MockBackend::with_script(64, completions.iter().copied()))
+    }
+
+    #[test]
+    fn test_mock_streams_script_with_options() {
+        let backend = mock(&["Hello there. END ignored", "<think>hmm</think> 4"]);
+        let options = GenerationOptions {
+            stop_sequences: vec!["END".to_string()],
+            ..GenerationOptions::default()
+        };
+
+        let mut streamed = String::new();
+        let result = backend
+            .generate("prompt", &options, &mut |chunk| {
+                streamed.push_str(&chunk.text)
+            })
+            .unwrap();
+        assert_eq!(result.text, "Hello there. ");
+        assert_eq!(streamed, result.text);
+        assert_eq!(result.stop_reason, StopReason::StopSequence);
+
+        let options = GenerationOptions {
+            reasoning: Some(ReasoningOptions::default()),
+            logprobs: Some(0),
+            ..GenerationOptions::default()
+        };
+        let result = backend
+            .chat(
+                &[("user".to_string(), "2+2?".to_string())],
+                &options,
+                &mut |_| {},
+            )
+            .unwrap();
+        assert_eq!(result.text, "4");
+        assert_eq!(result.reasoning.as_deref(), Some("hmm"));
+        assert_eq!(result.confidence(), Some(1.0));
+        assert_eq!(backend.prompts(), vec!["prompt", "user: 2+2?\n"]);
+
+        // An exhausted script falls back to an empty completion
+        let result = backend
+            .generate(
+                "again",
+                &GenerationOptions::with_max_tokens(Some(1)),
+                &mut |_| {},
+            )
+            .unwrap();
+        assert_eq!(result.text, "");
+        assert_eq!(result.stop_reason, StopReason::EndOfGeneration);
+    }
+
+    #[test]
+    fn test_hash_embeddings_are_deterministic() {
+        let a = hash_embedding("Rust ownership rules", 64);
+        assert_eq!(a, hash_embedding("rust, ownership; RULES", 64));
+        assert!((a.iter().map(|x| x * x).sum::<f32>() - 1.0).abs() < 1e-5);
+        assert!(hash_embedding("", 64).iter().all(|&x| x == 0.0));
+
+        let tokens = MockBackend::new(8)
+            .tokenize("a b", true, true, None)
+            .unwrap();
+        assert_eq!(tokens.len(), 3);
+        assert_eq!(tokens[0], 1);
+    }
+
+    #[test]
+    fn test_scoped_backend_is_restored() {
+        let backend = mock(&[]);
+        with_inference_backend(backend, || {
+            assert!(tokenizer::tokenize("one two", false, true, None).is_ok());
+        });
+        // Back on llama.cpp, which has no model loaded
+        assert!(tokenizer::tokenize("one two", false, true, Some("missing")).is_err());
+    }
+
+    #[test]
+    fn test_topic_extraction_with_mock() {
+        let backend = mock(&["[\"rust\", \"memory\", \"safety\"]"]);
+        let topics = with_inference_backend(backend.clone(), || {
+            inference::extract_topics("Rust makes memory safety practical.".to_string(), None)
+        })
+        .unwrap();
+        assert_eq!(topics, vec!["rust", "memory", "safety"]);
+        assert!(backend.prompts()[0].contains("Rust makes memory safety practical."));
+    }
+
+    #[test]
+    fn test_semantic_search_with_mock() {
+        let results = with_inference_backend(mock(&[]), || {
+            let entries = embeddings::batch_embed(vec![
+                "rust borrow checker notes".to_string(),
+                "banana bread recipe".to_string(),
+            ])?;
+            embeddings::semantic_search("rust borrow checker".to_string(), &entries, 2)
+        })
+        .unwrap();
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].id, "batch_0");
+    }
+
+    #[test]
+    fn test_tool_call_with_mock() {
+        let backend = mock(&[
+            r#"{"tool": "list_files", "parameters": {"path": "notes"}, "description": "List notes"}"#,
+        ]);
+        let call = with_inference_backend(backend, || {
+            mcp::generate_tool_call(
+                vec![(
+                    "user".to_string(),
+                    "What is in my notes folder?".to_string(),
+                )],
+                None,
+                None,
+            )
+        })
+        .unwrap();
+        assert_eq!(call.tool, "list_files");
+    }
+}
@@ -0,0 +1,516 @@
+//! Markdown Chunking
+//!
+//! Splits notes into token-limited chunks for embedding:
+//! - Headings start a new chunk; chunks never span two sections
+//! - Paragraphs and fenced code blocks are kept whole when they fit, and
+//!   split by lines (then words) when they do not
+//! - Consecutive chunks of a section share up to `overlap_tokens` of text
+//! - Every chunk keeps its heading path and byte range in the note
+//!
+//! The note is tokenized once and ranges are measured on its token offsets.
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::backend;
+use crate::embedding_cache;
+use crate::embeddings::{EmbeddingEntry, NoteMetadata};
+
+/// Chunking limits
+#[derive(Debug, Clone)]
+pub struct ChunkerOptions {
+    /// Maximum tokens per chunk (default: 512)
+    pub max_tokens: u32,
+    /// Tokens repeated from the end of the previous chunk (default: 64)
+    pub overlap_tokens: u32,
+    /// Chat model whose tokenizer counts tokens (`None` = the embedding
+    /// model, or the default chat model when none is loaded)
+    pub model_id: Option<String>,
+}
+
+impl Default for ChunkerOptions {
+    fn default() -> Self {
+        Self {
+            max_tokens: 512,
+            overlap_tokens: 64,
+            model_id: None,
+        }
+    }
+}
+
+/// A chunk of a markdown note
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MarkdownChunk {
+    /// Position of the chunk within the note
+    pub index: usize,
+    /// Titles of the enclosing headings, outermost first
+    pub heading_path: Vec<String>,
+    /// Start byte offset in the note
+    pub start: usize,
+    /// End byte offset in the note (exclusive)
+    pub end: usize,
+    /// The chunk text (`note[start..end]`)
+    pub text: String,
+    /// Number of tokens in the embedded input: the heading path and `text`
+    pub token_count: u32,
+}
+
+/// A byte range of the note that is packed into chunks as a whole
+#[derive(Debug, Clone, Copy)]
+struct Unit {
+    start: usize,
+    end: usize,
+    tokens: u32,
+    section: usize,
+}
+
+/// Split a markdown note into chunks
+///
+/// A chunk's `max_tokens` covers exactly what `embed_markdown` embeds: the
+/// heading path line and the note text from its first to its last block.
+///
+/// # Arguments
+/// * `text` - The note's markdown
+/// * `options` - Chunk size, overlap and tokenizer model
+///
+/// # Returns
+/// * Chunks in note order
+pub fn chunk_markdown(text: &str, options: &ChunkerOptions) -> Result<Vec<MarkdownChunk>> {
+    let max_tokens = options.max_tokens.max(1);
+    let overlap_tokens = options.overlap_tokens.min(max_tokens / 2);
+    let backend = backend::active_backend();
+    let model_id = options.model_id.as_deref();
+
+    let offsets = backend.token_offsets(text, model_id)?;
+    let count = |start: usize, end: usize| tokens_in(&offsets, start, end);
+
+    // The heading path is embedded with every chunk of its section
+    let (blocks, sections) = markdown_blocks(text);
+    let headers = sections
+        .iter()
+        .map(|path| {
+            Ok(backend
+                .token_offsets(&embedding_header(path), model_id)?
+                .len() as u32)
+        })
+        .collect::<Result<Vec<u32>>>()?;
+    let budget = |section: usize| max_tokens.saturating_sub(headers[section]).max(1);
+
+    let mut units = Vec::new();
+    for (start, end, section) in blocks {
+        for (start, end) in split_to_fit(text, start, end, budget(section), &offsets) {
+            units.push(Unit {
+                start,
+                end,
+                tokens: count(start, end),
+                section,
+            });
+        }
+    }
+
+    let mut chunks = Vec::new();
+    let mut current: Vec<Unit> = Vec::new();
+    for unit in units {
+        let budget = budget(unit.section);
+        let same_section = current.first().is_some_and(|u| u.section == unit.section);
+        // Counted over the whole range, so the gaps between blocks count too
+        let fits = current
+            .first()
+            .is_some_and(|first| count(first.start, unit.end) <= budget);
+        if !current.is_empty() && (!same_section || !fits) {
+            let mut overlap = if same_section {
+                overlap_tail(&current, overlap_tokens, budget.saturating_sub(unit.tokens))
+            } else {
+                Vec::new()
+            };
+            while overlap
+                .first()
+                .is_some_and(|first| count(first.start, unit.end) > budget)
+            {
+                overlap.remove(0);
+            }
+            chunks.push(make_chunk(
+                text,
+                &current,
+                &sections,
+                &headers,
+                chunks.len(),
+                &count,
+            ));
+            current = overlap;
+        }
+        current.push(unit);
+    }
+    if !current.is_empty() {
+        chunks.push(make_chunk(
+            text,
+            &current,
+            &sections,
+            &headers,
+            chunks.len(),
+            &count,
+        ));
+    }
+    Ok(chunks)
+}
+
+/// Chunk a note and embed each chunk as an `EmbeddingEntry`
+///
+/// Entries get ids like `note#chunk3`, the full chunk text as
+/// `text_preview` and the chunk's byte range in their metadata. The heading
+/// path is embedded along with the text so a chunk stays findable by its
+/// section title.
+///
+/// # Arguments
+/// * `note_id` - Id of the note (e.g., its path)
+/// * `text` - The note's markdown
+/// * `options` - Chunk size, overlap and tokenizer model
+///
+/// # Returns
+/// * One entry per chunk, in note order
+pub fn embed_markdown(
+    note_id: &str,
+    text: &str,
+    options: &ChunkerOptions,
+) -> Result<Vec<EmbeddingEntry>> {
+    let chunks = chunk_markdown(text, options)?;
+    let inputs: Vec<String> = chunks
+        .iter()
+        .map(|chunk| embedding_header(&chunk.heading_path) + &chunk.text)
+        .collect();
+    let vectors = embedding_cache::embed_documents(&inputs)?;
+
+    Ok(chunks
+        .into_iter()
+        .zip(vectors)
+        .map(|(chunk, vector)| EmbeddingEntry {
+            id: chunk_entry_id(note_id, chunk.index),
+            vector,
+            text_preview: Some(chunk.text),
+            metadata: NoteMetadata {
+                chunk_start: Some(chunk.start),
+                chunk_end: Some(chunk.end),
+                ..NoteMetadata::default()
+            },
+        })
+        .collect())
+}
+
+/// Line placed before a chunk's text when it is embedded
+fn embedding_header(heading_path: &[String]) -> String {
+    if heading_path.is_empty() {
+        String::new()
+    } else {
+        format!("{}\n\n", heading_path.join(" > "))
+    }
+}
+
+/// Number of tokens starting in `start..end`
+fn tokens_in(offsets: &[usize], start: usize, end: usize) -> u32 {
+    let first = offsets.partition_point(|&offset| offset < start);
+    let last = offsets.partition_point(|&offset| offset < end);
+    last.saturating_sub(first) as u32
+}
+
+/// Id of chunk `index` of `note_id`
+pub fn chunk_entry_id(note_id: &str, index: usize) -> String {
+    format!("{}#chunk{}", note_id, index)
+}
+
+/// A heading, code block or paragraph as `(start, end, section)`
+type Block = (usize, usize, usize);
+
+/// Split the note into headings, code blocks and paragraphs
+///
+/// Returns the blocks and the heading path of each section (section 0 is
+/// the text before the first heading).
+fn markdown_blocks(text: &str) -> (Vec<Block>, Vec<Vec<String>>) {
+    let mut blocks = Vec::new();
+    let mut sections = vec![Vec::new()];
+    let mut headings: Vec<(usize, String)> = Vec::new();
+    let mut paragraph: Option<usize> = None;
+    let mut fence: Option<(usize, String)> = None;
+
+    let mut pos = 0;
+    for line in text.split_inclusive('\n') {
+        let line_start = pos;
+        pos += line.len();
+        let trimmed = line.trim();
+        let section = sections.len() - 1;
+
+        if let Some((start, marker)) = &fence {
+            if trimmed.starts_with(marker.as_str()) {
+                blocks.push((*start, pos, section));
+                fence = None;
+            }
+            continue;
+        }
+
+        let fence_marker = ["```", "~~~"]
+            .into_iter()
+            .find(|marker| trimmed.starts_with(marker));
+        let heading = heading_level(trimmed);
+        if trimmed.is_empty() || fence_marker.is_some() || heading.is_some() {
+            if let Some(start) = paragraph.take() {
+                blocks.push((start, line_start, section));
+            }
+        }
+
+        if let Some(marker) = fence_marker {
+            fence = Some((line_start, marker.to_string()));
+        } else if let Some(level) = heading {
+            headings.retain(|(l, _)| *l < level);
+            headings.push((level, trimmed[level..].trim().to_string()));
+            sections.push(headings.iter().map(|(_, title)| title.clone()).collect());
+            blocks.push((line_start, pos, sections.len() - 1));
+        } else if !trimmed.is_empty() && paragraph.is_none() {
+            paragraph = Some(line_start);
+        }
+    }
+
+    // An unclosed fence or final paragraph runs to the end
+    let section = sections.len() - 1;
+    if let Some(start) = fence.map(|(start, _)| start).or(paragraph) {
+        blocks.push((start, text.len(), section));
+    }
+    (blocks, sections)
+}
+
+/// Level of an ATX heading line (`#` to `######` followed by a space)
+fn heading_level(line: &str) -> Option<usize> {
+    let level = line.bytes().take_while(|b| *b == b'#').count();
+    let rest = &line[level..];
+    ((1..=6).contains(&level) && (rest.is_empty() || rest.starts_with(' '))).then_some(level)
+}
+
+/// Split `text[start..end]` into ranges of at most `max_tokens`, at line
+/// boundaries, at word boundaries for overlong lines, or at token boundaries
+/// for overlong words
+fn split_to_fit(
+    text: &str,
+    start: usize,
+    end: usize,
+    max_tokens: u32,
+    offsets: &[usize],
+) -> Vec<(usize, usize)> {
+    let count = |start: usize, end: usize| tokens_in(offsets, start, end);
+    if count(start, end) <= max_tokens {
+        return vec![(start, end)];
+    }
+
+    let lines = pieces(text, start, end, |s| s.split_inclusive('\n'));
+    let pieces = if lines.len() > 1 {
+        lines
+    } else {
+        pieces(text, start, end, |s| s.split_inclusive(char::is_whitespace))
+    };
+    if pieces.len() <= 1 {
+        return token_windows(offsets, start, end, max_tokens);
+    }
+
+    let mut ranges = Vec::new();
+    let mut current: Option<(usize, usize)> = None;
+    for piece in pieces {
+        current = match current {
+            Some((s, _)) if count(s, piece.1) <= max_tokens => Some((s, piece.1)),
+            Some(range) => {
+                ranges.push(range);
+                Some(piece)
+            }
+            None => Some(piece),
+        };
+    }
+    ranges.extend(current);
+
+    // Lines that are still too long are split again by words
+    ranges
+        .into_iter()
+        .flat_map(|(s, e)| split_to_fit(text, s, e, max_tokens, offsets))
+        .collect()
+}
+
+/// Cut `start..end` into runs of `max_tokens` tokens
+fn token_windows(
+    offsets: &[usize],
+    start: usize,
+    end: usize,
+    max_tokens: u32,
+) -> Vec<(usize, usize)> {
+    let first = offsets.partition_point(|&offset| offset <= start);
+    let last = offsets.partition_point(|&offset| offset < end);
+    let mut cuts: Vec<usize> = offsets[first..last]
+        .iter()
+        .copied()
+        .skip(max_tokens as usize - 1)
+        .step_by(max_tokens as usize)
+        .collect();
+    cuts.dedup();
+
+    let mut ranges = Vec::new();
+    let mut pos = start;
+    for cut in cuts.into_iter().chain([end]) {
+        if cut > pos {
+            ranges.push((pos, cut));
+            pos = cut;
+        }
+    }
+    ranges
+}
+
+/// Byte ranges of the pieces `split` yields for `text[start..end]`
+fn pieces<'a, I>(
+    text: &'a str,
+    start: usize,
+    end: usize,
+    split: impl Fn(&'a str) -> I,
+) -> Vec<(usize, usize)>
+where
+    I: Iterator<Item = &'a str>,
+{
+    let mut pos = start;
+    split(&text[start..end])
+        .map(|piece| {
+            let range = (pos, pos + piece.len());
+            pos += piece.len();
+            range
+        })
+        .collect()
+}
+
+/// Trailing units of a chunk that fit in the overlap (and leave `room`)
+fn overlap_tail(units: &[Unit], overlap_tokens: u32, room: u32) -> Vec<Unit> {
+    let limit = overlap_tokens.min(room);
+    let mut tokens = 0;
+    let mut tail = Vec::new();
+    // The whole chunk is never repeated, so chunking always advances
+    for unit in units.iter().skip(1).rev() {
+        if tokens + unit.tokens > limit {
+            break;
+        }
+        tokens += unit.tokens;
+        tail.insert(0, *unit);
+    }
+    tail
+}
+
+/// Build the chunk covering `units`, counting its heading path
+fn make_chunk(
+    text: &str,
+    units: &[Unit],
+    sections: &[Vec<String>],
+    headers: &[u32],
+    index: usize,
+    count: &dyn Fn(usize, usize) -> u32,
+) -> MarkdownChunk {
+    let start = units[0].start;
+    let end = units[units.len() - 1].end;
+    let section = units[0].section;
+    MarkdownChunk {
+        index,
+        heading_path: sections[section].clone(),
+        start,
+        end,
+        text: text[start..end].to_string(),
+        token_count: headers[section] + count(start, end),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::{with_inference_backend, MockBackend};
+    use std::sync::Arc;
+
+    fn chunk(text: &str, max_tokens: u32, overlap_tokens: u32) -> Vec<MarkdownChunk> {
+        let options = ChunkerOptions {
+            max_tokens,
+            overlap_tokens,
+            model_id: None,
+        };
+        with_inference_backend(Arc::new(MockBackend::new(8)), || {
+            chunk_markdown(text, &options)
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn test_headings_start_chunks() {
+        let note = "Intro line.\n\n# Rust\n\nOwnership rules.\n\n## Traits\n\nShared behaviour.\n\n# Garden\n\nTomatoes.\n";
+        let chunks = chunk(note, 100, 0);
+
+        let paths: Vec<Vec<String>> = chunks.iter().map(|c| c.heading_path.clone()).collect();
+        assert_eq!(
+            paths,
+            vec![
+                vec![],
+                vec!["Rust".to_string()],
+                vec!["Rust".to_string(), "Traits".to_string()],
+                vec!["Garden".to_string()],
+            ]
+        );
+        for c in &chunks {
+            assert_eq!(&note[c.start..c.end], c.text);
+        }
+        assert!(chunks[2].text.starts_with("## Traits"));
+    }
+
+    #[test]
+    fn test_code_blocks_stay_whole() {
+        let note = "# Code\n\n```rust\nfn main() {\n\n    # not a heading\n}\n```\n\nAfter.\n";
+        let chunks = chunk(note, 100, 0);
+        assert_eq!(chunks.len(), 1);
+        assert_eq!(chunks[0].heading_path, vec!["Code".to_string()]);
+
+        let (blocks, sections) = markdown_blocks(note);
+        assert_eq!(sections.len(), 2);
+        assert_eq!(blocks.len(), 3);
+        assert!(note[blocks[1].0..blocks[1].1].ends_with("```\n"));
+    }
+
+    #[test]
+    fn test_long_paragraphs_split_with_overlap() {
+        let paragraphs: Vec<String> = (0..6)
+            .map(|i| format!("paragraph {} has five words.", i))
+            .collect();
+        let note = paragraphs.join("\n\n");
+        let chunks = chunk(&note, 12, 5);
+
+        assert!(chunks.len() > 1);
+        assert!(chunks.iter().all(|c| c.token_count <= 12));
+        // Each chunk repeats the last paragraph of the one before
+        for pair in chunks.windows(2) {
+            assert!(pair[1].start < pair[0].end);
+        }
+
+        // A single overlong line is split by words
+        let words = "word ".repeat(30);
+        let chunks = chunk(&words, 10, 0);
+        assert_eq!(chunks.len(), 3);
+        assert_eq!(chunks[0].token_count, 10);
+
+        // A word longer than the limit is cut at token boundaries
+        let offsets = [0, 3, 6, 9, 12, 15];
+        assert_eq!(
+            token_windows(&offsets, 0, 17, 2),
+            vec![(0, 6), (6, 12), (12, 17)]
+        );
+    }
+
+    #[test]
+    fn test_embed_markdown_ids() {
+        let backend = Arc::new(MockBackend::new(8));
+        let entries = with_inference_backend(backend, || {
+            embed_markdown(
+                "notes/rust.md",
+                "# A\n\none\n\n# B\n\ntwo\n",
+                &ChunkerOptions::default(),
+            )
+        })
+        .unwrap();
+        let ids: Vec<&str> = entries.iter().map(|e| e.id.as_str()).collect();
+        assert_eq!(ids, vec!["notes/rust.md#chunk0", "notes/rust.md#chunk1"]);
+        assert_eq!(entries[1].text_preview.as_deref(), Some("# B\n\ntwo\n"));
+        assert_eq!(entries[1].metadata.chunk_start, Some(10));
+        assert_eq!(entries[1].metadata.chunk_end, Some(19));
+    }
+}
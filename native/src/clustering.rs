@@ -6,7 +6,8 @@
 //! Features:
 //! - Automatic cluster discovery from note embeddings
 //! - Color assignment for visual grouping
-//! - Semantic edge detection for hidden connections
+//! - Semantic edge detection for hidden connections (exhaustive, or through
+//!   an HNSW index for large collections)
 
 use anyhow::Result;
 use linfa::prelude::Predict;
@@ -15,9 +16,11 @@ use linfa::DatasetBase;
 use linfa_clustering::KMeans;
 use ndarray::Array2;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use crate::embeddings::{cosine_similarity, EmbeddingEntry};
+use crate::hnsw::HnswIndex;
+use crate::vector_store::VectorStore;
 
 /// Cluster assignment result
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -115,7 +118,50 @@ pub fn cluster_embeddings_kmeans(
     k: Option<usize>,
     max_iterations: Option<usize>,
 ) -> Result<ClusteringResult> {
-    if entries.is_empty() {
+    let ids: Vec<String> = entries.iter().map(|entry| entry.id.clone()).collect();
+    let dim = entries.first().map_or(0, |entry| entry.vector.len());
+
+    // Build data matrix (n_samples x n_features)
+    let mut data = Array2::<f64>::zeros((entries.len(), dim));
+    for (i, entry) in entries.iter().enumerate() {
+        for (j, &val) in entry.vector.iter().enumerate() {
+            data[[i, j]] = val as f64;
+        }
+    }
+
+    cluster_rows(ids, data, k, max_iterations)
+}
+
+/// Run K-Means clustering on the entries of a vector store
+///
+/// The data matrix is filled from the store one row at a time, without
+/// copying the entries first.
+pub fn cluster_store_kmeans(
+    store: &VectorStore,
+    k: Option<usize>,
+    max_iterations: Option<usize>,
+) -> Result<ClusteringResult> {
+    let mut ids = Vec::with_capacity(store.len());
+    let mut data = Array2::<f64>::zeros((store.len(), store.dimension()));
+    store.for_each_vector(|id, vector, _, _| {
+        for (j, &val) in vector.iter().enumerate() {
+            data[[ids.len(), j]] = val as f64;
+        }
+        ids.push(id.to_string());
+        Ok(())
+    })?;
+
+    cluster_rows(ids, data, k, max_iterations)
+}
+
+/// Run K-Means clustering on the rows of `data`, one per id
+fn cluster_rows(
+    ids: Vec<String>,
+    data: Array2<f64>,
+    k: Option<usize>,
+    max_iterations: Option<usize>,
+) -> Result<ClusteringResult> {
+    if ids.is_empty() {
         return Ok(ClusteringResult {
             assignments: vec![],
             clusters: vec![],
@@ -123,10 +169,10 @@ pub fn cluster_embeddings_kmeans(
         });
     }
 
-    if entries.len() == 1 {
+    if ids.len() == 1 {
         return Ok(ClusteringResult {
             assignments: vec![ClusterAssignment {
-                id: entries[0].id.clone(),
+                id: ids[0].clone(),
                 cluster_id: 0,
                 color: get_cluster_color(0),
             }],
@@ -134,7 +180,7 @@ pub fn cluster_embeddings_kmeans(
                 id: 0,
                 size: 1,
                 color: get_cluster_color(0),
-                centroid: Some(entries[0].vector.clone()),
+                centroid: Some(data.row(0).iter().map(|&v| v as f32).collect()),
             }],
             k: 1,
         });
@@ -143,24 +189,12 @@ pub fn cluster_embeddings_kmeans(
     // Determine optimal K
     let k = k.unwrap_or_else(|| {
         // Rule of thumb: sqrt(n/2), clamped to reasonable range
-        let auto_k = ((entries.len() as f64 / 2.0).sqrt().ceil() as usize).max(2);
-        auto_k.min(entries.len()).min(16) // Max 16 clusters
+        let auto_k = ((ids.len() as f64 / 2.0).sqrt().ceil() as usize).max(2);
+        auto_k.min(ids.len()).min(16) // Max 16 clusters
     });
 
     let max_iter = max_iterations.unwrap_or(100);
-
-    // Get embedding dimension
-    let dim = entries[0].vector.len();
-
-    // Build data matrix (n_samples x n_features)
-    let n_samples = entries.len();
-    let mut data = Array2::<f64>::zeros((n_samples, dim));
-
-    for (i, entry) in entries.iter().enumerate() {
-        for (j, &val) in entry.vector.iter().enumerate() {
-            data[[i, j]] = val as f64;
-        }
-    }
+    let n_samples = ids.len();
 
     // Create dataset
     let dataset = DatasetBase::from(data);
@@ -178,12 +212,12 @@ pub fn cluster_embeddings_kmeans(
     let mut assignments = Vec::with_capacity(n_samples);
     let mut cluster_counts: HashMap<usize, usize> = HashMap::new();
 
-    for (i, entry) in entries.iter().enumerate() {
+    for (i, id) in ids.into_iter().enumerate() {
         let cluster_id = predictions[i];
         *cluster_counts.entry(cluster_id).or_insert(0) += 1;
 
         assignments.push(ClusterAssignment {
-            id: entry.id.clone(),
+            id,
             cluster_id,
             color: get_cluster_color(cluster_id),
         });
@@ -229,19 +263,7 @@ pub fn discover_semantic_edges(
 ) -> SemanticEdgeResult {
     let threshold = threshold.unwrap_or(0.85);
     let mut edges = Vec::new();
-
-    // Build set of existing links for O(1) lookup
-    let existing_set: std::collections::HashSet<(String, String)> = existing_links
-        .map(|links| {
-            links
-                .iter()
-                .flat_map(|(s, t)| {
-                    // Both directions
-                    vec![(s.clone(), t.clone()), (t.clone(), s.clone())]
-                })
-                .collect()
-        })
-        .unwrap_or_default();
+    let existing_set = link_set(existing_links);
 
     // Compare all pairs (O(n²) but necessary for similarity)
     for i in 0..entries.len() {
@@ -265,7 +287,115 @@ pub fn discover_semantic_edges(
         }
     }
 
-    // Sort by similarity descending
+    sorted_edges(edges)
+}
+
+/// Discover semantic edges between the entries of a vector store
+///
+/// Compares the store's mapped rows without copying the entries.
+///
+/// # Arguments
+/// * `store` - Store holding the note embeddings
+/// * `threshold` - Minimum similarity for edge creation (default: 0.85)
+/// * `existing_links` - Set of existing hard links (source->target pairs)
+///
+/// # Returns
+/// * Semantic edges with similarity scores
+pub fn discover_semantic_edges_stored(
+    store: &VectorStore,
+    threshold: Option<f32>,
+    existing_links: Option<&[(String, String)]>,
+) -> SemanticEdgeResult {
+    let existing_set = link_set(existing_links);
+    let edges = store
+        .similar_pairs(threshold.unwrap_or(0.85))
+        .into_iter()
+        .map(|(source, target, similarity)| SemanticEdge {
+            is_ghost: !existing_set.contains(&(source.to_string(), target.to_string())),
+            source: source.to_string(),
+            target: target.to_string(),
+            similarity,
+        })
+        .collect();
+
+    sorted_edges(edges)
+}
+
+/// Discover semantic edges by querying an HNSW index instead of comparing
+/// every pair
+///
+/// Each note is linked to at most `max_neighbors` of its nearest notes, so
+/// the cost grows as O(n log n).
+///
+/// # Arguments
+/// * `index` - Index over the note embeddings
+/// * `threshold` - Minimum similarity for edge creation (default: 0.85)
+/// * `existing_links` - Set of existing hard links (source->target pairs)
+/// * `max_neighbors` - Most edges found per note (default: 10)
+///
+/// # Returns
+/// * Semantic edges with similarity scores
+pub fn discover_semantic_edges_indexed(
+    index: &HnswIndex,
+    threshold: Option<f32>,
+    existing_links: Option<&[(String, String)]>,
+    max_neighbors: Option<usize>,
+) -> SemanticEdgeResult {
+    let threshold = threshold.unwrap_or(0.85);
+    let max_neighbors = max_neighbors.unwrap_or(10);
+    let existing_set = link_set(existing_links);
+
+    let entries = index.entries();
+    let positions: HashMap<&str, usize> = entries
+        .iter()
+        .enumerate()
+        .map(|(i, entry)| (entry.id.as_str(), i))
+        .collect();
+    let mut seen = HashSet::new();
+    let mut edges = Vec::new();
+
+    for (i, entry) in entries.iter().enumerate() {
+        for result in index.search(&entry.vector, max_neighbors + 1, threshold) {
+            let Some(&j) = positions.get(result.id.as_str()) else {
+                continue;
+            };
+            // Either side may find the pair; the earlier note is the source
+            let pair = (i.min(j), i.max(j));
+            if i == j || !seen.insert(pair) {
+                continue;
+            }
+            let source = entries[pair.0].id.clone();
+            let target = entries[pair.1].id.clone();
+            let is_ghost = !existing_set.contains(&(source.clone(), target.clone()));
+            edges.push(SemanticEdge {
+                source,
+                target,
+                similarity: result.score,
+                is_ghost,
+            });
+        }
+    }
+
+    sorted_edges(edges)
+}
+
+/// Existing links in both directions, for O(1) lookup
+fn link_set(existing_links: Option<&[(String, String)]>) -> HashSet<(String, String)> {
+    existing_links
+        .map(|links| {
+            links
+                .iter()
+                .flat_map(|(s, t)| {
+                    // Both directions
+                    vec![(s.clone(), t.clone()), (t.clone(), s.clone())]
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Sort edges by similarity descending
+fn sorted_edges(mut edges: Vec<SemanticEdge>) -> SemanticEdgeResult {
     edges.sort_by(|a, b| {
         b.similarity
             .partial_cmp(&a.similarity)
@@ -291,15 +421,30 @@ pub fn analyze_knowledge_graph(
     Ok((clustering, edges))
 }
 
+/// Cluster and find semantic edges between the entries of a vector store
+pub fn analyze_knowledge_graph_stored(
+    store: &VectorStore,
+    k: Option<usize>,
+    similarity_threshold: Option<f32>,
+    existing_links: Option<&[(String, String)]>,
+) -> Result<(ClusteringResult, SemanticEdgeResult)> {
+    let clustering = cluster_store_kmeans(store, k, None)?;
+    let edges = discover_semantic_edges_stored(store, similarity_threshold, existing_links);
+
+    Ok((clustering, edges))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::embeddings::NoteMetadata;
 
     fn make_entry(id: &str, vector: Vec<f32>) -> EmbeddingEntry {
         EmbeddingEntry {
             id: id.to_string(),
             vector,
             text_preview: None,
+            metadata: NoteMetadata::default(),
         }
     }
 
@@ -402,6 +547,74 @@ mod tests {
         assert!(!result.edges[0].is_ghost);
     }
 
+    #[test]
+    fn test_semantic_edges_indexed_match_exhaustive() {
+        let entries = vec![
+            make_entry("a", vec![1.0, 0.0, 0.0]),
+            make_entry("b", vec![0.99, 0.01, 0.0]),
+            make_entry("c", vec![0.0, 1.0, 0.0]),
+            make_entry("d", vec![0.0, 0.98, 0.05]),
+            make_entry("e", vec![0.0, 0.0, 1.0]),
+        ];
+        let mut index = HnswIndex::new(Default::default());
+        for entry in &entries {
+            index.insert(entry.clone()).unwrap();
+        }
+
+        let existing = vec![("d".to_string(), "c".to_string())];
+        let exhaustive = discover_semantic_edges(&entries, Some(0.95), Some(&existing));
+        let indexed = discover_semantic_edges_indexed(&index, Some(0.95), Some(&existing), None);
+
+        let pairs = |result: &SemanticEdgeResult| -> Vec<(String, String, bool)> {
+            result
+                .edges
+                .iter()
+                .map(|e| (e.source.clone(), e.target.clone(), e.is_ghost))
+                .collect()
+        };
+        assert_eq!(indexed.count, 2);
+        assert_eq!(pairs(&indexed), pairs(&exhaustive));
+    }
+
+    #[test]
+    fn test_store_analysis_matches_entries() {
+        let entries = vec![
+            make_entry("a", vec![1.0, 0.0, 0.0]),
+            make_entry("b", vec![0.99, 0.01, 0.0]),
+            make_entry("c", vec![0.0, 1.0, 0.0]),
+            make_entry("d", vec![0.0, 0.98, 0.05]),
+        ];
+        let dir = tempfile::tempdir().unwrap();
+        let mut store = VectorStore::open(dir.path()).unwrap();
+        store.upsert(&entries).unwrap();
+
+        let existing = vec![("d".to_string(), "c".to_string())];
+        let (clustering, edges) =
+            analyze_knowledge_graph_stored(&store, Some(2), Some(0.95), Some(&existing)).unwrap();
+        let expected = discover_semantic_edges(&entries, Some(0.95), Some(&existing));
+
+        let cluster = |id: &str| {
+            clustering
+                .assignments
+                .iter()
+                .find(|a| a.id == id)
+                .unwrap()
+                .cluster_id
+        };
+        assert_eq!(clustering.assignments.len(), 4);
+        assert_eq!(cluster("a"), cluster("b"));
+        assert_eq!(cluster("c"), cluster("d"));
+        assert_ne!(cluster("a"), cluster("c"));
+        let pairs = |result: &SemanticEdgeResult| -> Vec<(String, String, bool)> {
+            result
+                .edges
+                .iter()
+                .map(|e| (e.source.clone(), e.target.clone(), e.is_ghost))
+                .collect()
+        };
+        assert_eq!(pairs(&edges), pairs(&expected));
+    }
+
     #[test]
     fn test_color_assignment() {
         // Test that colors are assigned properly
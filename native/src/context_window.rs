@@ -0,0 +1,574 @@
+//! Context Window Management
+//!
+//! Fits long conversations into the model's context window:
+//! - Token counts per message
+//! - Dropping the oldest turns while pinning the system prompt
+//! - Sliding-window truncation of the oldest messages
+//! - Summarizing evicted turns with the model itself
+//! - A report of which messages were dropped, truncated or summarized
+
+use anyhow::{anyhow, Result};
+use llama_cpp_2::model::AddBos;
+use once_cell::sync::Lazy;
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::atomic::AtomicBool;
+
+use crate::backend::fnv1a;
+use crate::inference::{self, GenerationOptions, ModelState, SamplerConfig, StopReason};
+use crate::tokenizer;
+
+/// What to do when a conversation does not fit the context window
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ContextStrategy {
+    /// Fail with an error that reports the token counts
+    #[default]
+    Error,
+    /// Drop the oldest turns, keeping system messages and the latest message
+    DropOldest,
+    /// Drop the oldest messages one by one and cut the oldest kept message
+    /// so the window is filled as far as possible
+    SlidingWindow,
+    /// Replace the oldest turns with a summary written by the model
+    Summarize,
+}
+
+/// How conversations that exceed the context window are handled
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContextPolicy {
+    /// Strategy applied when the window is full
+    pub strategy: ContextStrategy,
+    /// Maximum length of a summary of evicted turns
+    pub summary_max_tokens: u32,
+}
+
+impl Default for ContextPolicy {
+    /// Same as leaving `GenerationOptions::context` unset
+    fn default() -> Self {
+        Self {
+            strategy: ContextStrategy::default(),
+            summary_max_tokens: 256,
+        }
+    }
+}
+
+/// What the context manager did to a conversation
+///
+/// Message indices refer to the conversation as it was passed in.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ContextReport {
+    /// Size of the context window in tokens
+    pub context_size: u32,
+    /// Tokens used by the prompt that was actually sent
+    pub prompt_tokens: u32,
+    /// Tokens in the content of each original message
+    pub message_tokens: Vec<u32>,
+    /// Messages left out entirely
+    pub dropped: Vec<u32>,
+    /// Messages whose beginning was cut off
+    pub truncated: Vec<u32>,
+    /// Messages replaced by the summary
+    pub summarized: Vec<u32>,
+    /// Summary inserted in place of the summarized messages
+    pub summary: Option<String>,
+}
+
+/// Room kept free for the summary message wrapper and its template tokens
+const SUMMARY_OVERHEAD_TOKENS: usize = 32;
+
+/// Summaries kept before the cache is cleared
+const MAX_CACHED_SUMMARIES: usize = 64;
+
+/// Summaries of evicted turns, keyed by model, length limit and evicted messages
+///
+/// Every turn of a long conversation evicts the same prefix until more turns
+/// have to go, so the summary is written once per prefix.
+static SUMMARIES: Lazy<Mutex<HashMap<u64, String>>> = Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Count the tokens in the content of each message
+///
+/// # Arguments
+/// * `messages` - Conversation as (role, content) pairs
+/// * `model_id` - Model whose tokenizer is used (`None` = the default model)
+pub fn count_chat_tokens(
+    messages: &[(String, String)],
+    model_id: Option<&str>,
+) -> Result<Vec<u32>> {
+    let state = inference::current_state(model_id)?;
+    count_message_tokens(&state, messages)
+}
+
+fn count_message_tokens(state: &ModelState, messages: &[(String, String)]) -> Result<Vec<u32>> {
+    messages
+        .iter()
+        .map(|(_, content)| {
+            state
+                .model
+                .str_to_token(content, AddBos::Never)
+                .map(|tokens| tokens.len() as u32)
+                .map_err(|e| anyhow!("Failed to tokenize: {:?}", e))
+        })
+        .collect()
+}
+
+/// Fit a conversation into a context of `n_ctx` tokens
+///
+/// Room for the reply (`options.max_tokens`) is reserved first, then the
+/// strategy from `options.context` is applied if the prompt is too long.
+/// A cancelled summary leaves the evicted turns out without one.
+///
+/// # Returns
+/// * The messages to send and a report of what was changed
+pub(crate) fn fit_conversation(
+    state: &ModelState,
+    messages: Vec<(String, String)>,
+    options: &GenerationOptions,
+    n_ctx: u32,
+    cancel: Option<&AtomicBool>,
+) -> Result<(Vec<(String, String)>, ContextReport)> {
+    let reserve = options.max_tokens.unwrap_or(state.config.max_tokens);
+    let budget = n_ctx.saturating_sub(reserve) as usize;
+    if budget == 0 {
+        return Err(anyhow!(
+            "Reply of {} tokens leaves no room for the prompt in a {}-token context",
+            reserve,
+            n_ctx
+        ));
+    }
+
+    let policy = options.context.clone().unwrap_or_default();
+    let mut count = |messages: &[(String, String)]| tokenizer::count_prompt_tokens(state, messages);
+
+    let mut report = ContextReport {
+        context_size: n_ctx,
+        message_tokens: count_message_tokens(state, &messages)?,
+        ..ContextReport::default()
+    };
+
+    let fitted = if policy.strategy == ContextStrategy::Summarize {
+        let summary_room = policy.summary_max_tokens as usize + SUMMARY_OVERHEAD_TOKENS;
+        let plan = fit_messages(
+            &messages,
+            budget.saturating_sub(summary_room).max(1),
+            ContextStrategy::DropOldest,
+            &mut count,
+        )?;
+
+        let evicted: Vec<_> = plan.dropped.iter().map(|&i| &messages[i]).collect();
+        let summary = if evicted.is_empty() {
+            None
+        } else {
+            summarize(state, &evicted, policy.summary_max_tokens, n_ctx, cancel)?
+        };
+
+        match summary {
+            None => {
+                report.dropped = to_u32(&plan.dropped);
+                plan.messages
+            }
+            Some(summary) => {
+                let mut with_summary = plan.messages;
+                let head = leading_system_count(&with_summary);
+                with_summary.insert(
+                    head,
+                    (
+                        "system".to_string(),
+                        format!("Summary of the earlier conversation:\n{}", summary),
+                    ),
+                );
+                report.summarized = to_u32(&plan.dropped);
+                report.summary = Some(summary);
+                with_summary
+            }
+        }
+    } else {
+        let plan = fit_messages(&messages, budget, policy.strategy, &mut count)?;
+        report.dropped = to_u32(&plan.dropped);
+        report.truncated = to_u32(&plan.truncated);
+        plan.messages
+    };
+
+    report.prompt_tokens = count(&fitted)? as u32;
+    if !report.dropped.is_empty() || !report.truncated.is_empty() || report.summary.is_some() {
+        log::info!(
+            "Fitted conversation into {} tokens (dropped {:?}, truncated {:?}, summarized {:?})",
+            report.prompt_tokens,
+            report.dropped,
+            report.truncated,
+            report.summarized
+        );
+    }
+    Ok((fitted, report))
+}
+
+/// Ask the model for a summary of evicted messages
+///
+/// # Returns
+/// * The summary, or `None` if the request was cancelled while writing it
+fn summarize(
+    state: &ModelState,
+    evicted: &[&(String, String)],
+    max_tokens: u32,
+    n_ctx: u32,
+    cancel: Option<&AtomicBool>,
+) -> Result<Option<String>> {
+    let key = summary_key(state, evicted, max_tokens);
+    if let Some(summary) = SUMMARIES.lock().get(&key) {
+        return Ok(Some(summary.clone()));
+    }
+
+    let transcript = evicted
+        .iter()
+        .map(|(role, content)| format!("{}: {}", role, content))
+        .collect::<Vec<_>>()
+        .join("\n\n");
+    let request = vec![
+        (
+            "system".to_string(),
+            "Summarize the conversation below. Keep facts, decisions, names and open \
+            questions. Reply with the summary only."
+                .to_string(),
+        ),
+        ("user".to_string(), transcript),
+    ];
+
+    // A very long transcript keeps its most recent part
+    let mut count = |messages: &[(String, String)]| tokenizer::count_prompt_tokens(state, messages);
+    let budget = n_ctx.saturating_sub(max_tokens).max(1) as usize;
+    let request = fit_messages(&request, budget, ContextStrategy::SlidingWindow, &mut count)?;
+
+    let options = GenerationOptions {
+        max_tokens: Some(max_tokens),
+        sampler: Some(SamplerConfig::greedy()),
+        ..GenerationOptions::default()
+    };
+    let prompt = inference::build_chat_prompt_for(state, &request.messages);
+    let result = inference::run_generation(state, &prompt, &options, cancel, &mut |_| {})?;
+    if result.stop_reason == StopReason::Cancelled {
+        return Ok(None);
+    }
+
+    let summary = result.text.trim().to_string();
+    let mut summaries = SUMMARIES.lock();
+    if summaries.len() >= MAX_CACHED_SUMMARIES {
+        summaries.clear();
+    }
+    summaries.insert(key, summary.clone());
+    Ok(Some(summary))
+}
+
+/// Cache key for the summary of `evicted` written by the model in `state`
+fn summary_key(state: &ModelState, evicted: &[&(String, String)], max_tokens: u32) -> u64 {
+    let mut bytes = format!("{}\0{}\0", state.model_path, max_tokens).into_bytes();
+    for (role, content) in evicted {
+        // Lengths keep role/content boundaries unambiguous
+        bytes.extend_from_slice(&(role.len() as u64).to_le_bytes());
+        bytes.extend_from_slice(role.as_bytes());
+        bytes.extend_from_slice(&(content.len() as u64).to_le_bytes());
+        bytes.extend_from_slice(content.as_bytes());
+    }
+    fnv1a(&bytes)
+}
+
+/// Counts the tokens of a rendered conversation
+type TokenCounter<'a> = dyn FnMut(&[(String, String)]) -> Result<usize> + 'a;
+
+/// Messages chosen to fit a token budget
+#[derive(Debug, Default)]
+struct FitPlan {
+    messages: Vec<(String, String)>,
+    dropped: Vec<usize>,
+    truncated: Vec<usize>,
+}
+
+/// Drop or cut messages until the rendered prompt fits `budget` tokens
+///
+/// Leading system messages and the latest message are pinned. `count`
+/// returns the token count of a rendered conversation; it is called once per
+/// message, and the conversation's size is the template's fixed part plus
+/// the size of every kept message.
+fn fit_messages(
+    messages: &[(String, String)],
+    budget: usize,
+    strategy: ContextStrategy,
+    count: &mut TokenCounter,
+) -> Result<FitPlan> {
+    let base = count(&[])?;
+    let costs = messages
+        .iter()
+        .map(|message| message_cost(message, base, count))
+        .collect::<Result<Vec<_>>>()?;
+
+    let mut plan = FitPlan {
+        messages: messages.to_vec(),
+        ..FitPlan::default()
+    };
+    let mut tokens = base + costs.iter().sum::<usize>();
+    if tokens <= budget {
+        return Ok(plan);
+    }
+    if strategy == ContextStrategy::Error {
+        return Err(anyhow!(
+            "Conversation needs {} tokens but only {} fit in the context window",
+            tokens,
+            budget
+        ));
+    }
+
+    let sliding = strategy == ContextStrategy::SlidingWindow;
+    let head = leading_system_count(messages);
+
+    // Evicted units always sit right after the pinned system messages
+    for unit in eviction_units(messages, head, sliding) {
+        if tokens <= budget {
+            break;
+        }
+
+        let removed: Vec<_> = plan.messages.drain(head..head + unit.len()).collect();
+        tokens -= unit.iter().map(|&i| costs[i]).sum::<usize>();
+
+        // Dropping this message made room, so keep as much of its end as fits
+        if sliding && tokens <= budget {
+            if let Some(cut) = truncate_front(&removed[0], budget - tokens, base, count)? {
+                tokens += cut.1;
+                plan.messages.insert(head, cut.0);
+                plan.truncated.push(unit[0]);
+                continue;
+            }
+        }
+        plan.dropped.extend(unit);
+    }
+
+    // Last resort for the sliding window: cut the latest message itself
+    if tokens > budget && sliding && plan.messages.len() > head {
+        let last = plan.messages.len() - 1;
+        let others = tokens - costs[messages.len() - 1];
+        let room = budget.saturating_sub(others);
+        if let Some(cut) = truncate_front(&plan.messages[last], room, base, count)? {
+            tokens = others + cut.1;
+            plan.messages[last] = cut.0;
+            plan.truncated.push(messages.len() - 1);
+        }
+    }
+
+    if tokens > budget {
+        return Err(anyhow!(
+            "The system prompt and latest message need {} tokens but only {} fit in the context window",
+            tokens,
+            budget
+        ));
+    }
+    Ok(plan)
+}
+
+/// Tokens a message adds to a rendered conversation
+fn message_cost(
+    message: &(String, String),
+    base: usize,
+    count: &mut TokenCounter,
+) -> Result<usize> {
+    Ok(count(std::slice::from_ref(message))?.saturating_sub(base))
+}
+
+/// Number of system messages at the start of the conversation
+fn leading_system_count(messages: &[(String, String)]) -> usize {
+    messages
+        .iter()
+        .take_while(|(role, _)| role == "system")
+        .count()
+}
+
+/// Groups of message indices that are evicted together, oldest first
+///
+/// A turn starts at a user message and includes the replies that follow.
+/// The latest message is never part of a unit.
+fn eviction_units(
+    messages: &[(String, String)],
+    head: usize,
+    per_message: bool,
+) -> Vec<Vec<usize>> {
+    let mut units: Vec<Vec<usize>> = Vec::new();
+    for (index, (role, _)) in messages
+        .iter()
+        .enumerate()
+        .take(messages.len().saturating_sub(1))
+        .skip(head)
+    {
+        match units.last_mut() {
+            Some(unit) if !per_message && role != "user" => unit.push(index),
+            _ => units.push(vec![index]),
+        }
+    }
+    units
+}
+
+/// Cut the start of a message's content so it costs at most `room` tokens
+///
+/// # Returns
+/// * The cut message and its cost, or `None` if nothing of it fits
+fn truncate_front(
+    message: &(String, String),
+    room: usize,
+    base: usize,
+    count: &mut TokenCounter,
+) -> Result<Option<((String, String), usize)>> {
+    let (role, content) = message;
+    let boundaries: Vec<usize> = content.char_indices().map(|(i, _)| i).collect();
+
+    // Binary search for the earliest start that fits
+    let (mut low, mut high) = (0, boundaries.len());
+    let mut best = None;
+    while low < high {
+        let mid = (low + high) / 2;
+        let candidate = (role.clone(), content[boundaries[mid]..].to_string());
+        let tokens = message_cost(&candidate, base, count)?;
+        if tokens <= room {
+            best = Some((candidate, tokens));
+            high = mid;
+        } else {
+            low = mid + 1;
+        }
+    }
+    Ok(best)
+}
+
+fn to_u32(indices: &[usize]) -> Vec<u32> {
+    indices.iter().map(|&i| i as u32).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn message(role: &str, content: &str) -> (String, String) {
+        (role.to_string(), content.to_string())
+    }
+
+    /// One token per character plus two per message for the template
+    fn count_chars(messages: &[(String, String)]) -> Result<usize> {
+        Ok(messages.iter().map(|(_, content)| content.len() + 2).sum())
+    }
+
+    fn conversation() -> Vec<(String, String)> {
+        vec![
+            message("system", "be brief"),       // 10
+            message("user", "first question"),   // 16
+            message("assistant", "first reply"), // 13
+            message("user", "second question"),  // 17
+            message("assistant", "second"),      // 8
+            message("user", "latest"),           // 8
+        ]
+    }
+
+    #[test]
+    fn test_fits_without_changes() {
+        let plan = fit_messages(
+            &conversation(),
+            100,
+            ContextStrategy::Error,
+            &mut count_chars,
+        )
+        .unwrap();
+        assert_eq!(plan.messages.len(), 6);
+        assert!(plan.dropped.is_empty());
+
+        assert!(fit_messages(
+            &conversation(),
+            50,
+            ContextStrategy::Error,
+            &mut count_chars
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_drop_oldest_turns() {
+        // 72 tokens in total; dropping the first turn (29) leaves 43
+        let plan = fit_messages(
+            &conversation(),
+            50,
+            ContextStrategy::DropOldest,
+            &mut count_chars,
+        )
+        .unwrap();
+        assert_eq!(plan.dropped, vec![1, 2]);
+        assert_eq!(plan.messages[0].1, "be brief");
+        assert_eq!(plan.messages.last().unwrap().1, "latest");
+
+        // Only the pinned messages fit
+        let plan = fit_messages(
+            &conversation(),
+            20,
+            ContextStrategy::DropOldest,
+            &mut count_chars,
+        )
+        .unwrap();
+        assert_eq!(plan.dropped, vec![1, 2, 3, 4]);
+        assert_eq!(plan.messages.len(), 2);
+
+        // Not even the pinned messages fit
+        assert!(fit_messages(
+            &conversation(),
+            10,
+            ContextStrategy::DropOldest,
+            &mut count_chars
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_sliding_window_truncates() {
+        // Dropping "first question" leaves 56 and "first reply" 43, so the
+        // latter is cut to fit 47
+        let plan = fit_messages(
+            &conversation(),
+            47,
+            ContextStrategy::SlidingWindow,
+            &mut count_chars,
+        )
+        .unwrap();
+        assert_eq!(plan.dropped, vec![1]);
+        assert_eq!(plan.truncated, vec![2]);
+        assert_eq!(plan.messages[1].1, "ly");
+        assert_eq!(count_chars(&plan.messages).unwrap(), 47);
+
+        // With only the pinned messages left, the latest one is cut
+        let plan = fit_messages(
+            &conversation(),
+            15,
+            ContextStrategy::SlidingWindow,
+            &mut count_chars,
+        )
+        .unwrap();
+        assert_eq!(plan.truncated, vec![5]);
+        assert_eq!(plan.messages.last().unwrap().1, "est");
+    }
+
+    #[test]
+    fn test_counts_each_message_once() {
+        let mut calls = 0;
+        let mut count = |messages: &[(String, String)]| {
+            calls += 1;
+            count_chars(messages)
+        };
+        fit_messages(&conversation(), 20, ContextStrategy::DropOldest, &mut count).unwrap();
+
+        // The empty template plus one call per message
+        assert_eq!(calls, conversation().len() + 1);
+    }
+
+    #[test]
+    fn test_eviction_units() {
+        let messages = conversation();
+        assert_eq!(
+            eviction_units(&messages, 1, false),
+            vec![vec![1, 2], vec![3, 4]]
+        );
+        assert_eq!(
+            eviction_units(&messages, 1, true),
+            vec![vec![1], vec![2], vec![3], vec![4]]
+        );
+        assert!(eviction_units(&messages[..1], 1, false).is_empty());
+    }
+}
@@ -0,0 +1,640 @@
+//! Near-Duplicate Detection
+//!
+//! Finds notes and chunks that repeat each other:
+//! - MinHash signatures over word shingles, bucketed with locality-sensitive
+//!   hashing, find textually similar pairs without comparing every pair
+//! - Embedding similarity adds pairs whose wording drifted, such as a pasted
+//!   paragraph inside otherwise different notes; neighbors come from an HNSW
+//!   index rather than a comparison of every pair
+//! - Each pair is confirmed on the text: a high shingle Jaccard similarity,
+//!   or a shared passage of at least `min_span_words` words
+//!
+//! High cosine similarity alone marks paraphrases, not duplicates, so it
+//! never confirms a pair. For passages copied between long notes, chunk
+//! entries (e.g. from `embed_markdown_note`) give the best results.
+
+use anyhow::{bail, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+
+use crate::backend::fnv1a;
+use crate::embeddings::{cosine_similarity, EmbeddingEntry, NoteMetadata};
+use crate::hnsw::{HnswConfig, HnswIndex};
+use crate::rag;
+use crate::vector_store::VectorStore;
+
+/// Embedding neighbors checked per document
+const EMBEDDING_NEIGHBORS: usize = 32;
+
+/// A note or chunk to check for duplicates
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DedupDocument {
+    /// Note id, or chunk id such as `note#chunk3`
+    pub id: String,
+    /// Full text
+    pub text: String,
+    /// Embedding of the text, if available
+    pub vector: Option<Vec<f32>>,
+    /// Creation time in milliseconds since the Unix epoch; older documents
+    /// are preferred as the canonical copy
+    pub created_at: Option<i64>,
+}
+
+impl TryFrom<&EmbeddingEntry> for DedupDocument {
+    type Error = anyhow::Error;
+
+    /// Use the entry's `text_preview` as the text
+    ///
+    /// Fails for entries without one, since nothing could confirm their
+    /// pairs. Previews cut to 100 bytes (from the batch embedding functions)
+    /// only match within those bytes; chunk entries carry their full text.
+    fn try_from(entry: &EmbeddingEntry) -> Result<Self> {
+        let Some(text) = entry.text_preview.clone().filter(|text| !text.is_empty()) else {
+            bail!("Entry {} has no text to compare", entry.id);
+        };
+        Ok(Self {
+            id: entry.id.clone(),
+            text,
+            vector: Some(entry.vector.clone()),
+            created_at: entry.metadata.created_at,
+        })
+    }
+}
+
+/// Thresholds for duplicate detection
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DedupOptions {
+    /// Words per shingle (default: 5)
+    pub shingle_words: usize,
+    /// MinHash signature length; a multiple of `bands` (default: 128)
+    pub num_hashes: usize,
+    /// LSH bands; more bands find pairs with lower similarity (default: 32)
+    pub bands: usize,
+    /// Shingle Jaccard similarity that makes a pair near-duplicates (default: 0.5)
+    pub min_jaccard: f32,
+    /// Cosine similarity that makes a pair worth checking for shared passages (default: 0.9)
+    pub min_cosine: f32,
+    /// Words in the shortest shared passage reported (default: 12)
+    pub min_span_words: usize,
+}
+
+impl Default for DedupOptions {
+    fn default() -> Self {
+        Self {
+            shingle_words: 5,
+            num_hashes: 128,
+            bands: 32,
+            min_jaccard: 0.5,
+            min_cosine: 0.9,
+            min_span_words: 12,
+        }
+    }
+}
+
+/// A passage found in both documents of a pair, as byte ranges
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DuplicateSpan {
+    /// Start of the passage in the first document
+    pub a_start: usize,
+    /// End of the passage in the first document
+    pub a_end: usize,
+    /// Start of the passage in the second document
+    pub b_start: usize,
+    /// End of the passage in the second document
+    pub b_end: usize,
+    /// Length of the passage in words
+    pub words: usize,
+}
+
+/// Two documents that duplicate each other
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DuplicatePair {
+    /// First document id
+    pub a: String,
+    /// Second document id
+    pub b: String,
+    /// Jaccard similarity of the shingle sets
+    pub jaccard: f32,
+    /// Cosine similarity of the embeddings, if both have one
+    pub cosine: Option<f32>,
+    /// Shared passages, longest first
+    pub spans: Vec<DuplicateSpan>,
+}
+
+/// Documents connected by duplicate pairs
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DuplicateGroup {
+    /// Document ids in the group
+    pub ids: Vec<String>,
+    /// Suggested document to keep: the oldest, then the longest
+    pub canonical: String,
+    /// Note of the suggested document (differs from `canonical` for chunks)
+    pub canonical_note: String,
+    /// The pairs that formed the group
+    pub pairs: Vec<DuplicatePair>,
+}
+
+/// Words of a document with their byte ranges, and its shingle hashes
+struct Analyzed {
+    words: Vec<(usize, usize)>,
+    shingles: Vec<u64>,
+    shingle_set: HashSet<u64>,
+}
+
+/// Group near-duplicate documents
+///
+/// # Arguments
+/// * `documents` - Notes or chunks to compare
+/// * `options` - Detection thresholds
+///
+/// # Returns
+/// * Duplicate groups, largest first; documents without duplicates are omitted
+pub fn find_duplicates(documents: &[DedupDocument], options: &DedupOptions) -> Vec<DuplicateGroup> {
+    let mut finder = DuplicateFinder::new(options.clone());
+    for document in documents {
+        finder.add(
+            &document.id,
+            &document.text,
+            document.vector.as_deref(),
+            document.created_at,
+        );
+    }
+    finder.finish()
+}
+
+/// Group near-duplicate entries of a vector store
+///
+/// Entries are read from the store one at a time and need their text as
+/// `text_preview`, as for `DedupDocument::try_from`.
+pub fn find_duplicates_in_store(
+    store: &VectorStore,
+    options: &DedupOptions,
+) -> Result<Vec<DuplicateGroup>> {
+    let mut finder = DuplicateFinder::new(options.clone());
+    store.for_each_vector(|id, vector, preview, metadata| {
+        let Some(text) = preview.filter(|text| !text.is_empty()) else {
+            bail!("Entry {} has no text to compare", id);
+        };
+        finder.add(id, text, Some(vector), metadata.created_at);
+        Ok(())
+    })?;
+    Ok(finder.finish())
+}
+
+/// Collects documents one at a time and groups their duplicates
+///
+/// Keeps the word ranges and shingles of each text rather than the text,
+/// and inserts vectors into an HNSW index as they arrive.
+pub struct DuplicateFinder {
+    options: DedupOptions,
+    ids: Vec<String>,
+    created_at: Vec<Option<i64>>,
+    analyzed: Vec<Analyzed>,
+    index: HnswIndex,
+}
+
+impl DuplicateFinder {
+    /// Create an empty finder
+    pub fn new(options: DedupOptions) -> Self {
+        Self {
+            options,
+            ids: Vec::new(),
+            created_at: Vec::new(),
+            analyzed: Vec::new(),
+            index: HnswIndex::new(HnswConfig::default()),
+        }
+    }
+
+    /// Add a document
+    ///
+    /// A vector that is empty or differs in dimension from the first one is
+    /// ignored, as it cannot be compared.
+    pub fn add(&mut self, id: &str, text: &str, vector: Option<&[f32]>, created_at: Option<i64>) {
+        let document = self.ids.len();
+        if let Some(vector) = vector {
+            // Fails only for vectors the index cannot compare
+            let _ = self.index.insert(EmbeddingEntry {
+                id: document.to_string(),
+                vector: vector.to_vec(),
+                text_preview: None,
+                metadata: NoteMetadata::default(),
+            });
+        }
+        self.ids.push(id.to_string());
+        self.created_at.push(created_at);
+        self.analyzed
+            .push(analyze(text, self.options.shingle_words.max(1)));
+    }
+
+    /// Confirm candidate pairs on the text and group them
+    ///
+    /// # Returns
+    /// * Duplicate groups, largest first; documents without duplicates are omitted
+    pub fn finish(self) -> Vec<DuplicateGroup> {
+        let options = &self.options;
+        let shingle_words = options.shingle_words.max(1);
+        let mut candidates = lsh_candidates(&self.analyzed, options);
+        candidates.extend(self.embedding_candidates());
+        let mut candidates: Vec<(usize, usize)> = candidates.into_iter().collect();
+        candidates.sort_unstable();
+
+        let mut pairs = Vec::new();
+        for (i, j) in candidates {
+            let (a, b) = (&self.analyzed[i], &self.analyzed[j]);
+            let jaccard = jaccard(&a.shingle_set, &b.shingle_set);
+            let spans = shared_spans(a, b, shingle_words, options.min_span_words);
+            if jaccard >= options.min_jaccard || !spans.is_empty() {
+                pairs.push((
+                    i,
+                    j,
+                    DuplicatePair {
+                        a: self.ids[i].clone(),
+                        b: self.ids[j].clone(),
+                        jaccard,
+                        cosine: self.pair_cosine(i, j),
+                        spans,
+                    },
+                ));
+            }
+        }
+
+        self.group_pairs(pairs)
+    }
+
+    /// Pairs whose embeddings are at least `min_cosine` similar, among each
+    /// document's nearest neighbors in the index
+    fn embedding_candidates(&self) -> HashSet<(usize, usize)> {
+        let mut candidates = HashSet::new();
+        for i in 0..self.ids.len() {
+            let Some(vector) = self.index.vector(&i.to_string()) else {
+                continue;
+            };
+            let neighbors =
+                self.index
+                    .search(vector, EMBEDDING_NEIGHBORS + 1, self.options.min_cosine);
+            for neighbor in neighbors {
+                let Ok(j) = neighbor.id.parse::<usize>() else {
+                    continue;
+                };
+                if i != j {
+                    candidates.insert((i.min(j), i.max(j)));
+                }
+            }
+        }
+        candidates
+    }
+
+    fn pair_cosine(&self, i: usize, j: usize) -> Option<f32> {
+        let a = self.index.vector(&i.to_string())?;
+        let b = self.index.vector(&j.to_string())?;
+        Some(cosine_similarity(a, b))
+    }
+
+    /// Union pairs into groups and pick a canonical document for each
+    fn group_pairs(&self, pairs: Vec<(usize, usize, DuplicatePair)>) -> Vec<DuplicateGroup> {
+        let mut parent: Vec<usize> = (0..self.ids.len()).collect();
+        fn root(parent: &mut [usize], mut i: usize) -> usize {
+            while parent[i] != i {
+                parent[i] = parent[parent[i]];
+                i = parent[i];
+            }
+            i
+        }
+        for (i, j, _) in &pairs {
+            let (ri, rj) = (root(&mut parent, *i), root(&mut parent, *j));
+            parent[ri.max(rj)] = ri.min(rj);
+        }
+
+        let mut groups: HashMap<usize, (Vec<usize>, Vec<DuplicatePair>)> = HashMap::new();
+        for (i, _, pair) in pairs {
+            let group_root = root(&mut parent, i);
+            groups.entry(group_root).or_default().1.push(pair);
+        }
+        for document in 0..self.ids.len() {
+            let group_root = root(&mut parent, document);
+            if let Some(group) = groups.get_mut(&group_root) {
+                group.0.push(document);
+            }
+        }
+
+        let mut result: Vec<DuplicateGroup> = groups
+            .into_values()
+            .map(|(members, pairs)| {
+                let canonical = *members
+                    .iter()
+                    .min_by_key(|&&i| {
+                        (
+                            self.created_at[i].unwrap_or(i64::MAX),
+                            std::cmp::Reverse(self.analyzed[i].words.len()),
+                            i,
+                        )
+                    })
+                    .expect("groups have members");
+                let canonical_id = self.ids[canonical].clone();
+                DuplicateGroup {
+                    ids: members.iter().map(|&i| self.ids[i].clone()).collect(),
+                    canonical_note: rag::parse_chunk_id(&canonical_id).0,
+                    canonical: canonical_id,
+                    pairs,
+                }
+            })
+            .collect();
+        result.sort_by(|a, b| b.ids.len().cmp(&a.ids.len()).then(a.ids.cmp(&b.ids)));
+        result
+    }
+}
+
+/// Lowercase words with their byte ranges, and hashes of every run of
+/// `shingle_words` words
+fn analyze(text: &str, shingle_words: usize) -> Analyzed {
+    let mut words = Vec::new();
+    let mut start = None;
+    for (i, c) in text.char_indices() {
+        let in_word = c.is_alphanumeric() || c == '_';
+        match (in_word, start) {
+            (true, None) => start = Some(i),
+            (false, Some(s)) => {
+                words.push((s, i));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    if let Some(s) = start {
+        words.push((s, text.len()));
+    }
+
+    let lowered: Vec<String> = words
+        .iter()
+        .map(|&(s, e)| text[s..e].to_lowercase())
+        .collect();
+    let shingles: Vec<u64> = lowered
+        .windows(shingle_words)
+        .map(|window| fnv1a(window.join(" ").as_bytes()))
+        .collect();
+    let shingle_set = shingles.iter().copied().collect();
+    Analyzed {
+        words,
+        shingles,
+        shingle_set,
+    }
+}
+
+/// Pairs whose MinHash signatures agree on every row of some band
+fn lsh_candidates(analyzed: &[Analyzed], options: &DedupOptions) -> HashSet<(usize, usize)> {
+    let bands = options.bands.max(1);
+    let rows = (options.num_hashes / bands).max(1);
+    let seeds: Vec<u64> = (0..bands * rows).map(|i| mix(i as u64 + 1)).collect();
+
+    let mut buckets: HashMap<(usize, u64), Vec<usize>> = HashMap::new();
+    for (document, analyzed) in analyzed.iter().enumerate() {
+        if analyzed.shingle_set.is_empty() {
+            continue;
+        }
+        let signature: Vec<u64> = seeds
+            .iter()
+            .map(|seed| {
+                analyzed
+                    .shingle_set
+                    .iter()
+                    .map(|shingle| mix(shingle ^ seed))
+                    .min()
+                    .unwrap_or(u64::MAX)
+            })
+            .collect();
+        for (band, rows) in signature.chunks_exact(rows).enumerate() {
+            let key = rows.iter().fold(0u64, |hash, row| mix(hash ^ row));
+            buckets.entry((band, key)).or_default().push(document);
+        }
+    }
+
+    let mut candidates = HashSet::new();
+    for members in buckets.values() {
+        for (n, &i) in members.iter().enumerate() {
+            for &j in &members[n + 1..] {
+                candidates.insert((i.min(j), i.max(j)));
+            }
+        }
+    }
+    candidates
+}
+
+/// SplitMix64 finalizer, used as a family of hash functions
+fn mix(value: u64) -> u64 {
+    let mut z = value.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
+fn jaccard(a: &HashSet<u64>, b: &HashSet<u64>) -> f32 {
+    let union = a.union(b).count();
+    if union == 0 {
+        return 0.0;
+    }
+    a.intersection(b).count() as f32 / union as f32
+}
+
+/// Maximal runs of shingles that appear in the same order in both documents
+fn shared_spans(
+    a: &Analyzed,
+    b: &Analyzed,
+    shingle_words: usize,
+    min_span_words: usize,
+) -> Vec<DuplicateSpan> {
+    let mut positions: HashMap<u64, Vec<usize>> = HashMap::new();
+    for (i, shingle) in a.shingles.iter().enumerate() {
+        positions.entry(*shingle).or_default().push(i);
+    }
+
+    // Run length of matches ending at (a shingle, current b shingle)
+    let mut previous: HashMap<usize, usize> = HashMap::new();
+    let mut runs = Vec::new();
+    for (j, shingle) in b.shingles.iter().enumerate() {
+        let mut current = HashMap::new();
+        for &i in positions.get(shingle).map(Vec::as_slice).unwrap_or(&[]) {
+            let length = i
+                .checked_sub(1)
+                .and_then(|p| previous.get(&p))
+                .map_or(1, |length| length + 1);
+            current.insert(i, length);
+        }
+        for (&i, &length) in &previous {
+            if !current.contains_key(&(i + 1)) {
+                runs.push((i, j - 1, length));
+            }
+        }
+        previous = current;
+    }
+    if let Some(last) = b.shingles.len().checked_sub(1) {
+        runs.extend(previous.into_iter().map(|(i, length)| (i, last, length)));
+    }
+
+    let mut spans: Vec<DuplicateSpan> = runs
+        .into_iter()
+        .map(|(a_end, b_end, length)| {
+            let words = length + shingle_words - 1;
+            let (a_first, b_first) = (a_end + 1 - length, b_end + 1 - length);
+            let (a_last, b_last) = (a_end + shingle_words - 1, b_end + shingle_words - 1);
+            DuplicateSpan {
+                a_start: a.words[a_first].0,
+                a_end: a.words[a_last].1,
+                b_start: b.words[b_first].0,
+                b_end: b.words[b_last].1,
+                words,
+            }
+        })
+        .filter(|span| span.words >= min_span_words)
+        .collect();
+    spans.sort_by(|x, y| y.words.cmp(&x.words).then(x.a_start.cmp(&y.a_start)));
+    spans
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn document(id: &str, text: &str, vector: Option<Vec<f32>>, created_at: i64) -> DedupDocument {
+        DedupDocument {
+            id: id.to_string(),
+            text: text.to_string(),
+            vector,
+            created_at: Some(created_at),
+        }
+    }
+
+    const LECTURE: &str = "The mitochondria is the powerhouse of the cell and produces \
+        most of the chemical energy needed to power biochemical reactions, stored in \
+        a molecule called adenosine triphosphate which cells use everywhere.";
+
+    #[test]
+    fn test_near_duplicate_notes() {
+        let edited = LECTURE.replace("everywhere", "constantly");
+        let documents = vec![
+            document("copy.md", &edited, None, 20),
+            document(
+                "other.md",
+                "Shopping list: eggs, milk, bread and coffee.",
+                None,
+                5,
+            ),
+            document("original.md", LECTURE, None, 10),
+        ];
+
+        let groups = find_duplicates(&documents, &DedupOptions::default());
+
+        assert_eq!(groups.len(), 1);
+        assert_eq!(groups[0].ids, vec!["copy.md", "original.md"]);
+        assert_eq!(groups[0].canonical, "original.md");
+        let pair = &groups[0].pairs[0];
+        assert!(pair.jaccard > 0.8);
+        assert_eq!(pair.spans[0].words, 30);
+    }
+
+    #[test]
+    fn test_shared_passage_and_paraphrase() {
+        let a = format!("Week three notes. {} Quiz on Friday.", LECTURE);
+        let b = format!(
+            "Biology summary for the exam: {} Remember the diagrams!",
+            LECTURE
+        );
+        let paraphrase = "Cells get their energy from mitochondria, which make ATP.";
+        let documents = vec![
+            document("week3.md#chunk0", &a, Some(vec![1.0, 0.1]), 1),
+            document("summary.md#chunk2", &b, Some(vec![0.95, 0.2]), 2),
+            document("flashcards.md", paraphrase, Some(vec![1.0, 0.15]), 3),
+        ];
+
+        let groups = find_duplicates(&documents, &DedupOptions::default());
+
+        assert_eq!(groups.len(), 1);
+        let group = &groups[0];
+        assert_eq!(group.ids, vec!["week3.md#chunk0", "summary.md#chunk2"]);
+        assert_eq!(group.canonical_note, "week3.md");
+        let pair = &group.pairs[0];
+        assert!(pair.cosine.unwrap() > 0.9);
+        assert_eq!(pair.spans.len(), 1);
+        let span = &pair.spans[0];
+        assert_eq!(&a[span.a_start..span.a_end], &LECTURE[..LECTURE.len() - 1]);
+        assert_eq!(&b[span.b_start..span.b_end], &LECTURE[..LECTURE.len() - 1]);
+    }
+
+    #[test]
+    fn test_embedding_candidates_and_entries() {
+        let mut state = 3u64;
+        let mut random = move || {
+            state = mix(state);
+            (state >> 40) as f32 / (1u64 << 24) as f32 - 0.5
+        };
+        let mut documents: Vec<DedupDocument> = (0..300)
+            .map(|i| {
+                let vector = (0..16).map(|_| random()).collect();
+                document(&format!("n{}", i), "", Some(vector), i)
+            })
+            .collect();
+        let near_copy: Vec<f32> = documents[7]
+            .vector
+            .as_ref()
+            .unwrap()
+            .iter()
+            .map(|x| x * 1.01)
+            .collect();
+        documents[250].vector = Some(near_copy);
+        documents[10].vector = None;
+
+        let mut finder = DuplicateFinder::new(DedupOptions {
+            min_cosine: 0.99,
+            ..DedupOptions::default()
+        });
+        for document in &documents {
+            finder.add(&document.id, "", document.vector.as_deref(), None);
+        }
+        let candidates = finder.embedding_candidates();
+        assert!(candidates.contains(&(7, 250)));
+        assert!(candidates.len() < 10);
+
+        let entry = EmbeddingEntry {
+            id: "empty".to_string(),
+            vector: vec![1.0],
+            text_preview: None,
+            metadata: NoteMetadata::default(),
+        };
+        assert!(DedupDocument::try_from(&entry).is_err());
+    }
+
+    #[test]
+    fn test_duplicates_in_store() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut store = VectorStore::open(dir.path()).unwrap();
+        let edited = LECTURE.replace("everywhere", "constantly");
+        let entry = |id: &str, text: &str, created_at: i64| EmbeddingEntry {
+            id: id.to_string(),
+            vector: vec![1.0, created_at as f32],
+            text_preview: Some(text.to_string()),
+            metadata: NoteMetadata {
+                created_at: Some(created_at),
+                ..NoteMetadata::default()
+            },
+        };
+        store
+            .upsert(&[
+                entry("copy.md", &edited, 20),
+                entry("other.md", "Shopping list: eggs, milk, bread.", 5),
+                entry("original.md", LECTURE, 10),
+            ])
+            .unwrap();
+
+        let groups = find_duplicates_in_store(&store, &DedupOptions::default()).unwrap();
+        assert_eq!(groups.len(), 1);
+        assert_eq!(groups[0].ids, vec!["copy.md", "original.md"]);
+        assert_eq!(groups[0].canonical, "original.md");
+
+        store
+            .upsert(&[EmbeddingEntry {
+                text_preview: None,
+                ..entry("empty.md", "", 30)
+            }])
+            .unwrap();
+        assert!(find_duplicates_in_store(&store, &DedupOptions::default()).is_err());
+    }
+}
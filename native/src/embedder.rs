@@ -0,0 +1,515 @@
+//! Embedding Model
+//!
+//! Computes text embeddings in batches:
+//! - Dedicated slot for an embedding model (nomic-embed, bge, ...)
+//! - Selectable pooling (native, mean, CLS, last token)
+//! - Instruction prefixes for queries and documents
+//! - Long texts split into overlapping chunks and averaged
+//! - Many sequences decoded together in a single batch
+//!
+//! Without an embedding model, the default chat model is used with mean pooling.
+
+use anyhow::{anyhow, Result};
+use llama_cpp_2::context::params::{LlamaContextParams, LlamaPoolingType};
+use llama_cpp_2::llama_backend::LlamaBackend;
+use llama_cpp_2::llama_batch::LlamaBatch;
+use llama_cpp_2::model::params::LlamaModelParams;
+use llama_cpp_2::model::{AddBos, LlamaModel};
+use llama_cpp_2::token::LlamaToken;
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use std::num::NonZeroU32;
+use std::ops::Range;
+use std::path::Path;
+use std::sync::Arc;
+
+use crate::gguf::{self, GgufMetadata};
+use crate::inference;
+use crate::tokenizer;
+
+/// How token states are combined into one vector per sequence
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PoolingMode {
+    /// Use the pooling the model was trained with (mean if it declares none)
+    Native,
+    /// Average of all token states
+    Mean,
+    /// State of the first (CLS) token
+    Cls,
+    /// State of the last token
+    Last,
+}
+
+/// What a text is embedded as, which selects the instruction prefix
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum EmbeddingKind {
+    /// A search query
+    Query,
+    /// A document to be searched (notes, chunks)
+    Document,
+}
+
+/// Configuration for the embedding model
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EmbeddingConfig {
+    /// Number of GPU layers to offload (99 = all possible)
+    pub n_gpu_layers: u32,
+    /// Number of CPU threads for processing
+    pub n_threads: i32,
+    /// How token states are pooled
+    pub pooling: PoolingMode,
+    /// Maximum tokens per chunk (capped at the model's training context)
+    pub chunk_tokens: u32,
+    /// Tokens shared by consecutive chunks of a long text
+    pub chunk_overlap: u32,
+    /// Chunks decoded together in one batch
+    pub max_sequences: u32,
+    /// Prepended to queries (e.g. "search_query: " for nomic-embed)
+    pub query_prefix: String,
+    /// Prepended to documents (e.g. "search_document: " for nomic-embed)
+    pub document_prefix: String,
+}
+
+impl Default for EmbeddingConfig {
+    fn default() -> Self {
+        Self {
+            n_gpu_layers: 99,
+            n_threads: 4,
+            pooling: PoolingMode::Native,
+            chunk_tokens: 512,
+            chunk_overlap: 64,
+            max_sequences: 32,
+            query_prefix: String::new(),
+            document_prefix: String::new(),
+        }
+    }
+}
+
+impl EmbeddingConfig {
+    /// Settings for embedding with a chat model, which has no pooling of its own
+    fn for_chat_model(n_threads: i32) -> Self {
+        Self {
+            n_threads,
+            pooling: PoolingMode::Mean,
+            max_sequences: 8,
+            ..Self::default()
+        }
+    }
+
+    fn prefix(&self, kind: EmbeddingKind) -> &str {
+        match kind {
+            EmbeddingKind::Query => &self.query_prefix,
+            EmbeddingKind::Document => &self.document_prefix,
+        }
+    }
+}
+
+/// The loaded embedding model
+struct EmbeddingModel {
+    backend: &'static LlamaBackend,
+    model: LlamaModel,
+    config: EmbeddingConfig,
+    metadata: GgufMetadata,
+}
+
+/// Embedding model slot, separate from the chat model registry
+static EMBEDDING_MODEL: Mutex<Option<Arc<EmbeddingModel>>> = Mutex::new(None);
+
+/// Load a dedicated embedding model, replacing any previous one
+///
+/// # Arguments
+/// * `model_path` - Full path to the GGUF embedding model
+/// * `config` - Optional embedding configuration
+pub fn load_embedding_model(model_path: String, config: Option<EmbeddingConfig>) -> Result<()> {
+    let config = config.unwrap_or_default();
+    let metadata = gguf::inspect_gguf(Path::new(&model_path))?;
+
+    log::info!(
+        "Loading embedding model from: {} (architecture: {:?})",
+        model_path,
+        metadata.architecture
+    );
+
+    let backend = inference::backend()?;
+    let model_params = LlamaModelParams::default().with_n_gpu_layers(config.n_gpu_layers);
+    let model = LlamaModel::load_from_file(backend, Path::new(&model_path), &model_params)
+        .map_err(|e| anyhow!("Failed to load embedding model: {:?}", e))?;
+
+    *EMBEDDING_MODEL.lock() = Some(Arc::new(EmbeddingModel {
+        backend,
+        model,
+        config,
+        metadata,
+    }));
+    Ok(())
+}
+
+/// Unload the embedding model (embeddings fall back to the chat model)
+pub fn unload_embedding_model() {
+    *EMBEDDING_MODEL.lock() = None;
+}
+
+/// Check if a dedicated embedding model is loaded
+pub fn is_embedding_model_loaded() -> bool {
+    EMBEDDING_MODEL.lock().is_some()
+}
+
+/// Dimension of the vectors `embed_texts` returns
+pub fn embedding_dimension() -> Result<usize> {
+    let embedder = EMBEDDING_MODEL.lock().clone();
+    match embedder {
+        Some(embedder) => Ok(embedder.model.n_embd() as usize),
+        None => inference::get_embedding_dimension(None),
+    }
+}
+
+/// Byte offsets in `text` where each token starts
+///
+/// `None` uses the embedding model's tokenizer, or the default chat model's
+/// when no embedding model is loaded (the model `embed_texts` falls back to).
+pub(crate) fn token_offsets(text: &str, model_id: Option<&str>) -> Result<Vec<usize>> {
+    let embedder = match model_id {
+        Some(_) => None,
+        None => EMBEDDING_MODEL.lock().clone(),
+    };
+    match embedder {
+        Some(embedder) => tokenizer::model_token_offsets(&embedder.model, text),
+        None => tokenizer::model_token_offsets(&inference::current_state(model_id)?.model, text),
+    }
+}
+
+/// Embed several texts, using the embedding model if one is loaded
+///
+/// # Arguments
+/// * `texts` - Texts to embed
+/// * `kind` - Whether the texts are queries or documents
+///
+/// # Returns
+/// * One L2-normalized vector per text, in input order
+pub fn embed_texts(texts: &[String], kind: EmbeddingKind) -> Result<Vec<Vec<f32>>> {
+    let embedder = EMBEDDING_MODEL.lock().clone();
+    match embedder {
+        Some(embedder) => {
+            let pooling = resolve_pooling(embedder.config.pooling, &embedder.metadata);
+            embed_with_model(
+                &embedder.model,
+                embedder.backend,
+                &embedder.config,
+                pooling,
+                texts,
+                kind,
+            )
+        }
+        None => embed_with_chat_model(texts, None),
+    }
+}
+
+/// Identity of the model `embed_texts` uses, for caching its vectors
+///
+/// Covers the model file, pooling and instruction prefix, so it changes
+/// whenever the same text would embed differently.
+pub fn embedding_model_identity(kind: EmbeddingKind) -> Result<String> {
+    let embedder = EMBEDDING_MODEL.lock().clone();
+    let (metadata, pooling, prefix) = match &embedder {
+        Some(embedder) => (
+            embedder.metadata.clone(),
+            embedder.config.pooling,
+            embedder.config.prefix(kind).to_string(),
+        ),
+        None => (
+            inference::current_state(None)?.metadata.clone(),
+            PoolingMode::Mean,
+            String::new(),
+        ),
+    };
+    Ok(format!(
+        "{}|{}|{}|{}|{}|{:?}|{}",
+        metadata.name.as_deref().unwrap_or(""),
+        metadata.architecture.as_deref().unwrap_or(""),
+        metadata.quantization.as_deref().unwrap_or(""),
+        metadata.parameter_count,
+        metadata.file_size,
+        pooling,
+        prefix
+    ))
+}
+
+/// Embed texts with a model from the chat model registry (mean pooling)
+///
+/// # Arguments
+/// * `texts` - Texts to embed
+/// * `model_id` - Model to use (`None` = the default model)
+pub fn embed_with_chat_model(texts: &[String], model_id: Option<&str>) -> Result<Vec<Vec<f32>>> {
+    let state = inference::current_state(model_id)?;
+    let config = EmbeddingConfig::for_chat_model(state.config.n_threads);
+    embed_with_model(
+        &state.model,
+        state.backend,
+        &config,
+        LlamaPoolingType::Mean,
+        texts,
+        EmbeddingKind::Document,
+    )
+}
+
+/// Map a pooling mode to llama.cpp, deferring to the model when asked
+fn resolve_pooling(mode: PoolingMode, metadata: &GgufMetadata) -> LlamaPoolingType {
+    match mode {
+        // Models declaring no pooling (or NONE = 0) would return no sequence embedding
+        PoolingMode::Native if metadata.pooling_type.is_some_and(|pooling| pooling != 0) => {
+            LlamaPoolingType::Unspecified
+        }
+        PoolingMode::Native | PoolingMode::Mean => LlamaPoolingType::Mean,
+        PoolingMode::Cls => LlamaPoolingType::Cls,
+        PoolingMode::Last => LlamaPoolingType::Last,
+    }
+}
+
+/// A piece of one input text, small enough to decode as one sequence
+struct Chunk {
+    text_index: usize,
+    tokens: Vec<LlamaToken>,
+}
+
+fn embed_with_model(
+    model: &LlamaModel,
+    backend: &LlamaBackend,
+    config: &EmbeddingConfig,
+    pooling: LlamaPoolingType,
+    texts: &[String],
+    kind: EmbeddingKind,
+) -> Result<Vec<Vec<f32>>> {
+    let n_embd = model.n_embd() as usize;
+    let chunk_size = (config.chunk_tokens.min(model.n_ctx_train()) as usize).max(1);
+    let max_sequences = config.max_sequences.max(1) as usize;
+
+    // Split every text into chunks of at most `chunk_size` tokens
+    let bos = model.token_bos();
+    let mut chunks = Vec::new();
+    for (text_index, text) in texts.iter().enumerate() {
+        let input = format!("{}{}", config.prefix(kind), text);
+        let tokens = model
+            .str_to_token(&input, AddBos::Always)
+            .map_err(|e| anyhow!("Failed to tokenize: {:?}", e))?;
+
+        // Every chunk starts with BOS/CLS, so windows cover the tokens after it
+        let (prefix, body) = match tokens.split_first() {
+            Some((first, rest)) if *first == bos => (Some(*first), rest),
+            _ => (None, tokens.as_slice()),
+        };
+        let window = chunk_size - usize::from(prefix.is_some());
+        let overlap = config.chunk_overlap as usize;
+
+        for range in chunk_ranges(body.len(), window.max(1), overlap) {
+            let mut chunk_tokens: Vec<LlamaToken> = prefix.into_iter().collect();
+            chunk_tokens.extend_from_slice(&body[range]);
+            chunks.push(Chunk {
+                text_index,
+                tokens: chunk_tokens,
+            });
+        }
+    }
+
+    // A decode holds one window of tokens: the longest chunk, or several
+    // shorter chunks packed together. Encoder models need the whole batch in
+    // one ubatch, so n_batch and n_ubatch match.
+    let lengths: Vec<usize> = chunks.iter().map(|chunk| chunk.tokens.len()).collect();
+    let longest = lengths.iter().copied().max().unwrap_or(0);
+    let total: usize = lengths.iter().sum();
+    let batch_tokens = chunk_size.min(total).max(longest).max(1);
+    let groups = group_chunks(&lengths, max_sequences, batch_tokens);
+    let n_seq = groups.iter().map(|group| group.len()).max().unwrap_or(1);
+
+    // Causal models keep a cache: each sequence gets `longest` cells
+    let n_ctx = (longest.max(1) * n_seq) as u32;
+    let ctx_params = LlamaContextParams::default()
+        .with_n_ctx(NonZeroU32::new(n_ctx))
+        .with_n_batch(batch_tokens as u32)
+        .with_n_ubatch(batch_tokens as u32)
+        .with_n_seq_max(n_seq as u32)
+        .with_n_threads(config.n_threads)
+        .with_n_threads_batch(config.n_threads)
+        .with_embeddings(true)
+        .with_pooling_type(pooling);
+
+    let mut ctx = model
+        .new_context(backend, ctx_params)
+        .map_err(|e| anyhow!("Failed to create embedding context: {:?}", e))?;
+    let mut batch = LlamaBatch::new(batch_tokens, 1);
+
+    // Weighted sum of chunk vectors per text
+    let mut sums = vec![vec![0.0f32; n_embd]; texts.len()];
+    for range in groups {
+        let group = &chunks[range];
+        batch.clear();
+        ctx.clear_kv_cache();
+
+        for (seq_id, chunk) in group.iter().enumerate() {
+            if !chunk.tokens.is_empty() {
+                batch.add_sequence(&chunk.tokens, seq_id as i32, true)?;
+            }
+        }
+        if batch.n_tokens() == 0 {
+            continue;
+        }
+
+        ctx.decode(&mut batch)
+            .map_err(|e| anyhow!("Failed to compute embeddings: {:?}", e))?;
+
+        for (seq_id, chunk) in group.iter().enumerate() {
+            if chunk.tokens.is_empty() {
+                continue;
+            }
+            let embedding = ctx
+                .embeddings_seq_ith(seq_id as i32)
+                .map_err(|e| anyhow!("Failed to get embedding for sequence {}: {:?}", seq_id, e))?;
+            add_weighted(
+                &mut sums[chunk.text_index],
+                embedding,
+                chunk.tokens.len() as f32,
+            );
+        }
+    }
+
+    // L2 normalize for cosine similarity
+    for sum in &mut sums {
+        normalize(sum);
+    }
+    Ok(sums)
+}
+
+/// Split consecutive chunks into decode groups
+///
+/// A group holds at most `max_sequences` chunks and `max_tokens` tokens; a
+/// chunk longer than `max_tokens` still gets a group of its own.
+fn group_chunks(lengths: &[usize], max_sequences: usize, max_tokens: usize) -> Vec<Range<usize>> {
+    let mut groups = Vec::new();
+    let mut start = 0;
+    let mut tokens = 0;
+    for (i, &len) in lengths.iter().enumerate() {
+        let full = i - start >= max_sequences || tokens + len > max_tokens;
+        if i > start && full {
+            groups.push(start..i);
+            start = i;
+            tokens = 0;
+        }
+        tokens += len;
+    }
+    if start < lengths.len() {
+        groups.push(start..lengths.len());
+    }
+    groups
+}
+
+/// Overlapping windows of at most `size` items covering `len` items
+fn chunk_ranges(len: usize, size: usize, overlap: usize) -> Vec<Range<usize>> {
+    // Keep at least half of each window new, so chunking always advances
+    let step = size - overlap.min(size / 2);
+    let mut ranges = Vec::new();
+    let mut start = 0;
+    loop {
+        let end = (start + size).min(len);
+        ranges.push(start..end);
+        if end == len {
+            break;
+        }
+        start += step;
+    }
+    ranges
+}
+
+fn add_weighted(sum: &mut [f32], vector: &[f32], weight: f32) {
+    for (total, value) in sum.iter_mut().zip(vector) {
+        *total += value * weight;
+    }
+}
+
+fn normalize(vector: &mut [f32]) {
+    let norm: f32 = vector.iter().map(|x| x * x).sum::<f32>().sqrt();
+    if norm > 0.0 {
+        for value in vector.iter_mut() {
+            *value /= norm;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_chunk_ranges() {
+        // Short texts are a single chunk, even when empty
+        assert_eq!(chunk_ranges(0, 4, 1).first(), Some(&(0..0)));
+        assert_eq!(chunk_ranges(4, 4, 1).len(), 1);
+        assert_eq!(chunk_ranges(10, 4, 1), vec![0..4, 3..7, 6..10]);
+        assert_eq!(chunk_ranges(9, 4, 0), vec![0..4, 4..8, 8..9]);
+
+        // An overlap as large as the window would never advance
+        assert_eq!(chunk_ranges(6, 4, 4), vec![0..4, 2..6]);
+    }
+
+    #[test]
+    fn test_group_chunks() {
+        // Short chunks are packed up to the token budget
+        assert_eq!(group_chunks(&[3, 3, 3, 3], 8, 6), vec![0..2, 2..4]);
+        // The sequence limit applies even when tokens remain
+        assert_eq!(group_chunks(&[1, 1, 1], 2, 100), vec![0..2, 2..3]);
+        // A long chunk is never merged with its neighbours
+        assert_eq!(group_chunks(&[2, 8, 2], 8, 8), vec![0..1, 1..2, 2..3]);
+        assert!(group_chunks(&[], 4, 4).is_empty());
+    }
+
+    #[test]
+    fn test_weighted_average_of_chunks() {
+        let mut sum = vec![0.0; 2];
+        add_weighted(&mut sum, &[1.0, 0.0], 3.0);
+        add_weighted(&mut sum, &[0.0, 1.0], 1.0);
+        normalize(&mut sum);
+
+        // The longer chunk dominates, and the result is a unit vector
+        assert!(sum[0] > sum[1]);
+        let norm: f32 = sum.iter().map(|x| x * x).sum::<f32>().sqrt();
+        assert!((norm - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_resolve_pooling() {
+        let plain = GgufMetadata::default();
+        let bert = GgufMetadata {
+            pooling_type: Some(2),
+            ..GgufMetadata::default()
+        };
+
+        assert_eq!(
+            resolve_pooling(PoolingMode::Native, &bert),
+            LlamaPoolingType::Unspecified
+        );
+        assert_eq!(
+            resolve_pooling(PoolingMode::Native, &plain),
+            LlamaPoolingType::Mean
+        );
+        assert_eq!(
+            resolve_pooling(PoolingMode::Last, &bert),
+            LlamaPoolingType::Last
+        );
+
+        // Declared NONE pooling falls back to mean
+        let none = GgufMetadata {
+            pooling_type: Some(0),
+            ..GgufMetadata::default()
+        };
+        assert_eq!(
+            resolve_pooling(PoolingMode::Native, &none),
+            LlamaPoolingType::Mean
+        );
+    }
+
+    #[test]
+    fn test_embedding_requires_model() {
+        unload_embedding_model();
+        assert!(!is_embedding_model_loaded());
+        assert!(embed_texts(&["note".to_string()], EmbeddingKind::Document).is_err());
+        assert!(load_embedding_model("/nonexistent.gguf".to_string(), None).is_err());
+    }
+}
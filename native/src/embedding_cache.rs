@@ -0,0 +1,490 @@
+//! Embedding Cache
+//!
+//! Stores computed embeddings on disk so unchanged notes are not embedded
+//! again on every start:
+//! - Entries are keyed by a hash of the text and of the embedding model's
+//!   identity (model file, pooling and prefix)
+//! - Loading a different model invalidates all entries of the previous one
+//! - New entries are appended to the file; compaction rewrites it without
+//!   invalidated and superseded records
+//!
+//! File layout: an 8-byte magic header followed by records of
+//! `text_hash: u64, text_len: u32, model_hash: u64, dimension: u32` and
+//! `dimension` f32 values, all little-endian.
+
+use anyhow::{anyhow, bail, Result};
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs::{self, File, OpenOptions};
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+use crate::backend::{self, fnv1a, InferenceBackend};
+use crate::embedder::EmbeddingKind;
+
+const MAGIC: &[u8; 8] = b"KVXEMB01";
+const RECORD_HEADER_LEN: usize = 24;
+
+/// Size and health of a cache
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EmbeddingCacheStats {
+    /// Embeddings served for the current model
+    pub entries: usize,
+    /// Records in the file that compaction would remove
+    pub stale_records: usize,
+    /// Size of the cache file in bytes
+    pub file_bytes: u64,
+}
+
+/// Text key: hash and byte length
+type TextKey = (u64, u32);
+
+/// Cached vectors found for a batch of texts, and the ones still to embed
+struct Lookup {
+    keys: Vec<TextKey>,
+    vectors: Vec<Option<Vec<f32>>>,
+    /// Indices of the texts to embed, without repeats
+    missing: Vec<usize>,
+}
+
+impl Lookup {
+    /// Embed the texts that were not cached, in input order
+    fn embed_missing(
+        &self,
+        backend: &dyn InferenceBackend,
+        texts: &[String],
+        kind: EmbeddingKind,
+    ) -> Result<Vec<(TextKey, Vec<f32>)>> {
+        if self.missing.is_empty() {
+            return Ok(Vec::new());
+        }
+        let inputs: Vec<String> = self.missing.iter().map(|&i| texts[i].clone()).collect();
+        let vectors = backend.embed(&inputs, kind)?;
+        if vectors.len() != inputs.len() {
+            bail!(
+                "Backend returned {} embeddings for {} texts",
+                vectors.len(),
+                inputs.len()
+            );
+        }
+        Ok(self
+            .missing
+            .iter()
+            .map(|&i| self.keys[i])
+            .zip(vectors)
+            .collect())
+    }
+
+    /// One vector per text, from the cache or from `computed`
+    fn assemble(self, computed: &[(TextKey, Vec<f32>)]) -> Result<Vec<Vec<f32>>> {
+        let computed: HashMap<TextKey, &Vec<f32>> = computed
+            .iter()
+            .map(|(key, vector)| (*key, vector))
+            .collect();
+        self.vectors
+            .into_iter()
+            .zip(&self.keys)
+            .map(|(vector, key)| {
+                vector
+                    .or_else(|| computed.get(key).map(|vector| vector.to_vec()))
+                    .ok_or_else(|| anyhow!("Missing embedding"))
+            })
+            .collect()
+    }
+}
+
+/// Embeddings persisted in an append-only file
+pub struct EmbeddingCache {
+    path: PathBuf,
+    file: File,
+    /// Entries per model hash; only the current model's are kept once known
+    models: HashMap<u64, HashMap<TextKey, Vec<f32>>>,
+    current_model: Option<u64>,
+    records: usize,
+}
+
+impl EmbeddingCache {
+    /// Open a cache file, creating it if needed
+    ///
+    /// A record cut off by a crash is dropped.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let bytes = match fs::read(&path) {
+            Ok(bytes) => bytes,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Vec::new(),
+            Err(e) => return Err(anyhow!("Failed to read embedding cache: {}", e)),
+        };
+        if !bytes.is_empty() && !bytes.starts_with(MAGIC) {
+            bail!("{} is not an embedding cache", path.display());
+        }
+
+        let mut models: HashMap<u64, HashMap<TextKey, Vec<f32>>> = HashMap::new();
+        let mut records = 0;
+        let mut pos = MAGIC.len();
+        while let Some((text_key, model_hash, vector, end)) = read_record(&bytes, pos) {
+            models
+                .entry(model_hash)
+                .or_default()
+                .insert(text_key, vector);
+            records += 1;
+            pos = end;
+        }
+
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .map_err(|e| anyhow!("Failed to open embedding cache: {}", e))?;
+        if bytes.is_empty() {
+            (&file).write_all(MAGIC)?;
+        } else if pos < bytes.len() {
+            file.set_len(pos as u64)?;
+        }
+
+        Ok(Self {
+            path,
+            file,
+            models,
+            current_model: None,
+            records,
+        })
+    }
+
+    /// Embeddings for `texts`, computing and storing only the missing ones
+    ///
+    /// # Arguments
+    /// * `backend` - Backend that embeds cache misses
+    /// * `texts` - Texts to embed
+    /// * `kind` - Whether the texts are queries or documents
+    ///
+    /// # Returns
+    /// * One vector per text, in input order
+    pub fn get_or_compute(
+        &mut self,
+        backend: &dyn InferenceBackend,
+        texts: &[String],
+        kind: EmbeddingKind,
+    ) -> Result<Vec<Vec<f32>>> {
+        let model_hash = fnv1a(backend.embedding_model_id(kind)?.as_bytes());
+        let lookup = self.lookup(model_hash, texts);
+        let computed = lookup.embed_missing(backend, texts, kind)?;
+        self.store(model_hash, &computed)?;
+        lookup.assemble(&computed)
+    }
+
+    /// Find the cached vectors of `texts` under `model_hash`
+    ///
+    /// The returned lookup lists the texts to embed; their vectors go to
+    /// `store`. The cache need not stay locked in between.
+    fn lookup(&mut self, model_hash: u64, texts: &[String]) -> Lookup {
+        self.use_model(model_hash);
+        let entries = self.models.entry(model_hash).or_default();
+
+        let keys: Vec<TextKey> = texts.iter().map(|text| text_key(text)).collect();
+        let mut missing: Vec<usize> = Vec::new();
+        let vectors = keys
+            .iter()
+            .enumerate()
+            .map(|(i, key)| {
+                let vector = entries.get(key).cloned();
+                // Repeated texts are embedded once
+                if vector.is_none() && !missing.iter().any(|&m| keys[m] == *key) {
+                    missing.push(i);
+                }
+                vector
+            })
+            .collect();
+        Lookup {
+            keys,
+            vectors,
+            missing,
+        }
+    }
+
+    /// Store vectors computed for `model_hash`
+    ///
+    /// Nothing is stored if another model was loaded while they were computed.
+    fn store(&mut self, model_hash: u64, computed: &[(TextKey, Vec<f32>)]) -> Result<()> {
+        if computed.is_empty() || self.current_model != Some(model_hash) {
+            return Ok(());
+        }
+        let entries = self.models.entry(model_hash).or_default();
+        let mut writer = BufWriter::new(&self.file);
+        for &(key, ref vector) in computed {
+            write_record(&mut writer, key, model_hash, vector)?;
+            entries.insert(key, vector.clone());
+        }
+        writer.flush()?;
+        self.records += computed.len();
+        Ok(())
+    }
+
+    /// Rewrite the file without invalidated and superseded records
+    pub fn compact(&mut self) -> Result<EmbeddingCacheStats> {
+        let tmp_path = self.path.with_extension("tmp");
+        {
+            let mut writer = BufWriter::new(File::create(&tmp_path)?);
+            writer.write_all(MAGIC)?;
+            for (&model_hash, entries) in &self.models {
+                for (&key, vector) in entries {
+                    write_record(&mut writer, key, model_hash, vector)?;
+                }
+            }
+            writer
+                .into_inner()
+                .map_err(|e| e.into_error())?
+                .sync_all()?;
+        }
+        fs::rename(&tmp_path, &self.path)?;
+
+        self.file = OpenOptions::new().append(true).open(&self.path)?;
+        self.records = self.models.values().map(HashMap::len).sum();
+        self.stats()
+    }
+
+    /// Drop every entry
+    pub fn clear(&mut self) -> Result<()> {
+        self.models.clear();
+        self.compact().map(|_| ())
+    }
+
+    /// Entry count and file size
+    pub fn stats(&self) -> Result<EmbeddingCacheStats> {
+        let live: usize = self.models.values().map(HashMap::len).sum();
+        Ok(EmbeddingCacheStats {
+            entries: self
+                .current_model
+                .and_then(|model| self.models.get(&model))
+                .map_or(live, HashMap::len),
+            stale_records: self.records - live,
+            file_bytes: self.file.metadata()?.len(),
+        })
+    }
+
+    /// Make `model_hash` current, invalidating entries of other models
+    fn use_model(&mut self, model_hash: u64) {
+        if self.current_model != Some(model_hash) {
+            self.models.retain(|&model, _| model == model_hash);
+            self.current_model = Some(model_hash);
+        }
+    }
+}
+
+fn text_key(text: &str) -> TextKey {
+    (fnv1a(text.as_bytes()), text.len() as u32)
+}
+
+fn write_record(
+    writer: &mut impl Write,
+    (text_hash, text_len): TextKey,
+    model_hash: u64,
+    vector: &[f32],
+) -> Result<()> {
+    writer.write_all(&text_hash.to_le_bytes())?;
+    writer.write_all(&text_len.to_le_bytes())?;
+    writer.write_all(&model_hash.to_le_bytes())?;
+    writer.write_all(&(vector.len() as u32).to_le_bytes())?;
+    for value in vector {
+        writer.write_all(&value.to_le_bytes())?;
+    }
+    Ok(())
+}
+
+/// The record at `pos` and the offset after it, if it is complete
+fn read_record(bytes: &[u8], pos: usize) -> Option<(TextKey, u64, Vec<f32>, usize)> {
+    let header = bytes.get(pos..pos.checked_add(RECORD_HEADER_LEN)?)?;
+    let text_hash = u64::from_le_bytes(header[0..8].try_into().ok()?);
+    let text_len = u32::from_le_bytes(header[8..12].try_into().ok()?);
+    let model_hash = u64::from_le_bytes(header[12..20].try_into().ok()?);
+    let dimension = u32::from_le_bytes(header[20..24].try_into().ok()?) as usize;
+
+    // A corrupt dimension must not overflow the offsets
+    let start = pos + RECORD_HEADER_LEN;
+    let end = start.checked_add(dimension.checked_mul(4)?)?;
+    let vector = bytes
+        .get(start..end)?
+        .chunks_exact(4)
+        .map(|b| f32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+        .collect();
+    Some(((text_hash, text_len), model_hash, vector, end))
+}
+
+/// Cache used by `embed_documents`
+static CACHE: Mutex<Option<EmbeddingCache>> = Mutex::new(None);
+
+/// Open the process-wide embedding cache, replacing any open one
+pub fn open_embedding_cache(path: &str) -> Result<()> {
+    let cache = EmbeddingCache::open(path)?;
+    *CACHE.lock() = Some(cache);
+    Ok(())
+}
+
+/// Close the process-wide embedding cache
+pub fn close_embedding_cache() {
+    *CACHE.lock() = None;
+}
+
+/// Compact the process-wide embedding cache
+pub fn compact_embedding_cache() -> Result<EmbeddingCacheStats> {
+    match CACHE.lock().as_mut() {
+        Some(cache) => cache.compact(),
+        None => bail!("No embedding cache is open"),
+    }
+}
+
+/// Statistics of the process-wide embedding cache (`None` if none is open)
+pub fn embedding_cache_stats() -> Result<Option<EmbeddingCacheStats>> {
+    CACHE.lock().as_ref().map(EmbeddingCache::stats).transpose()
+}
+
+/// Embed documents with the active backend, through the cache when one is open
+///
+/// # Arguments
+/// * `texts` - Texts to embed
+///
+/// # Returns
+/// * One vector per text, in input order
+pub fn embed_documents(texts: &[String]) -> Result<Vec<Vec<f32>>> {
+    let backend = backend::active_backend();
+    let kind = EmbeddingKind::Document;
+    if CACHE.lock().is_none() {
+        return backend.embed(texts, kind);
+    }
+
+    // The cache is not locked while embedding, so other callers are not
+    // blocked behind a long batch
+    let model_hash = fnv1a(backend.embedding_model_id(kind)?.as_bytes());
+    let Some(lookup) = CACHE
+        .lock()
+        .as_mut()
+        .map(|cache| cache.lookup(model_hash, texts))
+    else {
+        return backend.embed(texts, kind);
+    };
+    let computed = lookup.embed_missing(backend.as_ref(), texts, kind)?;
+    // Closed meanwhile: nothing to store
+    if let Some(cache) = CACHE.lock().as_mut() {
+        cache.store(model_hash, &computed)?;
+    }
+    lookup.assemble(&computed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::MockBackend;
+
+    fn texts(items: &[&str]) -> Vec<String> {
+        items.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_only_missing_texts_are_embedded() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("embeddings.bin");
+        let backend = MockBackend::new(8);
+
+        let mut cache = EmbeddingCache::open(&path).unwrap();
+        let first = cache
+            .get_or_compute(
+                &backend,
+                &texts(&["alpha", "beta", "alpha"]),
+                EmbeddingKind::Document,
+            )
+            .unwrap();
+        assert_eq!(backend.embedded_texts(), texts(&["alpha", "beta"]));
+        assert_eq!(first[0], first[2]);
+        drop(cache);
+
+        // Reopened from disk, only the new note is embedded
+        let mut cache = EmbeddingCache::open(&path).unwrap();
+        let second = cache
+            .get_or_compute(
+                &backend,
+                &texts(&["beta", "gamma"]),
+                EmbeddingKind::Document,
+            )
+            .unwrap();
+        assert_eq!(backend.embedded_texts(), texts(&["alpha", "beta", "gamma"]));
+        assert_eq!(second[0], first[1]);
+        assert_eq!(cache.stats().unwrap().entries, 3);
+    }
+
+    #[test]
+    fn test_model_change_invalidates_and_compacts() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("embeddings.bin");
+        let old_model = MockBackend::new(8);
+        let new_model = MockBackend::new(16);
+
+        let mut cache = EmbeddingCache::open(&path).unwrap();
+        cache
+            .get_or_compute(
+                &old_model,
+                &texts(&["alpha", "beta"]),
+                EmbeddingKind::Document,
+            )
+            .unwrap();
+        let vectors = cache
+            .get_or_compute(&new_model, &texts(&["alpha"]), EmbeddingKind::Document)
+            .unwrap();
+        assert_eq!(vectors[0].len(), 16);
+        assert_eq!(new_model.embedded_texts(), texts(&["alpha"]));
+
+        let stats = cache.stats().unwrap();
+        assert_eq!((stats.entries, stats.stale_records), (1, 2));
+        let before = stats.file_bytes;
+        let stats = cache.compact().unwrap();
+        assert_eq!(stats.stale_records, 0);
+        assert!(stats.file_bytes < before);
+
+        let cache = EmbeddingCache::open(&path).unwrap();
+        assert_eq!(cache.records, 1);
+    }
+
+    #[test]
+    fn test_truncated_record_is_dropped() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("embeddings.bin");
+        let backend = MockBackend::new(8);
+
+        let mut cache = EmbeddingCache::open(&path).unwrap();
+        cache
+            .get_or_compute(
+                &backend,
+                &texts(&["alpha", "beta"]),
+                EmbeddingKind::Document,
+            )
+            .unwrap();
+        drop(cache);
+
+        let len = fs::metadata(&path).unwrap().len();
+        OpenOptions::new()
+            .write(true)
+            .open(&path)
+            .unwrap()
+            .set_len(len - 5)
+            .unwrap();
+
+        let mut cache = EmbeddingCache::open(&path).unwrap();
+        assert_eq!(cache.records, 1);
+        cache
+            .get_or_compute(&backend, &texts(&["beta"]), EmbeddingKind::Document)
+            .unwrap();
+        drop(cache);
+        assert_eq!(EmbeddingCache::open(&path).unwrap().records, 2);
+
+        // A record claiming a huge dimension is dropped like a cut-off one
+        let mut file = OpenOptions::new().append(true).open(&path).unwrap();
+        write_record(&mut file, (1, 1), 2, &[]).unwrap();
+        let len = fs::metadata(&path).unwrap().len();
+        let mut corrupt = fs::read(&path).unwrap();
+        corrupt[len as usize - 4..].copy_from_slice(&u32::MAX.to_le_bytes());
+        fs::write(&path, &corrupt).unwrap();
+        assert_eq!(EmbeddingCache::open(&path).unwrap().records, 2);
+
+        fs::write(&path, b"not a cache").unwrap();
+        assert!(EmbeddingCache::open(&path).is_err());
+    }
+}
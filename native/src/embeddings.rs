@@ -3,12 +3,17 @@
 //! Provides utilities for working with embeddings:
 //! - Cosine similarity calculation
 //! - Batch embedding operations
-//! - Semantic search across vectors
+//! - Semantic search across vectors (linear scan, HNSW index or vector store)
 
 use anyhow::Result;
+use parking_lot::Mutex;
 use serde::{Deserialize, Serialize};
 
-use crate::inference;
+use crate::backend;
+use crate::embedder::{self, EmbeddingKind};
+use crate::embedding_cache;
+use crate::hnsw::HnswIndex;
+use crate::vector_store::VectorStore;
 
 /// A stored embedding with metadata
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -19,6 +24,28 @@ pub struct EmbeddingEntry {
     pub vector: Vec<f32>,
     /// Optional text preview
     pub text_preview: Option<String>,
+    /// Folder, tags, timestamps and type of the source note
+    #[serde(default)]
+    pub metadata: NoteMetadata,
+}
+
+/// Typed metadata of the note an embedding came from
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct NoteMetadata {
+    /// Folder path relative to the notes root (e.g., "courses/math")
+    pub folder: Option<String>,
+    /// Tags without the leading `#`
+    pub tags: Vec<String>,
+    /// Creation time in milliseconds since the Unix epoch
+    pub created_at: Option<i64>,
+    /// Last modification time in milliseconds since the Unix epoch
+    pub modified_at: Option<i64>,
+    /// Kind of note (e.g., "markdown", "canvas", "transcript")
+    pub note_type: Option<String>,
+    /// Start byte offset of a chunk entry within its note
+    pub chunk_start: Option<usize>,
+    /// End byte offset (exclusive) of a chunk entry within its note
+    pub chunk_end: Option<usize>,
 }
 
 /// Result of a similarity search
@@ -56,10 +83,36 @@ pub fn cosine_similarity(a: &[f32], b: &[f32]) -> f32 {
 
 /// Compute embeddings for multiple texts
 pub fn batch_embed(texts: Vec<String>) -> Result<Vec<EmbeddingEntry>> {
+    // Cached texts are skipped; the rest go through the model in shared batches
+    let vectors = embedding_cache::embed_documents(&texts)?;
+    Ok(batch_entries(&texts, vectors))
+}
+
+/// Compute embeddings for multiple texts with a specific loaded chat model
+///
+/// # Arguments
+/// * `texts` - Texts to embed
+/// * `model_id` - Loaded model to embed with (None uses the active backend)
+///
+/// # Returns
+/// * One entry per text, in input order
+pub fn batch_embed_with_model(
+    texts: Vec<String>,
+    model_id: Option<&str>,
+) -> Result<Vec<EmbeddingEntry>> {
+    let vectors = match model_id {
+        // The cache is keyed by the active backend, so other models bypass it
+        Some(id) => embedder::embed_with_chat_model(&texts, Some(id))?,
+        None => embedding_cache::embed_documents(&texts)?,
+    };
+    Ok(batch_entries(&texts, vectors))
+}
+
+/// Build the entries returned by the batch embedding functions
+fn batch_entries(texts: &[String], vectors: Vec<Vec<f32>>) -> Vec<EmbeddingEntry> {
     let mut results = Vec::with_capacity(texts.len());
 
-    for (i, text) in texts.iter().enumerate() {
-        let vector = inference::get_embedding(text.clone())?;
+    for (i, (text, vector)) in texts.iter().zip(vectors).enumerate() {
         let preview = if text.len() > 100 {
             Some(format!("{}...", &text[..100]))
         } else {
@@ -70,10 +123,11 @@ pub fn batch_embed(texts: Vec<String>) -> Result<Vec<EmbeddingEntry>> {
             id: format!("batch_{}", i),
             vector,
             text_preview: preview,
+            metadata: NoteMetadata::default(),
         });
     }
 
-    Ok(results)
+    results
 }
 
 /// Find most similar entries to a query embedding
@@ -133,12 +187,59 @@ pub fn semantic_search(
     top_k: usize,
 ) -> Result<Vec<SimilarityResult>> {
     // Embed the query
-    let query_embedding = inference::get_embedding(query_text)?;
+    let query_embedding = embed_query(&query_text)?;
 
     // Find similar
     Ok(find_similar(&query_embedding, entries, top_k, 0.5))
 }
 
+/// Embed a search query
+pub fn embed_query(query_text: &str) -> Result<Vec<f32>> {
+    Ok(backend::active_backend()
+        .embed(&[query_text.to_string()], EmbeddingKind::Query)?
+        .remove(0))
+}
+
+/// Semantic search through an HNSW index instead of a linear scan
+///
+/// The query is embedded before the index is locked.
+///
+/// # Arguments
+/// * `query_text` - The search query text
+/// * `index` - Index over the entries to search
+/// * `top_k` - Number of results to return
+///
+/// # Returns
+/// * List of similarity results
+pub fn semantic_search_indexed(
+    query_text: String,
+    index: &Mutex<HnswIndex>,
+    top_k: usize,
+) -> Result<Vec<SimilarityResult>> {
+    let query_embedding = embed_query(&query_text)?;
+    Ok(index.lock().search(&query_embedding, top_k, 0.5))
+}
+
+/// Semantic search over a persistent vector store
+///
+/// The query is embedded before the store is locked.
+///
+/// # Arguments
+/// * `query_text` - The search query text
+/// * `store` - Store holding the entries to search
+/// * `top_k` - Number of results to return
+///
+/// # Returns
+/// * List of similarity results
+pub fn semantic_search_stored(
+    query_text: String,
+    store: &Mutex<VectorStore>,
+    top_k: usize,
+) -> Result<Vec<SimilarityResult>> {
+    let query_embedding = embed_query(&query_text)?;
+    Ok(store.lock().search(&query_embedding, top_k, 0.5))
+}
+
 /// Cluster embeddings by similarity
 ///
 /// Simple clustering: group embeddings that are above threshold similarity
@@ -208,16 +309,19 @@ mod tests {
                 id: "a".to_string(),
                 vector: vec![1.0, 0.0, 0.0],
                 text_preview: None,
+                metadata: NoteMetadata::default(),
             },
             EmbeddingEntry {
                 id: "b".to_string(),
                 vector: vec![0.9, 0.1, 0.0],
                 text_preview: None,
+                metadata: NoteMetadata::default(),
             },
             EmbeddingEntry {
                 id: "c".to_string(),
                 vector: vec![0.0, 1.0, 0.0],
                 text_preview: None,
+                metadata: NoteMetadata::default(),
             },
         ];
 
@@ -236,16 +340,19 @@ mod tests {
                 id: "a".to_string(),
                 vector: vec![1.0, 0.0],
                 text_preview: None,
+                metadata: NoteMetadata::default(),
             },
             EmbeddingEntry {
                 id: "b".to_string(),
                 vector: vec![0.95, 0.05],
                 text_preview: None,
+                metadata: NoteMetadata::default(),
             },
             EmbeddingEntry {
                 id: "c".to_string(),
                 vector: vec![0.0, 1.0],
                 text_preview: None,
+                metadata: NoteMetadata::default(),
             },
         ];
 
@@ -0,0 +1,848 @@
+//! Metadata Filters and Diversified Search
+//!
+//! Narrows and diversifies similarity search:
+//! - Filter expressions over note metadata, such as
+//!   `tag in [exam, lecture] and folder under courses/math and modified >= 2024-09-01`
+//! - Maximal marginal relevance (MMR) re-ranking, which trades some relevance
+//!   for variety so many chunks of one note do not crowd out everything else
+//!
+//! Filter syntax:
+//! - `tag in [a, b]`, `tag = a`, `tag != a` (case-insensitive, `#` optional)
+//! - `folder under a/b` (the folder or any subfolder), `folder = a/b`, `folder != a/b`
+//! - `type in [a, b]`, `type = a`, `type != a`
+//! - `created` / `modified` with `=`, `<`, `<=`, `>`, `>=` and a UTC date
+//!   (`2024-09-01`), date-time (`2024-09-01T14:30[:00]`) or epoch milliseconds;
+//!   `= 2024-09-01` and `<= 2024-09-01` include the whole day
+//! - `and`, `or`, `not` and parentheses; values with spaces are quoted
+
+use anyhow::{anyhow, bail, Result};
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+
+use crate::embeddings::{self, cosine_similarity, EmbeddingEntry, NoteMetadata, SimilarityResult};
+use crate::hnsw::HnswIndex;
+use crate::vector_store::VectorStore;
+
+/// A parsed filter expression
+#[derive(Debug, Clone, PartialEq)]
+pub enum MetadataFilter {
+    /// Every filter matches
+    All(Vec<MetadataFilter>),
+    /// At least one filter matches
+    Any(Vec<MetadataFilter>),
+    /// The filter does not match
+    Not(Box<MetadataFilter>),
+    /// The note has at least one of the tags (lowercase, without `#`)
+    TagIn(Vec<String>),
+    /// The note is in the folder or one of its subfolders
+    FolderUnder(String),
+    /// The note is directly in the folder
+    FolderIs(String),
+    /// The note type is one of these (case-insensitive)
+    NoteTypeIn(Vec<String>),
+    /// Created within `[from, to)`, in epoch milliseconds
+    Created { from: Option<i64>, to: Option<i64> },
+    /// Modified within `[from, to)`, in epoch milliseconds
+    Modified { from: Option<i64>, to: Option<i64> },
+}
+
+impl MetadataFilter {
+    /// Parse a filter expression
+    pub fn parse(expression: &str) -> Result<Self> {
+        let mut parser = Parser {
+            tokens: lex(expression)?,
+            pos: 0,
+        };
+        let filter = parser.or()?;
+        if let Some(token) = parser.tokens.get(parser.pos) {
+            bail!("Unexpected {} in filter", token);
+        }
+        Ok(filter)
+    }
+
+    /// Whether a note with this metadata passes the filter
+    pub fn matches(&self, metadata: &NoteMetadata) -> bool {
+        let folder = metadata.folder.as_deref().map_or("", trim_folder);
+        match self {
+            Self::All(filters) => filters.iter().all(|f| f.matches(metadata)),
+            Self::Any(filters) => filters.iter().any(|f| f.matches(metadata)),
+            Self::Not(filter) => !filter.matches(metadata),
+            Self::TagIn(tags) => metadata
+                .tags
+                .iter()
+                .any(|tag| tags.contains(&normalize_tag(tag))),
+            Self::FolderUnder(prefix) => {
+                prefix.is_empty()
+                    || folder
+                        .strip_prefix(prefix.as_str())
+                        .is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+            }
+            Self::FolderIs(expected) => folder == expected,
+            Self::NoteTypeIn(types) => metadata
+                .note_type
+                .as_ref()
+                .is_some_and(|note_type| types.iter().any(|t| t.eq_ignore_ascii_case(note_type))),
+            Self::Created { from, to } => in_range(metadata.created_at, *from, *to),
+            Self::Modified { from, to } => in_range(metadata.modified_at, *from, *to),
+        }
+    }
+}
+
+/// Settings for a filtered similarity search
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SearchOptions {
+    /// Number of results to return (default: 10)
+    pub top_k: usize,
+    /// Minimum cosine similarity (default: 0.5)
+    pub threshold: f32,
+    /// Filter expression over note metadata (`None` = every entry)
+    pub filter: Option<String>,
+    /// MMR trade-off: 1.0 ranks by relevance only, 0.0 by novelty only
+    /// (`None` = no re-ranking)
+    pub mmr_lambda: Option<f32>,
+    /// Most relevant results considered by MMR (default: 50)
+    pub mmr_candidates: usize,
+}
+
+impl Default for SearchOptions {
+    fn default() -> Self {
+        Self {
+            top_k: 10,
+            threshold: 0.5,
+            filter: None,
+            mmr_lambda: None,
+            mmr_candidates: 50,
+        }
+    }
+}
+
+/// Find the entries most similar to a query embedding among those that
+/// pass the filter, optionally re-ranked for diversity
+///
+/// # Arguments
+/// * `query` - The query embedding vector
+/// * `entries` - List of entries to search
+/// * `options` - Result count, threshold, filter and MMR settings
+///
+/// # Returns
+/// * Similarity results, by score or in MMR order
+pub fn find_similar_filtered(
+    query: &[f32],
+    entries: &[EmbeddingEntry],
+    options: &SearchOptions,
+) -> Result<Vec<SimilarityResult>> {
+    let filter = parse_filter(options)?;
+    let scored = entries
+        .iter()
+        .filter(|entry| passes(&filter, &entry.metadata))
+        .map(|entry| (cosine_similarity(query, &entry.vector), entry))
+        .filter(|(score, _)| *score >= options.threshold)
+        .collect();
+    Ok(rank(scored, options))
+}
+
+/// Filtered search through an HNSW index
+///
+/// The index is searched with a growing candidate pool until enough
+/// candidates pass the filter.
+///
+/// # Arguments
+/// * `query` - The query embedding vector
+/// * `index` - Index over the entries to search
+/// * `options` - Result count, threshold, filter and MMR settings
+///
+/// # Returns
+/// * Similarity results, by score or in MMR order
+pub fn find_similar_filtered_indexed(
+    query: &[f32],
+    index: &HnswIndex,
+    options: &SearchOptions,
+) -> Result<Vec<SimilarityResult>> {
+    let filter = parse_filter(options)?;
+    let wanted = match options.mmr_lambda {
+        Some(_) => options.mmr_candidates.max(options.top_k),
+        None => options.top_k,
+    };
+
+    let mut fetch = if filter.is_some() { wanted * 4 } else { wanted };
+    let candidates = loop {
+        let results = index.search(query, fetch, options.threshold);
+        let exhausted = results.len() < fetch || fetch >= index.len();
+        let candidates: Vec<(f32, EmbeddingEntry)> = results
+            .into_iter()
+            .filter_map(|result| Some((result.score, index.entry(&result.id)?)))
+            .filter(|(_, entry)| passes(&filter, &entry.metadata))
+            .collect();
+        if candidates.len() >= wanted || exhausted {
+            break candidates;
+        }
+        fetch = (fetch * 2).min(index.len());
+    };
+
+    let scored = candidates
+        .iter()
+        .map(|(score, entry)| (*score, entry))
+        .collect();
+    Ok(rank(scored, options))
+}
+
+/// Filtered search over a persistent vector store
+///
+/// The filter runs on the store's metadata records before any vector is
+/// scored, and only the candidates kept for ranking are read out of the
+/// store.
+///
+/// # Arguments
+/// * `query` - The query embedding vector
+/// * `store` - Store holding the entries to search
+/// * `options` - Result count, threshold, filter and MMR settings
+///
+/// # Returns
+/// * Similarity results, by score or in MMR order
+pub fn find_similar_filtered_stored(
+    query: &[f32],
+    store: &VectorStore,
+    options: &SearchOptions,
+) -> Result<Vec<SimilarityResult>> {
+    let filter = parse_filter(options)?;
+    let wanted = match options.mmr_lambda {
+        Some(_) => options.mmr_candidates.max(options.top_k),
+        None => options.top_k,
+    };
+    let candidates: Vec<(f32, EmbeddingEntry)> = store
+        .search_where(query, wanted, options.threshold, |metadata| {
+            passes(&filter, metadata)
+        })
+        .into_iter()
+        .filter_map(|result| Some((result.score, store.get(&result.id)?)))
+        .collect();
+
+    let scored = candidates
+        .iter()
+        .map(|(score, entry)| (*score, entry))
+        .collect();
+    Ok(rank(scored, options))
+}
+
+/// Embed query text, then run a filtered search over entries
+///
+/// # Arguments
+/// * `query_text` - The search query text
+/// * `entries` - List of entries to search
+/// * `options` - Result count, threshold, filter and MMR settings
+///
+/// # Returns
+/// * Similarity results, by score or in MMR order
+pub fn semantic_search_filtered(
+    query_text: String,
+    entries: &[EmbeddingEntry],
+    options: &SearchOptions,
+) -> Result<Vec<SimilarityResult>> {
+    let query_embedding = embeddings::embed_query(&query_text)?;
+
+    find_similar_filtered(&query_embedding, entries, options)
+}
+
+/// Embed query text, then run a filtered search through an HNSW index
+///
+/// The index is locked only after the query is embedded.
+pub fn semantic_search_filtered_indexed(
+    query_text: String,
+    index: &Mutex<HnswIndex>,
+    options: &SearchOptions,
+) -> Result<Vec<SimilarityResult>> {
+    let query_embedding = embeddings::embed_query(&query_text)?;
+    find_similar_filtered_indexed(&query_embedding, &index.lock(), options)
+}
+
+/// Embed query text, then run a filtered search over a vector store
+///
+/// The store is locked only after the query is embedded.
+pub fn semantic_search_filtered_stored(
+    query_text: String,
+    store: &Mutex<VectorStore>,
+    options: &SearchOptions,
+) -> Result<Vec<SimilarityResult>> {
+    let query_embedding = embeddings::embed_query(&query_text)?;
+    find_similar_filtered_stored(&query_embedding, &store.lock(), options)
+}
+
+/// Order candidates with maximal marginal relevance
+///
+/// Each step picks the candidate maximizing
+/// `lambda * relevance - (1 - lambda) * max similarity to those already picked`.
+///
+/// # Arguments
+/// * `candidates` - Relevance scores with their entries
+/// * `top_k` - Number of candidates to pick
+/// * `lambda` - Relevance weight between 0.0 and 1.0
+///
+/// # Returns
+/// * Indices into `candidates`, in pick order
+pub fn mmr_select(candidates: &[(f32, &EmbeddingEntry)], top_k: usize, lambda: f32) -> Vec<usize> {
+    let lambda = lambda.clamp(0.0, 1.0);
+    let mut picked = vec![false; candidates.len()];
+    let mut redundancy = vec![0.0f32; candidates.len()];
+    let mut order = Vec::with_capacity(top_k.min(candidates.len()));
+
+    while order.len() < top_k.min(candidates.len()) {
+        let mut best: Option<(usize, f32)> = None;
+        for (i, (score, _)) in candidates.iter().enumerate() {
+            if picked[i] {
+                continue;
+            }
+            let value = lambda * score - (1.0 - lambda) * redundancy[i];
+            if best.is_none_or(|(_, best_value)| value > best_value) {
+                best = Some((i, value));
+            }
+        }
+        let Some((chosen, _)) = best else {
+            break;
+        };
+        picked[chosen] = true;
+        order.push(chosen);
+
+        let chosen_vector = &candidates[chosen].1.vector;
+        for (i, (_, entry)) in candidates.iter().enumerate() {
+            if !picked[i] {
+                let similarity = cosine_similarity(&entry.vector, chosen_vector);
+                if order.len() == 1 || similarity > redundancy[i] {
+                    redundancy[i] = similarity;
+                }
+            }
+        }
+    }
+    order
+}
+
+fn parse_filter(options: &SearchOptions) -> Result<Option<MetadataFilter>> {
+    options
+        .filter
+        .as_deref()
+        .filter(|expression| !expression.trim().is_empty())
+        .map(MetadataFilter::parse)
+        .transpose()
+}
+
+fn passes(filter: &Option<MetadataFilter>, metadata: &NoteMetadata) -> bool {
+    match filter {
+        Some(filter) => filter.matches(metadata),
+        None => true,
+    }
+}
+
+/// Sort by score, then keep the top results or re-rank them with MMR
+fn rank(mut scored: Vec<(f32, &EmbeddingEntry)>, options: &SearchOptions) -> Vec<SimilarityResult> {
+    scored.sort_by(|a, b| b.0.total_cmp(&a.0));
+    let result = |(score, entry): (f32, &EmbeddingEntry)| SimilarityResult {
+        id: entry.id.clone(),
+        score,
+        text_preview: entry.text_preview.clone(),
+    };
+
+    match options.mmr_lambda {
+        Some(lambda) => {
+            scored.truncate(options.mmr_candidates.max(options.top_k));
+            mmr_select(&scored, options.top_k, lambda)
+                .into_iter()
+                .map(|i| result(scored[i]))
+                .collect()
+        }
+        None => scored.into_iter().take(options.top_k).map(result).collect(),
+    }
+}
+
+fn in_range(value: Option<i64>, from: Option<i64>, to: Option<i64>) -> bool {
+    value.is_some_and(|value| {
+        from.is_none_or(|from| value >= from) && to.is_none_or(|to| value < to)
+    })
+}
+
+fn normalize_tag(tag: &str) -> String {
+    tag.trim().trim_start_matches('#').to_lowercase()
+}
+
+fn trim_folder(folder: &str) -> &str {
+    folder.trim().trim_matches('/')
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    /// Bare word: a keyword, field or unquoted value
+    Word(String),
+    /// Quoted value
+    Quoted(String),
+    Op(&'static str),
+    Symbol(char),
+}
+
+impl std::fmt::Display for Token {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Token::Word(word) => write!(f, "'{}'", word),
+            Token::Quoted(value) => write!(f, "\"{}\"", value),
+            Token::Op(op) => write!(f, "'{}'", op),
+            Token::Symbol(symbol) => write!(f, "'{}'", symbol),
+        }
+    }
+}
+
+fn lex(expression: &str) -> Result<Vec<Token>> {
+    let mut tokens = Vec::new();
+    let mut chars = expression.chars().peekable();
+    while let Some(&c) = chars.peek() {
+        match c {
+            c if c.is_whitespace() => {
+                chars.next();
+            }
+            '(' | ')' | '[' | ']' | ',' => {
+                tokens.push(Token::Symbol(c));
+                chars.next();
+            }
+            '"' | '\'' => {
+                chars.next();
+                let mut value = String::new();
+                loop {
+                    match chars.next() {
+                        Some(end) if end == c => break,
+                        Some(ch) => value.push(ch),
+                        None => bail!("Unterminated string in filter"),
+                    }
+                }
+                tokens.push(Token::Quoted(value));
+            }
+            '=' | '!' | '<' | '>' => {
+                chars.next();
+                let equals = chars.next_if_eq(&'=').is_some();
+                tokens.push(Token::Op(match (c, equals) {
+                    ('=', _) => "=",
+                    ('!', true) => "!=",
+                    ('<', false) => "<",
+                    ('<', true) => "<=",
+                    ('>', false) => ">",
+                    ('>', true) => ">=",
+                    _ => bail!("Unexpected '!' in filter"),
+                }));
+            }
+            _ => {
+                let mut word = String::new();
+                while let Some(&ch) = chars.peek() {
+                    if ch.is_whitespace() || "()[],\"'=!<>".contains(ch) {
+                        break;
+                    }
+                    word.push(ch);
+                    chars.next();
+                }
+                tokens.push(Token::Word(word));
+            }
+        }
+    }
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser {
+    fn next(&mut self) -> Result<Token> {
+        let token = self
+            .tokens
+            .get(self.pos)
+            .cloned()
+            .ok_or_else(|| anyhow!("Unexpected end of filter"))?;
+        self.pos += 1;
+        Ok(token)
+    }
+
+    /// Consume `keyword` (case-insensitive) if it comes next
+    fn keyword(&mut self, keyword: &str) -> bool {
+        match self.tokens.get(self.pos) {
+            Some(Token::Word(word)) if word.eq_ignore_ascii_case(keyword) => {
+                self.pos += 1;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn symbol(&mut self, symbol: char) -> bool {
+        if self.tokens.get(self.pos) == Some(&Token::Symbol(symbol)) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn or(&mut self) -> Result<MetadataFilter> {
+        let mut filters = vec![self.and()?];
+        while self.keyword("or") {
+            filters.push(self.and()?);
+        }
+        Ok(combine(filters, MetadataFilter::Any))
+    }
+
+    fn and(&mut self) -> Result<MetadataFilter> {
+        let mut filters = vec![self.unary()?];
+        while self.keyword("and") {
+            filters.push(self.unary()?);
+        }
+        Ok(combine(filters, MetadataFilter::All))
+    }
+
+    fn unary(&mut self) -> Result<MetadataFilter> {
+        if self.keyword("not") {
+            return Ok(MetadataFilter::Not(Box::new(self.unary()?)));
+        }
+        if self.symbol('(') {
+            let filter = self.or()?;
+            if !self.symbol(')') {
+                bail!("Missing ')' in filter");
+            }
+            return Ok(filter);
+        }
+        self.condition()
+    }
+
+    fn condition(&mut self) -> Result<MetadataFilter> {
+        let field = match self.next()? {
+            Token::Word(word) => word.to_lowercase(),
+            token => bail!("Expected a field name, found {}", token),
+        };
+        match field.as_str() {
+            "tag" | "tags" => {
+                let filter = |values: Vec<String>| {
+                    MetadataFilter::TagIn(values.iter().map(|v| normalize_tag(v)).collect())
+                };
+                self.membership(filter)
+            }
+            "type" | "note_type" => self.membership(MetadataFilter::NoteTypeIn),
+            "folder" => {
+                if self.keyword("under") {
+                    return Ok(MetadataFilter::FolderUnder(
+                        trim_folder(&self.value()?).to_string(),
+                    ));
+                }
+                let (op, value) = self.value_after_op()?;
+                if op != "=" && op != "!=" {
+                    bail!("Expected 'under', '=' or '!=', found '{}'", op);
+                }
+                let filter = MetadataFilter::FolderIs(trim_folder(&value).to_string());
+                Ok(negate_if(op == "!=", filter))
+            }
+            "created" | "modified" => {
+                let (op, value) = self.value_after_op()?;
+                let (start, end) = parse_time(&value)?;
+                let (from, to) = match op {
+                    "=" | "!=" => (Some(start), Some(end)),
+                    "<" => (None, Some(start)),
+                    "<=" => (None, Some(end)),
+                    ">" => (Some(end), None),
+                    _ => (Some(start), None),
+                };
+                let filter = if field == "created" {
+                    MetadataFilter::Created { from, to }
+                } else {
+                    MetadataFilter::Modified { from, to }
+                };
+                Ok(negate_if(op == "!=", filter))
+            }
+            _ => bail!("Unknown filter field: {}", field),
+        }
+    }
+
+    /// `in [a, b]`, `= a` or `!= a`
+    fn membership(
+        &mut self,
+        filter: impl Fn(Vec<String>) -> MetadataFilter,
+    ) -> Result<MetadataFilter> {
+        if self.keyword("in") {
+            return Ok(filter(self.list()?));
+        }
+        let (op, value) = self.value_after_op()?;
+        if op != "=" && op != "!=" {
+            bail!("Expected 'in', '=' or '!=', found '{}'", op);
+        }
+        Ok(negate_if(op == "!=", filter(vec![value])))
+    }
+
+    fn value_after_op(&mut self) -> Result<(&'static str, String)> {
+        match self.next()? {
+            Token::Op(op) => Ok((op, self.value()?)),
+            token => bail!("Expected a comparison, found {}", token),
+        }
+    }
+
+    fn value(&mut self) -> Result<String> {
+        match self.next()? {
+            Token::Word(value) | Token::Quoted(value) => Ok(value),
+            token => bail!("Expected a value, found {}", token),
+        }
+    }
+
+    fn list(&mut self) -> Result<Vec<String>> {
+        if !self.symbol('[') {
+            bail!("Expected '[' after 'in'");
+        }
+        let mut values = Vec::new();
+        while !self.symbol(']') {
+            if !values.is_empty() && !self.symbol(',') {
+                bail!("Expected ',' or ']' in list");
+            }
+            values.push(self.value()?);
+        }
+        Ok(values)
+    }
+}
+
+fn combine(
+    mut filters: Vec<MetadataFilter>,
+    group: fn(Vec<MetadataFilter>) -> MetadataFilter,
+) -> MetadataFilter {
+    if filters.len() == 1 {
+        filters.remove(0)
+    } else {
+        group(filters)
+    }
+}
+
+fn negate_if(negate: bool, filter: MetadataFilter) -> MetadataFilter {
+    if negate {
+        MetadataFilter::Not(Box::new(filter))
+    } else {
+        filter
+    }
+}
+
+/// Start and exclusive end of a time value, in epoch milliseconds
+///
+/// Dates cover the whole day, date-times the whole minute or second and
+/// plain numbers a single millisecond.
+fn parse_time(value: &str) -> Result<(i64, i64)> {
+    if let Ok(millis) = value.parse::<i64>() {
+        return Ok((millis, millis.saturating_add(1)));
+    }
+    let invalid = || anyhow!("Invalid date in filter: {}", value);
+    let value = value.trim_end_matches('Z');
+    let (date, time) = match value.split_once('T') {
+        Some((date, time)) => (date, Some(time)),
+        None => (value, None),
+    };
+
+    let mut date_parts = date.splitn(3, '-');
+    let mut date_part = || -> Result<i64> {
+        date_parts
+            .next()
+            .and_then(|part| part.parse().ok())
+            .ok_or_else(invalid)
+    };
+    let (year, month, day) = (date_part()?, date_part()?, date_part()?);
+    if !(0..=9999).contains(&year)
+        || !(1..=12).contains(&month)
+        || !(1..=days_in_month(year, month)).contains(&day)
+    {
+        return Err(invalid());
+    }
+    let day_start = days_from_civil(year, month, day) * 86_400_000;
+
+    let Some(time) = time else {
+        return Ok((day_start, day_start + 86_400_000));
+    };
+    let parts = time
+        .split(':')
+        .map(|part| part.parse::<i64>().map_err(|_| invalid()))
+        .collect::<Result<Vec<i64>>>()?;
+    let (hours, minutes, seconds, length) = match parts[..] {
+        [hours, minutes] => (hours, minutes, 0, 60_000),
+        [hours, minutes, seconds] => (hours, minutes, seconds, 1000),
+        _ => return Err(invalid()),
+    };
+    if !(0..24).contains(&hours) || !(0..60).contains(&minutes) || !(0..60).contains(&seconds) {
+        return Err(invalid());
+    }
+    let start = hours * 3_600_000 + minutes * 60_000 + seconds * 1000;
+    Ok((day_start + start, day_start + start + length))
+}
+
+/// Number of days in a month of the proleptic Gregorian calendar
+fn days_in_month(year: i64, month: i64) -> i64 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Days since 1970-01-01 for a proleptic Gregorian date
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(id: &str, vector: Vec<f32>, metadata: NoteMetadata) -> EmbeddingEntry {
+        EmbeddingEntry {
+            id: id.to_string(),
+            vector,
+            text_preview: None,
+            metadata,
+        }
+    }
+
+    fn note(folder: &str, tags: &[&str], modified_at: i64) -> NoteMetadata {
+        NoteMetadata {
+            folder: Some(folder.to_string()),
+            tags: tags.iter().map(|t| t.to_string()).collect(),
+            created_at: None,
+            modified_at: Some(modified_at),
+            note_type: Some("markdown".to_string()),
+            ..NoteMetadata::default()
+        }
+    }
+
+    #[test]
+    fn test_parse_and_match() {
+        let sept = parse_time("2024-09-01").unwrap().0;
+        assert_eq!(sept, 1_725_148_800_000);
+        assert_eq!(
+            parse_time("2024-09-01T00:01").unwrap(),
+            (sept + 60_000, sept + 120_000)
+        );
+        assert!(parse_time("2024-13-01").is_err());
+        assert!(parse_time("2024-02-31").is_err());
+        assert!(parse_time("2023-02-29").is_err());
+        assert!(parse_time("2024-02-29").is_ok());
+        assert!(parse_time("2024-09-01T24:00").is_err());
+        assert!(parse_time("2024-09-01T12:60").is_err());
+        assert_eq!(parse_time(&i64::MAX.to_string()).unwrap().1, i64::MAX);
+
+        let filter = MetadataFilter::parse(
+            "tag in [#Exam, lecture] and folder under \"courses/math\" and modified >= 2024-09-01",
+        )
+        .unwrap();
+        assert!(filter.matches(&note("courses/math/linear", &["exam"], sept)));
+        assert!(!filter.matches(&note("courses/mathematics", &["exam"], sept)));
+        assert!(!filter.matches(&note("courses/math", &["homework"], sept)));
+        assert!(!filter.matches(&note("courses/math", &["Lecture"], sept - 1)));
+        assert!(filter.matches(&note("/courses/math/", &["Lecture"], sept)));
+
+        let filter =
+            MetadataFilter::parse("not (type = canvas or modified <= 2024-08-31)").unwrap();
+        assert!(filter.matches(&note("", &[], sept)));
+        assert!(!filter.matches(&note("", &[], sept - 1)));
+
+        assert!(MetadataFilter::parse("tag in [a, b").is_err());
+        assert!(MetadataFilter::parse("colour = red").is_err());
+        assert!(MetadataFilter::parse("tag = a b").is_err());
+    }
+
+    #[test]
+    fn test_filtered_search() {
+        let entries = vec![
+            entry("a", vec![1.0, 0.0], note("work", &["rust"], 0)),
+            entry("b", vec![0.9, 0.1], note("personal", &["rust"], 0)),
+            entry("c", vec![0.8, 0.2], note("work/archive", &["go"], 0)),
+        ];
+        let options = SearchOptions {
+            filter: Some("folder under work".to_string()),
+            ..SearchOptions::default()
+        };
+
+        let results = find_similar_filtered(&[1.0, 0.0], &entries, &options).unwrap();
+        let ids: Vec<&str> = results.iter().map(|r| r.id.as_str()).collect();
+        assert_eq!(ids, vec!["a", "c"]);
+
+        let bad = SearchOptions {
+            filter: Some("folder under".to_string()),
+            ..SearchOptions::default()
+        };
+        assert!(find_similar_filtered(&[1.0, 0.0], &entries, &bad).is_err());
+    }
+
+    #[test]
+    fn test_mmr_spreads_results_across_notes() {
+        // Three near-identical chunks of one note and one different note
+        let metadata = NoteMetadata::default();
+        let entries = vec![
+            entry("lecture#chunk0", vec![1.0, 0.0, 0.0], metadata.clone()),
+            entry("lecture#chunk1", vec![0.99, 0.01, 0.0], metadata.clone()),
+            entry("lecture#chunk2", vec![0.98, 0.02, 0.0], metadata.clone()),
+            entry("summary", vec![0.7, 0.0, 0.7], metadata),
+        ];
+        let query = [1.0, 0.0, 0.3];
+
+        let plain = SearchOptions {
+            top_k: 2,
+            threshold: 0.0,
+            ..SearchOptions::default()
+        };
+        let results = find_similar_filtered(&query, &entries, &plain).unwrap();
+        assert!(results.iter().all(|r| r.id.starts_with("lecture")));
+
+        let diverse = SearchOptions {
+            mmr_lambda: Some(0.5),
+            ..plain
+        };
+        let results = find_similar_filtered(&query, &entries, &diverse).unwrap();
+        let ids: Vec<&str> = results.iter().map(|r| r.id.as_str()).collect();
+        assert_eq!(ids, vec!["lecture#chunk0", "summary"]);
+    }
+
+    #[test]
+    fn test_filtered_search_through_index() {
+        let mut index = HnswIndex::new(Default::default());
+        for i in 0..200 {
+            let angle = i as f32 * 0.01;
+            let folder = if i % 50 == 0 { "rare" } else { "common" };
+            index
+                .insert(entry(
+                    &format!("n{}", i),
+                    vec![angle.cos(), angle.sin()],
+                    note(folder, &[], 0),
+                ))
+                .unwrap();
+        }
+        let options = SearchOptions {
+            top_k: 3,
+            threshold: -1.0,
+            filter: Some("folder = rare".to_string()),
+            ..SearchOptions::default()
+        };
+
+        let results = find_similar_filtered_indexed(&[1.0, 0.0], &index, &options).unwrap();
+        let ids: Vec<&str> = results.iter().map(|r| r.id.as_str()).collect();
+        assert_eq!(ids, vec!["n0", "n50", "n100"]);
+    }
+
+    #[test]
+    fn test_filtered_search_in_store() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut store = VectorStore::open(dir.path()).unwrap();
+        store
+            .upsert(&[
+                entry("a", vec![1.0, 0.0], note("work", &["rust"], 0)),
+                entry("b", vec![0.9, 0.1], note("personal", &["rust"], 0)),
+                entry("c", vec![0.8, 0.2], note("work/archive", &["go"], 0)),
+            ])
+            .unwrap();
+        let options = SearchOptions {
+            filter: Some("folder under work".to_string()),
+            mmr_lambda: Some(0.5),
+            ..SearchOptions::default()
+        };
+
+        let results = find_similar_filtered_stored(&[1.0, 0.0], &store, &options).unwrap();
+        let ids: Vec<&str> = results.iter().map(|r| r.id.as_str()).collect();
+        assert_eq!(ids, vec!["a", "c"]);
+    }
+}
@@ -38,7 +38,7 @@ flutter_rust_bridge::frb_generated_boilerplate!(
     default_rust_auto_opaque = RustAutoOpaqueNom,
 );
 pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_VERSION: &str = "2.12.0";
-pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = 1959240715;
+pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = -1814554323;
 
 // Section: executor
 
@@ -264,22 +264,33 @@ fn wire__crate__api__analyze_knowledge_graph_impl(
         },
     )
 }
-fn wire__crate__api__batch_embed_impl(
+fn wire__crate__api__analyze_knowledge_graph_in_store_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
-    texts: impl CstDecode<Vec<String>>,
+    store_id: impl CstDecode<u64>,
+    k: impl CstDecode<Option<usize>>,
+    similarity_threshold: impl CstDecode<Option<f32>>,
+    existing_links: impl CstDecode<Option<Vec<(String, String)>>>,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "batch_embed",
+            debug_name: "analyze_knowledge_graph_in_store",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
-            let api_texts = texts.cst_decode();
+            let api_store_id = store_id.cst_decode();
+            let api_k = k.cst_decode();
+            let api_similarity_threshold = similarity_threshold.cst_decode();
+            let api_existing_links = existing_links.cst_decode();
             move |context| {
                 transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || {
-                        let output_ok = crate::api::batch_embed(api_texts)?;
+                        let output_ok = crate::api::analyze_knowledge_graph_in_store(
+                            api_store_id,
+                            api_k,
+                            api_similarity_threshold,
+                            api_existing_links,
+                        )?;
                         Ok(output_ok)
                     })(),
                 )
@@ -287,24 +298,22 @@ fn wire__crate__api__batch_embed_impl(
         },
     )
 }
-fn wire__crate__api__chat_completion_impl(
+fn wire__crate__api__batch_embed_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
-    messages: impl CstDecode<Vec<(String, String)>>,
-    max_tokens: impl CstDecode<Option<u32>>,
+    texts: impl CstDecode<Vec<String>>,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "chat_completion",
+            debug_name: "batch_embed",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
-            let api_messages = messages.cst_decode();
-            let api_max_tokens = max_tokens.cst_decode();
+            let api_texts = texts.cst_decode();
             move |context| {
                 transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || {
-                        let output_ok = crate::api::chat_completion(api_messages, api_max_tokens)?;
+                        let output_ok = crate::api::batch_embed(api_texts)?;
                         Ok(output_ok)
                     })(),
                 )
@@ -312,89 +321,68 @@ fn wire__crate__api__chat_completion_impl(
         },
     )
 }
-fn wire__crate__api__clear_graph_impl() -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco
-{
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::DcoCodec, _>(
+fn wire__crate__api__batch_embed_with_model_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    texts: impl CstDecode<Vec<String>>,
+    model_id: impl CstDecode<Option<String>>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "clear_graph",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            debug_name: "batch_embed_with_model",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
-            transform_result_dco::<_, _, ()>((move || {
-                let output_ok = Result::<_, ()>::Ok({
-                    crate::api::clear_graph();
-                })?;
-                Ok(output_ok)
-            })())
+            let api_texts = texts.cst_decode();
+            let api_model_id = model_id.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::batch_embed_with_model(api_texts, api_model_id)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
         },
     )
 }
-fn wire__crate__api__clear_stream_graph_impl(
+fn wire__crate__api__cancel_generation_impl(
+    request_id: impl CstDecode<u64>,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::DcoCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "clear_stream_graph",
+            debug_name: "cancel_generation",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
         move || {
+            let api_request_id = request_id.cst_decode();
             transform_result_dco::<_, _, ()>((move || {
-                let output_ok = Result::<_, ()>::Ok({
-                    crate::api::clear_stream_graph();
-                })?;
+                let output_ok = Result::<_, ()>::Ok(crate::api::cancel_generation(api_request_id))?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__cluster_embeddings_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
-    entries: impl CstDecode<Vec<crate::embeddings::EmbeddingEntry>>,
-    threshold: impl CstDecode<f32>,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
-        flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "cluster_embeddings",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
-        },
-        move || {
-            let api_entries = entries.cst_decode();
-            let api_threshold = threshold.cst_decode();
-            move |context| {
-                transform_result_dco::<_, _, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(crate::api::cluster_embeddings(
-                        api_entries,
-                        api_threshold,
-                    ))?;
-                    Ok(output_ok)
-                })())
-            }
-        },
-    )
-}
-fn wire__crate__api__cluster_notes_impl(
+fn wire__crate__api__chat_completion_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
-    entries: impl CstDecode<Vec<crate::embeddings::EmbeddingEntry>>,
-    k: impl CstDecode<Option<usize>>,
-    max_iterations: impl CstDecode<Option<usize>>,
+    messages: impl CstDecode<Vec<(String, String)>>,
+    max_tokens: impl CstDecode<Option<u32>>,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "cluster_notes",
+            debug_name: "chat_completion",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
-            let api_entries = entries.cst_decode();
-            let api_k = k.cst_decode();
-            let api_max_iterations = max_iterations.cst_decode();
+            let api_messages = messages.cst_decode();
+            let api_max_tokens = max_tokens.cst_decode();
             move |context| {
                 transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || {
-                        let output_ok =
-                            crate::api::cluster_notes(api_entries, api_k, api_max_iterations)?;
+                        let output_ok = crate::api::chat_completion(api_messages, api_max_tokens)?;
                         Ok(output_ok)
                     })(),
                 )
@@ -402,22 +390,38 @@ fn wire__crate__api__cluster_notes_impl(
         },
     )
 }
-fn wire__crate__api__compute_graph_layout_impl(
+fn wire__crate__api__chat_completion_stream_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
-    iterations: impl CstDecode<Option<u32>>,
+    messages: impl CstDecode<Vec<(String, String)>>,
+    max_tokens: impl CstDecode<Option<u32>>,
+    stop_sequences: impl CstDecode<Option<Vec<String>>>,
+    model_id: impl CstDecode<Option<String>>,
+    sink: impl CstDecode<
+        StreamSink<crate::inference::GenerationChunk, flutter_rust_bridge::for_generated::DcoCodec>,
+    >,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "compute_graph_layout",
+            debug_name: "chat_completion_stream",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
-            let api_iterations = iterations.cst_decode();
+            let api_messages = messages.cst_decode();
+            let api_max_tokens = max_tokens.cst_decode();
+            let api_stop_sequences = stop_sequences.cst_decode();
+            let api_model_id = model_id.cst_decode();
+            let api_sink = sink.cst_decode();
             move |context| {
                 transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || {
-                        let output_ok = crate::api::compute_graph_layout(api_iterations)?;
+                        let output_ok = crate::api::chat_completion_stream(
+                            api_messages,
+                            api_max_tokens,
+                            api_stop_sequences,
+                            api_model_id,
+                            api_sink,
+                        )?;
                         Ok(output_ok)
                     })(),
                 )
@@ -425,25 +429,30 @@ fn wire__crate__api__compute_graph_layout_impl(
         },
     )
 }
-fn wire__crate__api__connect_note_to_topics_impl(
+fn wire__crate__api__chat_completion_with_model_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
-    note_id: impl CstDecode<String>,
-    topic_ids: impl CstDecode<Vec<String>>,
+    messages: impl CstDecode<Vec<(String, String)>>,
+    max_tokens: impl CstDecode<Option<u32>>,
+    model_id: impl CstDecode<Option<String>>,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "connect_note_to_topics",
+            debug_name: "chat_completion_with_model",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
-            let api_note_id = note_id.cst_decode();
-            let api_topic_ids = topic_ids.cst_decode();
+            let api_messages = messages.cst_decode();
+            let api_max_tokens = max_tokens.cst_decode();
+            let api_model_id = model_id.cst_decode();
             move |context| {
                 transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || {
-                        let output_ok =
-                            crate::api::connect_note_to_topics(api_note_id, api_topic_ids)?;
+                        let output_ok = crate::api::chat_completion_with_model(
+                            api_messages,
+                            api_max_tokens,
+                            api_model_id,
+                        )?;
                         Ok(output_ok)
                     })(),
                 )
@@ -451,73 +460,25 @@ fn wire__crate__api__connect_note_to_topics_impl(
         },
     )
 }
-fn wire__crate__api__cosine_similarity_impl(
-    a: impl CstDecode<Vec<f32>>,
-    b: impl CstDecode<Vec<f32>>,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::DcoCodec, _>(
-        flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "cosine_similarity",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
-        },
-        move || {
-            let api_a = a.cst_decode();
-            let api_b = b.cst_decode();
-            transform_result_dco::<_, _, ()>((move || {
-                let output_ok = Result::<_, ()>::Ok(crate::api::cosine_similarity(api_a, api_b))?;
-                Ok(output_ok)
-            })())
-        },
-    )
-}
-fn wire__crate__api__discover_semantic_edges_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
-    entries: impl CstDecode<Vec<crate::embeddings::EmbeddingEntry>>,
-    threshold: impl CstDecode<Option<f32>>,
-    existing_links: impl CstDecode<Option<Vec<(String, String)>>>,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
-        flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "discover_semantic_edges",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
-        },
-        move || {
-            let api_entries = entries.cst_decode();
-            let api_threshold = threshold.cst_decode();
-            let api_existing_links = existing_links.cst_decode();
-            move |context| {
-                transform_result_dco::<_, _, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(crate::api::discover_semantic_edges(
-                        api_entries,
-                        api_threshold,
-                        api_existing_links,
-                    ))?;
-                    Ok(output_ok)
-                })())
-            }
-        },
-    )
-}
-fn wire__crate__api__extract_topics_impl(
+fn wire__crate__api__chat_completion_with_options_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
-    text: impl CstDecode<String>,
-    num_topics: impl CstDecode<Option<u32>>,
+    messages: impl CstDecode<Vec<(String, String)>>,
+    options: impl CstDecode<crate::inference::GenerationOptions>,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "extract_topics",
+            debug_name: "chat_completion_with_options",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
-            let api_text = text.cst_decode();
-            let api_num_topics = num_topics.cst_decode();
+            let api_messages = messages.cst_decode();
+            let api_options = options.cst_decode();
             move |context| {
                 transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || {
-                        let output_ok = crate::api::extract_topics(api_text, api_num_topics)?;
+                        let output_ok =
+                            crate::api::chat_completion_with_options(api_messages, api_options)?;
                         Ok(output_ok)
                     })(),
                 )
@@ -525,56 +486,30 @@ fn wire__crate__api__extract_topics_impl(
         },
     )
 }
-fn wire__crate__api__find_similar_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
-    query: impl CstDecode<Vec<f32>>,
-    entries: impl CstDecode<Vec<crate::embeddings::EmbeddingEntry>>,
-    top_k: impl CstDecode<usize>,
-    threshold: impl CstDecode<f32>,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
-        flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "find_similar",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
-        },
-        move || {
-            let api_query = query.cst_decode();
-            let api_entries = entries.cst_decode();
-            let api_top_k = top_k.cst_decode();
-            let api_threshold = threshold.cst_decode();
-            move |context| {
-                transform_result_dco::<_, _, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(crate::api::find_similar(
-                        api_query,
-                        api_entries,
-                        api_top_k,
-                        api_threshold,
-                    ))?;
-                    Ok(output_ok)
-                })())
-            }
-        },
-    )
-}
-fn wire__crate__api__generate_text_impl(
+fn wire__crate__api__chat_session_completion_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
-    prompt: impl CstDecode<String>,
-    max_tokens: impl CstDecode<Option<u32>>,
+    session_id: impl CstDecode<u64>,
+    messages: impl CstDecode<Vec<(String, String)>>,
+    options: impl CstDecode<crate::inference::GenerationOptions>,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "generate_text",
+            debug_name: "chat_session_completion",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
-            let api_prompt = prompt.cst_decode();
-            let api_max_tokens = max_tokens.cst_decode();
+            let api_session_id = session_id.cst_decode();
+            let api_messages = messages.cst_decode();
+            let api_options = options.cst_decode();
             move |context| {
                 transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || {
-                        let output_ok = crate::api::generate_text(api_prompt, api_max_tokens)?;
+                        let output_ok = crate::api::chat_session_completion(
+                            api_session_id,
+                            api_messages,
+                            api_options,
+                        )?;
                         Ok(output_ok)
                     })(),
                 )
@@ -582,22 +517,38 @@ fn wire__crate__api__generate_text_impl(
         },
     )
 }
-fn wire__crate__api__get_embedding_impl(
+fn wire__crate__api__chat_session_stream_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
-    text: impl CstDecode<String>,
+    session_id: impl CstDecode<u64>,
+    messages: impl CstDecode<Vec<(String, String)>>,
+    max_tokens: impl CstDecode<Option<u32>>,
+    stop_sequences: impl CstDecode<Option<Vec<String>>>,
+    sink: impl CstDecode<
+        StreamSink<crate::inference::GenerationChunk, flutter_rust_bridge::for_generated::DcoCodec>,
+    >,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_embedding",
+            debug_name: "chat_session_stream",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
-            let api_text = text.cst_decode();
+            let api_session_id = session_id.cst_decode();
+            let api_messages = messages.cst_decode();
+            let api_max_tokens = max_tokens.cst_decode();
+            let api_stop_sequences = stop_sequences.cst_decode();
+            let api_sink = sink.cst_decode();
             move |context| {
                 transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || {
-                        let output_ok = crate::api::get_embedding(api_text)?;
+                        let output_ok = crate::api::chat_session_stream(
+                            api_session_id,
+                            api_messages,
+                            api_max_tokens,
+                            api_stop_sequences,
+                            api_sink,
+                        )?;
                         Ok(output_ok)
                     })(),
                 )
@@ -605,36 +556,53 @@ fn wire__crate__api__get_embedding_impl(
         },
     )
 }
-fn wire__crate__api__get_embedding_dimension_impl(
+fn wire__crate__api__chat_session_token_count_impl(
+    session_id: impl CstDecode<u64>,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::DcoCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_embedding_dimension",
+            debug_name: "chat_session_token_count",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
         move || {
+            let api_session_id = session_id.cst_decode();
             transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
                 (move || {
-                    let output_ok = crate::api::get_embedding_dimension()?;
+                    let output_ok = crate::api::chat_session_token_count(api_session_id)?;
                     Ok(output_ok)
                 })(),
             )
         },
     )
 }
-fn wire__crate__api__get_graph_state_impl(port_: flutter_rust_bridge::for_generated::MessagePort) {
+fn wire__crate__api__chunk_markdown_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    text: impl CstDecode<String>,
+    max_tokens: impl CstDecode<Option<u32>>,
+    overlap_tokens: impl CstDecode<Option<u32>>,
+    model_id: impl CstDecode<Option<String>>,
+) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_graph_state",
+            debug_name: "chunk_markdown",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
+            let api_text = text.cst_decode();
+            let api_max_tokens = max_tokens.cst_decode();
+            let api_overlap_tokens = overlap_tokens.cst_decode();
+            let api_model_id = model_id.cst_decode();
             move |context| {
                 transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || {
-                        let output_ok = crate::api::get_graph_state()?;
+                        let output_ok = crate::api::chunk_markdown(
+                            api_text,
+                            api_max_tokens,
+                            api_overlap_tokens,
+                            api_model_id,
+                        )?;
                         Ok(output_ok)
                     })(),
                 )
@@ -642,219 +610,181 @@ fn wire__crate__api__get_graph_state_impl(port_: flutter_rust_bridge::for_genera
         },
     )
 }
-fn wire__crate__api__get_model_type_impl(
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::DcoCodec, _>(
+fn wire__crate__api__clear_graph_impl() -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco
+{
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::DcoCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_model_type",
+            debug_name: "clear_graph",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
         move || {
             transform_result_dco::<_, _, ()>((move || {
-                let output_ok = Result::<_, ()>::Ok(crate::api::get_model_type())?;
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::clear_graph();
+                })?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__get_or_create_topic_hub_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
-    topic: impl CstDecode<String>,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+fn wire__crate__api__clear_stream_graph_impl(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::DcoCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_or_create_topic_hub",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            debug_name: "clear_stream_graph",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
         move || {
-            let api_topic = topic.cst_decode();
-            move |context| {
-                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let output_ok = crate::api::get_or_create_topic_hub(api_topic)?;
-                        Ok(output_ok)
-                    })(),
-                )
-            }
+            transform_result_dco::<_, _, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::clear_stream_graph();
+                })?;
+                Ok(output_ok)
+            })())
         },
     )
 }
-fn wire__crate__api__get_stream_graph_stats_impl(
+fn wire__crate__api__close_chat_session_impl(
+    session_id: impl CstDecode<u64>,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::DcoCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_stream_graph_stats",
+            debug_name: "close_chat_session",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
         move || {
+            let api_session_id = session_id.cst_decode();
             transform_result_dco::<_, _, ()>((move || {
-                let output_ok = Result::<_, ()>::Ok(crate::api::get_stream_graph_stats())?;
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::close_chat_session(api_session_id))?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__get_version_impl() -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco
-{
+fn wire__crate__api__close_embedding_cache_impl(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::DcoCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_version",
+            debug_name: "close_embedding_cache",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
         move || {
             transform_result_dco::<_, _, ()>((move || {
-                let output_ok = Result::<_, ()>::Ok(crate::api::get_version())?;
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::close_embedding_cache();
+                })?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__get_visible_graph_nodes_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+fn wire__crate__api__close_hnsw_index_impl(
+    index_id: impl CstDecode<u64>,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::DcoCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_visible_graph_nodes",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            debug_name: "close_hnsw_index",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
         move || {
-            move |context| {
-                transform_result_dco::<_, _, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(crate::api::get_visible_graph_nodes())?;
-                    Ok(output_ok)
-                })())
-            }
+            let api_index_id = index_id.cst_decode();
+            transform_result_dco::<_, _, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::close_hnsw_index(api_index_id))?;
+                Ok(output_ok)
+            })())
         },
     )
 }
-fn wire__crate__api__health_check_impl() -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco
-{
+fn wire__crate__api__close_quantized_index_impl(
+    index_id: impl CstDecode<u64>,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::DcoCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "health_check",
+            debug_name: "close_quantized_index",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
         move || {
+            let api_index_id = index_id.cst_decode();
             transform_result_dco::<_, _, ()>((move || {
-                let output_ok = Result::<_, ()>::Ok(crate::api::health_check())?;
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::close_quantized_index(api_index_id))?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__init_graph_impl() -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+fn wire__crate__api__close_vector_store_impl(
+    store_id: impl CstDecode<u64>,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::DcoCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "init_graph",
+            debug_name: "close_vector_store",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
         move || {
+            let api_store_id = store_id.cst_decode();
             transform_result_dco::<_, _, ()>((move || {
-                let output_ok = Result::<_, ()>::Ok({
-                    crate::api::init_graph();
-                })?;
+                let output_ok = Result::<_, ()>::Ok(crate::api::close_vector_store(api_store_id))?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__init_mcp_impl(
+fn wire__crate__api__cluster_embeddings_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
-    base_path: impl CstDecode<String>,
-    max_file_size: impl CstDecode<Option<usize>>,
-    allowed_extensions: impl CstDecode<Option<Vec<String>>>,
+    entries: impl CstDecode<Vec<crate::embeddings::EmbeddingEntry>>,
+    threshold: impl CstDecode<f32>,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "init_mcp",
+            debug_name: "cluster_embeddings",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
-            let api_base_path = base_path.cst_decode();
-            let api_max_file_size = max_file_size.cst_decode();
-            let api_allowed_extensions = allowed_extensions.cst_decode();
+            let api_entries = entries.cst_decode();
+            let api_threshold = threshold.cst_decode();
             move |context| {
-                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let output_ok = crate::api::init_mcp(
-                            api_base_path,
-                            api_max_file_size,
-                            api_allowed_extensions,
-                        )?;
-                        Ok(output_ok)
-                    })(),
-                )
-            }
-        },
-    )
-}
-fn wire__crate__api__init_model_impl(
-    model_path: impl CstDecode<String>,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::DcoCodec, _>(
-        flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "init_model",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
-        },
-        move || {
-            let api_model_path = model_path.cst_decode();
-            transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
-                (move || {
-                    let output_ok = crate::api::init_model(api_model_path)?;
+                transform_result_dco::<_, _, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::cluster_embeddings(
+                        api_entries,
+                        api_threshold,
+                    ))?;
                     Ok(output_ok)
-                })(),
-            )
+                })())
+            }
         },
     )
 }
-fn wire__crate__api__init_model_with_config_impl(
+fn wire__crate__api__cluster_notes_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
-    model_path: impl CstDecode<String>,
-    n_gpu_layers: impl CstDecode<u32>,
-    n_ctx: impl CstDecode<u32>,
-    n_threads: impl CstDecode<i32>,
-    temperature: impl CstDecode<f32>,
-    top_p: impl CstDecode<f32>,
-    max_tokens: impl CstDecode<u32>,
-    model_type: impl CstDecode<Option<i32>>,
+    entries: impl CstDecode<Vec<crate::embeddings::EmbeddingEntry>>,
+    k: impl CstDecode<Option<usize>>,
+    max_iterations: impl CstDecode<Option<usize>>,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "init_model_with_config",
+            debug_name: "cluster_notes",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
-            let api_model_path = model_path.cst_decode();
-            let api_n_gpu_layers = n_gpu_layers.cst_decode();
-            let api_n_ctx = n_ctx.cst_decode();
-            let api_n_threads = n_threads.cst_decode();
-            let api_temperature = temperature.cst_decode();
-            let api_top_p = top_p.cst_decode();
-            let api_max_tokens = max_tokens.cst_decode();
-            let api_model_type = model_type.cst_decode();
+            let api_entries = entries.cst_decode();
+            let api_k = k.cst_decode();
+            let api_max_iterations = max_iterations.cst_decode();
             move |context| {
                 transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || {
-                        let output_ok = crate::api::init_model_with_config(
-                            api_model_path,
-                            api_n_gpu_layers,
-                            api_n_ctx,
-                            api_n_threads,
-                            api_temperature,
-                            api_top_p,
-                            api_max_tokens,
-                            api_model_type,
-                        )?;
+                        let output_ok =
+                            crate::api::cluster_notes(api_entries, api_k, api_max_iterations)?;
                         Ok(output_ok)
                     })(),
                 )
@@ -862,88 +792,30 @@ fn wire__crate__api__init_model_with_config_impl(
         },
     )
 }
-fn wire__crate__api__is_graph_stream_running_impl(
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::DcoCodec, _>(
-        flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "is_graph_stream_running",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
-        },
-        move || {
-            transform_result_dco::<_, _, ()>((move || {
-                let output_ok = Result::<_, ()>::Ok(crate::api::is_graph_stream_running())?;
-                Ok(output_ok)
-            })())
-        },
-    )
-}
-fn wire__crate__api__is_mcp_initialized_impl(
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::DcoCodec, _>(
-        flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "is_mcp_initialized",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
-        },
-        move || {
-            transform_result_dco::<_, _, ()>((move || {
-                let output_ok = Result::<_, ()>::Ok(crate::api::is_mcp_initialized())?;
-                Ok(output_ok)
-            })())
-        },
-    )
-}
-fn wire__crate__api__is_model_loaded_impl(
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::DcoCodec, _>(
-        flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "is_model_loaded",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
-        },
-        move || {
-            transform_result_dco::<_, _, ()>((move || {
-                let output_ok = Result::<_, ()>::Ok(crate::api::is_model_loaded())?;
-                Ok(output_ok)
-            })())
-        },
-    )
-}
-fn wire__crate__api__mcp_classify_task_impl(
-    message: impl CstDecode<String>,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::DcoCodec, _>(
-        flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "mcp_classify_task",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
-        },
-        move || {
-            let api_message = message.cst_decode();
-            transform_result_dco::<_, _, ()>((move || {
-                let output_ok = Result::<_, ()>::Ok(crate::api::mcp_classify_task(api_message))?;
-                Ok(output_ok)
-            })())
-        },
-    )
-}
-fn wire__crate__api__mcp_create_folder_impl(
+fn wire__crate__api__cluster_notes_in_store_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
-    path: impl CstDecode<String>,
+    store_id: impl CstDecode<u64>,
+    k: impl CstDecode<Option<usize>>,
+    max_iterations: impl CstDecode<Option<usize>>,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "mcp_create_folder",
+            debug_name: "cluster_notes_in_store",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
-            let api_path = path.cst_decode();
+            let api_store_id = store_id.cst_decode();
+            let api_k = k.cst_decode();
+            let api_max_iterations = max_iterations.cst_decode();
             move |context| {
                 transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || {
-                        let output_ok = crate::api::mcp_create_folder(api_path)?;
+                        let output_ok = crate::api::cluster_notes_in_store(
+                            api_store_id,
+                            api_k,
+                            api_max_iterations,
+                        )?;
                         Ok(output_ok)
                     })(),
                 )
@@ -951,22 +823,20 @@ fn wire__crate__api__mcp_create_folder_impl(
         },
     )
 }
-fn wire__crate__api__mcp_delete_file_impl(
+fn wire__crate__api__compact_embedding_cache_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
-    path: impl CstDecode<String>,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "mcp_delete_file",
+            debug_name: "compact_embedding_cache",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
-            let api_path = path.cst_decode();
             move |context| {
                 transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || {
-                        let output_ok = crate::api::mcp_delete_file(api_path)?;
+                        let output_ok = crate::api::compact_embedding_cache()?;
                         Ok(output_ok)
                     })(),
                 )
@@ -974,150 +844,22 @@ fn wire__crate__api__mcp_delete_file_impl(
         },
     )
 }
-fn wire__crate__api__mcp_execute_tool_call_impl(
+fn wire__crate__api__compact_vector_store_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
-    tool_call: impl CstDecode<crate::mcp::MCPToolCall>,
+    store_id: impl CstDecode<u64>,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "mcp_execute_tool_call",
+            debug_name: "compact_vector_store",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
-            let api_tool_call = tool_call.cst_decode();
-            move |context| {
-                transform_result_dco::<_, _, ()>((move || {
-                    let output_ok =
-                        Result::<_, ()>::Ok(crate::api::mcp_execute_tool_call(api_tool_call))?;
-                    Ok(output_ok)
-                })())
-            }
-        },
-    )
-}
-fn wire__crate__api__mcp_get_all_tools_impl(
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::DcoCodec, _>(
-        flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "mcp_get_all_tools",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
-        },
-        move || {
-            transform_result_dco::<_, _, ()>((move || {
-                let output_ok = Result::<_, ()>::Ok(crate::api::mcp_get_all_tools())?;
-                Ok(output_ok)
-            })())
-        },
-    )
-}
-fn wire__crate__api__mcp_get_model_for_task_impl(
-    category: impl CstDecode<crate::mcp::TaskCategory>,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::DcoCodec, _>(
-        flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "mcp_get_model_for_task",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
-        },
-        move || {
-            let api_category = category.cst_decode();
-            transform_result_dco::<_, _, ()>((move || {
-                let output_ok =
-                    Result::<_, ()>::Ok(crate::api::mcp_get_model_for_task(api_category))?;
-                Ok(output_ok)
-            })())
-        },
-    )
-}
-fn wire__crate__api__mcp_get_tool_description_impl(
-    tool: impl CstDecode<crate::mcp::MCPTool>,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::DcoCodec, _>(
-        flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "mcp_get_tool_description",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
-        },
-        move || {
-            let api_tool = tool.cst_decode();
-            transform_result_dco::<_, _, ()>((move || {
-                let output_ok =
-                    Result::<_, ()>::Ok(crate::api::mcp_get_tool_description(api_tool))?;
-                Ok(output_ok)
-            })())
-        },
-    )
-}
-fn wire__crate__api__mcp_get_tool_name_impl(
-    tool: impl CstDecode<crate::mcp::MCPTool>,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::DcoCodec, _>(
-        flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "mcp_get_tool_name",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
-        },
-        move || {
-            let api_tool = tool.cst_decode();
-            transform_result_dco::<_, _, ()>((move || {
-                let output_ok = Result::<_, ()>::Ok(crate::api::mcp_get_tool_name(api_tool))?;
-                Ok(output_ok)
-            })())
-        },
-    )
-}
-fn wire__crate__api__mcp_get_tool_parameters_impl(
-    tool: impl CstDecode<crate::mcp::MCPTool>,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::DcoCodec, _>(
-        flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "mcp_get_tool_parameters",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
-        },
-        move || {
-            let api_tool = tool.cst_decode();
-            transform_result_dco::<_, _, ()>((move || {
-                let output_ok = Result::<_, ()>::Ok(crate::api::mcp_get_tool_parameters(api_tool))?;
-                Ok(output_ok)
-            })())
-        },
-    )
-}
-fn wire__crate__api__mcp_get_tool_schemas_impl(
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::DcoCodec, _>(
-        flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "mcp_get_tool_schemas",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
-        },
-        move || {
-            transform_result_dco::<_, _, ()>((move || {
-                let output_ok = Result::<_, ()>::Ok(crate::api::mcp_get_tool_schemas())?;
-                Ok(output_ok)
-            })())
-        },
-    )
-}
-fn wire__crate__api__mcp_list_files_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
-    path: impl CstDecode<String>,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
-        flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "mcp_list_files",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
-        },
-        move || {
-            let api_path = path.cst_decode();
+            let api_store_id = store_id.cst_decode();
             move |context| {
                 transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || {
-                        let output_ok = crate::api::mcp_list_files(api_path)?;
+                        let output_ok = crate::api::compact_vector_store(api_store_id)?;
                         Ok(output_ok)
                     })(),
                 )
@@ -1125,22 +867,22 @@ fn wire__crate__api__mcp_list_files_impl(
         },
     )
 }
-fn wire__crate__api__mcp_parse_tool_call_impl(
+fn wire__crate__api__compute_graph_layout_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
-    json: impl CstDecode<String>,
+    iterations: impl CstDecode<Option<u32>>,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "mcp_parse_tool_call",
+            debug_name: "compute_graph_layout",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
-            let api_json = json.cst_decode();
+            let api_iterations = iterations.cst_decode();
             move |context| {
                 transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || {
-                        let output_ok = crate::api::mcp_parse_tool_call(api_json)?;
+                        let output_ok = crate::api::compute_graph_layout(api_iterations)?;
                         Ok(output_ok)
                     })(),
                 )
@@ -1148,22 +890,25 @@ fn wire__crate__api__mcp_parse_tool_call_impl(
         },
     )
 }
-fn wire__crate__api__mcp_read_file_impl(
+fn wire__crate__api__connect_note_to_topics_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
-    path: impl CstDecode<String>,
+    note_id: impl CstDecode<String>,
+    topic_ids: impl CstDecode<Vec<String>>,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "mcp_read_file",
+            debug_name: "connect_note_to_topics",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
-            let api_path = path.cst_decode();
+            let api_note_id = note_id.cst_decode();
+            let api_topic_ids = topic_ids.cst_decode();
             move |context| {
                 transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || {
-                        let output_ok = crate::api::mcp_read_file(api_path)?;
+                        let output_ok =
+                            crate::api::connect_note_to_topics(api_note_id, api_topic_ids)?;
                         Ok(output_ok)
                     })(),
                 )
@@ -1171,42 +916,45 @@ fn wire__crate__api__mcp_read_file_impl(
         },
     )
 }
-fn wire__crate__api__mcp_validate_path_impl(
-    path: impl CstDecode<String>,
+fn wire__crate__api__cosine_similarity_impl(
+    a: impl CstDecode<Vec<f32>>,
+    b: impl CstDecode<Vec<f32>>,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::DcoCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "mcp_validate_path",
+            debug_name: "cosine_similarity",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
         move || {
-            let api_path = path.cst_decode();
+            let api_a = a.cst_decode();
+            let api_b = b.cst_decode();
             transform_result_dco::<_, _, ()>((move || {
-                let output_ok = Result::<_, ()>::Ok(crate::api::mcp_validate_path(api_path))?;
+                let output_ok = Result::<_, ()>::Ok(crate::api::cosine_similarity(api_a, api_b))?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__mcp_write_file_impl(
+fn wire__crate__api__count_chat_prompt_tokens_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
-    path: impl CstDecode<String>,
-    content: impl CstDecode<String>,
+    messages: impl CstDecode<Vec<(String, String)>>,
+    model_id: impl CstDecode<Option<String>>,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "mcp_write_file",
+            debug_name: "count_chat_prompt_tokens",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
-            let api_path = path.cst_decode();
-            let api_content = content.cst_decode();
+            let api_messages = messages.cst_decode();
+            let api_model_id = model_id.cst_decode();
             move |context| {
                 transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || {
-                        let output_ok = crate::api::mcp_write_file(api_path, api_content)?;
+                        let output_ok =
+                            crate::api::count_chat_prompt_tokens(api_messages, api_model_id)?;
                         Ok(output_ok)
                     })(),
                 )
@@ -1214,24 +962,24 @@ fn wire__crate__api__mcp_write_file_impl(
         },
     )
 }
-fn wire__crate__api__pin_stream_node_impl(
+fn wire__crate__api__count_chat_tokens_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
-    id: impl CstDecode<String>,
-    pinned: impl CstDecode<bool>,
+    messages: impl CstDecode<Vec<(String, String)>>,
+    model_id: impl CstDecode<Option<String>>,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "pin_stream_node",
+            debug_name: "count_chat_tokens",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
-            let api_id = id.cst_decode();
-            let api_pinned = pinned.cst_decode();
+            let api_messages = messages.cst_decode();
+            let api_model_id = model_id.cst_decode();
             move |context| {
                 transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || {
-                        let output_ok = crate::api::pin_stream_node(api_id, api_pinned)?;
+                        let output_ok = crate::api::count_chat_tokens(api_messages, api_model_id)?;
                         Ok(output_ok)
                     })(),
                 )
@@ -1239,22 +987,24 @@ fn wire__crate__api__pin_stream_node_impl(
         },
     )
 }
-fn wire__crate__api__remove_graph_node_impl(
+fn wire__crate__api__count_tokens_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
-    node_id: impl CstDecode<String>,
+    text: impl CstDecode<String>,
+    model_id: impl CstDecode<Option<String>>,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "remove_graph_node",
+            debug_name: "count_tokens",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
-            let api_node_id = node_id.cst_decode();
+            let api_text = text.cst_decode();
+            let api_model_id = model_id.cst_decode();
             move |context| {
                 transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || {
-                        let output_ok = crate::api::remove_graph_node(api_node_id)?;
+                        let output_ok = crate::api::count_tokens(api_text, api_model_id)?;
                         Ok(output_ok)
                     })(),
                 )
@@ -1262,24 +1012,24 @@ fn wire__crate__api__remove_graph_node_impl(
         },
     )
 }
-fn wire__crate__api__remove_stream_edge_impl(
+fn wire__crate__api__create_chat_session_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
-    from_id: impl CstDecode<String>,
-    to_id: impl CstDecode<String>,
+    n_ctx: impl CstDecode<Option<u32>>,
+    model_id: impl CstDecode<Option<String>>,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "remove_stream_edge",
+            debug_name: "create_chat_session",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
-            let api_from_id = from_id.cst_decode();
-            let api_to_id = to_id.cst_decode();
+            let api_n_ctx = n_ctx.cst_decode();
+            let api_model_id = model_id.cst_decode();
             move |context| {
                 transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || {
-                        let output_ok = crate::api::remove_stream_edge(api_from_id, api_to_id)?;
+                        let output_ok = crate::api::create_chat_session(api_n_ctx, api_model_id)?;
                         Ok(output_ok)
                     })(),
                 )
@@ -1287,50 +1037,43 @@ fn wire__crate__api__remove_stream_edge_impl(
         },
     )
 }
-fn wire__crate__api__remove_stream_node_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
-    id: impl CstDecode<String>,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+fn wire__crate__api__create_hnsw_index_impl(
+    config: impl CstDecode<Option<crate::hnsw::HnswConfig>>,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::DcoCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "remove_stream_node",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            debug_name: "create_hnsw_index",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
         move || {
-            let api_id = id.cst_decode();
-            move |context| {
-                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let output_ok = crate::api::remove_stream_node(api_id)?;
-                        Ok(output_ok)
-                    })(),
-                )
-            }
+            let api_config = config.cst_decode();
+            transform_result_dco::<_, _, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::create_hnsw_index(api_config))?;
+                Ok(output_ok)
+            })())
         },
     )
 }
-fn wire__crate__api__semantic_search_impl(
+fn wire__crate__api__create_hnsw_index_from_store_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
-    query_text: impl CstDecode<String>,
-    entries: impl CstDecode<Vec<crate::embeddings::EmbeddingEntry>>,
-    top_k: impl CstDecode<usize>,
+    store_id: impl CstDecode<u64>,
+    config: impl CstDecode<Option<crate::hnsw::HnswConfig>>,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "semantic_search",
+            debug_name: "create_hnsw_index_from_store",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
-            let api_query_text = query_text.cst_decode();
-            let api_entries = entries.cst_decode();
-            let api_top_k = top_k.cst_decode();
+            let api_store_id = store_id.cst_decode();
+            let api_config = config.cst_decode();
             move |context| {
                 transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || {
                         let output_ok =
-                            crate::api::semantic_search(api_query_text, api_entries, api_top_k)?;
+                            crate::api::create_hnsw_index_from_store(api_store_id, api_config)?;
                         Ok(output_ok)
                     })(),
                 )
@@ -1338,26 +1081,24 @@ fn wire__crate__api__semantic_search_impl(
         },
     )
 }
-fn wire__crate__api__set_stream_node_position_impl(
+fn wire__crate__api__create_quantized_index_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
-    id: impl CstDecode<String>,
-    x: impl CstDecode<f32>,
-    y: impl CstDecode<f32>,
+    entries: impl CstDecode<Vec<crate::embeddings::EmbeddingEntry>>,
+    kind: impl CstDecode<crate::quantization::QuantizationKind>,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "set_stream_node_position",
+            debug_name: "create_quantized_index",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
-            let api_id = id.cst_decode();
-            let api_x = x.cst_decode();
-            let api_y = y.cst_decode();
+            let api_entries = entries.cst_decode();
+            let api_kind = kind.cst_decode();
             move |context| {
                 transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || {
-                        let output_ok = crate::api::set_stream_node_position(api_id, api_x, api_y)?;
+                        let output_ok = crate::api::create_quantized_index(api_entries, api_kind)?;
                         Ok(output_ok)
                     })(),
                 )
@@ -1365,20 +1106,25 @@ fn wire__crate__api__set_stream_node_position_impl(
         },
     )
 }
-fn wire__crate__api__start_graph_stream_impl(
+fn wire__crate__api__create_quantized_index_from_store_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
+    store_id: impl CstDecode<u64>,
+    kind: impl CstDecode<crate::quantization::QuantizationKind>,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "start_graph_stream",
+            debug_name: "create_quantized_index_from_store",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
+            let api_store_id = store_id.cst_decode();
+            let api_kind = kind.cst_decode();
             move |context| {
                 transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || {
-                        let output_ok = crate::api::start_graph_stream()?;
+                        let output_ok =
+                            crate::api::create_quantized_index_from_store(api_store_id, api_kind)?;
                         Ok(output_ok)
                     })(),
                 )
@@ -1386,67 +1132,173 @@ fn wire__crate__api__start_graph_stream_impl(
         },
     )
 }
-fn wire__crate__api__stop_graph_stream_impl(
+fn wire__crate__api__default_context_policy_impl(
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::DcoCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "stop_graph_stream",
+            debug_name: "default_context_policy",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
         move || {
             transform_result_dco::<_, _, ()>((move || {
-                let output_ok = Result::<_, ()>::Ok({
-                    crate::api::stop_graph_stream();
-                })?;
+                let output_ok = Result::<_, ()>::Ok(crate::api::default_context_policy())?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__unload_model_impl() -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco
-{
+fn wire__crate__api__default_dedup_options_impl(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::DcoCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "unload_model",
+            debug_name: "default_dedup_options",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
         move || {
             transform_result_dco::<_, _, ()>((move || {
-                let output_ok = Result::<_, ()>::Ok({
-                    crate::api::unload_model();
-                })?;
+                let output_ok = Result::<_, ()>::Ok(crate::api::default_dedup_options())?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__update_graph_viewport_impl(
+fn wire__crate__api__default_embedding_config_impl(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::DcoCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "default_embedding_config",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            transform_result_dco::<_, _, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::default_embedding_config())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__default_hnsw_config_impl(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::DcoCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "default_hnsw_config",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            transform_result_dco::<_, _, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::default_hnsw_config())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__default_hybrid_options_impl(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::DcoCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "default_hybrid_options",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            transform_result_dco::<_, _, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::default_hybrid_options())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__default_reasoning_options_impl(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::DcoCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "default_reasoning_options",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            transform_result_dco::<_, _, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::default_reasoning_options())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__default_sampler_config_impl(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::DcoCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "default_sampler_config",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            transform_result_dco::<_, _, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::default_sampler_config())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__default_search_options_impl(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::DcoCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "default_search_options",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            transform_result_dco::<_, _, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::default_search_options())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__default_summary_options_impl(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::DcoCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "default_summary_options",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            transform_result_dco::<_, _, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::default_summary_options())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__detokenize_tokens_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
-    x: impl CstDecode<f32>,
-    y: impl CstDecode<f32>,
-    width: impl CstDecode<f32>,
-    height: impl CstDecode<f32>,
-    scale: impl CstDecode<f32>,
+    tokens: impl CstDecode<Vec<i32>>,
+    render_special: impl CstDecode<bool>,
+    model_id: impl CstDecode<Option<String>>,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "update_graph_viewport",
+            debug_name: "detokenize_tokens",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
-            let api_x = x.cst_decode();
-            let api_y = y.cst_decode();
-            let api_width = width.cst_decode();
-            let api_height = height.cst_decode();
-            let api_scale = scale.cst_decode();
+            let api_tokens = tokens.cst_decode();
+            let api_render_special = render_special.cst_decode();
+            let api_model_id = model_id.cst_decode();
             move |context| {
                 transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || {
-                        let output_ok = crate::api::update_graph_viewport(
-                            api_x, api_y, api_width, api_height, api_scale,
+                        let output_ok = crate::api::detokenize_tokens(
+                            api_tokens,
+                            api_render_special,
+                            api_model_id,
                         )?;
                         Ok(output_ok)
                     })(),
@@ -1455,3349 +1307,14386 @@ fn wire__crate__api__update_graph_viewport_impl(
         },
     )
 }
-
-// Section: dart2rust
-
-impl CstDecode<bool> for bool {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    fn cst_decode(self) -> bool {
-        self
-    }
-}
-impl CstDecode<f32> for f32 {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    fn cst_decode(self) -> f32 {
-        self
-    }
+fn wire__crate__api__discover_semantic_edges_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    entries: impl CstDecode<Vec<crate::embeddings::EmbeddingEntry>>,
+    threshold: impl CstDecode<Option<f32>>,
+    existing_links: impl CstDecode<Option<Vec<(String, String)>>>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "discover_semantic_edges",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_entries = entries.cst_decode();
+            let api_threshold = threshold.cst_decode();
+            let api_existing_links = existing_links.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::discover_semantic_edges(
+                        api_entries,
+                        api_threshold,
+                        api_existing_links,
+                    ))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
 }
-impl CstDecode<i32> for i32 {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    fn cst_decode(self) -> i32 {
-        self
-    }
+fn wire__crate__api__discover_semantic_edges_in_index_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    index_id: impl CstDecode<u64>,
+    threshold: impl CstDecode<Option<f32>>,
+    existing_links: impl CstDecode<Option<Vec<(String, String)>>>,
+    max_neighbors: impl CstDecode<Option<usize>>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "discover_semantic_edges_in_index",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_index_id = index_id.cst_decode();
+            let api_threshold = threshold.cst_decode();
+            let api_existing_links = existing_links.cst_decode();
+            let api_max_neighbors = max_neighbors.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::discover_semantic_edges_in_index(
+                            api_index_id,
+                            api_threshold,
+                            api_existing_links,
+                            api_max_neighbors,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
 }
-impl CstDecode<crate::mcp::MCPParamType> for i32 {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    fn cst_decode(self) -> crate::mcp::MCPParamType {
-        match self {
-            0 => crate::mcp::MCPParamType::String,
-            1 => crate::mcp::MCPParamType::Boolean,
-            2 => crate::mcp::MCPParamType::Integer,
-            3 => crate::mcp::MCPParamType::Array,
-            _ => unreachable!("Invalid variant for MCPParamType: {}", self),
-        }
-    }
+fn wire__crate__api__discover_semantic_edges_in_store_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    store_id: impl CstDecode<u64>,
+    threshold: impl CstDecode<Option<f32>>,
+    existing_links: impl CstDecode<Option<Vec<(String, String)>>>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "discover_semantic_edges_in_store",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_store_id = store_id.cst_decode();
+            let api_threshold = threshold.cst_decode();
+            let api_existing_links = existing_links.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::discover_semantic_edges_in_store(
+                            api_store_id,
+                            api_threshold,
+                            api_existing_links,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
 }
-impl CstDecode<crate::mcp::MCPTool> for i32 {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    fn cst_decode(self) -> crate::mcp::MCPTool {
-        match self {
-            0 => crate::mcp::MCPTool::ReadFile,
-            1 => crate::mcp::MCPTool::WriteFile,
-            2 => crate::mcp::MCPTool::DeleteFile,
-            3 => crate::mcp::MCPTool::CreateFolder,
-            4 => crate::mcp::MCPTool::ListFiles,
-            5 => crate::mcp::MCPTool::CalendarLua,
-            6 => crate::mcp::MCPTool::TimerLua,
-            7 => crate::mcp::MCPTool::ExportMarkdown,
-            _ => unreachable!("Invalid variant for MCPTool: {}", self),
-        }
-    }
+fn wire__crate__api__embed_markdown_note_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    note_id: impl CstDecode<String>,
+    text: impl CstDecode<String>,
+    max_tokens: impl CstDecode<Option<u32>>,
+    overlap_tokens: impl CstDecode<Option<u32>>,
+    metadata: impl CstDecode<Option<crate::embeddings::NoteMetadata>>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "embed_markdown_note",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_note_id = note_id.cst_decode();
+            let api_text = text.cst_decode();
+            let api_max_tokens = max_tokens.cst_decode();
+            let api_overlap_tokens = overlap_tokens.cst_decode();
+            let api_metadata = metadata.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::embed_markdown_note(
+                            api_note_id,
+                            api_text,
+                            api_max_tokens,
+                            api_overlap_tokens,
+                            api_metadata,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
 }
-impl CstDecode<crate::mcp::TaskCategory> for i32 {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    fn cst_decode(self) -> crate::mcp::TaskCategory {
+fn wire__crate__api__embed_texts_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    texts: impl CstDecode<Vec<String>>,
+    kind: impl CstDecode<crate::embedder::EmbeddingKind>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "embed_texts",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_texts = texts.cst_decode();
+            let api_kind = kind.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::embed_texts(api_texts, api_kind)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__extract_topics_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    text: impl CstDecode<String>,
+    num_topics: impl CstDecode<Option<u32>>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "extract_topics",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_text = text.cst_decode();
+            let api_num_topics = num_topics.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::extract_topics(api_text, api_num_topics)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__extract_topics_with_model_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    text: impl CstDecode<String>,
+    num_topics: impl CstDecode<Option<u32>>,
+    model_id: impl CstDecode<Option<String>>,
+    min_confidence: impl CstDecode<Option<f32>>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "extract_topics_with_model",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_text = text.cst_decode();
+            let api_num_topics = num_topics.cst_decode();
+            let api_model_id = model_id.cst_decode();
+            let api_min_confidence = min_confidence.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::extract_topics_with_model(
+                            api_text,
+                            api_num_topics,
+                            api_model_id,
+                            api_min_confidence,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__find_duplicate_entries_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    entries: impl CstDecode<Vec<crate::embeddings::EmbeddingEntry>>,
+    options: impl CstDecode<Option<crate::dedup::DedupOptions>>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "find_duplicate_entries",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_entries = entries.cst_decode();
+            let api_options = options.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::find_duplicate_entries(api_entries, api_options)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__find_duplicates_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    documents: impl CstDecode<Vec<crate::dedup::DedupDocument>>,
+    options: impl CstDecode<Option<crate::dedup::DedupOptions>>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "find_duplicates",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_documents = documents.cst_decode();
+            let api_options = options.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::find_duplicates(
+                        api_documents,
+                        api_options,
+                    ))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__find_duplicates_in_store_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    store_id: impl CstDecode<u64>,
+    options: impl CstDecode<Option<crate::dedup::DedupOptions>>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "find_duplicates_in_store",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_store_id = store_id.cst_decode();
+            let api_options = options.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::find_duplicates_in_store(api_store_id, api_options)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__find_similar_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    query: impl CstDecode<Vec<f32>>,
+    entries: impl CstDecode<Vec<crate::embeddings::EmbeddingEntry>>,
+    top_k: impl CstDecode<usize>,
+    threshold: impl CstDecode<f32>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "find_similar",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_query = query.cst_decode();
+            let api_entries = entries.cst_decode();
+            let api_top_k = top_k.cst_decode();
+            let api_threshold = threshold.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::find_similar(
+                        api_query,
+                        api_entries,
+                        api_top_k,
+                        api_threshold,
+                    ))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__find_similar_filtered_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    query: impl CstDecode<Vec<f32>>,
+    entries: impl CstDecode<Vec<crate::embeddings::EmbeddingEntry>>,
+    options: impl CstDecode<crate::filtered_search::SearchOptions>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "find_similar_filtered",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_query = query.cst_decode();
+            let api_entries = entries.cst_decode();
+            let api_options = options.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::find_similar_filtered(api_query, api_entries, api_options)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__find_similar_in_index_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    index_id: impl CstDecode<u64>,
+    query: impl CstDecode<Vec<f32>>,
+    top_k: impl CstDecode<usize>,
+    threshold: impl CstDecode<f32>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "find_similar_in_index",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_index_id = index_id.cst_decode();
+            let api_query = query.cst_decode();
+            let api_top_k = top_k.cst_decode();
+            let api_threshold = threshold.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::find_similar_in_index(
+                            api_index_id,
+                            api_query,
+                            api_top_k,
+                            api_threshold,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__find_similar_in_store_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    store_id: impl CstDecode<u64>,
+    query: impl CstDecode<Vec<f32>>,
+    top_k: impl CstDecode<usize>,
+    threshold: impl CstDecode<f32>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "find_similar_in_store",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_store_id = store_id.cst_decode();
+            let api_query = query.cst_decode();
+            let api_top_k = top_k.cst_decode();
+            let api_threshold = threshold.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::find_similar_in_store(
+                            api_store_id,
+                            api_query,
+                            api_top_k,
+                            api_threshold,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__find_similar_quantized_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    index_id: impl CstDecode<u64>,
+    query: impl CstDecode<Vec<f32>>,
+    top_k: impl CstDecode<usize>,
+    threshold: impl CstDecode<f32>,
+    rescore_candidates: impl CstDecode<Option<usize>>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "find_similar_quantized",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_index_id = index_id.cst_decode();
+            let api_query = query.cst_decode();
+            let api_top_k = top_k.cst_decode();
+            let api_threshold = threshold.cst_decode();
+            let api_rescore_candidates = rescore_candidates.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::find_similar_quantized(
+                            api_index_id,
+                            api_query,
+                            api_top_k,
+                            api_threshold,
+                            api_rescore_candidates,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__generate_text_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    prompt: impl CstDecode<String>,
+    max_tokens: impl CstDecode<Option<u32>>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "generate_text",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_prompt = prompt.cst_decode();
+            let api_max_tokens = max_tokens.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::generate_text(api_prompt, api_max_tokens)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__generate_text_stream_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    prompt: impl CstDecode<String>,
+    max_tokens: impl CstDecode<Option<u32>>,
+    stop_sequences: impl CstDecode<Option<Vec<String>>>,
+    model_id: impl CstDecode<Option<String>>,
+    sink: impl CstDecode<
+        StreamSink<crate::inference::GenerationChunk, flutter_rust_bridge::for_generated::DcoCodec>,
+    >,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "generate_text_stream",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_prompt = prompt.cst_decode();
+            let api_max_tokens = max_tokens.cst_decode();
+            let api_stop_sequences = stop_sequences.cst_decode();
+            let api_model_id = model_id.cst_decode();
+            let api_sink = sink.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::generate_text_stream(
+                            api_prompt,
+                            api_max_tokens,
+                            api_stop_sequences,
+                            api_model_id,
+                            api_sink,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__generate_text_with_model_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    prompt: impl CstDecode<String>,
+    max_tokens: impl CstDecode<Option<u32>>,
+    model_id: impl CstDecode<Option<String>>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "generate_text_with_model",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_prompt = prompt.cst_decode();
+            let api_max_tokens = max_tokens.cst_decode();
+            let api_model_id = model_id.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::generate_text_with_model(
+                            api_prompt,
+                            api_max_tokens,
+                            api_model_id,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__generate_text_with_options_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    prompt: impl CstDecode<String>,
+    options: impl CstDecode<crate::inference::GenerationOptions>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "generate_text_with_options",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_prompt = prompt.cst_decode();
+            let api_options = options.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::generate_text_with_options(api_prompt, api_options)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__get_embedding_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    text: impl CstDecode<String>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_embedding",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_text = text.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::get_embedding(api_text)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__get_embedding_cache_stats_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_embedding_cache_stats",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            move |context| {
+                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::get_embedding_cache_stats()?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__get_embedding_dimension_impl(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::DcoCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_embedding_dimension",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                (move || {
+                    let output_ok = crate::api::get_embedding_dimension()?;
+                    Ok(output_ok)
+                })(),
+            )
+        },
+    )
+}
+fn wire__crate__api__get_embedding_with_model_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    text: impl CstDecode<String>,
+    model_id: impl CstDecode<Option<String>>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_embedding_with_model",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_text = text.cst_decode();
+            let api_model_id = model_id.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::get_embedding_with_model(api_text, api_model_id)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__get_graph_state_impl(port_: flutter_rust_bridge::for_generated::MessagePort) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_graph_state",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            move |context| {
+                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::get_graph_state()?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__get_model_metadata_impl(
+    model_id: impl CstDecode<Option<String>>,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::DcoCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_model_metadata",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let api_model_id = model_id.cst_decode();
+            transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                (move || {
+                    let output_ok = crate::api::get_model_metadata(api_model_id)?;
+                    Ok(output_ok)
+                })(),
+            )
+        },
+    )
+}
+fn wire__crate__api__get_model_type_impl(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::DcoCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_model_type",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            transform_result_dco::<_, _, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::get_model_type())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__get_or_create_topic_hub_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    topic: impl CstDecode<String>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_or_create_topic_hub",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_topic = topic.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::get_or_create_topic_hub(api_topic)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__get_stream_graph_stats_impl(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::DcoCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_stream_graph_stats",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            transform_result_dco::<_, _, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::get_stream_graph_stats())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__get_tokenizer_info_impl(
+    model_id: impl CstDecode<Option<String>>,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::DcoCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_tokenizer_info",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let api_model_id = model_id.cst_decode();
+            transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                (move || {
+                    let output_ok = crate::api::get_tokenizer_info(api_model_id)?;
+                    Ok(output_ok)
+                })(),
+            )
+        },
+    )
+}
+fn wire__crate__api__get_vector_store_stats_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    store_id: impl CstDecode<u64>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_vector_store_stats",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_store_id = store_id.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::get_vector_store_stats(api_store_id)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__get_version_impl() -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco
+{
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::DcoCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_version",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            transform_result_dco::<_, _, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::get_version())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__get_visible_graph_nodes_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_visible_graph_nodes",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            move |context| {
+                transform_result_dco::<_, _, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::get_visible_graph_nodes())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__greedy_sampler_config_impl(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::DcoCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "greedy_sampler_config",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            transform_result_dco::<_, _, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::greedy_sampler_config())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__health_check_impl() -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco
+{
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::DcoCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "health_check",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            transform_result_dco::<_, _, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::health_check())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__hnsw_index_insert_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    index_id: impl CstDecode<u64>,
+    entries: impl CstDecode<Vec<crate::embeddings::EmbeddingEntry>>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "hnsw_index_insert",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_index_id = index_id.cst_decode();
+            let api_entries = entries.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::hnsw_index_insert(api_index_id, api_entries)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__hnsw_index_insert_with_texts_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    index_id: impl CstDecode<u64>,
+    entries: impl CstDecode<Vec<crate::embeddings::EmbeddingEntry>>,
+    texts: impl CstDecode<Vec<String>>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "hnsw_index_insert_with_texts",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_index_id = index_id.cst_decode();
+            let api_entries = entries.cst_decode();
+            let api_texts = texts.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::hnsw_index_insert_with_texts(
+                            api_index_id,
+                            api_entries,
+                            api_texts,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__hnsw_index_len_impl(
+    index_id: impl CstDecode<u64>,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::DcoCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "hnsw_index_len",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let api_index_id = index_id.cst_decode();
+            transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                (move || {
+                    let output_ok = crate::api::hnsw_index_len(api_index_id)?;
+                    Ok(output_ok)
+                })(),
+            )
+        },
+    )
+}
+fn wire__crate__api__hnsw_index_remove_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    index_id: impl CstDecode<u64>,
+    ids: impl CstDecode<Vec<String>>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "hnsw_index_remove",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_index_id = index_id.cst_decode();
+            let api_ids = ids.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::hnsw_index_remove(api_index_id, api_ids)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__hybrid_search_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    query_text: impl CstDecode<String>,
+    entries: impl CstDecode<Vec<crate::embeddings::EmbeddingEntry>>,
+    top_k: impl CstDecode<usize>,
+    options: impl CstDecode<Option<crate::hybrid_search::HybridOptions>>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "hybrid_search",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_query_text = query_text.cst_decode();
+            let api_entries = entries.cst_decode();
+            let api_top_k = top_k.cst_decode();
+            let api_options = options.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::hybrid_search(
+                            api_query_text,
+                            api_entries,
+                            api_top_k,
+                            api_options,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__hybrid_search_in_index_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    index_id: impl CstDecode<u64>,
+    query_text: impl CstDecode<String>,
+    top_k: impl CstDecode<usize>,
+    options: impl CstDecode<Option<crate::hybrid_search::HybridOptions>>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "hybrid_search_in_index",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_index_id = index_id.cst_decode();
+            let api_query_text = query_text.cst_decode();
+            let api_top_k = top_k.cst_decode();
+            let api_options = options.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::hybrid_search_in_index(
+                            api_index_id,
+                            api_query_text,
+                            api_top_k,
+                            api_options,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__init_graph_impl() -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::DcoCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "init_graph",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            transform_result_dco::<_, _, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::init_graph();
+                })?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__init_mcp_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    base_path: impl CstDecode<String>,
+    max_file_size: impl CstDecode<Option<usize>>,
+    allowed_extensions: impl CstDecode<Option<Vec<String>>>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "init_mcp",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_base_path = base_path.cst_decode();
+            let api_max_file_size = max_file_size.cst_decode();
+            let api_allowed_extensions = allowed_extensions.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::init_mcp(
+                            api_base_path,
+                            api_max_file_size,
+                            api_allowed_extensions,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__init_model_impl(
+    model_path: impl CstDecode<String>,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::DcoCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "init_model",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let api_model_path = model_path.cst_decode();
+            transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                (move || {
+                    let output_ok = crate::api::init_model(api_model_path)?;
+                    Ok(output_ok)
+                })(),
+            )
+        },
+    )
+}
+fn wire__crate__api__init_model_with_config_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    model_path: impl CstDecode<String>,
+    n_gpu_layers: impl CstDecode<u32>,
+    n_ctx: impl CstDecode<u32>,
+    n_threads: impl CstDecode<i32>,
+    temperature: impl CstDecode<f32>,
+    top_p: impl CstDecode<f32>,
+    max_tokens: impl CstDecode<u32>,
+    model_type: impl CstDecode<Option<i32>>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "init_model_with_config",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_model_path = model_path.cst_decode();
+            let api_n_gpu_layers = n_gpu_layers.cst_decode();
+            let api_n_ctx = n_ctx.cst_decode();
+            let api_n_threads = n_threads.cst_decode();
+            let api_temperature = temperature.cst_decode();
+            let api_top_p = top_p.cst_decode();
+            let api_max_tokens = max_tokens.cst_decode();
+            let api_model_type = model_type.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::init_model_with_config(
+                            api_model_path,
+                            api_n_gpu_layers,
+                            api_n_ctx,
+                            api_n_threads,
+                            api_temperature,
+                            api_top_p,
+                            api_max_tokens,
+                            api_model_type,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__inspect_model_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    model_path: impl CstDecode<String>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "inspect_model",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_model_path = model_path.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::inspect_model(api_model_path)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__is_embedding_model_loaded_impl(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::DcoCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "is_embedding_model_loaded",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            transform_result_dco::<_, _, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::is_embedding_model_loaded())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__is_graph_stream_running_impl(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::DcoCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "is_graph_stream_running",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            transform_result_dco::<_, _, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::is_graph_stream_running())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__is_mcp_initialized_impl(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::DcoCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "is_mcp_initialized",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            transform_result_dco::<_, _, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::is_mcp_initialized())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__is_model_loaded_impl(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::DcoCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "is_model_loaded",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            transform_result_dco::<_, _, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::is_model_loaded())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__json_schema_to_grammar_impl(
+    schema_json: impl CstDecode<String>,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::DcoCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "json_schema_to_grammar",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let api_schema_json = schema_json.cst_decode();
+            transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                (move || {
+                    let output_ok = crate::api::json_schema_to_grammar(api_schema_json)?;
+                    Ok(output_ok)
+                })(),
+            )
+        },
+    )
+}
+fn wire__crate__api__list_loaded_models_impl(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::DcoCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_loaded_models",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            transform_result_dco::<_, _, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::list_loaded_models())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__load_chat_session_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    path: impl CstDecode<String>,
+    n_ctx: impl CstDecode<Option<u32>>,
+    model_id: impl CstDecode<Option<String>>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "load_chat_session",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_path = path.cst_decode();
+            let api_n_ctx = n_ctx.cst_decode();
+            let api_model_id = model_id.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::load_chat_session(api_path, api_n_ctx, api_model_id)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__load_embedding_model_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    model_path: impl CstDecode<String>,
+    config: impl CstDecode<Option<crate::embedder::EmbeddingConfig>>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "load_embedding_model",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_model_path = model_path.cst_decode();
+            let api_config = config.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::load_embedding_model(api_model_path, api_config)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__load_hnsw_index_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    path: impl CstDecode<String>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "load_hnsw_index",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_path = path.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::load_hnsw_index(api_path)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__load_model_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    model_id: impl CstDecode<String>,
+    model_path: impl CstDecode<String>,
+    n_ctx: impl CstDecode<Option<u32>>,
+    n_gpu_layers: impl CstDecode<Option<u32>>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "load_model",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_model_id = model_id.cst_decode();
+            let api_model_path = model_path.cst_decode();
+            let api_n_ctx = n_ctx.cst_decode();
+            let api_n_gpu_layers = n_gpu_layers.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::load_model(
+                            api_model_id,
+                            api_model_path,
+                            api_n_ctx,
+                            api_n_gpu_layers,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__load_quantized_index_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    path: impl CstDecode<String>,
+    store_id: impl CstDecode<Option<u64>>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "load_quantized_index",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_path = path.cst_decode();
+            let api_store_id = store_id.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::load_quantized_index(api_path, api_store_id)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__mcp_classify_task_impl(
+    message: impl CstDecode<String>,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::DcoCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "mcp_classify_task",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let api_message = message.cst_decode();
+            transform_result_dco::<_, _, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::mcp_classify_task(api_message))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__mcp_classify_task_with_model_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    message: impl CstDecode<String>,
+    model_id: impl CstDecode<Option<String>>,
+    min_confidence: impl CstDecode<Option<f32>>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "mcp_classify_task_with_model",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_message = message.cst_decode();
+            let api_model_id = model_id.cst_decode();
+            let api_min_confidence = min_confidence.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::mcp_classify_task_with_model(
+                            api_message,
+                            api_model_id,
+                            api_min_confidence,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__mcp_create_folder_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    path: impl CstDecode<String>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "mcp_create_folder",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_path = path.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::mcp_create_folder(api_path)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__mcp_delete_file_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    path: impl CstDecode<String>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "mcp_delete_file",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_path = path.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::mcp_delete_file(api_path)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__mcp_execute_tool_call_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    tool_call: impl CstDecode<crate::mcp::MCPToolCall>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "mcp_execute_tool_call",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_tool_call = tool_call.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::mcp_execute_tool_call(api_tool_call))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__mcp_generate_tool_call_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    messages: impl CstDecode<Vec<(String, String)>>,
+    max_tokens: impl CstDecode<Option<u32>>,
+    model_id: impl CstDecode<Option<String>>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "mcp_generate_tool_call",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_messages = messages.cst_decode();
+            let api_max_tokens = max_tokens.cst_decode();
+            let api_model_id = model_id.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::mcp_generate_tool_call(
+                            api_messages,
+                            api_max_tokens,
+                            api_model_id,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__mcp_get_all_tools_impl(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::DcoCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "mcp_get_all_tools",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            transform_result_dco::<_, _, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::mcp_get_all_tools())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__mcp_get_model_for_task_impl(
+    category: impl CstDecode<crate::mcp::TaskCategory>,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::DcoCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "mcp_get_model_for_task",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let api_category = category.cst_decode();
+            transform_result_dco::<_, _, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::mcp_get_model_for_task(api_category))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__mcp_get_model_id_for_task_impl(
+    category: impl CstDecode<crate::mcp::TaskCategory>,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::DcoCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "mcp_get_model_id_for_task",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let api_category = category.cst_decode();
+            transform_result_dco::<_, _, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::mcp_get_model_id_for_task(api_category))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__mcp_get_tool_description_impl(
+    tool: impl CstDecode<crate::mcp::MCPTool>,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::DcoCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "mcp_get_tool_description",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let api_tool = tool.cst_decode();
+            transform_result_dco::<_, _, ()>((move || {
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::mcp_get_tool_description(api_tool))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__mcp_get_tool_name_impl(
+    tool: impl CstDecode<crate::mcp::MCPTool>,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::DcoCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "mcp_get_tool_name",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let api_tool = tool.cst_decode();
+            transform_result_dco::<_, _, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::mcp_get_tool_name(api_tool))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__mcp_get_tool_parameters_impl(
+    tool: impl CstDecode<crate::mcp::MCPTool>,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::DcoCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "mcp_get_tool_parameters",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let api_tool = tool.cst_decode();
+            transform_result_dco::<_, _, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::mcp_get_tool_parameters(api_tool))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__mcp_get_tool_schemas_impl(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::DcoCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "mcp_get_tool_schemas",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            transform_result_dco::<_, _, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::mcp_get_tool_schemas())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__mcp_list_files_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    path: impl CstDecode<String>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "mcp_list_files",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_path = path.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::mcp_list_files(api_path)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__mcp_parse_tool_call_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    json: impl CstDecode<String>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "mcp_parse_tool_call",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_json = json.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::mcp_parse_tool_call(api_json)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__mcp_parse_tool_calls_impl(
+    reply: impl CstDecode<String>,
+    model_type: impl CstDecode<Option<i32>>,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::DcoCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "mcp_parse_tool_calls",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let api_reply = reply.cst_decode();
+            let api_model_type = model_type.cst_decode();
+            transform_result_dco::<_, _, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::mcp_parse_tool_calls(
+                    api_reply,
+                    api_model_type,
+                ))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__mcp_read_file_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    path: impl CstDecode<String>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "mcp_read_file",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_path = path.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::mcp_read_file(api_path)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__mcp_run_agent_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    messages: impl CstDecode<Vec<(String, String)>>,
+    max_steps: impl CstDecode<Option<u32>>,
+    max_tokens: impl CstDecode<Option<u32>>,
+    model_id: impl CstDecode<Option<String>>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "mcp_run_agent",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_messages = messages.cst_decode();
+            let api_max_steps = max_steps.cst_decode();
+            let api_max_tokens = max_tokens.cst_decode();
+            let api_model_id = model_id.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::mcp_run_agent(
+                            api_messages,
+                            api_max_steps,
+                            api_max_tokens,
+                            api_model_id,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__mcp_run_agent_stream_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    messages: impl CstDecode<Vec<(String, String)>>,
+    max_steps: impl CstDecode<Option<u32>>,
+    max_tokens: impl CstDecode<Option<u32>>,
+    model_id: impl CstDecode<Option<String>>,
+    sink: impl CstDecode<
+        StreamSink<crate::agent::AgentStep, flutter_rust_bridge::for_generated::DcoCodec>,
+    >,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "mcp_run_agent_stream",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_messages = messages.cst_decode();
+            let api_max_steps = max_steps.cst_decode();
+            let api_max_tokens = max_tokens.cst_decode();
+            let api_model_id = model_id.cst_decode();
+            let api_sink = sink.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::mcp_run_agent_stream(
+                            api_messages,
+                            api_max_steps,
+                            api_max_tokens,
+                            api_model_id,
+                            api_sink,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__mcp_validate_path_impl(
+    path: impl CstDecode<String>,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::DcoCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "mcp_validate_path",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let api_path = path.cst_decode();
+            transform_result_dco::<_, _, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::mcp_validate_path(api_path))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__mcp_write_file_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    path: impl CstDecode<String>,
+    content: impl CstDecode<String>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "mcp_write_file",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_path = path.cst_decode();
+            let api_content = content.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::mcp_write_file(api_path, api_content)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__measure_quantization_recall_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    entries: impl CstDecode<Vec<crate::embeddings::EmbeddingEntry>>,
+    queries: impl CstDecode<Option<Vec<Vec<f32>>>>,
+    kind: impl CstDecode<crate::quantization::QuantizationKind>,
+    top_k: impl CstDecode<usize>,
+    rescore_candidates: impl CstDecode<usize>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "measure_quantization_recall",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_entries = entries.cst_decode();
+            let api_queries = queries.cst_decode();
+            let api_kind = kind.cst_decode();
+            let api_top_k = top_k.cst_decode();
+            let api_rescore_candidates = rescore_candidates.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::measure_quantization_recall(
+                            api_entries,
+                            api_queries,
+                            api_kind,
+                            api_top_k,
+                            api_rescore_candidates,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__open_embedding_cache_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    path: impl CstDecode<String>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "open_embedding_cache",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_path = path.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::open_embedding_cache(api_path)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__open_vector_store_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    path: impl CstDecode<String>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "open_vector_store",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_path = path.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::open_vector_store(api_path)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__pin_stream_node_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    id: impl CstDecode<String>,
+    pinned: impl CstDecode<bool>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "pin_stream_node",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_id = id.cst_decode();
+            let api_pinned = pinned.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::pin_stream_node(api_id, api_pinned)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__poll_generation_impl(
+    request_id: impl CstDecode<u64>,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::DcoCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "poll_generation",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let api_request_id = request_id.cst_decode();
+            transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                (move || {
+                    let output_ok = crate::api::poll_generation(api_request_id)?;
+                    Ok(output_ok)
+                })(),
+            )
+        },
+    )
+}
+fn wire__crate__api__quantized_index_insert_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    index_id: impl CstDecode<u64>,
+    entries: impl CstDecode<Vec<crate::embeddings::EmbeddingEntry>>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "quantized_index_insert",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_index_id = index_id.cst_decode();
+            let api_entries = entries.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::quantized_index_insert(api_index_id, api_entries)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__quantized_index_remove_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    index_id: impl CstDecode<u64>,
+    ids: impl CstDecode<Vec<String>>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "quantized_index_remove",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_index_id = index_id.cst_decode();
+            let api_ids = ids.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::quantized_index_remove(api_index_id, api_ids)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__rag_answer_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    question: impl CstDecode<String>,
+    chunks: impl CstDecode<Vec<crate::embeddings::EmbeddingEntry>>,
+    top_k: impl CstDecode<Option<u32>>,
+    context_tokens: impl CstDecode<Option<u32>>,
+    max_tokens: impl CstDecode<Option<u32>>,
+    model_id: impl CstDecode<Option<String>>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "rag_answer",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_question = question.cst_decode();
+            let api_chunks = chunks.cst_decode();
+            let api_top_k = top_k.cst_decode();
+            let api_context_tokens = context_tokens.cst_decode();
+            let api_max_tokens = max_tokens.cst_decode();
+            let api_model_id = model_id.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::rag_answer(
+                            api_question,
+                            api_chunks,
+                            api_top_k,
+                            api_context_tokens,
+                            api_max_tokens,
+                            api_model_id,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__remove_graph_node_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    node_id: impl CstDecode<String>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "remove_graph_node",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_node_id = node_id.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::remove_graph_node(api_node_id)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__remove_stream_edge_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    from_id: impl CstDecode<String>,
+    to_id: impl CstDecode<String>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "remove_stream_edge",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_from_id = from_id.cst_decode();
+            let api_to_id = to_id.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::remove_stream_edge(api_from_id, api_to_id)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__remove_stream_node_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    id: impl CstDecode<String>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "remove_stream_node",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_id = id.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::remove_stream_node(api_id)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__save_chat_session_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    session_id: impl CstDecode<u64>,
+    path: impl CstDecode<String>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "save_chat_session",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_session_id = session_id.cst_decode();
+            let api_path = path.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::save_chat_session(api_session_id, api_path)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__save_hnsw_index_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    index_id: impl CstDecode<u64>,
+    path: impl CstDecode<String>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "save_hnsw_index",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_index_id = index_id.cst_decode();
+            let api_path = path.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::save_hnsw_index(api_index_id, api_path)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__save_quantized_index_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    index_id: impl CstDecode<u64>,
+    path: impl CstDecode<String>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "save_quantized_index",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_index_id = index_id.cst_decode();
+            let api_path = path.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::save_quantized_index(api_index_id, api_path)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__semantic_search_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    query_text: impl CstDecode<String>,
+    entries: impl CstDecode<Vec<crate::embeddings::EmbeddingEntry>>,
+    top_k: impl CstDecode<usize>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "semantic_search",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_query_text = query_text.cst_decode();
+            let api_entries = entries.cst_decode();
+            let api_top_k = top_k.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::semantic_search(api_query_text, api_entries, api_top_k)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__semantic_search_filtered_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    query_text: impl CstDecode<String>,
+    entries: impl CstDecode<Vec<crate::embeddings::EmbeddingEntry>>,
+    options: impl CstDecode<crate::filtered_search::SearchOptions>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "semantic_search_filtered",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_query_text = query_text.cst_decode();
+            let api_entries = entries.cst_decode();
+            let api_options = options.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::semantic_search_filtered(
+                            api_query_text,
+                            api_entries,
+                            api_options,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__semantic_search_in_index_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    index_id: impl CstDecode<u64>,
+    query_text: impl CstDecode<String>,
+    top_k: impl CstDecode<usize>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "semantic_search_in_index",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_index_id = index_id.cst_decode();
+            let api_query_text = query_text.cst_decode();
+            let api_top_k = top_k.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::semantic_search_in_index(
+                            api_index_id,
+                            api_query_text,
+                            api_top_k,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__semantic_search_in_index_filtered_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    index_id: impl CstDecode<u64>,
+    query_text: impl CstDecode<String>,
+    options: impl CstDecode<crate::filtered_search::SearchOptions>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "semantic_search_in_index_filtered",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_index_id = index_id.cst_decode();
+            let api_query_text = query_text.cst_decode();
+            let api_options = options.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::semantic_search_in_index_filtered(
+                            api_index_id,
+                            api_query_text,
+                            api_options,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__semantic_search_in_store_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    store_id: impl CstDecode<u64>,
+    query_text: impl CstDecode<String>,
+    top_k: impl CstDecode<usize>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "semantic_search_in_store",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_store_id = store_id.cst_decode();
+            let api_query_text = query_text.cst_decode();
+            let api_top_k = top_k.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::semantic_search_in_store(
+                            api_store_id,
+                            api_query_text,
+                            api_top_k,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__semantic_search_in_store_filtered_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    store_id: impl CstDecode<u64>,
+    query_text: impl CstDecode<String>,
+    options: impl CstDecode<crate::filtered_search::SearchOptions>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "semantic_search_in_store_filtered",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_store_id = store_id.cst_decode();
+            let api_query_text = query_text.cst_decode();
+            let api_options = options.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::semantic_search_in_store_filtered(
+                            api_store_id,
+                            api_query_text,
+                            api_options,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__set_default_model_impl(
+    model_id: impl CstDecode<String>,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::DcoCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_default_model",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let api_model_id = model_id.cst_decode();
+            transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                (move || {
+                    let output_ok = crate::api::set_default_model(api_model_id)?;
+                    Ok(output_ok)
+                })(),
+            )
+        },
+    )
+}
+fn wire__crate__api__set_model_memory_budget_impl(
+    budget_bytes: impl CstDecode<Option<u64>>,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::DcoCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_model_memory_budget",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let api_budget_bytes = budget_bytes.cst_decode();
+            transform_result_dco::<_, _, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::set_model_memory_budget(api_budget_bytes);
+                })?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__set_stream_node_position_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    id: impl CstDecode<String>,
+    x: impl CstDecode<f32>,
+    y: impl CstDecode<f32>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_stream_node_position",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_id = id.cst_decode();
+            let api_x = x.cst_decode();
+            let api_y = y.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::set_stream_node_position(api_id, api_x, api_y)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__start_chat_completion_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    messages: impl CstDecode<Vec<(String, String)>>,
+    max_tokens: impl CstDecode<Option<u32>>,
+    stop_sequences: impl CstDecode<Option<Vec<String>>>,
+    model_id: impl CstDecode<Option<String>>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "start_chat_completion",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_messages = messages.cst_decode();
+            let api_max_tokens = max_tokens.cst_decode();
+            let api_stop_sequences = stop_sequences.cst_decode();
+            let api_model_id = model_id.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::start_chat_completion(
+                            api_messages,
+                            api_max_tokens,
+                            api_stop_sequences,
+                            api_model_id,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__start_generation_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    prompt: impl CstDecode<String>,
+    max_tokens: impl CstDecode<Option<u32>>,
+    stop_sequences: impl CstDecode<Option<Vec<String>>>,
+    model_id: impl CstDecode<Option<String>>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "start_generation",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_prompt = prompt.cst_decode();
+            let api_max_tokens = max_tokens.cst_decode();
+            let api_stop_sequences = stop_sequences.cst_decode();
+            let api_model_id = model_id.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::start_generation(
+                            api_prompt,
+                            api_max_tokens,
+                            api_stop_sequences,
+                            api_model_id,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__start_graph_stream_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "start_graph_stream",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            move |context| {
+                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::start_graph_stream()?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__stop_graph_stream_impl(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::DcoCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "stop_graph_stream",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            transform_result_dco::<_, _, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::stop_graph_stream();
+                })?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__summarize_text_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    text: impl CstDecode<String>,
+    options: impl CstDecode<Option<crate::summarize::SummaryOptions>>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "summarize_text",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_text = text.cst_decode();
+            let api_options = options.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::summarize_text(api_text, api_options)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__summarize_transcript_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    segments: impl CstDecode<Vec<crate::summarize::TranscriptSegment>>,
+    options: impl CstDecode<Option<crate::summarize::SummaryOptions>>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "summarize_transcript",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_segments = segments.cst_decode();
+            let api_options = options.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::summarize_transcript(api_segments, api_options)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__tokenize_text_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    text: impl CstDecode<String>,
+    add_bos: impl CstDecode<bool>,
+    parse_special: impl CstDecode<bool>,
+    model_id: impl CstDecode<Option<String>>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "tokenize_text",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_text = text.cst_decode();
+            let api_add_bos = add_bos.cst_decode();
+            let api_parse_special = parse_special.cst_decode();
+            let api_model_id = model_id.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::tokenize_text(
+                            api_text,
+                            api_add_bos,
+                            api_parse_special,
+                            api_model_id,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__unload_embedding_model_impl(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::DcoCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "unload_embedding_model",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            transform_result_dco::<_, _, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::unload_embedding_model();
+                })?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__unload_model_impl() -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco
+{
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::DcoCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "unload_model",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            transform_result_dco::<_, _, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::unload_model();
+                })?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__unload_model_by_id_impl(
+    model_id: impl CstDecode<String>,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::DcoCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "unload_model_by_id",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let api_model_id = model_id.cst_decode();
+            transform_result_dco::<_, _, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::unload_model_by_id(api_model_id))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__update_graph_viewport_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    x: impl CstDecode<f32>,
+    y: impl CstDecode<f32>,
+    width: impl CstDecode<f32>,
+    height: impl CstDecode<f32>,
+    scale: impl CstDecode<f32>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "update_graph_viewport",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_x = x.cst_decode();
+            let api_y = y.cst_decode();
+            let api_width = width.cst_decode();
+            let api_height = height.cst_decode();
+            let api_scale = scale.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::update_graph_viewport(
+                            api_x, api_y, api_width, api_height, api_scale,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__use_llama_inference_backend_impl(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::DcoCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "use_llama_inference_backend",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            transform_result_dco::<_, _, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::use_llama_inference_backend();
+                })?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__use_mock_inference_backend_impl(
+    completions: impl CstDecode<Vec<String>>,
+    fallback: impl CstDecode<Option<String>>,
+    embedding_dimension: impl CstDecode<u32>,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::DcoCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "use_mock_inference_backend",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let api_completions = completions.cst_decode();
+            let api_fallback = fallback.cst_decode();
+            let api_embedding_dimension = embedding_dimension.cst_decode();
+            transform_result_dco::<_, _, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok({
+                    crate::api::use_mock_inference_backend(
+                        api_completions,
+                        api_fallback,
+                        api_embedding_dimension,
+                    );
+                })?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__validate_search_filter_impl(
+    filter: impl CstDecode<String>,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::DcoCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "validate_search_filter",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let api_filter = filter.cst_decode();
+            transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                (move || {
+                    let output_ok = crate::api::validate_search_filter(api_filter)?;
+                    Ok(output_ok)
+                })(),
+            )
+        },
+    )
+}
+fn wire__crate__api__vector_store_delete_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    store_id: impl CstDecode<u64>,
+    ids: impl CstDecode<Vec<String>>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "vector_store_delete",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_store_id = store_id.cst_decode();
+            let api_ids = ids.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::vector_store_delete(api_store_id, api_ids)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__vector_store_get_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    store_id: impl CstDecode<u64>,
+    ids: impl CstDecode<Vec<String>>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "vector_store_get",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_store_id = store_id.cst_decode();
+            let api_ids = ids.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::vector_store_get(api_store_id, api_ids)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__vector_store_len_impl(
+    store_id: impl CstDecode<u64>,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::DcoCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "vector_store_len",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let api_store_id = store_id.cst_decode();
+            transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                (move || {
+                    let output_ok = crate::api::vector_store_len(api_store_id)?;
+                    Ok(output_ok)
+                })(),
+            )
+        },
+    )
+}
+fn wire__crate__api__vector_store_upsert_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    store_id: impl CstDecode<u64>,
+    entries: impl CstDecode<Vec<crate::embeddings::EmbeddingEntry>>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "vector_store_upsert",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_store_id = store_id.cst_decode();
+            let api_entries = entries.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::vector_store_upsert(api_store_id, api_entries)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__wait_for_generation_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    request_id: impl CstDecode<u64>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "wait_for_generation",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_request_id = request_id.cst_decode();
+            move |context| {
+                transform_result_dco::<_, _, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::wait_for_generation(api_request_id)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+
+// Section: dart2rust
+
+impl CstDecode<crate::agent::AgentStopReason> for i32 {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::agent::AgentStopReason {
+        match self {
+            0 => crate::agent::AgentStopReason::FinalAnswer,
+            1 => crate::agent::AgentStopReason::StepLimit,
+            _ => unreachable!("Invalid variant for AgentStopReason: {}", self),
+        }
+    }
+}
+impl CstDecode<bool> for bool {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> bool {
+        self
+    }
+}
+impl CstDecode<crate::context_window::ContextStrategy> for i32 {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::context_window::ContextStrategy {
+        match self {
+            0 => crate::context_window::ContextStrategy::Error,
+            1 => crate::context_window::ContextStrategy::DropOldest,
+            2 => crate::context_window::ContextStrategy::SlidingWindow,
+            3 => crate::context_window::ContextStrategy::Summarize,
+            _ => unreachable!("Invalid variant for ContextStrategy: {}", self),
+        }
+    }
+}
+impl CstDecode<crate::embedder::EmbeddingKind> for i32 {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::embedder::EmbeddingKind {
+        match self {
+            0 => crate::embedder::EmbeddingKind::Query,
+            1 => crate::embedder::EmbeddingKind::Document,
+            _ => unreachable!("Invalid variant for EmbeddingKind: {}", self),
+        }
+    }
+}
+impl CstDecode<f32> for f32 {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> f32 {
+        self
+    }
+}
+impl CstDecode<crate::hybrid_search::FusionMode> for i32 {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::hybrid_search::FusionMode {
+        match self {
+            0 => crate::hybrid_search::FusionMode::ReciprocalRank,
+            1 => crate::hybrid_search::FusionMode::Weighted,
+            _ => unreachable!("Invalid variant for FusionMode: {}", self),
+        }
+    }
+}
+impl CstDecode<i32> for i32 {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> i32 {
+        self
+    }
+}
+impl CstDecode<i64> for i64 {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> i64 {
+        self
+    }
+}
+impl CstDecode<crate::mcp::MCPParamType> for i32 {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::mcp::MCPParamType {
+        match self {
+            0 => crate::mcp::MCPParamType::String,
+            1 => crate::mcp::MCPParamType::Boolean,
+            2 => crate::mcp::MCPParamType::Integer,
+            3 => crate::mcp::MCPParamType::Array,
+            _ => unreachable!("Invalid variant for MCPParamType: {}", self),
+        }
+    }
+}
+impl CstDecode<crate::mcp::MCPTool> for i32 {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::mcp::MCPTool {
+        match self {
+            0 => crate::mcp::MCPTool::ReadFile,
+            1 => crate::mcp::MCPTool::WriteFile,
+            2 => crate::mcp::MCPTool::DeleteFile,
+            3 => crate::mcp::MCPTool::CreateFolder,
+            4 => crate::mcp::MCPTool::ListFiles,
+            5 => crate::mcp::MCPTool::CalendarLua,
+            6 => crate::mcp::MCPTool::TimerLua,
+            7 => crate::mcp::MCPTool::ExportMarkdown,
+            _ => unreachable!("Invalid variant for MCPTool: {}", self),
+        }
+    }
+}
+impl CstDecode<crate::embedder::PoolingMode> for i32 {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::embedder::PoolingMode {
+        match self {
+            0 => crate::embedder::PoolingMode::Native,
+            1 => crate::embedder::PoolingMode::Mean,
+            2 => crate::embedder::PoolingMode::Cls,
+            3 => crate::embedder::PoolingMode::Last,
+            _ => unreachable!("Invalid variant for PoolingMode: {}", self),
+        }
+    }
+}
+impl CstDecode<crate::quantization::QuantizationKind> for i32 {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::quantization::QuantizationKind {
+        match self {
+            0 => crate::quantization::QuantizationKind::Int8,
+            1 => crate::quantization::QuantizationKind::Binary,
+            _ => unreachable!("Invalid variant for QuantizationKind: {}", self),
+        }
+    }
+}
+impl CstDecode<crate::tool_parser::ReplySpanKind> for i32 {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::tool_parser::ReplySpanKind {
+        match self {
+            0 => crate::tool_parser::ReplySpanKind::Text,
+            1 => crate::tool_parser::ReplySpanKind::ToolCall,
+            _ => unreachable!("Invalid variant for ReplySpanKind: {}", self),
+        }
+    }
+}
+impl CstDecode<crate::inference::StopReason> for i32 {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::inference::StopReason {
+        match self {
+            0 => crate::inference::StopReason::EndOfGeneration,
+            1 => crate::inference::StopReason::MaxTokens,
+            2 => crate::inference::StopReason::StopSequence,
+            3 => crate::inference::StopReason::Cancelled,
+            _ => unreachable!("Invalid variant for StopReason: {}", self),
+        }
+    }
+}
+impl CstDecode<crate::summarize::SummaryStyle> for i32 {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::summarize::SummaryStyle {
+        match self {
+            0 => crate::summarize::SummaryStyle::Bullets,
+            1 => crate::summarize::SummaryStyle::Abstract,
+            2 => crate::summarize::SummaryStyle::KeyTakeaways,
+            _ => unreachable!("Invalid variant for SummaryStyle: {}", self),
+        }
+    }
+}
+impl CstDecode<crate::mcp::TaskCategory> for i32 {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::mcp::TaskCategory {
+        match self {
+            0 => crate::mcp::TaskCategory::Conversation,
+            1 => crate::mcp::TaskCategory::ToolUse,
+            2 => crate::mcp::TaskCategory::CodeGeneration,
+            _ => unreachable!("Invalid variant for TaskCategory: {}", self),
+        }
+    }
+}
+impl CstDecode<u32> for u32 {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> u32 {
+        self
+    }
+}
+impl CstDecode<u64> for u64 {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> u64 {
+        self
+    }
+}
+impl CstDecode<u8> for u8 {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> u8 {
+        self
+    }
+}
+impl CstDecode<usize> for usize {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> usize {
+        self
+    }
+}
+impl SseDecode for flutter_rust_bridge::for_generated::anyhow::Error {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::anyhow::anyhow!("{}", inner);
+    }
+}
+
+impl SseDecode
+    for StreamSink<crate::agent::AgentStep, flutter_rust_bridge::for_generated::DcoCodec>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode
+    for StreamSink<crate::inference::GenerationChunk, flutter_rust_bridge::for_generated::DcoCodec>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode for String {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <Vec<u8>>::sse_decode(deserializer);
+        return String::from_utf8(inner).unwrap();
+    }
+}
+
+impl SseDecode for crate::agent::AgentRun {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_answer = <String>::sse_decode(deserializer);
+        let mut var_stopReason = <crate::agent::AgentStopReason>::sse_decode(deserializer);
+        let mut var_steps = <Vec<crate::agent::AgentStep>>::sse_decode(deserializer);
+        let mut var_messages = <Vec<(String, String)>>::sse_decode(deserializer);
+        return crate::agent::AgentRun {
+            answer: var_answer,
+            stop_reason: var_stopReason,
+            steps: var_steps,
+            messages: var_messages,
+        };
+    }
+}
+
+impl SseDecode for crate::agent::AgentStep {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_reply = <String>::sse_decode(deserializer);
+        let mut var_toolCalls = <Vec<crate::mcp::MCPToolCall>>::sse_decode(deserializer);
+        let mut var_results = <Vec<crate::mcp::MCPToolResult>>::sse_decode(deserializer);
+        return crate::agent::AgentStep {
+            reply: var_reply,
+            tool_calls: var_toolCalls,
+            results: var_results,
+        };
+    }
+}
+
+impl SseDecode for crate::agent::AgentStopReason {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::agent::AgentStopReason::FinalAnswer,
+            1 => crate::agent::AgentStopReason::StepLimit,
+            _ => unreachable!("Invalid variant for AgentStopReason: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for bool {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u8().unwrap() != 0
+    }
+}
+
+impl SseDecode for crate::clustering::ClusterAssignment {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <String>::sse_decode(deserializer);
+        let mut var_clusterId = <usize>::sse_decode(deserializer);
+        let mut var_color = <String>::sse_decode(deserializer);
+        return crate::clustering::ClusterAssignment {
+            id: var_id,
+            cluster_id: var_clusterId,
+            color: var_color,
+        };
+    }
+}
+
+impl SseDecode for crate::clustering::ClusterInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <usize>::sse_decode(deserializer);
+        let mut var_size = <usize>::sse_decode(deserializer);
+        let mut var_color = <String>::sse_decode(deserializer);
+        let mut var_centroid = <Option<Vec<f32>>>::sse_decode(deserializer);
+        return crate::clustering::ClusterInfo {
+            id: var_id,
+            size: var_size,
+            color: var_color,
+            centroid: var_centroid,
+        };
+    }
+}
+
+impl SseDecode for crate::clustering::ClusteringResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_assignments =
+            <Vec<crate::clustering::ClusterAssignment>>::sse_decode(deserializer);
+        let mut var_clusters = <Vec<crate::clustering::ClusterInfo>>::sse_decode(deserializer);
+        let mut var_k = <usize>::sse_decode(deserializer);
+        return crate::clustering::ClusteringResult {
+            assignments: var_assignments,
+            clusters: var_clusters,
+            k: var_k,
+        };
+    }
+}
+
+impl SseDecode for crate::context_window::ContextPolicy {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_strategy = <crate::context_window::ContextStrategy>::sse_decode(deserializer);
+        let mut var_summaryMaxTokens = <u32>::sse_decode(deserializer);
+        return crate::context_window::ContextPolicy {
+            strategy: var_strategy,
+            summary_max_tokens: var_summaryMaxTokens,
+        };
+    }
+}
+
+impl SseDecode for crate::context_window::ContextReport {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_contextSize = <u32>::sse_decode(deserializer);
+        let mut var_promptTokens = <u32>::sse_decode(deserializer);
+        let mut var_messageTokens = <Vec<u32>>::sse_decode(deserializer);
+        let mut var_dropped = <Vec<u32>>::sse_decode(deserializer);
+        let mut var_truncated = <Vec<u32>>::sse_decode(deserializer);
+        let mut var_summarized = <Vec<u32>>::sse_decode(deserializer);
+        let mut var_summary = <Option<String>>::sse_decode(deserializer);
+        return crate::context_window::ContextReport {
+            context_size: var_contextSize,
+            prompt_tokens: var_promptTokens,
+            message_tokens: var_messageTokens,
+            dropped: var_dropped,
+            truncated: var_truncated,
+            summarized: var_summarized,
+            summary: var_summary,
+        };
+    }
+}
+
+impl SseDecode for crate::context_window::ContextStrategy {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::context_window::ContextStrategy::Error,
+            1 => crate::context_window::ContextStrategy::DropOldest,
+            2 => crate::context_window::ContextStrategy::SlidingWindow,
+            3 => crate::context_window::ContextStrategy::Summarize,
+            _ => unreachable!("Invalid variant for ContextStrategy: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::dedup::DedupDocument {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <String>::sse_decode(deserializer);
+        let mut var_text = <String>::sse_decode(deserializer);
+        let mut var_vector = <Option<Vec<f32>>>::sse_decode(deserializer);
+        let mut var_createdAt = <Option<i64>>::sse_decode(deserializer);
+        return crate::dedup::DedupDocument {
+            id: var_id,
+            text: var_text,
+            vector: var_vector,
+            created_at: var_createdAt,
+        };
+    }
+}
+
+impl SseDecode for crate::dedup::DedupOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_shingleWords = <usize>::sse_decode(deserializer);
+        let mut var_numHashes = <usize>::sse_decode(deserializer);
+        let mut var_bands = <usize>::sse_decode(deserializer);
+        let mut var_minJaccard = <f32>::sse_decode(deserializer);
+        let mut var_minCosine = <f32>::sse_decode(deserializer);
+        let mut var_minSpanWords = <usize>::sse_decode(deserializer);
+        return crate::dedup::DedupOptions {
+            shingle_words: var_shingleWords,
+            num_hashes: var_numHashes,
+            bands: var_bands,
+            min_jaccard: var_minJaccard,
+            min_cosine: var_minCosine,
+            min_span_words: var_minSpanWords,
+        };
+    }
+}
+
+impl SseDecode for crate::dedup::DuplicateGroup {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_ids = <Vec<String>>::sse_decode(deserializer);
+        let mut var_canonical = <String>::sse_decode(deserializer);
+        let mut var_canonicalNote = <String>::sse_decode(deserializer);
+        let mut var_pairs = <Vec<crate::dedup::DuplicatePair>>::sse_decode(deserializer);
+        return crate::dedup::DuplicateGroup {
+            ids: var_ids,
+            canonical: var_canonical,
+            canonical_note: var_canonicalNote,
+            pairs: var_pairs,
+        };
+    }
+}
+
+impl SseDecode for crate::dedup::DuplicatePair {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_a = <String>::sse_decode(deserializer);
+        let mut var_b = <String>::sse_decode(deserializer);
+        let mut var_jaccard = <f32>::sse_decode(deserializer);
+        let mut var_cosine = <Option<f32>>::sse_decode(deserializer);
+        let mut var_spans = <Vec<crate::dedup::DuplicateSpan>>::sse_decode(deserializer);
+        return crate::dedup::DuplicatePair {
+            a: var_a,
+            b: var_b,
+            jaccard: var_jaccard,
+            cosine: var_cosine,
+            spans: var_spans,
+        };
+    }
+}
+
+impl SseDecode for crate::dedup::DuplicateSpan {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_aStart = <usize>::sse_decode(deserializer);
+        let mut var_aEnd = <usize>::sse_decode(deserializer);
+        let mut var_bStart = <usize>::sse_decode(deserializer);
+        let mut var_bEnd = <usize>::sse_decode(deserializer);
+        let mut var_words = <usize>::sse_decode(deserializer);
+        return crate::dedup::DuplicateSpan {
+            a_start: var_aStart,
+            a_end: var_aEnd,
+            b_start: var_bStart,
+            b_end: var_bEnd,
+            words: var_words,
+        };
+    }
+}
+
+impl SseDecode for crate::embedding_cache::EmbeddingCacheStats {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_entries = <usize>::sse_decode(deserializer);
+        let mut var_staleRecords = <usize>::sse_decode(deserializer);
+        let mut var_fileBytes = <u64>::sse_decode(deserializer);
+        return crate::embedding_cache::EmbeddingCacheStats {
+            entries: var_entries,
+            stale_records: var_staleRecords,
+            file_bytes: var_fileBytes,
+        };
+    }
+}
+
+impl SseDecode for crate::api::EmbeddingCluster {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_ids = <Vec<String>>::sse_decode(deserializer);
+        return crate::api::EmbeddingCluster { ids: var_ids };
+    }
+}
+
+impl SseDecode for crate::embedder::EmbeddingConfig {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_nGpuLayers = <u32>::sse_decode(deserializer);
+        let mut var_nThreads = <i32>::sse_decode(deserializer);
+        let mut var_pooling = <crate::embedder::PoolingMode>::sse_decode(deserializer);
+        let mut var_chunkTokens = <u32>::sse_decode(deserializer);
+        let mut var_chunkOverlap = <u32>::sse_decode(deserializer);
+        let mut var_maxSequences = <u32>::sse_decode(deserializer);
+        let mut var_queryPrefix = <String>::sse_decode(deserializer);
+        let mut var_documentPrefix = <String>::sse_decode(deserializer);
+        return crate::embedder::EmbeddingConfig {
+            n_gpu_layers: var_nGpuLayers,
+            n_threads: var_nThreads,
+            pooling: var_pooling,
+            chunk_tokens: var_chunkTokens,
+            chunk_overlap: var_chunkOverlap,
+            max_sequences: var_maxSequences,
+            query_prefix: var_queryPrefix,
+            document_prefix: var_documentPrefix,
+        };
+    }
+}
+
+impl SseDecode for crate::embeddings::EmbeddingEntry {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <String>::sse_decode(deserializer);
+        let mut var_vector = <Vec<f32>>::sse_decode(deserializer);
+        let mut var_textPreview = <Option<String>>::sse_decode(deserializer);
+        let mut var_metadata = <crate::embeddings::NoteMetadata>::sse_decode(deserializer);
+        return crate::embeddings::EmbeddingEntry {
+            id: var_id,
+            vector: var_vector,
+            text_preview: var_textPreview,
+            metadata: var_metadata,
+        };
+    }
+}
+
+impl SseDecode for crate::embedder::EmbeddingKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::embedder::EmbeddingKind::Query,
+            1 => crate::embedder::EmbeddingKind::Document,
+            _ => unreachable!("Invalid variant for EmbeddingKind: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for f32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_f32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for crate::hybrid_search::FusionMode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::hybrid_search::FusionMode::ReciprocalRank,
+            1 => crate::hybrid_search::FusionMode::Weighted,
+            _ => unreachable!("Invalid variant for FusionMode: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::inference::GenerationChunk {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_text = <String>::sse_decode(deserializer);
+        let mut var_reasoning = <String>::sse_decode(deserializer);
+        let mut var_stopReason = <Option<crate::inference::StopReason>>::sse_decode(deserializer);
+        return crate::inference::GenerationChunk {
+            text: var_text,
+            reasoning: var_reasoning,
+            stop_reason: var_stopReason,
+        };
+    }
+}
+
+impl SseDecode for crate::inference::GenerationOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_maxTokens = <Option<u32>>::sse_decode(deserializer);
+        let mut var_stopSequences = <Vec<String>>::sse_decode(deserializer);
+        let mut var_sampler = <Option<crate::inference::SamplerConfig>>::sse_decode(deserializer);
+        let mut var_grammar = <Option<String>>::sse_decode(deserializer);
+        let mut var_jsonSchema = <Option<String>>::sse_decode(deserializer);
+        let mut var_modelId = <Option<String>>::sse_decode(deserializer);
+        let mut var_context =
+            <Option<crate::context_window::ContextPolicy>>::sse_decode(deserializer);
+        let mut var_reasoning =
+            <Option<crate::reasoning::ReasoningOptions>>::sse_decode(deserializer);
+        let mut var_logprobs = <Option<u32>>::sse_decode(deserializer);
+        return crate::inference::GenerationOptions {
+            max_tokens: var_maxTokens,
+            stop_sequences: var_stopSequences,
+            sampler: var_sampler,
+            grammar: var_grammar,
+            json_schema: var_jsonSchema,
+            model_id: var_modelId,
+            context: var_context,
+            reasoning: var_reasoning,
+            logprobs: var_logprobs,
+        };
+    }
+}
+
+impl SseDecode for crate::inference::GenerationPoll {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_text = <String>::sse_decode(deserializer);
+        let mut var_reasoning = <String>::sse_decode(deserializer);
+        let mut var_result = <Option<crate::inference::GenerationResult>>::sse_decode(deserializer);
+        return crate::inference::GenerationPoll {
+            text: var_text,
+            reasoning: var_reasoning,
+            result: var_result,
+        };
+    }
+}
+
+impl SseDecode for crate::inference::GenerationResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_text = <String>::sse_decode(deserializer);
+        let mut var_reasoning = <Option<String>>::sse_decode(deserializer);
+        let mut var_reasoningTokens = <u32>::sse_decode(deserializer);
+        let mut var_stopReason = <crate::inference::StopReason>::sse_decode(deserializer);
+        let mut var_tokensGenerated = <u32>::sse_decode(deserializer);
+        let mut var_context =
+            <Option<crate::context_window::ContextReport>>::sse_decode(deserializer);
+        let mut var_logprobs =
+            <Option<Vec<crate::inference::TokenLogprob>>>::sse_decode(deserializer);
+        return crate::inference::GenerationResult {
+            text: var_text,
+            reasoning: var_reasoning,
+            reasoning_tokens: var_reasoningTokens,
+            stop_reason: var_stopReason,
+            tokens_generated: var_tokensGenerated,
+            context: var_context,
+            logprobs: var_logprobs,
+        };
+    }
+}
+
+impl SseDecode for crate::gguf::GgufMetadata {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_version = <u32>::sse_decode(deserializer);
+        let mut var_architecture = <Option<String>>::sse_decode(deserializer);
+        let mut var_name = <Option<String>>::sse_decode(deserializer);
+        let mut var_chatTemplate = <Option<String>>::sse_decode(deserializer);
+        let mut var_contextLength = <Option<u64>>::sse_decode(deserializer);
+        let mut var_embeddingLength = <Option<u64>>::sse_decode(deserializer);
+        let mut var_blockCount = <Option<u64>>::sse_decode(deserializer);
+        let mut var_poolingType = <Option<u32>>::sse_decode(deserializer);
+        let mut var_quantization = <Option<String>>::sse_decode(deserializer);
+        let mut var_parameterCount = <u64>::sse_decode(deserializer);
+        let mut var_tensorCount = <u64>::sse_decode(deserializer);
+        let mut var_tokenizerModel = <Option<String>>::sse_decode(deserializer);
+        let mut var_vocabSize = <Option<u64>>::sse_decode(deserializer);
+        let mut var_bosTokenId = <Option<u32>>::sse_decode(deserializer);
+        let mut var_eosTokenId = <Option<u32>>::sse_decode(deserializer);
+        let mut var_fileSize = <u64>::sse_decode(deserializer);
+        return crate::gguf::GgufMetadata {
+            version: var_version,
+            architecture: var_architecture,
+            name: var_name,
+            chat_template: var_chatTemplate,
+            context_length: var_contextLength,
+            embedding_length: var_embeddingLength,
+            block_count: var_blockCount,
+            pooling_type: var_poolingType,
+            quantization: var_quantization,
+            parameter_count: var_parameterCount,
+            tensor_count: var_tensorCount,
+            tokenizer_model: var_tokenizerModel,
+            vocab_size: var_vocabSize,
+            bos_token_id: var_bosTokenId,
+            eos_token_id: var_eosTokenId,
+            file_size: var_fileSize,
+        };
+    }
+}
+
+impl SseDecode for crate::graph::GraphEdge {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_source = <String>::sse_decode(deserializer);
+        let mut var_target = <String>::sse_decode(deserializer);
+        let mut var_weight = <f32>::sse_decode(deserializer);
+        let mut var_edgeType = <String>::sse_decode(deserializer);
+        return crate::graph::GraphEdge {
+            source: var_source,
+            target: var_target,
+            weight: var_weight,
+            edge_type: var_edgeType,
+        };
+    }
+}
+
+impl SseDecode for crate::graph::GraphNode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <String>::sse_decode(deserializer);
+        let mut var_label = <String>::sse_decode(deserializer);
+        let mut var_nodeType = <String>::sse_decode(deserializer);
+        let mut var_x = <f32>::sse_decode(deserializer);
+        let mut var_y = <f32>::sse_decode(deserializer);
+        let mut var_color = <Option<String>>::sse_decode(deserializer);
+        let mut var_metadata = <Option<String>>::sse_decode(deserializer);
+        return crate::graph::GraphNode {
+            id: var_id,
+            label: var_label,
+            node_type: var_nodeType,
+            x: var_x,
+            y: var_y,
+            color: var_color,
+            metadata: var_metadata,
+        };
+    }
+}
+
+impl SseDecode for crate::graph::GraphState {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_nodes = <Vec<crate::graph::GraphNode>>::sse_decode(deserializer);
+        let mut var_edges = <Vec<crate::graph::GraphEdge>>::sse_decode(deserializer);
+        return crate::graph::GraphState {
+            nodes: var_nodes,
+            edges: var_edges,
+        };
+    }
+}
+
+impl SseDecode for crate::hnsw::HnswConfig {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_m = <u32>::sse_decode(deserializer);
+        let mut var_efConstruction = <u32>::sse_decode(deserializer);
+        let mut var_efSearch = <u32>::sse_decode(deserializer);
+        return crate::hnsw::HnswConfig {
+            m: var_m,
+            ef_construction: var_efConstruction,
+            ef_search: var_efSearch,
+        };
+    }
+}
+
+impl SseDecode for crate::hybrid_search::HybridHit {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <String>::sse_decode(deserializer);
+        let mut var_score = <f32>::sse_decode(deserializer);
+        let mut var_semanticScore = <Option<f32>>::sse_decode(deserializer);
+        let mut var_semanticRank = <Option<u32>>::sse_decode(deserializer);
+        let mut var_lexicalScore = <Option<f32>>::sse_decode(deserializer);
+        let mut var_lexicalRank = <Option<u32>>::sse_decode(deserializer);
+        let mut var_matchedTerms = <Vec<String>>::sse_decode(deserializer);
+        let mut var_textPreview = <Option<String>>::sse_decode(deserializer);
+        return crate::hybrid_search::HybridHit {
+            id: var_id,
+            score: var_score,
+            semantic_score: var_semanticScore,
+            semantic_rank: var_semanticRank,
+            lexical_score: var_lexicalScore,
+            lexical_rank: var_lexicalRank,
+            matched_terms: var_matchedTerms,
+            text_preview: var_textPreview,
+        };
+    }
+}
+
+impl SseDecode for crate::hybrid_search::HybridOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_fusion = <crate::hybrid_search::FusionMode>::sse_decode(deserializer);
+        let mut var_semanticWeight = <f32>::sse_decode(deserializer);
+        let mut var_rrfK = <f32>::sse_decode(deserializer);
+        let mut var_candidates = <usize>::sse_decode(deserializer);
+        let mut var_minSemanticScore = <f32>::sse_decode(deserializer);
+        return crate::hybrid_search::HybridOptions {
+            fusion: var_fusion,
+            semantic_weight: var_semanticWeight,
+            rrf_k: var_rrfK,
+            candidates: var_candidates,
+            min_semantic_score: var_minSemanticScore,
+        };
+    }
+}
+
+impl SseDecode for i32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for i64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_i64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for crate::api::KnowledgeGraphAnalysis {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_clustering = <crate::clustering::ClusteringResult>::sse_decode(deserializer);
+        let mut var_semanticEdges =
+            <crate::clustering::SemanticEdgeResult>::sse_decode(deserializer);
+        return crate::api::KnowledgeGraphAnalysis {
+            clustering: var_clustering,
+            semantic_edges: var_semanticEdges,
+        };
+    }
+}
+
+impl SseDecode for Vec<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<String>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::agent::AgentStep> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::agent::AgentStep>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::clustering::ClusterAssignment> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::clustering::ClusterAssignment>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::clustering::ClusterInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::clustering::ClusterInfo>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::dedup::DedupDocument> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::dedup::DedupDocument>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::dedup::DuplicateGroup> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::dedup::DuplicateGroup>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::dedup::DuplicatePair> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::dedup::DuplicatePair>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::dedup::DuplicateSpan> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::dedup::DuplicateSpan>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::EmbeddingCluster> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::EmbeddingCluster>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::embeddings::EmbeddingEntry> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::embeddings::EmbeddingEntry>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::graph::GraphEdge> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::graph::GraphEdge>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::graph::GraphNode> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::graph::GraphNode>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::hybrid_search::HybridHit> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::hybrid_search::HybridHit>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<Vec<f32>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<Vec<f32>>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::inference::LoadedModelInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::inference::LoadedModelInfo>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::chunker::MarkdownChunk> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::chunker::MarkdownChunk>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::mcp::MCPParameter> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::mcp::MCPParameter>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::mcp::MCPTool> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::mcp::MCPTool>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::mcp::MCPToolCall> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::mcp::MCPToolCall>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::mcp::MCPToolResult> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::mcp::MCPToolResult>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::streaming::NodePosition> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::streaming::NodePosition>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<f32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<f32>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<i32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<i32>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<u32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<u32>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<u8> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<u8>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::rag::RagSource> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::rag::RagSource>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<(String, String)> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<(String, String)>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::tool_parser::ReplySpan> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::tool_parser::ReplySpan>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::clustering::SemanticEdge> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::clustering::SemanticEdge>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::embeddings::SimilarityResult> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::embeddings::SimilarityResult>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::inference::TokenCandidate> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::inference::TokenCandidate>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::inference::TokenLogprob> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::inference::TokenLogprob>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::summarize::TranscriptSegment> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::summarize::TranscriptSegment>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for crate::inference::LoadedModelInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_modelId = <String>::sse_decode(deserializer);
+        let mut var_modelPath = <String>::sse_decode(deserializer);
+        let mut var_sizeBytes = <u64>::sse_decode(deserializer);
+        let mut var_isDefault = <bool>::sse_decode(deserializer);
+        return crate::inference::LoadedModelInfo {
+            model_id: var_modelId,
+            model_path: var_modelPath,
+            size_bytes: var_sizeBytes,
+            is_default: var_isDefault,
+        };
+    }
+}
+
+impl SseDecode for crate::chunker::MarkdownChunk {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_index = <usize>::sse_decode(deserializer);
+        let mut var_headingPath = <Vec<String>>::sse_decode(deserializer);
+        let mut var_start = <usize>::sse_decode(deserializer);
+        let mut var_end = <usize>::sse_decode(deserializer);
+        let mut var_text = <String>::sse_decode(deserializer);
+        let mut var_tokenCount = <u32>::sse_decode(deserializer);
+        return crate::chunker::MarkdownChunk {
+            index: var_index,
+            heading_path: var_headingPath,
+            start: var_start,
+            end: var_end,
+            text: var_text,
+            token_count: var_tokenCount,
+        };
+    }
+}
+
+impl SseDecode for crate::mcp::MCPParamType {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::mcp::MCPParamType::String,
+            1 => crate::mcp::MCPParamType::Boolean,
+            2 => crate::mcp::MCPParamType::Integer,
+            3 => crate::mcp::MCPParamType::Array,
+            _ => unreachable!("Invalid variant for MCPParamType: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::mcp::MCPParameter {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_description = <String>::sse_decode(deserializer);
+        let mut var_paramType = <crate::mcp::MCPParamType>::sse_decode(deserializer);
+        let mut var_required_ = <bool>::sse_decode(deserializer);
+        return crate::mcp::MCPParameter {
+            name: var_name,
+            description: var_description,
+            param_type: var_paramType,
+            required: var_required_,
+        };
+    }
+}
+
+impl SseDecode for crate::mcp::MCPTool {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::mcp::MCPTool::ReadFile,
+            1 => crate::mcp::MCPTool::WriteFile,
+            2 => crate::mcp::MCPTool::DeleteFile,
+            3 => crate::mcp::MCPTool::CreateFolder,
+            4 => crate::mcp::MCPTool::ListFiles,
+            5 => crate::mcp::MCPTool::CalendarLua,
+            6 => crate::mcp::MCPTool::TimerLua,
+            7 => crate::mcp::MCPTool::ExportMarkdown,
+            _ => unreachable!("Invalid variant for MCPTool: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::mcp::MCPToolCall {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_tool = <String>::sse_decode(deserializer);
+        let mut var_parametersJson = <String>::sse_decode(deserializer);
+        let mut var_description = <String>::sse_decode(deserializer);
+        return crate::mcp::MCPToolCall {
+            tool: var_tool,
+            parameters_json: var_parametersJson,
+            description: var_description,
+        };
+    }
+}
+
+impl SseDecode for crate::mcp::MCPToolResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_success = <bool>::sse_decode(deserializer);
+        let mut var_result = <String>::sse_decode(deserializer);
+        let mut var_tool = <String>::sse_decode(deserializer);
+        return crate::mcp::MCPToolResult {
+            success: var_success,
+            result: var_result,
+            tool: var_tool,
+        };
+    }
+}
+
+impl SseDecode for crate::streaming::NodePosition {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <String>::sse_decode(deserializer);
+        let mut var_x = <f32>::sse_decode(deserializer);
+        let mut var_y = <f32>::sse_decode(deserializer);
+        let mut var_radius = <f32>::sse_decode(deserializer);
+        let mut var_color = <u32>::sse_decode(deserializer);
+        let mut var_nodeType = <String>::sse_decode(deserializer);
+        return crate::streaming::NodePosition {
+            id: var_id,
+            x: var_x,
+            y: var_y,
+            radius: var_radius,
+            color: var_color,
+            node_type: var_nodeType,
+        };
+    }
+}
+
+impl SseDecode for crate::embeddings::NoteMetadata {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_folder = <Option<String>>::sse_decode(deserializer);
+        let mut var_tags = <Vec<String>>::sse_decode(deserializer);
+        let mut var_createdAt = <Option<i64>>::sse_decode(deserializer);
+        let mut var_modifiedAt = <Option<i64>>::sse_decode(deserializer);
+        let mut var_noteType = <Option<String>>::sse_decode(deserializer);
+        let mut var_chunkStart = <Option<usize>>::sse_decode(deserializer);
+        let mut var_chunkEnd = <Option<usize>>::sse_decode(deserializer);
+        return crate::embeddings::NoteMetadata {
+            folder: var_folder,
+            tags: var_tags,
+            created_at: var_createdAt,
+            modified_at: var_modifiedAt,
+            note_type: var_noteType,
+            chunk_start: var_chunkStart,
+            chunk_end: var_chunkEnd,
+        };
+    }
+}
+
+impl SseDecode for Option<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<String>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::context_window::ContextPolicy> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::context_window::ContextPolicy>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::context_window::ContextReport> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::context_window::ContextReport>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::dedup::DedupOptions> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::dedup::DedupOptions>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::embedding_cache::EmbeddingCacheStats> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::embedding_cache::EmbeddingCacheStats>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::embedder::EmbeddingConfig> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::embedder::EmbeddingConfig>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<f32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<f32>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::inference::GenerationResult> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::inference::GenerationResult>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::hnsw::HnswConfig> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::hnsw::HnswConfig>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::hybrid_search::HybridOptions> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::hybrid_search::HybridOptions>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<i32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<i32>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<i64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<i64>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::embeddings::NoteMetadata> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::embeddings::NoteMetadata>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::reasoning::ReasoningOptions> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::reasoning::ReasoningOptions>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::inference::SamplerConfig> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::inference::SamplerConfig>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::inference::StopReason> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::inference::StopReason>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::summarize::SummaryOptions> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::summarize::SummaryOptions>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<u32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u32>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<u64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u64>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<usize> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<usize>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<Vec<String>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<Vec<String>>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<Vec<Vec<f32>>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<Vec<Vec<f32>>>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<Vec<f32>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<Vec<f32>>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<Vec<(String, String)>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<Vec<(String, String)>>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<Vec<crate::inference::TokenLogprob>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<Vec<crate::inference::TokenLogprob>>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for crate::tool_parser::ParsedReply {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_toolCalls = <Vec<crate::mcp::MCPToolCall>>::sse_decode(deserializer);
+        let mut var_spans = <Vec<crate::tool_parser::ReplySpan>>::sse_decode(deserializer);
+        let mut var_text = <String>::sse_decode(deserializer);
+        return crate::tool_parser::ParsedReply {
+            tool_calls: var_toolCalls,
+            spans: var_spans,
+            text: var_text,
+        };
+    }
+}
+
+impl SseDecode for crate::embedder::PoolingMode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::embedder::PoolingMode::Native,
+            1 => crate::embedder::PoolingMode::Mean,
+            2 => crate::embedder::PoolingMode::Cls,
+            3 => crate::embedder::PoolingMode::Last,
+            _ => unreachable!("Invalid variant for PoolingMode: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::quantization::QuantizationKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::quantization::QuantizationKind::Int8,
+            1 => crate::quantization::QuantizationKind::Binary,
+            _ => unreachable!("Invalid variant for QuantizationKind: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::rag::RagAnswer {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_answer = <String>::sse_decode(deserializer);
+        let mut var_sources = <Vec<crate::rag::RagSource>>::sse_decode(deserializer);
+        let mut var_skipped = <usize>::sse_decode(deserializer);
+        return crate::rag::RagAnswer {
+            answer: var_answer,
+            sources: var_sources,
+            skipped: var_skipped,
+        };
+    }
+}
+
+impl SseDecode for crate::rag::RagSource {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_citation = <u32>::sse_decode(deserializer);
+        let mut var_noteId = <String>::sse_decode(deserializer);
+        let mut var_chunkOffset = <Option<usize>>::sse_decode(deserializer);
+        let mut var_chunkIndex = <Option<usize>>::sse_decode(deserializer);
+        let mut var_score = <f32>::sse_decode(deserializer);
+        let mut var_text = <String>::sse_decode(deserializer);
+        let mut var_cited = <bool>::sse_decode(deserializer);
+        return crate::rag::RagSource {
+            citation: var_citation,
+            note_id: var_noteId,
+            chunk_offset: var_chunkOffset,
+            chunk_index: var_chunkIndex,
+            score: var_score,
+            text: var_text,
+            cited: var_cited,
+        };
+    }
+}
+
+impl SseDecode for crate::reasoning::ReasoningOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_enabled = <bool>::sse_decode(deserializer);
+        let mut var_openTag = <String>::sse_decode(deserializer);
+        let mut var_closeTag = <String>::sse_decode(deserializer);
+        let mut var_thinkingBudget = <Option<u32>>::sse_decode(deserializer);
+        return crate::reasoning::ReasoningOptions {
+            enabled: var_enabled,
+            open_tag: var_openTag,
+            close_tag: var_closeTag,
+            thinking_budget: var_thinkingBudget,
+        };
+    }
+}
+
+impl SseDecode for crate::quantization::RecallReport {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_queries = <usize>::sse_decode(deserializer);
+        let mut var_topK = <usize>::sse_decode(deserializer);
+        let mut var_recall = <f32>::sse_decode(deserializer);
+        let mut var_rescoredRecall = <f32>::sse_decode(deserializer);
+        let mut var_f32Bytes = <usize>::sse_decode(deserializer);
+        let mut var_quantizedBytes = <usize>::sse_decode(deserializer);
+        return crate::quantization::RecallReport {
+            queries: var_queries,
+            top_k: var_topK,
+            recall: var_recall,
+            rescored_recall: var_rescoredRecall,
+            f32_bytes: var_f32Bytes,
+            quantized_bytes: var_quantizedBytes,
+        };
+    }
+}
+
+impl SseDecode for (String, String) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_field0 = <String>::sse_decode(deserializer);
+        let mut var_field1 = <String>::sse_decode(deserializer);
+        return (var_field0, var_field1);
+    }
+}
+
+impl SseDecode for crate::tool_parser::ReplySpan {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_start = <usize>::sse_decode(deserializer);
+        let mut var_end = <usize>::sse_decode(deserializer);
+        let mut var_kind = <crate::tool_parser::ReplySpanKind>::sse_decode(deserializer);
+        let mut var_callCount = <usize>::sse_decode(deserializer);
+        return crate::tool_parser::ReplySpan {
+            start: var_start,
+            end: var_end,
+            kind: var_kind,
+            call_count: var_callCount,
+        };
+    }
+}
+
+impl SseDecode for crate::tool_parser::ReplySpanKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::tool_parser::ReplySpanKind::Text,
+            1 => crate::tool_parser::ReplySpanKind::ToolCall,
+            _ => unreachable!("Invalid variant for ReplySpanKind: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::inference::SamplerConfig {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_temperature = <f32>::sse_decode(deserializer);
+        let mut var_topK = <i32>::sse_decode(deserializer);
+        let mut var_topP = <f32>::sse_decode(deserializer);
+        let mut var_minP = <f32>::sse_decode(deserializer);
+        let mut var_repeatPenalty = <f32>::sse_decode(deserializer);
+        let mut var_frequencyPenalty = <f32>::sse_decode(deserializer);
+        let mut var_presencePenalty = <f32>::sse_decode(deserializer);
+        let mut var_penaltyLastN = <i32>::sse_decode(deserializer);
+        let mut var_seed = <Option<u32>>::sse_decode(deserializer);
+        let mut var_greedy = <bool>::sse_decode(deserializer);
+        return crate::inference::SamplerConfig {
+            temperature: var_temperature,
+            top_k: var_topK,
+            top_p: var_topP,
+            min_p: var_minP,
+            repeat_penalty: var_repeatPenalty,
+            frequency_penalty: var_frequencyPenalty,
+            presence_penalty: var_presencePenalty,
+            penalty_last_n: var_penaltyLastN,
+            seed: var_seed,
+            greedy: var_greedy,
+        };
+    }
+}
+
+impl SseDecode for crate::filtered_search::SearchOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_topK = <usize>::sse_decode(deserializer);
+        let mut var_threshold = <f32>::sse_decode(deserializer);
+        let mut var_filter = <Option<String>>::sse_decode(deserializer);
+        let mut var_mmrLambda = <Option<f32>>::sse_decode(deserializer);
+        let mut var_mmrCandidates = <usize>::sse_decode(deserializer);
+        return crate::filtered_search::SearchOptions {
+            top_k: var_topK,
+            threshold: var_threshold,
+            filter: var_filter,
+            mmr_lambda: var_mmrLambda,
+            mmr_candidates: var_mmrCandidates,
+        };
+    }
+}
+
+impl SseDecode for crate::clustering::SemanticEdge {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_source = <String>::sse_decode(deserializer);
+        let mut var_target = <String>::sse_decode(deserializer);
+        let mut var_similarity = <f32>::sse_decode(deserializer);
+        let mut var_isGhost = <bool>::sse_decode(deserializer);
+        return crate::clustering::SemanticEdge {
+            source: var_source,
+            target: var_target,
+            similarity: var_similarity,
+            is_ghost: var_isGhost,
+        };
+    }
+}
+
+impl SseDecode for crate::clustering::SemanticEdgeResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_edges = <Vec<crate::clustering::SemanticEdge>>::sse_decode(deserializer);
+        let mut var_count = <usize>::sse_decode(deserializer);
+        return crate::clustering::SemanticEdgeResult {
+            edges: var_edges,
+            count: var_count,
+        };
+    }
+}
+
+impl SseDecode for crate::embeddings::SimilarityResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <String>::sse_decode(deserializer);
+        let mut var_score = <f32>::sse_decode(deserializer);
+        let mut var_textPreview = <Option<String>>::sse_decode(deserializer);
+        return crate::embeddings::SimilarityResult {
+            id: var_id,
+            score: var_score,
+            text_preview: var_textPreview,
+        };
+    }
+}
+
+impl SseDecode for crate::inference::StopReason {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::inference::StopReason::EndOfGeneration,
+            1 => crate::inference::StopReason::MaxTokens,
+            2 => crate::inference::StopReason::StopSequence,
+            3 => crate::inference::StopReason::Cancelled,
+            _ => unreachable!("Invalid variant for StopReason: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::StreamGraphStats {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_nodeCount = <usize>::sse_decode(deserializer);
+        let mut var_edgeCount = <usize>::sse_decode(deserializer);
+        let mut var_visibleCount = <usize>::sse_decode(deserializer);
+        return crate::api::StreamGraphStats {
+            node_count: var_nodeCount,
+            edge_count: var_edgeCount,
+            visible_count: var_visibleCount,
+        };
+    }
+}
+
+impl SseDecode for crate::summarize::Summary {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_summary = <String>::sse_decode(deserializer);
+        let mut var_chunkSummaries = <Vec<String>>::sse_decode(deserializer);
+        let mut var_mergeLevels = <u32>::sse_decode(deserializer);
+        return crate::summarize::Summary {
+            summary: var_summary,
+            chunk_summaries: var_chunkSummaries,
+            merge_levels: var_mergeLevels,
+        };
+    }
+}
+
+impl SseDecode for crate::summarize::SummaryOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_style = <crate::summarize::SummaryStyle>::sse_decode(deserializer);
+        let mut var_maxWords = <u32>::sse_decode(deserializer);
+        let mut var_chunkTokens = <u32>::sse_decode(deserializer);
+        let mut var_modelId = <Option<String>>::sse_decode(deserializer);
+        return crate::summarize::SummaryOptions {
+            style: var_style,
+            max_words: var_maxWords,
+            chunk_tokens: var_chunkTokens,
+            model_id: var_modelId,
+        };
+    }
+}
+
+impl SseDecode for crate::summarize::SummaryStyle {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::summarize::SummaryStyle::Bullets,
+            1 => crate::summarize::SummaryStyle::Abstract,
+            2 => crate::summarize::SummaryStyle::KeyTakeaways,
+            _ => unreachable!("Invalid variant for SummaryStyle: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::mcp::TaskCategory {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::mcp::TaskCategory::Conversation,
+            1 => crate::mcp::TaskCategory::ToolUse,
+            2 => crate::mcp::TaskCategory::CodeGeneration,
+            _ => unreachable!("Invalid variant for TaskCategory: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::inference::TokenCandidate {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_token = <i32>::sse_decode(deserializer);
+        let mut var_text = <String>::sse_decode(deserializer);
+        let mut var_logprob = <f32>::sse_decode(deserializer);
+        return crate::inference::TokenCandidate {
+            token: var_token,
+            text: var_text,
+            logprob: var_logprob,
+        };
+    }
+}
+
+impl SseDecode for crate::inference::TokenLogprob {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_token = <i32>::sse_decode(deserializer);
+        let mut var_text = <String>::sse_decode(deserializer);
+        let mut var_logprob = <f32>::sse_decode(deserializer);
+        let mut var_topAlternatives =
+            <Vec<crate::inference::TokenCandidate>>::sse_decode(deserializer);
+        return crate::inference::TokenLogprob {
+            token: var_token,
+            text: var_text,
+            logprob: var_logprob,
+            top_alternatives: var_topAlternatives,
+        };
+    }
+}
+
+impl SseDecode for crate::tokenizer::TokenizerInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_vocabSize = <u32>::sse_decode(deserializer);
+        let mut var_nCtxTrain = <u32>::sse_decode(deserializer);
+        let mut var_nCtx = <u32>::sse_decode(deserializer);
+        let mut var_bosToken = <i32>::sse_decode(deserializer);
+        let mut var_eosToken = <i32>::sse_decode(deserializer);
+        let mut var_tokenizerModel = <Option<String>>::sse_decode(deserializer);
+        return crate::tokenizer::TokenizerInfo {
+            vocab_size: var_vocabSize,
+            n_ctx_train: var_nCtxTrain,
+            n_ctx: var_nCtx,
+            bos_token: var_bosToken,
+            eos_token: var_eosToken,
+            tokenizer_model: var_tokenizerModel,
+        };
+    }
+}
+
+impl SseDecode for crate::summarize::TranscriptSegment {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_text = <String>::sse_decode(deserializer);
+        let mut var_startTime = <f32>::sse_decode(deserializer);
+        let mut var_endTime = <f32>::sse_decode(deserializer);
+        return crate::summarize::TranscriptSegment {
+            text: var_text,
+            start_time: var_startTime,
+            end_time: var_endTime,
+        };
+    }
+}
+
+impl SseDecode for u32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u8 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u8().unwrap()
+    }
+}
+
+impl SseDecode for () {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
+}
+
+impl SseDecode for usize {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u64::<NativeEndian>().unwrap() as _
+    }
+}
+
+impl SseDecode for crate::vector_store::VectorStoreStats {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_entries = <usize>::sse_decode(deserializer);
+        let mut var_deadRows = <usize>::sse_decode(deserializer);
+        let mut var_dimension = <usize>::sse_decode(deserializer);
+        let mut var_fileBytes = <u64>::sse_decode(deserializer);
+        return crate::vector_store::VectorStoreStats {
+            entries: var_entries,
+            dead_rows: var_deadRows,
+            dimension: var_dimension,
+            file_bytes: var_fileBytes,
+        };
+    }
+}
+
+fn pde_ffi_dispatcher_primary_impl(
+    func_id: i32,
+    port: flutter_rust_bridge::for_generated::MessagePort,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+        _ => unreachable!(),
+    }
+}
+
+fn pde_ffi_dispatcher_sync_impl(
+    func_id: i32,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+        _ => unreachable!(),
+    }
+}
+
+// Section: rust2dart
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::agent::AgentRun {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.answer.into_into_dart().into_dart(),
+            self.stop_reason.into_into_dart().into_dart(),
+            self.steps.into_into_dart().into_dart(),
+            self.messages.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::agent::AgentRun {}
+impl flutter_rust_bridge::IntoIntoDart<crate::agent::AgentRun> for crate::agent::AgentRun {
+    fn into_into_dart(self) -> crate::agent::AgentRun {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::agent::AgentStep {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.reply.into_into_dart().into_dart(),
+            self.tool_calls.into_into_dart().into_dart(),
+            self.results.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::agent::AgentStep {}
+impl flutter_rust_bridge::IntoIntoDart<crate::agent::AgentStep> for crate::agent::AgentStep {
+    fn into_into_dart(self) -> crate::agent::AgentStep {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::agent::AgentStopReason {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::FinalAnswer => 0.into_dart(),
+            Self::StepLimit => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::agent::AgentStopReason {}
+impl flutter_rust_bridge::IntoIntoDart<crate::agent::AgentStopReason>
+    for crate::agent::AgentStopReason
+{
+    fn into_into_dart(self) -> crate::agent::AgentStopReason {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::clustering::ClusterAssignment {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.cluster_id.into_into_dart().into_dart(),
+            self.color.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::clustering::ClusterAssignment
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::clustering::ClusterAssignment>
+    for crate::clustering::ClusterAssignment
+{
+    fn into_into_dart(self) -> crate::clustering::ClusterAssignment {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::clustering::ClusterInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.size.into_into_dart().into_dart(),
+            self.color.into_into_dart().into_dart(),
+            self.centroid.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::clustering::ClusterInfo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::clustering::ClusterInfo>
+    for crate::clustering::ClusterInfo
+{
+    fn into_into_dart(self) -> crate::clustering::ClusterInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::clustering::ClusteringResult {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.assignments.into_into_dart().into_dart(),
+            self.clusters.into_into_dart().into_dart(),
+            self.k.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::clustering::ClusteringResult
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::clustering::ClusteringResult>
+    for crate::clustering::ClusteringResult
+{
+    fn into_into_dart(self) -> crate::clustering::ClusteringResult {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::context_window::ContextPolicy {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.strategy.into_into_dart().into_dart(),
+            self.summary_max_tokens.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::context_window::ContextPolicy
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::context_window::ContextPolicy>
+    for crate::context_window::ContextPolicy
+{
+    fn into_into_dart(self) -> crate::context_window::ContextPolicy {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::context_window::ContextReport {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.context_size.into_into_dart().into_dart(),
+            self.prompt_tokens.into_into_dart().into_dart(),
+            self.message_tokens.into_into_dart().into_dart(),
+            self.dropped.into_into_dart().into_dart(),
+            self.truncated.into_into_dart().into_dart(),
+            self.summarized.into_into_dart().into_dart(),
+            self.summary.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::context_window::ContextReport
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::context_window::ContextReport>
+    for crate::context_window::ContextReport
+{
+    fn into_into_dart(self) -> crate::context_window::ContextReport {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::context_window::ContextStrategy {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Error => 0.into_dart(),
+            Self::DropOldest => 1.into_dart(),
+            Self::SlidingWindow => 2.into_dart(),
+            Self::Summarize => 3.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::context_window::ContextStrategy
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::context_window::ContextStrategy>
+    for crate::context_window::ContextStrategy
+{
+    fn into_into_dart(self) -> crate::context_window::ContextStrategy {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::dedup::DedupDocument {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.text.into_into_dart().into_dart(),
+            self.vector.into_into_dart().into_dart(),
+            self.created_at.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::dedup::DedupDocument {}
+impl flutter_rust_bridge::IntoIntoDart<crate::dedup::DedupDocument>
+    for crate::dedup::DedupDocument
+{
+    fn into_into_dart(self) -> crate::dedup::DedupDocument {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::dedup::DedupOptions {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.shingle_words.into_into_dart().into_dart(),
+            self.num_hashes.into_into_dart().into_dart(),
+            self.bands.into_into_dart().into_dart(),
+            self.min_jaccard.into_into_dart().into_dart(),
+            self.min_cosine.into_into_dart().into_dart(),
+            self.min_span_words.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::dedup::DedupOptions {}
+impl flutter_rust_bridge::IntoIntoDart<crate::dedup::DedupOptions> for crate::dedup::DedupOptions {
+    fn into_into_dart(self) -> crate::dedup::DedupOptions {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::dedup::DuplicateGroup {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.ids.into_into_dart().into_dart(),
+            self.canonical.into_into_dart().into_dart(),
+            self.canonical_note.into_into_dart().into_dart(),
+            self.pairs.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::dedup::DuplicateGroup {}
+impl flutter_rust_bridge::IntoIntoDart<crate::dedup::DuplicateGroup>
+    for crate::dedup::DuplicateGroup
+{
+    fn into_into_dart(self) -> crate::dedup::DuplicateGroup {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::dedup::DuplicatePair {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.a.into_into_dart().into_dart(),
+            self.b.into_into_dart().into_dart(),
+            self.jaccard.into_into_dart().into_dart(),
+            self.cosine.into_into_dart().into_dart(),
+            self.spans.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::dedup::DuplicatePair {}
+impl flutter_rust_bridge::IntoIntoDart<crate::dedup::DuplicatePair>
+    for crate::dedup::DuplicatePair
+{
+    fn into_into_dart(self) -> crate::dedup::DuplicatePair {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::dedup::DuplicateSpan {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.a_start.into_into_dart().into_dart(),
+            self.a_end.into_into_dart().into_dart(),
+            self.b_start.into_into_dart().into_dart(),
+            self.b_end.into_into_dart().into_dart(),
+            self.words.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::dedup::DuplicateSpan {}
+impl flutter_rust_bridge::IntoIntoDart<crate::dedup::DuplicateSpan>
+    for crate::dedup::DuplicateSpan
+{
+    fn into_into_dart(self) -> crate::dedup::DuplicateSpan {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::embedding_cache::EmbeddingCacheStats {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.entries.into_into_dart().into_dart(),
+            self.stale_records.into_into_dart().into_dart(),
+            self.file_bytes.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::embedding_cache::EmbeddingCacheStats
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::embedding_cache::EmbeddingCacheStats>
+    for crate::embedding_cache::EmbeddingCacheStats
+{
+    fn into_into_dart(self) -> crate::embedding_cache::EmbeddingCacheStats {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::EmbeddingCluster {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [self.ids.into_into_dart().into_dart()].into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::EmbeddingCluster {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::EmbeddingCluster>
+    for crate::api::EmbeddingCluster
+{
+    fn into_into_dart(self) -> crate::api::EmbeddingCluster {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::embedder::EmbeddingConfig {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.n_gpu_layers.into_into_dart().into_dart(),
+            self.n_threads.into_into_dart().into_dart(),
+            self.pooling.into_into_dart().into_dart(),
+            self.chunk_tokens.into_into_dart().into_dart(),
+            self.chunk_overlap.into_into_dart().into_dart(),
+            self.max_sequences.into_into_dart().into_dart(),
+            self.query_prefix.into_into_dart().into_dart(),
+            self.document_prefix.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::embedder::EmbeddingConfig
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::embedder::EmbeddingConfig>
+    for crate::embedder::EmbeddingConfig
+{
+    fn into_into_dart(self) -> crate::embedder::EmbeddingConfig {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::embeddings::EmbeddingEntry {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.vector.into_into_dart().into_dart(),
+            self.text_preview.into_into_dart().into_dart(),
+            self.metadata.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::embeddings::EmbeddingEntry
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::embeddings::EmbeddingEntry>
+    for crate::embeddings::EmbeddingEntry
+{
+    fn into_into_dart(self) -> crate::embeddings::EmbeddingEntry {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::embedder::EmbeddingKind {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Query => 0.into_dart(),
+            Self::Document => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::embedder::EmbeddingKind
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::embedder::EmbeddingKind>
+    for crate::embedder::EmbeddingKind
+{
+    fn into_into_dart(self) -> crate::embedder::EmbeddingKind {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::hybrid_search::FusionMode {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::ReciprocalRank => 0.into_dart(),
+            Self::Weighted => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::hybrid_search::FusionMode
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::hybrid_search::FusionMode>
+    for crate::hybrid_search::FusionMode
+{
+    fn into_into_dart(self) -> crate::hybrid_search::FusionMode {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::inference::GenerationChunk {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.text.into_into_dart().into_dart(),
+            self.reasoning.into_into_dart().into_dart(),
+            self.stop_reason.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::inference::GenerationChunk
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::inference::GenerationChunk>
+    for crate::inference::GenerationChunk
+{
+    fn into_into_dart(self) -> crate::inference::GenerationChunk {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::inference::GenerationOptions {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.max_tokens.into_into_dart().into_dart(),
+            self.stop_sequences.into_into_dart().into_dart(),
+            self.sampler.into_into_dart().into_dart(),
+            self.grammar.into_into_dart().into_dart(),
+            self.json_schema.into_into_dart().into_dart(),
+            self.model_id.into_into_dart().into_dart(),
+            self.context.into_into_dart().into_dart(),
+            self.reasoning.into_into_dart().into_dart(),
+            self.logprobs.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::inference::GenerationOptions
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::inference::GenerationOptions>
+    for crate::inference::GenerationOptions
+{
+    fn into_into_dart(self) -> crate::inference::GenerationOptions {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::inference::GenerationPoll {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.text.into_into_dart().into_dart(),
+            self.reasoning.into_into_dart().into_dart(),
+            self.result.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::inference::GenerationPoll
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::inference::GenerationPoll>
+    for crate::inference::GenerationPoll
+{
+    fn into_into_dart(self) -> crate::inference::GenerationPoll {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::inference::GenerationResult {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.text.into_into_dart().into_dart(),
+            self.reasoning.into_into_dart().into_dart(),
+            self.reasoning_tokens.into_into_dart().into_dart(),
+            self.stop_reason.into_into_dart().into_dart(),
+            self.tokens_generated.into_into_dart().into_dart(),
+            self.context.into_into_dart().into_dart(),
+            self.logprobs.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::inference::GenerationResult
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::inference::GenerationResult>
+    for crate::inference::GenerationResult
+{
+    fn into_into_dart(self) -> crate::inference::GenerationResult {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::gguf::GgufMetadata {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.version.into_into_dart().into_dart(),
+            self.architecture.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.chat_template.into_into_dart().into_dart(),
+            self.context_length.into_into_dart().into_dart(),
+            self.embedding_length.into_into_dart().into_dart(),
+            self.block_count.into_into_dart().into_dart(),
+            self.pooling_type.into_into_dart().into_dart(),
+            self.quantization.into_into_dart().into_dart(),
+            self.parameter_count.into_into_dart().into_dart(),
+            self.tensor_count.into_into_dart().into_dart(),
+            self.tokenizer_model.into_into_dart().into_dart(),
+            self.vocab_size.into_into_dart().into_dart(),
+            self.bos_token_id.into_into_dart().into_dart(),
+            self.eos_token_id.into_into_dart().into_dart(),
+            self.file_size.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::gguf::GgufMetadata {}
+impl flutter_rust_bridge::IntoIntoDart<crate::gguf::GgufMetadata> for crate::gguf::GgufMetadata {
+    fn into_into_dart(self) -> crate::gguf::GgufMetadata {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::graph::GraphEdge {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.source.into_into_dart().into_dart(),
+            self.target.into_into_dart().into_dart(),
+            self.weight.into_into_dart().into_dart(),
+            self.edge_type.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::graph::GraphEdge {}
+impl flutter_rust_bridge::IntoIntoDart<crate::graph::GraphEdge> for crate::graph::GraphEdge {
+    fn into_into_dart(self) -> crate::graph::GraphEdge {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::graph::GraphNode {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.label.into_into_dart().into_dart(),
+            self.node_type.into_into_dart().into_dart(),
+            self.x.into_into_dart().into_dart(),
+            self.y.into_into_dart().into_dart(),
+            self.color.into_into_dart().into_dart(),
+            self.metadata.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::graph::GraphNode {}
+impl flutter_rust_bridge::IntoIntoDart<crate::graph::GraphNode> for crate::graph::GraphNode {
+    fn into_into_dart(self) -> crate::graph::GraphNode {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::graph::GraphState {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.nodes.into_into_dart().into_dart(),
+            self.edges.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::graph::GraphState {}
+impl flutter_rust_bridge::IntoIntoDart<crate::graph::GraphState> for crate::graph::GraphState {
+    fn into_into_dart(self) -> crate::graph::GraphState {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::hnsw::HnswConfig {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.m.into_into_dart().into_dart(),
+            self.ef_construction.into_into_dart().into_dart(),
+            self.ef_search.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::hnsw::HnswConfig {}
+impl flutter_rust_bridge::IntoIntoDart<crate::hnsw::HnswConfig> for crate::hnsw::HnswConfig {
+    fn into_into_dart(self) -> crate::hnsw::HnswConfig {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::hybrid_search::HybridHit {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.score.into_into_dart().into_dart(),
+            self.semantic_score.into_into_dart().into_dart(),
+            self.semantic_rank.into_into_dart().into_dart(),
+            self.lexical_score.into_into_dart().into_dart(),
+            self.lexical_rank.into_into_dart().into_dart(),
+            self.matched_terms.into_into_dart().into_dart(),
+            self.text_preview.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::hybrid_search::HybridHit
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::hybrid_search::HybridHit>
+    for crate::hybrid_search::HybridHit
+{
+    fn into_into_dart(self) -> crate::hybrid_search::HybridHit {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::hybrid_search::HybridOptions {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.fusion.into_into_dart().into_dart(),
+            self.semantic_weight.into_into_dart().into_dart(),
+            self.rrf_k.into_into_dart().into_dart(),
+            self.candidates.into_into_dart().into_dart(),
+            self.min_semantic_score.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::hybrid_search::HybridOptions
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::hybrid_search::HybridOptions>
+    for crate::hybrid_search::HybridOptions
+{
+    fn into_into_dart(self) -> crate::hybrid_search::HybridOptions {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::KnowledgeGraphAnalysis {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.clustering.into_into_dart().into_dart(),
+            self.semantic_edges.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::KnowledgeGraphAnalysis
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::KnowledgeGraphAnalysis>
+    for crate::api::KnowledgeGraphAnalysis
+{
+    fn into_into_dart(self) -> crate::api::KnowledgeGraphAnalysis {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::inference::LoadedModelInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.model_id.into_into_dart().into_dart(),
+            self.model_path.into_into_dart().into_dart(),
+            self.size_bytes.into_into_dart().into_dart(),
+            self.is_default.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::inference::LoadedModelInfo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::inference::LoadedModelInfo>
+    for crate::inference::LoadedModelInfo
+{
+    fn into_into_dart(self) -> crate::inference::LoadedModelInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::chunker::MarkdownChunk {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.index.into_into_dart().into_dart(),
+            self.heading_path.into_into_dart().into_dart(),
+            self.start.into_into_dart().into_dart(),
+            self.end.into_into_dart().into_dart(),
+            self.text.into_into_dart().into_dart(),
+            self.token_count.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::chunker::MarkdownChunk {}
+impl flutter_rust_bridge::IntoIntoDart<crate::chunker::MarkdownChunk>
+    for crate::chunker::MarkdownChunk
+{
+    fn into_into_dart(self) -> crate::chunker::MarkdownChunk {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::mcp::MCPParamType {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::String => 0.into_dart(),
+            Self::Boolean => 1.into_dart(),
+            Self::Integer => 2.into_dart(),
+            Self::Array => 3.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::mcp::MCPParamType {}
+impl flutter_rust_bridge::IntoIntoDart<crate::mcp::MCPParamType> for crate::mcp::MCPParamType {
+    fn into_into_dart(self) -> crate::mcp::MCPParamType {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::mcp::MCPParameter {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.name.into_into_dart().into_dart(),
+            self.description.into_into_dart().into_dart(),
+            self.param_type.into_into_dart().into_dart(),
+            self.required.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::mcp::MCPParameter {}
+impl flutter_rust_bridge::IntoIntoDart<crate::mcp::MCPParameter> for crate::mcp::MCPParameter {
+    fn into_into_dart(self) -> crate::mcp::MCPParameter {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::mcp::MCPTool {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::ReadFile => 0.into_dart(),
+            Self::WriteFile => 1.into_dart(),
+            Self::DeleteFile => 2.into_dart(),
+            Self::CreateFolder => 3.into_dart(),
+            Self::ListFiles => 4.into_dart(),
+            Self::CalendarLua => 5.into_dart(),
+            Self::TimerLua => 6.into_dart(),
+            Self::ExportMarkdown => 7.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::mcp::MCPTool {}
+impl flutter_rust_bridge::IntoIntoDart<crate::mcp::MCPTool> for crate::mcp::MCPTool {
+    fn into_into_dart(self) -> crate::mcp::MCPTool {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::mcp::MCPToolCall {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.tool.into_into_dart().into_dart(),
+            self.parameters_json.into_into_dart().into_dart(),
+            self.description.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::mcp::MCPToolCall {}
+impl flutter_rust_bridge::IntoIntoDart<crate::mcp::MCPToolCall> for crate::mcp::MCPToolCall {
+    fn into_into_dart(self) -> crate::mcp::MCPToolCall {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::mcp::MCPToolResult {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.success.into_into_dart().into_dart(),
+            self.result.into_into_dart().into_dart(),
+            self.tool.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::mcp::MCPToolResult {}
+impl flutter_rust_bridge::IntoIntoDart<crate::mcp::MCPToolResult> for crate::mcp::MCPToolResult {
+    fn into_into_dart(self) -> crate::mcp::MCPToolResult {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::streaming::NodePosition {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.x.into_into_dart().into_dart(),
+            self.y.into_into_dart().into_dart(),
+            self.radius.into_into_dart().into_dart(),
+            self.color.into_into_dart().into_dart(),
+            self.node_type.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::streaming::NodePosition
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::streaming::NodePosition>
+    for crate::streaming::NodePosition
+{
+    fn into_into_dart(self) -> crate::streaming::NodePosition {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::embeddings::NoteMetadata {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.folder.into_into_dart().into_dart(),
+            self.tags.into_into_dart().into_dart(),
+            self.created_at.into_into_dart().into_dart(),
+            self.modified_at.into_into_dart().into_dart(),
+            self.note_type.into_into_dart().into_dart(),
+            self.chunk_start.into_into_dart().into_dart(),
+            self.chunk_end.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::embeddings::NoteMetadata
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::embeddings::NoteMetadata>
+    for crate::embeddings::NoteMetadata
+{
+    fn into_into_dart(self) -> crate::embeddings::NoteMetadata {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::tool_parser::ParsedReply {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.tool_calls.into_into_dart().into_dart(),
+            self.spans.into_into_dart().into_dart(),
+            self.text.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::tool_parser::ParsedReply
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::tool_parser::ParsedReply>
+    for crate::tool_parser::ParsedReply
+{
+    fn into_into_dart(self) -> crate::tool_parser::ParsedReply {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::embedder::PoolingMode {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Native => 0.into_dart(),
+            Self::Mean => 1.into_dart(),
+            Self::Cls => 2.into_dart(),
+            Self::Last => 3.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::embedder::PoolingMode {}
+impl flutter_rust_bridge::IntoIntoDart<crate::embedder::PoolingMode>
+    for crate::embedder::PoolingMode
+{
+    fn into_into_dart(self) -> crate::embedder::PoolingMode {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::quantization::QuantizationKind {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Int8 => 0.into_dart(),
+            Self::Binary => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::quantization::QuantizationKind
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::quantization::QuantizationKind>
+    for crate::quantization::QuantizationKind
+{
+    fn into_into_dart(self) -> crate::quantization::QuantizationKind {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::rag::RagAnswer {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.answer.into_into_dart().into_dart(),
+            self.sources.into_into_dart().into_dart(),
+            self.skipped.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::rag::RagAnswer {}
+impl flutter_rust_bridge::IntoIntoDart<crate::rag::RagAnswer> for crate::rag::RagAnswer {
+    fn into_into_dart(self) -> crate::rag::RagAnswer {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::rag::RagSource {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.citation.into_into_dart().into_dart(),
+            self.note_id.into_into_dart().into_dart(),
+            self.chunk_offset.into_into_dart().into_dart(),
+            self.chunk_index.into_into_dart().into_dart(),
+            self.score.into_into_dart().into_dart(),
+            self.text.into_into_dart().into_dart(),
+            self.cited.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::rag::RagSource {}
+impl flutter_rust_bridge::IntoIntoDart<crate::rag::RagSource> for crate::rag::RagSource {
+    fn into_into_dart(self) -> crate::rag::RagSource {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::reasoning::ReasoningOptions {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.enabled.into_into_dart().into_dart(),
+            self.open_tag.into_into_dart().into_dart(),
+            self.close_tag.into_into_dart().into_dart(),
+            self.thinking_budget.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::reasoning::ReasoningOptions
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::reasoning::ReasoningOptions>
+    for crate::reasoning::ReasoningOptions
+{
+    fn into_into_dart(self) -> crate::reasoning::ReasoningOptions {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::quantization::RecallReport {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.queries.into_into_dart().into_dart(),
+            self.top_k.into_into_dart().into_dart(),
+            self.recall.into_into_dart().into_dart(),
+            self.rescored_recall.into_into_dart().into_dart(),
+            self.f32_bytes.into_into_dart().into_dart(),
+            self.quantized_bytes.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::quantization::RecallReport
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::quantization::RecallReport>
+    for crate::quantization::RecallReport
+{
+    fn into_into_dart(self) -> crate::quantization::RecallReport {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::tool_parser::ReplySpan {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.start.into_into_dart().into_dart(),
+            self.end.into_into_dart().into_dart(),
+            self.kind.into_into_dart().into_dart(),
+            self.call_count.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::tool_parser::ReplySpan {}
+impl flutter_rust_bridge::IntoIntoDart<crate::tool_parser::ReplySpan>
+    for crate::tool_parser::ReplySpan
+{
+    fn into_into_dart(self) -> crate::tool_parser::ReplySpan {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::tool_parser::ReplySpanKind {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Text => 0.into_dart(),
+            Self::ToolCall => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::tool_parser::ReplySpanKind
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::tool_parser::ReplySpanKind>
+    for crate::tool_parser::ReplySpanKind
+{
+    fn into_into_dart(self) -> crate::tool_parser::ReplySpanKind {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::inference::SamplerConfig {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.temperature.into_into_dart().into_dart(),
+            self.top_k.into_into_dart().into_dart(),
+            self.top_p.into_into_dart().into_dart(),
+            self.min_p.into_into_dart().into_dart(),
+            self.repeat_penalty.into_into_dart().into_dart(),
+            self.frequency_penalty.into_into_dart().into_dart(),
+            self.presence_penalty.into_into_dart().into_dart(),
+            self.penalty_last_n.into_into_dart().into_dart(),
+            self.seed.into_into_dart().into_dart(),
+            self.greedy.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::inference::SamplerConfig
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::inference::SamplerConfig>
+    for crate::inference::SamplerConfig
+{
+    fn into_into_dart(self) -> crate::inference::SamplerConfig {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::filtered_search::SearchOptions {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.top_k.into_into_dart().into_dart(),
+            self.threshold.into_into_dart().into_dart(),
+            self.filter.into_into_dart().into_dart(),
+            self.mmr_lambda.into_into_dart().into_dart(),
+            self.mmr_candidates.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::filtered_search::SearchOptions
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::filtered_search::SearchOptions>
+    for crate::filtered_search::SearchOptions
+{
+    fn into_into_dart(self) -> crate::filtered_search::SearchOptions {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::clustering::SemanticEdge {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.source.into_into_dart().into_dart(),
+            self.target.into_into_dart().into_dart(),
+            self.similarity.into_into_dart().into_dart(),
+            self.is_ghost.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::clustering::SemanticEdge
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::clustering::SemanticEdge>
+    for crate::clustering::SemanticEdge
+{
+    fn into_into_dart(self) -> crate::clustering::SemanticEdge {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::clustering::SemanticEdgeResult {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.edges.into_into_dart().into_dart(),
+            self.count.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::clustering::SemanticEdgeResult
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::clustering::SemanticEdgeResult>
+    for crate::clustering::SemanticEdgeResult
+{
+    fn into_into_dart(self) -> crate::clustering::SemanticEdgeResult {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::embeddings::SimilarityResult {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.score.into_into_dart().into_dart(),
+            self.text_preview.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::embeddings::SimilarityResult
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::embeddings::SimilarityResult>
+    for crate::embeddings::SimilarityResult
+{
+    fn into_into_dart(self) -> crate::embeddings::SimilarityResult {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::inference::StopReason {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         match self {
-            0 => crate::mcp::TaskCategory::Conversation,
-            1 => crate::mcp::TaskCategory::ToolUse,
-            2 => crate::mcp::TaskCategory::CodeGeneration,
-            _ => unreachable!("Invalid variant for TaskCategory: {}", self),
+            Self::EndOfGeneration => 0.into_dart(),
+            Self::MaxTokens => 1.into_dart(),
+            Self::StopSequence => 2.into_dart(),
+            Self::Cancelled => 3.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::inference::StopReason {}
+impl flutter_rust_bridge::IntoIntoDart<crate::inference::StopReason>
+    for crate::inference::StopReason
+{
+    fn into_into_dart(self) -> crate::inference::StopReason {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::StreamGraphStats {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.node_count.into_into_dart().into_dart(),
+            self.edge_count.into_into_dart().into_dart(),
+            self.visible_count.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::StreamGraphStats {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::StreamGraphStats>
+    for crate::api::StreamGraphStats
+{
+    fn into_into_dart(self) -> crate::api::StreamGraphStats {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::summarize::Summary {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.summary.into_into_dart().into_dart(),
+            self.chunk_summaries.into_into_dart().into_dart(),
+            self.merge_levels.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::summarize::Summary {}
+impl flutter_rust_bridge::IntoIntoDart<crate::summarize::Summary> for crate::summarize::Summary {
+    fn into_into_dart(self) -> crate::summarize::Summary {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::summarize::SummaryOptions {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.style.into_into_dart().into_dart(),
+            self.max_words.into_into_dart().into_dart(),
+            self.chunk_tokens.into_into_dart().into_dart(),
+            self.model_id.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::summarize::SummaryOptions
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::summarize::SummaryOptions>
+    for crate::summarize::SummaryOptions
+{
+    fn into_into_dart(self) -> crate::summarize::SummaryOptions {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::summarize::SummaryStyle {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Bullets => 0.into_dart(),
+            Self::Abstract => 1.into_dart(),
+            Self::KeyTakeaways => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::summarize::SummaryStyle
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::summarize::SummaryStyle>
+    for crate::summarize::SummaryStyle
+{
+    fn into_into_dart(self) -> crate::summarize::SummaryStyle {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::mcp::TaskCategory {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Conversation => 0.into_dart(),
+            Self::ToolUse => 1.into_dart(),
+            Self::CodeGeneration => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::mcp::TaskCategory {}
+impl flutter_rust_bridge::IntoIntoDart<crate::mcp::TaskCategory> for crate::mcp::TaskCategory {
+    fn into_into_dart(self) -> crate::mcp::TaskCategory {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::inference::TokenCandidate {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.token.into_into_dart().into_dart(),
+            self.text.into_into_dart().into_dart(),
+            self.logprob.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::inference::TokenCandidate
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::inference::TokenCandidate>
+    for crate::inference::TokenCandidate
+{
+    fn into_into_dart(self) -> crate::inference::TokenCandidate {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::inference::TokenLogprob {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.token.into_into_dart().into_dart(),
+            self.text.into_into_dart().into_dart(),
+            self.logprob.into_into_dart().into_dart(),
+            self.top_alternatives.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::inference::TokenLogprob
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::inference::TokenLogprob>
+    for crate::inference::TokenLogprob
+{
+    fn into_into_dart(self) -> crate::inference::TokenLogprob {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::tokenizer::TokenizerInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.vocab_size.into_into_dart().into_dart(),
+            self.n_ctx_train.into_into_dart().into_dart(),
+            self.n_ctx.into_into_dart().into_dart(),
+            self.bos_token.into_into_dart().into_dart(),
+            self.eos_token.into_into_dart().into_dart(),
+            self.tokenizer_model.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::tokenizer::TokenizerInfo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::tokenizer::TokenizerInfo>
+    for crate::tokenizer::TokenizerInfo
+{
+    fn into_into_dart(self) -> crate::tokenizer::TokenizerInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::summarize::TranscriptSegment {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.text.into_into_dart().into_dart(),
+            self.start_time.into_into_dart().into_dart(),
+            self.end_time.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::summarize::TranscriptSegment
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::summarize::TranscriptSegment>
+    for crate::summarize::TranscriptSegment
+{
+    fn into_into_dart(self) -> crate::summarize::TranscriptSegment {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::vector_store::VectorStoreStats {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.entries.into_into_dart().into_dart(),
+            self.dead_rows.into_into_dart().into_dart(),
+            self.dimension.into_into_dart().into_dart(),
+            self.file_bytes.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::vector_store::VectorStoreStats
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::vector_store::VectorStoreStats>
+    for crate::vector_store::VectorStoreStats
+{
+    fn into_into_dart(self) -> crate::vector_store::VectorStoreStats {
+        self
+    }
+}
+
+impl SseEncode for flutter_rust_bridge::for_generated::anyhow::Error {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(format!("{:?}", self), serializer);
+    }
+}
+
+impl SseEncode
+    for StreamSink<crate::agent::AgentStep, flutter_rust_bridge::for_generated::DcoCodec>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
+    }
+}
+
+impl SseEncode
+    for StreamSink<crate::inference::GenerationChunk, flutter_rust_bridge::for_generated::DcoCodec>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
+    }
+}
+
+impl SseEncode for String {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<u8>>::sse_encode(self.into_bytes(), serializer);
+    }
+}
+
+impl SseEncode for crate::agent::AgentRun {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.answer, serializer);
+        <crate::agent::AgentStopReason>::sse_encode(self.stop_reason, serializer);
+        <Vec<crate::agent::AgentStep>>::sse_encode(self.steps, serializer);
+        <Vec<(String, String)>>::sse_encode(self.messages, serializer);
+    }
+}
+
+impl SseEncode for crate::agent::AgentStep {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.reply, serializer);
+        <Vec<crate::mcp::MCPToolCall>>::sse_encode(self.tool_calls, serializer);
+        <Vec<crate::mcp::MCPToolResult>>::sse_encode(self.results, serializer);
+    }
+}
+
+impl SseEncode for crate::agent::AgentStopReason {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::agent::AgentStopReason::FinalAnswer => 0,
+                crate::agent::AgentStopReason::StepLimit => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for bool {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_u8(self as _).unwrap();
+    }
+}
+
+impl SseEncode for crate::clustering::ClusterAssignment {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.id, serializer);
+        <usize>::sse_encode(self.cluster_id, serializer);
+        <String>::sse_encode(self.color, serializer);
+    }
+}
+
+impl SseEncode for crate::clustering::ClusterInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <usize>::sse_encode(self.id, serializer);
+        <usize>::sse_encode(self.size, serializer);
+        <String>::sse_encode(self.color, serializer);
+        <Option<Vec<f32>>>::sse_encode(self.centroid, serializer);
+    }
+}
+
+impl SseEncode for crate::clustering::ClusteringResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<crate::clustering::ClusterAssignment>>::sse_encode(self.assignments, serializer);
+        <Vec<crate::clustering::ClusterInfo>>::sse_encode(self.clusters, serializer);
+        <usize>::sse_encode(self.k, serializer);
+    }
+}
+
+impl SseEncode for crate::context_window::ContextPolicy {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::context_window::ContextStrategy>::sse_encode(self.strategy, serializer);
+        <u32>::sse_encode(self.summary_max_tokens, serializer);
+    }
+}
+
+impl SseEncode for crate::context_window::ContextReport {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.context_size, serializer);
+        <u32>::sse_encode(self.prompt_tokens, serializer);
+        <Vec<u32>>::sse_encode(self.message_tokens, serializer);
+        <Vec<u32>>::sse_encode(self.dropped, serializer);
+        <Vec<u32>>::sse_encode(self.truncated, serializer);
+        <Vec<u32>>::sse_encode(self.summarized, serializer);
+        <Option<String>>::sse_encode(self.summary, serializer);
+    }
+}
+
+impl SseEncode for crate::context_window::ContextStrategy {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::context_window::ContextStrategy::Error => 0,
+                crate::context_window::ContextStrategy::DropOldest => 1,
+                crate::context_window::ContextStrategy::SlidingWindow => 2,
+                crate::context_window::ContextStrategy::Summarize => 3,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::dedup::DedupDocument {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.text, serializer);
+        <Option<Vec<f32>>>::sse_encode(self.vector, serializer);
+        <Option<i64>>::sse_encode(self.created_at, serializer);
+    }
+}
+
+impl SseEncode for crate::dedup::DedupOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <usize>::sse_encode(self.shingle_words, serializer);
+        <usize>::sse_encode(self.num_hashes, serializer);
+        <usize>::sse_encode(self.bands, serializer);
+        <f32>::sse_encode(self.min_jaccard, serializer);
+        <f32>::sse_encode(self.min_cosine, serializer);
+        <usize>::sse_encode(self.min_span_words, serializer);
+    }
+}
+
+impl SseEncode for crate::dedup::DuplicateGroup {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<String>>::sse_encode(self.ids, serializer);
+        <String>::sse_encode(self.canonical, serializer);
+        <String>::sse_encode(self.canonical_note, serializer);
+        <Vec<crate::dedup::DuplicatePair>>::sse_encode(self.pairs, serializer);
+    }
+}
+
+impl SseEncode for crate::dedup::DuplicatePair {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.a, serializer);
+        <String>::sse_encode(self.b, serializer);
+        <f32>::sse_encode(self.jaccard, serializer);
+        <Option<f32>>::sse_encode(self.cosine, serializer);
+        <Vec<crate::dedup::DuplicateSpan>>::sse_encode(self.spans, serializer);
+    }
+}
+
+impl SseEncode for crate::dedup::DuplicateSpan {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <usize>::sse_encode(self.a_start, serializer);
+        <usize>::sse_encode(self.a_end, serializer);
+        <usize>::sse_encode(self.b_start, serializer);
+        <usize>::sse_encode(self.b_end, serializer);
+        <usize>::sse_encode(self.words, serializer);
+    }
+}
+
+impl SseEncode for crate::embedding_cache::EmbeddingCacheStats {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <usize>::sse_encode(self.entries, serializer);
+        <usize>::sse_encode(self.stale_records, serializer);
+        <u64>::sse_encode(self.file_bytes, serializer);
+    }
+}
+
+impl SseEncode for crate::api::EmbeddingCluster {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<String>>::sse_encode(self.ids, serializer);
+    }
+}
+
+impl SseEncode for crate::embedder::EmbeddingConfig {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.n_gpu_layers, serializer);
+        <i32>::sse_encode(self.n_threads, serializer);
+        <crate::embedder::PoolingMode>::sse_encode(self.pooling, serializer);
+        <u32>::sse_encode(self.chunk_tokens, serializer);
+        <u32>::sse_encode(self.chunk_overlap, serializer);
+        <u32>::sse_encode(self.max_sequences, serializer);
+        <String>::sse_encode(self.query_prefix, serializer);
+        <String>::sse_encode(self.document_prefix, serializer);
+    }
+}
+
+impl SseEncode for crate::embeddings::EmbeddingEntry {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.id, serializer);
+        <Vec<f32>>::sse_encode(self.vector, serializer);
+        <Option<String>>::sse_encode(self.text_preview, serializer);
+        <crate::embeddings::NoteMetadata>::sse_encode(self.metadata, serializer);
+    }
+}
+
+impl SseEncode for crate::embedder::EmbeddingKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::embedder::EmbeddingKind::Query => 0,
+                crate::embedder::EmbeddingKind::Document => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for f32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_f32::<NativeEndian>(self).unwrap();
+    }
+}
+
+impl SseEncode for crate::hybrid_search::FusionMode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::hybrid_search::FusionMode::ReciprocalRank => 0,
+                crate::hybrid_search::FusionMode::Weighted => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::inference::GenerationChunk {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.text, serializer);
+        <String>::sse_encode(self.reasoning, serializer);
+        <Option<crate::inference::StopReason>>::sse_encode(self.stop_reason, serializer);
+    }
+}
+
+impl SseEncode for crate::inference::GenerationOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<u32>>::sse_encode(self.max_tokens, serializer);
+        <Vec<String>>::sse_encode(self.stop_sequences, serializer);
+        <Option<crate::inference::SamplerConfig>>::sse_encode(self.sampler, serializer);
+        <Option<String>>::sse_encode(self.grammar, serializer);
+        <Option<String>>::sse_encode(self.json_schema, serializer);
+        <Option<String>>::sse_encode(self.model_id, serializer);
+        <Option<crate::context_window::ContextPolicy>>::sse_encode(self.context, serializer);
+        <Option<crate::reasoning::ReasoningOptions>>::sse_encode(self.reasoning, serializer);
+        <Option<u32>>::sse_encode(self.logprobs, serializer);
+    }
+}
+
+impl SseEncode for crate::inference::GenerationPoll {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.text, serializer);
+        <String>::sse_encode(self.reasoning, serializer);
+        <Option<crate::inference::GenerationResult>>::sse_encode(self.result, serializer);
+    }
+}
+
+impl SseEncode for crate::inference::GenerationResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.text, serializer);
+        <Option<String>>::sse_encode(self.reasoning, serializer);
+        <u32>::sse_encode(self.reasoning_tokens, serializer);
+        <crate::inference::StopReason>::sse_encode(self.stop_reason, serializer);
+        <u32>::sse_encode(self.tokens_generated, serializer);
+        <Option<crate::context_window::ContextReport>>::sse_encode(self.context, serializer);
+        <Option<Vec<crate::inference::TokenLogprob>>>::sse_encode(self.logprobs, serializer);
+    }
+}
+
+impl SseEncode for crate::gguf::GgufMetadata {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.version, serializer);
+        <Option<String>>::sse_encode(self.architecture, serializer);
+        <Option<String>>::sse_encode(self.name, serializer);
+        <Option<String>>::sse_encode(self.chat_template, serializer);
+        <Option<u64>>::sse_encode(self.context_length, serializer);
+        <Option<u64>>::sse_encode(self.embedding_length, serializer);
+        <Option<u64>>::sse_encode(self.block_count, serializer);
+        <Option<u32>>::sse_encode(self.pooling_type, serializer);
+        <Option<String>>::sse_encode(self.quantization, serializer);
+        <u64>::sse_encode(self.parameter_count, serializer);
+        <u64>::sse_encode(self.tensor_count, serializer);
+        <Option<String>>::sse_encode(self.tokenizer_model, serializer);
+        <Option<u64>>::sse_encode(self.vocab_size, serializer);
+        <Option<u32>>::sse_encode(self.bos_token_id, serializer);
+        <Option<u32>>::sse_encode(self.eos_token_id, serializer);
+        <u64>::sse_encode(self.file_size, serializer);
+    }
+}
+
+impl SseEncode for crate::graph::GraphEdge {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.source, serializer);
+        <String>::sse_encode(self.target, serializer);
+        <f32>::sse_encode(self.weight, serializer);
+        <String>::sse_encode(self.edge_type, serializer);
+    }
+}
+
+impl SseEncode for crate::graph::GraphNode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.label, serializer);
+        <String>::sse_encode(self.node_type, serializer);
+        <f32>::sse_encode(self.x, serializer);
+        <f32>::sse_encode(self.y, serializer);
+        <Option<String>>::sse_encode(self.color, serializer);
+        <Option<String>>::sse_encode(self.metadata, serializer);
+    }
+}
+
+impl SseEncode for crate::graph::GraphState {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<crate::graph::GraphNode>>::sse_encode(self.nodes, serializer);
+        <Vec<crate::graph::GraphEdge>>::sse_encode(self.edges, serializer);
+    }
+}
+
+impl SseEncode for crate::hnsw::HnswConfig {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.m, serializer);
+        <u32>::sse_encode(self.ef_construction, serializer);
+        <u32>::sse_encode(self.ef_search, serializer);
+    }
+}
+
+impl SseEncode for crate::hybrid_search::HybridHit {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.id, serializer);
+        <f32>::sse_encode(self.score, serializer);
+        <Option<f32>>::sse_encode(self.semantic_score, serializer);
+        <Option<u32>>::sse_encode(self.semantic_rank, serializer);
+        <Option<f32>>::sse_encode(self.lexical_score, serializer);
+        <Option<u32>>::sse_encode(self.lexical_rank, serializer);
+        <Vec<String>>::sse_encode(self.matched_terms, serializer);
+        <Option<String>>::sse_encode(self.text_preview, serializer);
+    }
+}
+
+impl SseEncode for crate::hybrid_search::HybridOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::hybrid_search::FusionMode>::sse_encode(self.fusion, serializer);
+        <f32>::sse_encode(self.semantic_weight, serializer);
+        <f32>::sse_encode(self.rrf_k, serializer);
+        <usize>::sse_encode(self.candidates, serializer);
+        <f32>::sse_encode(self.min_semantic_score, serializer);
+    }
+}
+
+impl SseEncode for i32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_i32::<NativeEndian>(self).unwrap();
+    }
+}
+
+impl SseEncode for i64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_i64::<NativeEndian>(self).unwrap();
+    }
+}
+
+impl SseEncode for crate::api::KnowledgeGraphAnalysis {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::clustering::ClusteringResult>::sse_encode(self.clustering, serializer);
+        <crate::clustering::SemanticEdgeResult>::sse_encode(self.semantic_edges, serializer);
+    }
+}
+
+impl SseEncode for Vec<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <String>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::agent::AgentStep> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::agent::AgentStep>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::clustering::ClusterAssignment> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::clustering::ClusterAssignment>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::clustering::ClusterInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::clustering::ClusterInfo>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::dedup::DedupDocument> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::dedup::DedupDocument>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::dedup::DuplicateGroup> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::dedup::DuplicateGroup>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::dedup::DuplicatePair> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::dedup::DuplicatePair>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::dedup::DuplicateSpan> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::dedup::DuplicateSpan>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::EmbeddingCluster> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::EmbeddingCluster>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::embeddings::EmbeddingEntry> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::embeddings::EmbeddingEntry>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::graph::GraphEdge> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::graph::GraphEdge>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::graph::GraphNode> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::graph::GraphNode>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::hybrid_search::HybridHit> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::hybrid_search::HybridHit>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<Vec<f32>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <Vec<f32>>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::inference::LoadedModelInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::inference::LoadedModelInfo>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::chunker::MarkdownChunk> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::chunker::MarkdownChunk>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::mcp::MCPParameter> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::mcp::MCPParameter>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::mcp::MCPTool> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::mcp::MCPTool>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::mcp::MCPToolCall> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::mcp::MCPToolCall>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::mcp::MCPToolResult> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::mcp::MCPToolResult>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::streaming::NodePosition> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::streaming::NodePosition>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<f32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <f32>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<i32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <i32>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<u32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <u32>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<u8> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <u8>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::rag::RagSource> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::rag::RagSource>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<(String, String)> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <(String, String)>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::tool_parser::ReplySpan> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::tool_parser::ReplySpan>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::clustering::SemanticEdge> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::clustering::SemanticEdge>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::embeddings::SimilarityResult> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::embeddings::SimilarityResult>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::inference::TokenCandidate> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::inference::TokenCandidate>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::inference::TokenLogprob> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::inference::TokenLogprob>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::summarize::TranscriptSegment> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::summarize::TranscriptSegment>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for crate::inference::LoadedModelInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.model_id, serializer);
+        <String>::sse_encode(self.model_path, serializer);
+        <u64>::sse_encode(self.size_bytes, serializer);
+        <bool>::sse_encode(self.is_default, serializer);
+    }
+}
+
+impl SseEncode for crate::chunker::MarkdownChunk {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <usize>::sse_encode(self.index, serializer);
+        <Vec<String>>::sse_encode(self.heading_path, serializer);
+        <usize>::sse_encode(self.start, serializer);
+        <usize>::sse_encode(self.end, serializer);
+        <String>::sse_encode(self.text, serializer);
+        <u32>::sse_encode(self.token_count, serializer);
+    }
+}
+
+impl SseEncode for crate::mcp::MCPParamType {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::mcp::MCPParamType::String => 0,
+                crate::mcp::MCPParamType::Boolean => 1,
+                crate::mcp::MCPParamType::Integer => 2,
+                crate::mcp::MCPParamType::Array => 3,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::mcp::MCPParameter {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.name, serializer);
+        <String>::sse_encode(self.description, serializer);
+        <crate::mcp::MCPParamType>::sse_encode(self.param_type, serializer);
+        <bool>::sse_encode(self.required, serializer);
+    }
+}
+
+impl SseEncode for crate::mcp::MCPTool {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::mcp::MCPTool::ReadFile => 0,
+                crate::mcp::MCPTool::WriteFile => 1,
+                crate::mcp::MCPTool::DeleteFile => 2,
+                crate::mcp::MCPTool::CreateFolder => 3,
+                crate::mcp::MCPTool::ListFiles => 4,
+                crate::mcp::MCPTool::CalendarLua => 5,
+                crate::mcp::MCPTool::TimerLua => 6,
+                crate::mcp::MCPTool::ExportMarkdown => 7,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::mcp::MCPToolCall {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.tool, serializer);
+        <String>::sse_encode(self.parameters_json, serializer);
+        <String>::sse_encode(self.description, serializer);
+    }
+}
+
+impl SseEncode for crate::mcp::MCPToolResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.success, serializer);
+        <String>::sse_encode(self.result, serializer);
+        <String>::sse_encode(self.tool, serializer);
+    }
+}
+
+impl SseEncode for crate::streaming::NodePosition {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.id, serializer);
+        <f32>::sse_encode(self.x, serializer);
+        <f32>::sse_encode(self.y, serializer);
+        <f32>::sse_encode(self.radius, serializer);
+        <u32>::sse_encode(self.color, serializer);
+        <String>::sse_encode(self.node_type, serializer);
+    }
+}
+
+impl SseEncode for crate::embeddings::NoteMetadata {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<String>>::sse_encode(self.folder, serializer);
+        <Vec<String>>::sse_encode(self.tags, serializer);
+        <Option<i64>>::sse_encode(self.created_at, serializer);
+        <Option<i64>>::sse_encode(self.modified_at, serializer);
+        <Option<String>>::sse_encode(self.note_type, serializer);
+        <Option<usize>>::sse_encode(self.chunk_start, serializer);
+        <Option<usize>>::sse_encode(self.chunk_end, serializer);
+    }
+}
+
+impl SseEncode for Option<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <String>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::context_window::ContextPolicy> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::context_window::ContextPolicy>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::context_window::ContextReport> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::context_window::ContextReport>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::dedup::DedupOptions> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::dedup::DedupOptions>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::embedding_cache::EmbeddingCacheStats> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::embedding_cache::EmbeddingCacheStats>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::embedder::EmbeddingConfig> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::embedder::EmbeddingConfig>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<f32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <f32>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::inference::GenerationResult> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::inference::GenerationResult>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::hnsw::HnswConfig> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::hnsw::HnswConfig>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::hybrid_search::HybridOptions> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::hybrid_search::HybridOptions>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<i32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <i32>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<i64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <i64>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::embeddings::NoteMetadata> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::embeddings::NoteMetadata>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::reasoning::ReasoningOptions> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::reasoning::ReasoningOptions>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::inference::SamplerConfig> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::inference::SamplerConfig>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::inference::StopReason> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::inference::StopReason>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::summarize::SummaryOptions> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::summarize::SummaryOptions>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<u32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <u32>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<u64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <u64>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<usize> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <usize>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<Vec<String>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <Vec<String>>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<Vec<Vec<f32>>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <Vec<Vec<f32>>>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<Vec<f32>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <Vec<f32>>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<Vec<(String, String)>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <Vec<(String, String)>>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<Vec<crate::inference::TokenLogprob>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <Vec<crate::inference::TokenLogprob>>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for crate::tool_parser::ParsedReply {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<crate::mcp::MCPToolCall>>::sse_encode(self.tool_calls, serializer);
+        <Vec<crate::tool_parser::ReplySpan>>::sse_encode(self.spans, serializer);
+        <String>::sse_encode(self.text, serializer);
+    }
+}
+
+impl SseEncode for crate::embedder::PoolingMode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::embedder::PoolingMode::Native => 0,
+                crate::embedder::PoolingMode::Mean => 1,
+                crate::embedder::PoolingMode::Cls => 2,
+                crate::embedder::PoolingMode::Last => 3,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::quantization::QuantizationKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::quantization::QuantizationKind::Int8 => 0,
+                crate::quantization::QuantizationKind::Binary => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::rag::RagAnswer {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.answer, serializer);
+        <Vec<crate::rag::RagSource>>::sse_encode(self.sources, serializer);
+        <usize>::sse_encode(self.skipped, serializer);
+    }
+}
+
+impl SseEncode for crate::rag::RagSource {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.citation, serializer);
+        <String>::sse_encode(self.note_id, serializer);
+        <Option<usize>>::sse_encode(self.chunk_offset, serializer);
+        <Option<usize>>::sse_encode(self.chunk_index, serializer);
+        <f32>::sse_encode(self.score, serializer);
+        <String>::sse_encode(self.text, serializer);
+        <bool>::sse_encode(self.cited, serializer);
+    }
+}
+
+impl SseEncode for crate::reasoning::ReasoningOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.enabled, serializer);
+        <String>::sse_encode(self.open_tag, serializer);
+        <String>::sse_encode(self.close_tag, serializer);
+        <Option<u32>>::sse_encode(self.thinking_budget, serializer);
+    }
+}
+
+impl SseEncode for crate::quantization::RecallReport {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <usize>::sse_encode(self.queries, serializer);
+        <usize>::sse_encode(self.top_k, serializer);
+        <f32>::sse_encode(self.recall, serializer);
+        <f32>::sse_encode(self.rescored_recall, serializer);
+        <usize>::sse_encode(self.f32_bytes, serializer);
+        <usize>::sse_encode(self.quantized_bytes, serializer);
+    }
+}
+
+impl SseEncode for (String, String) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.0, serializer);
+        <String>::sse_encode(self.1, serializer);
+    }
+}
+
+impl SseEncode for crate::tool_parser::ReplySpan {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <usize>::sse_encode(self.start, serializer);
+        <usize>::sse_encode(self.end, serializer);
+        <crate::tool_parser::ReplySpanKind>::sse_encode(self.kind, serializer);
+        <usize>::sse_encode(self.call_count, serializer);
+    }
+}
+
+impl SseEncode for crate::tool_parser::ReplySpanKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::tool_parser::ReplySpanKind::Text => 0,
+                crate::tool_parser::ReplySpanKind::ToolCall => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::inference::SamplerConfig {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <f32>::sse_encode(self.temperature, serializer);
+        <i32>::sse_encode(self.top_k, serializer);
+        <f32>::sse_encode(self.top_p, serializer);
+        <f32>::sse_encode(self.min_p, serializer);
+        <f32>::sse_encode(self.repeat_penalty, serializer);
+        <f32>::sse_encode(self.frequency_penalty, serializer);
+        <f32>::sse_encode(self.presence_penalty, serializer);
+        <i32>::sse_encode(self.penalty_last_n, serializer);
+        <Option<u32>>::sse_encode(self.seed, serializer);
+        <bool>::sse_encode(self.greedy, serializer);
+    }
+}
+
+impl SseEncode for crate::filtered_search::SearchOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <usize>::sse_encode(self.top_k, serializer);
+        <f32>::sse_encode(self.threshold, serializer);
+        <Option<String>>::sse_encode(self.filter, serializer);
+        <Option<f32>>::sse_encode(self.mmr_lambda, serializer);
+        <usize>::sse_encode(self.mmr_candidates, serializer);
+    }
+}
+
+impl SseEncode for crate::clustering::SemanticEdge {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.source, serializer);
+        <String>::sse_encode(self.target, serializer);
+        <f32>::sse_encode(self.similarity, serializer);
+        <bool>::sse_encode(self.is_ghost, serializer);
+    }
+}
+
+impl SseEncode for crate::clustering::SemanticEdgeResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<crate::clustering::SemanticEdge>>::sse_encode(self.edges, serializer);
+        <usize>::sse_encode(self.count, serializer);
+    }
+}
+
+impl SseEncode for crate::embeddings::SimilarityResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.id, serializer);
+        <f32>::sse_encode(self.score, serializer);
+        <Option<String>>::sse_encode(self.text_preview, serializer);
+    }
+}
+
+impl SseEncode for crate::inference::StopReason {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::inference::StopReason::EndOfGeneration => 0,
+                crate::inference::StopReason::MaxTokens => 1,
+                crate::inference::StopReason::StopSequence => 2,
+                crate::inference::StopReason::Cancelled => 3,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::StreamGraphStats {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <usize>::sse_encode(self.node_count, serializer);
+        <usize>::sse_encode(self.edge_count, serializer);
+        <usize>::sse_encode(self.visible_count, serializer);
+    }
+}
+
+impl SseEncode for crate::summarize::Summary {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.summary, serializer);
+        <Vec<String>>::sse_encode(self.chunk_summaries, serializer);
+        <u32>::sse_encode(self.merge_levels, serializer);
+    }
+}
+
+impl SseEncode for crate::summarize::SummaryOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::summarize::SummaryStyle>::sse_encode(self.style, serializer);
+        <u32>::sse_encode(self.max_words, serializer);
+        <u32>::sse_encode(self.chunk_tokens, serializer);
+        <Option<String>>::sse_encode(self.model_id, serializer);
+    }
+}
+
+impl SseEncode for crate::summarize::SummaryStyle {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::summarize::SummaryStyle::Bullets => 0,
+                crate::summarize::SummaryStyle::Abstract => 1,
+                crate::summarize::SummaryStyle::KeyTakeaways => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::mcp::TaskCategory {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::mcp::TaskCategory::Conversation => 0,
+                crate::mcp::TaskCategory::ToolUse => 1,
+                crate::mcp::TaskCategory::CodeGeneration => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::inference::TokenCandidate {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.token, serializer);
+        <String>::sse_encode(self.text, serializer);
+        <f32>::sse_encode(self.logprob, serializer);
+    }
+}
+
+impl SseEncode for crate::inference::TokenLogprob {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.token, serializer);
+        <String>::sse_encode(self.text, serializer);
+        <f32>::sse_encode(self.logprob, serializer);
+        <Vec<crate::inference::TokenCandidate>>::sse_encode(self.top_alternatives, serializer);
+    }
+}
+
+impl SseEncode for crate::tokenizer::TokenizerInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.vocab_size, serializer);
+        <u32>::sse_encode(self.n_ctx_train, serializer);
+        <u32>::sse_encode(self.n_ctx, serializer);
+        <i32>::sse_encode(self.bos_token, serializer);
+        <i32>::sse_encode(self.eos_token, serializer);
+        <Option<String>>::sse_encode(self.tokenizer_model, serializer);
+    }
+}
+
+impl SseEncode for crate::summarize::TranscriptSegment {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.text, serializer);
+        <f32>::sse_encode(self.start_time, serializer);
+        <f32>::sse_encode(self.end_time, serializer);
+    }
+}
+
+impl SseEncode for u32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_u32::<NativeEndian>(self).unwrap();
+    }
+}
+
+impl SseEncode for u64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_u64::<NativeEndian>(self).unwrap();
+    }
+}
+
+impl SseEncode for u8 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_u8(self).unwrap();
+    }
+}
+
+impl SseEncode for () {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {}
+}
+
+impl SseEncode for usize {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer
+            .cursor
+            .write_u64::<NativeEndian>(self as _)
+            .unwrap();
+    }
+}
+
+impl SseEncode for crate::vector_store::VectorStoreStats {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <usize>::sse_encode(self.entries, serializer);
+        <usize>::sse_encode(self.dead_rows, serializer);
+        <usize>::sse_encode(self.dimension, serializer);
+        <u64>::sse_encode(self.file_bytes, serializer);
+    }
+}
+
+#[cfg(not(target_family = "wasm"))]
+mod io {
+    // This file is automatically generated, so please do not edit it.
+    // @generated by `flutter_rust_bridge`@ 2.12.0.
+
+    // Section: imports
+
+    use super::*;
+    use flutter_rust_bridge::for_generated::byteorder::{
+        NativeEndian, ReadBytesExt, WriteBytesExt,
+    };
+    use flutter_rust_bridge::for_generated::{transform_result_dco, Lifetimeable, Lockable};
+    use flutter_rust_bridge::{Handler, IntoIntoDart};
+
+    // Section: boilerplate
+
+    flutter_rust_bridge::frb_generated_boilerplate_io!();
+
+    // Section: dart2rust
+
+    impl CstDecode<flutter_rust_bridge::for_generated::anyhow::Error>
+        for *mut wire_cst_list_prim_u_8_strict
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> flutter_rust_bridge::for_generated::anyhow::Error {
+            unimplemented!()
+        }
+    }
+    impl
+        CstDecode<StreamSink<crate::agent::AgentStep, flutter_rust_bridge::for_generated::DcoCodec>>
+        for *mut wire_cst_list_prim_u_8_strict
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(
+            self,
+        ) -> StreamSink<crate::agent::AgentStep, flutter_rust_bridge::for_generated::DcoCodec>
+        {
+            let raw: String = self.cst_decode();
+            StreamSink::deserialize(raw)
+        }
+    }
+    impl
+        CstDecode<
+            StreamSink<
+                crate::inference::GenerationChunk,
+                flutter_rust_bridge::for_generated::DcoCodec,
+            >,
+        > for *mut wire_cst_list_prim_u_8_strict
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(
+            self,
+        ) -> StreamSink<
+            crate::inference::GenerationChunk,
+            flutter_rust_bridge::for_generated::DcoCodec,
+        > {
+            let raw: String = self.cst_decode();
+            StreamSink::deserialize(raw)
+        }
+    }
+    impl CstDecode<String> for *mut wire_cst_list_prim_u_8_strict {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> String {
+            let vec: Vec<u8> = self.cst_decode();
+            String::from_utf8(vec).unwrap()
+        }
+    }
+    impl CstDecode<crate::agent::AgentRun> for wire_cst_agent_run {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::agent::AgentRun {
+            crate::agent::AgentRun {
+                answer: self.answer.cst_decode(),
+                stop_reason: self.stop_reason.cst_decode(),
+                steps: self.steps.cst_decode(),
+                messages: self.messages.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::agent::AgentStep> for wire_cst_agent_step {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::agent::AgentStep {
+            crate::agent::AgentStep {
+                reply: self.reply.cst_decode(),
+                tool_calls: self.tool_calls.cst_decode(),
+                results: self.results.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::context_window::ContextPolicy> for *mut wire_cst_context_policy {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::context_window::ContextPolicy {
+            let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
+            CstDecode::<crate::context_window::ContextPolicy>::cst_decode(*wrap).into()
+        }
+    }
+    impl CstDecode<crate::context_window::ContextReport> for *mut wire_cst_context_report {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::context_window::ContextReport {
+            let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
+            CstDecode::<crate::context_window::ContextReport>::cst_decode(*wrap).into()
+        }
+    }
+    impl CstDecode<crate::dedup::DedupOptions> for *mut wire_cst_dedup_options {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::dedup::DedupOptions {
+            let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
+            CstDecode::<crate::dedup::DedupOptions>::cst_decode(*wrap).into()
+        }
+    }
+    impl CstDecode<crate::embedding_cache::EmbeddingCacheStats>
+        for *mut wire_cst_embedding_cache_stats
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::embedding_cache::EmbeddingCacheStats {
+            let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
+            CstDecode::<crate::embedding_cache::EmbeddingCacheStats>::cst_decode(*wrap).into()
+        }
+    }
+    impl CstDecode<crate::embedder::EmbeddingConfig> for *mut wire_cst_embedding_config {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::embedder::EmbeddingConfig {
+            let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
+            CstDecode::<crate::embedder::EmbeddingConfig>::cst_decode(*wrap).into()
+        }
+    }
+    impl CstDecode<f32> for *mut f32 {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> f32 {
+            unsafe { *flutter_rust_bridge::for_generated::box_from_leak_ptr(self) }
+        }
+    }
+    impl CstDecode<crate::inference::GenerationOptions> for *mut wire_cst_generation_options {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::inference::GenerationOptions {
+            let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
+            CstDecode::<crate::inference::GenerationOptions>::cst_decode(*wrap).into()
+        }
+    }
+    impl CstDecode<crate::inference::GenerationResult> for *mut wire_cst_generation_result {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::inference::GenerationResult {
+            let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
+            CstDecode::<crate::inference::GenerationResult>::cst_decode(*wrap).into()
+        }
+    }
+    impl CstDecode<crate::hnsw::HnswConfig> for *mut wire_cst_hnsw_config {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::hnsw::HnswConfig {
+            let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
+            CstDecode::<crate::hnsw::HnswConfig>::cst_decode(*wrap).into()
+        }
+    }
+    impl CstDecode<crate::hybrid_search::HybridOptions> for *mut wire_cst_hybrid_options {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::hybrid_search::HybridOptions {
+            let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
+            CstDecode::<crate::hybrid_search::HybridOptions>::cst_decode(*wrap).into()
+        }
+    }
+    impl CstDecode<i32> for *mut i32 {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> i32 {
+            unsafe { *flutter_rust_bridge::for_generated::box_from_leak_ptr(self) }
+        }
+    }
+    impl CstDecode<i64> for *mut i64 {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> i64 {
+            unsafe { *flutter_rust_bridge::for_generated::box_from_leak_ptr(self) }
+        }
+    }
+    impl CstDecode<crate::mcp::MCPToolCall> for *mut wire_cst_mcp_tool_call {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::mcp::MCPToolCall {
+            let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
+            CstDecode::<crate::mcp::MCPToolCall>::cst_decode(*wrap).into()
+        }
+    }
+    impl CstDecode<crate::embeddings::NoteMetadata> for *mut wire_cst_note_metadata {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::embeddings::NoteMetadata {
+            let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
+            CstDecode::<crate::embeddings::NoteMetadata>::cst_decode(*wrap).into()
+        }
+    }
+    impl CstDecode<crate::reasoning::ReasoningOptions> for *mut wire_cst_reasoning_options {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::reasoning::ReasoningOptions {
+            let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
+            CstDecode::<crate::reasoning::ReasoningOptions>::cst_decode(*wrap).into()
+        }
+    }
+    impl CstDecode<crate::inference::SamplerConfig> for *mut wire_cst_sampler_config {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::inference::SamplerConfig {
+            let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
+            CstDecode::<crate::inference::SamplerConfig>::cst_decode(*wrap).into()
+        }
+    }
+    impl CstDecode<crate::filtered_search::SearchOptions> for *mut wire_cst_search_options {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::filtered_search::SearchOptions {
+            let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
+            CstDecode::<crate::filtered_search::SearchOptions>::cst_decode(*wrap).into()
+        }
+    }
+    impl CstDecode<crate::inference::StopReason> for *mut i32 {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::inference::StopReason {
+            let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
+            CstDecode::<crate::inference::StopReason>::cst_decode(*wrap).into()
+        }
+    }
+    impl CstDecode<crate::summarize::SummaryOptions> for *mut wire_cst_summary_options {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::summarize::SummaryOptions {
+            let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
+            CstDecode::<crate::summarize::SummaryOptions>::cst_decode(*wrap).into()
+        }
+    }
+    impl CstDecode<u32> for *mut u32 {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> u32 {
+            unsafe { *flutter_rust_bridge::for_generated::box_from_leak_ptr(self) }
+        }
+    }
+    impl CstDecode<u64> for *mut u64 {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> u64 {
+            unsafe { *flutter_rust_bridge::for_generated::box_from_leak_ptr(self) }
+        }
+    }
+    impl CstDecode<usize> for *mut usize {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> usize {
+            unsafe { *flutter_rust_bridge::for_generated::box_from_leak_ptr(self) }
+        }
+    }
+    impl CstDecode<crate::clustering::ClusterAssignment> for wire_cst_cluster_assignment {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::clustering::ClusterAssignment {
+            crate::clustering::ClusterAssignment {
+                id: self.id.cst_decode(),
+                cluster_id: self.cluster_id.cst_decode(),
+                color: self.color.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::clustering::ClusterInfo> for wire_cst_cluster_info {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::clustering::ClusterInfo {
+            crate::clustering::ClusterInfo {
+                id: self.id.cst_decode(),
+                size: self.size.cst_decode(),
+                color: self.color.cst_decode(),
+                centroid: self.centroid.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::clustering::ClusteringResult> for wire_cst_clustering_result {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::clustering::ClusteringResult {
+            crate::clustering::ClusteringResult {
+                assignments: self.assignments.cst_decode(),
+                clusters: self.clusters.cst_decode(),
+                k: self.k.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::context_window::ContextPolicy> for wire_cst_context_policy {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::context_window::ContextPolicy {
+            crate::context_window::ContextPolicy {
+                strategy: self.strategy.cst_decode(),
+                summary_max_tokens: self.summary_max_tokens.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::context_window::ContextReport> for wire_cst_context_report {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::context_window::ContextReport {
+            crate::context_window::ContextReport {
+                context_size: self.context_size.cst_decode(),
+                prompt_tokens: self.prompt_tokens.cst_decode(),
+                message_tokens: self.message_tokens.cst_decode(),
+                dropped: self.dropped.cst_decode(),
+                truncated: self.truncated.cst_decode(),
+                summarized: self.summarized.cst_decode(),
+                summary: self.summary.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::dedup::DedupDocument> for wire_cst_dedup_document {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::dedup::DedupDocument {
+            crate::dedup::DedupDocument {
+                id: self.id.cst_decode(),
+                text: self.text.cst_decode(),
+                vector: self.vector.cst_decode(),
+                created_at: self.created_at.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::dedup::DedupOptions> for wire_cst_dedup_options {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::dedup::DedupOptions {
+            crate::dedup::DedupOptions {
+                shingle_words: self.shingle_words.cst_decode(),
+                num_hashes: self.num_hashes.cst_decode(),
+                bands: self.bands.cst_decode(),
+                min_jaccard: self.min_jaccard.cst_decode(),
+                min_cosine: self.min_cosine.cst_decode(),
+                min_span_words: self.min_span_words.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::dedup::DuplicateGroup> for wire_cst_duplicate_group {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::dedup::DuplicateGroup {
+            crate::dedup::DuplicateGroup {
+                ids: self.ids.cst_decode(),
+                canonical: self.canonical.cst_decode(),
+                canonical_note: self.canonical_note.cst_decode(),
+                pairs: self.pairs.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::dedup::DuplicatePair> for wire_cst_duplicate_pair {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::dedup::DuplicatePair {
+            crate::dedup::DuplicatePair {
+                a: self.a.cst_decode(),
+                b: self.b.cst_decode(),
+                jaccard: self.jaccard.cst_decode(),
+                cosine: self.cosine.cst_decode(),
+                spans: self.spans.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::dedup::DuplicateSpan> for wire_cst_duplicate_span {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::dedup::DuplicateSpan {
+            crate::dedup::DuplicateSpan {
+                a_start: self.a_start.cst_decode(),
+                a_end: self.a_end.cst_decode(),
+                b_start: self.b_start.cst_decode(),
+                b_end: self.b_end.cst_decode(),
+                words: self.words.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::embedding_cache::EmbeddingCacheStats> for wire_cst_embedding_cache_stats {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::embedding_cache::EmbeddingCacheStats {
+            crate::embedding_cache::EmbeddingCacheStats {
+                entries: self.entries.cst_decode(),
+                stale_records: self.stale_records.cst_decode(),
+                file_bytes: self.file_bytes.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::api::EmbeddingCluster> for wire_cst_embedding_cluster {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::EmbeddingCluster {
+            crate::api::EmbeddingCluster {
+                ids: self.ids.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::embedder::EmbeddingConfig> for wire_cst_embedding_config {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::embedder::EmbeddingConfig {
+            crate::embedder::EmbeddingConfig {
+                n_gpu_layers: self.n_gpu_layers.cst_decode(),
+                n_threads: self.n_threads.cst_decode(),
+                pooling: self.pooling.cst_decode(),
+                chunk_tokens: self.chunk_tokens.cst_decode(),
+                chunk_overlap: self.chunk_overlap.cst_decode(),
+                max_sequences: self.max_sequences.cst_decode(),
+                query_prefix: self.query_prefix.cst_decode(),
+                document_prefix: self.document_prefix.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::embeddings::EmbeddingEntry> for wire_cst_embedding_entry {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::embeddings::EmbeddingEntry {
+            crate::embeddings::EmbeddingEntry {
+                id: self.id.cst_decode(),
+                vector: self.vector.cst_decode(),
+                text_preview: self.text_preview.cst_decode(),
+                metadata: self.metadata.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::inference::GenerationChunk> for wire_cst_generation_chunk {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::inference::GenerationChunk {
+            crate::inference::GenerationChunk {
+                text: self.text.cst_decode(),
+                reasoning: self.reasoning.cst_decode(),
+                stop_reason: self.stop_reason.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::inference::GenerationOptions> for wire_cst_generation_options {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::inference::GenerationOptions {
+            crate::inference::GenerationOptions {
+                max_tokens: self.max_tokens.cst_decode(),
+                stop_sequences: self.stop_sequences.cst_decode(),
+                sampler: self.sampler.cst_decode(),
+                grammar: self.grammar.cst_decode(),
+                json_schema: self.json_schema.cst_decode(),
+                model_id: self.model_id.cst_decode(),
+                context: self.context.cst_decode(),
+                reasoning: self.reasoning.cst_decode(),
+                logprobs: self.logprobs.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::inference::GenerationPoll> for wire_cst_generation_poll {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::inference::GenerationPoll {
+            crate::inference::GenerationPoll {
+                text: self.text.cst_decode(),
+                reasoning: self.reasoning.cst_decode(),
+                result: self.result.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::inference::GenerationResult> for wire_cst_generation_result {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::inference::GenerationResult {
+            crate::inference::GenerationResult {
+                text: self.text.cst_decode(),
+                reasoning: self.reasoning.cst_decode(),
+                reasoning_tokens: self.reasoning_tokens.cst_decode(),
+                stop_reason: self.stop_reason.cst_decode(),
+                tokens_generated: self.tokens_generated.cst_decode(),
+                context: self.context.cst_decode(),
+                logprobs: self.logprobs.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::gguf::GgufMetadata> for wire_cst_gguf_metadata {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::gguf::GgufMetadata {
+            crate::gguf::GgufMetadata {
+                version: self.version.cst_decode(),
+                architecture: self.architecture.cst_decode(),
+                name: self.name.cst_decode(),
+                chat_template: self.chat_template.cst_decode(),
+                context_length: self.context_length.cst_decode(),
+                embedding_length: self.embedding_length.cst_decode(),
+                block_count: self.block_count.cst_decode(),
+                pooling_type: self.pooling_type.cst_decode(),
+                quantization: self.quantization.cst_decode(),
+                parameter_count: self.parameter_count.cst_decode(),
+                tensor_count: self.tensor_count.cst_decode(),
+                tokenizer_model: self.tokenizer_model.cst_decode(),
+                vocab_size: self.vocab_size.cst_decode(),
+                bos_token_id: self.bos_token_id.cst_decode(),
+                eos_token_id: self.eos_token_id.cst_decode(),
+                file_size: self.file_size.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::graph::GraphEdge> for wire_cst_graph_edge {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::graph::GraphEdge {
+            crate::graph::GraphEdge {
+                source: self.source.cst_decode(),
+                target: self.target.cst_decode(),
+                weight: self.weight.cst_decode(),
+                edge_type: self.edge_type.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::graph::GraphNode> for wire_cst_graph_node {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::graph::GraphNode {
+            crate::graph::GraphNode {
+                id: self.id.cst_decode(),
+                label: self.label.cst_decode(),
+                node_type: self.node_type.cst_decode(),
+                x: self.x.cst_decode(),
+                y: self.y.cst_decode(),
+                color: self.color.cst_decode(),
+                metadata: self.metadata.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::graph::GraphState> for wire_cst_graph_state {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::graph::GraphState {
+            crate::graph::GraphState {
+                nodes: self.nodes.cst_decode(),
+                edges: self.edges.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::hnsw::HnswConfig> for wire_cst_hnsw_config {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::hnsw::HnswConfig {
+            crate::hnsw::HnswConfig {
+                m: self.m.cst_decode(),
+                ef_construction: self.ef_construction.cst_decode(),
+                ef_search: self.ef_search.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::hybrid_search::HybridHit> for wire_cst_hybrid_hit {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::hybrid_search::HybridHit {
+            crate::hybrid_search::HybridHit {
+                id: self.id.cst_decode(),
+                score: self.score.cst_decode(),
+                semantic_score: self.semantic_score.cst_decode(),
+                semantic_rank: self.semantic_rank.cst_decode(),
+                lexical_score: self.lexical_score.cst_decode(),
+                lexical_rank: self.lexical_rank.cst_decode(),
+                matched_terms: self.matched_terms.cst_decode(),
+                text_preview: self.text_preview.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::hybrid_search::HybridOptions> for wire_cst_hybrid_options {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::hybrid_search::HybridOptions {
+            crate::hybrid_search::HybridOptions {
+                fusion: self.fusion.cst_decode(),
+                semantic_weight: self.semantic_weight.cst_decode(),
+                rrf_k: self.rrf_k.cst_decode(),
+                candidates: self.candidates.cst_decode(),
+                min_semantic_score: self.min_semantic_score.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::api::KnowledgeGraphAnalysis> for wire_cst_knowledge_graph_analysis {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::KnowledgeGraphAnalysis {
+            crate::api::KnowledgeGraphAnalysis {
+                clustering: self.clustering.cst_decode(),
+                semantic_edges: self.semantic_edges.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<Vec<String>> for *mut wire_cst_list_String {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<String> {
+            let vec = unsafe {
+                let wrap = flutter_rust_bridge::for_generated::box_from_leak_ptr(self);
+                flutter_rust_bridge::for_generated::vec_from_leak_ptr(wrap.ptr, wrap.len)
+            };
+            vec.into_iter().map(CstDecode::cst_decode).collect()
+        }
+    }
+    impl CstDecode<Vec<crate::agent::AgentStep>> for *mut wire_cst_list_agent_step {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<crate::agent::AgentStep> {
+            let vec = unsafe {
+                let wrap = flutter_rust_bridge::for_generated::box_from_leak_ptr(self);
+                flutter_rust_bridge::for_generated::vec_from_leak_ptr(wrap.ptr, wrap.len)
+            };
+            vec.into_iter().map(CstDecode::cst_decode).collect()
+        }
+    }
+    impl CstDecode<Vec<crate::clustering::ClusterAssignment>>
+        for *mut wire_cst_list_cluster_assignment
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<crate::clustering::ClusterAssignment> {
+            let vec = unsafe {
+                let wrap = flutter_rust_bridge::for_generated::box_from_leak_ptr(self);
+                flutter_rust_bridge::for_generated::vec_from_leak_ptr(wrap.ptr, wrap.len)
+            };
+            vec.into_iter().map(CstDecode::cst_decode).collect()
+        }
+    }
+    impl CstDecode<Vec<crate::clustering::ClusterInfo>> for *mut wire_cst_list_cluster_info {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<crate::clustering::ClusterInfo> {
+            let vec = unsafe {
+                let wrap = flutter_rust_bridge::for_generated::box_from_leak_ptr(self);
+                flutter_rust_bridge::for_generated::vec_from_leak_ptr(wrap.ptr, wrap.len)
+            };
+            vec.into_iter().map(CstDecode::cst_decode).collect()
+        }
+    }
+    impl CstDecode<Vec<crate::dedup::DedupDocument>> for *mut wire_cst_list_dedup_document {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<crate::dedup::DedupDocument> {
+            let vec = unsafe {
+                let wrap = flutter_rust_bridge::for_generated::box_from_leak_ptr(self);
+                flutter_rust_bridge::for_generated::vec_from_leak_ptr(wrap.ptr, wrap.len)
+            };
+            vec.into_iter().map(CstDecode::cst_decode).collect()
+        }
+    }
+    impl CstDecode<Vec<crate::dedup::DuplicateGroup>> for *mut wire_cst_list_duplicate_group {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<crate::dedup::DuplicateGroup> {
+            let vec = unsafe {
+                let wrap = flutter_rust_bridge::for_generated::box_from_leak_ptr(self);
+                flutter_rust_bridge::for_generated::vec_from_leak_ptr(wrap.ptr, wrap.len)
+            };
+            vec.into_iter().map(CstDecode::cst_decode).collect()
+        }
+    }
+    impl CstDecode<Vec<crate::dedup::DuplicatePair>> for *mut wire_cst_list_duplicate_pair {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<crate::dedup::DuplicatePair> {
+            let vec = unsafe {
+                let wrap = flutter_rust_bridge::for_generated::box_from_leak_ptr(self);
+                flutter_rust_bridge::for_generated::vec_from_leak_ptr(wrap.ptr, wrap.len)
+            };
+            vec.into_iter().map(CstDecode::cst_decode).collect()
+        }
+    }
+    impl CstDecode<Vec<crate::dedup::DuplicateSpan>> for *mut wire_cst_list_duplicate_span {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<crate::dedup::DuplicateSpan> {
+            let vec = unsafe {
+                let wrap = flutter_rust_bridge::for_generated::box_from_leak_ptr(self);
+                flutter_rust_bridge::for_generated::vec_from_leak_ptr(wrap.ptr, wrap.len)
+            };
+            vec.into_iter().map(CstDecode::cst_decode).collect()
+        }
+    }
+    impl CstDecode<Vec<crate::api::EmbeddingCluster>> for *mut wire_cst_list_embedding_cluster {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<crate::api::EmbeddingCluster> {
+            let vec = unsafe {
+                let wrap = flutter_rust_bridge::for_generated::box_from_leak_ptr(self);
+                flutter_rust_bridge::for_generated::vec_from_leak_ptr(wrap.ptr, wrap.len)
+            };
+            vec.into_iter().map(CstDecode::cst_decode).collect()
+        }
+    }
+    impl CstDecode<Vec<crate::embeddings::EmbeddingEntry>> for *mut wire_cst_list_embedding_entry {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<crate::embeddings::EmbeddingEntry> {
+            let vec = unsafe {
+                let wrap = flutter_rust_bridge::for_generated::box_from_leak_ptr(self);
+                flutter_rust_bridge::for_generated::vec_from_leak_ptr(wrap.ptr, wrap.len)
+            };
+            vec.into_iter().map(CstDecode::cst_decode).collect()
+        }
+    }
+    impl CstDecode<Vec<crate::graph::GraphEdge>> for *mut wire_cst_list_graph_edge {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<crate::graph::GraphEdge> {
+            let vec = unsafe {
+                let wrap = flutter_rust_bridge::for_generated::box_from_leak_ptr(self);
+                flutter_rust_bridge::for_generated::vec_from_leak_ptr(wrap.ptr, wrap.len)
+            };
+            vec.into_iter().map(CstDecode::cst_decode).collect()
+        }
+    }
+    impl CstDecode<Vec<crate::graph::GraphNode>> for *mut wire_cst_list_graph_node {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<crate::graph::GraphNode> {
+            let vec = unsafe {
+                let wrap = flutter_rust_bridge::for_generated::box_from_leak_ptr(self);
+                flutter_rust_bridge::for_generated::vec_from_leak_ptr(wrap.ptr, wrap.len)
+            };
+            vec.into_iter().map(CstDecode::cst_decode).collect()
+        }
+    }
+    impl CstDecode<Vec<crate::hybrid_search::HybridHit>> for *mut wire_cst_list_hybrid_hit {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<crate::hybrid_search::HybridHit> {
+            let vec = unsafe {
+                let wrap = flutter_rust_bridge::for_generated::box_from_leak_ptr(self);
+                flutter_rust_bridge::for_generated::vec_from_leak_ptr(wrap.ptr, wrap.len)
+            };
+            vec.into_iter().map(CstDecode::cst_decode).collect()
+        }
+    }
+    impl CstDecode<Vec<Vec<f32>>> for *mut wire_cst_list_list_prim_f_32_strict {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<Vec<f32>> {
+            let vec = unsafe {
+                let wrap = flutter_rust_bridge::for_generated::box_from_leak_ptr(self);
+                flutter_rust_bridge::for_generated::vec_from_leak_ptr(wrap.ptr, wrap.len)
+            };
+            vec.into_iter().map(CstDecode::cst_decode).collect()
+        }
+    }
+    impl CstDecode<Vec<crate::inference::LoadedModelInfo>> for *mut wire_cst_list_loaded_model_info {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<crate::inference::LoadedModelInfo> {
+            let vec = unsafe {
+                let wrap = flutter_rust_bridge::for_generated::box_from_leak_ptr(self);
+                flutter_rust_bridge::for_generated::vec_from_leak_ptr(wrap.ptr, wrap.len)
+            };
+            vec.into_iter().map(CstDecode::cst_decode).collect()
+        }
+    }
+    impl CstDecode<Vec<crate::chunker::MarkdownChunk>> for *mut wire_cst_list_markdown_chunk {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<crate::chunker::MarkdownChunk> {
+            let vec = unsafe {
+                let wrap = flutter_rust_bridge::for_generated::box_from_leak_ptr(self);
+                flutter_rust_bridge::for_generated::vec_from_leak_ptr(wrap.ptr, wrap.len)
+            };
+            vec.into_iter().map(CstDecode::cst_decode).collect()
+        }
+    }
+    impl CstDecode<Vec<crate::mcp::MCPParameter>> for *mut wire_cst_list_mcp_parameter {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<crate::mcp::MCPParameter> {
+            let vec = unsafe {
+                let wrap = flutter_rust_bridge::for_generated::box_from_leak_ptr(self);
+                flutter_rust_bridge::for_generated::vec_from_leak_ptr(wrap.ptr, wrap.len)
+            };
+            vec.into_iter().map(CstDecode::cst_decode).collect()
+        }
+    }
+    impl CstDecode<Vec<crate::mcp::MCPTool>> for *mut wire_cst_list_mcp_tool {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<crate::mcp::MCPTool> {
+            let vec = unsafe {
+                let wrap = flutter_rust_bridge::for_generated::box_from_leak_ptr(self);
+                flutter_rust_bridge::for_generated::vec_from_leak_ptr(wrap.ptr, wrap.len)
+            };
+            vec.into_iter().map(CstDecode::cst_decode).collect()
+        }
+    }
+    impl CstDecode<Vec<crate::mcp::MCPToolCall>> for *mut wire_cst_list_mcp_tool_call {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<crate::mcp::MCPToolCall> {
+            let vec = unsafe {
+                let wrap = flutter_rust_bridge::for_generated::box_from_leak_ptr(self);
+                flutter_rust_bridge::for_generated::vec_from_leak_ptr(wrap.ptr, wrap.len)
+            };
+            vec.into_iter().map(CstDecode::cst_decode).collect()
+        }
+    }
+    impl CstDecode<Vec<crate::mcp::MCPToolResult>> for *mut wire_cst_list_mcp_tool_result {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<crate::mcp::MCPToolResult> {
+            let vec = unsafe {
+                let wrap = flutter_rust_bridge::for_generated::box_from_leak_ptr(self);
+                flutter_rust_bridge::for_generated::vec_from_leak_ptr(wrap.ptr, wrap.len)
+            };
+            vec.into_iter().map(CstDecode::cst_decode).collect()
+        }
+    }
+    impl CstDecode<Vec<crate::streaming::NodePosition>> for *mut wire_cst_list_node_position {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<crate::streaming::NodePosition> {
+            let vec = unsafe {
+                let wrap = flutter_rust_bridge::for_generated::box_from_leak_ptr(self);
+                flutter_rust_bridge::for_generated::vec_from_leak_ptr(wrap.ptr, wrap.len)
+            };
+            vec.into_iter().map(CstDecode::cst_decode).collect()
+        }
+    }
+    impl CstDecode<Vec<f32>> for *mut wire_cst_list_prim_f_32_loose {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<f32> {
+            unsafe {
+                let wrap = flutter_rust_bridge::for_generated::box_from_leak_ptr(self);
+                flutter_rust_bridge::for_generated::vec_from_leak_ptr(wrap.ptr, wrap.len)
+            }
+        }
+    }
+    impl CstDecode<Vec<f32>> for *mut wire_cst_list_prim_f_32_strict {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<f32> {
+            unsafe {
+                let wrap = flutter_rust_bridge::for_generated::box_from_leak_ptr(self);
+                flutter_rust_bridge::for_generated::vec_from_leak_ptr(wrap.ptr, wrap.len)
+            }
+        }
+    }
+    impl CstDecode<Vec<i32>> for *mut wire_cst_list_prim_i_32_loose {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<i32> {
+            unsafe {
+                let wrap = flutter_rust_bridge::for_generated::box_from_leak_ptr(self);
+                flutter_rust_bridge::for_generated::vec_from_leak_ptr(wrap.ptr, wrap.len)
+            }
+        }
+    }
+    impl CstDecode<Vec<i32>> for *mut wire_cst_list_prim_i_32_strict {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<i32> {
+            unsafe {
+                let wrap = flutter_rust_bridge::for_generated::box_from_leak_ptr(self);
+                flutter_rust_bridge::for_generated::vec_from_leak_ptr(wrap.ptr, wrap.len)
+            }
+        }
+    }
+    impl CstDecode<Vec<u32>> for *mut wire_cst_list_prim_u_32_strict {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<u32> {
+            unsafe {
+                let wrap = flutter_rust_bridge::for_generated::box_from_leak_ptr(self);
+                flutter_rust_bridge::for_generated::vec_from_leak_ptr(wrap.ptr, wrap.len)
+            }
+        }
+    }
+    impl CstDecode<Vec<u8>> for *mut wire_cst_list_prim_u_8_strict {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<u8> {
+            unsafe {
+                let wrap = flutter_rust_bridge::for_generated::box_from_leak_ptr(self);
+                flutter_rust_bridge::for_generated::vec_from_leak_ptr(wrap.ptr, wrap.len)
+            }
+        }
+    }
+    impl CstDecode<Vec<crate::rag::RagSource>> for *mut wire_cst_list_rag_source {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<crate::rag::RagSource> {
+            let vec = unsafe {
+                let wrap = flutter_rust_bridge::for_generated::box_from_leak_ptr(self);
+                flutter_rust_bridge::for_generated::vec_from_leak_ptr(wrap.ptr, wrap.len)
+            };
+            vec.into_iter().map(CstDecode::cst_decode).collect()
+        }
+    }
+    impl CstDecode<Vec<(String, String)>> for *mut wire_cst_list_record_string_string {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<(String, String)> {
+            let vec = unsafe {
+                let wrap = flutter_rust_bridge::for_generated::box_from_leak_ptr(self);
+                flutter_rust_bridge::for_generated::vec_from_leak_ptr(wrap.ptr, wrap.len)
+            };
+            vec.into_iter().map(CstDecode::cst_decode).collect()
+        }
+    }
+    impl CstDecode<Vec<crate::tool_parser::ReplySpan>> for *mut wire_cst_list_reply_span {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<crate::tool_parser::ReplySpan> {
+            let vec = unsafe {
+                let wrap = flutter_rust_bridge::for_generated::box_from_leak_ptr(self);
+                flutter_rust_bridge::for_generated::vec_from_leak_ptr(wrap.ptr, wrap.len)
+            };
+            vec.into_iter().map(CstDecode::cst_decode).collect()
+        }
+    }
+    impl CstDecode<Vec<crate::clustering::SemanticEdge>> for *mut wire_cst_list_semantic_edge {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<crate::clustering::SemanticEdge> {
+            let vec = unsafe {
+                let wrap = flutter_rust_bridge::for_generated::box_from_leak_ptr(self);
+                flutter_rust_bridge::for_generated::vec_from_leak_ptr(wrap.ptr, wrap.len)
+            };
+            vec.into_iter().map(CstDecode::cst_decode).collect()
+        }
+    }
+    impl CstDecode<Vec<crate::embeddings::SimilarityResult>> for *mut wire_cst_list_similarity_result {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<crate::embeddings::SimilarityResult> {
+            let vec = unsafe {
+                let wrap = flutter_rust_bridge::for_generated::box_from_leak_ptr(self);
+                flutter_rust_bridge::for_generated::vec_from_leak_ptr(wrap.ptr, wrap.len)
+            };
+            vec.into_iter().map(CstDecode::cst_decode).collect()
+        }
+    }
+    impl CstDecode<Vec<crate::inference::TokenCandidate>> for *mut wire_cst_list_token_candidate {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<crate::inference::TokenCandidate> {
+            let vec = unsafe {
+                let wrap = flutter_rust_bridge::for_generated::box_from_leak_ptr(self);
+                flutter_rust_bridge::for_generated::vec_from_leak_ptr(wrap.ptr, wrap.len)
+            };
+            vec.into_iter().map(CstDecode::cst_decode).collect()
+        }
+    }
+    impl CstDecode<Vec<crate::inference::TokenLogprob>> for *mut wire_cst_list_token_logprob {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<crate::inference::TokenLogprob> {
+            let vec = unsafe {
+                let wrap = flutter_rust_bridge::for_generated::box_from_leak_ptr(self);
+                flutter_rust_bridge::for_generated::vec_from_leak_ptr(wrap.ptr, wrap.len)
+            };
+            vec.into_iter().map(CstDecode::cst_decode).collect()
+        }
+    }
+    impl CstDecode<Vec<crate::summarize::TranscriptSegment>> for *mut wire_cst_list_transcript_segment {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<crate::summarize::TranscriptSegment> {
+            let vec = unsafe {
+                let wrap = flutter_rust_bridge::for_generated::box_from_leak_ptr(self);
+                flutter_rust_bridge::for_generated::vec_from_leak_ptr(wrap.ptr, wrap.len)
+            };
+            vec.into_iter().map(CstDecode::cst_decode).collect()
+        }
+    }
+    impl CstDecode<crate::inference::LoadedModelInfo> for wire_cst_loaded_model_info {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::inference::LoadedModelInfo {
+            crate::inference::LoadedModelInfo {
+                model_id: self.model_id.cst_decode(),
+                model_path: self.model_path.cst_decode(),
+                size_bytes: self.size_bytes.cst_decode(),
+                is_default: self.is_default.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::chunker::MarkdownChunk> for wire_cst_markdown_chunk {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::chunker::MarkdownChunk {
+            crate::chunker::MarkdownChunk {
+                index: self.index.cst_decode(),
+                heading_path: self.heading_path.cst_decode(),
+                start: self.start.cst_decode(),
+                end: self.end.cst_decode(),
+                text: self.text.cst_decode(),
+                token_count: self.token_count.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::mcp::MCPParameter> for wire_cst_mcp_parameter {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::mcp::MCPParameter {
+            crate::mcp::MCPParameter {
+                name: self.name.cst_decode(),
+                description: self.description.cst_decode(),
+                param_type: self.param_type.cst_decode(),
+                required: self.required.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::mcp::MCPToolCall> for wire_cst_mcp_tool_call {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::mcp::MCPToolCall {
+            crate::mcp::MCPToolCall {
+                tool: self.tool.cst_decode(),
+                parameters_json: self.parameters_json.cst_decode(),
+                description: self.description.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::mcp::MCPToolResult> for wire_cst_mcp_tool_result {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::mcp::MCPToolResult {
+            crate::mcp::MCPToolResult {
+                success: self.success.cst_decode(),
+                result: self.result.cst_decode(),
+                tool: self.tool.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::streaming::NodePosition> for wire_cst_node_position {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::streaming::NodePosition {
+            crate::streaming::NodePosition {
+                id: self.id.cst_decode(),
+                x: self.x.cst_decode(),
+                y: self.y.cst_decode(),
+                radius: self.radius.cst_decode(),
+                color: self.color.cst_decode(),
+                node_type: self.node_type.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::embeddings::NoteMetadata> for wire_cst_note_metadata {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::embeddings::NoteMetadata {
+            crate::embeddings::NoteMetadata {
+                folder: self.folder.cst_decode(),
+                tags: self.tags.cst_decode(),
+                created_at: self.created_at.cst_decode(),
+                modified_at: self.modified_at.cst_decode(),
+                note_type: self.note_type.cst_decode(),
+                chunk_start: self.chunk_start.cst_decode(),
+                chunk_end: self.chunk_end.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::tool_parser::ParsedReply> for wire_cst_parsed_reply {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::tool_parser::ParsedReply {
+            crate::tool_parser::ParsedReply {
+                tool_calls: self.tool_calls.cst_decode(),
+                spans: self.spans.cst_decode(),
+                text: self.text.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::rag::RagAnswer> for wire_cst_rag_answer {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::rag::RagAnswer {
+            crate::rag::RagAnswer {
+                answer: self.answer.cst_decode(),
+                sources: self.sources.cst_decode(),
+                skipped: self.skipped.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::rag::RagSource> for wire_cst_rag_source {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::rag::RagSource {
+            crate::rag::RagSource {
+                citation: self.citation.cst_decode(),
+                note_id: self.note_id.cst_decode(),
+                chunk_offset: self.chunk_offset.cst_decode(),
+                chunk_index: self.chunk_index.cst_decode(),
+                score: self.score.cst_decode(),
+                text: self.text.cst_decode(),
+                cited: self.cited.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::reasoning::ReasoningOptions> for wire_cst_reasoning_options {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::reasoning::ReasoningOptions {
+            crate::reasoning::ReasoningOptions {
+                enabled: self.enabled.cst_decode(),
+                open_tag: self.open_tag.cst_decode(),
+                close_tag: self.close_tag.cst_decode(),
+                thinking_budget: self.thinking_budget.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::quantization::RecallReport> for wire_cst_recall_report {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::quantization::RecallReport {
+            crate::quantization::RecallReport {
+                queries: self.queries.cst_decode(),
+                top_k: self.top_k.cst_decode(),
+                recall: self.recall.cst_decode(),
+                rescored_recall: self.rescored_recall.cst_decode(),
+                f32_bytes: self.f32_bytes.cst_decode(),
+                quantized_bytes: self.quantized_bytes.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<(String, String)> for wire_cst_record_string_string {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> (String, String) {
+            (self.field0.cst_decode(), self.field1.cst_decode())
+        }
+    }
+    impl CstDecode<crate::tool_parser::ReplySpan> for wire_cst_reply_span {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::tool_parser::ReplySpan {
+            crate::tool_parser::ReplySpan {
+                start: self.start.cst_decode(),
+                end: self.end.cst_decode(),
+                kind: self.kind.cst_decode(),
+                call_count: self.call_count.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::inference::SamplerConfig> for wire_cst_sampler_config {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::inference::SamplerConfig {
+            crate::inference::SamplerConfig {
+                temperature: self.temperature.cst_decode(),
+                top_k: self.top_k.cst_decode(),
+                top_p: self.top_p.cst_decode(),
+                min_p: self.min_p.cst_decode(),
+                repeat_penalty: self.repeat_penalty.cst_decode(),
+                frequency_penalty: self.frequency_penalty.cst_decode(),
+                presence_penalty: self.presence_penalty.cst_decode(),
+                penalty_last_n: self.penalty_last_n.cst_decode(),
+                seed: self.seed.cst_decode(),
+                greedy: self.greedy.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::filtered_search::SearchOptions> for wire_cst_search_options {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::filtered_search::SearchOptions {
+            crate::filtered_search::SearchOptions {
+                top_k: self.top_k.cst_decode(),
+                threshold: self.threshold.cst_decode(),
+                filter: self.filter.cst_decode(),
+                mmr_lambda: self.mmr_lambda.cst_decode(),
+                mmr_candidates: self.mmr_candidates.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::clustering::SemanticEdge> for wire_cst_semantic_edge {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::clustering::SemanticEdge {
+            crate::clustering::SemanticEdge {
+                source: self.source.cst_decode(),
+                target: self.target.cst_decode(),
+                similarity: self.similarity.cst_decode(),
+                is_ghost: self.is_ghost.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::clustering::SemanticEdgeResult> for wire_cst_semantic_edge_result {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::clustering::SemanticEdgeResult {
+            crate::clustering::SemanticEdgeResult {
+                edges: self.edges.cst_decode(),
+                count: self.count.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::embeddings::SimilarityResult> for wire_cst_similarity_result {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::embeddings::SimilarityResult {
+            crate::embeddings::SimilarityResult {
+                id: self.id.cst_decode(),
+                score: self.score.cst_decode(),
+                text_preview: self.text_preview.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::api::StreamGraphStats> for wire_cst_stream_graph_stats {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::StreamGraphStats {
+            crate::api::StreamGraphStats {
+                node_count: self.node_count.cst_decode(),
+                edge_count: self.edge_count.cst_decode(),
+                visible_count: self.visible_count.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::summarize::Summary> for wire_cst_summary {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::summarize::Summary {
+            crate::summarize::Summary {
+                summary: self.summary.cst_decode(),
+                chunk_summaries: self.chunk_summaries.cst_decode(),
+                merge_levels: self.merge_levels.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::summarize::SummaryOptions> for wire_cst_summary_options {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::summarize::SummaryOptions {
+            crate::summarize::SummaryOptions {
+                style: self.style.cst_decode(),
+                max_words: self.max_words.cst_decode(),
+                chunk_tokens: self.chunk_tokens.cst_decode(),
+                model_id: self.model_id.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::inference::TokenCandidate> for wire_cst_token_candidate {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::inference::TokenCandidate {
+            crate::inference::TokenCandidate {
+                token: self.token.cst_decode(),
+                text: self.text.cst_decode(),
+                logprob: self.logprob.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::inference::TokenLogprob> for wire_cst_token_logprob {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::inference::TokenLogprob {
+            crate::inference::TokenLogprob {
+                token: self.token.cst_decode(),
+                text: self.text.cst_decode(),
+                logprob: self.logprob.cst_decode(),
+                top_alternatives: self.top_alternatives.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::tokenizer::TokenizerInfo> for wire_cst_tokenizer_info {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::tokenizer::TokenizerInfo {
+            crate::tokenizer::TokenizerInfo {
+                vocab_size: self.vocab_size.cst_decode(),
+                n_ctx_train: self.n_ctx_train.cst_decode(),
+                n_ctx: self.n_ctx.cst_decode(),
+                bos_token: self.bos_token.cst_decode(),
+                eos_token: self.eos_token.cst_decode(),
+                tokenizer_model: self.tokenizer_model.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::summarize::TranscriptSegment> for wire_cst_transcript_segment {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::summarize::TranscriptSegment {
+            crate::summarize::TranscriptSegment {
+                text: self.text.cst_decode(),
+                start_time: self.start_time.cst_decode(),
+                end_time: self.end_time.cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::vector_store::VectorStoreStats> for wire_cst_vector_store_stats {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::vector_store::VectorStoreStats {
+            crate::vector_store::VectorStoreStats {
+                entries: self.entries.cst_decode(),
+                dead_rows: self.dead_rows.cst_decode(),
+                dimension: self.dimension.cst_decode(),
+                file_bytes: self.file_bytes.cst_decode(),
+            }
+        }
+    }
+    impl NewWithNullPtr for wire_cst_agent_run {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                answer: core::ptr::null_mut(),
+                stop_reason: Default::default(),
+                steps: core::ptr::null_mut(),
+                messages: core::ptr::null_mut(),
+            }
+        }
+    }
+    impl Default for wire_cst_agent_run {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_agent_step {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                reply: core::ptr::null_mut(),
+                tool_calls: core::ptr::null_mut(),
+                results: core::ptr::null_mut(),
+            }
+        }
+    }
+    impl Default for wire_cst_agent_step {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_cluster_assignment {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                id: core::ptr::null_mut(),
+                cluster_id: Default::default(),
+                color: core::ptr::null_mut(),
+            }
+        }
+    }
+    impl Default for wire_cst_cluster_assignment {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_cluster_info {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                id: Default::default(),
+                size: Default::default(),
+                color: core::ptr::null_mut(),
+                centroid: core::ptr::null_mut(),
+            }
+        }
+    }
+    impl Default for wire_cst_cluster_info {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_clustering_result {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                assignments: core::ptr::null_mut(),
+                clusters: core::ptr::null_mut(),
+                k: Default::default(),
+            }
+        }
+    }
+    impl Default for wire_cst_clustering_result {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_context_policy {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                strategy: Default::default(),
+                summary_max_tokens: Default::default(),
+            }
+        }
+    }
+    impl Default for wire_cst_context_policy {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_context_report {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                context_size: Default::default(),
+                prompt_tokens: Default::default(),
+                message_tokens: core::ptr::null_mut(),
+                dropped: core::ptr::null_mut(),
+                truncated: core::ptr::null_mut(),
+                summarized: core::ptr::null_mut(),
+                summary: core::ptr::null_mut(),
+            }
+        }
+    }
+    impl Default for wire_cst_context_report {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_dedup_document {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                id: core::ptr::null_mut(),
+                text: core::ptr::null_mut(),
+                vector: core::ptr::null_mut(),
+                created_at: core::ptr::null_mut(),
+            }
+        }
+    }
+    impl Default for wire_cst_dedup_document {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_dedup_options {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                shingle_words: Default::default(),
+                num_hashes: Default::default(),
+                bands: Default::default(),
+                min_jaccard: Default::default(),
+                min_cosine: Default::default(),
+                min_span_words: Default::default(),
+            }
+        }
+    }
+    impl Default for wire_cst_dedup_options {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_duplicate_group {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                ids: core::ptr::null_mut(),
+                canonical: core::ptr::null_mut(),
+                canonical_note: core::ptr::null_mut(),
+                pairs: core::ptr::null_mut(),
+            }
+        }
+    }
+    impl Default for wire_cst_duplicate_group {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_duplicate_pair {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                a: core::ptr::null_mut(),
+                b: core::ptr::null_mut(),
+                jaccard: Default::default(),
+                cosine: core::ptr::null_mut(),
+                spans: core::ptr::null_mut(),
+            }
+        }
+    }
+    impl Default for wire_cst_duplicate_pair {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_duplicate_span {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                a_start: Default::default(),
+                a_end: Default::default(),
+                b_start: Default::default(),
+                b_end: Default::default(),
+                words: Default::default(),
+            }
+        }
+    }
+    impl Default for wire_cst_duplicate_span {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_embedding_cache_stats {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                entries: Default::default(),
+                stale_records: Default::default(),
+                file_bytes: Default::default(),
+            }
+        }
+    }
+    impl Default for wire_cst_embedding_cache_stats {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_embedding_cluster {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                ids: core::ptr::null_mut(),
+            }
+        }
+    }
+    impl Default for wire_cst_embedding_cluster {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_embedding_config {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                n_gpu_layers: Default::default(),
+                n_threads: Default::default(),
+                pooling: Default::default(),
+                chunk_tokens: Default::default(),
+                chunk_overlap: Default::default(),
+                max_sequences: Default::default(),
+                query_prefix: core::ptr::null_mut(),
+                document_prefix: core::ptr::null_mut(),
+            }
+        }
+    }
+    impl Default for wire_cst_embedding_config {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_embedding_entry {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                id: core::ptr::null_mut(),
+                vector: core::ptr::null_mut(),
+                text_preview: core::ptr::null_mut(),
+                metadata: Default::default(),
+            }
+        }
+    }
+    impl Default for wire_cst_embedding_entry {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_generation_chunk {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                text: core::ptr::null_mut(),
+                reasoning: core::ptr::null_mut(),
+                stop_reason: core::ptr::null_mut(),
+            }
+        }
+    }
+    impl Default for wire_cst_generation_chunk {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_generation_options {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                max_tokens: core::ptr::null_mut(),
+                stop_sequences: core::ptr::null_mut(),
+                sampler: core::ptr::null_mut(),
+                grammar: core::ptr::null_mut(),
+                json_schema: core::ptr::null_mut(),
+                model_id: core::ptr::null_mut(),
+                context: core::ptr::null_mut(),
+                reasoning: core::ptr::null_mut(),
+                logprobs: core::ptr::null_mut(),
+            }
+        }
+    }
+    impl Default for wire_cst_generation_options {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_generation_poll {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                text: core::ptr::null_mut(),
+                reasoning: core::ptr::null_mut(),
+                result: core::ptr::null_mut(),
+            }
+        }
+    }
+    impl Default for wire_cst_generation_poll {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_generation_result {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                text: core::ptr::null_mut(),
+                reasoning: core::ptr::null_mut(),
+                reasoning_tokens: Default::default(),
+                stop_reason: Default::default(),
+                tokens_generated: Default::default(),
+                context: core::ptr::null_mut(),
+                logprobs: core::ptr::null_mut(),
+            }
+        }
+    }
+    impl Default for wire_cst_generation_result {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_gguf_metadata {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                version: Default::default(),
+                architecture: core::ptr::null_mut(),
+                name: core::ptr::null_mut(),
+                chat_template: core::ptr::null_mut(),
+                context_length: core::ptr::null_mut(),
+                embedding_length: core::ptr::null_mut(),
+                block_count: core::ptr::null_mut(),
+                pooling_type: core::ptr::null_mut(),
+                quantization: core::ptr::null_mut(),
+                parameter_count: Default::default(),
+                tensor_count: Default::default(),
+                tokenizer_model: core::ptr::null_mut(),
+                vocab_size: core::ptr::null_mut(),
+                bos_token_id: core::ptr::null_mut(),
+                eos_token_id: core::ptr::null_mut(),
+                file_size: Default::default(),
+            }
+        }
+    }
+    impl Default for wire_cst_gguf_metadata {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_graph_edge {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                source: core::ptr::null_mut(),
+                target: core::ptr::null_mut(),
+                weight: Default::default(),
+                edge_type: core::ptr::null_mut(),
+            }
+        }
+    }
+    impl Default for wire_cst_graph_edge {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_graph_node {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                id: core::ptr::null_mut(),
+                label: core::ptr::null_mut(),
+                node_type: core::ptr::null_mut(),
+                x: Default::default(),
+                y: Default::default(),
+                color: core::ptr::null_mut(),
+                metadata: core::ptr::null_mut(),
+            }
+        }
+    }
+    impl Default for wire_cst_graph_node {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_graph_state {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                nodes: core::ptr::null_mut(),
+                edges: core::ptr::null_mut(),
+            }
+        }
+    }
+    impl Default for wire_cst_graph_state {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_hnsw_config {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                m: Default::default(),
+                ef_construction: Default::default(),
+                ef_search: Default::default(),
+            }
+        }
+    }
+    impl Default for wire_cst_hnsw_config {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_hybrid_hit {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                id: core::ptr::null_mut(),
+                score: Default::default(),
+                semantic_score: core::ptr::null_mut(),
+                semantic_rank: core::ptr::null_mut(),
+                lexical_score: core::ptr::null_mut(),
+                lexical_rank: core::ptr::null_mut(),
+                matched_terms: core::ptr::null_mut(),
+                text_preview: core::ptr::null_mut(),
+            }
+        }
+    }
+    impl Default for wire_cst_hybrid_hit {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_hybrid_options {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                fusion: Default::default(),
+                semantic_weight: Default::default(),
+                rrf_k: Default::default(),
+                candidates: Default::default(),
+                min_semantic_score: Default::default(),
+            }
+        }
+    }
+    impl Default for wire_cst_hybrid_options {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_knowledge_graph_analysis {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                clustering: Default::default(),
+                semantic_edges: Default::default(),
+            }
+        }
+    }
+    impl Default for wire_cst_knowledge_graph_analysis {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_loaded_model_info {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                model_id: core::ptr::null_mut(),
+                model_path: core::ptr::null_mut(),
+                size_bytes: Default::default(),
+                is_default: Default::default(),
+            }
+        }
+    }
+    impl Default for wire_cst_loaded_model_info {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_markdown_chunk {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                index: Default::default(),
+                heading_path: core::ptr::null_mut(),
+                start: Default::default(),
+                end: Default::default(),
+                text: core::ptr::null_mut(),
+                token_count: Default::default(),
+            }
+        }
+    }
+    impl Default for wire_cst_markdown_chunk {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_mcp_parameter {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                name: core::ptr::null_mut(),
+                description: core::ptr::null_mut(),
+                param_type: Default::default(),
+                required: Default::default(),
+            }
+        }
+    }
+    impl Default for wire_cst_mcp_parameter {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_mcp_tool_call {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                tool: core::ptr::null_mut(),
+                parameters_json: core::ptr::null_mut(),
+                description: core::ptr::null_mut(),
+            }
+        }
+    }
+    impl Default for wire_cst_mcp_tool_call {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_mcp_tool_result {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                success: Default::default(),
+                result: core::ptr::null_mut(),
+                tool: core::ptr::null_mut(),
+            }
+        }
+    }
+    impl Default for wire_cst_mcp_tool_result {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_node_position {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                id: core::ptr::null_mut(),
+                x: Default::default(),
+                y: Default::default(),
+                radius: Default::default(),
+                color: Default::default(),
+                node_type: core::ptr::null_mut(),
+            }
+        }
+    }
+    impl Default for wire_cst_node_position {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_note_metadata {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                folder: core::ptr::null_mut(),
+                tags: core::ptr::null_mut(),
+                created_at: core::ptr::null_mut(),
+                modified_at: core::ptr::null_mut(),
+                note_type: core::ptr::null_mut(),
+                chunk_start: core::ptr::null_mut(),
+                chunk_end: core::ptr::null_mut(),
+            }
+        }
+    }
+    impl Default for wire_cst_note_metadata {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_parsed_reply {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                tool_calls: core::ptr::null_mut(),
+                spans: core::ptr::null_mut(),
+                text: core::ptr::null_mut(),
+            }
+        }
+    }
+    impl Default for wire_cst_parsed_reply {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_rag_answer {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                answer: core::ptr::null_mut(),
+                sources: core::ptr::null_mut(),
+                skipped: Default::default(),
+            }
+        }
+    }
+    impl Default for wire_cst_rag_answer {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
         }
     }
-}
-impl CstDecode<u32> for u32 {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    fn cst_decode(self) -> u32 {
-        self
+    impl NewWithNullPtr for wire_cst_rag_source {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                citation: Default::default(),
+                note_id: core::ptr::null_mut(),
+                chunk_offset: core::ptr::null_mut(),
+                chunk_index: core::ptr::null_mut(),
+                score: Default::default(),
+                text: core::ptr::null_mut(),
+                cited: Default::default(),
+            }
+        }
+    }
+    impl Default for wire_cst_rag_source {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_reasoning_options {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                enabled: Default::default(),
+                open_tag: core::ptr::null_mut(),
+                close_tag: core::ptr::null_mut(),
+                thinking_budget: core::ptr::null_mut(),
+            }
+        }
+    }
+    impl Default for wire_cst_reasoning_options {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_recall_report {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                queries: Default::default(),
+                top_k: Default::default(),
+                recall: Default::default(),
+                rescored_recall: Default::default(),
+                f32_bytes: Default::default(),
+                quantized_bytes: Default::default(),
+            }
+        }
+    }
+    impl Default for wire_cst_recall_report {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_record_string_string {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                field0: core::ptr::null_mut(),
+                field1: core::ptr::null_mut(),
+            }
+        }
+    }
+    impl Default for wire_cst_record_string_string {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_reply_span {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                start: Default::default(),
+                end: Default::default(),
+                kind: Default::default(),
+                call_count: Default::default(),
+            }
+        }
+    }
+    impl Default for wire_cst_reply_span {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_sampler_config {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                temperature: Default::default(),
+                top_k: Default::default(),
+                top_p: Default::default(),
+                min_p: Default::default(),
+                repeat_penalty: Default::default(),
+                frequency_penalty: Default::default(),
+                presence_penalty: Default::default(),
+                penalty_last_n: Default::default(),
+                seed: core::ptr::null_mut(),
+                greedy: Default::default(),
+            }
+        }
+    }
+    impl Default for wire_cst_sampler_config {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_search_options {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                top_k: Default::default(),
+                threshold: Default::default(),
+                filter: core::ptr::null_mut(),
+                mmr_lambda: core::ptr::null_mut(),
+                mmr_candidates: Default::default(),
+            }
+        }
+    }
+    impl Default for wire_cst_search_options {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_semantic_edge {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                source: core::ptr::null_mut(),
+                target: core::ptr::null_mut(),
+                similarity: Default::default(),
+                is_ghost: Default::default(),
+            }
+        }
+    }
+    impl Default for wire_cst_semantic_edge {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_semantic_edge_result {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                edges: core::ptr::null_mut(),
+                count: Default::default(),
+            }
+        }
+    }
+    impl Default for wire_cst_semantic_edge_result {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_similarity_result {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                id: core::ptr::null_mut(),
+                score: Default::default(),
+                text_preview: core::ptr::null_mut(),
+            }
+        }
+    }
+    impl Default for wire_cst_similarity_result {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_stream_graph_stats {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                node_count: Default::default(),
+                edge_count: Default::default(),
+                visible_count: Default::default(),
+            }
+        }
+    }
+    impl Default for wire_cst_stream_graph_stats {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_summary {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                summary: core::ptr::null_mut(),
+                chunk_summaries: core::ptr::null_mut(),
+                merge_levels: Default::default(),
+            }
+        }
+    }
+    impl Default for wire_cst_summary {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_summary_options {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                style: Default::default(),
+                max_words: Default::default(),
+                chunk_tokens: Default::default(),
+                model_id: core::ptr::null_mut(),
+            }
+        }
+    }
+    impl Default for wire_cst_summary_options {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_token_candidate {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                token: Default::default(),
+                text: core::ptr::null_mut(),
+                logprob: Default::default(),
+            }
+        }
+    }
+    impl Default for wire_cst_token_candidate {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_token_logprob {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                token: Default::default(),
+                text: core::ptr::null_mut(),
+                logprob: Default::default(),
+                top_alternatives: core::ptr::null_mut(),
+            }
+        }
+    }
+    impl Default for wire_cst_token_logprob {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_tokenizer_info {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                vocab_size: Default::default(),
+                n_ctx_train: Default::default(),
+                n_ctx: Default::default(),
+                bos_token: Default::default(),
+                eos_token: Default::default(),
+                tokenizer_model: core::ptr::null_mut(),
+            }
+        }
+    }
+    impl Default for wire_cst_tokenizer_info {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_transcript_segment {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                text: core::ptr::null_mut(),
+                start_time: Default::default(),
+                end_time: Default::default(),
+            }
+        }
+    }
+    impl Default for wire_cst_transcript_segment {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+    impl NewWithNullPtr for wire_cst_vector_store_stats {
+        fn new_with_null_ptr() -> Self {
+            Self {
+                entries: Default::default(),
+                dead_rows: Default::default(),
+                dimension: Default::default(),
+                file_bytes: Default::default(),
+            }
+        }
+    }
+    impl Default for wire_cst_vector_store_stats {
+        fn default() -> Self {
+            Self::new_with_null_ptr()
+        }
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__add_graph_edge(
+        port_: i64,
+        source: *mut wire_cst_list_prim_u_8_strict,
+        target: *mut wire_cst_list_prim_u_8_strict,
+        weight: f32,
+        edge_type: *mut wire_cst_list_prim_u_8_strict,
+    ) {
+        wire__crate__api__add_graph_edge_impl(port_, source, target, weight, edge_type)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__add_graph_edges(
+        port_: i64,
+        edges: *mut wire_cst_list_graph_edge,
+    ) {
+        wire__crate__api__add_graph_edges_impl(port_, edges)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__add_graph_node(
+        port_: i64,
+        id: *mut wire_cst_list_prim_u_8_strict,
+        label: *mut wire_cst_list_prim_u_8_strict,
+        node_type: *mut wire_cst_list_prim_u_8_strict,
+        x: f32,
+        y: f32,
+        color: *mut wire_cst_list_prim_u_8_strict,
+        metadata: *mut wire_cst_list_prim_u_8_strict,
+    ) {
+        wire__crate__api__add_graph_node_impl(port_, id, label, node_type, x, y, color, metadata)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__add_graph_nodes(
+        port_: i64,
+        nodes: *mut wire_cst_list_graph_node,
+    ) {
+        wire__crate__api__add_graph_nodes_impl(port_, nodes)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__add_stream_edge(
+        port_: i64,
+        from_id: *mut wire_cst_list_prim_u_8_strict,
+        to_id: *mut wire_cst_list_prim_u_8_strict,
+        strength: f32,
+    ) {
+        wire__crate__api__add_stream_edge_impl(port_, from_id, to_id, strength)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__add_stream_node(
+        port_: i64,
+        id: *mut wire_cst_list_prim_u_8_strict,
+        x: f32,
+        y: f32,
+        radius: f32,
+        color: u32,
+    ) {
+        wire__crate__api__add_stream_node_impl(port_, id, x, y, radius, color)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__analyze_knowledge_graph(
+        port_: i64,
+        entries: *mut wire_cst_list_embedding_entry,
+        k: *mut usize,
+        similarity_threshold: *mut f32,
+        existing_links: *mut wire_cst_list_record_string_string,
+    ) {
+        wire__crate__api__analyze_knowledge_graph_impl(
+            port_,
+            entries,
+            k,
+            similarity_threshold,
+            existing_links,
+        )
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__analyze_knowledge_graph_in_store(
+        port_: i64,
+        store_id: u64,
+        k: *mut usize,
+        similarity_threshold: *mut f32,
+        existing_links: *mut wire_cst_list_record_string_string,
+    ) {
+        wire__crate__api__analyze_knowledge_graph_in_store_impl(
+            port_,
+            store_id,
+            k,
+            similarity_threshold,
+            existing_links,
+        )
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__batch_embed(
+        port_: i64,
+        texts: *mut wire_cst_list_String,
+    ) {
+        wire__crate__api__batch_embed_impl(port_, texts)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__batch_embed_with_model(
+        port_: i64,
+        texts: *mut wire_cst_list_String,
+        model_id: *mut wire_cst_list_prim_u_8_strict,
+    ) {
+        wire__crate__api__batch_embed_with_model_impl(port_, texts, model_id)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__cancel_generation(
+        request_id: u64,
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__api__cancel_generation_impl(request_id)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__chat_completion(
+        port_: i64,
+        messages: *mut wire_cst_list_record_string_string,
+        max_tokens: *mut u32,
+    ) {
+        wire__crate__api__chat_completion_impl(port_, messages, max_tokens)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__chat_completion_stream(
+        port_: i64,
+        messages: *mut wire_cst_list_record_string_string,
+        max_tokens: *mut u32,
+        stop_sequences: *mut wire_cst_list_String,
+        model_id: *mut wire_cst_list_prim_u_8_strict,
+        sink: *mut wire_cst_list_prim_u_8_strict,
+    ) {
+        wire__crate__api__chat_completion_stream_impl(
+            port_,
+            messages,
+            max_tokens,
+            stop_sequences,
+            model_id,
+            sink,
+        )
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__chat_completion_with_model(
+        port_: i64,
+        messages: *mut wire_cst_list_record_string_string,
+        max_tokens: *mut u32,
+        model_id: *mut wire_cst_list_prim_u_8_strict,
+    ) {
+        wire__crate__api__chat_completion_with_model_impl(port_, messages, max_tokens, model_id)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__chat_completion_with_options(
+        port_: i64,
+        messages: *mut wire_cst_list_record_string_string,
+        options: *mut wire_cst_generation_options,
+    ) {
+        wire__crate__api__chat_completion_with_options_impl(port_, messages, options)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__chat_session_completion(
+        port_: i64,
+        session_id: u64,
+        messages: *mut wire_cst_list_record_string_string,
+        options: *mut wire_cst_generation_options,
+    ) {
+        wire__crate__api__chat_session_completion_impl(port_, session_id, messages, options)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__chat_session_stream(
+        port_: i64,
+        session_id: u64,
+        messages: *mut wire_cst_list_record_string_string,
+        max_tokens: *mut u32,
+        stop_sequences: *mut wire_cst_list_String,
+        sink: *mut wire_cst_list_prim_u_8_strict,
+    ) {
+        wire__crate__api__chat_session_stream_impl(
+            port_,
+            session_id,
+            messages,
+            max_tokens,
+            stop_sequences,
+            sink,
+        )
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__chat_session_token_count(
+        session_id: u64,
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__api__chat_session_token_count_impl(session_id)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__chunk_markdown(
+        port_: i64,
+        text: *mut wire_cst_list_prim_u_8_strict,
+        max_tokens: *mut u32,
+        overlap_tokens: *mut u32,
+        model_id: *mut wire_cst_list_prim_u_8_strict,
+    ) {
+        wire__crate__api__chunk_markdown_impl(port_, text, max_tokens, overlap_tokens, model_id)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__clear_graph(
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__api__clear_graph_impl()
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__clear_stream_graph(
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__api__clear_stream_graph_impl()
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__close_chat_session(
+        session_id: u64,
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__api__close_chat_session_impl(session_id)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__close_embedding_cache(
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__api__close_embedding_cache_impl()
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__close_hnsw_index(
+        index_id: u64,
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__api__close_hnsw_index_impl(index_id)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__close_quantized_index(
+        index_id: u64,
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__api__close_quantized_index_impl(index_id)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__close_vector_store(
+        store_id: u64,
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__api__close_vector_store_impl(store_id)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__cluster_embeddings(
+        port_: i64,
+        entries: *mut wire_cst_list_embedding_entry,
+        threshold: f32,
+    ) {
+        wire__crate__api__cluster_embeddings_impl(port_, entries, threshold)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__cluster_notes(
+        port_: i64,
+        entries: *mut wire_cst_list_embedding_entry,
+        k: *mut usize,
+        max_iterations: *mut usize,
+    ) {
+        wire__crate__api__cluster_notes_impl(port_, entries, k, max_iterations)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__cluster_notes_in_store(
+        port_: i64,
+        store_id: u64,
+        k: *mut usize,
+        max_iterations: *mut usize,
+    ) {
+        wire__crate__api__cluster_notes_in_store_impl(port_, store_id, k, max_iterations)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__compact_embedding_cache(port_: i64) {
+        wire__crate__api__compact_embedding_cache_impl(port_)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__compact_vector_store(
+        port_: i64,
+        store_id: u64,
+    ) {
+        wire__crate__api__compact_vector_store_impl(port_, store_id)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__compute_graph_layout(
+        port_: i64,
+        iterations: *mut u32,
+    ) {
+        wire__crate__api__compute_graph_layout_impl(port_, iterations)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__connect_note_to_topics(
+        port_: i64,
+        note_id: *mut wire_cst_list_prim_u_8_strict,
+        topic_ids: *mut wire_cst_list_String,
+    ) {
+        wire__crate__api__connect_note_to_topics_impl(port_, note_id, topic_ids)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__cosine_similarity(
+        a: *mut wire_cst_list_prim_f_32_loose,
+        b: *mut wire_cst_list_prim_f_32_loose,
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__api__cosine_similarity_impl(a, b)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__count_chat_prompt_tokens(
+        port_: i64,
+        messages: *mut wire_cst_list_record_string_string,
+        model_id: *mut wire_cst_list_prim_u_8_strict,
+    ) {
+        wire__crate__api__count_chat_prompt_tokens_impl(port_, messages, model_id)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__count_chat_tokens(
+        port_: i64,
+        messages: *mut wire_cst_list_record_string_string,
+        model_id: *mut wire_cst_list_prim_u_8_strict,
+    ) {
+        wire__crate__api__count_chat_tokens_impl(port_, messages, model_id)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__count_tokens(
+        port_: i64,
+        text: *mut wire_cst_list_prim_u_8_strict,
+        model_id: *mut wire_cst_list_prim_u_8_strict,
+    ) {
+        wire__crate__api__count_tokens_impl(port_, text, model_id)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__create_chat_session(
+        port_: i64,
+        n_ctx: *mut u32,
+        model_id: *mut wire_cst_list_prim_u_8_strict,
+    ) {
+        wire__crate__api__create_chat_session_impl(port_, n_ctx, model_id)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__create_hnsw_index(
+        config: *mut wire_cst_hnsw_config,
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__api__create_hnsw_index_impl(config)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__create_hnsw_index_from_store(
+        port_: i64,
+        store_id: u64,
+        config: *mut wire_cst_hnsw_config,
+    ) {
+        wire__crate__api__create_hnsw_index_from_store_impl(port_, store_id, config)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__create_quantized_index(
+        port_: i64,
+        entries: *mut wire_cst_list_embedding_entry,
+        kind: i32,
+    ) {
+        wire__crate__api__create_quantized_index_impl(port_, entries, kind)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__create_quantized_index_from_store(
+        port_: i64,
+        store_id: u64,
+        kind: i32,
+    ) {
+        wire__crate__api__create_quantized_index_from_store_impl(port_, store_id, kind)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__default_context_policy(
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__api__default_context_policy_impl()
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__default_dedup_options(
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__api__default_dedup_options_impl()
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__default_embedding_config(
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__api__default_embedding_config_impl()
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__default_hnsw_config(
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__api__default_hnsw_config_impl()
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__default_hybrid_options(
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__api__default_hybrid_options_impl()
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__default_reasoning_options(
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__api__default_reasoning_options_impl()
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__default_sampler_config(
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__api__default_sampler_config_impl()
     }
-}
-impl CstDecode<u8> for u8 {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    fn cst_decode(self) -> u8 {
-        self
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__default_search_options(
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__api__default_search_options_impl()
     }
-}
-impl CstDecode<usize> for usize {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    fn cst_decode(self) -> usize {
-        self
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__default_summary_options(
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__api__default_summary_options_impl()
     }
-}
-impl SseDecode for flutter_rust_bridge::for_generated::anyhow::Error {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <String>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::anyhow::anyhow!("{}", inner);
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__detokenize_tokens(
+        port_: i64,
+        tokens: *mut wire_cst_list_prim_i_32_loose,
+        render_special: bool,
+        model_id: *mut wire_cst_list_prim_u_8_strict,
+    ) {
+        wire__crate__api__detokenize_tokens_impl(port_, tokens, render_special, model_id)
     }
-}
 
-impl SseDecode for String {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <Vec<u8>>::sse_decode(deserializer);
-        return String::from_utf8(inner).unwrap();
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__discover_semantic_edges(
+        port_: i64,
+        entries: *mut wire_cst_list_embedding_entry,
+        threshold: *mut f32,
+        existing_links: *mut wire_cst_list_record_string_string,
+    ) {
+        wire__crate__api__discover_semantic_edges_impl(port_, entries, threshold, existing_links)
     }
-}
 
-impl SseDecode for bool {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap() != 0
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__discover_semantic_edges_in_index(
+        port_: i64,
+        index_id: u64,
+        threshold: *mut f32,
+        existing_links: *mut wire_cst_list_record_string_string,
+        max_neighbors: *mut usize,
+    ) {
+        wire__crate__api__discover_semantic_edges_in_index_impl(
+            port_,
+            index_id,
+            threshold,
+            existing_links,
+            max_neighbors,
+        )
     }
-}
 
-impl SseDecode for crate::clustering::ClusterAssignment {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_id = <String>::sse_decode(deserializer);
-        let mut var_clusterId = <usize>::sse_decode(deserializer);
-        let mut var_color = <String>::sse_decode(deserializer);
-        return crate::clustering::ClusterAssignment {
-            id: var_id,
-            cluster_id: var_clusterId,
-            color: var_color,
-        };
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__discover_semantic_edges_in_store(
+        port_: i64,
+        store_id: u64,
+        threshold: *mut f32,
+        existing_links: *mut wire_cst_list_record_string_string,
+    ) {
+        wire__crate__api__discover_semantic_edges_in_store_impl(
+            port_,
+            store_id,
+            threshold,
+            existing_links,
+        )
     }
-}
 
-impl SseDecode for crate::clustering::ClusterInfo {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_id = <usize>::sse_decode(deserializer);
-        let mut var_size = <usize>::sse_decode(deserializer);
-        let mut var_color = <String>::sse_decode(deserializer);
-        let mut var_centroid = <Option<Vec<f32>>>::sse_decode(deserializer);
-        return crate::clustering::ClusterInfo {
-            id: var_id,
-            size: var_size,
-            color: var_color,
-            centroid: var_centroid,
-        };
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__embed_markdown_note(
+        port_: i64,
+        note_id: *mut wire_cst_list_prim_u_8_strict,
+        text: *mut wire_cst_list_prim_u_8_strict,
+        max_tokens: *mut u32,
+        overlap_tokens: *mut u32,
+        metadata: *mut wire_cst_note_metadata,
+    ) {
+        wire__crate__api__embed_markdown_note_impl(
+            port_,
+            note_id,
+            text,
+            max_tokens,
+            overlap_tokens,
+            metadata,
+        )
     }
-}
 
-impl SseDecode for crate::clustering::ClusteringResult {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_assignments =
-            <Vec<crate::clustering::ClusterAssignment>>::sse_decode(deserializer);
-        let mut var_clusters = <Vec<crate::clustering::ClusterInfo>>::sse_decode(deserializer);
-        let mut var_k = <usize>::sse_decode(deserializer);
-        return crate::clustering::ClusteringResult {
-            assignments: var_assignments,
-            clusters: var_clusters,
-            k: var_k,
-        };
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__embed_texts(
+        port_: i64,
+        texts: *mut wire_cst_list_String,
+        kind: i32,
+    ) {
+        wire__crate__api__embed_texts_impl(port_, texts, kind)
     }
-}
 
-impl SseDecode for crate::api::EmbeddingCluster {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_ids = <Vec<String>>::sse_decode(deserializer);
-        return crate::api::EmbeddingCluster { ids: var_ids };
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__extract_topics(
+        port_: i64,
+        text: *mut wire_cst_list_prim_u_8_strict,
+        num_topics: *mut u32,
+    ) {
+        wire__crate__api__extract_topics_impl(port_, text, num_topics)
     }
-}
 
-impl SseDecode for crate::embeddings::EmbeddingEntry {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_id = <String>::sse_decode(deserializer);
-        let mut var_vector = <Vec<f32>>::sse_decode(deserializer);
-        let mut var_textPreview = <Option<String>>::sse_decode(deserializer);
-        return crate::embeddings::EmbeddingEntry {
-            id: var_id,
-            vector: var_vector,
-            text_preview: var_textPreview,
-        };
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__extract_topics_with_model(
+        port_: i64,
+        text: *mut wire_cst_list_prim_u_8_strict,
+        num_topics: *mut u32,
+        model_id: *mut wire_cst_list_prim_u_8_strict,
+        min_confidence: *mut f32,
+    ) {
+        wire__crate__api__extract_topics_with_model_impl(
+            port_,
+            text,
+            num_topics,
+            model_id,
+            min_confidence,
+        )
     }
-}
 
-impl SseDecode for f32 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_f32::<NativeEndian>().unwrap()
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__find_duplicate_entries(
+        port_: i64,
+        entries: *mut wire_cst_list_embedding_entry,
+        options: *mut wire_cst_dedup_options,
+    ) {
+        wire__crate__api__find_duplicate_entries_impl(port_, entries, options)
     }
-}
 
-impl SseDecode for crate::graph::GraphEdge {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_source = <String>::sse_decode(deserializer);
-        let mut var_target = <String>::sse_decode(deserializer);
-        let mut var_weight = <f32>::sse_decode(deserializer);
-        let mut var_edgeType = <String>::sse_decode(deserializer);
-        return crate::graph::GraphEdge {
-            source: var_source,
-            target: var_target,
-            weight: var_weight,
-            edge_type: var_edgeType,
-        };
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__find_duplicates(
+        port_: i64,
+        documents: *mut wire_cst_list_dedup_document,
+        options: *mut wire_cst_dedup_options,
+    ) {
+        wire__crate__api__find_duplicates_impl(port_, documents, options)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__find_duplicates_in_store(
+        port_: i64,
+        store_id: u64,
+        options: *mut wire_cst_dedup_options,
+    ) {
+        wire__crate__api__find_duplicates_in_store_impl(port_, store_id, options)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__find_similar(
+        port_: i64,
+        query: *mut wire_cst_list_prim_f_32_loose,
+        entries: *mut wire_cst_list_embedding_entry,
+        top_k: usize,
+        threshold: f32,
+    ) {
+        wire__crate__api__find_similar_impl(port_, query, entries, top_k, threshold)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__find_similar_filtered(
+        port_: i64,
+        query: *mut wire_cst_list_prim_f_32_loose,
+        entries: *mut wire_cst_list_embedding_entry,
+        options: *mut wire_cst_search_options,
+    ) {
+        wire__crate__api__find_similar_filtered_impl(port_, query, entries, options)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__find_similar_in_index(
+        port_: i64,
+        index_id: u64,
+        query: *mut wire_cst_list_prim_f_32_loose,
+        top_k: usize,
+        threshold: f32,
+    ) {
+        wire__crate__api__find_similar_in_index_impl(port_, index_id, query, top_k, threshold)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__find_similar_in_store(
+        port_: i64,
+        store_id: u64,
+        query: *mut wire_cst_list_prim_f_32_loose,
+        top_k: usize,
+        threshold: f32,
+    ) {
+        wire__crate__api__find_similar_in_store_impl(port_, store_id, query, top_k, threshold)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__find_similar_quantized(
+        port_: i64,
+        index_id: u64,
+        query: *mut wire_cst_list_prim_f_32_loose,
+        top_k: usize,
+        threshold: f32,
+        rescore_candidates: *mut usize,
+    ) {
+        wire__crate__api__find_similar_quantized_impl(
+            port_,
+            index_id,
+            query,
+            top_k,
+            threshold,
+            rescore_candidates,
+        )
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__generate_text(
+        port_: i64,
+        prompt: *mut wire_cst_list_prim_u_8_strict,
+        max_tokens: *mut u32,
+    ) {
+        wire__crate__api__generate_text_impl(port_, prompt, max_tokens)
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__generate_text_stream(
+        port_: i64,
+        prompt: *mut wire_cst_list_prim_u_8_strict,
+        max_tokens: *mut u32,
+        stop_sequences: *mut wire_cst_list_String,
+        model_id: *mut wire_cst_list_prim_u_8_strict,
+        sink: *mut wire_cst_list_prim_u_8_strict,
+    ) {
+        wire__crate__api__generate_text_stream_impl(
+            port_,
+            prompt,
+            max_tokens,
+            stop_sequences,
+            model_id,
+            sink,
+        )
+    }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__generate_text_with_model(
+        port_: i64,
+        prompt: *mut wire_cst_list_prim_u_8_strict,
+        max_tokens: *mut u32,
+        model_id: *mut wire_cst_list_prim_u_8_strict,
+    ) {
+        wire__crate__api__generate_text_with_model_impl(port_, prompt, max_tokens, model_id)
     }
-}
 
-impl SseDecode for crate::graph::GraphNode {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_id = <String>::sse_decode(deserializer);
-        let mut var_label = <String>::sse_decode(deserializer);
-        let mut var_nodeType = <String>::sse_decode(deserializer);
-        let mut var_x = <f32>::sse_decode(deserializer);
-        let mut var_y = <f32>::sse_decode(deserializer);
-        let mut var_color = <Option<String>>::sse_decode(deserializer);
-        let mut var_metadata = <Option<String>>::sse_decode(deserializer);
-        return crate::graph::GraphNode {
-            id: var_id,
-            label: var_label,
-            node_type: var_nodeType,
-            x: var_x,
-            y: var_y,
-            color: var_color,
-            metadata: var_metadata,
-        };
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__generate_text_with_options(
+        port_: i64,
+        prompt: *mut wire_cst_list_prim_u_8_strict,
+        options: *mut wire_cst_generation_options,
+    ) {
+        wire__crate__api__generate_text_with_options_impl(port_, prompt, options)
     }
-}
 
-impl SseDecode for crate::graph::GraphState {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_nodes = <Vec<crate::graph::GraphNode>>::sse_decode(deserializer);
-        let mut var_edges = <Vec<crate::graph::GraphEdge>>::sse_decode(deserializer);
-        return crate::graph::GraphState {
-            nodes: var_nodes,
-            edges: var_edges,
-        };
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__get_embedding(
+        port_: i64,
+        text: *mut wire_cst_list_prim_u_8_strict,
+    ) {
+        wire__crate__api__get_embedding_impl(port_, text)
     }
-}
 
-impl SseDecode for i32 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__get_embedding_cache_stats(port_: i64) {
+        wire__crate__api__get_embedding_cache_stats_impl(port_)
     }
-}
 
-impl SseDecode for crate::api::KnowledgeGraphAnalysis {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_clustering = <crate::clustering::ClusteringResult>::sse_decode(deserializer);
-        let mut var_semanticEdges =
-            <crate::clustering::SemanticEdgeResult>::sse_decode(deserializer);
-        return crate::api::KnowledgeGraphAnalysis {
-            clustering: var_clustering,
-            semantic_edges: var_semanticEdges,
-        };
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__get_embedding_dimension(
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__api__get_embedding_dimension_impl()
     }
-}
 
-impl SseDecode for Vec<String> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = Vec::with_capacity(len_ as usize);
-        for idx_ in 0..len_ {
-            ans_.push(<String>::sse_decode(deserializer));
-        }
-        return ans_;
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__get_embedding_with_model(
+        port_: i64,
+        text: *mut wire_cst_list_prim_u_8_strict,
+        model_id: *mut wire_cst_list_prim_u_8_strict,
+    ) {
+        wire__crate__api__get_embedding_with_model_impl(port_, text, model_id)
     }
-}
 
-impl SseDecode for Vec<crate::clustering::ClusterAssignment> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = Vec::with_capacity(len_ as usize);
-        for idx_ in 0..len_ {
-            ans_.push(<crate::clustering::ClusterAssignment>::sse_decode(
-                deserializer,
-            ));
-        }
-        return ans_;
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__get_graph_state(port_: i64) {
+        wire__crate__api__get_graph_state_impl(port_)
     }
-}
 
-impl SseDecode for Vec<crate::clustering::ClusterInfo> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = Vec::with_capacity(len_ as usize);
-        for idx_ in 0..len_ {
-            ans_.push(<crate::clustering::ClusterInfo>::sse_decode(deserializer));
-        }
-        return ans_;
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__get_model_metadata(
+        model_id: *mut wire_cst_list_prim_u_8_strict,
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__api__get_model_metadata_impl(model_id)
     }
-}
 
-impl SseDecode for Vec<crate::api::EmbeddingCluster> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = Vec::with_capacity(len_ as usize);
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::EmbeddingCluster>::sse_decode(deserializer));
-        }
-        return ans_;
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__get_model_type(
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__api__get_model_type_impl()
     }
-}
 
-impl SseDecode for Vec<crate::embeddings::EmbeddingEntry> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = Vec::with_capacity(len_ as usize);
-        for idx_ in 0..len_ {
-            ans_.push(<crate::embeddings::EmbeddingEntry>::sse_decode(
-                deserializer,
-            ));
-        }
-        return ans_;
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__get_or_create_topic_hub(
+        port_: i64,
+        topic: *mut wire_cst_list_prim_u_8_strict,
+    ) {
+        wire__crate__api__get_or_create_topic_hub_impl(port_, topic)
     }
-}
 
-impl SseDecode for Vec<crate::graph::GraphEdge> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = Vec::with_capacity(len_ as usize);
-        for idx_ in 0..len_ {
-            ans_.push(<crate::graph::GraphEdge>::sse_decode(deserializer));
-        }
-        return ans_;
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__get_stream_graph_stats(
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__api__get_stream_graph_stats_impl()
     }
-}
 
-impl SseDecode for Vec<crate::graph::GraphNode> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = Vec::with_capacity(len_ as usize);
-        for idx_ in 0..len_ {
-            ans_.push(<crate::graph::GraphNode>::sse_decode(deserializer));
-        }
-        return ans_;
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__get_tokenizer_info(
+        model_id: *mut wire_cst_list_prim_u_8_strict,
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__api__get_tokenizer_info_impl(model_id)
     }
-}
 
-impl SseDecode for Vec<crate::mcp::MCPParameter> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = Vec::with_capacity(len_ as usize);
-        for idx_ in 0..len_ {
-            ans_.push(<crate::mcp::MCPParameter>::sse_decode(deserializer));
-        }
-        return ans_;
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__get_vector_store_stats(
+        port_: i64,
+        store_id: u64,
+    ) {
+        wire__crate__api__get_vector_store_stats_impl(port_, store_id)
     }
-}
 
-impl SseDecode for Vec<crate::mcp::MCPTool> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = Vec::with_capacity(len_ as usize);
-        for idx_ in 0..len_ {
-            ans_.push(<crate::mcp::MCPTool>::sse_decode(deserializer));
-        }
-        return ans_;
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__get_version(
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__api__get_version_impl()
     }
-}
 
-impl SseDecode for Vec<crate::streaming::NodePosition> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = Vec::with_capacity(len_ as usize);
-        for idx_ in 0..len_ {
-            ans_.push(<crate::streaming::NodePosition>::sse_decode(deserializer));
-        }
-        return ans_;
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__get_visible_graph_nodes(port_: i64) {
+        wire__crate__api__get_visible_graph_nodes_impl(port_)
     }
-}
 
-impl SseDecode for Vec<f32> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = Vec::with_capacity(len_ as usize);
-        for idx_ in 0..len_ {
-            ans_.push(<f32>::sse_decode(deserializer));
-        }
-        return ans_;
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__greedy_sampler_config(
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__api__greedy_sampler_config_impl()
     }
-}
 
-impl SseDecode for Vec<u8> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = Vec::with_capacity(len_ as usize);
-        for idx_ in 0..len_ {
-            ans_.push(<u8>::sse_decode(deserializer));
-        }
-        return ans_;
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__health_check(
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__api__health_check_impl()
     }
-}
 
-impl SseDecode for Vec<(String, String)> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = Vec::with_capacity(len_ as usize);
-        for idx_ in 0..len_ {
-            ans_.push(<(String, String)>::sse_decode(deserializer));
-        }
-        return ans_;
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__hnsw_index_insert(
+        port_: i64,
+        index_id: u64,
+        entries: *mut wire_cst_list_embedding_entry,
+    ) {
+        wire__crate__api__hnsw_index_insert_impl(port_, index_id, entries)
     }
-}
 
-impl SseDecode for Vec<crate::clustering::SemanticEdge> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = Vec::with_capacity(len_ as usize);
-        for idx_ in 0..len_ {
-            ans_.push(<crate::clustering::SemanticEdge>::sse_decode(deserializer));
-        }
-        return ans_;
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__hnsw_index_insert_with_texts(
+        port_: i64,
+        index_id: u64,
+        entries: *mut wire_cst_list_embedding_entry,
+        texts: *mut wire_cst_list_String,
+    ) {
+        wire__crate__api__hnsw_index_insert_with_texts_impl(port_, index_id, entries, texts)
     }
-}
 
-impl SseDecode for Vec<crate::embeddings::SimilarityResult> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = Vec::with_capacity(len_ as usize);
-        for idx_ in 0..len_ {
-            ans_.push(<crate::embeddings::SimilarityResult>::sse_decode(
-                deserializer,
-            ));
-        }
-        return ans_;
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__hnsw_index_len(
+        index_id: u64,
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__api__hnsw_index_len_impl(index_id)
     }
-}
 
-impl SseDecode for crate::mcp::MCPParamType {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <i32>::sse_decode(deserializer);
-        return match inner {
-            0 => crate::mcp::MCPParamType::String,
-            1 => crate::mcp::MCPParamType::Boolean,
-            2 => crate::mcp::MCPParamType::Integer,
-            3 => crate::mcp::MCPParamType::Array,
-            _ => unreachable!("Invalid variant for MCPParamType: {}", inner),
-        };
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__hnsw_index_remove(
+        port_: i64,
+        index_id: u64,
+        ids: *mut wire_cst_list_String,
+    ) {
+        wire__crate__api__hnsw_index_remove_impl(port_, index_id, ids)
     }
-}
 
-impl SseDecode for crate::mcp::MCPParameter {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_name = <String>::sse_decode(deserializer);
-        let mut var_description = <String>::sse_decode(deserializer);
-        let mut var_paramType = <crate::mcp::MCPParamType>::sse_decode(deserializer);
-        let mut var_required_ = <bool>::sse_decode(deserializer);
-        return crate::mcp::MCPParameter {
-            name: var_name,
-            description: var_description,
-            param_type: var_paramType,
-            required: var_required_,
-        };
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__hybrid_search(
+        port_: i64,
+        query_text: *mut wire_cst_list_prim_u_8_strict,
+        entries: *mut wire_cst_list_embedding_entry,
+        top_k: usize,
+        options: *mut wire_cst_hybrid_options,
+    ) {
+        wire__crate__api__hybrid_search_impl(port_, query_text, entries, top_k, options)
     }
-}
 
-impl SseDecode for crate::mcp::MCPTool {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <i32>::sse_decode(deserializer);
-        return match inner {
-            0 => crate::mcp::MCPTool::ReadFile,
-            1 => crate::mcp::MCPTool::WriteFile,
-            2 => crate::mcp::MCPTool::DeleteFile,
-            3 => crate::mcp::MCPTool::CreateFolder,
-            4 => crate::mcp::MCPTool::ListFiles,
-            5 => crate::mcp::MCPTool::CalendarLua,
-            6 => crate::mcp::MCPTool::TimerLua,
-            7 => crate::mcp::MCPTool::ExportMarkdown,
-            _ => unreachable!("Invalid variant for MCPTool: {}", inner),
-        };
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__hybrid_search_in_index(
+        port_: i64,
+        index_id: u64,
+        query_text: *mut wire_cst_list_prim_u_8_strict,
+        top_k: usize,
+        options: *mut wire_cst_hybrid_options,
+    ) {
+        wire__crate__api__hybrid_search_in_index_impl(port_, index_id, query_text, top_k, options)
     }
-}
 
-impl SseDecode for crate::mcp::MCPToolCall {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_tool = <String>::sse_decode(deserializer);
-        let mut var_parametersJson = <String>::sse_decode(deserializer);
-        let mut var_description = <String>::sse_decode(deserializer);
-        return crate::mcp::MCPToolCall {
-            tool: var_tool,
-            parameters_json: var_parametersJson,
-            description: var_description,
-        };
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__init_graph(
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__api__init_graph_impl()
     }
-}
 
-impl SseDecode for crate::mcp::MCPToolResult {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_success = <bool>::sse_decode(deserializer);
-        let mut var_result = <String>::sse_decode(deserializer);
-        let mut var_tool = <String>::sse_decode(deserializer);
-        return crate::mcp::MCPToolResult {
-            success: var_success,
-            result: var_result,
-            tool: var_tool,
-        };
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__init_mcp(
+        port_: i64,
+        base_path: *mut wire_cst_list_prim_u_8_strict,
+        max_file_size: *mut usize,
+        allowed_extensions: *mut wire_cst_list_String,
+    ) {
+        wire__crate__api__init_mcp_impl(port_, base_path, max_file_size, allowed_extensions)
     }
-}
 
-impl SseDecode for crate::streaming::NodePosition {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_id = <String>::sse_decode(deserializer);
-        let mut var_x = <f32>::sse_decode(deserializer);
-        let mut var_y = <f32>::sse_decode(deserializer);
-        let mut var_radius = <f32>::sse_decode(deserializer);
-        let mut var_color = <u32>::sse_decode(deserializer);
-        let mut var_nodeType = <String>::sse_decode(deserializer);
-        return crate::streaming::NodePosition {
-            id: var_id,
-            x: var_x,
-            y: var_y,
-            radius: var_radius,
-            color: var_color,
-            node_type: var_nodeType,
-        };
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__init_model(
+        model_path: *mut wire_cst_list_prim_u_8_strict,
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__api__init_model_impl(model_path)
     }
-}
 
-impl SseDecode for Option<String> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<String>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__init_model_with_config(
+        port_: i64,
+        model_path: *mut wire_cst_list_prim_u_8_strict,
+        n_gpu_layers: u32,
+        n_ctx: u32,
+        n_threads: i32,
+        temperature: f32,
+        top_p: f32,
+        max_tokens: u32,
+        model_type: *mut i32,
+    ) {
+        wire__crate__api__init_model_with_config_impl(
+            port_,
+            model_path,
+            n_gpu_layers,
+            n_ctx,
+            n_threads,
+            temperature,
+            top_p,
+            max_tokens,
+            model_type,
+        )
     }
-}
 
-impl SseDecode for Option<f32> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<f32>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__inspect_model(
+        port_: i64,
+        model_path: *mut wire_cst_list_prim_u_8_strict,
+    ) {
+        wire__crate__api__inspect_model_impl(port_, model_path)
     }
-}
 
-impl SseDecode for Option<i32> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<i32>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__is_embedding_model_loaded(
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__api__is_embedding_model_loaded_impl()
     }
-}
 
-impl SseDecode for Option<u32> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<u32>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__is_graph_stream_running(
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__api__is_graph_stream_running_impl()
     }
-}
 
-impl SseDecode for Option<usize> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<usize>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__is_mcp_initialized(
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__api__is_mcp_initialized_impl()
     }
-}
 
-impl SseDecode for Option<Vec<String>> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<Vec<String>>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__is_model_loaded(
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__api__is_model_loaded_impl()
     }
-}
 
-impl SseDecode for Option<Vec<f32>> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<Vec<f32>>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__json_schema_to_grammar(
+        schema_json: *mut wire_cst_list_prim_u_8_strict,
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__api__json_schema_to_grammar_impl(schema_json)
     }
-}
 
-impl SseDecode for Option<Vec<(String, String)>> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<Vec<(String, String)>>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__list_loaded_models(
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__api__list_loaded_models_impl()
     }
-}
 
-impl SseDecode for (String, String) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_field0 = <String>::sse_decode(deserializer);
-        let mut var_field1 = <String>::sse_decode(deserializer);
-        return (var_field0, var_field1);
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__load_chat_session(
+        port_: i64,
+        path: *mut wire_cst_list_prim_u_8_strict,
+        n_ctx: *mut u32,
+        model_id: *mut wire_cst_list_prim_u_8_strict,
+    ) {
+        wire__crate__api__load_chat_session_impl(port_, path, n_ctx, model_id)
     }
-}
 
-impl SseDecode for crate::clustering::SemanticEdge {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_source = <String>::sse_decode(deserializer);
-        let mut var_target = <String>::sse_decode(deserializer);
-        let mut var_similarity = <f32>::sse_decode(deserializer);
-        let mut var_isGhost = <bool>::sse_decode(deserializer);
-        return crate::clustering::SemanticEdge {
-            source: var_source,
-            target: var_target,
-            similarity: var_similarity,
-            is_ghost: var_isGhost,
-        };
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__load_embedding_model(
+        port_: i64,
+        model_path: *mut wire_cst_list_prim_u_8_strict,
+        config: *mut wire_cst_embedding_config,
+    ) {
+        wire__crate__api__load_embedding_model_impl(port_, model_path, config)
     }
-}
 
-impl SseDecode for crate::clustering::SemanticEdgeResult {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_edges = <Vec<crate::clustering::SemanticEdge>>::sse_decode(deserializer);
-        let mut var_count = <usize>::sse_decode(deserializer);
-        return crate::clustering::SemanticEdgeResult {
-            edges: var_edges,
-            count: var_count,
-        };
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__load_hnsw_index(
+        port_: i64,
+        path: *mut wire_cst_list_prim_u_8_strict,
+    ) {
+        wire__crate__api__load_hnsw_index_impl(port_, path)
     }
-}
 
-impl SseDecode for crate::embeddings::SimilarityResult {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_id = <String>::sse_decode(deserializer);
-        let mut var_score = <f32>::sse_decode(deserializer);
-        let mut var_textPreview = <Option<String>>::sse_decode(deserializer);
-        return crate::embeddings::SimilarityResult {
-            id: var_id,
-            score: var_score,
-            text_preview: var_textPreview,
-        };
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__load_model(
+        port_: i64,
+        model_id: *mut wire_cst_list_prim_u_8_strict,
+        model_path: *mut wire_cst_list_prim_u_8_strict,
+        n_ctx: *mut u32,
+        n_gpu_layers: *mut u32,
+    ) {
+        wire__crate__api__load_model_impl(port_, model_id, model_path, n_ctx, n_gpu_layers)
     }
-}
 
-impl SseDecode for crate::api::StreamGraphStats {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_nodeCount = <usize>::sse_decode(deserializer);
-        let mut var_edgeCount = <usize>::sse_decode(deserializer);
-        let mut var_visibleCount = <usize>::sse_decode(deserializer);
-        return crate::api::StreamGraphStats {
-            node_count: var_nodeCount,
-            edge_count: var_edgeCount,
-            visible_count: var_visibleCount,
-        };
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__load_quantized_index(
+        port_: i64,
+        path: *mut wire_cst_list_prim_u_8_strict,
+        store_id: *mut u64,
+    ) {
+        wire__crate__api__load_quantized_index_impl(port_, path, store_id)
     }
-}
 
-impl SseDecode for crate::mcp::TaskCategory {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <i32>::sse_decode(deserializer);
-        return match inner {
-            0 => crate::mcp::TaskCategory::Conversation,
-            1 => crate::mcp::TaskCategory::ToolUse,
-            2 => crate::mcp::TaskCategory::CodeGeneration,
-            _ => unreachable!("Invalid variant for TaskCategory: {}", inner),
-        };
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__mcp_classify_task(
+        message: *mut wire_cst_list_prim_u_8_strict,
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__api__mcp_classify_task_impl(message)
     }
-}
 
-impl SseDecode for u32 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u32::<NativeEndian>().unwrap()
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__mcp_classify_task_with_model(
+        port_: i64,
+        message: *mut wire_cst_list_prim_u_8_strict,
+        model_id: *mut wire_cst_list_prim_u_8_strict,
+        min_confidence: *mut f32,
+    ) {
+        wire__crate__api__mcp_classify_task_with_model_impl(
+            port_,
+            message,
+            model_id,
+            min_confidence,
+        )
     }
-}
 
-impl SseDecode for u8 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap()
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__mcp_create_folder(
+        port_: i64,
+        path: *mut wire_cst_list_prim_u_8_strict,
+    ) {
+        wire__crate__api__mcp_create_folder_impl(port_, path)
     }
-}
 
-impl SseDecode for () {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
-}
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__mcp_delete_file(
+        port_: i64,
+        path: *mut wire_cst_list_prim_u_8_strict,
+    ) {
+        wire__crate__api__mcp_delete_file_impl(port_, path)
+    }
 
-impl SseDecode for usize {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u64::<NativeEndian>().unwrap() as _
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__mcp_execute_tool_call(
+        port_: i64,
+        tool_call: *mut wire_cst_mcp_tool_call,
+    ) {
+        wire__crate__api__mcp_execute_tool_call_impl(port_, tool_call)
     }
-}
 
-fn pde_ffi_dispatcher_primary_impl(
-    func_id: i32,
-    port: flutter_rust_bridge::for_generated::MessagePort,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        _ => unreachable!(),
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__mcp_generate_tool_call(
+        port_: i64,
+        messages: *mut wire_cst_list_record_string_string,
+        max_tokens: *mut u32,
+        model_id: *mut wire_cst_list_prim_u_8_strict,
+    ) {
+        wire__crate__api__mcp_generate_tool_call_impl(port_, messages, max_tokens, model_id)
     }
-}
 
-fn pde_ffi_dispatcher_sync_impl(
-    func_id: i32,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        _ => unreachable!(),
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__mcp_get_all_tools(
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__api__mcp_get_all_tools_impl()
     }
-}
 
-// Section: rust2dart
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__mcp_get_model_for_task(
+        category: i32,
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__api__mcp_get_model_for_task_impl(category)
+    }
 
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::clustering::ClusterAssignment {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.id.into_into_dart().into_dart(),
-            self.cluster_id.into_into_dart().into_dart(),
-            self.color.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__mcp_get_model_id_for_task(
+        category: i32,
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__api__mcp_get_model_id_for_task_impl(category)
     }
-}
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::clustering::ClusterAssignment
-{
-}
-impl flutter_rust_bridge::IntoIntoDart<crate::clustering::ClusterAssignment>
-    for crate::clustering::ClusterAssignment
-{
-    fn into_into_dart(self) -> crate::clustering::ClusterAssignment {
-        self
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__mcp_get_tool_description(
+        tool: i32,
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__api__mcp_get_tool_description_impl(tool)
     }
-}
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::clustering::ClusterInfo {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.id.into_into_dart().into_dart(),
-            self.size.into_into_dart().into_dart(),
-            self.color.into_into_dart().into_dart(),
-            self.centroid.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__mcp_get_tool_name(
+        tool: i32,
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__api__mcp_get_tool_name_impl(tool)
     }
-}
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::clustering::ClusterInfo
-{
-}
-impl flutter_rust_bridge::IntoIntoDart<crate::clustering::ClusterInfo>
-    for crate::clustering::ClusterInfo
-{
-    fn into_into_dart(self) -> crate::clustering::ClusterInfo {
-        self
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__mcp_get_tool_parameters(
+        tool: i32,
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__api__mcp_get_tool_parameters_impl(tool)
     }
-}
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::clustering::ClusteringResult {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.assignments.into_into_dart().into_dart(),
-            self.clusters.into_into_dart().into_dart(),
-            self.k.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__mcp_get_tool_schemas(
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__api__mcp_get_tool_schemas_impl()
     }
-}
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::clustering::ClusteringResult
-{
-}
-impl flutter_rust_bridge::IntoIntoDart<crate::clustering::ClusteringResult>
-    for crate::clustering::ClusteringResult
-{
-    fn into_into_dart(self) -> crate::clustering::ClusteringResult {
-        self
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__mcp_list_files(
+        port_: i64,
+        path: *mut wire_cst_list_prim_u_8_strict,
+    ) {
+        wire__crate__api__mcp_list_files_impl(port_, path)
     }
-}
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::EmbeddingCluster {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [self.ids.into_into_dart().into_dart()].into_dart()
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__mcp_parse_tool_call(
+        port_: i64,
+        json: *mut wire_cst_list_prim_u_8_strict,
+    ) {
+        wire__crate__api__mcp_parse_tool_call_impl(port_, json)
     }
-}
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::EmbeddingCluster {}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::EmbeddingCluster>
-    for crate::api::EmbeddingCluster
-{
-    fn into_into_dart(self) -> crate::api::EmbeddingCluster {
-        self
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__mcp_parse_tool_calls(
+        reply: *mut wire_cst_list_prim_u_8_strict,
+        model_type: *mut i32,
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__api__mcp_parse_tool_calls_impl(reply, model_type)
     }
-}
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::embeddings::EmbeddingEntry {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.id.into_into_dart().into_dart(),
-            self.vector.into_into_dart().into_dart(),
-            self.text_preview.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__mcp_read_file(
+        port_: i64,
+        path: *mut wire_cst_list_prim_u_8_strict,
+    ) {
+        wire__crate__api__mcp_read_file_impl(port_, path)
     }
-}
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::embeddings::EmbeddingEntry
-{
-}
-impl flutter_rust_bridge::IntoIntoDart<crate::embeddings::EmbeddingEntry>
-    for crate::embeddings::EmbeddingEntry
-{
-    fn into_into_dart(self) -> crate::embeddings::EmbeddingEntry {
-        self
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__mcp_run_agent(
+        port_: i64,
+        messages: *mut wire_cst_list_record_string_string,
+        max_steps: *mut u32,
+        max_tokens: *mut u32,
+        model_id: *mut wire_cst_list_prim_u_8_strict,
+    ) {
+        wire__crate__api__mcp_run_agent_impl(port_, messages, max_steps, max_tokens, model_id)
     }
-}
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::graph::GraphEdge {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.source.into_into_dart().into_dart(),
-            self.target.into_into_dart().into_dart(),
-            self.weight.into_into_dart().into_dart(),
-            self.edge_type.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__mcp_run_agent_stream(
+        port_: i64,
+        messages: *mut wire_cst_list_record_string_string,
+        max_steps: *mut u32,
+        max_tokens: *mut u32,
+        model_id: *mut wire_cst_list_prim_u_8_strict,
+        sink: *mut wire_cst_list_prim_u_8_strict,
+    ) {
+        wire__crate__api__mcp_run_agent_stream_impl(
+            port_, messages, max_steps, max_tokens, model_id, sink,
+        )
     }
-}
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::graph::GraphEdge {}
-impl flutter_rust_bridge::IntoIntoDart<crate::graph::GraphEdge> for crate::graph::GraphEdge {
-    fn into_into_dart(self) -> crate::graph::GraphEdge {
-        self
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__mcp_validate_path(
+        path: *mut wire_cst_list_prim_u_8_strict,
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__api__mcp_validate_path_impl(path)
     }
-}
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::graph::GraphNode {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.id.into_into_dart().into_dart(),
-            self.label.into_into_dart().into_dart(),
-            self.node_type.into_into_dart().into_dart(),
-            self.x.into_into_dart().into_dart(),
-            self.y.into_into_dart().into_dart(),
-            self.color.into_into_dart().into_dart(),
-            self.metadata.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__mcp_write_file(
+        port_: i64,
+        path: *mut wire_cst_list_prim_u_8_strict,
+        content: *mut wire_cst_list_prim_u_8_strict,
+    ) {
+        wire__crate__api__mcp_write_file_impl(port_, path, content)
     }
-}
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::graph::GraphNode {}
-impl flutter_rust_bridge::IntoIntoDart<crate::graph::GraphNode> for crate::graph::GraphNode {
-    fn into_into_dart(self) -> crate::graph::GraphNode {
-        self
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__measure_quantization_recall(
+        port_: i64,
+        entries: *mut wire_cst_list_embedding_entry,
+        queries: *mut wire_cst_list_list_prim_f_32_strict,
+        kind: i32,
+        top_k: usize,
+        rescore_candidates: usize,
+    ) {
+        wire__crate__api__measure_quantization_recall_impl(
+            port_,
+            entries,
+            queries,
+            kind,
+            top_k,
+            rescore_candidates,
+        )
     }
-}
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::graph::GraphState {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.nodes.into_into_dart().into_dart(),
-            self.edges.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__open_embedding_cache(
+        port_: i64,
+        path: *mut wire_cst_list_prim_u_8_strict,
+    ) {
+        wire__crate__api__open_embedding_cache_impl(port_, path)
     }
-}
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::graph::GraphState {}
-impl flutter_rust_bridge::IntoIntoDart<crate::graph::GraphState> for crate::graph::GraphState {
-    fn into_into_dart(self) -> crate::graph::GraphState {
-        self
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__open_vector_store(
+        port_: i64,
+        path: *mut wire_cst_list_prim_u_8_strict,
+    ) {
+        wire__crate__api__open_vector_store_impl(port_, path)
     }
-}
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::KnowledgeGraphAnalysis {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.clustering.into_into_dart().into_dart(),
-            self.semantic_edges.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__pin_stream_node(
+        port_: i64,
+        id: *mut wire_cst_list_prim_u_8_strict,
+        pinned: bool,
+    ) {
+        wire__crate__api__pin_stream_node_impl(port_, id, pinned)
     }
-}
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::KnowledgeGraphAnalysis
-{
-}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::KnowledgeGraphAnalysis>
-    for crate::api::KnowledgeGraphAnalysis
-{
-    fn into_into_dart(self) -> crate::api::KnowledgeGraphAnalysis {
-        self
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__poll_generation(
+        request_id: u64,
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__api__poll_generation_impl(request_id)
     }
-}
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::mcp::MCPParamType {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        match self {
-            Self::String => 0.into_dart(),
-            Self::Boolean => 1.into_dart(),
-            Self::Integer => 2.into_dart(),
-            Self::Array => 3.into_dart(),
-            _ => unreachable!(),
-        }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__quantized_index_insert(
+        port_: i64,
+        index_id: u64,
+        entries: *mut wire_cst_list_embedding_entry,
+    ) {
+        wire__crate__api__quantized_index_insert_impl(port_, index_id, entries)
     }
-}
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::mcp::MCPParamType {}
-impl flutter_rust_bridge::IntoIntoDart<crate::mcp::MCPParamType> for crate::mcp::MCPParamType {
-    fn into_into_dart(self) -> crate::mcp::MCPParamType {
-        self
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__quantized_index_remove(
+        port_: i64,
+        index_id: u64,
+        ids: *mut wire_cst_list_String,
+    ) {
+        wire__crate__api__quantized_index_remove_impl(port_, index_id, ids)
     }
-}
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::mcp::MCPParameter {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.name.into_into_dart().into_dart(),
-            self.description.into_into_dart().into_dart(),
-            self.param_type.into_into_dart().into_dart(),
-            self.required.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__rag_answer(
+        port_: i64,
+        question: *mut wire_cst_list_prim_u_8_strict,
+        chunks: *mut wire_cst_list_embedding_entry,
+        top_k: *mut u32,
+        context_tokens: *mut u32,
+        max_tokens: *mut u32,
+        model_id: *mut wire_cst_list_prim_u_8_strict,
+    ) {
+        wire__crate__api__rag_answer_impl(
+            port_,
+            question,
+            chunks,
+            top_k,
+            context_tokens,
+            max_tokens,
+            model_id,
+        )
     }
-}
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::mcp::MCPParameter {}
-impl flutter_rust_bridge::IntoIntoDart<crate::mcp::MCPParameter> for crate::mcp::MCPParameter {
-    fn into_into_dart(self) -> crate::mcp::MCPParameter {
-        self
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__remove_graph_node(
+        port_: i64,
+        node_id: *mut wire_cst_list_prim_u_8_strict,
+    ) {
+        wire__crate__api__remove_graph_node_impl(port_, node_id)
     }
-}
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::mcp::MCPTool {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        match self {
-            Self::ReadFile => 0.into_dart(),
-            Self::WriteFile => 1.into_dart(),
-            Self::DeleteFile => 2.into_dart(),
-            Self::CreateFolder => 3.into_dart(),
-            Self::ListFiles => 4.into_dart(),
-            Self::CalendarLua => 5.into_dart(),
-            Self::TimerLua => 6.into_dart(),
-            Self::ExportMarkdown => 7.into_dart(),
-            _ => unreachable!(),
-        }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__remove_stream_edge(
+        port_: i64,
+        from_id: *mut wire_cst_list_prim_u_8_strict,
+        to_id: *mut wire_cst_list_prim_u_8_strict,
+    ) {
+        wire__crate__api__remove_stream_edge_impl(port_, from_id, to_id)
     }
-}
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::mcp::MCPTool {}
-impl flutter_rust_bridge::IntoIntoDart<crate::mcp::MCPTool> for crate::mcp::MCPTool {
-    fn into_into_dart(self) -> crate::mcp::MCPTool {
-        self
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__remove_stream_node(
+        port_: i64,
+        id: *mut wire_cst_list_prim_u_8_strict,
+    ) {
+        wire__crate__api__remove_stream_node_impl(port_, id)
     }
-}
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::mcp::MCPToolCall {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.tool.into_into_dart().into_dart(),
-            self.parameters_json.into_into_dart().into_dart(),
-            self.description.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__save_chat_session(
+        port_: i64,
+        session_id: u64,
+        path: *mut wire_cst_list_prim_u_8_strict,
+    ) {
+        wire__crate__api__save_chat_session_impl(port_, session_id, path)
     }
-}
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::mcp::MCPToolCall {}
-impl flutter_rust_bridge::IntoIntoDart<crate::mcp::MCPToolCall> for crate::mcp::MCPToolCall {
-    fn into_into_dart(self) -> crate::mcp::MCPToolCall {
-        self
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__save_hnsw_index(
+        port_: i64,
+        index_id: u64,
+        path: *mut wire_cst_list_prim_u_8_strict,
+    ) {
+        wire__crate__api__save_hnsw_index_impl(port_, index_id, path)
     }
-}
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::mcp::MCPToolResult {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.success.into_into_dart().into_dart(),
-            self.result.into_into_dart().into_dart(),
-            self.tool.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__save_quantized_index(
+        port_: i64,
+        index_id: u64,
+        path: *mut wire_cst_list_prim_u_8_strict,
+    ) {
+        wire__crate__api__save_quantized_index_impl(port_, index_id, path)
     }
-}
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::mcp::MCPToolResult {}
-impl flutter_rust_bridge::IntoIntoDart<crate::mcp::MCPToolResult> for crate::mcp::MCPToolResult {
-    fn into_into_dart(self) -> crate::mcp::MCPToolResult {
-        self
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__semantic_search(
+        port_: i64,
+        query_text: *mut wire_cst_list_prim_u_8_strict,
+        entries: *mut wire_cst_list_embedding_entry,
+        top_k: usize,
+    ) {
+        wire__crate__api__semantic_search_impl(port_, query_text, entries, top_k)
     }
-}
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::streaming::NodePosition {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.id.into_into_dart().into_dart(),
-            self.x.into_into_dart().into_dart(),
-            self.y.into_into_dart().into_dart(),
-            self.radius.into_into_dart().into_dart(),
-            self.color.into_into_dart().into_dart(),
-            self.node_type.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__semantic_search_filtered(
+        port_: i64,
+        query_text: *mut wire_cst_list_prim_u_8_strict,
+        entries: *mut wire_cst_list_embedding_entry,
+        options: *mut wire_cst_search_options,
+    ) {
+        wire__crate__api__semantic_search_filtered_impl(port_, query_text, entries, options)
     }
-}
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::streaming::NodePosition
-{
-}
-impl flutter_rust_bridge::IntoIntoDart<crate::streaming::NodePosition>
-    for crate::streaming::NodePosition
-{
-    fn into_into_dart(self) -> crate::streaming::NodePosition {
-        self
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__semantic_search_in_index(
+        port_: i64,
+        index_id: u64,
+        query_text: *mut wire_cst_list_prim_u_8_strict,
+        top_k: usize,
+    ) {
+        wire__crate__api__semantic_search_in_index_impl(port_, index_id, query_text, top_k)
     }
-}
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::clustering::SemanticEdge {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.source.into_into_dart().into_dart(),
-            self.target.into_into_dart().into_dart(),
-            self.similarity.into_into_dart().into_dart(),
-            self.is_ghost.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__semantic_search_in_index_filtered(
+        port_: i64,
+        index_id: u64,
+        query_text: *mut wire_cst_list_prim_u_8_strict,
+        options: *mut wire_cst_search_options,
+    ) {
+        wire__crate__api__semantic_search_in_index_filtered_impl(
+            port_, index_id, query_text, options,
+        )
     }
-}
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::clustering::SemanticEdge
-{
-}
-impl flutter_rust_bridge::IntoIntoDart<crate::clustering::SemanticEdge>
-    for crate::clustering::SemanticEdge
-{
-    fn into_into_dart(self) -> crate::clustering::SemanticEdge {
-        self
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__semantic_search_in_store(
+        port_: i64,
+        store_id: u64,
+        query_text: *mut wire_cst_list_prim_u_8_strict,
+        top_k: usize,
+    ) {
+        wire__crate__api__semantic_search_in_store_impl(port_, store_id, query_text, top_k)
     }
-}
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::clustering::SemanticEdgeResult {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.edges.into_into_dart().into_dart(),
-            self.count.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__semantic_search_in_store_filtered(
+        port_: i64,
+        store_id: u64,
+        query_text: *mut wire_cst_list_prim_u_8_strict,
+        options: *mut wire_cst_search_options,
+    ) {
+        wire__crate__api__semantic_search_in_store_filtered_impl(
+            port_, store_id, query_text, options,
+        )
     }
-}
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::clustering::SemanticEdgeResult
-{
-}
-impl flutter_rust_bridge::IntoIntoDart<crate::clustering::SemanticEdgeResult>
-    for crate::clustering::SemanticEdgeResult
-{
-    fn into_into_dart(self) -> crate::clustering::SemanticEdgeResult {
-        self
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__set_default_model(
+        model_id: *mut wire_cst_list_prim_u_8_strict,
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__api__set_default_model_impl(model_id)
     }
-}
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::embeddings::SimilarityResult {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.id.into_into_dart().into_dart(),
-            self.score.into_into_dart().into_dart(),
-            self.text_preview.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__set_model_memory_budget(
+        budget_bytes: *mut u64,
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__api__set_model_memory_budget_impl(budget_bytes)
     }
-}
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::embeddings::SimilarityResult
-{
-}
-impl flutter_rust_bridge::IntoIntoDart<crate::embeddings::SimilarityResult>
-    for crate::embeddings::SimilarityResult
-{
-    fn into_into_dart(self) -> crate::embeddings::SimilarityResult {
-        self
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__set_stream_node_position(
+        port_: i64,
+        id: *mut wire_cst_list_prim_u_8_strict,
+        x: f32,
+        y: f32,
+    ) {
+        wire__crate__api__set_stream_node_position_impl(port_, id, x, y)
     }
-}
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::StreamGraphStats {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.node_count.into_into_dart().into_dart(),
-            self.edge_count.into_into_dart().into_dart(),
-            self.visible_count.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__start_chat_completion(
+        port_: i64,
+        messages: *mut wire_cst_list_record_string_string,
+        max_tokens: *mut u32,
+        stop_sequences: *mut wire_cst_list_String,
+        model_id: *mut wire_cst_list_prim_u_8_strict,
+    ) {
+        wire__crate__api__start_chat_completion_impl(
+            port_,
+            messages,
+            max_tokens,
+            stop_sequences,
+            model_id,
+        )
     }
-}
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::StreamGraphStats {}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::StreamGraphStats>
-    for crate::api::StreamGraphStats
-{
-    fn into_into_dart(self) -> crate::api::StreamGraphStats {
-        self
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__start_generation(
+        port_: i64,
+        prompt: *mut wire_cst_list_prim_u_8_strict,
+        max_tokens: *mut u32,
+        stop_sequences: *mut wire_cst_list_String,
+        model_id: *mut wire_cst_list_prim_u_8_strict,
+    ) {
+        wire__crate__api__start_generation_impl(port_, prompt, max_tokens, stop_sequences, model_id)
     }
-}
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::mcp::TaskCategory {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        match self {
-            Self::Conversation => 0.into_dart(),
-            Self::ToolUse => 1.into_dart(),
-            Self::CodeGeneration => 2.into_dart(),
-            _ => unreachable!(),
-        }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__start_graph_stream(port_: i64) {
+        wire__crate__api__start_graph_stream_impl(port_)
     }
-}
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::mcp::TaskCategory {}
-impl flutter_rust_bridge::IntoIntoDart<crate::mcp::TaskCategory> for crate::mcp::TaskCategory {
-    fn into_into_dart(self) -> crate::mcp::TaskCategory {
-        self
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__stop_graph_stream(
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__api__stop_graph_stream_impl()
     }
-}
 
-impl SseEncode for flutter_rust_bridge::for_generated::anyhow::Error {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(format!("{:?}", self), serializer);
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__summarize_text(
+        port_: i64,
+        text: *mut wire_cst_list_prim_u_8_strict,
+        options: *mut wire_cst_summary_options,
+    ) {
+        wire__crate__api__summarize_text_impl(port_, text, options)
     }
-}
 
-impl SseEncode for String {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <Vec<u8>>::sse_encode(self.into_bytes(), serializer);
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__summarize_transcript(
+        port_: i64,
+        segments: *mut wire_cst_list_transcript_segment,
+        options: *mut wire_cst_summary_options,
+    ) {
+        wire__crate__api__summarize_transcript_impl(port_, segments, options)
     }
-}
 
-impl SseEncode for bool {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_u8(self as _).unwrap();
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__tokenize_text(
+        port_: i64,
+        text: *mut wire_cst_list_prim_u_8_strict,
+        add_bos: bool,
+        parse_special: bool,
+        model_id: *mut wire_cst_list_prim_u_8_strict,
+    ) {
+        wire__crate__api__tokenize_text_impl(port_, text, add_bos, parse_special, model_id)
     }
-}
 
-impl SseEncode for crate::clustering::ClusterAssignment {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(self.id, serializer);
-        <usize>::sse_encode(self.cluster_id, serializer);
-        <String>::sse_encode(self.color, serializer);
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__unload_embedding_model(
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__api__unload_embedding_model_impl()
     }
-}
 
-impl SseEncode for crate::clustering::ClusterInfo {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <usize>::sse_encode(self.id, serializer);
-        <usize>::sse_encode(self.size, serializer);
-        <String>::sse_encode(self.color, serializer);
-        <Option<Vec<f32>>>::sse_encode(self.centroid, serializer);
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__unload_model(
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__api__unload_model_impl()
     }
-}
 
-impl SseEncode for crate::clustering::ClusteringResult {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <Vec<crate::clustering::ClusterAssignment>>::sse_encode(self.assignments, serializer);
-        <Vec<crate::clustering::ClusterInfo>>::sse_encode(self.clusters, serializer);
-        <usize>::sse_encode(self.k, serializer);
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__unload_model_by_id(
+        model_id: *mut wire_cst_list_prim_u_8_strict,
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__api__unload_model_by_id_impl(model_id)
     }
-}
 
-impl SseEncode for crate::api::EmbeddingCluster {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <Vec<String>>::sse_encode(self.ids, serializer);
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__update_graph_viewport(
+        port_: i64,
+        x: f32,
+        y: f32,
+        width: f32,
+        height: f32,
+        scale: f32,
+    ) {
+        wire__crate__api__update_graph_viewport_impl(port_, x, y, width, height, scale)
     }
-}
 
-impl SseEncode for crate::embeddings::EmbeddingEntry {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(self.id, serializer);
-        <Vec<f32>>::sse_encode(self.vector, serializer);
-        <Option<String>>::sse_encode(self.text_preview, serializer);
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__use_llama_inference_backend(
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__api__use_llama_inference_backend_impl()
     }
-}
 
-impl SseEncode for f32 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_f32::<NativeEndian>(self).unwrap();
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__use_mock_inference_backend(
+        completions: *mut wire_cst_list_String,
+        fallback: *mut wire_cst_list_prim_u_8_strict,
+        embedding_dimension: u32,
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__api__use_mock_inference_backend_impl(
+            completions,
+            fallback,
+            embedding_dimension,
+        )
     }
-}
 
-impl SseEncode for crate::graph::GraphEdge {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(self.source, serializer);
-        <String>::sse_encode(self.target, serializer);
-        <f32>::sse_encode(self.weight, serializer);
-        <String>::sse_encode(self.edge_type, serializer);
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__validate_search_filter(
+        filter: *mut wire_cst_list_prim_u_8_strict,
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__api__validate_search_filter_impl(filter)
     }
-}
 
-impl SseEncode for crate::graph::GraphNode {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(self.id, serializer);
-        <String>::sse_encode(self.label, serializer);
-        <String>::sse_encode(self.node_type, serializer);
-        <f32>::sse_encode(self.x, serializer);
-        <f32>::sse_encode(self.y, serializer);
-        <Option<String>>::sse_encode(self.color, serializer);
-        <Option<String>>::sse_encode(self.metadata, serializer);
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__vector_store_delete(
+        port_: i64,
+        store_id: u64,
+        ids: *mut wire_cst_list_String,
+    ) {
+        wire__crate__api__vector_store_delete_impl(port_, store_id, ids)
     }
-}
 
-impl SseEncode for crate::graph::GraphState {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <Vec<crate::graph::GraphNode>>::sse_encode(self.nodes, serializer);
-        <Vec<crate::graph::GraphEdge>>::sse_encode(self.edges, serializer);
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__vector_store_get(
+        port_: i64,
+        store_id: u64,
+        ids: *mut wire_cst_list_String,
+    ) {
+        wire__crate__api__vector_store_get_impl(port_, store_id, ids)
     }
-}
 
-impl SseEncode for i32 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_i32::<NativeEndian>(self).unwrap();
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__vector_store_len(
+        store_id: u64,
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__api__vector_store_len_impl(store_id)
     }
-}
 
-impl SseEncode for crate::api::KnowledgeGraphAnalysis {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <crate::clustering::ClusteringResult>::sse_encode(self.clustering, serializer);
-        <crate::clustering::SemanticEdgeResult>::sse_encode(self.semantic_edges, serializer);
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__vector_store_upsert(
+        port_: i64,
+        store_id: u64,
+        entries: *mut wire_cst_list_embedding_entry,
+    ) {
+        wire__crate__api__vector_store_upsert_impl(port_, store_id, entries)
     }
-}
 
-impl SseEncode for Vec<String> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <String>::sse_encode(item, serializer);
-        }
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_wire__crate__api__wait_for_generation(
+        port_: i64,
+        request_id: u64,
+    ) {
+        wire__crate__api__wait_for_generation_impl(port_, request_id)
     }
-}
 
-impl SseEncode for Vec<crate::clustering::ClusterAssignment> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::clustering::ClusterAssignment>::sse_encode(item, serializer);
-        }
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_cst_new_box_autoadd_context_policy(
+    ) -> *mut wire_cst_context_policy {
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(
+            wire_cst_context_policy::new_with_null_ptr(),
+        )
     }
-}
 
-impl SseEncode for Vec<crate::clustering::ClusterInfo> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::clustering::ClusterInfo>::sse_encode(item, serializer);
-        }
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_cst_new_box_autoadd_context_report(
+    ) -> *mut wire_cst_context_report {
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(
+            wire_cst_context_report::new_with_null_ptr(),
+        )
     }
-}
 
-impl SseEncode for Vec<crate::api::EmbeddingCluster> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::EmbeddingCluster>::sse_encode(item, serializer);
-        }
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_cst_new_box_autoadd_dedup_options(
+    ) -> *mut wire_cst_dedup_options {
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(
+            wire_cst_dedup_options::new_with_null_ptr(),
+        )
     }
-}
 
-impl SseEncode for Vec<crate::embeddings::EmbeddingEntry> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::embeddings::EmbeddingEntry>::sse_encode(item, serializer);
-        }
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_cst_new_box_autoadd_embedding_cache_stats(
+    ) -> *mut wire_cst_embedding_cache_stats {
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(
+            wire_cst_embedding_cache_stats::new_with_null_ptr(),
+        )
     }
-}
 
-impl SseEncode for Vec<crate::graph::GraphEdge> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::graph::GraphEdge>::sse_encode(item, serializer);
-        }
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_cst_new_box_autoadd_embedding_config(
+    ) -> *mut wire_cst_embedding_config {
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(
+            wire_cst_embedding_config::new_with_null_ptr(),
+        )
     }
-}
 
-impl SseEncode for Vec<crate::graph::GraphNode> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::graph::GraphNode>::sse_encode(item, serializer);
-        }
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_cst_new_box_autoadd_f_32(value: f32) -> *mut f32 {
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(value)
     }
-}
 
-impl SseEncode for Vec<crate::mcp::MCPParameter> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::mcp::MCPParameter>::sse_encode(item, serializer);
-        }
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_cst_new_box_autoadd_generation_options(
+    ) -> *mut wire_cst_generation_options {
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(
+            wire_cst_generation_options::new_with_null_ptr(),
+        )
     }
-}
 
-impl SseEncode for Vec<crate::mcp::MCPTool> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::mcp::MCPTool>::sse_encode(item, serializer);
-        }
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_cst_new_box_autoadd_generation_result(
+    ) -> *mut wire_cst_generation_result {
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(
+            wire_cst_generation_result::new_with_null_ptr(),
+        )
     }
-}
 
-impl SseEncode for Vec<crate::streaming::NodePosition> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::streaming::NodePosition>::sse_encode(item, serializer);
-        }
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_cst_new_box_autoadd_hnsw_config() -> *mut wire_cst_hnsw_config {
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(
+            wire_cst_hnsw_config::new_with_null_ptr(),
+        )
     }
-}
 
-impl SseEncode for Vec<f32> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <f32>::sse_encode(item, serializer);
-        }
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_cst_new_box_autoadd_hybrid_options(
+    ) -> *mut wire_cst_hybrid_options {
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(
+            wire_cst_hybrid_options::new_with_null_ptr(),
+        )
     }
-}
 
-impl SseEncode for Vec<u8> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <u8>::sse_encode(item, serializer);
-        }
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_cst_new_box_autoadd_i_32(value: i32) -> *mut i32 {
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(value)
     }
-}
 
-impl SseEncode for Vec<(String, String)> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <(String, String)>::sse_encode(item, serializer);
-        }
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_cst_new_box_autoadd_i_64(value: i64) -> *mut i64 {
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(value)
     }
-}
 
-impl SseEncode for Vec<crate::clustering::SemanticEdge> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::clustering::SemanticEdge>::sse_encode(item, serializer);
-        }
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_cst_new_box_autoadd_mcp_tool_call(
+    ) -> *mut wire_cst_mcp_tool_call {
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(
+            wire_cst_mcp_tool_call::new_with_null_ptr(),
+        )
     }
-}
 
-impl SseEncode for Vec<crate::embeddings::SimilarityResult> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::embeddings::SimilarityResult>::sse_encode(item, serializer);
-        }
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_cst_new_box_autoadd_note_metadata(
+    ) -> *mut wire_cst_note_metadata {
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(
+            wire_cst_note_metadata::new_with_null_ptr(),
+        )
     }
-}
 
-impl SseEncode for crate::mcp::MCPParamType {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(
-            match self {
-                crate::mcp::MCPParamType::String => 0,
-                crate::mcp::MCPParamType::Boolean => 1,
-                crate::mcp::MCPParamType::Integer => 2,
-                crate::mcp::MCPParamType::Array => 3,
-                _ => {
-                    unimplemented!("");
-                }
-            },
-            serializer,
-        );
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_cst_new_box_autoadd_reasoning_options(
+    ) -> *mut wire_cst_reasoning_options {
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(
+            wire_cst_reasoning_options::new_with_null_ptr(),
+        )
     }
-}
 
-impl SseEncode for crate::mcp::MCPParameter {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(self.name, serializer);
-        <String>::sse_encode(self.description, serializer);
-        <crate::mcp::MCPParamType>::sse_encode(self.param_type, serializer);
-        <bool>::sse_encode(self.required, serializer);
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_cst_new_box_autoadd_sampler_config(
+    ) -> *mut wire_cst_sampler_config {
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(
+            wire_cst_sampler_config::new_with_null_ptr(),
+        )
     }
-}
 
-impl SseEncode for crate::mcp::MCPTool {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(
-            match self {
-                crate::mcp::MCPTool::ReadFile => 0,
-                crate::mcp::MCPTool::WriteFile => 1,
-                crate::mcp::MCPTool::DeleteFile => 2,
-                crate::mcp::MCPTool::CreateFolder => 3,
-                crate::mcp::MCPTool::ListFiles => 4,
-                crate::mcp::MCPTool::CalendarLua => 5,
-                crate::mcp::MCPTool::TimerLua => 6,
-                crate::mcp::MCPTool::ExportMarkdown => 7,
-                _ => {
-                    unimplemented!("");
-                }
-            },
-            serializer,
-        );
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_cst_new_box_autoadd_search_options(
+    ) -> *mut wire_cst_search_options {
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(
+            wire_cst_search_options::new_with_null_ptr(),
+        )
     }
-}
 
-impl SseEncode for crate::mcp::MCPToolCall {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(self.tool, serializer);
-        <String>::sse_encode(self.parameters_json, serializer);
-        <String>::sse_encode(self.description, serializer);
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_cst_new_box_autoadd_stop_reason(value: i32) -> *mut i32 {
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(value)
     }
-}
 
-impl SseEncode for crate::mcp::MCPToolResult {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.success, serializer);
-        <String>::sse_encode(self.result, serializer);
-        <String>::sse_encode(self.tool, serializer);
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_cst_new_box_autoadd_summary_options(
+    ) -> *mut wire_cst_summary_options {
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(
+            wire_cst_summary_options::new_with_null_ptr(),
+        )
     }
-}
 
-impl SseEncode for crate::streaming::NodePosition {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(self.id, serializer);
-        <f32>::sse_encode(self.x, serializer);
-        <f32>::sse_encode(self.y, serializer);
-        <f32>::sse_encode(self.radius, serializer);
-        <u32>::sse_encode(self.color, serializer);
-        <String>::sse_encode(self.node_type, serializer);
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_cst_new_box_autoadd_u_32(value: u32) -> *mut u32 {
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(value)
     }
-}
 
-impl SseEncode for Option<String> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <String>::sse_encode(value, serializer);
-        }
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_cst_new_box_autoadd_u_64(value: u64) -> *mut u64 {
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(value)
     }
-}
 
-impl SseEncode for Option<f32> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <f32>::sse_encode(value, serializer);
-        }
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_cst_new_box_autoadd_usize(value: usize) -> *mut usize {
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(value)
     }
-}
 
-impl SseEncode for Option<i32> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <i32>::sse_encode(value, serializer);
-        }
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_cst_new_list_String(len: i32) -> *mut wire_cst_list_String {
+        let wrap = wire_cst_list_String {
+            ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(
+                <*mut wire_cst_list_prim_u_8_strict>::new_with_null_ptr(),
+                len,
+            ),
+            len,
+        };
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(wrap)
     }
-}
 
-impl SseEncode for Option<u32> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <u32>::sse_encode(value, serializer);
-        }
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_cst_new_list_agent_step(
+        len: i32,
+    ) -> *mut wire_cst_list_agent_step {
+        let wrap = wire_cst_list_agent_step {
+            ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(
+                <wire_cst_agent_step>::new_with_null_ptr(),
+                len,
+            ),
+            len,
+        };
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(wrap)
     }
-}
 
-impl SseEncode for Option<usize> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <usize>::sse_encode(value, serializer);
-        }
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_cst_new_list_cluster_assignment(
+        len: i32,
+    ) -> *mut wire_cst_list_cluster_assignment {
+        let wrap = wire_cst_list_cluster_assignment {
+            ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(
+                <wire_cst_cluster_assignment>::new_with_null_ptr(),
+                len,
+            ),
+            len,
+        };
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(wrap)
     }
-}
 
-impl SseEncode for Option<Vec<String>> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <Vec<String>>::sse_encode(value, serializer);
-        }
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_cst_new_list_cluster_info(
+        len: i32,
+    ) -> *mut wire_cst_list_cluster_info {
+        let wrap = wire_cst_list_cluster_info {
+            ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(
+                <wire_cst_cluster_info>::new_with_null_ptr(),
+                len,
+            ),
+            len,
+        };
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(wrap)
     }
-}
 
-impl SseEncode for Option<Vec<f32>> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <Vec<f32>>::sse_encode(value, serializer);
-        }
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_cst_new_list_dedup_document(
+        len: i32,
+    ) -> *mut wire_cst_list_dedup_document {
+        let wrap = wire_cst_list_dedup_document {
+            ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(
+                <wire_cst_dedup_document>::new_with_null_ptr(),
+                len,
+            ),
+            len,
+        };
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(wrap)
     }
-}
 
-impl SseEncode for Option<Vec<(String, String)>> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <Vec<(String, String)>>::sse_encode(value, serializer);
-        }
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_cst_new_list_duplicate_group(
+        len: i32,
+    ) -> *mut wire_cst_list_duplicate_group {
+        let wrap = wire_cst_list_duplicate_group {
+            ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(
+                <wire_cst_duplicate_group>::new_with_null_ptr(),
+                len,
+            ),
+            len,
+        };
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(wrap)
     }
-}
 
-impl SseEncode for (String, String) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(self.0, serializer);
-        <String>::sse_encode(self.1, serializer);
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_cst_new_list_duplicate_pair(
+        len: i32,
+    ) -> *mut wire_cst_list_duplicate_pair {
+        let wrap = wire_cst_list_duplicate_pair {
+            ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(
+                <wire_cst_duplicate_pair>::new_with_null_ptr(),
+                len,
+            ),
+            len,
+        };
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(wrap)
     }
-}
 
-impl SseEncode for crate::clustering::SemanticEdge {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(self.source, serializer);
-        <String>::sse_encode(self.target, serializer);
-        <f32>::sse_encode(self.similarity, serializer);
-        <bool>::sse_encode(self.is_ghost, serializer);
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_cst_new_list_duplicate_span(
+        len: i32,
+    ) -> *mut wire_cst_list_duplicate_span {
+        let wrap = wire_cst_list_duplicate_span {
+            ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(
+                <wire_cst_duplicate_span>::new_with_null_ptr(),
+                len,
+            ),
+            len,
+        };
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(wrap)
     }
-}
 
-impl SseEncode for crate::clustering::SemanticEdgeResult {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <Vec<crate::clustering::SemanticEdge>>::sse_encode(self.edges, serializer);
-        <usize>::sse_encode(self.count, serializer);
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_cst_new_list_embedding_cluster(
+        len: i32,
+    ) -> *mut wire_cst_list_embedding_cluster {
+        let wrap = wire_cst_list_embedding_cluster {
+            ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(
+                <wire_cst_embedding_cluster>::new_with_null_ptr(),
+                len,
+            ),
+            len,
+        };
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(wrap)
     }
-}
 
-impl SseEncode for crate::embeddings::SimilarityResult {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(self.id, serializer);
-        <f32>::sse_encode(self.score, serializer);
-        <Option<String>>::sse_encode(self.text_preview, serializer);
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_cst_new_list_embedding_entry(
+        len: i32,
+    ) -> *mut wire_cst_list_embedding_entry {
+        let wrap = wire_cst_list_embedding_entry {
+            ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(
+                <wire_cst_embedding_entry>::new_with_null_ptr(),
+                len,
+            ),
+            len,
+        };
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(wrap)
     }
-}
 
-impl SseEncode for crate::api::StreamGraphStats {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <usize>::sse_encode(self.node_count, serializer);
-        <usize>::sse_encode(self.edge_count, serializer);
-        <usize>::sse_encode(self.visible_count, serializer);
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_cst_new_list_graph_edge(
+        len: i32,
+    ) -> *mut wire_cst_list_graph_edge {
+        let wrap = wire_cst_list_graph_edge {
+            ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(
+                <wire_cst_graph_edge>::new_with_null_ptr(),
+                len,
+            ),
+            len,
+        };
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(wrap)
     }
-}
 
-impl SseEncode for crate::mcp::TaskCategory {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(
-            match self {
-                crate::mcp::TaskCategory::Conversation => 0,
-                crate::mcp::TaskCategory::ToolUse => 1,
-                crate::mcp::TaskCategory::CodeGeneration => 2,
-                _ => {
-                    unimplemented!("");
-                }
-            },
-            serializer,
-        );
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_cst_new_list_graph_node(
+        len: i32,
+    ) -> *mut wire_cst_list_graph_node {
+        let wrap = wire_cst_list_graph_node {
+            ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(
+                <wire_cst_graph_node>::new_with_null_ptr(),
+                len,
+            ),
+            len,
+        };
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(wrap)
     }
-}
 
-impl SseEncode for u32 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_u32::<NativeEndian>(self).unwrap();
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_cst_new_list_hybrid_hit(
+        len: i32,
+    ) -> *mut wire_cst_list_hybrid_hit {
+        let wrap = wire_cst_list_hybrid_hit {
+            ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(
+                <wire_cst_hybrid_hit>::new_with_null_ptr(),
+                len,
+            ),
+            len,
+        };
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(wrap)
     }
-}
 
-impl SseEncode for u8 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_u8(self).unwrap();
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_cst_new_list_list_prim_f_32_strict(
+        len: i32,
+    ) -> *mut wire_cst_list_list_prim_f_32_strict {
+        let wrap = wire_cst_list_list_prim_f_32_strict {
+            ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(
+                <*mut wire_cst_list_prim_f_32_strict>::new_with_null_ptr(),
+                len,
+            ),
+            len,
+        };
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(wrap)
     }
-}
-
-impl SseEncode for () {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {}
-}
 
-impl SseEncode for usize {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer
-            .cursor
-            .write_u64::<NativeEndian>(self as _)
-            .unwrap();
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_cst_new_list_loaded_model_info(
+        len: i32,
+    ) -> *mut wire_cst_list_loaded_model_info {
+        let wrap = wire_cst_list_loaded_model_info {
+            ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(
+                <wire_cst_loaded_model_info>::new_with_null_ptr(),
+                len,
+            ),
+            len,
+        };
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(wrap)
     }
-}
-
-#[cfg(not(target_family = "wasm"))]
-mod io {
-    // This file is automatically generated, so please do not edit it.
-    // @generated by `flutter_rust_bridge`@ 2.12.0.
-
-    // Section: imports
-
-    use super::*;
-    use flutter_rust_bridge::for_generated::byteorder::{
-        NativeEndian, ReadBytesExt, WriteBytesExt,
-    };
-    use flutter_rust_bridge::for_generated::{transform_result_dco, Lifetimeable, Lockable};
-    use flutter_rust_bridge::{Handler, IntoIntoDart};
 
-    // Section: boilerplate
-
-    flutter_rust_bridge::frb_generated_boilerplate_io!();
-
-    // Section: dart2rust
-
-    impl CstDecode<flutter_rust_bridge::for_generated::anyhow::Error>
-        for *mut wire_cst_list_prim_u_8_strict
-    {
-        // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> flutter_rust_bridge::for_generated::anyhow::Error {
-            unimplemented!()
-        }
-    }
-    impl CstDecode<String> for *mut wire_cst_list_prim_u_8_strict {
-        // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> String {
-            let vec: Vec<u8> = self.cst_decode();
-            String::from_utf8(vec).unwrap()
-        }
-    }
-    impl CstDecode<f32> for *mut f32 {
-        // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> f32 {
-            unsafe { *flutter_rust_bridge::for_generated::box_from_leak_ptr(self) }
-        }
-    }
-    impl CstDecode<i32> for *mut i32 {
-        // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> i32 {
-            unsafe { *flutter_rust_bridge::for_generated::box_from_leak_ptr(self) }
-        }
-    }
-    impl CstDecode<crate::mcp::MCPToolCall> for *mut wire_cst_mcp_tool_call {
-        // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> crate::mcp::MCPToolCall {
-            let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
-            CstDecode::<crate::mcp::MCPToolCall>::cst_decode(*wrap).into()
-        }
-    }
-    impl CstDecode<u32> for *mut u32 {
-        // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> u32 {
-            unsafe { *flutter_rust_bridge::for_generated::box_from_leak_ptr(self) }
-        }
-    }
-    impl CstDecode<usize> for *mut usize {
-        // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> usize {
-            unsafe { *flutter_rust_bridge::for_generated::box_from_leak_ptr(self) }
-        }
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_cst_new_list_markdown_chunk(
+        len: i32,
+    ) -> *mut wire_cst_list_markdown_chunk {
+        let wrap = wire_cst_list_markdown_chunk {
+            ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(
+                <wire_cst_markdown_chunk>::new_with_null_ptr(),
+                len,
+            ),
+            len,
+        };
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(wrap)
     }
-    impl CstDecode<crate::clustering::ClusterAssignment> for wire_cst_cluster_assignment {
-        // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> crate::clustering::ClusterAssignment {
-            crate::clustering::ClusterAssignment {
-                id: self.id.cst_decode(),
-                cluster_id: self.cluster_id.cst_decode(),
-                color: self.color.cst_decode(),
-            }
-        }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_cst_new_list_mcp_parameter(
+        len: i32,
+    ) -> *mut wire_cst_list_mcp_parameter {
+        let wrap = wire_cst_list_mcp_parameter {
+            ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(
+                <wire_cst_mcp_parameter>::new_with_null_ptr(),
+                len,
+            ),
+            len,
+        };
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(wrap)
     }
-    impl CstDecode<crate::clustering::ClusterInfo> for wire_cst_cluster_info {
-        // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> crate::clustering::ClusterInfo {
-            crate::clustering::ClusterInfo {
-                id: self.id.cst_decode(),
-                size: self.size.cst_decode(),
-                color: self.color.cst_decode(),
-                centroid: self.centroid.cst_decode(),
-            }
-        }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_cst_new_list_mcp_tool(len: i32) -> *mut wire_cst_list_mcp_tool {
+        let wrap = wire_cst_list_mcp_tool {
+            ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(Default::default(), len),
+            len,
+        };
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(wrap)
     }
-    impl CstDecode<crate::clustering::ClusteringResult> for wire_cst_clustering_result {
-        // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> crate::clustering::ClusteringResult {
-            crate::clustering::ClusteringResult {
-                assignments: self.assignments.cst_decode(),
-                clusters: self.clusters.cst_decode(),
-                k: self.k.cst_decode(),
-            }
-        }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_cst_new_list_mcp_tool_call(
+        len: i32,
+    ) -> *mut wire_cst_list_mcp_tool_call {
+        let wrap = wire_cst_list_mcp_tool_call {
+            ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(
+                <wire_cst_mcp_tool_call>::new_with_null_ptr(),
+                len,
+            ),
+            len,
+        };
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(wrap)
     }
-    impl CstDecode<crate::api::EmbeddingCluster> for wire_cst_embedding_cluster {
-        // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> crate::api::EmbeddingCluster {
-            crate::api::EmbeddingCluster {
-                ids: self.ids.cst_decode(),
-            }
-        }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_cst_new_list_mcp_tool_result(
+        len: i32,
+    ) -> *mut wire_cst_list_mcp_tool_result {
+        let wrap = wire_cst_list_mcp_tool_result {
+            ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(
+                <wire_cst_mcp_tool_result>::new_with_null_ptr(),
+                len,
+            ),
+            len,
+        };
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(wrap)
     }
-    impl CstDecode<crate::embeddings::EmbeddingEntry> for wire_cst_embedding_entry {
-        // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> crate::embeddings::EmbeddingEntry {
-            crate::embeddings::EmbeddingEntry {
-                id: self.id.cst_decode(),
-                vector: self.vector.cst_decode(),
-                text_preview: self.text_preview.cst_decode(),
-            }
-        }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_cst_new_list_node_position(
+        len: i32,
+    ) -> *mut wire_cst_list_node_position {
+        let wrap = wire_cst_list_node_position {
+            ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(
+                <wire_cst_node_position>::new_with_null_ptr(),
+                len,
+            ),
+            len,
+        };
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(wrap)
     }
-    impl CstDecode<crate::graph::GraphEdge> for wire_cst_graph_edge {
-        // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> crate::graph::GraphEdge {
-            crate::graph::GraphEdge {
-                source: self.source.cst_decode(),
-                target: self.target.cst_decode(),
-                weight: self.weight.cst_decode(),
-                edge_type: self.edge_type.cst_decode(),
-            }
-        }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_cst_new_list_prim_f_32_loose(
+        len: i32,
+    ) -> *mut wire_cst_list_prim_f_32_loose {
+        let ans = wire_cst_list_prim_f_32_loose {
+            ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(Default::default(), len),
+            len,
+        };
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(ans)
     }
-    impl CstDecode<crate::graph::GraphNode> for wire_cst_graph_node {
-        // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> crate::graph::GraphNode {
-            crate::graph::GraphNode {
-                id: self.id.cst_decode(),
-                label: self.label.cst_decode(),
-                node_type: self.node_type.cst_decode(),
-                x: self.x.cst_decode(),
-                y: self.y.cst_decode(),
-                color: self.color.cst_decode(),
-                metadata: self.metadata.cst_decode(),
-            }
-        }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_cst_new_list_prim_f_32_strict(
+        len: i32,
+    ) -> *mut wire_cst_list_prim_f_32_strict {
+        let ans = wire_cst_list_prim_f_32_strict {
+            ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(Default::default(), len),
+            len,
+        };
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(ans)
     }
-    impl CstDecode<crate::graph::GraphState> for wire_cst_graph_state {
-        // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> crate::graph::GraphState {
-            crate::graph::GraphState {
-                nodes: self.nodes.cst_decode(),
-                edges: self.edges.cst_decode(),
-            }
-        }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_cst_new_list_prim_i_32_loose(
+        len: i32,
+    ) -> *mut wire_cst_list_prim_i_32_loose {
+        let ans = wire_cst_list_prim_i_32_loose {
+            ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(Default::default(), len),
+            len,
+        };
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(ans)
     }
-    impl CstDecode<crate::api::KnowledgeGraphAnalysis> for wire_cst_knowledge_graph_analysis {
-        // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> crate::api::KnowledgeGraphAnalysis {
-            crate::api::KnowledgeGraphAnalysis {
-                clustering: self.clustering.cst_decode(),
-                semantic_edges: self.semantic_edges.cst_decode(),
-            }
-        }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_cst_new_list_prim_i_32_strict(
+        len: i32,
+    ) -> *mut wire_cst_list_prim_i_32_strict {
+        let ans = wire_cst_list_prim_i_32_strict {
+            ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(Default::default(), len),
+            len,
+        };
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(ans)
     }
-    impl CstDecode<Vec<String>> for *mut wire_cst_list_String {
-        // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> Vec<String> {
-            let vec = unsafe {
-                let wrap = flutter_rust_bridge::for_generated::box_from_leak_ptr(self);
-                flutter_rust_bridge::for_generated::vec_from_leak_ptr(wrap.ptr, wrap.len)
-            };
-            vec.into_iter().map(CstDecode::cst_decode).collect()
-        }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_cst_new_list_prim_u_32_strict(
+        len: i32,
+    ) -> *mut wire_cst_list_prim_u_32_strict {
+        let ans = wire_cst_list_prim_u_32_strict {
+            ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(Default::default(), len),
+            len,
+        };
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(ans)
     }
-    impl CstDecode<Vec<crate::clustering::ClusterAssignment>>
-        for *mut wire_cst_list_cluster_assignment
-    {
-        // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> Vec<crate::clustering::ClusterAssignment> {
-            let vec = unsafe {
-                let wrap = flutter_rust_bridge::for_generated::box_from_leak_ptr(self);
-                flutter_rust_bridge::for_generated::vec_from_leak_ptr(wrap.ptr, wrap.len)
-            };
-            vec.into_iter().map(CstDecode::cst_decode).collect()
-        }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_cst_new_list_prim_u_8_strict(
+        len: i32,
+    ) -> *mut wire_cst_list_prim_u_8_strict {
+        let ans = wire_cst_list_prim_u_8_strict {
+            ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(Default::default(), len),
+            len,
+        };
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(ans)
     }
-    impl CstDecode<Vec<crate::clustering::ClusterInfo>> for *mut wire_cst_list_cluster_info {
-        // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> Vec<crate::clustering::ClusterInfo> {
-            let vec = unsafe {
-                let wrap = flutter_rust_bridge::for_generated::box_from_leak_ptr(self);
-                flutter_rust_bridge::for_generated::vec_from_leak_ptr(wrap.ptr, wrap.len)
-            };
-            vec.into_iter().map(CstDecode::cst_decode).collect()
-        }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_cst_new_list_rag_source(
+        len: i32,
+    ) -> *mut wire_cst_list_rag_source {
+        let wrap = wire_cst_list_rag_source {
+            ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(
+                <wire_cst_rag_source>::new_with_null_ptr(),
+                len,
+            ),
+            len,
+        };
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(wrap)
     }
-    impl CstDecode<Vec<crate::api::EmbeddingCluster>> for *mut wire_cst_list_embedding_cluster {
-        // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> Vec<crate::api::EmbeddingCluster> {
-            let vec = unsafe {
-                let wrap = flutter_rust_bridge::for_generated::box_from_leak_ptr(self);
-                flutter_rust_bridge::for_generated::vec_from_leak_ptr(wrap.ptr, wrap.len)
-            };
-            vec.into_iter().map(CstDecode::cst_decode).collect()
-        }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_cst_new_list_record_string_string(
+        len: i32,
+    ) -> *mut wire_cst_list_record_string_string {
+        let wrap = wire_cst_list_record_string_string {
+            ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(
+                <wire_cst_record_string_string>::new_with_null_ptr(),
+                len,
+            ),
+            len,
+        };
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(wrap)
     }
-    impl CstDecode<Vec<crate::embeddings::EmbeddingEntry>> for *mut wire_cst_list_embedding_entry {
-        // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> Vec<crate::embeddings::EmbeddingEntry> {
-            let vec = unsafe {
-                let wrap = flutter_rust_bridge::for_generated::box_from_leak_ptr(self);
-                flutter_rust_bridge::for_generated::vec_from_leak_ptr(wrap.ptr, wrap.len)
-            };
-            vec.into_iter().map(CstDecode::cst_decode).collect()
-        }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_cst_new_list_reply_span(
+        len: i32,
+    ) -> *mut wire_cst_list_reply_span {
+        let wrap = wire_cst_list_reply_span {
+            ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(
+                <wire_cst_reply_span>::new_with_null_ptr(),
+                len,
+            ),
+            len,
+        };
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(wrap)
     }
-    impl CstDecode<Vec<crate::graph::GraphEdge>> for *mut wire_cst_list_graph_edge {
-        // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> Vec<crate::graph::GraphEdge> {
-            let vec = unsafe {
-                let wrap = flutter_rust_bridge::for_generated::box_from_leak_ptr(self);
-                flutter_rust_bridge::for_generated::vec_from_leak_ptr(wrap.ptr, wrap.len)
-            };
-            vec.into_iter().map(CstDecode::cst_decode).collect()
-        }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_cst_new_list_semantic_edge(
+        len: i32,
+    ) -> *mut wire_cst_list_semantic_edge {
+        let wrap = wire_cst_list_semantic_edge {
+            ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(
+                <wire_cst_semantic_edge>::new_with_null_ptr(),
+                len,
+            ),
+            len,
+        };
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(wrap)
     }
-    impl CstDecode<Vec<crate::graph::GraphNode>> for *mut wire_cst_list_graph_node {
-        // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> Vec<crate::graph::GraphNode> {
-            let vec = unsafe {
-                let wrap = flutter_rust_bridge::for_generated::box_from_leak_ptr(self);
-                flutter_rust_bridge::for_generated::vec_from_leak_ptr(wrap.ptr, wrap.len)
-            };
-            vec.into_iter().map(CstDecode::cst_decode).collect()
-        }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_cst_new_list_similarity_result(
+        len: i32,
+    ) -> *mut wire_cst_list_similarity_result {
+        let wrap = wire_cst_list_similarity_result {
+            ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(
+                <wire_cst_similarity_result>::new_with_null_ptr(),
+                len,
+            ),
+            len,
+        };
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(wrap)
     }
-    impl CstDecode<Vec<crate::mcp::MCPParameter>> for *mut wire_cst_list_mcp_parameter {
-        // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> Vec<crate::mcp::MCPParameter> {
-            let vec = unsafe {
-                let wrap = flutter_rust_bridge::for_generated::box_from_leak_ptr(self);
-                flutter_rust_bridge::for_generated::vec_from_leak_ptr(wrap.ptr, wrap.len)
-            };
-            vec.into_iter().map(CstDecode::cst_decode).collect()
-        }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_cst_new_list_token_candidate(
+        len: i32,
+    ) -> *mut wire_cst_list_token_candidate {
+        let wrap = wire_cst_list_token_candidate {
+            ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(
+                <wire_cst_token_candidate>::new_with_null_ptr(),
+                len,
+            ),
+            len,
+        };
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(wrap)
     }
-    impl CstDecode<Vec<crate::mcp::MCPTool>> for *mut wire_cst_list_mcp_tool {
-        // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> Vec<crate::mcp::MCPTool> {
-            let vec = unsafe {
-                let wrap = flutter_rust_bridge::for_generated::box_from_leak_ptr(self);
-                flutter_rust_bridge::for_generated::vec_from_leak_ptr(wrap.ptr, wrap.len)
-            };
-            vec.into_iter().map(CstDecode::cst_decode).collect()
-        }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_cst_new_list_token_logprob(
+        len: i32,
+    ) -> *mut wire_cst_list_token_logprob {
+        let wrap = wire_cst_list_token_logprob {
+            ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(
+                <wire_cst_token_logprob>::new_with_null_ptr(),
+                len,
+            ),
+            len,
+        };
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(wrap)
     }
-    impl CstDecode<Vec<crate::streaming::NodePosition>> for *mut wire_cst_list_node_position {
-        // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> Vec<crate::streaming::NodePosition> {
-            let vec = unsafe {
-                let wrap = flutter_rust_bridge::for_generated::box_from_leak_ptr(self);
-                flutter_rust_bridge::for_generated::vec_from_leak_ptr(wrap.ptr, wrap.len)
-            };
-            vec.into_iter().map(CstDecode::cst_decode).collect()
-        }
+
+    #[unsafe(no_mangle)]
+    pub extern "C" fn frbgen_kivixa_cst_new_list_transcript_segment(
+        len: i32,
+    ) -> *mut wire_cst_list_transcript_segment {
+        let wrap = wire_cst_list_transcript_segment {
+            ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(
+                <wire_cst_transcript_segment>::new_with_null_ptr(),
+                len,
+            ),
+            len,
+        };
+        flutter_rust_bridge::for_generated::new_leak_box_ptr(wrap)
     }
-    impl CstDecode<Vec<f32>> for *mut wire_cst_list_prim_f_32_loose {
-        // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> Vec<f32> {
-            unsafe {
-                let wrap = flutter_rust_bridge::for_generated::box_from_leak_ptr(self);
-                flutter_rust_bridge::for_generated::vec_from_leak_ptr(wrap.ptr, wrap.len)
-            }
-        }
+
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_agent_run {
+        answer: *mut wire_cst_list_prim_u_8_strict,
+        stop_reason: i32,
+        steps: *mut wire_cst_list_agent_step,
+        messages: *mut wire_cst_list_record_string_string,
     }
-    impl CstDecode<Vec<f32>> for *mut wire_cst_list_prim_f_32_strict {
-        // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> Vec<f32> {
-            unsafe {
-                let wrap = flutter_rust_bridge::for_generated::box_from_leak_ptr(self);
-                flutter_rust_bridge::for_generated::vec_from_leak_ptr(wrap.ptr, wrap.len)
-            }
-        }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_agent_step {
+        reply: *mut wire_cst_list_prim_u_8_strict,
+        tool_calls: *mut wire_cst_list_mcp_tool_call,
+        results: *mut wire_cst_list_mcp_tool_result,
     }
-    impl CstDecode<Vec<u8>> for *mut wire_cst_list_prim_u_8_strict {
-        // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> Vec<u8> {
-            unsafe {
-                let wrap = flutter_rust_bridge::for_generated::box_from_leak_ptr(self);
-                flutter_rust_bridge::for_generated::vec_from_leak_ptr(wrap.ptr, wrap.len)
-            }
-        }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_cluster_assignment {
+        id: *mut wire_cst_list_prim_u_8_strict,
+        cluster_id: usize,
+        color: *mut wire_cst_list_prim_u_8_strict,
     }
-    impl CstDecode<Vec<(String, String)>> for *mut wire_cst_list_record_string_string {
-        // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> Vec<(String, String)> {
-            let vec = unsafe {
-                let wrap = flutter_rust_bridge::for_generated::box_from_leak_ptr(self);
-                flutter_rust_bridge::for_generated::vec_from_leak_ptr(wrap.ptr, wrap.len)
-            };
-            vec.into_iter().map(CstDecode::cst_decode).collect()
-        }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_cluster_info {
+        id: usize,
+        size: usize,
+        color: *mut wire_cst_list_prim_u_8_strict,
+        centroid: *mut wire_cst_list_prim_f_32_strict,
     }
-    impl CstDecode<Vec<crate::clustering::SemanticEdge>> for *mut wire_cst_list_semantic_edge {
-        // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> Vec<crate::clustering::SemanticEdge> {
-            let vec = unsafe {
-                let wrap = flutter_rust_bridge::for_generated::box_from_leak_ptr(self);
-                flutter_rust_bridge::for_generated::vec_from_leak_ptr(wrap.ptr, wrap.len)
-            };
-            vec.into_iter().map(CstDecode::cst_decode).collect()
-        }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_clustering_result {
+        assignments: *mut wire_cst_list_cluster_assignment,
+        clusters: *mut wire_cst_list_cluster_info,
+        k: usize,
     }
-    impl CstDecode<Vec<crate::embeddings::SimilarityResult>> for *mut wire_cst_list_similarity_result {
-        // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> Vec<crate::embeddings::SimilarityResult> {
-            let vec = unsafe {
-                let wrap = flutter_rust_bridge::for_generated::box_from_leak_ptr(self);
-                flutter_rust_bridge::for_generated::vec_from_leak_ptr(wrap.ptr, wrap.len)
-            };
-            vec.into_iter().map(CstDecode::cst_decode).collect()
-        }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_context_policy {
+        strategy: i32,
+        summary_max_tokens: u32,
     }
-    impl CstDecode<crate::mcp::MCPParameter> for wire_cst_mcp_parameter {
-        // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> crate::mcp::MCPParameter {
-            crate::mcp::MCPParameter {
-                name: self.name.cst_decode(),
-                description: self.description.cst_decode(),
-                param_type: self.param_type.cst_decode(),
-                required: self.required.cst_decode(),
-            }
-        }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_context_report {
+        context_size: u32,
+        prompt_tokens: u32,
+        message_tokens: *mut wire_cst_list_prim_u_32_strict,
+        dropped: *mut wire_cst_list_prim_u_32_strict,
+        truncated: *mut wire_cst_list_prim_u_32_strict,
+        summarized: *mut wire_cst_list_prim_u_32_strict,
+        summary: *mut wire_cst_list_prim_u_8_strict,
     }
-    impl CstDecode<crate::mcp::MCPToolCall> for wire_cst_mcp_tool_call {
-        // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> crate::mcp::MCPToolCall {
-            crate::mcp::MCPToolCall {
-                tool: self.tool.cst_decode(),
-                parameters_json: self.parameters_json.cst_decode(),
-                description: self.description.cst_decode(),
-            }
-        }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_dedup_document {
+        id: *mut wire_cst_list_prim_u_8_strict,
+        text: *mut wire_cst_list_prim_u_8_strict,
+        vector: *mut wire_cst_list_prim_f_32_strict,
+        created_at: *mut i64,
     }
-    impl CstDecode<crate::mcp::MCPToolResult> for wire_cst_mcp_tool_result {
-        // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> crate::mcp::MCPToolResult {
-            crate::mcp::MCPToolResult {
-                success: self.success.cst_decode(),
-                result: self.result.cst_decode(),
-                tool: self.tool.cst_decode(),
-            }
-        }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_dedup_options {
+        shingle_words: usize,
+        num_hashes: usize,
+        bands: usize,
+        min_jaccard: f32,
+        min_cosine: f32,
+        min_span_words: usize,
     }
-    impl CstDecode<crate::streaming::NodePosition> for wire_cst_node_position {
-        // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> crate::streaming::NodePosition {
-            crate::streaming::NodePosition {
-                id: self.id.cst_decode(),
-                x: self.x.cst_decode(),
-                y: self.y.cst_decode(),
-                radius: self.radius.cst_decode(),
-                color: self.color.cst_decode(),
-                node_type: self.node_type.cst_decode(),
-            }
-        }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_duplicate_group {
+        ids: *mut wire_cst_list_String,
+        canonical: *mut wire_cst_list_prim_u_8_strict,
+        canonical_note: *mut wire_cst_list_prim_u_8_strict,
+        pairs: *mut wire_cst_list_duplicate_pair,
     }
-    impl CstDecode<(String, String)> for wire_cst_record_string_string {
-        // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> (String, String) {
-            (self.field0.cst_decode(), self.field1.cst_decode())
-        }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_duplicate_pair {
+        a: *mut wire_cst_list_prim_u_8_strict,
+        b: *mut wire_cst_list_prim_u_8_strict,
+        jaccard: f32,
+        cosine: *mut f32,
+        spans: *mut wire_cst_list_duplicate_span,
     }
-    impl CstDecode<crate::clustering::SemanticEdge> for wire_cst_semantic_edge {
-        // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> crate::clustering::SemanticEdge {
-            crate::clustering::SemanticEdge {
-                source: self.source.cst_decode(),
-                target: self.target.cst_decode(),
-                similarity: self.similarity.cst_decode(),
-                is_ghost: self.is_ghost.cst_decode(),
-            }
-        }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_duplicate_span {
+        a_start: usize,
+        a_end: usize,
+        b_start: usize,
+        b_end: usize,
+        words: usize,
     }
-    impl CstDecode<crate::clustering::SemanticEdgeResult> for wire_cst_semantic_edge_result {
-        // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> crate::clustering::SemanticEdgeResult {
-            crate::clustering::SemanticEdgeResult {
-                edges: self.edges.cst_decode(),
-                count: self.count.cst_decode(),
-            }
-        }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_embedding_cache_stats {
+        entries: usize,
+        stale_records: usize,
+        file_bytes: u64,
     }
-    impl CstDecode<crate::embeddings::SimilarityResult> for wire_cst_similarity_result {
-        // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> crate::embeddings::SimilarityResult {
-            crate::embeddings::SimilarityResult {
-                id: self.id.cst_decode(),
-                score: self.score.cst_decode(),
-                text_preview: self.text_preview.cst_decode(),
-            }
-        }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_embedding_cluster {
+        ids: *mut wire_cst_list_String,
     }
-    impl CstDecode<crate::api::StreamGraphStats> for wire_cst_stream_graph_stats {
-        // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> crate::api::StreamGraphStats {
-            crate::api::StreamGraphStats {
-                node_count: self.node_count.cst_decode(),
-                edge_count: self.edge_count.cst_decode(),
-                visible_count: self.visible_count.cst_decode(),
-            }
-        }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_embedding_config {
+        n_gpu_layers: u32,
+        n_threads: i32,
+        pooling: i32,
+        chunk_tokens: u32,
+        chunk_overlap: u32,
+        max_sequences: u32,
+        query_prefix: *mut wire_cst_list_prim_u_8_strict,
+        document_prefix: *mut wire_cst_list_prim_u_8_strict,
     }
-    impl NewWithNullPtr for wire_cst_cluster_assignment {
-        fn new_with_null_ptr() -> Self {
-            Self {
-                id: core::ptr::null_mut(),
-                cluster_id: Default::default(),
-                color: core::ptr::null_mut(),
-            }
-        }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_embedding_entry {
+        id: *mut wire_cst_list_prim_u_8_strict,
+        vector: *mut wire_cst_list_prim_f_32_strict,
+        text_preview: *mut wire_cst_list_prim_u_8_strict,
+        metadata: wire_cst_note_metadata,
     }
-    impl Default for wire_cst_cluster_assignment {
-        fn default() -> Self {
-            Self::new_with_null_ptr()
-        }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_generation_chunk {
+        text: *mut wire_cst_list_prim_u_8_strict,
+        reasoning: *mut wire_cst_list_prim_u_8_strict,
+        stop_reason: *mut i32,
     }
-    impl NewWithNullPtr for wire_cst_cluster_info {
-        fn new_with_null_ptr() -> Self {
-            Self {
-                id: Default::default(),
-                size: Default::default(),
-                color: core::ptr::null_mut(),
-                centroid: core::ptr::null_mut(),
-            }
-        }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_generation_options {
+        max_tokens: *mut u32,
+        stop_sequences: *mut wire_cst_list_String,
+        sampler: *mut wire_cst_sampler_config,
+        grammar: *mut wire_cst_list_prim_u_8_strict,
+        json_schema: *mut wire_cst_list_prim_u_8_strict,
+        model_id: *mut wire_cst_list_prim_u_8_strict,
+        context: *mut wire_cst_context_policy,
+        reasoning: *mut wire_cst_reasoning_options,
+        logprobs: *mut u32,
     }
-    impl Default for wire_cst_cluster_info {
-        fn default() -> Self {
-            Self::new_with_null_ptr()
-        }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_generation_poll {
+        text: *mut wire_cst_list_prim_u_8_strict,
+        reasoning: *mut wire_cst_list_prim_u_8_strict,
+        result: *mut wire_cst_generation_result,
     }
-    impl NewWithNullPtr for wire_cst_clustering_result {
-        fn new_with_null_ptr() -> Self {
-            Self {
-                assignments: core::ptr::null_mut(),
-                clusters: core::ptr::null_mut(),
-                k: Default::default(),
-            }
-        }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_generation_result {
+        text: *mut wire_cst_list_prim_u_8_strict,
+        reasoning: *mut wire_cst_list_prim_u_8_strict,
+        reasoning_tokens: u32,
+        stop_reason: i32,
+        tokens_generated: u32,
+        context: *mut wire_cst_context_report,
+        logprobs: *mut wire_cst_list_token_logprob,
     }
-    impl Default for wire_cst_clustering_result {
-        fn default() -> Self {
-            Self::new_with_null_ptr()
-        }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_gguf_metadata {
+        version: u32,
+        architecture: *mut wire_cst_list_prim_u_8_strict,
+        name: *mut wire_cst_list_prim_u_8_strict,
+        chat_template: *mut wire_cst_list_prim_u_8_strict,
+        context_length: *mut u64,
+        embedding_length: *mut u64,
+        block_count: *mut u64,
+        pooling_type: *mut u32,
+        quantization: *mut wire_cst_list_prim_u_8_strict,
+        parameter_count: u64,
+        tensor_count: u64,
+        tokenizer_model: *mut wire_cst_list_prim_u_8_strict,
+        vocab_size: *mut u64,
+        bos_token_id: *mut u32,
+        eos_token_id: *mut u32,
+        file_size: u64,
     }
-    impl NewWithNullPtr for wire_cst_embedding_cluster {
-        fn new_with_null_ptr() -> Self {
-            Self {
-                ids: core::ptr::null_mut(),
-            }
-        }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_graph_edge {
+        source: *mut wire_cst_list_prim_u_8_strict,
+        target: *mut wire_cst_list_prim_u_8_strict,
+        weight: f32,
+        edge_type: *mut wire_cst_list_prim_u_8_strict,
     }
-    impl Default for wire_cst_embedding_cluster {
-        fn default() -> Self {
-            Self::new_with_null_ptr()
-        }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_graph_node {
+        id: *mut wire_cst_list_prim_u_8_strict,
+        label: *mut wire_cst_list_prim_u_8_strict,
+        node_type: *mut wire_cst_list_prim_u_8_strict,
+        x: f32,
+        y: f32,
+        color: *mut wire_cst_list_prim_u_8_strict,
+        metadata: *mut wire_cst_list_prim_u_8_strict,
     }
-    impl NewWithNullPtr for wire_cst_embedding_entry {
-        fn new_with_null_ptr() -> Self {
-            Self {
-                id: core::ptr::null_mut(),
-                vector: core::ptr::null_mut(),
-                text_preview: core::ptr::null_mut(),
-            }
-        }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_graph_state {
+        nodes: *mut wire_cst_list_graph_node,
+        edges: *mut wire_cst_list_graph_edge,
     }
-    impl Default for wire_cst_embedding_entry {
-        fn default() -> Self {
-            Self::new_with_null_ptr()
-        }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_hnsw_config {
+        m: u32,
+        ef_construction: u32,
+        ef_search: u32,
     }
-    impl NewWithNullPtr for wire_cst_graph_edge {
-        fn new_with_null_ptr() -> Self {
-            Self {
-                source: core::ptr::null_mut(),
-                target: core::ptr::null_mut(),
-                weight: Default::default(),
-                edge_type: core::ptr::null_mut(),
-            }
-        }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_hybrid_hit {
+        id: *mut wire_cst_list_prim_u_8_strict,
+        score: f32,
+        semantic_score: *mut f32,
+        semantic_rank: *mut u32,
+        lexical_score: *mut f32,
+        lexical_rank: *mut u32,
+        matched_terms: *mut wire_cst_list_String,
+        text_preview: *mut wire_cst_list_prim_u_8_strict,
     }
-    impl Default for wire_cst_graph_edge {
-        fn default() -> Self {
-            Self::new_with_null_ptr()
-        }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_hybrid_options {
+        fusion: i32,
+        semantic_weight: f32,
+        rrf_k: f32,
+        candidates: usize,
+        min_semantic_score: f32,
     }
-    impl NewWithNullPtr for wire_cst_graph_node {
-        fn new_with_null_ptr() -> Self {
-            Self {
-                id: core::ptr::null_mut(),
-                label: core::ptr::null_mut(),
-                node_type: core::ptr::null_mut(),
-                x: Default::default(),
-                y: Default::default(),
-                color: core::ptr::null_mut(),
-                metadata: core::ptr::null_mut(),
-            }
-        }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_knowledge_graph_analysis {
+        clustering: wire_cst_clustering_result,
+        semantic_edges: wire_cst_semantic_edge_result,
     }
-    impl Default for wire_cst_graph_node {
-        fn default() -> Self {
-            Self::new_with_null_ptr()
-        }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_list_String {
+        ptr: *mut *mut wire_cst_list_prim_u_8_strict,
+        len: i32,
     }
-    impl NewWithNullPtr for wire_cst_graph_state {
-        fn new_with_null_ptr() -> Self {
-            Self {
-                nodes: core::ptr::null_mut(),
-                edges: core::ptr::null_mut(),
-            }
-        }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_list_agent_step {
+        ptr: *mut wire_cst_agent_step,
+        len: i32,
     }
-    impl Default for wire_cst_graph_state {
-        fn default() -> Self {
-            Self::new_with_null_ptr()
-        }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_list_cluster_assignment {
+        ptr: *mut wire_cst_cluster_assignment,
+        len: i32,
     }
-    impl NewWithNullPtr for wire_cst_knowledge_graph_analysis {
-        fn new_with_null_ptr() -> Self {
-            Self {
-                clustering: Default::default(),
-                semantic_edges: Default::default(),
-            }
-        }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_list_cluster_info {
+        ptr: *mut wire_cst_cluster_info,
+        len: i32,
     }
-    impl Default for wire_cst_knowledge_graph_analysis {
-        fn default() -> Self {
-            Self::new_with_null_ptr()
-        }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_list_dedup_document {
+        ptr: *mut wire_cst_dedup_document,
+        len: i32,
     }
-    impl NewWithNullPtr for wire_cst_mcp_parameter {
-        fn new_with_null_ptr() -> Self {
-            Self {
-                name: core::ptr::null_mut(),
-                description: core::ptr::null_mut(),
-                param_type: Default::default(),
-                required: Default::default(),
-            }
-        }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_list_duplicate_group {
+        ptr: *mut wire_cst_duplicate_group,
+        len: i32,
     }
-    impl Default for wire_cst_mcp_parameter {
-        fn default() -> Self {
-            Self::new_with_null_ptr()
-        }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_list_duplicate_pair {
+        ptr: *mut wire_cst_duplicate_pair,
+        len: i32,
     }
-    impl NewWithNullPtr for wire_cst_mcp_tool_call {
-        fn new_with_null_ptr() -> Self {
-            Self {
-                tool: core::ptr::null_mut(),
-                parameters_json: core::ptr::null_mut(),
-                description: core::ptr::null_mut(),
-            }
-        }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_list_duplicate_span {
+        ptr: *mut wire_cst_duplicate_span,
+        len: i32,
     }
-    impl Default for wire_cst_mcp_tool_call {
-        fn default() -> Self {
-            Self::new_with_null_ptr()
-        }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_list_embedding_cluster {
+        ptr: *mut wire_cst_embedding_cluster,
+        len: i32,
     }
-    impl NewWithNullPtr for wire_cst_mcp_tool_result {
-        fn new_with_null_ptr() -> Self {
-            Self {
-                success: Default::default(),
-                result: core::ptr::null_mut(),
-                tool: core::ptr::null_mut(),
-            }
-        }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_list_embedding_entry {
+        ptr: *mut wire_cst_embedding_entry,
+        len: i32,
+    }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_list_graph_edge {
+        ptr: *mut wire_cst_graph_edge,
+        len: i32,
     }
-    impl Default for wire_cst_mcp_tool_result {
-        fn default() -> Self {
-            Self::new_with_null_ptr()
-        }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_list_graph_node {
+        ptr: *mut wire_cst_graph_node,
+        len: i32,
     }
-    impl NewWithNullPtr for wire_cst_node_position {
-        fn new_with_null_ptr() -> Self {
-            Self {
-                id: core::ptr::null_mut(),
-                x: Default::default(),
-                y: Default::default(),
-                radius: Default::default(),
-                color: Default::default(),
-                node_type: core::ptr::null_mut(),
-            }
-        }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_list_hybrid_hit {
+        ptr: *mut wire_cst_hybrid_hit,
+        len: i32,
     }
-    impl Default for wire_cst_node_position {
-        fn default() -> Self {
-            Self::new_with_null_ptr()
-        }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_list_list_prim_f_32_strict {
+        ptr: *mut *mut wire_cst_list_prim_f_32_strict,
+        len: i32,
     }
-    impl NewWithNullPtr for wire_cst_record_string_string {
-        fn new_with_null_ptr() -> Self {
-            Self {
-                field0: core::ptr::null_mut(),
-                field1: core::ptr::null_mut(),
-            }
-        }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_list_loaded_model_info {
+        ptr: *mut wire_cst_loaded_model_info,
+        len: i32,
     }
-    impl Default for wire_cst_record_string_string {
-        fn default() -> Self {
-            Self::new_with_null_ptr()
-        }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_list_markdown_chunk {
+        ptr: *mut wire_cst_markdown_chunk,
+        len: i32,
     }
-    impl NewWithNullPtr for wire_cst_semantic_edge {
-        fn new_with_null_ptr() -> Self {
-            Self {
-                source: core::ptr::null_mut(),
-                target: core::ptr::null_mut(),
-                similarity: Default::default(),
-                is_ghost: Default::default(),
-            }
-        }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_list_mcp_parameter {
+        ptr: *mut wire_cst_mcp_parameter,
+        len: i32,
     }
-    impl Default for wire_cst_semantic_edge {
-        fn default() -> Self {
-            Self::new_with_null_ptr()
-        }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_list_mcp_tool {
+        ptr: *mut i32,
+        len: i32,
     }
-    impl NewWithNullPtr for wire_cst_semantic_edge_result {
-        fn new_with_null_ptr() -> Self {
-            Self {
-                edges: core::ptr::null_mut(),
-                count: Default::default(),
-            }
-        }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_list_mcp_tool_call {
+        ptr: *mut wire_cst_mcp_tool_call,
+        len: i32,
     }
-    impl Default for wire_cst_semantic_edge_result {
-        fn default() -> Self {
-            Self::new_with_null_ptr()
-        }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_list_mcp_tool_result {
+        ptr: *mut wire_cst_mcp_tool_result,
+        len: i32,
     }
-    impl NewWithNullPtr for wire_cst_similarity_result {
-        fn new_with_null_ptr() -> Self {
-            Self {
-                id: core::ptr::null_mut(),
-                score: Default::default(),
-                text_preview: core::ptr::null_mut(),
-            }
-        }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_list_node_position {
+        ptr: *mut wire_cst_node_position,
+        len: i32,
     }
-    impl Default for wire_cst_similarity_result {
-        fn default() -> Self {
-            Self::new_with_null_ptr()
-        }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_list_prim_f_32_loose {
+        ptr: *mut f32,
+        len: i32,
     }
-    impl NewWithNullPtr for wire_cst_stream_graph_stats {
-        fn new_with_null_ptr() -> Self {
-            Self {
-                node_count: Default::default(),
-                edge_count: Default::default(),
-                visible_count: Default::default(),
-            }
-        }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_list_prim_f_32_strict {
+        ptr: *mut f32,
+        len: i32,
     }
-    impl Default for wire_cst_stream_graph_stats {
-        fn default() -> Self {
-            Self::new_with_null_ptr()
-        }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_list_prim_i_32_loose {
+        ptr: *mut i32,
+        len: i32,
     }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_kivixa_wire__crate__api__add_graph_edge(
-        port_: i64,
-        source: *mut wire_cst_list_prim_u_8_strict,
-        target: *mut wire_cst_list_prim_u_8_strict,
-        weight: f32,
-        edge_type: *mut wire_cst_list_prim_u_8_strict,
-    ) {
-        wire__crate__api__add_graph_edge_impl(port_, source, target, weight, edge_type)
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_list_prim_i_32_strict {
+        ptr: *mut i32,
+        len: i32,
     }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_kivixa_wire__crate__api__add_graph_edges(
-        port_: i64,
-        edges: *mut wire_cst_list_graph_edge,
-    ) {
-        wire__crate__api__add_graph_edges_impl(port_, edges)
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_list_prim_u_32_strict {
+        ptr: *mut u32,
+        len: i32,
     }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_kivixa_wire__crate__api__add_graph_node(
-        port_: i64,
-        id: *mut wire_cst_list_prim_u_8_strict,
-        label: *mut wire_cst_list_prim_u_8_strict,
-        node_type: *mut wire_cst_list_prim_u_8_strict,
-        x: f32,
-        y: f32,
-        color: *mut wire_cst_list_prim_u_8_strict,
-        metadata: *mut wire_cst_list_prim_u_8_strict,
-    ) {
-        wire__crate__api__add_graph_node_impl(port_, id, label, node_type, x, y, color, metadata)
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_list_prim_u_8_strict {
+        ptr: *mut u8,
+        len: i32,
     }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_kivixa_wire__crate__api__add_graph_nodes(
-        port_: i64,
-        nodes: *mut wire_cst_list_graph_node,
-    ) {
-        wire__crate__api__add_graph_nodes_impl(port_, nodes)
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_list_rag_source {
+        ptr: *mut wire_cst_rag_source,
+        len: i32,
     }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_kivixa_wire__crate__api__add_stream_edge(
-        port_: i64,
-        from_id: *mut wire_cst_list_prim_u_8_strict,
-        to_id: *mut wire_cst_list_prim_u_8_strict,
-        strength: f32,
-    ) {
-        wire__crate__api__add_stream_edge_impl(port_, from_id, to_id, strength)
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_list_record_string_string {
+        ptr: *mut wire_cst_record_string_string,
+        len: i32,
     }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_kivixa_wire__crate__api__add_stream_node(
-        port_: i64,
-        id: *mut wire_cst_list_prim_u_8_strict,
-        x: f32,
-        y: f32,
-        radius: f32,
-        color: u32,
-    ) {
-        wire__crate__api__add_stream_node_impl(port_, id, x, y, radius, color)
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_list_reply_span {
+        ptr: *mut wire_cst_reply_span,
+        len: i32,
     }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_kivixa_wire__crate__api__analyze_knowledge_graph(
-        port_: i64,
-        entries: *mut wire_cst_list_embedding_entry,
-        k: *mut usize,
-        similarity_threshold: *mut f32,
-        existing_links: *mut wire_cst_list_record_string_string,
-    ) {
-        wire__crate__api__analyze_knowledge_graph_impl(
-            port_,
-            entries,
-            k,
-            similarity_threshold,
-            existing_links,
-        )
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_list_semantic_edge {
+        ptr: *mut wire_cst_semantic_edge,
+        len: i32,
     }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_kivixa_wire__crate__api__batch_embed(
-        port_: i64,
-        texts: *mut wire_cst_list_String,
-    ) {
-        wire__crate__api__batch_embed_impl(port_, texts)
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_list_similarity_result {
+        ptr: *mut wire_cst_similarity_result,
+        len: i32,
     }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_kivixa_wire__crate__api__chat_completion(
-        port_: i64,
-        messages: *mut wire_cst_list_record_string_string,
-        max_tokens: *mut u32,
-    ) {
-        wire__crate__api__chat_completion_impl(port_, messages, max_tokens)
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_list_token_candidate {
+        ptr: *mut wire_cst_token_candidate,
+        len: i32,
     }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_kivixa_wire__crate__api__clear_graph(
-    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
-        wire__crate__api__clear_graph_impl()
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_list_token_logprob {
+        ptr: *mut wire_cst_token_logprob,
+        len: i32,
     }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_kivixa_wire__crate__api__clear_stream_graph(
-    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
-        wire__crate__api__clear_stream_graph_impl()
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_list_transcript_segment {
+        ptr: *mut wire_cst_transcript_segment,
+        len: i32,
     }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_kivixa_wire__crate__api__cluster_embeddings(
-        port_: i64,
-        entries: *mut wire_cst_list_embedding_entry,
-        threshold: f32,
-    ) {
-        wire__crate__api__cluster_embeddings_impl(port_, entries, threshold)
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_loaded_model_info {
+        model_id: *mut wire_cst_list_prim_u_8_strict,
+        model_path: *mut wire_cst_list_prim_u_8_strict,
+        size_bytes: u64,
+        is_default: bool,
     }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_kivixa_wire__crate__api__cluster_notes(
-        port_: i64,
-        entries: *mut wire_cst_list_embedding_entry,
-        k: *mut usize,
-        max_iterations: *mut usize,
-    ) {
-        wire__crate__api__cluster_notes_impl(port_, entries, k, max_iterations)
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_markdown_chunk {
+        index: usize,
+        heading_path: *mut wire_cst_list_String,
+        start: usize,
+        end: usize,
+        text: *mut wire_cst_list_prim_u_8_strict,
+        token_count: u32,
     }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_kivixa_wire__crate__api__compute_graph_layout(
-        port_: i64,
-        iterations: *mut u32,
-    ) {
-        wire__crate__api__compute_graph_layout_impl(port_, iterations)
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_mcp_parameter {
+        name: *mut wire_cst_list_prim_u_8_strict,
+        description: *mut wire_cst_list_prim_u_8_strict,
+        param_type: i32,
+        required: bool,
     }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_kivixa_wire__crate__api__connect_note_to_topics(
-        port_: i64,
-        note_id: *mut wire_cst_list_prim_u_8_strict,
-        topic_ids: *mut wire_cst_list_String,
-    ) {
-        wire__crate__api__connect_note_to_topics_impl(port_, note_id, topic_ids)
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_mcp_tool_call {
+        tool: *mut wire_cst_list_prim_u_8_strict,
+        parameters_json: *mut wire_cst_list_prim_u_8_strict,
+        description: *mut wire_cst_list_prim_u_8_strict,
     }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_kivixa_wire__crate__api__cosine_similarity(
-        a: *mut wire_cst_list_prim_f_32_loose,
-        b: *mut wire_cst_list_prim_f_32_loose,
-    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
-        wire__crate__api__cosine_similarity_impl(a, b)
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_mcp_tool_result {
+        success: bool,
+        result: *mut wire_cst_list_prim_u_8_strict,
+        tool: *mut wire_cst_list_prim_u_8_strict,
     }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_kivixa_wire__crate__api__discover_semantic_edges(
-        port_: i64,
-        entries: *mut wire_cst_list_embedding_entry,
-        threshold: *mut f32,
-        existing_links: *mut wire_cst_list_record_string_string,
-    ) {
-        wire__crate__api__discover_semantic_edges_impl(port_, entries, threshold, existing_links)
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_node_position {
+        id: *mut wire_cst_list_prim_u_8_strict,
+        x: f32,
+        y: f32,
+        radius: f32,
+        color: u32,
+        node_type: *mut wire_cst_list_prim_u_8_strict,
     }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_kivixa_wire__crate__api__extract_topics(
-        port_: i64,
-        text: *mut wire_cst_list_prim_u_8_strict,
-        num_topics: *mut u32,
-    ) {
-        wire__crate__api__extract_topics_impl(port_, text, num_topics)
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_note_metadata {
+        folder: *mut wire_cst_list_prim_u_8_strict,
+        tags: *mut wire_cst_list_String,
+        created_at: *mut i64,
+        modified_at: *mut i64,
+        note_type: *mut wire_cst_list_prim_u_8_strict,
+        chunk_start: *mut usize,
+        chunk_end: *mut usize,
     }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_kivixa_wire__crate__api__find_similar(
-        port_: i64,
-        query: *mut wire_cst_list_prim_f_32_loose,
-        entries: *mut wire_cst_list_embedding_entry,
-        top_k: usize,
-        threshold: f32,
-    ) {
-        wire__crate__api__find_similar_impl(port_, query, entries, top_k, threshold)
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_parsed_reply {
+        tool_calls: *mut wire_cst_list_mcp_tool_call,
+        spans: *mut wire_cst_list_reply_span,
+        text: *mut wire_cst_list_prim_u_8_strict,
     }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_kivixa_wire__crate__api__generate_text(
-        port_: i64,
-        prompt: *mut wire_cst_list_prim_u_8_strict,
-        max_tokens: *mut u32,
-    ) {
-        wire__crate__api__generate_text_impl(port_, prompt, max_tokens)
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_rag_answer {
+        answer: *mut wire_cst_list_prim_u_8_strict,
+        sources: *mut wire_cst_list_rag_source,
+        skipped: usize,
     }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_kivixa_wire__crate__api__get_embedding(
-        port_: i64,
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_rag_source {
+        citation: u32,
+        note_id: *mut wire_cst_list_prim_u_8_strict,
+        chunk_offset: *mut usize,
+        chunk_index: *mut usize,
+        score: f32,
         text: *mut wire_cst_list_prim_u_8_strict,
-    ) {
-        wire__crate__api__get_embedding_impl(port_, text)
+        cited: bool,
     }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_kivixa_wire__crate__api__get_embedding_dimension(
-    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
-        wire__crate__api__get_embedding_dimension_impl()
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_reasoning_options {
+        enabled: bool,
+        open_tag: *mut wire_cst_list_prim_u_8_strict,
+        close_tag: *mut wire_cst_list_prim_u_8_strict,
+        thinking_budget: *mut u32,
     }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_kivixa_wire__crate__api__get_graph_state(port_: i64) {
-        wire__crate__api__get_graph_state_impl(port_)
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_recall_report {
+        queries: usize,
+        top_k: usize,
+        recall: f32,
+        rescored_recall: f32,
+        f32_bytes: usize,
+        quantized_bytes: usize,
     }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_kivixa_wire__crate__api__get_model_type(
-    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
-        wire__crate__api__get_model_type_impl()
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_record_string_string {
+        field0: *mut wire_cst_list_prim_u_8_strict,
+        field1: *mut wire_cst_list_prim_u_8_strict,
     }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_kivixa_wire__crate__api__get_or_create_topic_hub(
-        port_: i64,
-        topic: *mut wire_cst_list_prim_u_8_strict,
-    ) {
-        wire__crate__api__get_or_create_topic_hub_impl(port_, topic)
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_reply_span {
+        start: usize,
+        end: usize,
+        kind: i32,
+        call_count: usize,
     }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_kivixa_wire__crate__api__get_stream_graph_stats(
-    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
-        wire__crate__api__get_stream_graph_stats_impl()
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_sampler_config {
+        temperature: f32,
+        top_k: i32,
+        top_p: f32,
+        min_p: f32,
+        repeat_penalty: f32,
+        frequency_penalty: f32,
+        presence_penalty: f32,
+        penalty_last_n: i32,
+        seed: *mut u32,
+        greedy: bool,
     }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_kivixa_wire__crate__api__get_version(
-    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
-        wire__crate__api__get_version_impl()
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_search_options {
+        top_k: usize,
+        threshold: f32,
+        filter: *mut wire_cst_list_prim_u_8_strict,
+        mmr_lambda: *mut f32,
+        mmr_candidates: usize,
     }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_kivixa_wire__crate__api__get_visible_graph_nodes(port_: i64) {
-        wire__crate__api__get_visible_graph_nodes_impl(port_)
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_semantic_edge {
+        source: *mut wire_cst_list_prim_u_8_strict,
+        target: *mut wire_cst_list_prim_u_8_strict,
+        similarity: f32,
+        is_ghost: bool,
     }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_kivixa_wire__crate__api__health_check(
-    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
-        wire__crate__api__health_check_impl()
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_semantic_edge_result {
+        edges: *mut wire_cst_list_semantic_edge,
+        count: usize,
     }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_kivixa_wire__crate__api__init_graph(
-    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
-        wire__crate__api__init_graph_impl()
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_similarity_result {
+        id: *mut wire_cst_list_prim_u_8_strict,
+        score: f32,
+        text_preview: *mut wire_cst_list_prim_u_8_strict,
     }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_kivixa_wire__crate__api__init_mcp(
-        port_: i64,
-        base_path: *mut wire_cst_list_prim_u_8_strict,
-        max_file_size: *mut usize,
-        allowed_extensions: *mut wire_cst_list_String,
-    ) {
-        wire__crate__api__init_mcp_impl(port_, base_path, max_file_size, allowed_extensions)
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_stream_graph_stats {
+        node_count: usize,
+        edge_count: usize,
+        visible_count: usize,
     }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_kivixa_wire__crate__api__init_model(
-        model_path: *mut wire_cst_list_prim_u_8_strict,
-    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
-        wire__crate__api__init_model_impl(model_path)
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_summary {
+        summary: *mut wire_cst_list_prim_u_8_strict,
+        chunk_summaries: *mut wire_cst_list_String,
+        merge_levels: u32,
+    }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_summary_options {
+        style: i32,
+        max_words: u32,
+        chunk_tokens: u32,
+        model_id: *mut wire_cst_list_prim_u_8_strict,
+    }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_token_candidate {
+        token: i32,
+        text: *mut wire_cst_list_prim_u_8_strict,
+        logprob: f32,
     }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_kivixa_wire__crate__api__init_model_with_config(
-        port_: i64,
-        model_path: *mut wire_cst_list_prim_u_8_strict,
-        n_gpu_layers: u32,
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_token_logprob {
+        token: i32,
+        text: *mut wire_cst_list_prim_u_8_strict,
+        logprob: f32,
+        top_alternatives: *mut wire_cst_list_token_candidate,
+    }
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_tokenizer_info {
+        vocab_size: u32,
+        n_ctx_train: u32,
         n_ctx: u32,
-        n_threads: i32,
-        temperature: f32,
-        top_p: f32,
-        max_tokens: u32,
-        model_type: *mut i32,
-    ) {
-        wire__crate__api__init_model_with_config_impl(
-            port_,
-            model_path,
-            n_gpu_layers,
-            n_ctx,
-            n_threads,
-            temperature,
-            top_p,
-            max_tokens,
-            model_type,
-        )
+        bos_token: i32,
+        eos_token: i32,
+        tokenizer_model: *mut wire_cst_list_prim_u_8_strict,
     }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_kivixa_wire__crate__api__is_graph_stream_running(
-    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
-        wire__crate__api__is_graph_stream_running_impl()
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_transcript_segment {
+        text: *mut wire_cst_list_prim_u_8_strict,
+        start_time: f32,
+        end_time: f32,
     }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_kivixa_wire__crate__api__is_mcp_initialized(
-    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
-        wire__crate__api__is_mcp_initialized_impl()
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct wire_cst_vector_store_stats {
+        entries: usize,
+        dead_rows: usize,
+        dimension: usize,
+        file_bytes: u64,
     }
+}
+#[cfg(not(target_family = "wasm"))]
+pub use io::*;
 
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_kivixa_wire__crate__api__is_model_loaded(
-    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
-        wire__crate__api__is_model_loaded_impl()
-    }
+/// cbindgen:ignore
+#[cfg(target_family = "wasm")]
+mod web {
+    // This file is automatically generated, so please do not edit it.
+    // @generated by `flutter_rust_bridge`@ 2.12.0.
 
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_kivixa_wire__crate__api__mcp_classify_task(
-        message: *mut wire_cst_list_prim_u_8_strict,
-    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
-        wire__crate__api__mcp_classify_task_impl(message)
-    }
+    // Section: imports
 
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_kivixa_wire__crate__api__mcp_create_folder(
-        port_: i64,
-        path: *mut wire_cst_list_prim_u_8_strict,
-    ) {
-        wire__crate__api__mcp_create_folder_impl(port_, path)
-    }
+    use super::*;
+    use flutter_rust_bridge::for_generated::byteorder::{
+        NativeEndian, ReadBytesExt, WriteBytesExt,
+    };
+    use flutter_rust_bridge::for_generated::wasm_bindgen;
+    use flutter_rust_bridge::for_generated::wasm_bindgen::prelude::*;
+    use flutter_rust_bridge::for_generated::{transform_result_dco, Lifetimeable, Lockable};
+    use flutter_rust_bridge::{Handler, IntoIntoDart};
 
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_kivixa_wire__crate__api__mcp_delete_file(
-        port_: i64,
-        path: *mut wire_cst_list_prim_u_8_strict,
-    ) {
-        wire__crate__api__mcp_delete_file_impl(port_, path)
-    }
+    // Section: boilerplate
 
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_kivixa_wire__crate__api__mcp_execute_tool_call(
-        port_: i64,
-        tool_call: *mut wire_cst_mcp_tool_call,
-    ) {
-        wire__crate__api__mcp_execute_tool_call_impl(port_, tool_call)
-    }
+    flutter_rust_bridge::frb_generated_boilerplate_web!();
 
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_kivixa_wire__crate__api__mcp_get_all_tools(
-    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
-        wire__crate__api__mcp_get_all_tools_impl()
-    }
+    // Section: dart2rust
 
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_kivixa_wire__crate__api__mcp_get_model_for_task(
-        category: i32,
-    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
-        wire__crate__api__mcp_get_model_for_task_impl(category)
+    impl CstDecode<flutter_rust_bridge::for_generated::anyhow::Error> for String {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> flutter_rust_bridge::for_generated::anyhow::Error {
+            unimplemented!()
+        }
     }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_kivixa_wire__crate__api__mcp_get_tool_description(
-        tool: i32,
-    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
-        wire__crate__api__mcp_get_tool_description_impl(tool)
+    impl
+        CstDecode<StreamSink<crate::agent::AgentStep, flutter_rust_bridge::for_generated::DcoCodec>>
+        for String
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(
+            self,
+        ) -> StreamSink<crate::agent::AgentStep, flutter_rust_bridge::for_generated::DcoCodec>
+        {
+            StreamSink::deserialize(self)
+        }
     }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_kivixa_wire__crate__api__mcp_get_tool_name(
-        tool: i32,
-    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
-        wire__crate__api__mcp_get_tool_name_impl(tool)
+    impl
+        CstDecode<
+            StreamSink<
+                crate::inference::GenerationChunk,
+                flutter_rust_bridge::for_generated::DcoCodec,
+            >,
+        > for String
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(
+            self,
+        ) -> StreamSink<
+            crate::inference::GenerationChunk,
+            flutter_rust_bridge::for_generated::DcoCodec,
+        > {
+            StreamSink::deserialize(self)
+        }
     }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_kivixa_wire__crate__api__mcp_get_tool_parameters(
-        tool: i32,
-    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
-        wire__crate__api__mcp_get_tool_parameters_impl(tool)
+    impl CstDecode<String> for String {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> String {
+            self
+        }
     }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_kivixa_wire__crate__api__mcp_get_tool_schemas(
-    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
-        wire__crate__api__mcp_get_tool_schemas_impl()
+    impl CstDecode<crate::agent::AgentRun>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::agent::AgentRun {
+            let self_ = self
+                .dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap();
+            assert_eq!(
+                self_.length(),
+                4,
+                "Expected 4 elements, got {}",
+                self_.length()
+            );
+            crate::agent::AgentRun {
+                answer: self_.get(0).cst_decode(),
+                stop_reason: self_.get(1).cst_decode(),
+                steps: self_.get(2).cst_decode(),
+                messages: self_.get(3).cst_decode(),
+            }
+        }
     }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_kivixa_wire__crate__api__mcp_list_files(
-        port_: i64,
-        path: *mut wire_cst_list_prim_u_8_strict,
-    ) {
-        wire__crate__api__mcp_list_files_impl(port_, path)
+    impl CstDecode<crate::agent::AgentStep>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::agent::AgentStep {
+            let self_ = self
+                .dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap();
+            assert_eq!(
+                self_.length(),
+                3,
+                "Expected 3 elements, got {}",
+                self_.length()
+            );
+            crate::agent::AgentStep {
+                reply: self_.get(0).cst_decode(),
+                tool_calls: self_.get(1).cst_decode(),
+                results: self_.get(2).cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::clustering::ClusterAssignment>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::clustering::ClusterAssignment {
+            let self_ = self
+                .dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap();
+            assert_eq!(
+                self_.length(),
+                3,
+                "Expected 3 elements, got {}",
+                self_.length()
+            );
+            crate::clustering::ClusterAssignment {
+                id: self_.get(0).cst_decode(),
+                cluster_id: self_.get(1).cst_decode(),
+                color: self_.get(2).cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::clustering::ClusterInfo>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::clustering::ClusterInfo {
+            let self_ = self
+                .dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap();
+            assert_eq!(
+                self_.length(),
+                4,
+                "Expected 4 elements, got {}",
+                self_.length()
+            );
+            crate::clustering::ClusterInfo {
+                id: self_.get(0).cst_decode(),
+                size: self_.get(1).cst_decode(),
+                color: self_.get(2).cst_decode(),
+                centroid: self_.get(3).cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::clustering::ClusteringResult>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::clustering::ClusteringResult {
+            let self_ = self
+                .dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap();
+            assert_eq!(
+                self_.length(),
+                3,
+                "Expected 3 elements, got {}",
+                self_.length()
+            );
+            crate::clustering::ClusteringResult {
+                assignments: self_.get(0).cst_decode(),
+                clusters: self_.get(1).cst_decode(),
+                k: self_.get(2).cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::context_window::ContextPolicy>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::context_window::ContextPolicy {
+            let self_ = self
+                .dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap();
+            assert_eq!(
+                self_.length(),
+                2,
+                "Expected 2 elements, got {}",
+                self_.length()
+            );
+            crate::context_window::ContextPolicy {
+                strategy: self_.get(0).cst_decode(),
+                summary_max_tokens: self_.get(1).cst_decode(),
+            }
+        }
     }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_kivixa_wire__crate__api__mcp_parse_tool_call(
-        port_: i64,
-        json: *mut wire_cst_list_prim_u_8_strict,
-    ) {
-        wire__crate__api__mcp_parse_tool_call_impl(port_, json)
+    impl CstDecode<crate::context_window::ContextReport>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::context_window::ContextReport {
+            let self_ = self
+                .dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap();
+            assert_eq!(
+                self_.length(),
+                7,
+                "Expected 7 elements, got {}",
+                self_.length()
+            );
+            crate::context_window::ContextReport {
+                context_size: self_.get(0).cst_decode(),
+                prompt_tokens: self_.get(1).cst_decode(),
+                message_tokens: self_.get(2).cst_decode(),
+                dropped: self_.get(3).cst_decode(),
+                truncated: self_.get(4).cst_decode(),
+                summarized: self_.get(5).cst_decode(),
+                summary: self_.get(6).cst_decode(),
+            }
+        }
     }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_kivixa_wire__crate__api__mcp_read_file(
-        port_: i64,
-        path: *mut wire_cst_list_prim_u_8_strict,
-    ) {
-        wire__crate__api__mcp_read_file_impl(port_, path)
+    impl CstDecode<crate::dedup::DedupDocument>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::dedup::DedupDocument {
+            let self_ = self
+                .dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap();
+            assert_eq!(
+                self_.length(),
+                4,
+                "Expected 4 elements, got {}",
+                self_.length()
+            );
+            crate::dedup::DedupDocument {
+                id: self_.get(0).cst_decode(),
+                text: self_.get(1).cst_decode(),
+                vector: self_.get(2).cst_decode(),
+                created_at: self_.get(3).cst_decode(),
+            }
+        }
     }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_kivixa_wire__crate__api__mcp_validate_path(
-        path: *mut wire_cst_list_prim_u_8_strict,
-    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
-        wire__crate__api__mcp_validate_path_impl(path)
+    impl CstDecode<crate::dedup::DedupOptions>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::dedup::DedupOptions {
+            let self_ = self
+                .dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap();
+            assert_eq!(
+                self_.length(),
+                6,
+                "Expected 6 elements, got {}",
+                self_.length()
+            );
+            crate::dedup::DedupOptions {
+                shingle_words: self_.get(0).cst_decode(),
+                num_hashes: self_.get(1).cst_decode(),
+                bands: self_.get(2).cst_decode(),
+                min_jaccard: self_.get(3).cst_decode(),
+                min_cosine: self_.get(4).cst_decode(),
+                min_span_words: self_.get(5).cst_decode(),
+            }
+        }
     }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_kivixa_wire__crate__api__mcp_write_file(
-        port_: i64,
-        path: *mut wire_cst_list_prim_u_8_strict,
-        content: *mut wire_cst_list_prim_u_8_strict,
-    ) {
-        wire__crate__api__mcp_write_file_impl(port_, path, content)
+    impl CstDecode<crate::dedup::DuplicateGroup>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::dedup::DuplicateGroup {
+            let self_ = self
+                .dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap();
+            assert_eq!(
+                self_.length(),
+                4,
+                "Expected 4 elements, got {}",
+                self_.length()
+            );
+            crate::dedup::DuplicateGroup {
+                ids: self_.get(0).cst_decode(),
+                canonical: self_.get(1).cst_decode(),
+                canonical_note: self_.get(2).cst_decode(),
+                pairs: self_.get(3).cst_decode(),
+            }
+        }
     }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_kivixa_wire__crate__api__pin_stream_node(
-        port_: i64,
-        id: *mut wire_cst_list_prim_u_8_strict,
-        pinned: bool,
-    ) {
-        wire__crate__api__pin_stream_node_impl(port_, id, pinned)
+    impl CstDecode<crate::dedup::DuplicatePair>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::dedup::DuplicatePair {
+            let self_ = self
+                .dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap();
+            assert_eq!(
+                self_.length(),
+                5,
+                "Expected 5 elements, got {}",
+                self_.length()
+            );
+            crate::dedup::DuplicatePair {
+                a: self_.get(0).cst_decode(),
+                b: self_.get(1).cst_decode(),
+                jaccard: self_.get(2).cst_decode(),
+                cosine: self_.get(3).cst_decode(),
+                spans: self_.get(4).cst_decode(),
+            }
+        }
     }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_kivixa_wire__crate__api__remove_graph_node(
-        port_: i64,
-        node_id: *mut wire_cst_list_prim_u_8_strict,
-    ) {
-        wire__crate__api__remove_graph_node_impl(port_, node_id)
+    impl CstDecode<crate::dedup::DuplicateSpan>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::dedup::DuplicateSpan {
+            let self_ = self
+                .dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap();
+            assert_eq!(
+                self_.length(),
+                5,
+                "Expected 5 elements, got {}",
+                self_.length()
+            );
+            crate::dedup::DuplicateSpan {
+                a_start: self_.get(0).cst_decode(),
+                a_end: self_.get(1).cst_decode(),
+                b_start: self_.get(2).cst_decode(),
+                b_end: self_.get(3).cst_decode(),
+                words: self_.get(4).cst_decode(),
+            }
+        }
     }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_kivixa_wire__crate__api__remove_stream_edge(
-        port_: i64,
-        from_id: *mut wire_cst_list_prim_u_8_strict,
-        to_id: *mut wire_cst_list_prim_u_8_strict,
-    ) {
-        wire__crate__api__remove_stream_edge_impl(port_, from_id, to_id)
+    impl CstDecode<crate::embedding_cache::EmbeddingCacheStats>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::embedding_cache::EmbeddingCacheStats {
+            let self_ = self
+                .dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap();
+            assert_eq!(
+                self_.length(),
+                3,
+                "Expected 3 elements, got {}",
+                self_.length()
+            );
+            crate::embedding_cache::EmbeddingCacheStats {
+                entries: self_.get(0).cst_decode(),
+                stale_records: self_.get(1).cst_decode(),
+                file_bytes: self_.get(2).cst_decode(),
+            }
+        }
     }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_kivixa_wire__crate__api__remove_stream_node(
-        port_: i64,
-        id: *mut wire_cst_list_prim_u_8_strict,
-    ) {
-        wire__crate__api__remove_stream_node_impl(port_, id)
+    impl CstDecode<crate::api::EmbeddingCluster>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::EmbeddingCluster {
+            let self_ = self
+                .dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap();
+            assert_eq!(
+                self_.length(),
+                1,
+                "Expected 1 elements, got {}",
+                self_.length()
+            );
+            crate::api::EmbeddingCluster {
+                ids: self_.get(0).cst_decode(),
+            }
+        }
     }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_kivixa_wire__crate__api__semantic_search(
-        port_: i64,
-        query_text: *mut wire_cst_list_prim_u_8_strict,
-        entries: *mut wire_cst_list_embedding_entry,
-        top_k: usize,
-    ) {
-        wire__crate__api__semantic_search_impl(port_, query_text, entries, top_k)
+    impl CstDecode<crate::embedder::EmbeddingConfig>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::embedder::EmbeddingConfig {
+            let self_ = self
+                .dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap();
+            assert_eq!(
+                self_.length(),
+                8,
+                "Expected 8 elements, got {}",
+                self_.length()
+            );
+            crate::embedder::EmbeddingConfig {
+                n_gpu_layers: self_.get(0).cst_decode(),
+                n_threads: self_.get(1).cst_decode(),
+                pooling: self_.get(2).cst_decode(),
+                chunk_tokens: self_.get(3).cst_decode(),
+                chunk_overlap: self_.get(4).cst_decode(),
+                max_sequences: self_.get(5).cst_decode(),
+                query_prefix: self_.get(6).cst_decode(),
+                document_prefix: self_.get(7).cst_decode(),
+            }
+        }
     }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_kivixa_wire__crate__api__set_stream_node_position(
-        port_: i64,
-        id: *mut wire_cst_list_prim_u_8_strict,
-        x: f32,
-        y: f32,
-    ) {
-        wire__crate__api__set_stream_node_position_impl(port_, id, x, y)
+    impl CstDecode<crate::embeddings::EmbeddingEntry>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::embeddings::EmbeddingEntry {
+            let self_ = self
+                .dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap();
+            assert_eq!(
+                self_.length(),
+                4,
+                "Expected 4 elements, got {}",
+                self_.length()
+            );
+            crate::embeddings::EmbeddingEntry {
+                id: self_.get(0).cst_decode(),
+                vector: self_.get(1).cst_decode(),
+                text_preview: self_.get(2).cst_decode(),
+                metadata: self_.get(3).cst_decode(),
+            }
+        }
     }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_kivixa_wire__crate__api__start_graph_stream(port_: i64) {
-        wire__crate__api__start_graph_stream_impl(port_)
+    impl CstDecode<crate::inference::GenerationChunk>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::inference::GenerationChunk {
+            let self_ = self
+                .dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap();
+            assert_eq!(
+                self_.length(),
+                3,
+                "Expected 3 elements, got {}",
+                self_.length()
+            );
+            crate::inference::GenerationChunk {
+                text: self_.get(0).cst_decode(),
+                reasoning: self_.get(1).cst_decode(),
+                stop_reason: self_.get(2).cst_decode(),
+            }
+        }
     }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_kivixa_wire__crate__api__stop_graph_stream(
-    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
-        wire__crate__api__stop_graph_stream_impl()
+    impl CstDecode<crate::inference::GenerationOptions>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::inference::GenerationOptions {
+            let self_ = self
+                .dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap();
+            assert_eq!(
+                self_.length(),
+                9,
+                "Expected 9 elements, got {}",
+                self_.length()
+            );
+            crate::inference::GenerationOptions {
+                max_tokens: self_.get(0).cst_decode(),
+                stop_sequences: self_.get(1).cst_decode(),
+                sampler: self_.get(2).cst_decode(),
+                grammar: self_.get(3).cst_decode(),
+                json_schema: self_.get(4).cst_decode(),
+                model_id: self_.get(5).cst_decode(),
+                context: self_.get(6).cst_decode(),
+                reasoning: self_.get(7).cst_decode(),
+                logprobs: self_.get(8).cst_decode(),
+            }
+        }
     }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_kivixa_wire__crate__api__unload_model(
-    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
-        wire__crate__api__unload_model_impl()
+    impl CstDecode<crate::inference::GenerationPoll>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::inference::GenerationPoll {
+            let self_ = self
+                .dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap();
+            assert_eq!(
+                self_.length(),
+                3,
+                "Expected 3 elements, got {}",
+                self_.length()
+            );
+            crate::inference::GenerationPoll {
+                text: self_.get(0).cst_decode(),
+                reasoning: self_.get(1).cst_decode(),
+                result: self_.get(2).cst_decode(),
+            }
+        }
     }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_kivixa_wire__crate__api__update_graph_viewport(
-        port_: i64,
-        x: f32,
-        y: f32,
-        width: f32,
-        height: f32,
-        scale: f32,
-    ) {
-        wire__crate__api__update_graph_viewport_impl(port_, x, y, width, height, scale)
+    impl CstDecode<crate::inference::GenerationResult>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::inference::GenerationResult {
+            let self_ = self
+                .dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap();
+            assert_eq!(
+                self_.length(),
+                7,
+                "Expected 7 elements, got {}",
+                self_.length()
+            );
+            crate::inference::GenerationResult {
+                text: self_.get(0).cst_decode(),
+                reasoning: self_.get(1).cst_decode(),
+                reasoning_tokens: self_.get(2).cst_decode(),
+                stop_reason: self_.get(3).cst_decode(),
+                tokens_generated: self_.get(4).cst_decode(),
+                context: self_.get(5).cst_decode(),
+                logprobs: self_.get(6).cst_decode(),
+            }
+        }
     }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_kivixa_cst_new_box_autoadd_f_32(value: f32) -> *mut f32 {
-        flutter_rust_bridge::for_generated::new_leak_box_ptr(value)
+    impl CstDecode<crate::gguf::GgufMetadata>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::gguf::GgufMetadata {
+            let self_ = self
+                .dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap();
+            assert_eq!(
+                self_.length(),
+                16,
+                "Expected 16 elements, got {}",
+                self_.length()
+            );
+            crate::gguf::GgufMetadata {
+                version: self_.get(0).cst_decode(),
+                architecture: self_.get(1).cst_decode(),
+                name: self_.get(2).cst_decode(),
+                chat_template: self_.get(3).cst_decode(),
+                context_length: self_.get(4).cst_decode(),
+                embedding_length: self_.get(5).cst_decode(),
+                block_count: self_.get(6).cst_decode(),
+                pooling_type: self_.get(7).cst_decode(),
+                quantization: self_.get(8).cst_decode(),
+                parameter_count: self_.get(9).cst_decode(),
+                tensor_count: self_.get(10).cst_decode(),
+                tokenizer_model: self_.get(11).cst_decode(),
+                vocab_size: self_.get(12).cst_decode(),
+                bos_token_id: self_.get(13).cst_decode(),
+                eos_token_id: self_.get(14).cst_decode(),
+                file_size: self_.get(15).cst_decode(),
+            }
+        }
     }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_kivixa_cst_new_box_autoadd_i_32(value: i32) -> *mut i32 {
-        flutter_rust_bridge::for_generated::new_leak_box_ptr(value)
+    impl CstDecode<crate::graph::GraphEdge>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::graph::GraphEdge {
+            let self_ = self
+                .dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap();
+            assert_eq!(
+                self_.length(),
+                4,
+                "Expected 4 elements, got {}",
+                self_.length()
+            );
+            crate::graph::GraphEdge {
+                source: self_.get(0).cst_decode(),
+                target: self_.get(1).cst_decode(),
+                weight: self_.get(2).cst_decode(),
+                edge_type: self_.get(3).cst_decode(),
+            }
+        }
     }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_kivixa_cst_new_box_autoadd_mcp_tool_call(
-    ) -> *mut wire_cst_mcp_tool_call {
-        flutter_rust_bridge::for_generated::new_leak_box_ptr(
-            wire_cst_mcp_tool_call::new_with_null_ptr(),
-        )
+    impl CstDecode<crate::graph::GraphNode>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::graph::GraphNode {
+            let self_ = self
+                .dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap();
+            assert_eq!(
+                self_.length(),
+                7,
+                "Expected 7 elements, got {}",
+                self_.length()
+            );
+            crate::graph::GraphNode {
+                id: self_.get(0).cst_decode(),
+                label: self_.get(1).cst_decode(),
+                node_type: self_.get(2).cst_decode(),
+                x: self_.get(3).cst_decode(),
+                y: self_.get(4).cst_decode(),
+                color: self_.get(5).cst_decode(),
+                metadata: self_.get(6).cst_decode(),
+            }
+        }
     }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_kivixa_cst_new_box_autoadd_u_32(value: u32) -> *mut u32 {
-        flutter_rust_bridge::for_generated::new_leak_box_ptr(value)
+    impl CstDecode<crate::graph::GraphState>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::graph::GraphState {
+            let self_ = self
+                .dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap();
+            assert_eq!(
+                self_.length(),
+                2,
+                "Expected 2 elements, got {}",
+                self_.length()
+            );
+            crate::graph::GraphState {
+                nodes: self_.get(0).cst_decode(),
+                edges: self_.get(1).cst_decode(),
+            }
+        }
     }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_kivixa_cst_new_box_autoadd_usize(value: usize) -> *mut usize {
-        flutter_rust_bridge::for_generated::new_leak_box_ptr(value)
+    impl CstDecode<crate::hnsw::HnswConfig>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::hnsw::HnswConfig {
+            let self_ = self
+                .dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap();
+            assert_eq!(
+                self_.length(),
+                3,
+                "Expected 3 elements, got {}",
+                self_.length()
+            );
+            crate::hnsw::HnswConfig {
+                m: self_.get(0).cst_decode(),
+                ef_construction: self_.get(1).cst_decode(),
+                ef_search: self_.get(2).cst_decode(),
+            }
+        }
     }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_kivixa_cst_new_list_String(len: i32) -> *mut wire_cst_list_String {
-        let wrap = wire_cst_list_String {
-            ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(
-                <*mut wire_cst_list_prim_u_8_strict>::new_with_null_ptr(),
-                len,
-            ),
-            len,
-        };
-        flutter_rust_bridge::for_generated::new_leak_box_ptr(wrap)
+    impl CstDecode<crate::hybrid_search::HybridHit>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::hybrid_search::HybridHit {
+            let self_ = self
+                .dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap();
+            assert_eq!(
+                self_.length(),
+                8,
+                "Expected 8 elements, got {}",
+                self_.length()
+            );
+            crate::hybrid_search::HybridHit {
+                id: self_.get(0).cst_decode(),
+                score: self_.get(1).cst_decode(),
+                semantic_score: self_.get(2).cst_decode(),
+                semantic_rank: self_.get(3).cst_decode(),
+                lexical_score: self_.get(4).cst_decode(),
+                lexical_rank: self_.get(5).cst_decode(),
+                matched_terms: self_.get(6).cst_decode(),
+                text_preview: self_.get(7).cst_decode(),
+            }
+        }
     }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_kivixa_cst_new_list_cluster_assignment(
-        len: i32,
-    ) -> *mut wire_cst_list_cluster_assignment {
-        let wrap = wire_cst_list_cluster_assignment {
-            ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(
-                <wire_cst_cluster_assignment>::new_with_null_ptr(),
-                len,
-            ),
-            len,
-        };
-        flutter_rust_bridge::for_generated::new_leak_box_ptr(wrap)
+    impl CstDecode<crate::hybrid_search::HybridOptions>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::hybrid_search::HybridOptions {
+            let self_ = self
+                .dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap();
+            assert_eq!(
+                self_.length(),
+                5,
+                "Expected 5 elements, got {}",
+                self_.length()
+            );
+            crate::hybrid_search::HybridOptions {
+                fusion: self_.get(0).cst_decode(),
+                semantic_weight: self_.get(1).cst_decode(),
+                rrf_k: self_.get(2).cst_decode(),
+                candidates: self_.get(3).cst_decode(),
+                min_semantic_score: self_.get(4).cst_decode(),
+            }
+        }
     }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_kivixa_cst_new_list_cluster_info(
-        len: i32,
-    ) -> *mut wire_cst_list_cluster_info {
-        let wrap = wire_cst_list_cluster_info {
-            ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(
-                <wire_cst_cluster_info>::new_with_null_ptr(),
-                len,
-            ),
-            len,
-        };
-        flutter_rust_bridge::for_generated::new_leak_box_ptr(wrap)
+    impl CstDecode<crate::api::KnowledgeGraphAnalysis>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::api::KnowledgeGraphAnalysis {
+            let self_ = self
+                .dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap();
+            assert_eq!(
+                self_.length(),
+                2,
+                "Expected 2 elements, got {}",
+                self_.length()
+            );
+            crate::api::KnowledgeGraphAnalysis {
+                clustering: self_.get(0).cst_decode(),
+                semantic_edges: self_.get(1).cst_decode(),
+            }
+        }
     }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_kivixa_cst_new_list_embedding_cluster(
-        len: i32,
-    ) -> *mut wire_cst_list_embedding_cluster {
-        let wrap = wire_cst_list_embedding_cluster {
-            ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(
-                <wire_cst_embedding_cluster>::new_with_null_ptr(),
-                len,
-            ),
-            len,
-        };
-        flutter_rust_bridge::for_generated::new_leak_box_ptr(wrap)
+    impl CstDecode<Vec<String>> for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<String> {
+            self.dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap()
+                .iter()
+                .map(CstDecode::cst_decode)
+                .collect()
+        }
     }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_kivixa_cst_new_list_embedding_entry(
-        len: i32,
-    ) -> *mut wire_cst_list_embedding_entry {
-        let wrap = wire_cst_list_embedding_entry {
-            ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(
-                <wire_cst_embedding_entry>::new_with_null_ptr(),
-                len,
-            ),
-            len,
-        };
-        flutter_rust_bridge::for_generated::new_leak_box_ptr(wrap)
+    impl CstDecode<Vec<crate::agent::AgentStep>>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<crate::agent::AgentStep> {
+            self.dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap()
+                .iter()
+                .map(CstDecode::cst_decode)
+                .collect()
+        }
     }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_kivixa_cst_new_list_graph_edge(
-        len: i32,
-    ) -> *mut wire_cst_list_graph_edge {
-        let wrap = wire_cst_list_graph_edge {
-            ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(
-                <wire_cst_graph_edge>::new_with_null_ptr(),
-                len,
-            ),
-            len,
-        };
-        flutter_rust_bridge::for_generated::new_leak_box_ptr(wrap)
+    impl CstDecode<Vec<crate::clustering::ClusterAssignment>>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<crate::clustering::ClusterAssignment> {
+            self.dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap()
+                .iter()
+                .map(CstDecode::cst_decode)
+                .collect()
+        }
     }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_kivixa_cst_new_list_graph_node(
-        len: i32,
-    ) -> *mut wire_cst_list_graph_node {
-        let wrap = wire_cst_list_graph_node {
-            ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(
-                <wire_cst_graph_node>::new_with_null_ptr(),
-                len,
-            ),
-            len,
-        };
-        flutter_rust_bridge::for_generated::new_leak_box_ptr(wrap)
+    impl CstDecode<Vec<crate::clustering::ClusterInfo>>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<crate::clustering::ClusterInfo> {
+            self.dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap()
+                .iter()
+                .map(CstDecode::cst_decode)
+                .collect()
+        }
     }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_kivixa_cst_new_list_mcp_parameter(
-        len: i32,
-    ) -> *mut wire_cst_list_mcp_parameter {
-        let wrap = wire_cst_list_mcp_parameter {
-            ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(
-                <wire_cst_mcp_parameter>::new_with_null_ptr(),
-                len,
-            ),
-            len,
-        };
-        flutter_rust_bridge::for_generated::new_leak_box_ptr(wrap)
+    impl CstDecode<Vec<crate::dedup::DedupDocument>>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<crate::dedup::DedupDocument> {
+            self.dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap()
+                .iter()
+                .map(CstDecode::cst_decode)
+                .collect()
+        }
     }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_kivixa_cst_new_list_mcp_tool(len: i32) -> *mut wire_cst_list_mcp_tool {
-        let wrap = wire_cst_list_mcp_tool {
-            ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(Default::default(), len),
-            len,
-        };
-        flutter_rust_bridge::for_generated::new_leak_box_ptr(wrap)
+    impl CstDecode<Vec<crate::dedup::DuplicateGroup>>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<crate::dedup::DuplicateGroup> {
+            self.dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap()
+                .iter()
+                .map(CstDecode::cst_decode)
+                .collect()
+        }
     }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_kivixa_cst_new_list_node_position(
-        len: i32,
-    ) -> *mut wire_cst_list_node_position {
-        let wrap = wire_cst_list_node_position {
-            ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(
-                <wire_cst_node_position>::new_with_null_ptr(),
-                len,
-            ),
-            len,
-        };
-        flutter_rust_bridge::for_generated::new_leak_box_ptr(wrap)
+    impl CstDecode<Vec<crate::dedup::DuplicatePair>>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<crate::dedup::DuplicatePair> {
+            self.dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap()
+                .iter()
+                .map(CstDecode::cst_decode)
+                .collect()
+        }
     }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_kivixa_cst_new_list_prim_f_32_loose(
-        len: i32,
-    ) -> *mut wire_cst_list_prim_f_32_loose {
-        let ans = wire_cst_list_prim_f_32_loose {
-            ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(Default::default(), len),
-            len,
-        };
-        flutter_rust_bridge::for_generated::new_leak_box_ptr(ans)
+    impl CstDecode<Vec<crate::dedup::DuplicateSpan>>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<crate::dedup::DuplicateSpan> {
+            self.dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap()
+                .iter()
+                .map(CstDecode::cst_decode)
+                .collect()
+        }
     }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_kivixa_cst_new_list_prim_f_32_strict(
-        len: i32,
-    ) -> *mut wire_cst_list_prim_f_32_strict {
-        let ans = wire_cst_list_prim_f_32_strict {
-            ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(Default::default(), len),
-            len,
-        };
-        flutter_rust_bridge::for_generated::new_leak_box_ptr(ans)
+    impl CstDecode<Vec<crate::api::EmbeddingCluster>>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<crate::api::EmbeddingCluster> {
+            self.dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap()
+                .iter()
+                .map(CstDecode::cst_decode)
+                .collect()
+        }
     }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_kivixa_cst_new_list_prim_u_8_strict(
-        len: i32,
-    ) -> *mut wire_cst_list_prim_u_8_strict {
-        let ans = wire_cst_list_prim_u_8_strict {
-            ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(Default::default(), len),
-            len,
-        };
-        flutter_rust_bridge::for_generated::new_leak_box_ptr(ans)
+    impl CstDecode<Vec<crate::embeddings::EmbeddingEntry>>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<crate::embeddings::EmbeddingEntry> {
+            self.dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap()
+                .iter()
+                .map(CstDecode::cst_decode)
+                .collect()
+        }
     }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_kivixa_cst_new_list_record_string_string(
-        len: i32,
-    ) -> *mut wire_cst_list_record_string_string {
-        let wrap = wire_cst_list_record_string_string {
-            ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(
-                <wire_cst_record_string_string>::new_with_null_ptr(),
-                len,
-            ),
-            len,
-        };
-        flutter_rust_bridge::for_generated::new_leak_box_ptr(wrap)
+    impl CstDecode<Vec<crate::graph::GraphEdge>>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<crate::graph::GraphEdge> {
+            self.dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap()
+                .iter()
+                .map(CstDecode::cst_decode)
+                .collect()
+        }
     }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_kivixa_cst_new_list_semantic_edge(
-        len: i32,
-    ) -> *mut wire_cst_list_semantic_edge {
-        let wrap = wire_cst_list_semantic_edge {
-            ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(
-                <wire_cst_semantic_edge>::new_with_null_ptr(),
-                len,
-            ),
-            len,
-        };
-        flutter_rust_bridge::for_generated::new_leak_box_ptr(wrap)
+    impl CstDecode<Vec<crate::graph::GraphNode>>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<crate::graph::GraphNode> {
+            self.dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap()
+                .iter()
+                .map(CstDecode::cst_decode)
+                .collect()
+        }
+    }
+    impl CstDecode<Vec<crate::hybrid_search::HybridHit>>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<crate::hybrid_search::HybridHit> {
+            self.dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap()
+                .iter()
+                .map(CstDecode::cst_decode)
+                .collect()
+        }
     }
-
-    #[unsafe(no_mangle)]
-    pub extern "C" fn frbgen_kivixa_cst_new_list_similarity_result(
-        len: i32,
-    ) -> *mut wire_cst_list_similarity_result {
-        let wrap = wire_cst_list_similarity_result {
-            ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(
-                <wire_cst_similarity_result>::new_with_null_ptr(),
-                len,
-            ),
-            len,
-        };
-        flutter_rust_bridge::for_generated::new_leak_box_ptr(wrap)
+    impl CstDecode<Vec<Vec<f32>>> for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<Vec<f32>> {
+            self.dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap()
+                .iter()
+                .map(CstDecode::cst_decode)
+                .collect()
+        }
     }
-
-    #[repr(C)]
-    #[derive(Clone, Copy)]
-    pub struct wire_cst_cluster_assignment {
-        id: *mut wire_cst_list_prim_u_8_strict,
-        cluster_id: usize,
-        color: *mut wire_cst_list_prim_u_8_strict,
+    impl CstDecode<Vec<crate::inference::LoadedModelInfo>>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<crate::inference::LoadedModelInfo> {
+            self.dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap()
+                .iter()
+                .map(CstDecode::cst_decode)
+                .collect()
+        }
     }
-    #[repr(C)]
-    #[derive(Clone, Copy)]
-    pub struct wire_cst_cluster_info {
-        id: usize,
-        size: usize,
-        color: *mut wire_cst_list_prim_u_8_strict,
-        centroid: *mut wire_cst_list_prim_f_32_strict,
+    impl CstDecode<Vec<crate::chunker::MarkdownChunk>>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<crate::chunker::MarkdownChunk> {
+            self.dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap()
+                .iter()
+                .map(CstDecode::cst_decode)
+                .collect()
+        }
     }
-    #[repr(C)]
-    #[derive(Clone, Copy)]
-    pub struct wire_cst_clustering_result {
-        assignments: *mut wire_cst_list_cluster_assignment,
-        clusters: *mut wire_cst_list_cluster_info,
-        k: usize,
+    impl CstDecode<Vec<crate::mcp::MCPParameter>>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<crate::mcp::MCPParameter> {
+            self.dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap()
+                .iter()
+                .map(CstDecode::cst_decode)
+                .collect()
+        }
     }
-    #[repr(C)]
-    #[derive(Clone, Copy)]
-    pub struct wire_cst_embedding_cluster {
-        ids: *mut wire_cst_list_String,
+    impl CstDecode<Vec<crate::mcp::MCPTool>>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<crate::mcp::MCPTool> {
+            self.dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap()
+                .iter()
+                .map(CstDecode::cst_decode)
+                .collect()
+        }
     }
-    #[repr(C)]
-    #[derive(Clone, Copy)]
-    pub struct wire_cst_embedding_entry {
-        id: *mut wire_cst_list_prim_u_8_strict,
-        vector: *mut wire_cst_list_prim_f_32_strict,
-        text_preview: *mut wire_cst_list_prim_u_8_strict,
+    impl CstDecode<Vec<crate::mcp::MCPToolCall>>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<crate::mcp::MCPToolCall> {
+            self.dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap()
+                .iter()
+                .map(CstDecode::cst_decode)
+                .collect()
+        }
     }
-    #[repr(C)]
-    #[derive(Clone, Copy)]
-    pub struct wire_cst_graph_edge {
-        source: *mut wire_cst_list_prim_u_8_strict,
-        target: *mut wire_cst_list_prim_u_8_strict,
-        weight: f32,
-        edge_type: *mut wire_cst_list_prim_u_8_strict,
+    impl CstDecode<Vec<crate::mcp::MCPToolResult>>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<crate::mcp::MCPToolResult> {
+            self.dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap()
+                .iter()
+                .map(CstDecode::cst_decode)
+                .collect()
+        }
     }
-    #[repr(C)]
-    #[derive(Clone, Copy)]
-    pub struct wire_cst_graph_node {
-        id: *mut wire_cst_list_prim_u_8_strict,
-        label: *mut wire_cst_list_prim_u_8_strict,
-        node_type: *mut wire_cst_list_prim_u_8_strict,
-        x: f32,
-        y: f32,
-        color: *mut wire_cst_list_prim_u_8_strict,
-        metadata: *mut wire_cst_list_prim_u_8_strict,
+    impl CstDecode<Vec<crate::streaming::NodePosition>>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<crate::streaming::NodePosition> {
+            self.dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap()
+                .iter()
+                .map(CstDecode::cst_decode)
+                .collect()
+        }
     }
-    #[repr(C)]
-    #[derive(Clone, Copy)]
-    pub struct wire_cst_graph_state {
-        nodes: *mut wire_cst_list_graph_node,
-        edges: *mut wire_cst_list_graph_edge,
+    impl CstDecode<Vec<f32>> for Box<[f32]> {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<f32> {
+            self.into_vec()
+        }
     }
-    #[repr(C)]
-    #[derive(Clone, Copy)]
-    pub struct wire_cst_knowledge_graph_analysis {
-        clustering: wire_cst_clustering_result,
-        semantic_edges: wire_cst_semantic_edge_result,
+    impl CstDecode<Vec<i32>> for Box<[i32]> {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<i32> {
+            self.into_vec()
+        }
     }
-    #[repr(C)]
-    #[derive(Clone, Copy)]
-    pub struct wire_cst_list_String {
-        ptr: *mut *mut wire_cst_list_prim_u_8_strict,
-        len: i32,
+    impl CstDecode<Vec<u32>> for Box<[u32]> {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<u32> {
+            self.into_vec()
+        }
     }
-    #[repr(C)]
-    #[derive(Clone, Copy)]
-    pub struct wire_cst_list_cluster_assignment {
-        ptr: *mut wire_cst_cluster_assignment,
-        len: i32,
+    impl CstDecode<Vec<u8>> for Box<[u8]> {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<u8> {
+            self.into_vec()
+        }
     }
-    #[repr(C)]
-    #[derive(Clone, Copy)]
-    pub struct wire_cst_list_cluster_info {
-        ptr: *mut wire_cst_cluster_info,
-        len: i32,
+    impl CstDecode<Vec<crate::rag::RagSource>>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<crate::rag::RagSource> {
+            self.dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap()
+                .iter()
+                .map(CstDecode::cst_decode)
+                .collect()
+        }
     }
-    #[repr(C)]
-    #[derive(Clone, Copy)]
-    pub struct wire_cst_list_embedding_cluster {
-        ptr: *mut wire_cst_embedding_cluster,
-        len: i32,
+    impl CstDecode<Vec<(String, String)>>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<(String, String)> {
+            self.dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap()
+                .iter()
+                .map(CstDecode::cst_decode)
+                .collect()
+        }
     }
-    #[repr(C)]
-    #[derive(Clone, Copy)]
-    pub struct wire_cst_list_embedding_entry {
-        ptr: *mut wire_cst_embedding_entry,
-        len: i32,
+    impl CstDecode<Vec<crate::tool_parser::ReplySpan>>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<crate::tool_parser::ReplySpan> {
+            self.dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap()
+                .iter()
+                .map(CstDecode::cst_decode)
+                .collect()
+        }
     }
-    #[repr(C)]
-    #[derive(Clone, Copy)]
-    pub struct wire_cst_list_graph_edge {
-        ptr: *mut wire_cst_graph_edge,
-        len: i32,
+    impl CstDecode<Vec<crate::clustering::SemanticEdge>>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<crate::clustering::SemanticEdge> {
+            self.dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap()
+                .iter()
+                .map(CstDecode::cst_decode)
+                .collect()
+        }
+    }
+    impl CstDecode<Vec<crate::embeddings::SimilarityResult>>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<crate::embeddings::SimilarityResult> {
+            self.dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap()
+                .iter()
+                .map(CstDecode::cst_decode)
+                .collect()
+        }
+    }
+    impl CstDecode<Vec<crate::inference::TokenCandidate>>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<crate::inference::TokenCandidate> {
+            self.dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap()
+                .iter()
+                .map(CstDecode::cst_decode)
+                .collect()
+        }
     }
-    #[repr(C)]
-    #[derive(Clone, Copy)]
-    pub struct wire_cst_list_graph_node {
-        ptr: *mut wire_cst_graph_node,
-        len: i32,
+    impl CstDecode<Vec<crate::inference::TokenLogprob>>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<crate::inference::TokenLogprob> {
+            self.dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap()
+                .iter()
+                .map(CstDecode::cst_decode)
+                .collect()
+        }
     }
-    #[repr(C)]
-    #[derive(Clone, Copy)]
-    pub struct wire_cst_list_mcp_parameter {
-        ptr: *mut wire_cst_mcp_parameter,
-        len: i32,
+    impl CstDecode<Vec<crate::summarize::TranscriptSegment>>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Vec<crate::summarize::TranscriptSegment> {
+            self.dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap()
+                .iter()
+                .map(CstDecode::cst_decode)
+                .collect()
+        }
     }
-    #[repr(C)]
-    #[derive(Clone, Copy)]
-    pub struct wire_cst_list_mcp_tool {
-        ptr: *mut i32,
-        len: i32,
+    impl CstDecode<crate::inference::LoadedModelInfo>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::inference::LoadedModelInfo {
+            let self_ = self
+                .dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap();
+            assert_eq!(
+                self_.length(),
+                4,
+                "Expected 4 elements, got {}",
+                self_.length()
+            );
+            crate::inference::LoadedModelInfo {
+                model_id: self_.get(0).cst_decode(),
+                model_path: self_.get(1).cst_decode(),
+                size_bytes: self_.get(2).cst_decode(),
+                is_default: self_.get(3).cst_decode(),
+            }
+        }
     }
-    #[repr(C)]
-    #[derive(Clone, Copy)]
-    pub struct wire_cst_list_node_position {
-        ptr: *mut wire_cst_node_position,
-        len: i32,
+    impl CstDecode<crate::chunker::MarkdownChunk>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::chunker::MarkdownChunk {
+            let self_ = self
+                .dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap();
+            assert_eq!(
+                self_.length(),
+                6,
+                "Expected 6 elements, got {}",
+                self_.length()
+            );
+            crate::chunker::MarkdownChunk {
+                index: self_.get(0).cst_decode(),
+                heading_path: self_.get(1).cst_decode(),
+                start: self_.get(2).cst_decode(),
+                end: self_.get(3).cst_decode(),
+                text: self_.get(4).cst_decode(),
+                token_count: self_.get(5).cst_decode(),
+            }
+        }
     }
-    #[repr(C)]
-    #[derive(Clone, Copy)]
-    pub struct wire_cst_list_prim_f_32_loose {
-        ptr: *mut f32,
-        len: i32,
+    impl CstDecode<crate::mcp::MCPParameter>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::mcp::MCPParameter {
+            let self_ = self
+                .dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap();
+            assert_eq!(
+                self_.length(),
+                4,
+                "Expected 4 elements, got {}",
+                self_.length()
+            );
+            crate::mcp::MCPParameter {
+                name: self_.get(0).cst_decode(),
+                description: self_.get(1).cst_decode(),
+                param_type: self_.get(2).cst_decode(),
+                required: self_.get(3).cst_decode(),
+            }
+        }
     }
-    #[repr(C)]
-    #[derive(Clone, Copy)]
-    pub struct wire_cst_list_prim_f_32_strict {
-        ptr: *mut f32,
-        len: i32,
+    impl CstDecode<crate::mcp::MCPToolCall>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::mcp::MCPToolCall {
+            let self_ = self
+                .dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap();
+            assert_eq!(
+                self_.length(),
+                3,
+                "Expected 3 elements, got {}",
+                self_.length()
+            );
+            crate::mcp::MCPToolCall {
+                tool: self_.get(0).cst_decode(),
+                parameters_json: self_.get(1).cst_decode(),
+                description: self_.get(2).cst_decode(),
+            }
+        }
     }
-    #[repr(C)]
-    #[derive(Clone, Copy)]
-    pub struct wire_cst_list_prim_u_8_strict {
-        ptr: *mut u8,
-        len: i32,
+    impl CstDecode<crate::mcp::MCPToolResult>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::mcp::MCPToolResult {
+            let self_ = self
+                .dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap();
+            assert_eq!(
+                self_.length(),
+                3,
+                "Expected 3 elements, got {}",
+                self_.length()
+            );
+            crate::mcp::MCPToolResult {
+                success: self_.get(0).cst_decode(),
+                result: self_.get(1).cst_decode(),
+                tool: self_.get(2).cst_decode(),
+            }
+        }
     }
-    #[repr(C)]
-    #[derive(Clone, Copy)]
-    pub struct wire_cst_list_record_string_string {
-        ptr: *mut wire_cst_record_string_string,
-        len: i32,
+    impl CstDecode<crate::streaming::NodePosition>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::streaming::NodePosition {
+            let self_ = self
+                .dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap();
+            assert_eq!(
+                self_.length(),
+                6,
+                "Expected 6 elements, got {}",
+                self_.length()
+            );
+            crate::streaming::NodePosition {
+                id: self_.get(0).cst_decode(),
+                x: self_.get(1).cst_decode(),
+                y: self_.get(2).cst_decode(),
+                radius: self_.get(3).cst_decode(),
+                color: self_.get(4).cst_decode(),
+                node_type: self_.get(5).cst_decode(),
+            }
+        }
     }
-    #[repr(C)]
-    #[derive(Clone, Copy)]
-    pub struct wire_cst_list_semantic_edge {
-        ptr: *mut wire_cst_semantic_edge,
-        len: i32,
+    impl CstDecode<crate::embeddings::NoteMetadata>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::embeddings::NoteMetadata {
+            let self_ = self
+                .dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap();
+            assert_eq!(
+                self_.length(),
+                7,
+                "Expected 7 elements, got {}",
+                self_.length()
+            );
+            crate::embeddings::NoteMetadata {
+                folder: self_.get(0).cst_decode(),
+                tags: self_.get(1).cst_decode(),
+                created_at: self_.get(2).cst_decode(),
+                modified_at: self_.get(3).cst_decode(),
+                note_type: self_.get(4).cst_decode(),
+                chunk_start: self_.get(5).cst_decode(),
+                chunk_end: self_.get(6).cst_decode(),
+            }
+        }
     }
-    #[repr(C)]
-    #[derive(Clone, Copy)]
-    pub struct wire_cst_list_similarity_result {
-        ptr: *mut wire_cst_similarity_result,
-        len: i32,
+    impl CstDecode<Option<String>> for Option<String> {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Option<String> {
+            self.map(CstDecode::cst_decode)
+        }
     }
-    #[repr(C)]
-    #[derive(Clone, Copy)]
-    pub struct wire_cst_mcp_parameter {
-        name: *mut wire_cst_list_prim_u_8_strict,
-        description: *mut wire_cst_list_prim_u_8_strict,
-        param_type: i32,
-        required: bool,
+    impl CstDecode<Option<Vec<f32>>> for Option<Box<[f32]>> {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> Option<Vec<f32>> {
+            self.map(CstDecode::cst_decode)
+        }
     }
-    #[repr(C)]
-    #[derive(Clone, Copy)]
-    pub struct wire_cst_mcp_tool_call {
-        tool: *mut wire_cst_list_prim_u_8_strict,
-        parameters_json: *mut wire_cst_list_prim_u_8_strict,
-        description: *mut wire_cst_list_prim_u_8_strict,
+    impl CstDecode<crate::tool_parser::ParsedReply>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::tool_parser::ParsedReply {
+            let self_ = self
+                .dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap();
+            assert_eq!(
+                self_.length(),
+                3,
+                "Expected 3 elements, got {}",
+                self_.length()
+            );
+            crate::tool_parser::ParsedReply {
+                tool_calls: self_.get(0).cst_decode(),
+                spans: self_.get(1).cst_decode(),
+                text: self_.get(2).cst_decode(),
+            }
+        }
     }
-    #[repr(C)]
-    #[derive(Clone, Copy)]
-    pub struct wire_cst_mcp_tool_result {
-        success: bool,
-        result: *mut wire_cst_list_prim_u_8_strict,
-        tool: *mut wire_cst_list_prim_u_8_strict,
+    impl CstDecode<crate::rag::RagAnswer>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::rag::RagAnswer {
+            let self_ = self
+                .dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap();
+            assert_eq!(
+                self_.length(),
+                3,
+                "Expected 3 elements, got {}",
+                self_.length()
+            );
+            crate::rag::RagAnswer {
+                answer: self_.get(0).cst_decode(),
+                sources: self_.get(1).cst_decode(),
+                skipped: self_.get(2).cst_decode(),
+            }
+        }
+    }
+    impl CstDecode<crate::rag::RagSource>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::rag::RagSource {
+            let self_ = self
+                .dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap();
+            assert_eq!(
+                self_.length(),
+                7,
+                "Expected 7 elements, got {}",
+                self_.length()
+            );
+            crate::rag::RagSource {
+                citation: self_.get(0).cst_decode(),
+                note_id: self_.get(1).cst_decode(),
+                chunk_offset: self_.get(2).cst_decode(),
+                chunk_index: self_.get(3).cst_decode(),
+                score: self_.get(4).cst_decode(),
+                text: self_.get(5).cst_decode(),
+                cited: self_.get(6).cst_decode(),
+            }
+        }
     }
-    #[repr(C)]
-    #[derive(Clone, Copy)]
-    pub struct wire_cst_node_position {
-        id: *mut wire_cst_list_prim_u_8_strict,
-        x: f32,
-        y: f32,
-        radius: f32,
-        color: u32,
-        node_type: *mut wire_cst_list_prim_u_8_strict,
+    impl CstDecode<crate::reasoning::ReasoningOptions>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::reasoning::ReasoningOptions {
+            let self_ = self
+                .dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap();
+            assert_eq!(
+                self_.length(),
+                4,
+                "Expected 4 elements, got {}",
+                self_.length()
+            );
+            crate::reasoning::ReasoningOptions {
+                enabled: self_.get(0).cst_decode(),
+                open_tag: self_.get(1).cst_decode(),
+                close_tag: self_.get(2).cst_decode(),
+                thinking_budget: self_.get(3).cst_decode(),
+            }
+        }
     }
-    #[repr(C)]
-    #[derive(Clone, Copy)]
-    pub struct wire_cst_record_string_string {
-        field0: *mut wire_cst_list_prim_u_8_strict,
-        field1: *mut wire_cst_list_prim_u_8_strict,
+    impl CstDecode<crate::quantization::RecallReport>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::quantization::RecallReport {
+            let self_ = self
+                .dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap();
+            assert_eq!(
+                self_.length(),
+                6,
+                "Expected 6 elements, got {}",
+                self_.length()
+            );
+            crate::quantization::RecallReport {
+                queries: self_.get(0).cst_decode(),
+                top_k: self_.get(1).cst_decode(),
+                recall: self_.get(2).cst_decode(),
+                rescored_recall: self_.get(3).cst_decode(),
+                f32_bytes: self_.get(4).cst_decode(),
+                quantized_bytes: self_.get(5).cst_decode(),
+            }
+        }
     }
-    #[repr(C)]
-    #[derive(Clone, Copy)]
-    pub struct wire_cst_semantic_edge {
-        source: *mut wire_cst_list_prim_u_8_strict,
-        target: *mut wire_cst_list_prim_u_8_strict,
-        similarity: f32,
-        is_ghost: bool,
+    impl CstDecode<(String, String)> for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> (String, String) {
+            let self_ = self
+                .dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap();
+            assert_eq!(
+                self_.length(),
+                2,
+                "Expected 2 elements, got {}",
+                self_.length()
+            );
+            (self_.get(0).cst_decode(), self_.get(1).cst_decode())
+        }
     }
-    #[repr(C)]
-    #[derive(Clone, Copy)]
-    pub struct wire_cst_semantic_edge_result {
-        edges: *mut wire_cst_list_semantic_edge,
-        count: usize,
+    impl CstDecode<crate::tool_parser::ReplySpan>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::tool_parser::ReplySpan {
+            let self_ = self
+                .dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap();
+            assert_eq!(
+                self_.length(),
+                4,
+                "Expected 4 elements, got {}",
+                self_.length()
+            );
+            crate::tool_parser::ReplySpan {
+                start: self_.get(0).cst_decode(),
+                end: self_.get(1).cst_decode(),
+                kind: self_.get(2).cst_decode(),
+                call_count: self_.get(3).cst_decode(),
+            }
+        }
     }
-    #[repr(C)]
-    #[derive(Clone, Copy)]
-    pub struct wire_cst_similarity_result {
-        id: *mut wire_cst_list_prim_u_8_strict,
-        score: f32,
-        text_preview: *mut wire_cst_list_prim_u_8_strict,
+    impl CstDecode<crate::inference::SamplerConfig>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::inference::SamplerConfig {
+            let self_ = self
+                .dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap();
+            assert_eq!(
+                self_.length(),
+                10,
+                "Expected 10 elements, got {}",
+                self_.length()
+            );
+            crate::inference::SamplerConfig {
+                temperature: self_.get(0).cst_decode(),
+                top_k: self_.get(1).cst_decode(),
+                top_p: self_.get(2).cst_decode(),
+                min_p: self_.get(3).cst_decode(),
+                repeat_penalty: self_.get(4).cst_decode(),
+                frequency_penalty: self_.get(5).cst_decode(),
+                presence_penalty: self_.get(6).cst_decode(),
+                penalty_last_n: self_.get(7).cst_decode(),
+                seed: self_.get(8).cst_decode(),
+                greedy: self_.get(9).cst_decode(),
+            }
+        }
     }
-    #[repr(C)]
-    #[derive(Clone, Copy)]
-    pub struct wire_cst_stream_graph_stats {
-        node_count: usize,
-        edge_count: usize,
-        visible_count: usize,
+    impl CstDecode<crate::filtered_search::SearchOptions>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::filtered_search::SearchOptions {
+            let self_ = self
+                .dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap();
+            assert_eq!(
+                self_.length(),
+                5,
+                "Expected 5 elements, got {}",
+                self_.length()
+            );
+            crate::filtered_search::SearchOptions {
+                top_k: self_.get(0).cst_decode(),
+                threshold: self_.get(1).cst_decode(),
+                filter: self_.get(2).cst_decode(),
+                mmr_lambda: self_.get(3).cst_decode(),
+                mmr_candidates: self_.get(4).cst_decode(),
+            }
+        }
     }
-}
-#[cfg(not(target_family = "wasm"))]
-pub use io::*;
-
-/// cbindgen:ignore
-#[cfg(target_family = "wasm")]
-mod web {
-    // This file is automatically generated, so please do not edit it.
-    // @generated by `flutter_rust_bridge`@ 2.12.0.
-
-    // Section: imports
-
-    use super::*;
-    use flutter_rust_bridge::for_generated::byteorder::{
-        NativeEndian, ReadBytesExt, WriteBytesExt,
-    };
-    use flutter_rust_bridge::for_generated::wasm_bindgen;
-    use flutter_rust_bridge::for_generated::wasm_bindgen::prelude::*;
-    use flutter_rust_bridge::for_generated::{transform_result_dco, Lifetimeable, Lockable};
-    use flutter_rust_bridge::{Handler, IntoIntoDart};
-
-    // Section: boilerplate
-
-    flutter_rust_bridge::frb_generated_boilerplate_web!();
-
-    // Section: dart2rust
-
-    impl CstDecode<flutter_rust_bridge::for_generated::anyhow::Error> for String {
+    impl CstDecode<crate::clustering::SemanticEdge>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
         // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> flutter_rust_bridge::for_generated::anyhow::Error {
-            unimplemented!()
+        fn cst_decode(self) -> crate::clustering::SemanticEdge {
+            let self_ = self
+                .dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap();
+            assert_eq!(
+                self_.length(),
+                4,
+                "Expected 4 elements, got {}",
+                self_.length()
+            );
+            crate::clustering::SemanticEdge {
+                source: self_.get(0).cst_decode(),
+                target: self_.get(1).cst_decode(),
+                similarity: self_.get(2).cst_decode(),
+                is_ghost: self_.get(3).cst_decode(),
+            }
         }
     }
-    impl CstDecode<String> for String {
+    impl CstDecode<crate::clustering::SemanticEdgeResult>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
         // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> String {
-            self
+        fn cst_decode(self) -> crate::clustering::SemanticEdgeResult {
+            let self_ = self
+                .dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
+                .unwrap();
+            assert_eq!(
+                self_.length(),
+                2,
+                "Expected 2 elements, got {}",
+                self_.length()
+            );
+            crate::clustering::SemanticEdgeResult {
+                edges: self_.get(0).cst_decode(),
+                count: self_.get(1).cst_decode(),
+            }
         }
     }
-    impl CstDecode<crate::clustering::ClusterAssignment>
+    impl CstDecode<crate::embeddings::SimilarityResult>
         for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
     {
         // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> crate::clustering::ClusterAssignment {
+        fn cst_decode(self) -> crate::embeddings::SimilarityResult {
             let self_ = self
                 .dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
                 .unwrap();
@@ -4807,40 +15696,39 @@ mod web {
                 "Expected 3 elements, got {}",
                 self_.length()
             );
-            crate::clustering::ClusterAssignment {
+            crate::embeddings::SimilarityResult {
                 id: self_.get(0).cst_decode(),
-                cluster_id: self_.get(1).cst_decode(),
-                color: self_.get(2).cst_decode(),
+                score: self_.get(1).cst_decode(),
+                text_preview: self_.get(2).cst_decode(),
             }
         }
     }
-    impl CstDecode<crate::clustering::ClusterInfo>
+    impl CstDecode<crate::api::StreamGraphStats>
         for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
     {
         // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> crate::clustering::ClusterInfo {
+        fn cst_decode(self) -> crate::api::StreamGraphStats {
             let self_ = self
                 .dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
                 .unwrap();
             assert_eq!(
                 self_.length(),
-                4,
-                "Expected 4 elements, got {}",
+                3,
+                "Expected 3 elements, got {}",
                 self_.length()
             );
-            crate::clustering::ClusterInfo {
-                id: self_.get(0).cst_decode(),
-                size: self_.get(1).cst_decode(),
-                color: self_.get(2).cst_decode(),
-                centroid: self_.get(3).cst_decode(),
+            crate::api::StreamGraphStats {
+                node_count: self_.get(0).cst_decode(),
+                edge_count: self_.get(1).cst_decode(),
+                visible_count: self_.get(2).cst_decode(),
             }
         }
     }
-    impl CstDecode<crate::clustering::ClusteringResult>
+    impl CstDecode<crate::summarize::Summary>
         for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
     {
         // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> crate::clustering::ClusteringResult {
+        fn cst_decode(self) -> crate::summarize::Summary {
             let self_ = self
                 .dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
                 .unwrap();
@@ -4850,37 +15738,40 @@ mod web {
                 "Expected 3 elements, got {}",
                 self_.length()
             );
-            crate::clustering::ClusteringResult {
-                assignments: self_.get(0).cst_decode(),
-                clusters: self_.get(1).cst_decode(),
-                k: self_.get(2).cst_decode(),
+            crate::summarize::Summary {
+                summary: self_.get(0).cst_decode(),
+                chunk_summaries: self_.get(1).cst_decode(),
+                merge_levels: self_.get(2).cst_decode(),
             }
         }
     }
-    impl CstDecode<crate::api::EmbeddingCluster>
+    impl CstDecode<crate::summarize::SummaryOptions>
         for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
     {
         // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> crate::api::EmbeddingCluster {
+        fn cst_decode(self) -> crate::summarize::SummaryOptions {
             let self_ = self
                 .dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
                 .unwrap();
             assert_eq!(
                 self_.length(),
-                1,
-                "Expected 1 elements, got {}",
+                4,
+                "Expected 4 elements, got {}",
                 self_.length()
             );
-            crate::api::EmbeddingCluster {
-                ids: self_.get(0).cst_decode(),
+            crate::summarize::SummaryOptions {
+                style: self_.get(0).cst_decode(),
+                max_words: self_.get(1).cst_decode(),
+                chunk_tokens: self_.get(2).cst_decode(),
+                model_id: self_.get(3).cst_decode(),
             }
         }
     }
-    impl CstDecode<crate::embeddings::EmbeddingEntry>
+    impl CstDecode<crate::inference::TokenCandidate>
         for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
     {
         // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> crate::embeddings::EmbeddingEntry {
+        fn cst_decode(self) -> crate::inference::TokenCandidate {
             let self_ = self
                 .dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
                 .unwrap();
@@ -4890,18 +15781,18 @@ mod web {
                 "Expected 3 elements, got {}",
                 self_.length()
             );
-            crate::embeddings::EmbeddingEntry {
-                id: self_.get(0).cst_decode(),
-                vector: self_.get(1).cst_decode(),
-                text_preview: self_.get(2).cst_decode(),
+            crate::inference::TokenCandidate {
+                token: self_.get(0).cst_decode(),
+                text: self_.get(1).cst_decode(),
+                logprob: self_.get(2).cst_decode(),
             }
         }
     }
-    impl CstDecode<crate::graph::GraphEdge>
+    impl CstDecode<crate::inference::TokenLogprob>
         for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
     {
         // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> crate::graph::GraphEdge {
+        fn cst_decode(self) -> crate::inference::TokenLogprob {
             let self_ = self
                 .dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
                 .unwrap();
@@ -4911,714 +15802,947 @@ mod web {
                 "Expected 4 elements, got {}",
                 self_.length()
             );
-            crate::graph::GraphEdge {
-                source: self_.get(0).cst_decode(),
-                target: self_.get(1).cst_decode(),
-                weight: self_.get(2).cst_decode(),
-                edge_type: self_.get(3).cst_decode(),
+            crate::inference::TokenLogprob {
+                token: self_.get(0).cst_decode(),
+                text: self_.get(1).cst_decode(),
+                logprob: self_.get(2).cst_decode(),
+                top_alternatives: self_.get(3).cst_decode(),
             }
         }
     }
-    impl CstDecode<crate::graph::GraphNode>
+    impl CstDecode<crate::tokenizer::TokenizerInfo>
         for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
     {
         // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> crate::graph::GraphNode {
+        fn cst_decode(self) -> crate::tokenizer::TokenizerInfo {
             let self_ = self
                 .dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
                 .unwrap();
             assert_eq!(
                 self_.length(),
-                7,
-                "Expected 7 elements, got {}",
+                6,
+                "Expected 6 elements, got {}",
                 self_.length()
             );
-            crate::graph::GraphNode {
-                id: self_.get(0).cst_decode(),
-                label: self_.get(1).cst_decode(),
-                node_type: self_.get(2).cst_decode(),
-                x: self_.get(3).cst_decode(),
-                y: self_.get(4).cst_decode(),
-                color: self_.get(5).cst_decode(),
-                metadata: self_.get(6).cst_decode(),
+            crate::tokenizer::TokenizerInfo {
+                vocab_size: self_.get(0).cst_decode(),
+                n_ctx_train: self_.get(1).cst_decode(),
+                n_ctx: self_.get(2).cst_decode(),
+                bos_token: self_.get(3).cst_decode(),
+                eos_token: self_.get(4).cst_decode(),
+                tokenizer_model: self_.get(5).cst_decode(),
             }
         }
     }
-    impl CstDecode<crate::graph::GraphState>
+    impl CstDecode<crate::summarize::TranscriptSegment>
         for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
     {
         // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> crate::graph::GraphState {
+        fn cst_decode(self) -> crate::summarize::TranscriptSegment {
             let self_ = self
                 .dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
                 .unwrap();
             assert_eq!(
                 self_.length(),
-                2,
-                "Expected 2 elements, got {}",
+                3,
+                "Expected 3 elements, got {}",
                 self_.length()
             );
-            crate::graph::GraphState {
-                nodes: self_.get(0).cst_decode(),
-                edges: self_.get(1).cst_decode(),
+            crate::summarize::TranscriptSegment {
+                text: self_.get(0).cst_decode(),
+                start_time: self_.get(1).cst_decode(),
+                end_time: self_.get(2).cst_decode(),
             }
         }
     }
-    impl CstDecode<crate::api::KnowledgeGraphAnalysis>
+    impl CstDecode<crate::vector_store::VectorStoreStats>
         for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
     {
         // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> crate::api::KnowledgeGraphAnalysis {
+        fn cst_decode(self) -> crate::vector_store::VectorStoreStats {
             let self_ = self
                 .dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
                 .unwrap();
             assert_eq!(
                 self_.length(),
-                2,
-                "Expected 2 elements, got {}",
+                4,
+                "Expected 4 elements, got {}",
                 self_.length()
             );
-            crate::api::KnowledgeGraphAnalysis {
-                clustering: self_.get(0).cst_decode(),
-                semantic_edges: self_.get(1).cst_decode(),
+            crate::vector_store::VectorStoreStats {
+                entries: self_.get(0).cst_decode(),
+                dead_rows: self_.get(1).cst_decode(),
+                dimension: self_.get(2).cst_decode(),
+                file_bytes: self_.get(3).cst_decode(),
             }
         }
     }
-    impl CstDecode<Vec<String>> for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue {
+    impl CstDecode<flutter_rust_bridge::for_generated::anyhow::Error>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
         // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> Vec<String> {
-            self.dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
-                .unwrap()
-                .iter()
-                .map(CstDecode::cst_decode)
-                .collect()
+        fn cst_decode(self) -> flutter_rust_bridge::for_generated::anyhow::Error {
+            unimplemented!()
         }
     }
-    impl CstDecode<Vec<crate::clustering::ClusterAssignment>>
+    impl
+        CstDecode<StreamSink<crate::agent::AgentStep, flutter_rust_bridge::for_generated::DcoCodec>>
         for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
     {
         // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> Vec<crate::clustering::ClusterAssignment> {
-            self.dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
-                .unwrap()
-                .iter()
-                .map(CstDecode::cst_decode)
-                .collect()
+        fn cst_decode(
+            self,
+        ) -> StreamSink<crate::agent::AgentStep, flutter_rust_bridge::for_generated::DcoCodec>
+        {
+            StreamSink::deserialize(self.as_string().expect("should be a string"))
         }
     }
-    impl CstDecode<Vec<crate::clustering::ClusterInfo>>
-        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    impl
+        CstDecode<
+            StreamSink<
+                crate::inference::GenerationChunk,
+                flutter_rust_bridge::for_generated::DcoCodec,
+            >,
+        > for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
     {
         // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> Vec<crate::clustering::ClusterInfo> {
-            self.dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
-                .unwrap()
-                .iter()
-                .map(CstDecode::cst_decode)
-                .collect()
+        fn cst_decode(
+            self,
+        ) -> StreamSink<
+            crate::inference::GenerationChunk,
+            flutter_rust_bridge::for_generated::DcoCodec,
+        > {
+            StreamSink::deserialize(self.as_string().expect("should be a string"))
         }
     }
-    impl CstDecode<Vec<crate::api::EmbeddingCluster>>
-        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
-    {
+    impl CstDecode<String> for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue {
         // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> Vec<crate::api::EmbeddingCluster> {
-            self.dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
-                .unwrap()
-                .iter()
-                .map(CstDecode::cst_decode)
-                .collect()
+        fn cst_decode(self) -> String {
+            self.as_string().expect("non-UTF-8 string, or not a string")
         }
     }
-    impl CstDecode<Vec<crate::embeddings::EmbeddingEntry>>
+    impl CstDecode<crate::agent::AgentStopReason>
         for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
     {
         // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> Vec<crate::embeddings::EmbeddingEntry> {
-            self.dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
-                .unwrap()
-                .iter()
-                .map(CstDecode::cst_decode)
-                .collect()
+        fn cst_decode(self) -> crate::agent::AgentStopReason {
+            (self.unchecked_into_f64() as i32).cst_decode()
         }
     }
-    impl CstDecode<Vec<crate::graph::GraphEdge>>
-        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
-    {
+    impl CstDecode<bool> for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue {
         // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> Vec<crate::graph::GraphEdge> {
-            self.dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
-                .unwrap()
-                .iter()
-                .map(CstDecode::cst_decode)
-                .collect()
+        fn cst_decode(self) -> bool {
+            self.is_truthy()
         }
     }
-    impl CstDecode<Vec<crate::graph::GraphNode>>
+    impl CstDecode<crate::context_window::ContextStrategy>
         for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
     {
         // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> Vec<crate::graph::GraphNode> {
-            self.dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
-                .unwrap()
-                .iter()
-                .map(CstDecode::cst_decode)
-                .collect()
+        fn cst_decode(self) -> crate::context_window::ContextStrategy {
+            (self.unchecked_into_f64() as i32).cst_decode()
         }
     }
-    impl CstDecode<Vec<crate::mcp::MCPParameter>>
+    impl CstDecode<crate::embedder::EmbeddingKind>
         for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
     {
         // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> Vec<crate::mcp::MCPParameter> {
-            self.dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
-                .unwrap()
-                .iter()
-                .map(CstDecode::cst_decode)
-                .collect()
+        fn cst_decode(self) -> crate::embedder::EmbeddingKind {
+            (self.unchecked_into_f64() as i32).cst_decode()
         }
     }
-    impl CstDecode<Vec<crate::mcp::MCPTool>>
-        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
-    {
+    impl CstDecode<f32> for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue {
         // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> Vec<crate::mcp::MCPTool> {
-            self.dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
-                .unwrap()
-                .iter()
-                .map(CstDecode::cst_decode)
-                .collect()
+        fn cst_decode(self) -> f32 {
+            self.unchecked_into_f64() as _
         }
     }
-    impl CstDecode<Vec<crate::streaming::NodePosition>>
+    impl CstDecode<crate::hybrid_search::FusionMode>
         for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
     {
         // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> Vec<crate::streaming::NodePosition> {
-            self.dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
-                .unwrap()
-                .iter()
-                .map(CstDecode::cst_decode)
-                .collect()
+        fn cst_decode(self) -> crate::hybrid_search::FusionMode {
+            (self.unchecked_into_f64() as i32).cst_decode()
         }
     }
-    impl CstDecode<Vec<f32>> for Box<[f32]> {
+    impl CstDecode<i32> for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> i32 {
+            self.unchecked_into_f64() as _
+        }
+    }
+    impl CstDecode<i64> for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> i64 {
+            ::std::convert::TryInto::<i64>::try_into(self).unwrap() as _
+        }
+    }
+    impl CstDecode<Vec<f32>> for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue {
         // Codec=Cst (C-struct based), see doc to use other codecs
         fn cst_decode(self) -> Vec<f32> {
-            self.into_vec()
+            self.unchecked_into::<flutter_rust_bridge::for_generated::js_sys::Float32Array>()
+                .to_vec()
+                .into()
         }
     }
-    impl CstDecode<Vec<u8>> for Box<[u8]> {
+    impl CstDecode<Vec<i32>> for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue {
         // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> Vec<u8> {
-            self.into_vec()
+        fn cst_decode(self) -> Vec<i32> {
+            self.unchecked_into::<flutter_rust_bridge::for_generated::js_sys::Int32Array>()
+                .to_vec()
+                .into()
         }
     }
-    impl CstDecode<Vec<(String, String)>>
-        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
-    {
+    impl CstDecode<Vec<u32>> for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue {
         // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> Vec<(String, String)> {
-            self.dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
-                .unwrap()
-                .iter()
-                .map(CstDecode::cst_decode)
-                .collect()
+        fn cst_decode(self) -> Vec<u32> {
+            self.unchecked_into::<flutter_rust_bridge::for_generated::js_sys::Uint32Array>()
+                .to_vec()
+                .into()
         }
     }
-    impl CstDecode<Vec<crate::clustering::SemanticEdge>>
-        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
-    {
+    impl CstDecode<Vec<u8>> for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue {
         // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> Vec<crate::clustering::SemanticEdge> {
-            self.dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
-                .unwrap()
-                .iter()
-                .map(CstDecode::cst_decode)
-                .collect()
+        fn cst_decode(self) -> Vec<u8> {
+            self.unchecked_into::<flutter_rust_bridge::for_generated::js_sys::Uint8Array>()
+                .to_vec()
+                .into()
         }
     }
-    impl CstDecode<Vec<crate::embeddings::SimilarityResult>>
+    impl CstDecode<crate::mcp::MCPParamType>
         for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
     {
         // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> Vec<crate::embeddings::SimilarityResult> {
-            self.dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
-                .unwrap()
-                .iter()
-                .map(CstDecode::cst_decode)
-                .collect()
+        fn cst_decode(self) -> crate::mcp::MCPParamType {
+            (self.unchecked_into_f64() as i32).cst_decode()
         }
     }
-    impl CstDecode<crate::mcp::MCPParameter>
+    impl CstDecode<crate::mcp::MCPTool> for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::mcp::MCPTool {
+            (self.unchecked_into_f64() as i32).cst_decode()
+        }
+    }
+    impl CstDecode<crate::embedder::PoolingMode>
         for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
     {
         // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> crate::mcp::MCPParameter {
-            let self_ = self
-                .dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
-                .unwrap();
-            assert_eq!(
-                self_.length(),
-                4,
-                "Expected 4 elements, got {}",
-                self_.length()
-            );
-            crate::mcp::MCPParameter {
-                name: self_.get(0).cst_decode(),
-                description: self_.get(1).cst_decode(),
-                param_type: self_.get(2).cst_decode(),
-                required: self_.get(3).cst_decode(),
-            }
+        fn cst_decode(self) -> crate::embedder::PoolingMode {
+            (self.unchecked_into_f64() as i32).cst_decode()
         }
     }
-    impl CstDecode<crate::mcp::MCPToolCall>
+    impl CstDecode<crate::quantization::QuantizationKind>
         for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
     {
         // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> crate::mcp::MCPToolCall {
-            let self_ = self
-                .dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
-                .unwrap();
-            assert_eq!(
-                self_.length(),
-                3,
-                "Expected 3 elements, got {}",
-                self_.length()
-            );
-            crate::mcp::MCPToolCall {
-                tool: self_.get(0).cst_decode(),
-                parameters_json: self_.get(1).cst_decode(),
-                description: self_.get(2).cst_decode(),
-            }
+        fn cst_decode(self) -> crate::quantization::QuantizationKind {
+            (self.unchecked_into_f64() as i32).cst_decode()
         }
     }
-    impl CstDecode<crate::mcp::MCPToolResult>
+    impl CstDecode<crate::tool_parser::ReplySpanKind>
         for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
     {
         // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> crate::mcp::MCPToolResult {
-            let self_ = self
-                .dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
-                .unwrap();
-            assert_eq!(
-                self_.length(),
-                3,
-                "Expected 3 elements, got {}",
-                self_.length()
-            );
-            crate::mcp::MCPToolResult {
-                success: self_.get(0).cst_decode(),
-                result: self_.get(1).cst_decode(),
-                tool: self_.get(2).cst_decode(),
-            }
+        fn cst_decode(self) -> crate::tool_parser::ReplySpanKind {
+            (self.unchecked_into_f64() as i32).cst_decode()
         }
     }
-    impl CstDecode<crate::streaming::NodePosition>
+    impl CstDecode<crate::inference::StopReason>
         for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
     {
-        // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> crate::streaming::NodePosition {
-            let self_ = self
-                .dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
-                .unwrap();
-            assert_eq!(
-                self_.length(),
-                6,
-                "Expected 6 elements, got {}",
-                self_.length()
-            );
-            crate::streaming::NodePosition {
-                id: self_.get(0).cst_decode(),
-                x: self_.get(1).cst_decode(),
-                y: self_.get(2).cst_decode(),
-                radius: self_.get(3).cst_decode(),
-                color: self_.get(4).cst_decode(),
-                node_type: self_.get(5).cst_decode(),
-            }
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> crate::inference::StopReason {
+            (self.unchecked_into_f64() as i32).cst_decode()
         }
     }
-    impl CstDecode<Option<String>> for Option<String> {
+    impl CstDecode<crate::summarize::SummaryStyle>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
         // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> Option<String> {
-            self.map(CstDecode::cst_decode)
+        fn cst_decode(self) -> crate::summarize::SummaryStyle {
+            (self.unchecked_into_f64() as i32).cst_decode()
         }
     }
-    impl CstDecode<Option<Vec<f32>>> for Option<Box<[f32]>> {
+    impl CstDecode<crate::mcp::TaskCategory>
+        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
+    {
         // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> Option<Vec<f32>> {
-            self.map(CstDecode::cst_decode)
+        fn cst_decode(self) -> crate::mcp::TaskCategory {
+            (self.unchecked_into_f64() as i32).cst_decode()
         }
     }
-    impl CstDecode<(String, String)> for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue {
+    impl CstDecode<u32> for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue {
         // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> (String, String) {
-            let self_ = self
-                .dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
-                .unwrap();
-            assert_eq!(
-                self_.length(),
-                2,
-                "Expected 2 elements, got {}",
-                self_.length()
-            );
-            (self_.get(0).cst_decode(), self_.get(1).cst_decode())
+        fn cst_decode(self) -> u32 {
+            self.unchecked_into_f64() as _
         }
     }
-    impl CstDecode<crate::clustering::SemanticEdge>
-        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
-    {
+    impl CstDecode<u64> for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue {
         // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> crate::clustering::SemanticEdge {
-            let self_ = self
-                .dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
-                .unwrap();
-            assert_eq!(
-                self_.length(),
-                4,
-                "Expected 4 elements, got {}",
-                self_.length()
-            );
-            crate::clustering::SemanticEdge {
-                source: self_.get(0).cst_decode(),
-                target: self_.get(1).cst_decode(),
-                similarity: self_.get(2).cst_decode(),
-                is_ghost: self_.get(3).cst_decode(),
-            }
+        fn cst_decode(self) -> u64 {
+            ::std::convert::TryInto::<u64>::try_into(self).unwrap() as _
         }
     }
-    impl CstDecode<crate::clustering::SemanticEdgeResult>
-        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
-    {
+    impl CstDecode<u8> for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue {
         // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> crate::clustering::SemanticEdgeResult {
-            let self_ = self
-                .dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
-                .unwrap();
-            assert_eq!(
-                self_.length(),
-                2,
-                "Expected 2 elements, got {}",
-                self_.length()
-            );
-            crate::clustering::SemanticEdgeResult {
-                edges: self_.get(0).cst_decode(),
-                count: self_.get(1).cst_decode(),
-            }
+        fn cst_decode(self) -> u8 {
+            self.unchecked_into_f64() as _
+        }
+    }
+    impl CstDecode<usize> for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue {
+        // Codec=Cst (C-struct based), see doc to use other codecs
+        fn cst_decode(self) -> usize {
+            ::std::convert::TryInto::<u64>::try_into(self).unwrap() as _
         }
     }
-    impl CstDecode<crate::embeddings::SimilarityResult>
-        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
-    {
-        // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> crate::embeddings::SimilarityResult {
-            let self_ = self
-                .dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
-                .unwrap();
-            assert_eq!(
-                self_.length(),
-                3,
-                "Expected 3 elements, got {}",
-                self_.length()
-            );
-            crate::embeddings::SimilarityResult {
-                id: self_.get(0).cst_decode(),
-                score: self_.get(1).cst_decode(),
-                text_preview: self_.get(2).cst_decode(),
-            }
-        }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__add_graph_edge(
+        port_: flutter_rust_bridge::for_generated::MessagePort,
+        source: String,
+        target: String,
+        weight: f32,
+        edge_type: String,
+    ) {
+        wire__crate__api__add_graph_edge_impl(port_, source, target, weight, edge_type)
+    }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__add_graph_edges(
+        port_: flutter_rust_bridge::for_generated::MessagePort,
+        edges: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+    ) {
+        wire__crate__api__add_graph_edges_impl(port_, edges)
+    }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__add_graph_node(
+        port_: flutter_rust_bridge::for_generated::MessagePort,
+        id: String,
+        label: String,
+        node_type: String,
+        x: f32,
+        y: f32,
+        color: Option<String>,
+        metadata: Option<String>,
+    ) {
+        wire__crate__api__add_graph_node_impl(port_, id, label, node_type, x, y, color, metadata)
+    }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__add_graph_nodes(
+        port_: flutter_rust_bridge::for_generated::MessagePort,
+        nodes: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+    ) {
+        wire__crate__api__add_graph_nodes_impl(port_, nodes)
+    }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__add_stream_edge(
+        port_: flutter_rust_bridge::for_generated::MessagePort,
+        from_id: String,
+        to_id: String,
+        strength: f32,
+    ) {
+        wire__crate__api__add_stream_edge_impl(port_, from_id, to_id, strength)
+    }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__add_stream_node(
+        port_: flutter_rust_bridge::for_generated::MessagePort,
+        id: String,
+        x: f32,
+        y: f32,
+        radius: f32,
+        color: u32,
+    ) {
+        wire__crate__api__add_stream_node_impl(port_, id, x, y, radius, color)
+    }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__analyze_knowledge_graph(
+        port_: flutter_rust_bridge::for_generated::MessagePort,
+        entries: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        k: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        similarity_threshold: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        existing_links: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+    ) {
+        wire__crate__api__analyze_knowledge_graph_impl(
+            port_,
+            entries,
+            k,
+            similarity_threshold,
+            existing_links,
+        )
+    }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__analyze_knowledge_graph_in_store(
+        port_: flutter_rust_bridge::for_generated::MessagePort,
+        store_id: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        k: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        similarity_threshold: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        existing_links: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+    ) {
+        wire__crate__api__analyze_knowledge_graph_in_store_impl(
+            port_,
+            store_id,
+            k,
+            similarity_threshold,
+            existing_links,
+        )
+    }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__batch_embed(
+        port_: flutter_rust_bridge::for_generated::MessagePort,
+        texts: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+    ) {
+        wire__crate__api__batch_embed_impl(port_, texts)
+    }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__batch_embed_with_model(
+        port_: flutter_rust_bridge::for_generated::MessagePort,
+        texts: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        model_id: Option<String>,
+    ) {
+        wire__crate__api__batch_embed_with_model_impl(port_, texts, model_id)
+    }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__cancel_generation(
+        request_id: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__api__cancel_generation_impl(request_id)
+    }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__chat_completion(
+        port_: flutter_rust_bridge::for_generated::MessagePort,
+        messages: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        max_tokens: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+    ) {
+        wire__crate__api__chat_completion_impl(port_, messages, max_tokens)
+    }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__chat_completion_stream(
+        port_: flutter_rust_bridge::for_generated::MessagePort,
+        messages: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        max_tokens: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        stop_sequences: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        model_id: Option<String>,
+        sink: String,
+    ) {
+        wire__crate__api__chat_completion_stream_impl(
+            port_,
+            messages,
+            max_tokens,
+            stop_sequences,
+            model_id,
+            sink,
+        )
+    }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__chat_completion_with_model(
+        port_: flutter_rust_bridge::for_generated::MessagePort,
+        messages: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        max_tokens: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        model_id: Option<String>,
+    ) {
+        wire__crate__api__chat_completion_with_model_impl(port_, messages, max_tokens, model_id)
+    }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__chat_completion_with_options(
+        port_: flutter_rust_bridge::for_generated::MessagePort,
+        messages: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        options: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+    ) {
+        wire__crate__api__chat_completion_with_options_impl(port_, messages, options)
+    }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__chat_session_completion(
+        port_: flutter_rust_bridge::for_generated::MessagePort,
+        session_id: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        messages: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        options: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+    ) {
+        wire__crate__api__chat_session_completion_impl(port_, session_id, messages, options)
+    }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__chat_session_stream(
+        port_: flutter_rust_bridge::for_generated::MessagePort,
+        session_id: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        messages: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        max_tokens: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        stop_sequences: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        sink: String,
+    ) {
+        wire__crate__api__chat_session_stream_impl(
+            port_,
+            session_id,
+            messages,
+            max_tokens,
+            stop_sequences,
+            sink,
+        )
+    }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__chat_session_token_count(
+        session_id: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__api__chat_session_token_count_impl(session_id)
+    }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__chunk_markdown(
+        port_: flutter_rust_bridge::for_generated::MessagePort,
+        text: String,
+        max_tokens: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        overlap_tokens: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        model_id: Option<String>,
+    ) {
+        wire__crate__api__chunk_markdown_impl(port_, text, max_tokens, overlap_tokens, model_id)
+    }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__clear_graph(
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__api__clear_graph_impl()
+    }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__clear_stream_graph(
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__api__clear_stream_graph_impl()
+    }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__close_chat_session(
+        session_id: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__api__close_chat_session_impl(session_id)
     }
-    impl CstDecode<crate::api::StreamGraphStats>
-        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
-    {
-        // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> crate::api::StreamGraphStats {
-            let self_ = self
-                .dyn_into::<flutter_rust_bridge::for_generated::js_sys::Array>()
-                .unwrap();
-            assert_eq!(
-                self_.length(),
-                3,
-                "Expected 3 elements, got {}",
-                self_.length()
-            );
-            crate::api::StreamGraphStats {
-                node_count: self_.get(0).cst_decode(),
-                edge_count: self_.get(1).cst_decode(),
-                visible_count: self_.get(2).cst_decode(),
-            }
-        }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__close_embedding_cache(
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__api__close_embedding_cache_impl()
     }
-    impl CstDecode<flutter_rust_bridge::for_generated::anyhow::Error>
-        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
-    {
-        // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> flutter_rust_bridge::for_generated::anyhow::Error {
-            unimplemented!()
-        }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__close_hnsw_index(
+        index_id: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__api__close_hnsw_index_impl(index_id)
     }
-    impl CstDecode<String> for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue {
-        // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> String {
-            self.as_string().expect("non-UTF-8 string, or not a string")
-        }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__close_quantized_index(
+        index_id: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__api__close_quantized_index_impl(index_id)
     }
-    impl CstDecode<bool> for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue {
-        // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> bool {
-            self.is_truthy()
-        }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__close_vector_store(
+        store_id: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__api__close_vector_store_impl(store_id)
     }
-    impl CstDecode<f32> for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue {
-        // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> f32 {
-            self.unchecked_into_f64() as _
-        }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__cluster_embeddings(
+        port_: flutter_rust_bridge::for_generated::MessagePort,
+        entries: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        threshold: f32,
+    ) {
+        wire__crate__api__cluster_embeddings_impl(port_, entries, threshold)
     }
-    impl CstDecode<i32> for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue {
-        // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> i32 {
-            self.unchecked_into_f64() as _
-        }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__cluster_notes(
+        port_: flutter_rust_bridge::for_generated::MessagePort,
+        entries: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        k: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        max_iterations: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+    ) {
+        wire__crate__api__cluster_notes_impl(port_, entries, k, max_iterations)
     }
-    impl CstDecode<Vec<f32>> for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue {
-        // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> Vec<f32> {
-            self.unchecked_into::<flutter_rust_bridge::for_generated::js_sys::Float32Array>()
-                .to_vec()
-                .into()
-        }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__cluster_notes_in_store(
+        port_: flutter_rust_bridge::for_generated::MessagePort,
+        store_id: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        k: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        max_iterations: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+    ) {
+        wire__crate__api__cluster_notes_in_store_impl(port_, store_id, k, max_iterations)
     }
-    impl CstDecode<Vec<u8>> for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue {
-        // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> Vec<u8> {
-            self.unchecked_into::<flutter_rust_bridge::for_generated::js_sys::Uint8Array>()
-                .to_vec()
-                .into()
-        }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__compact_embedding_cache(
+        port_: flutter_rust_bridge::for_generated::MessagePort,
+    ) {
+        wire__crate__api__compact_embedding_cache_impl(port_)
     }
-    impl CstDecode<crate::mcp::MCPParamType>
-        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
-    {
-        // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> crate::mcp::MCPParamType {
-            (self.unchecked_into_f64() as i32).cst_decode()
-        }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__compact_vector_store(
+        port_: flutter_rust_bridge::for_generated::MessagePort,
+        store_id: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+    ) {
+        wire__crate__api__compact_vector_store_impl(port_, store_id)
     }
-    impl CstDecode<crate::mcp::MCPTool> for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue {
-        // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> crate::mcp::MCPTool {
-            (self.unchecked_into_f64() as i32).cst_decode()
-        }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__compute_graph_layout(
+        port_: flutter_rust_bridge::for_generated::MessagePort,
+        iterations: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+    ) {
+        wire__crate__api__compute_graph_layout_impl(port_, iterations)
     }
-    impl CstDecode<crate::mcp::TaskCategory>
-        for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue
-    {
-        // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> crate::mcp::TaskCategory {
-            (self.unchecked_into_f64() as i32).cst_decode()
-        }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__connect_note_to_topics(
+        port_: flutter_rust_bridge::for_generated::MessagePort,
+        note_id: String,
+        topic_ids: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+    ) {
+        wire__crate__api__connect_note_to_topics_impl(port_, note_id, topic_ids)
     }
-    impl CstDecode<u32> for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue {
-        // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> u32 {
-            self.unchecked_into_f64() as _
-        }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__cosine_similarity(
+        a: Box<[f32]>,
+        b: Box<[f32]>,
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__api__cosine_similarity_impl(a, b)
     }
-    impl CstDecode<u8> for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue {
-        // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> u8 {
-            self.unchecked_into_f64() as _
-        }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__count_chat_prompt_tokens(
+        port_: flutter_rust_bridge::for_generated::MessagePort,
+        messages: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        model_id: Option<String>,
+    ) {
+        wire__crate__api__count_chat_prompt_tokens_impl(port_, messages, model_id)
     }
-    impl CstDecode<usize> for flutter_rust_bridge::for_generated::wasm_bindgen::JsValue {
-        // Codec=Cst (C-struct based), see doc to use other codecs
-        fn cst_decode(self) -> usize {
-            ::std::convert::TryInto::<u64>::try_into(self).unwrap() as _
-        }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__count_chat_tokens(
+        port_: flutter_rust_bridge::for_generated::MessagePort,
+        messages: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        model_id: Option<String>,
+    ) {
+        wire__crate__api__count_chat_tokens_impl(port_, messages, model_id)
     }
 
     #[wasm_bindgen]
-    pub fn wire__crate__api__add_graph_edge(
+    pub fn wire__crate__api__count_tokens(
         port_: flutter_rust_bridge::for_generated::MessagePort,
-        source: String,
-        target: String,
-        weight: f32,
-        edge_type: String,
+        text: String,
+        model_id: Option<String>,
     ) {
-        wire__crate__api__add_graph_edge_impl(port_, source, target, weight, edge_type)
+        wire__crate__api__count_tokens_impl(port_, text, model_id)
     }
 
     #[wasm_bindgen]
-    pub fn wire__crate__api__add_graph_edges(
+    pub fn wire__crate__api__create_chat_session(
         port_: flutter_rust_bridge::for_generated::MessagePort,
-        edges: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        n_ctx: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        model_id: Option<String>,
     ) {
-        wire__crate__api__add_graph_edges_impl(port_, edges)
+        wire__crate__api__create_chat_session_impl(port_, n_ctx, model_id)
+    }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__create_hnsw_index(
+        config: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__api__create_hnsw_index_impl(config)
+    }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__create_hnsw_index_from_store(
+        port_: flutter_rust_bridge::for_generated::MessagePort,
+        store_id: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        config: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+    ) {
+        wire__crate__api__create_hnsw_index_from_store_impl(port_, store_id, config)
+    }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__create_quantized_index(
+        port_: flutter_rust_bridge::for_generated::MessagePort,
+        entries: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        kind: i32,
+    ) {
+        wire__crate__api__create_quantized_index_impl(port_, entries, kind)
+    }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__create_quantized_index_from_store(
+        port_: flutter_rust_bridge::for_generated::MessagePort,
+        store_id: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        kind: i32,
+    ) {
+        wire__crate__api__create_quantized_index_from_store_impl(port_, store_id, kind)
+    }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__default_context_policy(
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__api__default_context_policy_impl()
+    }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__default_dedup_options(
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__api__default_dedup_options_impl()
+    }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__default_embedding_config(
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__api__default_embedding_config_impl()
+    }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__default_hnsw_config(
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__api__default_hnsw_config_impl()
+    }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__default_hybrid_options(
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__api__default_hybrid_options_impl()
+    }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__default_reasoning_options(
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__api__default_reasoning_options_impl()
+    }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__default_sampler_config(
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__api__default_sampler_config_impl()
+    }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__default_search_options(
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__api__default_search_options_impl()
     }
 
     #[wasm_bindgen]
-    pub fn wire__crate__api__add_graph_node(
-        port_: flutter_rust_bridge::for_generated::MessagePort,
-        id: String,
-        label: String,
-        node_type: String,
-        x: f32,
-        y: f32,
-        color: Option<String>,
-        metadata: Option<String>,
-    ) {
-        wire__crate__api__add_graph_node_impl(port_, id, label, node_type, x, y, color, metadata)
+    pub fn wire__crate__api__default_summary_options(
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__api__default_summary_options_impl()
     }
 
     #[wasm_bindgen]
-    pub fn wire__crate__api__add_graph_nodes(
+    pub fn wire__crate__api__detokenize_tokens(
         port_: flutter_rust_bridge::for_generated::MessagePort,
-        nodes: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        tokens: Box<[i32]>,
+        render_special: bool,
+        model_id: Option<String>,
     ) {
-        wire__crate__api__add_graph_nodes_impl(port_, nodes)
+        wire__crate__api__detokenize_tokens_impl(port_, tokens, render_special, model_id)
     }
 
     #[wasm_bindgen]
-    pub fn wire__crate__api__add_stream_edge(
+    pub fn wire__crate__api__discover_semantic_edges(
         port_: flutter_rust_bridge::for_generated::MessagePort,
-        from_id: String,
-        to_id: String,
-        strength: f32,
+        entries: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        threshold: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        existing_links: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
     ) {
-        wire__crate__api__add_stream_edge_impl(port_, from_id, to_id, strength)
+        wire__crate__api__discover_semantic_edges_impl(port_, entries, threshold, existing_links)
     }
 
     #[wasm_bindgen]
-    pub fn wire__crate__api__add_stream_node(
+    pub fn wire__crate__api__discover_semantic_edges_in_index(
         port_: flutter_rust_bridge::for_generated::MessagePort,
-        id: String,
-        x: f32,
-        y: f32,
-        radius: f32,
-        color: u32,
+        index_id: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        threshold: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        existing_links: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        max_neighbors: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
     ) {
-        wire__crate__api__add_stream_node_impl(port_, id, x, y, radius, color)
+        wire__crate__api__discover_semantic_edges_in_index_impl(
+            port_,
+            index_id,
+            threshold,
+            existing_links,
+            max_neighbors,
+        )
     }
 
     #[wasm_bindgen]
-    pub fn wire__crate__api__analyze_knowledge_graph(
+    pub fn wire__crate__api__discover_semantic_edges_in_store(
         port_: flutter_rust_bridge::for_generated::MessagePort,
-        entries: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
-        k: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
-        similarity_threshold: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        store_id: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        threshold: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
         existing_links: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
     ) {
-        wire__crate__api__analyze_knowledge_graph_impl(
+        wire__crate__api__discover_semantic_edges_in_store_impl(
             port_,
-            entries,
-            k,
-            similarity_threshold,
+            store_id,
+            threshold,
             existing_links,
         )
     }
 
     #[wasm_bindgen]
-    pub fn wire__crate__api__batch_embed(
+    pub fn wire__crate__api__embed_markdown_note(
         port_: flutter_rust_bridge::for_generated::MessagePort,
-        texts: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        note_id: String,
+        text: String,
+        max_tokens: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        overlap_tokens: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        metadata: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
     ) {
-        wire__crate__api__batch_embed_impl(port_, texts)
+        wire__crate__api__embed_markdown_note_impl(
+            port_,
+            note_id,
+            text,
+            max_tokens,
+            overlap_tokens,
+            metadata,
+        )
     }
 
     #[wasm_bindgen]
-    pub fn wire__crate__api__chat_completion(
+    pub fn wire__crate__api__embed_texts(
         port_: flutter_rust_bridge::for_generated::MessagePort,
-        messages: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
-        max_tokens: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        texts: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        kind: i32,
     ) {
-        wire__crate__api__chat_completion_impl(port_, messages, max_tokens)
+        wire__crate__api__embed_texts_impl(port_, texts, kind)
     }
 
     #[wasm_bindgen]
-    pub fn wire__crate__api__clear_graph(
-    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
-        wire__crate__api__clear_graph_impl()
+    pub fn wire__crate__api__extract_topics(
+        port_: flutter_rust_bridge::for_generated::MessagePort,
+        text: String,
+        num_topics: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+    ) {
+        wire__crate__api__extract_topics_impl(port_, text, num_topics)
     }
 
     #[wasm_bindgen]
-    pub fn wire__crate__api__clear_stream_graph(
-    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
-        wire__crate__api__clear_stream_graph_impl()
+    pub fn wire__crate__api__extract_topics_with_model(
+        port_: flutter_rust_bridge::for_generated::MessagePort,
+        text: String,
+        num_topics: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        model_id: Option<String>,
+        min_confidence: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+    ) {
+        wire__crate__api__extract_topics_with_model_impl(
+            port_,
+            text,
+            num_topics,
+            model_id,
+            min_confidence,
+        )
     }
 
     #[wasm_bindgen]
-    pub fn wire__crate__api__cluster_embeddings(
+    pub fn wire__crate__api__find_duplicate_entries(
         port_: flutter_rust_bridge::for_generated::MessagePort,
         entries: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
-        threshold: f32,
+        options: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
     ) {
-        wire__crate__api__cluster_embeddings_impl(port_, entries, threshold)
+        wire__crate__api__find_duplicate_entries_impl(port_, entries, options)
     }
 
     #[wasm_bindgen]
-    pub fn wire__crate__api__cluster_notes(
+    pub fn wire__crate__api__find_duplicates(
         port_: flutter_rust_bridge::for_generated::MessagePort,
-        entries: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
-        k: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
-        max_iterations: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        documents: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        options: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
     ) {
-        wire__crate__api__cluster_notes_impl(port_, entries, k, max_iterations)
+        wire__crate__api__find_duplicates_impl(port_, documents, options)
     }
 
     #[wasm_bindgen]
-    pub fn wire__crate__api__compute_graph_layout(
+    pub fn wire__crate__api__find_duplicates_in_store(
         port_: flutter_rust_bridge::for_generated::MessagePort,
-        iterations: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        store_id: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        options: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
     ) {
-        wire__crate__api__compute_graph_layout_impl(port_, iterations)
+        wire__crate__api__find_duplicates_in_store_impl(port_, store_id, options)
     }
 
     #[wasm_bindgen]
-    pub fn wire__crate__api__connect_note_to_topics(
+    pub fn wire__crate__api__find_similar(
         port_: flutter_rust_bridge::for_generated::MessagePort,
-        note_id: String,
-        topic_ids: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        query: Box<[f32]>,
+        entries: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        top_k: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        threshold: f32,
     ) {
-        wire__crate__api__connect_note_to_topics_impl(port_, note_id, topic_ids)
+        wire__crate__api__find_similar_impl(port_, query, entries, top_k, threshold)
     }
 
     #[wasm_bindgen]
-    pub fn wire__crate__api__cosine_similarity(
-        a: Box<[f32]>,
-        b: Box<[f32]>,
-    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
-        wire__crate__api__cosine_similarity_impl(a, b)
+    pub fn wire__crate__api__find_similar_filtered(
+        port_: flutter_rust_bridge::for_generated::MessagePort,
+        query: Box<[f32]>,
+        entries: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        options: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+    ) {
+        wire__crate__api__find_similar_filtered_impl(port_, query, entries, options)
     }
 
     #[wasm_bindgen]
-    pub fn wire__crate__api__discover_semantic_edges(
+    pub fn wire__crate__api__find_similar_in_index(
         port_: flutter_rust_bridge::for_generated::MessagePort,
-        entries: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
-        threshold: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
-        existing_links: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        index_id: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        query: Box<[f32]>,
+        top_k: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        threshold: f32,
     ) {
-        wire__crate__api__discover_semantic_edges_impl(port_, entries, threshold, existing_links)
+        wire__crate__api__find_similar_in_index_impl(port_, index_id, query, top_k, threshold)
     }
 
     #[wasm_bindgen]
-    pub fn wire__crate__api__extract_topics(
+    pub fn wire__crate__api__find_similar_in_store(
         port_: flutter_rust_bridge::for_generated::MessagePort,
-        text: String,
-        num_topics: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        store_id: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        query: Box<[f32]>,
+        top_k: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        threshold: f32,
     ) {
-        wire__crate__api__extract_topics_impl(port_, text, num_topics)
+        wire__crate__api__find_similar_in_store_impl(port_, store_id, query, top_k, threshold)
     }
 
     #[wasm_bindgen]
-    pub fn wire__crate__api__find_similar(
+    pub fn wire__crate__api__find_similar_quantized(
         port_: flutter_rust_bridge::for_generated::MessagePort,
+        index_id: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
         query: Box<[f32]>,
-        entries: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
         top_k: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
         threshold: f32,
+        rescore_candidates: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
     ) {
-        wire__crate__api__find_similar_impl(port_, query, entries, top_k, threshold)
+        wire__crate__api__find_similar_quantized_impl(
+            port_,
+            index_id,
+            query,
+            top_k,
+            threshold,
+            rescore_candidates,
+        )
     }
 
     #[wasm_bindgen]
@@ -5630,6 +16754,44 @@ mod web {
         wire__crate__api__generate_text_impl(port_, prompt, max_tokens)
     }
 
+    #[wasm_bindgen]
+    pub fn wire__crate__api__generate_text_stream(
+        port_: flutter_rust_bridge::for_generated::MessagePort,
+        prompt: String,
+        max_tokens: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        stop_sequences: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        model_id: Option<String>,
+        sink: String,
+    ) {
+        wire__crate__api__generate_text_stream_impl(
+            port_,
+            prompt,
+            max_tokens,
+            stop_sequences,
+            model_id,
+            sink,
+        )
+    }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__generate_text_with_model(
+        port_: flutter_rust_bridge::for_generated::MessagePort,
+        prompt: String,
+        max_tokens: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        model_id: Option<String>,
+    ) {
+        wire__crate__api__generate_text_with_model_impl(port_, prompt, max_tokens, model_id)
+    }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__generate_text_with_options(
+        port_: flutter_rust_bridge::for_generated::MessagePort,
+        prompt: String,
+        options: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+    ) {
+        wire__crate__api__generate_text_with_options_impl(port_, prompt, options)
+    }
+
     #[wasm_bindgen]
     pub fn wire__crate__api__get_embedding(
         port_: flutter_rust_bridge::for_generated::MessagePort,
@@ -5638,12 +16800,28 @@ mod web {
         wire__crate__api__get_embedding_impl(port_, text)
     }
 
+    #[wasm_bindgen]
+    pub fn wire__crate__api__get_embedding_cache_stats(
+        port_: flutter_rust_bridge::for_generated::MessagePort,
+    ) {
+        wire__crate__api__get_embedding_cache_stats_impl(port_)
+    }
+
     #[wasm_bindgen]
     pub fn wire__crate__api__get_embedding_dimension(
     ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
         wire__crate__api__get_embedding_dimension_impl()
     }
 
+    #[wasm_bindgen]
+    pub fn wire__crate__api__get_embedding_with_model(
+        port_: flutter_rust_bridge::for_generated::MessagePort,
+        text: String,
+        model_id: Option<String>,
+    ) {
+        wire__crate__api__get_embedding_with_model_impl(port_, text, model_id)
+    }
+
     #[wasm_bindgen]
     pub fn wire__crate__api__get_graph_state(
         port_: flutter_rust_bridge::for_generated::MessagePort,
@@ -5651,6 +16829,13 @@ mod web {
         wire__crate__api__get_graph_state_impl(port_)
     }
 
+    #[wasm_bindgen]
+    pub fn wire__crate__api__get_model_metadata(
+        model_id: Option<String>,
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__api__get_model_metadata_impl(model_id)
+    }
+
     #[wasm_bindgen]
     pub fn wire__crate__api__get_model_type(
     ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
@@ -5671,6 +16856,21 @@ mod web {
         wire__crate__api__get_stream_graph_stats_impl()
     }
 
+    #[wasm_bindgen]
+    pub fn wire__crate__api__get_tokenizer_info(
+        model_id: Option<String>,
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__api__get_tokenizer_info_impl(model_id)
+    }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__get_vector_store_stats(
+        port_: flutter_rust_bridge::for_generated::MessagePort,
+        store_id: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+    ) {
+        wire__crate__api__get_vector_store_stats_impl(port_, store_id)
+    }
+
     #[wasm_bindgen]
     pub fn wire__crate__api__get_version(
     ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
@@ -5684,12 +16884,75 @@ mod web {
         wire__crate__api__get_visible_graph_nodes_impl(port_)
     }
 
+    #[wasm_bindgen]
+    pub fn wire__crate__api__greedy_sampler_config(
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__api__greedy_sampler_config_impl()
+    }
+
     #[wasm_bindgen]
     pub fn wire__crate__api__health_check(
     ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
         wire__crate__api__health_check_impl()
     }
 
+    #[wasm_bindgen]
+    pub fn wire__crate__api__hnsw_index_insert(
+        port_: flutter_rust_bridge::for_generated::MessagePort,
+        index_id: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        entries: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+    ) {
+        wire__crate__api__hnsw_index_insert_impl(port_, index_id, entries)
+    }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__hnsw_index_insert_with_texts(
+        port_: flutter_rust_bridge::for_generated::MessagePort,
+        index_id: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        entries: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        texts: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+    ) {
+        wire__crate__api__hnsw_index_insert_with_texts_impl(port_, index_id, entries, texts)
+    }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__hnsw_index_len(
+        index_id: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__api__hnsw_index_len_impl(index_id)
+    }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__hnsw_index_remove(
+        port_: flutter_rust_bridge::for_generated::MessagePort,
+        index_id: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        ids: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+    ) {
+        wire__crate__api__hnsw_index_remove_impl(port_, index_id, ids)
+    }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__hybrid_search(
+        port_: flutter_rust_bridge::for_generated::MessagePort,
+        query_text: String,
+        entries: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        top_k: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        options: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+    ) {
+        wire__crate__api__hybrid_search_impl(port_, query_text, entries, top_k, options)
+    }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__hybrid_search_in_index(
+        port_: flutter_rust_bridge::for_generated::MessagePort,
+        index_id: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        query_text: String,
+        top_k: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        options: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+    ) {
+        wire__crate__api__hybrid_search_in_index_impl(port_, index_id, query_text, top_k, options)
+    }
+
     #[wasm_bindgen]
     pub fn wire__crate__api__init_graph() -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco
     {
@@ -5739,21 +17002,95 @@ mod web {
     }
 
     #[wasm_bindgen]
-    pub fn wire__crate__api__is_graph_stream_running(
-    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
-        wire__crate__api__is_graph_stream_running_impl()
+    pub fn wire__crate__api__inspect_model(
+        port_: flutter_rust_bridge::for_generated::MessagePort,
+        model_path: String,
+    ) {
+        wire__crate__api__inspect_model_impl(port_, model_path)
+    }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__is_embedding_model_loaded(
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__api__is_embedding_model_loaded_impl()
+    }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__is_graph_stream_running(
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__api__is_graph_stream_running_impl()
+    }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__is_mcp_initialized(
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__api__is_mcp_initialized_impl()
+    }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__is_model_loaded(
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__api__is_model_loaded_impl()
+    }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__json_schema_to_grammar(
+        schema_json: String,
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__api__json_schema_to_grammar_impl(schema_json)
+    }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__list_loaded_models(
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__api__list_loaded_models_impl()
+    }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__load_chat_session(
+        port_: flutter_rust_bridge::for_generated::MessagePort,
+        path: String,
+        n_ctx: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        model_id: Option<String>,
+    ) {
+        wire__crate__api__load_chat_session_impl(port_, path, n_ctx, model_id)
+    }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__load_embedding_model(
+        port_: flutter_rust_bridge::for_generated::MessagePort,
+        model_path: String,
+        config: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+    ) {
+        wire__crate__api__load_embedding_model_impl(port_, model_path, config)
     }
 
     #[wasm_bindgen]
-    pub fn wire__crate__api__is_mcp_initialized(
-    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
-        wire__crate__api__is_mcp_initialized_impl()
+    pub fn wire__crate__api__load_hnsw_index(
+        port_: flutter_rust_bridge::for_generated::MessagePort,
+        path: String,
+    ) {
+        wire__crate__api__load_hnsw_index_impl(port_, path)
     }
 
     #[wasm_bindgen]
-    pub fn wire__crate__api__is_model_loaded(
-    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
-        wire__crate__api__is_model_loaded_impl()
+    pub fn wire__crate__api__load_model(
+        port_: flutter_rust_bridge::for_generated::MessagePort,
+        model_id: String,
+        model_path: String,
+        n_ctx: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        n_gpu_layers: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+    ) {
+        wire__crate__api__load_model_impl(port_, model_id, model_path, n_ctx, n_gpu_layers)
+    }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__load_quantized_index(
+        port_: flutter_rust_bridge::for_generated::MessagePort,
+        path: String,
+        store_id: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+    ) {
+        wire__crate__api__load_quantized_index_impl(port_, path, store_id)
     }
 
     #[wasm_bindgen]
@@ -5763,6 +17100,21 @@ mod web {
         wire__crate__api__mcp_classify_task_impl(message)
     }
 
+    #[wasm_bindgen]
+    pub fn wire__crate__api__mcp_classify_task_with_model(
+        port_: flutter_rust_bridge::for_generated::MessagePort,
+        message: String,
+        model_id: Option<String>,
+        min_confidence: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+    ) {
+        wire__crate__api__mcp_classify_task_with_model_impl(
+            port_,
+            message,
+            model_id,
+            min_confidence,
+        )
+    }
+
     #[wasm_bindgen]
     pub fn wire__crate__api__mcp_create_folder(
         port_: flutter_rust_bridge::for_generated::MessagePort,
@@ -5787,6 +17139,16 @@ mod web {
         wire__crate__api__mcp_execute_tool_call_impl(port_, tool_call)
     }
 
+    #[wasm_bindgen]
+    pub fn wire__crate__api__mcp_generate_tool_call(
+        port_: flutter_rust_bridge::for_generated::MessagePort,
+        messages: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        max_tokens: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        model_id: Option<String>,
+    ) {
+        wire__crate__api__mcp_generate_tool_call_impl(port_, messages, max_tokens, model_id)
+    }
+
     #[wasm_bindgen]
     pub fn wire__crate__api__mcp_get_all_tools(
     ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
@@ -5800,6 +17162,13 @@ mod web {
         wire__crate__api__mcp_get_model_for_task_impl(category)
     }
 
+    #[wasm_bindgen]
+    pub fn wire__crate__api__mcp_get_model_id_for_task(
+        category: i32,
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__api__mcp_get_model_id_for_task_impl(category)
+    }
+
     #[wasm_bindgen]
     pub fn wire__crate__api__mcp_get_tool_description(
         tool: i32,
@@ -5843,6 +17212,14 @@ mod web {
         wire__crate__api__mcp_parse_tool_call_impl(port_, json)
     }
 
+    #[wasm_bindgen]
+    pub fn wire__crate__api__mcp_parse_tool_calls(
+        reply: String,
+        model_type: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__api__mcp_parse_tool_calls_impl(reply, model_type)
+    }
+
     #[wasm_bindgen]
     pub fn wire__crate__api__mcp_read_file(
         port_: flutter_rust_bridge::for_generated::MessagePort,
@@ -5851,6 +17228,31 @@ mod web {
         wire__crate__api__mcp_read_file_impl(port_, path)
     }
 
+    #[wasm_bindgen]
+    pub fn wire__crate__api__mcp_run_agent(
+        port_: flutter_rust_bridge::for_generated::MessagePort,
+        messages: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        max_steps: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        max_tokens: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        model_id: Option<String>,
+    ) {
+        wire__crate__api__mcp_run_agent_impl(port_, messages, max_steps, max_tokens, model_id)
+    }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__mcp_run_agent_stream(
+        port_: flutter_rust_bridge::for_generated::MessagePort,
+        messages: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        max_steps: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        max_tokens: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        model_id: Option<String>,
+        sink: String,
+    ) {
+        wire__crate__api__mcp_run_agent_stream_impl(
+            port_, messages, max_steps, max_tokens, model_id, sink,
+        )
+    }
+
     #[wasm_bindgen]
     pub fn wire__crate__api__mcp_validate_path(
         path: String,
@@ -5867,6 +17269,41 @@ mod web {
         wire__crate__api__mcp_write_file_impl(port_, path, content)
     }
 
+    #[wasm_bindgen]
+    pub fn wire__crate__api__measure_quantization_recall(
+        port_: flutter_rust_bridge::for_generated::MessagePort,
+        entries: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        queries: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        kind: i32,
+        top_k: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        rescore_candidates: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+    ) {
+        wire__crate__api__measure_quantization_recall_impl(
+            port_,
+            entries,
+            queries,
+            kind,
+            top_k,
+            rescore_candidates,
+        )
+    }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__open_embedding_cache(
+        port_: flutter_rust_bridge::for_generated::MessagePort,
+        path: String,
+    ) {
+        wire__crate__api__open_embedding_cache_impl(port_, path)
+    }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__open_vector_store(
+        port_: flutter_rust_bridge::for_generated::MessagePort,
+        path: String,
+    ) {
+        wire__crate__api__open_vector_store_impl(port_, path)
+    }
+
     #[wasm_bindgen]
     pub fn wire__crate__api__pin_stream_node(
         port_: flutter_rust_bridge::for_generated::MessagePort,
@@ -5876,6 +17313,52 @@ mod web {
         wire__crate__api__pin_stream_node_impl(port_, id, pinned)
     }
 
+    #[wasm_bindgen]
+    pub fn wire__crate__api__poll_generation(
+        request_id: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__api__poll_generation_impl(request_id)
+    }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__quantized_index_insert(
+        port_: flutter_rust_bridge::for_generated::MessagePort,
+        index_id: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        entries: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+    ) {
+        wire__crate__api__quantized_index_insert_impl(port_, index_id, entries)
+    }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__quantized_index_remove(
+        port_: flutter_rust_bridge::for_generated::MessagePort,
+        index_id: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        ids: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+    ) {
+        wire__crate__api__quantized_index_remove_impl(port_, index_id, ids)
+    }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__rag_answer(
+        port_: flutter_rust_bridge::for_generated::MessagePort,
+        question: String,
+        chunks: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        top_k: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        context_tokens: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        max_tokens: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        model_id: Option<String>,
+    ) {
+        wire__crate__api__rag_answer_impl(
+            port_,
+            question,
+            chunks,
+            top_k,
+            context_tokens,
+            max_tokens,
+            model_id,
+        )
+    }
+
     #[wasm_bindgen]
     pub fn wire__crate__api__remove_graph_node(
         port_: flutter_rust_bridge::for_generated::MessagePort,
@@ -5901,6 +17384,33 @@ mod web {
         wire__crate__api__remove_stream_node_impl(port_, id)
     }
 
+    #[wasm_bindgen]
+    pub fn wire__crate__api__save_chat_session(
+        port_: flutter_rust_bridge::for_generated::MessagePort,
+        session_id: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        path: String,
+    ) {
+        wire__crate__api__save_chat_session_impl(port_, session_id, path)
+    }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__save_hnsw_index(
+        port_: flutter_rust_bridge::for_generated::MessagePort,
+        index_id: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        path: String,
+    ) {
+        wire__crate__api__save_hnsw_index_impl(port_, index_id, path)
+    }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__save_quantized_index(
+        port_: flutter_rust_bridge::for_generated::MessagePort,
+        index_id: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        path: String,
+    ) {
+        wire__crate__api__save_quantized_index_impl(port_, index_id, path)
+    }
+
     #[wasm_bindgen]
     pub fn wire__crate__api__semantic_search(
         port_: flutter_rust_bridge::for_generated::MessagePort,
@@ -5911,6 +17421,74 @@ mod web {
         wire__crate__api__semantic_search_impl(port_, query_text, entries, top_k)
     }
 
+    #[wasm_bindgen]
+    pub fn wire__crate__api__semantic_search_filtered(
+        port_: flutter_rust_bridge::for_generated::MessagePort,
+        query_text: String,
+        entries: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        options: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+    ) {
+        wire__crate__api__semantic_search_filtered_impl(port_, query_text, entries, options)
+    }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__semantic_search_in_index(
+        port_: flutter_rust_bridge::for_generated::MessagePort,
+        index_id: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        query_text: String,
+        top_k: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+    ) {
+        wire__crate__api__semantic_search_in_index_impl(port_, index_id, query_text, top_k)
+    }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__semantic_search_in_index_filtered(
+        port_: flutter_rust_bridge::for_generated::MessagePort,
+        index_id: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        query_text: String,
+        options: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+    ) {
+        wire__crate__api__semantic_search_in_index_filtered_impl(
+            port_, index_id, query_text, options,
+        )
+    }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__semantic_search_in_store(
+        port_: flutter_rust_bridge::for_generated::MessagePort,
+        store_id: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        query_text: String,
+        top_k: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+    ) {
+        wire__crate__api__semantic_search_in_store_impl(port_, store_id, query_text, top_k)
+    }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__semantic_search_in_store_filtered(
+        port_: flutter_rust_bridge::for_generated::MessagePort,
+        store_id: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        query_text: String,
+        options: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+    ) {
+        wire__crate__api__semantic_search_in_store_filtered_impl(
+            port_, store_id, query_text, options,
+        )
+    }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__set_default_model(
+        model_id: String,
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__api__set_default_model_impl(model_id)
+    }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__set_model_memory_budget(
+        budget_bytes: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__api__set_model_memory_budget_impl(budget_bytes)
+    }
+
     #[wasm_bindgen]
     pub fn wire__crate__api__set_stream_node_position(
         port_: flutter_rust_bridge::for_generated::MessagePort,
@@ -5921,6 +17499,34 @@ mod web {
         wire__crate__api__set_stream_node_position_impl(port_, id, x, y)
     }
 
+    #[wasm_bindgen]
+    pub fn wire__crate__api__start_chat_completion(
+        port_: flutter_rust_bridge::for_generated::MessagePort,
+        messages: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        max_tokens: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        stop_sequences: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        model_id: Option<String>,
+    ) {
+        wire__crate__api__start_chat_completion_impl(
+            port_,
+            messages,
+            max_tokens,
+            stop_sequences,
+            model_id,
+        )
+    }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__start_generation(
+        port_: flutter_rust_bridge::for_generated::MessagePort,
+        prompt: String,
+        max_tokens: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        stop_sequences: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        model_id: Option<String>,
+    ) {
+        wire__crate__api__start_generation_impl(port_, prompt, max_tokens, stop_sequences, model_id)
+    }
+
     #[wasm_bindgen]
     pub fn wire__crate__api__start_graph_stream(
         port_: flutter_rust_bridge::for_generated::MessagePort,
@@ -5934,12 +17540,54 @@ mod web {
         wire__crate__api__stop_graph_stream_impl()
     }
 
+    #[wasm_bindgen]
+    pub fn wire__crate__api__summarize_text(
+        port_: flutter_rust_bridge::for_generated::MessagePort,
+        text: String,
+        options: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+    ) {
+        wire__crate__api__summarize_text_impl(port_, text, options)
+    }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__summarize_transcript(
+        port_: flutter_rust_bridge::for_generated::MessagePort,
+        segments: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        options: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+    ) {
+        wire__crate__api__summarize_transcript_impl(port_, segments, options)
+    }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__tokenize_text(
+        port_: flutter_rust_bridge::for_generated::MessagePort,
+        text: String,
+        add_bos: bool,
+        parse_special: bool,
+        model_id: Option<String>,
+    ) {
+        wire__crate__api__tokenize_text_impl(port_, text, add_bos, parse_special, model_id)
+    }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__unload_embedding_model(
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__api__unload_embedding_model_impl()
+    }
+
     #[wasm_bindgen]
     pub fn wire__crate__api__unload_model(
     ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
         wire__crate__api__unload_model_impl()
     }
 
+    #[wasm_bindgen]
+    pub fn wire__crate__api__unload_model_by_id(
+        model_id: String,
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__api__unload_model_by_id_impl(model_id)
+    }
+
     #[wasm_bindgen]
     pub fn wire__crate__api__update_graph_viewport(
         port_: flutter_rust_bridge::for_generated::MessagePort,
@@ -5951,6 +17599,74 @@ mod web {
     ) {
         wire__crate__api__update_graph_viewport_impl(port_, x, y, width, height, scale)
     }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__use_llama_inference_backend(
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__api__use_llama_inference_backend_impl()
+    }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__use_mock_inference_backend(
+        completions: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        fallback: Option<String>,
+        embedding_dimension: u32,
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__api__use_mock_inference_backend_impl(
+            completions,
+            fallback,
+            embedding_dimension,
+        )
+    }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__validate_search_filter(
+        filter: String,
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__api__validate_search_filter_impl(filter)
+    }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__vector_store_delete(
+        port_: flutter_rust_bridge::for_generated::MessagePort,
+        store_id: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        ids: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+    ) {
+        wire__crate__api__vector_store_delete_impl(port_, store_id, ids)
+    }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__vector_store_get(
+        port_: flutter_rust_bridge::for_generated::MessagePort,
+        store_id: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        ids: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+    ) {
+        wire__crate__api__vector_store_get_impl(port_, store_id, ids)
+    }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__vector_store_len(
+        store_id: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+    ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+        wire__crate__api__vector_store_len_impl(store_id)
+    }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__vector_store_upsert(
+        port_: flutter_rust_bridge::for_generated::MessagePort,
+        store_id: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+        entries: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+    ) {
+        wire__crate__api__vector_store_upsert_impl(port_, store_id, entries)
+    }
+
+    #[wasm_bindgen]
+    pub fn wire__crate__api__wait_for_generation(
+        port_: flutter_rust_bridge::for_generated::MessagePort,
+        request_id: flutter_rust_bridge::for_generated::wasm_bindgen::JsValue,
+    ) {
+        wire__crate__api__wait_for_generation_impl(port_, request_id)
+    }
 }
 #[cfg(target_family = "wasm")]
 pub use web::*;
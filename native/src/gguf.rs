@@ -0,0 +1,506 @@
+//! GGUF Metadata Inspector
+//!
+//! Reads the header of a GGUF model file without loading its weights:
+//! - Architecture, name and embedded chat template
+//! - Context length, embedding size and layer count
+//! - Quantization type and parameter count
+//! - Tokenizer model, vocabulary size and special tokens
+
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufReader, Read};
+use std::path::Path;
+
+use crate::inference::ModelType;
+
+const GGUF_MAGIC: &[u8; 4] = b"GGUF";
+
+/// Longest string accepted from a header (guards against corrupt lengths)
+const MAX_STRING_LEN: u64 = 64 * 1024 * 1024;
+
+/// Model details read from a GGUF header
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GgufMetadata {
+    /// GGUF format version
+    pub version: u32,
+    /// Model architecture (e.g. "llama", "qwen2", "phi3", "gemma3")
+    pub architecture: Option<String>,
+    /// Model name set by the converter
+    pub name: Option<String>,
+    /// Jinja chat template embedded in the file
+    pub chat_template: Option<String>,
+    /// Context length the model was trained with
+    pub context_length: Option<u64>,
+    /// Size of the hidden state (embedding dimension)
+    pub embedding_length: Option<u64>,
+    /// Number of transformer layers
+    pub block_count: Option<u64>,
+    /// Pooling declared by embedding models (llama.cpp `LLAMA_POOLING_TYPE_*`)
+    pub pooling_type: Option<u32>,
+    /// Quantization type of the weights (e.g. "Q4_K_M")
+    pub quantization: Option<String>,
+    /// Total number of weights across all tensors
+    pub parameter_count: u64,
+    /// Number of tensors in the file
+    pub tensor_count: u64,
+    /// Tokenizer family (e.g. "gpt2", "llama")
+    pub tokenizer_model: Option<String>,
+    /// Number of tokens in the vocabulary
+    pub vocab_size: Option<u64>,
+    /// Beginning-of-sequence token id
+    pub bos_token_id: Option<u32>,
+    /// End-of-sequence token id
+    pub eos_token_id: Option<u32>,
+    /// Size of the model file in bytes
+    pub file_size: u64,
+}
+
+impl GgufMetadata {
+    /// Prompt format implied by the metadata, if it identifies one
+    ///
+    /// The embedded chat template is the most reliable signal, followed by
+    /// the model name and architecture. Phi-4 (non-mini) ships a ChatML-style
+    /// template, so the Phi family is matched before the `<|im_start|>`
+    /// marker that otherwise identifies Qwen.
+    pub fn model_type(&self) -> Option<ModelType> {
+        let name = self.name.as_deref().unwrap_or_default().to_lowercase();
+        let is_phi = name.starts_with("phi")
+            || self
+                .architecture
+                .as_deref()
+                .is_some_and(|arch| arch.starts_with("phi"));
+
+        if let Some(template) = &self.chat_template {
+            if template.contains("<|recipient|>") || template.contains("<|from|>") {
+                return Some(ModelType::Functionary);
+            }
+            if is_phi {
+                return Some(ModelType::Phi4);
+            }
+            if template.contains("<|im_start|>") {
+                return Some(ModelType::Qwen);
+            }
+            if template.contains("<|user|>") && template.contains("<|end|>") {
+                return Some(ModelType::Phi4);
+            }
+        }
+
+        if name.contains("functionary") {
+            return Some(ModelType::Functionary);
+        }
+
+        match self.architecture.as_deref()? {
+            arch if arch.starts_with("qwen") => Some(ModelType::Qwen),
+            arch if arch.starts_with("phi") => Some(ModelType::Phi4),
+            _ => None,
+        }
+    }
+
+    fn from_values(
+        version: u32,
+        tensor_count: u64,
+        parameter_count: u64,
+        values: &HashMap<String, GgufValue>,
+    ) -> Self {
+        let string = |key: &str| values.get(key).and_then(GgufValue::as_str);
+        let uint = |key: &str| values.get(key).and_then(GgufValue::as_uint);
+
+        let architecture = string("general.architecture");
+        let arch_uint = |suffix: &str| {
+            architecture
+                .as_ref()
+                .and_then(|arch| uint(&format!("{}.{}", arch, suffix)))
+        };
+
+        Self {
+            version,
+            context_length: arch_uint("context_length"),
+            embedding_length: arch_uint("embedding_length"),
+            block_count: arch_uint("block_count"),
+            pooling_type: arch_uint("pooling_type").map(|pooling| pooling as u32),
+            name: string("general.name"),
+            chat_template: string("tokenizer.chat_template"),
+            quantization: uint("general.file_type").map(file_type_name),
+            parameter_count,
+            tensor_count,
+            tokenizer_model: string("tokenizer.ggml.model"),
+            vocab_size: values
+                .get("tokenizer.ggml.tokens")
+                .and_then(GgufValue::array_len),
+            bos_token_id: uint("tokenizer.ggml.bos_token_id").map(|id| id as u32),
+            eos_token_id: uint("tokenizer.ggml.eos_token_id").map(|id| id as u32),
+            architecture,
+            file_size: 0,
+        }
+    }
+}
+
+/// Read the metadata of a GGUF file without loading its weights
+///
+/// # Arguments
+/// * `path` - Path to the GGUF model file
+///
+/// # Returns
+/// * The model details, or an error if the file is not a supported GGUF file
+pub fn inspect_gguf(path: &Path) -> Result<GgufMetadata> {
+    let file = File::open(path).map_err(|e| anyhow!("Cannot open {}: {}", path.display(), e))?;
+    let file_size = file.metadata()?.len();
+
+    let mut metadata = read_metadata(BufReader::new(file))
+        .map_err(|e| anyhow!("Failed to read GGUF header of {}: {}", path.display(), e))?;
+    metadata.file_size = file_size;
+    Ok(metadata)
+}
+
+/// Parse the header and tensor table from the start of a GGUF stream
+fn read_metadata<R: Read>(reader: R) -> Result<GgufMetadata> {
+    let mut reader = GgufReader(reader);
+
+    if &reader.bytes::<4>()? != GGUF_MAGIC {
+        return Err(anyhow!("Not a GGUF file"));
+    }
+    let version = reader.u32()?;
+    if !(2..=3).contains(&version) {
+        return Err(anyhow!("Unsupported GGUF version: {}", version));
+    }
+
+    let tensor_count = reader.u64()?;
+    let value_count = reader.u64()?;
+
+    let mut values = HashMap::new();
+    for _ in 0..value_count {
+        let key = reader.string()?;
+        let value_type = reader.u32()?;
+        values.insert(key, reader.value(value_type)?);
+    }
+
+    // The tensor table follows the key-value pairs; the weights come after it
+    let mut parameter_count = 0u64;
+    for _ in 0..tensor_count {
+        reader.string()?;
+        let n_dims = reader.u32()?;
+        let mut elements = 1u64;
+        for _ in 0..n_dims {
+            elements = elements.saturating_mul(reader.u64()?);
+        }
+        reader.u32()?; // tensor type
+        reader.u64()?; // data offset
+        parameter_count = parameter_count.saturating_add(elements);
+    }
+
+    Ok(GgufMetadata::from_values(
+        version,
+        tensor_count,
+        parameter_count,
+        &values,
+    ))
+}
+
+/// Name of a llama.cpp file type (`general.file_type`)
+fn file_type_name(file_type: u64) -> String {
+    let name = match file_type {
+        0 => "F32",
+        1 => "F16",
+        2 => "Q4_0",
+        3 => "Q4_1",
+        7 => "Q8_0",
+        8 => "Q5_0",
+        9 => "Q5_1",
+        10 => "Q2_K",
+        11 => "Q3_K_S",
+        12 => "Q3_K_M",
+        13 => "Q3_K_L",
+        14 => "Q4_K_S",
+        15 => "Q4_K_M",
+        16 => "Q5_K_S",
+        17 => "Q5_K_M",
+        18 => "Q6_K",
+        19 => "IQ2_XXS",
+        20 => "IQ2_XS",
+        21 => "Q2_K_S",
+        22 => "IQ3_XS",
+        23 => "IQ3_XXS",
+        24 => "IQ1_S",
+        25 => "IQ4_NL",
+        26 => "IQ3_S",
+        27 => "IQ3_M",
+        28 => "IQ2_S",
+        29 => "IQ2_M",
+        30 => "IQ4_XS",
+        31 => "IQ1_M",
+        32 => "BF16",
+        36 => "TQ1_0",
+        37 => "TQ2_0",
+        other => return format!("unknown ({})", other),
+    };
+    name.to_string()
+}
+
+/// A metadata value from the header
+enum GgufValue {
+    UInt(u64),
+    Int(i64),
+    String(String),
+    /// Only the length is kept (e.g. the vocabulary size of the token list)
+    Array(u64),
+    /// Floats and booleans, which nothing reads yet
+    Other,
+}
+
+impl GgufValue {
+    fn as_str(&self) -> Option<String> {
+        match self {
+            GgufValue::String(value) => Some(value.clone()),
+            _ => None,
+        }
+    }
+
+    fn as_uint(&self) -> Option<u64> {
+        match self {
+            GgufValue::UInt(value) => Some(*value),
+            GgufValue::Int(value) => u64::try_from(*value).ok(),
+            _ => None,
+        }
+    }
+
+    fn array_len(&self) -> Option<u64> {
+        match self {
+            GgufValue::Array(len) => Some(*len),
+            _ => None,
+        }
+    }
+}
+
+/// Little-endian reader for GGUF primitives
+struct GgufReader<R>(R);
+
+impl<R: Read> GgufReader<R> {
+    fn bytes<const N: usize>(&mut self) -> Result<[u8; N]> {
+        let mut buf = [0u8; N];
+        self.0.read_exact(&mut buf)?;
+        Ok(buf)
+    }
+
+    fn u32(&mut self) -> Result<u32> {
+        Ok(u32::from_le_bytes(self.bytes()?))
+    }
+
+    fn u64(&mut self) -> Result<u64> {
+        Ok(u64::from_le_bytes(self.bytes()?))
+    }
+
+    fn string(&mut self) -> Result<String> {
+        let len = self.u64()?;
+        if len > MAX_STRING_LEN {
+            return Err(anyhow!("String of {} bytes is too long", len));
+        }
+        let mut buf = vec![0u8; len as usize];
+        self.0.read_exact(&mut buf)?;
+        Ok(String::from_utf8_lossy(&buf).into_owned())
+    }
+
+    fn value(&mut self, value_type: u32) -> Result<GgufValue> {
+        Ok(match value_type {
+            0 => GgufValue::UInt(u8::from_le_bytes(self.bytes()?) as u64),
+            1 => GgufValue::Int(i8::from_le_bytes(self.bytes()?) as i64),
+            2 => GgufValue::UInt(u16::from_le_bytes(self.bytes()?) as u64),
+            3 => GgufValue::Int(i16::from_le_bytes(self.bytes()?) as i64),
+            4 => GgufValue::UInt(self.u32()? as u64),
+            5 => GgufValue::Int(i32::from_le_bytes(self.bytes()?) as i64),
+            6 => {
+                self.bytes::<4>()?;
+                GgufValue::Other
+            }
+            7 => {
+                self.bytes::<1>()?;
+                GgufValue::Other
+            }
+            8 => GgufValue::String(self.string()?),
+            9 => {
+                let item_type = self.u32()?;
+                let len = self.u64()?;
+                for _ in 0..len {
+                    self.value(item_type)?;
+                }
+                GgufValue::Array(len)
+            }
+            10 => GgufValue::UInt(self.u64()?),
+            11 => GgufValue::Int(i64::from_le_bytes(self.bytes()?)),
+            12 => {
+                self.bytes::<8>()?;
+                GgufValue::Other
+            }
+            other => return Err(anyhow!("Unknown GGUF value type: {}", other)),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Builds a GGUF header in memory
+    #[derive(Default)]
+    struct HeaderBuilder {
+        values: Vec<u8>,
+        value_count: u64,
+        tensors: Vec<u8>,
+        tensor_count: u64,
+    }
+
+    fn push_string(buf: &mut Vec<u8>, value: &str) {
+        buf.extend_from_slice(&(value.len() as u64).to_le_bytes());
+        buf.extend_from_slice(value.as_bytes());
+    }
+
+    impl HeaderBuilder {
+        fn key(&mut self, key: &str, value_type: u32) -> &mut Vec<u8> {
+            self.value_count += 1;
+            push_string(&mut self.values, key);
+            self.values.extend_from_slice(&value_type.to_le_bytes());
+            &mut self.values
+        }
+
+        fn string(mut self, key: &str, value: &str) -> Self {
+            push_string(self.key(key, 8), value);
+            self
+        }
+
+        fn u32(mut self, key: &str, value: u32) -> Self {
+            self.key(key, 4).extend_from_slice(&value.to_le_bytes());
+            self
+        }
+
+        fn strings(mut self, key: &str, items: &[&str]) -> Self {
+            let buf = self.key(key, 9);
+            buf.extend_from_slice(&8u32.to_le_bytes());
+            buf.extend_from_slice(&(items.len() as u64).to_le_bytes());
+            for item in items {
+                push_string(buf, item);
+            }
+            self
+        }
+
+        fn tensor(mut self, name: &str, dims: &[u64]) -> Self {
+            self.tensor_count += 1;
+            push_string(&mut self.tensors, name);
+            self.tensors
+                .extend_from_slice(&(dims.len() as u32).to_le_bytes());
+            for dim in dims {
+                self.tensors.extend_from_slice(&dim.to_le_bytes());
+            }
+            self.tensors.extend_from_slice(&0u32.to_le_bytes());
+            self.tensors.extend_from_slice(&0u64.to_le_bytes());
+            self
+        }
+
+        fn build(self) -> Vec<u8> {
+            let mut buf = GGUF_MAGIC.to_vec();
+            buf.extend_from_slice(&3u32.to_le_bytes());
+            buf.extend_from_slice(&self.tensor_count.to_le_bytes());
+            buf.extend_from_slice(&self.value_count.to_le_bytes());
+            buf.extend(self.values);
+            buf.extend(self.tensors);
+            buf
+        }
+    }
+
+    #[test]
+    fn test_read_metadata() {
+        let tokens: Vec<String> = (0..100).map(|i| format!("tok{}", i)).collect();
+        let tokens: Vec<&str> = tokens.iter().map(String::as_str).collect();
+        let header = HeaderBuilder::default()
+            .string("general.architecture", "qwen2")
+            .string("general.name", "Assistant")
+            .u32("general.file_type", 15)
+            .u32("qwen2.context_length", 32768)
+            .u32("qwen2.embedding_length", 896)
+            .u32("qwen2.block_count", 24)
+            .string("tokenizer.ggml.model", "gpt2")
+            .strings("tokenizer.ggml.tokens", &tokens)
+            .u32("tokenizer.ggml.eos_token_id", 7)
+            .tensor("token_embd.weight", &[896, 100])
+            .tensor("output_norm.weight", &[896])
+            .build();
+
+        let metadata = read_metadata(header.as_slice()).unwrap();
+        assert_eq!(metadata.version, 3);
+        assert_eq!(metadata.architecture.as_deref(), Some("qwen2"));
+        assert_eq!(metadata.name.as_deref(), Some("Assistant"));
+        assert_eq!(metadata.quantization.as_deref(), Some("Q4_K_M"));
+        assert_eq!(metadata.context_length, Some(32768));
+        assert_eq!(metadata.embedding_length, Some(896));
+        assert_eq!(metadata.block_count, Some(24));
+        assert_eq!(metadata.tokenizer_model.as_deref(), Some("gpt2"));
+        assert_eq!(metadata.vocab_size, Some(100));
+        assert_eq!(metadata.bos_token_id, None);
+        assert_eq!(metadata.eos_token_id, Some(7));
+        assert_eq!(metadata.tensor_count, 2);
+        assert_eq!(metadata.parameter_count, 896 * 100 + 896);
+        assert_eq!(metadata.model_type(), Some(ModelType::Qwen));
+    }
+
+    #[test]
+    fn test_model_type_from_metadata() {
+        let with_template = |template: &str| GgufMetadata {
+            architecture: Some("llama".to_string()),
+            chat_template: Some(template.to_string()),
+            ..GgufMetadata::default()
+        };
+
+        assert_eq!(
+            with_template("{{'<|im_start|>' + message['role']}}").model_type(),
+            Some(ModelType::Qwen)
+        );
+        assert_eq!(
+            with_template("{{'<|user|>' + content + '<|end|>'}}").model_type(),
+            Some(ModelType::Phi4)
+        );
+        assert_eq!(
+            with_template("{{'<|from|>' + role + '<|recipient|>'}}").model_type(),
+            Some(ModelType::Functionary)
+        );
+
+        // Without a recognised template the architecture decides
+        let phi = GgufMetadata {
+            architecture: Some("phi3".to_string()),
+            ..GgufMetadata::default()
+        };
+        assert_eq!(phi.model_type(), Some(ModelType::Phi4));
+        assert_eq!(with_template("{{ bos_token }}").model_type(), None);
+
+        // Phi-4 uses ChatML markers but must not be taken for Qwen
+        let phi4 = GgufMetadata {
+            architecture: Some("phi3".to_string()),
+            name: Some("Phi 4".to_string()),
+            chat_template: Some("{{'<|im_start|>' + role + '<|im_sep|>'}}".to_string()),
+            ..GgufMetadata::default()
+        };
+        assert_eq!(phi4.model_type(), Some(ModelType::Phi4));
+    }
+
+    #[test]
+    fn test_rejects_invalid_files() {
+        assert!(read_metadata(&b"GGML\x03\0\0\0"[..]).is_err());
+        assert!(read_metadata(&b"GGUF\x01\0\0\0"[..]).is_err());
+
+        // Truncated header
+        let mut header = HeaderBuilder::default()
+            .string("general.architecture", "llama")
+            .build();
+        header.truncate(header.len() - 2);
+        assert!(read_metadata(header.as_slice()).is_err());
+
+        // Corrupt string length
+        let mut header = GGUF_MAGIC.to_vec();
+        header.extend_from_slice(&3u32.to_le_bytes());
+        header.extend_from_slice(&0u64.to_le_bytes());
+        header.extend_from_slice(&1u64.to_le_bytes());
+        header.extend_from_slice(&u64::MAX.to_le_bytes());
+        assert!(read_metadata(header.as_slice()).is_err());
+
+        assert!(inspect_gguf(Path::new("/nonexistent/model.gguf")).is_err());
+    }
+}
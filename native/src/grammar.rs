@@ -0,0 +1,466 @@
+//! Constrained Decoding Grammars
+//!
+//! Converts JSON Schemas into GBNF grammars for llama.cpp so that
+//! generated output is guaranteed to parse:
+//! - Objects with required and optional properties
+//! - Arrays with item schemas and length bounds
+//! - Strings, numbers, integers, booleans and null
+//! - `enum`, `const`, `anyOf` / `oneOf` and type unions
+
+use anyhow::{anyhow, bail, Result};
+use serde_json::{Map, Value};
+use std::collections::HashSet;
+
+/// Primitive rules shared by every generated grammar
+const PRIMITIVE_RULES: &[(&str, &str)] = &[
+    // Bounded whitespace so the model cannot pad forever
+    ("space", r#"| " " | "\n" [ \t]{0,20}"#),
+    (
+        "char",
+        r#"[^"\\\x7F\x00-\x1F] | "\\" (["\\bfnrt/] | "u" [0-9a-fA-F]{4})"#,
+    ),
+    ("string", r#""\"" char* "\"" space"#),
+    (
+        "number",
+        r#""-"? ("0" | [1-9] [0-9]{0,15}) ("." [0-9]+)? ([eE] [-+]? [0-9]+)? space"#,
+    ),
+    ("integer", r#""-"? ("0" | [1-9] [0-9]{0,15}) space"#),
+    ("boolean", r#"("true" | "false") space"#),
+    ("null", r#""null" space"#),
+    (
+        "value",
+        r#"object | array | string | number | boolean | null"#,
+    ),
+    (
+        "object",
+        r#""{" space (string ":" space value ("," space string ":" space value)*)? "}" space"#,
+    ),
+    (
+        "array",
+        r#""[" space (value ("," space value)*)? "]" space"#,
+    ),
+];
+
+/// Rules a primitive rule depends on
+fn primitive_dependencies(name: &str) -> &'static [&'static str] {
+    match name {
+        "string" => &["char", "space"],
+        "number" | "integer" | "boolean" | "null" => &["space"],
+        "value" | "object" | "array" => &[
+            "value", "object", "array", "string", "char", "number", "boolean", "null", "space",
+        ],
+        _ => &[],
+    }
+}
+
+/// Convert a JSON Schema string into a GBNF grammar
+///
+/// # Arguments
+/// * `schema_json` - The JSON Schema, serialized as a string
+///
+/// # Returns
+/// * GBNF grammar text with a `root` rule
+pub fn json_schema_str_to_gbnf(schema_json: &str) -> Result<String> {
+    let schema: Value =
+        serde_json::from_str(schema_json).map_err(|e| anyhow!("Invalid JSON schema: {}", e))?;
+    json_schema_to_gbnf(&schema)
+}
+
+/// Convert a JSON Schema into a GBNF grammar
+///
+/// # Arguments
+/// * `schema` - The JSON Schema
+///
+/// # Returns
+/// * GBNF grammar text with a `root` rule
+pub fn json_schema_to_gbnf(schema: &Value) -> Result<String> {
+    let mut builder = GrammarBuilder::default();
+    builder.names.insert("root".to_string());
+    let root = builder.visit(schema, "root")?;
+    builder.rules.insert(0, ("root".to_string(), root));
+    Ok(builder.render())
+}
+
+/// Encode text as a GBNF string literal
+fn gbnf_literal(text: &str) -> String {
+    let mut literal = String::with_capacity(text.len() + 2);
+    literal.push('"');
+    for c in text.chars() {
+        match c {
+            '"' => literal.push_str("\\\""),
+            '\\' => literal.push_str("\\\\"),
+            '\n' => literal.push_str("\\n"),
+            '\r' => literal.push_str("\\r"),
+            '\t' => literal.push_str("\\t"),
+            c => literal.push(c),
+        }
+    }
+    literal.push('"');
+    literal
+}
+
+/// Rule names may only contain letters, digits and dashes
+fn sanitize_rule_name(name: &str) -> String {
+    name.chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' })
+        .collect()
+}
+
+#[derive(Default)]
+struct GrammarBuilder {
+    rules: Vec<(String, String)>,
+    names: HashSet<String>,
+}
+
+impl GrammarBuilder {
+    /// Add a primitive rule (and its dependencies) and return its name
+    fn primitive(&mut self, name: &'static str) -> String {
+        for dependency in std::iter::once(&name).chain(primitive_dependencies(name)) {
+            if self.names.insert(dependency.to_string()) {
+                let (_, body) = PRIMITIVE_RULES
+                    .iter()
+                    .find(|(rule, _)| rule == dependency)
+                    .expect("primitive rule is defined");
+                self.rules.push((dependency.to_string(), body.to_string()));
+            }
+        }
+        name.to_string()
+    }
+
+    /// Add a named rule, de-duplicating the name, and return it
+    fn add_rule(&mut self, hint: &str, body: String) -> String {
+        let base = sanitize_rule_name(hint);
+        let mut name = base.clone();
+        let mut suffix = 1;
+        while self.names.contains(&name) || PRIMITIVE_RULES.iter().any(|(rule, _)| *rule == name) {
+            name = format!("{}-{}", base, suffix);
+            suffix += 1;
+        }
+        self.names.insert(name.clone());
+        self.rules.push((name.clone(), body));
+        name
+    }
+
+    /// Build the grammar expression matching `schema`
+    fn visit(&mut self, schema: &Value, hint: &str) -> Result<String> {
+        let schema = match schema {
+            // `true` / `{}` accept any JSON value
+            Value::Bool(true) => return Ok(self.primitive("value")),
+            Value::Object(schema) => schema,
+            _ => bail!("Unsupported schema at {}: {}", hint, schema),
+        };
+
+        if schema.contains_key("$ref") {
+            bail!("Schema references ($ref) are not supported");
+        }
+
+        if let Some(value) = schema.get("const") {
+            let space = self.primitive("space");
+            return Ok(format!("{} {}", gbnf_literal(&value.to_string()), space));
+        }
+
+        if let Some(values) = schema.get("enum").and_then(Value::as_array) {
+            let space = self.primitive("space");
+            let alternatives: Vec<String> = values
+                .iter()
+                .map(|value| gbnf_literal(&value.to_string()))
+                .collect();
+            return Ok(format!("({}) {}", alternatives.join(" | "), space));
+        }
+
+        if let Some(variants) = schema
+            .get("anyOf")
+            .or_else(|| schema.get("oneOf"))
+            .and_then(Value::as_array)
+        {
+            let alternatives = variants
+                .iter()
+                .enumerate()
+                .map(|(i, variant)| self.visit(variant, &format!("{}-{}", hint, i)))
+                .collect::<Result<Vec<_>>>()?;
+            return Ok(self.add_rule(hint, alternatives.join(" | ")));
+        }
+
+        match schema.get("type") {
+            Some(Value::String(kind)) => self.visit_type(kind, schema, hint),
+            Some(Value::Array(kinds)) => {
+                let alternatives = kinds
+                    .iter()
+                    .map(|kind| {
+                        let kind = kind
+                            .as_str()
+                            .ok_or_else(|| anyhow!("Schema type must be a string"))?;
+                        self.visit_type(kind, schema, &format!("{}-{}", hint, kind))
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(self.add_rule(hint, alternatives.join(" | ")))
+            }
+            Some(other) => bail!("Unsupported schema type: {}", other),
+            None if schema.contains_key("properties") => self.visit_type("object", schema, hint),
+            None if schema.contains_key("items") => self.visit_type("array", schema, hint),
+            None => Ok(self.primitive("value")),
+        }
+    }
+
+    fn visit_type(
+        &mut self,
+        kind: &str,
+        schema: &Map<String, Value>,
+        hint: &str,
+    ) -> Result<String> {
+        match kind {
+            "string" => Ok(self.visit_string(schema, hint)),
+            "number" => Ok(self.primitive("number")),
+            "integer" => Ok(self.primitive("integer")),
+            "boolean" => Ok(self.primitive("boolean")),
+            "null" => Ok(self.primitive("null")),
+            "array" => self.visit_array(schema, hint),
+            "object" => self.visit_object(schema, hint),
+            other => bail!("Unsupported schema type: {}", other),
+        }
+    }
+
+    fn visit_string(&mut self, schema: &Map<String, Value>, hint: &str) -> String {
+        let min = schema.get("minLength").and_then(Value::as_u64);
+        let max = schema.get("maxLength").and_then(Value::as_u64);
+        if min.is_none() && max.is_none() {
+            return self.primitive("string");
+        }
+
+        self.primitive("char");
+        let space = self.primitive("space");
+        let repeat = match max {
+            Some(max) => format!("{{{},{}}}", min.unwrap_or(0), max),
+            None => format!("{{{},}}", min.unwrap_or(0)),
+        };
+        self.add_rule(hint, format!(r#""\"" char{} "\"" {}"#, repeat, space))
+    }
+
+    fn visit_array(&mut self, schema: &Map<String, Value>, hint: &str) -> Result<String> {
+        let item = match schema.get("items") {
+            Some(items) => self.visit(items, &format!("{}-item", hint))?,
+            None => self.primitive("value"),
+        };
+        let space = self.primitive("space");
+        let min = schema.get("minItems").and_then(Value::as_u64).unwrap_or(0);
+        let max = schema.get("maxItems").and_then(Value::as_u64);
+
+        let items = match (min, max) {
+            (_, Some(0)) => String::new(),
+            (0, None) => format!(r#"({item} ("," {space} {item})*)?"#),
+            (min, None) => format!(r#"{item} ("," {space} {item}){{{},}}"#, min - 1),
+            (0, Some(max)) => format!(r#"({item} ("," {space} {item}){{0,{}}})?"#, max - 1),
+            (min, Some(max)) => format!(
+                r#"{item} ("," {space} {item}){{{},{}}}"#,
+                min - 1,
+                max.max(min) - 1
+            ),
+        };
+
+        Ok(self.add_rule(
+            hint,
+            format!(r#""[" {space} {items} "]" {space}"#).replace("  ", " "),
+        ))
+    }
+
+    fn visit_object(&mut self, schema: &Map<String, Value>, hint: &str) -> Result<String> {
+        let Some(properties) = schema.get("properties").and_then(Value::as_object) else {
+            return Ok(self.primitive("object"));
+        };
+        // Required properties are emitted in the order of the `required` list,
+        // since JSON object keys carry no reliable order of their own
+        let required: Vec<&str> = schema
+            .get("required")
+            .and_then(Value::as_array)
+            .map(|names| names.iter().filter_map(Value::as_str).collect())
+            .unwrap_or_default();
+        let space = self.primitive("space");
+
+        let mut required_pairs = Vec::new();
+        for name in &required {
+            let property = properties.get(*name).unwrap_or(&Value::Bool(true));
+            required_pairs.push(self.property_pair(name, property, hint)?);
+        }
+
+        let mut optional_pairs = Vec::new();
+        for (name, property) in properties {
+            if !required.contains(&name.as_str()) {
+                optional_pairs.push(self.property_pair(name, property, hint)?);
+            }
+        }
+
+        // Optional properties keep their order but any subset may appear:
+        // opt-i ::= pair-i ("," opt-(i+1))? | opt-(i+1)
+        let mut optional_rule: Option<String> = None;
+        for (i, pair) in optional_pairs.iter().enumerate().rev() {
+            let body = match &optional_rule {
+                Some(next) => format!(r#"{pair} ("," {space} {next})? | {next}"#),
+                None => pair.clone(),
+            };
+            optional_rule = Some(self.add_rule(&format!("{}-opt-{}", hint, i), body));
+        }
+
+        let mut body = format!(r#""{{" {space}"#);
+        body.push(' ');
+        body.push_str(&required_pairs.join(&format!(r#" "," {space} "#)));
+        if let Some(optional) = optional_rule {
+            if required_pairs.is_empty() {
+                body.push_str(&format!("{}?", optional));
+            } else {
+                body.push_str(&format!(r#" ("," {space} {optional})?"#));
+            }
+        }
+        body.push_str(&format!(r#" "}}" {space}"#));
+
+        Ok(self.add_rule(hint, body.replace("  ", " ")))
+    }
+
+    /// Build `"name" space ":" space value` for one object property
+    fn property_pair(&mut self, name: &str, property: &Value, hint: &str) -> Result<String> {
+        let value = self.visit(property, &format!("{}-{}", hint, name))?;
+        let space = self.primitive("space");
+        Ok(format!(
+            "{} {} \":\" {} {}",
+            gbnf_literal(&Value::String(name.to_string()).to_string()),
+            space,
+            space,
+            value
+        ))
+    }
+
+    fn render(&self) -> String {
+        self.rules
+            .iter()
+            .map(|(name, body)| format!("{} ::= {}\n", name, body))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn rule<'a>(grammar: &'a str, name: &str) -> &'a str {
+        let prefix = format!("{} ::= ", name);
+        grammar
+            .lines()
+            .find_map(|line| line.strip_prefix(prefix.as_str()))
+            .unwrap_or_else(|| panic!("rule {} missing from:\n{}", name, grammar))
+    }
+
+    #[test]
+    fn test_string_array_with_exact_length() {
+        let schema = json!({
+            "type": "array",
+            "items": { "type": "string" },
+            "minItems": 3,
+            "maxItems": 3
+        });
+        let grammar = json_schema_to_gbnf(&schema).unwrap();
+
+        assert!(grammar.starts_with("root ::= root-1\n"));
+        assert_eq!(
+            rule(&grammar, "root-1"),
+            r#""[" space string ("," space string){2,2} "]" space"#
+        );
+        assert!(grammar.contains("string ::= "));
+        assert!(grammar.contains("char ::= "));
+    }
+
+    #[test]
+    fn test_object_required_and_optional() {
+        let schema = json!({
+            "type": "object",
+            "properties": {
+                "path": { "type": "string" },
+                "recursive": { "type": "boolean" }
+            },
+            "required": ["path"]
+        });
+        let grammar = json_schema_to_gbnf(&schema).unwrap();
+
+        assert_eq!(
+            rule(&grammar, "root-1"),
+            r#""{" space "\"path\"" space ":" space string ("," space root-opt-0)? "}" space"#
+        );
+        assert_eq!(
+            rule(&grammar, "root-opt-0"),
+            r#""\"recursive\"" space ":" space boolean"#
+        );
+    }
+
+    #[test]
+    fn test_object_required_order() {
+        let schema = json!({
+            "type": "object",
+            "properties": {
+                "a": { "type": "string" },
+                "z": { "type": "string" }
+            },
+            "required": ["z", "a"]
+        });
+        let grammar = json_schema_to_gbnf(&schema).unwrap();
+
+        assert_eq!(
+            rule(&grammar, "root-1"),
+            r#""{" space "\"z\"" space ":" space string "," space "\"a\"" space ":" space string "}" space"#
+        );
+    }
+
+    #[test]
+    fn test_object_all_optional() {
+        let schema = json!({
+            "type": "object",
+            "properties": {
+                "a": { "type": "integer" },
+                "b": { "type": "null" }
+            }
+        });
+        let grammar = json_schema_to_gbnf(&schema).unwrap();
+
+        assert_eq!(
+            rule(&grammar, "root-opt-0"),
+            r#""\"a\"" space ":" space integer ("," space root-opt-1)? | root-opt-1"#
+        );
+        assert_eq!(
+            rule(&grammar, "root-1"),
+            r#""{" space root-opt-0? "}" space"#
+        );
+    }
+
+    #[test]
+    fn test_const_enum_and_any_of() {
+        let schema = json!({
+            "anyOf": [
+                { "const": "read_file" },
+                { "enum": ["a", 1, null] }
+            ]
+        });
+        let grammar = json_schema_to_gbnf(&schema).unwrap();
+
+        assert_eq!(
+            rule(&grammar, "root-1"),
+            r#""\"read_file\"" space | ("\"a\"" | "1" | "null") space"#
+        );
+    }
+
+    #[test]
+    fn test_string_length_bounds() {
+        let schema = json!({ "type": "string", "maxLength": 10 });
+        let grammar = json_schema_to_gbnf(&schema).unwrap();
+        assert_eq!(rule(&grammar, "root-1"), r#""\"" char{0,10} "\"" space"#);
+    }
+
+    #[test]
+    fn test_unsupported_schemas() {
+        assert!(json_schema_to_gbnf(&json!({ "$ref": "#/defs/a" })).is_err());
+        assert!(json_schema_to_gbnf(&json!({ "type": "date" })).is_err());
+        assert!(json_schema_str_to_gbnf("not json").is_err());
+    }
+
+    #[test]
+    fn test_gbnf_literal_escapes() {
+        assert_eq!(gbnf_literal("a\"b\\c\n"), r#""a\"b\\c\n""#);
+    }
+}
@@ -0,0 +1,960 @@
+//! Approximate Nearest-Neighbor Index (HNSW)
+//!
+//! Hierarchical navigable small-world graph over embedding vectors:
+//! - Search visits O(log n) nodes instead of scanning every entry
+//! - Entries can be inserted and removed incrementally; removed entries stay
+//!   in the graph as routing-only nodes until the index is rebuilt
+//! - `m` and `ef` trade memory and build time for recall
+//! - Indexes are saved to and loaded from a compact binary file
+//! - A BM25 keyword index over the entries' full text (or their previews)
+//!   is kept alongside, for hybrid search
+//!
+//! Vectors are L2-normalized on insert, so distance is `1 - cosine`.
+
+use anyhow::{anyhow, bail, Result};
+use once_cell::sync::Lazy;
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use std::cmp::{Ordering, Reverse};
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::fs;
+use std::io::Write;
+use std::path::Path;
+use std::sync::atomic::{AtomicU64, Ordering as AtomicOrdering};
+use std::sync::Arc;
+
+use crate::embeddings::{EmbeddingEntry, NoteMetadata, SimilarityResult};
+use crate::hybrid_search::Bm25Index;
+use crate::vector_store::VectorStore;
+
+const MAGIC: &[u8; 8] = b"KVXHNSW1";
+/// Levels are capped so a degenerate random draw cannot blow up the graph
+const MAX_LEVEL: usize = 16;
+
+/// Graph parameters
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct HnswConfig {
+    /// Links per node on the upper layers; twice as many on layer 0 (default: 16)
+    pub m: u32,
+    /// Candidates considered while inserting (default: 200)
+    pub ef_construction: u32,
+    /// Candidates considered while searching (default: 64)
+    pub ef_search: u32,
+}
+
+impl Default for HnswConfig {
+    fn default() -> Self {
+        Self {
+            m: 16,
+            ef_construction: 200,
+            ef_search: 64,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Node {
+    id: String,
+    vector: Vec<f32>,
+    text_preview: Option<String>,
+    /// Text indexed for keyword search, when it is not the preview
+    keyword_text: Option<String>,
+    /// Note metadata, for filtered search
+    metadata: NoteMetadata,
+    /// Links on each layer from 0 up to the node's level
+    neighbors: Vec<Vec<u32>>,
+    deleted: bool,
+}
+
+/// A node and its distance to the query, ordered by distance
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Candidate {
+    distance: f32,
+    node: u32,
+}
+
+impl Eq for Candidate {}
+
+impl PartialOrd for Candidate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Candidate {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.distance
+            .total_cmp(&other.distance)
+            .then(self.node.cmp(&other.node))
+    }
+}
+
+/// HNSW index over embedding entries
+#[derive(Debug, Clone)]
+pub struct HnswIndex {
+    config: HnswConfig,
+    dimension: usize,
+    nodes: Vec<Node>,
+    /// Live entries by id
+    ids: HashMap<String, u32>,
+    entry_point: Option<u32>,
+    max_level: usize,
+    rng_state: u64,
+    /// Keyword index over the text previews of live entries
+    lexical: Bm25Index,
+}
+
+impl HnswIndex {
+    /// Create an empty index
+    pub fn new(config: HnswConfig) -> Self {
+        Self {
+            config: HnswConfig {
+                m: config.m.max(2),
+                ef_construction: config.ef_construction.max(1),
+                ef_search: config.ef_search.max(1),
+            },
+            dimension: 0,
+            nodes: Vec::new(),
+            ids: HashMap::new(),
+            entry_point: None,
+            max_level: 0,
+            rng_state: 0x9e37_79b9_7f4a_7c15,
+            lexical: Bm25Index::new(),
+        }
+    }
+
+    /// Number of live entries
+    pub fn len(&self) -> usize {
+        self.ids.len()
+    }
+
+    /// Whether the index has no live entries
+    pub fn is_empty(&self) -> bool {
+        self.ids.is_empty()
+    }
+
+    /// Whether an entry with this id is indexed
+    pub fn contains(&self, id: &str) -> bool {
+        self.ids.contains_key(id)
+    }
+
+    /// Keyword index over the text previews of live entries
+    pub fn lexical(&self) -> &Bm25Index {
+        &self.lexical
+    }
+
+    /// Text preview of a live entry
+    pub fn text_preview(&self, id: &str) -> Option<String> {
+        let node = *self.ids.get(id)?;
+        self.nodes[node as usize].text_preview.clone()
+    }
+
+    /// Normalized vector of a live entry
+    pub fn vector(&self, id: &str) -> Option<&[f32]> {
+        let node = *self.ids.get(id)?;
+        Some(&self.nodes[node as usize].vector)
+    }
+
+    /// A live entry with its normalized vector
+    pub fn entry(&self, id: &str) -> Option<EmbeddingEntry> {
+        let node = &self.nodes[*self.ids.get(id)? as usize];
+        Some(EmbeddingEntry {
+            id: node.id.clone(),
+            vector: node.vector.clone(),
+            text_preview: node.text_preview.clone(),
+            metadata: node.metadata.clone(),
+        })
+    }
+
+    /// Live entries with their normalized vectors, in insertion order
+    pub fn entries(&self) -> Vec<EmbeddingEntry> {
+        self.nodes
+            .iter()
+            .filter(|node| !node.deleted)
+            .map(|node| EmbeddingEntry {
+                id: node.id.clone(),
+                vector: node.vector.clone(),
+                text_preview: node.text_preview.clone(),
+                metadata: node.metadata.clone(),
+            })
+            .collect()
+    }
+
+    /// Insert an entry, replacing any entry with the same id
+    ///
+    /// Keyword search sees the entry's `text_preview`.
+    pub fn insert(&mut self, entry: EmbeddingEntry) -> Result<()> {
+        self.insert_with_text(entry, None)
+    }
+
+    /// Insert an entry with the full text to index for keyword search
+    ///
+    /// # Arguments
+    /// * `entry` - The entry; replaces any entry with the same id
+    /// * `text` - Text for the keyword index (`None` = the `text_preview`)
+    pub fn insert_with_text(&mut self, entry: EmbeddingEntry, text: Option<String>) -> Result<()> {
+        if entry.vector.is_empty() {
+            bail!("Cannot index an empty vector for {}", entry.id);
+        }
+        if self.dimension == 0 {
+            self.dimension = entry.vector.len();
+        } else if entry.vector.len() != self.dimension {
+            bail!(
+                "Vector for {} has dimension {}, the index has {}",
+                entry.id,
+                entry.vector.len(),
+                self.dimension
+            );
+        }
+        self.remove(&entry.id);
+
+        let mut vector = entry.vector;
+        normalize(&mut vector);
+        let keyword_text = text.filter(|text| Some(text) != entry.text_preview.as_ref());
+        if let Some(text) = keyword_text.as_ref().or(entry.text_preview.as_ref()) {
+            self.lexical.insert(&entry.id, text);
+        }
+        let level = self.random_level();
+        let node = self.nodes.len() as u32;
+        self.nodes.push(Node {
+            id: entry.id.clone(),
+            vector,
+            text_preview: entry.text_preview,
+            keyword_text,
+            metadata: entry.metadata,
+            neighbors: vec![Vec::new(); level + 1],
+            deleted: false,
+        });
+        self.ids.insert(entry.id, node);
+
+        let Some(entry_point) = self.entry_point else {
+            self.entry_point = Some(node);
+            self.max_level = level;
+            return Ok(());
+        };
+
+        let query = self.nodes[node as usize].vector.clone();
+        let mut entry_points = vec![entry_point];
+        for layer in (level + 1..=self.max_level).rev() {
+            entry_points = vec![self.search_layer(&query, &entry_points, 1, layer, false)[0].node];
+        }
+        for layer in (0..=level.min(self.max_level)).rev() {
+            let found = self.search_layer(
+                &query,
+                &entry_points,
+                self.config.ef_construction as usize,
+                layer,
+                false,
+            );
+            let selected = self.select_neighbors(&found, self.max_links(layer));
+            for &neighbor in &selected {
+                self.link(neighbor, node, layer);
+            }
+            self.nodes[node as usize].neighbors[layer] = selected;
+            entry_points = found.iter().map(|c| c.node).collect();
+        }
+
+        if level > self.max_level {
+            self.entry_point = Some(node);
+            self.max_level = level;
+        }
+        Ok(())
+    }
+
+    /// Remove an entry by id
+    ///
+    /// The node keeps routing searches until removed nodes outnumber live
+    /// ones, at which point the index is rebuilt.
+    ///
+    /// # Returns
+    /// * `true` if the entry existed
+    pub fn remove(&mut self, id: &str) -> bool {
+        let Some(node) = self.ids.remove(id) else {
+            return false;
+        };
+        self.nodes[node as usize].deleted = true;
+        self.lexical.remove(id);
+        if self.nodes.len() - self.ids.len() > self.ids.len() {
+            self.rebuild();
+        }
+        true
+    }
+
+    /// Rebuild the graph from the live entries, dropping removed nodes
+    pub fn rebuild(&mut self) {
+        let entries = self.entries();
+        let mut rebuilt = Self::new(self.config);
+        rebuilt.rng_state = self.rng_state;
+        for entry in entries {
+            let text = self.nodes[self.ids[&entry.id] as usize]
+                .keyword_text
+                .clone();
+            // Vectors were validated when first inserted
+            let _ = rebuilt.insert_with_text(entry, text);
+        }
+        *self = rebuilt;
+    }
+
+    /// Find the entries most similar to a query vector
+    ///
+    /// # Arguments
+    /// * `query` - The query embedding vector
+    /// * `top_k` - Number of results to return
+    /// * `threshold` - Minimum cosine similarity
+    ///
+    /// # Returns
+    /// * Results sorted by score descending
+    pub fn search(&self, query: &[f32], top_k: usize, threshold: f32) -> Vec<SimilarityResult> {
+        let Some(entry_point) = self.entry_point else {
+            return Vec::new();
+        };
+        if query.len() != self.dimension || top_k == 0 {
+            return Vec::new();
+        }
+        let mut query = query.to_vec();
+        normalize(&mut query);
+
+        let mut entry_points = vec![entry_point];
+        for layer in (1..=self.max_level).rev() {
+            entry_points = vec![self.search_layer(&query, &entry_points, 1, layer, false)[0].node];
+        }
+        let ef = (self.config.ef_search as usize).max(top_k);
+        self.search_layer(&query, &entry_points, ef, 0, true)
+            .into_iter()
+            .map(|c| {
+                let node = &self.nodes[c.node as usize];
+                SimilarityResult {
+                    id: node.id.clone(),
+                    score: 1.0 - c.distance,
+                    text_preview: node.text_preview.clone(),
+                }
+            })
+            .filter(|r| r.score >= threshold)
+            .take(top_k)
+            .collect()
+    }
+
+    /// Save the index to a file
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let mut out = Vec::new();
+        out.extend_from_slice(MAGIC);
+        for value in [
+            self.config.m,
+            self.config.ef_construction,
+            self.config.ef_search,
+            self.dimension as u32,
+            self.max_level as u32,
+            self.entry_point.unwrap_or(u32::MAX),
+            self.nodes.len() as u32,
+        ] {
+            out.extend_from_slice(&value.to_le_bytes());
+        }
+        out.extend_from_slice(&self.rng_state.to_le_bytes());
+
+        for node in &self.nodes {
+            write_str(&mut out, &node.id);
+            match &node.text_preview {
+                Some(preview) => {
+                    out.push(1);
+                    write_str(&mut out, preview);
+                }
+                None => out.push(0),
+            }
+            match &node.keyword_text {
+                Some(text) => {
+                    out.push(1);
+                    write_str(&mut out, text);
+                }
+                None => out.push(0),
+            }
+            write_str(&mut out, &serde_json::to_string(&node.metadata)?);
+            out.push(node.deleted as u8);
+            for value in &node.vector {
+                out.extend_from_slice(&value.to_le_bytes());
+            }
+            out.extend_from_slice(&(node.neighbors.len() as u32).to_le_bytes());
+            for links in &node.neighbors {
+                out.extend_from_slice(&(links.len() as u32).to_le_bytes());
+                for link in links {
+                    out.extend_from_slice(&link.to_le_bytes());
+                }
+            }
+        }
+
+        let tmp_path = path.with_extension("tmp");
+        let mut file = fs::File::create(&tmp_path)?;
+        file.write_all(&out)?;
+        file.sync_all()?;
+        fs::rename(&tmp_path, path)?;
+        Ok(())
+    }
+
+    /// Load an index saved with `save`
+    pub fn load(path: &Path) -> Result<Self> {
+        let bytes = fs::read(path)?;
+        if !bytes.starts_with(MAGIC) {
+            bail!("{} is not an HNSW index", path.display());
+        }
+        let mut reader = Reader::new(&bytes, MAGIC.len());
+
+        let config = HnswConfig {
+            m: reader.u32()?,
+            ef_construction: reader.u32()?,
+            ef_search: reader.u32()?,
+        };
+        let dimension = reader.u32()? as usize;
+        let max_level = reader.u32()? as usize;
+        if max_level > MAX_LEVEL {
+            bail!("Corrupt HNSW index: {} levels", max_level);
+        }
+        let entry_point = Some(reader.u32()?).filter(|&node| node != u32::MAX);
+        let node_count = reader.u32()? as usize;
+        let rng_state = reader.u64()?;
+        // Id, preview flag, keyword text flag, metadata, deleted flag, vector
+        // and one level
+        let min_node_len = dimension
+            .checked_mul(4)
+            .and_then(|len| len.checked_add(19))
+            .ok_or_else(|| anyhow!("Corrupt HNSW index: dimension {}", dimension))?;
+        reader.ensure(node_count, min_node_len)?;
+
+        let mut nodes = Vec::with_capacity(node_count);
+        let mut ids = HashMap::new();
+        let mut lexical = Bm25Index::new();
+        for index in 0..node_count {
+            let id = reader.string()?;
+            let text_preview = match reader.u8()? {
+                0 => None,
+                _ => Some(reader.string()?),
+            };
+            let keyword_text = match reader.u8()? {
+                0 => None,
+                _ => Some(reader.string()?),
+            };
+            let metadata: NoteMetadata = serde_json::from_str(&reader.string()?)?;
+            let deleted = reader.u8()? != 0;
+            let vector = (0..dimension)
+                .map(|_| reader.u32().map(f32::from_bits))
+                .collect::<Result<Vec<f32>>>()?;
+            let levels = reader.u32()? as usize;
+            if levels == 0 || levels > MAX_LEVEL + 1 {
+                bail!("Corrupt HNSW index: node {} has {} levels", index, levels);
+            }
+            let mut neighbors = Vec::with_capacity(levels);
+            for _ in 0..levels {
+                let count = reader.u32()? as usize;
+                reader.ensure(count, 4)?;
+                let links = (0..count)
+                    .map(|_| reader.u32())
+                    .collect::<Result<Vec<u32>>>()?;
+                if links.iter().any(|&link| link as usize >= node_count) {
+                    bail!("Corrupt HNSW index: link out of range");
+                }
+                neighbors.push(links);
+            }
+            if !deleted {
+                ids.insert(id.clone(), index as u32);
+                if let Some(text) = keyword_text.as_ref().or(text_preview.as_ref()) {
+                    lexical.insert(&id, text);
+                }
+            }
+            nodes.push(Node {
+                id,
+                vector,
+                text_preview,
+                keyword_text,
+                metadata,
+                neighbors,
+                deleted,
+            });
+        }
+        if entry_point.is_some_and(|node| node as usize >= node_count) {
+            bail!("Corrupt HNSW index: entry point out of range");
+        }
+
+        Ok(Self {
+            config,
+            dimension,
+            nodes,
+            ids,
+            entry_point,
+            max_level,
+            rng_state,
+            lexical,
+        })
+    }
+
+    /// Best `ef` nodes on a layer, nearest first
+    ///
+    /// With `live_only`, removed nodes are still traversed but not returned,
+    /// so tombstones do not take result slots.
+    fn search_layer(
+        &self,
+        query: &[f32],
+        entry_points: &[u32],
+        ef: usize,
+        layer: usize,
+        live_only: bool,
+    ) -> Vec<Candidate> {
+        let returned = |node: u32| !live_only || !self.nodes[node as usize].deleted;
+        let mut visited: HashSet<u32> = entry_points.iter().copied().collect();
+        let mut candidates = BinaryHeap::new();
+        let mut results = BinaryHeap::new();
+        for &node in entry_points {
+            let candidate = Candidate {
+                distance: distance(query, &self.nodes[node as usize].vector),
+                node,
+            };
+            candidates.push(Reverse(candidate));
+            if returned(node) {
+                results.push(candidate);
+            }
+        }
+
+        while let Some(Reverse(current)) = candidates.pop() {
+            let worst = results.peek().map_or(f32::MAX, |c: &Candidate| c.distance);
+            if current.distance > worst && results.len() >= ef {
+                break;
+            }
+            let Some(links) = self.nodes[current.node as usize].neighbors.get(layer) else {
+                continue;
+            };
+            for &neighbor in links {
+                if !visited.insert(neighbor) {
+                    continue;
+                }
+                let candidate = Candidate {
+                    distance: distance(query, &self.nodes[neighbor as usize].vector),
+                    node: neighbor,
+                };
+                let worst = results.peek().map_or(f32::MAX, |c| c.distance);
+                if results.len() < ef || candidate.distance < worst {
+                    candidates.push(Reverse(candidate));
+                    if returned(neighbor) {
+                        results.push(candidate);
+                        if results.len() > ef {
+                            results.pop();
+                        }
+                    }
+                }
+            }
+        }
+        results.into_sorted_vec()
+    }
+
+    /// Pick up to `m` diverse neighbors from candidates sorted nearest first
+    ///
+    /// A candidate closer to an already selected neighbor than to the new
+    /// node is skipped at first, which keeps links spread across clusters;
+    /// skipped candidates fill any remaining slots.
+    fn select_neighbors(&self, candidates: &[Candidate], m: usize) -> Vec<u32> {
+        let mut selected: Vec<Candidate> = Vec::with_capacity(m);
+        let mut skipped = Vec::new();
+        for &candidate in candidates {
+            if selected.len() >= m {
+                break;
+            }
+            let vector = &self.nodes[candidate.node as usize].vector;
+            let diverse = selected.iter().all(|s| {
+                distance(vector, &self.nodes[s.node as usize].vector) > candidate.distance
+            });
+            if diverse {
+                selected.push(candidate);
+            } else {
+                skipped.push(candidate);
+            }
+        }
+        let remaining = m.saturating_sub(selected.len());
+        selected.extend(skipped.into_iter().take(remaining));
+        selected.into_iter().map(|c| c.node).collect()
+    }
+
+    /// Add a link from `from` to `to`, pruning `from`'s links if needed
+    fn link(&mut self, from: u32, to: u32, layer: usize) {
+        let max_links = self.max_links(layer);
+        let links = &mut self.nodes[from as usize].neighbors[layer];
+        links.push(to);
+        if links.len() <= max_links {
+            return;
+        }
+
+        let vector = &self.nodes[from as usize].vector;
+        let mut candidates: Vec<Candidate> = self.nodes[from as usize].neighbors[layer]
+            .iter()
+            .map(|&node| Candidate {
+                distance: distance(vector, &self.nodes[node as usize].vector),
+                node,
+            })
+            .collect();
+        candidates.sort();
+        let pruned = self.select_neighbors(&candidates, max_links);
+        self.nodes[from as usize].neighbors[layer] = pruned;
+    }
+
+    fn max_links(&self, layer: usize) -> usize {
+        let m = self.config.m as usize;
+        if layer == 0 {
+            m * 2
+        } else {
+            m
+        }
+    }
+
+    /// Level for a new node: geometric with ratio `1/m`
+    fn random_level(&mut self) -> usize {
+        // xorshift64*
+        self.rng_state ^= self.rng_state >> 12;
+        self.rng_state ^= self.rng_state << 25;
+        self.rng_state ^= self.rng_state >> 27;
+        let bits = self.rng_state.wrapping_mul(0x2545_f491_4f6c_dd1d) >> 11;
+        let uniform = (bits as f64 + 1.0) / (1u64 << 53) as f64;
+        let level = -uniform.ln() / (self.config.m as f64).ln();
+        (level as usize).min(MAX_LEVEL)
+    }
+}
+
+fn distance(a: &[f32], b: &[f32]) -> f32 {
+    1.0 - a.iter().zip(b).map(|(x, y)| x * y).sum::<f32>()
+}
+
+fn normalize(vector: &mut [f32]) {
+    let norm = vector.iter().map(|x| x * x).sum::<f32>().sqrt();
+    if norm > 0.0 {
+        vector.iter_mut().for_each(|x| *x /= norm);
+    }
+}
+
+/// Write a length-prefixed string
+pub(crate) fn write_str(out: &mut Vec<u8>, text: &str) {
+    out.extend_from_slice(&(text.len() as u32).to_le_bytes());
+    out.extend_from_slice(text.as_bytes());
+}
+
+/// Little-endian reader that fails on truncated input
+pub(crate) struct Reader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    /// Read `bytes` from offset `pos`
+    pub(crate) fn new(bytes: &'a [u8], pos: usize) -> Self {
+        Self { bytes, pos }
+    }
+
+    pub(crate) fn take(&mut self, len: usize) -> Result<&'a [u8]> {
+        let slice = self
+            .pos
+            .checked_add(len)
+            .and_then(|end| self.bytes.get(self.pos..end))
+            .ok_or_else(|| anyhow!("Truncated index file"))?;
+        self.pos += len;
+        Ok(slice)
+    }
+
+    /// Fail unless the remaining input can hold `count` items of at least
+    /// `item_len` bytes, so a corrupt count cannot trigger a huge allocation
+    pub(crate) fn ensure(&self, count: usize, item_len: usize) -> Result<()> {
+        let remaining = self.bytes.len() - self.pos;
+        if count
+            .checked_mul(item_len)
+            .is_none_or(|len| len > remaining)
+        {
+            bail!("Truncated index file");
+        }
+        Ok(())
+    }
+
+    pub(crate) fn u8(&mut self) -> Result<u8> {
+        Ok(self.take(1)?[0])
+    }
+
+    pub(crate) fn u32(&mut self) -> Result<u32> {
+        Ok(u32::from_le_bytes(self.take(4)?.try_into()?))
+    }
+
+    pub(crate) fn u64(&mut self) -> Result<u64> {
+        Ok(u64::from_le_bytes(self.take(8)?.try_into()?))
+    }
+
+    pub(crate) fn string(&mut self) -> Result<String> {
+        let len = self.u32()? as usize;
+        Ok(String::from_utf8(self.take(len)?.to_vec())?)
+    }
+}
+
+/// Open indexes by id
+static INDEXES: Lazy<Mutex<HashMap<u64, Arc<Mutex<HnswIndex>>>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+static NEXT_INDEX_ID: AtomicU64 = AtomicU64::new(1);
+
+fn register_index(index: HnswIndex) -> u64 {
+    let id = NEXT_INDEX_ID.fetch_add(1, AtomicOrdering::Relaxed);
+    INDEXES.lock().insert(id, Arc::new(Mutex::new(index)));
+    id
+}
+
+/// An open index by id
+pub(crate) fn find_index(index_id: u64) -> Result<Arc<Mutex<HnswIndex>>> {
+    INDEXES
+        .lock()
+        .get(&index_id)
+        .cloned()
+        .ok_or_else(|| anyhow!("Unknown HNSW index: {}", index_id))
+}
+
+/// Create an empty index
+///
+/// # Returns
+/// * Index id for the other index functions
+pub fn create_index(config: Option<HnswConfig>) -> u64 {
+    register_index(HnswIndex::new(config.unwrap_or_default()))
+}
+
+/// Load an index from a file
+///
+/// # Returns
+/// * Index id for the other index functions
+pub fn load_index(path: &Path) -> Result<u64> {
+    Ok(register_index(HnswIndex::load(path)?))
+}
+
+/// Save an open index to a file
+pub fn save_index(index_id: u64, path: &Path) -> Result<()> {
+    find_index(index_id)?.lock().save(path)
+}
+
+/// Close an index and free its memory
+///
+/// # Returns
+/// * `true` if the index existed
+pub fn close_index(index_id: u64) -> bool {
+    INDEXES.lock().remove(&index_id).is_some()
+}
+
+/// Insert or replace entries in an index
+pub fn index_insert(index_id: u64, entries: Vec<EmbeddingEntry>) -> Result<()> {
+    let index = find_index(index_id)?;
+    let mut index = index.lock();
+    for entry in entries {
+        index.insert(entry)?;
+    }
+    Ok(())
+}
+
+/// Insert or replace the entries of a vector store in an index
+///
+/// Entries are read from the store one at a time.
+pub fn index_insert_stored(index_id: u64, store: &VectorStore) -> Result<()> {
+    let index = find_index(index_id)?;
+    let mut index = index.lock();
+    store.for_each_vector(|id, vector, preview, metadata| {
+        index.insert(EmbeddingEntry {
+            id: id.to_string(),
+            vector: vector.to_vec(),
+            text_preview: preview.cloned(),
+            metadata: metadata.clone(),
+        })
+    })
+}
+
+/// Insert or replace entries in an index, with the full text of each for
+/// keyword search
+///
+/// # Arguments
+/// * `entries` - Entries to insert
+/// * `texts` - Full text of each entry, in the same order
+pub fn index_insert_with_texts(
+    index_id: u64,
+    entries: Vec<EmbeddingEntry>,
+    texts: Vec<String>,
+) -> Result<()> {
+    if entries.len() != texts.len() {
+        bail!("{} entries but {} texts", entries.len(), texts.len());
+    }
+    let index = find_index(index_id)?;
+    let mut index = index.lock();
+    for (entry, text) in entries.into_iter().zip(texts) {
+        index.insert_with_text(entry, Some(text))?;
+    }
+    Ok(())
+}
+
+/// Remove entries from an index
+///
+/// # Returns
+/// * Number of entries that were removed
+pub fn index_remove(index_id: u64, ids: &[String]) -> Result<usize> {
+    let index = find_index(index_id)?;
+    let mut index = index.lock();
+    Ok(ids.iter().filter(|id| index.remove(id)).count())
+}
+
+/// Number of live entries in an index
+pub fn index_len(index_id: u64) -> Result<usize> {
+    Ok(find_index(index_id)?.lock().len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::embeddings::find_similar;
+
+    /// Deterministic pseudo-random unit vectors
+    fn random_entries(count: usize, dimension: usize) -> Vec<EmbeddingEntry> {
+        let mut state = 42u64;
+        (0..count)
+            .map(|i| {
+                let vector = (0..dimension)
+                    .map(|_| {
+                        state = state.wrapping_mul(6364136223846793005).wrapping_add(1);
+                        ((state >> 33) as f32 / (1u64 << 31) as f32) - 0.5
+                    })
+                    .collect();
+                EmbeddingEntry {
+                    id: format!("n{}", i),
+                    vector,
+                    text_preview: None,
+                    metadata: NoteMetadata::default(),
+                }
+            })
+            .collect()
+    }
+
+    fn build(entries: &[EmbeddingEntry]) -> HnswIndex {
+        let mut index = HnswIndex::new(HnswConfig::default());
+        for entry in entries {
+            index.insert(entry.clone()).unwrap();
+        }
+        index
+    }
+
+    #[test]
+    fn test_recall_against_linear_scan() {
+        let entries = random_entries(500, 16);
+        let index = build(&entries);
+        assert_eq!(index.len(), 500);
+
+        let mut hits = 0;
+        for query in entries.iter().take(50) {
+            let exact: HashSet<String> = find_similar(&query.vector, &entries, 10, -1.0)
+                .into_iter()
+                .map(|r| r.id)
+                .collect();
+            let found = index.search(&query.vector, 10, -1.0);
+            assert_eq!(found[0].id, query.id);
+            hits += found.iter().filter(|r| exact.contains(&r.id)).count();
+        }
+        assert!(hits as f32 / 500.0 > 0.9, "recall too low: {}", hits);
+    }
+
+    #[test]
+    fn test_remove_and_replace() {
+        let entries = random_entries(100, 8);
+        let mut index = build(&entries);
+
+        assert!(index.remove("n3"));
+        assert!(!index.remove("n3"));
+        assert!(index
+            .search(&entries[3].vector, 5, -1.0)
+            .iter()
+            .all(|r| r.id != "n3"));
+
+        // Re-inserting an id replaces its vector
+        index
+            .insert(EmbeddingEntry {
+                id: "n4".to_string(),
+                vector: entries[7].vector.clone(),
+                text_preview: Some("moved".to_string()),
+                metadata: NoteMetadata::default(),
+            })
+            .unwrap();
+        assert_eq!(index.len(), 99);
+        let found = index.search(&entries[7].vector, 2, 0.99);
+        assert_eq!(found.len(), 2);
+        assert_eq!(index.lexical().search("moved", 5)[0].id, "n4");
+
+        // Tombstones do not take result slots
+        for entry in &entries[20..30] {
+            index.remove(&entry.id);
+        }
+        assert_eq!(index.search(&entries[25].vector, 10, -1.0).len(), 10);
+
+        // Removing most entries triggers a rebuild
+        for entry in &entries[10..] {
+            index.remove(&entry.id);
+        }
+        assert_eq!(index.len(), 9);
+        assert!(index.nodes.len() <= 2 * index.len() + 1);
+        assert_eq!(index.search(&entries[5].vector, 1, -1.0)[0].id, "n5");
+
+        let mismatched = EmbeddingEntry {
+            id: "bad".to_string(),
+            vector: vec![1.0; 3],
+            text_preview: None,
+            metadata: NoteMetadata::default(),
+        };
+        assert!(index.insert(mismatched).is_err());
+    }
+
+    #[test]
+    fn test_save_and_load() {
+        let entries = random_entries(200, 8);
+        let mut index = build(&entries);
+        index.remove("n0");
+        let metadata = NoteMetadata {
+            folder: Some("courses".to_string()),
+            tags: vec!["exam".to_string()],
+            ..NoteMetadata::default()
+        };
+        index
+            .insert(EmbeddingEntry {
+                metadata: metadata.clone(),
+                ..entries[1].clone()
+            })
+            .unwrap();
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("notes.hnsw");
+        index.save(&path).unwrap();
+        let loaded = HnswIndex::load(&path).unwrap();
+
+        assert_eq!(loaded.len(), 199);
+        assert!(!loaded.contains("n0"));
+        assert_eq!(loaded.entry("n1").unwrap().metadata, metadata);
+        let query = &entries[42].vector;
+        let ids = |results: Vec<SimilarityResult>| -> Vec<String> {
+            results.into_iter().map(|r| r.id).collect()
+        };
+        assert_eq!(
+            ids(loaded.search(query, 5, -1.0)),
+            ids(index.search(query, 5, -1.0))
+        );
+
+        // The full text given on insert is kept for keyword search
+        index
+            .insert_with_text(
+                EmbeddingEntry {
+                    text_preview: Some("short preview".to_string()),
+                    ..entries[2].clone()
+                },
+                Some("short preview of the lecture on eigenvalues".to_string()),
+            )
+            .unwrap();
+        index.save(&path).unwrap();
+        let loaded = HnswIndex::load(&path).unwrap();
+        assert_eq!(loaded.lexical().search("eigenvalues", 5)[0].id, "n2");
+        assert_eq!(loaded.text_preview("n2").as_deref(), Some("short preview"));
+
+        fs::write(&path, &fs::read(&path).unwrap()[..100]).unwrap();
+        assert!(HnswIndex::load(&path).is_err());
+
+        // A node count larger than the file can hold is rejected up front
+        index.save(&path).unwrap();
+        let mut corrupt = fs::read(&path).unwrap();
+        corrupt[32..36].copy_from_slice(&u32::MAX.to_le_bytes());
+        fs::write(&path, &corrupt).unwrap();
+        assert!(HnswIndex::load(&path).is_err());
+    }
+}
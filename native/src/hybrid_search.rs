@@ -0,0 +1,525 @@
+//! Hybrid Keyword + Vector Search
+//!
+//! Embeddings miss exact terms such as function names, people's names or
+//! course codes. This module adds a BM25 keyword index and fuses its ranking
+//! with the semantic one:
+//! - Reciprocal rank fusion, which needs no score calibration
+//! - A weighted sum of the cosine score and the normalized BM25 score
+//!
+//! Every hit reports the score and rank from each signal and the query terms
+//! it matched, for highlighting.
+
+use anyhow::Result;
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+
+use crate::embeddings::{self, EmbeddingEntry, SimilarityResult};
+use crate::hnsw::HnswIndex;
+
+/// How the two rankings are combined
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum FusionMode {
+    /// Sum of `weight / (rrf_k + rank)` over both rankings
+    #[default]
+    ReciprocalRank,
+    /// Weighted sum of the cosine score and the BM25 score scaled to 0..1
+    Weighted,
+}
+
+/// Settings for a hybrid search
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HybridOptions {
+    /// How the rankings are combined
+    pub fusion: FusionMode,
+    /// Weight of the semantic signal; the keyword signal gets the rest (default: 0.5)
+    pub semantic_weight: f32,
+    /// Rank offset for reciprocal rank fusion (default: 60)
+    pub rrf_k: f32,
+    /// Candidates taken from each ranking before fusion (default: 50)
+    pub candidates: usize,
+    /// Minimum cosine similarity for a semantic candidate (default: 0.0)
+    pub min_semantic_score: f32,
+}
+
+impl Default for HybridOptions {
+    fn default() -> Self {
+        Self {
+            fusion: FusionMode::ReciprocalRank,
+            semantic_weight: 0.5,
+            rrf_k: 60.0,
+            candidates: 50,
+            min_semantic_score: 0.0,
+        }
+    }
+}
+
+/// A keyword search result
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LexicalHit {
+    /// ID of the matched entry
+    pub id: String,
+    /// BM25 score
+    pub score: f32,
+    /// Query terms found in the entry
+    pub matched_terms: Vec<String>,
+}
+
+/// A hybrid search result
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HybridHit {
+    /// ID of the matched entry
+    pub id: String,
+    /// Fused score used for ranking
+    pub score: f32,
+    /// Cosine similarity, if the entry was a semantic candidate
+    pub semantic_score: Option<f32>,
+    /// 1-based rank in the semantic ranking
+    pub semantic_rank: Option<u32>,
+    /// BM25 score, if the entry matched a query term
+    pub lexical_score: Option<f32>,
+    /// 1-based rank in the keyword ranking
+    pub lexical_rank: Option<u32>,
+    /// Query terms found in the entry (lowercase)
+    pub matched_terms: Vec<String>,
+    /// Optional text preview
+    pub text_preview: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+struct Document {
+    id: String,
+    length: u32,
+    terms: Vec<String>,
+}
+
+/// BM25 inverted index over entry texts
+#[derive(Debug, Clone)]
+pub struct Bm25Index {
+    /// Term frequency saturation (default: 1.2)
+    k1: f32,
+    /// Length normalization (default: 0.75)
+    b: f32,
+    documents: HashMap<u32, Document>,
+    ids: HashMap<String, u32>,
+    /// Term -> (document, term frequency)
+    postings: HashMap<String, HashMap<u32, u32>>,
+    total_length: u64,
+    next_document: u32,
+}
+
+impl Default for Bm25Index {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Bm25Index {
+    /// Create an empty index with the usual BM25 parameters
+    pub fn new() -> Self {
+        Self {
+            k1: 1.2,
+            b: 0.75,
+            documents: HashMap::new(),
+            ids: HashMap::new(),
+            postings: HashMap::new(),
+            total_length: 0,
+            next_document: 0,
+        }
+    }
+
+    /// Build an index from the text previews of embedding entries
+    pub fn from_entries(entries: &[EmbeddingEntry]) -> Self {
+        let mut index = Self::new();
+        for entry in entries {
+            if let Some(text) = &entry.text_preview {
+                index.insert(&entry.id, text);
+            }
+        }
+        index
+    }
+
+    /// Number of indexed documents
+    pub fn len(&self) -> usize {
+        self.documents.len()
+    }
+
+    /// Whether the index has no documents
+    pub fn is_empty(&self) -> bool {
+        self.documents.is_empty()
+    }
+
+    /// Index a document, replacing any document with the same id
+    pub fn insert(&mut self, id: &str, text: &str) {
+        self.remove(id);
+
+        let tokens = terms(text);
+        let mut frequencies: HashMap<String, u32> = HashMap::new();
+        for token in &tokens {
+            *frequencies.entry(token.clone()).or_insert(0) += 1;
+        }
+
+        let document = self.next_document;
+        self.next_document += 1;
+        for (term, frequency) in &frequencies {
+            self.postings
+                .entry(term.clone())
+                .or_default()
+                .insert(document, *frequency);
+        }
+        self.total_length += tokens.len() as u64;
+        self.ids.insert(id.to_string(), document);
+        self.documents.insert(
+            document,
+            Document {
+                id: id.to_string(),
+                length: tokens.len() as u32,
+                terms: frequencies.into_keys().collect(),
+            },
+        );
+    }
+
+    /// Remove a document
+    ///
+    /// # Returns
+    /// * `true` if the document existed
+    pub fn remove(&mut self, id: &str) -> bool {
+        let Some(document) = self.ids.remove(id) else {
+            return false;
+        };
+        let Some(removed) = self.documents.remove(&document) else {
+            return false;
+        };
+        for term in &removed.terms {
+            if let Some(posting) = self.postings.get_mut(term) {
+                posting.remove(&document);
+                if posting.is_empty() {
+                    self.postings.remove(term);
+                }
+            }
+        }
+        self.total_length -= removed.length as u64;
+        true
+    }
+
+    /// Rank documents by BM25 score for a query
+    ///
+    /// # Arguments
+    /// * `query` - Free-text query
+    /// * `top_k` - Number of results to return
+    ///
+    /// # Returns
+    /// * Matching documents sorted by score descending
+    pub fn search(&self, query: &str, top_k: usize) -> Vec<LexicalHit> {
+        if self.documents.is_empty() {
+            return Vec::new();
+        }
+        let n = self.documents.len() as f32;
+        let average_length = (self.total_length as f32 / n).max(1.0);
+
+        let mut seen = HashSet::new();
+        let mut scores: HashMap<u32, (f32, Vec<String>)> = HashMap::new();
+        for term in terms(query) {
+            if !seen.insert(term.clone()) {
+                continue;
+            }
+            let Some(posting) = self.postings.get(&term) else {
+                continue;
+            };
+            let df = posting.len() as f32;
+            let idf = (1.0 + (n - df + 0.5) / (df + 0.5)).ln();
+            for (&document, &frequency) in posting {
+                let length = self.documents[&document].length as f32;
+                let tf = frequency as f32;
+                let norm = self.k1 * (1.0 - self.b + self.b * length / average_length);
+                let entry = scores.entry(document).or_default();
+                entry.0 += idf * tf * (self.k1 + 1.0) / (tf + norm);
+                entry.1.push(term.clone());
+            }
+        }
+
+        let mut hits: Vec<LexicalHit> = scores
+            .into_iter()
+            .map(|(document, (score, matched_terms))| LexicalHit {
+                id: self.documents[&document].id.clone(),
+                score,
+                matched_terms,
+            })
+            .collect();
+        hits.sort_by(|a, b| b.score.total_cmp(&a.score).then_with(|| a.id.cmp(&b.id)));
+        hits.truncate(top_k);
+        hits
+    }
+}
+
+/// Lowercase search terms: runs of letters, digits and underscores
+///
+/// Identifiers such as `parse_tool_call` and codes such as `CS101` stay whole.
+pub fn terms(text: &str) -> Vec<String> {
+    text.split(|c: char| !(c.is_alphanumeric() || c == '_'))
+        .filter(|term| !term.is_empty())
+        .map(str::to_lowercase)
+        .collect()
+}
+
+/// Hybrid search over entries: embed the query, rank the entries both ways
+/// and fuse the rankings
+///
+/// The keyword index is built from the previews on every call. For repeated
+/// queries over the same notes, insert them into an `HnswIndex` (with their
+/// full text) and use `hybrid_search_indexed`, which keeps its index.
+///
+/// # Arguments
+/// * `query_text` - The search query text
+/// * `entries` - Entries to search; keywords are matched in `text_preview`
+/// * `top_k` - Number of results to return
+/// * `options` - Fusion settings
+///
+/// # Returns
+/// * Hits sorted by fused score
+pub fn hybrid_search(
+    query_text: &str,
+    entries: &[EmbeddingEntry],
+    top_k: usize,
+    options: &HybridOptions,
+) -> Result<Vec<HybridHit>> {
+    let query = embeddings::embed_query(query_text)?;
+    let semantic = embeddings::find_similar(
+        &query,
+        entries,
+        options.candidates,
+        options.min_semantic_score,
+    );
+    let lexical = Bm25Index::from_entries(entries).search(query_text, options.candidates);
+
+    let previews: HashMap<&str, &Option<String>> = entries
+        .iter()
+        .map(|entry| (entry.id.as_str(), &entry.text_preview))
+        .collect();
+    let mut hits = fuse(&semantic, &lexical, top_k, options);
+    for hit in &mut hits {
+        if hit.text_preview.is_none() {
+            hit.text_preview = previews.get(hit.id.as_str()).and_then(|p| (*p).clone());
+        }
+    }
+    Ok(hits)
+}
+
+/// Hybrid search through an HNSW index and the keyword index kept with it
+///
+/// Keywords match the full text given to `HnswIndex::insert_with_text`, or
+/// the preview of entries inserted without one.
+///
+/// # Arguments
+/// * `query_text` - The search query text
+/// * `index` - Index over the entries to search
+/// * `top_k` - Number of results to return
+/// * `options` - Fusion settings
+///
+/// # Returns
+/// * Hits sorted by fused score
+pub fn hybrid_search_indexed(
+    query_text: &str,
+    index: &Mutex<HnswIndex>,
+    top_k: usize,
+    options: &HybridOptions,
+) -> Result<Vec<HybridHit>> {
+    // Embedding can take a while; the index is locked only for the lookups
+    let query = embeddings::embed_query(query_text)?;
+    let index = index.lock();
+    let semantic = index.search(&query, options.candidates, options.min_semantic_score);
+    let lexical = index.lexical().search(query_text, options.candidates);
+
+    let mut hits = fuse(&semantic, &lexical, top_k, options);
+    for hit in &mut hits {
+        if hit.text_preview.is_none() {
+            hit.text_preview = index.text_preview(&hit.id);
+        }
+    }
+    Ok(hits)
+}
+
+/// Combine a semantic and a keyword ranking
+///
+/// # Arguments
+/// * `semantic` - Semantic results, best first
+/// * `lexical` - Keyword results, best first
+/// * `top_k` - Number of results to return
+/// * `options` - Fusion mode and weights
+///
+/// # Returns
+/// * Hits sorted by fused score
+pub fn fuse(
+    semantic: &[SimilarityResult],
+    lexical: &[LexicalHit],
+    top_k: usize,
+    options: &HybridOptions,
+) -> Vec<HybridHit> {
+    let semantic_weight = options.semantic_weight.clamp(0.0, 1.0);
+    let lexical_weight = 1.0 - semantic_weight;
+    let max_lexical = lexical.iter().map(|hit| hit.score).fold(0.0f32, f32::max);
+
+    let mut hits: Vec<HybridHit> = Vec::new();
+    let mut positions: HashMap<String, usize> = HashMap::new();
+    let mut hit_for = |id: &str, hits: &mut Vec<HybridHit>| -> usize {
+        *positions.entry(id.to_string()).or_insert_with(|| {
+            hits.push(HybridHit {
+                id: id.to_string(),
+                score: 0.0,
+                semantic_score: None,
+                semantic_rank: None,
+                lexical_score: None,
+                lexical_rank: None,
+                matched_terms: Vec::new(),
+                text_preview: None,
+            });
+            hits.len() - 1
+        })
+    };
+
+    for (rank, result) in semantic.iter().enumerate() {
+        let i = hit_for(&result.id, &mut hits);
+        let hit = &mut hits[i];
+        hit.semantic_score = Some(result.score);
+        hit.semantic_rank = Some(rank as u32 + 1);
+        hit.text_preview = result.text_preview.clone();
+        hit.score += semantic_weight
+            * match options.fusion {
+                FusionMode::ReciprocalRank => 1.0 / (options.rrf_k + rank as f32 + 1.0),
+                FusionMode::Weighted => result.score.max(0.0),
+            };
+    }
+    for (rank, result) in lexical.iter().enumerate() {
+        let i = hit_for(&result.id, &mut hits);
+        let hit = &mut hits[i];
+        hit.lexical_score = Some(result.score);
+        hit.lexical_rank = Some(rank as u32 + 1);
+        hit.matched_terms = result.matched_terms.clone();
+        hit.score += lexical_weight
+            * match options.fusion {
+                FusionMode::ReciprocalRank => 1.0 / (options.rrf_k + rank as f32 + 1.0),
+                FusionMode::Weighted if max_lexical > 0.0 => result.score / max_lexical,
+                FusionMode::Weighted => 0.0,
+            };
+    }
+
+    hits.sort_by(|a, b| b.score.total_cmp(&a.score).then_with(|| a.id.cmp(&b.id)));
+    hits.truncate(top_k);
+    hits
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::{with_inference_backend, InferenceBackend, MockBackend};
+    use crate::embedder::EmbeddingKind;
+    use crate::embeddings::NoteMetadata;
+    use std::sync::Arc;
+
+    fn entry(backend: &MockBackend, id: &str, text: &str) -> EmbeddingEntry {
+        EmbeddingEntry {
+            id: id.to_string(),
+            vector: backend
+                .embed(&[text.to_string()], EmbeddingKind::Document)
+                .unwrap()
+                .remove(0),
+            text_preview: Some(text.to_string()),
+            metadata: NoteMetadata::default(),
+        }
+    }
+
+    #[test]
+    fn test_bm25_ranking_and_removal() {
+        let mut index = Bm25Index::new();
+        index.insert("a", "Call parse_tool_call on the reply");
+        index.insert("b", "The reply is parsed, then the reply is shown");
+        index.insert("c", "CS101 lecture notes");
+
+        assert_eq!(
+            terms("Call parse_tool_call, CS101!"),
+            vec!["call", "parse_tool_call", "cs101"]
+        );
+
+        let hits = index.search("parse_tool_call reply", 10);
+        assert_eq!(hits[0].id, "a");
+        assert_eq!(hits[0].matched_terms, vec!["parse_tool_call", "reply"]);
+        assert_eq!(hits.len(), 2);
+
+        assert_eq!(index.search("cs101", 10)[0].id, "c");
+        assert!(index.remove("c"));
+        assert!(index.search("cs101", 10).is_empty());
+        assert_eq!(index.len(), 2);
+
+        // Re-inserting replaces the old text
+        index.insert("a", "nothing relevant");
+        assert_eq!(index.search("parse_tool_call", 10).len(), 0);
+    }
+
+    #[test]
+    fn test_fusion_modes() {
+        let semantic = vec![
+            SimilarityResult {
+                id: "x".to_string(),
+                score: 0.9,
+                text_preview: None,
+            },
+            SimilarityResult {
+                id: "y".to_string(),
+                score: 0.8,
+                text_preview: None,
+            },
+        ];
+        let lexical = vec![
+            LexicalHit {
+                id: "y".to_string(),
+                score: 4.0,
+                matched_terms: vec!["cs101".to_string()],
+            },
+            LexicalHit {
+                id: "z".to_string(),
+                score: 2.0,
+                matched_terms: vec!["notes".to_string()],
+            },
+        ];
+
+        // y is ranked by both signals, so it wins under RRF
+        let hits = fuse(&semantic, &lexical, 10, &HybridOptions::default());
+        let ids: Vec<&str> = hits.iter().map(|h| h.id.as_str()).collect();
+        assert_eq!(ids, vec!["y", "x", "z"]);
+        assert_eq!(hits[0].semantic_rank, Some(2));
+        assert_eq!(hits[0].lexical_rank, Some(1));
+        assert_eq!(hits[0].matched_terms, vec!["cs101"]);
+        assert_eq!(hits[1].lexical_score, None);
+
+        // All weight on the semantic signal
+        let options = HybridOptions {
+            fusion: FusionMode::Weighted,
+            semantic_weight: 1.0,
+            ..HybridOptions::default()
+        };
+        let hits = fuse(&semantic, &lexical, 2, &options);
+        assert_eq!(hits[0].id, "x");
+        assert!((hits[0].score - 0.9).abs() < 1e-6);
+        assert_eq!(hits.len(), 2);
+    }
+
+    #[test]
+    fn test_hybrid_search_finds_exact_terms() {
+        let backend = Arc::new(MockBackend::new(64));
+        let entries = vec![
+            entry(&backend, "rust.md", "ownership and borrowing in rust"),
+            entry(&backend, "course.md", "MATH2040 linear algebra syllabus"),
+            entry(&backend, "garden.md", "planting tomatoes in spring"),
+        ];
+
+        let hits = with_inference_backend(backend, || {
+            hybrid_search("when is math2040", &entries, 2, &HybridOptions::default())
+        })
+        .unwrap();
+
+        assert_eq!(hits[0].id, "course.md");
+        assert_eq!(hits[0].matched_terms, vec!["math2040"]);
+        assert!(hits[0].semantic_score.is_some());
+        assert!(hits[0].text_preview.is_some());
+    }
+}
@@ -3,19 +3,38 @@
 //! Provides GPU-accelerated inference using llama.cpp bindings.
 //! Supports multiple models (Phi-4, Qwen, Functionary) with automatic
 //! chat template selection based on model type.
-//! Supports text generation and embedding extraction.
+//! Supports text generation (blocking or token-by-token streaming) and
+//! embedding extraction.
 
 use anyhow::{anyhow, Result};
 use llama_cpp_2::context::params::LlamaContextParams;
+use llama_cpp_2::context::LlamaContext;
 use llama_cpp_2::llama_backend::LlamaBackend;
 use llama_cpp_2::llama_batch::LlamaBatch;
 use llama_cpp_2::model::params::LlamaModelParams;
 use llama_cpp_2::model::{AddBos, LlamaChatMessage, LlamaModel, Special};
-use llama_cpp_2::token::data_array::LlamaTokenDataArray;
-use parking_lot::Mutex;
+use llama_cpp_2::sampling::LlamaSampler;
+use llama_cpp_2::token::data::LlamaTokenData;
+use llama_cpp_2::token::LlamaToken;
+use once_cell::sync::{Lazy, OnceCell};
+use parking_lot::{Condvar, Mutex};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::num::NonZeroU32;
 use std::path::Path;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::mpsc;
+
+use crate::backend;
+use crate::context_window::{self, ContextPolicy, ContextReport};
+use crate::embedder::{self, EmbeddingKind};
+use crate::gguf::{self, GgufMetadata};
+use crate::grammar;
+use crate::reasoning::{self, ReasoningOptions, ReasoningSplitter};
+use crate::registry::ModelRegistry;
+use crate::session;
 
 /// Supported model types with different chat templates
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -28,6 +47,27 @@ pub enum ModelType {
     Functionary,
 }
 
+impl ModelType {
+    /// Model type for its code in the Dart API (0 = Phi4, 1 = Qwen, 2 = Functionary)
+    pub(crate) fn from_code(code: Option<i32>) -> Option<Self> {
+        match code? {
+            0 => Some(Self::Phi4),
+            1 => Some(Self::Qwen),
+            2 => Some(Self::Functionary),
+            _ => None,
+        }
+    }
+
+    /// Code of the model type in the Dart API
+    pub(crate) fn code(self) -> i32 {
+        match self {
+            Self::Phi4 => 0,
+            Self::Qwen => 1,
+            Self::Functionary => 2,
+        }
+    }
+}
+
 /// Configuration for inference
 #[derive(Debug, Clone)]
 pub struct InferenceConfig {
@@ -37,14 +77,12 @@ pub struct InferenceConfig {
     pub n_ctx: u32,
     /// Number of CPU threads for processing
     pub n_threads: i32,
-    /// Temperature for sampling (0.0 = deterministic, 1.0 = creative)
-    pub temperature: f32,
-    /// Top-p sampling (nucleus sampling)
-    pub top_p: f32,
+    /// Default sampling parameters for requests that do not override them
+    pub sampler: SamplerConfig,
     /// Maximum tokens to generate
     pub max_tokens: u32,
-    /// Model type for chat template selection
-    pub model_type: ModelType,
+    /// Model type for chat template selection (`None` = detect from GGUF metadata)
+    pub model_type: Option<ModelType>,
 }
 
 impl Default for InferenceConfig {
@@ -53,24 +91,298 @@ impl Default for InferenceConfig {
             n_gpu_layers: 99, // Offload everything to GPU
             n_ctx: 4096,      // Reasonable context for most tasks
             n_threads: 4,     // Use 4 CPU threads
+            sampler: SamplerConfig::default(),
+            max_tokens: 512,
+            model_type: None, // Detected when the model is loaded
+        }
+    }
+}
+
+/// llama.cpp treats this seed as "pick a random seed"
+const LLAMA_DEFAULT_SEED: u32 = u32::MAX;
+
+/// Sampling parameters applied at every decode step
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SamplerConfig {
+    /// Temperature for sampling (0.0 = deterministic, 1.0 = creative)
+    pub temperature: f32,
+    /// Keep only the k most likely tokens (0 = disabled)
+    pub top_k: i32,
+    /// Top-p sampling (nucleus sampling, 1.0 = disabled)
+    pub top_p: f32,
+    /// Drop tokens less likely than `min_p` times the most likely one (0.0 = disabled)
+    pub min_p: f32,
+    /// Penalty for repeating recent tokens (1.0 = disabled)
+    pub repeat_penalty: f32,
+    /// Penalty scaled by how often a token already appeared (0.0 = disabled)
+    pub frequency_penalty: f32,
+    /// Flat penalty for any token that already appeared (0.0 = disabled)
+    pub presence_penalty: f32,
+    /// Number of recent tokens the penalties look at (-1 = whole context)
+    pub penalty_last_n: i32,
+    /// Seed for reproducible output (None = random)
+    pub seed: Option<u32>,
+    /// Always pick the most likely token, ignoring temperature and truncation
+    pub greedy: bool,
+}
+
+impl Default for SamplerConfig {
+    fn default() -> Self {
+        Self {
             temperature: 0.7,
+            top_k: 40,
             top_p: 0.9,
-            max_tokens: 512,
-            model_type: ModelType::Phi4, // Default to Phi-4 for compatibility
+            min_p: 0.05,
+            repeat_penalty: 1.0,
+            frequency_penalty: 0.0,
+            presence_penalty: 0.0,
+            penalty_last_n: 64,
+            seed: None,
+            greedy: false,
         }
     }
 }
 
+/// One step of the sampler chain, in the order it is applied
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum SamplerStage {
+    Penalties {
+        last_n: i32,
+        repeat: f32,
+        frequency: f32,
+        presence: f32,
+    },
+    TopK(i32),
+    TopP(f32),
+    MinP(f32),
+    Temperature(f32),
+    Distribution(u32),
+    Greedy,
+}
+
+impl SamplerConfig {
+    /// Deterministic sampling that always picks the most likely token
+    pub fn greedy() -> Self {
+        Self {
+            greedy: true,
+            ..Self::default()
+        }
+    }
+
+    /// Resolve the config into the stages of the sampler chain
+    ///
+    /// Disabled stages are left out, and a non-positive temperature is
+    /// treated as greedy decoding. A negative `penalty_last_n` becomes `n_ctx`,
+    /// since llama.cpp itself would clamp it to 0 and disable the penalties.
+    fn stages(&self, n_ctx: u32) -> Vec<SamplerStage> {
+        let mut stages = Vec::new();
+
+        if self.repeat_penalty != 1.0
+            || self.frequency_penalty != 0.0
+            || self.presence_penalty != 0.0
+        {
+            let last_n = if self.penalty_last_n < 0 {
+                i32::try_from(n_ctx).unwrap_or(i32::MAX)
+            } else {
+                self.penalty_last_n
+            };
+            stages.push(SamplerStage::Penalties {
+                last_n,
+                repeat: self.repeat_penalty,
+                frequency: self.frequency_penalty,
+                presence: self.presence_penalty,
+            });
+        }
+
+        if self.greedy || self.temperature <= 0.0 {
+            stages.push(SamplerStage::Greedy);
+            return stages;
+        }
+
+        if self.top_k > 0 {
+            stages.push(SamplerStage::TopK(self.top_k));
+        }
+        if self.top_p < 1.0 {
+            stages.push(SamplerStage::TopP(self.top_p));
+        }
+        if self.min_p > 0.0 {
+            stages.push(SamplerStage::MinP(self.min_p));
+        }
+        stages.push(SamplerStage::Temperature(self.temperature));
+        stages.push(SamplerStage::Distribution(
+            self.seed.unwrap_or(LLAMA_DEFAULT_SEED),
+        ));
+
+        stages
+    }
+
+    /// Build the llama.cpp sampler chain for a context of `n_ctx` tokens
+    fn build(&self, n_ctx: u32) -> LlamaSampler {
+        LlamaSampler::chain_simple(self.stages(n_ctx).into_iter().map(|stage| match stage {
+            SamplerStage::Penalties {
+                last_n,
+                repeat,
+                frequency,
+                presence,
+            } => LlamaSampler::penalties(last_n, repeat, frequency, presence),
+            SamplerStage::TopK(k) => LlamaSampler::top_k(k),
+            SamplerStage::TopP(p) => LlamaSampler::top_p(p, 1),
+            SamplerStage::MinP(p) => LlamaSampler::min_p(p, 1),
+            SamplerStage::Temperature(t) => LlamaSampler::temp(t),
+            SamplerStage::Distribution(seed) => LlamaSampler::dist(seed),
+            SamplerStage::Greedy => LlamaSampler::greedy(),
+        }))
+    }
+}
+
+/// Per-request generation settings
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GenerationOptions {
+    /// Maximum tokens to generate (defaults to the model config)
+    pub max_tokens: Option<u32>,
+    /// Strings that end generation when they appear in the output
+    pub stop_sequences: Vec<String>,
+    /// Sampling parameters (defaults to the model config)
+    pub sampler: Option<SamplerConfig>,
+    /// GBNF grammar the output must match
+    pub grammar: Option<String>,
+    /// JSON Schema the output must match (converted to a grammar)
+    pub json_schema: Option<String>,
+    /// Model to run on (defaults to the default model)
+    pub model_id: Option<String>,
+    /// How chat histories that exceed the context window are fitted
+    /// (`None` = fail with an error)
+    pub context: Option<ContextPolicy>,
+    /// How a reasoning trace is separated from the answer
+    /// (`None` = automatic for reasoning models such as DeepSeek-R1; always
+    /// off when a grammar or JSON Schema is set)
+    pub reasoning: Option<ReasoningOptions>,
+    /// Return per-token log-probabilities with this many alternatives each
+    /// (`None` = off, capped at `MAX_TOP_LOGPROBS`)
+    pub logprobs: Option<u32>,
+}
+
+impl GenerationOptions {
+    /// Options with only a token limit set
+    pub fn with_max_tokens(max_tokens: Option<u32>) -> Self {
+        Self {
+            max_tokens,
+            ..Self::default()
+        }
+    }
+
+    /// Resolve the grammar constraint, converting a JSON Schema if one was given
+    fn resolve_grammar(&self) -> Result<Option<String>> {
+        match (&self.grammar, &self.json_schema) {
+            (Some(_), Some(_)) => Err(anyhow!("Set either grammar or json_schema, not both")),
+            (Some(grammar), None) => Ok(Some(grammar.clone())),
+            (None, Some(schema)) => grammar::json_schema_str_to_gbnf(schema).map(Some),
+            (None, None) => Ok(None),
+        }
+    }
+
+    /// Reasoning separation to apply, if any
+    ///
+    /// Constrained output has no reasoning block: the grammar decides every
+    /// token, so a thinking trace can neither be split off nor closed early.
+    fn resolve_reasoning(&self, is_reasoning_model: bool) -> Option<ReasoningOptions> {
+        if self.grammar.is_some() || self.json_schema.is_some() {
+            return None;
+        }
+        match &self.reasoning {
+            Some(options) => options.enabled.then(|| options.clone()),
+            None => is_reasoning_model.then(ReasoningOptions::default),
+        }
+    }
+}
+
+/// Id used by `init_model`, which manages a single model like before the registry
+pub const DEFAULT_MODEL_ID: &str = "default";
+
 /// State of the AI model
-struct ModelState {
-    backend: LlamaBackend,
-    model: LlamaModel,
-    config: InferenceConfig,
+pub(crate) struct ModelState {
+    pub(crate) backend: &'static LlamaBackend,
+    pub(crate) model: LlamaModel,
+    pub(crate) config: InferenceConfig,
+    pub(crate) model_type: ModelType,
+    pub(crate) metadata: GgufMetadata,
+    pub(crate) model_path: String,
     model_hint: String,
 }
 
-/// Global AI state (singleton pattern)
-static AI_STATE: Mutex<Option<Arc<ModelState>>> = Mutex::new(None);
+impl ModelState {
+    /// Whether the model wraps its thinking in reasoning tags
+    fn is_reasoning_model(&self) -> bool {
+        reasoning::is_reasoning_model(self.metadata.chat_template.as_deref(), &self.model_hint)
+    }
+}
+
+/// Summary of a loaded model
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LoadedModelInfo {
+    /// Id the model was loaded under
+    pub model_id: String,
+    /// Path of the GGUF file
+    pub model_path: String,
+    /// Size of the model file, counted against the memory budget
+    pub size_bytes: u64,
+    /// Whether calls without a model id use this model
+    pub is_default: bool,
+}
+
+/// The llama.cpp backend can only be initialized once per process
+static BACKEND: OnceCell<LlamaBackend> = OnceCell::new();
+
+/// Loaded models keyed by id
+static MODELS: Lazy<Mutex<ModelRegistry<ModelState>>> =
+    Lazy::new(|| Mutex::new(ModelRegistry::default()));
+
+pub(crate) fn backend() -> Result<&'static LlamaBackend> {
+    BACKEND.get_or_try_init(|| {
+        LlamaBackend::init().map_err(|e| anyhow!("Failed to initialize backend: {:?}", e))
+    })
+}
+
+/// Take a reference to a loaded model without holding the registry lock
+///
+/// Long-running work must not keep the global lock, otherwise a single
+/// generation blocks embeddings and every other model call until it ends.
+///
+/// # Arguments
+/// * `model_id` - Model to use (`None` = the default model)
+pub(crate) fn current_state(model_id: Option<&str>) -> Result<Arc<ModelState>> {
+    MODELS.lock().get(model_id).ok_or_else(|| match model_id {
+        Some(id) => anyhow!("Model not loaded: {}", id),
+        None => anyhow!("Model not loaded"),
+    })
+}
+
+/// Id of the model used when calls give none
+pub(crate) fn default_model_id() -> Option<String> {
+    MODELS.lock().default_id().map(str::to_string)
+}
+
+/// Drop models removed from the registry, closing sessions that use them
+fn release_models(models: Vec<(String, Arc<ModelState>)>) {
+    for (model_id, state) in models {
+        session::close_chat_sessions_for_model(&state);
+        log::info!("Model {} unloaded", model_id);
+    }
+}
+
+/// Pick the prompt format for a model
+///
+/// An explicit type always wins. Otherwise the GGUF metadata decides, and
+/// the filename is only consulted when the metadata is inconclusive.
+fn resolve_model_type(
+    explicit: Option<ModelType>,
+    metadata: &GgufMetadata,
+    model_path: &str,
+) -> ModelType {
+    explicit
+        .or_else(|| metadata.model_type())
+        .unwrap_or_else(|| detect_model_type(model_path))
+}
 
 /// Detect model type from the model filename
 fn detect_model_type(model_path: &str) -> ModelType {
@@ -98,26 +410,77 @@ fn detect_model_type(model_path: &str) -> ModelType {
 /// * `Ok(())` if model loaded successfully
 /// * `Err(...)` if loading failed
 pub fn init_model(model_path: String, config: Option<InferenceConfig>) -> Result<()> {
-    let mut config = config.unwrap_or_default();
+    load_model(DEFAULT_MODEL_ID.to_string(), model_path, config)?;
+    MODELS.lock().set_default(DEFAULT_MODEL_ID)
+}
 
-    // Auto-detect model type from filename if not explicitly set
-    let detected_type = detect_model_type(&model_path);
-    config.model_type = detected_type;
+/// Load a model under an id, alongside any models already loaded
+///
+/// Least recently used models are evicted if the memory budget would be
+/// exceeded. A model already loaded under the same id is unloaded before the
+/// new one is read, so both are never resident at once.
+///
+/// # Arguments
+/// * `model_id` - Id used to address the model in later calls
+/// * `model_path` - Full path to the GGUF model file
+/// * `config` - Optional inference configuration (model_type auto-detected if not specified)
+pub fn load_model(
+    model_id: String,
+    model_path: String,
+    config: Option<InferenceConfig>,
+) -> Result<()> {
+    let config = config.unwrap_or_default();
+
+    // Read the header first: it is cheap and drives template selection
+    let metadata = gguf::inspect_gguf(Path::new(&model_path))?;
+    let model_type = resolve_model_type(config.model_type, &metadata, &model_path);
 
     log::info!(
-        "Initializing model from: {} (type: {:?})",
+        "Initializing model {} from: {} (type: {:?}, architecture: {:?})",
+        model_id,
         model_path,
-        config.model_type
+        model_type,
+        metadata.architecture
     );
 
-    // Initialize the llama.cpp backend
-    let backend = LlamaBackend::init()?;
+    // Reserve the budget under the registry lock before loading, so two
+    // concurrent loads cannot both claim the same free memory
+    let size_bytes = metadata.file_size;
+    let evicted = MODELS.lock().reserve(&model_id, size_bytes)?;
+    release_models(evicted);
+
+    let state = match read_model(model_path, config, model_type, metadata) {
+        Ok(state) => state,
+        Err(error) => {
+            MODELS.lock().cancel_reservation(&model_id);
+            return Err(error);
+        }
+    };
+
+    let replaced = MODELS
+        .lock()
+        .insert(model_id.clone(), Arc::new(state), size_bytes);
+    if let Some(replaced) = replaced {
+        release_models(vec![(model_id, replaced)]);
+    }
+
+    Ok(())
+}
+
+/// Load the weights of a model whose memory has been reserved
+fn read_model(
+    model_path: String,
+    config: InferenceConfig,
+    model_type: ModelType,
+    metadata: GgufMetadata,
+) -> Result<ModelState> {
+    let backend = backend()?;
 
     // Configure model parameters for GPU acceleration
     let model_params = LlamaModelParams::default().with_n_gpu_layers(config.n_gpu_layers);
 
     // Load the model from file
-    let model = LlamaModel::load_from_file(&backend, Path::new(&model_path), &model_params)
+    let model = LlamaModel::load_from_file(backend, Path::new(&model_path), &model_params)
         .map_err(|e| anyhow!("Failed to load model: {:?}", e))?;
 
     log::info!(
@@ -125,25 +488,91 @@ pub fn init_model(model_path: String, config: Option<InferenceConfig>) -> Result
         model.n_vocab()
     );
 
-    // Store state globally
-    *AI_STATE.lock() = Some(Arc::new(ModelState {
+    Ok(ModelState {
         backend,
         model,
         config,
-        model_hint: model_path.to_lowercase(),
-    }));
-
-    Ok(())
+        model_type,
+        model_hint: format!(
+            "{} {}",
+            model_path.to_lowercase(),
+            metadata.architecture.as_deref().unwrap_or_default()
+        ),
+        metadata,
+        model_path,
+    })
 }
 
-/// Check if the model is loaded and ready
+/// Check if the default model is loaded and ready
 pub fn is_model_loaded() -> bool {
-    AI_STATE.lock().is_some()
+    MODELS.lock().peek(None).is_some()
 }
 
-/// Get the currently loaded model type
+/// Get the default model's type
 pub fn get_model_type() -> Option<ModelType> {
-    AI_STATE.lock().as_ref().map(|s| s.config.model_type)
+    MODELS.lock().peek(None).map(|s| s.model_type)
+}
+
+/// Type of a loaded model (`None` id = the default model)
+pub fn get_model_type_by_id(model_id: Option<&str>) -> Option<ModelType> {
+    MODELS.lock().peek(model_id).map(|s| s.model_type)
+}
+
+/// Make a loaded model the default for calls without a model id
+pub fn set_default_model(model_id: &str) -> Result<()> {
+    MODELS.lock().set_default(model_id)
+}
+
+/// List loaded models, most recently used first
+pub fn list_loaded_models() -> Vec<LoadedModelInfo> {
+    let models = MODELS.lock();
+    let default_id = models.default_id();
+    models
+        .entries()
+        .into_iter()
+        .map(|(model_id, state, size_bytes)| LoadedModelInfo {
+            model_id: model_id.to_string(),
+            model_path: state.model_path.clone(),
+            size_bytes,
+            is_default: Some(model_id) == default_id,
+        })
+        .collect()
+}
+
+/// Find a loaded model of the given type, preferring the most recently used
+pub fn find_model_by_type(model_type: ModelType) -> Option<String> {
+    MODELS
+        .lock()
+        .entries()
+        .into_iter()
+        .find(|(_, state, _)| state.model_type == model_type)
+        .map(|(model_id, _, _)| model_id.to_string())
+}
+
+/// Limit the combined size of loaded models (`None` = unlimited)
+///
+/// Least recently used models are unloaded until the registry fits.
+pub fn set_model_memory_budget(budget_bytes: Option<u64>) {
+    let evicted = MODELS.lock().set_memory_budget(budget_bytes);
+    release_models(evicted);
+}
+
+/// Current memory use and budget of loaded models, in bytes
+pub fn get_model_memory_usage() -> (u64, Option<u64>) {
+    let models = MODELS.lock();
+    (models.memory_used(), models.memory_budget())
+}
+
+/// GGUF metadata of a loaded model
+///
+/// # Arguments
+/// * `model_id` - Model to query (`None` = the default model)
+pub fn get_model_metadata(model_id: Option<&str>) -> Result<GgufMetadata> {
+    let models = MODELS.lock();
+    let state = models
+        .peek(model_id)
+        .ok_or_else(|| anyhow!("Model not loaded"))?;
+    Ok(state.metadata.clone())
 }
 
 /// Initialize the Phi-4 model (backward-compatible alias for init_model)
@@ -162,182 +591,1051 @@ pub fn get_model_type() -> Option<ModelType> {
 pub fn init_phi4(model_path: String, config: Option<InferenceConfig>) -> Result<()> {
     // Set model type explicitly to Phi4 for backward compatibility
     let mut config = config.unwrap_or_default();
-    config.model_type = ModelType::Phi4;
+    config.model_type = Some(ModelType::Phi4);
     init_model(model_path, Some(config))
 }
 
-/// Unload the model and free resources
+/// Unload the model loaded by `init_model` and free its resources
+///
+/// Models loaded under other ids stay loaded; see `unload_model_by_id`.
 pub fn unload_model() {
-    *AI_STATE.lock() = None;
-    log::info!("Model unloaded");
+    unload_model_by_id(DEFAULT_MODEL_ID);
 }
 
-/// Get the model's embedding dimension
-pub fn get_embedding_dimension() -> Result<usize> {
-    let guard = AI_STATE.lock();
-    let state = guard.as_ref().ok_or_else(|| anyhow!("Model not loaded"))?;
-    Ok(state.model.n_embd() as usize)
+/// Unload a single model
+///
+/// # Returns
+/// * `true` if the model was loaded
+pub fn unload_model_by_id(model_id: &str) -> bool {
+    let removed = MODELS.lock().remove(model_id);
+    match removed {
+        Some(state) => {
+            release_models(vec![(model_id.to_string(), state)]);
+            true
+        }
+        None => false,
+    }
 }
 
-/// Generate text completion from a prompt
+/// Get a model's embedding dimension
 ///
 /// # Arguments
-/// * `prompt` - The input prompt
-/// * `max_tokens` - Optional override for max tokens to generate
+/// * `model_id` - Model to query (`None` = the default model)
+pub fn get_embedding_dimension(model_id: Option<&str>) -> Result<usize> {
+    let models = MODELS.lock();
+    let state = models
+        .peek(model_id)
+        .ok_or_else(|| anyhow!("Model not loaded"))?;
+    Ok(state.model.n_embd() as usize)
+}
+
+/// Why a generation loop stopped
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum StopReason {
+    /// The model emitted an end-of-generation token
+    EndOfGeneration,
+    /// The `max_tokens` limit was reached
+    MaxTokens,
+    /// One of the requested stop sequences appeared in the output
+    StopSequence,
+    /// The request was cancelled through its handle
+    Cancelled,
+}
+
+/// A piece of streamed generation output
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GenerationChunk {
+    /// Newly detokenized answer text (empty on the final chunk)
+    pub text: String,
+    /// Newly detokenized reasoning text (empty unless reasoning is separated)
+    pub reasoning: String,
+    /// Set only on the final chunk, reporting why generation stopped
+    pub stop_reason: Option<StopReason>,
+}
+
+/// Complete result of a generation loop
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GenerationResult {
+    /// The generated text (without any matched stop sequence or reasoning trace)
+    pub text: String,
+    /// The reasoning trace, when it was separated from the answer
+    pub reasoning: Option<String>,
+    /// Number of sampled tokens that belonged to the reasoning trace
+    pub reasoning_tokens: u32,
+    /// Why generation stopped
+    pub stop_reason: StopReason,
+    /// Number of tokens sampled from the model
+    pub tokens_generated: u32,
+    /// How a chat history was fitted into the context window (chat requests only)
+    pub context: Option<ContextReport>,
+    /// Every sampled token with its log-probability (when requested)
+    pub logprobs: Option<Vec<TokenLogprob>>,
+}
+
+impl GenerationResult {
+    /// Average log-probability of the sampled tokens, if logprobs were requested
+    pub fn mean_logprob(&self) -> Option<f32> {
+        let tokens = self.logprobs.as_ref().filter(|tokens| !tokens.is_empty())?;
+        Some(tokens.iter().map(|token| token.logprob).sum::<f32>() / tokens.len() as f32)
+    }
+
+    /// Geometric-mean probability of the sampled tokens (0.0 to 1.0)
+    pub fn confidence(&self) -> Option<f32> {
+        self.mean_logprob().map(f32::exp)
+    }
+}
+
+/// Most alternatives returned per token
+pub const MAX_TOP_LOGPROBS: u32 = 20;
+
+/// A candidate token and its log-probability
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TokenCandidate {
+    /// Token id
+    pub token: i32,
+    /// Token text (partial characters are replaced)
+    pub text: String,
+    /// Natural-log probability under the model
+    pub logprob: f32,
+}
+
+/// A sampled token with its log-probability and the likeliest alternatives
 ///
-/// # Returns
-/// * Generated text completion
-pub fn generate_text(prompt: String, max_tokens: Option<u32>) -> Result<String> {
-    let guard = AI_STATE.lock();
-    let state = guard.as_ref().ok_or_else(|| anyhow!("Model not loaded"))?;
+/// Probabilities come from the model's distribution before sampling
+/// (temperature, top-k), so they measure the model's own confidence. A grammar
+/// is applied first: tokens it rejects get no probability and the rest are
+/// renormalized.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TokenLogprob {
+    /// Token id
+    pub token: i32,
+    /// Token text (partial characters are replaced)
+    pub text: String,
+    /// Natural-log probability under the model
+    pub logprob: f32,
+    /// Most likely tokens at this position, best first
+    pub top_alternatives: Vec<TokenCandidate>,
+}
+
+/// Log-normalizer of the candidates' softmax and their `top_n` most likely
+/// tokens with log-probabilities, best first
+///
+/// Candidates rejected by the grammar (logit -inf) are left out. Only `top_n`
+/// entries are kept while scanning, so no vocabulary-sized buffer is needed.
+fn candidate_logprobs(
+    candidates: &[LlamaTokenData],
+    top_n: usize,
+) -> (f32, Vec<(LlamaToken, f32)>) {
+    let max = candidates
+        .iter()
+        .map(LlamaTokenData::logit)
+        .fold(f32::NEG_INFINITY, f32::max);
+    let sum_exp: f32 = candidates
+        .iter()
+        .map(|candidate| (candidate.logit() - max).exp())
+        .sum();
+    let log_sum = max + sum_exp.ln();
+
+    let mut top: Vec<(LlamaToken, f32)> = Vec::with_capacity(top_n + 1);
+    if top_n > 0 {
+        for candidate in candidates {
+            let logit = candidate.logit();
+            let full = top.len() == top_n;
+            if logit == f32::NEG_INFINITY || full && logit <= top[top_n - 1].1 {
+                continue;
+            }
+            let at = top.partition_point(|&(_, other)| other >= logit);
+            top.insert(at, (candidate.id(), logit));
+            top.truncate(top_n);
+        }
+    }
+
+    let alternatives = top
+        .into_iter()
+        .map(|(id, logit)| (id, logit - log_sum))
+        .collect();
+    (log_sum, alternatives)
+}
+
+/// Text of a single token, for logprob reports
+fn token_text(state: &ModelState, token: LlamaToken) -> Result<String> {
+    let bytes = state
+        .model
+        .token_to_bytes(token, Special::Tokenize)
+        .map_err(|e| anyhow!("Failed to detokenize: {:?}", e))?;
+    Ok(String::from_utf8_lossy(&bytes).into_owned())
+}
+
+/// Build the logprob report for a sampled token
+///
+/// `log_sum` and `alternatives` come from `candidate_logprobs`; `logit` is the
+/// sampled token's logit, which the grammar left unchanged since it allowed it.
+fn token_logprob(
+    state: &ModelState,
+    token: LlamaToken,
+    logit: f32,
+    log_sum: f32,
+    alternatives: Vec<(LlamaToken, f32)>,
+) -> Result<TokenLogprob> {
+    let top_alternatives = alternatives
+        .into_iter()
+        .map(|(candidate, logprob)| {
+            Ok(TokenCandidate {
+                token: candidate.0,
+                text: token_text(state, candidate)?,
+                logprob,
+            })
+        })
+        .collect::<Result<_>>()?;
+
+    Ok(TokenLogprob {
+        token: token.0,
+        text: token_text(state, token)?,
+        logprob: logit - log_sum,
+        top_alternatives,
+    })
+}
+
+/// Incremental UTF-8 decoder for token bytes
+///
+/// A single multi-byte character can be split across several tokens, so
+/// bytes are buffered until they form complete characters.
+#[derive(Debug, Default)]
+struct Utf8Decoder {
+    pending: Vec<u8>,
+}
+
+impl Utf8Decoder {
+    /// Append token bytes and return every complete character decoded so far
+    fn push(&mut self, bytes: &[u8]) -> String {
+        self.pending.extend_from_slice(bytes);
+        let mut output = String::new();
+
+        loop {
+            match std::str::from_utf8(&self.pending) {
+                Ok(text) => {
+                    output.push_str(text);
+                    self.pending.clear();
+                    break;
+                }
+                Err(error) => {
+                    let valid = error.valid_up_to();
+                    output.push_str(&String::from_utf8_lossy(&self.pending[..valid]));
+                    match error.error_len() {
+                        // Invalid sequence: replace it and keep decoding
+                        Some(len) => {
+                            output.push(char::REPLACEMENT_CHARACTER);
+                            self.pending.drain(..valid + len);
+                        }
+                        // Incomplete sequence: wait for the next token
+                        None => {
+                            self.pending.drain(..valid);
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+
+        output
+    }
+
+    /// Flush any trailing bytes that never formed a complete character
+    fn finish(&mut self) -> String {
+        let rest = String::from_utf8_lossy(&self.pending).into_owned();
+        self.pending.clear();
+        rest
+    }
+}
+
+/// Find the earliest stop sequence occurrence at or after `from`
+fn find_stop_sequence(text: &str, stop_sequences: &[String], from: usize) -> Option<usize> {
+    stop_sequences
+        .iter()
+        .filter(|stop| !stop.is_empty())
+        .filter_map(|stop| {
+            // Start far enough back to catch a match that began in an earlier piece
+            let mut start = from.saturating_sub(stop.len());
+            while !text.is_char_boundary(start) {
+                start -= 1;
+            }
+            text[start..].find(stop.as_str()).map(|pos| start + pos)
+        })
+        .min()
+}
+
+/// Length of the longest suffix of `text` that is a proper prefix of `pattern`
+pub(crate) fn partial_match_len(text: &str, pattern: &str) -> usize {
+    (1..pattern.len())
+        .rev()
+        .find(|&len| pattern.is_char_boundary(len) && text.ends_with(&pattern[..len]))
+        .unwrap_or(0)
+}
+
+/// Turns sampled text into chunks
+///
+/// Separates the reasoning trace from the answer and holds back answer text
+/// that may be the start of a stop sequence, so a stop sequence split across
+/// tokens never leaks into the stream. Stop sequences apply to the answer only.
+struct OutputFilter<'a> {
+    stop_sequences: &'a [String],
+    splitter: Option<ReasoningSplitter>,
+    answer: String,
+    /// Length of the answer prefix already sent as chunks
+    emitted: usize,
+}
+
+impl<'a> OutputFilter<'a> {
+    fn new(stop_sequences: &'a [String], splitter: Option<ReasoningSplitter>) -> Self {
+        Self {
+            stop_sequences,
+            splitter,
+            answer: String::new(),
+            emitted: 0,
+        }
+    }
+
+    fn is_thinking(&self) -> bool {
+        self.splitter.as_ref().is_some_and(|s| s.is_thinking())
+    }
 
-    let max_tokens = max_tokens.unwrap_or(state.config.max_tokens);
+    /// Add a decoded piece; returns true once a stop sequence matched
+    fn push(&mut self, piece: &str, on_chunk: &mut dyn FnMut(GenerationChunk)) -> bool {
+        let (reasoning, answer) = match &mut self.splitter {
+            Some(splitter) => splitter.push(piece),
+            None => (String::new(), piece.to_string()),
+        };
+        self.accept(reasoning, &answer, true, on_chunk)
+    }
+
+    /// Flush everything held back; returns true if a stop sequence matched
+    fn finish(&mut self, on_chunk: &mut dyn FnMut(GenerationChunk)) -> bool {
+        let (reasoning, answer) = match &mut self.splitter {
+            Some(splitter) => splitter.finish(),
+            None => (String::new(), String::new()),
+        };
+        self.accept(reasoning, &answer, false, on_chunk)
+    }
+
+    fn accept(
+        &mut self,
+        reasoning: String,
+        answer: &str,
+        hold_back: bool,
+        on_chunk: &mut dyn FnMut(GenerationChunk),
+    ) -> bool {
+        let start = self.answer.len();
+        self.answer.push_str(answer);
+
+        if let Some(stop_at) = find_stop_sequence(&self.answer, self.stop_sequences, start) {
+            self.answer.truncate(stop_at);
+            self.emit(reasoning, stop_at, on_chunk);
+            return true;
+        }
+
+        let held = if hold_back {
+            self.stop_sequences
+                .iter()
+                .map(|stop| partial_match_len(&self.answer, stop))
+                .max()
+                .unwrap_or(0)
+        } else {
+            0
+        };
+        self.emit(reasoning, self.answer.len() - held, on_chunk);
+        false
+    }
 
-    // Create context for this generation
+    fn emit(&mut self, reasoning: String, end: usize, on_chunk: &mut dyn FnMut(GenerationChunk)) {
+        let text = if end > self.emitted {
+            self.answer[self.emitted..end].to_string()
+        } else {
+            String::new()
+        };
+        self.emitted = self.emitted.max(end);
+        if !text.is_empty() || !reasoning.is_empty() {
+            on_chunk(GenerationChunk {
+                text,
+                reasoning,
+                stop_reason: None,
+            });
+        }
+    }
+}
+
+/// Run scripted output through the stop-sequence and reasoning handling of
+/// the decode loop
+///
+/// Used by backends that produce text without a llama.cpp model. `tokens`
+/// holds `(token id, text)` pairs; scripted tokens count as certain, so
+/// requested logprobs are 0.0 without alternatives.
+pub(crate) fn replay_tokens(
+    tokens: &[(i32, String)],
+    options: &GenerationOptions,
+    on_chunk: &mut dyn FnMut(GenerationChunk),
+) -> GenerationResult {
+    let max_tokens = options.max_tokens.unwrap_or(u32::MAX) as usize;
+    let reasoning = options.reasoning.as_ref().filter(|options| options.enabled);
+    let splitter = reasoning.map(|options| ReasoningSplitter::new(options, false));
+    let mut output = OutputFilter::new(&options.stop_sequences, splitter);
+
+    let mut logprobs = options.logprobs.map(|_| Vec::new());
+    let mut tokens_generated = 0u32;
+    let mut reasoning_tokens = 0u32;
+    let mut stop_reason = StopReason::EndOfGeneration;
+
+    for (index, (token, text)) in tokens.iter().enumerate() {
+        if index == max_tokens {
+            stop_reason = StopReason::MaxTokens;
+            break;
+        }
+        tokens_generated += 1;
+        if output.is_thinking() {
+            reasoning_tokens += 1;
+        }
+        if let Some(logprobs) = logprobs.as_mut() {
+            logprobs.push(TokenLogprob {
+                token: *token,
+                text: text.clone(),
+                logprob: 0.0,
+                top_alternatives: Vec::new(),
+            });
+        }
+        if output.push(text, on_chunk) {
+            stop_reason = StopReason::StopSequence;
+            break;
+        }
+    }
+
+    if stop_reason != StopReason::StopSequence && output.finish(on_chunk) {
+        stop_reason = StopReason::StopSequence;
+    }
+
+    on_chunk(GenerationChunk {
+        text: String::new(),
+        reasoning: String::new(),
+        stop_reason: Some(stop_reason),
+    });
+
+    GenerationResult {
+        reasoning: output.splitter.as_ref().map(|s| s.reasoning()),
+        text: output.answer,
+        stop_reason,
+        tokens_generated,
+        reasoning_tokens,
+        context: None,
+        logprobs,
+    }
+}
+
+/// Create a context for generation on the loaded model
+///
+/// `n_ctx` overrides the configured context size.
+pub(crate) fn new_generation_context(
+    state: &ModelState,
+    n_ctx: Option<u32>,
+) -> Result<LlamaContext<'_>> {
     let ctx_params = LlamaContextParams::default()
-        .with_n_ctx(NonZeroU32::new(state.config.n_ctx))
+        .with_n_ctx(NonZeroU32::new(n_ctx.unwrap_or(state.config.n_ctx)))
         .with_n_threads(state.config.n_threads)
         .with_n_threads_batch(state.config.n_threads);
 
-    let mut ctx = state
+    state
         .model
-        .new_context(&state.backend, ctx_params)
-        .map_err(|e| anyhow!("Failed to create context: {:?}", e))?;
+        .new_context(state.backend, ctx_params)
+        .map_err(|e| anyhow!("Failed to create context: {:?}", e))
+}
 
-    // Tokenize the prompt
+/// Tokenize a prompt for the loaded model
+pub(crate) fn tokenize_prompt(state: &ModelState, prompt: &str) -> Result<Vec<LlamaToken>> {
     let tokens = state
         .model
-        .str_to_token(&prompt, AddBos::Always)
+        .str_to_token(prompt, AddBos::Always)
         .map_err(|e| anyhow!("Failed to tokenize: {:?}", e))?;
 
     log::debug!("Prompt tokenized to {} tokens", tokens.len());
+    Ok(tokens)
+}
 
-    // Create batch and process prompt
-    let mut batch = LlamaBatch::new(state.config.n_ctx as usize, 1);
+/// Decode `tokens` into sequence 0 starting at position `start`
+///
+/// Tokens are fed in chunks of the context's batch size. The returned batch
+/// holds the logits of the last token, ready for sampling. Returns `None` if
+/// `cancel` is set before the whole prompt has been decoded.
+pub(crate) fn decode_prompt(
+    ctx: &mut LlamaContext,
+    tokens: &[LlamaToken],
+    start: usize,
+    cancel: Option<&AtomicBool>,
+) -> Result<Option<LlamaBatch<'static>>> {
+    if tokens.is_empty() {
+        return Err(anyhow!("Cannot decode an empty prompt"));
+    }
+    let n_ctx = ctx.n_ctx() as usize;
+    if start + tokens.len() >= n_ctx {
+        return Err(anyhow!(
+            "Prompt needs {} tokens but the context window holds {}",
+            start + tokens.len(),
+            n_ctx
+        ));
+    }
+
+    let n_batch = (ctx.n_batch() as usize).max(1);
+    let mut batch = LlamaBatch::new(n_batch, 1);
+
+    for (chunk_index, chunk) in tokens.chunks(n_batch).enumerate() {
+        // Long prompts take a while to prefill, so cancellation is checked per batch
+        if cancel.is_some_and(|flag| flag.load(Ordering::Relaxed)) {
+            return Ok(None);
+        }
+
+        batch.clear();
+        let offset = start + chunk_index * n_batch;
+        for (i, token) in chunk.iter().enumerate() {
+            let is_last = offset + i == start + tokens.len() - 1;
+            batch.add(*token, (offset + i) as i32, &[0], is_last)?;
+        }
 
-    for (i, token) in tokens.iter().enumerate() {
-        let is_last = i == tokens.len() - 1;
-        batch.add(*token, i as i32, &[0], is_last)?;
+        ctx.decode(&mut batch)
+            .map_err(|e| anyhow!("Failed to decode prompt: {:?}", e))?;
     }
 
-    ctx.decode(&mut batch)
-        .map_err(|e| anyhow!("Failed to decode prompt: {:?}", e))?;
+    Ok(Some(batch))
+}
 
-    // Generate tokens
-    let mut output_tokens = Vec::new();
-    let mut n_cur = tokens.len();
+/// Generate text with a fresh context
+///
+/// Calls `on_chunk` with each detokenized piece as soon as it is sampled,
+/// followed by a final chunk carrying the stop reason.
+pub(crate) fn run_generation(
+    state: &ModelState,
+    prompt: &str,
+    options: &GenerationOptions,
+    cancel: Option<&AtomicBool>,
+    on_chunk: &mut dyn FnMut(GenerationChunk),
+) -> Result<GenerationResult> {
+    let mut ctx = new_generation_context(state, None)?;
+    let tokens = tokenize_prompt(state, prompt)?;
+    let Some(mut batch) = decode_prompt(&mut ctx, &tokens, 0, cancel)? else {
+        return Ok(cancelled_during_prompt(on_chunk));
+    };
 
-    // Use a simple random seed for sampling
-    let mut rng_seed: u32 = std::time::SystemTime::now()
-        .duration_since(std::time::UNIX_EPOCH)
-        .unwrap_or_default()
-        .as_secs() as u32;
+    decode_loop(
+        state, &mut ctx, &mut batch, prompt, &tokens, options, cancel, on_chunk,
+    )
+    .map(|(result, _)| result)
+}
 
-    for _ in 0..max_tokens {
-        // Sample next token
-        let candidates = ctx.candidates_ith(batch.n_tokens() - 1);
-        let mut candidates_array = LlamaTokenDataArray::from_iter(candidates, false);
+/// Result of a request cancelled before its first token was sampled
+fn cancelled_during_prompt(on_chunk: &mut dyn FnMut(GenerationChunk)) -> GenerationResult {
+    on_chunk(GenerationChunk {
+        text: String::new(),
+        reasoning: String::new(),
+        stop_reason: Some(StopReason::Cancelled),
+    });
+
+    GenerationResult {
+        text: String::new(),
+        reasoning: None,
+        reasoning_tokens: 0,
+        stop_reason: StopReason::Cancelled,
+        tokens_generated: 0,
+        context: None,
+        logprobs: None,
+    }
+}
 
-        // Sample with random seed
-        rng_seed = rng_seed.wrapping_mul(1103515245).wrapping_add(12345);
-        let new_token = candidates_array.sample_token(rng_seed);
+/// Core decode loop shared by every generation entry point
+///
+/// Samples from the logits in `batch` and continues the sequence after
+/// `history`, the tokens already in the KV cache; `prompt` is their text. The
+/// history is fed to the penalty samplers so repeats of the prompt count too.
+/// Returns the result together with the generated tokens that were decoded
+/// into the KV cache.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn decode_loop(
+    state: &ModelState,
+    ctx: &mut LlamaContext,
+    batch: &mut LlamaBatch,
+    prompt: &str,
+    history: &[LlamaToken],
+    options: &GenerationOptions,
+    cancel: Option<&AtomicBool>,
+    on_chunk: &mut dyn FnMut(GenerationChunk),
+) -> Result<(GenerationResult, Vec<LlamaToken>)> {
+    let max_tokens = options.max_tokens.unwrap_or(state.config.max_tokens);
+    let sampler_config = options.sampler.as_ref().unwrap_or(&state.config.sampler);
+    let reasoning = options.resolve_reasoning(state.is_reasoning_model());
+
+    // Only the penalty stages keep history; the grammar must not see the prompt
+    let mut sampler = sampler_config.build(ctx.n_ctx());
+    sampler.accept_many(history);
+
+    // The grammar masks out invalid tokens before the chain runs. It is kept
+    // separate so logprobs can be read from the candidates it allows.
+    let mut grammar = match options.resolve_grammar()? {
+        Some(grammar) => Some(
+            LlamaSampler::grammar(&state.model, &grammar, "root")
+                .map_err(|e| anyhow!("Invalid grammar: {:?}", e))?,
+        ),
+        None => None,
+    };
+
+    let splitter = reasoning.as_ref().map(|options| {
+        ReasoningSplitter::new(options, reasoning::prompt_opens_reasoning(prompt, options))
+    });
+    let mut output = OutputFilter::new(&options.stop_sequences, splitter);
+
+    let mut n_cur = history.len() as i32;
+    let mut decoded = Vec::new();
+    let mut decoder = Utf8Decoder::default();
+    let mut tokens_generated = 0u32;
+    let mut reasoning_tokens = 0u32;
+    let mut logprobs = options.logprobs.map(|_| Vec::new());
+    let mut stop_reason = StopReason::MaxTokens;
+
+    while tokens_generated < max_tokens {
+        // Cancellation is observed at every token boundary
+        if cancel.is_some_and(|flag| flag.load(Ordering::Relaxed)) {
+            stop_reason = StopReason::Cancelled;
+            break;
+        }
+
+        let index = batch.n_tokens() - 1;
+        let mut candidates = ctx.token_data_array_ith(index);
+        if let Some(grammar) = &grammar {
+            candidates.apply_sampler(grammar);
+        }
+        let distribution = options.logprobs.map(|top_n| {
+            candidate_logprobs(&candidates.data, top_n.min(MAX_TOP_LOGPROBS) as usize)
+        });
+
+        // Sample next token and feed it to the grammar and penalty history
+        candidates.apply_sampler(&sampler);
+        let new_token = candidates
+            .selected_token()
+            .ok_or_else(|| anyhow!("Sampler did not select a token"))?;
+        if let Some(grammar) = grammar.as_mut() {
+            grammar.accept(new_token);
+        }
+        sampler.accept(new_token);
 
         // Check for end of generation
         if state.model.is_eog_token(new_token) {
+            stop_reason = StopReason::EndOfGeneration;
             break;
         }
 
-        output_tokens.push(new_token);
+        let bytes = state
+            .model
+            .token_to_bytes(new_token, Special::Tokenize)
+            .map_err(|e| anyhow!("Failed to detokenize: {:?}", e))?;
+        tokens_generated += 1;
+        if output.is_thinking() {
+            reasoning_tokens += 1;
+        }
+        if let (Some((log_sum, alternatives)), Some(logprobs)) = (distribution, logprobs.as_mut()) {
+            let logit = ctx.get_logits_ith(index)[new_token.0 as usize];
+            logprobs.push(token_logprob(
+                state,
+                new_token,
+                logit,
+                log_sum,
+                alternatives,
+            )?);
+        }
+
+        if output.push(&decoder.push(&bytes), on_chunk) {
+            stop_reason = StopReason::StopSequence;
+            break;
+        }
 
         // Prepare next batch
         batch.clear();
-        batch.add(new_token, n_cur as i32, &[0], true)?;
+        batch.add(new_token, n_cur, &[0], true)?;
         n_cur += 1;
 
-        ctx.decode(&mut batch)
+        ctx.decode(batch)
             .map_err(|e| anyhow!("Failed to decode: {:?}", e))?;
+        decoded.push(new_token);
+
+        // Out of thinking budget: close the block so the model answers
+        if let Some(options) = &reasoning {
+            let over_budget = options
+                .thinking_budget
+                .is_some_and(|budget| reasoning_tokens >= budget);
+            if over_budget && output.is_thinking() {
+                if cancel.is_some_and(|flag| flag.load(Ordering::Relaxed)) {
+                    stop_reason = StopReason::Cancelled;
+                    break;
+                }
+
+                let close = format!("\n{}\n\n", options.close_tag);
+                let forced = state
+                    .model
+                    .str_to_token(&close, AddBos::Never)
+                    .map_err(|e| anyhow!("Failed to tokenize: {:?}", e))?;
+
+                // No room left to close the block and answer
+                if n_cur as usize + forced.len() >= ctx.n_ctx() as usize {
+                    break;
+                }
+
+                batch.clear();
+                for (i, token) in forced.iter().enumerate() {
+                    batch.add(*token, n_cur, &[0], i == forced.len() - 1)?;
+                    n_cur += 1;
+                }
+                ctx.decode(batch)
+                    .map_err(|e| anyhow!("Failed to decode: {:?}", e))?;
+
+                // The forced tokens count as output for the penalty samplers
+                for token in &forced {
+                    sampler.accept(*token);
+                }
+                decoded.extend(forced);
+                if output.push(&close, on_chunk) {
+                    stop_reason = StopReason::StopSequence;
+                    break;
+                }
+            }
+        }
     }
 
-    // Convert tokens back to text
-    let output = output_tokens
-        .iter()
-        .map(|t| state.model.token_to_str(*t, Special::Tokenize))
-        .collect::<Result<Vec<_>, _>>()
-        .map_err(|e| anyhow!("Failed to detokenize: {:?}", e))?
-        .join("");
+    if stop_reason != StopReason::StopSequence {
+        let rest = decoder.finish();
+        if output.push(&rest, on_chunk) || output.finish(on_chunk) {
+            stop_reason = StopReason::StopSequence;
+        }
+    }
 
-    Ok(output)
+    on_chunk(GenerationChunk {
+        text: String::new(),
+        reasoning: String::new(),
+        stop_reason: Some(stop_reason),
+    });
+
+    Ok((
+        GenerationResult {
+            reasoning: output.splitter.as_ref().map(|s| s.reasoning()),
+            text: output.answer,
+            stop_reason,
+            tokens_generated,
+            reasoning_tokens,
+            context: None,
+            logprobs,
+        },
+        decoded,
+    ))
 }
 
-/// Generate embeddings for text (for vector search)
+/// Generate text completion from a prompt
 ///
 /// # Arguments
-/// * `text` - The text to embed
+/// * `prompt` - The input prompt
+/// * `max_tokens` - Optional override for max tokens to generate
 ///
 /// # Returns
-/// * Vector of floats representing the embedding
-pub fn get_embedding(text: String) -> Result<Vec<f32>> {
-    let guard = AI_STATE.lock();
-    let state = guard.as_ref().ok_or_else(|| anyhow!("Model not loaded"))?;
+/// * Generated text completion
+pub fn generate_text(prompt: String, max_tokens: Option<u32>) -> Result<String> {
+    generate_text_with_options(prompt, GenerationOptions::with_max_tokens(max_tokens))
+        .map(|result| result.text)
+}
 
-    // Create context optimized for embedding extraction
-    let ctx_params = LlamaContextParams::default()
-        .with_n_ctx(NonZeroU32::new(2048)) // Smaller context for embeddings
-        .with_n_threads(state.config.n_threads)
-        .with_n_threads_batch(state.config.n_threads)
-        .with_embeddings(true); // Enable embedding extraction
+/// Generate text completion with per-request options
+///
+/// # Arguments
+/// * `prompt` - The input prompt
+/// * `options` - Token limit, stop sequences and sampler overrides
+///
+/// # Returns
+/// * The full generation result
+pub fn generate_text_with_options(
+    prompt: String,
+    options: GenerationOptions,
+) -> Result<GenerationResult> {
+    generate_text_streaming(prompt, options, |_| {})
+}
 
-    let mut ctx = state
-        .model
-        .new_context(&state.backend, ctx_params)
-        .map_err(|e| anyhow!("Failed to create embedding context: {:?}", e))?;
+/// Generate text completion, streaming each token as it is sampled
+///
+/// # Arguments
+/// * `prompt` - The input prompt
+/// * `options` - Token limit, stop sequences and sampler overrides
+/// * `on_chunk` - Called with each detokenized piece, then once more with the stop reason
+///
+/// # Returns
+/// * The full generation result
+pub fn generate_text_streaming<F>(
+    prompt: String,
+    options: GenerationOptions,
+    mut on_chunk: F,
+) -> Result<GenerationResult>
+where
+    F: FnMut(GenerationChunk),
+{
+    backend::active_backend().generate(&prompt, &options, &mut on_chunk)
+}
 
-    // Tokenize input
-    let tokens = state
-        .model
-        .str_to_token(&text, AddBos::Always)
-        .map_err(|e| anyhow!("Failed to tokenize: {:?}", e))?;
+/// Generate text on a loaded llama.cpp model (the default backend)
+pub(crate) fn llama_generate(
+    prompt: &str,
+    options: &GenerationOptions,
+    on_chunk: &mut dyn FnMut(GenerationChunk),
+) -> Result<GenerationResult> {
+    let state = current_state(options.model_id.as_deref())?;
+    run_generation(&state, prompt, options, None, on_chunk)
+}
 
-    // Create batch with all tokens
-    let mut batch = LlamaBatch::new(2048, 1);
-    for (i, token) in tokens.iter().enumerate() {
-        let is_last = i == tokens.len() - 1;
-        batch.add(*token, i as i32, &[0], is_last)?;
-    }
+/// Generate text completion on a background thread, returning a channel of chunks
+///
+/// Intended for Rust callers that are not going through flutter_rust_bridge.
+/// The channel yields each piece as it is sampled, then a final chunk with the
+/// stop reason, or an error if generation failed.
+pub fn generate_text_channel(
+    prompt: String,
+    options: GenerationOptions,
+) -> mpsc::UnboundedReceiver<Result<GenerationChunk>> {
+    let (tx, rx) = mpsc::unbounded_channel();
+
+    std::thread::spawn(move || {
+        let result = generate_text_streaming(prompt, options, |chunk| {
+            let _ = tx.send(Ok(chunk));
+        });
+        if let Err(error) = result {
+            let _ = tx.send(Err(error));
+        }
+    });
+
+    rx
+}
+
+/// Progress of a generation started through a request handle
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GenerationPoll {
+    /// Text generated since the previous poll
+    pub text: String,
+    /// Reasoning text generated since the previous poll
+    pub reasoning: String,
+    /// Final result, present once the request has finished
+    pub result: Option<GenerationResult>,
+}
 
-    // Process
-    ctx.decode(&mut batch)
-        .map_err(|e| anyhow!("Failed to compute embeddings: {:?}", e))?;
+/// Shared state between a request handle and its worker thread
+#[derive(Default)]
+struct GenerationRequest {
+    cancelled: AtomicBool,
+    progress: Mutex<RequestProgress>,
+    finished: Condvar,
+}
 
-    // Extract embeddings (mean pooling over all tokens)
-    let n_embd = state.model.n_embd() as usize;
-    let mut pooled_embedding = vec![0.0f32; n_embd];
-    let n_tokens = tokens.len();
+#[derive(Default)]
+struct RequestProgress {
+    /// Text produced since the last poll
+    unread: String,
+    /// Reasoning produced since the last poll
+    unread_reasoning: String,
+    /// Set by the worker thread when generation ends
+    done: bool,
+    /// When generation ended, used to release requests nobody claims
+    finished_at: Option<Instant>,
+    /// Final outcome, taken by whichever caller claims it first
+    outcome: Option<Result<GenerationResult>>,
+}
 
-    for i in 0..n_tokens {
-        let emb = ctx
-            .embeddings_ith(i as i32)
-            .map_err(|e| anyhow!("Failed to get embedding at position {}: {:?}", i, e))?;
+/// In-flight and finished-but-unclaimed generation requests
+static GENERATION_REQUESTS: Lazy<Mutex<HashMap<u64, Arc<GenerationRequest>>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// How long a finished request waits to be claimed before it is released
+const UNCLAIMED_REQUEST_TTL: Duration = Duration::from_secs(10 * 60);
+
+static NEXT_REQUEST_ID: AtomicU64 = AtomicU64::new(1);
+
+/// Run `job` on a worker thread and register it under a new request id
+fn spawn_request<F>(job: F) -> u64
+where
+    F: FnOnce(&AtomicBool, &mut dyn FnMut(GenerationChunk)) -> Result<GenerationResult>
+        + Send
+        + 'static,
+{
+    let id = NEXT_REQUEST_ID.fetch_add(1, Ordering::Relaxed);
+    let request = Arc::new(GenerationRequest::default());
+    let mut requests = GENERATION_REQUESTS.lock();
+    release_unclaimed(&mut requests);
+    requests.insert(id, request.clone());
+    drop(requests);
+
+    std::thread::spawn(move || {
+        let outcome = job(&request.cancelled, &mut |chunk| {
+            let mut progress = request.progress.lock();
+            progress.unread.push_str(&chunk.text);
+            progress.unread_reasoning.push_str(&chunk.reasoning);
+        });
+        let mut progress = request.progress.lock();
+        progress.outcome = Some(outcome);
+        progress.done = true;
+        progress.finished_at = Some(Instant::now());
+        drop(progress);
+        request.finished.notify_all();
+    });
+
+    id
+}
 
-        for (j, val) in emb.iter().enumerate() {
-            pooled_embedding[j] += val;
-        }
-    }
+/// Drop finished requests whose result was not claimed within the TTL
+fn release_unclaimed(requests: &mut HashMap<u64, Arc<GenerationRequest>>) {
+    requests.retain(|_, request| {
+        request
+            .progress
+            .lock()
+            .finished_at
+            .is_none_or(|finished| finished.elapsed() < UNCLAIMED_REQUEST_TTL)
+    });
+}
+
+fn find_request(request_id: u64) -> Result<Arc<GenerationRequest>> {
+    GENERATION_REQUESTS
+        .lock()
+        .get(&request_id)
+        .cloned()
+        .ok_or_else(|| anyhow!("Unknown generation request: {}", request_id))
+}
+
+/// Start a text completion in the background
+///
+/// # Arguments
+/// * `prompt` - The input prompt
+/// * `options` - Token limit, stop sequences and sampler overrides
+///
+/// # Returns
+/// * Request id to pass to `poll_generation`, `wait_for_generation` or `cancel_generation`
+pub fn start_generation(prompt: String, options: GenerationOptions) -> Result<u64> {
+    let state = current_state(options.model_id.as_deref())?;
+    Ok(start_with_state(state, prompt, options))
+}
+
+fn start_with_state(state: Arc<ModelState>, prompt: String, options: GenerationOptions) -> u64 {
+    spawn_request(move |cancel, on_chunk| {
+        run_generation(&state, &prompt, &options, Some(cancel), on_chunk)
+    })
+}
+
+/// Start a chat completion in the background
+///
+/// # Returns
+/// * Request id to pass to `poll_generation`, `wait_for_generation` or `cancel_generation`
+pub fn start_chat_completion(
+    messages: Vec<(String, String)>,
+    options: GenerationOptions,
+) -> Result<u64> {
+    let state = current_state(options.model_id.as_deref())?;
+
+    // Fitting may ask the model for a summary, so it runs with the request
+    Ok(spawn_request(move |cancel, on_chunk| {
+        run_chat(&state, messages, &options, Some(cancel), on_chunk)
+    }))
+}
+
+/// Ask a running request to stop at the next token boundary
+///
+/// # Returns
+/// * `true` if the request exists and had not finished yet
+pub fn cancel_generation(request_id: u64) -> bool {
+    let Ok(request) = find_request(request_id) else {
+        return false;
+    };
+    request.cancelled.store(true, Ordering::Relaxed);
+    let done = request.progress.lock().done;
+    !done
+}
 
-    // Average (mean pooling)
-    for val in &mut pooled_embedding {
-        *val /= n_tokens as f32;
+/// Collect the text produced since the last poll without blocking
+///
+/// Once the result is returned the request id is released.
+/// A finished request whose result is never claimed is released after
+/// `UNCLAIMED_REQUEST_TTL`.
+pub fn poll_generation(request_id: u64) -> Result<GenerationPoll> {
+    let request = find_request(request_id)?;
+
+    let mut progress = request.progress.lock();
+    let text = std::mem::take(&mut progress.unread);
+    let reasoning = std::mem::take(&mut progress.unread_reasoning);
+    if !progress.done {
+        return Ok(GenerationPoll {
+            text,
+            reasoning,
+            result: None,
+        });
     }
+    let outcome = progress.outcome.take();
+    drop(progress);
+
+    GENERATION_REQUESTS.lock().remove(&request_id);
+    let result = claim_outcome(request_id, outcome)?;
+    Ok(GenerationPoll {
+        text,
+        reasoning,
+        result: Some(result),
+    })
+}
 
-    // L2 normalize for cosine similarity
-    let norm: f32 = pooled_embedding.iter().map(|x| x * x).sum::<f32>().sqrt();
-    if norm > 0.0 {
-        for val in &mut pooled_embedding {
-            *val /= norm;
-        }
+/// Block until a request finishes and return its result
+///
+/// A cancelled request returns the partial output with `StopReason::Cancelled`.
+/// The request id is released afterwards.
+pub fn wait_for_generation(request_id: u64) -> Result<GenerationResult> {
+    let request = find_request(request_id)?;
+
+    let mut progress = request.progress.lock();
+    while !progress.done {
+        request.finished.wait(&mut progress);
     }
+    let outcome = progress.outcome.take();
+    drop(progress);
+
+    GENERATION_REQUESTS.lock().remove(&request_id);
+    claim_outcome(request_id, outcome)
+}
 
-    Ok(pooled_embedding)
+fn claim_outcome(
+    request_id: u64,
+    outcome: Option<Result<GenerationResult>>,
+) -> Result<GenerationResult> {
+    outcome.unwrap_or_else(|| {
+        Err(anyhow!(
+            "Generation request {} was already claimed",
+            request_id
+        ))
+    })
+}
+
+/// Generate embeddings for text (for vector search)
+///
+/// Uses the dedicated embedding model when one is loaded, otherwise the
+/// default chat model.
+///
+/// # Arguments
+/// * `text` - The text to embed
+///
+/// # Returns
+/// * Vector of floats representing the embedding
+pub fn get_embedding(text: String) -> Result<Vec<f32>> {
+    let mut vectors = backend::active_backend().embed(&[text], EmbeddingKind::Document)?;
+    Ok(vectors.remove(0))
+}
+
+/// Generate embeddings for text with a specific model
+///
+/// # Arguments
+/// * `text` - The text to embed
+/// * `model_id` - Model to use (`None` = the default model)
+///
+/// # Returns
+/// * Vector of floats representing the embedding
+pub fn get_embedding_with_model(text: String, model_id: Option<&str>) -> Result<Vec<f32>> {
+    let mut vectors = embedder::embed_with_chat_model(&[text], model_id)?;
+    Ok(vectors.remove(0))
 }
 
 /// Chat completion with conversation history
@@ -351,27 +1649,95 @@ pub fn get_embedding(text: String) -> Result<Vec<f32>> {
 /// # Returns
 /// * Assistant's response
 pub fn chat_completion(messages: Vec<(String, String)>, max_tokens: Option<u32>) -> Result<String> {
-    let guard = AI_STATE.lock();
-    let state = guard.as_ref().ok_or_else(|| anyhow!("Model not loaded"))?;
-    let model_type = state.config.model_type;
+    chat_completion_with_options(messages, GenerationOptions::with_max_tokens(max_tokens))
+        .map(|result| result.text)
+}
+
+/// Chat completion with per-request options
+///
+/// # Arguments
+/// * `messages` - List of (role, content) pairs where role is "system", "user", or "assistant"
+/// * `options` - Token limit, stop sequences and sampler overrides
+///
+/// # Returns
+/// * The full generation result
+pub fn chat_completion_with_options(
+    messages: Vec<(String, String)>,
+    options: GenerationOptions,
+) -> Result<GenerationResult> {
+    chat_completion_streaming(messages, options, |_| {})
+}
+
+/// Chat completion, streaming each token of the assistant's reply as it is sampled
+///
+/// # Arguments
+/// * `messages` - List of (role, content) pairs where role is "system", "user", or "assistant"
+/// * `options` - Token limit, stop sequences and sampler overrides
+/// * `on_chunk` - Called with each detokenized piece, then once more with the stop reason
+///
+/// # Returns
+/// * The full generation result
+pub fn chat_completion_streaming<F>(
+    messages: Vec<(String, String)>,
+    options: GenerationOptions,
+    mut on_chunk: F,
+) -> Result<GenerationResult>
+where
+    F: FnMut(GenerationChunk),
+{
+    backend::active_backend().chat(&messages, &options, &mut on_chunk)
+}
+
+/// Chat on a loaded llama.cpp model (the default backend)
+pub(crate) fn llama_chat(
+    messages: &[(String, String)],
+    options: &GenerationOptions,
+    on_chunk: &mut dyn FnMut(GenerationChunk),
+) -> Result<GenerationResult> {
+    let state = current_state(options.model_id.as_deref())?;
+    run_chat(&state, messages.to_vec(), options, None, on_chunk)
+}
+
+/// Fit a conversation into the context window, then generate the reply
+fn run_chat(
+    state: &ModelState,
+    messages: Vec<(String, String)>,
+    options: &GenerationOptions,
+    cancel: Option<&AtomicBool>,
+    on_chunk: &mut dyn FnMut(GenerationChunk),
+) -> Result<GenerationResult> {
+    let (messages, report) =
+        context_window::fit_conversation(state, messages, options, state.config.n_ctx, cancel)?;
+    let prompt = build_chat_prompt_for(state, &messages);
+
+    let mut result = run_generation(state, &prompt, options, cancel, on_chunk)?;
+    result.context = Some(report);
+    Ok(result)
+}
+
+/// Render a conversation into a prompt for a specific model
+pub(crate) fn build_chat_prompt_for(state: &ModelState, messages: &[(String, String)]) -> String {
+    let model_type = state.model_type;
     let model_hint = state.model_hint.as_str();
 
+    // Models without an embedded template go straight to the built-in formats
+    if state.metadata.chat_template.is_none() {
+        return format_chat_prompt_fallback(messages, model_type, model_hint);
+    }
+
     // Prefer the model's own chat template (llama.cpp apply_chat_template path).
     // This gives much better compatibility for newer reasoning families and
     // template-specific token conventions.
-    let prompt = match format_with_model_template(state, &messages) {
+    match format_with_model_template(state, messages) {
         Ok(prompt) => prompt,
         Err(error) => {
             log::warn!(
                 "Falling back to legacy prompt formatter after template failure: {}",
                 error
             );
-            format_chat_prompt_fallback(&messages, model_type, model_hint)
+            format_chat_prompt_fallback(messages, model_type, model_hint)
         }
-    };
-
-    drop(guard); // Release lock before generation
-    generate_text(prompt, max_tokens)
+    }
 }
 
 /// Format messages using the GGUF model's baked chat template via llama.cpp.
@@ -553,6 +1919,36 @@ fn format_functionary_prompt(messages: &[(String, String)]) -> String {
 /// # Returns
 /// * List of topic strings
 pub fn extract_topics(text: String, num_topics: Option<u32>) -> Result<Vec<String>> {
+    extract_topics_with_model(text, num_topics, None, None)
+}
+
+/// Longest topic the extraction grammar allows, in characters
+const MAX_TOPIC_CHARS: u32 = 64;
+
+/// Token limit that always fits `num_topics` topics of the maximum length
+///
+/// Budgets one token per character, twice over for escapes, plus the
+/// quotes, separators and whitespace the grammar allows around each item.
+fn topic_token_budget(num_topics: u32) -> u32 {
+    num_topics
+        .saturating_mul(MAX_TOPIC_CHARS * 2 + 8)
+        .saturating_add(8)
+}
+
+/// Extract key topics from text with a specific model
+///
+/// # Arguments
+/// * `text` - The text to analyze
+/// * `num_topics` - Number of topics to extract (default: 3)
+/// * `model_id` - Model to use (`None` = the default model)
+/// * `min_confidence` - Reject answers whose average token probability is
+///   lower (0.0 to 1.0, `None` = accept any answer)
+pub fn extract_topics_with_model(
+    text: String,
+    num_topics: Option<u32>,
+    model_id: Option<String>,
+    min_confidence: Option<f32>,
+) -> Result<Vec<String>> {
     let num_topics = num_topics.unwrap_or(3);
 
     let system_prompt = "You are a helpful assistant that extracts key topics from text. \
@@ -570,10 +1966,36 @@ pub fn extract_topics(text: String, num_topics: Option<u32>) -> Result<Vec<Strin
         ("user".to_string(), user_prompt),
     ];
 
-    let response = chat_completion(messages, Some(100))?;
+    // Constrain the output to exactly `num_topics` strings so it always parses,
+    // and decode greedily so the same note yields the same topics
+    let schema = serde_json::json!({
+        "type": "array",
+        "items": { "type": "string", "maxLength": MAX_TOPIC_CHARS },
+        "minItems": num_topics,
+        "maxItems": num_topics,
+    });
+    let options = GenerationOptions {
+        max_tokens: Some(topic_token_budget(num_topics)),
+        sampler: Some(SamplerConfig::greedy()),
+        grammar: Some(grammar::json_schema_to_gbnf(&schema)?),
+        model_id,
+        logprobs: min_confidence.map(|_| 0),
+        ..GenerationOptions::default()
+    };
+    let result = chat_completion_with_options(messages, options)?;
+    if let (Some(min), Some(confidence)) = (min_confidence, result.confidence()) {
+        if confidence < min {
+            return Err(anyhow!(
+                "Topic extraction confidence {:.2} is below {:.2}",
+                confidence,
+                min
+            ));
+        }
+    }
+    let response = result.text;
 
     // Parse JSON response
-    let topics: Vec<String> = serde_json::from_str(&response.trim()).map_err(|e| {
+    let topics: Vec<String> = serde_json::from_str(response.trim()).map_err(|e| {
         anyhow!(
             "Failed to parse topics JSON: {}. Response was: {}",
             e,
@@ -588,13 +2010,97 @@ pub fn extract_topics(text: String, num_topics: Option<u32>) -> Result<Vec<Strin
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_topic_token_budget() {
+        // A full-length topic never runs out of tokens, however many are asked for
+        for num_topics in [1, 3, 10] {
+            assert!(topic_token_budget(num_topics) >= num_topics * (MAX_TOPIC_CHARS + 4));
+        }
+        assert_eq!(topic_token_budget(u32::MAX), u32::MAX);
+    }
+
     #[test]
     fn test_default_config() {
         let config = InferenceConfig::default();
         assert_eq!(config.n_gpu_layers, 99);
         assert_eq!(config.n_ctx, 4096);
         assert_eq!(config.n_threads, 4);
-        assert_eq!(config.model_type, ModelType::Phi4);
+        assert_eq!(config.model_type, None);
+    }
+
+    #[test]
+    fn test_sampler_stages_default() {
+        let stages = SamplerConfig::default().stages(4096);
+        assert_eq!(
+            stages,
+            vec![
+                SamplerStage::TopK(40),
+                SamplerStage::TopP(0.9),
+                SamplerStage::MinP(0.05),
+                SamplerStage::Temperature(0.7),
+                SamplerStage::Distribution(LLAMA_DEFAULT_SEED),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_sampler_stages_greedy() {
+        assert_eq!(
+            SamplerConfig::greedy().stages(4096),
+            vec![SamplerStage::Greedy]
+        );
+
+        // Zero temperature is deterministic as well
+        let config = SamplerConfig {
+            temperature: 0.0,
+            ..SamplerConfig::default()
+        };
+        assert_eq!(config.stages(4096), vec![SamplerStage::Greedy]);
+    }
+
+    #[test]
+    fn test_sampler_stages_penalties_and_seed() {
+        let config = SamplerConfig {
+            top_k: 0,
+            top_p: 1.0,
+            min_p: 0.0,
+            repeat_penalty: 1.1,
+            presence_penalty: 0.5,
+            seed: Some(42),
+            ..SamplerConfig::default()
+        };
+        assert_eq!(
+            config.stages(4096),
+            vec![
+                SamplerStage::Penalties {
+                    last_n: 64,
+                    repeat: 1.1,
+                    frequency: 0.0,
+                    presence: 0.5,
+                },
+                SamplerStage::Temperature(0.7),
+                SamplerStage::Distribution(42),
+            ]
+        );
+
+        // -1 looks back over the whole context window
+        let config = SamplerConfig {
+            penalty_last_n: -1,
+            greedy: true,
+            ..config
+        };
+        assert_eq!(
+            config.stages(2048),
+            vec![
+                SamplerStage::Penalties {
+                    last_n: 2048,
+                    repeat: 1.1,
+                    frequency: 0.0,
+                    presence: 0.5,
+                },
+                SamplerStage::Greedy,
+            ]
+        );
     }
 
     #[test]
@@ -603,6 +2109,30 @@ mod tests {
         assert!(get_model_type().is_none());
     }
 
+    #[test]
+    fn test_resolve_model_type() {
+        let qwen = GgufMetadata {
+            architecture: Some("qwen2".to_string()),
+            ..GgufMetadata::default()
+        };
+
+        // Metadata beats a filename that says nothing about the model
+        assert_eq!(
+            resolve_model_type(None, &qwen, "/models/assistant.gguf"),
+            ModelType::Qwen
+        );
+        // An explicit type is never overwritten
+        assert_eq!(
+            resolve_model_type(Some(ModelType::Functionary), &qwen, "/models/qwen.gguf"),
+            ModelType::Functionary
+        );
+        // The filename is the last resort
+        assert_eq!(
+            resolve_model_type(None, &GgufMetadata::default(), "/models/qwen.gguf"),
+            ModelType::Qwen
+        );
+    }
+
     #[test]
     fn test_detect_model_type() {
         assert_eq!(
@@ -655,6 +2185,324 @@ mod tests {
         ); // Default
     }
 
+    #[test]
+    fn test_utf8_decoder_split_character() {
+        let mut decoder = Utf8Decoder::default();
+        let bytes = "héllo".as_bytes();
+        // "é" is two bytes; split it across two pushes
+        assert_eq!(decoder.push(&bytes[..2]), "h");
+        assert_eq!(decoder.push(&bytes[2..]), "éllo");
+        assert_eq!(decoder.finish(), "");
+    }
+
+    #[test]
+    fn test_utf8_decoder_invalid_bytes() {
+        let mut decoder = Utf8Decoder::default();
+        assert_eq!(decoder.push(&[b'a', 0xFF, b'b']), "a\u{FFFD}b");
+        assert_eq!(decoder.push(&[0xE2, 0x82]), "");
+        assert_eq!(decoder.finish(), "\u{FFFD}");
+    }
+
+    #[test]
+    fn test_find_stop_sequence() {
+        let stops = vec!["</answer>".to_string(), "\n\nUser:".to_string()];
+        assert_eq!(find_stop_sequence("hello", &stops, 0), None);
+        assert_eq!(find_stop_sequence("hello</answer>", &stops, 5), Some(5));
+        // Match that started in an earlier piece is still found
+        assert_eq!(find_stop_sequence("hello</ans", &stops, 5), None);
+        assert_eq!(
+            find_stop_sequence("hello</answer> more", &stops, 10),
+            Some(5)
+        );
+        // Empty stop sequences are ignored
+        assert_eq!(find_stop_sequence("abc", &["".to_string()], 0), None);
+    }
+
+    fn candidates(logits: &[f32]) -> Vec<LlamaTokenData> {
+        logits
+            .iter()
+            .enumerate()
+            .map(|(id, &logit)| LlamaTokenData::new(LlamaToken(id as i32), logit, 0.0))
+            .collect()
+    }
+
+    #[test]
+    fn test_candidate_logprobs() {
+        let logits = [1.0f32, 3.0, 2.0, 0.0];
+        let (log_sum, top) = candidate_logprobs(&candidates(&logits), 2);
+
+        let total: f32 = logits.iter().map(|l| l.exp()).sum();
+        assert!((2.0 - log_sum - (2.0f32.exp() / total).ln()).abs() < 1e-5);
+        let ids: Vec<LlamaToken> = top.iter().map(|(id, _)| *id).collect();
+        assert_eq!(ids, vec![LlamaToken(1), LlamaToken(2)]);
+        assert!((top[1].1 - (2.0 - log_sum)).abs() < 1e-6);
+
+        // Asking for more alternatives than the vocabulary returns them all
+        let (_, top) = candidate_logprobs(&candidates(&logits), 10);
+        assert_eq!(top.len(), 4);
+        let (_, top) = candidate_logprobs(&candidates(&logits), 0);
+        assert!(top.is_empty());
+    }
+
+    #[test]
+    fn test_candidate_logprobs_after_grammar() {
+        // The grammar rejected tokens 1 and 3, so 0 and 2 share all the mass
+        let logits = [1.0f32, f32::NEG_INFINITY, 1.0, f32::NEG_INFINITY];
+        let (log_sum, top) = candidate_logprobs(&candidates(&logits), 4);
+
+        assert!((1.0 - log_sum - 0.5f32.ln()).abs() < 1e-6);
+        assert_eq!(top.len(), 2);
+        assert!(top.iter().all(|(id, _)| id.0 % 2 == 0));
+    }
+
+    #[test]
+    fn test_mean_logprob_and_confidence() {
+        let token = |logprob: f32| TokenLogprob {
+            token: 0,
+            text: String::new(),
+            logprob,
+            top_alternatives: Vec::new(),
+        };
+        let mut result = GenerationResult {
+            text: String::new(),
+            reasoning: None,
+            reasoning_tokens: 0,
+            stop_reason: StopReason::EndOfGeneration,
+            tokens_generated: 2,
+            context: None,
+            logprobs: None,
+        };
+        assert_eq!(result.confidence(), None);
+
+        result.logprobs = Some(vec![token(-0.5), token(-1.5)]);
+        assert_eq!(result.mean_logprob(), Some(-1.0));
+        assert!((result.confidence().unwrap() - (-1.0f32).exp()).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_partial_match_len() {
+        assert_eq!(partial_match_len("hello</ans", "</answer>"), 5);
+        assert_eq!(partial_match_len("hello<", "</answer>"), 1);
+        assert_eq!(partial_match_len("hello", "</answer>"), 0);
+        // A full match is not a partial one
+        assert_eq!(partial_match_len("ab", "ab"), 0);
+        assert_eq!(partial_match_len("xa", "ab"), 1);
+        assert_eq!(partial_match_len("x\u{e9}", "\u{e9}t"), 2);
+    }
+
+    /// Feed pieces through an output filter, returning the streamed
+    /// (answer, reasoning) and whether a stop sequence matched
+    fn filter_pieces(
+        pieces: &[&str],
+        stops: &[String],
+        reasoning: bool,
+    ) -> (Vec<String>, String, bool) {
+        let splitter =
+            reasoning.then(|| ReasoningSplitter::new(&ReasoningOptions::default(), false));
+        let mut filter = OutputFilter::new(stops, splitter);
+        let mut chunks = Vec::new();
+        let mut streamed_reasoning = String::new();
+        let mut on_chunk = |chunk: GenerationChunk| {
+            if !chunk.text.is_empty() {
+                chunks.push(chunk.text);
+            }
+            streamed_reasoning.push_str(&chunk.reasoning);
+        };
+
+        let mut stopped = false;
+        for piece in pieces {
+            if filter.push(piece, &mut on_chunk) {
+                stopped = true;
+                break;
+            }
+        }
+        if !stopped {
+            stopped = filter.finish(&mut on_chunk);
+        }
+        assert_eq!(chunks.concat(), filter.answer);
+        (chunks, streamed_reasoning, stopped)
+    }
+
+    #[test]
+    fn test_output_filter_holds_back_partial_stop() {
+        let stops = vec!["</answer>".to_string()];
+
+        let (chunks, _, stopped) = filter_pieces(&["Hi", " </ans", "wer> tail"], &stops, false);
+        assert!(stopped);
+        assert_eq!(chunks, vec!["Hi", " "]);
+
+        // A false alarm is released once it stops matching
+        let (chunks, _, stopped) = filter_pieces(&["a </an", "chor"], &stops, false);
+        assert!(!stopped);
+        assert_eq!(chunks, vec!["a ", "</anchor"]);
+
+        // Held-back text is flushed when generation ends
+        let (chunks, _, stopped) = filter_pieces(&["a </"], &stops, false);
+        assert!(!stopped);
+        assert_eq!(chunks, vec!["a ", "</"]);
+    }
+
+    #[test]
+    fn test_reasoning_is_off_for_constrained_output() {
+        let options = GenerationOptions::default();
+        assert!(options.resolve_reasoning(true).is_some());
+        assert!(options.resolve_reasoning(false).is_none());
+
+        let options = GenerationOptions {
+            json_schema: Some(r#"{"type": "string"}"#.to_string()),
+            reasoning: Some(ReasoningOptions::default()),
+            ..GenerationOptions::default()
+        };
+        assert!(options.resolve_reasoning(true).is_none());
+
+        let options = GenerationOptions {
+            grammar: Some("root ::= \"yes\"".to_string()),
+            ..GenerationOptions::default()
+        };
+        assert!(options.resolve_reasoning(true).is_none());
+    }
+
+    #[test]
+    fn test_output_filter_separates_reasoning() {
+        // Stop sequences inside the reasoning trace do not end generation
+        let stops = vec!["\n\n".to_string()];
+        let (chunks, reasoning, stopped) = filter_pieces(
+            &[
+                "<think>",
+                "step one\n\nstep two",
+                "</think>\n\n",
+                "4",
+                "\n\nmore",
+            ],
+            &stops,
+            true,
+        );
+        assert!(stopped);
+        assert_eq!(chunks, vec!["4"]);
+        assert_eq!(reasoning, "step one\n\nstep two");
+    }
+
+    /// Register a request that keeps producing output until it is cancelled
+    fn spawn_endless_request() -> u64 {
+        spawn_request(|cancel, on_chunk| {
+            let mut text = String::new();
+            let mut tokens_generated = 0;
+            while !cancel.load(Ordering::Relaxed) {
+                text.push_str("ab");
+                tokens_generated += 1;
+                on_chunk(GenerationChunk {
+                    text: "ab".to_string(),
+                    reasoning: String::new(),
+                    stop_reason: None,
+                });
+                std::thread::sleep(std::time::Duration::from_millis(1));
+            }
+            Ok(GenerationResult {
+                text,
+                reasoning: None,
+                stop_reason: StopReason::Cancelled,
+                tokens_generated,
+                reasoning_tokens: 0,
+                context: None,
+                logprobs: None,
+            })
+        })
+    }
+
+    #[test]
+    fn test_cancel_returns_partial_output() {
+        let id = spawn_endless_request();
+
+        let mut streamed = String::new();
+        while streamed.is_empty() {
+            streamed.push_str(&poll_generation(id).unwrap().text);
+            std::thread::sleep(std::time::Duration::from_millis(1));
+        }
+
+        assert!(cancel_generation(id));
+        let result = wait_for_generation(id).unwrap();
+        assert_eq!(result.stop_reason, StopReason::Cancelled);
+        assert!(result.text.starts_with(&streamed));
+        assert!(result.tokens_generated > 0);
+
+        // The handle is released once the result has been claimed
+        assert!(!cancel_generation(id));
+        assert!(poll_generation(id).is_err());
+    }
+
+    #[test]
+    fn test_poll_returns_result_once() {
+        let id = spawn_request(|_, on_chunk| {
+            on_chunk(GenerationChunk {
+                text: "done".to_string(),
+                reasoning: "hmm".to_string(),
+                stop_reason: None,
+            });
+            Ok(GenerationResult {
+                text: "done".to_string(),
+                reasoning: Some("hmm".to_string()),
+                stop_reason: StopReason::EndOfGeneration,
+                tokens_generated: 2,
+                reasoning_tokens: 1,
+                context: None,
+                logprobs: None,
+            })
+        });
+
+        let mut text = String::new();
+        let mut reasoning = String::new();
+        let result = loop {
+            let poll = poll_generation(id).unwrap();
+            text.push_str(&poll.text);
+            reasoning.push_str(&poll.reasoning);
+            if let Some(result) = poll.result {
+                break result;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(1));
+        };
+
+        assert_eq!(text, "done");
+        assert_eq!(reasoning, "hmm");
+        assert_eq!(result.stop_reason, StopReason::EndOfGeneration);
+        assert!(wait_for_generation(id).is_err());
+    }
+
+    #[test]
+    fn test_unclaimed_requests_are_released() {
+        let id = spawn_request(|_, _| Err(anyhow!("never claimed")));
+        let request = find_request(id).unwrap();
+        while !request.progress.lock().done {
+            std::thread::sleep(std::time::Duration::from_millis(1));
+        }
+
+        // Finished moments ago: kept for the caller to claim
+        spawn_request(|_, _| Err(anyhow!("unused")));
+        assert!(find_request(id).is_ok());
+
+        // Finished longer ago than the TTL: released by the next request
+        let expired = Instant::now().checked_sub(UNCLAIMED_REQUEST_TTL * 2);
+        if let Some(expired) = expired {
+            request.progress.lock().finished_at = Some(expired);
+            spawn_request(|_, _| Err(anyhow!("unused")));
+            assert!(find_request(id).is_err());
+        }
+    }
+
+    #[test]
+    fn test_start_generation_requires_model() {
+        assert!(start_generation("hi".to_string(), GenerationOptions::default()).is_err());
+        assert!(!cancel_generation(u64::MAX));
+    }
+
+    #[test]
+    fn test_unknown_model_id() {
+        let err = current_state(Some("missing")).err().unwrap();
+        assert_eq!(err.to_string(), "Model not loaded: missing");
+        assert!(!unload_model_by_id("missing"));
+        assert!(set_default_model("missing").is_err());
+        assert!(load_model("x".to_string(), "/nonexistent.gguf".to_string(), None).is_err());
+    }
+
     #[test]
     fn test_phi4_prompt_format() {
         let messages = vec![
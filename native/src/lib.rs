@@ -2,22 +2,64 @@
 //!
 //! This library provides the native Rust backend for Kivixa's AI features:
 //! - Multi-model inference engine with llama.cpp (Phi-4, Qwen, Functionary)
+//! - Pluggable inference backends, including a deterministic mock for tests
+//! - GGUF metadata inspection for model details and template selection
+//! - Persistent chat sessions with KV-cache reuse
+//! - Context-window management for long conversations
+//! - Tokenizer utilities for exact token counts
+//! - Reasoning-trace separation for thinking models
+//! - Model registry with LRU eviction under a memory budget
 //! - Model Context Protocol (MCP) for AI-powered tool execution
+//! - Agent loop that executes tool calls and feeds results back
+//! - Tool-call parsing for each model family's output format
 //! - Knowledge graph with force-directed layout
+//! - Grammar-constrained decoding from JSON Schemas
 //! - Vector embeddings for semantic search
+//! - HNSW approximate nearest-neighbor index for large collections
+//! - Hybrid BM25 keyword + vector search with rank fusion
+//! - Metadata filters and MMR diversification for search results
+//! - Persistent, memory-mapped vector store with incremental upserts
+//! - Int8 and binary vector quantization with full-precision rescoring
+//! - Near-duplicate detection for notes and copied passages
+//! - Retrieval-augmented chat over notes with citations
+//! - Map-reduce summarization of long notes and transcripts
+//! - Dedicated embedding model with batched decoding and chunking
+//! - On-disk embedding cache keyed by content and model
+//! - Token-aware markdown chunking for indexing notes
 //! - K-Means clustering for automatic note grouping
 //! - Quadtree spatial indexing for viewport culling
 //! - Streaming graph simulation at 60fps
 
 mod frb_generated;
 
+pub mod agent;
 pub mod api;
+pub mod backend;
+pub mod chunker;
 pub mod clustering;
+pub mod context_window;
+pub mod dedup;
+pub mod embedder;
+pub mod embedding_cache;
 pub mod embeddings;
+pub mod filtered_search;
+pub mod gguf;
+pub mod grammar;
 pub mod graph;
+pub mod hnsw;
+pub mod hybrid_search;
 pub mod inference;
 pub mod mcp;
+pub mod quantization;
 pub mod quadtree;
+pub mod rag;
+pub mod reasoning;
+pub mod registry;
+pub mod session;
 pub mod streaming;
+pub mod summarize;
+pub mod tokenizer;
+pub mod tool_parser;
+pub mod vector_store;
 
 pub use api::*;
@@ -12,6 +12,10 @@ use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
+use crate::grammar;
+use crate::inference::{self, GenerationOptions, SamplerConfig};
+use crate::tool_parser;
+
 
 // Tool Definitions
 
@@ -187,6 +191,35 @@ impl MCPTool {
         }
     }
 
+    /// JSON Schema for this tool's parameters object
+    pub fn parameters_schema(&self) -> serde_json::Value {
+        let parameters = self.parameters();
+
+        let properties: serde_json::Map<String, serde_json::Value> = parameters
+            .iter()
+            .map(|p| {
+                (
+                    p.name.clone(),
+                    serde_json::json!({
+                        "type": p.param_type.json_type(),
+                        "description": p.description,
+                    }),
+                )
+            })
+            .collect();
+        let required: Vec<&str> = parameters
+            .iter()
+            .filter(|p| p.required)
+            .map(|p| p.name.as_str())
+            .collect();
+
+        serde_json::json!({
+            "type": "object",
+            "properties": properties,
+            "required": required,
+        })
+    }
+
     /// Parse tool from string name
     pub fn from_name(name: &str) -> Option<MCPTool> {
         match name {
@@ -213,6 +246,18 @@ pub enum MCPParamType {
     Array,
 }
 
+impl MCPParamType {
+    /// JSON Schema type name for this parameter type
+    pub fn json_type(&self) -> &'static str {
+        match self {
+            MCPParamType::String => "string",
+            MCPParamType::Boolean => "boolean",
+            MCPParamType::Integer => "integer",
+            MCPParamType::Array => "array",
+        }
+    }
+}
+
 /// Parameter definition for MCP tools
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[frb]
@@ -655,12 +700,7 @@ pub fn get_tool_schemas() -> String {
                     serde_json::json!({
                         "name": p.name,
                         "description": p.description,
-                        "type": match p.param_type {
-                            MCPParamType::String => "string",
-                            MCPParamType::Boolean => "boolean",
-                            MCPParamType::Integer => "integer",
-                            MCPParamType::Array => "array",
-                        },
+                        "type": p.param_type.json_type(),
                         "required": p.required
                     })
                 })
@@ -677,9 +717,118 @@ pub fn get_tool_schemas() -> String {
     serde_json::to_string_pretty(&tools).unwrap_or_default()
 }
 
+/// JSON Schema for a single tool call to any of `tools`
+///
+/// Matches `{"tool": ..., "parameters": {...}, "description": ...}` with the
+/// parameters object checked against the chosen tool's parameter list.
+pub fn tool_call_schema(tools: &[MCPTool]) -> serde_json::Value {
+    let variants: Vec<serde_json::Value> = tools
+        .iter()
+        .map(|tool| {
+            serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "tool": { "const": tool.name() },
+                    "parameters": tool.parameters_schema(),
+                    "description": { "type": "string" },
+                },
+                "required": ["tool", "parameters", "description"],
+            })
+        })
+        .collect();
+
+    serde_json::json!({ "anyOf": variants })
+}
+
+/// GBNF grammar that constrains generation to a valid tool call
+pub fn tool_call_grammar(tools: &[MCPTool]) -> Result<String> {
+    grammar::json_schema_to_gbnf(&tool_call_schema(tools))
+}
+
+/// Tool call as written by a model, with parameters inline or pre-serialized
+///
+/// The OpenAI-style `name` / `arguments` keys are accepted as well.
+#[derive(Deserialize)]
+struct RawToolCall {
+    #[serde(alias = "name")]
+    tool: String,
+    #[serde(default, alias = "arguments")]
+    parameters: Option<serde_json::Value>,
+    #[serde(default)]
+    parameters_json: Option<String>,
+    #[serde(default)]
+    description: String,
+}
+
 /// Parse a tool call from JSON
+///
+/// Accepts either a `parameters` object or a `parameters_json` string.
 pub fn parse_tool_call(json: &str) -> Result<MCPToolCall> {
-    serde_json::from_str(json).map_err(|e| anyhow!("Failed to parse tool call: {}", e))
+    let raw: RawToolCall =
+        serde_json::from_str(json).map_err(|e| anyhow!("Failed to parse tool call: {}", e))?;
+
+    let parameters_json = match (raw.parameters_json, raw.parameters) {
+        (Some(parameters_json), _) => parameters_json,
+        (None, Some(parameters)) => parameters.to_string(),
+        (None, None) => "{}".to_string(),
+    };
+
+    Ok(MCPToolCall {
+        tool: raw.tool,
+        parameters_json,
+        description: raw.description,
+    })
+}
+
+/// Parse every tool call embedded in a model reply
+///
+/// Accepts `<tool_call>` tags, fenced JSON and bare JSON objects shaped like
+/// a call (`tool`, or `name` with `arguments`); prose around them is ignored.
+/// Use `tool_parser::parse_reply` for a model family's native format.
+pub fn parse_tool_calls(reply: &str) -> Vec<MCPToolCall> {
+    tool_parser::parse_reply(reply, None).tool_calls
+}
+
+/// Ask the loaded model for a tool call, constrained so the output always parses
+///
+/// The tool schemas are appended to the system message (or added as one)
+/// so the model knows what each tool does.
+///
+/// # Arguments
+/// * `messages` - Conversation as (role, content) pairs
+/// * `max_tokens` - Optional override for max tokens to generate
+/// * `model_id` - Model to run on (`None` = the default model)
+///
+/// # Returns
+/// * The parsed tool call
+pub fn generate_tool_call(
+    mut messages: Vec<(String, String)>,
+    max_tokens: Option<u32>,
+    model_id: Option<String>,
+) -> Result<MCPToolCall> {
+    let tools = MCPTool::all();
+    let instructions = format!(
+        "Respond with a single tool call as JSON: \
+        {{\"tool\": \"tool_name\", \"parameters\": {{...}}, \"description\": \"what this action does\"}}\n\n\
+        Available tools:\n{}",
+        get_tool_schemas()
+    );
+    match messages.first_mut() {
+        Some((role, content)) if role == "system" => {
+            content.push_str("\n\n");
+            content.push_str(&instructions);
+        }
+        _ => messages.insert(0, ("system".to_string(), instructions)),
+    }
+
+    let options = GenerationOptions {
+        max_tokens,
+        grammar: Some(tool_call_grammar(&tools)?),
+        model_id,
+        ..GenerationOptions::default()
+    };
+    let response = inference::chat_completion_with_options(messages, options)?;
+    parse_tool_call(&response.text)
 }
 
 /// Execute a tool call
@@ -904,6 +1053,62 @@ pub fn classify_task(message: &str) -> TaskCategory {
     TaskCategory::Conversation
 }
 
+/// Confidence below which the model classifier defers to keywords
+pub const DEFAULT_CLASSIFIER_CONFIDENCE: f32 = 0.5;
+
+/// Classify a user message with a loaded model
+///
+/// The model answers with a category name under a grammar. When the average
+/// probability of its answer is below `min_confidence`, the keyword
+/// classifier decides instead.
+///
+/// # Arguments
+/// * `message` - User message to classify
+/// * `model_id` - Model to ask (`None` = the default model)
+/// * `min_confidence` - Required average token probability (0.0 to 1.0)
+pub fn classify_task_with_model(
+    message: &str,
+    model_id: Option<String>,
+    min_confidence: f32,
+) -> Result<TaskCategory> {
+    let messages = vec![
+        (
+            "system".to_string(),
+            "Classify the user's request. Answer conversation for questions and chat, \
+            tool_use for actions on files, folders, calendar events or timers, and \
+            code_generation for writing or fixing code."
+                .to_string(),
+        ),
+        ("user".to_string(), message.to_string()),
+    ];
+    let options = GenerationOptions {
+        max_tokens: Some(8),
+        sampler: Some(SamplerConfig::greedy()),
+        grammar: Some(r#"root ::= "conversation" | "tool_use" | "code_generation""#.to_string()),
+        model_id,
+        logprobs: Some(0),
+        ..GenerationOptions::default()
+    };
+    let result = inference::chat_completion_with_options(messages, options)?;
+
+    let confident = result
+        .confidence()
+        .is_some_and(|confidence| confidence >= min_confidence);
+    Ok(parse_task_category(&result.text)
+        .filter(|_| confident)
+        .unwrap_or_else(|| classify_task(message)))
+}
+
+/// Parse the category name produced by `classify_task_with_model`
+fn parse_task_category(label: &str) -> Option<TaskCategory> {
+    match label.trim() {
+        "conversation" => Some(TaskCategory::Conversation),
+        "tool_use" => Some(TaskCategory::ToolUse),
+        "code_generation" => Some(TaskCategory::CodeGeneration),
+        _ => None,
+    }
+}
+
 /// Get recommended model type for a task category
 pub fn get_model_for_task(category: TaskCategory) -> crate::inference::ModelType {
     match category {
@@ -922,6 +1127,15 @@ pub fn get_model_name_for_task(category: TaskCategory) -> &'static str {
     }
 }
 
+/// Get the id of a loaded model suited to a task category
+///
+/// # Returns
+/// * The most recently used loaded model of the recommended type, or `None`
+///   if no such model is loaded (callers then fall back to the default model)
+pub fn get_model_id_for_task(category: TaskCategory) -> Option<String> {
+    crate::inference::find_model_by_type(get_model_for_task(category))
+}
+
 
 // Tests
 
@@ -1033,6 +1247,34 @@ mod tests {
         drop(temp);
     }
 
+    #[test]
+    fn test_parse_tool_calls_from_reply() {
+        let reply = r#"Let me check. {"tool": "list_files", "parameters": {"path": "notes"}}
+<tool_call>
+{"name": "read_file", "arguments": {"path": "a {b}.md"}}
+</tool_call>
+Some other JSON: {"name": "not a call"}"#;
+        let calls = parse_tool_calls(reply);
+        assert_eq!(calls.len(), 2);
+        assert_eq!(calls[0].tool, "list_files");
+        assert_eq!(calls[1].tool, "read_file");
+        assert_eq!(calls[1].get_parameters()["path"], "a {b}.md");
+
+        assert!(parse_tool_calls("No tools needed.").is_empty());
+        assert!(parse_tool_calls("{\"tool\": \"read_file\"").is_empty());
+    }
+
+    #[test]
+    fn test_parse_task_category() {
+        assert_eq!(parse_task_category("tool_use"), Some(TaskCategory::ToolUse));
+        assert_eq!(
+            parse_task_category(" code_generation\n"),
+            Some(TaskCategory::CodeGeneration)
+        );
+        assert_eq!(parse_task_category("chat"), None);
+        assert!(classify_task_with_model("hi", Some("missing".to_string()), 0.5).is_err());
+    }
+
     #[test]
     fn test_task_classification() {
         // Tool use
@@ -1127,6 +1369,34 @@ mod tests {
         assert_eq!(params.get("path").and_then(|v| v.as_str()), Some("test.md"));
     }
 
+    #[test]
+    fn test_tool_call_parsing_inline_parameters() {
+        let json = r#"{"tool": "list_files", "parameters": {"path": "notes", "recursive": true}, "description": "List notes"}"#;
+
+        let call = parse_tool_call(json).unwrap();
+        assert_eq!(call.tool, "list_files");
+        assert_eq!(call.description, "List notes");
+        let params = call.get_parameters();
+        assert_eq!(params.get("path").and_then(|v| v.as_str()), Some("notes"));
+        assert_eq!(
+            params.get("recursive").and_then(|v| v.as_bool()),
+            Some(true)
+        );
+    }
+
+    #[test]
+    fn test_tool_call_grammar() {
+        let schema = MCPTool::WriteFile.parameters_schema();
+        assert_eq!(schema["required"], serde_json::json!(["path", "content"]));
+        assert_eq!(schema["properties"]["append"]["type"], "boolean");
+
+        let grammar = tool_call_grammar(&MCPTool::all()).unwrap();
+        assert!(grammar.starts_with("root ::= "));
+        for tool in MCPTool::all() {
+            assert!(grammar.contains(&format!(r#""\"{}\"""#, tool.name())));
+        }
+    }
+
     #[test]
     fn test_tool_execution() {
         let temp = setup_mcp();
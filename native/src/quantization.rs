@@ -0,0 +1,804 @@
+//! Quantized Embedding Search
+//!
+//! Shrinks vectors held in memory for similarity search:
+//! - Int8: one signed byte per dimension plus a per-vector scale (4x smaller)
+//! - Binary: one sign bit per dimension (32x smaller), compared by Hamming
+//!   distance
+//!
+//! The quantized scan picks candidates; an optional rescoring pass with the
+//! full-precision vectors (e.g. from a memory-mapped vector store) restores
+//! the exact cosine ranking. `measure_recall` reports how close the results
+//! come to an exact search.
+//!
+//! An index built from a vector store follows the store's upserts and
+//! deletes. Indexes are saved as magic `KVXQNT01`, kind, dimension and count
+//! (u32), then per entry its id, optional preview and codes, little-endian.
+
+use anyhow::{anyhow, bail, Result};
+use once_cell::sync::Lazy;
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::io::Write;
+use std::path::Path;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+
+use crate::embeddings::{cosine_similarity, find_similar, EmbeddingEntry, SimilarityResult};
+use crate::hnsw::{write_str, Reader};
+use crate::vector_store;
+
+const MAGIC: &[u8; 8] = b"KVXQNT01";
+
+/// How vectors are compressed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum QuantizationKind {
+    /// Scalar quantization to signed bytes
+    #[default]
+    Int8,
+    /// One sign bit per dimension
+    Binary,
+}
+
+/// Quantized vectors with their ids, searched by brute force
+#[derive(Debug, Clone)]
+pub struct QuantizedIndex {
+    kind: QuantizationKind,
+    dimension: usize,
+    ids: Vec<String>,
+    previews: Vec<Option<String>>,
+    positions: HashMap<String, usize>,
+    /// Int8 codes, `dimension` per vector
+    codes: Vec<i8>,
+    /// Int8 scale of each vector
+    scales: Vec<f32>,
+    /// Sign bits, `words()` per vector
+    bits: Vec<u64>,
+}
+
+impl QuantizedIndex {
+    /// Create an empty index
+    pub fn new(kind: QuantizationKind) -> Self {
+        Self {
+            kind,
+            dimension: 0,
+            ids: Vec::new(),
+            previews: Vec::new(),
+            positions: HashMap::new(),
+            codes: Vec::new(),
+            scales: Vec::new(),
+            bits: Vec::new(),
+        }
+    }
+
+    /// Build an index from entries
+    pub fn from_entries(entries: &[EmbeddingEntry], kind: QuantizationKind) -> Result<Self> {
+        let mut index = Self::new(kind);
+        for entry in entries {
+            index.insert(entry)?;
+        }
+        Ok(index)
+    }
+
+    /// Quantization used by this index
+    pub fn kind(&self) -> QuantizationKind {
+        self.kind
+    }
+
+    /// Number of indexed vectors
+    pub fn len(&self) -> usize {
+        self.ids.len()
+    }
+
+    /// Whether the index has no vectors
+    pub fn is_empty(&self) -> bool {
+        self.ids.is_empty()
+    }
+
+    /// Bytes used by the quantized vectors
+    pub fn vector_bytes(&self) -> usize {
+        self.codes.len() + self.scales.len() * 4 + self.bits.len() * 8
+    }
+
+    /// Insert an entry, replacing any entry with the same id
+    pub fn insert(&mut self, entry: &EmbeddingEntry) -> Result<()> {
+        self.insert_vector(&entry.id, &entry.vector, entry.text_preview.clone())
+    }
+
+    /// Insert a vector by id, replacing any vector with the same id
+    pub fn insert_vector(
+        &mut self,
+        id: &str,
+        vector: &[f32],
+        text_preview: Option<String>,
+    ) -> Result<()> {
+        if vector.is_empty() {
+            bail!("Cannot quantize an empty vector for {}", id);
+        }
+        if self.dimension == 0 {
+            self.dimension = vector.len();
+        } else if vector.len() != self.dimension {
+            bail!(
+                "Vector for {} has dimension {}, the index has {}",
+                id,
+                vector.len(),
+                self.dimension
+            );
+        }
+        self.remove(id);
+
+        match self.kind {
+            QuantizationKind::Int8 => {
+                let (codes, scale) = quantize_int8(vector);
+                self.codes.extend_from_slice(&codes);
+                self.scales.push(scale);
+            }
+            QuantizationKind::Binary => self.bits.extend(quantize_binary(vector)),
+        }
+        self.positions.insert(id.to_string(), self.ids.len());
+        self.ids.push(id.to_string());
+        self.previews.push(text_preview);
+        Ok(())
+    }
+
+    /// Remove an entry by id
+    ///
+    /// # Returns
+    /// * `true` if the entry existed
+    pub fn remove(&mut self, id: &str) -> bool {
+        let Some(position) = self.positions.remove(id) else {
+            return false;
+        };
+        // Move the last vector into the freed slot
+        let last = self.ids.len() - 1;
+        match self.kind {
+            QuantizationKind::Int8 => {
+                let dimension = self.dimension;
+                self.codes
+                    .copy_within(last * dimension.., position * dimension);
+                self.codes.truncate(last * dimension);
+                self.scales.swap_remove(position);
+            }
+            QuantizationKind::Binary => {
+                let words = self.words();
+                self.bits.copy_within(last * words.., position * words);
+                self.bits.truncate(last * words);
+            }
+        }
+        self.ids.swap_remove(position);
+        self.previews.swap_remove(position);
+        if position < last {
+            self.positions.insert(self.ids[position].clone(), position);
+        }
+        true
+    }
+
+    /// Approximate search on the quantized vectors
+    ///
+    /// Int8 scores approximate cosine similarity; binary scores estimate it
+    /// from the angle implied by the Hamming distance.
+    ///
+    /// # Arguments
+    /// * `query` - The query embedding vector (full precision)
+    /// * `top_k` - Number of results to return
+    /// * `threshold` - Minimum approximate similarity
+    ///
+    /// # Returns
+    /// * Results sorted by approximate score descending
+    pub fn search(&self, query: &[f32], top_k: usize, threshold: f32) -> Vec<SimilarityResult> {
+        if query.len() != self.dimension || self.is_empty() {
+            return Vec::new();
+        }
+        let mut scored: Vec<(f32, usize)> = match self.kind {
+            QuantizationKind::Int8 => {
+                let (query_codes, query_scale) = quantize_int8(query);
+                self.codes
+                    .chunks_exact(self.dimension)
+                    .zip(&self.scales)
+                    .map(|(codes, scale)| {
+                        dot_int8(&query_codes, codes) as f32 * query_scale * scale
+                    })
+                    .enumerate()
+                    .map(|(i, score)| (score, i))
+                    .collect()
+            }
+            QuantizationKind::Binary => {
+                let query_bits = quantize_binary(query);
+                let dimension = self.dimension as f32;
+                self.bits
+                    .chunks_exact(self.words())
+                    .map(|bits| {
+                        let distance = hamming(&query_bits, bits) as f32;
+                        (std::f32::consts::PI * distance / dimension).cos()
+                    })
+                    .enumerate()
+                    .map(|(i, score)| (score, i))
+                    .collect()
+            }
+        };
+        scored.retain(|(score, _)| *score >= threshold);
+        top_by_score(&mut scored, top_k);
+        scored
+            .into_iter()
+            .map(|(score, i)| SimilarityResult {
+                id: self.ids[i].clone(),
+                score,
+                text_preview: self.previews[i].clone(),
+            })
+            .collect()
+    }
+
+    /// Approximate search followed by exact rescoring of the best candidates
+    ///
+    /// # Arguments
+    /// * `query` - The query embedding vector
+    /// * `top_k` - Number of results to return
+    /// * `threshold` - Minimum exact cosine similarity
+    /// * `candidates` - Quantized results to rescore (at least `top_k`)
+    /// * `full_vector` - Full-precision vector by id
+    ///
+    /// # Returns
+    /// * Results sorted by exact score descending
+    pub fn search_rescored(
+        &self,
+        query: &[f32],
+        top_k: usize,
+        threshold: f32,
+        candidates: usize,
+        full_vector: impl Fn(&str) -> Option<Vec<f32>>,
+    ) -> Vec<SimilarityResult> {
+        let mut results: Vec<SimilarityResult> = self
+            .search(query, candidates.max(top_k), f32::NEG_INFINITY)
+            .into_iter()
+            .filter_map(|result| {
+                let vector = full_vector(&result.id)?;
+                Some(SimilarityResult {
+                    score: cosine_similarity(query, &vector),
+                    ..result
+                })
+            })
+            .filter(|result| result.score >= threshold)
+            .collect();
+        results.sort_by(|a, b| b.score.total_cmp(&a.score));
+        results.truncate(top_k);
+        results
+    }
+
+    /// Save the quantized codes to a file
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let mut out = Vec::with_capacity(self.vector_bytes() + MAGIC.len() + 12);
+        out.extend_from_slice(MAGIC);
+        let kind = match self.kind {
+            QuantizationKind::Int8 => 0u32,
+            QuantizationKind::Binary => 1,
+        };
+        for value in [kind, self.dimension as u32, self.ids.len() as u32] {
+            out.extend_from_slice(&value.to_le_bytes());
+        }
+        for (i, id) in self.ids.iter().enumerate() {
+            write_str(&mut out, id);
+            match &self.previews[i] {
+                Some(preview) => {
+                    out.push(1);
+                    write_str(&mut out, preview);
+                }
+                None => out.push(0),
+            }
+            match self.kind {
+                QuantizationKind::Int8 => {
+                    let codes = &self.codes[i * self.dimension..(i + 1) * self.dimension];
+                    out.extend(codes.iter().map(|&code| code as u8));
+                    out.extend_from_slice(&self.scales[i].to_le_bytes());
+                }
+                QuantizationKind::Binary => {
+                    let words = self.words();
+                    for word in &self.bits[i * words..(i + 1) * words] {
+                        out.extend_from_slice(&word.to_le_bytes());
+                    }
+                }
+            }
+        }
+
+        let tmp_path = path.with_extension("tmp");
+        let mut file = fs::File::create(&tmp_path)?;
+        file.write_all(&out)?;
+        file.sync_all()?;
+        fs::rename(&tmp_path, path)?;
+        Ok(())
+    }
+
+    /// Load an index saved with `save`
+    pub fn load(path: &Path) -> Result<Self> {
+        let bytes = fs::read(path)?;
+        if !bytes.starts_with(MAGIC) {
+            bail!("{} is not a quantized index", path.display());
+        }
+        let mut reader = Reader::new(&bytes, MAGIC.len());
+        let kind = match reader.u32()? {
+            0 => QuantizationKind::Int8,
+            1 => QuantizationKind::Binary,
+            other => bail!("Unknown quantization kind {}", other),
+        };
+        let mut index = Self::new(kind);
+        index.dimension = reader.u32()? as usize;
+        let count = reader.u32()? as usize;
+        let code_len = match kind {
+            QuantizationKind::Int8 => index.dimension + 4,
+            QuantizationKind::Binary => index.words() * 8,
+        };
+        // Id length, preview flag and codes
+        reader.ensure(count, code_len + 5)?;
+
+        for _ in 0..count {
+            let id = reader.string()?;
+            let preview = match reader.u8()? {
+                0 => None,
+                _ => Some(reader.string()?),
+            };
+            match kind {
+                QuantizationKind::Int8 => {
+                    let codes = reader.take(index.dimension)?;
+                    index.codes.extend(codes.iter().map(|&code| code as i8));
+                    index.scales.push(f32::from_bits(reader.u32()?));
+                }
+                QuantizationKind::Binary => {
+                    for _ in 0..index.words() {
+                        index.bits.push(reader.u64()?);
+                    }
+                }
+            }
+            if index
+                .positions
+                .insert(id.clone(), index.ids.len())
+                .is_some()
+            {
+                bail!("Corrupt quantized index: duplicate id {}", id);
+            }
+            index.ids.push(id);
+            index.previews.push(preview);
+        }
+        Ok(index)
+    }
+
+    /// 64-bit words per binary code
+    fn words(&self) -> usize {
+        self.dimension.div_ceil(64)
+    }
+}
+
+/// Int8 codes of a vector after L2 normalization, with the scale that maps
+/// codes back to values
+pub fn quantize_int8(vector: &[f32]) -> (Vec<i8>, f32) {
+    let norm = vector.iter().map(|x| x * x).sum::<f32>().sqrt();
+    let max = vector.iter().fold(0.0f32, |max, x| max.max(x.abs()));
+    if norm == 0.0 || max == 0.0 {
+        return (vec![0; vector.len()], 0.0);
+    }
+    let scale = max / norm / 127.0;
+    let codes = vector
+        .iter()
+        .map(|x| (x / norm / scale).round().clamp(-127.0, 127.0) as i8)
+        .collect();
+    (codes, scale)
+}
+
+/// Sign bits of a vector, packed little-endian into 64-bit words
+pub fn quantize_binary(vector: &[f32]) -> Vec<u64> {
+    let mut words = vec![0u64; vector.len().div_ceil(64)];
+    for (i, value) in vector.iter().enumerate() {
+        if *value > 0.0 {
+            words[i / 64] |= 1 << (i % 64);
+        }
+    }
+    words
+}
+
+/// Int8 dot product in fixed-width lanes the compiler can vectorize
+fn dot_int8(a: &[i8], b: &[i8]) -> i32 {
+    const LANES: usize = 16;
+    let mut lanes = [0i32; LANES];
+    let (a_chunks, b_chunks) = (a.chunks_exact(LANES), b.chunks_exact(LANES));
+    let tail: i32 = a_chunks
+        .remainder()
+        .iter()
+        .zip(b_chunks.remainder())
+        .map(|(x, y)| *x as i32 * *y as i32)
+        .sum();
+    for (x, y) in a_chunks.zip(b_chunks) {
+        for lane in 0..LANES {
+            lanes[lane] += x[lane] as i32 * y[lane] as i32;
+        }
+    }
+    lanes.iter().sum::<i32>() + tail
+}
+
+fn hamming(a: &[u64], b: &[u64]) -> u32 {
+    a.iter().zip(b).map(|(x, y)| (x ^ y).count_ones()).sum()
+}
+
+/// Keep the `top_k` highest scores, sorted descending
+fn top_by_score(scored: &mut Vec<(f32, usize)>, top_k: usize) {
+    if top_k == 0 {
+        scored.clear();
+        return;
+    }
+    if scored.len() > top_k {
+        scored.select_nth_unstable_by(top_k - 1, |a, b| b.0.total_cmp(&a.0));
+        scored.truncate(top_k);
+    }
+    scored.sort_by(|a, b| b.0.total_cmp(&a.0).then(a.1.cmp(&b.1)));
+}
+
+/// Recall of quantized search against exact search
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecallReport {
+    /// Queries evaluated
+    pub queries: usize,
+    /// Results compared per query
+    pub top_k: usize,
+    /// Fraction of the exact top-k found by the quantized scan alone
+    pub recall: f32,
+    /// Fraction of the exact top-k found after rescoring
+    pub rescored_recall: f32,
+    /// Bytes of the f32 vectors
+    pub f32_bytes: usize,
+    /// Bytes of the quantized vectors
+    pub quantized_bytes: usize,
+}
+
+/// Measure how well quantized search matches exact cosine search
+///
+/// # Arguments
+/// * `entries` - Entries to index
+/// * `queries` - Query vectors (empty = up to 100 of the entries' own vectors)
+/// * `kind` - Quantization to evaluate
+/// * `top_k` - Results compared per query
+/// * `rescore_candidates` - Candidates rescored with full precision
+///
+/// # Returns
+/// * Average recall with and without rescoring, and memory use
+pub fn measure_recall(
+    entries: &[EmbeddingEntry],
+    queries: &[Vec<f32>],
+    kind: QuantizationKind,
+    top_k: usize,
+    rescore_candidates: usize,
+) -> Result<RecallReport> {
+    let index = QuantizedIndex::from_entries(entries, kind)?;
+    let vectors: HashMap<&str, &Vec<f32>> = entries
+        .iter()
+        .map(|entry| (entry.id.as_str(), &entry.vector))
+        .collect();
+    let sample: Vec<Vec<f32>>;
+    let queries = if queries.is_empty() {
+        let step = (entries.len() / 100).max(1);
+        sample = entries
+            .iter()
+            .step_by(step)
+            .take(100)
+            .map(|entry| entry.vector.clone())
+            .collect();
+        &sample
+    } else {
+        queries
+    };
+    if queries.is_empty() {
+        bail!("No queries to measure recall with");
+    }
+
+    let ids = |results: Vec<SimilarityResult>| -> HashSet<String> {
+        results.into_iter().map(|result| result.id).collect()
+    };
+    let (mut found, mut found_rescored, mut total) = (0, 0, 0);
+    for query in queries {
+        let exact = ids(find_similar(query, entries, top_k, f32::NEG_INFINITY));
+        let approximate = ids(index.search(query, top_k, f32::NEG_INFINITY));
+        let rescored =
+            ids(
+                index.search_rescored(query, top_k, f32::NEG_INFINITY, rescore_candidates, |id| {
+                    vectors.get(id).map(|vector| vector.to_vec())
+                }),
+            );
+        found += exact.intersection(&approximate).count();
+        found_rescored += exact.intersection(&rescored).count();
+        total += exact.len();
+    }
+
+    let total = total.max(1) as f32;
+    Ok(RecallReport {
+        queries: queries.len(),
+        top_k,
+        recall: found as f32 / total,
+        rescored_recall: found_rescored as f32 / total,
+        f32_bytes: entries.iter().map(|entry| entry.vector.len() * 4).sum(),
+        quantized_bytes: index.vector_bytes(),
+    })
+}
+
+// Open quantized indexes
+
+struct RegisteredIndex {
+    index: QuantizedIndex,
+    /// Vector store holding the full-precision vectors, for rescoring
+    store_id: Option<u64>,
+}
+
+static INDEXES: Lazy<Mutex<HashMap<u64, Arc<Mutex<RegisteredIndex>>>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+static NEXT_INDEX_ID: AtomicU64 = AtomicU64::new(1);
+
+fn register_index(index: QuantizedIndex, store_id: Option<u64>) -> u64 {
+    let id = NEXT_INDEX_ID.fetch_add(1, Ordering::Relaxed);
+    let registered = RegisteredIndex { index, store_id };
+    INDEXES.lock().insert(id, Arc::new(Mutex::new(registered)));
+    id
+}
+
+fn find_index(index_id: u64) -> Result<Arc<Mutex<RegisteredIndex>>> {
+    INDEXES
+        .lock()
+        .get(&index_id)
+        .cloned()
+        .ok_or_else(|| anyhow!("Unknown quantized index: {}", index_id))
+}
+
+/// Quantize entries into a new index
+///
+/// Without a vector store behind it, the index cannot rescore.
+///
+/// # Returns
+/// * Index id for the other quantized index functions
+pub fn create_index(entries: &[EmbeddingEntry], kind: QuantizationKind) -> Result<u64> {
+    Ok(register_index(
+        QuantizedIndex::from_entries(entries, kind)?,
+        None,
+    ))
+}
+
+/// Quantize the entries of a vector store into a new index that rescores
+/// with the store's full-precision vectors
+///
+/// # Returns
+/// * Index id for the other quantized index functions
+pub fn create_index_from_store(store_id: u64, kind: QuantizationKind) -> Result<u64> {
+    // Vectors are quantized as they are read, never all held at full size
+    let mut index = QuantizedIndex::new(kind);
+    vector_store::find_store(store_id)?
+        .lock()
+        .for_each_vector(|id, vector, preview, _| {
+            index.insert_vector(id, vector, preview.cloned())
+        })?;
+    Ok(register_index(index, Some(store_id)))
+}
+
+/// Load an index saved with `save_index`
+///
+/// # Arguments
+/// * `path` - File written by `save_index`
+/// * `store_id` - Open vector store to rescore with and follow, if any
+///
+/// # Returns
+/// * Index id for the other quantized index functions
+pub fn load_index(path: &Path, store_id: Option<u64>) -> Result<u64> {
+    if let Some(store_id) = store_id {
+        vector_store::find_store(store_id)?;
+    }
+    Ok(register_index(QuantizedIndex::load(path)?, store_id))
+}
+
+/// Save an open index to a file
+pub fn save_index(index_id: u64, path: &Path) -> Result<()> {
+    find_index(index_id)?.lock().index.save(path)
+}
+
+/// Close an index and free its memory
+///
+/// # Returns
+/// * `true` if the index existed
+pub fn close_index(index_id: u64) -> bool {
+    INDEXES.lock().remove(&index_id).is_some()
+}
+
+/// Insert or replace entries in an index
+pub fn index_insert(index_id: u64, entries: &[EmbeddingEntry]) -> Result<()> {
+    let registered = find_index(index_id)?;
+    let mut registered = registered.lock();
+    for entry in entries {
+        registered.index.insert(entry)?;
+    }
+    Ok(())
+}
+
+/// Remove entries from an index
+///
+/// # Returns
+/// * Number of entries that existed
+pub fn index_remove(index_id: u64, ids: &[String]) -> Result<usize> {
+    let registered = find_index(index_id)?;
+    let mut registered = registered.lock();
+    Ok(ids.iter().filter(|id| registered.index.remove(id)).count())
+}
+
+/// Indexes that follow a store
+fn store_indexes(store_id: u64) -> Vec<Arc<Mutex<RegisteredIndex>>> {
+    // Cloned out first, so no index is locked while holding the registry
+    let indexes: Vec<Arc<Mutex<RegisteredIndex>>> = INDEXES.lock().values().cloned().collect();
+    indexes
+        .into_iter()
+        .filter(|registered| registered.lock().store_id == Some(store_id))
+        .collect()
+}
+
+/// Apply entries upserted into a store to the indexes built from it
+pub(crate) fn store_upserted(store_id: u64, entries: &[EmbeddingEntry]) -> Result<()> {
+    for registered in store_indexes(store_id) {
+        let mut registered = registered.lock();
+        for entry in entries {
+            registered.index.insert(entry)?;
+        }
+    }
+    Ok(())
+}
+
+/// Apply ids deleted from a store to the indexes built from it
+pub(crate) fn store_deleted(store_id: u64, ids: &[String]) {
+    for registered in store_indexes(store_id) {
+        let mut registered = registered.lock();
+        for id in ids {
+            registered.index.remove(id);
+        }
+    }
+}
+
+/// Search an index, rescoring `rescore_candidates` results with the
+/// full-precision vectors of its store when given
+pub fn index_search(
+    index_id: u64,
+    query: &[f32],
+    top_k: usize,
+    threshold: f32,
+    rescore_candidates: Option<usize>,
+) -> Result<Vec<SimilarityResult>> {
+    let registered = find_index(index_id)?;
+    let registered = registered.lock();
+    let Some(candidates) = rescore_candidates else {
+        return Ok(registered.index.search(query, top_k, threshold));
+    };
+    let Some(store_id) = registered.store_id else {
+        bail!(
+            "Quantized index {} has no vector store to rescore with",
+            index_id
+        );
+    };
+    let store = vector_store::find_store(store_id)?;
+    let store = store.lock();
+    Ok(registered
+        .index
+        .search_rescored(query, top_k, threshold, candidates, |id| {
+            store.get(id).map(|entry| entry.vector)
+        }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::embeddings::NoteMetadata;
+
+    /// Entries around a few shared directions, like embeddings of related notes
+    fn clustered_entries(count: usize, dimension: usize) -> Vec<EmbeddingEntry> {
+        let mut state = 7u64;
+        let mut random = move || {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            ((state >> 33) as f32 / (1u64 << 31) as f32) - 0.5
+        };
+        let centers: Vec<Vec<f32>> = (0..8)
+            .map(|_| (0..dimension).map(|_| random()).collect())
+            .collect();
+        (0..count)
+            .map(|i| EmbeddingEntry {
+                id: format!("n{}", i),
+                vector: centers[i % centers.len()]
+                    .iter()
+                    .map(|c| c + 0.6 * random())
+                    .collect(),
+                text_preview: None,
+                metadata: NoteMetadata::default(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_quantize() {
+        let (codes, scale) = quantize_int8(&[3.0, -4.0, 0.0]);
+        assert_eq!(codes, vec![95, -127, 0]);
+        assert!((codes[1] as f32 * scale + 0.8).abs() < 1e-6);
+        assert_eq!(quantize_binary(&[1.0, -1.0, 0.5]), vec![0b101]);
+        assert_eq!(dot_int8(&[1; 35], &[2; 35]), 70);
+    }
+
+    #[test]
+    fn test_insert_and_remove() {
+        let entries = clustered_entries(20, 70);
+        for kind in [QuantizationKind::Int8, QuantizationKind::Binary] {
+            let mut index = QuantizedIndex::from_entries(&entries, kind).unwrap();
+            assert!(index.remove("n3"));
+            assert!(!index.remove("n3"));
+            assert_eq!(index.len(), 19);
+            // n19 moved into the freed slot and is still found
+            let results = index.search(&entries[19].vector, 1, -1.0);
+            assert_eq!(results[0].id, "n19");
+            assert!(index
+                .search(&entries[3].vector, 19, -1.0)
+                .iter()
+                .all(|r| r.id != "n3"));
+        }
+        assert_eq!(
+            QuantizedIndex::from_entries(&entries, QuantizationKind::Binary)
+                .unwrap()
+                .vector_bytes(),
+            20 * 2 * 8
+        );
+    }
+
+    #[test]
+    fn test_save_load_and_store_sync() {
+        let entries = clustered_entries(30, 70);
+        let dir = tempfile::tempdir().unwrap();
+        let store_id = vector_store::open_store(&dir.path().join("store")).unwrap();
+        vector_store::store_upsert(store_id, entries[..20].to_vec()).unwrap();
+
+        for kind in [QuantizationKind::Int8, QuantizationKind::Binary] {
+            let index_id = create_index_from_store(store_id, kind).unwrap();
+            let path = dir.path().join("index.qnt");
+            save_index(index_id, &path).unwrap();
+            let loaded = QuantizedIndex::load(&path).unwrap();
+            let registered = find_index(index_id).unwrap();
+            let original = &registered.lock().index;
+            assert_eq!(loaded.ids, original.ids);
+            assert_eq!(
+                (&loaded.codes, &loaded.scales, &loaded.bits),
+                (&original.codes, &original.scales, &original.bits)
+            );
+            close_index(index_id);
+        }
+
+        // Store writes reach the indexes built from it
+        let index_id = create_index_from_store(store_id, QuantizationKind::Int8).unwrap();
+        vector_store::store_upsert(store_id, entries[20..].to_vec()).unwrap();
+        vector_store::store_delete(store_id, &["n0".to_string()]).unwrap();
+        let found = index_search(index_id, &entries[25].vector, 1, -1.0, Some(10)).unwrap();
+        assert_eq!(found[0].id, "n25");
+        let found = index_search(index_id, &entries[0].vector, 30, -1.0, None).unwrap();
+        assert_eq!(found.len(), 29);
+        assert!(found.iter().all(|r| r.id != "n0"));
+
+        close_index(index_id);
+        vector_store::close_store(store_id);
+        fs::write(dir.path().join("bad.qnt"), b"KVXQNT01\0\0\0\0").unwrap();
+        assert!(QuantizedIndex::load(&dir.path().join("bad.qnt")).is_err());
+    }
+
+    #[test]
+    fn test_recall() {
+        let entries = clustered_entries(600, 96);
+
+        let int8 = measure_recall(&entries, &[], QuantizationKind::Int8, 10, 40).unwrap();
+        assert_eq!(int8.queries, 100);
+        assert!(int8.recall >= 0.9, "int8 recall {}", int8.recall);
+        assert!(int8.rescored_recall >= 0.99);
+        assert!(int8.quantized_bytes * 3 < int8.f32_bytes);
+
+        let binary = measure_recall(&entries, &[], QuantizationKind::Binary, 10, 100).unwrap();
+        assert!(
+            binary.rescored_recall >= 0.9,
+            "binary recall {}",
+            binary.rescored_recall
+        );
+        assert!(binary.rescored_recall >= binary.recall);
+        assert!(binary.quantized_bytes * 20 < binary.f32_bytes);
+    }
+}
@@ -0,0 +1,320 @@
+//! Retrieval-Augmented Chat
+//!
+//! Answers questions from the user's notes:
+//! - The question is embedded and the closest note chunks are retrieved
+//! - Chunks are packed into the prompt, best first, within a token budget
+//! - The model cites chunks as `[n]`; citations are mapped back to note ids
+//!   and chunk offsets so the UI can link to them
+//!
+//! Chunk entries are `EmbeddingEntry` values whose id is `note_id#offset`
+//! (see `chunk_id`) or `note_id#chunkN` (see `chunker::embed_markdown`, which
+//! also records the offset in the metadata), and whose `text_preview` holds
+//! the full chunk text.
+
+use anyhow::{bail, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::embeddings::{self, EmbeddingEntry};
+use crate::inference::{self, GenerationOptions};
+use crate::tokenizer;
+
+/// Settings for a retrieval-augmented answer
+#[derive(Debug, Clone)]
+pub struct RagOptions {
+    /// Number of chunks to retrieve (default: 5)
+    pub top_k: usize,
+    /// Minimum similarity for a chunk to be used (default: 0.3)
+    pub min_score: f32,
+    /// Token budget for the retrieved context (default: 1024)
+    pub context_tokens: u32,
+    /// Maximum tokens for the answer (defaults to the model config)
+    pub max_tokens: Option<u32>,
+    /// Model to answer with (`None` = the default model)
+    pub model_id: Option<String>,
+}
+
+impl Default for RagOptions {
+    fn default() -> Self {
+        Self {
+            top_k: 5,
+            min_score: 0.3,
+            context_tokens: 1024,
+            max_tokens: None,
+            model_id: None,
+        }
+    }
+}
+
+/// A retrieved chunk given to the model
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RagSource {
+    /// Citation number used in the answer (`[1]`, `[2]`, ...)
+    pub citation: u32,
+    /// Note the chunk belongs to
+    pub note_id: String,
+    /// Byte offset of the chunk within the note (from `note_id#offset` ids or
+    /// the entry's `chunk_start`)
+    pub chunk_offset: Option<usize>,
+    /// Position of the chunk within the note (for `note_id#chunkN` ids)
+    pub chunk_index: Option<usize>,
+    /// Similarity between the question and the chunk
+    pub score: f32,
+    /// The chunk text
+    pub text: String,
+    /// Whether the answer cites this chunk
+    pub cited: bool,
+}
+
+/// An answer grounded in retrieved notes
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RagAnswer {
+    /// The model's answer, with `[n]` citations
+    pub answer: String,
+    /// Chunks placed in the prompt, best first
+    pub sources: Vec<RagSource>,
+    /// Retrieved chunks left out because the token budget was used up
+    pub skipped: usize,
+}
+
+/// Id of the chunk of `note_id` starting at byte `offset`
+pub fn chunk_id(note_id: &str, offset: usize) -> String {
+    format!("{}#{}", note_id, offset)
+}
+
+/// Split a chunk id into its note id, byte offset and chunk index
+///
+/// Ids without a recognised `#offset` or `#chunkN` suffix name a whole note.
+pub fn parse_chunk_id(id: &str) -> (String, Option<usize>, Option<usize>) {
+    if let Some((note_id, suffix)) = id.rsplit_once('#') {
+        if let Ok(offset) = suffix.parse() {
+            return (note_id.to_string(), Some(offset), None);
+        }
+        if let Some(Ok(index)) = suffix.strip_prefix("chunk").map(str::parse) {
+            return (note_id.to_string(), None, Some(index));
+        }
+    }
+    (id.to_string(), None, None)
+}
+
+/// Answer a question from note chunks, citing the chunks used
+///
+/// # Arguments
+/// * `question` - The user's question
+/// * `chunks` - Embedded note chunks to retrieve from
+/// * `options` - Retrieval, budget and model settings
+///
+/// # Returns
+/// * The answer with the sources it was given
+pub fn answer_with_notes(
+    question: &str,
+    chunks: &[EmbeddingEntry],
+    options: &RagOptions,
+) -> Result<RagAnswer> {
+    let query = embeddings::embed_query(question)?;
+    let retrieved = embeddings::find_similar(&query, chunks, options.top_k, options.min_score);
+
+    let mut sources = Vec::new();
+    let mut context = String::new();
+    let mut used_tokens = 0u32;
+    let mut skipped = 0;
+    for result in retrieved {
+        let Some(text) = result.text_preview.filter(|text| !text.trim().is_empty()) else {
+            skipped += 1;
+            continue;
+        };
+        let (note_id, chunk_offset, chunk_index) = parse_chunk_id(&result.id);
+        // Chunks from `embed_markdown` carry their offset in the metadata
+        let chunk_offset = chunk_offset.or_else(|| {
+            chunks
+                .iter()
+                .find(|chunk| chunk.id == result.id)
+                .and_then(|chunk| chunk.metadata.chunk_start)
+        });
+        let citation = sources.len() as u32 + 1;
+        let block = format!("[{}] {}\n{}\n\n", citation, note_id, text.trim());
+
+        // Smaller chunks further down may still fit
+        let tokens = tokenizer::count_tokens(&block, options.model_id.as_deref())?;
+        if used_tokens + tokens > options.context_tokens {
+            skipped += 1;
+            continue;
+        }
+        used_tokens += tokens;
+        context.push_str(&block);
+        sources.push(RagSource {
+            citation,
+            note_id,
+            chunk_offset,
+            chunk_index,
+            score: result.score,
+            text,
+            cited: false,
+        });
+    }
+
+    if sources.is_empty() {
+        bail!("No notes matched the question");
+    }
+
+    let messages = vec![
+        (
+            "system".to_string(),
+            "Answer the question using only the numbered notes below. \
+            Cite the notes you use as [1], [2], and so on after each claim. \
+            If the notes do not contain the answer, say so.\n\n"
+                .to_string()
+                + context.trim_end(),
+        ),
+        ("user".to_string(), question.to_string()),
+    ];
+    let generation = GenerationOptions {
+        max_tokens: options.max_tokens,
+        model_id: options.model_id.clone(),
+        ..GenerationOptions::default()
+    };
+    let answer = inference::chat_completion_with_options(messages, generation)?
+        .text
+        .trim()
+        .to_string();
+
+    for citation in citations(&answer) {
+        if let Some(source) = sources.iter_mut().find(|s| s.citation == citation) {
+            source.cited = true;
+        }
+    }
+
+    Ok(RagAnswer {
+        answer,
+        sources,
+        skipped,
+    })
+}
+
+/// Citation numbers in text, in order of first appearance
+///
+/// Accepts `[1]` as well as grouped forms like `[1, 3]` and `[2][4]`.
+fn citations(text: &str) -> Vec<u32> {
+    let mut found = Vec::new();
+    let mut rest = text;
+    while let Some(open) = rest.find('[') {
+        rest = &rest[open + 1..];
+        let Some(close) = rest.find(']') else {
+            break;
+        };
+        let numbers: Option<Vec<u32>> = rest[..close]
+            .split(',')
+            .map(|n| n.trim().parse().ok())
+            .collect();
+        for n in numbers.unwrap_or_default() {
+            if !found.contains(&n) {
+                found.push(n);
+            }
+        }
+        rest = &rest[close + 1..];
+    }
+    found
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::{with_inference_backend, InferenceBackend, MockBackend};
+    use crate::embedder::EmbeddingKind;
+    use crate::embeddings::NoteMetadata;
+    use std::sync::Arc;
+
+    fn chunk(backend: &MockBackend, note_id: &str, offset: usize, text: &str) -> EmbeddingEntry {
+        EmbeddingEntry {
+            id: chunk_id(note_id, offset),
+            vector: backend
+                .embed(&[text.to_string()], EmbeddingKind::Document)
+                .unwrap()
+                .remove(0),
+            text_preview: Some(text.to_string()),
+            metadata: NoteMetadata::default(),
+        }
+    }
+
+    #[test]
+    fn test_chunk_ids_and_citations() {
+        assert_eq!(
+            parse_chunk_id(&chunk_id("notes/a#b.md", 120)),
+            ("notes/a#b.md".to_string(), Some(120), None)
+        );
+        assert_eq!(
+            parse_chunk_id("notes/a.md#chunk3"),
+            ("notes/a.md".to_string(), None, Some(3))
+        );
+        assert_eq!(
+            parse_chunk_id("notes/plain.md"),
+            ("notes/plain.md".to_string(), None, None)
+        );
+        assert_eq!(
+            citations("Yes [2]. Also [1, 3][2] but not [x]."),
+            vec![2, 1, 3]
+        );
+    }
+
+    #[test]
+    fn test_answer_cites_retrieved_notes() {
+        let backend = Arc::new(MockBackend::with_script(
+            64,
+            ["Rust prevents data races with the borrow checker [1]."],
+        ));
+        let chunks = vec![
+            chunk(
+                &backend,
+                "rust.md",
+                0,
+                "the borrow checker prevents data races in rust",
+            ),
+            chunk(
+                &backend,
+                "rust.md",
+                512,
+                "rust traits describe shared behaviour",
+            ),
+            chunk(&backend, "garden.md", 0, "tomatoes need plenty of sun"),
+        ];
+        let options = RagOptions {
+            top_k: 2,
+            min_score: 0.1,
+            ..RagOptions::default()
+        };
+
+        let answer = with_inference_backend(backend.clone(), || {
+            answer_with_notes("how does rust prevent data races", &chunks, &options)
+        })
+        .unwrap();
+
+        assert_eq!(answer.sources[0].note_id, "rust.md");
+        assert_eq!(answer.sources[0].chunk_offset, Some(0));
+        assert!(answer.sources[0].cited);
+        assert!(answer.sources.iter().all(|s| s.note_id != "garden.md"));
+        assert!(backend.prompts()[0].contains("[1] rust.md\nthe borrow checker"));
+    }
+
+    #[test]
+    fn test_context_budget_skips_chunks() {
+        let backend = Arc::new(MockBackend::new(64).with_fallback("Not in the notes."));
+        let long = "rust ".repeat(200);
+        let chunks = vec![
+            chunk(&backend, "long.md", 0, &long),
+            chunk(&backend, "short.md", 0, "rust is fast"),
+        ];
+        let options = RagOptions {
+            min_score: 0.1,
+            context_tokens: 50,
+            ..RagOptions::default()
+        };
+
+        let answer =
+            with_inference_backend(backend, || answer_with_notes("rust", &chunks, &options))
+                .unwrap();
+
+        assert_eq!(answer.sources.len(), 1);
+        assert_eq!(answer.sources[0].note_id, "short.md");
+        assert_eq!(answer.skipped, 1);
+        assert!(!answer.sources[0].cited);
+    }
+}
@@ -0,0 +1,255 @@
+//! Reasoning Trace Separation
+//!
+//! Reasoning models (DeepSeek-R1 distills, QwQ, Qwen3) think out loud inside
+//! `<think>...</think>` before answering. This module splits streamed output
+//! into the reasoning trace and the final answer:
+//! - Tags are matched even when they are split across tokens
+//! - Prompts whose template already opened the thinking block are handled
+//! - Generation can force the block closed after a thinking budget
+
+use serde::{Deserialize, Serialize};
+
+use crate::inference::partial_match_len;
+
+/// How reasoning output is separated from the answer
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReasoningOptions {
+    /// Separate the reasoning trace (false = return the raw output verbatim)
+    pub enabled: bool,
+    /// Tag that opens the reasoning block
+    pub open_tag: String,
+    /// Tag that closes the reasoning block
+    pub close_tag: String,
+    /// Maximum tokens spent thinking before the block is closed and the
+    /// model has to answer (`None` = unlimited)
+    pub thinking_budget: Option<u32>,
+}
+
+impl Default for ReasoningOptions {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            open_tag: "<think>".to_string(),
+            close_tag: "</think>".to_string(),
+            thinking_budget: None,
+        }
+    }
+}
+
+/// Whether a model emits reasoning blocks, judged from its chat template and
+/// its file name / architecture hint
+pub(crate) fn is_reasoning_model(chat_template: Option<&str>, model_hint: &str) -> bool {
+    chat_template.is_some_and(|template| template.contains("<think>"))
+        || ["deepseek-r1", "r1-distill", "qwq"]
+            .iter()
+            .any(|name| model_hint.contains(name))
+}
+
+/// Whether the prompt ends inside an open reasoning block
+///
+/// Some templates (e.g. newer R1 distills) append the open tag to the
+/// generation prompt, so the model starts thinking without emitting it.
+pub(crate) fn prompt_opens_reasoning(prompt: &str, options: &ReasoningOptions) -> bool {
+    match prompt.rfind(options.open_tag.as_str()) {
+        Some(open) => !prompt[open..].contains(options.close_tag.as_str()),
+        None => false,
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Phase {
+    /// Before any output: the open tag may still follow
+    Leading,
+    /// Inside the reasoning block
+    Thinking,
+    /// After the reasoning block (or without one)
+    Answer,
+}
+
+/// Incremental splitter for generated text
+#[derive(Debug)]
+pub(crate) struct ReasoningSplitter {
+    open_tag: String,
+    close_tag: String,
+    phase: Phase,
+    /// Text held back because it may be the start of a tag
+    pending: String,
+    reasoning: String,
+    /// Strip whitespace between a tag and the text that follows it
+    trim_start: bool,
+}
+
+impl ReasoningSplitter {
+    /// Create a splitter; `opened` means the prompt already opened the block
+    pub(crate) fn new(options: &ReasoningOptions, opened: bool) -> Self {
+        Self {
+            open_tag: options.open_tag.clone(),
+            close_tag: options.close_tag.clone(),
+            phase: if opened {
+                Phase::Thinking
+            } else {
+                Phase::Leading
+            },
+            pending: String::new(),
+            reasoning: String::new(),
+            trim_start: opened,
+        }
+    }
+
+    /// Whether generated tokens currently belong to the reasoning trace
+    pub(crate) fn is_thinking(&self) -> bool {
+        self.phase == Phase::Thinking
+    }
+
+    /// Feed a piece of output and return the `(reasoning, answer)` text it completes
+    pub(crate) fn push(&mut self, piece: &str) -> (String, String) {
+        self.pending.push_str(piece);
+        let mut reasoning = String::new();
+        let mut answer = String::new();
+
+        loop {
+            match self.phase {
+                Phase::Leading => {
+                    let trimmed = self.pending.trim_start();
+                    if let Some(rest) = trimmed.strip_prefix(self.open_tag.as_str()) {
+                        self.pending = rest.to_string();
+                        self.phase = Phase::Thinking;
+                        self.trim_start = true;
+                        continue;
+                    }
+                    if !self.open_tag.starts_with(trimmed) {
+                        answer = std::mem::take(&mut self.pending);
+                        self.phase = Phase::Answer;
+                    }
+                }
+                Phase::Thinking => {
+                    if let Some(close) = self.pending.find(self.close_tag.as_str()) {
+                        self.take_text(close, &mut reasoning);
+                        self.pending.drain(..self.close_tag.len());
+                        self.phase = Phase::Answer;
+                        self.trim_start = true;
+                        continue;
+                    }
+                    // Hold back what may be the start of the close tag
+                    let keep = partial_match_len(&self.pending, &self.close_tag);
+                    self.take_text(self.pending.len() - keep, &mut reasoning);
+                }
+                Phase::Answer => {
+                    let len = self.pending.len();
+                    self.take_text(len, &mut answer);
+                }
+            }
+            break;
+        }
+
+        self.reasoning.push_str(&reasoning);
+        (reasoning, answer)
+    }
+
+    /// Flush held-back text once generation has ended
+    pub(crate) fn finish(&mut self) -> (String, String) {
+        let rest = std::mem::take(&mut self.pending);
+        match self.phase {
+            // An unterminated block still counts as reasoning
+            Phase::Thinking => {
+                self.reasoning.push_str(&rest);
+                (rest, String::new())
+            }
+            Phase::Leading | Phase::Answer => (String::new(), rest),
+        }
+    }
+
+    /// The full reasoning trace without surrounding whitespace
+    pub(crate) fn reasoning(&self) -> String {
+        self.reasoning.trim().to_string()
+    }
+
+    /// Move `pending[..end]` into `out`, dropping leading whitespace after a tag
+    fn take_text(&mut self, end: usize, out: &mut String) {
+        let text: String = self.pending.drain(..end).collect();
+        if self.trim_start {
+            let trimmed = text.trim_start();
+            if !trimmed.is_empty() {
+                self.trim_start = false;
+            }
+            out.push_str(trimmed);
+        } else {
+            out.push_str(&text);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn split(pieces: &[&str], opened: bool) -> (String, String) {
+        let mut splitter = ReasoningSplitter::new(&ReasoningOptions::default(), opened);
+        let mut reasoning = String::new();
+        let mut answer = String::new();
+        for piece in pieces {
+            let (r, a) = splitter.push(piece);
+            reasoning.push_str(&r);
+            answer.push_str(&a);
+        }
+        let (r, a) = splitter.finish();
+        reasoning.push_str(&r);
+        answer.push_str(&a);
+        (reasoning, answer)
+    }
+
+    #[test]
+    fn test_split_tags_across_pieces() {
+        let (reasoning, answer) = split(
+            &[
+                "<th",
+                "ink>\nLet me",
+                " think.</",
+                "thi",
+                "nk>\n\nThe answer",
+                " is 4.",
+            ],
+            false,
+        );
+        assert_eq!(reasoning, "Let me think.");
+        assert_eq!(answer, "The answer is 4.");
+    }
+
+    #[test]
+    fn test_prompt_opened_block_and_plain_output() {
+        let (reasoning, answer) = split(&["Two plus two.\n</think>\n\n4"], true);
+        assert_eq!(reasoning, "Two plus two.\n");
+        assert_eq!(answer, "4");
+
+        // Output that does not start with the tag is all answer
+        let (reasoning, answer) = split(&["<", "b>bold</b>"], false);
+        assert_eq!(reasoning, "");
+        assert_eq!(answer, "<b>bold</b>");
+
+        // Unterminated thinking stays reasoning
+        let (reasoning, answer) = split(&["<think>still going"], false);
+        assert_eq!(reasoning, "still going");
+        assert_eq!(answer, "");
+    }
+
+    #[test]
+    fn test_reasoning_detection() {
+        let options = ReasoningOptions::default();
+        assert!(prompt_opens_reasoning("<|Assistant|><think>\n", &options));
+        assert!(!prompt_opens_reasoning(
+            "<think>a</think>answer<|User|>hi<|Assistant|>",
+            &options
+        ));
+        assert!(!prompt_opens_reasoning("<|im_start|>assistant\n", &options));
+
+        assert!(is_reasoning_model(
+            None,
+            "/models/deepseek-r1-distill-qwen-7b-q4_k_m.gguf qwen2"
+        ));
+        assert!(is_reasoning_model(
+            Some("{{ '<think>' }}"),
+            "model.gguf llama"
+        ));
+        assert!(!is_reasoning_model(None, "/models/qwen2.5-3b.gguf qwen2"));
+    }
+}
@@ -0,0 +1,362 @@
+//! Model Registry
+//!
+//! Tracks several loaded models by id:
+//! - Least-recently-used ordering for eviction
+//! - Optional memory budget shared by all loaded models, reserved before a
+//!   model starts loading
+//! - A default model for calls that do not name one
+
+use anyhow::{anyhow, Result};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// A loaded model and its bookkeeping
+struct RegistryEntry<T> {
+    value: Arc<T>,
+    size_bytes: u64,
+    last_used: u64,
+}
+
+/// Loaded models keyed by id, with LRU eviction under a memory budget
+///
+/// Values are handed out as `Arc`s, so an evicted model stays alive until
+/// in-flight work that already holds it has finished.
+pub struct ModelRegistry<T> {
+    entries: HashMap<String, RegistryEntry<T>>,
+    /// Budget held by models that are still loading
+    reserved: HashMap<String, u64>,
+    default_id: Option<String>,
+    memory_budget: Option<u64>,
+    clock: u64,
+}
+
+impl<T> Default for ModelRegistry<T> {
+    fn default() -> Self {
+        Self {
+            entries: HashMap::new(),
+            reserved: HashMap::new(),
+            default_id: None,
+            memory_budget: None,
+            clock: 0,
+        }
+    }
+}
+
+impl<T> ModelRegistry<T> {
+    /// Resolve an optional id to a loaded model id (`None` means the default model)
+    pub fn resolve_id<'a>(&'a self, model_id: Option<&'a str>) -> Option<&'a str> {
+        match model_id {
+            Some(id) => self.entries.contains_key(id).then_some(id),
+            None => self.default_id.as_deref(),
+        }
+    }
+
+    /// Look up a model and mark it as most recently used
+    pub fn get(&mut self, model_id: Option<&str>) -> Option<Arc<T>> {
+        let id = self.resolve_id(model_id)?.to_string();
+        self.clock += 1;
+        let entry = self.entries.get_mut(&id)?;
+        entry.last_used = self.clock;
+        Some(entry.value.clone())
+    }
+
+    /// Look up a model without affecting the LRU order
+    pub fn peek(&self, model_id: Option<&str>) -> Option<&Arc<T>> {
+        let id = self.resolve_id(model_id)?;
+        self.entries.get(id).map(|entry| &entry.value)
+    }
+
+    /// Whether a model with this id is loaded
+    pub fn contains(&self, model_id: &str) -> bool {
+        self.entries.contains_key(model_id)
+    }
+
+    /// Id of the model used when no id is given
+    pub fn default_id(&self) -> Option<&str> {
+        self.default_id.as_deref()
+    }
+
+    /// Make a loaded model the default
+    pub fn set_default(&mut self, model_id: &str) -> Result<()> {
+        if !self.entries.contains_key(model_id) {
+            return Err(anyhow!("Model not loaded: {}", model_id));
+        }
+        self.default_id = Some(model_id.to_string());
+        Ok(())
+    }
+
+    /// Total size of every loaded model
+    pub fn memory_used(&self) -> u64 {
+        self.entries.values().map(|entry| entry.size_bytes).sum()
+    }
+
+    /// Configured memory budget (`None` = unlimited)
+    pub fn memory_budget(&self) -> Option<u64> {
+        self.memory_budget
+    }
+
+    /// Change the memory budget, evicting models until the registry fits
+    ///
+    /// # Returns
+    /// * The evicted models
+    pub fn set_memory_budget(&mut self, budget: Option<u64>) -> Vec<(String, Arc<T>)> {
+        self.memory_budget = budget;
+        match budget {
+            Some(budget) => self.evict_until(budget, 0),
+            None => Vec::new(),
+        }
+    }
+
+    /// Budget held by models that are still loading
+    fn reserved_bytes(&self) -> u64 {
+        self.reserved.values().sum()
+    }
+
+    /// Reserve room for a model that is about to be loaded
+    ///
+    /// Least recently used models are evicted until the new model fits next
+    /// to the loaded models and the other reservations. A model already
+    /// registered under `model_id` is removed too, so the old and new weights
+    /// are never resident at the same time. The reservation is released by
+    /// `insert` or `cancel_reservation`.
+    ///
+    /// # Returns
+    /// * The removed models, or an error if the model cannot fit the budget
+    pub fn reserve(&mut self, model_id: &str, size_bytes: u64) -> Result<Vec<(String, Arc<T>)>> {
+        if self.reserved.contains_key(model_id) {
+            return Err(anyhow!("Model {} is already being loaded", model_id));
+        }
+        if let Some(budget) = self.memory_budget {
+            let pending = self.reserved_bytes();
+            if pending.saturating_add(size_bytes) > budget {
+                return Err(anyhow!(
+                    "Model needs {} bytes but the memory budget is {} bytes ({} reserved by loads in progress)",
+                    size_bytes,
+                    budget,
+                    pending
+                ));
+            }
+        }
+
+        // The default id keeps pointing at a model that is being reloaded
+        let mut evicted: Vec<_> = self
+            .entries
+            .remove(model_id)
+            .map(|entry| (model_id.to_string(), entry.value))
+            .into_iter()
+            .collect();
+        if let Some(budget) = self.memory_budget {
+            evicted.extend(self.evict_until(budget, size_bytes));
+        }
+        self.reserved.insert(model_id.to_string(), size_bytes);
+        Ok(evicted)
+    }
+
+    /// Release the reservation of a model that failed to load
+    pub fn cancel_reservation(&mut self, model_id: &str) {
+        self.reserved.remove(model_id);
+        let default_missing = self
+            .default_id
+            .as_deref()
+            .is_some_and(|id| !self.entries.contains_key(id) && !self.reserved.contains_key(id));
+        if default_missing {
+            self.default_id = self.most_recent_id();
+        }
+    }
+
+    fn most_recent_id(&self) -> Option<String> {
+        self.entries
+            .iter()
+            .max_by_key(|(_, entry)| entry.last_used)
+            .map(|(id, _)| id.clone())
+    }
+
+    fn evict_until(&mut self, budget: u64, incoming: u64) -> Vec<(String, Arc<T>)> {
+        let mut evicted = Vec::new();
+
+        loop {
+            let used = self.memory_used() + self.reserved_bytes();
+            if used + incoming <= budget {
+                break;
+            }
+
+            let victim = self
+                .entries
+                .iter()
+                .min_by_key(|(_, entry)| entry.last_used)
+                .map(|(id, _)| id.clone());
+            let Some(victim) = victim else {
+                break;
+            };
+
+            log::info!("Evicting model {} to stay within memory budget", victim);
+            if let Some(value) = self.remove(&victim) {
+                evicted.push((victim, value));
+            }
+        }
+
+        evicted
+    }
+
+    /// Register a model, replacing any model with the same id
+    ///
+    /// Releases the model's reservation. The first model registered becomes
+    /// the default.
+    ///
+    /// # Returns
+    /// * The replaced model, if any
+    pub fn insert(&mut self, model_id: String, value: Arc<T>, size_bytes: u64) -> Option<Arc<T>> {
+        self.reserved.remove(&model_id);
+        self.clock += 1;
+        if self.default_id.is_none() {
+            self.default_id = Some(model_id.clone());
+        }
+        self.entries
+            .insert(
+                model_id,
+                RegistryEntry {
+                    value,
+                    size_bytes,
+                    last_used: self.clock,
+                },
+            )
+            .map(|entry| entry.value)
+    }
+
+    /// Remove a model
+    ///
+    /// If it was the default, the most recently used remaining model takes over.
+    pub fn remove(&mut self, model_id: &str) -> Option<Arc<T>> {
+        let entry = self.entries.remove(model_id)?;
+        if self.default_id.as_deref() == Some(model_id) {
+            self.default_id = self.most_recent_id();
+        }
+        Some(entry.value)
+    }
+
+    /// Remove every model
+    pub fn clear(&mut self) -> Vec<(String, Arc<T>)> {
+        self.default_id = None;
+        self.entries
+            .drain()
+            .map(|(id, entry)| (id, entry.value))
+            .collect()
+    }
+
+    /// Loaded models with their sizes, most recently used first
+    pub fn entries(&self) -> Vec<(&str, &Arc<T>, u64)> {
+        let mut entries: Vec<_> = self.entries.iter().collect();
+        entries.sort_by_key(|(_, entry)| std::cmp::Reverse(entry.last_used));
+        entries
+            .into_iter()
+            .map(|(id, entry)| (id.as_str(), &entry.value, entry.size_bytes))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn registry_with(models: &[(&str, u64)]) -> ModelRegistry<String> {
+        let mut registry = ModelRegistry::default();
+        for (id, size) in models {
+            registry.insert(id.to_string(), Arc::new(id.to_string()), *size);
+        }
+        registry
+    }
+
+    fn ids(evicted: &[(String, Arc<String>)]) -> Vec<&str> {
+        evicted.iter().map(|(id, _)| id.as_str()).collect()
+    }
+
+    #[test]
+    fn test_default_model() {
+        let mut registry = registry_with(&[("phi4", 10), ("qwen", 20)]);
+        assert_eq!(registry.default_id(), Some("phi4"));
+        assert_eq!(
+            registry.get(None).as_deref().map(String::as_str),
+            Some("phi4")
+        );
+        assert!(registry.get(Some("missing")).is_none());
+
+        registry.set_default("qwen").unwrap();
+        assert_eq!(registry.peek(None).map(|m| m.as_str()), Some("qwen"));
+        assert!(registry.set_default("missing").is_err());
+
+        // Removing the default promotes the most recently used model
+        registry.remove("qwen");
+        assert_eq!(registry.default_id(), Some("phi4"));
+        registry.clear();
+        assert_eq!(registry.default_id(), None);
+    }
+
+    #[test]
+    fn test_lru_eviction() {
+        let mut registry = registry_with(&[("a", 40), ("b", 40), ("c", 20)]);
+        registry.get(Some("a"));
+
+        // Budget of 100 with a new 30-byte model: "b" is the least recently used
+        registry.set_memory_budget(Some(100));
+        let evicted = registry.reserve("d", 30).unwrap();
+        assert_eq!(ids(&evicted), vec!["b"]);
+        assert_eq!(registry.memory_used(), 60);
+
+        // A model larger than the whole budget can never fit
+        assert!(registry.reserve("e", 101).is_err());
+    }
+
+    #[test]
+    fn test_reservations_share_the_budget() {
+        let mut registry = registry_with(&[("a", 40)]);
+        registry.set_memory_budget(Some(100));
+
+        // Two loads in flight: the second must make room for the first as well
+        assert!(registry.reserve("b", 50).unwrap().is_empty());
+        assert_eq!(ids(&registry.reserve("c", 50).unwrap()), vec!["a"]);
+        assert!(registry.reserve("d", 10).is_err());
+        assert!(registry.reserve("b", 50).is_err());
+
+        // A failed load gives its share back
+        registry.cancel_reservation("c");
+        assert!(registry.reserve("d", 10).unwrap().is_empty());
+        registry.insert("b".to_string(), Arc::new("b".to_string()), 50);
+        registry.insert("d".to_string(), Arc::new("d".to_string()), 10);
+        assert_eq!(registry.memory_used(), 60);
+        assert_eq!(registry.default_id(), Some("b"));
+    }
+
+    #[test]
+    fn test_replacing_model_unloads_it_first() {
+        let mut registry = registry_with(&[("a", 50), ("b", 50)]);
+        registry.set_memory_budget(Some(100));
+
+        // Reloading "a" removes the old weights before the new ones load
+        let evicted = registry.reserve("a", 50).unwrap();
+        assert_eq!(ids(&evicted), vec!["a"]);
+        assert_eq!(registry.memory_used(), 50);
+        assert_eq!(registry.default_id(), Some("a"));
+
+        let replaced = registry.insert("a".to_string(), Arc::new("a2".to_string()), 50);
+        assert!(replaced.is_none());
+        assert_eq!(registry.memory_used(), 100);
+        assert_eq!(registry.peek(None).map(|m| m.as_str()), Some("a2"));
+
+        // If the reload fails, the default falls back to a loaded model
+        registry.reserve("a", 50).unwrap();
+        registry.cancel_reservation("a");
+        assert_eq!(registry.default_id(), Some("b"));
+    }
+
+    #[test]
+    fn test_shrinking_budget_evicts() {
+        let mut registry = registry_with(&[("a", 30), ("b", 30), ("c", 30)]);
+        registry.get(Some("a"));
+
+        let evicted = registry.set_memory_budget(Some(40));
+        assert_eq!(ids(&evicted), vec!["b", "c"]);
+        assert_eq!(registry.entries().len(), 1);
+        assert_eq!(registry.default_id(), Some("a"));
+        assert!(registry.set_memory_budget(None).is_empty());
+    }
+}
@@ -0,0 +1,499 @@
+//! Persistent Chat Sessions
+//!
+//! Keeps a llama.cpp context alive between chat turns:
+//! - New turns only decode the tokens added since the previous turn
+//! - Edited history reuses the longest matching prefix of the KV cache
+//! - Sessions can be saved to and restored from llama.cpp session files
+
+use anyhow::{anyhow, Result};
+use llama_cpp_2::llama_batch::LlamaBatch;
+use llama_cpp_2::token::LlamaToken;
+use once_cell::sync::Lazy;
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+
+use crate::context_window;
+use crate::gguf::GgufMetadata;
+use crate::inference::{self, GenerationChunk, GenerationOptions, GenerationResult, ModelState};
+
+use owned_context::OwnedContext;
+
+mod owned_context {
+    use anyhow::Result;
+    use llama_cpp_2::context::LlamaContext;
+    use std::sync::Arc;
+
+    use crate::inference::{self, ModelState};
+
+    /// A llama.cpp context bundled with the model it was created from
+    ///
+    /// The context borrows the model, so its lifetime is erased to store it.
+    /// The fields are private to this module and never reassigned, which keeps
+    /// the model alive for as long as the context exists; `ctx` is declared
+    /// first so it is dropped before `state`.
+    pub(super) struct OwnedContext {
+        ctx: LlamaContext<'static>,
+        state: Arc<ModelState>,
+    }
+
+    // SAFETY: sessions are only used while their mutex is held, so the
+    // llama.cpp context is never touched from two threads at once.
+    unsafe impl Send for OwnedContext {}
+
+    impl OwnedContext {
+        pub(super) fn new(state: Arc<ModelState>, n_ctx: Option<u32>) -> Result<Self> {
+            let ctx = inference::new_generation_context(&state, n_ctx)?;
+            // SAFETY: the model sits behind an `Arc` owned by this struct, so its
+            // address is stable, and the struct never lets it go before the context.
+            let ctx =
+                unsafe { std::mem::transmute::<LlamaContext<'_>, LlamaContext<'static>>(ctx) };
+            Ok(Self { ctx, state })
+        }
+
+        pub(super) fn state(&self) -> &Arc<ModelState> {
+            &self.state
+        }
+
+        pub(super) fn ctx(&self) -> &LlamaContext<'static> {
+            &self.ctx
+        }
+
+        pub(super) fn ctx_mut(&mut self) -> &mut LlamaContext<'static> {
+            &mut self.ctx
+        }
+    }
+}
+
+/// A conversation with its own context and KV cache
+struct ChatSession {
+    context: OwnedContext,
+    /// Tokens currently held in the KV cache, in position order
+    tokens: Vec<LlamaToken>,
+}
+
+impl ChatSession {
+    fn new(state: Arc<ModelState>, n_ctx: Option<u32>) -> Result<Self> {
+        Ok(Self {
+            context: OwnedContext::new(state, n_ctx)?,
+            tokens: Vec::new(),
+        })
+    }
+
+    /// Bring the KV cache in line with `prompt`, decoding only what changed
+    fn sync_prompt(&mut self, prompt: &[LlamaToken]) -> Result<LlamaBatch<'static>> {
+        if prompt.is_empty() {
+            return Err(anyhow!("Cannot decode an empty prompt"));
+        }
+
+        // Always re-decode at least the last token so there are logits to sample from
+        let mut reuse = common_prefix_len(&self.tokens, prompt).min(prompt.len() - 1);
+
+        if reuse < self.tokens.len() {
+            let removed = self
+                .context
+                .ctx_mut()
+                .clear_kv_cache_seq(Some(0), Some(reuse as u32), None)
+                .map_err(|e| anyhow!("Failed to trim KV cache: {:?}", e))?;
+            if !removed {
+                // Some architectures cannot drop part of a sequence
+                self.context.ctx_mut().clear_kv_cache();
+                reuse = 0;
+            }
+            self.tokens.truncate(reuse);
+        }
+
+        log::debug!(
+            "Session reuses {} cached tokens, decoding {} new",
+            reuse,
+            prompt.len() - reuse
+        );
+
+        let batch =
+            inference::decode_prompt(self.context.ctx_mut(), &prompt[reuse..], reuse, None)?
+                .ok_or_else(|| anyhow!("Prompt decoding was cancelled"))?;
+        self.tokens.extend_from_slice(&prompt[reuse..]);
+        Ok(batch)
+    }
+
+    fn complete(
+        &mut self,
+        messages: &[(String, String)],
+        options: &GenerationOptions,
+        on_chunk: &mut dyn FnMut(GenerationChunk),
+    ) -> Result<GenerationResult> {
+        let state = self.context.state().clone();
+        let (messages, report) = context_window::fit_conversation(
+            &state,
+            messages.to_vec(),
+            options,
+            self.context.ctx().n_ctx(),
+            None,
+        )?;
+        let prompt = inference::build_chat_prompt_for(&state, &messages);
+        let prompt_tokens = inference::tokenize_prompt(&state, &prompt)?;
+
+        let outcome = match self.sync_prompt(&prompt_tokens) {
+            Ok(mut batch) => inference::decode_loop(
+                &state,
+                self.context.ctx_mut(),
+                &mut batch,
+                &prompt,
+                &self.tokens,
+                options,
+                None,
+                on_chunk,
+            ),
+            Err(error) => Err(error),
+        };
+
+        match outcome {
+            Ok((mut result, generated)) => {
+                self.tokens.extend(generated);
+                result.context = Some(report);
+                Ok(result)
+            }
+            Err(error) => {
+                // The cache may be partially written, so start over on the next turn
+                self.context.ctx_mut().clear_kv_cache();
+                self.tokens.clear();
+                Err(error)
+            }
+        }
+    }
+}
+
+/// Version of the session header format
+const SESSION_HEADER_VERSION: u32 = 1;
+
+/// Written next to a session file to record what it was evaluated with
+///
+/// A KV cache only makes sense for the exact model and context size that
+/// produced it, so restoring checks the header first.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct SessionHeader {
+    version: u32,
+    /// GGUF file the session was evaluated with
+    model_path: String,
+    /// Size of the GGUF file in bytes
+    model_size: u64,
+    /// Model architecture from the GGUF metadata
+    architecture: Option<String>,
+    /// Weight and tensor counts, which tell apart models of the same family
+    parameter_count: u64,
+    tensor_count: u64,
+    /// Context size of the saved session
+    n_ctx: u32,
+}
+
+impl SessionHeader {
+    fn new(model_path: &str, metadata: &GgufMetadata, n_ctx: u32) -> Self {
+        Self {
+            version: SESSION_HEADER_VERSION,
+            model_path: model_path.to_string(),
+            model_size: metadata.file_size,
+            architecture: metadata.architecture.clone(),
+            parameter_count: metadata.parameter_count,
+            tensor_count: metadata.tensor_count,
+            n_ctx,
+        }
+    }
+
+    /// Header file for a session file: the same path with `.meta` appended
+    fn path_for(session_path: &Path) -> PathBuf {
+        let mut path = session_path.as_os_str().to_owned();
+        path.push(".meta");
+        PathBuf::from(path)
+    }
+
+    fn write(&self, session_path: &Path) -> Result<()> {
+        let json = serde_json::to_string(self)?;
+        std::fs::write(Self::path_for(session_path), json)
+            .map_err(|e| anyhow!("Failed to write session header: {}", e))
+    }
+
+    fn read(session_path: &Path) -> Result<Self> {
+        let path = Self::path_for(session_path);
+        let json = std::fs::read_to_string(&path)
+            .map_err(|e| anyhow!("Failed to read session header {}: {}", path.display(), e))?;
+        let header: Self = serde_json::from_str(&json)
+            .map_err(|e| anyhow!("Invalid session header {}: {}", path.display(), e))?;
+        if header.version != SESSION_HEADER_VERSION {
+            return Err(anyhow!(
+                "Unsupported session header version: {}",
+                header.version
+            ));
+        }
+        Ok(header)
+    }
+
+    /// Check that `model` is the model this session was saved from
+    fn check_model(&self, model: &SessionHeader) -> Result<()> {
+        let same_model = self.model_size == model.model_size
+            && self.architecture == model.architecture
+            && self.parameter_count == model.parameter_count
+            && self.tensor_count == model.tensor_count;
+        if !same_model {
+            return Err(anyhow!(
+                "Session was saved with model {}, not {}",
+                self.model_path,
+                model.model_path
+            ));
+        }
+        Ok(())
+    }
+
+    /// Context size to restore into, defaulting to the saved one
+    fn resolve_n_ctx(&self, n_ctx: Option<u32>) -> Result<u32> {
+        match n_ctx {
+            Some(n_ctx) if n_ctx < self.n_ctx => Err(anyhow!(
+                "Session was saved with a context of {} tokens, {} is too small",
+                self.n_ctx,
+                n_ctx
+            )),
+            Some(n_ctx) => Ok(n_ctx),
+            None => Ok(self.n_ctx),
+        }
+    }
+}
+
+/// Length of the shared prefix of two token sequences
+fn common_prefix_len(cached: &[LlamaToken], prompt: &[LlamaToken]) -> usize {
+    cached
+        .iter()
+        .zip(prompt)
+        .take_while(|(a, b)| a == b)
+        .count()
+}
+
+/// An open session and the model it runs on
+///
+/// The model is kept next to the session so closing the sessions of a model
+/// never has to lock a session that may be busy generating.
+struct SessionEntry {
+    model: Arc<ModelState>,
+    session: Arc<Mutex<ChatSession>>,
+}
+
+/// Open chat sessions by id
+static CHAT_SESSIONS: Lazy<Mutex<HashMap<u64, SessionEntry>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+static NEXT_SESSION_ID: AtomicU64 = AtomicU64::new(1);
+
+fn register_session(session: ChatSession) -> u64 {
+    let id = NEXT_SESSION_ID.fetch_add(1, Ordering::Relaxed);
+    let entry = SessionEntry {
+        model: session.context.state().clone(),
+        session: Arc::new(Mutex::new(session)),
+    };
+    CHAT_SESSIONS.lock().insert(id, entry);
+    id
+}
+
+fn find_session(session_id: u64) -> Result<Arc<Mutex<ChatSession>>> {
+    CHAT_SESSIONS
+        .lock()
+        .get(&session_id)
+        .map(|entry| entry.session.clone())
+        .ok_or_else(|| anyhow!("Unknown chat session: {}", session_id))
+}
+
+/// Create a chat session on a loaded model
+///
+/// # Arguments
+/// * `n_ctx` - Optional context size (defaults to the model config)
+/// * `model_id` - Model to run on (`None` = the default model)
+///
+/// # Returns
+/// * Session id for the other session functions
+pub fn create_chat_session(n_ctx: Option<u32>, model_id: Option<&str>) -> Result<u64> {
+    let state = inference::current_state(model_id)?;
+    Ok(register_session(ChatSession::new(state, n_ctx)?))
+}
+
+/// Run a chat turn in a session
+///
+/// `messages` is the full conversation, as for `chat_completion`. Only the
+/// part that differs from what the session already evaluated is decoded.
+///
+/// # Returns
+/// * The generation result for the assistant's reply
+pub fn chat_session_completion(
+    session_id: u64,
+    messages: Vec<(String, String)>,
+    options: GenerationOptions,
+) -> Result<GenerationResult> {
+    chat_session_streaming(session_id, messages, options, |_| {})
+}
+
+/// Run a chat turn in a session, streaming each token as it is sampled
+pub fn chat_session_streaming<F>(
+    session_id: u64,
+    messages: Vec<(String, String)>,
+    options: GenerationOptions,
+    mut on_chunk: F,
+) -> Result<GenerationResult>
+where
+    F: FnMut(GenerationChunk),
+{
+    let session = find_session(session_id)?;
+    let mut session = session.lock();
+    session.complete(&messages, &options, &mut on_chunk)
+}
+
+/// Number of tokens held in a session's KV cache
+pub fn chat_session_token_count(session_id: u64) -> Result<usize> {
+    Ok(find_session(session_id)?.lock().tokens.len())
+}
+
+/// Save a session's KV cache to a llama.cpp session file
+///
+/// A small header recording the model and context size is written next to
+/// it, at the same path with `.meta` appended.
+pub fn save_chat_session(session_id: u64, path: &Path) -> Result<()> {
+    let session = find_session(session_id)?;
+    let session = session.lock();
+    let state = session.context.state();
+    let ctx = session.context.ctx();
+    ctx.save_session_file(path, &session.tokens)
+        .map_err(|e| anyhow!("Failed to save session: {:?}", e))?;
+    SessionHeader::new(&state.model_path, &state.metadata, ctx.n_ctx()).write(path)
+}
+
+/// Restore a session saved with `save_chat_session`
+///
+/// The next turn continues from the restored KV cache without re-evaluating
+/// the saved conversation.
+///
+/// The session file must have been saved from the same model, which is
+/// checked against its header. `n_ctx` defaults to the saved context size
+/// and may not be smaller.
+///
+/// # Returns
+/// * Id of the new session
+pub fn load_chat_session(path: &Path, n_ctx: Option<u32>, model_id: Option<&str>) -> Result<u64> {
+    let header = SessionHeader::read(path)?;
+    let state = inference::current_state(model_id)?;
+    let n_ctx = header.resolve_n_ctx(n_ctx)?;
+    header.check_model(&SessionHeader::new(
+        &state.model_path,
+        &state.metadata,
+        n_ctx,
+    ))?;
+    let mut session = ChatSession::new(state, Some(n_ctx))?;
+
+    let max_tokens = session.context.ctx().n_ctx() as usize;
+    session.tokens = session
+        .context
+        .ctx_mut()
+        .load_session_file(path, max_tokens)
+        .map_err(|e| anyhow!("Failed to load session: {:?}", e))?;
+
+    log::info!(
+        "Restored chat session with {} cached tokens",
+        session.tokens.len()
+    );
+    Ok(register_session(session))
+}
+
+/// Close a session and free its context
+///
+/// # Returns
+/// * `true` if the session existed
+pub fn close_chat_session(session_id: u64) -> bool {
+    let removed = CHAT_SESSIONS.lock().remove(&session_id);
+    removed.is_some()
+}
+
+/// Close the sessions running on a model that is being unloaded or replaced
+///
+/// Sessions in the middle of a turn finish it first; their context is freed
+/// when the turn releases it.
+pub(crate) fn close_chat_sessions_for_model(state: &Arc<ModelState>) {
+    let mut sessions = CHAT_SESSIONS.lock();
+    let ids: Vec<u64> = sessions
+        .iter()
+        .filter(|(_, entry)| Arc::ptr_eq(&entry.model, state))
+        .map(|(id, _)| *id)
+        .collect();
+    let closed: Vec<SessionEntry> = ids.iter().filter_map(|id| sessions.remove(id)).collect();
+    drop(sessions);
+
+    // Contexts are freed outside the registry lock
+    drop(closed);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_common_prefix_len() {
+        let cached = [LlamaToken(1), LlamaToken(2), LlamaToken(3)];
+        assert_eq!(common_prefix_len(&cached, &cached), 3);
+        assert_eq!(
+            common_prefix_len(&cached, &[LlamaToken(1), LlamaToken(2), LlamaToken(9)]),
+            2
+        );
+        assert_eq!(
+            common_prefix_len(
+                &cached,
+                &[LlamaToken(1), LlamaToken(2), LlamaToken(3), LlamaToken(4)]
+            ),
+            3
+        );
+        assert_eq!(common_prefix_len(&[], &cached), 0);
+    }
+
+    #[test]
+    fn test_session_header() {
+        let metadata = GgufMetadata {
+            architecture: Some("qwen2".to_string()),
+            parameter_count: 500_000_000,
+            tensor_count: 290,
+            file_size: 400_000_000,
+            ..GgufMetadata::default()
+        };
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("chat.session");
+
+        // Restoring without a header is refused
+        assert!(SessionHeader::read(&path).is_err());
+
+        let header = SessionHeader::new("/models/qwen.gguf", &metadata, 2048);
+        header.write(&path).unwrap();
+        assert!(dir.path().join("chat.session.meta").exists());
+        let restored = SessionHeader::read(&path).unwrap();
+        assert_eq!(restored, header);
+
+        // The same weights at another path are accepted, other weights are not
+        let moved = SessionHeader::new("/sdcard/qwen.gguf", &metadata, 2048);
+        assert!(restored.check_model(&moved).is_ok());
+        let other = GgufMetadata {
+            parameter_count: 1_500_000_000,
+            ..metadata
+        };
+        let err = restored
+            .check_model(&SessionHeader::new("/models/qwen-1.5b.gguf", &other, 2048))
+            .unwrap_err();
+        assert!(err.to_string().contains("/models/qwen.gguf"));
+
+        // The context defaults to the saved size and cannot shrink below it
+        assert_eq!(restored.resolve_n_ctx(None).unwrap(), 2048);
+        assert_eq!(restored.resolve_n_ctx(Some(4096)).unwrap(), 4096);
+        assert!(restored.resolve_n_ctx(Some(1024)).is_err());
+    }
+
+    #[test]
+    fn test_session_requires_model() {
+        assert!(create_chat_session(None, None).is_err());
+        assert!(
+            chat_session_completion(u64::MAX, Vec::new(), GenerationOptions::default()).is_err()
+        );
+        assert!(!close_chat_session(u64::MAX));
+    }
+}
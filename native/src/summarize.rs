@@ -0,0 +1,432 @@
+//! Map-Reduce Summarization
+//!
+//! Summarizes texts longer than the context window:
+//! - The input is split into token-limited chunks (notes by markdown
+//!   structure, transcripts by segment) and each chunk is summarized
+//! - Chunk summaries are merged in groups, level by level, until they fit
+//!   in one request, which writes the final summary
+//! - Transcript chunks carry `[mm:ss]` timestamps, which the summaries keep
+
+use anyhow::{bail, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::backend;
+use crate::chunker::{self, ChunkerOptions};
+use crate::inference::{self, GenerationOptions};
+use crate::tokenizer;
+
+/// Joins the summaries of a merge group
+const SEPARATOR: &str = "\n\n";
+
+/// Shape of the final summary
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum SummaryStyle {
+    /// A bulleted list of the main points
+    #[default]
+    Bullets,
+    /// One or two paragraphs of prose
+    Abstract,
+    /// A numbered list of the most important conclusions
+    KeyTakeaways,
+}
+
+/// Length, style and chunking settings for a summary
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SummaryOptions {
+    /// Shape of the final summary
+    pub style: SummaryStyle,
+    /// Target length of the final summary in words
+    pub max_words: u32,
+    /// Tokens of input per request (chunks and merge groups)
+    pub chunk_tokens: u32,
+    /// Model to summarize with (`None` = the default model)
+    pub model_id: Option<String>,
+}
+
+impl Default for SummaryOptions {
+    fn default() -> Self {
+        Self {
+            style: SummaryStyle::Bullets,
+            max_words: 200,
+            chunk_tokens: 1536,
+            model_id: None,
+        }
+    }
+}
+
+/// A timed piece of a transcript
+///
+/// Mirrors `TranscriptionSegment` from the audio crate, so its serialized
+/// segments can be deserialized directly.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TranscriptSegment {
+    /// The transcribed text
+    pub text: String,
+    /// Start time in seconds
+    pub start_time: f32,
+    /// End time in seconds
+    pub end_time: f32,
+}
+
+/// A finished summary with its intermediate steps
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Summary {
+    /// The final summary
+    pub summary: String,
+    /// Summary of each input chunk, in order
+    pub chunk_summaries: Vec<String>,
+    /// Merge rounds, including the final one (0 = the input fit in one chunk)
+    pub merge_levels: u32,
+}
+
+/// Summarize a note or any long text
+///
+/// # Arguments
+/// * `text` - The text to summarize (markdown is chunked at headings)
+/// * `options` - Length, style and chunking settings
+///
+/// # Returns
+/// * The summary with the chunk summaries it was built from
+pub fn summarize_text(text: &str, options: &SummaryOptions) -> Result<Summary> {
+    let chunker_options = ChunkerOptions {
+        max_tokens: options.chunk_tokens,
+        overlap_tokens: 0,
+        // Chunks are sized for the chat model that reads them
+        model_id: options
+            .model_id
+            .clone()
+            .or_else(inference::default_model_id),
+    };
+    let chunks: Vec<String> = chunker::chunk_markdown(text, &chunker_options)?
+        .into_iter()
+        .map(|chunk| chunk.text)
+        .collect();
+    map_reduce(chunks, false, options)
+}
+
+/// Summarize a transcript, keeping timestamps for the key points
+///
+/// # Arguments
+/// * `segments` - Transcript segments in order
+/// * `options` - Length, style and chunking settings
+///
+/// # Returns
+/// * The summary with the chunk summaries it was built from
+pub fn summarize_transcript(
+    segments: &[TranscriptSegment],
+    options: &SummaryOptions,
+) -> Result<Summary> {
+    let mut chunks = Vec::new();
+    let mut current = String::new();
+    let mut current_tokens = 0;
+    for segment in segments {
+        let text = segment.text.trim();
+        if text.is_empty() {
+            continue;
+        }
+        let line = format!("[{}] {}\n", format_timestamp(segment.start_time), text);
+        let tokens = tokenizer::count_tokens(&line, options.model_id.as_deref())?;
+        if !current.is_empty() && current_tokens + tokens > options.chunk_tokens {
+            chunks.push(std::mem::take(&mut current));
+            current_tokens = 0;
+        }
+        current.push_str(&line);
+        current_tokens += tokens;
+    }
+    if !current.is_empty() {
+        chunks.push(current);
+    }
+    map_reduce(chunks, true, options)
+}
+
+/// `seconds` as `mm:ss`, or `h:mm:ss` from one hour on
+pub fn format_timestamp(seconds: f32) -> String {
+    let total = seconds.max(0.0) as u32;
+    let (hours, minutes, seconds) = (total / 3600, total / 60 % 60, total % 60);
+    if hours > 0 {
+        format!("{}:{:02}:{:02}", hours, minutes, seconds)
+    } else {
+        format!("{:02}:{:02}", minutes, seconds)
+    }
+}
+
+fn map_reduce(chunks: Vec<String>, timestamps: bool, options: &SummaryOptions) -> Result<Summary> {
+    if chunks.is_empty() {
+        bail!("Nothing to summarize");
+    }
+    // A single chunk is summarized in the requested style right away
+    if chunks.len() == 1 {
+        let summary = complete(&final_prompt(options, timestamps), &chunks[0], options)?;
+        return Ok(Summary {
+            summary: summary.clone(),
+            chunk_summaries: vec![summary],
+            merge_levels: 0,
+        });
+    }
+
+    let mut chunk_summaries = Vec::with_capacity(chunks.len());
+    for chunk in &chunks {
+        chunk_summaries.push(complete(&map_prompt(timestamps), chunk, options)?);
+    }
+
+    let mut summaries = chunk_summaries.clone();
+    let mut merge_levels = 0;
+    loop {
+        let groups = merge_groups(summaries, timestamps, options)?;
+        if groups.len() == 1 {
+            let combined = groups[0].join(SEPARATOR);
+            let summary = complete(&final_prompt(options, timestamps), &combined, options)?;
+            return Ok(Summary {
+                summary,
+                chunk_summaries,
+                merge_levels: merge_levels + 1,
+            });
+        }
+        let mut merged = Vec::with_capacity(groups.len());
+        for group in groups {
+            merged.push(complete(
+                &merge_prompt(timestamps),
+                &group.join(SEPARATOR),
+                options,
+            )?);
+        }
+        summaries = merged;
+        merge_levels += 1;
+    }
+}
+
+/// Consecutive summaries grouped to fit in one request
+///
+/// Summaries over half the budget are condensed first, so any two fit
+/// together: groups hold at least two summaries (all but a last one left
+/// over) and every level shrinks the list.
+fn merge_groups(
+    summaries: Vec<String>,
+    timestamps: bool,
+    options: &SummaryOptions,
+) -> Result<Vec<Vec<String>>> {
+    let model_id = options.model_id.as_deref();
+    let separator = tokenizer::count_tokens(SEPARATOR, model_id)?;
+    let budget = options.chunk_tokens.max(2 + separator);
+    let half = (budget - separator) / 2;
+
+    let mut groups: Vec<Vec<String>> = Vec::new();
+    let mut tokens = 0;
+    for mut summary in summaries {
+        let mut count = tokenizer::count_tokens(&summary, model_id)?;
+        if count > half {
+            summary = condense(&summary, half, timestamps, options)?;
+            count = tokenizer::count_tokens(&summary, model_id)?;
+        }
+        match groups.last_mut() {
+            Some(group) if tokens + separator + count <= budget => {
+                group.push(summary);
+                tokens += separator + count;
+            }
+            _ => {
+                groups.push(vec![summary]);
+                tokens = count;
+            }
+        }
+    }
+    Ok(groups)
+}
+
+/// `summary` condensed to at most `max_tokens` tokens
+///
+/// The model writes at most `max_tokens` tokens, and whatever retokenizes
+/// past the budget is cut off.
+fn condense(
+    summary: &str,
+    max_tokens: u32,
+    timestamps: bool,
+    options: &SummaryOptions,
+) -> Result<String> {
+    let condensed = complete_within(&condense_prompt(timestamps), summary, max_tokens, options)?;
+    let model_id = options
+        .model_id
+        .clone()
+        .or_else(inference::default_model_id);
+    let offsets = backend::active_backend().token_offsets(&condensed, model_id.as_deref())?;
+    Ok(match offsets.get(max_tokens as usize) {
+        Some(&end) => condensed[..end].trim_end().to_string(),
+        None => condensed,
+    })
+}
+
+fn map_prompt(timestamps: bool) -> String {
+    let mut prompt = "Summarize this part of a longer document. \
+        List its main points concisely, keeping names, numbers and definitions."
+        .to_string();
+    if timestamps {
+        prompt.push_str(" Start each point with the [mm:ss] timestamp where it is discussed.");
+    }
+    prompt
+}
+
+fn merge_prompt(timestamps: bool) -> String {
+    let mut prompt = "These are summaries of consecutive parts of a longer document. \
+        Merge them into one concise list of main points, removing repetition."
+        .to_string();
+    if timestamps {
+        prompt.push_str(" Keep the [mm:ss] timestamps of the points you keep.");
+    }
+    prompt
+}
+
+fn condense_prompt(timestamps: bool) -> String {
+    let mut prompt = "This is a summary of part of a longer document. \
+        Shorten it to its most important points."
+        .to_string();
+    if timestamps {
+        prompt.push_str(" Keep the [mm:ss] timestamps of the points you keep.");
+    }
+    prompt
+}
+
+fn final_prompt(options: &SummaryOptions, timestamps: bool) -> String {
+    let shape = match options.style {
+        SummaryStyle::Bullets => "a bulleted list of the main points",
+        SummaryStyle::Abstract => "an abstract of one or two paragraphs of prose",
+        SummaryStyle::KeyTakeaways => "a numbered list of the key takeaways",
+    };
+    let mut prompt = format!(
+        "Summarize the following as {} in at most {} words.",
+        shape, options.max_words
+    );
+    if timestamps {
+        prompt.push_str(" Keep the [mm:ss] timestamp of each point.");
+    }
+    prompt
+}
+
+fn complete(instruction: &str, input: &str, options: &SummaryOptions) -> Result<String> {
+    // Roughly two tokens per word, with room for list markers
+    complete_within(instruction, input, options.max_words * 2 + 64, options)
+}
+
+fn complete_within(
+    instruction: &str,
+    input: &str,
+    max_tokens: u32,
+    options: &SummaryOptions,
+) -> Result<String> {
+    let messages = vec![
+        ("system".to_string(), instruction.to_string()),
+        ("user".to_string(), input.to_string()),
+    ];
+    let generation = GenerationOptions {
+        max_tokens: Some(max_tokens),
+        model_id: options.model_id.clone(),
+        ..GenerationOptions::default()
+    };
+    Ok(
+        inference::chat_completion_with_options(messages, generation)?
+            .text
+            .trim()
+            .to_string(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::{with_inference_backend, MockBackend};
+    use std::sync::Arc;
+
+    #[test]
+    fn test_format_timestamp() {
+        assert_eq!(format_timestamp(0.0), "00:00");
+        assert_eq!(format_timestamp(754.6), "12:34");
+        assert_eq!(format_timestamp(3725.0), "1:02:05");
+    }
+
+    #[test]
+    fn test_map_reduce_over_sections() {
+        let note: String = (0..8)
+            .map(|i| format!("# Part {}\n\n{}\n\n", i, "lecture words ".repeat(20)))
+            .collect();
+        let backend = Arc::new(MockBackend::new(8).with_fallback("merged points here"));
+        let options = SummaryOptions {
+            style: SummaryStyle::KeyTakeaways,
+            chunk_tokens: 50,
+            ..SummaryOptions::default()
+        };
+
+        let summary =
+            with_inference_backend(backend.clone(), || summarize_text(&note, &options)).unwrap();
+
+        assert_eq!(summary.chunk_summaries.len(), 8);
+        assert_eq!(summary.summary, "merged points here");
+        assert!(summary.merge_levels >= 1);
+        let prompts = backend.prompts();
+        assert!(prompts
+            .last()
+            .unwrap()
+            .contains("a numbered list of the key takeaways in at most 200 words"));
+    }
+
+    #[test]
+    fn test_merge_requests_fit_when_summaries_are_long() {
+        let note: String = (0..8)
+            .map(|i| format!("# Part {}\n\n{}\n\n", i, "lecture words ".repeat(20)))
+            .collect();
+        // Every summary is longer than half of `chunk_tokens`
+        let long = "point ".repeat(40);
+        let backend = Arc::new(MockBackend::new(8).with_fallback(long.trim_end()));
+        let options = SummaryOptions {
+            chunk_tokens: 50,
+            ..SummaryOptions::default()
+        };
+
+        let summary =
+            with_inference_backend(backend.clone(), || summarize_text(&note, &options)).unwrap();
+
+        assert_eq!(summary.chunk_summaries.len(), 8);
+        let prompts = backend.prompts();
+        assert!(prompts.iter().any(|p| p.contains("Shorten it")));
+        let merges: Vec<&String> = prompts
+            .iter()
+            .filter(|p| p.contains("Merge them") || p.contains("in at most 200 words"))
+            .collect();
+        assert!(merges.len() >= 2);
+        for prompt in merges {
+            let input = prompt.split_once("\nuser: ").unwrap().1;
+            let tokens = input.split_inclusive(char::is_whitespace).count();
+            assert!(tokens <= 50, "{} tokens in a merge request", tokens);
+        }
+    }
+
+    #[test]
+    fn test_transcript_keeps_timestamps() {
+        let segments = vec![
+            TranscriptSegment {
+                text: "Welcome to the meeting.".to_string(),
+                start_time: 0.0,
+                end_time: 3.0,
+            },
+            TranscriptSegment {
+                text: "  ".to_string(),
+                start_time: 3.0,
+                end_time: 4.0,
+            },
+            TranscriptSegment {
+                text: "Budget is approved.".to_string(),
+                start_time: 65.0,
+                end_time: 68.0,
+            },
+        ];
+        let backend = Arc::new(MockBackend::with_script(8, ["- [01:05] Budget approved"]));
+
+        let summary = with_inference_backend(backend.clone(), || {
+            summarize_transcript(&segments, &SummaryOptions::default())
+        })
+        .unwrap();
+
+        assert_eq!(summary.summary, "- [01:05] Budget approved");
+        assert_eq!(summary.merge_levels, 0);
+        let prompt = &backend.prompts()[0];
+        assert!(prompt.contains("[00:00] Welcome to the meeting.\n[01:05] Budget is approved."));
+        assert!(prompt.contains("Keep the [mm:ss] timestamp"));
+    }
+}
@@ -0,0 +1,232 @@
+//! Tokenizer Utilities
+//!
+//! Exact token counts from a loaded model's own tokenizer:
+//! - Tokenize text into ids and detokenize ids back into text
+//! - Count tokens of plain text or of a templated chat prompt
+//! - Report model limits (training context, vocabulary size, special tokens)
+
+use anyhow::{anyhow, Result};
+use llama_cpp_2::model::{AddBos, LlamaModel, Special};
+use llama_cpp_2::token::LlamaToken;
+use serde::{Deserialize, Serialize};
+
+use crate::backend;
+use crate::inference::{self, ModelState};
+
+/// Tokenizer details and limits of a loaded model
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TokenizerInfo {
+    /// Number of tokens in the vocabulary
+    pub vocab_size: u32,
+    /// Context length the model was trained with
+    pub n_ctx_train: u32,
+    /// Context length configured for generation
+    pub n_ctx: u32,
+    /// Beginning-of-sequence token id
+    pub bos_token: i32,
+    /// End-of-sequence token id
+    pub eos_token: i32,
+    /// Tokenizer family from the GGUF metadata (e.g. "gpt2", "llama")
+    pub tokenizer_model: Option<String>,
+}
+
+/// Tokenize text with a loaded model
+///
+/// # Arguments
+/// * `text` - The text to tokenize
+/// * `add_bos` - Whether to prepend the beginning-of-sequence token
+/// * `parse_special` - Whether control tokens written in the text (e.g.
+///   `<|im_start|>`) are recognised, as they are when prompts are tokenized
+///   for generation
+/// * `model_id` - Model to use (`None` = the default model)
+///
+/// # Returns
+/// * Token ids
+pub fn tokenize(
+    text: &str,
+    add_bos: bool,
+    parse_special: bool,
+    model_id: Option<&str>,
+) -> Result<Vec<i32>> {
+    backend::active_backend().tokenize(text, add_bos, parse_special, model_id)
+}
+
+/// Tokenize with a loaded llama.cpp model (the default backend)
+pub(crate) fn llama_tokenize(
+    text: &str,
+    add_bos: bool,
+    parse_special: bool,
+    model_id: Option<&str>,
+) -> Result<Vec<i32>> {
+    let state = inference::current_state(model_id)?;
+    let add_bos = if add_bos {
+        AddBos::Always
+    } else {
+        AddBos::Never
+    };
+
+    let tokens = str_to_token(&state.model, text, add_bos, parse_special)?;
+    Ok(tokens.into_iter().map(|token| token.0).collect())
+}
+
+fn str_to_token(
+    model: &LlamaModel,
+    text: &str,
+    add_bos: AddBos,
+    parse_special: bool,
+) -> Result<Vec<LlamaToken>> {
+    model
+        .str_to_token_with_special(text, add_bos, parse_special)
+        .map_err(|e| anyhow!("Failed to tokenize: {:?}", e))
+}
+
+/// Byte offsets in `text` where each of `model`'s tokens starts
+pub(crate) fn model_token_offsets(model: &LlamaModel, text: &str) -> Result<Vec<usize>> {
+    let lengths = str_to_token(model, text, AddBos::Never, true)?
+        .into_iter()
+        .map(|token| {
+            model
+                .token_to_bytes(token, Special::Tokenize)
+                .map(|bytes| bytes.len())
+                .map_err(|e| anyhow!("Failed to detokenize token {}: {:?}", token.0, e))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok(offsets_from_lengths(text, &lengths))
+}
+
+/// Token start offsets from the byte length of each token's text
+///
+/// SentencePiece tokenizers prepend a space, which is not part of `text`.
+/// Offsets inside a character (byte-fallback tokens) move to its start.
+fn offsets_from_lengths(text: &str, lengths: &[usize]) -> Vec<usize> {
+    let added = lengths.iter().sum::<usize>().saturating_sub(text.len());
+    let mut pos = 0usize;
+    lengths
+        .iter()
+        .map(|len| {
+            let mut start = pos.saturating_sub(added).min(text.len());
+            while !text.is_char_boundary(start) {
+                start -= 1;
+            }
+            pos += len;
+            start
+        })
+        .collect()
+}
+
+/// Turn token ids back into text
+///
+/// # Arguments
+/// * `tokens` - Token ids from `tokenize`
+/// * `render_special` - Whether control tokens appear in the text
+/// * `model_id` - Model to use (`None` = the default model)
+///
+/// # Returns
+/// * The decoded text (invalid UTF-8 from partial characters is replaced)
+pub fn detokenize(tokens: &[i32], render_special: bool, model_id: Option<&str>) -> Result<String> {
+    let state = inference::current_state(model_id)?;
+    let tokens = to_llama_tokens(tokens, state.model.n_vocab())?;
+    let special = if render_special {
+        Special::Tokenize
+    } else {
+        Special::Plaintext
+    };
+
+    let mut bytes = Vec::new();
+    for token in tokens {
+        let piece = state
+            .model
+            .token_to_bytes(token, special)
+            .map_err(|e| anyhow!("Failed to detokenize token {}: {:?}", token.0, e))?;
+        bytes.extend(piece);
+    }
+    Ok(String::from_utf8_lossy(&bytes).into_owned())
+}
+
+/// Count the tokens in a text (without the beginning-of-sequence token)
+pub fn count_tokens(text: &str, model_id: Option<&str>) -> Result<u32> {
+    Ok(tokenize(text, false, true, model_id)?.len() as u32)
+}
+
+/// Count the tokens of a conversation after the chat template is applied
+///
+/// This is the exact prompt size `chat_completion` sends to the model.
+pub fn count_chat_prompt_tokens(
+    messages: &[(String, String)],
+    model_id: Option<&str>,
+) -> Result<u32> {
+    let state = inference::current_state(model_id)?;
+    Ok(count_prompt_tokens(&state, messages)? as u32)
+}
+
+/// Tokens in the prompt rendered from a conversation
+pub(crate) fn count_prompt_tokens(
+    state: &ModelState,
+    messages: &[(String, String)],
+) -> Result<usize> {
+    let prompt = inference::build_chat_prompt_for(state, messages);
+    Ok(inference::tokenize_prompt(state, &prompt)?.len())
+}
+
+/// Get the tokenizer details and limits of a loaded model
+pub fn get_tokenizer_info(model_id: Option<&str>) -> Result<TokenizerInfo> {
+    let state = inference::current_state(model_id)?;
+    Ok(TokenizerInfo {
+        vocab_size: state.model.n_vocab().max(0) as u32,
+        n_ctx_train: state.model.n_ctx_train(),
+        n_ctx: state.config.n_ctx,
+        bos_token: state.model.token_bos().0,
+        eos_token: state.model.token_eos().0,
+        tokenizer_model: state.metadata.tokenizer_model.clone(),
+    })
+}
+
+/// Check ids against the vocabulary before handing them to llama.cpp
+fn to_llama_tokens(tokens: &[i32], n_vocab: i32) -> Result<Vec<LlamaToken>> {
+    tokens
+        .iter()
+        .map(|&id| {
+            if (0..n_vocab).contains(&id) {
+                Ok(LlamaToken(id))
+            } else {
+                Err(anyhow!(
+                    "Token id {} is outside the vocabulary (0..{})",
+                    id,
+                    n_vocab
+                ))
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_token_ids_are_validated() {
+        assert_eq!(
+            to_llama_tokens(&[0, 5, 99], 100).unwrap(),
+            vec![LlamaToken(0), LlamaToken(5), LlamaToken(99)]
+        );
+        assert!(to_llama_tokens(&[100], 100).is_err());
+        assert!(to_llama_tokens(&[-1], 100).is_err());
+    }
+
+    #[test]
+    fn test_offsets_from_lengths() {
+        assert_eq!(offsets_from_lengths("hello world", &[5, 6]), vec![0, 5]);
+        // A prepended space shifts every offset but the first
+        assert_eq!(offsets_from_lengths("hello world", &[6, 6]), vec![0, 5]);
+        // Byte tokens of a multi-byte character start at the character
+        assert_eq!(offsets_from_lengths("aé", &[1, 1, 1]), vec![0, 1, 1]);
+    }
+
+    #[test]
+    fn test_tokenizer_requires_model() {
+        assert!(tokenize("hello", true, true, Some("missing")).is_err());
+        assert!(detokenize(&[1, 2], false, Some("missing")).is_err());
+        assert!(count_chat_prompt_tokens(&[], Some("missing")).is_err());
+        assert!(get_tokenizer_info(Some("missing")).is_err());
+    }
+}
@@ -0,0 +1,512 @@
+//! Tool-Call Parsing
+//!
+//! Extracts tool calls from free-form model output. Each model family wraps
+//! calls differently:
+//! - Qwen: `<tool_call>{"name": ..., "arguments": ...}</tool_call>`
+//! - Functionary: `<|recipient|>tool_name` / `>>>tool_name` blocks, or
+//!   `<function=tool_name>{...}</function>`
+//! - Phi-4: `<|tool_call|>[...]<|/tool_call|>` or fenced JSON
+//!
+//! The family's own format is matched first; fenced and bare JSON are then
+//! accepted anywhere else. Every byte of the reply is reported as either
+//! plain text or part of a call.
+
+use serde::{Deserialize, Serialize};
+
+use crate::inference::ModelType;
+use crate::mcp::{self, MCPToolCall};
+
+/// What a span of a reply holds
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ReplySpanKind {
+    /// Prose meant for the user
+    Text,
+    /// One or more tool calls, including their wrapping tags
+    ToolCall,
+}
+
+/// A byte range of a reply
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReplySpan {
+    /// Start byte offset
+    pub start: usize,
+    /// End byte offset (exclusive)
+    pub end: usize,
+    /// Whether the range is text or tool calls
+    pub kind: ReplySpanKind,
+    /// Number of calls parsed from the range (0 for text)
+    pub call_count: usize,
+}
+
+/// A model reply split into tool calls and text
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ParsedReply {
+    /// Calls in the order they appear
+    pub tool_calls: Vec<MCPToolCall>,
+    /// Text and call spans covering the reply in order (whitespace-only
+    /// text between calls is left out)
+    pub spans: Vec<ReplySpan>,
+    /// The text spans joined, without control markers
+    pub text: String,
+}
+
+/// A region of the reply that holds calls
+#[derive(Debug)]
+struct Block {
+    start: usize,
+    end: usize,
+    calls: Vec<MCPToolCall>,
+}
+
+type Extractor = fn(&str) -> Vec<Block>;
+
+/// Split a model reply into tool calls and text
+///
+/// # Arguments
+/// * `reply` - The raw model output
+/// * `model_type` - Family whose call format to expect (`None` = generic)
+pub fn parse_reply(reply: &str, model_type: Option<ModelType>) -> ParsedReply {
+    let native: &[Extractor] = match model_type {
+        Some(ModelType::Qwen) | None => &[hermes_blocks],
+        Some(ModelType::Functionary) => {
+            &[functionary_function_blocks, functionary_recipient_blocks]
+        }
+        Some(ModelType::Phi4) => &[phi4_blocks],
+    };
+
+    let mut blocks = Vec::new();
+    for extractor in native
+        .iter()
+        .chain([fenced_blocks as Extractor, bare_json_blocks].iter())
+    {
+        extract_in_gaps(reply, &mut blocks, *extractor);
+    }
+
+    let mut parsed = ParsedReply {
+        tool_calls: Vec::new(),
+        spans: Vec::new(),
+        text: String::new(),
+    };
+    let mut pos = 0;
+    for block in blocks {
+        push_text(reply, pos, block.start, model_type, &mut parsed);
+        parsed.spans.push(ReplySpan {
+            start: block.start,
+            end: block.end,
+            kind: ReplySpanKind::ToolCall,
+            call_count: block.calls.len(),
+        });
+        parsed.tool_calls.extend(block.calls);
+        pos = block.end;
+    }
+    push_text(reply, pos, reply.len(), model_type, &mut parsed);
+
+    parsed.text = parsed.text.trim().to_string();
+    parsed
+}
+
+/// Record `reply[start..end]` as text unless it is only whitespace or markers
+fn push_text(
+    reply: &str,
+    start: usize,
+    end: usize,
+    model_type: Option<ModelType>,
+    parsed: &mut ParsedReply,
+) {
+    let text = strip_markers(&reply[start..end], model_type);
+    if text.trim().is_empty() {
+        return;
+    }
+    parsed.spans.push(ReplySpan {
+        start,
+        end,
+        kind: ReplySpanKind::Text,
+        call_count: 0,
+    });
+    parsed.text.push_str(&text);
+}
+
+/// Remove the turn markers a family writes around plain text
+fn strip_markers(text: &str, model_type: Option<ModelType>) -> String {
+    let markers: &[&str] = match model_type {
+        Some(ModelType::Functionary) => &[
+            "<|from|>assistant\n",
+            "<|recipient|>all\n",
+            "<|content|>",
+            "<|stop|>",
+            ">>>all\n",
+        ],
+        Some(ModelType::Phi4) => &["<|end|>"],
+        Some(ModelType::Qwen) => &["<|im_end|>"],
+        None => &[],
+    };
+    markers
+        .iter()
+        .fold(text.to_string(), |text, marker| text.replace(marker, ""))
+}
+
+/// Run `extractor` on every stretch of the reply not yet claimed by a block
+fn extract_in_gaps(reply: &str, blocks: &mut Vec<Block>, extractor: Extractor) {
+    let mut found = Vec::new();
+    let mut pos = 0;
+    let ends = blocks
+        .iter()
+        .map(|block| (block.start, block.end))
+        .chain(std::iter::once((reply.len(), reply.len())));
+    for (start, end) in ends {
+        for mut block in extractor(&reply[pos..start]) {
+            block.start += pos;
+            block.end += pos;
+            found.push(block);
+        }
+        pos = end;
+    }
+    blocks.extend(found);
+    blocks.sort_by_key(|block| block.start);
+}
+
+/// Calls in a JSON payload: one call object or an array of them
+fn calls_from_json(payload: &str) -> Option<Vec<MCPToolCall>> {
+    let value: serde_json::Value = serde_json::from_str(payload.trim()).ok()?;
+    let items = match &value {
+        serde_json::Value::Array(items) => items.as_slice(),
+        _ => std::slice::from_ref(&value),
+    };
+    let calls: Option<Vec<MCPToolCall>> = items.iter().map(call_from_value).collect();
+    calls.filter(|calls| !calls.is_empty())
+}
+
+/// A call from a JSON object with `tool`, or `name` with `arguments`
+fn call_from_value(value: &serde_json::Value) -> Option<MCPToolCall> {
+    let is_call = value.get("tool").is_some()
+        || (value.get("name").is_some() && value.get("arguments").is_some());
+    if !is_call {
+        return None;
+    }
+    mcp::parse_tool_call(&value.to_string()).ok()
+}
+
+/// Blocks between `open` and `close` tags (an unclosed block runs to the end)
+fn tagged_blocks(text: &str, open: &str, close: &str) -> Vec<Block> {
+    let mut blocks = Vec::new();
+    let mut pos = 0;
+    while let Some(found) = text[pos..].find(open) {
+        let start = pos + found;
+        let payload_start = start + open.len();
+        let (payload_end, end) = match text[payload_start..].find(close) {
+            Some(offset) => (payload_start + offset, payload_start + offset + close.len()),
+            None => (text.len(), text.len()),
+        };
+        if let Some(calls) = calls_from_json(&text[payload_start..payload_end]) {
+            blocks.push(Block { start, end, calls });
+        }
+        pos = end;
+    }
+    blocks
+}
+
+/// Qwen / Hermes `<tool_call>` blocks
+fn hermes_blocks(text: &str) -> Vec<Block> {
+    tagged_blocks(text, "<tool_call>", "</tool_call>")
+}
+
+/// Phi-4 `<|tool_call|>` blocks
+fn phi4_blocks(text: &str) -> Vec<Block> {
+    tagged_blocks(text, "<|tool_call|>", "<|/tool_call|>")
+}
+
+/// Functionary v3.1 `<function=name>{arguments}</function>` blocks
+fn functionary_function_blocks(text: &str) -> Vec<Block> {
+    let mut blocks = Vec::new();
+    let mut pos = 0;
+    while let Some(found) = text[pos..].find("<function=") {
+        let start = pos + found;
+        let name_start = start + "<function=".len();
+        let Some(name_len) = text[name_start..].find('>') else {
+            break;
+        };
+        let name = &text[name_start..name_start + name_len];
+        let payload_start = name_start + name_len + 1;
+        let (payload_end, end) = match text[payload_start..].find("</function>") {
+            Some(offset) => (
+                payload_start + offset,
+                payload_start + offset + "</function>".len(),
+            ),
+            None => (text.len(), text.len()),
+        };
+        if let Some(call) = named_call(name, &text[payload_start..payload_end]) {
+            blocks.push(Block {
+                start,
+                end,
+                calls: vec![call],
+            });
+        }
+        pos = end;
+    }
+    blocks
+}
+
+/// Functionary v2 `<|recipient|>name\n<|content|>{...}` and v3 `>>>name\n{...}` blocks
+///
+/// A recipient of `all` addresses the user, so its content stays text.
+/// `>>>` only counts at the start of a line, so prose like `a >>> b` is text.
+fn functionary_recipient_blocks(text: &str) -> Vec<Block> {
+    const MARKERS: [&str; 2] = ["<|recipient|>", ">>>"];
+    const ENDS: [&str; 4] = ["<|from|>", "<|recipient|>", ">>>", "<|stop|>"];
+
+    let anchored =
+        |marker: &str, start: usize| marker != ">>>" || start == 0 || text[..start].ends_with('\n');
+    let next_marker = |from: usize, markers: &[&str]| {
+        markers
+            .iter()
+            .filter_map(|marker| {
+                text[from..]
+                    .match_indices(marker)
+                    .map(|(offset, _)| from + offset)
+                    .find(|&start| anchored(marker, start))
+                    .map(|start| (start, marker.len()))
+            })
+            .min()
+    };
+
+    let mut blocks = Vec::new();
+    let mut pos = 0;
+    while let Some((marker_start, marker_len)) = next_marker(pos, &MARKERS) {
+        let name_start = marker_start + marker_len;
+        let name_end = text[name_start..]
+            .find(['\n', '<'])
+            .map_or(text.len(), |offset| name_start + offset);
+        let name = text[name_start..name_end].trim();
+
+        let mut payload_start = name_end;
+        if let Some(rest) = text[payload_start..].strip_prefix('\n') {
+            payload_start = text.len() - rest.len();
+        }
+        if let Some(rest) = text[payload_start..].strip_prefix("<|content|>") {
+            payload_start = text.len() - rest.len();
+        }
+        let end = next_marker(payload_start, &ENDS).map_or(text.len(), |(start, _)| start);
+
+        if name != "all" {
+            if let Some(call) = named_call(name, &text[payload_start..end]) {
+                // Claim the turn header written just before the recipient
+                let header = "<|from|>assistant\n";
+                let start = if text[..marker_start].ends_with(header) {
+                    marker_start - header.len()
+                } else {
+                    marker_start
+                };
+                blocks.push(Block {
+                    start,
+                    end,
+                    calls: vec![call],
+                });
+            }
+        }
+        pos = end.max(name_start);
+    }
+    blocks
+}
+
+/// A call to `name` whose payload starts with its arguments object
+fn named_call(name: &str, payload: &str) -> Option<MCPToolCall> {
+    if name.is_empty() {
+        return None;
+    }
+    let (start, end) = json_spans(payload).into_iter().next()?;
+    if !payload[..start].trim().is_empty() {
+        return None;
+    }
+    let arguments: serde_json::Value = serde_json::from_str(&payload[start..end]).ok()?;
+    if !arguments.is_object() {
+        return None;
+    }
+    Some(MCPToolCall {
+        tool: name.to_string(),
+        parameters_json: arguments.to_string(),
+        description: String::new(),
+    })
+}
+
+/// Fenced code blocks (```json ... ```) whose content is a call or list of calls
+fn fenced_blocks(text: &str) -> Vec<Block> {
+    let mut blocks = Vec::new();
+    let mut pos = 0;
+    while let Some(found) = text[pos..].find("```") {
+        let start = pos + found;
+        let info_start = start + 3;
+        let Some(info_len) = text[info_start..].find('\n') else {
+            break;
+        };
+        let payload_start = info_start + info_len + 1;
+        let Some(close) = text[payload_start..].find("```") else {
+            break;
+        };
+        let payload_end = payload_start + close;
+        let end = payload_end + 3;
+
+        if let Some(calls) = calls_from_json(&text[payload_start..payload_end]) {
+            blocks.push(Block { start, end, calls });
+        }
+        pos = end;
+    }
+    blocks
+}
+
+/// Bare JSON objects or arrays that are calls
+fn bare_json_blocks(text: &str) -> Vec<Block> {
+    json_spans(text)
+        .into_iter()
+        .filter_map(|(start, end)| {
+            calls_from_json(&text[start..end]).map(|calls| Block { start, end, calls })
+        })
+        .collect()
+}
+
+/// Byte ranges of the top-level `{...}` and `[...]` blocks in `text`
+///
+/// Brackets inside JSON strings are skipped; unbalanced blocks are dropped.
+fn json_spans(text: &str) -> Vec<(usize, usize)> {
+    let mut spans = Vec::new();
+    let mut stack = Vec::new();
+    let mut start = 0;
+    let mut in_string = false;
+    let mut escaped = false;
+
+    for (i, c) in text.char_indices() {
+        if in_string {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+        match c {
+            '"' if !stack.is_empty() => in_string = true,
+            '{' | '[' => {
+                if stack.is_empty() {
+                    start = i;
+                }
+                stack.push(if c == '{' { '}' } else { ']' });
+            }
+            '}' | ']' if !stack.is_empty() => {
+                if stack.pop() != Some(c) {
+                    // Mismatched bracket: not JSON, start over
+                    stack.clear();
+                } else if stack.is_empty() {
+                    spans.push((start, i + 1));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    spans
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tools(parsed: &ParsedReply) -> Vec<&str> {
+        parsed
+            .tool_calls
+            .iter()
+            .map(|call| call.tool.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn test_qwen_tool_call_tags() {
+        let reply = "I'll look.\n<tool_call>\n{\"name\": \"read_file\", \"arguments\": {\"path\": \"a.md\"}}\n</tool_call>\n<tool_call>\n{\"name\": \"list_files\", \"arguments\": {}}\n</tool_call>";
+        let parsed = parse_reply(reply, Some(ModelType::Qwen));
+
+        assert_eq!(tools(&parsed), vec!["read_file", "list_files"]);
+        assert_eq!(parsed.tool_calls[0].get_parameters()["path"], "a.md");
+        assert_eq!(parsed.text, "I'll look.");
+        let kinds: Vec<ReplySpanKind> = parsed.spans.iter().map(|s| s.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                ReplySpanKind::Text,
+                ReplySpanKind::ToolCall,
+                ReplySpanKind::ToolCall
+            ]
+        );
+        assert_eq!(
+            &reply[parsed.spans[0].start..parsed.spans[0].end],
+            "I'll look.\n"
+        );
+    }
+
+    #[test]
+    fn test_functionary_recipient_blocks() {
+        let reply = "Checking your notes.\n<|from|>assistant\n<|recipient|>list_files\n<|content|>{\"path\": \"notes\"}\n<|from|>assistant\n<|recipient|>read_file\n<|content|>{\"path\": \"todo.md\"}<|stop|>";
+        let parsed = parse_reply(reply, Some(ModelType::Functionary));
+        assert_eq!(tools(&parsed), vec!["list_files", "read_file"]);
+        assert_eq!(parsed.tool_calls[1].get_parameters()["path"], "todo.md");
+        assert_eq!(parsed.text, "Checking your notes.");
+
+        // v3 style, with a message to the user
+        let parsed = parse_reply(
+            ">>>all\nSure.\n>>>timer_lua\n{\"minutes\": 25}",
+            Some(ModelType::Functionary),
+        );
+        assert_eq!(tools(&parsed), vec!["timer_lua"]);
+        assert_eq!(parsed.text, "Sure.");
+
+        // `>>>` inside a line is not a recipient
+        let parsed = parse_reply(
+            ">>>all\nShift with a >>> b.\n>>>timer_lua\n{\"minutes\": 5}",
+            Some(ModelType::Functionary),
+        );
+        assert_eq!(tools(&parsed), vec!["timer_lua"]);
+        assert_eq!(parsed.text, "Shift with a >>> b.");
+
+        let parsed = parse_reply(
+            "<function=read_file>{\"path\": \"a.md\"}</function>",
+            Some(ModelType::Functionary),
+        );
+        assert_eq!(tools(&parsed), vec!["read_file"]);
+        assert!(parsed.text.is_empty());
+    }
+
+    #[test]
+    fn test_phi4_fenced_and_bare_json() {
+        let reply = "Here you go:\n```json\n[{\"name\": \"create_folder\", \"arguments\": {\"path\": \"x\"}}, {\"tool\": \"list_files\", \"parameters\": {}}]\n```\nDone.";
+        let parsed = parse_reply(reply, Some(ModelType::Phi4));
+        assert_eq!(tools(&parsed), vec!["create_folder", "list_files"]);
+        assert_eq!(parsed.spans[1].call_count, 2);
+        assert_eq!(parsed.text, "Here you go:\n\nDone.");
+
+        let parsed = parse_reply(
+            "<|tool_call|>[{\"name\": \"read_file\", \"arguments\": {\"path\": \"b.md\"}}]<|/tool_call|>",
+            Some(ModelType::Phi4),
+        );
+        assert_eq!(tools(&parsed), vec!["read_file"]);
+    }
+
+    #[test]
+    fn test_non_calls_stay_text() {
+        let reply = "Use `{}` like this:\n```rust\nfn main() { println!(\"[x]\"); }\n```\nand {\"name\": \"value\"} is data.";
+        let parsed = parse_reply(reply, None);
+        assert!(parsed.tool_calls.is_empty());
+        assert_eq!(parsed.spans.len(), 1);
+        assert_eq!(parsed.text, reply);
+
+        // Broken JSON inside tags is not a call
+        let parsed = parse_reply("<tool_call>{\"name\": </tool_call>", Some(ModelType::Qwen));
+        assert!(parsed.tool_calls.is_empty());
+    }
+
+    #[test]
+    fn test_json_spans() {
+        assert_eq!(
+            json_spans("a {\"b\": \"}\"} c [1, [2]] ]"),
+            vec![(2, 12), (15, 23)]
+        );
+        assert!(json_spans("{ ] }").is_empty());
+    }
+}
@@ -0,0 +1,881 @@
+//! Persistent Vector Store
+//!
+//! Rust-owned storage for embedding entries, so searches and clustering no
+//! longer need every vector shipped across FFI:
+//! - Upserts and deletes by id are appended to disk, so writes stay small
+//! - Vectors live in a memory-mapped file of fixed-size rows; ids, previews
+//!   and metadata live in a JSON-lines sidecar log
+//! - Vectors are synced before the log line that refers to them, and a torn
+//!   tail left by a crash is dropped on open
+//! - Compaction writes a new generation of both files and switches to it by
+//!   atomically replacing the `CURRENT` file
+//!
+//! A store is a directory holding:
+//! - `CURRENT` - `{"version":1,"generation":N}`
+//! - `vectors-N.bin` - magic `KVXVEC01`, format version (u32), dimension (u32),
+//!   then rows of little-endian f32
+//! - `entries-N.log` - one JSON record per upsert or delete
+
+use anyhow::{anyhow, bail, Result};
+use memmap2::Mmap;
+use once_cell::sync::Lazy;
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs::{self, File, OpenOptions};
+use std::io::{BufWriter, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+
+use crate::embeddings::{EmbeddingEntry, NoteMetadata, SimilarityResult};
+use crate::quantization;
+
+const MAGIC: &[u8; 8] = b"KVXVEC01";
+const FORMAT_VERSION: u32 = 1;
+const HEADER_LEN: u64 = 16;
+
+/// Contents of the `CURRENT` file
+#[derive(Debug, Serialize, Deserialize)]
+struct Current {
+    version: u32,
+    generation: u64,
+}
+
+/// One line of the entries log
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "op", rename_all = "lowercase")]
+enum LogRecord {
+    Upsert {
+        id: String,
+        row: u32,
+        text_preview: Option<String>,
+        #[serde(default)]
+        metadata: NoteMetadata,
+    },
+    Delete {
+        id: String,
+    },
+}
+
+#[derive(Debug, Clone)]
+struct Record {
+    row: u32,
+    text_preview: Option<String>,
+    metadata: NoteMetadata,
+}
+
+/// Size and garbage of a vector store
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VectorStoreStats {
+    /// Live entries
+    pub entries: usize,
+    /// Vector rows of replaced or deleted entries, dropped by compaction
+    pub dead_rows: usize,
+    /// Embedding dimension (0 until the first upsert)
+    pub dimension: usize,
+    /// Size of the vector and log files in bytes
+    pub file_bytes: u64,
+}
+
+/// An open vector store directory
+#[derive(Debug)]
+pub struct VectorStore {
+    dir: PathBuf,
+    generation: u64,
+    dimension: usize,
+    vectors: File,
+    /// Map of the vector file; `None` while it has no rows
+    map: Option<Mmap>,
+    rows: u32,
+    log: File,
+    records: HashMap<String, Record>,
+    dead_rows: usize,
+}
+
+impl VectorStore {
+    /// Open a store directory, creating an empty store if needed
+    pub fn open(dir: &Path) -> Result<Self> {
+        fs::create_dir_all(dir)?;
+        let generation = match fs::read_to_string(dir.join("CURRENT")) {
+            Ok(text) => {
+                let current: Current = serde_json::from_str(&text)
+                    .map_err(|e| anyhow!("Corrupt vector store CURRENT file: {}", e))?;
+                if current.version > FORMAT_VERSION {
+                    bail!(
+                        "Vector store format version {} is newer than supported ({})",
+                        current.version,
+                        FORMAT_VERSION
+                    );
+                }
+                current.generation
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                write_current(dir, 0)?;
+                0
+            }
+            Err(e) => return Err(e.into()),
+        };
+
+        let store = Self::open_generation(dir, generation)?;
+        remove_other_generations(dir, generation)?;
+        Ok(store)
+    }
+
+    /// Open the files of one generation, whether or not it is current
+    fn open_generation(dir: &Path, generation: u64) -> Result<Self> {
+        let mut vectors = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(vectors_path(dir, generation))?;
+        let mut len = vectors.metadata()?.len();
+        if len < HEADER_LEN {
+            // New file, or a crash before the header was synced
+            vectors.set_len(0)?;
+            write_header(&mut vectors, 0)?;
+            len = HEADER_LEN;
+        }
+        let dimension = read_header(&vectors, dir)?;
+        let row_bytes = (dimension * 4) as u64;
+        let rows = (len - HEADER_LEN).checked_div(row_bytes).unwrap_or(0);
+        if HEADER_LEN + rows * row_bytes != len {
+            // Drop a partially written row
+            vectors.set_len(HEADER_LEN + rows * row_bytes)?;
+        }
+
+        let log_path = log_path(dir, generation);
+        let log_bytes = match fs::read(&log_path) {
+            Ok(bytes) => bytes,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Vec::new(),
+            Err(e) => return Err(e.into()),
+        };
+        let mut records: HashMap<String, Record> = HashMap::new();
+        let mut valid_len = 0;
+        let mut lines = log_bytes.split_inclusive(|&b| b == b'\n').peekable();
+        while let Some(line) = lines.next() {
+            let is_last = lines.peek().is_none();
+            let parsed = serde_json::from_slice::<LogRecord>(line);
+            let record = match parsed {
+                Ok(record) if line.ends_with(b"\n") => record,
+                // A torn final line is an interrupted write
+                _ if is_last => break,
+                _ => bail!("Corrupt vector store log at byte {}", valid_len),
+            };
+            match record {
+                LogRecord::Upsert {
+                    id,
+                    row,
+                    text_preview,
+                    metadata,
+                } => {
+                    if row as u64 >= rows {
+                        bail!("Vector store log refers to missing row {}", row);
+                    }
+                    let record = Record {
+                        row,
+                        text_preview,
+                        metadata,
+                    };
+                    records.insert(id, record);
+                }
+                LogRecord::Delete { id } => {
+                    records.remove(&id);
+                }
+            }
+            valid_len += line.len();
+        }
+        let log = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&log_path)?;
+        if valid_len < log_bytes.len() {
+            log.set_len(valid_len as u64)?;
+        }
+        // Rows of replaced and deleted entries, and rows written just before
+        // a crash that never reached the log
+        let dead_rows = rows as usize - records.len();
+
+        let mut store = Self {
+            dir: dir.to_path_buf(),
+            generation,
+            dimension,
+            vectors,
+            map: None,
+            rows: rows as u32,
+            log,
+            records,
+            dead_rows,
+        };
+        store.remap()?;
+        Ok(store)
+    }
+
+    /// Number of live entries
+    pub fn len(&self) -> usize {
+        self.records.len()
+    }
+
+    /// Whether the store has no live entries
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+
+    /// Whether an entry with this id is stored
+    pub fn contains(&self, id: &str) -> bool {
+        self.records.contains_key(id)
+    }
+
+    /// Embedding dimension (0 until the first upsert)
+    pub fn dimension(&self) -> usize {
+        self.dimension
+    }
+
+    /// Insert entries, replacing entries with the same id
+    ///
+    /// The batch is durable when this returns.
+    pub fn upsert(&mut self, entries: &[EmbeddingEntry]) -> Result<()> {
+        if entries.is_empty() {
+            return Ok(());
+        }
+        let mut dimension = self.dimension;
+        for entry in entries {
+            if entry.vector.is_empty() {
+                bail!("Cannot store an empty vector for {}", entry.id);
+            }
+            if dimension == 0 {
+                dimension = entry.vector.len();
+            } else if entry.vector.len() != dimension {
+                bail!(
+                    "Vector for {} has dimension {}, the store has {}",
+                    entry.id,
+                    entry.vector.len(),
+                    dimension
+                );
+            }
+        }
+        if self.dimension == 0 {
+            self.vectors.seek(SeekFrom::Start(0))?;
+            write_header(&mut self.vectors, dimension)?;
+            self.dimension = dimension;
+        }
+
+        // Vectors first, so every log line points at synced data
+        let mut bytes = Vec::with_capacity(entries.len() * dimension * 4);
+        for entry in entries {
+            for value in &entry.vector {
+                bytes.extend_from_slice(&value.to_le_bytes());
+            }
+        }
+        let first_row = self.rows;
+        let mut lines = Vec::new();
+        let mut records = Vec::with_capacity(entries.len());
+        for (i, entry) in entries.iter().enumerate() {
+            let row = first_row + i as u32;
+            let record = LogRecord::Upsert {
+                id: entry.id.clone(),
+                row,
+                text_preview: entry.text_preview.clone(),
+                metadata: entry.metadata.clone(),
+            };
+            serde_json::to_writer(&mut lines, &record)?;
+            lines.push(b'\n');
+            records.push(record);
+        }
+        let rows_len = HEADER_LEN + first_row as u64 * (dimension * 4) as u64;
+        let log_len = self.log.metadata()?.len();
+        if let Err(e) = self.write_rows(rows_len, &bytes, &lines) {
+            // Drop what the failed write left behind, so the next upsert's
+            // rows line up with its log lines
+            let _ = self.vectors.set_len(rows_len);
+            let _ = self.log.set_len(log_len);
+            return Err(e);
+        }
+
+        self.rows += records.len() as u32;
+        for record in records {
+            if let LogRecord::Upsert {
+                id,
+                row,
+                text_preview,
+                metadata,
+            } = record
+            {
+                let record = Record {
+                    row,
+                    text_preview,
+                    metadata,
+                };
+                if self.records.insert(id, record).is_some() {
+                    self.dead_rows += 1;
+                }
+            }
+        }
+        self.remap()?;
+        self.compact_if_sparse()
+    }
+
+    /// Delete entries by id
+    ///
+    /// # Returns
+    /// * Number of entries that existed
+    pub fn delete(&mut self, ids: &[String]) -> Result<usize> {
+        let existing: Vec<&String> = ids.iter().filter(|id| self.contains(id)).collect();
+        if existing.is_empty() {
+            return Ok(0);
+        }
+        let mut lines = Vec::new();
+        for id in &existing {
+            let record = LogRecord::Delete { id: id.to_string() };
+            serde_json::to_writer(&mut lines, &record)?;
+            lines.push(b'\n');
+        }
+        self.append_log(&lines)?;
+
+        let mut removed = 0;
+        for id in ids {
+            if self.records.remove(id).is_some() {
+                removed += 1;
+            }
+        }
+        self.dead_rows += removed;
+        self.compact_if_sparse()?;
+        Ok(removed)
+    }
+
+    /// A stored entry by id
+    pub fn get(&self, id: &str) -> Option<EmbeddingEntry> {
+        self.records.get(id).map(|record| self.entry(id, record))
+    }
+
+    /// All live entries, oldest write first
+    pub fn entries(&self) -> Vec<EmbeddingEntry> {
+        self.records_by_row()
+            .into_iter()
+            .map(|(id, record)| self.entry(id, record))
+            .collect()
+    }
+
+    /// Visit every live entry's id, vector, preview and metadata, oldest
+    /// write first
+    ///
+    /// Rows are decoded one at a time, so the store's vectors are never all
+    /// copied into memory.
+    pub fn for_each_vector(
+        &self,
+        mut visit: impl FnMut(&str, &[f32], Option<&String>, &NoteMetadata) -> Result<()>,
+    ) -> Result<()> {
+        let mut vector = Vec::with_capacity(self.dimension);
+        for (id, record) in self.records_by_row() {
+            decode_row(self.row_bytes(record.row), &mut vector);
+            visit(id, &vector, record.text_preview.as_ref(), &record.metadata)?;
+        }
+        Ok(())
+    }
+
+    /// Find the entries most similar to a query embedding
+    ///
+    /// Scans the mapped vectors without copying them.
+    ///
+    /// # Arguments
+    /// * `query` - The query embedding vector
+    /// * `top_k` - Number of results to return
+    /// * `threshold` - Minimum similarity score
+    ///
+    /// # Returns
+    /// * Results sorted by score descending; empty if the query's dimension
+    ///   differs from the store's
+    pub fn search(&self, query: &[f32], top_k: usize, threshold: f32) -> Vec<SimilarityResult> {
+        self.search_where(query, top_k, threshold, |_| true)
+    }
+
+    /// Find the entries most similar to a query embedding among those whose
+    /// metadata passes `keep`
+    ///
+    /// Filters on the records before scoring, and scans the mapped vectors
+    /// without copying them.
+    ///
+    /// # Returns
+    /// * Results sorted by score descending; empty if the query's dimension
+    ///   differs from the store's
+    pub fn search_where(
+        &self,
+        query: &[f32],
+        top_k: usize,
+        threshold: f32,
+        keep: impl Fn(&NoteMetadata) -> bool,
+    ) -> Vec<SimilarityResult> {
+        if query.len() != self.dimension {
+            return Vec::new();
+        }
+        let query_norm = query.iter().map(|x| x * x).sum::<f32>().sqrt();
+        let mut results: Vec<SimilarityResult> = self
+            .records
+            .iter()
+            .filter(|(_, record)| keep(&record.metadata))
+            .map(|(id, record)| SimilarityResult {
+                id: id.clone(),
+                score: similarity(query, query_norm, self.row_bytes(record.row)),
+                text_preview: record.text_preview.clone(),
+            })
+            .filter(|result| result.score >= threshold)
+            .collect();
+        results.sort_by(|a, b| b.score.total_cmp(&a.score).then_with(|| a.id.cmp(&b.id)));
+        results.truncate(top_k);
+        results
+    }
+
+    /// Pairs of entries whose vectors are at least `threshold` similar
+    ///
+    /// Compares every pair of mapped rows, decoding one row at a time.
+    ///
+    /// # Returns
+    /// * `(earlier id, later id, similarity)`, in write order of the earlier
+    ///   entry, then of the later one
+    pub fn similar_pairs(&self, threshold: f32) -> Vec<(&str, &str, f32)> {
+        let records = self.records_by_row();
+        let mut pairs = Vec::new();
+        let mut vector = Vec::with_capacity(self.dimension);
+        for (i, (id, record)) in records.iter().enumerate() {
+            decode_row(self.row_bytes(record.row), &mut vector);
+            let norm = vector.iter().map(|x| x * x).sum::<f32>().sqrt();
+            for (other_id, other) in &records[i + 1..] {
+                let score = similarity(&vector, norm, self.row_bytes(other.row));
+                if score >= threshold {
+                    pairs.push((id.as_str(), other_id.as_str(), score));
+                }
+            }
+        }
+        pairs
+    }
+
+    /// Entry count, garbage and file sizes
+    pub fn stats(&self) -> Result<VectorStoreStats> {
+        Ok(VectorStoreStats {
+            entries: self.records.len(),
+            dead_rows: self.dead_rows,
+            dimension: self.dimension,
+            file_bytes: self.vectors.metadata()?.len() + self.log.metadata()?.len(),
+        })
+    }
+
+    /// Rewrite the store without dead rows and superseded log records
+    ///
+    /// The new generation becomes visible atomically, and only once it has
+    /// been opened; a crash or error before the switch leaves the store on
+    /// the old generation.
+    pub fn compact(&mut self) -> Result<VectorStoreStats> {
+        let generation = self.generation + 1;
+        {
+            let records = self.records_by_row();
+            let mut vectors = BufWriter::new(File::create(vectors_path(&self.dir, generation))?);
+            write_header(&mut vectors, self.dimension)?;
+            for (_, record) in &records {
+                vectors.write_all(self.row_bytes(record.row))?;
+            }
+            vectors
+                .into_inner()
+                .map_err(|e| e.into_error())?
+                .sync_all()?;
+
+            let mut log = BufWriter::new(File::create(log_path(&self.dir, generation))?);
+            for (row, (id, record)) in records.into_iter().enumerate() {
+                let record = LogRecord::Upsert {
+                    id: id.clone(),
+                    row: row as u32,
+                    text_preview: record.text_preview.clone(),
+                    metadata: record.metadata.clone(),
+                };
+                serde_json::to_writer(&mut log, &record)?;
+                log.write_all(b"\n")?;
+            }
+            log.into_inner().map_err(|e| e.into_error())?.sync_all()?;
+        }
+        // Files of a failed attempt are removed on the next open
+        let compacted = Self::open_generation(&self.dir, generation)?;
+        write_current(&self.dir, generation)?;
+
+        // Replacing the store closes the old files, so they can be removed
+        *self = compacted;
+        remove_other_generations(&self.dir, generation)?;
+        self.stats()
+    }
+
+    fn compact_if_sparse(&mut self) -> Result<()> {
+        if self.dead_rows > self.records.len() {
+            self.compact()?;
+        }
+        Ok(())
+    }
+
+    /// Write vector rows at `offset`, then the log lines referring to them
+    fn write_rows(&mut self, offset: u64, rows: &[u8], lines: &[u8]) -> Result<()> {
+        self.vectors.seek(SeekFrom::Start(offset))?;
+        self.vectors.write_all(rows)?;
+        self.vectors.sync_data()?;
+        self.append_log(lines)
+    }
+
+    fn append_log(&mut self, lines: &[u8]) -> Result<()> {
+        self.log.write_all(lines)?;
+        self.log.sync_data()?;
+        Ok(())
+    }
+
+    fn remap(&mut self) -> Result<()> {
+        self.map = None;
+        if self.rows > 0 {
+            // SAFETY: the file is private to this store, which only appends
+            // to it and remaps afterwards; truncation after a failed upsert
+            // only removes bytes past the mapped rows.
+            self.map = Some(unsafe { Mmap::map(&self.vectors)? });
+        }
+        Ok(())
+    }
+
+    fn row_bytes(&self, row: u32) -> &[u8] {
+        let row_len = self.dimension * 4;
+        let start = HEADER_LEN as usize + row as usize * row_len;
+        match &self.map {
+            Some(map) => &map[start..start + row_len],
+            None => &[],
+        }
+    }
+
+    /// Live records, oldest write first
+    fn records_by_row(&self) -> Vec<(&String, &Record)> {
+        let mut records: Vec<(&String, &Record)> = self.records.iter().collect();
+        records.sort_by_key(|(_, record)| record.row);
+        records
+    }
+
+    fn entry(&self, id: &str, record: &Record) -> EmbeddingEntry {
+        let mut vector = Vec::with_capacity(self.dimension);
+        decode_row(self.row_bytes(record.row), &mut vector);
+        EmbeddingEntry {
+            id: id.to_string(),
+            vector,
+            text_preview: record.text_preview.clone(),
+            metadata: record.metadata.clone(),
+        }
+    }
+}
+
+/// Replace the contents of `vector` with a stored row
+fn decode_row(row: &[u8], vector: &mut Vec<f32>) {
+    vector.clear();
+    vector.extend(
+        row.chunks_exact(4)
+            .map(|b| f32::from_le_bytes([b[0], b[1], b[2], b[3]])),
+    );
+}
+
+/// Cosine similarity of a query and a stored row
+fn similarity(query: &[f32], query_norm: f32, row: &[u8]) -> f32 {
+    let mut dot = 0.0;
+    let mut norm = 0.0;
+    for (q, b) in query.iter().zip(row.chunks_exact(4)) {
+        let value = f32::from_le_bytes([b[0], b[1], b[2], b[3]]);
+        dot += q * value;
+        norm += value * value;
+    }
+    if query_norm == 0.0 || norm == 0.0 {
+        return 0.0;
+    }
+    dot / (query_norm * norm.sqrt())
+}
+
+fn vectors_path(dir: &Path, generation: u64) -> PathBuf {
+    dir.join(format!("vectors-{}.bin", generation))
+}
+
+fn log_path(dir: &Path, generation: u64) -> PathBuf {
+    dir.join(format!("entries-{}.log", generation))
+}
+
+fn write_header(out: &mut impl Write, dimension: usize) -> Result<()> {
+    out.write_all(MAGIC)?;
+    out.write_all(&FORMAT_VERSION.to_le_bytes())?;
+    out.write_all(&(dimension as u32).to_le_bytes())?;
+    out.flush()?;
+    Ok(())
+}
+
+fn read_header(file: &File, dir: &Path) -> Result<usize> {
+    let mut header = [0u8; HEADER_LEN as usize];
+    let mut reader = file;
+    reader.seek(SeekFrom::Start(0))?;
+    reader.read_exact(&mut header)?;
+    if &header[..8] != MAGIC {
+        bail!("{} is not a vector store", dir.display());
+    }
+    let version = u32::from_le_bytes([header[8], header[9], header[10], header[11]]);
+    if version > FORMAT_VERSION {
+        bail!(
+            "Vector store format version {} is newer than supported ({})",
+            version,
+            FORMAT_VERSION
+        );
+    }
+    Ok(u32::from_le_bytes([header[12], header[13], header[14], header[15]]) as usize)
+}
+
+/// Atomically point the store at a generation
+fn write_current(dir: &Path, generation: u64) -> Result<()> {
+    let current = Current {
+        version: FORMAT_VERSION,
+        generation,
+    };
+    let tmp_path = dir.join("CURRENT.tmp");
+    let mut file = File::create(&tmp_path)?;
+    file.write_all(serde_json::to_string(&current)?.as_bytes())?;
+    file.sync_all()?;
+    fs::rename(&tmp_path, dir.join("CURRENT"))?;
+    Ok(())
+}
+
+/// Remove files of older generations and unfinished compactions
+fn remove_other_generations(dir: &Path, generation: u64) -> Result<()> {
+    let keep = [
+        vectors_path(dir, generation),
+        log_path(dir, generation),
+        dir.join("CURRENT"),
+    ];
+    for file in fs::read_dir(dir)? {
+        let path = file?.path();
+        let name = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
+        let ours = name.starts_with("vectors-") || name.starts_with("entries-");
+        if ours && !keep.contains(&path) {
+            // Files still open elsewhere (on Windows) are removed on a later open
+            let _ = fs::remove_file(&path);
+        }
+    }
+    Ok(())
+}
+
+// Open stores
+
+/// An open store and its canonical directory
+type OpenStore = (PathBuf, Arc<Mutex<VectorStore>>);
+
+/// Open stores by id
+static STORES: Lazy<Mutex<HashMap<u64, OpenStore>>> = Lazy::new(|| Mutex::new(HashMap::new()));
+
+static NEXT_STORE_ID: AtomicU64 = AtomicU64::new(1);
+
+/// An open store by id
+pub(crate) fn find_store(store_id: u64) -> Result<Arc<Mutex<VectorStore>>> {
+    STORES
+        .lock()
+        .get(&store_id)
+        .map(|(_, store)| store.clone())
+        .ok_or_else(|| anyhow!("Unknown vector store: {}", store_id))
+}
+
+/// Open a store directory
+///
+/// Opening a directory that is already open returns the existing id, so
+/// two handles never write the same files.
+///
+/// # Returns
+/// * Store id for the other store functions
+pub fn open_store(dir: &Path) -> Result<u64> {
+    fs::create_dir_all(dir)?;
+    let dir = dir.canonicalize()?;
+    // Directories are compared without locking the stores, which may be
+    // busy with a long upsert or compaction
+    let mut stores = STORES.lock();
+    if let Some((&id, _)) = stores.iter().find(|(_, (open_dir, _))| *open_dir == dir) {
+        return Ok(id);
+    }
+    let store = VectorStore::open(&dir)?;
+    let id = NEXT_STORE_ID.fetch_add(1, Ordering::Relaxed);
+    stores.insert(id, (dir, Arc::new(Mutex::new(store))));
+    Ok(id)
+}
+
+/// Close a store
+///
+/// # Returns
+/// * `true` if the store was open
+pub fn close_store(store_id: u64) -> bool {
+    STORES.lock().remove(&store_id).is_some()
+}
+
+/// Insert or replace entries in a store
+///
+/// Quantized indexes built from the store are updated too.
+pub fn store_upsert(store_id: u64, entries: Vec<EmbeddingEntry>) -> Result<()> {
+    find_store(store_id)?.lock().upsert(&entries)?;
+    quantization::store_upserted(store_id, &entries)
+}
+
+/// Delete entries from a store
+///
+/// # Returns
+/// * Number of entries that existed
+pub fn store_delete(store_id: u64, ids: &[String]) -> Result<usize> {
+    let removed = find_store(store_id)?.lock().delete(ids)?;
+    quantization::store_deleted(store_id, ids);
+    Ok(removed)
+}
+
+/// Entries of a store by id; unknown ids are skipped
+pub fn store_get(store_id: u64, ids: &[String]) -> Result<Vec<EmbeddingEntry>> {
+    let store = find_store(store_id)?;
+    let store = store.lock();
+    Ok(ids.iter().filter_map(|id| store.get(id)).collect())
+}
+
+/// Number of live entries in a store
+pub fn store_len(store_id: u64) -> Result<usize> {
+    Ok(find_store(store_id)?.lock().len())
+}
+
+/// Entry count, garbage and file sizes of a store
+pub fn store_stats(store_id: u64) -> Result<VectorStoreStats> {
+    find_store(store_id)?.lock().stats()
+}
+
+/// Compact a store
+pub fn compact_store(store_id: u64) -> Result<VectorStoreStats> {
+    find_store(store_id)?.lock().compact()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(id: &str, vector: Vec<f32>) -> EmbeddingEntry {
+        EmbeddingEntry {
+            id: id.to_string(),
+            vector,
+            text_preview: Some(format!("preview of {}", id)),
+            metadata: NoteMetadata::default(),
+        }
+    }
+
+    #[test]
+    fn test_upsert_delete_and_reopen() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut store = VectorStore::open(dir.path()).unwrap();
+        store
+            .upsert(&[
+                entry("a", vec![1.0, 0.0]),
+                entry("b", vec![0.0, 1.0]),
+                entry("c", vec![0.7, 0.7]),
+            ])
+            .unwrap();
+        let mut moved = entry("b", vec![0.9, 0.1]);
+        moved.metadata.tags = vec!["moved".to_string()];
+        store.upsert(&[moved]).unwrap();
+        assert_eq!(
+            store.delete(&["c".to_string(), "zz".to_string()]).unwrap(),
+            1
+        );
+        assert!(store.upsert(&[entry("d", vec![1.0; 3])]).is_err());
+
+        let results = store.search(&[1.0, 0.0], 5, 0.5);
+        let ids: Vec<&str> = results.iter().map(|r| r.id.as_str()).collect();
+        assert_eq!(ids, vec!["a", "b"]);
+        assert!(store.search(&[1.0, 0.0, 0.0], 5, -1.0).is_empty());
+        drop(store);
+
+        let store = VectorStore::open(dir.path()).unwrap();
+        assert_eq!(store.len(), 2);
+        assert_eq!(store.dimension(), 2);
+        let b = store.get("b").unwrap();
+        assert_eq!(b.vector, vec![0.9, 0.1]);
+        assert_eq!(b.metadata.tags, vec!["moved"]);
+        assert_eq!(store.stats().unwrap().dead_rows, 2);
+        let ids: Vec<String> = store.entries().into_iter().map(|e| e.id).collect();
+        assert_eq!(ids, vec!["a", "b"]);
+    }
+
+    #[test]
+    fn test_torn_writes_are_dropped() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut store = VectorStore::open(dir.path()).unwrap();
+        store.upsert(&[entry("a", vec![1.0, 0.0])]).unwrap();
+        drop(store);
+
+        // A crash mid-row and mid-log-line
+        let mut vectors = OpenOptions::new()
+            .append(true)
+            .open(vectors_path(dir.path(), 0))
+            .unwrap();
+        vectors.write_all(&[0, 0, 128]).unwrap();
+        let mut log = OpenOptions::new()
+            .append(true)
+            .open(log_path(dir.path(), 0))
+            .unwrap();
+        log.write_all(br#"{"op":"upsert","id":"b","#).unwrap();
+
+        let mut store = VectorStore::open(dir.path()).unwrap();
+        assert_eq!(store.len(), 1);
+        store.upsert(&[entry("b", vec![0.0, 1.0])]).unwrap();
+        drop(store);
+        let store = VectorStore::open(dir.path()).unwrap();
+        assert_eq!(store.get("b").unwrap().vector, vec![0.0, 1.0]);
+    }
+
+    #[test]
+    fn test_failed_log_append_is_rolled_back() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut store = VectorStore::open(dir.path()).unwrap();
+        store.upsert(&[entry("a", vec![1.0, 0.0])]).unwrap();
+
+        // A log handle that cannot be written fails the append after the
+        // vectors were synced
+        let log_file = log_path(dir.path(), 0);
+        let log = std::mem::replace(&mut store.log, File::open(&log_file).unwrap());
+        assert!(store.upsert(&[entry("b", vec![0.0, 1.0])]).is_err());
+        store.log = log;
+        assert!(!store.contains("b"));
+
+        store.upsert(&[entry("c", vec![0.6, 0.8])]).unwrap();
+        assert_eq!(store.get("c").unwrap().vector, vec![0.6, 0.8]);
+        assert_eq!(store.get("a").unwrap().vector, vec![1.0, 0.0]);
+        drop(store);
+
+        let store = VectorStore::open(dir.path()).unwrap();
+        assert_eq!(store.len(), 2);
+        assert_eq!(store.stats().unwrap().dead_rows, 0);
+        assert_eq!(store.get("c").unwrap().vector, vec![0.6, 0.8]);
+    }
+
+    #[test]
+    fn test_compaction_switches_generation() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut store = VectorStore::open(dir.path()).unwrap();
+        let entries: Vec<EmbeddingEntry> = (0..10)
+            .map(|i| entry(&format!("n{}", i), vec![i as f32, 1.0]))
+            .collect();
+        store.upsert(&entries).unwrap();
+        let before = store.stats().unwrap().file_bytes;
+
+        // Deleting most entries compacts automatically
+        let ids: Vec<String> = (0..7).map(|i| format!("n{}", i)).collect();
+        store.delete(&ids).unwrap();
+        let stats = store.stats().unwrap();
+        assert_eq!(stats.entries, 3);
+        assert_eq!(stats.dead_rows, 0);
+        assert!(stats.file_bytes < before);
+        assert_eq!(store.generation, 1);
+        assert!(!vectors_path(dir.path(), 0).exists());
+
+        // A failed compaction leaves the store on its generation
+        fs::create_dir(log_path(dir.path(), 2)).unwrap();
+        assert!(store.compact().is_err());
+        assert_eq!(store.generation, 1);
+        store.upsert(&[entry("n0", vec![0.0, 1.0])]).unwrap();
+        fs::remove_dir(log_path(dir.path(), 2)).unwrap();
+
+        drop(store);
+        let store = VectorStore::open(dir.path()).unwrap();
+        assert_eq!(store.get("n9").unwrap().vector, vec![9.0, 1.0]);
+        assert_eq!(store.get("n0").unwrap().vector, vec![0.0, 1.0]);
+        assert!(store.get("n1").is_none());
+    }
+}